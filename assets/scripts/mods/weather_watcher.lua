@@ -0,0 +1,29 @@
+-- Weather Watcher Example Mod
+-- Shows the gameplay API: event subscriptions, delayed callbacks, smart objects and the save store
+
+function on_weather_changed(weather, temperature)
+    local storms = tonumber(store_get("storms_seen") or "0")
+    if weather == "Rainy" or weather == "Snowy" then
+        storms = storms + 1
+        store_set("storms_seen", tostring(storms))
+        log_info("Bad weather #" .. storms .. " at " .. temperature .. "C, putting out a box to shelter in shortly")
+        schedule_callback(5.0, "put_out_shelter")
+    end
+end
+
+function put_out_shelter()
+    if #find_smart_objects("cardboard_box") == 0 then
+        spawn_smart_object("cardboard_box", 120.0, -80.0)
+    end
+end
+
+function on_photo_taken(species, score)
+    if species ~= "" and score > tonumber(store_get("best_score") or "0") then
+        store_set("best_score", tostring(score))
+        store_set("best_species", species)
+        log_info("New best photo: " .. species .. " (" .. score .. ")")
+    end
+end
+
+subscribe_event("weather_changed", "on_weather_changed")
+subscribe_event("photo_taken", "on_photo_taken")
@@ -0,0 +1,52 @@
+// Full-screen fog: a pale veil that washes out contrast, with slow drifting wisps.
+// params: x = density 0-1, y = seconds elapsed, z = drift speed, w unused
+#import bevy_ui::ui_vertex_output::UiVertexOutput
+
+@group(1) @binding(0) var<uniform> color: vec4<f32>;
+@group(1) @binding(1) var<uniform> params: vec4<f32>;
+
+fn hash(p: vec2<f32>) -> f32 {
+    return fract(sin(dot(p, vec2<f32>(127.1, 311.7))) * 43758.5453);
+}
+
+fn value_noise(p: vec2<f32>) -> f32 {
+    let i = floor(p);
+    let f = fract(p);
+    let u = f * f * (3.0 - 2.0 * f);
+    let a = hash(i);
+    let b = hash(i + vec2<f32>(1.0, 0.0));
+    let c = hash(i + vec2<f32>(0.0, 1.0));
+    let d = hash(i + vec2<f32>(1.0, 1.0));
+    return mix(mix(a, b, u.x), mix(c, d, u.x), u.y);
+}
+
+fn wisps(p: vec2<f32>) -> f32 {
+    var total = 0.0;
+    var amplitude = 0.5;
+    var q = p;
+    for (var octave = 0; octave < 4; octave++) {
+        total += value_noise(q) * amplitude;
+        q *= 2.0;
+        amplitude *= 0.5;
+    }
+    return total;
+}
+
+@fragment
+fn fragment(in: UiVertexOutput) -> @location(0) vec4<f32> {
+    let density = params.x;
+    let time = params.y;
+    let drift = params.z;
+
+    // Stretched sideways so the banks read as low-lying layers rather than blobs
+    let aspect = in.size.x / max(in.size.y, 1.0);
+    let p = vec2<f32>(in.uv.x * aspect * 2.0, in.uv.y * 5.0);
+    let banks = wisps(p + vec2<f32>(time * drift * 0.03, 0.0));
+    let detail = wisps(p * 2.5 - vec2<f32>(time * drift * 0.05, time * 0.01));
+
+    // Thicker toward the top of the view, where the yard is furthest away
+    let depth = mix(1.0, 0.7, in.uv.y);
+    let veil = density * 0.55 * depth;
+    let alpha = clamp(veil + density * (banks * 0.35 + detail * 0.15 - 0.2), 0.0, 0.9);
+    return vec4<f32>(color.rgb, alpha * color.a);
+}
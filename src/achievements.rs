@@ -14,8 +14,9 @@ impl Plugin for AchievementPlugin {
                 photo_achievement_system,
                 species_achievement_system,
                 currency_achievement_system,
+                sustainability_achievement_system,
                 achievement_notification_system,
-            ).run_if(in_state(crate::AppState::Playing)));
+            ).in_set(crate::GameSet::Simulation).run_if(in_state(crate::AppState::Playing)));
     }
 }
 
@@ -39,6 +40,11 @@ pub enum Achievement {
     // Feeder achievements
     FeederMaintainer,     // Upgrade first feeder
     FeederExpert,         // Have 3 level-2+ feeders
+    
+    // Sustainability achievements
+    FirstCompost,         // Spread a batch of compost
+    WasteNotWantNot,      // Compost 100 scoops of waste
+    GreenSanctuary,       // Reach a sustainability score of 75
 }
 
 impl Achievement {
@@ -55,6 +61,9 @@ impl Achievement {
             Self::Millionaire => "Millionaire",
             Self::FeederMaintainer => "Feeder Maintainer",
             Self::FeederExpert => "Feeder Expert",
+            Self::FirstCompost => "Black Gold",
+            Self::WasteNotWantNot => "Waste Not, Want Not",
+            Self::GreenSanctuary => "Green Sanctuary",
         }
     }
     
@@ -71,6 +80,9 @@ impl Achievement {
             Self::Millionaire => "Accumulate 10,000 currency",
             Self::FeederMaintainer => "Upgrade your first feeder",
             Self::FeederExpert => "Have 3 feeders at level 2 or higher",
+            Self::FirstCompost => "Spread your first batch of compost",
+            Self::WasteNotWantNot => "Compost 100 scoops of seed hulls and spills",
+            Self::GreenSanctuary => "Reach a sustainability score of 75",
         }
     }
     
@@ -78,8 +90,9 @@ impl Achievement {
         match self {
             Self::FirstPhoto | Self::FirstSpecies => 25,
             Self::ActionShot | Self::MultiSpeciesShot => 50,
-            Self::FeederMaintainer => 75,
-            Self::CommonCollector | Self::FeederExpert => 100,
+            Self::FeederMaintainer | Self::FirstCompost => 75,
+            Self::CommonCollector | Self::FeederExpert | Self::WasteNotWantNot => 100,
+            Self::GreenSanctuary => 150,
             Self::PhotoMaster | Self::Ornithologist => 200,
             Self::Wealthy => 250,
             Self::Millionaire => 500,
@@ -220,4 +233,33 @@ fn currency_achievement_system(
             achievement_events.write(AchievementUnlockedEvent { achievement: Achievement::Millionaire });
         }
     }
-}
\ No newline at end of file
+}
+
+fn sustainability_achievement_system(
+    compost: Res<crate::compost::Compost>,
+    sustainability: Res<crate::sanctuary_management::SustainabilityScore>,
+    mut progress: ResMut<AchievementProgress>,
+    mut achievement_events: EventWriter<AchievementUnlockedEvent>,
+) {
+    if compost.is_changed() {
+        // First compost spread
+        if compost.batches_spread >= 1 && !progress.is_unlocked(&Achievement::FirstCompost) {
+            progress.unlock(Achievement::FirstCompost);
+            achievement_events.write(AchievementUnlockedEvent { achievement: Achievement::FirstCompost });
+        }
+        
+        // Waste not, want not
+        if compost.waste_composted >= 100.0 && !progress.is_unlocked(&Achievement::WasteNotWantNot) {
+            progress.unlock(Achievement::WasteNotWantNot);
+            achievement_events.write(AchievementUnlockedEvent { achievement: Achievement::WasteNotWantNot });
+        }
+    }
+    
+    if sustainability.is_changed() {
+        // Green sanctuary
+        if sustainability.score >= 75.0 && !progress.is_unlocked(&Achievement::GreenSanctuary) {
+            progress.unlock(Achievement::GreenSanctuary);
+            achievement_events.write(AchievementUnlockedEvent { achievement: Achievement::GreenSanctuary });
+        }
+    }
+}
@@ -2,8 +2,9 @@
 use bevy::prelude::*;
 use crate::bird::{BirdSpecies, Bird};
 use crate::bird_ai::components::{BirdAI, BirdState, Blackboard};
-use crate::environment::resources::{WeatherState, TimeState};
-use crate::environment::components::{Weather};
+use crate::environment::resources::{WeatherState, TimeState, WeatherChangeEvent};
+use crate::environment::components::{Weather, Season};
+use crate::journal::milestones::{MilestoneEvent, MilestoneKind};
 // use crate::flocking::components::Flock;
 
 pub struct AdvancedWeatherPlugin;
@@ -14,6 +15,7 @@ impl Plugin for AdvancedWeatherPlugin {
             .init_resource::<StormManager>()
             .init_resource::<TemperatureManager>()
             .init_resource::<WindManager>()
+            .init_resource::<PrecipitationRadar>()
             .add_event::<StormEvent>()
             .add_event::<EmergencyFlockingEvent>()
             .add_event::<TemperatureStressEvent>()
@@ -25,7 +27,9 @@ impl Plugin for AdvancedWeatherPlugin {
                 temperature_feeding_urgency_system,
                 wind_flight_effects_system,
                 weather_stress_system,
-            ).run_if(in_state(crate::AppState::Playing)));
+                precipitation_radar_system,
+                precipitation_arrival_system,
+            ).in_set(crate::GameSet::Simulation).run_if(in_state(crate::AppState::Playing)));
     }
 }
 
@@ -150,6 +154,7 @@ impl WindEffects {
             Weather::Rainy => base_speed * 1.2,
             Weather::Snowy => base_speed * 0.8,
             Weather::Windy => base_speed * 2.5,
+            Weather::Foggy => base_speed * 0.3,
         };
 
         Self {
@@ -236,6 +241,94 @@ pub struct WindManager {
     pub base_wind_speed: f32,
 }
 
+// Radar coverage radius around the sanctuary, in km
+pub const RADAR_RANGE_KM: f32 = 100.0;
+
+// Precipitation cells tracked across the region, positioned in km relative to the sanctuary
+#[derive(Resource, Default)]
+pub struct PrecipitationRadar {
+    pub cells: Vec<PrecipitationCell>,
+    hours_until_next_cell: f32,
+    was_overhead: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct PrecipitationCell {
+    pub kind: PrecipitationKind,
+    pub position: Vec2,
+    pub velocity: Vec2, // km per game hour
+    pub radius: f32,    // km
+    pub intensity: f32, // 0.0-1.0
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PrecipitationKind {
+    Rain,
+    Snow,
+    Thunderstorm,
+}
+
+impl PrecipitationKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Rain => "Rain",
+            Self::Snow => "Snow",
+            Self::Thunderstorm => "Thunderstorm",
+        }
+    }
+
+    pub fn weather(&self) -> Weather {
+        match self {
+            Self::Snow => Weather::Snowy,
+            Self::Rain | Self::Thunderstorm => Weather::Rainy,
+        }
+    }
+}
+
+impl PrecipitationCell {
+    pub fn position_in(&self, hours: f32) -> Vec2 {
+        self.position + self.velocity * hours
+    }
+
+    pub fn covers_sanctuary_in(&self, hours: f32) -> bool {
+        self.position_in(hours).length() <= self.radius
+    }
+
+    // Hours until the leading edge reaches the sanctuary, if its track crosses it at all
+    pub fn hours_until_arrival(&self) -> Option<f32> {
+        if self.covers_sanctuary_in(0.0) {
+            return Some(0.0);
+        }
+        let speed_sq = self.velocity.length_squared();
+        if speed_sq <= f32::EPSILON {
+            return None;
+        }
+        // Closest approach along the track, then back off by the cell radius
+        let closest_time = -self.position.dot(self.velocity) / speed_sq;
+        let miss_distance = self.position_in(closest_time).length();
+        if closest_time < 0.0 || miss_distance > self.radius {
+            return None;
+        }
+        let half_chord = (self.radius * self.radius - miss_distance * miss_distance).sqrt();
+        Some((closest_time - half_chord / speed_sq.sqrt()).max(0.0))
+    }
+}
+
+impl PrecipitationRadar {
+    pub fn overhead(&self) -> Option<&PrecipitationCell> {
+        self.cells.iter()
+            .filter(|cell| cell.covers_sanctuary_in(0.0))
+            .max_by(|a, b| a.intensity.total_cmp(&b.intensity))
+    }
+
+    // Strongest cell expected over the sanctuary the given number of hours from now
+    pub fn outlook(&self, hours: f32) -> Option<&PrecipitationCell> {
+        self.cells.iter()
+            .filter(|cell| cell.covers_sanctuary_in(hours))
+            .max_by(|a, b| a.intensity.total_cmp(&b.intensity))
+    }
+}
+
 // Events
 #[derive(Event)]
 pub struct StormEvent {
@@ -310,14 +403,14 @@ fn storm_detection_system(
     weather_state: Res<WeatherState>,
     time: Res<Time>,
     mut storm_events: EventWriter<StormEvent>,
+    mut milestone_events: EventWriter<MilestoneEvent>,
 ) {
     storm_manager.time_remaining -= time.delta_secs();
     storm_manager.warning_time -= time.delta_secs();
 
     // Detect storm conditions from weather
     let new_severity = match weather_state.current_weather {
-        Weather::Clear => StormSeverity::Light,
-        Weather::Cloudy => StormSeverity::Light,
+        Weather::Clear | Weather::Cloudy | Weather::Foggy => StormSeverity::Light,
         Weather::Rainy => {
             if weather_state.temperature < 5.0 {
                 StormSeverity::Moderate // Cold rain is more severe
@@ -352,6 +445,14 @@ fn storm_detection_system(
 
     // Clear storm when time expires
     if storm_manager.time_remaining <= 0.0 {
+        let survived = match storm_manager.current_severity {
+            StormSeverity::Severe => Some("Came through a severe storm"),
+            StormSeverity::Extreme => Some("Came through an extreme storm"),
+            _ => None,
+        };
+        if let Some(title) = survived {
+            milestone_events.write(MilestoneEvent { kind: MilestoneKind::Storm, title: title.to_string(), species: None });
+        }
         storm_manager.current_severity = StormSeverity::Light;
         storm_manager.is_storm_warning = false;
     }
@@ -627,4 +728,100 @@ fn weather_stress_system(
             blackboard.internal.fear = (blackboard.internal.fear + effective_stress * time.delta_secs() * 0.2).clamp(0.0, 1.0);
         }
     }
-}
\ No newline at end of file
+}
+
+fn precipitation_radar_system(
+    mut radar: ResMut<PrecipitationRadar>,
+    weather_state: Res<WeatherState>,
+    time_state: Res<TimeState>,
+    wind_manager: Res<WindManager>,
+    time: Res<Time>,
+) {
+    let hours = time.delta_secs() / time_state.time_speed.max(0.001);
+
+    for cell in radar.cells.iter_mut() {
+        cell.position += cell.velocity * hours;
+    }
+    radar.cells.retain(|cell| cell.position.length() <= RADAR_RANGE_KM * 1.3);
+
+    // Cells ride the steering wind, which runs faster than the gusts felt at ground level
+    let direction = wind_manager.current_effects.direction.normalize_or(Vec2::X);
+    let steering_speed = wind_manager.current_effects.speed_kmh.max(15.0) * 1.5;
+    let season = time_state.get_season();
+    let cold = weather_state.temperature < 1.0;
+
+    let kind_for = |roll: f32| {
+        if cold {
+            PrecipitationKind::Snow
+        } else if season == Season::Summer && roll < 0.4 {
+            PrecipitationKind::Thunderstorm
+        } else {
+            PrecipitationKind::Rain
+        }
+    };
+
+    // Whatever the sky is doing right now has to show up on the radar
+    let raining = matches!(weather_state.current_weather, Weather::Rainy | Weather::Snowy);
+    if raining && radar.overhead().is_none() {
+        radar.cells.push(PrecipitationCell {
+            kind: kind_for(rand::random::<f32>()),
+            position: -direction * 10.0,
+            velocity: direction * steering_speed,
+            radius: 25.0 + rand::random::<f32>() * 15.0,
+            intensity: 0.5 + rand::random::<f32>() * 0.3,
+        });
+    }
+
+    radar.hours_until_next_cell -= hours;
+    if radar.hours_until_next_cell > 0.0 {
+        return;
+    }
+
+    // Wetter seasons build new cells more often
+    let interval = match season {
+        Season::Spring | Season::Fall => 3.0,
+        Season::Winter => 4.0,
+        Season::Summer => 5.0,
+    };
+    radar.hours_until_next_cell = interval * (0.5 + rand::random::<f32>());
+
+    let perpendicular = Vec2::new(-direction.y, direction.x);
+    let kind = kind_for(rand::random::<f32>());
+    radar.cells.push(PrecipitationCell {
+        kind,
+        position: -direction * RADAR_RANGE_KM + perpendicular * (rand::random::<f32>() - 0.5) * 120.0,
+        velocity: direction * steering_speed * (0.8 + rand::random::<f32>() * 0.4),
+        radius: match kind {
+            PrecipitationKind::Thunderstorm => 12.0 + rand::random::<f32>() * 10.0,
+            _ => 20.0 + rand::random::<f32>() * 25.0,
+        },
+        intensity: match kind {
+            PrecipitationKind::Thunderstorm => 0.8 + rand::random::<f32>() * 0.2,
+            _ => 0.3 + rand::random::<f32>() * 0.5,
+        },
+    });
+}
+
+// A cell drifting over the sanctuary brings its rain or snow with it
+fn precipitation_arrival_system(
+    mut radar: ResMut<PrecipitationRadar>,
+    mut weather_state: ResMut<WeatherState>,
+    mut weather_events: EventWriter<WeatherChangeEvent>,
+) {
+    let overhead_kind = radar.overhead().map(|cell| cell.kind);
+    let arrived = overhead_kind.is_some() && !radar.was_overhead;
+    radar.was_overhead = overhead_kind.is_some();
+
+    let Some(kind) = overhead_kind.filter(|_| arrived) else { return };
+    if weather_state.current_weather == kind.weather() {
+        return;
+    }
+
+    weather_state.current_weather = kind.weather();
+    weather_state.weather_timer.reset();
+    weather_events.write(WeatherChangeEvent {
+        new_weather: kind.weather(),
+        temperature: weather_state.temperature,
+    });
+    info!("🌧️ {} cell arrived over the sanctuary", kind.name());
+}
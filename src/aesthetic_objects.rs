@@ -1,5 +1,6 @@
 use bevy::prelude::*;
-use crate::environment::{components::Season, resources::TimeState};
+use crate::environment::{components::Season, resources::TimeState, region::SanctuaryRegion};
+use crate::garden_styles::GardenStyle;
 
 pub struct AestheticObjectsPlugin;
 
@@ -10,8 +11,9 @@ impl Plugin for AestheticObjectsPlugin {
             .add_systems(Update, (
                 seasonal_decoration_system,
                 wind_sway_system,
+                regional_planting_system,
                 flower_bloom_system,
-            ).run_if(in_state(crate::AppState::Playing)));
+            ).in_set(crate::GameSet::Presentation).run_if(in_state(crate::AppState::Playing)));
     }
 }
 
@@ -49,7 +51,7 @@ pub struct FlowerBed {
     pub color_intensity: f32,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum AestheticType {
     // Natural decorations
     FlowerBed,
@@ -87,6 +89,12 @@ pub enum FlowerType {
     Sunflowers,  // Late summer
     Mums,        // Fall
     Pansies,     // Cool weather
+    Brittlebush,     // Desert spring
+    DesertMarigold,  // Desert summer
+    Chamisa,         // Desert fall
+    CaliforniaPoppy, // Coastal spring
+    Fuchsia,         // Coastal summer
+    PacificAster,    // Coastal fall
 }
 
 impl AestheticType {
@@ -136,6 +144,25 @@ impl AestheticType {
         }
     }
     
+    // Decorated sprite shown in a season, for scenery that gets dressed up through the year
+    pub fn seasonal_sprite(&self, season: Season) -> Option<&'static str> {
+        match (self, season) {
+            (Self::GardenOrnament, Season::Spring) => Some("garden_ornament_blooms"),
+            (Self::GardenOrnament, Season::Fall) => Some("garden_ornament_pumpkins"),
+            (Self::GardenOrnament, Season::Winter) => Some("garden_ornament_lights"),
+            (Self::PicketFence, Season::Spring) => Some("picket_fence_blooms"),
+            (Self::PicketFence, Season::Winter) => Some("picket_fence_lights"),
+            (Self::MailboxPost, Season::Fall) => Some("mailbox_post_pumpkin"),
+            (Self::MailboxPost, Season::Winter) => Some("mailbox_post_wreath"),
+            _ => None,
+        }
+    }
+    
+    // Thematic tag used by garden style sets
+    pub fn garden_style(&self) -> Option<GardenStyle> {
+        GardenStyle::ALL.into_iter().find(|style| style.scenery().contains(self))
+    }
+    
     pub fn provides_photo_bonus(&self) -> u32 {
         match self {
             // High visual interest objects
@@ -164,6 +191,9 @@ impl FlowerType {
             Self::Roses | Self::Sunflowers => Season::Summer,
             Self::Mums => Season::Fall,
             Self::Pansies => Season::Winter, // Cool weather flowers
+            Self::Brittlebush | Self::CaliforniaPoppy => Season::Spring,
+            Self::DesertMarigold | Self::Fuchsia => Season::Summer,
+            Self::Chamisa | Self::PacificAster => Season::Fall,
         }
     }
     
@@ -176,6 +206,12 @@ impl FlowerType {
             Self::Sunflowers => Color::srgb(1.0, 0.8, 0.1),
             Self::Mums => Color::srgb(0.8, 0.5, 0.2),
             Self::Pansies => Color::srgb(0.6, 0.3, 0.8),
+            Self::Brittlebush => Color::srgb(0.95, 0.85, 0.2),
+            Self::DesertMarigold => Color::srgb(1.0, 0.75, 0.1),
+            Self::Chamisa => Color::srgb(0.85, 0.75, 0.25),
+            Self::CaliforniaPoppy => Color::srgb(1.0, 0.55, 0.1),
+            Self::Fuchsia => Color::srgb(0.85, 0.15, 0.5),
+            Self::PacificAster => Color::srgb(0.65, 0.55, 0.9),
         }
     }
 }
@@ -612,24 +648,51 @@ pub fn wind_sway_system(
     }
 }
 
+// Replants the beds and recolours the hills to suit the sanctuary's region
+pub fn regional_planting_system(
+    region: Res<SanctuaryRegion>,
+    mut flower_query: Query<&mut FlowerBed>,
+    mut hills_query: Query<(&mut Sprite, &AestheticObject)>,
+) {
+    if !region.is_changed() {
+        return;
+    }
+    
+    for mut flower_bed in &mut flower_query {
+        flower_bed.flower_type = region.0.flower_for(flower_bed.peak_season);
+    }
+    
+    for (mut sprite, object) in &mut hills_query {
+        if matches!(object.object_type, AestheticType::DistantHills) {
+            sprite.color = region.0.hills_color();
+        }
+    }
+}
+
 pub fn flower_bloom_system(
     time_state: Res<TimeState>,
-    mut flower_query: Query<(&mut Sprite, &mut FlowerBed)>,
+    mut flower_query: Query<(&mut Sprite, &mut FlowerBed, Option<&crate::garden_pests::PlantHealth>, Option<&crate::compost::Fertilized>)>,
     time: Res<Time>,
 ) {
     let current_season = time_state.get_season();
     
-    for (mut sprite, mut flower_bed) in &mut flower_query {
+    for (mut sprite, mut flower_bed, health, fertilized) in &mut flower_query {
         let in_bloom_season = flower_bed.flower_type.bloom_season() == current_season;
         
         if in_bloom_season {
-            // Blooming - increase bloom stage
-            flower_bed.bloom_stage = (flower_bed.bloom_stage + 0.5 * time.delta().as_secs_f32()).min(1.0);
+            // Blooming - increase bloom stage, faster in a composted bed
+            let growth = if fertilized.is_some() { 0.75 } else { 0.5 };
+            flower_bed.bloom_stage = (flower_bed.bloom_stage + growth * time.delta().as_secs_f32()).min(1.0);
         } else {
             // Out of season - decrease bloom stage
             flower_bed.bloom_stage = (flower_bed.bloom_stage - 0.3 * time.delta().as_secs_f32()).max(0.0);
         }
         
+        // A bed damaged by pests can't bloom past its health
+        if let Some(health) = health {
+            flower_bed.bloom_stage = flower_bed.bloom_stage.min(health.0);
+        }
+        
         // Adjust visual appearance based on bloom stage
         let base_color = flower_bed.flower_type.color();
         let [r, g, b, _] = base_color.to_srgba().to_f32_array();
@@ -0,0 +1,378 @@
+// Ambient Wildlife - Chipmunks, Rabbits and Deer
+use bevy::prelude::*;
+use std::collections::HashMap;
+use crate::environment::resources::TimeState;
+use crate::environment::components::Season;
+use crate::feeder::{Feeder, FeederType, FeederDepletionEvent};
+use crate::photo_mode::components::{PhotoTakenEvent, PhotoTarget};
+use crate::photo_mode::resources::CurrencyResource;
+use crate::predator_prey::Predator;
+use crate::domestic_threats::DomesticAnimal;
+use crate::notifications::{resources::ShowNotificationEvent, components::NotificationType};
+
+pub struct AmbientWildlifePlugin;
+
+impl Plugin for AmbientWildlifePlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<WildlifeManager>()
+            .init_resource::<OtherWildlifeLog>()
+            .add_systems(Update, (
+                wildlife_spawning_system,
+                wildlife_threat_system,
+                wildlife_movement_system,
+                wildlife_feeder_raid_system,
+                wildlife_photo_system,
+            ).chain().in_set(crate::GameSet::Simulation).run_if(in_state(crate::AppState::Playing)));
+    }
+}
+
+// Resources
+#[derive(Resource)]
+pub struct WildlifeManager {
+    pub spawn_timer: Timer,
+    pub max_animals: usize,
+}
+
+impl Default for WildlifeManager {
+    fn default() -> Self {
+        Self {
+            spawn_timer: Timer::from_seconds(40.0, TimerMode::Repeating),
+            max_animals: 4,
+        }
+    }
+}
+
+// Journal record for the "Other Wildlife" section
+#[derive(Resource, Default)]
+pub struct OtherWildlifeLog {
+    pub entries: HashMap<WildlifeSpecies, WildlifeEntry>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct WildlifeEntry {
+    pub sightings: u32,
+    pub photos: u32,
+    pub first_photographed_day: Option<u32>,
+}
+
+// Components
+#[derive(Component)]
+pub struct AmbientWildlife {
+    pub species: WildlifeSpecies,
+    pub state: WildlifeState,
+    pub target: Vec2,
+    pub state_timer: Timer,
+    pub visit_timer: Timer,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WildlifeSpecies {
+    EasternChipmunk,
+    CottontailRabbit,
+    WhiteTailedDeer,
+}
+
+impl WildlifeSpecies {
+    pub const ALL: [WildlifeSpecies; 3] = [
+        Self::EasternChipmunk,
+        Self::CottontailRabbit,
+        Self::WhiteTailedDeer,
+    ];
+
+    pub fn common_name(&self) -> &'static str {
+        match self {
+            Self::EasternChipmunk => "Eastern Chipmunk",
+            Self::CottontailRabbit => "Eastern Cottontail",
+            Self::WhiteTailedDeer => "White-tailed Deer",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::EasternChipmunk => "Stuffs its cheek pouches with spilled seed. Hibernates through winter.",
+            Self::CottontailRabbit => "Grazes on lawn edges at dawn and dusk, freezing before it bolts.",
+            Self::WhiteTailedDeer => "Visits after dark and will happily empty a platform feeder overnight.",
+        }
+    }
+
+    fn color(&self) -> Color {
+        match self {
+            Self::EasternChipmunk => Color::srgb(0.6, 0.4, 0.25),
+            Self::CottontailRabbit => Color::srgb(0.55, 0.5, 0.45),
+            Self::WhiteTailedDeer => Color::srgb(0.6, 0.45, 0.3),
+        }
+    }
+
+    fn size(&self) -> Vec2 {
+        match self {
+            Self::EasternChipmunk => Vec2::new(14.0, 10.0),
+            Self::CottontailRabbit => Vec2::new(24.0, 18.0),
+            Self::WhiteTailedDeer => Vec2::new(70.0, 60.0),
+        }
+    }
+
+    fn speed(&self) -> f32 {
+        match self {
+            Self::EasternChipmunk => 70.0,
+            Self::CottontailRabbit => 60.0,
+            Self::WhiteTailedDeer => 35.0,
+        }
+    }
+
+    // How close a threat can get before the animal bolts
+    fn flight_distance(&self) -> f32 {
+        match self {
+            Self::EasternChipmunk => 90.0,
+            Self::CottontailRabbit => 140.0,
+            Self::WhiteTailedDeer => 220.0,
+        }
+    }
+
+    pub fn is_active(&self, hour: f32, season: Season) -> bool {
+        match self {
+            Self::EasternChipmunk => season != Season::Winter && (7.0..18.0).contains(&hour),
+            Self::CottontailRabbit => (5.0..9.0).contains(&hour) || (17.0..22.0).contains(&hour),
+            Self::WhiteTailedDeer => !(6.0..19.0).contains(&hour),
+        }
+    }
+
+    fn raids_feeder(&self, feeder_type: FeederType) -> bool {
+        match self {
+            Self::EasternChipmunk => matches!(feeder_type, FeederType::Ground | FeederType::Seed),
+            Self::CottontailRabbit => false,
+            Self::WhiteTailedDeer => matches!(feeder_type, FeederType::Ground),
+        }
+    }
+
+    // Food taken per second while raiding a feeder
+    fn raid_rate(&self) -> f32 {
+        match self {
+            Self::EasternChipmunk => 0.5,
+            Self::CottontailRabbit => 0.0,
+            Self::WhiteTailedDeer => 3.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WildlifeState {
+    Foraging,
+    Moving,
+    Fleeing,
+    Leaving,
+}
+
+const GROUND_LEVEL_MIN: f32 = -260.0;
+const GROUND_LEVEL_MAX: f32 = -120.0;
+
+fn random_ground_position() -> Vec2 {
+    Vec2::new(
+        (rand::random::<f32>() - 0.5) * 1000.0,
+        GROUND_LEVEL_MIN + rand::random::<f32>() * (GROUND_LEVEL_MAX - GROUND_LEVEL_MIN),
+    )
+}
+
+fn wildlife_spawning_system(
+    mut commands: Commands,
+    mut manager: ResMut<WildlifeManager>,
+    mut wildlife_log: ResMut<OtherWildlifeLog>,
+    wildlife_query: Query<&AmbientWildlife>,
+    time_state: Res<TimeState>,
+    time: Res<Time>,
+) {
+    manager.spawn_timer.tick(time.delta());
+    if !manager.spawn_timer.just_finished() || wildlife_query.iter().count() >= manager.max_animals {
+        return;
+    }
+
+    let season = time_state.get_season();
+    let active: Vec<WildlifeSpecies> = WildlifeSpecies::ALL.iter()
+        .copied()
+        .filter(|species| species.is_active(time_state.hour, season))
+        .collect();
+
+    if active.is_empty() || rand::random::<f32>() > 0.6 {
+        return;
+    }
+
+    let species = active[rand::random::<u32>() as usize % active.len()];
+    let side = if rand::random::<bool>() { 1.0 } else { -1.0 };
+    let spawn_pos = Vec2::new(side * 650.0, GROUND_LEVEL_MIN + rand::random::<f32>() * 100.0);
+
+    commands.spawn((
+        Sprite::from_color(species.color(), species.size()),
+        Transform::from_translation(spawn_pos.extend(0.4)),
+        AmbientWildlife {
+            species,
+            state: WildlifeState::Moving,
+            target: random_ground_position(),
+            state_timer: Timer::from_seconds(5.0, TimerMode::Once),
+            visit_timer: Timer::from_seconds(90.0 + rand::random::<f32>() * 90.0, TimerMode::Once),
+        },
+    ));
+
+    wildlife_log.entries.entry(species).or_default().sightings += 1;
+    info!("🐾 A {} appeared in the sanctuary", species.common_name());
+}
+
+// Bolt away from hunting birds and visiting dogs or cats
+fn wildlife_threat_system(
+    mut wildlife_query: Query<(&mut AmbientWildlife, &Transform)>,
+    predator_query: Query<&Transform, With<Predator>>,
+    domestic_query: Query<&Transform, With<DomesticAnimal>>,
+) {
+    for (mut wildlife, transform) in wildlife_query.iter_mut() {
+        if matches!(wildlife.state, WildlifeState::Fleeing | WildlifeState::Leaving) {
+            continue;
+        }
+
+        let position = transform.translation.truncate();
+        let flight_distance = wildlife.species.flight_distance();
+
+        let threat = predator_query.iter()
+            .chain(domestic_query.iter())
+            .map(|threat_transform| threat_transform.translation.truncate())
+            .find(|threat_pos| threat_pos.distance(position) < flight_distance);
+
+        if let Some(threat_pos) = threat {
+            let away = (position - threat_pos).normalize_or_zero();
+            wildlife.state = WildlifeState::Fleeing;
+            wildlife.target = position + away * 300.0;
+            wildlife.state_timer = Timer::from_seconds(3.0, TimerMode::Once);
+        }
+    }
+}
+
+fn wildlife_movement_system(
+    mut commands: Commands,
+    mut wildlife_query: Query<(Entity, &mut AmbientWildlife, &mut Transform)>,
+    time_state: Res<TimeState>,
+    time: Res<Time>,
+) {
+    let season = time_state.get_season();
+
+    for (entity, mut wildlife, mut transform) in wildlife_query.iter_mut() {
+        wildlife.state_timer.tick(time.delta());
+        wildlife.visit_timer.tick(time.delta());
+
+        let species = wildlife.species;
+        if wildlife.state != WildlifeState::Leaving
+            && (wildlife.visit_timer.finished() || !species.is_active(time_state.hour, season))
+        {
+            wildlife.state = WildlifeState::Leaving;
+            let side = transform.translation.x.signum();
+            wildlife.target = Vec2::new(side * 750.0, transform.translation.y);
+        }
+
+        let position = transform.translation.truncate();
+        let speed = match wildlife.state {
+            WildlifeState::Foraging => {
+                // Occasional small hops while feeding
+                if wildlife.state_timer.finished() {
+                    wildlife.state = WildlifeState::Moving;
+                    wildlife.target = random_ground_position();
+                }
+                0.0
+            },
+            WildlifeState::Moving => {
+                if position.distance(wildlife.target) < 10.0 {
+                    wildlife.state = WildlifeState::Foraging;
+                    wildlife.state_timer = Timer::from_seconds(4.0 + rand::random::<f32>() * 8.0, TimerMode::Once);
+                }
+                species.speed()
+            },
+            WildlifeState::Fleeing => {
+                if wildlife.state_timer.finished() {
+                    wildlife.state = WildlifeState::Moving;
+                    wildlife.target = random_ground_position();
+                }
+                species.speed() * 3.0
+            },
+            WildlifeState::Leaving => {
+                if position.distance(wildlife.target) < 20.0 {
+                    commands.entity(entity).despawn();
+                    continue;
+                }
+                species.speed() * 1.5
+            },
+        };
+
+        let direction = (wildlife.target - position).normalize_or_zero();
+        let mut new_position = position + direction * speed * time.delta_secs();
+        if wildlife.state != WildlifeState::Leaving {
+            new_position.y = new_position.y.clamp(GROUND_LEVEL_MIN, GROUND_LEVEL_MAX);
+        }
+        transform.translation = new_position.extend(transform.translation.z);
+    }
+}
+
+// Chipmunks pilfer seed and deer strip platform feeders while foraging nearby
+fn wildlife_feeder_raid_system(
+    wildlife_query: Query<(&AmbientWildlife, &Transform)>,
+    feeder_query: Query<(Entity, &Feeder, &Transform)>,
+    mut depletion_events: EventWriter<FeederDepletionEvent>,
+    time: Res<Time>,
+) {
+    for (wildlife, transform) in wildlife_query.iter() {
+        if wildlife.state != WildlifeState::Foraging {
+            continue;
+        }
+
+        let position = transform.translation.truncate();
+        for (feeder_entity, feeder, feeder_transform) in feeder_query.iter() {
+            if feeder.current_capacity <= 0.0
+                || !wildlife.species.raids_feeder(feeder.feeder_type)
+                || feeder_transform.translation.truncate().distance(position) > 80.0
+            {
+                continue;
+            }
+
+            depletion_events.write(FeederDepletionEvent {
+                feeder_entity,
+                amount: wildlife.species.raid_rate() * time.delta_secs(),
+            });
+        }
+    }
+}
+
+fn wildlife_photo_system(
+    mut photo_events: EventReader<PhotoTakenEvent>,
+    camera_query: Query<&Transform, With<PhotoTarget>>,
+    wildlife_query: Query<(&AmbientWildlife, &Transform)>,
+    mut wildlife_log: ResMut<OtherWildlifeLog>,
+    mut currency: ResMut<CurrencyResource>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+    time_state: Res<TimeState>,
+) {
+    for _event in photo_events.read() {
+        let Ok(camera_transform) = camera_query.single() else {
+            continue;
+        };
+        let camera_pos = camera_transform.translation.truncate();
+
+        let subject = wildlife_query.iter()
+            .map(|(wildlife, transform)| (wildlife.species, transform.translation.truncate().distance(camera_pos)))
+            .filter(|(_, distance)| *distance < 200.0)
+            .min_by(|a, b| a.1.total_cmp(&b.1));
+
+        let Some((species, _)) = subject else {
+            continue;
+        };
+
+        let entry = wildlife_log.entries.entry(species).or_default();
+        entry.photos += 1;
+
+        if entry.first_photographed_day.is_none() {
+            entry.first_photographed_day = Some(time_state.day_of_year);
+            currency.0 += 25;
+            notification_events.write(ShowNotificationEvent {
+                notification: NotificationType::Info {
+                    message: format!("New entry in Other Wildlife: {}", species.common_name()),
+                },
+            });
+        }
+
+        info!("📸 Photographed a {} ({} photos)", species.common_name(), entry.photos);
+    }
+}
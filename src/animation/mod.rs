@@ -20,6 +20,6 @@ impl Plugin for AnimationPlugin {
                 update_sprite_on_state_change_system,
                 advance_animation_frames_system,
             ).chain().run_if(in_state(AppState::Playing)))
-            .add_systems(Update, sprite_flip_system.run_if(in_state(AppState::Playing)));
+            .add_systems(Update, sprite_flip_system.in_set(crate::GameSet::Presentation).run_if(in_state(AppState::Playing)));
     }
 }
\ No newline at end of file
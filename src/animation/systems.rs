@@ -24,7 +24,18 @@ fn setup_species_spritesheet(
     cache: &mut TextureAtlasCache,
 ) {
     let species_name = species_filename(&species);
-    let texture_handle = asset_server.load(&format!("birds/{}.png", species_name));
+    register_species_spritesheet(species, &format!("birds/{}.png", species_name), asset_server, texture_atlas_layouts, cache);
+}
+
+// Also used by species packs to swap in their own sheets
+pub fn register_species_spritesheet(
+    species: BirdSpecies,
+    texture_path: &str,
+    asset_server: &AssetServer,
+    texture_atlas_layouts: &mut Assets<TextureAtlasLayout>,
+    cache: &mut TextureAtlasCache,
+) {
+    let texture_handle = asset_server.load(texture_path.to_string());
     
     // Spritesheet layout: 7 rows (one per state), 6 frames per row
     let layout = TextureAtlasLayout::from_grid(
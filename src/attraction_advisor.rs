@@ -0,0 +1,436 @@
+// Attraction Advisor - Target species list with concrete steps to bring them into the yard
+use bevy::prelude::*;
+use std::collections::HashSet;
+use crate::bird::{seasonal_spawn_weights, BirdSpecies};
+use crate::bird_ai::components::{BirdAction, ProvidesUtility};
+use crate::bird_data::BirdDataRegistry;
+use crate::difficulty::DifficultySettings;
+use crate::catalog::components::{PlaceableItemType, PlaceableObject};
+use crate::environment::components::Season;
+use crate::environment::resources::{SeasonalState, TimeState};
+use crate::feeder::{Feeder, FeederType};
+use crate::journal::resources::{BirdEducationData, DiscoveredSpecies, MONTH_NAMES, MONTH_STARTS};
+use crate::keybindings::{GameAction, KeyBindings};
+use crate::sanctuary_management::{EnhancementType, HabitatEnhancement};
+
+const PANEL_WIDTH: f32 = 560.0;
+
+pub struct AttractionAdvisorPlugin;
+
+impl Plugin for AttractionAdvisorPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<SpeciesTargets>()
+            .init_resource::<AttractionAdvisor>()
+            .add_systems(Update, (
+                toggle_advisor_panel_system,
+                advisor_target_button_system,
+                refresh_advisor_panel_system,
+            ).chain().in_set(crate::GameSet::UI).run_if(in_state(crate::AppState::Playing)))
+            .add_systems(OnExit(crate::AppState::Playing), close_advisor_panel_system);
+    }
+}
+
+// Resources
+#[derive(Resource, Default, Clone)]
+pub struct SpeciesTargets {
+    pub species: HashSet<BirdSpecies>,
+}
+
+impl SpeciesTargets {
+    pub fn toggle(&mut self, species: BirdSpecies) {
+        if !self.species.remove(&species) {
+            self.species.insert(species);
+        }
+    }
+}
+
+#[derive(Resource)]
+pub struct AttractionAdvisor {
+    pub is_open: bool,
+    // Feeders and birdbaths come and go while the panel is up
+    refresh_timer: Timer,
+    panel_dirty: bool,
+}
+
+impl Default for AttractionAdvisor {
+    fn default() -> Self {
+        Self {
+            is_open: false,
+            refresh_timer: Timer::from_seconds(5.0, TimerMode::Repeating),
+            panel_dirty: false,
+        }
+    }
+}
+
+// Components
+#[derive(Component)]
+pub struct AdvisorPanel;
+
+#[derive(Component)]
+pub struct AdvisorTargetButton {
+    pub species: BirdSpecies,
+    pub targeted: bool,
+}
+
+// What the yard currently offers, gathered once per refresh
+struct YardSnapshot {
+    foods: HashSet<FeederType>,
+    has_water: bool,
+    has_native_bed: bool,
+}
+
+struct TargetAdvice {
+    species: BirdSpecies,
+    // Share of this season's arrivals, from the same weights the spawner draws with
+    share: f32,
+    actions: Vec<String>,
+}
+
+fn season_start_day(season: Season) -> u32 {
+    match season {
+        Season::Winter => 356,
+        Season::Spring => 80,
+        Season::Summer => 172,
+        Season::Fall => 265,
+    }
+}
+
+fn month_name(day_of_year: u32) -> &'static str {
+    let month = MONTH_STARTS.iter().rposition(|start| *start <= day_of_year).unwrap_or(0);
+    MONTH_NAMES[month]
+}
+
+// Catalog item that stocks each kind of food
+fn food_item(feeder_type: FeederType) -> PlaceableItemType {
+    match feeder_type {
+        FeederType::Seed => PlaceableItemType::PremiumSeed,
+        FeederType::Suet => PlaceableItemType::SuetCake,
+        FeederType::Nectar => PlaceableItemType::NectarFeeder,
+        FeederType::Fruit => PlaceableItemType::FruitDispenser,
+        FeederType::Ground => PlaceableItemType::BasicBirdSeed,
+    }
+}
+
+fn item_food(item_type: &PlaceableItemType) -> Option<FeederType> {
+    match item_type {
+        PlaceableItemType::BasicBirdSeed | PlaceableItemType::PremiumSeed => Some(FeederType::Seed),
+        PlaceableItemType::SuetCake => Some(FeederType::Suet),
+        PlaceableItemType::NectarFeeder => Some(FeederType::Nectar),
+        PlaceableItemType::FruitDispenser => Some(FeederType::Fruit),
+        _ => None,
+    }
+}
+
+fn seasonal_shares(
+    species: BirdSpecies,
+    bird_registry: &BirdDataRegistry,
+    education_data: &BirdEducationData,
+    difficulty: &DifficultySettings,
+    current: &SeasonalState,
+) -> [(Season, f32); 4] {
+    [Season::Spring, Season::Summer, Season::Fall, Season::Winter].map(|season| {
+        let mut pool = current.clone();
+        pool.update_for_season(season);
+        let weights = seasonal_spawn_weights(&pool, bird_registry, education_data, difficulty, season);
+        let total: f32 = weights.iter().map(|(_, weight)| weight).sum();
+        let weight = weights.iter().find(|(candidate, _)| *candidate == species).map_or(0.0, |(_, weight)| *weight);
+        (season, if total > 0.0 { weight / total } else { 0.0 })
+    })
+}
+
+fn advise(
+    species: BirdSpecies,
+    yard: &YardSnapshot,
+    season: Season,
+    bird_registry: &BirdDataRegistry,
+    education_data: &BirdEducationData,
+    difficulty: &DifficultySettings,
+    current: &SeasonalState,
+) -> TargetAdvice {
+    let shares = seasonal_shares(species, bird_registry, education_data, difficulty, current);
+    let share_in = |season: Season| shares.iter().find(|(s, _)| *s == season).map_or(0.0, |(_, share)| *share);
+    let share = share_in(season);
+    let mut actions = Vec::new();
+
+    // Arrival month for a season, using the passage window for migrants
+    let arrival_month = |season: Season| {
+        let day = match (season, education_data.migration_data.get(&species)) {
+            (Season::Spring, Some(migration)) if migration.is_migratory => migration.seasonal_windows().0.0,
+            (Season::Fall, Some(migration)) if migration.is_migratory => migration.seasonal_windows().1.0,
+            _ => season_start_day(season),
+        };
+        month_name(day)
+    };
+
+    let upcoming = std::iter::successors(Some(season.next()), |s| Some(s.next())).take(3);
+    let best_ahead = upcoming
+        .map(|s| (s, share_in(s)))
+        .fold(None, |best: Option<(Season, f32)>, (s, share)| match best {
+            Some((_, best_share)) if best_share >= share => best,
+            _ if share > 0.0 => Some((s, share)),
+            _ => best,
+        });
+
+    match best_ahead {
+        Some((ahead, _)) if share <= 0.0 => {
+            actions.push(format!("Not around in {:?} - wait for {}", season, arrival_month(ahead)));
+        }
+        Some((ahead, ahead_share)) if ahead_share >= share * 1.5 => {
+            actions.push(format!("Odds improve in {:?} - wait for {}", ahead, arrival_month(ahead)));
+        }
+        None if share <= 0.0 => {
+            actions.push("Not part of any season's visitors in this region".to_string());
+        }
+        _ => {}
+    }
+
+    let preferences = species.feeding_preferences();
+    if let Some(preferred) = preferences.first().copied() {
+        if !preferences.iter().any(|food| yard.foods.contains(food)) {
+            let item = food_item(preferred);
+            let verb = if preferred == FeederType::Ground { "Scatter" } else { "Add" };
+            actions.push(format!("{} {} for {:?} food", verb, item.name(), preferred));
+        }
+
+        let (peak_hour, peak) = (5..=20)
+            .map(|hour| (hour, species.time_based_feeding_modifier(preferred, hour as f32)))
+            .fold((0, 0.0), |best, candidate| if candidate.1 > best.1 { candidate } else { best });
+        if peak > 1.05 {
+            actions.push(format!("Watch around {:02}:00, its busiest feeding time", peak_hour));
+        }
+    }
+
+    if !yard.has_water {
+        actions.push(format!("Add a water feature like a {}", PlaceableItemType::BasicBirdbath.name()));
+    }
+
+    let drawn_by_natives = EnhancementType::NativePlanting.attracted_species().iter().any(|(s, _)| *s == species);
+    if drawn_by_natives && !yard.has_native_bed {
+        actions.push("Plant a native bed from the Grants panel".to_string());
+    }
+
+    TargetAdvice { species, share, actions }
+}
+
+pub fn toggle_advisor_panel_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    key_bindings: Res<KeyBindings>,
+    mut advisor: ResMut<AttractionAdvisor>,
+) {
+    if key_bindings.is_action_just_pressed(GameAction::OpenAdvisor, &keyboard, &mouse) {
+        advisor.is_open = !advisor.is_open;
+        advisor.panel_dirty = true;
+    }
+}
+
+pub fn advisor_target_button_system(
+    mut button_query: Query<(&Interaction, &AdvisorTargetButton, &mut BackgroundColor), Changed<Interaction>>,
+    mut targets: ResMut<SpeciesTargets>,
+    mut advisor: ResMut<AttractionAdvisor>,
+) {
+    for (interaction, button, mut bg_color) in &mut button_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *bg_color = Color::srgb(0.5, 0.7, 0.5).into();
+                targets.toggle(button.species);
+                advisor.panel_dirty = true;
+            }
+            Interaction::Hovered => *bg_color = Color::srgb(0.7, 0.6, 0.5).into(),
+            Interaction::None => *bg_color = target_button_color(button.targeted).into(),
+        }
+    }
+}
+
+fn target_button_color(targeted: bool) -> Color {
+    if targeted {
+        Color::srgb(0.45, 0.6, 0.4)
+    } else {
+        Color::srgb(0.6, 0.5, 0.4)
+    }
+}
+
+pub fn refresh_advisor_panel_system(
+    mut commands: Commands,
+    mut advisor: ResMut<AttractionAdvisor>,
+    targets: Res<SpeciesTargets>,
+    discovered: Res<DiscoveredSpecies>,
+    time_state: Res<TimeState>,
+    seasonal_state: Res<SeasonalState>,
+    bird_registry: Res<BirdDataRegistry>,
+    education_data: Res<BirdEducationData>,
+    difficulty: Res<DifficultySettings>,
+    feeder_query: Query<&Feeder>,
+    object_query: Query<&PlaceableObject>,
+    utility_query: Query<&ProvidesUtility>,
+    enhancement_query: Query<&HabitatEnhancement>,
+    panel_query: Query<Entity, With<AdvisorPanel>>,
+    time: Res<Time>,
+) {
+    advisor.refresh_timer.tick(time.delta());
+    let timer_fired = advisor.is_open && advisor.refresh_timer.just_finished();
+    if !advisor.panel_dirty && !timer_fired {
+        return;
+    }
+    advisor.panel_dirty = false;
+
+    for panel in &panel_query {
+        commands.entity(panel).despawn();
+    }
+
+    if !advisor.is_open {
+        return;
+    }
+
+    let yard = YardSnapshot {
+        foods: feeder_query.iter().map(|feeder| feeder.feeder_type)
+            .chain(object_query.iter().filter_map(|object| item_food(&object.item_type)))
+            .collect(),
+        has_water: utility_query.iter().any(|utility| utility.action == BirdAction::Drink),
+        has_native_bed: enhancement_query.iter()
+            .any(|enhancement| enhancement.enhancement_type == EnhancementType::NativePlanting),
+    };
+
+    let season = time_state.get_season();
+    let mut advice: Vec<TargetAdvice> = targets.species.iter()
+        .map(|species| advise(*species, &yard, season, &bird_registry, &education_data, &difficulty, &seasonal_state))
+        .collect();
+    advice.sort_by_key(|entry| format!("{:?}", entry.species));
+
+    let mut candidates: Vec<BirdSpecies> = education_data.species_facts.keys().copied().collect();
+    candidates.sort_by_key(|species| format!("{:?}", species));
+
+    spawn_advisor_panel(&mut commands, &advice, &candidates, &targets, &discovered, season);
+}
+
+pub fn close_advisor_panel_system(
+    mut commands: Commands,
+    mut advisor: ResMut<AttractionAdvisor>,
+    panel_query: Query<Entity, With<AdvisorPanel>>,
+) {
+    advisor.is_open = false;
+    for panel in &panel_query {
+        commands.entity(panel).despawn();
+    }
+}
+
+fn spawn_advisor_panel(
+    commands: &mut Commands,
+    advice: &[TargetAdvice],
+    candidates: &[BirdSpecies],
+    targets: &SpeciesTargets,
+    discovered: &DiscoveredSpecies,
+    season: Season,
+) {
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            right: Val::Px(20.0),
+            top: Val::Px(80.0),
+            width: Val::Px(PANEL_WIDTH),
+            flex_direction: FlexDirection::Column,
+            padding: UiRect::all(Val::Px(14.0)),
+            row_gap: Val::Px(6.0),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.95, 0.92, 0.88, 0.95)),
+        BorderRadius::all(Val::Px(8.0)),
+        AdvisorPanel,
+        Name::new("AttractionAdvisor"),
+    )).with_children(|panel| {
+        panel.spawn((
+            Text::new("Attraction Advisor"),
+            TextFont { font_size: 20.0, ..default() },
+            TextColor(Color::srgb(0.3, 0.2, 0.1)),
+        ));
+        panel.spawn((
+            Text::new(format!("{:?} - advice for your target species", season)),
+            TextFont { font_size: 12.0, ..default() },
+            TextColor(Color::srgb(0.5, 0.4, 0.3)),
+        ));
+
+        if advice.is_empty() {
+            panel.spawn((
+                Text::new("No targets yet. Pick species below to get advice on attracting them."),
+                TextFont { font_size: 13.0, ..default() },
+                TextColor(Color::srgb(0.4, 0.3, 0.2)),
+            ));
+        }
+
+        for entry in advice {
+            panel.spawn((
+                Node {
+                    width: Val::Percent(100.0),
+                    flex_direction: FlexDirection::Column,
+                    padding: UiRect::all(Val::Px(8.0)),
+                    row_gap: Val::Px(2.0),
+                    ..default()
+                },
+                BackgroundColor(Color::srgba(0.85, 0.8, 0.72, 0.6)),
+                BorderRadius::all(Val::Px(6.0)),
+            )).with_children(|card| {
+                let seen = if discovered.0.contains(&entry.species) { "seen" } else { "not yet seen" };
+                card.spawn((
+                    Text::new(format!("{:?} ({}) - {:.1}% of arrivals this season", entry.species, seen, entry.share * 100.0)),
+                    TextFont { font_size: 14.0, ..default() },
+                    TextColor(Color::srgb(0.3, 0.2, 0.1)),
+                ));
+
+                if entry.actions.is_empty() {
+                    card.spawn((
+                        Text::new("Your yard already has what it needs - just keep watching"),
+                        TextFont { font_size: 12.0, ..default() },
+                        TextColor(Color::srgb(0.4, 0.3, 0.2)),
+                    ));
+                }
+                for action in &entry.actions {
+                    card.spawn((
+                        Text::new(format!("- {}", action)),
+                        TextFont { font_size: 12.0, ..default() },
+                        TextColor(Color::srgb(0.4, 0.3, 0.2)),
+                    ));
+                }
+            });
+        }
+
+        panel.spawn((
+            Text::new("Species"),
+            TextFont { font_size: 14.0, ..default() },
+            TextColor(Color::srgb(0.3, 0.2, 0.1)),
+            Node {
+                margin: UiRect::top(Val::Px(6.0)),
+                ..default()
+            },
+        ));
+
+        panel.spawn(Node {
+            width: Val::Percent(100.0),
+            flex_wrap: FlexWrap::Wrap,
+            column_gap: Val::Px(4.0),
+            row_gap: Val::Px(4.0),
+            ..default()
+        }).with_children(|grid| {
+            for species in candidates {
+                let targeted = targets.species.contains(species);
+                grid.spawn((
+                    Button,
+                    Node {
+                        padding: UiRect::axes(Val::Px(6.0), Val::Px(3.0)),
+                        ..default()
+                    },
+                    BackgroundColor(target_button_color(targeted)),
+                    BorderRadius::all(Val::Px(4.0)),
+                    AdvisorTargetButton { species: *species, targeted },
+                )).with_children(|button| {
+                    let mark = if targeted { "* " } else { "" };
+                    button.spawn((
+                        Text::new(format!("{}{:?}", mark, species)),
+                        TextFont { font_size: 11.0, ..default() },
+                        TextColor(Color::WHITE),
+                    ));
+                });
+            }
+        });
+    });
+}
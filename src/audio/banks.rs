@@ -0,0 +1,188 @@
+// src/audio/banks.rs
+//
+// Music and soundscape loops are long, so instead of holding each file in memory they're decoded
+// straight from disk as they play. Bird calls are short and played constantly, so they stay
+// resident, grouped into banks by region and season: a season's bank loads the calls of every
+// species in its pool, and banks that go unused are evicted - keeping just the active one on Low
+// graphics quality, and the previous one too otherwise so calls still in flight aren't cut off.
+use bevy::prelude::*;
+use bevy::audio::Source;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use crate::audio::soundscape::{user_ambient_directory, USER_AMBIENT_SOURCE};
+use crate::audio::systems::{vocalization_path, CallType};
+use crate::environment::components::Season;
+use crate::environment::region::Region;
+use crate::environment::resources::{SeasonalState, TimeState};
+use crate::menu::resources::{GameSettings, GraphicsQuality};
+use crate::species_packs::SpeciesPackManager;
+
+const RESIDENT_BANKS: usize = 2;
+const RESIDENT_BANKS_LOW_END: usize = 1;
+
+// A file decoded as it plays; looping reopens the file rather than keeping decoded samples around
+#[derive(Asset, TypePath, Debug, Clone)]
+pub struct StreamedAudio {
+    pub path: PathBuf,
+    pub looping: bool,
+}
+
+impl StreamedAudio {
+    /// Resolves an asset path, including user_ambient:// files, to the file on disk
+    pub fn new(asset_path: &str, looping: bool) -> Self {
+        let path = match asset_path.strip_prefix(&format!("{}://", USER_AMBIENT_SOURCE)) {
+            Some(file_name) => user_ambient_directory().join(file_name),
+            None => Path::new("assets").join(asset_path),
+        };
+        Self { path, looping }
+    }
+}
+
+pub struct FileStream {
+    path: PathBuf,
+    looping: bool,
+    decoder: Option<rodio::Decoder<BufReader<File>>>,
+}
+
+impl FileStream {
+    fn open(path: &Path) -> Option<rodio::Decoder<BufReader<File>>> {
+        let file = File::open(path)
+            .map_err(|e| warn!("Could not open streamed audio {:?}: {}", path, e))
+            .ok()?;
+        rodio::Decoder::new(BufReader::new(file))
+            .map_err(|e| warn!("Could not decode streamed audio {:?}: {}", path, e))
+            .ok()
+    }
+}
+
+impl Iterator for FileStream {
+    type Item = i16;
+
+    fn next(&mut self) -> Option<i16> {
+        if let Some(sample) = self.decoder.as_mut()?.next() {
+            return Some(sample);
+        }
+        if !self.looping {
+            self.decoder = None;
+            return None;
+        }
+        // One reopen per sample asked for, so an empty file ends the stream instead of spinning
+        self.decoder = Self::open(&self.path);
+        self.decoder.as_mut()?.next()
+    }
+}
+
+impl Source for FileStream {
+    fn current_frame_len(&self) -> Option<usize> {
+        self.decoder.as_ref().map_or(Some(0), |decoder| decoder.current_frame_len())
+    }
+
+    fn channels(&self) -> u16 {
+        self.decoder.as_ref().map_or(1, |decoder| decoder.channels())
+    }
+
+    fn sample_rate(&self) -> u32 {
+        self.decoder.as_ref().map_or(44_100, |decoder| decoder.sample_rate())
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        None
+    }
+}
+
+impl Decodable for StreamedAudio {
+    type DecoderItem = i16;
+    type Decoder = FileStream;
+
+    fn decoder(&self) -> FileStream {
+        FileStream {
+            path: self.path.clone(),
+            looping: self.looping,
+            decoder: FileStream::open(&self.path),
+        }
+    }
+}
+
+struct VocalizationBank {
+    region: Region,
+    season: Season,
+    calls: HashMap<String, Handle<bevy::audio::AudioSource>>,
+}
+
+// Resources
+#[derive(Resource, Default)]
+pub struct AudioBanks {
+    streams: HashMap<(String, bool), Handle<StreamedAudio>>,
+    // Most recently activated last; the last one is the active bank
+    banks: Vec<VocalizationBank>,
+}
+
+impl AudioBanks {
+    /// Handle for playing a file by streaming it from disk
+    pub fn stream(&mut self, assets: &mut Assets<StreamedAudio>, asset_path: &str, looping: bool) -> Handle<StreamedAudio> {
+        self.streams.entry((asset_path.to_string(), looping))
+            .or_insert_with(|| assets.add(StreamedAudio::new(asset_path, looping)))
+            .clone()
+    }
+
+    /// A call from the active bank, loading it into the bank if the season's pool didn't cover it
+    pub fn vocalization(&mut self, asset_server: &AssetServer, path: String) -> Handle<bevy::audio::AudioSource> {
+        let Some(bank) = self.banks.last_mut() else {
+            return asset_server.load(path);
+        };
+        bank.calls.entry(path)
+            .or_insert_with_key(|path| asset_server.load(path.clone()))
+            .clone()
+    }
+
+    pub fn resident_calls(&self) -> usize {
+        self.banks.iter().map(|bank| bank.calls.len()).sum()
+    }
+}
+
+// Switches to the region and season's bank, fills it with the pool's calls and evicts stale banks
+pub fn audio_bank_system(
+    mut banks: ResMut<AudioBanks>,
+    seasonal_state: Res<SeasonalState>,
+    time_state: Res<TimeState>,
+    species_packs: Res<SpeciesPackManager>,
+    game_settings: Option<Res<GameSettings>>,
+    asset_server: Res<AssetServer>,
+) {
+    let (region, season) = (seasonal_state.region, time_state.get_season());
+    let is_active = banks.banks.last().is_some_and(|bank| bank.region == region && bank.season == season);
+    if is_active && !seasonal_state.is_changed() && !species_packs.is_changed() {
+        return;
+    }
+
+    if !is_active {
+        let bank = match banks.banks.iter().position(|bank| bank.region == region && bank.season == season) {
+            Some(index) => banks.banks.remove(index),
+            None => VocalizationBank { region, season, calls: HashMap::new() },
+        };
+        banks.banks.push(bank);
+    }
+
+    let Some(bank) = banks.banks.last_mut() else { return };
+    for species in seasonal_state.available_species.keys() {
+        for call_type in CallType::ALL {
+            bank.calls.entry(vocalization_path(&species_packs, *species, call_type))
+                .or_insert_with_key(|path| asset_server.load(path.clone()));
+        }
+    }
+
+    let resident = match game_settings.map(|settings| settings.graphics_quality) {
+        Some(GraphicsQuality::Low) => RESIDENT_BANKS_LOW_END,
+        _ => RESIDENT_BANKS,
+    };
+    let evicted = banks.banks.len().saturating_sub(resident);
+    // Dropping the handles frees the sounds once nothing still playing holds them
+    banks.banks.drain(..evicted);
+    if !is_active {
+        info!("🔊 Audio bank {} {:?} active, {} calls resident, {} banks evicted",
+            region.name(), season, banks.resident_calls(), evicted);
+    }
+}
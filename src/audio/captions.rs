@@ -0,0 +1,271 @@
+// Captions - A feed naming sounds as they play, for players who can't hear them and for learning calls
+//
+// Sounds that should be captioned carry a SoundCaption when they're spawned. With captions turned on
+// in the accessibility settings, each one shows as a line at the bottom of the screen for a few
+// seconds - "Northern Cardinal song, left", "Wind" - with an arrow on the side it came from. Sounds
+// without a position, like the soundscape loops, get no arrow. The same sound heard again while its
+// line is up refreshes it and counts the repeat instead of adding a line. Bird calls, nature, yard
+// and neighborhood sounds can each be filtered out, and calls can carry their mnemonic from the species
+// data - "Carolina Wren song: tea-kettle tea-kettle tea-kettle".
+use bevy::prelude::*;
+use std::collections::VecDeque;
+use crate::audio::resources::AudioManager;
+use crate::bird::BirdSpecies;
+use crate::bird_data::BirdDataRegistry;
+use crate::journal::resources::BirdEducationData;
+use crate::menu::resources::GameSettings;
+
+const CAPTION_SECS: f32 = 4.0;
+const MAX_CAPTIONS: usize = 5;
+// Sideways distance from the middle of the view before a sound counts as left or right
+const SIDE_THRESHOLD: f32 = 120.0;
+const DISTANT_RANGE: f32 = 350.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CaptionCategory {
+    BirdCalls,
+    Nature,
+    Yard,
+    Neighborhood,
+}
+
+impl CaptionCategory {
+    pub fn enabled(&self, settings: &GameSettings) -> bool {
+        match self {
+            Self::BirdCalls => settings.caption_bird_calls,
+            Self::Nature => settings.caption_nature,
+            Self::Yard => settings.caption_yard,
+            Self::Neighborhood => settings.caption_neighborhood,
+        }
+    }
+
+    fn color(&self) -> Color {
+        match self {
+            Self::BirdCalls => Color::srgb(1.0, 0.95, 0.7),
+            Self::Nature => Color::srgb(0.75, 0.95, 0.8),
+            Self::Yard => Color::srgb(0.95, 0.85, 0.7),
+            Self::Neighborhood => Color::srgb(0.8, 0.85, 0.95),
+        }
+    }
+}
+
+// Names a sound for the caption feed; calls name the species from the journal when shown
+#[derive(Component, Clone)]
+pub struct SoundCaption {
+    pub category: CaptionCategory,
+    pub species: Option<BirdSpecies>,
+    pub sound: String,
+    // Call type name ("Song", "Alarm", ...) for looking up the call's mnemonic
+    pub vocalization: Option<String>,
+}
+
+impl SoundCaption {
+    pub fn call(species: BirdSpecies, sound: impl Into<String>) -> Self {
+        Self { category: CaptionCategory::BirdCalls, species: Some(species), sound: sound.into(), vocalization: None }
+    }
+
+    pub fn sound(category: CaptionCategory, sound: impl Into<String>) -> Self {
+        Self { category, species: None, sound: sound.into(), vocalization: None }
+    }
+
+    pub fn with_vocalization(mut self, call_type: impl Into<String>) -> Self {
+        self.vocalization = Some(call_type.into());
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum CaptionSide {
+    Left,
+    Right,
+    Center,
+}
+
+struct CaptionLine {
+    text: String,
+    category: CaptionCategory,
+    position: Option<Vec2>,
+    // Where the sound is relative to the view, and whether it's far off
+    side: Option<(CaptionSide, bool)>,
+    age: f32,
+    repeats: u32,
+}
+
+impl CaptionLine {
+    fn label(&self) -> String {
+        let mut label = self.text.clone();
+        match self.side {
+            Some((CaptionSide::Left, distant)) => label.push_str(if distant { ", far left" } else { ", left" }),
+            Some((CaptionSide::Right, distant)) => label.push_str(if distant { ", far right" } else { ", right" }),
+            Some((CaptionSide::Center, true)) => label.push_str(", distant"),
+            _ => {}
+        }
+        if self.repeats > 1 {
+            label.push_str(&format!(" (x{})", self.repeats));
+        }
+        label
+    }
+}
+
+#[derive(Resource, Default)]
+pub struct CaptionFeed {
+    lines: VecDeque<CaptionLine>,
+    dirty: bool,
+}
+
+#[derive(Component)]
+pub struct CaptionFeedRoot;
+
+fn caption_side(position: Vec2, listener: Vec2) -> (CaptionSide, bool) {
+    let offset = position.x - listener.x;
+    let side = if offset < -SIDE_THRESHOLD {
+        CaptionSide::Left
+    } else if offset > SIDE_THRESHOLD {
+        CaptionSide::Right
+    } else {
+        CaptionSide::Center
+    };
+    (side, position.distance(listener) > DISTANT_RANGE)
+}
+
+pub fn collect_captions_system(
+    caption_query: Query<(&SoundCaption, Option<&GlobalTransform>), Added<SoundCaption>>,
+    settings: Res<GameSettings>,
+    education_data: Res<BirdEducationData>,
+    bird_data: Res<BirdDataRegistry>,
+    mut feed: ResMut<CaptionFeed>,
+) {
+    if !settings.sound_captions {
+        return;
+    }
+
+    for (caption, transform) in &caption_query {
+        if !caption.category.enabled(&settings) {
+            continue;
+        }
+        let text = match caption.species {
+            Some(species) => {
+                let name = education_data.species_facts.get(&species)
+                    .map(|facts| facts.common_name.clone())
+                    .unwrap_or_else(|| format!("{:?}", species));
+                let mnemonic = caption.vocalization.as_deref()
+                    .filter(|_| settings.caption_mnemonics)
+                    .and_then(|call_type| bird_data.get_mnemonic(&species, call_type));
+                match mnemonic {
+                    Some(phrase) => format!("{} {}: {}", name, caption.sound, phrase),
+                    None => format!("{} {}", name, caption.sound),
+                }
+            }
+            None => caption.sound.clone(),
+        };
+        let position = transform.map(|transform| transform.translation().truncate());
+
+        if let Some(line) = feed.lines.iter_mut().find(|line| line.text == text) {
+            line.age = 0.0;
+            line.repeats += 1;
+            line.position = position;
+        } else {
+            feed.lines.push_back(CaptionLine {
+                text,
+                category: caption.category,
+                position,
+                side: None,
+                age: 0.0,
+                repeats: 1,
+            });
+            if feed.lines.len() > MAX_CAPTIONS {
+                feed.lines.pop_front();
+            }
+        }
+        feed.dirty = true;
+    }
+}
+
+// Ages lines out, keeps their arrows pointing the right way as the view pans and redraws on change
+pub fn refresh_caption_feed_system(
+    mut commands: Commands,
+    mut feed: ResMut<CaptionFeed>,
+    settings: Res<GameSettings>,
+    audio_manager: Res<AudioManager>,
+    root_query: Query<Entity, With<CaptionFeedRoot>>,
+    time: Res<Time>,
+) {
+    if !settings.sound_captions {
+        if !feed.lines.is_empty() || !root_query.is_empty() {
+            feed.lines.clear();
+            for root in &root_query {
+                commands.entity(root).despawn();
+            }
+        }
+        return;
+    }
+
+    let feed = feed.bypass_change_detection();
+    let listener = audio_manager.listener_position;
+    let before = feed.lines.len();
+    feed.lines.retain(|line| line.age + time.delta_secs() < CAPTION_SECS);
+    let mut dirty = feed.dirty || feed.lines.len() != before || root_query.is_empty();
+
+    for line in feed.lines.iter_mut() {
+        line.age += time.delta_secs();
+        let side = line.position.map(|position| caption_side(position, listener));
+        if side != line.side {
+            line.side = side;
+            dirty = true;
+        }
+    }
+    feed.dirty = false;
+    if !dirty {
+        return;
+    }
+
+    for root in &root_query {
+        commands.entity(root).despawn();
+    }
+    if feed.lines.is_empty() {
+        return;
+    }
+
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(90.0),
+            width: Val::Percent(100.0),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            row_gap: Val::Px(4.0),
+            ..default()
+        },
+        Pickable::IGNORE,
+        GlobalZIndex(30),
+        CaptionFeedRoot,
+        StateScoped(crate::AppState::Playing),
+        Name::new("CaptionFeed"),
+    )).with_children(|feed_node| {
+        for line in &feed.lines {
+            let side = line.side.map(|(side, _)| side);
+            feed_node.spawn((
+                Node {
+                    padding: UiRect::axes(Val::Px(10.0), Val::Px(4.0)),
+                    column_gap: Val::Px(8.0),
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.65)),
+                BorderRadius::all(Val::Px(4.0)),
+            )).with_children(|row| {
+                if side == Some(CaptionSide::Left) {
+                    row.spawn((Text::new("←"), TextFont { font_size: 16.0, ..default() }, TextColor(Color::WHITE)));
+                }
+                row.spawn((
+                    Text::new(line.label()),
+                    TextFont { font_size: 16.0, ..default() },
+                    TextColor(line.category.color()),
+                ));
+                if side == Some(CaptionSide::Right) {
+                    row.spawn((Text::new("→"), TextFont { font_size: 16.0, ..default() }, TextColor(Color::WHITE)));
+                }
+            });
+        }
+    });
+}
@@ -28,3 +28,16 @@ pub struct AudioSinkComponent {
 pub struct AudioCleanupTimer {
     pub timer: Timer,
 }
+
+#[derive(Component)]
+pub struct MusicFade {
+    pub current: f32, // 0.0-1.0 fade level
+    pub target: f32,
+}
+
+// A bird call competing for one of the mixer's voices, see mixer.rs
+#[derive(Component)]
+pub struct Vocalization {
+    pub species: crate::bird::BirdSpecies,
+    pub level: f32, // Loudness before the calls bus and master volume
+}
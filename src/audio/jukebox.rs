@@ -0,0 +1,430 @@
+// src/audio/jukebox.rs
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use crate::audio::banks::{AudioBanks, StreamedAudio};
+use crate::audio::components::MusicFade;
+use crate::audio::resources::AudioManager;
+use crate::achievements::{Achievement, AchievementUnlockedEvent};
+use crate::environment::components::Season;
+use crate::environment::resources::TimeState;
+use crate::keybindings::{GameAction, KeyBindings};
+use crate::notifications::{resources::ShowNotificationEvent, components::NotificationType};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MusicTrack {
+    MorningMeadow,
+    GardenWaltz,
+    SpringChorus,
+    SummerHaze,
+    AutumnDrift,
+    WinterHush,
+    FirstLight,
+    Fieldnotes,
+    CollectorsLament,
+    GoldenFeathers,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TrackUnlock {
+    Default,
+    Season(Season),
+    Achievement(Achievement),
+}
+
+impl MusicTrack {
+    pub const ALL: [MusicTrack; 10] = [
+        Self::MorningMeadow,
+        Self::GardenWaltz,
+        Self::SpringChorus,
+        Self::SummerHaze,
+        Self::AutumnDrift,
+        Self::WinterHush,
+        Self::FirstLight,
+        Self::Fieldnotes,
+        Self::CollectorsLament,
+        Self::GoldenFeathers,
+    ];
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            Self::MorningMeadow => "Morning Meadow",
+            Self::GardenWaltz => "Garden Waltz",
+            Self::SpringChorus => "Spring Chorus",
+            Self::SummerHaze => "Summer Haze",
+            Self::AutumnDrift => "Autumn Drift",
+            Self::WinterHush => "Winter Hush",
+            Self::FirstLight => "First Light",
+            Self::Fieldnotes => "Fieldnotes",
+            Self::CollectorsLament => "Collector's Lament",
+            Self::GoldenFeathers => "Golden Feathers",
+        }
+    }
+
+    pub fn asset_path(&self) -> &'static str {
+        match self {
+            Self::MorningMeadow => "audio/music/morning_meadow.ogg",
+            Self::GardenWaltz => "audio/music/garden_waltz.ogg",
+            Self::SpringChorus => "audio/music/spring_chorus.ogg",
+            Self::SummerHaze => "audio/music/summer_haze.ogg",
+            Self::AutumnDrift => "audio/music/autumn_drift.ogg",
+            Self::WinterHush => "audio/music/winter_hush.ogg",
+            Self::FirstLight => "audio/music/first_light.ogg",
+            Self::Fieldnotes => "audio/music/fieldnotes.ogg",
+            Self::CollectorsLament => "audio/music/collectors_lament.ogg",
+            Self::GoldenFeathers => "audio/music/golden_feathers.ogg",
+        }
+    }
+
+    pub fn unlock(&self) -> TrackUnlock {
+        match self {
+            Self::MorningMeadow | Self::GardenWaltz => TrackUnlock::Default,
+            Self::SpringChorus => TrackUnlock::Season(Season::Spring),
+            Self::SummerHaze => TrackUnlock::Season(Season::Summer),
+            Self::AutumnDrift => TrackUnlock::Season(Season::Fall),
+            Self::WinterHush => TrackUnlock::Season(Season::Winter),
+            Self::FirstLight => TrackUnlock::Achievement(Achievement::FirstPhoto),
+            Self::Fieldnotes => TrackUnlock::Achievement(Achievement::CommonCollector),
+            Self::CollectorsLament => TrackUnlock::Achievement(Achievement::Ornithologist),
+            Self::GoldenFeathers => TrackUnlock::Achievement(Achievement::Wealthy),
+        }
+    }
+
+    pub fn unlock_hint(&self) -> String {
+        match self.unlock() {
+            TrackUnlock::Default => "Available from the start".to_string(),
+            TrackUnlock::Season(season) => format!("Play through {:?}", season),
+            TrackUnlock::Achievement(achievement) => format!("Earn \"{}\"", achievement.name()),
+        }
+    }
+}
+
+#[derive(Resource)]
+pub struct Jukebox {
+    pub unlocked: HashSet<MusicTrack>,
+    pub now_playing: Option<MusicTrack>,
+    pub shuffle: bool,
+    // The stream of the track now playing, to tell when it has run out
+    playing_stream: Option<Handle<StreamedAudio>>,
+    pub is_open: bool,
+    panel_dirty: bool,
+    last_season: Option<Season>,
+}
+
+impl Default for Jukebox {
+    fn default() -> Self {
+        let unlocked = MusicTrack::ALL.iter()
+            .copied()
+            .filter(|track| track.unlock() == TrackUnlock::Default)
+            .collect();
+
+        Self {
+            unlocked,
+            now_playing: None,
+            shuffle: true,
+            playing_stream: None,
+            is_open: false,
+            panel_dirty: false,
+            last_season: None,
+        }
+    }
+}
+
+impl Jukebox {
+    pub fn unlock(&mut self, track: MusicTrack) -> bool {
+        self.unlocked.insert(track)
+    }
+
+    fn random_unlocked(&self) -> Option<MusicTrack> {
+        let candidates: Vec<MusicTrack> = MusicTrack::ALL.iter()
+            .copied()
+            .filter(|track| self.unlocked.contains(track) && Some(*track) != self.now_playing)
+            .collect();
+
+        if candidates.is_empty() {
+            return self.now_playing;
+        }
+
+        Some(candidates[rand::random::<u32>() as usize % candidates.len()])
+    }
+}
+
+#[derive(Event)]
+pub struct PlayTrackEvent {
+    pub track: Option<MusicTrack>, // None picks a random unlocked track
+}
+
+// UI Components
+#[derive(Component)]
+pub struct JukeboxPanel;
+
+#[derive(Component)]
+pub struct JukeboxTrackButton {
+    pub track: MusicTrack,
+}
+
+#[derive(Component)]
+pub struct JukeboxShuffleButton;
+
+#[derive(Component)]
+pub struct JukeboxNextButton;
+
+pub fn start_music_system(mut play_events: EventWriter<PlayTrackEvent>, jukebox: Res<Jukebox>) {
+    if jukebox.now_playing.is_none() {
+        play_events.write(PlayTrackEvent { track: None });
+    }
+}
+
+pub fn play_track_system(
+    mut play_events: EventReader<PlayTrackEvent>,
+    mut jukebox: ResMut<Jukebox>,
+    mut audio_manager: ResMut<AudioManager>,
+    mut audio_banks: ResMut<AudioBanks>,
+    mut streamed_assets: ResMut<Assets<StreamedAudio>>,
+) {
+    for event in play_events.read() {
+        let track = match event.track {
+            Some(track) if jukebox.unlocked.contains(&track) => Some(track),
+            Some(_) => None,
+            None => jukebox.random_unlocked(),
+        };
+
+        let Some(track) = track else { continue };
+
+        // Tracks play once through so the jukebox can tell when one ends
+        let stream = audio_banks.stream(&mut streamed_assets, track.asset_path(), false);
+        audio_manager.crossfade_to(stream.clone());
+        jukebox.now_playing = Some(track);
+        jukebox.playing_stream = Some(stream);
+        jukebox.panel_dirty = true;
+        info!("🎵 Now playing: {}", track.title());
+    }
+}
+
+// When the track runs out, shuffle moves on to another one and otherwise the same one starts again
+pub fn track_finished_system(
+    jukebox: Res<Jukebox>,
+    music_query: Query<(&AudioPlayer<StreamedAudio>, &MusicFade, &AudioSink)>,
+    mut play_events: EventWriter<PlayTrackEvent>,
+) {
+    let (Some(track), Some(stream)) = (jukebox.now_playing, jukebox.playing_stream.as_ref()) else { return };
+
+    // Tracks already fading out have been replaced
+    let finished = music_query.iter()
+        .any(|(player, fade, sink)| player.0 == *stream && fade.target > 0.0 && sink.empty());
+    if finished {
+        play_events.write(PlayTrackEvent { track: if jukebox.shuffle { None } else { Some(track) } });
+    }
+}
+
+pub fn music_unlock_system(
+    mut jukebox: ResMut<Jukebox>,
+    mut achievement_events: EventReader<AchievementUnlockedEvent>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+    time_state: Res<TimeState>,
+) {
+    let mut newly_unlocked = Vec::new();
+
+    let season = time_state.get_season();
+    if jukebox.last_season != Some(season) {
+        jukebox.last_season = Some(season);
+        newly_unlocked.extend(MusicTrack::ALL.iter().copied().filter(|track| track.unlock() == TrackUnlock::Season(season)));
+    }
+
+    for event in achievement_events.read() {
+        newly_unlocked.extend(MusicTrack::ALL.iter().copied().filter(|track| {
+            track.unlock() == TrackUnlock::Achievement(event.achievement.clone())
+        }));
+    }
+
+    for track in newly_unlocked {
+        if jukebox.unlock(track) {
+            jukebox.panel_dirty = true;
+            notification_events.write(ShowNotificationEvent {
+                notification: NotificationType::Info {
+                    message: format!("New music unlocked: {}", track.title()),
+                },
+            });
+        }
+    }
+}
+
+pub fn toggle_jukebox_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    key_bindings: Res<KeyBindings>,
+    mut jukebox: ResMut<Jukebox>,
+) {
+    if key_bindings.is_action_just_pressed(GameAction::OpenJukebox, &keyboard, &mouse) {
+        jukebox.is_open = !jukebox.is_open;
+        jukebox.panel_dirty = true;
+    }
+}
+
+// Rebuilds the panel so the now-playing marker, unlocks and shuffle label stay current
+pub fn refresh_jukebox_panel_system(
+    mut commands: Commands,
+    mut jukebox: ResMut<Jukebox>,
+    panel_query: Query<Entity, With<JukeboxPanel>>,
+) {
+    if !jukebox.panel_dirty {
+        return;
+    }
+    jukebox.panel_dirty = false;
+
+    for panel in &panel_query {
+        commands.entity(panel).despawn();
+    }
+
+    if jukebox.is_open {
+        spawn_jukebox_panel(&mut commands, &jukebox);
+    }
+}
+
+pub fn close_jukebox_system(
+    mut commands: Commands,
+    mut jukebox: ResMut<Jukebox>,
+    panel_query: Query<Entity, With<JukeboxPanel>>,
+) {
+    jukebox.is_open = false;
+    for panel in &panel_query {
+        commands.entity(panel).despawn();
+    }
+}
+
+fn spawn_jukebox_panel(commands: &mut Commands, jukebox: &Jukebox) {
+    commands.spawn((
+        Node {
+            width: Val::Px(320.0),
+            position_type: PositionType::Absolute,
+            right: Val::Px(20.0),
+            top: Val::Px(80.0),
+            flex_direction: FlexDirection::Column,
+            padding: UiRect::all(Val::Px(12.0)),
+            row_gap: Val::Px(6.0),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.95, 0.92, 0.88, 0.95)),
+        BorderRadius::all(Val::Px(8.0)),
+        JukeboxPanel,
+    )).with_children(|panel| {
+        panel.spawn((
+            Text::new("Jukebox"),
+            TextFont { font_size: 18.0, ..default() },
+            TextColor(Color::srgb(0.3, 0.2, 0.1)),
+        ));
+
+        for track in MusicTrack::ALL {
+            let unlocked = jukebox.unlocked.contains(&track);
+            let label = if !unlocked {
+                format!("🔒 {}", track.unlock_hint())
+            } else if jukebox.now_playing == Some(track) {
+                format!("▶ {}", track.title())
+            } else {
+                track.title().to_string()
+            };
+
+            let mut button = panel.spawn((
+                Button,
+                Node {
+                    width: Val::Percent(100.0),
+                    height: Val::Px(28.0),
+                    align_items: AlignItems::Center,
+                    padding: UiRect::horizontal(Val::Px(8.0)),
+                    ..default()
+                },
+                BackgroundColor(track_button_color(unlocked, Interaction::None)),
+                BorderRadius::all(Val::Px(4.0)),
+            ));
+            button.with_children(|button| {
+                button.spawn((
+                    Text::new(label),
+                    TextFont { font_size: 13.0, ..default() },
+                    TextColor(if unlocked { Color::WHITE } else { Color::srgb(0.5, 0.45, 0.4) }),
+                ));
+            });
+
+            if unlocked {
+                button.insert(JukeboxTrackButton { track });
+            }
+        }
+
+        panel.spawn(Node {
+            width: Val::Percent(100.0),
+            flex_direction: FlexDirection::Row,
+            justify_content: JustifyContent::SpaceBetween,
+            margin: UiRect::top(Val::Px(6.0)),
+            ..default()
+        }).with_children(|controls| {
+            let shuffle_label = if jukebox.shuffle { "Shuffle: On" } else { "Shuffle: Off" };
+            for (label, is_shuffle) in [(shuffle_label, true), ("Next Track", false)] {
+                let mut button = controls.spawn((
+                    Button,
+                    Node {
+                        width: Val::Px(140.0),
+                        height: Val::Px(30.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.6, 0.5, 0.4)),
+                    BorderRadius::all(Val::Px(6.0)),
+                ));
+                button.with_children(|button| {
+                    button.spawn((
+                        Text::new(label),
+                        TextFont { font_size: 13.0, ..default() },
+                        TextColor(Color::WHITE),
+                    ));
+                });
+
+                if is_shuffle {
+                    button.insert(JukeboxShuffleButton);
+                } else {
+                    button.insert(JukeboxNextButton);
+                }
+            }
+        });
+    });
+}
+
+fn track_button_color(unlocked: bool, interaction: Interaction) -> Color {
+    match (unlocked, interaction) {
+        (false, _) => Color::srgb(0.85, 0.82, 0.78),
+        (true, Interaction::Pressed) => Color::srgb(0.5, 0.7, 0.5),
+        (true, Interaction::Hovered) => Color::srgb(0.7, 0.6, 0.5),
+        (true, Interaction::None) => Color::srgb(0.6, 0.5, 0.4),
+    }
+}
+
+pub fn jukebox_interaction_system(
+    track_query: Query<(&Interaction, &JukeboxTrackButton), Changed<Interaction>>,
+    shuffle_query: Query<&Interaction, (Changed<Interaction>, With<JukeboxShuffleButton>)>,
+    next_query: Query<&Interaction, (Changed<Interaction>, With<JukeboxNextButton>)>,
+    mut color_query: Query<(&Interaction, &mut BackgroundColor), (Changed<Interaction>, With<JukeboxTrackButton>)>,
+    mut jukebox: ResMut<Jukebox>,
+    mut play_events: EventWriter<PlayTrackEvent>,
+) {
+    for (interaction, mut color) in &mut color_query {
+        *color = track_button_color(true, *interaction).into();
+    }
+
+    for (interaction, button) in &track_query {
+        if *interaction == Interaction::Pressed {
+            play_events.write(PlayTrackEvent { track: Some(button.track) });
+        }
+    }
+
+    for interaction in &shuffle_query {
+        if *interaction == Interaction::Pressed {
+            jukebox.shuffle = !jukebox.shuffle;
+            jukebox.panel_dirty = true;
+        }
+    }
+
+    for interaction in &next_query {
+        if *interaction == Interaction::Pressed {
+            play_events.write(PlayTrackEvent { track: None });
+        }
+    }
+}
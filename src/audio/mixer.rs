@@ -0,0 +1,113 @@
+// Mixer - Keeps a crowded yard's calls from piling up into noise
+//
+// Every bird call carries a Vocalization and competes for one of a handful of voices. A call's
+// priority is how close it is to the listener plus a bonus for rarer species, so a nearby cardinal
+// or a visiting rarity wins over a distant sparrow. When there are more calls than voices the least
+// important ones give way: calls that haven't started yet are dropped, and a newcomer only cuts off
+// a playing call if it clearly outranks it. While calls are sounding the soundscape ducks under them
+// and comes back up slowly once they finish. Calls, ambience, effects and music each have a bus
+// under the master volume, set from the audio settings.
+use bevy::prelude::*;
+use bevy::audio::{AudioSink, AudioSinkPlayback, Volume};
+use crate::audio::{components::Vocalization, resources::*, soundscape::*};
+use crate::menu::resources::GameSettings;
+
+// Beyond this a call is considered as far away as it gets
+const VOICE_PRIORITY_RANGE: f32 = 500.0;
+// Priority per rarity tier above common
+const RARITY_PRIORITY: f32 = 0.35;
+// A playing call keeps its voice unless a newcomer beats it by this much
+const PLAYING_PRIORITY_BONUS: f32 = 0.15;
+
+// Ambience level while calls are sounding, and how fast it goes down and comes back
+const DUCKED_AMBIENT: f32 = 0.55;
+const DUCK_ATTACK_SECS: f32 = 0.15;
+const DUCK_RELEASE_SECS: f32 = 1.5;
+
+pub fn sync_mixer_settings_system(
+    game_settings: Option<Res<GameSettings>>,
+    mut audio_manager: ResMut<AudioManager>,
+) {
+    let Some(settings) = game_settings else { return };
+    if !settings.is_changed() {
+        return;
+    }
+
+    audio_manager.buses = MixerBuses {
+        calls: settings.call_volume,
+        ambient: settings.ambient_volume,
+        effects: settings.sfx_volume,
+        music: settings.music_volume,
+    };
+    audio_manager.max_voices = settings.max_bird_voices.max(1);
+}
+
+fn voice_priority(voice: &Vocalization, position: Vec2, listener: Vec2) -> f32 {
+    let proximity = 1.0 - (listener.distance(position) / VOICE_PRIORITY_RANGE).min(1.0);
+    proximity + voice.species.rarity_tier().saturating_sub(1) as f32 * RARITY_PRIORITY
+}
+
+// Clears finished calls, drops the least important ones over the voice limit and sets call volumes
+pub fn voice_limiter_system(
+    mut commands: Commands,
+    mut audio_manager: ResMut<AudioManager>,
+    audio_settings: Res<AudioSettings>,
+    mut voice_query: Query<(Entity, &Vocalization, &GlobalTransform, Option<&mut AudioSink>)>,
+) {
+    let listener = audio_manager.listener_position;
+    let mut voices: Vec<(Entity, f32)> = Vec::new();
+
+    for (entity, voice, transform, sink) in &voice_query {
+        let playing = match sink {
+            Some(sink) if sink.empty() => {
+                commands.entity(entity).despawn();
+                continue;
+            }
+            Some(_) => true,
+            None => false,
+        };
+        let mut priority = voice_priority(voice, transform.translation().truncate(), listener);
+        if playing {
+            priority += PLAYING_PRIORITY_BONUS;
+        }
+        voices.push((entity, priority));
+    }
+
+    if voices.len() > audio_manager.max_voices {
+        voices.sort_by(|a, b| b.1.total_cmp(&a.1));
+        for (entity, _) in voices.drain(audio_manager.max_voices..) {
+            commands.entity(entity).despawn();
+        }
+    }
+    audio_manager.active_voices = voices.len();
+
+    let call_gain = audio_manager.bus_volume(MixerBus::Calls) * audio_settings.volume;
+    for (entity, _) in &voices {
+        if let Ok((_, voice, _, Some(mut sink))) = voice_query.get_mut(*entity) {
+            sink.set_volume(Volume::Linear(voice.level * call_gain));
+        }
+    }
+}
+
+// Lowers the soundscape under calls and applies the ambience bus to the looping layers
+pub fn ambient_ducking_system(
+    mut audio_manager: ResMut<AudioManager>,
+    audio_settings: Res<AudioSettings>,
+    soundscape: Res<SoundscapeSettings>,
+    mut player_query: Query<(&SoundscapeLayerPlayer, &mut AudioSink)>,
+    time: Res<Time>,
+) {
+    let target = if audio_manager.active_voices > 0 { DUCKED_AMBIENT } else { 1.0 };
+    let duck = audio_manager.ambient_duck;
+    let step = time.delta_secs() * (1.0 - DUCKED_AMBIENT);
+    audio_manager.ambient_duck = if duck > target {
+        (duck - step / DUCK_ATTACK_SECS).max(target)
+    } else {
+        (duck + step / DUCK_RELEASE_SECS).min(target)
+    };
+
+    let ambient_gain = audio_manager.bus_volume(MixerBus::Ambient) * audio_settings.volume;
+    for (player, mut sink) in &mut player_query {
+        sink.set_volume(Volume::Linear(soundscape.layer_volume(&player.layer) * ambient_gain));
+    }
+}
@@ -1,12 +1,26 @@
 // src/audio/mod.rs
 use bevy::prelude::*;
+use bevy::audio::AddAudioSource;
 
 pub mod components;
 pub mod resources;
 pub mod systems;
+pub mod soundscape;
+pub mod jukebox;
+pub mod spectrogram;
+pub mod banks;
+pub mod music_director;
+pub mod mixer;
+pub mod captions;
 
 use resources::*;
 use systems::*;
+use soundscape::*;
+use jukebox::*;
+use banks::*;
+use music_director::*;
+use mixer::*;
+use captions::*;
 use crate::AppState;
 
 pub struct AudioPlugin;
@@ -16,24 +30,65 @@ impl Plugin for AudioPlugin {
         app
             .init_resource::<AudioSettings>()
             .init_resource::<AudioManager>()
+            .init_resource::<SoundscapeSettings>()
+            .init_resource::<AvailableAmbientImports>()
+            .init_resource::<Jukebox>()
+            .init_resource::<AudioBanks>()
+            .init_resource::<MusicDirector>()
+            .init_resource::<CaptionFeed>()
+            .add_audio_source::<StreamedAudio>()
             .add_event::<AudioPlayEvent>()
+            .add_event::<ImportAmbientTracksEvent>()
+            .add_event::<PlayTrackEvent>()
             .add_systems(Startup, audio_setup_system)
+            .add_systems(OnEnter(AppState::Playing), (restart_soundscape_system, start_music_system))
+            .add_systems(OnExit(AppState::Playing), (stop_soundscape_system, close_jukebox_system))
+            .add_systems(Update, import_ambient_tracks_system.in_set(crate::GameSet::Presentation))
+            .add_systems(Update, sync_mixer_settings_system.before(music_crossfade_system).in_set(crate::GameSet::Presentation))
+            .add_systems(Update, audio_bank_system.in_set(crate::GameSet::Presentation).run_if(in_state(AppState::Playing)))
+            // Music keeps playing through menus and the journal
+            .add_systems(Update, (
+                track_finished_system,
+                play_track_system,
+                music_crossfade_system,
+            ).chain().in_set(crate::GameSet::Presentation))
             .add_systems(Update, (
                 audio_event_system,
                 update_positional_audio_system,
                 audio_cleanup_system,
                 bird_vocalization_system,
                 ambient_feeder_audio_system,
-            ).run_if(in_state(AppState::Playing)));
+                soundscape_playback_system,
+            ).in_set(crate::GameSet::Presentation).run_if(in_state(AppState::Playing)))
+            // Calls compete for voices after this frame's calls are spawned, then the ambience ducks under them
+            .add_systems(Update, (
+                voice_limiter_system,
+                ambient_ducking_system,
+            ).chain().after(bird_vocalization_system).after(soundscape_playback_system).after(sync_mixer_settings_system)
+                .in_set(crate::GameSet::Presentation).run_if(in_state(AppState::Playing)))
+            // Layers only play over the yard; menus get the bare track
+            .add_systems(Update, (
+                spawn_music_layers_system,
+                music_intensity_system,
+                music_layer_mix_system,
+                achievement_stinger_system,
+            ).chain().after(music_crossfade_system).in_set(crate::GameSet::Presentation).run_if(in_state(AppState::Playing)))
+            .add_systems(Update, (
+                music_unlock_system,
+                toggle_jukebox_system,
+                jukebox_interaction_system,
+                refresh_jukebox_panel_system,
+            ).chain().after(play_track_system).in_set(crate::GameSet::UI).run_if(in_state(AppState::Playing)))
+            .add_systems(Update, (
+                collect_captions_system,
+                refresh_caption_feed_system,
+            ).chain().in_set(crate::GameSet::UI).run_if(in_state(AppState::Playing)));
     }
 }
 
 // Helper functions for common audio operations
-pub fn play_ambient(commands: &mut Commands, handle: Handle<bevy::audio::AudioSource>) {
-    commands.trigger(AudioPlayEvent {
-        source: crate::audio::resources::AudioSource::AmbientTrack(handle),
-        command: AudioCommand::PlayGlobal,
-    });
+pub fn play_ambient(audio_manager: &mut AudioManager, handle: Handle<StreamedAudio>) {
+    audio_manager.crossfade_to(handle);
 }
 
 pub fn play_bird_sound(commands: &mut Commands, entity: Entity, species: crate::bird::BirdSpecies, handle: Handle<bevy::audio::AudioSource>) {
@@ -0,0 +1,174 @@
+// Music Director - Layers over the jukebox track that follow what's happening in the yard
+//
+// The jukebox track is the bed. Over it the director keeps three looping stems - a soft pulse,
+// strings and a percussion line - and brings each in as the yard's intensity passes its threshold.
+// Intensity follows how many birds are about, jumps when a rare bird arrives, a predator strikes
+// or a storm rolls in, and eases back down over half a minute; at night it's held low so the
+// evenings stay calm. Achievements play a short stinger over the top.
+use bevy::prelude::*;
+use bevy::audio::Volume;
+use crate::achievements::AchievementUnlockedEvent;
+use crate::advanced_weather::StormEvent;
+use crate::audio::banks::{AudioBanks, StreamedAudio};
+use crate::audio::jukebox::Jukebox;
+use crate::audio::resources::AudioSettings;
+use crate::bird::Bird;
+use crate::environment::resources::TimeState;
+use crate::predator_prey::PredatorAttackEvent;
+
+// This many birds in the yard is as busy as it gets
+const BUSY_BIRD_COUNT: f32 = 20.0;
+// Share of full intensity the bird count alone can reach; the rest is left for events
+const CROWD_SHARE: f32 = 0.7;
+const RARE_TIER: u8 = 3;
+const RARE_ARRIVAL_BOOST: f32 = 0.5;
+const PREDATOR_BOOST: f32 = 0.6;
+const STORM_BOOST: f32 = 0.4;
+// Seconds for an event's boost to wear off completely
+const BOOST_DECAY_SECONDS: f32 = 30.0;
+// Intensity moves this much per second, so layers swell rather than snap in
+const INTENSITY_RATE: f32 = 0.2;
+const NIGHT_CEILING: f32 = 0.3;
+const LAYER_FADE_SECONDS: f32 = 2.0;
+const ACHIEVEMENT_STINGER: &str = "audio/music/stingers/achievement.ogg";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MusicLayer {
+    Pulse,
+    Strings,
+    Percussion,
+}
+
+impl MusicLayer {
+    pub const ALL: [MusicLayer; 3] = [Self::Pulse, Self::Strings, Self::Percussion];
+
+    // Intensity at which the layer starts to come in; it's at full volume a quarter higher
+    pub fn threshold(&self) -> f32 {
+        match self {
+            Self::Pulse => 0.15,
+            Self::Strings => 0.4,
+            Self::Percussion => 0.65,
+        }
+    }
+
+    pub fn asset_path(&self) -> &'static str {
+        match self {
+            Self::Pulse => "audio/music/layers/pulse.ogg",
+            Self::Strings => "audio/music/layers/strings.ogg",
+            Self::Percussion => "audio/music/layers/percussion.ogg",
+        }
+    }
+
+    fn level_at(&self, intensity: f32) -> f32 {
+        ((intensity - self.threshold()) / 0.25).clamp(0.0, 1.0)
+    }
+}
+
+// Resources
+#[derive(Resource, Default)]
+pub struct MusicDirector {
+    // 0 a quiet yard to 1 everything happening at once
+    pub intensity: f32,
+    // Extra intensity from recent events, wearing off over time
+    event_boost: f32,
+}
+
+// Components
+#[derive(Component)]
+pub struct MusicLayerSink {
+    pub layer: MusicLayer,
+    level: f32,
+}
+
+// The stems start with the first track, silent until the yard gets busy
+pub fn spawn_music_layers_system(
+    mut commands: Commands,
+    jukebox: Res<Jukebox>,
+    layer_query: Query<(), With<MusicLayerSink>>,
+    mut audio_banks: ResMut<AudioBanks>,
+    mut streamed_assets: ResMut<Assets<StreamedAudio>>,
+) {
+    if jukebox.now_playing.is_none() || !layer_query.is_empty() {
+        return;
+    }
+    for layer in MusicLayer::ALL {
+        commands.spawn((
+            AudioPlayer(audio_banks.stream(&mut streamed_assets, layer.asset_path(), true)),
+            PlaybackSettings::ONCE.with_volume(Volume::Linear(0.0)),
+            MusicLayerSink { layer, level: 0.0 },
+            Name::new(format!("Music Layer {:?}", layer)),
+            StateScoped(crate::AppState::Playing),
+        ));
+    }
+}
+
+pub fn music_intensity_system(
+    mut director: ResMut<MusicDirector>,
+    new_bird_query: Query<&Bird, Added<Bird>>,
+    bird_query: Query<(), With<Bird>>,
+    mut predator_events: EventReader<PredatorAttackEvent>,
+    mut storm_events: EventReader<StormEvent>,
+    time_state: Res<TimeState>,
+    time: Res<Time>,
+) {
+    let dt = time.delta_secs();
+    let mut boost = (director.event_boost - dt / BOOST_DECAY_SECONDS).max(0.0);
+    if new_bird_query.iter().any(|bird| bird.species.rarity_tier() >= RARE_TIER) {
+        boost += RARE_ARRIVAL_BOOST;
+    }
+    if predator_events.read().count() > 0 {
+        boost += PREDATOR_BOOST;
+    }
+    if storm_events.read().count() > 0 {
+        boost += STORM_BOOST;
+    }
+    director.event_boost = boost.min(1.0);
+
+    let crowd = (bird_query.iter().count() as f32 / BUSY_BIRD_COUNT).min(1.0) * CROWD_SHARE;
+    let mut target = (crowd + director.event_boost).min(1.0);
+    // However busy the night gets, it stays at a soft pulse
+    if time_state.daylight_factor() < 0.5 {
+        target = target.min(NIGHT_CEILING);
+    }
+
+    let step = INTENSITY_RATE * dt;
+    director.intensity += (target - director.intensity).clamp(-step, step);
+}
+
+pub fn music_layer_mix_system(
+    director: Res<MusicDirector>,
+    audio_settings: Res<AudioSettings>,
+    game_settings: Option<Res<crate::menu::resources::GameSettings>>,
+    mut layer_query: Query<(&mut MusicLayerSink, Option<&mut AudioSink>)>,
+    time: Res<Time>,
+) {
+    let music_volume = game_settings.map_or(1.0, |settings| settings.music_volume) * audio_settings.volume;
+    let step = time.delta_secs() / LAYER_FADE_SECONDS;
+
+    for (mut layer, sink) in &mut layer_query {
+        let target = layer.layer.level_at(director.intensity);
+        layer.level += (target - layer.level).clamp(-step, step);
+        if let Some(mut sink) = sink {
+            sink.set_volume(Volume::Linear(layer.level * music_volume));
+        }
+    }
+}
+
+pub fn achievement_stinger_system(
+    mut commands: Commands,
+    mut achievement_events: EventReader<AchievementUnlockedEvent>,
+    asset_server: Res<AssetServer>,
+    audio_settings: Res<AudioSettings>,
+    game_settings: Option<Res<crate::menu::resources::GameSettings>>,
+) {
+    // Several unlocks in one frame share a single stinger
+    if achievement_events.read().count() == 0 {
+        return;
+    }
+    let music_volume = game_settings.map_or(1.0, |settings| settings.music_volume) * audio_settings.volume;
+    commands.spawn((
+        AudioPlayer::new(asset_server.load(ACHIEVEMENT_STINGER)),
+        PlaybackSettings::DESPAWN.with_volume(Volume::Linear(music_volume)),
+        Name::new("Music Stinger"),
+    ));
+}
@@ -1,6 +1,7 @@
 // src/audio/resources.rs
 use bevy::prelude::*;
 use std::collections::{HashMap, VecDeque};
+use crate::audio::banks::StreamedAudio;
 use crate::bird::BirdSpecies;
 
 #[derive(Resource)]
@@ -23,6 +24,43 @@ pub struct AudioManager {
     pub available_sinks: VecDeque<Entity>,
     pub in_use_sinks: HashMap<Entity, AudioCommand>,
     pub listener_position: Vec2,
+    // Music playback with crossfades between tracks
+    pub current_music: Option<Entity>,
+    pub queued_music: Option<Handle<StreamedAudio>>,
+    pub music_crossfade_secs: f32,
+    // Crowd mixing: calls compete for a few voices and the ambience ducks under them
+    pub buses: MixerBuses,
+    pub max_voices: usize,
+    pub active_voices: usize,
+    pub ambient_duck: f32, // 1.0 = not ducked
+}
+
+// Per-category volumes, each under the master volume
+#[derive(Debug, Clone, Copy)]
+pub struct MixerBuses {
+    pub calls: f32,
+    pub ambient: f32,
+    pub effects: f32,
+    pub music: f32,
+}
+
+impl Default for MixerBuses {
+    fn default() -> Self {
+        Self {
+            calls: 0.8,
+            ambient: 0.8,
+            effects: 0.8,
+            music: 0.7,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MixerBus {
+    Calls,
+    Ambient,
+    Effects,
+    Music,
 }
 
 impl Default for AudioManager {
@@ -31,6 +69,30 @@ impl Default for AudioManager {
             available_sinks: VecDeque::new(),
             in_use_sinks: HashMap::new(),
             listener_position: Vec2::ZERO,
+            current_music: None,
+            queued_music: None,
+            music_crossfade_secs: 3.0,
+            buses: MixerBuses::default(),
+            max_voices: 6,
+            active_voices: 0,
+            ambient_duck: 1.0,
+        }
+    }
+}
+
+impl AudioManager {
+    // Fades out whatever is playing and fades in the new track
+    pub fn crossfade_to(&mut self, handle: Handle<StreamedAudio>) {
+        self.queued_music = Some(handle);
+    }
+    
+    // Bus volume before the master volume; the ambient bus includes any ducking under calls
+    pub fn bus_volume(&self, bus: MixerBus) -> f32 {
+        match bus {
+            MixerBus::Calls => self.buses.calls,
+            MixerBus::Ambient => self.buses.ambient * self.ambient_duck,
+            MixerBus::Effects => self.buses.effects,
+            MixerBus::Music => self.buses.music,
         }
     }
 }
@@ -51,6 +113,6 @@ pub enum AudioCommand {
 #[derive(Clone, Debug)]
 pub enum AudioSource {
     BirdVocalization(Handle<bevy::audio::AudioSource>, BirdSpecies),
-    AmbientTrack(Handle<bevy::audio::AudioSource>),
+    AmbientTrack(Handle<StreamedAudio>),
     UiSound(Handle<bevy::audio::AudioSource>),
 }
\ No newline at end of file
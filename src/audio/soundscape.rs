@@ -0,0 +1,263 @@
+// src/audio/soundscape.rs
+use bevy::prelude::*;
+use bevy::asset::io::AssetSourceBuilder;
+use bevy::audio::{AudioSink, AudioSinkPlayback, Volume};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+use crate::audio::banks::{AudioBanks, StreamedAudio};
+use crate::audio::captions::{CaptionCategory, SoundCaption};
+use crate::audio::resources::{AudioManager, AudioSettings, MixerBus};
+
+// Asset source name for audio files the player drops into their ambient folder
+pub const USER_AMBIENT_SOURCE: &str = "user_ambient";
+
+const SUPPORTED_EXTENSIONS: [&str; 4] = ["ogg", "wav", "mp3", "flac"];
+
+#[derive(Resource, Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct SoundscapeSettings {
+    pub wind: f32,
+    pub water: f32,
+    pub insects: f32,
+    pub traffic: f32,
+    pub custom_tracks: Vec<CustomAmbientTrack>,
+}
+
+impl Default for SoundscapeSettings {
+    fn default() -> Self {
+        Self {
+            wind: 0.5,
+            water: 0.3,
+            insects: 0.4,
+            traffic: 0.0,
+            custom_tracks: Vec::new(),
+        }
+    }
+}
+
+impl SoundscapeSettings {
+    pub fn layer_volume(&self, layer: &AmbientLayer) -> f32 {
+        match layer {
+            AmbientLayer::Wind => self.wind,
+            AmbientLayer::Water => self.water,
+            AmbientLayer::Insects => self.insects,
+            AmbientLayer::Traffic => self.traffic,
+            AmbientLayer::Custom(file_name) => self.custom_tracks.iter()
+                .find(|track| &track.file_name == file_name)
+                .map_or(0.0, |track| track.volume),
+        }
+    }
+
+    pub fn set_layer_volume(&mut self, layer: &AmbientLayer, volume: f32) {
+        let volume = volume.clamp(0.0, 1.0);
+        match layer {
+            AmbientLayer::Wind => self.wind = volume,
+            AmbientLayer::Water => self.water = volume,
+            AmbientLayer::Insects => self.insects = volume,
+            AmbientLayer::Traffic => self.traffic = volume,
+            AmbientLayer::Custom(file_name) => {
+                if let Some(track) = self.custom_tracks.iter_mut().find(|track| &track.file_name == file_name) {
+                    track.volume = volume;
+                }
+            }
+        }
+    }
+
+    // Every layer that should currently have a looping player
+    pub fn active_layers(&self) -> Vec<AmbientLayer> {
+        let mut layers: Vec<AmbientLayer> = AmbientLayer::BUILT_IN.to_vec();
+        layers.extend(self.custom_tracks.iter().map(|track| AmbientLayer::Custom(track.file_name.clone())));
+        layers.retain(|layer| self.layer_volume(layer) > 0.0);
+        layers
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct CustomAmbientTrack {
+    pub file_name: String,
+    pub volume: f32,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AmbientLayer {
+    Wind,
+    Water,
+    Insects,
+    Traffic,
+    Custom(String),
+}
+
+impl AmbientLayer {
+    pub const BUILT_IN: [AmbientLayer; 4] = [
+        AmbientLayer::Wind,
+        AmbientLayer::Water,
+        AmbientLayer::Insects,
+        AmbientLayer::Traffic,
+    ];
+
+    pub fn label(&self) -> String {
+        match self {
+            Self::Wind => "Wind".to_string(),
+            Self::Water => "Water".to_string(),
+            Self::Insects => "Insects".to_string(),
+            Self::Traffic => "Traffic".to_string(),
+            Self::Custom(file_name) => file_name.clone(),
+        }
+    }
+
+    pub fn caption(&self) -> SoundCaption {
+        match self {
+            Self::Wind => SoundCaption::sound(CaptionCategory::Nature, "Wind"),
+            Self::Water => SoundCaption::sound(CaptionCategory::Nature, "Running water"),
+            Self::Insects => SoundCaption::sound(CaptionCategory::Nature, "Insects buzzing"),
+            Self::Traffic => SoundCaption::sound(CaptionCategory::Neighborhood, "Distant traffic"),
+            Self::Custom(file_name) => SoundCaption::sound(CaptionCategory::Nature, format!("Ambience: {}", file_name)),
+        }
+    }
+
+    pub fn asset_path(&self) -> String {
+        match self {
+            Self::Wind => "audio/ambient_wind.ogg".to_string(),
+            Self::Water => "audio/ambient_water.ogg".to_string(),
+            Self::Insects => "audio/ambient_insects.ogg".to_string(),
+            Self::Traffic => "audio/ambient_traffic.ogg".to_string(),
+            Self::Custom(file_name) => format!("{}://{}", USER_AMBIENT_SOURCE, file_name),
+        }
+    }
+}
+
+// Audio files found in the player's ambient folder, available to import
+#[derive(Resource, Default)]
+pub struct AvailableAmbientImports {
+    pub files: Vec<String>,
+}
+
+#[derive(Component)]
+pub struct SoundscapeLayerPlayer {
+    pub layer: AmbientLayer,
+}
+
+#[derive(Event)]
+pub struct ImportAmbientTracksEvent;
+
+pub fn user_ambient_directory() -> PathBuf {
+    dirs::data_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("AvianHaven")
+        .join("ambient")
+}
+
+// Must be called before DefaultPlugins so the asset server knows about the source
+pub fn register_user_ambient_source(app: &mut App) {
+    let directory = user_ambient_directory();
+    if let Err(e) = fs::create_dir_all(&directory) {
+        warn!("Could not create ambient import folder {:?}: {}", directory, e);
+    }
+
+    app.register_asset_source(
+        USER_AMBIENT_SOURCE,
+        AssetSourceBuilder::platform_default(&directory.to_string_lossy(), None),
+    );
+}
+
+pub fn scan_user_ambient_files() -> Vec<String> {
+    let mut files: Vec<String> = fs::read_dir(user_ambient_directory())
+        .map(|entries| {
+            entries.flatten()
+                .filter_map(|entry| entry.file_name().to_str().map(str::to_string))
+                .filter(|name| {
+                    name.rsplit_once('.')
+                        .is_some_and(|(_, ext)| SUPPORTED_EXTENSIONS.contains(&ext.to_lowercase().as_str()))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    files.sort();
+    files
+}
+
+pub fn import_ambient_tracks_system(
+    mut import_events: EventReader<ImportAmbientTracksEvent>,
+    mut available: ResMut<AvailableAmbientImports>,
+    mut settings: ResMut<SoundscapeSettings>,
+) {
+    for _event in import_events.read() {
+        available.files = scan_user_ambient_files();
+
+        let mut imported = 0;
+        for file_name in &available.files {
+            if settings.custom_tracks.iter().any(|track| &track.file_name == file_name) {
+                continue;
+            }
+
+            settings.custom_tracks.push(CustomAmbientTrack {
+                file_name: file_name.clone(),
+                volume: 0.5,
+            });
+            imported += 1;
+        }
+
+        info!("Imported {} custom ambient tracks from {:?}", imported, user_ambient_directory());
+    }
+}
+
+// Keeps one looping player per audible layer and applies slider volumes
+pub fn soundscape_playback_system(
+    mut commands: Commands,
+    settings: Res<SoundscapeSettings>,
+    audio_settings: Res<AudioSettings>,
+    audio_manager: Res<AudioManager>,
+    mut audio_banks: ResMut<AudioBanks>,
+    mut streamed_assets: ResMut<Assets<StreamedAudio>>,
+    mut player_query: Query<(Entity, &SoundscapeLayerPlayer, Option<&mut AudioSink>)>,
+) {
+    if !settings.is_changed() && !audio_settings.is_changed() {
+        return;
+    }
+
+    let active_layers = settings.active_layers();
+    let ambient_gain = audio_manager.bus_volume(MixerBus::Ambient) * audio_settings.volume;
+
+    for (entity, player, sink) in &mut player_query {
+        if !active_layers.contains(&player.layer) {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        if let Some(mut sink) = sink {
+            let volume = settings.layer_volume(&player.layer) * ambient_gain;
+            sink.set_volume(Volume::Linear(volume));
+        }
+    }
+
+    for layer in active_layers {
+        if player_query.iter().any(|(_, player, _)| player.layer == layer) {
+            continue;
+        }
+
+        let volume = settings.layer_volume(&layer) * ambient_gain;
+        // Streamed, and looped by the stream itself so the decoded loop is never held in memory
+        commands.spawn((
+            AudioPlayer(audio_banks.stream(&mut streamed_assets, &layer.asset_path(), true)),
+            PlaybackSettings::ONCE.with_volume(Volume::Linear(volume)),
+            Name::new(format!("Soundscape_{}", layer.label())),
+            layer.caption(),
+            SoundscapeLayerPlayer { layer },
+        ));
+    }
+}
+
+// Soundscape loops only play while the sanctuary is on screen
+pub fn stop_soundscape_system(
+    mut commands: Commands,
+    player_query: Query<Entity, With<SoundscapeLayerPlayer>>,
+) {
+    for entity in &player_query {
+        commands.entity(entity).despawn();
+    }
+}
+
+pub fn restart_soundscape_system(mut settings: ResMut<SoundscapeSettings>) {
+    settings.set_changed();
+}
@@ -0,0 +1,195 @@
+// src/audio/spectrogram.rs
+use bevy::prelude::*;
+use bevy::asset::RenderAssetUsages;
+use bevy::audio::{Decodable, Sample as _, Source as _};
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+
+const FRAME_SIZE: usize = 512;
+const HOP_SIZE: usize = 256;
+pub const SPECTROGRAM_BANDS: usize = 64;
+// Most songbird energy sits below this, so the upper bins are left off the display
+pub const MAX_DISPLAY_FREQUENCY: f32 = 11_000.0;
+const MAX_ANALYSIS_SECONDS: f32 = 12.0;
+const DYNAMIC_RANGE_DB: f32 = 70.0;
+// A column counts as part of a note within about 25 dB of the recording's peak
+const NOTE_LEVEL: f32 = 0.65;
+const MIN_NOTE_GAP_SECONDS: f32 = 0.04;
+
+// Time-frequency picture of a recording, one column per analysis frame with
+// band intensities normalized to 0.0-1.0, lowest frequency first
+pub struct Spectrogram {
+    pub columns: Vec<[f32; SPECTROGRAM_BANDS]>,
+    pub seconds_per_column: f32,
+    pub max_frequency: f32,
+}
+
+impl Spectrogram {
+    // Decodes the clip to mono and runs a short-time FFT over it
+    pub fn analyze(source: &bevy::audio::AudioSource) -> Self {
+        let decoder = source.decoder();
+        let channels = decoder.channels().max(1) as usize;
+        let sample_rate = decoder.sample_rate();
+        let max_samples = (MAX_ANALYSIS_SECONDS * sample_rate as f32) as usize * channels;
+
+        let interleaved: Vec<f32> = decoder.take(max_samples).map(|sample| sample.to_f32()).collect();
+        let mono: Vec<f32> = interleaved.chunks(channels)
+            .map(|frame| frame.iter().sum::<f32>() / frame.len() as f32)
+            .collect();
+
+        Self::from_samples(&mono, sample_rate)
+    }
+
+    pub fn from_samples(samples: &[f32], sample_rate: u32) -> Self {
+        let nyquist = sample_rate as f32 / 2.0;
+        let max_frequency = MAX_DISPLAY_FREQUENCY.min(nyquist);
+        let hz_per_bin = sample_rate as f32 / FRAME_SIZE as f32;
+        let window = hann_window();
+
+        let mut columns = Vec::new();
+        let mut start = 0;
+        while start + FRAME_SIZE <= samples.len() {
+            let frame: Vec<f32> = samples[start..start + FRAME_SIZE].iter()
+                .zip(&window)
+                .map(|(sample, weight)| sample * weight)
+                .collect();
+            let magnitudes = magnitude_spectrum(&frame);
+
+            // Each band keeps the loudest bin it covers so thin whistles stay visible
+            let mut column = [0.0f32; SPECTROGRAM_BANDS];
+            for (bin, magnitude) in magnitudes.iter().enumerate() {
+                let frequency = bin as f32 * hz_per_bin;
+                if frequency >= max_frequency {
+                    break;
+                }
+                let band = (frequency / max_frequency * SPECTROGRAM_BANDS as f32) as usize;
+                column[band] = column[band].max(*magnitude);
+            }
+            columns.push(column);
+            start += HOP_SIZE;
+        }
+
+        // Express everything in decibels below the loudest point of the recording
+        let peak = columns.iter().flatten().fold(0.0f32, |peak, value| peak.max(*value));
+        if peak > 0.0 {
+            for value in columns.iter_mut().flatten() {
+                let db = 20.0 * (*value / peak).max(1e-6).log10();
+                *value = (1.0 + db / DYNAMIC_RANGE_DB).clamp(0.0, 1.0);
+            }
+        }
+
+        Self {
+            columns,
+            seconds_per_column: HOP_SIZE as f32 / sample_rate.max(1) as f32,
+            max_frequency,
+        }
+    }
+
+    pub fn duration(&self) -> f32 {
+        self.columns.len() as f32 * self.seconds_per_column
+    }
+
+    // Start times of the notes: loud stretches after at least a short quiet gap
+    pub fn note_onsets(&self) -> Vec<f32> {
+        let min_gap = (MIN_NOTE_GAP_SECONDS / self.seconds_per_column.max(1e-6)).ceil() as usize;
+        let mut onsets = Vec::new();
+        let mut quiet = min_gap;
+        for (index, column) in self.columns.iter().enumerate() {
+            let loudness = column.iter().fold(0.0f32, |loudest, value| loudest.max(*value));
+            if loudness < NOTE_LEVEL {
+                quiet += 1;
+                continue;
+            }
+            if quiet >= min_gap {
+                onsets.push(index as f32 * self.seconds_per_column);
+            }
+            quiet = 0;
+        }
+        onsets
+    }
+
+    // Dark ink on paper like a printed field guide sonogram, high frequencies at the top
+    pub fn to_image(&self) -> Image {
+        let width = self.columns.len().max(1);
+        let mut rgba = vec![0u8; width * SPECTROGRAM_BANDS * 4];
+        let paper = Vec3::new(0.96, 0.93, 0.87);
+        let ink = Vec3::new(0.12, 0.08, 0.05);
+
+        for (x, column) in self.columns.iter().enumerate() {
+            for (band, intensity) in column.iter().enumerate() {
+                let y = SPECTROGRAM_BANDS - 1 - band;
+                let color = paper.lerp(ink, intensity.powf(1.5));
+                let index = (y * width + x) * 4;
+                rgba[index] = (color.x * 255.0) as u8;
+                rgba[index + 1] = (color.y * 255.0) as u8;
+                rgba[index + 2] = (color.z * 255.0) as u8;
+                rgba[index + 3] = 255;
+            }
+        }
+
+        Image::new(
+            Extent3d { width: width as u32, height: SPECTROGRAM_BANDS as u32, depth_or_array_layers: 1 },
+            TextureDimension::D2,
+            rgba,
+            TextureFormat::Rgba8UnormSrgb,
+            RenderAssetUsages::default(),
+        )
+    }
+}
+
+fn hann_window() -> Vec<f32> {
+    (0..FRAME_SIZE)
+        .map(|i| 0.5 - 0.5 * (std::f32::consts::TAU * i as f32 / (FRAME_SIZE - 1) as f32).cos())
+        .collect()
+}
+
+// Magnitudes of the first half of the spectrum; the frame length must be a power of two
+pub fn magnitude_spectrum(frame: &[f32]) -> Vec<f32> {
+    let mut real = frame.to_vec();
+    let mut imaginary = vec![0.0; frame.len()];
+    fft(&mut real, &mut imaginary);
+
+    real.iter().zip(&imaginary)
+        .take(frame.len() / 2)
+        .map(|(re, im)| (re * re + im * im).sqrt())
+        .collect()
+}
+
+// In-place iterative radix-2 Cooley-Tukey transform
+pub fn fft(real: &mut [f32], imaginary: &mut [f32]) {
+    let n = real.len();
+    debug_assert!(n.is_power_of_two() && imaginary.len() == n);
+
+    // Bit-reversal permutation
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            real.swap(i, j);
+            imaginary.swap(i, j);
+        }
+    }
+
+    let mut length = 2;
+    while length <= n {
+        let angle = -std::f32::consts::TAU / length as f32;
+        for start in (0..n).step_by(length) {
+            for k in 0..length / 2 {
+                let (sin, cos) = (angle * k as f32).sin_cos();
+                let even = start + k;
+                let odd = even + length / 2;
+                let odd_re = real[odd] * cos - imaginary[odd] * sin;
+                let odd_im = real[odd] * sin + imaginary[odd] * cos;
+                real[odd] = real[even] - odd_re;
+                imaginary[odd] = imaginary[even] - odd_im;
+                real[even] += odd_re;
+                imaginary[even] += odd_im;
+            }
+        }
+        length <<= 1;
+    }
+}
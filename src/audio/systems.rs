@@ -1,7 +1,7 @@
 // src/audio/systems.rs
 use bevy::prelude::*;
-use bevy::audio::PlaybackSettings;
-use crate::audio::{components::*, resources::*};
+use bevy::audio::{PlaybackSettings, AudioSink, AudioSinkPlayback, Volume};
+use crate::audio::{banks::AudioBanks, captions::{CaptionCategory, SoundCaption}, components::*, resources::*};
 use crate::bird_ai::components::{BirdAI, BirdState};
 use crate::animation::components::AnimatedBird;
 use crate::bird::BirdSpecies;
@@ -42,6 +42,16 @@ pub fn audio_event_system(
     time: Res<Time>,
 ) {
     for event in audio_events.read() {
+        let (audio_handle, bus) = match &event.source {
+            crate::audio::resources::AudioSource::BirdVocalization(handle, _) => (handle.clone(), MixerBus::Calls),
+            crate::audio::resources::AudioSource::UiSound(handle) => (handle.clone(), MixerBus::Effects),
+            // Ambient tracks are long-running music, crossfaded rather than pooled
+            crate::audio::resources::AudioSource::AmbientTrack(handle) => {
+                audio_manager.crossfade_to(handle.clone());
+                continue;
+            }
+        };
+        
         // Check if we have available sinks
         let sink_entity = if let Some(entity) = audio_manager.available_sinks.pop_front() {
             entity
@@ -56,12 +66,7 @@ pub fn audio_event_system(
             }
         };
         
-        // Get the actual audio source handle
-        let audio_handle = match &event.source {
-            crate::audio::resources::AudioSource::BirdVocalization(handle, _) => handle.clone(),
-            crate::audio::resources::AudioSource::AmbientTrack(handle) => handle.clone(),
-            crate::audio::resources::AudioSource::UiSound(handle) => handle.clone(),
-        };
+        let volume = Volume::Linear(audio_manager.bus_volume(bus) * audio_settings.volume);
         
         // Update sink component with timing info
         if let Ok(mut sink_component) = sink_query.get_mut(sink_entity) {
@@ -74,21 +79,21 @@ pub fn audio_event_system(
                 AudioCommand::PlayGlobal => {
                     commands.entity(sink_entity).insert((
                         AudioPlayer::new(audio_handle),
-                        PlaybackSettings::ONCE
+                        PlaybackSettings::ONCE.with_volume(volume)
                     ));
                 }
                 AudioCommand::PlayAt(position) => {
                     let (_gain, _panning) = calculate_positional_audio(*position, audio_manager.listener_position, 300.0);
                     commands.entity(sink_entity).insert((
                         AudioPlayer::new(audio_handle),
-                        PlaybackSettings::ONCE.with_spatial(true) // Volume control disabled for Bevy 0.16.1 compatibility
+                        PlaybackSettings::ONCE.with_spatial(true).with_volume(volume)
                     ));
                 }
                 AudioCommand::PlayFromEntity(_) => {
                     // Will be handled by update_positional_audio_system
                     commands.entity(sink_entity).insert((
                         AudioPlayer::new(audio_handle),
-                        PlaybackSettings::ONCE
+                        PlaybackSettings::ONCE.with_volume(volume)
                     ));
                 }
             }
@@ -189,18 +194,23 @@ pub fn audio_cleanup_system(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn bird_vocalization_system(
     mut commands: Commands,
     camera_query: Query<&Transform, (With<Camera2d>, Without<BirdAI>)>,
     bird_query: Query<(Entity, &Transform, &BirdState, &AnimatedBird), (With<BirdAI>, Changed<BirdState>)>,
     all_birds_query: Query<(&Transform, &AnimatedBird), With<BirdAI>>,
     asset_server: Res<AssetServer>,
-    time: Res<Time>,
+    species_packs: Res<crate::species_packs::SpeciesPackManager>,
+    mut audio_banks: ResMut<AudioBanks>,
+    audio_manager: Res<AudioManager>,
+    audio_settings: Res<AudioSettings>,
 ) {
     let Ok(camera_transform) = camera_query.single() else {
         return;
     };
     let camera_pos = camera_transform.translation.truncate();
+    let call_gain = audio_manager.bus_volume(MixerBus::Calls) * audio_settings.volume;
     
     for (entity, transform, bird_state, animated_bird) in &bird_query {
         let bird_pos = transform.translation.truncate();
@@ -215,23 +225,27 @@ pub fn bird_vocalization_system(
         let (should_vocalize, call_type) = determine_vocalization(bird_state, animated_bird.species, &all_birds_query, bird_pos);
         
         if should_vocalize {
-            let sound_path = get_species_sound_path(animated_bird.species, call_type);
-            let audio_handle = asset_server.load(sound_path);
+            let sound_path = vocalization_path(&species_packs, animated_bird.species, call_type);
+            let audio_handle = audio_banks.vocalization(&asset_server, sound_path);
             
             // Calculate positional audio with species-specific range
             let max_range = get_species_audio_range(animated_bird.species);
             let (gain, _panning) = calculate_positional_audio(bird_pos, camera_pos, max_range);
             
-            // Species-specific volume adjustment (calculated but not applied due to Bevy 0.16.1 limitations)
-            let _volume_modifier = get_species_volume(animated_bird.species);
-            let _final_volume = gain * _volume_modifier;
+            // Species-specific loudness; the mixer may still drop the call if too many are sounding
+            let level = gain * get_species_volume(animated_bird.species);
             
             commands.spawn((
                 AudioPlayer::new(audio_handle),
                 PlaybackSettings::ONCE
-                    // Volume disabled for Bevy 0.16.1 compatibility
-                    .with_spatial(true),
+                    .with_spatial(true)
+                    .with_volume(Volume::Linear(level * call_gain)),
                 Transform::from_translation(transform.translation),
+                Vocalization {
+                    species: animated_bird.species,
+                    level,
+                },
+                SoundCaption::call(animated_bird.species, call_type.caption()).with_vocalization(format!("{:?}", call_type)),
                 PositionalAudioSource {
                     source_entity: entity,
                     max_distance: max_range,
@@ -254,6 +268,28 @@ pub enum CallType {
     Territorial, // Aggressive display
 }
 
+impl CallType {
+    pub const ALL: [CallType; 5] = [Self::Song, Self::Alarm, Self::Contact, Self::Feeding, Self::Territorial];
+
+    // How the caption feed names the call, after the species
+    pub fn caption(&self) -> &'static str {
+        match self {
+            Self::Song => "song",
+            Self::Alarm => "alarm call",
+            Self::Contact => "contact call",
+            Self::Feeding => "feeding chatter",
+            Self::Territorial => "territorial call",
+        }
+    }
+}
+
+// Species packs can bring their own recordings
+pub fn vocalization_path(species_packs: &crate::species_packs::SpeciesPackManager, species: BirdSpecies, call_type: CallType) -> String {
+    species_packs.call_sound(species, &format!("{:?}", call_type))
+        .unwrap_or_else(|| get_species_sound_path(species, call_type))
+        .to_string()
+}
+
 fn determine_vocalization(
     bird_state: &BirdState, 
     species: BirdSpecies, 
@@ -301,7 +337,7 @@ fn determine_vocalization(
     }
 }
 
-fn get_species_sound_path(species: BirdSpecies, call_type: CallType) -> &'static str {
+pub fn get_species_sound_path(species: BirdSpecies, call_type: CallType) -> &'static str {
     match (species, call_type) {
         // Cardinals - distinctive calls
         (BirdSpecies::Cardinal, CallType::Song) => "audio/cardinal_song.ogg",
@@ -457,12 +493,15 @@ fn estimate_audio_duration(source: &crate::audio::resources::AudioSource) -> f32
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn ambient_feeder_audio_system(
     mut commands: Commands,
     camera_query: Query<&Transform, (With<Camera2d>, Without<BirdAI>)>,
     feeder_query: Query<(&Transform, &Feeder)>,
     bird_query: Query<&Transform, With<BirdAI>>,
     asset_server: Res<AssetServer>,
+    audio_manager: Res<AudioManager>,
+    audio_settings: Res<AudioSettings>,
     time: Res<Time>,
 ) {
     let Ok(camera_transform) = camera_query.single() else {
@@ -488,25 +527,74 @@ pub fn ambient_feeder_audio_system(
         
         // Play ambient feeder sounds based on activity level
         if birds_near_feeder > 0 && time.elapsed_secs() % 8.0 < 0.1 {
-            let sound_path = match birds_near_feeder {
-                1..=2 => "audio/feeder_light_activity.ogg",
-                3..=5 => "audio/feeder_moderate_activity.ogg",
-                _ => "audio/feeder_busy_activity.ogg",
+            let (sound_path, caption) = match birds_near_feeder {
+                1..=2 => ("audio/feeder_light_activity.ogg", "Seed rattling in a feeder"),
+                3..=5 => ("audio/feeder_moderate_activity.ogg", "Feeder chatter"),
+                _ => ("audio/feeder_busy_activity.ogg", "Busy feeder"),
             };
             
             let audio_handle = asset_server.load(sound_path);
-            let (_gain, _) = calculate_positional_audio(feeder_pos, camera_pos, 300.0);
+            let (gain, _) = calculate_positional_audio(feeder_pos, camera_pos, 300.0);
+            let volume = gain * audio_manager.bus_volume(MixerBus::Ambient) * audio_settings.volume;
             
             commands.spawn((
                 AudioPlayer::new(audio_handle),
-                PlaybackSettings::ONCE.with_spatial(true),
+                PlaybackSettings::DESPAWN
+                    .with_spatial(true)
+                    .with_volume(Volume::Linear(volume)),
                 Transform::from_translation(feeder_transform.translation),
                 PositionalAudioSource {
                     source_entity: Entity::PLACEHOLDER,
                     max_distance: 300.0,
                     volume_curve: AudioVolumeCurve::Linear,
                 },
+                SoundCaption::sound(CaptionCategory::Yard, caption),
             ));
         }
     }
 }
+
+pub fn music_crossfade_system(
+    mut commands: Commands,
+    mut audio_manager: ResMut<AudioManager>,
+    audio_settings: Res<AudioSettings>,
+    mut music_query: Query<(Entity, &mut MusicFade, Option<&mut AudioSink>)>,
+    time: Res<Time>,
+) {
+    if let Some(handle) = audio_manager.queued_music.take() {
+        if let Some(previous) = audio_manager.current_music {
+            if let Ok((_, mut fade, _)) = music_query.get_mut(previous) {
+                fade.target = 0.0;
+            }
+        }
+        
+        // The stream loops itself; LOOP would buffer the whole decoded track
+        let music_entity = commands.spawn((
+            AudioPlayer(handle),
+            PlaybackSettings::ONCE.with_volume(Volume::Linear(0.0)),
+            MusicFade { current: 0.0, target: 1.0 },
+            Name::new("Music"),
+        )).id();
+        audio_manager.current_music = Some(music_entity);
+    }
+    
+    let music_volume = audio_manager.bus_volume(MixerBus::Music) * audio_settings.volume;
+    let fade_step = time.delta_secs() / audio_manager.music_crossfade_secs.max(0.01);
+    
+    for (entity, mut fade, sink) in &mut music_query {
+        if fade.current < fade.target {
+            fade.current = (fade.current + fade_step).min(fade.target);
+        } else if fade.current > fade.target {
+            fade.current = (fade.current - fade_step).max(fade.target);
+        }
+        
+        if fade.target <= 0.0 && fade.current <= 0.0 {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        
+        if let Some(mut sink) = sink {
+            sink.set_volume(Volume::Linear(fade.current * music_volume));
+        }
+    }
+}
@@ -16,7 +16,7 @@ impl Plugin for AutomatedTestingPlugin {
                 test_runner_system,
                 metrics_collection_system,
                 keyboard_shortcuts_system,
-            ).run_if(in_state(crate::AppState::Playing)));
+            ).in_set(crate::GameSet::Simulation).run_if(in_state(crate::AppState::Playing)));
     }
 }
 
@@ -265,12 +265,13 @@ pub fn keyboard_shortcuts_system(
     mut test_events: EventWriter<TestEvent>,
     keyboard: Res<ButtonInput<KeyCode>>,
 ) {
-    // Quick test shortcuts (F1-F5)
+    // Quick test shortcuts (F1-F5); F2 on its own opens the UI inspector
     if keyboard.just_pressed(KeyCode::F1) {
         test_events.write(TestEvent::StartTest("population_stress".to_string()));
     }
     
-    if keyboard.just_pressed(KeyCode::F2) {
+    let ctrl = keyboard.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]);
+    if ctrl && keyboard.just_pressed(KeyCode::F2) {
         test_events.write(TestEvent::StartTest("seasonal_cycle".to_string()));
     }
     
@@ -0,0 +1,276 @@
+// Binoculars - Lightweight zoomed view for watching and identifying birds
+//
+// Unlike photo mode there is no shutter, score or camera HUD: the view zooms in behind a lens mask,
+// hand sway settles once the view is held still, and the bird nearest the center gets an ID hint
+// with its field marks. The name is only shown for species already in the journal, so the hint
+// teaches what to look for instead of giving the answer away.
+use bevy::prelude::*;
+use bevy::input::mouse::MouseWheel;
+use crate::bird::Bird;
+use crate::journal::resources::{BirdEducationData, DiscoveredSpecies};
+use crate::keybindings::{GameAction, KeyBindings};
+use crate::photo_mode::components::PhotoTarget;
+use crate::photo_mode::resources::PhotoModeSettings;
+use crate::weather_effects::fog::Fog;
+
+const DEFAULT_ZOOM: f32 = 3.0;
+const MIN_ZOOM: f32 = 2.0;
+const MAX_ZOOM: f32 = 6.0;
+const ZOOM_STEP: f32 = 0.5;
+// Hand shake in screen pixels at full sway, before stabilization
+const SWAY_PIXELS: f32 = 10.0;
+// Seconds of holding the view still before sway settles to its minimum
+const STEADY_SECONDS: f32 = 1.5;
+const STEADY_SWAY: f32 = 0.15;
+// Birds within this many screen pixels of the center get the ID hint
+const HINT_RADIUS: f32 = 140.0;
+
+pub struct BinocularsPlugin;
+
+impl Plugin for BinocularsPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<Binoculars>()
+            .add_systems(Update, toggle_binoculars_system
+                .run_if(crate::debug_console::console_is_not_visible)
+                .in_set(crate::GameSet::Input)
+                .run_if(in_state(crate::AppState::Playing)))
+            // After camera movement so sway is layered on top of where the player aimed
+            .add_systems(Update, (binoculars_view_system, binoculars_hint_system)
+                .chain()
+                .in_set(crate::GameSet::Presentation)
+                .run_if(in_state(crate::AppState::Playing)))
+            .add_systems(OnExit(crate::AppState::Playing), close_binoculars_system);
+    }
+}
+
+// Resources
+#[derive(Resource)]
+pub struct Binoculars {
+    pub active: bool,
+    pub zoom: f32,
+    // Projection scale from before the binoculars were raised
+    restore_scale: f32,
+    // 0 while the view moves, climbing to 1 as it is held still
+    steady: f32,
+    sway_time: f32,
+    applied_sway: Vec2,
+    last_aim: Vec2,
+}
+
+impl Default for Binoculars {
+    fn default() -> Self {
+        Self {
+            active: false,
+            zoom: DEFAULT_ZOOM,
+            restore_scale: 1.0,
+            steady: 0.0,
+            sway_time: 0.0,
+            applied_sway: Vec2::ZERO,
+            last_aim: Vec2::ZERO,
+        }
+    }
+}
+
+// Components
+#[derive(Component)]
+pub struct BinocularsOverlay;
+
+#[derive(Component)]
+pub struct BinocularsHintText;
+
+#[allow(clippy::too_many_arguments)]
+pub fn toggle_binoculars_system(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    key_bindings: Res<KeyBindings>,
+    photo_settings: Res<PhotoModeSettings>,
+    mut binoculars: ResMut<Binoculars>,
+    mut camera_query: Query<(&mut Transform, &mut Projection), With<PhotoTarget>>,
+    overlay_query: Query<Entity, With<BinocularsOverlay>>,
+) {
+    let pressed = key_bindings.is_action_just_pressed(GameAction::ToggleBinoculars, &keyboard, &mouse);
+    // Photo mode has its own zoom and HUD, so raising the camera lowers the binoculars
+    let lower = binoculars.active && (pressed || photo_settings.is_active);
+    let raise = !binoculars.active && pressed && !photo_settings.is_active;
+    if !lower && !raise {
+        return;
+    }
+
+    let Ok((mut transform, mut projection)) = camera_query.single_mut() else { return };
+    let Projection::Orthographic(ortho) = projection.as_mut() else { return };
+
+    if raise {
+        binoculars.active = true;
+        binoculars.restore_scale = ortho.scale;
+        binoculars.steady = 0.0;
+        binoculars.last_aim = transform.translation.truncate();
+        ortho.scale = binoculars.restore_scale / binoculars.zoom;
+        spawn_binoculars_overlay(&mut commands);
+        info!("🔭 Binoculars raised at {:.1}x", binoculars.zoom);
+    } else {
+        lower_binoculars(&mut binoculars, &mut transform, ortho);
+        for overlay in &overlay_query {
+            commands.entity(overlay).despawn();
+        }
+        info!("🔭 Binoculars lowered");
+    }
+}
+
+fn lower_binoculars(binoculars: &mut Binoculars, transform: &mut Transform, ortho: &mut OrthographicProjection) {
+    transform.translation -= binoculars.applied_sway.extend(0.0);
+    binoculars.applied_sway = Vec2::ZERO;
+    ortho.scale = binoculars.restore_scale;
+    binoculars.active = false;
+}
+
+// The overlay is state scoped; only the camera needs putting back
+pub fn close_binoculars_system(
+    mut binoculars: ResMut<Binoculars>,
+    mut camera_query: Query<(&mut Transform, &mut Projection), With<PhotoTarget>>,
+) {
+    if !binoculars.active {
+        return;
+    }
+    if let Ok((mut transform, mut projection)) = camera_query.single_mut() {
+        if let Projection::Orthographic(ortho) = projection.as_mut() {
+            lower_binoculars(&mut binoculars, &mut transform, ortho);
+        }
+    }
+}
+
+pub fn binoculars_view_system(
+    mut binoculars: ResMut<Binoculars>,
+    mut scroll_events: EventReader<MouseWheel>,
+    mut camera_query: Query<(&mut Transform, &mut Projection), With<PhotoTarget>>,
+    time: Res<Time>,
+) {
+    if !binoculars.active {
+        scroll_events.clear();
+        return;
+    }
+    let Ok((mut transform, mut projection)) = camera_query.single_mut() else { return };
+    let Projection::Orthographic(ortho) = projection.as_mut() else { return };
+
+    for scroll in scroll_events.read() {
+        binoculars.zoom = (binoculars.zoom + scroll.y.signum() * ZOOM_STEP).clamp(MIN_ZOOM, MAX_ZOOM);
+    }
+    ortho.scale = binoculars.restore_scale / binoculars.zoom;
+
+    // Where the player is pointing, without last frame's shake
+    let aim = transform.translation.truncate() - binoculars.applied_sway;
+    let dt = time.delta_secs();
+    if aim.distance(binoculars.last_aim) > 0.5 {
+        binoculars.steady = 0.0;
+    } else {
+        binoculars.steady = (binoculars.steady + dt / STEADY_SECONDS).min(1.0);
+    }
+    binoculars.last_aim = aim;
+
+    // Two slow sines per axis read as breathing and hand tremor rather than a regular wobble
+    binoculars.sway_time += dt;
+    let t = binoculars.sway_time;
+    let wobble = Vec2::new(
+        (t * 1.3).sin() + 0.5 * (t * 2.9).sin(),
+        (t * 1.1).cos() + 0.5 * (t * 3.7).sin(),
+    ) / 1.5;
+    let stabilization = 1.0 - (1.0 - STEADY_SWAY) * binoculars.steady;
+    // Shake grows with magnification like it does with real optics
+    let sway = wobble * SWAY_PIXELS * (binoculars.zoom / DEFAULT_ZOOM) * stabilization * ortho.scale;
+
+    transform.translation = (aim + sway).extend(transform.translation.z);
+    binoculars.applied_sway = sway;
+}
+
+pub fn binoculars_hint_system(
+    binoculars: Res<Binoculars>,
+    camera_query: Query<(&Transform, &Projection), With<PhotoTarget>>,
+    bird_query: Query<(&Transform, &Bird)>,
+    discovered: Res<DiscoveredSpecies>,
+    education_data: Res<BirdEducationData>,
+    fog: Res<Fog>,
+    mut hint_query: Query<&mut Text, With<BinocularsHintText>>,
+) {
+    if !binoculars.active {
+        return;
+    }
+    let Ok(mut hint) = hint_query.single_mut() else { return };
+    let Ok((camera_transform, Projection::Orthographic(ortho))) = camera_query.single() else { return };
+
+    let center = camera_transform.translation.truncate();
+    // Fog washes out anything not close to the middle of the lens
+    let radius = HINT_RADIUS * ortho.scale * fog.detection_factor();
+    let nearest = bird_query.iter()
+        .map(|(transform, bird)| (transform.translation.truncate().distance(center), bird.species))
+        .filter(|(distance, _)| *distance <= radius)
+        .min_by(|(a, _), (b, _)| a.total_cmp(b));
+
+    let text = match nearest {
+        None if fog.is_down() => "Too foggy to pick out a bird - center one closely".to_string(),
+        None => "Center a bird in view to study its field marks".to_string(),
+        Some((_, species)) => {
+            let facts = education_data.species_facts.get(&species);
+            let name = match facts {
+                Some(facts) if discovered.0.contains(&species) => facts.common_name.clone(),
+                _ if discovered.0.contains(&species) => format!("{:?}", species),
+                _ => "Unidentified bird".to_string(),
+            };
+            match facts {
+                Some(facts) => format!("{}\nField marks: {}", name, facts.identification_tips),
+                None => name,
+            }
+        }
+    };
+    if hint.0 != text {
+        hint.0 = text;
+    }
+}
+
+fn spawn_binoculars_overlay(commands: &mut Commands) {
+    commands.spawn((
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            position_type: PositionType::Absolute,
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            overflow: Overflow::clip(),
+            ..default()
+        },
+        GlobalZIndex(20),
+        Pickable::IGNORE,
+        BinocularsOverlay,
+        StateScoped(crate::AppState::Playing),
+    )).with_children(|overlay| {
+        // The lens opening; its oversized outline blacks out everything around it
+        overlay.spawn((
+            Node {
+                width: Val::Vw(80.0),
+                height: Val::Vh(80.0),
+                ..default()
+            },
+            BorderRadius::all(Val::Percent(50.0)),
+            Outline::new(Val::Vw(100.0), Val::ZERO, Color::srgba(0.02, 0.02, 0.03, 0.94)),
+            Pickable::IGNORE,
+        ));
+
+        overlay.spawn((
+            Text::new(""),
+            TextFont { font_size: 15.0, ..default() },
+            TextColor(Color::srgb(0.95, 0.92, 0.85)),
+            TextLayout::new_with_justify(JustifyText::Center),
+            Node {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(24.0),
+                max_width: Val::Px(640.0),
+                padding: UiRect::axes(Val::Px(12.0), Val::Px(6.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.55)),
+            BorderRadius::all(Val::Px(6.0)),
+            Pickable::IGNORE,
+            BinocularsHintText,
+        ));
+    });
+}
@@ -11,6 +11,10 @@ use crate::environment::resources::{TimeState, WeatherState, SeasonalState};
 use crate::environment::components::Season;
 use crate::journal::resources::BirdEducationData;
 
+const BIRD_COLLIDER_RADIUS: f32 = 10.0;
+// Rough half size of a bird sprite in world units, just outside its collider
+pub const BIRD_HALF_EXTENT: f32 = 12.0;
+
 pub struct BirdPlugin;
 
 impl Plugin for BirdPlugin {
@@ -26,7 +30,7 @@ impl Plugin for BirdPlugin {
                     bird_movement,
                     update_wander_timer,
                     environmental_bird_spawning_system,
-                ).run_if(in_state(AppState::Playing)),
+                ).in_set(crate::GameSet::Simulation).run_if(in_state(AppState::Playing)),
             );
     }
 }
@@ -175,6 +179,11 @@ impl BirdSpecies {
         }
     }
     
+    // Introduced species don't count toward native-habitat goals
+    pub fn is_native(&self) -> bool {
+        !matches!(self, Self::EuropeanStarling)
+    }
+    
     pub fn rarity_tier(&self) -> u8 {
         match self {
             // Tier 1 - Common
@@ -630,7 +639,7 @@ fn spawn_bird(commands: &mut Commands) {
         },
         Transform::from_xyz(x, y, 1.0),
         RigidBody::Dynamic,
-        Collider::ball(10.0),
+        Collider::ball(BIRD_COLLIDER_RADIUS),
         Bird { species },
         Velocity(Vec2::ZERO),
         WanderTimer(Timer::from_seconds(2.0, TimerMode::Repeating)),
@@ -694,6 +703,7 @@ fn spawn_bird(commands: &mut Commands) {
         energy_level: rng.random_range(0.5..1.0),
         last_hunt_time: 0.0,
         roost_arrival_time: None,
+        roost_slot: None,
     });
     
     // Add animation components
@@ -775,6 +785,13 @@ pub fn environmental_bird_spawning_system(
     // time: Res<Time>,
     bird_registry: Res<crate::bird_data::BirdDataRegistry>,
     education_data: Res<BirdEducationData>,
+    provisioning: Res<crate::bird_health::ProvisioningRecord>,
+    difficulty: Res<crate::difficulty::DifficultySettings>,
+    garden_styles: Res<crate::garden_styles::GardenStyles>,
+    irruptions: Res<crate::environment::irruption::Irruptions>,
+    pests: Res<crate::garden_pests::GardenPests>,
+    noise: Res<crate::neighborhood_noise::NeighborhoodNoise>,
+    diagnostics: Option<ResMut<crate::spawn_debug::SpawnDiagnostics>>,
 ) {
     // Environmental spawning logic
     let season = time_state.get_season();
@@ -783,11 +800,31 @@ pub fn environmental_bird_spawning_system(
     let time_activity = time_state.daylight_factor();
     let song_activity = time_state.song_period_activity(); // Dawn chorus and evening song multiplier
     
-    let spawn_chance = base_activity * weather_activity * time_activity * song_activity * 0.001; // Base spawn rate per frame
+    let return_rate = provisioning.return_rate_multiplier; // Well-fed sanctuaries see more returning birds
+    let style_rate = garden_styles.attraction_multiplier(); // Completed garden style sets draw a few more visitors
+    let noise_rate = noise.visit_rate(); // Fewer birds drop in while the neighborhood is loud
+    
+    let spawn_chance = base_activity * weather_activity * time_activity * song_activity * return_rate * style_rate * noise_rate * 0.001; // Base spawn rate per frame
     
     // Only spawn if we're under the bird limit and conditions are favorable
-    if bird_count.0 < 15 && rand::rng().random::<f32>() < spawn_chance {
-        spawn_seasonal_bird(&mut commands, &seasonal_state, &bird_registry, &education_data, season);
+    let spawned = if bird_count.0 < 15 && rand::rng().random::<f32>() < spawn_chance {
+        spawn_seasonal_bird(&mut commands, &seasonal_state, &bird_registry, &education_data, &difficulty, &irruptions, &pests, season)
+    } else {
+        None
+    };
+    
+    // Dev builds show every factor in the spawn debug panel, see spawn_debug.rs
+    if let Some(mut diagnostics) = diagnostics {
+        diagnostics.record(crate::spawn_debug::SpawnFactors {
+            season: base_activity,
+            weather: weather_activity,
+            time_of_day: time_activity,
+            song: song_activity,
+            provisioning: return_rate,
+            garden_style: style_rate,
+            noise: noise_rate,
+            chance: spawn_chance,
+        }, bird_count.0, spawned, &time_state);
     }
 }
 
@@ -796,44 +833,46 @@ fn spawn_seasonal_bird(
     seasonal_state: &SeasonalState,
     bird_registry: &crate::bird_data::BirdDataRegistry,
     education_data: &BirdEducationData,
+    difficulty: &crate::difficulty::DifficultySettings,
+    irruptions: &crate::environment::irruption::Irruptions,
+    pests: &crate::garden_pests::GardenPests,
     season: Season,
-) {
+) -> Option<crate::spawn_debug::SpawnRoll> {
     let mut rng = rand::rng();
     
     // Select species based on seasonal availability and migration data
-    let available_species: Vec<(BirdSpecies, f32)> = seasonal_state.available_species.iter()
-        .map(|(species, _)| {
-            let mut probability = bird_registry.get_spawn_probability(species, season);
-            
-            // Apply migration logic based on education data
-            if let Some(migration_data) = education_data.migration_data.get(species) {
-                probability *= get_migration_availability(*species, migration_data, season);
-            }
-            
-            (*species, probability)
-        })
-        .filter(|(_, prob)| *prob > 0.0) // Only include species available this season
-        .collect();
+    let mut available_species = seasonal_spawn_weights(seasonal_state, bird_registry, education_data, difficulty, season);
+    
+    // An irruption floods the yard with wandering finches, see environment/irruption.rs
+    irruptions.apply(&mut available_species);
+    // Pest outbreaks on the flower beds draw in the insect eaters, see garden_pests.rs
+    pests.apply(&mut available_species);
     
     if available_species.is_empty() {
-        return; // No species available
+        return None; // No species available
     }
     
     // Weighted random selection
     let total_weight: f32 = available_species.iter().map(|(_, weight)| weight).sum();
-    let mut random_weight = rng.random::<f32>() * total_weight;
+    let roll = rng.random::<f32>();
+    let mut random_weight = roll * total_weight;
     
-    let mut selected_species = available_species[0].0;
+    let (mut selected_species, mut selected_weight) = available_species[0];
     for (species, weight) in available_species {
         random_weight -= weight;
         if random_weight <= 0.0 {
-            selected_species = species;
+            (selected_species, selected_weight) = (species, weight);
             break;
         }
     }
     
     // Spawn the selected bird
     spawn_specific_bird(commands, selected_species);
+    Some(crate::spawn_debug::SpawnRoll {
+        species: selected_species,
+        roll,
+        odds: selected_weight / total_weight,
+    })
 }
 
 fn spawn_specific_bird(commands: &mut Commands, species: BirdSpecies) {
@@ -853,7 +892,7 @@ fn spawn_specific_bird(commands: &mut Commands, species: BirdSpecies) {
         },
         Transform::from_xyz(x, y, 1.0),
         RigidBody::Dynamic,
-        Collider::ball(10.0),
+        Collider::ball(BIRD_COLLIDER_RADIUS),
         Bird { species },
         Velocity(Vec2::ZERO),
         WanderTimer(Timer::from_seconds(2.0, TimerMode::Repeating)),
@@ -883,6 +922,56 @@ fn spawn_specific_bird(commands: &mut Commands, species: BirdSpecies) {
     ));
 }
 
+/// Spawn weight of every species in the seasonal pool, leaving out those with no chance this season
+pub fn seasonal_spawn_weights(
+    seasonal_state: &SeasonalState,
+    bird_registry: &crate::bird_data::BirdDataRegistry,
+    education_data: &BirdEducationData,
+    difficulty: &crate::difficulty::DifficultySettings,
+    season: Season,
+) -> Vec<(BirdSpecies, f32)> {
+    seasonal_state.available_species.keys()
+        .map(|species| (*species, spawn_weight_factors(*species, bird_registry, education_data, difficulty, season).weight()))
+        .filter(|(_, prob)| *prob > 0.0) // Only include species available this season
+        .collect()
+}
+
+/// The multipliers that make up one species' spawn weight
+#[derive(Debug, Clone, Copy)]
+pub struct SpawnWeightFactors {
+    // Species data spawn probability with its seasonal availability
+    pub seasonal: f32,
+    pub migration: f32,
+    pub rarity: f32,
+}
+
+impl SpawnWeightFactors {
+    pub fn weight(&self) -> f32 {
+        self.seasonal * self.migration * self.rarity
+    }
+}
+
+pub fn spawn_weight_factors(
+    species: BirdSpecies,
+    bird_registry: &crate::bird_data::BirdDataRegistry,
+    education_data: &BirdEducationData,
+    difficulty: &crate::difficulty::DifficultySettings,
+    season: Season,
+) -> SpawnWeightFactors {
+    // Apply migration logic based on education data
+    let migration = education_data.migration_data.get(&species)
+        .map_or(1.0, |migration_data| get_migration_availability(species, migration_data, season));
+    
+    // Rare and legendary visitors scale with the difficulty setting
+    let rarity = if species.rarity_tier() >= 3 { difficulty.rare_bird_multiplier() } else { 1.0 };
+    
+    SpawnWeightFactors {
+        seasonal: bird_registry.get_spawn_probability(&species, season),
+        migration,
+        rarity,
+    }
+}
+
 /// Determines migration availability based on education data and current season
 fn get_migration_availability(
     species: BirdSpecies,
@@ -69,6 +69,8 @@ pub enum BirdState {
     Caching,        // Storing food in hidden locations
     Retrieving,     // Recovering cached food items
     HoverFeeding,   // Hover feeding behavior for nectar species
+    Begging,        // Juvenile begging for food from a parent
+    Drumming,       // Woodpecker drumming on or excavating dead wood
 }
 
 #[derive(Component)]
@@ -173,4 +175,40 @@ pub struct ForagingState {
     pub search_progress: f32,     // Progress through current search pattern
     pub items_found: u32,         // Items found in current foraging session
     pub energy_spent: f32,        // Energy used in current session
-}
\ No newline at end of file
+}
+// A trip toward the current target, flown as an arc and dropped once the bird lands or turns back
+#[derive(Component)]
+pub struct FlightPath {
+    pub target: Entity,
+    pub start: Vec2,
+    pub style: crate::bird_data::FlightStyle,
+    pub cruise_speed: f32,
+    pub progress: f32,            // 0.0 at takeoff, 1.0 at the target
+    pub elapsed: f32,
+    pub heading: f32,             // Direction of travel last frame, radians
+    pub bank: f32,                // Current lean into the turn, radians
+}
+
+impl FlightPath {
+    pub fn new(target: Entity, start: Vec2, target_position: Vec2, style: crate::bird_data::FlightStyle, cruise_speed: f32) -> Self {
+        let chord = target_position - start;
+        Self {
+            target,
+            start,
+            style,
+            cruise_speed,
+            progress: 0.0,
+            elapsed: 0.0,
+            heading: chord.y.atan2(chord.x),
+            bank: 0.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FlightPhase {
+    Flapping,
+    Gliding,
+    Flaring,
+    Landed,
+}
@@ -93,6 +93,8 @@ impl BehaviorTreeConfig {
             "Caching" => BirdState::Caching,
             "Retrieving" => BirdState::Retrieving,
             "HoverFeeding" => BirdState::HoverFeeding,
+            "Begging" => BirdState::Begging,
+            "Drumming" => BirdState::Drumming,
             _ => BirdState::Wandering,
         }
     }
@@ -65,6 +65,7 @@ impl Plugin for BirdAiPlugin {
             .init_resource::<UtilityTimer>()
             .init_resource::<BehaviorTreeTimer>()
             .init_resource::<resources::BehaviorTreeConfigResource>()
+            .init_resource::<FeederPresence>()
             .add_systems(Startup, (setup_test_world, load_behavior_tree_config))
             .add_systems(Update, (
                 // Core AI systems
@@ -72,7 +73,7 @@ impl Plugin for BirdAiPlugin {
                 social_awareness_system,
                 behavior_tree_system,
                 need_decay_system,
-            ).run_if(in_state(AppState::Playing)))
+            ).in_set(crate::GameSet::AI).run_if(in_state(AppState::Playing)))
             .add_systems(Update, (
                 // Basic behavior systems
                 wandering_system,
@@ -82,7 +83,7 @@ impl Plugin for BirdAiPlugin {
                 bathing_system,
                 fleeing_system,
                 resting_system,
-            ).run_if(in_state(AppState::Playing)))
+            ).in_set(crate::GameSet::AI).run_if(in_state(AppState::Playing)))
             .add_systems(Update, (
                 // Advanced behavior systems
                 playing_system,
@@ -90,14 +91,14 @@ impl Plugin for BirdAiPlugin {
                 nesting_system,
                 roosting_system,
                 sheltering_system,
-            ).run_if(in_state(AppState::Playing)))
+            ).in_set(crate::GameSet::AI).run_if(in_state(AppState::Playing)))
             .add_systems(Update, (
                 // Social behavior systems
                 courting_system,
                 territorial_system,
                 flocking_system,
                 following_system,
-            ).run_if(in_state(AppState::Playing)))
+            ).in_set(crate::GameSet::AI).run_if(in_state(AppState::Playing)))
             .add_systems(Update, (
                 // Foraging behavior systems
                 foraging_system,
@@ -105,8 +106,9 @@ impl Plugin for BirdAiPlugin {
                 retrieving_system,
                 hover_feeding_system,
                 competitive_feeding_system,
-            ).run_if(in_state(AppState::Playing)))
-            .add_systems(Update, check_behavior_tree_loading);
+                presence_cleanup_system.after(eating_system).after(drinking_system).after(hover_feeding_system),
+            ).in_set(crate::GameSet::AI).run_if(in_state(AppState::Playing)))
+            .add_systems(Update, check_behavior_tree_loading.in_set(crate::GameSet::AI));
     }
 }
 
@@ -1,4 +1,7 @@
 use bevy::prelude::*;
+use std::collections::HashMap;
+use crate::bird::BirdSpecies;
+use crate::bird_ai::components::BirdState;
 use crate::bird_ai::config::BehaviorTreeConfig;
 
 #[derive(Resource)]
@@ -34,4 +37,72 @@ impl Default for BehaviorTreeConfigResource {
             handle: None,
         }
     }
-}
\ No newline at end of file
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PresenceActivity {
+    Eating,
+    Drinking,
+    HoverFeeding,
+}
+
+impl PresenceActivity {
+    pub fn state(&self) -> BirdState {
+        match self {
+            Self::Eating => BirdState::Eating,
+            Self::Drinking => BirdState::Drinking,
+            Self::HoverFeeding => BirdState::HoverFeeding,
+        }
+    }
+
+    pub fn verb(&self) -> &'static str {
+        match self {
+            Self::Eating => "eating",
+            Self::Drinking => "drinking",
+            Self::HoverFeeding => "hover-feeding",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Presence {
+    pub object: Entity,
+    pub species: BirdSpecies,
+    pub activity: PresenceActivity,
+    pub arrived: f32,
+}
+
+// Birds currently feeding or drinking, keyed by bird, maintained by the feeding behaviors
+#[derive(Resource, Default)]
+pub struct FeederPresence {
+    pub visits: HashMap<Entity, Presence>,
+}
+
+impl FeederPresence {
+    pub fn is_at(&self, bird: Entity, object: Entity) -> bool {
+        self.visits.get(&bird).is_some_and(|visit| visit.object == object)
+    }
+
+    pub fn arrive(&mut self, bird: Entity, object: Entity, species: BirdSpecies, activity: PresenceActivity, now: f32) {
+        self.visits.insert(bird, Presence { object, species, activity, arrived: now });
+    }
+
+    pub fn leave(&mut self, bird: Entity) {
+        self.visits.remove(&bird);
+    }
+
+    // Visits grouped by the object being used, busiest first, earliest arrivals first within a group
+    pub fn by_object(&self) -> Vec<(Entity, Vec<(Entity, &Presence)>)> {
+        let mut groups: HashMap<Entity, Vec<(Entity, &Presence)>> = HashMap::new();
+        for (bird, visit) in &self.visits {
+            groups.entry(visit.object).or_default().push((*bird, visit));
+        }
+
+        let mut groups: Vec<_> = groups.into_iter().collect();
+        for (_, visits) in &mut groups {
+            visits.sort_by(|a, b| a.1.arrived.total_cmp(&b.1.arrived));
+        }
+        groups.sort_by(|a, b| b.1.len().cmp(&a.1.len()).then(a.0.cmp(&b.0)));
+        groups
+    }
+}
@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use crate::bird_ai::components::{FlightPath, FlightPhase};
 
 pub fn execute_wandering(transform: &mut Transform, time: &Time) {
     let speed = 30.0;
@@ -7,23 +8,90 @@ pub fn execute_wandering(transform: &mut Transform, time: &Time) {
     transform.translation += (direction * speed * time.delta().as_secs_f32()).extend(0.0);
 }
 
-pub fn execute_moving_to_target(
+const ARRIVAL_DISTANCE: f32 = 25.0;
+// Share of cruising speed just after takeoff and at touchdown
+const TAKEOFF_SPEED: f32 = 0.5;
+const TAKEOFF_TIME: f32 = 0.4;
+const TOUCHDOWN_SPEED: f32 = 0.3;
+// Nose-up tilt at the end of the landing flare
+const FLARE_PITCH: f32 = 0.45;
+// How hard a turn has to be for full bank, and how quickly the lean follows it
+const FULL_BANK_TURN_RATE: f32 = 3.0;
+const BANK_EASE: f32 = 8.0;
+
+/// Flies one step along the path's arc toward the target and says what the wings are doing
+pub fn execute_flight(
     transform: &mut Transform,
-    target_transform: &Transform,
+    path: &mut FlightPath,
+    target_pos: Vec2,
     time: &Time,
-) -> bool {
-    let speed = 80.0;
+) -> FlightPhase {
+    let delta = time.delta_secs();
+    let style = path.style;
     let current_pos = transform.translation.truncate();
-    let target_pos = target_transform.translation.truncate();
-    let distance = current_pos.distance(target_pos);
-    
-    if distance < 25.0 {
-        return true; // Reached target
+    let remaining = current_pos.distance(target_pos) - ARRIVAL_DISTANCE;
+    let chord = target_pos - path.start;
+    let length = chord.length();
+
+    if remaining < 0.0 || length < f32::EPSILON {
+        transform.rotation = Quat::IDENTITY;
+        return FlightPhase::Landed;
+    }
+
+    // Bow the path upward: birds climb off one perch and drop onto the next
+    let mut normal = chord.perp() / length;
+    if normal.y < 0.0 {
+        normal = -normal;
+    }
+    let control = path.start + chord * 0.5 + normal * length * style.arc;
+    let path_length = length * (1.0 + 2.0 / 3.0 * style.arc * style.arc);
+
+    // Flap and glide in turns, easing up to speed after takeoff and bleeding it off in the flare
+    path.elapsed += delta;
+    let cycle = (style.flap_time + style.glide_time).max(0.01);
+    let in_cycle = path.elapsed % cycle;
+    let flapping = in_cycle < style.flap_time;
+    let flare = if style.flare_distance > 0.0 { 1.0 - (remaining / style.flare_distance).min(1.0) } else { 0.0 };
+
+    let mut speed = path.cruise_speed * if flapping { 1.0 } else { style.glide_speed };
+    speed *= TAKEOFF_SPEED + (1.0 - TAKEOFF_SPEED) * (path.elapsed / TAKEOFF_TIME).min(1.0);
+    speed *= 1.0 - (1.0 - TOUCHDOWN_SPEED) * flare;
+    path.progress = (path.progress + speed * delta / path_length).min(1.0);
+
+    let t = path.progress;
+    let mut position = path.start * (1.0 - t) * (1.0 - t) + control * 2.0 * (1.0 - t) * t + target_pos * t * t;
+
+    // Each flap burst climbs and each glide sinks, settling out toward both ends of the trip
+    if style.glide_time > 0.0 {
+        let rise = if flapping {
+            in_cycle / style.flap_time.max(0.01)
+        } else {
+            1.0 - (in_cycle - style.flap_time) / style.glide_time
+        };
+        position.y += style.undulation * (rise - 0.5) * (std::f32::consts::PI * t).sin();
+    }
+
+    // Lean into the turn the arc is making, then pitch up as the flare brakes
+    let step = position - current_pos;
+    if step.length_squared() > f32::EPSILON {
+        let heading = step.y.atan2(step.x);
+        let turn = ((heading - path.heading + std::f32::consts::PI).rem_euclid(std::f32::consts::TAU) - std::f32::consts::PI) / delta.max(0.001);
+        path.heading = heading;
+        let target_bank = (turn / FULL_BANK_TURN_RATE).clamp(-1.0, 1.0) * style.bank;
+        path.bank += (target_bank - path.bank) * (1.0 - (-BANK_EASE * delta).exp());
+    }
+    let facing = if path.heading.cos() >= 0.0 { 1.0 } else { -1.0 };
+    transform.rotation = Quat::from_rotation_z(path.bank + facing * FLARE_PITCH * flare);
+    transform.translation.x = position.x;
+    transform.translation.y = position.y;
+
+    if flare > 0.0 {
+        FlightPhase::Flaring
+    } else if flapping {
+        FlightPhase::Flapping
+    } else {
+        FlightPhase::Gliding
     }
-    
-    let direction = (target_pos - current_pos).normalize_or_zero();
-    transform.translation += (direction * speed * time.delta().as_secs_f32()).extend(0.0);
-    false
 }
 
 pub fn execute_fleeing(transform: &mut Transform, threat_direction: Vec2, time: &Time) {
@@ -2,8 +2,18 @@ use bevy::prelude::*;
 use crate::bird_ai::{components::*, resources::*, bt::*, states::*};
 use rand::Rng;
 use crate::bird::Bird;
-use crate::feeder::Feeder;
+use crate::feeder::{Feeder, FeederType};
+use crate::elevation::Elevation;
+use crate::feeder_physics::SeedSpill;
 use crate::environment::resources::{TimeState, WeatherState};
+use crate::nocturnal_behaviors::{NocturnalBehavior, RoostSite};
+use crate::water_quality::WaterQuality;
+use crate::weather_effects::components::Wetness;
+use crate::cavity_nesters::is_woodpecker;
+use crate::hummingbirds::NectarReservoir;
+use crate::lightning::LightningSnag;
+use crate::animation::components::AnimationController;
+use crate::bird_data::BirdDataRegistry;
 
 pub fn setup_test_world(mut commands: Commands) {
     // Water source for drinking (supplement to nectar feeders)
@@ -56,22 +66,37 @@ pub fn setup_test_world(mut commands: Commands) {
 }
 
 pub fn world_utility_query_system(
-    mut bird_query: Query<(&Transform, &mut Blackboard, &Bird), With<BirdAI>>,
-    object_query: Query<(Entity, &Transform, &ProvidesUtility), With<SmartObject>>,
-    feeder_query: Query<(Entity, &Transform, &ProvidesUtility, &Feeder), With<SmartObject>>,
+    mut bird_query: Query<(Entity, &Transform, &mut Blackboard, &Bird), With<BirdAI>>,
+    wetness_query: Query<&Wetness>,
+    lightning_snag_query: Query<(), With<LightningSnag>>,
+    object_query: Query<(Entity, &Transform, &ProvidesUtility, Option<&Elevation>, Option<&SeedSpill>), With<SmartObject>>,
+    feeder_query: Query<(Entity, &Transform, &ProvidesUtility, &Feeder, Option<&Elevation>), With<SmartObject>>,
+    water_query: Query<(&Transform, &WaterQuality)>,
     mut timer: ResMut<UtilityTimer>,
     time_state: Res<TimeState>,
     weather_state: Res<WeatherState>,
+    variability: Res<crate::environment::variability::YearlyVariability>,
     time: Res<Time>,
 ) {
     timer.0.tick(time.delta());
     if !timer.0.finished() { return; }
     
-    for (bird_transform, mut blackboard, bird) in bird_query.iter_mut() {
+    // Wild seed crops and dry summers change how much the yard is worth to birds this year
+    let year = variability.current();
+    let feeder_draw = year.feeder_draw(time_state.get_season());
+    let water_draw = year.water_draw(time_state.get_season());
+    
+    for (bird_entity, bird_transform, mut blackboard, bird) in bird_query.iter_mut() {
         blackboard.world_knowledge.available_actions.clear();
+        let wetness = wetness_query.get(bird_entity).map_or(0.0, |wetness| wetness.level);
         
         // Process feeders with species preferences
-        for (entity, obj_transform, utility, feeder) in feeder_query.iter() {
+        for (entity, obj_transform, utility, feeder, elevation) in feeder_query.iter() {
+            // Doves can't work a tube feeder, crows can't hang from a swinging one
+            if elevation.is_some_and(|elevation| !elevation.tier.accessible_to(bird.species)) {
+                continue;
+            }
+            
             let distance = bird_transform.translation.distance(obj_transform.translation);
             if distance <= utility.range {
                 let distance_factor = 1.0 - (distance / utility.range);
@@ -90,7 +115,7 @@ pub fn world_utility_query_system(
                 
                 let final_score = utility.base_utility * distance_factor * species_modifier * 
                                 weather_modifier * time_modifier * daylight_modifier * song_activity_modifier *
-                                seasonal_modifier * time_based_modifier * technique_modifier;
+                                seasonal_modifier * time_based_modifier * technique_modifier * feeder_draw;
                 
                 let entry = UtilityEntry { entity, score: final_score };
                 
@@ -105,13 +130,21 @@ pub fn world_utility_query_system(
         }
         
         // Process non-feeder smart objects (water sources, baths)
-        for (entity, obj_transform, utility) in object_query.iter() {
+        for (entity, obj_transform, utility, elevation, spill) in object_query.iter() {
             // Skip entities that are already processed as feeders
             if feeder_query.contains(entity) { continue; }
+            if elevation.is_some_and(|elevation| !elevation.tier.accessible_to(bird.species)) {
+                continue;
+            }
             
+            // A soaked bird will fly up to twice as far to get under cover
+            let range = if utility.action == BirdAction::Shelter { utility.range * (1.0 + wetness) } else { utility.range };
+            // Woodpeckers seek out lightning-struck deadwood from twice as far
+            let deadwood = lightning_snag_query.contains(entity);
+            let range = if deadwood && is_woodpecker(bird.species) { range * 2.0 } else { range };
             let distance = bird_transform.translation.distance(obj_transform.translation);
-            if distance <= utility.range {
-                let distance_factor = 1.0 - (distance / utility.range);
+            if distance <= range {
+                let distance_factor = 1.0 - (distance / range);
                 let song_activity_modifier = time_state.song_period_activity(); // Dawn chorus boost for all activities
                 
                 // Apply weather modifiers
@@ -129,7 +162,34 @@ pub fn world_utility_query_system(
                     };
                 }
                 
-                let final_score = utility.base_utility * distance_factor * song_activity_modifier * weather_modifier;
+                // Spilled seed is picked over by the birds that like feeding on the ground
+                let spill_modifier = if spill.is_some() {
+                    bird.species.feeding_technique_preference(FeederType::Ground)
+                } else {
+                    1.0
+                };
+                
+                // Beetles in lightning-struck deadwood are a woodpecker's find; other birds barely bother
+                let deadwood_modifier = match deadwood {
+                    true if is_woodpecker(bird.species) => 2.5,
+                    true => 0.3,
+                    false => 1.0,
+                };
+                
+                let year_modifier = match utility.action {
+                    BirdAction::Drink | BirdAction::Bathe => water_draw,
+                    _ => 1.0,
+                };
+                
+                // Fouled or algae-choked water puts birds off; a bath's second action sits at the same spot
+                let water_modifier = match utility.action {
+                    BirdAction::Drink | BirdAction::Bathe => water_query.iter()
+                        .find(|(water_transform, _)| water_transform.translation.truncate().distance(obj_transform.translation.truncate()) < 1.0)
+                        .map_or(1.0, |(_, water)| water.attraction(utility.action)),
+                    _ => 1.0,
+                };
+                
+                let final_score = utility.base_utility * distance_factor * song_activity_modifier * weather_modifier * spill_modifier * deadwood_modifier * year_modifier * water_modifier;
                 
                 let entry = UtilityEntry { entity, score: final_score };
                 
@@ -260,7 +320,7 @@ pub fn social_awareness_system(
 }
 
 pub fn behavior_tree_system(
-    mut bird_query: Query<(&mut BirdState, &mut Blackboard), With<BirdAI>>,
+    mut bird_query: Query<(&mut BirdState, &mut Blackboard, Option<&Wetness>), With<BirdAI>>,
     mut timer: ResMut<BehaviorTreeTimer>,
     time: Res<Time>,
     time_state: Res<TimeState>,
@@ -270,7 +330,7 @@ pub fn behavior_tree_system(
     timer.0.tick(time.delta());
     if !timer.0.finished() { return; }
     
-    for (mut state, mut blackboard) in bird_query.iter_mut() {
+    for (mut state, mut blackboard, wetness) in bird_query.iter_mut() {
         let new_state = if config_resource.use_configurable {
             if let Some(config) = &config_resource.config {
                 evaluate_behavior_tree_configurable(&blackboard, &time_state, &weather_state, config)
@@ -287,7 +347,8 @@ pub fn behavior_tree_system(
             let actions = &blackboard.world_knowledge.available_actions;
             
             let weather = weather_state.current_weather;
-            let shelter_urgency = weather.shelter_urgency();
+            // Already soaked birds want cover more than the weather alone would suggest
+            let shelter_urgency = weather.shelter_urgency() + wetness.map_or(0.0, |wetness| wetness.level * 0.3);
             
             blackboard.current_target = if shelter_urgency > 0.6 && actions.contains_key(&BirdAction::Shelter) {
                 // Critical weather - seek shelter immediately
@@ -354,64 +415,100 @@ pub fn wandering_system(
     }
 }
 
+// Cruising speed as a share of the species' flight_speed trait
+const FLIGHT_SPEED_SCALE: f32 = 0.55;
+const MIN_FLIGHT_SPEED: f32 = 50.0;
+
 pub fn moving_to_target_system(
-    mut bird_query: Query<(&mut Transform, &mut Blackboard, &mut BirdState), With<BirdAI>>,
+    mut commands: Commands,
+    mut bird_query: Query<(Entity, &Bird, &mut Transform, &Blackboard, &mut BirdState, Option<&mut FlightPath>, Option<&mut AnimationController>), With<BirdAI>>,
     target_query: Query<&Transform, Without<BirdAI>>,
+    bird_data: Res<BirdDataRegistry>,
     time: Res<Time>,
 ) {
-    for (mut bird_transform, mut blackboard, mut state) in bird_query.iter_mut() {
-        if *state == BirdState::MovingToTarget {
-            if let Some(target_entity) = blackboard.current_target {
-                if let Ok(target_transform) = target_query.get(target_entity) {
-                    let reached = execute_moving_to_target(&mut bird_transform, target_transform, &time);
-                    
-                    if reached {
-                        // Determine appropriate action based on the target's utility
-                        if let Some(target_entity) = blackboard.current_target {
-                            let actions = &blackboard.world_knowledge.available_actions;
-                            
-                            // Find what action this target provides
-                            let target_action = actions.iter()
-                                .find(|(_, entry)| entry.entity == target_entity)
-                                .map(|(action, _)| action);
-                                
-                            if let Some(action) = target_action {
-                                *state = match action {
-                                    BirdAction::Eat => BirdState::Eating,
-                                    BirdAction::Drink => BirdState::Drinking,
-                                    BirdAction::Bathe => BirdState::Bathing,
-                                    BirdAction::Perch => BirdState::Resting,
-                                    BirdAction::Play => BirdState::Playing,
-                                    BirdAction::Explore => BirdState::Exploring,
-                                    BirdAction::Nest => BirdState::Nesting,
-                                    BirdAction::Roost => BirdState::Roosting,
-                                    BirdAction::Shelter => BirdState::Sheltering,
-                                    BirdAction::Court => BirdState::Courting,
-                                    BirdAction::Follow => BirdState::Following,
-                                    BirdAction::Challenge => BirdState::Territorial,
-                                    BirdAction::Flock => BirdState::Flocking,
-                                    BirdAction::Forage => BirdState::Foraging,
-                                    BirdAction::Cache => BirdState::Caching,
-                                    BirdAction::Retrieve => BirdState::Retrieving,
-                                    BirdAction::HoverFeed => BirdState::HoverFeeding,
-                                };
-                            }
-                        }
-                    }
+    for (entity, bird, mut bird_transform, blackboard, mut state, flight, controller) in bird_query.iter_mut() {
+        // Birds that landed or were pulled into something else mid-trip level out and forget the path
+        if *state != BirdState::MovingToTarget {
+            if flight.is_some() {
+                commands.entity(entity).remove::<FlightPath>();
+                bird_transform.rotation = Quat::IDENTITY;
+            }
+            continue;
+        }
+        let Some(target_entity) = blackboard.current_target else { continue };
+        let Ok(target_transform) = target_query.get(target_entity) else { continue };
+        let target_pos = target_transform.translation.truncate();
+
+        // A new target means a new takeoff; the path is flown from the next frame
+        let Some(mut path) = flight.filter(|path| path.target == target_entity) else {
+            let cruise_speed = (bird_data.get_behavioral_trait(&bird.species, "flight_speed") * FLIGHT_SPEED_SCALE).max(MIN_FLIGHT_SPEED);
+            let style = bird_data.get_flight_style(&bird.species);
+            commands.entity(entity).insert(FlightPath::new(target_entity, bird_transform.translation.truncate(), target_pos, style, cruise_speed));
+            continue;
+        };
+
+        let phase = execute_flight(&mut bird_transform, &mut path, target_pos, &time);
+
+        // Wings hold still in a glide and beat double time to brake in the flare
+        if let Some(mut controller) = controller {
+            match phase {
+                FlightPhase::Gliding => controller.timer.pause(),
+                FlightPhase::Flaring => {
+                    controller.timer.unpause();
+                    controller.timer.tick(time.delta());
                 }
+                FlightPhase::Flapping | FlightPhase::Landed => controller.timer.unpause(),
             }
         }
+        if phase != FlightPhase::Landed {
+            continue;
+        }
+
+        // Determine appropriate action based on the target's utility
+        let actions = &blackboard.world_knowledge.available_actions;
+        let target_action = actions.iter()
+            .find(|(_, entry)| entry.entity == target_entity)
+            .map(|(action, _)| action);
+
+        if let Some(action) = target_action {
+            *state = match action {
+                BirdAction::Eat => BirdState::Eating,
+                BirdAction::Drink => BirdState::Drinking,
+                BirdAction::Bathe => BirdState::Bathing,
+                BirdAction::Perch => BirdState::Resting,
+                BirdAction::Play => BirdState::Playing,
+                BirdAction::Explore => BirdState::Exploring,
+                BirdAction::Nest => BirdState::Nesting,
+                BirdAction::Roost => BirdState::Roosting,
+                BirdAction::Shelter => BirdState::Sheltering,
+                BirdAction::Court => BirdState::Courting,
+                BirdAction::Follow => BirdState::Following,
+                BirdAction::Challenge => BirdState::Territorial,
+                BirdAction::Flock => BirdState::Flocking,
+                BirdAction::Forage => BirdState::Foraging,
+                BirdAction::Cache => BirdState::Caching,
+                BirdAction::Retrieve => BirdState::Retrieving,
+                BirdAction::HoverFeed => BirdState::HoverFeeding,
+            };
+        }
     }
 }
 
 pub fn eating_system(
     mut commands: Commands,
-    mut bird_query: Query<(&mut Blackboard, &mut BirdState), With<BirdAI>>,
+    mut bird_query: Query<(Entity, &Bird, &mut Blackboard, &mut BirdState), With<BirdAI>>,
     feeder_query: Query<&Feeder>,
+    mut presence: ResMut<FeederPresence>,
     time: Res<Time>,
 ) {
-    for (mut blackboard, mut state) in bird_query.iter_mut() {
+    for (entity, bird, mut blackboard, mut state) in bird_query.iter_mut() {
         if *state == BirdState::Eating {
+            if let Some(target_entity) = blackboard.current_target {
+                if !presence.is_at(entity, target_entity) {
+                    presence.arrive(entity, target_entity, bird.species, PresenceActivity::Eating, time.elapsed_secs());
+                }
+            }
+            
             let consumption_rate = 0.5 * time.delta().as_secs_f32();
             blackboard.internal.hunger -= consumption_rate;
             blackboard.internal.hunger = blackboard.internal.hunger.max(0.0);
@@ -429,6 +526,7 @@ pub fn eating_system(
             if blackboard.internal.hunger < 0.1 {
                 *state = BirdState::Wandering;
                 blackboard.current_target = None;
+                presence.leave(entity);
                 // info!("Bird finished eating and is now wandering");
             }
         }
@@ -437,12 +535,19 @@ pub fn eating_system(
 
 pub fn drinking_system(
     mut commands: Commands,
-    mut bird_query: Query<(&mut Blackboard, &mut BirdState), With<BirdAI>>,
+    mut bird_query: Query<(Entity, &Bird, &mut Blackboard, &mut BirdState), With<BirdAI>>,
     feeder_query: Query<&Feeder>,
+    mut presence: ResMut<FeederPresence>,
     time: Res<Time>,
 ) {
-    for (mut blackboard, mut state) in bird_query.iter_mut() {
+    for (entity, bird, mut blackboard, mut state) in bird_query.iter_mut() {
         if *state == BirdState::Drinking {
+            if let Some(target_entity) = blackboard.current_target {
+                if !presence.is_at(entity, target_entity) {
+                    presence.arrive(entity, target_entity, bird.species, PresenceActivity::Drinking, time.elapsed_secs());
+                }
+            }
+            
             let consumption_rate = 0.6 * time.delta().as_secs_f32();
             blackboard.internal.thirst -= consumption_rate;
             blackboard.internal.thirst = blackboard.internal.thirst.max(0.0);
@@ -460,6 +565,7 @@ pub fn drinking_system(
             if blackboard.internal.thirst < 0.1 {
                 *state = BirdState::Wandering;
                 blackboard.current_target = None;
+                presence.leave(entity);
                 // info!("Bird finished drinking and is now wandering");
             }
         }
@@ -599,23 +705,34 @@ pub fn nesting_system(
 }
 
 pub fn roosting_system(
-    mut bird_query: Query<(&mut Transform, &mut Blackboard, &mut BirdState), With<BirdAI>>,
+    mut bird_query: Query<(&mut Transform, &mut Blackboard, &mut BirdState, Option<&NocturnalBehavior>), With<BirdAI>>,
+    roost_query: Query<&RoostSite>,
+    weather_state: Res<WeatherState>,
     time: Res<Time>,
 ) {
-    for (mut transform, mut blackboard, mut state) in bird_query.iter_mut() {
+    for (mut transform, mut blackboard, mut state, nocturnal) in bird_query.iter_mut() {
         if *state == BirdState::Roosting {
-            execute_roosting(&mut transform, &time);
+            let roost = nocturnal
+                .and_then(|nocturnal| nocturnal.current_roost)
+                .and_then(|roost| roost_query.get(roost).ok());
             
-            // Roosting is very restorative - birds gather in safe spots for the night
-            blackboard.internal.energy += 0.4 * time.delta().as_secs_f32();
-            blackboard.internal.energy = blackboard.internal.energy.min(1.0);
+            // Birds with a chosen roost are held in place by the huddle instead
+            if roost.is_none() {
+                execute_roosting(&mut transform, &time);
+            }
+            
+            // Roosting is restorative, but a cold night in the open burns through reserves
+            let sheltered_temperature = weather_state.temperature + roost.map_or(0.0, RoostSite::warmth);
+            let cold_stress = ((5.0 - sheltered_temperature) / 20.0).clamp(0.0, 1.0);
+            blackboard.internal.energy += (0.4 - 0.5 * cold_stress) * time.delta().as_secs_f32();
+            blackboard.internal.energy = blackboard.internal.energy.clamp(0.0, 1.0);
             
             // Roosting significantly reduces fear (safety in numbers)
             blackboard.internal.fear -= 0.4 * time.delta().as_secs_f32();
             blackboard.internal.fear = blackboard.internal.fear.max(0.0);
             
-            // Birds typically roost for extended periods during evening/night
-            if blackboard.internal.energy > 0.9 && blackboard.internal.fear < 0.1 {
+            // Birds without a roost site leave once rested; the rest wait for their dawn departure wave
+            if roost.is_none() && blackboard.internal.energy > 0.9 && blackboard.internal.fear < 0.1 {
                 *state = BirdState::Wandering;
                 blackboard.current_target = None;
                 // info!("Bird finished roosting and is now wandering");
@@ -846,6 +963,7 @@ pub fn need_decay_system(
 
 pub fn foraging_system(
     mut bird_query: Query<(&mut Transform, &mut Blackboard, &mut BirdState, &ForagingTraits, &mut ForagingState), With<BirdAI>>,
+    terrain: Res<crate::terrain_painting::TerrainMap>,
     time: Res<Time>,
 ) {
     for (mut transform, mut blackboard, mut state, foraging_traits, mut foraging_state) in bird_query.iter_mut() {
@@ -853,8 +971,11 @@ pub fn foraging_system(
             let mut rng = rand::rng();
             execute_foraging(&mut transform, foraging_traits, &mut foraging_state, &time, &mut rng);
             
+            // Mulch and flowerbeds turn up more food than gravel
+            let suitability = terrain.foraging_suitability(transform.translation.truncate());
+            
             // Foraging gradually reduces hunger but uses energy
-            blackboard.internal.hunger -= 0.3 * time.delta().as_secs_f32();
+            blackboard.internal.hunger -= 0.3 * suitability * time.delta().as_secs_f32();
             blackboard.internal.hunger = blackboard.internal.hunger.max(0.0);
             
             blackboard.internal.energy -= 0.15 * time.delta().as_secs_f32();
@@ -864,7 +985,7 @@ pub fn foraging_system(
             foraging_state.energy_spent += 0.15 * time.delta().as_secs_f32();
             
             // Occasionally find food items
-            if rng.random_range(0.0..1.0) < 0.1 * time.delta().as_secs_f32() {
+            if rng.random_range(0.0..1.0) < 0.1 * suitability * time.delta().as_secs_f32() {
                 foraging_state.items_found += 1;
                 // Small hunger reduction for finding food
                 blackboard.internal.hunger -= 0.1;
@@ -884,10 +1005,11 @@ pub fn foraging_system(
 }
 
 pub fn caching_system(
-    mut bird_query: Query<(&mut Transform, &mut Blackboard, &mut BirdState, &ForagingTraits, &mut CacheData), With<BirdAI>>,
+    mut bird_query: Query<(Entity, &Bird, &mut Transform, &mut Blackboard, &mut BirdState, &ForagingTraits, &mut CacheData), With<BirdAI>>,
+    mut cache_events: EventWriter<crate::corvids::CacheEvent>,
     time: Res<Time>,
 ) {
-    for (mut transform, mut blackboard, mut state, foraging_traits, mut cache_data) in bird_query.iter_mut() {
+    for (entity, bird, mut transform, mut blackboard, mut state, foraging_traits, mut cache_data) in bird_query.iter_mut() {
         if *state == BirdState::Caching {
             execute_caching(&mut transform, &time);
             
@@ -905,14 +1027,22 @@ pub fn caching_system(
                     rng.random_range(-50.0..50.0)
                 );
                 
+                let food_amount = rng.random_range(0.5..1.0);
                 cache_data.cached_locations.push(CacheSpot {
                     location: cache_location,
-                    food_amount: rng.random_range(0.5..1.0),
+                    food_amount,
                     cache_time: time.elapsed().as_secs_f64(),
                     decay_rate: 0.01, // Food spoils slowly
                 });
                 
                 cache_data.current_cache_count += 1;
+                cache_events.write(crate::corvids::CacheEvent {
+                    bird: entity,
+                    species: bird.species,
+                    location: cache_location,
+                    food_amount,
+                    kind: crate::corvids::CacheEventKind::Stored,
+                });
                 
                 // Slight hunger increase from giving up immediate food
                 blackboard.internal.hunger += 0.05;
@@ -927,10 +1057,11 @@ pub fn caching_system(
 }
 
 pub fn retrieving_system(
-    mut bird_query: Query<(&mut Transform, &mut Blackboard, &mut BirdState, &ForagingTraits, &mut CacheData), With<BirdAI>>,
+    mut bird_query: Query<(Entity, &Bird, &mut Transform, &mut Blackboard, &mut BirdState, &ForagingTraits, &mut CacheData), With<BirdAI>>,
+    mut cache_events: EventWriter<crate::corvids::CacheEvent>,
     time: Res<Time>,
 ) {
-    for (mut transform, mut blackboard, mut state, foraging_traits, mut cache_data) in bird_query.iter_mut() {
+    for (entity, bird, mut transform, mut blackboard, mut state, foraging_traits, mut cache_data) in bird_query.iter_mut() {
         if *state == BirdState::Retrieving {
             if let Some(target_entity) = blackboard.current_target {
                 execute_retrieving(&mut transform, &time);
@@ -953,6 +1084,13 @@ pub fn retrieving_system(
                     if let Some(index) = cache_index {
                         let cache = cache_data.cached_locations.remove(index);
                         cache_data.current_cache_count -= 1;
+                        cache_events.write(crate::corvids::CacheEvent {
+                            bird: entity,
+                            species: bird.species,
+                            location: cache.location,
+                            food_amount: cache.food_amount,
+                            kind: crate::corvids::CacheEventKind::Retrieved,
+                        });
                         
                         // Reduce hunger based on cached food amount and decay
                         let food_value = cache.food_amount * (1.0 - cache.decay_rate * (time.elapsed().as_secs_f64() - cache.cache_time) as f32 / 3600.0);
@@ -973,12 +1111,26 @@ pub fn retrieving_system(
 }
 
 pub fn hover_feeding_system(
-    mut bird_query: Query<(&mut Transform, &mut Blackboard, &mut BirdState, &ForagingTraits), With<BirdAI>>,
+    mut bird_query: Query<(Entity, &Bird, &mut Transform, &mut Blackboard, &mut BirdState, &ForagingTraits), With<BirdAI>>,
+    reservoir_query: Query<&NectarReservoir>,
+    mut presence: ResMut<FeederPresence>,
     time: Res<Time>,
 ) {
-    for (mut transform, mut blackboard, mut state, foraging_traits) in bird_query.iter_mut() {
+    for (entity, bird, mut transform, mut blackboard, mut state, foraging_traits) in bird_query.iter_mut() {
         if *state == BirdState::HoverFeeding {
             if let Some(target_entity) = blackboard.current_target {
+                // Spoiled nectar is refused outright
+                let reservoir = reservoir_query.get(target_entity).ok();
+                if reservoir.is_some_and(|reservoir| reservoir.is_spoiled()) {
+                    *state = BirdState::Wandering;
+                    blackboard.current_target = None;
+                    presence.leave(entity);
+                    continue;
+                }
+
+                if !presence.is_at(entity, target_entity) {
+                    presence.arrive(entity, target_entity, bird.species, PresenceActivity::HoverFeeding, time.elapsed_secs());
+                }
                 execute_hover_feeding(&mut transform, &time);
                 
                 // Hover feeding energy cost modified by hover ability (higher ability = more efficient)
@@ -986,8 +1138,9 @@ pub fn hover_feeding_system(
                 blackboard.internal.energy -= energy_cost * time.delta().as_secs_f32();
                 blackboard.internal.energy = blackboard.internal.energy.max(0.0);
                 
-                // Feeding efficiency based on hover ability
-                let feeding_efficiency = 0.6 * foraging_traits.hover_ability;
+                // Feeding efficiency based on hover ability, quicker on fresh nectar at the right strength
+                let nectar_bonus = reservoir.map_or(0.0, |reservoir| 0.2 * reservoir.sweetness() * reservoir.freshness);
+                let feeding_efficiency = 0.6 * foraging_traits.hover_ability + nectar_bonus;
                 blackboard.internal.hunger -= feeding_efficiency * time.delta().as_secs_f32();
                 blackboard.internal.hunger = blackboard.internal.hunger.max(0.0);
                 
@@ -995,6 +1148,7 @@ pub fn hover_feeding_system(
                 if blackboard.internal.hunger < 0.1 || blackboard.internal.energy < 0.2 {
                     *state = BirdState::Wandering;
                     blackboard.current_target = None;
+                    presence.leave(entity);
                     info!("Bird finished hover feeding");
                 }
             } else {
@@ -1177,4 +1331,19 @@ fn calculate_mate_utility(
 fn is_breeding_season(time_state: &TimeState) -> bool {
     // Spring and early summer are breeding seasons
     matches!(time_state.hour, 6.0..=18.0) && time_state.day_of_year > 80 && time_state.day_of_year < 200
-}
\ No newline at end of file
+}
+
+// Drops visits for birds that were interrupted mid-meal or have left the scene
+pub fn presence_cleanup_system(
+    bird_query: Query<&BirdState, With<BirdAI>>,
+    mut presence: ResMut<FeederPresence>,
+) {
+    let departed: Vec<Entity> = presence.visits.iter()
+        .filter(|(bird, visit)| !matches!(bird_query.get(**bird), Ok(state) if *state == visit.activity.state()))
+        .map(|(bird, _)| *bird)
+        .collect();
+
+    for bird in departed {
+        presence.leave(bird);
+    }
+}
@@ -0,0 +1,342 @@
+// Bird Cam - Idle attract mode that hides the UI and drifts between busy feeders like a live webcam
+use bevy::prelude::*;
+use bevy::input::mouse::MouseWheel;
+use bevy::render::view::VisibilitySystems;
+use crate::bird_ai::resources::FeederPresence;
+use crate::feeder::Feeder;
+use crate::environment::resources::TimeState;
+use crate::environment::region::SanctuaryRegion;
+use crate::menu::resources::{GameSettings, MenuNavigationEvent, MenuType};
+use crate::photo_mode::resources::PhotoModeSettings;
+use crate::save_load::resources::{LoadGameEvent, SaveManager};
+
+// Choices offered in settings, in minutes; 0 never starts the bird cam on its own
+pub const IDLE_DELAY_OPTIONS: [f32; 5] = [0.0, 2.0, 5.0, 10.0, 20.0];
+const DWELL_SECONDS: f32 = 20.0;
+const PAN_RATE: f32 = 0.6;
+// Feeders shown recently are skipped so the cam doesn't bounce between the two busiest
+const RECENT_MEMORY: usize = 3;
+
+pub struct BirdCamPlugin;
+
+impl Plugin for BirdCamPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<BirdCam>()
+            .add_systems(OnEnter(crate::AppState::MainMenu), launch_into_bird_cam_system)
+            .add_systems(OnEnter(crate::AppState::Playing), setup_bird_cam_overlay)
+            .add_systems(OnExit(crate::AppState::Playing), cleanup_bird_cam_overlay)
+            .add_systems(Update, (
+                bird_cam_idle_system,
+                bird_cam_pan_system,
+                bird_cam_caption_system,
+            ).chain().in_set(crate::GameSet::Presentation).run_if(in_state(crate::AppState::Playing)))
+            // Panels set their own visibility during Update, so hiding happens just before it propagates
+            .add_systems(PostUpdate, bird_cam_ui_visibility_system.before(VisibilitySystems::VisibilityPropagate));
+    }
+}
+
+pub fn idle_delay_label(minutes: f32) -> String {
+    if minutes <= 0.0 {
+        "Off".to_string()
+    } else {
+        format!("After {:.0} min", minutes)
+    }
+}
+
+pub fn next_idle_delay(minutes: f32) -> f32 {
+    let index = IDLE_DELAY_OPTIONS.iter().position(|option| *option == minutes).unwrap_or(0);
+    IDLE_DELAY_OPTIONS[(index + 1) % IDLE_DELAY_OPTIONS.len()]
+}
+
+// Resources
+#[derive(Resource)]
+pub struct BirdCam {
+    pub active: bool,
+    // Set when the game was launched straight into the bird cam
+    pub launch_pending: bool,
+    idle_seconds: f32,
+    target: Option<Entity>,
+    recent: Vec<Entity>,
+    dwell_timer: Timer,
+}
+
+impl Default for BirdCam {
+    fn default() -> Self {
+        Self {
+            active: false,
+            launch_pending: false,
+            idle_seconds: 0.0,
+            target: None,
+            recent: Vec::new(),
+            dwell_timer: Timer::from_seconds(DWELL_SECONDS, TimerMode::Once),
+        }
+    }
+}
+
+impl BirdCam {
+    fn start(&mut self) {
+        self.active = true;
+        self.target = None;
+        self.recent.clear();
+        info!("Bird cam started");
+    }
+
+    fn stop(&mut self) {
+        self.active = false;
+        self.target = None;
+        self.idle_seconds = 0.0;
+        info!("Bird cam stopped");
+    }
+}
+
+// Components
+#[derive(Component)]
+pub struct BirdCamOverlay;
+
+#[derive(Component)]
+pub struct BirdCamCaption;
+
+// Remembers how a UI root was shown before the bird cam hid it
+#[derive(Component)]
+pub struct BirdCamHidden(Visibility);
+
+fn launch_into_bird_cam_system(
+    mut launched: Local<bool>,
+    settings: Res<GameSettings>,
+    mut bird_cam: ResMut<BirdCam>,
+    mut save_manager: ResMut<SaveManager>,
+    mut load_events: EventWriter<LoadGameEvent>,
+    mut menu_nav_events: EventWriter<MenuNavigationEvent>,
+    mut difficulty: ResMut<crate::difficulty::DifficultySettings>,
+) {
+    // Only the first trip to the main menu after starting the game counts
+    if *launched {
+        return;
+    }
+    *launched = true;
+
+    if !settings.launch_into_bird_cam {
+        return;
+    }
+
+    // Open the most recently played sanctuary, or a fresh one if there are no saves yet
+    match save_manager.list_save_files().into_iter().max_by_key(|save| save.last_modified) {
+        Some(save) => {
+            save_manager.current_save_slot = Some(save.slot);
+            load_events.write(LoadGameEvent { slot: save.slot });
+        }
+        None => {
+            save_manager.current_save_slot = Some(0);
+            *difficulty = settings.difficulty.clone();
+        }
+    }
+
+    bird_cam.launch_pending = true;
+    menu_nav_events.write(MenuNavigationEvent {
+        target_menu: MenuType::InGame,
+        target_app_state: Some(crate::AppState::Playing),
+    });
+}
+
+fn setup_bird_cam_overlay(mut commands: Commands) {
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(24.0),
+            top: Val::Px(20.0),
+            flex_direction: FlexDirection::Column,
+            row_gap: Val::Px(4.0),
+            padding: UiRect::axes(Val::Px(12.0), Val::Px(8.0)),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.45)),
+        BorderRadius::all(Val::Px(6.0)),
+        Visibility::Hidden,
+        BirdCamOverlay,
+    )).with_children(|overlay| {
+        overlay.spawn((
+            Text::new("● LIVE  Bird Cam"),
+            TextFont { font_size: 18.0, ..default() },
+            TextColor(Color::srgb(0.95, 0.3, 0.25)),
+        ));
+        overlay.spawn((
+            Text::new(""),
+            TextFont { font_size: 13.0, ..default() },
+            TextColor(Color::srgb(0.92, 0.92, 0.9)),
+            BirdCamCaption,
+        ));
+    });
+}
+
+fn cleanup_bird_cam_overlay(
+    mut commands: Commands,
+    overlay_query: Query<Entity, With<BirdCamOverlay>>,
+    mut bird_cam: ResMut<BirdCam>,
+) {
+    for entity in overlay_query.iter() {
+        commands.entity(entity).despawn();
+    }
+    if bird_cam.active {
+        bird_cam.stop();
+    }
+}
+
+fn bird_cam_idle_system(
+    mut bird_cam: ResMut<BirdCam>,
+    settings: Res<GameSettings>,
+    photo_settings: Res<PhotoModeSettings>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    mut cursor_events: EventReader<CursorMoved>,
+    mut wheel_events: EventReader<MouseWheel>,
+    time: Res<Time<Real>>,
+) {
+    let cursor_moved = cursor_events.read().count() > 0;
+    let wheel_moved = wheel_events.read().count() > 0;
+    let pressed = keys.get_just_pressed().next().is_some() || mouse_buttons.get_just_pressed().next().is_some();
+
+    if cursor_moved || wheel_moved || pressed {
+        bird_cam.idle_seconds = 0.0;
+        bird_cam.launch_pending = false;
+        if bird_cam.active {
+            bird_cam.stop();
+        }
+        return;
+    }
+
+    if bird_cam.active {
+        return;
+    }
+
+    if bird_cam.launch_pending {
+        bird_cam.launch_pending = false;
+        bird_cam.start();
+        return;
+    }
+
+    // Counted in real time so fast-forwarding the day doesn't shorten the wait
+    bird_cam.idle_seconds += time.delta_secs();
+    let delay_minutes = settings.bird_cam_idle_minutes;
+    if delay_minutes > 0.0 && bird_cam.idle_seconds >= delay_minutes * 60.0 && !photo_settings.is_active {
+        bird_cam.start();
+    }
+}
+
+fn bird_cam_pan_system(
+    mut bird_cam: ResMut<BirdCam>,
+    presence: Res<FeederPresence>,
+    object_query: Query<&GlobalTransform, Without<Camera2d>>,
+    feeder_query: Query<Entity, With<Feeder>>,
+    mut camera_query: Query<&mut Transform, With<Camera2d>>,
+    time: Res<Time>,
+) {
+    if !bird_cam.active {
+        return;
+    }
+
+    bird_cam.dwell_timer.tick(time.delta());
+    let target_lost = bird_cam.target.is_some_and(|target| object_query.get(target).is_err());
+
+    if bird_cam.target.is_none() || bird_cam.dwell_timer.finished() || target_lost {
+        // Busiest feeders and water first, then quiet feeders so there is always somewhere to look
+        let mut candidates: Vec<Entity> = presence.by_object().into_iter().map(|(object, _)| object).collect();
+        for feeder in feeder_query.iter() {
+            if !candidates.contains(&feeder) {
+                candidates.push(feeder);
+            }
+        }
+        candidates.retain(|candidate| object_query.get(*candidate).is_ok());
+
+        let fresh = candidates.iter().copied().find(|candidate| !bird_cam.recent.contains(candidate));
+        let next = fresh
+            .or_else(|| candidates.iter().copied().find(|candidate| Some(*candidate) != bird_cam.target))
+            .or(bird_cam.target.filter(|_| !target_lost));
+
+        if fresh.is_none() {
+            bird_cam.recent.clear();
+        }
+        if let Some(next) = next {
+            bird_cam.recent.push(next);
+            if bird_cam.recent.len() > RECENT_MEMORY {
+                bird_cam.recent.remove(0);
+            }
+        }
+        bird_cam.target = next;
+        bird_cam.dwell_timer.reset();
+    }
+
+    let Some(target) = bird_cam.target else { return };
+    let Ok(target_transform) = object_query.get(target) else { return };
+    let Ok(mut camera_transform) = camera_query.single_mut() else { return };
+
+    // A slow handheld sway keeps the shot from looking frozen while nothing moves
+    let elapsed = time.elapsed_secs();
+    let sway = Vec2::new((elapsed * 0.1).sin() * 30.0, (elapsed * 0.07).cos() * 15.0);
+    let goal = target_transform.translation().truncate() + sway;
+
+    let current = camera_transform.translation.truncate();
+    let next = current.lerp(goal, 1.0 - (-PAN_RATE * time.delta_secs()).exp());
+    camera_transform.translation.x = next.x;
+    camera_transform.translation.y = next.y;
+}
+
+fn bird_cam_caption_system(
+    bird_cam: Res<BirdCam>,
+    presence: Res<FeederPresence>,
+    time_state: Res<TimeState>,
+    region: Res<SanctuaryRegion>,
+    mut caption_query: Query<&mut Text, With<BirdCamCaption>>,
+) {
+    if !bird_cam.active {
+        return;
+    }
+    let Ok(mut text) = caption_query.single_mut() else { return };
+
+    let hour = time_state.hour.floor() as u32;
+    let minute = (time_state.hour.fract() * 60.0).floor() as u32;
+    let visitors = presence.visits.len();
+    let caption = format!(
+        "{}  ·  {:02}:{:02}  ·  {} bird{} at the feeders",
+        region.0.name(), hour, minute, visitors, if visitors == 1 { "" } else { "s" },
+    );
+
+    if text.0 != caption {
+        text.0 = caption;
+    }
+}
+
+fn bird_cam_ui_visibility_system(
+    mut commands: Commands,
+    bird_cam: Res<BirdCam>,
+    mut root_query: Query<(Entity, &mut Visibility, Option<&mut BirdCamHidden>), (With<Node>, Without<ChildOf>, Without<BirdCamOverlay>)>,
+    mut overlay_query: Query<&mut Visibility, With<BirdCamOverlay>>,
+) {
+    for mut visibility in overlay_query.iter_mut() {
+        let wanted = if bird_cam.active { Visibility::Inherited } else { Visibility::Hidden };
+        if *visibility != wanted {
+            *visibility = wanted;
+        }
+    }
+
+    for (entity, mut visibility, hidden) in root_query.iter_mut() {
+        match (bird_cam.active, hidden) {
+            (true, Some(mut hidden)) => {
+                // A panel that changed its own visibility meanwhile gets that restored instead
+                if *visibility != Visibility::Hidden {
+                    hidden.0 = *visibility;
+                    *visibility = Visibility::Hidden;
+                }
+            }
+            (true, None) => {
+                commands.entity(entity).insert(BirdCamHidden(*visibility));
+                *visibility = Visibility::Hidden;
+            }
+            (false, Some(hidden)) => {
+                *visibility = hidden.0;
+                commands.entity(entity).remove::<BirdCamHidden>();
+            }
+            (false, None) => {}
+        }
+    }
+}
@@ -22,8 +22,49 @@ pub struct BirdData {
     pub behavioral_traits: HashMap<String, f32>,
     pub audio_config: HashMap<String, f32>,
     pub seasonal_availability: HashMap<String, f32>,
+    #[serde(default)]
+    pub flight_style: Option<FlightStyle>,
+    /// Memory phrases by call type name ("Song", "Alarm", ...), syllables split by dashes and spaces
+    #[serde(default)]
+    pub mnemonics: HashMap<String, String>,
 }
 
+/// Splits a mnemonic into the syllables that are sung one note each
+pub fn mnemonic_syllables(phrase: &str) -> Vec<&str> {
+    phrase.split(['-', ' ']).filter(|syllable| !syllable.is_empty()).collect()
+}
+
+/// How a species gets between perches: the shape of its path and its rhythm of flapping and gliding
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct FlightStyle {
+    pub arc: f32,            // Sideways bow of the path as a share of its length
+    pub flap_time: f32,      // Seconds of flapping in each cycle
+    pub glide_time: f32,     // Seconds of gliding or folded wings after it, 0.0 = flaps throughout
+    pub glide_speed: f32,    // Speed while gliding as a share of flapping speed
+    pub undulation: f32,     // Height gained while flapping and lost again in the glide
+    pub bank: f32,           // Lean into turns in radians at the tightest turn
+    pub flare_distance: f32, // How far out the landing flare starts
+}
+
+impl Default for FlightStyle {
+    fn default() -> Self {
+        Self {
+            arc: 0.15,
+            flap_time: 0.6,
+            glide_time: 0.25,
+            glide_speed: 0.85,
+            undulation: 3.0,
+            bank: 0.35,
+            flare_distance: 40.0,
+        }
+    }
+}
+
+pub const BIRD_DATA_FILES: [&str; 2] = [
+    "data/birds/common_species.ron",
+    "data/birds/rare_species.ron",
+];
+
 #[derive(Resource, Default)]
 pub struct BirdDataRegistry {
     pub species_data: HashMap<String, BirdData>,
@@ -32,12 +73,7 @@ pub struct BirdDataRegistry {
 
 impl BirdDataRegistry {
     pub fn load_from_files(&mut self, asset_server: &AssetServer) {
-        let data_files = vec![
-            "data/birds/common_species.ron",
-            "data/birds/rare_species.ron",
-        ];
-        
-        for file_path in data_files {
+        for file_path in BIRD_DATA_FILES {
             if let Err(e) = self.load_species_file(file_path) {
                 error!("Failed to load bird data from {}: {}", file_path, e);
             } else {
@@ -49,6 +85,17 @@ impl BirdDataRegistry {
         info!("Bird data registry initialized with {} species", self.species_data.len());
     }
     
+    /// Re-reads every data file, keeping the current data unless all of them parse
+    pub fn reload_from_files(&mut self) -> Result<usize, String> {
+        let mut fresh = Self::default();
+        for file_path in BIRD_DATA_FILES {
+            fresh.load_species_file(file_path).map_err(|e| format!("{}: {}", file_path, e))?;
+            fresh.loaded_files.push(file_path.to_string());
+        }
+        *self = fresh;
+        Ok(self.species_data.len())
+    }
+    
     fn load_species_file(&mut self, file_path: &str) -> Result<(), Box<dyn std::error::Error>> {
         let full_path = format!("assets/{}", file_path);
         let content = std::fs::read_to_string(&full_path)?;
@@ -102,6 +149,34 @@ impl BirdDataRegistry {
             species.size_category_fallback()
         }
     }
+    
+    pub fn get_flight_style(&self, species: &BirdSpecies) -> FlightStyle {
+        self.get_species_data(&format!("{:?}", species))
+            .and_then(|data| data.flight_style)
+            .unwrap_or_else(|| species.flight_style_fallback())
+    }
+    
+    /// Classic phrase for remembering a vocalization, like "drink-your-tea", if it has one
+    pub fn get_mnemonic(&self, species: &BirdSpecies, call_type: &str) -> Option<&str> {
+        match self.get_species_data(&format!("{:?}", species)) {
+            Some(data) if !data.mnemonics.is_empty() => data.mnemonics.get(call_type).map(String::as_str),
+            _ => species.mnemonic_fallback(call_type),
+        }
+    }
+    
+    /// Approximate bill-to-tail length for the species' size category
+    pub fn get_length_cm(&self, species: &BirdSpecies) -> f32 {
+        match self.get_size_category(species) {
+            0 | 1 => 9.0,
+            2 => 13.0,
+            3 => 15.0,
+            4 => 21.0,
+            5 => 27.0,
+            6 => 42.0,
+            7 => 45.0,
+            _ => 56.0,
+        }
+    }
 }
 
 // Extension trait for BirdSpecies fallback methods
@@ -155,6 +230,76 @@ impl BirdSpecies {
         }
     }
     
+    fn mnemonic_fallback(&self, call_type: &str) -> Option<&'static str> {
+        let phrase = match (self, call_type) {
+            (Self::Goldfinch, "Song" | "Contact") => "po-ta-to-chip",
+            (Self::CarolinaWren, "Song") => "tea-kettle tea-kettle tea-kettle",
+            (Self::TuftedTitmouse, "Song") => "pe-ter pe-ter pe-ter",
+            (Self::MourningDove, "Song") => "coo-OO-oo-oo-oo",
+            (Self::RedWingedBlackbird, "Song" | "Territorial") => "conk-la-ree",
+            (Self::YellowWarbler, "Song") => "sweet-sweet-sweet I'm-so-sweet",
+            (Self::BrownThrasher, "Song") => "plant-it plant-it cov-er-it-up cov-er-it-up",
+            (Self::CommonCrow, _) => "caw-caw-caw",
+            (Self::WhiteBreastedNuthatch, _) => "yank-yank-yank",
+            (Self::BarredOwl, "Song") => "who-cooks-for-you who-cooks-for-you-all",
+            (Self::EasternBluebird, "Song") => "tru-ly tru-ly",
+            (Self::WoodThrush, "Song") => "ee-oh-lay",
+            (Self::Catbird, "Alarm" | "Contact") => "mew",
+            (Self::PaintedBunting, "Song") => "pew-eata pew-eata i-eat-you-too",
+            (Self::ProthonotaryWarbler, "Song") => "sweet-sweet-sweet-sweet",
+            _ => return None,
+        };
+        Some(phrase)
+    }
+    
+    fn flight_style_fallback(&self) -> FlightStyle {
+        match self {
+            // Woodpeckers climb on a burst of wingbeats then dip with wings shut
+            Self::DownyWoodpecker | Self::HairyWoodpecker | Self::PileatedWoodpecker
+                | Self::RedHeadedWoodpecker | Self::YellowBelledSapsucker => FlightStyle {
+                arc: 0.1,
+                flap_time: 0.35,
+                glide_time: 0.45,
+                glide_speed: 0.9,
+                undulation: 14.0,
+                bank: 0.3,
+                flare_distance: 35.0,
+            },
+            // Finches bound along in short hops of flapping and folded wings
+            Self::Goldfinch | Self::HouseFinch | Self::PurpleFinch => FlightStyle {
+                arc: 0.12,
+                flap_time: 0.22,
+                glide_time: 0.18,
+                glide_speed: 0.95,
+                undulation: 8.0,
+                bank: 0.3,
+                flare_distance: 30.0,
+            },
+            // Raptors sweep wide and glide most of the way
+            Self::RedTailedHawk | Self::CoopersHawk | Self::BaldEagle | Self::PeregrineFalcon
+                | Self::GreatHornedOwl | Self::BarredOwl => FlightStyle {
+                arc: 0.25,
+                flap_time: 0.6,
+                glide_time: 1.8,
+                glide_speed: 1.0,
+                undulation: 2.0,
+                bank: 0.6,
+                flare_distance: 70.0,
+            },
+            // Hummingbirds dart straight in on a constant blur of wings
+            Self::RubyThroatedHummingbird => FlightStyle {
+                arc: 0.04,
+                flap_time: 1.0,
+                glide_time: 0.0,
+                glide_speed: 1.0,
+                undulation: 0.0,
+                bank: 0.1,
+                flare_distance: 15.0,
+            },
+            _ => FlightStyle::default(),
+        }
+    }
+    
     fn size_category_fallback(&self) -> u8 {
         match self {
             // Tier 1 - Common birds (size 2-5)
@@ -0,0 +1,314 @@
+// Bird Health - Body Condition and Supplemental Feeding Consequences
+use bevy::prelude::*;
+use crate::bird_ai::components::{BirdAI, BirdState, Blackboard};
+use crate::environment::resources::{TimeState, WeatherState};
+use crate::environment::components::Weather;
+use crate::feeder::Feeder;
+use crate::family_groups::Juvenile;
+
+pub struct BirdHealthPlugin;
+
+impl Plugin for BirdHealthPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<ProvisioningRecord>()
+            .add_event::<MealEvent>()
+            .add_systems(Update, (
+                attach_body_condition_system,
+                meal_detection_system,
+                meal_condition_system,
+                weather_condition_system,
+                condition_priority_system,
+                condition_posture_system,
+                provisioning_tracking_system,
+            ).chain().in_set(crate::GameSet::Simulation).run_if(in_state(crate::AppState::Playing)));
+    }
+}
+
+// Components
+#[derive(Component)]
+pub struct BodyCondition {
+    pub condition: f32,          // 0.0 = emaciated, 1.0 = peak condition
+    pub recent_food_quality: f32, // Rolling average of meal quality
+    pub meals_eaten: u32,
+}
+
+impl Default for BodyCondition {
+    fn default() -> Self {
+        Self {
+            condition: 0.6,
+            recent_food_quality: 0.5,
+            meals_eaten: 0,
+        }
+    }
+}
+
+impl BodyCondition {
+    pub fn category(&self) -> ConditionCategory {
+        ConditionCategory::from_condition(self.condition)
+    }
+}
+
+#[derive(Component)]
+pub struct ConditionPosture {
+    pub category: ConditionCategory,
+    pub base_color: Color,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConditionCategory {
+    Poor,
+    Fair,
+    Good,
+    Excellent,
+}
+
+impl ConditionCategory {
+    pub fn from_condition(condition: f32) -> Self {
+        match condition {
+            c if c < 0.3 => Self::Poor,
+            c if c < 0.55 => Self::Fair,
+            c if c < 0.8 => Self::Good,
+            _ => Self::Excellent,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Poor => "Poor",
+            Self::Fair => "Fair",
+            Self::Good => "Good",
+            Self::Excellent => "Excellent",
+        }
+    }
+
+    // Fluffed and hunched when struggling, sleek when healthy
+    fn posture_scale(&self) -> Vec3 {
+        match self {
+            Self::Poor => Vec3::new(1.15, 0.85, 1.0),
+            Self::Fair => Vec3::new(1.05, 0.95, 1.0),
+            Self::Good | Self::Excellent => Vec3::ONE,
+        }
+    }
+
+    // Dull plumage for birds in poor condition
+    fn plumage_brightness(&self) -> f32 {
+        match self {
+            Self::Poor => 0.65,
+            Self::Fair => 0.85,
+            Self::Good => 1.0,
+            Self::Excellent => 1.05,
+        }
+    }
+}
+
+// Resources
+#[derive(Resource)]
+pub struct ProvisioningRecord {
+    pub daily_quality_total: f32,
+    pub daily_meals: u32,
+    pub rolling_quality: f32,      // Smoothed average over recent days
+    pub consistent_days: u32,      // Consecutive days of high-quality provisioning
+    pub return_rate_multiplier: f32, // Applied to environmental spawning
+    pub last_day: u32,
+}
+
+impl Default for ProvisioningRecord {
+    fn default() -> Self {
+        Self {
+            daily_quality_total: 0.0,
+            daily_meals: 0,
+            rolling_quality: 0.5,
+            consistent_days: 0,
+            return_rate_multiplier: 1.0,
+            last_day: 0,
+        }
+    }
+}
+
+// Events
+#[derive(Event)]
+pub struct MealEvent {
+    pub bird: Entity,
+    pub quality: f32,
+}
+
+const HIGH_QUALITY_THRESHOLD: f32 = 0.65;
+
+fn attach_body_condition_system(
+    mut commands: Commands,
+    bird_query: Query<(Entity, &Sprite), (With<BirdAI>, Without<BodyCondition>)>,
+) {
+    for (entity, sprite) in bird_query.iter() {
+        let condition = BodyCondition {
+            condition: 0.45 + rand::random::<f32>() * 0.3,
+            ..default()
+        };
+        commands.entity(entity).insert((
+            ConditionPosture {
+                category: condition.category(),
+                base_color: sprite.color,
+            },
+            condition,
+        ));
+    }
+}
+
+// A bird entering the eating state takes a meal from the closest feeder
+fn meal_detection_system(
+    bird_query: Query<(Entity, &Transform, &BirdState), (With<BodyCondition>, Changed<BirdState>)>,
+    feeder_query: Query<(&Transform, &Feeder)>,
+    weather_state: Res<WeatherState>,
+    mut meal_events: EventWriter<MealEvent>,
+) {
+    for (entity, transform, state) in bird_query.iter() {
+        let quality = match state {
+            BirdState::Eating | BirdState::HoverFeeding => {
+                let bird_pos = transform.translation.truncate();
+                feeder_query.iter()
+                    .filter(|(feeder_transform, _)| {
+                        feeder_transform.translation.truncate().distance(bird_pos) < 60.0
+                    })
+                    .map(|(_, feeder)| {
+                        let freshness = if feeder.current_capacity > 0.0 { 1.0 } else { 0.2 };
+                        let mut quality = feeder.feeder_type.food_quality(feeder.upgrade_level) * freshness;
+                        // Wet seed on open feeders spoils
+                        if matches!(weather_state.current_weather, Weather::Rainy)
+                            && matches!(feeder.feeder_type, crate::feeder::FeederType::Ground)
+                        {
+                            quality *= 0.6;
+                        }
+                        quality
+                    })
+                    .fold(None, |best: Option<f32>, q| Some(best.map_or(q, |b| b.max(q))))
+                    .unwrap_or(0.45) // Natural food away from feeders
+            },
+            BirdState::Foraging | BirdState::Retrieving => 0.45,
+            _ => continue,
+        };
+
+        meal_events.write(MealEvent { bird: entity, quality });
+    }
+}
+
+fn meal_condition_system(
+    mut meal_events: EventReader<MealEvent>,
+    mut condition_query: Query<&mut BodyCondition>,
+    mut provisioning: ResMut<ProvisioningRecord>,
+) {
+    for event in meal_events.read() {
+        let Ok(mut condition) = condition_query.get_mut(event.bird) else {
+            continue;
+        };
+
+        condition.meals_eaten += 1;
+        condition.recent_food_quality = condition.recent_food_quality * 0.8 + event.quality * 0.2;
+        // Good food builds reserves, junk food barely helps
+        condition.condition = (condition.condition + (event.quality - 0.3) * 0.05).clamp(0.0, 1.0);
+
+        provisioning.daily_quality_total += event.quality;
+        provisioning.daily_meals += 1;
+    }
+}
+
+// Metabolic drain scaled by weather severity
+fn weather_condition_system(
+    mut condition_query: Query<&mut BodyCondition>,
+    weather_state: Res<WeatherState>,
+    time_state: Res<TimeState>,
+    time: Res<Time>,
+) {
+    let game_hours = time.delta_secs() / time_state.time_speed;
+
+    let cold_stress = ((5.0 - weather_state.temperature) / 20.0).clamp(0.0, 1.0);
+    let weather_stress = match weather_state.current_weather {
+        Weather::Snowy => 0.6,
+        Weather::Rainy => 0.4,
+        Weather::Windy => 0.2,
+        _ => 0.0,
+    };
+    let drain_per_hour = 0.004 * (1.0 + cold_stress * 2.0 + weather_stress);
+
+    for mut condition in condition_query.iter_mut() {
+        condition.condition = (condition.condition - drain_per_hour * game_hours).max(0.0);
+    }
+}
+
+// Birds in poor condition prioritise food and rest over social behavior
+fn condition_priority_system(
+    mut bird_query: Query<(&BodyCondition, &mut Blackboard)>,
+    time: Res<Time>,
+) {
+    for (condition, mut blackboard) in bird_query.iter_mut() {
+        match condition.category() {
+            ConditionCategory::Poor => {
+                blackboard.internal.hunger = blackboard.internal.hunger.max(0.55);
+                blackboard.internal.social_need = (blackboard.internal.social_need - 0.1 * time.delta_secs()).max(0.0);
+                blackboard.internal.territorial_stress *= 0.9;
+            },
+            ConditionCategory::Fair => {
+                blackboard.internal.hunger = (blackboard.internal.hunger + 0.02 * time.delta_secs()).min(1.0);
+            },
+            ConditionCategory::Good | ConditionCategory::Excellent => {},
+        }
+    }
+}
+
+fn condition_posture_system(
+    mut bird_query: Query<(&BodyCondition, &mut ConditionPosture, &mut Transform, &mut Sprite), (Changed<BodyCondition>, Without<Juvenile>)>,
+) {
+    for (condition, mut posture, mut transform, mut sprite) in bird_query.iter_mut() {
+        let category = condition.category();
+        if category == posture.category {
+            continue;
+        }
+
+        posture.category = category;
+        transform.scale = category.posture_scale();
+
+        let base = posture.base_color.to_srgba();
+        let brightness = category.plumage_brightness();
+        sprite.color = Color::srgba(
+            (base.red * brightness).min(1.0),
+            (base.green * brightness).min(1.0),
+            (base.blue * brightness).min(1.0),
+            base.alpha,
+        );
+    }
+}
+
+// Consistent high-quality provisioning makes birds more likely to return
+fn provisioning_tracking_system(
+    mut provisioning: ResMut<ProvisioningRecord>,
+    time_state: Res<TimeState>,
+) {
+    if provisioning.last_day == time_state.day_of_year {
+        return;
+    }
+
+    if provisioning.last_day != 0 {
+        let daily_quality = if provisioning.daily_meals > 0 {
+            provisioning.daily_quality_total / provisioning.daily_meals as f32
+        } else {
+            0.0
+        };
+
+        provisioning.rolling_quality = provisioning.rolling_quality * 0.7 + daily_quality * 0.3;
+
+        if daily_quality >= HIGH_QUALITY_THRESHOLD {
+            provisioning.consistent_days += 1;
+        } else {
+            provisioning.consistent_days = 0;
+        }
+
+        let consistency_bonus = (provisioning.consistent_days as f32 * 0.05).min(0.5);
+        provisioning.return_rate_multiplier = (0.6 + provisioning.rolling_quality * 0.6 + consistency_bonus).clamp(0.5, 1.6);
+
+        info!("🍽️ Provisioning day summary: quality {:.2}, {} consistent days, return rate x{:.2}",
+            daily_quality, provisioning.consistent_days, provisioning.return_rate_multiplier);
+    }
+
+    provisioning.daily_quality_total = 0.0;
+    provisioning.daily_meals = 0;
+    provisioning.last_day = time_state.day_of_year;
+}
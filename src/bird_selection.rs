@@ -17,7 +17,7 @@ impl Plugin for BirdSelectionPlugin {
                     bird_selection_system,
                     selection_highlight_system,
                     update_selection_ui,
-                ).run_if(in_state(AppState::Playing))
+                ).in_set(crate::GameSet::UI).run_if(in_state(AppState::Playing))
             )
             .add_systems(OnEnter(AppState::Playing), setup_selection_ui)
             .add_systems(OnExit(AppState::Playing), cleanup_selection_ui);
@@ -356,6 +356,8 @@ fn format_state_description(state: BirdState) -> String {
         BirdState::Caching => "Caching food".to_string(),
         BirdState::Retrieving => "Retrieving cached food".to_string(),
         BirdState::HoverFeeding => "Hover feeding".to_string(),
+        BirdState::Begging => "Begging for food".to_string(),
+        BirdState::Drumming => "Drumming on dead wood".to_string(),
     }
 }
 
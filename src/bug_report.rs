@@ -0,0 +1,505 @@
+// Bug Report - F10 dialog that bundles what's needed to chase down a problem into one zip
+//
+// Pressing the key grabs a screenshot first, so the dialog itself isn't in it, then opens a panel
+// where the player can type what went wrong. Saving writes a zip to the bug reports folder holding
+// the screenshot, the last 200 log lines, and a report with the description, the current screen,
+// a summary of the loaded save and the system specs, ready to attach to an issue. Players who opt
+// in and have an endpoint set in settings.ron can send it straight off; that goes through curl on
+// the PATH so the game doesn't carry an HTTP client for one button.
+use bevy::prelude::*;
+use bevy::input::ButtonState;
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::render::renderer::RenderAdapterInfo;
+use bevy::render::view::screenshot::{Screenshot, ScreenshotCaptured};
+use bevy::tasks::{futures::check_ready, IoTaskPool, Task};
+use bevy::window::PrimaryWindow;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+use crate::keybindings::{GameAction, KeyBindings};
+use crate::menu::resources::GameSettings;
+use crate::notifications::{components::NotificationType, resources::ShowNotificationEvent};
+use crate::save_load::resources::{PlaytimeTracker, SaveManager};
+
+const LOG_LINES: usize = 200;
+const MAX_DESCRIPTION_LENGTH: usize = 500;
+
+pub struct BugReportPlugin;
+
+impl Plugin for BugReportPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<BugReport>()
+            .add_systems(Update, (
+                bug_report_toggle_system.run_if(crate::debug_console::console_is_not_visible),
+                bug_report_description_system,
+                bug_report_button_system,
+                bug_report_submit_system,
+                refresh_bug_report_panel_system,
+            ).chain().in_set(crate::GameSet::UI));
+    }
+}
+
+// Resources
+#[derive(Resource, Default)]
+pub struct BugReport {
+    stage: ReportStage,
+    // PNG of the screen as it was when the key was pressed
+    screenshot: Option<Vec<u8>>,
+    description: String,
+    submission: Option<Task<Result<(), String>>>,
+    panel_dirty: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+enum ReportStage {
+    #[default]
+    Closed,
+    Capturing,
+    Open,
+}
+
+// Components
+#[derive(Component)]
+pub struct BugReportPanel;
+
+#[derive(Component)]
+pub struct BugReportButton {
+    pub action: BugReportAction,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BugReportAction {
+    Save,
+    SaveAndSend,
+    ToggleAutoSubmit,
+    Cancel,
+}
+
+pub fn bug_report_toggle_system(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    key_bindings: Res<KeyBindings>,
+    mut report: ResMut<BugReport>,
+) {
+    if !key_bindings.is_action_just_pressed(GameAction::ReportBug, &keyboard, &mouse) {
+        return;
+    }
+    match report.stage {
+        ReportStage::Closed => {
+            report.stage = ReportStage::Capturing;
+            report.screenshot = None;
+            report.description.clear();
+            commands.spawn(Screenshot::primary_window()).observe(screenshot_observer);
+        }
+        ReportStage::Open => {
+            report.stage = ReportStage::Closed;
+            report.panel_dirty = true;
+        }
+        ReportStage::Capturing => {}
+    }
+}
+
+fn screenshot_observer(trigger: Trigger<ScreenshotCaptured>, mut report: ResMut<BugReport>) {
+    if report.stage != ReportStage::Capturing {
+        return;
+    }
+    report.screenshot = encode_png(&trigger.event().0);
+    report.stage = ReportStage::Open;
+    report.panel_dirty = true;
+}
+
+fn encode_png(image: &Image) -> Option<Vec<u8>> {
+    let format = bevy::image::ImageFormat::Png.as_image_crate_format()?;
+    let mut bytes = std::io::Cursor::new(Vec::new());
+    match image.clone().try_into_dynamic() {
+        Ok(dynamic) => dynamic.to_rgb8().write_to(&mut bytes, format).ok().map(|_| bytes.into_inner()),
+        Err(e) => {
+            warn!("Couldn't read bug report screenshot: {}", e);
+            None
+        }
+    }
+}
+
+// Typing while the dialog is open goes into the description
+pub fn bug_report_description_system(
+    mut keyboard_events: EventReader<KeyboardInput>,
+    mut report: ResMut<BugReport>,
+) {
+    if report.stage != ReportStage::Open {
+        keyboard_events.clear();
+        return;
+    }
+
+    let mut changed = false;
+    for event in keyboard_events.read() {
+        if event.state != ButtonState::Pressed {
+            continue;
+        }
+        match &event.logical_key {
+            Key::Character(text) if report.description.len() < MAX_DESCRIPTION_LENGTH => {
+                report.description.push_str(text);
+                changed = true;
+            }
+            Key::Space if report.description.len() < MAX_DESCRIPTION_LENGTH => {
+                report.description.push(' ');
+                changed = true;
+            }
+            Key::Backspace => {
+                report.description.pop();
+                changed = true;
+            }
+            _ => {}
+        }
+    }
+    if changed {
+        report.panel_dirty = true;
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn bug_report_button_system(
+    mut button_query: Query<(&Interaction, &BugReportButton, &mut BackgroundColor), Changed<Interaction>>,
+    mut report: ResMut<BugReport>,
+    mut settings: ResMut<GameSettings>,
+    state: Res<State<crate::AppState>>,
+    save_manager: Res<SaveManager>,
+    playtime: Res<PlaytimeTracker>,
+    adapter: Option<Res<RenderAdapterInfo>>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+) {
+    let mut chosen = None;
+    for (interaction, button, mut bg_color) in &mut button_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *bg_color = Color::srgb(0.5, 0.7, 0.5).into();
+                chosen = Some(button.action);
+            }
+            Interaction::Hovered => *bg_color = Color::srgb(0.7, 0.6, 0.5).into(),
+            Interaction::None => *bg_color = Color::srgb(0.6, 0.5, 0.4).into(),
+        }
+    }
+    let Some(action) = chosen else { return };
+
+    match action {
+        BugReportAction::ToggleAutoSubmit => {
+            settings.bug_report_auto_submit = !settings.bug_report_auto_submit;
+            if let Err(e) = settings.save_to_file() {
+                error!("Failed to save bug report setting: {}", e);
+            }
+        }
+        BugReportAction::Cancel => report.stage = ReportStage::Closed,
+        BugReportAction::Save | BugReportAction::SaveAndSend => {
+            let details = ReportDetails {
+                state: format!("{:?}", state.get()),
+                save_summary: save_summary(&save_manager, &playtime),
+                specs: system_specs(adapter.as_deref(), window_query.single().ok()),
+            };
+            let result = write_report(&report, &details);
+            let message = match &result {
+                Ok(path) => format!("Bug report saved to {}", path.display()),
+                Err(e) => format!("Bug report failed: {}", e),
+            };
+            match &result {
+                Ok(path) => {
+                    info!("{}", message);
+                    if action == BugReportAction::SaveAndSend {
+                        let endpoint = settings.bug_report_endpoint.clone();
+                        let path = path.clone();
+                        report.submission = Some(IoTaskPool::get().spawn(async move { submit_report(&endpoint, &path) }));
+                    }
+                }
+                Err(_) => error!("{}", message),
+            }
+            notification_events.write(ShowNotificationEvent {
+                notification: NotificationType::Info { message },
+            });
+            report.stage = ReportStage::Closed;
+        }
+    }
+    report.panel_dirty = true;
+}
+
+pub fn bug_report_submit_system(
+    mut report: ResMut<BugReport>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+) {
+    let Some(task) = report.submission.as_mut() else { return };
+    let Some(result) = check_ready(task) else { return };
+    report.submission = None;
+
+    let message = match result {
+        Ok(()) => "Bug report sent - thank you!".to_string(),
+        Err(e) => {
+            warn!("Bug report upload failed: {}", e);
+            "Couldn't send the bug report - it's still saved to attach by hand".to_string()
+        }
+    };
+    notification_events.write(ShowNotificationEvent {
+        notification: NotificationType::Info { message },
+    });
+}
+
+struct ReportDetails {
+    state: String,
+    save_summary: String,
+    specs: String,
+}
+
+fn save_summary(save_manager: &SaveManager, playtime: &PlaytimeTracker) -> String {
+    let saves = save_manager.list_save_files();
+    let mut summary = match save_manager.current_save_slot {
+        Some(slot) => format!("Current slot: {}\n", slot),
+        None => "Current slot: none (unsaved game or menus)\n".to_string(),
+    };
+    let _ = writeln!(summary, "Save files: {}", saves.len());
+    if let Some(save) = save_manager.current_save_slot.and_then(|slot| saves.iter().find(|save| save.slot == slot)) {
+        if let Some(region) = save.region {
+            let _ = writeln!(summary, "Region: {:?}", region);
+        }
+        if let Some(difficulty) = &save.difficulty {
+            let _ = writeln!(summary, "Difficulty: {}", difficulty.label());
+        }
+    }
+    let _ = writeln!(summary, "Playtime: {:.1} hours", playtime.get_total_seconds() / 3600.0);
+    summary
+}
+
+fn system_specs(adapter: Option<&RenderAdapterInfo>, window: Option<&Window>) -> String {
+    let mut specs = format!(
+        "Game version: {}\nOS: {} ({})\nCPU threads: {}\n",
+        env!("CARGO_PKG_VERSION"),
+        std::env::consts::OS,
+        std::env::consts::ARCH,
+        std::thread::available_parallelism().map_or(0, |threads| threads.get()),
+    );
+    if let Some(adapter) = adapter {
+        let _ = writeln!(specs, "GPU: {} ({:?}, driver {} {})", adapter.name, adapter.backend, adapter.driver, adapter.driver_info);
+    }
+    if let Some(window) = window {
+        let _ = writeln!(specs, "Window: {}x{} at {:.2}x scale, {:?}", window.physical_width(), window.physical_height(), window.scale_factor(), window.mode);
+    }
+    specs
+}
+
+pub fn bug_report_directory() -> PathBuf {
+    dirs::data_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("AvianHaven")
+        .join("bug_reports")
+}
+
+fn write_report(report: &BugReport, details: &ReportDetails) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    let directory = bug_report_directory();
+    fs::create_dir_all(&directory)?;
+
+    let description = if report.description.trim().is_empty() { "(none given)" } else { report.description.trim() };
+    let text = format!(
+        "AvianHaven bug report - {}\n\nWhat happened:\n{}\n\nScreen: {}\n\n{}\n{}",
+        chrono::Local::now().format("%Y-%m-%d %H:%M:%S"),
+        description,
+        details.state,
+        details.save_summary,
+        details.specs,
+    );
+    let log = crate::logging::recent_lines(LOG_LINES).join("\n");
+
+    let mut files = vec![("report.txt", text.into_bytes()), ("log.txt", log.into_bytes())];
+    if let Some(screenshot) = &report.screenshot {
+        files.push(("screenshot.png", screenshot.clone()));
+    }
+
+    let path = directory.join(format!("bug_report_{}.zip", chrono::Local::now().format("%Y%m%d_%H%M%S")));
+    fs::write(&path, zip_stored(&files))?;
+    Ok(path)
+}
+
+// Uncompressed zip; the screenshot is already compressed and the text is small
+fn zip_stored(files: &[(&str, Vec<u8>)]) -> Vec<u8> {
+    let mut zip = Vec::new();
+    let mut directory = Vec::new();
+
+    for (name, data) in files {
+        let offset = zip.len() as u32;
+        let crc = crc32(data);
+        let size = data.len() as u32;
+
+        // Local file header: version 2.0, no flags, stored, no timestamp
+        zip.extend_from_slice(&0x04034b50u32.to_le_bytes());
+        zip.extend_from_slice(&[20, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+        zip.extend_from_slice(&crc.to_le_bytes());
+        zip.extend_from_slice(&size.to_le_bytes());
+        zip.extend_from_slice(&size.to_le_bytes());
+        zip.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        zip.extend_from_slice(&0u16.to_le_bytes());
+        zip.extend_from_slice(name.as_bytes());
+        zip.extend_from_slice(data);
+
+        // Matching central directory entry
+        directory.extend_from_slice(&0x02014b50u32.to_le_bytes());
+        directory.extend_from_slice(&[20, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+        directory.extend_from_slice(&crc.to_le_bytes());
+        directory.extend_from_slice(&size.to_le_bytes());
+        directory.extend_from_slice(&size.to_le_bytes());
+        directory.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        // Extra field, comment, disk number, internal and external attributes
+        directory.extend_from_slice(&[0; 12]);
+        directory.extend_from_slice(&offset.to_le_bytes());
+        directory.extend_from_slice(name.as_bytes());
+    }
+
+    let directory_offset = zip.len() as u32;
+    let count = files.len() as u16;
+    zip.extend_from_slice(&directory);
+    zip.extend_from_slice(&0x06054b50u32.to_le_bytes());
+    zip.extend_from_slice(&[0, 0, 0, 0]);
+    zip.extend_from_slice(&count.to_le_bytes());
+    zip.extend_from_slice(&count.to_le_bytes());
+    zip.extend_from_slice(&(directory.len() as u32).to_le_bytes());
+    zip.extend_from_slice(&directory_offset.to_le_bytes());
+    zip.extend_from_slice(&0u16.to_le_bytes());
+    zip
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for byte in data {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 { (crc >> 1) ^ 0xEDB88320 } else { crc >> 1 };
+        }
+    }
+    !crc
+}
+
+fn submit_report(endpoint: &str, path: &Path) -> Result<(), String> {
+    let output = std::process::Command::new("curl")
+        .args(["--silent", "--show-error", "--fail", "--max-time", "60", "-F"])
+        .arg(format!("report=@{}", path.display()))
+        .arg(endpoint)
+        .output()
+        .map_err(|e| format!("could not run curl: {}", e))?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+    }
+}
+
+pub fn refresh_bug_report_panel_system(
+    mut commands: Commands,
+    mut report: ResMut<BugReport>,
+    settings: Res<GameSettings>,
+    panel_query: Query<Entity, With<BugReportPanel>>,
+) {
+    if !report.panel_dirty {
+        return;
+    }
+    report.panel_dirty = false;
+
+    for panel in &panel_query {
+        commands.entity(panel).despawn();
+    }
+    if report.stage == ReportStage::Open {
+        spawn_bug_report_panel(&mut commands, &report, &settings);
+    }
+}
+
+fn spawn_bug_report_panel(commands: &mut Commands, report: &BugReport, settings: &GameSettings) {
+    let body_font = TextFont { font_size: 12.0, ..default() };
+    let body_color = TextColor(Color::srgb(0.4, 0.3, 0.2));
+    let can_send = !settings.bug_report_endpoint.is_empty();
+
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Percent(50.0),
+            top: Val::Px(120.0),
+            width: Val::Px(460.0),
+            margin: UiRect::left(Val::Px(-230.0)),
+            flex_direction: FlexDirection::Column,
+            padding: UiRect::all(Val::Px(16.0)),
+            row_gap: Val::Px(8.0),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.95, 0.92, 0.88, 0.97)),
+        BorderRadius::all(Val::Px(8.0)),
+        GlobalZIndex(60),
+        BugReportPanel,
+        Name::new("BugReport"),
+    )).with_children(|panel| {
+        panel.spawn((
+            Text::new("Report a Bug"),
+            TextFont { font_size: 20.0, ..default() },
+            TextColor(Color::srgb(0.3, 0.2, 0.1)),
+        ));
+        panel.spawn((
+            Text::new("What happened? Type below, then save the report and attach it to an issue."),
+            body_font.clone(),
+            body_color,
+        ));
+        panel.spawn((
+            Node {
+                width: Val::Percent(100.0),
+                min_height: Val::Px(60.0),
+                padding: UiRect::all(Val::Px(8.0)),
+                ..default()
+            },
+            BackgroundColor(Color::WHITE),
+            BorderRadius::all(Val::Px(4.0)),
+        )).with_children(|field| {
+            field.spawn((
+                Text::new(format!("{}|", report.description)),
+                TextFont { font_size: 14.0, ..default() },
+                TextColor(Color::srgb(0.2, 0.15, 0.1)),
+            ));
+        });
+        panel.spawn((
+            Text::new(format!(
+                "Includes: {}the last {} log lines, the current screen, a save summary and system specs",
+                if report.screenshot.is_some() { "a screenshot, " } else { "" },
+                LOG_LINES,
+            )),
+            body_font.clone(),
+            TextColor(Color::srgb(0.5, 0.4, 0.3)),
+        ));
+
+        panel.spawn(Node {
+            flex_direction: FlexDirection::Row,
+            flex_wrap: FlexWrap::Wrap,
+            column_gap: Val::Px(8.0),
+            row_gap: Val::Px(6.0),
+            ..default()
+        }).with_children(|row| {
+            spawn_report_button(row, "Save Report", BugReportAction::Save);
+            if can_send {
+                spawn_report_button(row, &format!("Send Automatically: {}", if settings.bug_report_auto_submit { "On" } else { "Off" }), BugReportAction::ToggleAutoSubmit);
+                if settings.bug_report_auto_submit {
+                    spawn_report_button(row, "Save & Send", BugReportAction::SaveAndSend);
+                }
+            }
+            spawn_report_button(row, "Cancel", BugReportAction::Cancel);
+        });
+    });
+}
+
+fn spawn_report_button(parent: &mut ChildSpawnerCommands, label: &str, action: BugReportAction) {
+    parent.spawn((
+        Button,
+        Node {
+            padding: UiRect::axes(Val::Px(10.0), Val::Px(6.0)),
+            ..default()
+        },
+        BackgroundColor(Color::srgb(0.6, 0.5, 0.4)),
+        BorderRadius::all(Val::Px(4.0)),
+        BugReportButton { action },
+    )).with_children(|button| {
+        button.spawn((
+            Text::new(label),
+            TextFont { font_size: 13.0, ..default() },
+            TextColor(Color::WHITE),
+        ));
+    });
+}
@@ -0,0 +1,399 @@
+// Call Playback - Lure birds in with recordings of their calls, within limits that protect them
+use bevy::prelude::*;
+use std::collections::HashMap;
+use crate::audio::captions::SoundCaption;
+use crate::audio::systems::{get_species_sound_path, CallType};
+use crate::bird::{Bird, BirdSpecies};
+use crate::bird_ai::components::{BirdAI, BirdState, Blackboard};
+use crate::difficulty::DifficultySettings;
+use crate::domestic_threats::StewardRatingEvent;
+use crate::environment::components::Season;
+use crate::environment::resources::TimeState;
+use crate::journal::resources::DiscoveredSpecies;
+use crate::keybindings::{GameAction, KeyBindings};
+use crate::photo_mode::components::PhotoTarget;
+use crate::notifications::{resources::ShowNotificationEvent, components::NotificationType};
+use crate::species_packs::SpeciesPackManager;
+
+const PANEL_WIDTH: f32 = 420.0;
+const LURE_RANGE: f32 = 600.0;
+const LURE_SECONDS: f32 = 20.0;
+// Birds gather around the speaker rather than on top of it
+const ARRIVAL_RANGE: f32 = 40.0;
+// Each response adds this much stress; past 1.0 the bird gives up and flees
+const RESPONSE_STRESS: f32 = 0.35;
+
+pub struct CallPlaybackPlugin;
+
+impl Plugin for CallPlaybackPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<CallPlayback>()
+            .add_systems(Update, (
+                toggle_playback_panel_system.run_if(crate::debug_console::console_is_not_visible),
+                playback_button_system,
+                refresh_playback_panel_system,
+                playback_recovery_system,
+                lure_system.after(crate::bird_ai::systems::behavior_tree_system),
+            ).chain().in_set(crate::GameSet::AI).run_if(in_state(crate::AppState::Playing)))
+            .add_systems(OnExit(crate::AppState::Playing), close_playback_panel_system);
+    }
+}
+
+// Resources
+#[derive(Resource, Default)]
+pub struct CallPlayback {
+    pub is_open: bool,
+    // Recent plays per species, fading by one per game hour
+    pub recent_plays: HashMap<BirdSpecies, f32>,
+    pub total_plays: u32,
+    panel_dirty: bool,
+}
+
+impl CallPlayback {
+    // Birds stop answering a call they keep hearing with no bird behind it
+    pub fn effectiveness(&self, species: BirdSpecies) -> f32 {
+        1.0 / (1.0 + self.recent_plays.get(&species).copied().unwrap_or(0.0))
+    }
+}
+
+// Components
+#[derive(Component)]
+pub struct PlaybackPanel;
+
+#[derive(Component)]
+pub struct PlaybackSpeciesButton {
+    pub species: BirdSpecies,
+}
+
+// Where the recording is playing from, gone once it finishes
+#[derive(Component)]
+pub struct PlaybackSpeaker {
+    pub species: BirdSpecies,
+    pub timer: Timer,
+}
+
+#[derive(Component)]
+pub struct Lured {
+    pub speaker: Entity,
+}
+
+// Built up by answering playback, eases off over the day
+#[derive(Component, Default)]
+pub struct PlaybackStress(pub f32);
+
+fn is_breeding_season(season: Season) -> bool {
+    matches!(season, Season::Spring | Season::Summer)
+}
+
+pub fn toggle_playback_panel_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    key_bindings: Res<KeyBindings>,
+    mut playback: ResMut<CallPlayback>,
+) {
+    if key_bindings.is_action_just_pressed(GameAction::CallPlayback, &keyboard, &mouse) {
+        playback.is_open = !playback.is_open;
+        playback.panel_dirty = true;
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn playback_button_system(
+    mut commands: Commands,
+    mut button_query: Query<(&Interaction, &PlaybackSpeciesButton, &mut BackgroundColor), Changed<Interaction>>,
+    mut playback: ResMut<CallPlayback>,
+    camera_query: Query<&Transform, With<PhotoTarget>>,
+    speaker_query: Query<&PlaybackSpeaker>,
+    mut bird_query: Query<(Entity, &Transform, &Bird, Option<&mut PlaybackStress>), With<BirdAI>>,
+    difficulty: Res<DifficultySettings>,
+    time_state: Res<TimeState>,
+    asset_server: Res<AssetServer>,
+    species_packs: Res<SpeciesPackManager>,
+    mut rating_events: EventWriter<StewardRatingEvent>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+) {
+    let mut chosen = None;
+    for (interaction, button, mut bg_color) in &mut button_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *bg_color = Color::srgb(0.5, 0.7, 0.5).into();
+                chosen = Some(button.species);
+            }
+            Interaction::Hovered => *bg_color = Color::srgb(0.7, 0.6, 0.5).into(),
+            Interaction::None => *bg_color = Color::srgb(0.6, 0.5, 0.4).into(),
+        }
+    }
+    let Some(species) = chosen else { return };
+
+    if let Some(playing) = speaker_query.iter().next() {
+        notification_events.write(ShowNotificationEvent {
+            notification: NotificationType::Info {
+                message: format!("Let the {:?} recording finish first", playing.species),
+            },
+        });
+        return;
+    }
+
+    let breeding = is_breeding_season(time_state.get_season());
+    let breeding_penalty = if breeding { difficulty.breeding_playback_penalty() } else { Some(0.0) };
+    let Some(breeding_penalty) = breeding_penalty else {
+        notification_events.write(ShowNotificationEvent {
+            notification: NotificationType::Warning {
+                message: "Playback is off limits while birds are nesting".to_string(),
+            },
+        });
+        return;
+    };
+
+    let Ok(camera_transform) = camera_query.single() else { return };
+    let position = camera_transform.translation.truncate();
+    let effectiveness = playback.effectiveness(species);
+    let overused = playback.recent_plays.get(&species).copied().unwrap_or(0.0) >= difficulty.playback_limit();
+
+    let speaker = commands.spawn((
+        Transform::from_translation(position.extend(0.0)),
+        PlaybackSpeaker {
+            species,
+            timer: Timer::from_seconds(LURE_SECONDS, TimerMode::Once),
+        },
+        Name::new("PlaybackSpeaker"),
+    )).id();
+
+    let sound_path = species_packs.call_sound(species, "Song")
+        .unwrap_or_else(|| get_species_sound_path(species, CallType::Song))
+        .to_string();
+    commands.spawn((
+        AudioPlayer::new(asset_server.load(sound_path)),
+        PlaybackSettings::DESPAWN,
+        SoundCaption::call(species, "song from your speaker").with_vocalization("Song"),
+    ));
+
+    // Each bird in earshot decides whether to come and look for the intruder
+    let mut responders = 0;
+    for (entity, transform, bird, stress) in &mut bird_query {
+        if bird.species != species
+            || transform.translation.truncate().distance(position) > LURE_RANGE
+            || rand::random::<f32>() > effectiveness
+        {
+            continue;
+        }
+        match stress {
+            Some(mut stress) => stress.0 += RESPONSE_STRESS,
+            None => {
+                commands.entity(entity).insert(PlaybackStress(RESPONSE_STRESS));
+            }
+        }
+        commands.entity(entity).insert(Lured { speaker });
+        responders += 1;
+    }
+
+    *playback.recent_plays.entry(species).or_default() += 1.0;
+    playback.total_plays += 1;
+    playback.panel_dirty = true;
+
+    if breeding && breeding_penalty < 0.0 {
+        rating_events.write(StewardRatingEvent {
+            change: breeding_penalty,
+            reason: format!("Played {:?} calls during the breeding season", species),
+        });
+    }
+    if overused {
+        rating_events.write(StewardRatingEvent {
+            change: -1.0,
+            reason: format!("Overused {:?} playback", species),
+        });
+    }
+
+    let message = match (responders, overused) {
+        (0, true) => format!("No {:?} answered - they've heard that recording too often", species),
+        (0, false) => format!("No {:?} nearby answered the call", species),
+        (count, true) => format!("{} {:?} answered, but repeated playback is stressing them", count, species),
+        (count, false) => format!("{} {:?} coming to investigate the call", count, species),
+    };
+    notification_events.write(ShowNotificationEvent {
+        notification: NotificationType::Info { message },
+    });
+}
+
+// Lured birds head for the speaker until the recording ends; stressed ones give up and flee
+fn lure_system(
+    mut commands: Commands,
+    mut speaker_query: Query<(Entity, &mut PlaybackSpeaker, &Transform)>,
+    mut bird_query: Query<(Entity, &Lured, &PlaybackStress, &Transform, &mut BirdState, &mut Blackboard), With<BirdAI>>,
+    time: Res<Time>,
+) {
+    for (entity, mut speaker, _) in &mut speaker_query {
+        speaker.timer.tick(time.delta());
+        if speaker.timer.finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+
+    for (entity, lured, stress, transform, mut state, mut blackboard) in &mut bird_query {
+        let Ok((_, speaker, speaker_transform)) = speaker_query.get(lured.speaker) else {
+            commands.entity(entity).remove::<Lured>();
+            continue;
+        };
+        if speaker.timer.finished() {
+            commands.entity(entity).remove::<Lured>();
+            continue;
+        }
+
+        if stress.0 > 1.0 {
+            blackboard.internal.fear = (blackboard.internal.fear + 0.5).min(1.0);
+            blackboard.internal.energy = (blackboard.internal.energy - 0.1).max(0.0);
+            *state = BirdState::Fleeing;
+            commands.entity(entity).remove::<Lured>();
+            continue;
+        }
+
+        let distance = transform.translation.truncate().distance(speaker_transform.translation.truncate());
+        if distance < ARRIVAL_RANGE {
+            *state = BirdState::Exploring;
+        } else {
+            blackboard.current_target = Some(lured.speaker);
+            *state = BirdState::MovingToTarget;
+        }
+    }
+}
+
+// Birds calm down and forget the recording over game time
+fn playback_recovery_system(
+    mut commands: Commands,
+    mut playback: ResMut<CallPlayback>,
+    mut stress_query: Query<(Entity, &mut PlaybackStress)>,
+    time_state: Res<TimeState>,
+    time: Res<Time>,
+) {
+    let game_hours = time.delta_secs() / time_state.time_speed;
+
+    playback.recent_plays.retain(|_, plays| {
+        *plays -= game_hours;
+        *plays > 0.0
+    });
+
+    for (entity, mut stress) in &mut stress_query {
+        stress.0 -= game_hours / 24.0;
+        if stress.0 <= 0.0 {
+            commands.entity(entity).remove::<PlaybackStress>();
+        }
+    }
+}
+
+pub fn refresh_playback_panel_system(
+    mut commands: Commands,
+    mut playback: ResMut<CallPlayback>,
+    discovered: Res<DiscoveredSpecies>,
+    difficulty: Res<DifficultySettings>,
+    time_state: Res<TimeState>,
+    panel_query: Query<Entity, With<PlaybackPanel>>,
+) {
+    if !playback.panel_dirty {
+        return;
+    }
+    playback.panel_dirty = false;
+
+    for panel in &panel_query {
+        commands.entity(panel).despawn();
+    }
+    if !playback.is_open {
+        return;
+    }
+
+    let mut recordings: Vec<BirdSpecies> = discovered.0.iter().copied().collect();
+    recordings.sort_by_key(|species| format!("{:?}", species));
+    let breeding = is_breeding_season(time_state.get_season());
+
+    spawn_playback_panel(&mut commands, &playback, &recordings, breeding, &difficulty);
+}
+
+pub fn close_playback_panel_system(
+    mut commands: Commands,
+    mut playback: ResMut<CallPlayback>,
+    panel_query: Query<Entity, With<PlaybackPanel>>,
+) {
+    playback.is_open = false;
+    for panel in &panel_query {
+        commands.entity(panel).despawn();
+    }
+}
+
+fn spawn_playback_panel(
+    commands: &mut Commands,
+    playback: &CallPlayback,
+    recordings: &[BirdSpecies],
+    breeding: bool,
+    difficulty: &DifficultySettings,
+) {
+    let season_note = match (breeding, difficulty.breeding_playback_penalty()) {
+        (false, _) => "Outside the breeding season - play sparingly".to_string(),
+        (true, None) => "Breeding season - playback is off limits".to_string(),
+        (true, Some(penalty)) if penalty < 0.0 => format!("Breeding season - each play costs {:.0} steward rating", -penalty),
+        (true, Some(_)) => "Breeding season - go easy on nesting birds".to_string(),
+    };
+
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            right: Val::Px(20.0),
+            top: Val::Px(80.0),
+            width: Val::Px(PANEL_WIDTH),
+            flex_direction: FlexDirection::Column,
+            padding: UiRect::all(Val::Px(14.0)),
+            row_gap: Val::Px(6.0),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.95, 0.92, 0.88, 0.95)),
+        BorderRadius::all(Val::Px(8.0)),
+        PlaybackPanel,
+        Name::new("CallPlayback"),
+    )).with_children(|panel| {
+        panel.spawn((
+            Text::new("Call Playback"),
+            TextFont { font_size: 20.0, ..default() },
+            TextColor(Color::srgb(0.3, 0.2, 0.1)),
+        ));
+        panel.spawn((
+            Text::new(season_note),
+            TextFont { font_size: 12.0, ..default() },
+            TextColor(Color::srgb(0.5, 0.4, 0.3)),
+        ));
+
+        if recordings.is_empty() {
+            panel.spawn((
+                Text::new("No recordings yet. Species you log in the journal can be played back here."),
+                TextFont { font_size: 13.0, ..default() },
+                TextColor(Color::srgb(0.4, 0.3, 0.2)),
+            ));
+        }
+
+        panel.spawn(Node {
+            width: Val::Percent(100.0),
+            flex_wrap: FlexWrap::Wrap,
+            column_gap: Val::Px(4.0),
+            row_gap: Val::Px(4.0),
+            ..default()
+        }).with_children(|grid| {
+            for species in recordings {
+                let response = playback.effectiveness(*species);
+                grid.spawn((
+                    Button,
+                    Node {
+                        padding: UiRect::axes(Val::Px(6.0), Val::Px(3.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.6, 0.5, 0.4)),
+                    BorderRadius::all(Val::Px(4.0)),
+                    PlaybackSpeciesButton { species: *species },
+                )).with_children(|button| {
+                    button.spawn((
+                        Text::new(format!("{:?} ({:.0}%)", species, response * 100.0)),
+                        TextFont { font_size: 11.0, ..default() },
+                        TextColor(Color::WHITE),
+                    ));
+                });
+            }
+        });
+    });
+}
@@ -8,17 +8,31 @@ impl Plugin for CameraPlugin {
     fn build(&self, app: &mut App) {
         app
             .init_resource::<CameraState>()
+            .add_event::<CameraFocusEvent>()
             .add_systems(
                 Update,
-                camera_pan_system.run_if(in_state(AppState::Playing))
+                (camera_pan_system, camera_focus_system).chain().in_set(crate::GameSet::Input).run_if(in_state(AppState::Playing))
             );
     }
 }
 
+// Asks the camera to glide over to a point in the world
+#[derive(Event)]
+pub struct CameraFocusEvent {
+    pub position: Vec2,
+}
+
+// Where in the yard the mouse is pointing, for clicking on things placed in the world
+pub fn cursor_world_position(window: &Window, camera: &Camera, camera_transform: &GlobalTransform) -> Option<Vec2> {
+    let cursor_position = window.cursor_position()?;
+    camera.viewport_to_world_2d(camera_transform, cursor_position).ok()
+}
+
 #[derive(Resource, Default)]
 struct CameraState {
     is_dragging: bool,
     last_mouse_position: Vec2,
+    focus_target: Option<Vec2>,
 }
 
 fn camera_pan_system(
@@ -53,6 +67,31 @@ fn camera_pan_system(
             camera_transform.translation.y += delta.y; // Y is flipped in screen coordinates
             
             camera_state.last_mouse_position = event.position;
+            // Dragging takes control back from any glide in progress
+            camera_state.focus_target = None;
         }
     }
+}
+
+fn camera_focus_system(
+    mut camera_query: Query<&mut Transform, With<Camera2d>>,
+    mut focus_events: EventReader<CameraFocusEvent>,
+    mut camera_state: ResMut<CameraState>,
+    time: Res<Time>,
+) {
+    if let Some(event) = focus_events.read().last() {
+        camera_state.focus_target = Some(event.position);
+    }
+
+    let Some(target) = camera_state.focus_target else { return };
+    let Ok(mut camera_transform) = camera_query.single_mut() else { return };
+
+    let current = camera_transform.translation.truncate();
+    let next = current.lerp(target, 1.0 - (-6.0 * time.delta_secs()).exp());
+    camera_transform.translation.x = next.x;
+    camera_transform.translation.y = next.y;
+
+    if next.distance(target) < 1.0 {
+        camera_state.focus_target = None;
+    }
 }
\ No newline at end of file
@@ -1,6 +1,7 @@
 use bevy::prelude::*;
 use crate::bird::BirdSpecies;
 use crate::bird_ai::components::BirdAction;
+use crate::sanctuary_management::ReputationTier;
 
 #[derive(Component)]
 pub struct CatalogUI;
@@ -60,6 +61,8 @@ pub enum PlaceableItemType {
     SuetCake,
     NectarFeeder,
     FruitDispenser,
+    PeanutTray,
+    SeedCatcherTray,
     
     // Water features
     BasicBirdbath,
@@ -72,11 +75,16 @@ pub enum PlaceableItemType {
     FlowerPot,
     BirdHouse,
     NestingBox,
+    DeadSnag,
+    RoostBox,
+    CompostBin,
     
     // Special attraction items
     MirrorToy,
     BellToy,
     SwingSeat,
+    TripodCamera,
+    WildlifeBlind,
 }
 
 impl PlaceableItemType {
@@ -92,6 +100,8 @@ impl PlaceableItemType {
             Self::SuetCake => "Suet Cake",
             Self::NectarFeeder => "Nectar Feeder",
             Self::FruitDispenser => "Fruit Dispenser",
+            Self::PeanutTray => "Peanut Tray",
+            Self::SeedCatcherTray => "Seed Catcher Tray",
             Self::BasicBirdbath => "Basic Birdbath",
             Self::FountainBirdbath => "Fountain Birdbath",
             Self::StreamFeature => "Stream Feature",
@@ -100,9 +110,14 @@ impl PlaceableItemType {
             Self::FlowerPot => "Flower Pot",
             Self::BirdHouse => "Bird House",
             Self::NestingBox => "Nesting Box",
+            Self::DeadSnag => "Dead Snag",
+            Self::RoostBox => "Roost Box",
+            Self::CompostBin => "Compost Bin",
             Self::MirrorToy => "Mirror Toy",
             Self::BellToy => "Bell Toy",
             Self::SwingSeat => "Swing Seat",
+            Self::TripodCamera => "Tripod Camera",
+            Self::WildlifeBlind => "Wildlife Blind",
         }
     }
     
@@ -121,6 +136,8 @@ impl PlaceableItemType {
             Self::SuetCake => 40,
             Self::NectarFeeder => 120,
             Self::FruitDispenser => 90,
+            Self::PeanutTray => 35,
+            Self::SeedCatcherTray => 40,
             
             // Water features
             Self::BasicBirdbath => 80,
@@ -133,11 +150,26 @@ impl PlaceableItemType {
             Self::FlowerPot => 35,
             Self::BirdHouse => 100,
             Self::NestingBox => 120,
+            Self::DeadSnag => 140,
+            Self::RoostBox => 90,
+            Self::CompostBin => 110,
             
             // Special items
             Self::MirrorToy => 85,
             Self::BellToy => 70,
             Self::SwingSeat => 180,
+            Self::TripodCamera => 250,
+            Self::WildlifeBlind => 160,
+        }
+    }
+    
+    // Higher-end items only stock once the sanctuary has built a name for itself
+    pub fn required_reputation(&self) -> ReputationTier {
+        match self {
+            Self::FancyPerch | Self::FruitDispenser | Self::DeadSnag | Self::TripodCamera | Self::WildlifeBlind => ReputationTier::Local,
+            Self::FountainBirdbath | Self::NestingBox | Self::SwingSeat => ReputationTier::Regional,
+            Self::StreamFeature => ReputationTier::Renowned,
+            _ => ReputationTier::Unknown,
         }
     }
     
@@ -153,6 +185,8 @@ impl PlaceableItemType {
             Self::SuetCake => "High-energy suet cake for woodpeckers",
             Self::NectarFeeder => "Sweet nectar for hummingbirds",
             Self::FruitDispenser => "Fresh fruit for fruit-eating birds",
+            Self::PeanutTray => "Whole peanuts in the shell - crows and jays remember who puts them out",
+            Self::SeedCatcherTray => "Mesh tray that sits under a feeder and catches spilled seed before rodents find it",
             Self::BasicBirdbath => "Simple water source for drinking and bathing",
             Self::FountainBirdbath => "Elegant fountain that attracts more birds",
             Self::StreamFeature => "Flowing stream feature with naturalistic appeal",
@@ -161,9 +195,14 @@ impl PlaceableItemType {
             Self::FlowerPot => "Colorful flowers that attract insects and birds",
             Self::BirdHouse => "Nesting house for cavity-dwelling species",
             Self::NestingBox => "Specialized nesting box for breeding pairs",
+            Self::DeadSnag => "Standing dead trunk for woodpeckers to drum on and hollow out",
+            Self::RoostBox => "Insulated winter box with staggered perches where small birds huddle overnight",
+            Self::CompostBin => "Turns seed hulls and raked-up spills into fertilizer for the flower beds",
             Self::MirrorToy => "Reflective toy that fascinates certain species",
             Self::BellToy => "Small bell that birds enjoy playing with",
             Self::SwingSeat => "Fun swing that playful birds love to use",
+            Self::TripodCamera => "Camera on a fixed tripod - compose the frame once, then fire it remotely when birds step in",
+            Self::WildlifeBlind => "Camouflaged hide - shooting from inside it barely disturbs the birds",
         }
     }
     
@@ -178,9 +217,19 @@ impl PlaceableItemType {
                 BirdSpecies::DownyWoodpecker, BirdSpecies::HairyWoodpecker, BirdSpecies::WhiteBreastedNuthatch
             ],
             Self::NectarFeeder => vec![BirdSpecies::RubyThroatedHummingbird],
+            Self::DeadSnag => vec![
+                BirdSpecies::DownyWoodpecker, BirdSpecies::HairyWoodpecker, BirdSpecies::PileatedWoodpecker,
+                BirdSpecies::EasternBluebird,
+            ],
+            Self::RoostBox => vec![
+                BirdSpecies::Chickadee, BirdSpecies::CarolinaWren, BirdSpecies::EasternBluebird,
+                BirdSpecies::DownyWoodpecker,
+            ],
+            Self::CompostBin => vec![BirdSpecies::Robin, BirdSpecies::BrownThrasher],
             Self::FruitDispenser => vec![
                 BirdSpecies::BrownThrasher, BirdSpecies::ScarletTanager, BirdSpecies::BaltimoreOriole
             ],
+            Self::PeanutTray => vec![BirdSpecies::BlueJay, BirdSpecies::CommonCrow],
             _ => vec![], // Most items attract various species generally
         }
     }
@@ -198,6 +247,8 @@ impl PlaceableItemType {
             Self::SuetCake => vec![BirdAction::Eat],
             Self::NectarFeeder => vec![BirdAction::Eat], // Hummingbirds "eat" nectar
             Self::FruitDispenser => vec![BirdAction::Eat],
+            Self::PeanutTray => vec![BirdAction::Eat],
+            Self::SeedCatcherTray => vec![BirdAction::Forage], // Ground feeders pick over the catch
             
             // Water features - provide drinking and bathing
             Self::BasicBirdbath => vec![BirdAction::Drink, BirdAction::Bathe],
@@ -211,11 +262,19 @@ impl PlaceableItemType {
             
             // Nesting items - provide nesting behavior
             Self::BirdHouse | Self::NestingBox => vec![BirdAction::Nest, BirdAction::Perch],
+            // Cavities are claimed by the snag's own state machine rather than the Nest action
+            Self::DeadSnag => vec![BirdAction::Perch, BirdAction::Explore],
+            Self::RoostBox => vec![BirdAction::Roost, BirdAction::Perch],
+            // Worms and grubs work the edges of the heap
+            Self::CompostBin => vec![BirdAction::Forage],
             
             // Special items - provide play behavior
             Self::MirrorToy => vec![BirdAction::Play, BirdAction::Explore],
             Self::BellToy => vec![BirdAction::Play],
             Self::SwingSeat => vec![BirdAction::Play, BirdAction::Perch],
+            
+            // Photography gear - the odd bird lands on it
+            Self::TripodCamera | Self::WildlifeBlind => vec![BirdAction::Perch],
         }
     }
 
@@ -234,6 +293,8 @@ impl PlaceableItemType {
             Self::SuetCake => 0.8, // Specialist food for specific species
             Self::NectarFeeder => 0.9, // Essential for hummingbirds
             Self::FruitDispenser => 0.8,
+            Self::PeanutTray => 0.75, // Irresistible to corvids once stocked
+            Self::SeedCatcherTray => 0.3,
             
             // Water features - essential utility
             Self::BasicBirdbath => 0.8, // Essential for birds
@@ -248,11 +309,18 @@ impl PlaceableItemType {
             // Nesting items - seasonal but very important during breeding
             Self::BirdHouse => 0.7, // Good for cavity nesters
             Self::NestingBox => 0.8, // Specialized nesting
+            Self::DeadSnag => 0.6, // Natural perch, mostly valued by woodpeckers
+            Self::RoostBox => 0.75, // Warm shelter on cold nights
+            Self::CompostBin => 0.35,
             
             // Play items - moderate utility for enrichment
             Self::MirrorToy => 0.6, // Fascinating to some species
             Self::BellToy => 0.4, // Simple play object
             Self::SwingSeat => 0.7, // Fun and functional
+            
+            // Photography gear
+            Self::TripodCamera => 0.2, // Tolerated rather than sought out
+            Self::WildlifeBlind => 0.2,
         }
     }
 
@@ -269,6 +337,8 @@ impl PlaceableItemType {
             Self::SuetCake => 70.0,
             Self::NectarFeeder => 60.0, // Smaller range for precise feeders
             Self::FruitDispenser => 75.0,
+            Self::PeanutTray => 90.0,
+            Self::SeedCatcherTray => 40.0,
             
             // Water features - large attraction range
             Self::BasicBirdbath => 90.0,
@@ -282,11 +352,18 @@ impl PlaceableItemType {
             
             // Nesting items
             Self::BirdHouse | Self::NestingBox => 40.0,
+            Self::DeadSnag => 60.0,
+            Self::RoostBox => 80.0,
+            Self::CompostBin => 50.0,
             
             // Play items
             Self::MirrorToy => 35.0,
             Self::BellToy => 30.0,
             Self::SwingSeat => 45.0,
+            
+            // Photography gear
+            Self::TripodCamera => 25.0,
+            Self::WildlifeBlind => 40.0,
         }
     }
 
@@ -301,6 +378,8 @@ impl PlaceableItemType {
             Self::SuetCake => Vec2::new(25.0, 25.0),
             Self::NectarFeeder => Vec2::new(20.0, 35.0),
             Self::FruitDispenser => Vec2::new(35.0, 40.0),
+            Self::PeanutTray => Vec2::new(35.0, 15.0),
+            Self::SeedCatcherTray => Vec2::new(60.0, 10.0),
             Self::BasicBirdbath => Vec2::new(45.0, 45.0),
             Self::FountainBirdbath => Vec2::new(55.0, 55.0),
             Self::StreamFeature => Vec2::new(150.0, 30.0),
@@ -309,9 +388,14 @@ impl PlaceableItemType {
             Self::FlowerPot => Vec2::new(30.0, 25.0),
             Self::BirdHouse => Vec2::new(25.0, 35.0),
             Self::NestingBox => Vec2::new(30.0, 25.0),
+            Self::DeadSnag => Vec2::new(30.0, 110.0),
+            Self::RoostBox => Vec2::new(28.0, 40.0),
+            Self::CompostBin => Vec2::new(45.0, 40.0),
             Self::MirrorToy => Vec2::new(20.0, 30.0),
             Self::BellToy => Vec2::new(15.0, 20.0),
             Self::SwingSeat => Vec2::new(35.0, 40.0),
+            Self::TripodCamera => Vec2::new(20.0, 45.0),
+            Self::WildlifeBlind => Vec2::new(60.0, 50.0),
         }
     }
 }
\ No newline at end of file
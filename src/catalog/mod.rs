@@ -21,8 +21,10 @@ impl Plugin for CatalogPlugin {
             .add_systems(OnEnter(AppState::Playing), setup_catalog_items)
             .add_systems(OnExit(AppState::Playing), cleanup_catalog_ui)
 
+            .add_systems(Update, handle_catalog_input
+                .in_set(crate::GameSet::Input)
+                .run_if(in_state(AppState::Playing).and(crate::debug_console::console_is_not_visible))) // Re-enabled for keyboard shortcut
             .add_systems(Update, (
-                handle_catalog_input.run_if(crate::debug_console::console_is_not_visible), // Re-enabled for keyboard shortcut
                 // handle_category_buttons, // Disabled - using Lunex UI
                 // handle_purchase_buttons, // Disabled - using Lunex UI
                 // handle_place_buttons, // Disabled - using Lunex UI
@@ -31,7 +33,11 @@ impl Plugin for CatalogPlugin {
                 handle_object_placement, // Keep for functionality
                 start_placement_mode, // Keep for functionality
                 // update_catalog_visibility, // Disabled - using Lunex UI
-            ).run_if(in_state(AppState::Playing)))
+            ).in_set(crate::GameSet::Simulation).run_if(in_state(AppState::Playing)))
+            .add_systems(Update, (
+                provide_catalog_search_entries.in_set(crate::search_palette::SearchProviders),
+                catalog_search_jump_system,
+            ).in_set(crate::GameSet::UI))
            ;
     }
 }
\ No newline at end of file
@@ -42,6 +42,10 @@ pub enum ItemCategory {
 }
 
 impl ItemCategory {
+    pub const ALL: [ItemCategory; 5] = [
+        Self::Comfort, Self::Food, Self::Water, Self::Decorative, Self::Special,
+    ];
+
     pub fn name(&self) -> &str {
         match self {
             Self::Comfort => "Comfort",
@@ -67,6 +71,8 @@ impl ItemCategory {
                 PlaceableItemType::SuetCake,
                 PlaceableItemType::NectarFeeder,
                 PlaceableItemType::FruitDispenser,
+                PlaceableItemType::PeanutTray,
+                PlaceableItemType::SeedCatcherTray,
             ],
             Self::Water => vec![
                 PlaceableItemType::BasicBirdbath,
@@ -79,11 +85,16 @@ impl ItemCategory {
                 PlaceableItemType::FlowerPot,
                 PlaceableItemType::BirdHouse,
                 PlaceableItemType::NestingBox,
+                PlaceableItemType::DeadSnag,
+                PlaceableItemType::RoostBox,
+                PlaceableItemType::CompostBin,
             ],
             Self::Special => vec![
                 PlaceableItemType::MirrorToy,
                 PlaceableItemType::BellToy,
                 PlaceableItemType::SwingSeat,
+                PlaceableItemType::TripodCamera,
+                PlaceableItemType::WildlifeBlind,
             ],
         }
     }
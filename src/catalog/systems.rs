@@ -3,6 +3,7 @@ use bevy_rapier2d::prelude::*;
 use crate::catalog::{components::*, resources::*};
 use crate::bird_ai::components::{SmartObject, ProvidesUtility};
 use crate::despawn::SafeDespawn;
+use crate::search_palette::{SearchIndex, SearchJumpEvent, SearchTarget};
 
 // Helper function to get filename for object sprites
 pub fn object_filename(item_type: &PlaceableItemType) -> String {
@@ -17,6 +18,8 @@ pub fn object_filename(item_type: &PlaceableItemType) -> String {
         PlaceableItemType::SuetCake => "suet_cake".to_string(),
         PlaceableItemType::NectarFeeder => "nectar_feeder".to_string(),
         PlaceableItemType::FruitDispenser => "fruit_dispenser".to_string(),
+        PlaceableItemType::PeanutTray => "peanut_tray".to_string(),
+        PlaceableItemType::SeedCatcherTray => "seed_catcher_tray".to_string(),
         PlaceableItemType::BasicBirdbath => "basic_birdbath".to_string(),
         PlaceableItemType::FountainBirdbath => "fountain_birdbath".to_string(),
         PlaceableItemType::StreamFeature => "stream_feature".to_string(),
@@ -25,9 +28,14 @@ pub fn object_filename(item_type: &PlaceableItemType) -> String {
         PlaceableItemType::FlowerPot => "flower_pot".to_string(),
         PlaceableItemType::BirdHouse => "bird_house".to_string(),
         PlaceableItemType::NestingBox => "nesting_box".to_string(),
+        PlaceableItemType::DeadSnag => "dead_snag".to_string(),
+        PlaceableItemType::RoostBox => "roost_box".to_string(),
+        PlaceableItemType::CompostBin => "compost_bin".to_string(),
         PlaceableItemType::MirrorToy => "mirror_toy".to_string(),
         PlaceableItemType::BellToy => "bell_toy".to_string(),
         PlaceableItemType::SwingSeat => "swing_seat".to_string(),
+        PlaceableItemType::TripodCamera => "tripod_camera".to_string(),
+        PlaceableItemType::WildlifeBlind => "wildlife_blind".to_string(),
     }
 }
 
@@ -228,6 +236,32 @@ pub fn handle_catalog_input(
     }
 }
 
+// Every catalog item, for the search palette
+pub fn provide_catalog_search_entries(mut index: ResMut<SearchIndex>) {
+    for category in ItemCategory::ALL {
+        for item in category.items() {
+            let keywords = format!("{} {}", category.name(), item.description());
+            index.add("Catalog", item.name().to_string(), keywords, SearchTarget::CatalogItem(item));
+        }
+    }
+}
+
+// Opens the catalog on the item's category
+pub fn catalog_search_jump_system(
+    mut jump_events: EventReader<SearchJumpEvent>,
+    mut catalog_state: ResMut<CatalogState>,
+    mut app_state: ResMut<NextState<crate::AppState>>,
+) {
+    for event in jump_events.read() {
+        let SearchTarget::CatalogItem(item) = &event.target else { continue };
+        if let Some(category) = ItemCategory::ALL.into_iter().find(|category| category.items().contains(item)) {
+            catalog_state.selected_category = category;
+        }
+        catalog_state.is_open = true;
+        app_state.set(crate::AppState::Catalog);
+    }
+}
+
 pub fn handle_category_buttons(
     mut interaction_query: Query<
         (&Interaction, &CategoryButton, &mut BackgroundColor),
@@ -262,10 +296,11 @@ pub fn handle_purchase_buttons(
     >,
     mut purchase_events: EventWriter<PurchaseItemEvent>,
     inventory: Res<PlayerInventory>,
+    difficulty: Res<crate::difficulty::DifficultySettings>,
 ) {
     for (interaction, purchase_button) in interaction_query.iter() {
         if *interaction == Interaction::Pressed {
-            let price = purchase_button.item_type.price();
+            let price = difficulty.item_price(&purchase_button.item_type);
             if inventory.currency >= price {
                 purchase_events.write(PurchaseItemEvent {
                     item_type: purchase_button.item_type.clone(),
@@ -298,17 +333,33 @@ pub fn handle_purchase_events(
     mut inventory: ResMut<PlayerInventory>,
     mut notifications: EventWriter<crate::notifications::resources::ShowNotificationEvent>,
     mut catalog_state: ResMut<CatalogState>,
+    reputation: Res<crate::sanctuary_management::SanctuaryReputation>,
+    difficulty: Res<crate::difficulty::DifficultySettings>,
+    mut milestone_events: EventWriter<crate::journal::milestones::MilestoneEvent>,
 ) {
     for event in purchase_events.read() {
-        let price = event.item_type.price();
+        let price = difficulty.item_price(&event.item_type);
+        let required_tier = event.item_type.required_reputation();
         
-        if inventory.currency >= price {
+        if reputation.tier < required_tier {
+            notifications.write(crate::notifications::resources::ShowNotificationEvent {
+                notification: crate::notifications::components::NotificationType::Warning {
+                    message: format!("{} unlocks at {} reputation", event.item_type.name(), required_tier.name()),
+                },
+            });
+        } else if inventory.currency >= price {
             inventory.currency -= price;
             *inventory.owned_items.entry(event.item_type.clone()).or_insert(0) += 1;
             
             // Trigger UI update
             catalog_state.set_changed();
             
+            milestone_events.write(crate::journal::milestones::MilestoneEvent {
+                kind: crate::journal::milestones::MilestoneKind::Purchase,
+                title: format!("Bought a {} for {} coins", event.item_type.name(), price),
+                species: None,
+            });
+            
             notifications.write(crate::notifications::resources::ShowNotificationEvent {
                 notification: crate::notifications::components::NotificationType::Currency {
                     amount: price,
@@ -400,6 +451,7 @@ pub fn update_catalog_ui(
     mut commands: Commands,
     catalog_state: Res<CatalogState>,
     inventory: Res<PlayerInventory>,
+    difficulty: Res<crate::difficulty::DifficultySettings>,
     asset_server: Res<AssetServer>,
     mut items_grid_query: Query<Entity, With<ItemsGrid>>,
     currency_text_query: Query<Entity, (With<Text>, Without<ItemsGrid>)>,
@@ -454,7 +506,7 @@ pub fn update_catalog_ui(
                     )).with_children(|row| {
                         for item_type in chunk {
                             let owned_count = inventory.owned_items.get(item_type).unwrap_or(&0);
-                            let price = item_type.price();
+                            let price = difficulty.item_price(item_type);
                             let can_afford = inventory.currency >= price;
                             let has_items = *owned_count > 0;
                             
@@ -649,7 +701,7 @@ pub fn handle_object_placement(
             
             // Place object on left click
             if mouse_button.just_pressed(MouseButton::Left) {
-                info!("left mouse button just pressed (handle_object_placement)");
+                trace!("Left mouse pressed for object placement");
                 if let Some(item_type) = &catalog_state.selected_item {
                     place_events.write(PlaceObjectEvent {
                         item_type: item_type.clone(),
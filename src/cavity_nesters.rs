@@ -0,0 +1,543 @@
+// Cavity Nesters - Suet feeders, drumming snags and woodpecker-excavated nest cavities
+use bevy::prelude::*;
+use bevy::audio::Volume;
+use bevy_rapier2d::prelude::*;
+use crate::bird::{Bird, BirdSpecies};
+use crate::bird_ai::components::{BirdAI, BirdState, Blackboard, SmartObject, ProvidesUtility, BirdAction};
+use crate::feeder::{Feeder, FeederType};
+use crate::catalog::components::{PlaceableObject, PlaceableItemType};
+use crate::environment::resources::TimeState;
+use crate::environment::components::Season;
+use crate::audio::resources::{AudioManager, AudioSettings, MixerBus};
+use crate::audio::captions::SoundCaption;
+use crate::audio::components::{PositionalAudioSource, AudioVolumeCurve, Vocalization};
+use crate::photo_mode::components::{PhotoTakenEvent, PhotoTarget};
+use crate::journal::resources::{ResearchMissionManager, DataType};
+use crate::notifications::{resources::ShowNotificationEvent, components::NotificationType};
+use crate::sanctuary_management::{NestingEvent, NestingEventType};
+
+pub struct CavityNestersPlugin;
+
+impl Plugin for CavityNestersPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<CavityLog>()
+            .add_systems(Startup, spawn_natural_snag)
+            .add_systems(Update, (
+                attach_suet_feeder_system,
+                attach_snag_system,
+                snag_visit_system,
+                snag_work_system,
+                cavity_brood_system,
+                cavity_prospecting_system,
+                cavity_tenant_system,
+                cavity_visual_system,
+                cavity_photo_recognition_system,
+            ).chain()
+                // Runs after the behavior tree so drumming and prospecting states are not overwritten each tick
+                .after(crate::bird_ai::systems::behavior_tree_system)
+                .in_set(crate::GameSet::AI)
+                .run_if(in_state(crate::AppState::Playing)));
+    }
+}
+
+const SNAG_ATTRACTION_RANGE: f32 = 320.0;
+const PROSPECTING_RANGE: f32 = 360.0;
+const DRUM_AUDIO_RANGE: f32 = 450.0;
+// Days with a full work session needed to hollow out a cavity
+const EXCAVATION_DAYS: u32 = 3;
+// Seconds of hammering that count as a day's work
+const DAILY_WORK_SECS: f32 = 30.0;
+const EXCAVATOR_BROOD_DAYS: u32 = 3;
+const SECONDARY_BROOD_DAYS: u32 = 4;
+
+pub fn is_woodpecker(species: BirdSpecies) -> bool {
+    matches!(species,
+        BirdSpecies::DownyWoodpecker | BirdSpecies::HairyWoodpecker | BirdSpecies::PileatedWoodpecker |
+        BirdSpecies::RedHeadedWoodpecker | BirdSpecies::YellowBelledSapsucker
+    )
+}
+
+// Species that nest in cavities but cannot dig their own
+pub fn is_secondary_cavity_nester(species: BirdSpecies) -> bool {
+    matches!(species,
+        BirdSpecies::Chickadee | BirdSpecies::WhiteBreastedNuthatch | BirdSpecies::TuftedTitmouse |
+        BirdSpecies::CarolinaWren | BirdSpecies::EasternBluebird | BirdSpecies::EuropeanStarling |
+        BirdSpecies::ProthonotaryWarbler
+    )
+}
+
+// Resources
+#[derive(Resource, Default)]
+pub struct CavityLog {
+    pub drumrolls: u32,
+    pub cavities_excavated: u32,
+    pub secondary_broods: u32,
+}
+
+// Components
+#[derive(Component, Default)]
+pub struct Snag {
+    pub stage: CavityStage,
+    // Woodpecker working the trunk, or the nester moving into the cavity
+    pub claimed_by: Option<Entity>,
+    pub broods_raised: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum CavityStage {
+    // Bare dead wood, good for drumming
+    #[default]
+    Sound,
+    // Woodpecker chipping out a hole over several days
+    Excavating { days_worked: u32, last_day: u32, work_today: f32 },
+    // The excavator raises its own brood first
+    Excavated { excavator: BirdSpecies, since_day: u32 },
+    // Abandoned cavity, open to secondary nesters
+    Vacant,
+    Occupied { species: BirdSpecies, since_day: u32 },
+}
+
+impl CavityStage {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Sound => "Sound wood",
+            Self::Excavating { .. } => "Being excavated",
+            Self::Excavated { .. } => "Woodpecker nest",
+            Self::Vacant => "Vacant cavity",
+            Self::Occupied { .. } => "Occupied cavity",
+        }
+    }
+
+    // 0.0 for sound wood up to 1.0 for a finished hole
+    fn hole_size(&self) -> f32 {
+        match self {
+            Self::Sound => 0.0,
+            Self::Excavating { days_worked, work_today, .. } => {
+                (*days_worked as f32 + (work_today / DAILY_WORK_SECS).min(1.0)) / EXCAVATION_DAYS as f32
+            }
+            _ => 1.0,
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct CavityHole;
+
+#[derive(Component)]
+pub struct SnagWorker {
+    pub snag: Entity,
+    pub session: Timer,
+    pub drum_timer: Timer,
+    pub drumroll: Timer,
+    pub work_spot: Vec2,
+}
+
+#[derive(Component)]
+pub struct CavityProspector {
+    pub snag: Entity,
+}
+
+fn spawn_natural_snag(mut commands: Commands) {
+    commands.spawn((
+        Sprite::from_color(Color::srgb(0.45, 0.4, 0.36), Vec2::new(26.0, 110.0)),
+        Transform::from_xyz(280.0, 40.0, 0.4),
+        RigidBody::Fixed,
+        Collider::cuboid(13.0, 55.0),
+        Sensor,
+        Snag::default(),
+        SmartObject,
+        ProvidesUtility {
+            action: BirdAction::Perch,
+            base_utility: 0.6,
+            range: 60.0,
+        },
+        Name::new("Dead Snag"),
+    ));
+}
+
+// Placed suet cakes hang in a cage and behave like any other feeder
+fn attach_suet_feeder_system(
+    mut commands: Commands,
+    object_query: Query<(Entity, &PlaceableObject), (Added<PlaceableObject>, Without<Feeder>)>,
+) {
+    for (entity, object) in &object_query {
+        if object.item_type != PlaceableItemType::SuetCake {
+            continue;
+        }
+
+        let suet = FeederType::Suet;
+        commands.entity(entity).insert(Feeder {
+            feeder_type: suet,
+            attraction_radius: 140.0,
+            current_capacity: suet.max_capacity(0),
+            max_capacity: suet.max_capacity(0),
+            depletion_rate: suet.depletion_rate(0),
+            upgrade_level: 0,
+        });
+    }
+}
+
+fn attach_snag_system(
+    mut commands: Commands,
+    object_query: Query<(Entity, &PlaceableObject), (Added<PlaceableObject>, Without<Snag>)>,
+) {
+    for (entity, object) in &object_query {
+        if object.item_type == PlaceableItemType::DeadSnag {
+            commands.entity(entity).insert(Snag::default());
+        }
+    }
+}
+
+// Idle woodpeckers near an unclaimed snag fly over to drum and dig
+fn snag_visit_system(
+    mut commands: Commands,
+    mut bird_query: Query<(Entity, &Bird, &Transform, &mut BirdState, &mut Blackboard), (With<BirdAI>, Without<SnagWorker>, Without<CavityProspector>)>,
+    mut snag_query: Query<(Entity, &Transform, &mut Snag), Without<BirdAI>>,
+    time: Res<Time>,
+) {
+    for (entity, bird, transform, mut state, mut blackboard) in &mut bird_query {
+        if !is_woodpecker(bird.species) || *state != BirdState::Wandering || blackboard.internal.hunger > 0.6 {
+            continue;
+        }
+        if rand::random::<f32>() > 0.1 * time.delta_secs() {
+            continue;
+        }
+
+        let bird_pos = transform.translation.truncate();
+        let snag = snag_query.iter_mut()
+            .filter(|(_, snag_transform, snag)| {
+                snag.claimed_by.is_none()
+                    && !matches!(snag.stage, CavityStage::Occupied { .. })
+                    && snag_transform.translation.truncate().distance(bird_pos) < SNAG_ATTRACTION_RANGE
+            })
+            .min_by(|a, b| {
+                a.1.translation.truncate().distance(bird_pos)
+                    .total_cmp(&b.1.translation.truncate().distance(bird_pos))
+            });
+        let Some((snag_entity, snag_transform, mut snag)) = snag else { continue };
+
+        snag.claimed_by = Some(entity);
+        // Cling to the side of the trunk, a little below the top
+        let side = if bird_pos.x < snag_transform.translation.x { -1.0 } else { 1.0 };
+        let work_spot = snag_transform.translation.truncate() + Vec2::new(side * 14.0, 25.0);
+
+        commands.entity(entity).insert(SnagWorker {
+            snag: snag_entity,
+            session: Timer::from_seconds(45.0 + rand::random::<f32>() * 30.0, TimerMode::Once),
+            drum_timer: Timer::from_seconds(3.0 + rand::random::<f32>() * 4.0, TimerMode::Once),
+            drumroll: Timer::from_seconds(1.2, TimerMode::Once),
+            work_spot,
+        });
+        *state = BirdState::Drumming;
+        blackboard.current_target = Some(snag_entity);
+    }
+}
+
+// Drumrolls carry across the yard; in spring the same bird also chips out a nest hole
+fn snag_work_system(
+    mut commands: Commands,
+    mut worker_query: Query<(Entity, &Bird, &mut SnagWorker, &mut Transform, &mut BirdState, &mut Blackboard), With<BirdAI>>,
+    mut snag_query: Query<(&Transform, &mut Snag), Without<BirdAI>>,
+    camera_query: Query<&Transform, (With<Camera2d>, Without<BirdAI>, Without<Snag>)>,
+    time_state: Res<TimeState>,
+    audio_settings: Res<AudioSettings>,
+    audio_manager: Res<AudioManager>,
+    asset_server: Res<AssetServer>,
+    mut log: ResMut<CavityLog>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+    time: Res<Time>,
+) {
+    let elapsed = time.elapsed_secs();
+    let today = time_state.absolute_day();
+    let breeding = time_state.get_season() == Season::Spring;
+    let listener = camera_query.single().map(|t| t.translation.truncate()).ok();
+
+    for (entity, bird, mut worker, mut transform, mut state, mut blackboard) in &mut worker_query {
+        let Ok((snag_transform, mut snag)) = snag_query.get_mut(worker.snag) else {
+            commands.entity(entity).remove::<SnagWorker>();
+            *state = BirdState::Wandering;
+            continue;
+        };
+
+        worker.session.tick(time.delta());
+        let done = worker.session.finished() || blackboard.internal.hunger > 0.8 || blackboard.internal.fear > 0.6;
+        if done {
+            if snag.claimed_by == Some(entity) {
+                snag.claimed_by = None;
+            }
+            commands.entity(entity).remove::<SnagWorker>();
+            *state = BirdState::Wandering;
+            blackboard.current_target = None;
+            transform.rotation = Quat::IDENTITY;
+            continue;
+        }
+
+        *state = BirdState::Drumming;
+        blackboard.current_target = Some(worker.snag);
+
+        let current = transform.translation.truncate();
+        let to_spot = worker.work_spot - current;
+        if to_spot.length() > 4.0 {
+            let step = to_spot.normalize() * 150.0 * time.delta_secs();
+            transform.translation += step.clamp_length_max(to_spot.length()).extend(0.0);
+            continue;
+        }
+
+        worker.drum_timer.tick(time.delta());
+        worker.drumroll.tick(time.delta());
+
+        if worker.drum_timer.finished() {
+            worker.drum_timer = Timer::from_seconds(4.0 + rand::random::<f32>() * 5.0, TimerMode::Once);
+            worker.drumroll.reset();
+            log.drumrolls += 1;
+
+            let snag_pos = snag_transform.translation.truncate();
+            let distance = listener.map_or(0.0, |listener| listener.distance(snag_pos));
+            if distance < DRUM_AUDIO_RANGE {
+                let level = (1.0 - distance / DRUM_AUDIO_RANGE).powi(2);
+                commands.spawn((
+                    AudioPlayer::new(asset_server.load("audio/woodpecker_drumming.ogg")),
+                    PlaybackSettings::DESPAWN
+                        .with_spatial(true)
+                        .with_volume(Volume::Linear(level * audio_manager.bus_volume(MixerBus::Calls) * audio_settings.volume)),
+                    Transform::from_translation(snag_transform.translation),
+                    Vocalization { species: bird.species, level },
+                    SoundCaption::call(bird.species, "drumming"),
+                    PositionalAudioSource {
+                        source_entity: worker.snag,
+                        max_distance: DRUM_AUDIO_RANGE,
+                        volume_curve: AudioVolumeCurve::InverseSquare,
+                    },
+                ));
+            }
+        }
+
+        if !worker.drumroll.finished() {
+            // Rapid-fire drumroll
+            transform.rotation = Quat::from_rotation_z((elapsed * 55.0).sin() * 0.18);
+        } else if breeding && matches!(snag.stage, CavityStage::Sound | CavityStage::Excavating { .. }) {
+            // Slower, heavier blows while excavating
+            transform.rotation = Quat::from_rotation_z((elapsed * 7.0).sin().max(0.0) * 0.3);
+            blackboard.internal.energy = (blackboard.internal.energy - 0.02 * time.delta_secs()).max(0.0);
+            excavate(&mut snag, bird.species, today, time.delta_secs(), &mut log, &mut notification_events);
+        } else {
+            transform.rotation = Quat::IDENTITY;
+        }
+    }
+}
+
+fn excavate(
+    snag: &mut Snag,
+    species: BirdSpecies,
+    today: u32,
+    work_secs: f32,
+    log: &mut CavityLog,
+    notification_events: &mut EventWriter<ShowNotificationEvent>,
+) {
+    if snag.stage == CavityStage::Sound {
+        snag.stage = CavityStage::Excavating { days_worked: 0, last_day: today, work_today: 0.0 };
+    }
+    let CavityStage::Excavating { mut days_worked, mut last_day, mut work_today } = snag.stage else {
+        return;
+    };
+
+    if last_day != today {
+        last_day = today;
+        work_today = 0.0;
+    }
+    let was_short = work_today < DAILY_WORK_SECS;
+    work_today += work_secs;
+    if was_short && work_today >= DAILY_WORK_SECS {
+        days_worked += 1;
+    }
+
+    if days_worked >= EXCAVATION_DAYS {
+        snag.stage = CavityStage::Excavated { excavator: species, since_day: today };
+        log.cavities_excavated += 1;
+        notification_events.write(ShowNotificationEvent {
+            notification: NotificationType::Info {
+                message: format!("A {:?} has finished excavating a nest cavity", species),
+            },
+        });
+    } else {
+        snag.stage = CavityStage::Excavating { days_worked, last_day, work_today };
+    }
+}
+
+// Broods in the cavity fledge after a few days, leaving it free for the next tenant
+fn cavity_brood_system(
+    mut snag_query: Query<(Entity, &mut Snag)>,
+    time_state: Res<TimeState>,
+    mut log: ResMut<CavityLog>,
+    mut nesting_events: EventWriter<NestingEvent>,
+) {
+    let today = time_state.absolute_day();
+
+    for (entity, mut snag) in &mut snag_query {
+        let (species, since_day, brood_days) = match snag.stage {
+            CavityStage::Excavated { excavator, since_day } => (excavator, since_day, EXCAVATOR_BROOD_DAYS),
+            CavityStage::Occupied { species, since_day } => (species, since_day, SECONDARY_BROOD_DAYS),
+            _ => continue,
+        };
+        if today < since_day + brood_days {
+            continue;
+        }
+
+        if !is_woodpecker(species) {
+            log.secondary_broods += 1;
+        }
+        snag.broods_raised += 1;
+        snag.stage = CavityStage::Vacant;
+        nesting_events.write(NestingEvent {
+            box_id: entity,
+            species,
+            event_type: NestingEventType::Fledglings(2 + rand::random::<u32>() % 3),
+        });
+    }
+}
+
+// Secondary nesters scout vacant cavities during the breeding season
+fn cavity_prospecting_system(
+    mut commands: Commands,
+    mut bird_query: Query<(Entity, &Bird, &Transform, &BirdState), (With<BirdAI>, Without<CavityProspector>, Without<SnagWorker>)>,
+    mut snag_query: Query<(Entity, &Transform, &mut Snag), Without<BirdAI>>,
+    time_state: Res<TimeState>,
+    time: Res<Time>,
+) {
+    if !matches!(time_state.get_season(), Season::Spring | Season::Summer) {
+        return;
+    }
+
+    for (snag_entity, snag_transform, mut snag) in &mut snag_query {
+        if snag.stage != CavityStage::Vacant || snag.claimed_by.is_some() {
+            continue;
+        }
+        if rand::random::<f32>() > 0.05 * time.delta_secs() {
+            continue;
+        }
+
+        let snag_pos = snag_transform.translation.truncate();
+        let prospector = bird_query.iter_mut()
+            .find(|(_, bird, transform, state)| {
+                is_secondary_cavity_nester(bird.species)
+                    && **state == BirdState::Wandering
+                    && transform.translation.truncate().distance(snag_pos) < PROSPECTING_RANGE
+            })
+            .map(|(entity, ..)| entity);
+        let Some(prospector) = prospector else { continue };
+
+        snag.claimed_by = Some(prospector);
+        commands.entity(prospector).insert(CavityProspector { snag: snag_entity });
+    }
+}
+
+fn cavity_tenant_system(
+    mut commands: Commands,
+    mut bird_query: Query<(Entity, &Bird, &CavityProspector, &mut Transform, &mut BirdState, &mut Blackboard), With<BirdAI>>,
+    mut snag_query: Query<(&Transform, &mut Snag), Without<BirdAI>>,
+    time_state: Res<TimeState>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+    time: Res<Time>,
+) {
+    for (entity, bird, prospector, mut transform, mut state, mut blackboard) in &mut bird_query {
+        let Ok((snag_transform, mut snag)) = snag_query.get_mut(prospector.snag) else {
+            commands.entity(entity).remove::<CavityProspector>();
+            continue;
+        };
+        if snag.stage != CavityStage::Vacant || blackboard.internal.fear > 0.6 {
+            if snag.claimed_by == Some(entity) {
+                snag.claimed_by = None;
+            }
+            commands.entity(entity).remove::<CavityProspector>();
+            *state = BirdState::Wandering;
+            continue;
+        }
+
+        *state = BirdState::MovingToTarget;
+        blackboard.current_target = Some(prospector.snag);
+
+        let entrance = snag_transform.translation.truncate() + Vec2::new(0.0, 30.0);
+        let to_entrance = entrance - transform.translation.truncate();
+        if to_entrance.length() > 6.0 {
+            let step = to_entrance.normalize() * 120.0 * time.delta_secs();
+            transform.translation += step.clamp_length_max(to_entrance.length()).extend(0.0);
+            continue;
+        }
+
+        snag.stage = CavityStage::Occupied { species: bird.species, since_day: time_state.absolute_day() };
+        snag.claimed_by = None;
+        commands.entity(entity).remove::<CavityProspector>();
+        *state = BirdState::Nesting;
+        notification_events.write(ShowNotificationEvent {
+            notification: NotificationType::Info {
+                message: format!("A {:?} pair has moved into the old woodpecker cavity", bird.species),
+            },
+        });
+    }
+}
+
+// Grows the dark entrance hole as excavation progresses
+fn cavity_visual_system(
+    mut commands: Commands,
+    snag_query: Query<(Entity, &Snag, Option<&Children>), Changed<Snag>>,
+    mut hole_query: Query<&mut Sprite, With<CavityHole>>,
+) {
+    for (entity, snag, children) in &snag_query {
+        let size = snag.stage.hole_size();
+        let hole = children.and_then(|children| children.iter().find(|child| hole_query.contains(*child)));
+
+        match hole {
+            Some(hole) => {
+                if let Ok(mut sprite) = hole_query.get_mut(hole) {
+                    sprite.custom_size = Some(Vec2::splat(3.0 + size * 9.0));
+                }
+            }
+            None if size > 0.0 => {
+                commands.entity(entity).with_children(|parent| {
+                    parent.spawn((
+                        Sprite::from_color(Color::srgb(0.12, 0.08, 0.06), Vec2::splat(3.0 + size * 9.0)),
+                        Transform::from_xyz(0.0, 30.0, 0.05),
+                        CavityHole,
+                    ));
+                });
+            }
+            None => {}
+        }
+    }
+}
+
+fn cavity_photo_recognition_system(
+    mut photo_events: EventReader<PhotoTakenEvent>,
+    camera_query: Query<&Transform, With<PhotoTarget>>,
+    bird_query: Query<(&Transform, &BirdState, Option<&SnagWorker>, Option<&CavityProspector>)>,
+    snag_query: Query<(&Transform, &Snag), Without<BirdAI>>,
+    mut research: ResMut<ResearchMissionManager>,
+) {
+    for _event in photo_events.read() {
+        let Ok(camera_transform) = camera_query.single() else {
+            continue;
+        };
+        let camera_pos = camera_transform.translation.truncate();
+
+        for (transform, state, worker, prospector) in &bird_query {
+            if transform.translation.truncate().distance(camera_pos) > 200.0 {
+                continue;
+            }
+            if worker.is_some() && *state == BirdState::Drumming {
+                *research.collected_data.entry(DataType::BehaviorObservations).or_insert(0) += 1;
+            }
+            if prospector.is_some() {
+                *research.collected_data.entry(DataType::HabitatUse).or_insert(0) += 1;
+            }
+        }
+
+        let nest_in_frame = snag_query.iter().any(|(transform, snag)| {
+            matches!(snag.stage, CavityStage::Excavated { .. } | CavityStage::Occupied { .. })
+                && transform.translation.truncate().distance(camera_pos) < 200.0
+        });
+        if nest_in_frame {
+            *research.collected_data.entry(DataType::BreedingBehavior).or_insert(0) += 1;
+        }
+    }
+}
@@ -0,0 +1,411 @@
+// Clip Recorder - Rolling capture buffer for saving notable moments
+use bevy::prelude::*;
+use bevy::asset::RenderAssetUsages;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+use bevy::render::view::screenshot::{Screenshot, ScreenshotCaptured};
+use bevy::tasks::IoTaskPool;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::fs;
+use std::path::{Path, PathBuf};
+use crate::animation::components::AnimatedBird;
+use crate::bird::BirdSpecies;
+use crate::bird_ai::components::BirdState;
+use crate::environment::resources::TimeState;
+use crate::keybindings::{GameAction, KeyBindings};
+use crate::notifications::{resources::ShowNotificationEvent, components::NotificationType};
+use crate::predator_prey::PredatorAttackEvent;
+
+const CLIP_FPS: f32 = 10.0;
+const BUFFER_SECONDS: f32 = 8.0;
+const POST_ROLL_SECONDS: f32 = 2.0;
+const FRAME_WIDTH: u32 = 480;
+const AUTO_CLIP_COOLDOWN_SECONDS: f32 = 90.0;
+
+pub struct ClipRecorderPlugin;
+
+impl Plugin for ClipRecorderPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<ClipRecorder>()
+            .init_resource::<ClipLibrary>()
+            .add_event::<SaveClipEvent>()
+            .add_systems(Startup, load_clip_library_system)
+            .add_systems(Update, (
+                sync_clip_capture_system,
+                clip_frame_capture_system,
+                manual_clip_trigger_system.run_if(crate::debug_console::console_is_not_visible),
+                hawk_strike_clip_trigger_system,
+                rare_species_clip_trigger_system,
+                clip_save_system,
+            ).chain().in_set(crate::GameSet::Presentation).run_if(in_state(crate::AppState::Playing)));
+    }
+}
+
+// Resources
+#[derive(Resource)]
+pub struct ClipRecorder {
+    pub enabled: bool,
+    pub auto_capture: bool,
+    frames: VecDeque<ClipFrame>,
+    capture_timer: Timer,
+    auto_cooldown: Timer,
+    pending: Option<PendingClip>,
+}
+
+impl Default for ClipRecorder {
+    fn default() -> Self {
+        let mut auto_cooldown = Timer::from_seconds(AUTO_CLIP_COOLDOWN_SECONDS, TimerMode::Once);
+        auto_cooldown.tick(auto_cooldown.duration()); // Ready immediately
+
+        Self {
+            enabled: false,
+            auto_capture: true,
+            frames: VecDeque::new(),
+            capture_timer: Timer::from_seconds(1.0 / CLIP_FPS, TimerMode::Repeating),
+            auto_cooldown,
+            pending: None,
+        }
+    }
+}
+
+impl ClipRecorder {
+    fn max_frames() -> usize {
+        (CLIP_FPS * BUFFER_SECONDS) as usize
+    }
+
+    fn push_frame(&mut self, frame: ClipFrame) {
+        self.frames.push_back(frame);
+        while self.frames.len() > Self::max_frames() {
+            self.frames.pop_front();
+        }
+    }
+}
+
+#[derive(Clone)]
+struct ClipFrame {
+    width: u32,
+    height: u32,
+    rgba: Vec<u8>,
+}
+
+struct PendingClip {
+    trigger: ClipTrigger,
+    post_roll: Timer,
+    day: u32,
+}
+
+#[derive(Resource, Default)]
+pub struct ClipLibrary {
+    pub clips: Vec<ClipRecord>,
+}
+
+#[derive(Clone)]
+pub struct ClipRecord {
+    pub title: String,
+    pub day: u32,
+    pub frame_count: usize,
+    pub directory: PathBuf,
+    pub thumbnail: Option<Handle<Image>>,
+}
+
+impl ClipRecord {
+    // Only present when the clip-encoder feature produced a video
+    pub fn video_path(&self) -> Option<PathBuf> {
+        let path = self.directory.join("clip.mp4");
+        path.exists().then_some(path)
+    }
+
+    pub fn duration_secs(&self) -> f32 {
+        self.frame_count as f32 / CLIP_FPS
+    }
+}
+
+// Written next to the frames so clips reappear in the journal on the next launch
+#[derive(Serialize, Deserialize)]
+struct ClipMetadata {
+    title: String,
+    day: u32,
+    frame_count: usize,
+    fps: f32,
+}
+
+// Events
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ClipTrigger {
+    Manual,
+    HawkStrike,
+    RareSpecies(BirdSpecies),
+}
+
+impl ClipTrigger {
+    pub fn title(&self) -> String {
+        match self {
+            Self::Manual => "Saved moment".to_string(),
+            Self::HawkStrike => "Hawk strike".to_string(),
+            Self::RareSpecies(species) => format!("Rare visitor: {:?}", species),
+        }
+    }
+
+    fn is_automatic(&self) -> bool {
+        !matches!(self, Self::Manual)
+    }
+}
+
+#[derive(Event)]
+pub struct SaveClipEvent {
+    pub trigger: ClipTrigger,
+}
+
+pub fn clip_directory() -> PathBuf {
+    dirs::data_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("AvianHaven")
+        .join("clips")
+}
+
+pub fn load_clip_library_system(mut library: ResMut<ClipLibrary>) {
+    let Ok(entries) = fs::read_dir(clip_directory()) else { return };
+
+    let mut directories: Vec<PathBuf> = entries.flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.is_dir())
+        .collect();
+    directories.sort();
+
+    for directory in directories {
+        let Ok(content) = fs::read_to_string(directory.join("clip.ron")) else { continue };
+        let Ok(metadata) = ron::from_str::<ClipMetadata>(&content) else { continue };
+
+        library.clips.push(ClipRecord {
+            title: metadata.title,
+            day: metadata.day,
+            frame_count: metadata.frame_count,
+            directory,
+            thumbnail: None,
+        });
+    }
+
+    info!("🎬 Loaded {} saved clips", library.clips.len());
+}
+
+// Capture follows the setting; turning it off drops the buffered frames
+pub fn sync_clip_capture_system(
+    settings: Res<crate::menu::resources::GameSettings>,
+    mut recorder: ResMut<ClipRecorder>,
+) {
+    if recorder.enabled == settings.clip_capture {
+        return;
+    }
+    recorder.enabled = settings.clip_capture;
+    if !recorder.enabled {
+        recorder.frames.clear();
+        recorder.pending = None;
+    }
+}
+
+// Grabs a downscaled window frame at the clip frame rate
+pub fn clip_frame_capture_system(
+    mut commands: Commands,
+    mut recorder: ResMut<ClipRecorder>,
+    time: Res<Time>,
+) {
+    if !recorder.enabled {
+        return;
+    }
+
+    recorder.auto_cooldown.tick(time.delta());
+    recorder.capture_timer.tick(time.delta());
+    if recorder.capture_timer.just_finished() {
+        commands.spawn(Screenshot::primary_window()).observe(clip_frame_observer);
+    }
+}
+
+fn clip_frame_observer(trigger: Trigger<ScreenshotCaptured>, mut recorder: ResMut<ClipRecorder>) {
+    let Ok(image) = trigger.event().0.clone().try_into_dynamic() else { return };
+
+    // Keep the window aspect ratio at a fixed width
+    let scaled_height = (image.height() * FRAME_WIDTH / image.width().max(1)).max(1);
+    let frame = image.thumbnail_exact(FRAME_WIDTH, scaled_height).to_rgba8();
+
+    recorder.push_frame(ClipFrame {
+        width: frame.width(),
+        height: frame.height(),
+        rgba: frame.into_raw(),
+    });
+}
+
+pub fn manual_clip_trigger_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    key_bindings: Res<KeyBindings>,
+    recorder: Res<ClipRecorder>,
+    mut clip_events: EventWriter<SaveClipEvent>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+) {
+    if !key_bindings.is_action_just_pressed(GameAction::SaveClip, &keyboard, &mouse) {
+        return;
+    }
+    if !recorder.enabled {
+        notification_events.write(ShowNotificationEvent {
+            notification: NotificationType::Info {
+                message: "Turn on Record Clip Buffer in Settings to save clips".to_string(),
+            },
+        });
+        return;
+    }
+    clip_events.write(SaveClipEvent { trigger: ClipTrigger::Manual });
+}
+
+pub fn hawk_strike_clip_trigger_system(
+    mut attack_events: EventReader<PredatorAttackEvent>,
+    mut clip_events: EventWriter<SaveClipEvent>,
+) {
+    if attack_events.read().next().is_some() {
+        clip_events.write(SaveClipEvent { trigger: ClipTrigger::HawkStrike });
+    }
+}
+
+// A rare bird settling down to feed, drink or bathe is worth keeping
+pub fn rare_species_clip_trigger_system(
+    bird_query: Query<(&AnimatedBird, &BirdState), Changed<BirdState>>,
+    mut clip_events: EventWriter<SaveClipEvent>,
+) {
+    for (bird, state) in &bird_query {
+        let landed = matches!(state, BirdState::Eating | BirdState::Drinking | BirdState::Bathing);
+        if landed && bird.species.rarity_tier() >= 3 {
+            clip_events.write(SaveClipEvent { trigger: ClipTrigger::RareSpecies(bird.species) });
+            break;
+        }
+    }
+}
+
+pub fn clip_save_system(
+    mut clip_events: EventReader<SaveClipEvent>,
+    mut recorder: ResMut<ClipRecorder>,
+    mut library: ResMut<ClipLibrary>,
+    mut images: ResMut<Assets<Image>>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+    time_state: Res<TimeState>,
+    time: Res<Time>,
+) {
+    for event in clip_events.read() {
+        if !recorder.enabled || recorder.pending.is_some() {
+            continue;
+        }
+
+        if event.trigger.is_automatic() {
+            if !recorder.auto_capture || !recorder.auto_cooldown.finished() {
+                continue;
+            }
+            recorder.auto_cooldown.reset();
+        }
+
+        // Keep recording a little longer so the clip shows what happened next
+        recorder.pending = Some(PendingClip {
+            trigger: event.trigger,
+            post_roll: Timer::from_seconds(POST_ROLL_SECONDS, TimerMode::Once),
+            day: time_state.day_of_year,
+        });
+    }
+
+    let Some(pending) = recorder.pending.as_mut() else { return };
+    pending.post_roll.tick(time.delta());
+    if !pending.post_roll.finished() {
+        return;
+    }
+
+    let Some(pending) = recorder.pending.take() else { return };
+    let frames: Vec<ClipFrame> = recorder.frames.iter().cloned().collect();
+    if frames.is_empty() {
+        return;
+    }
+
+    let title = pending.trigger.title();
+    let directory = clip_directory().join(chrono::Local::now().format("clip_%Y%m%d_%H%M%S").to_string());
+
+    // Thumbnail from the moment the clip was triggered
+    let trigger_frame = frames.len().saturating_sub((POST_ROLL_SECONDS * CLIP_FPS) as usize + 1);
+    let thumbnail = images.add(frame_to_image(&frames[trigger_frame]));
+
+    library.clips.push(ClipRecord {
+        title: title.clone(),
+        day: pending.day,
+        frame_count: frames.len(),
+        directory: directory.clone(),
+        thumbnail: Some(thumbnail),
+    });
+
+    let metadata = ClipMetadata {
+        title: title.clone(),
+        day: pending.day,
+        frame_count: frames.len(),
+        fps: CLIP_FPS,
+    };
+
+    // Encoding dozens of PNGs is too slow for the main thread
+    IoTaskPool::get().spawn(async move {
+        match write_clip(&directory, &frames, &metadata) {
+            Ok(()) => info!("🎬 Clip saved to {:?}", directory),
+            Err(e) => error!("Failed to save clip to {:?}: {}", directory, e),
+        }
+    }).detach();
+
+    notification_events.write(ShowNotificationEvent {
+        notification: NotificationType::Info {
+            message: format!("Clip saved: {}", title),
+        },
+    });
+}
+
+fn frame_to_image(frame: &ClipFrame) -> Image {
+    Image::new(
+        Extent3d { width: frame.width, height: frame.height, depth_or_array_layers: 1 },
+        TextureDimension::D2,
+        frame.rgba.clone(),
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::default(),
+    )
+}
+
+fn write_clip(directory: &Path, frames: &[ClipFrame], metadata: &ClipMetadata) -> Result<(), Box<dyn std::error::Error>> {
+    fs::create_dir_all(directory)?;
+
+    for (index, frame) in frames.iter().enumerate() {
+        frame_to_image(frame)
+            .try_into_dynamic()?
+            .to_rgb8()
+            .save(directory.join(format!("frame_{:04}.png", index + 1)))?;
+    }
+
+    fs::write(directory.join("clip.ron"), ron::to_string(metadata)?)?;
+
+    encode_video(directory, metadata.fps);
+    Ok(())
+}
+
+// MP4 output shells out to ffmpeg, so it's opt-in via the clip-encoder feature
+#[cfg(feature = "clip-encoder")]
+fn encode_video(directory: &Path, fps: f32) {
+    let status = std::process::Command::new("ffmpeg")
+        .current_dir(directory)
+        .args([
+            "-y", "-loglevel", "error",
+            "-framerate", &fps.to_string(),
+            "-i", "frame_%04d.png",
+            // H.264 needs even dimensions
+            "-vf", "scale=trunc(iw/2)*2:trunc(ih/2)*2",
+            "-c:v", "libx264", "-pix_fmt", "yuv420p",
+            "clip.mp4",
+        ])
+        .status();
+
+    match status {
+        Ok(status) if status.success() => info!("🎬 Encoded clip.mp4 in {:?}", directory),
+        Ok(status) => warn!("ffmpeg exited with {} while encoding {:?}", status, directory),
+        Err(e) => warn!("Could not run ffmpeg to encode clip: {}", e),
+    }
+}
+
+#[cfg(not(feature = "clip-encoder"))]
+fn encode_video(_directory: &Path, _fps: f32) {}
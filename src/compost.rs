@@ -0,0 +1,231 @@
+// Compost - Turning seed hulls and raked-up spills into fertilizer for the flower beds
+//
+// Seed feeders drop hulls as they empty and raking clears spilled seed from under them. With a
+// compost bin in the yard that waste goes on the heap instead of out with the trash, where it
+// breaks down over a few weeks - quickly in summer, hardly at all in winter - into fertilizer.
+// Right-click the bin once a batch is ready to spread it over the flower beds, which recover
+// from pest damage and bloom faster for a couple of weeks. How much waste the sanctuary keeps out
+// of the trash feeds its sustainability score in sanctuary_management.rs.
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use crate::aesthetic_objects::FlowerBed;
+use crate::catalog::components::{PlaceableItemType, PlaceableObject};
+use crate::environment::components::Season;
+use crate::environment::resources::TimeState;
+use crate::feeder::{Feeder, FeederType};
+use crate::garden_pests::PlantHealth;
+use crate::notifications::{resources::ShowNotificationEvent, components::NotificationType};
+use crate::camera::cursor_world_position;
+
+// Share of the seed eaten from a feeder that drops as hulls
+const HULL_SHARE: f32 = 0.3;
+// A heap shrinks as it breaks down, so each unit of waste yields less fertilizer
+const FERTILIZER_YIELD: f32 = 0.5;
+// Fertilizer a bed takes, and how much makes a batch worth spreading
+const FERTILIZER_PER_BED: f32 = 2.0;
+const BATCH_SIZE: f32 = 6.0;
+const FERTILIZED_DAYS: u32 = 14;
+const HEALTH_BOOST: f32 = 0.3;
+const BIN_CLICK_RANGE: f32 = 30.0;
+
+pub struct CompostPlugin;
+
+impl Plugin for CompostPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<Compost>()
+            .add_systems(Update, (compost_bin_system, hull_collection_system, compost_system)
+                .chain()
+                .in_set(crate::GameSet::Simulation)
+                .run_if(in_state(crate::AppState::Playing)))
+            .add_systems(Update, compost_bin_click_system
+                .run_if(crate::debug_console::console_is_not_visible)
+                .in_set(crate::GameSet::Input)
+                .run_if(in_state(crate::AppState::Playing)));
+    }
+}
+
+// Heaps barely work in the cold
+fn daily_breakdown(season: Season) -> f32 {
+    match season {
+        Season::Summer => 0.08,
+        Season::Spring | Season::Fall => 0.05,
+        Season::Winter => 0.01,
+    }
+}
+
+// Resources
+#[derive(Resource, Serialize, Deserialize, Clone, Debug, Default)]
+pub struct Compost {
+    // Hulls and seed still breaking down, and finished fertilizer waiting to be spread
+    pub waste: f32,
+    pub fertilizer: f32,
+    // Lifetime totals: waste put on the heap, waste thrown out with no bin, fertilizer spread
+    pub waste_composted: f32,
+    pub waste_discarded: f32,
+    pub fertilizer_spread: f32,
+    pub batches_spread: u32,
+    pub last_roll_day: u32,
+    #[serde(skip)]
+    pub has_bin: bool,
+}
+
+impl Compost {
+    /// Puts waste on the heap, or in the trash when there's no bin to take it
+    pub fn collect(&mut self, amount: f32) {
+        if self.has_bin {
+            self.waste += amount;
+            self.waste_composted += amount;
+        } else {
+            self.waste_discarded += amount;
+        }
+    }
+
+    /// Share of all the yard's waste that went on the heap, 0-1
+    pub fn diverted_share(&self) -> f32 {
+        let total = self.waste_composted + self.waste_discarded;
+        if total > 0.0 { self.waste_composted / total } else { 0.0 }
+    }
+}
+
+// Components
+/// A flower bed fed with compost grows faster until it wears off
+#[derive(Component)]
+pub struct Fertilized {
+    pub days_left: u32,
+}
+
+pub fn compost_bin_system(
+    mut compost: ResMut<Compost>,
+    object_query: Query<&PlaceableObject>,
+) {
+    let has_bin = object_query.iter().any(|object| object.item_type == PlaceableItemType::CompostBin);
+    if compost.has_bin != has_bin {
+        compost.has_bin = has_bin;
+    }
+}
+
+// Hulls drop as seed feeders empty, whoever ate the seed
+pub fn hull_collection_system(
+    mut compost: ResMut<Compost>,
+    feeder_query: Query<(Entity, &Feeder), Changed<Feeder>>,
+    mut last_levels: Local<HashMap<Entity, f32>>,
+) {
+    for (entity, feeder) in &feeder_query {
+        let previous = last_levels.insert(entity, feeder.current_capacity);
+        if !matches!(feeder.feeder_type, FeederType::Seed | FeederType::Ground) {
+            continue;
+        }
+        let eaten = previous.map_or(0.0, |previous| previous - feeder.current_capacity);
+        if eaten > 0.0 {
+            compost.collect(eaten * HULL_SHARE);
+        }
+    }
+}
+
+// Once a day: the heap breaks down into fertilizer and fed beds use theirs up
+pub fn compost_system(
+    mut commands: Commands,
+    mut compost: ResMut<Compost>,
+    time_state: Res<TimeState>,
+    mut bed_query: Query<(Entity, &mut Fertilized)>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+) {
+    let today = time_state.absolute_day();
+    if compost.last_roll_day == today {
+        return;
+    }
+    compost.last_roll_day = today;
+
+    for (entity, mut fertilized) in &mut bed_query {
+        fertilized.days_left = fertilized.days_left.saturating_sub(1);
+        if fertilized.days_left == 0 {
+            commands.entity(entity).remove::<Fertilized>();
+        }
+    }
+
+    if !compost.has_bin || compost.waste <= 0.0 {
+        return;
+    }
+    let broken_down = compost.waste * daily_breakdown(time_state.get_season());
+    let was_ready = compost.fertilizer >= BATCH_SIZE;
+    compost.waste -= broken_down;
+    compost.fertilizer += broken_down * FERTILIZER_YIELD;
+
+    if !was_ready && compost.fertilizer >= BATCH_SIZE {
+        notification_events.write(ShowNotificationEvent {
+            notification: NotificationType::Info {
+                message: "A batch of compost is ready - right-click the bin to spread it on the flower beds".to_string(),
+            },
+        });
+    }
+}
+
+// Right-click the bin to spread the finished compost, neediest beds first
+#[allow(clippy::too_many_arguments)]
+pub fn compost_bin_click_system(
+    mut commands: Commands,
+    mouse_button: Res<ButtonInput<MouseButton>>,
+    windows: Query<&Window>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<Camera2d>>,
+    bin_query: Query<(&PlaceableObject, &Transform)>,
+    mut bed_query: Query<(Entity, &mut PlantHealth), With<FlowerBed>>,
+    mut compost: ResMut<Compost>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+) {
+    if !mouse_button.just_pressed(MouseButton::Right) {
+        return;
+    }
+    let Ok(window) = windows.single() else { return };
+    let Ok((camera, camera_transform)) = camera_query.single() else { return };
+    let Some(cursor) = cursor_world_position(window, camera, camera_transform) else { return };
+
+    let clicked = bin_query.iter().any(|(object, transform)| {
+        object.item_type == PlaceableItemType::CompostBin
+            && transform.translation.truncate().distance(cursor) <= BIN_CLICK_RANGE
+    });
+    if !clicked {
+        return;
+    }
+
+    if compost.fertilizer < BATCH_SIZE {
+        notification_events.write(ShowNotificationEvent {
+            notification: NotificationType::Info {
+                message: format!(
+                    "The compost is still breaking down - {:.0}% of a batch ready, {:.0} scoops of waste on the heap",
+                    compost.fertilizer / BATCH_SIZE * 100.0, compost.waste,
+                ),
+            },
+        });
+        return;
+    }
+
+    let mut beds: Vec<(Entity, Mut<PlantHealth>)> = bed_query.iter_mut().collect();
+    if beds.is_empty() {
+        notification_events.write(ShowNotificationEvent {
+            notification: NotificationType::Warning {
+                message: "There are no flower beds to spread the compost on".to_string(),
+            },
+        });
+        return;
+    }
+    beds.sort_by(|(_, a), (_, b)| a.0.total_cmp(&b.0));
+
+    let bed_count = beds.len().min((compost.fertilizer / FERTILIZER_PER_BED) as usize);
+    for (entity, health) in beds.iter_mut().take(bed_count) {
+        health.0 = (health.0 + HEALTH_BOOST).min(1.0);
+        commands.entity(*entity).insert(Fertilized { days_left: FERTILIZED_DAYS });
+    }
+    let used = bed_count as f32 * FERTILIZER_PER_BED;
+    compost.fertilizer -= used;
+    compost.fertilizer_spread += used;
+    compost.batches_spread += 1;
+
+    info!("🌱 Spread compost on {} flower beds", bed_count);
+    notification_events.write(ShowNotificationEvent {
+        notification: NotificationType::Info {
+            message: format!("Spread compost on {} flower beds - they'll grow stronger for the next two weeks", bed_count),
+        },
+    });
+}
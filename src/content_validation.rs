@@ -0,0 +1,383 @@
+// Content Validation - Startup check of the game's data files with a "Content Problems" dialog
+//
+// The asset loaders for behavior trees, species packs and the smart object catalog log a parse
+// error and carry on without the file, which is easy to miss. Before any of that, this reads every
+// data file it knows about, built-in and modded, and parses it into the same types the game uses,
+// then checks the names the parsers can't, like behavior results and season keys. Everything
+// found goes into one list with the file, line and what was expected, shown as a dialog over the
+// main menu. Run with `--strict-content` (or AVIANHAVEN_STRICT_CONTENT=1) and the game refuses to
+// start instead, for modders and CI.
+use bevy::prelude::*;
+use serde::de::DeserializeOwned;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use crate::bird_ai::config::{BehaviorCondition, BehaviorTreeConfig};
+use crate::bird_data::{BirdSpeciesConfig, BIRD_DATA_FILES};
+use crate::smart_objects::config::SmartObjectCatalog;
+use crate::species_packs::{self, SpeciesPack};
+
+const STRICT_FLAG: &str = "--strict-content";
+const STRICT_ENV: &str = "AVIANHAVEN_STRICT_CONTENT";
+const SEASON_NAMES: [&str; 4] = ["Spring", "Summer", "Fall", "Winter"];
+pub const BEHAVIOR_TREE_FILE: &str = "data/behavior_tree.ron";
+pub const SMART_OBJECT_CATALOG_FILE: &str = "data/smart_objects/catalog_items.ron";
+const SHOWN_PROBLEMS: usize = 20;
+
+pub struct ContentValidationPlugin;
+
+impl Plugin for ContentValidationPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<ContentProblems>()
+            .add_systems(Startup, validate_content_system)
+            .add_systems(OnEnter(crate::AppState::MainMenu), show_content_problems_system)
+            .add_systems(Update, content_problems_button_system.in_set(crate::GameSet::UI));
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ContentProblem {
+    // Path as the game loads it, e.g. "data/behavior_tree.ron"
+    pub file: String,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub message: String,
+    pub expected: Option<String>,
+}
+
+impl ContentProblem {
+    fn new(file: &str, message: impl Into<String>) -> Self {
+        Self { file: file.to_string(), line: None, column: None, message: message.into(), expected: None }
+    }
+
+    // Points at the first line mentioning the offending value, when there is one
+    fn at(mut self, content: &str, needle: &str) -> Self {
+        self.line = content.lines().position(|line| line.contains(needle)).map(|index| index + 1);
+        self
+    }
+
+    fn expecting(mut self, expected: impl Into<String>) -> Self {
+        self.expected = Some(expected.into());
+        self
+    }
+
+    fn from_ron(file: &str, error: &ron::error::SpannedError) -> Self {
+        use ron::error::Error;
+
+        let in_outer = |outer: &Option<String>| outer.as_ref().map_or(String::new(), |outer| format!(" in `{}`", outer));
+        let one_of = |expected: &[&str]| format!("one of {}", expected.iter().map(|name| format!("`{}`", name)).collect::<Vec<_>>().join(", "));
+        let (message, expected) = match &error.code {
+            Error::MissingStructField { field, outer } => {
+                (format!("Missing field `{}`{}", field, in_outer(outer)), Some(format!("a `{}` field", field)))
+            }
+            Error::NoSuchStructField { expected, found, outer } => {
+                (format!("Unknown field `{}`{}", found, in_outer(outer)), Some(one_of(expected)))
+            }
+            Error::NoSuchEnumVariant { expected, found, outer } => {
+                (format!("Unknown variant `{}`{}", found, in_outer(outer)), Some(one_of(expected)))
+            }
+            Error::DuplicateStructField { field, outer } => {
+                (format!("Field `{}` given twice{}", field, in_outer(outer)), None)
+            }
+            Error::InvalidValueForType { expected, found } => {
+                (format!("Found {}", found), Some(expected.clone()))
+            }
+            other => (other.to_string(), None),
+        };
+
+        Self {
+            file: file.to_string(),
+            line: Some(error.position.line),
+            column: Some(error.position.col),
+            message,
+            expected,
+        }
+    }
+
+    fn location(&self) -> String {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => format!("{}:{}:{}", self.file, line, column),
+            (Some(line), None) => format!("{}:{}", self.file, line),
+            _ => self.file.clone(),
+        }
+    }
+}
+
+// Resources
+#[derive(Resource, Default)]
+pub struct ContentProblems {
+    pub problems: Vec<ContentProblem>,
+    dismissed: bool,
+}
+
+// Components
+#[derive(Component)]
+pub struct ContentProblemsDialog;
+
+#[derive(Component)]
+pub struct ContentProblemsDismissButton;
+
+pub fn strict_mode() -> bool {
+    std::env::args().any(|arg| arg == STRICT_FLAG)
+        || std::env::var(STRICT_ENV).is_ok_and(|value| value != "0")
+}
+
+// Data files are read from the assets folder next to the working directory, like bird_data.rs does
+fn parse_file<T: DeserializeOwned>(problems: &mut Vec<ContentProblem>, file: &str, path: &Path) -> Option<(T, String)> {
+    let content = match std::fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) => {
+            problems.push(ContentProblem::new(file, format!("Could not read the file: {}", e)));
+            return None;
+        }
+    };
+    match ron::de::from_str::<T>(&content) {
+        Ok(parsed) => Some((parsed, content)),
+        Err(e) => {
+            problems.push(ContentProblem::from_ron(file, &e));
+            None
+        }
+    }
+}
+
+fn asset_path(file: &str) -> PathBuf {
+    Path::new("assets").join(file)
+}
+
+fn check_season_keys<'a>(
+    problems: &mut Vec<ContentProblem>,
+    file: &str,
+    content: &str,
+    owner: &str,
+    keys: impl Iterator<Item = (&'a String, &'a f32)>,
+) {
+    for (season, weight) in keys {
+        if !SEASON_NAMES.contains(&season.as_str()) {
+            problems.push(ContentProblem::new(file, format!("{} has an unknown season \"{}\"", owner, season))
+                .at(content, &format!("\"{}\"", season))
+                .expecting(SEASON_NAMES.join(", ")));
+        } else if *weight < 0.0 {
+            problems.push(ContentProblem::new(file, format!("{} has a negative {} weight", owner, season))
+                .at(content, &format!("\"{}\"", season))
+                .expecting("a weight of 0 or more"));
+        }
+    }
+}
+
+// Unknown results and actions fall back to wandering at runtime, so the parser never complains
+fn validate_behavior_tree(problems: &mut Vec<ContentProblem>) {
+    let file = BEHAVIOR_TREE_FILE;
+    let Some((config, content)) = parse_file::<BehaviorTreeConfig>(problems, file, &asset_path(file)) else { return };
+
+    let known_state = |name: &str| format!("{:?}", config.get_bird_state_from_string(name)) == name;
+    let behaviors = std::iter::once(("default_behavior", &config.default_behavior))
+        .chain(config.rules.iter().map(|rule| (rule.name.as_str(), &rule.result)));
+    for (owner, behavior) in behaviors {
+        if !known_state(behavior) {
+            problems.push(ContentProblem::new(file, format!("Rule \"{}\" results in unknown behavior \"{}\"", owner, behavior))
+                .at(&content, &format!("\"{}\"", behavior))
+                .expecting("a bird state such as \"Eating\" or \"Wandering\""));
+        }
+    }
+
+    for rule in &config.rules {
+        for condition in &rule.conditions {
+            let (BehaviorCondition::ActionAvailable { action } | BehaviorCondition::ActionNotAvailable { action }) = condition else { continue };
+            if config.get_bird_action_from_string(action).is_none() {
+                problems.push(ContentProblem::new(file, format!("Rule \"{}\" checks unknown action \"{}\"", rule.name, action))
+                    .at(&content, &format!("\"{}\"", action))
+                    .expecting("a bird action such as \"Eat\" or \"Perch\""));
+            }
+        }
+    }
+}
+
+fn validate_smart_object_catalog(problems: &mut Vec<ContentProblem>) {
+    let file = SMART_OBJECT_CATALOG_FILE;
+    let Some((catalog, content)) = parse_file::<SmartObjectCatalog>(problems, file, &asset_path(file)) else { return };
+
+    let mut ids = HashSet::new();
+    for item in &catalog.items {
+        if !ids.insert(item.id.as_str()) {
+            problems.push(ContentProblem::new(file, format!("Item id \"{}\" is used more than once", item.id))
+                .at(&content, &format!("\"{}\"", item.id))
+                .expecting("a unique id per item"));
+        }
+    }
+}
+
+fn validate_bird_data(problems: &mut Vec<ContentProblem>) {
+    for file in BIRD_DATA_FILES {
+        let Some((config, content)) = parse_file::<BirdSpeciesConfig>(problems, file, &asset_path(file)) else { continue };
+        for bird in &config.species {
+            check_season_keys(problems, file, &content, &bird.name, bird.seasonal_availability.iter());
+        }
+    }
+}
+
+fn validate_species_pack(problems: &mut Vec<ContentProblem>, file: &str, path: &Path) {
+    let Some((pack, content)) = parse_file::<SpeciesPack>(problems, file, path) else { return };
+    for entry in &pack.species {
+        check_season_keys(problems, file, &content, &format!("{:?}", entry.species), entry.seasons.iter());
+    }
+}
+
+pub fn validate_content_system(
+    mut content_problems: ResMut<ContentProblems>,
+    mut exit_events: EventWriter<AppExit>,
+) {
+    let problems = &mut content_problems.problems;
+    validate_behavior_tree(problems);
+    validate_smart_object_catalog(problems);
+    validate_bird_data(problems);
+    for file in species_packs::BUILT_IN_PACKS {
+        validate_species_pack(problems, file, &asset_path(file));
+    }
+    for file_name in species_packs::scan_mod_packs() {
+        let file = format!("{}://{}", species_packs::MOD_PACK_SOURCE, file_name);
+        validate_species_pack(problems, &file, &species_packs::mod_pack_directory().join(&file_name));
+    }
+
+    if problems.is_empty() {
+        info!("Content validation: all data files look good");
+        return;
+    }
+
+    for problem in problems.iter() {
+        let expected = problem.expected.as_ref().map_or(String::new(), |expected| format!(" (expected {})", expected));
+        warn!("Content problem at {}: {}{}", problem.location(), problem.message, expected);
+    }
+
+    if strict_mode() {
+        error!("❌ Strict content mode: refusing to start with {} content problems", problems.len());
+        exit_events.write(AppExit::error());
+    }
+}
+
+pub fn show_content_problems_system(
+    mut commands: Commands,
+    content_problems: Res<ContentProblems>,
+    dialog_query: Query<Entity, With<ContentProblemsDialog>>,
+) {
+    if content_problems.problems.is_empty() || content_problems.dismissed || !dialog_query.is_empty() {
+        return;
+    }
+    let problems = &content_problems.problems;
+
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.5)),
+        GlobalZIndex(100),
+        ContentProblemsDialog,
+        Name::new("ContentProblems"),
+    )).with_children(|overlay| {
+        overlay.spawn((
+            Node {
+                width: Val::Px(640.0),
+                max_height: Val::Percent(80.0),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(16.0)),
+                row_gap: Val::Px(6.0),
+                overflow: Overflow::clip(),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.95, 0.92, 0.88, 0.98)),
+            BorderRadius::all(Val::Px(8.0)),
+        )).with_children(|dialog| {
+            dialog.spawn((
+                Text::new(format!("Content Problems ({})", problems.len())),
+                TextFont { font_size: 20.0, ..default() },
+                TextColor(Color::srgb(0.6, 0.2, 0.1)),
+            ));
+            dialog.spawn((
+                Text::new("Some game data files couldn't be loaded as written. The game skips or falls back on defaults for these, so birds and objects may behave differently than intended."),
+                TextFont { font_size: 12.0, ..default() },
+                TextColor(Color::srgb(0.3, 0.2, 0.1)),
+                Node {
+                    margin: UiRect::bottom(Val::Px(6.0)),
+                    ..default()
+                },
+            ));
+
+            for problem in problems.iter().take(SHOWN_PROBLEMS) {
+                dialog.spawn(Node {
+                    flex_direction: FlexDirection::Column,
+                    ..default()
+                }).with_children(|row| {
+                    row.spawn((
+                        Text::new(problem.location()),
+                        TextFont { font_size: 11.0, ..default() },
+                        TextColor(Color::srgb(0.5, 0.4, 0.3)),
+                    ));
+                    row.spawn((
+                        Text::new(problem.message.clone()),
+                        TextFont { font_size: 13.0, ..default() },
+                        TextColor(Color::srgb(0.2, 0.1, 0.05)),
+                    ));
+                    if let Some(expected) = &problem.expected {
+                        row.spawn((
+                            Text::new(format!("Expected {}", expected)),
+                            TextFont { font_size: 11.0, ..default() },
+                            TextColor(Color::srgb(0.3, 0.45, 0.25)),
+                        ));
+                    }
+                });
+            }
+            if problems.len() > SHOWN_PROBLEMS {
+                dialog.spawn((
+                    Text::new(format!("...and {} more, see the log for the full list", problems.len() - SHOWN_PROBLEMS)),
+                    TextFont { font_size: 12.0, ..default() },
+                    TextColor(Color::srgb(0.5, 0.4, 0.3)),
+                ));
+            }
+
+            dialog.spawn((
+                Button,
+                Node {
+                    align_self: AlignSelf::End,
+                    padding: UiRect::axes(Val::Px(14.0), Val::Px(6.0)),
+                    margin: UiRect::top(Val::Px(8.0)),
+                    ..default()
+                },
+                BackgroundColor(Color::srgb(0.6, 0.5, 0.4)),
+                BorderRadius::all(Val::Px(4.0)),
+                ContentProblemsDismissButton,
+            )).with_children(|button| {
+                button.spawn((
+                    Text::new("Continue anyway"),
+                    TextFont { font_size: 13.0, ..default() },
+                    TextColor(Color::WHITE),
+                ));
+            });
+        });
+    });
+}
+
+type DismissButtonQuery<'w, 's> = Query<'w, 's, (&'static Interaction, &'static mut BackgroundColor), (Changed<Interaction>, With<ContentProblemsDismissButton>)>;
+
+pub fn content_problems_button_system(
+    mut commands: Commands,
+    mut content_problems: ResMut<ContentProblems>,
+    mut interaction_query: DismissButtonQuery,
+    dialog_query: Query<Entity, With<ContentProblemsDialog>>,
+) {
+    for (interaction, mut color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                content_problems.dismissed = true;
+                for dialog in &dialog_query {
+                    commands.entity(dialog).despawn();
+                }
+            }
+            Interaction::Hovered => *color = Color::srgb(0.7, 0.6, 0.5).into(),
+            Interaction::None => *color = Color::srgb(0.6, 0.5, 0.4).into(),
+        }
+    }
+}
@@ -0,0 +1,667 @@
+// Corvids - Cache memory, peanut training and gifts from crows and jays
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use crate::bird::{Bird, BirdSpecies};
+use crate::bird_ai::components::{BirdAI, BirdState, Blackboard, CacheData, CacheSpot, ProvidesUtility};
+use crate::catalog::components::{PlaceableObject, PlaceableItemType};
+use crate::catalog::resources::PlayerInventory;
+use crate::environment::resources::TimeState;
+use crate::notifications::{resources::ShowNotificationEvent, components::NotificationType};
+use crate::difficulty::DifficultySettings;
+use crate::camera::cursor_world_position;
+
+pub struct CorvidsPlugin;
+
+impl Plugin for CorvidsPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<CorvidMemory>()
+            .init_resource::<CorvidPanelState>()
+            .add_event::<CacheEvent>()
+            .add_systems(Update, (
+                attach_peanut_tray_system,
+                remember_caches_system,
+                recall_caches_system,
+                cache_visit_start_system,
+                cache_visit_system,
+                peanut_visit_system,
+                daily_trust_system,
+                gift_drop_system,
+                gift_pickup_system,
+            ).chain()
+                // Runs after the behavior tree so cache visits are not overwritten each tick
+                .after(crate::bird_ai::systems::behavior_tree_system)
+                .in_set(crate::GameSet::AI)
+                .run_if(in_state(crate::AppState::Playing)))
+            .add_systems(OnEnter(crate::AppState::Playing), setup_corvid_panel)
+            .add_systems(OnExit(crate::AppState::Playing), cleanup_corvid_panel)
+            .add_systems(Update, (
+                corvid_panel_button_system,
+                refresh_corvid_panel_system,
+            ).chain().in_set(crate::GameSet::UI).run_if(in_state(crate::AppState::Playing)));
+    }
+}
+
+const RAID_CHANCE: f32 = 0.35;
+const RAID_RANGE: f32 = 300.0;
+const GIFT_TRUST: f32 = 0.5;
+const PEANUT_COST: f32 = 5.0;
+
+pub fn is_corvid(species: BirdSpecies) -> bool {
+    matches!(species, BirdSpecies::BlueJay | BirdSpecies::CommonCrow)
+}
+
+// Resources
+
+// Saved with the sanctuary so returning corvids know where they left their food and who feeds them
+#[derive(Resource, Serialize, Deserialize, Clone, Debug, Default)]
+pub struct CorvidMemory {
+    pub caches: Vec<RememberedCache>,
+    // 0.0 wary to 1.0 fully habituated, built by consistent peanut offerings
+    pub trust: HashMap<BirdSpecies, f32>,
+    pub offering_streak: u32,
+    pub last_visit_day: u32,
+    pub last_gift_day: u32,
+    pub gifts: Vec<CorvidGift>,
+    pub raids_observed: u32,
+}
+
+impl CorvidMemory {
+    pub fn trust(&self, species: BirdSpecies) -> f32 {
+        self.trust.get(&species).copied().unwrap_or(0.0)
+    }
+
+    fn forget_cache(&mut self, location: Vec2) -> Option<RememberedCache> {
+        let index = self.caches.iter().position(|cache| cache.position().distance(location) < 5.0)?;
+        Some(self.caches.remove(index))
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct RememberedCache {
+    pub owner: BirdSpecies,
+    pub location: [f32; 2],
+    pub food_amount: f32,
+    pub cached_day: u32,
+}
+
+impl RememberedCache {
+    pub fn position(&self) -> Vec2 {
+        Vec2::from(self.location)
+    }
+}
+
+#[derive(Resource)]
+pub struct CorvidPanelState {
+    refresh_timer: Timer,
+    dirty: bool,
+}
+
+impl Default for CorvidPanelState {
+    fn default() -> Self {
+        Self {
+            refresh_timer: Timer::from_seconds(2.0, TimerMode::Repeating),
+            dirty: true,
+        }
+    }
+}
+
+// Trinkets left near the peanut tray by trusting corvids, collected as a set
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CorvidGift {
+    ShinyPebble,
+    BottleCap,
+    PaperClip,
+    SeaGlass,
+    BlueBead,
+    BrassButton,
+    LostEarring,
+    SilverKey,
+}
+
+impl CorvidGift {
+    pub const ALL: [CorvidGift; 8] = [
+        Self::ShinyPebble, Self::BottleCap, Self::PaperClip, Self::SeaGlass,
+        Self::BlueBead, Self::BrassButton, Self::LostEarring, Self::SilverKey,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::ShinyPebble => "Shiny Pebble",
+            Self::BottleCap => "Bottle Cap",
+            Self::PaperClip => "Paper Clip",
+            Self::SeaGlass => "Sea Glass",
+            Self::BlueBead => "Blue Bead",
+            Self::BrassButton => "Brass Button",
+            Self::LostEarring => "Lost Earring",
+            Self::SilverKey => "Silver Key",
+        }
+    }
+
+    // Rarer trinkets only turn up once a corvid trusts you deeply
+    fn required_trust(&self) -> f32 {
+        match self {
+            Self::ShinyPebble | Self::BottleCap | Self::PaperClip => GIFT_TRUST,
+            Self::SeaGlass | Self::BlueBead | Self::BrassButton => 0.7,
+            Self::LostEarring | Self::SilverKey => 0.9,
+        }
+    }
+
+    fn color(&self) -> Color {
+        match self {
+            Self::ShinyPebble => Color::srgb(0.7, 0.7, 0.72),
+            Self::BottleCap => Color::srgb(0.8, 0.2, 0.15),
+            Self::PaperClip => Color::srgb(0.75, 0.78, 0.8),
+            Self::SeaGlass => Color::srgb(0.5, 0.8, 0.65),
+            Self::BlueBead => Color::srgb(0.2, 0.35, 0.85),
+            Self::BrassButton => Color::srgb(0.8, 0.65, 0.25),
+            Self::LostEarring => Color::srgb(0.95, 0.85, 0.4),
+            Self::SilverKey => Color::srgb(0.85, 0.87, 0.9),
+        }
+    }
+}
+
+// Components
+#[derive(Component)]
+pub struct PeanutTray {
+    pub stocked: bool,
+}
+
+#[derive(Component)]
+pub struct CacheVisit {
+    pub location: Vec2,
+    pub owner: BirdSpecies,
+    pub dig_timer: Timer,
+}
+
+#[derive(Component)]
+pub struct GiftItem {
+    pub gift: CorvidGift,
+    pub giver: BirdSpecies,
+}
+
+#[derive(Component)]
+pub struct CorvidPanel;
+
+#[derive(Component)]
+pub struct CorvidPanelText;
+
+#[derive(Component)]
+pub struct OfferPeanutsButton;
+
+// Events
+#[derive(Event)]
+pub struct CacheEvent {
+    pub bird: Entity,
+    pub species: BirdSpecies,
+    pub location: Vec2,
+    pub food_amount: f32,
+    pub kind: CacheEventKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CacheEventKind {
+    Stored,
+    Retrieved,
+}
+
+fn attach_peanut_tray_system(
+    mut commands: Commands,
+    object_query: Query<(Entity, &PlaceableObject), (Added<PlaceableObject>, Without<PeanutTray>)>,
+    mut panel_state: ResMut<CorvidPanelState>,
+) {
+    for (entity, object) in &object_query {
+        if object.item_type == PlaceableItemType::PeanutTray {
+            commands.entity(entity).insert(PeanutTray { stocked: false });
+            panel_state.dirty = true;
+        }
+    }
+}
+
+fn remember_caches_system(
+    mut cache_events: EventReader<CacheEvent>,
+    mut memory: ResMut<CorvidMemory>,
+    time_state: Res<TimeState>,
+) {
+    for event in cache_events.read() {
+        if !is_corvid(event.species) {
+            continue;
+        }
+
+        match event.kind {
+            CacheEventKind::Stored => memory.caches.push(RememberedCache {
+                owner: event.species,
+                location: event.location.to_array(),
+                food_amount: event.food_amount,
+                cached_day: time_state.absolute_day(),
+            }),
+            CacheEventKind::Retrieved => {
+                memory.forget_cache(event.location);
+            }
+        }
+    }
+}
+
+// Newly arrived corvids pick up where their species left off
+fn recall_caches_system(
+    mut bird_query: Query<(&Bird, &mut CacheData), Added<CacheData>>,
+    memory: Res<CorvidMemory>,
+    time: Res<Time>,
+) {
+    for (bird, mut cache_data) in &mut bird_query {
+        if !is_corvid(bird.species) {
+            continue;
+        }
+
+        let capacity = cache_data.max_cache_capacity as usize;
+        cache_data.cached_locations = memory.caches.iter()
+            .filter(|cache| cache.owner == bird.species)
+            .take(capacity)
+            .map(|cache| CacheSpot {
+                location: cache.position(),
+                food_amount: cache.food_amount,
+                cache_time: time.elapsed().as_secs_f64(),
+                decay_rate: 0.01,
+            })
+            .collect();
+        cache_data.current_cache_count = cache_data.cached_locations.len() as u32;
+    }
+}
+
+// Hungry corvids fly back to a remembered cache, or raid one they saw another bird make
+fn cache_visit_start_system(
+    mut commands: Commands,
+    mut bird_query: Query<(Entity, &Bird, &Transform, &mut BirdState, &mut Blackboard), (With<BirdAI>, Without<CacheVisit>)>,
+    cache_query: Query<(&Bird, &CacheData)>,
+    memory: Res<CorvidMemory>,
+    time: Res<Time>,
+) {
+    for (entity, bird, transform, mut state, mut blackboard) in &mut bird_query {
+        if !is_corvid(bird.species) || *state != BirdState::Wandering || blackboard.internal.hunger < 0.55 {
+            continue;
+        }
+        if rand::random::<f32>() > 0.2 * time.delta_secs() {
+            continue;
+        }
+
+        let bird_pos = transform.translation.truncate();
+        let nearest = |caches: &mut dyn Iterator<Item = (BirdSpecies, Vec2)>| {
+            caches.min_by(|a, b| a.1.distance(bird_pos).total_cmp(&b.1.distance(bird_pos)))
+        };
+
+        let own = nearest(&mut memory.caches.iter()
+            .filter(|cache| cache.owner == bird.species)
+            .map(|cache| (cache.owner, cache.position())));
+        let raid = nearest(&mut memory.caches.iter()
+            .filter(|cache| cache.owner != bird.species)
+            .map(|cache| (cache.owner, cache.position()))
+            .chain(cache_query.iter()
+                .filter(|(other, _)| !is_corvid(other.species))
+                .flat_map(|(other, data)| data.cached_locations.iter().map(move |spot| (other.species, spot.location))))
+            .filter(|(_, location)| location.distance(bird_pos) < RAID_RANGE));
+
+        let target = match (own, raid) {
+            (_, Some(raid)) if rand::random::<f32>() < RAID_CHANCE => Some(raid),
+            (Some(own), _) => Some(own),
+            (None, raid) => raid,
+        };
+        let Some((owner, location)) = target else { continue };
+
+        commands.entity(entity).insert(CacheVisit {
+            location,
+            owner,
+            dig_timer: Timer::from_seconds(1.5, TimerMode::Once),
+        });
+        *state = BirdState::Retrieving;
+        blackboard.current_target = None;
+    }
+}
+
+fn cache_visit_system(
+    mut commands: Commands,
+    mut bird_query: Query<(Entity, &Bird, &mut CacheVisit, &mut Transform, &mut BirdState, &mut Blackboard), With<BirdAI>>,
+    mut cache_query: Query<(&Bird, &mut CacheData), Without<CacheVisit>>,
+    mut memory: ResMut<CorvidMemory>,
+    time: Res<Time>,
+) {
+    for (entity, bird, mut visit, mut transform, mut state, mut blackboard) in &mut bird_query {
+        *state = BirdState::Retrieving;
+        blackboard.current_target = None;
+
+        let to_cache = visit.location - transform.translation.truncate();
+        if to_cache.length() > 5.0 {
+            let step = to_cache.normalize() * 140.0 * time.delta_secs();
+            transform.translation += step.clamp_length_max(to_cache.length()).extend(0.0);
+            continue;
+        }
+
+        // Probing the ground with the bill
+        visit.dig_timer.tick(time.delta());
+        transform.rotation = Quat::from_rotation_z(-0.35 + (time.elapsed_secs() * 12.0).sin() * 0.15);
+        if !visit.dig_timer.finished() {
+            continue;
+        }
+
+        let mut food = memory.forget_cache(visit.location).map(|cache| cache.food_amount);
+        for (other, mut cache_data) in &mut cache_query {
+            if other.species != visit.owner {
+                continue;
+            }
+            if let Some(index) = cache_data.cached_locations.iter().position(|spot| spot.location.distance(visit.location) < 5.0) {
+                let spot = cache_data.cached_locations.remove(index);
+                cache_data.current_cache_count = cache_data.current_cache_count.saturating_sub(1);
+                food.get_or_insert(spot.food_amount);
+                break;
+            }
+        }
+
+        if let Some(food) = food {
+            blackboard.internal.hunger = (blackboard.internal.hunger - food * 0.5).max(0.0);
+            if visit.owner != bird.species {
+                memory.raids_observed += 1;
+                info!("{:?} raided a {:?} cache", bird.species, visit.owner);
+            }
+        }
+
+        transform.rotation = Quat::IDENTITY;
+        commands.entity(entity).remove::<CacheVisit>();
+        *state = BirdState::Wandering;
+    }
+}
+
+// A corvid emptying the tray counts toward the daily offering streak; some peanuts get cached
+fn peanut_visit_system(
+    mut bird_query: Query<(&Bird, &mut BirdState, &mut Blackboard), With<BirdAI>>,
+    mut tray_query: Query<(&mut PeanutTray, &mut ProvidesUtility)>,
+    mut memory: ResMut<CorvidMemory>,
+    mut panel_state: ResMut<CorvidPanelState>,
+    time_state: Res<TimeState>,
+) {
+    let today = time_state.absolute_day();
+
+    for (bird, mut state, mut blackboard) in &mut bird_query {
+        if !is_corvid(bird.species) || *state != BirdState::Eating {
+            continue;
+        }
+        let Some(target) = blackboard.current_target else { continue };
+        let Ok((mut tray, mut utility)) = tray_query.get_mut(target) else { continue };
+        if !tray.stocked {
+            continue;
+        }
+
+        tray.stocked = false;
+        utility.base_utility = 0.0;
+        panel_state.dirty = true;
+
+        if memory.last_visit_day != today {
+            memory.offering_streak = if memory.last_visit_day + 1 == today { memory.offering_streak + 1 } else { 1 };
+            memory.last_visit_day = today;
+
+            let gain = 0.04 + 0.02 * memory.offering_streak.min(7) as f32;
+            let trust = memory.trust.entry(bird.species).or_insert(0.0);
+            *trust = (*trust + gain).min(1.0);
+        }
+
+        blackboard.internal.hunger = (blackboard.internal.hunger - 0.4).max(0.0);
+        if blackboard.internal.hunger < 0.3 {
+            // Carry the rest off to hide
+            *state = BirdState::Caching;
+            blackboard.current_target = None;
+        }
+    }
+}
+
+// Trust fades if the tray goes unvisited for a few days
+fn daily_trust_system(
+    time_state: Res<TimeState>,
+    mut memory: ResMut<CorvidMemory>,
+    mut last_checked: Local<u32>,
+) {
+    let today = time_state.absolute_day();
+    if *last_checked == today {
+        return;
+    }
+    *last_checked = today;
+
+    if memory.last_visit_day == 0 || today <= memory.last_visit_day + 2 {
+        return;
+    }
+    memory.offering_streak = 0;
+    for trust in memory.trust.values_mut() {
+        *trust = (*trust - 0.05).max(0.0);
+    }
+}
+
+// At dawn a trusting corvid may leave a trinket beside the tray
+fn gift_drop_system(
+    mut commands: Commands,
+    tray_query: Query<&Transform, With<PeanutTray>>,
+    gift_query: Query<(), With<GiftItem>>,
+    time_state: Res<TimeState>,
+    mut memory: ResMut<CorvidMemory>,
+) {
+    let today = time_state.absolute_day();
+    if !(6.0..8.0).contains(&time_state.hour) || memory.last_gift_day == today || !gift_query.is_empty() {
+        return;
+    }
+    memory.last_gift_day = today;
+
+    let Some((giver, trust)) = memory.trust.iter()
+        .map(|(species, trust)| (*species, *trust))
+        .max_by(|a, b| a.1.total_cmp(&b.1)) else { return };
+    if trust < GIFT_TRUST || rand::random::<f32>() > trust * 0.6 {
+        return;
+    }
+    let Some(tray_transform) = tray_query.iter().next() else { return };
+
+    // Favor trinkets not yet in the collection
+    let eligible: Vec<CorvidGift> = CorvidGift::ALL.into_iter()
+        .filter(|gift| gift.required_trust() <= trust)
+        .collect();
+    let missing: Vec<CorvidGift> = eligible.iter().copied().filter(|gift| !memory.gifts.contains(gift)).collect();
+    let pool = if !missing.is_empty() && rand::random::<f32>() < 0.75 { missing } else { eligible };
+    let gift = pool[rand::random::<u32>() as usize % pool.len()];
+
+    let offset = Vec2::new(rand::random::<f32>() - 0.5, -rand::random::<f32>()) * 40.0;
+    commands.spawn((
+        Sprite::from_color(gift.color(), Vec2::splat(7.0)),
+        Transform::from_translation(tray_transform.translation + offset.extend(0.1)),
+        GiftItem { gift, giver },
+        Name::new("Corvid Gift"),
+    ));
+}
+
+// Right-click a trinket to pick it up
+fn gift_pickup_system(
+    mut commands: Commands,
+    mouse_button: Res<ButtonInput<MouseButton>>,
+    windows: Query<&Window>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<Camera2d>>,
+    gift_query: Query<(Entity, &GiftItem, &Transform)>,
+    mut memory: ResMut<CorvidMemory>,
+    mut inventory: ResMut<PlayerInventory>,
+    mut panel_state: ResMut<CorvidPanelState>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+) {
+    if !mouse_button.just_pressed(MouseButton::Right) {
+        return;
+    }
+
+    let Ok(window) = windows.single() else { return };
+    let Ok((camera, camera_transform)) = camera_query.single() else { return };
+    let Some(world_position) = cursor_world_position(window, camera, camera_transform) else { return };
+
+    let Some((entity, item, _)) = gift_query.iter()
+        .find(|(_, _, transform)| transform.translation.truncate().distance(world_position) < 20.0) else { return };
+
+    commands.entity(entity).despawn();
+    panel_state.dirty = true;
+
+    if memory.gifts.contains(&item.gift) {
+        inventory.currency += 10;
+        notification_events.write(ShowNotificationEvent {
+            notification: NotificationType::Currency {
+                amount: 10,
+                reason: format!("Another {} from a {:?}", item.gift.name(), item.giver),
+            },
+        });
+        return;
+    }
+
+    memory.gifts.push(item.gift);
+    if memory.gifts.len() == CorvidGift::ALL.len() {
+        inventory.currency += 150;
+        notification_events.write(ShowNotificationEvent {
+            notification: NotificationType::Achievement {
+                title: "Corvid Confidant".to_string(),
+                description: "Collected every gift the crows and jays could find".to_string(),
+                currency_reward: 150,
+            },
+        });
+    } else {
+        notification_events.write(ShowNotificationEvent {
+            notification: NotificationType::Info {
+                message: format!(
+                    "A {:?} left you a {} ({}/{})",
+                    item.giver, item.gift.name(), memory.gifts.len(), CorvidGift::ALL.len()
+                ),
+            },
+        });
+    }
+}
+
+fn setup_corvid_panel(mut commands: Commands, mut panel_state: ResMut<CorvidPanelState>) {
+    panel_state.dirty = true;
+
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(290.0),
+            bottom: Val::Px(20.0),
+            width: Val::Px(220.0),
+            flex_direction: FlexDirection::Column,
+            padding: UiRect::all(Val::Px(8.0)),
+            row_gap: Val::Px(4.0),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.95, 0.92, 0.88, 0.9)),
+        BorderRadius::all(Val::Px(6.0)),
+        Visibility::Hidden,
+        CorvidPanel,
+        Name::new("CorvidPanel"),
+    )).with_children(|panel| {
+        panel.spawn((
+            Text::new("Corvid Notes"),
+            TextFont { font_size: 13.0, ..default() },
+            TextColor(Color::srgb(0.3, 0.2, 0.1)),
+        ));
+        panel.spawn((
+            Text::new(""),
+            TextFont { font_size: 11.0, ..default() },
+            TextColor(Color::srgb(0.4, 0.3, 0.2)),
+            CorvidPanelText,
+        ));
+        panel.spawn((
+            Button,
+            Node {
+                padding: UiRect::axes(Val::Px(6.0), Val::Px(3.0)),
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.6, 0.5, 0.4)),
+            BorderRadius::all(Val::Px(3.0)),
+            OfferPeanutsButton,
+        )).with_children(|button| {
+            button.spawn((
+                Text::new("Offer Peanuts"),
+                TextFont { font_size: 11.0, ..default() },
+                TextColor(Color::WHITE),
+            ));
+        });
+    });
+}
+
+fn cleanup_corvid_panel(
+    mut commands: Commands,
+    panel_query: Query<Entity, With<CorvidPanel>>,
+    gift_query: Query<Entity, With<GiftItem>>,
+) {
+    for entity in panel_query.iter().chain(gift_query.iter()) {
+        commands.entity(entity).despawn();
+    }
+}
+
+fn corvid_panel_button_system(
+    mut button_query: Query<(&Interaction, &mut BackgroundColor), (Changed<Interaction>, With<OfferPeanutsButton>)>,
+    mut tray_query: Query<(&mut PeanutTray, &mut ProvidesUtility)>,
+    mut inventory: ResMut<PlayerInventory>,
+    difficulty: Res<DifficultySettings>,
+    mut panel_state: ResMut<CorvidPanelState>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+) {
+    for (interaction, mut color) in &mut button_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *color = BackgroundColor(Color::srgb(0.5, 0.7, 0.5));
+
+                let empty: Vec<_> = tray_query.iter_mut().filter(|(tray, _)| !tray.stocked).collect();
+                if empty.is_empty() {
+                    continue;
+                }
+                let cost = (PEANUT_COST * difficulty.seed_cost_multiplier()).round() as u32 * empty.len() as u32;
+                if inventory.currency < cost {
+                    notification_events.write(ShowNotificationEvent {
+                        notification: NotificationType::Warning {
+                            message: format!("Peanuts cost {} coins", cost),
+                        },
+                    });
+                    continue;
+                }
+
+                inventory.currency -= cost;
+                for (mut tray, mut utility) in empty {
+                    tray.stocked = true;
+                    utility.base_utility = PlaceableItemType::PeanutTray.base_utility();
+                }
+                panel_state.dirty = true;
+            }
+            Interaction::Hovered => *color = BackgroundColor(Color::srgb(0.7, 0.6, 0.5)),
+            Interaction::None => *color = BackgroundColor(Color::srgb(0.6, 0.5, 0.4)),
+        }
+    }
+}
+
+fn refresh_corvid_panel_system(
+    mut panel_state: ResMut<CorvidPanelState>,
+    memory: Res<CorvidMemory>,
+    tray_query: Query<&PeanutTray>,
+    mut panel_query: Query<&mut Visibility, With<CorvidPanel>>,
+    mut text_query: Query<&mut Text, With<CorvidPanelText>>,
+    time: Res<Time>,
+) {
+    panel_state.refresh_timer.tick(time.delta());
+    if !panel_state.dirty && !panel_state.refresh_timer.just_finished() {
+        return;
+    }
+    panel_state.dirty = false;
+
+    for mut visibility in &mut panel_query {
+        *visibility = if tray_query.is_empty() { Visibility::Hidden } else { Visibility::Inherited };
+    }
+
+    let stocked = tray_query.iter().filter(|tray| tray.stocked).count();
+    let mut lines = vec![
+        format!("Trays stocked: {}/{}", stocked, tray_query.iter().count()),
+        format!("Offering streak: {} days", memory.offering_streak),
+    ];
+    for species in [BirdSpecies::BlueJay, BirdSpecies::CommonCrow] {
+        lines.push(format!("{:?} trust: {:.0}%", species, memory.trust(species) * 100.0));
+    }
+    lines.push(format!("Caches remembered: {}", memory.caches.len()));
+    lines.push(format!("Gifts: {}/{}", memory.gifts.len(), CorvidGift::ALL.len()));
+
+    for mut text in &mut text_query {
+        text.0 = lines.join("\n");
+    }
+}
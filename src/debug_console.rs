@@ -16,7 +16,11 @@ impl Plugin for DebugConsolePlugin {
                 console_command_processor,
                 console_ui_update_system.run_if(console_is_visible),
                 console_message_display_system.run_if(console_is_visible),
-            ))
+            ).in_set(crate::GameSet::Input))
+            .add_systems(Update, (
+                provide_console_search_entries.in_set(crate::search_palette::SearchProviders),
+                console_search_jump_system,
+            ).in_set(crate::GameSet::UI))
             .add_systems(Startup, setup_console_ui);
     }
 }
@@ -31,6 +35,26 @@ pub fn console_is_not_visible(console_state: Res<ConsoleState>) -> bool {
     !console_state.visible
 }
 
+// Usage and description of every command, listed by help and offered in the search palette
+const COMMANDS: [(&str, &str); 16] = [
+    ("test run <scenario>", "Run test scenario (population_stress, seasonal_cycle)"),
+    ("test stop", "Stop current test"),
+    ("test list", "List available test scenarios"),
+    ("test status", "Show current test status"),
+    ("time <multiplier>", "Set time acceleration (1-100)"),
+    ("spawn <species> <count>", "Spawn birds (robin, cardinal, bluejay)"),
+    ("population", "Show current bird population"),
+    ("benchmark [stop]", "Profile AI and particles at 100/500/1000 birds in a storm"),
+    ("spawns", "Toggle the spawn weight breakdown (dev builds)"),
+    ("entities", "Toggle the entity count and archetype dashboard (dev builds)"),
+    ("scripts", "Toggle the Lua script health panel"),
+    ("experiment [start [days] | cancel]", "Compare the yard before and after a layout change"),
+    ("log [module] <level> | reset", "Set the log level, for one module or all, saved for next launch"),
+    ("logs [text]", "Toggle the log viewer, optionally showing only lines containing text"),
+    ("clear", "Clear console"),
+    ("help", "Show this help"),
+];
+
 #[derive(Resource)]
 pub struct ConsoleState {
     pub visible: bool,
@@ -219,6 +243,36 @@ pub fn console_toggle_system(
     }
 }
 
+pub fn provide_console_search_entries(mut index: ResMut<crate::search_palette::SearchIndex>) {
+    for (usage, description) in COMMANDS {
+        index.add("Console command", usage, description, crate::search_palette::SearchTarget::ConsoleCommand(usage));
+    }
+}
+
+// Opens the console with the command typed in, stopping short of any arguments it needs
+pub fn console_search_jump_system(
+    mut jump_events: EventReader<crate::search_palette::SearchJumpEvent>,
+    mut console_state: ResMut<ConsoleState>,
+    mut console_query: Query<&mut Node, With<ConsoleRootUI>>,
+    mut input_query: Query<&mut ConsoleTextInput>,
+) {
+    for event in jump_events.read() {
+        let crate::search_palette::SearchTarget::ConsoleCommand(usage) = event.target else { continue };
+        console_state.visible = true;
+        if let Ok(mut console_node) = console_query.single_mut() {
+            console_node.display = Display::Flex;
+        }
+        if let Ok(mut input) = input_query.single_mut() {
+            input.current_text = match usage.find('<') {
+                Some(arguments) => usage[..arguments].to_string(),
+                None => usage.to_string(),
+            };
+            input.cursor_position = input.current_text.len();
+            input.focused = true;
+        }
+    }
+}
+
 pub fn console_text_input_system(
     console_state: Res<ConsoleState>,
     mut console_history: ResMut<ConsoleHistory>,
@@ -360,21 +414,19 @@ pub fn console_command_processor(
     mut acceleration: ResMut<crate::automated_testing::TimeAcceleration>,
     testing_state: Res<crate::automated_testing::TestingState>,
     bird_query: Query<&crate::bird::Bird>,
+    mut benchmark_events: EventWriter<crate::performance::benchmark::BenchmarkEvent>,
+    benchmark: Res<crate::performance::benchmark::BenchmarkRun>,
+    mut spawn_debug: Option<ResMut<crate::spawn_debug::SpawnDebugPanel>>,
+    mut entity_dashboard: Option<ResMut<crate::performance::dashboard::EntityDashboard>>,
     mut commands: Commands,
 ) {
     for command in command_events.read() {
         match command.command.as_str() {
             "help" => {
                 add_console_message(&mut console_history, "Available commands:".to_string(), MessageType::Info);
-                add_console_message(&mut console_history, "  test run <scenario>  - Run test scenario (population_stress, seasonal_cycle)".to_string(), MessageType::Info);
-                add_console_message(&mut console_history, "  test stop           - Stop current test".to_string(), MessageType::Info);
-                add_console_message(&mut console_history, "  test list           - List available test scenarios".to_string(), MessageType::Info);
-                add_console_message(&mut console_history, "  test status         - Show current test status".to_string(), MessageType::Info);
-                add_console_message(&mut console_history, "  time <multiplier>   - Set time acceleration (1-100)".to_string(), MessageType::Info);
-                add_console_message(&mut console_history, "  spawn <species> <count> - Spawn birds (robin, cardinal, bluejay)".to_string(), MessageType::Info);
-                add_console_message(&mut console_history, "  population          - Show current bird population".to_string(), MessageType::Info);
-                add_console_message(&mut console_history, "  clear              - Clear console".to_string(), MessageType::Info);
-                add_console_message(&mut console_history, "  help               - Show this help".to_string(), MessageType::Info);
+                for (usage, description) in COMMANDS {
+                    add_console_message(&mut console_history, format!("  {:<23} - {}", usage, description), MessageType::Info);
+                }
             },
             "test" => {
                 if command.args.is_empty() {
@@ -469,6 +521,64 @@ pub fn console_command_processor(
                     }
                 }
             },
+            "benchmark" => {
+                use crate::performance::benchmark::BenchmarkEvent;
+                match command.args.first().map(String::as_str) {
+                    None if benchmark.active => {
+                        add_console_message(&mut console_history, "Benchmark already running. Use 'benchmark stop' to end it".to_string(), MessageType::Warning);
+                    },
+                    None => {
+                        benchmark_events.write(BenchmarkEvent::Start);
+                        add_console_message(&mut console_history, "Starting benchmark: 100, 500 and 1000 birds in a storm (~21s). Results go to the log".to_string(), MessageType::Success);
+                    },
+                    Some("stop") => {
+                        benchmark_events.write(BenchmarkEvent::Stop);
+                        add_console_message(&mut console_history, "Stopping benchmark".to_string(), MessageType::Info);
+                    },
+                    Some(other) => {
+                        add_console_message(&mut console_history, format!("Unknown benchmark command: {}. Usage: benchmark [stop]", other), MessageType::Error);
+                    },
+                }
+            },
+            "spawns" => {
+                match spawn_debug.as_mut() {
+                    Some(panel) => {
+                        panel.toggle();
+                        add_console_message(&mut console_history, format!("Spawn debug panel {}", if panel.is_open { "opened" } else { "closed" }), MessageType::Success);
+                    },
+                    None => {
+                        add_console_message(&mut console_history, "The spawn debug panel is only available in dev builds".to_string(), MessageType::Warning);
+                    },
+                }
+            },
+            "entities" => {
+                match entity_dashboard.as_mut() {
+                    Some(dashboard) => {
+                        dashboard.toggle();
+                        add_console_message(&mut console_history, format!("Entity dashboard {}", if dashboard.is_open { "opened" } else { "closed" }), MessageType::Success);
+                    },
+                    None => {
+                        add_console_message(&mut console_history, "The entity dashboard is only available in dev builds".to_string(), MessageType::Warning);
+                    },
+                }
+            },
+            "scripts" => {
+                // The scripting plugin's health panel reads the command itself
+                add_console_message(&mut console_history, "Toggling the script health panel".to_string(), MessageType::Info);
+            },
+            "experiment" => {
+                // Handled by the experiments plugin
+                let (message, message_type) = match command.args.first().map(|arg| arg.as_str()) {
+                    None => ("Toggling the experiment panel".to_string(), MessageType::Info),
+                    Some("start") => ("Saving the layout as the experiment baseline".to_string(), MessageType::Success),
+                    Some("cancel") => ("Cancelling the running experiment".to_string(), MessageType::Info),
+                    Some(other) => (format!("Unknown experiment command: {}. Usage: experiment [start [days] | cancel]", other), MessageType::Error),
+                };
+                add_console_message(&mut console_history, message, message_type);
+            },
+            "log" | "logs" => {
+                // Handled by the logging plugin, which reports back itself
+            },
             "clear" => {
                 console_history.messages.clear();
                 add_console_message(&mut console_history, "Console cleared".to_string(), MessageType::Info);
@@ -537,7 +647,7 @@ pub fn console_message_display_system(
     }
 }
 
-fn add_console_message(history: &mut ConsoleHistory, text: String, message_type: MessageType) {
+pub fn add_console_message(history: &mut ConsoleHistory, text: String, message_type: MessageType) {
     history.messages.push_back(ConsoleMessage {
         text,
         message_type,
@@ -1,4 +1,6 @@
 use bevy::prelude::*;
+use std::collections::HashMap;
+use crate::AppState;
 
 #[derive(Component)]
 pub struct PendingDespawn {
@@ -46,4 +48,76 @@ impl SafeDespawn for EntityCommands<'_> {
         self.try_insert(PendingDespawn { delay });
         self
     }
-}
\ No newline at end of file
+}
+
+// Despawn audit - screens spawn their roots with StateScoped; anything still alive after its state exits is logged
+const AUDIT_GRACE_SECONDS: f32 = 0.25;
+
+#[derive(Resource, Default)]
+pub struct DespawnAudit {
+    // UI roots and the state they were spawned in
+    tracked: HashMap<Entity, AppState>,
+    // Exited states waiting out the safe_despawn delay before being checked
+    pending: Vec<(AppState, f32)>,
+    baseline_taken: bool,
+}
+
+pub fn track_state_roots_system(
+    mut audit: ResMut<DespawnAudit>,
+    state: Res<State<AppState>>,
+    root_query: Query<Entity, (Added<Node>, Without<ChildOf>)>,
+) {
+    // Startup UI lives for the whole session, so only roots spawned after the first frame are tracked
+    if !audit.baseline_taken {
+        audit.baseline_taken = true;
+        return;
+    }
+    for entity in &root_query {
+        audit.tracked.insert(entity, *state.get());
+    }
+}
+
+pub fn despawn_audit_system(
+    mut audit: ResMut<DespawnAudit>,
+    mut transitions: EventReader<StateTransitionEvent<AppState>>,
+    state: Res<State<AppState>>,
+    survivor_query: Query<(Option<&Name>, Option<&StateScoped<AppState>>)>,
+    time: Res<Time<Real>>,
+) {
+    for transition in transitions.read() {
+        if let Some(exited) = transition.exited.filter(|exited| transition.entered != Some(*exited)) {
+            audit.pending.push((exited, AUDIT_GRACE_SECONDS));
+        }
+    }
+
+    let delta = time.delta_secs();
+    let mut due = Vec::new();
+    audit.pending.retain_mut(|(exited, remaining)| {
+        *remaining -= delta;
+        if *remaining > 0.0 {
+            return true;
+        }
+        due.push(*exited);
+        false
+    });
+
+    for exited in due {
+        audit.tracked.retain(|entity, spawned_in| {
+            if *spawned_in != exited {
+                return true;
+            }
+            let Ok((name, scoped)) = survivor_query.get(*entity) else { return false };
+            // Spawned during the old state but scoped to the new one is intended
+            if scoped.is_some_and(|scoped| scoped.0 == *state.get()) {
+                return false;
+            }
+            warn!(
+                "Despawn audit: UI root {:?}{} spawned in {:?} survived leaving it - add StateScoped or clean it up",
+                entity,
+                name.map(|name| format!(" \"{}\"", name)).unwrap_or_default(),
+                exited,
+            );
+            false
+        });
+    }
+}
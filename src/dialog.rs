@@ -0,0 +1,265 @@
+// Dialog - Conversation panel for visiting characters
+use bevy::prelude::*;
+
+pub struct DialogPlugin;
+
+impl Plugin for DialogPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<DialogState>()
+            .add_event::<StartDialogEvent>()
+            .add_event::<DialogChoiceEvent>()
+            .add_systems(Update, (
+                start_dialog_system,
+                dialog_button_system,
+                refresh_dialog_ui_system,
+            ).chain().in_set(crate::GameSet::UI).run_if(in_state(crate::AppState::Playing)))
+            .add_systems(OnExit(crate::AppState::Playing), close_dialog_system);
+    }
+}
+
+// Resources
+#[derive(Resource, Default)]
+pub struct DialogState {
+    active: Option<ActiveDialog>,
+    ui_dirty: bool,
+}
+
+impl DialogState {
+    pub fn is_open(&self) -> bool {
+        self.active.is_some()
+    }
+
+    pub fn speaking_with(&self) -> Option<Entity> {
+        self.active.as_ref().map(|dialog| dialog.source)
+    }
+}
+
+struct ActiveDialog {
+    source: Entity,
+    speaker: String,
+    portrait_color: Color,
+    lines: Vec<String>,
+    line: usize,
+    options: Vec<DialogOption>,
+}
+
+#[derive(Debug, Clone)]
+pub struct DialogOption {
+    pub id: u32,
+    pub label: String,
+}
+
+impl DialogOption {
+    pub fn new(id: u32, label: impl Into<String>) -> Self {
+        Self { id, label: label.into() }
+    }
+}
+
+// Components
+#[derive(Component)]
+pub struct DialogPanel;
+
+#[derive(Component)]
+pub struct DialogContinueButton;
+
+#[derive(Component)]
+pub struct DialogOptionButton {
+    pub id: u32,
+}
+
+// Events
+#[derive(Event)]
+pub struct StartDialogEvent {
+    pub source: Entity,
+    pub speaker: String,
+    pub portrait_color: Color,
+    pub lines: Vec<String>,
+    pub options: Vec<DialogOption>, // Shown after the last line; a plain goodbye if empty
+}
+
+#[derive(Event)]
+pub struct DialogChoiceEvent {
+    pub source: Entity,
+    pub option_id: u32,
+}
+
+// Option id sent when the player closes a dialog that had no choices
+pub const DIALOG_GOODBYE: u32 = u32::MAX;
+
+fn start_dialog_system(
+    mut start_events: EventReader<StartDialogEvent>,
+    mut dialog_state: ResMut<DialogState>,
+) {
+    for event in start_events.read() {
+        if dialog_state.is_open() {
+            continue;
+        }
+
+        let options = if event.options.is_empty() {
+            vec![DialogOption::new(DIALOG_GOODBYE, "Goodbye")]
+        } else {
+            event.options.clone()
+        };
+
+        dialog_state.active = Some(ActiveDialog {
+            source: event.source,
+            speaker: event.speaker.clone(),
+            portrait_color: event.portrait_color,
+            lines: event.lines.clone(),
+            line: 0,
+            options,
+        });
+        dialog_state.ui_dirty = true;
+    }
+}
+
+fn dialog_button_system(
+    continue_query: Query<&Interaction, (Changed<Interaction>, With<DialogContinueButton>)>,
+    mut option_query: Query<(&Interaction, &DialogOptionButton, &mut BackgroundColor), Changed<Interaction>>,
+    mut dialog_state: ResMut<DialogState>,
+    mut choice_events: EventWriter<DialogChoiceEvent>,
+) {
+    for interaction in &continue_query {
+        if *interaction == Interaction::Pressed {
+            if let Some(dialog) = dialog_state.active.as_mut() {
+                dialog.line = (dialog.line + 1).min(dialog.lines.len().saturating_sub(1));
+            }
+            dialog_state.ui_dirty = true;
+        }
+    }
+
+    for (interaction, option, mut bg_color) in &mut option_query {
+        match *interaction {
+            Interaction::Pressed => {
+                if let Some(dialog) = dialog_state.active.take() {
+                    choice_events.write(DialogChoiceEvent {
+                        source: dialog.source,
+                        option_id: option.id,
+                    });
+                }
+                dialog_state.ui_dirty = true;
+            }
+            Interaction::Hovered => *bg_color = Color::srgb(0.7, 0.6, 0.5).into(),
+            Interaction::None => *bg_color = Color::srgb(0.6, 0.5, 0.4).into(),
+        }
+    }
+}
+
+fn refresh_dialog_ui_system(
+    mut commands: Commands,
+    mut dialog_state: ResMut<DialogState>,
+    panel_query: Query<Entity, With<DialogPanel>>,
+) {
+    if !dialog_state.ui_dirty {
+        return;
+    }
+    dialog_state.ui_dirty = false;
+
+    for panel in &panel_query {
+        commands.entity(panel).despawn();
+    }
+
+    let Some(dialog) = dialog_state.active.as_ref() else { return };
+    let on_last_line = dialog.line + 1 >= dialog.lines.len();
+
+    commands.spawn((
+        Node {
+            width: Val::Percent(60.0),
+            position_type: PositionType::Absolute,
+            left: Val::Percent(20.0),
+            bottom: Val::Px(30.0),
+            flex_direction: FlexDirection::Row,
+            padding: UiRect::all(Val::Px(14.0)),
+            column_gap: Val::Px(14.0),
+            border: UiRect::all(Val::Px(2.0)),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.95, 0.92, 0.88, 0.97)),
+        BorderColor(Color::srgb(0.6, 0.5, 0.4)),
+        BorderRadius::all(Val::Px(8.0)),
+        ZIndex(40),
+        DialogPanel,
+    )).with_children(|panel| {
+        // Portrait
+        panel.spawn((
+            Node {
+                width: Val::Px(64.0),
+                height: Val::Px(64.0),
+                flex_shrink: 0.0,
+                ..default()
+            },
+            BackgroundColor(dialog.portrait_color),
+            BorderRadius::all(Val::Px(32.0)),
+        ));
+
+        panel.spawn(Node {
+            flex_grow: 1.0,
+            flex_direction: FlexDirection::Column,
+            row_gap: Val::Px(8.0),
+            ..default()
+        }).with_children(|body| {
+            body.spawn((
+                Text::new(dialog.speaker.clone()),
+                TextFont { font_size: 16.0, ..default() },
+                TextColor(Color::srgb(0.3, 0.2, 0.1)),
+            ));
+
+            body.spawn((
+                Text::new(dialog.lines.get(dialog.line).cloned().unwrap_or_default()),
+                TextFont { font_size: 14.0, ..default() },
+                TextColor(Color::srgb(0.4, 0.3, 0.2)),
+            ));
+
+            body.spawn(Node {
+                flex_direction: FlexDirection::Row,
+                flex_wrap: FlexWrap::Wrap,
+                column_gap: Val::Px(8.0),
+                row_gap: Val::Px(6.0),
+                margin: UiRect::top(Val::Px(4.0)),
+                ..default()
+            }).with_children(|buttons| {
+                if !on_last_line {
+                    spawn_dialog_button(buttons, "Continue...", DialogContinueButton);
+                    return;
+                }
+
+                for option in &dialog.options {
+                    spawn_dialog_button(buttons, &option.label, DialogOptionButton { id: option.id });
+                }
+            });
+        });
+    });
+}
+
+fn spawn_dialog_button(parent: &mut ChildSpawnerCommands, label: &str, marker: impl Bundle) {
+    parent.spawn((
+        Button,
+        Node {
+            padding: UiRect::axes(Val::Px(12.0), Val::Px(6.0)),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        },
+        BackgroundColor(Color::srgb(0.6, 0.5, 0.4)),
+        BorderRadius::all(Val::Px(4.0)),
+        marker,
+    )).with_children(|button| {
+        button.spawn((
+            Text::new(label),
+            TextFont { font_size: 13.0, ..default() },
+            TextColor(Color::WHITE),
+        ));
+    });
+}
+
+fn close_dialog_system(
+    mut commands: Commands,
+    mut dialog_state: ResMut<DialogState>,
+    panel_query: Query<Entity, With<DialogPanel>>,
+) {
+    dialog_state.active = None;
+    for panel in &panel_query {
+        commands.entity(panel).despawn();
+    }
+}
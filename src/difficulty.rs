@@ -0,0 +1,211 @@
+// Difficulty - Presets and individual realism settings for the simulation
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use crate::catalog::components::PlaceableItemType;
+
+pub struct DifficultyPlugin;
+
+impl Plugin for DifficultyPlugin {
+    fn build(&self, app: &mut App) {
+        // Replaced at startup with the player's preference from the settings file
+        app.init_resource::<DifficultySettings>();
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum DifficultyPreset {
+    Relaxed,
+    #[default]
+    Standard,
+    Naturalist,
+    Custom,
+}
+
+impl DifficultyPreset {
+    pub const ALL: [DifficultyPreset; 4] = [Self::Relaxed, Self::Standard, Self::Naturalist, Self::Custom];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Relaxed => "Relaxed",
+            Self::Standard => "Standard",
+            Self::Naturalist => "Naturalist",
+            Self::Custom => "Custom",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        let index = Self::ALL.iter().position(|preset| preset == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+    // Custom has no level of its own and keeps whatever was set
+    fn level(&self) -> Option<RealismLevel> {
+        match self {
+            Self::Relaxed => Some(RealismLevel::Relaxed),
+            Self::Standard => Some(RealismLevel::Standard),
+            Self::Naturalist => Some(RealismLevel::Naturalist),
+            Self::Custom => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum RealismLevel {
+    Relaxed,
+    #[default]
+    Standard,
+    Naturalist,
+}
+
+impl RealismLevel {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Relaxed => "Relaxed",
+            Self::Standard => "Standard",
+            Self::Naturalist => "Naturalist",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        match self {
+            Self::Relaxed => Self::Standard,
+            Self::Standard => Self::Naturalist,
+            Self::Naturalist => Self::Relaxed,
+        }
+    }
+
+    fn pick<T>(&self, relaxed: T, standard: T, naturalist: T) -> T {
+        match self {
+            Self::Relaxed => relaxed,
+            Self::Standard => standard,
+            Self::Naturalist => naturalist,
+        }
+    }
+}
+
+// The individual knobs a custom game can set
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RealismAspect {
+    SeedCosts,
+    Depletion,
+    Predators,
+    Identification,
+    RareBirds,
+    Playback,
+}
+
+impl RealismAspect {
+    pub const ALL: [RealismAspect; 6] = [
+        Self::SeedCosts, Self::Depletion, Self::Predators, Self::Identification, Self::RareBirds, Self::Playback,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::SeedCosts => "Seed Costs",
+            Self::Depletion => "Feeder Depletion",
+            Self::Predators => "Predator Frequency",
+            Self::Identification => "Identification Strictness",
+            Self::RareBirds => "Rare Bird Odds",
+            Self::Playback => "Call Playback Ethics",
+        }
+    }
+}
+
+#[derive(Resource, Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct DifficultySettings {
+    pub preset: DifficultyPreset,
+    pub seed_costs: RealismLevel,
+    pub depletion: RealismLevel,
+    pub predators: RealismLevel,
+    pub identification: RealismLevel,
+    pub rare_birds: RealismLevel,
+    #[serde(default)]
+    pub playback: RealismLevel,
+}
+
+impl DifficultySettings {
+    pub fn apply_preset(&mut self, preset: DifficultyPreset) {
+        self.preset = preset;
+        if let Some(level) = preset.level() {
+            for aspect in RealismAspect::ALL {
+                *self.level_mut(aspect) = level;
+            }
+        }
+    }
+
+    pub fn level(&self, aspect: RealismAspect) -> RealismLevel {
+        match aspect {
+            RealismAspect::SeedCosts => self.seed_costs,
+            RealismAspect::Depletion => self.depletion,
+            RealismAspect::Predators => self.predators,
+            RealismAspect::Identification => self.identification,
+            RealismAspect::RareBirds => self.rare_birds,
+            RealismAspect::Playback => self.playback,
+        }
+    }
+
+    fn level_mut(&mut self, aspect: RealismAspect) -> &mut RealismLevel {
+        match aspect {
+            RealismAspect::SeedCosts => &mut self.seed_costs,
+            RealismAspect::Depletion => &mut self.depletion,
+            RealismAspect::Predators => &mut self.predators,
+            RealismAspect::Identification => &mut self.identification,
+            RealismAspect::RareBirds => &mut self.rare_birds,
+            RealismAspect::Playback => &mut self.playback,
+        }
+    }
+
+    // Changing any single knob turns the game into a custom one
+    pub fn cycle_aspect(&mut self, aspect: RealismAspect) {
+        let next = self.level(aspect).next();
+        *self.level_mut(aspect) = next;
+        self.preset = DifficultyPreset::Custom;
+    }
+
+    pub fn label(&self) -> &'static str {
+        self.preset.name()
+    }
+
+    pub fn seed_cost_multiplier(&self) -> f32 {
+        self.seed_costs.pick(0.6, 1.0, 1.4)
+    }
+
+    pub fn depletion_multiplier(&self) -> f32 {
+        self.depletion.pick(0.5, 1.0, 1.5)
+    }
+
+    pub fn predator_frequency(&self) -> f32 {
+        self.predators.pick(0.4, 1.0, 1.6)
+    }
+
+    // Minimum centering plus clarity score for a photo to identify its species
+    pub fn identification_threshold(&self) -> u32 {
+        self.identification.pick(0, 15, 30)
+    }
+
+    pub fn rare_bird_multiplier(&self) -> f32 {
+        self.rare_birds.pick(1.8, 1.0, 0.6)
+    }
+
+    // Plays of one species' call before it counts as overuse
+    pub fn playback_limit(&self) -> f32 {
+        self.playback.pick(5.0, 3.0, 2.0)
+    }
+
+    // Steward rating lost per breeding-season play; None means playback is off limits then
+    pub fn breeding_playback_penalty(&self) -> Option<f32> {
+        self.playback.pick(Some(0.0), Some(-2.0), None)
+    }
+
+    // Food is the only stock that scales; décor and furniture keep their list price
+    pub fn item_price(&self, item_type: &PlaceableItemType) -> u32 {
+        let price = item_type.price();
+        match item_type {
+            PlaceableItemType::BasicBirdSeed | PlaceableItemType::PremiumSeed | PlaceableItemType::SuetCake |
+            PlaceableItemType::NectarFeeder | PlaceableItemType::FruitDispenser => {
+                (price as f32 * self.seed_cost_multiplier()).round() as u32
+            }
+            _ => price,
+        }
+    }
+}
@@ -0,0 +1,557 @@
+// Domestic Threats - Neighborhood Cats and Dogs
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use crate::bird_ai::components::{BirdAI, BirdState, Blackboard};
+use crate::elevation::Elevation;
+use crate::garden_lines::GardenLines;
+use crate::sanctuary_management::{PredatorDeterrent, PredatorType, PredatorManagement, PredatorSighting};
+use crate::notifications::{resources::ShowNotificationEvent, components::NotificationType};
+use crate::camera::cursor_world_position;
+
+pub struct DomesticThreatsPlugin;
+
+impl Plugin for DomesticThreatsPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<DomesticThreatManager>()
+            .add_event::<DomesticHuntEvent>()
+            .add_event::<NeighborDialogEvent>()
+            .add_event::<StewardRatingEvent>()
+            .add_systems(Update, (
+                domestic_animal_spawning_system,
+                domestic_animal_movement_system,
+                cat_stalking_system,
+                deterrent_response_system,
+                hunt_outcome_system,
+                neighbor_dialog_trigger_system,
+                neighbor_dialog_system,
+                steward_rating_system,
+            ).chain()
+                // Startled birds must not be overwritten by the behavior tree in the same frame
+                .after(crate::bird_ai::systems::behavior_tree_system)
+                .in_set(crate::GameSet::AI)
+                .run_if(in_state(crate::AppState::Playing)));
+    }
+}
+
+// Resources
+#[derive(Resource)]
+pub struct DomesticThreatManager {
+    pub spawn_timer: Timer,
+    pub max_animals: usize,
+    pub stalks_attempted: u32,
+    pub birds_startled: u32,
+    pub birds_escaped: u32,
+    pub visits_deterred: u32,
+    pub cats_belled: u32,
+    pub steward_rating: f32, // 0-100, how well the player protects visiting birds
+    // What each neighbor has agreed to, kept with the save so a belled cat stays belled
+    pub agreements: HashMap<u32, NeighborAgreement>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct NeighborAgreement {
+    pub collar_bell: bool,
+}
+
+impl Default for DomesticThreatManager {
+    fn default() -> Self {
+        Self {
+            spawn_timer: Timer::from_seconds(75.0, TimerMode::Repeating),
+            max_animals: 2,
+            stalks_attempted: 0,
+            birds_startled: 0,
+            birds_escaped: 0,
+            visits_deterred: 0,
+            cats_belled: 0,
+            steward_rating: 50.0,
+            agreements: HashMap::new(),
+        }
+    }
+}
+
+// Components
+#[derive(Component)]
+pub struct DomesticAnimal {
+    pub kind: DomesticKind,
+    pub state: DomesticState,
+    pub speed: f32,
+    pub wander_target: Vec2,
+    pub visit_timer: Timer, // Time before the animal wanders home
+    pub has_collar_bell: bool,
+    pub neighbor_id: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DomesticKind {
+    Cat,
+    Dog,
+}
+
+impl DomesticKind {
+    pub fn predator_type(&self) -> PredatorType {
+        match self {
+            Self::Cat => PredatorType::Cat,
+            Self::Dog => PredatorType::Dog,
+        }
+    }
+
+    fn color(&self) -> Color {
+        match self {
+            Self::Cat => Color::srgb(0.85, 0.55, 0.25),
+            Self::Dog => Color::srgb(0.45, 0.35, 0.25),
+        }
+    }
+
+    fn size(&self) -> Vec2 {
+        match self {
+            Self::Cat => Vec2::new(36.0, 20.0),
+            Self::Dog => Vec2::new(52.0, 30.0),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Cat => "cat",
+            Self::Dog => "dog",
+        }
+    }
+
+    // The same neighbors live around the sanctuary all game, most of them with a cat
+    fn of_neighbor(neighbor_id: u32) -> Self {
+        if neighbor_id == NEIGHBOR_COUNT - 1 { Self::Dog } else { Self::Cat }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DomesticState {
+    Roaming,
+    Stalking(Entity),
+    Leaving,
+}
+
+// Events
+#[derive(Event)]
+pub struct DomesticHuntEvent {
+    pub animal: Entity,
+    pub target: Entity,
+    pub outcome: HuntOutcome,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HuntOutcome {
+    Startled, // Pounce reached the bird - it flushes in a panic
+    Escaped,  // Bird noticed in time and flew off calmly
+}
+
+#[derive(Event)]
+pub struct NeighborDialogEvent {
+    pub animal: Entity,
+    pub request: NeighborRequest,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NeighborRequest {
+    CollarBell,
+    KeepIndoors,
+}
+
+// Hook for anything that affects how well the player is looking after visiting birds
+#[derive(Event)]
+pub struct StewardRatingEvent {
+    pub change: f32,
+    pub reason: String,
+}
+
+const NEIGHBOR_COUNT: u32 = 4;
+const STALK_DETECTION_RANGE: f32 = 250.0;
+const POUNCE_RANGE: f32 = 40.0;
+const BELL_WARNING_RANGE: f32 = 120.0;
+// Chance per second that a bird in range hears the bell and flees
+const BELL_WARNING_RATE: f32 = 3.0;
+
+fn domestic_animal_spawning_system(
+    mut commands: Commands,
+    mut manager: ResMut<DomesticThreatManager>,
+    animal_query: Query<&DomesticAnimal>,
+    bird_query: Query<Entity, With<BirdAI>>,
+    difficulty: Res<crate::difficulty::DifficultySettings>,
+    time: Res<Time>,
+) {
+    manager.spawn_timer.tick(time.delta());
+    if !manager.spawn_timer.just_finished() {
+        return;
+    }
+
+    if animal_query.iter().count() >= manager.max_animals || bird_query.iter().count() < 3 {
+        return;
+    }
+
+    if rand::random::<f32>() > 0.4 * difficulty.predator_frequency() {
+        return;
+    }
+
+    // Each neighbor's pet visits one at a time
+    let free: Vec<u32> = (0..NEIGHBOR_COUNT)
+        .filter(|id| animal_query.iter().all(|animal| animal.neighbor_id != *id))
+        .collect();
+    if free.is_empty() {
+        return;
+    }
+    let neighbor_id = free[rand::random::<u32>() as usize % free.len()];
+    let kind = DomesticKind::of_neighbor(neighbor_id);
+    let side = if rand::random::<bool>() { 1.0 } else { -1.0 };
+    let spawn_pos = Vec2::new(side * 650.0, -220.0 + rand::random::<f32>() * 120.0);
+
+    commands.spawn((
+        Sprite::from_color(kind.color(), kind.size()),
+        Transform::from_translation(spawn_pos.extend(0.45)),
+        DomesticAnimal {
+            kind,
+            state: DomesticState::Roaming,
+            speed: match kind {
+                DomesticKind::Cat => 45.0,
+                DomesticKind::Dog => 90.0,
+            },
+            wander_target: Vec2::new(-side * 200.0, spawn_pos.y),
+            visit_timer: Timer::from_seconds(60.0 + rand::random::<f32>() * 60.0, TimerMode::Once),
+            has_collar_bell: manager.agreements.get(&neighbor_id).is_some_and(|agreement| agreement.collar_bell),
+            neighbor_id,
+        },
+    ));
+
+    info!("A neighborhood {} wandered into the sanctuary", kind.name());
+}
+
+fn domestic_animal_movement_system(
+    mut commands: Commands,
+    mut animal_query: Query<(Entity, &mut DomesticAnimal, &mut Transform), Without<BirdAI>>,
+    bird_query: Query<&Transform, With<BirdAI>>,
+    garden_lines: Res<GardenLines>,
+    time: Res<Time>,
+) {
+    for (entity, mut animal, mut transform) in animal_query.iter_mut() {
+        animal.visit_timer.tick(time.delta());
+        if animal.visit_timer.just_finished() {
+            animal.state = DomesticState::Leaving;
+        }
+
+        let position = transform.translation.truncate();
+        let (target, speed) = match animal.state {
+            DomesticState::Roaming => {
+                if position.distance(animal.wander_target) < 20.0 {
+                    animal.wander_target = Vec2::new(
+                        (rand::random::<f32>() - 0.5) * 900.0,
+                        -250.0 + rand::random::<f32>() * 150.0,
+                    );
+                }
+                (animal.wander_target, animal.speed)
+            },
+            DomesticState::Stalking(prey) => {
+                let Ok(prey_transform) = bird_query.get(prey) else {
+                    animal.state = DomesticState::Roaming;
+                    continue;
+                };
+                // Cats creep in low and slow
+                (prey_transform.translation.truncate(), animal.speed * 0.5)
+            },
+            DomesticState::Leaving => {
+                let exit = Vec2::new(position.x.signum() * 750.0, position.y);
+                if position.distance(exit) < 30.0 {
+                    commands.entity(entity).despawn();
+                    continue;
+                }
+                (exit, animal.speed * 1.5)
+            },
+        };
+
+        let direction = (target - position).normalize_or_zero();
+        let step = direction * speed * time.delta_secs();
+
+        // Dogs turn back at fences and hedges, though nothing keeps an animal from heading home
+        if animal.kind == DomesticKind::Dog
+            && animal.state != DomesticState::Leaving
+            && garden_lines.blocks(position, position + step * 4.0)
+        {
+            animal.wander_target = position - direction * 150.0;
+            continue;
+        }
+
+        transform.translation += step.extend(0.0);
+        if direction.x.abs() > 0.1 {
+            transform.scale.x = direction.x.signum();
+        }
+    }
+}
+
+// Returns the combined deterrent effectiveness covering a position
+fn deterrent_protection(
+    position: Vec2,
+    predator_type: PredatorType,
+    deterrent_query: &Query<&PredatorDeterrent>,
+) -> f32 {
+    deterrent_query.iter()
+        .filter(|deterrent| deterrent.active && deterrent.position.truncate().distance(position) <= deterrent.range)
+        .map(|deterrent| deterrent.deterrent_type.effectiveness_against(predator_type) * deterrent.effectiveness)
+        .fold(0.0_f32, |protection, effect| 1.0 - (1.0 - protection) * (1.0 - effect))
+}
+
+// Cats pick out ground-feeding birds and creep towards them
+fn cat_stalking_system(
+    mut animal_query: Query<(Entity, &mut DomesticAnimal, &Transform)>,
+    bird_query: Query<(Entity, &Transform, &BirdState), With<BirdAI>>,
+    elevated_query: Query<(&Transform, &Elevation), Without<BirdAI>>,
+    deterrent_query: Query<&PredatorDeterrent>,
+    mut manager: ResMut<DomesticThreatManager>,
+    mut hunt_events: EventWriter<DomesticHuntEvent>,
+    time: Res<Time>,
+) {
+    for (animal_entity, mut animal, animal_transform) in animal_query.iter_mut() {
+        let animal_pos = animal_transform.translation.truncate();
+
+        match animal.state {
+            DomesticState::Roaming => {
+                // Dogs just bound through - their noise flushes birds instead
+                if animal.kind != DomesticKind::Cat {
+                    continue;
+                }
+
+                let target = bird_query.iter()
+                    .filter(|(_, transform, state)| {
+                        is_ground_feeding(transform.translation.truncate(), state, &elevated_query)
+                    })
+                    .map(|(entity, transform, _)| (entity, transform.translation.truncate().distance(animal_pos)))
+                    .filter(|(_, distance)| *distance < STALK_DETECTION_RANGE)
+                    .min_by(|a, b| a.1.total_cmp(&b.1));
+
+                if let Some((bird, _)) = target {
+                    animal.state = DomesticState::Stalking(bird);
+                    manager.stalks_attempted += 1;
+                }
+            },
+            DomesticState::Stalking(prey) => {
+                let Ok((_, prey_transform, _)) = bird_query.get(prey) else {
+                    animal.state = DomesticState::Roaming;
+                    continue;
+                };
+
+                let prey_pos = prey_transform.translation.truncate();
+                let distance = prey_pos.distance(animal_pos);
+
+                // A collar bell gives the bird a chance to hear the approach
+                if animal.has_collar_bell && distance < BELL_WARNING_RANGE && rand::random::<f32>() < BELL_WARNING_RATE * time.delta_secs() {
+                    hunt_events.write(DomesticHuntEvent {
+                        animal: animal_entity,
+                        target: prey,
+                        outcome: HuntOutcome::Escaped,
+                    });
+                    animal.state = DomesticState::Roaming;
+                    continue;
+                }
+
+                if distance <= POUNCE_RANGE {
+                    let protection = deterrent_protection(prey_pos, PredatorType::Cat, &deterrent_query);
+                    let pounce_success = 0.35 * (1.0 - protection);
+
+                    let outcome = if rand::random::<f32>() < pounce_success {
+                        HuntOutcome::Startled
+                    } else {
+                        HuntOutcome::Escaped
+                    };
+
+                    hunt_events.write(DomesticHuntEvent {
+                        animal: animal_entity,
+                        target: prey,
+                        outcome,
+                    });
+                    animal.state = DomesticState::Leaving;
+                }
+            },
+            DomesticState::Leaving => {},
+        }
+    }
+}
+
+fn is_ground_feeding(
+    position: Vec2,
+    state: &BirdState,
+    elevated_query: &Query<(&Transform, &Elevation), Without<BirdAI>>,
+) -> bool {
+    match state {
+        BirdState::Foraging => true,
+        // Eating at something low enough for a cat to get at
+        BirdState::Eating => elevated_query.iter().any(|(transform, elevation)| {
+            elevation.tier.reachable_by_cats()
+                && transform.translation.truncate().distance(position) < 80.0
+        }),
+        _ => false,
+    }
+}
+
+// Sprinklers and fences send visiting animals home
+fn deterrent_response_system(
+    mut animal_query: Query<(&mut DomesticAnimal, &Transform)>,
+    deterrent_query: Query<&PredatorDeterrent>,
+    mut bird_query: Query<(&Transform, &mut BirdState, &mut Blackboard), With<BirdAI>>,
+    mut manager: ResMut<DomesticThreatManager>,
+    mut predator_management: ResMut<PredatorManagement>,
+    mut rating_events: EventWriter<StewardRatingEvent>,
+    time: Res<Time>,
+) {
+    for (mut animal, transform) in animal_query.iter_mut() {
+        if animal.state == DomesticState::Leaving {
+            continue;
+        }
+
+        let position = transform.translation.truncate();
+        let protection = deterrent_protection(position, animal.kind.predator_type(), &deterrent_query);
+
+        // Checked every frame, so scale the chance down to roughly once per second
+        if protection > 0.0 && rand::random::<f32>() < protection * time.delta_secs() {
+            animal.state = DomesticState::Leaving;
+            manager.visits_deterred += 1;
+
+            predator_management.predator_activity.recent_sightings.push(PredatorSighting {
+                predator_type: animal.kind.predator_type(),
+                location: transform.translation,
+                timestamp: time.elapsed_secs(),
+                deterred: true,
+            });
+            predator_management.predator_activity.successful_attacks_prevented += 1;
+
+            rating_events.write(StewardRatingEvent {
+                change: 1.0,
+                reason: format!("Deterrent turned away a neighborhood {}", animal.kind.name()),
+            });
+            info!("🚿 Deterrent sent the {} home", animal.kind.name());
+            continue;
+        }
+
+        // Dogs rushing through flush any nearby birds
+        if animal.kind == DomesticKind::Dog {
+            for (bird_transform, mut state, mut blackboard) in bird_query.iter_mut() {
+                if bird_transform.translation.truncate().distance(position) < 100.0 {
+                    blackboard.internal.fear = (blackboard.internal.fear + 0.5).min(1.0);
+                    *state = BirdState::Fleeing;
+                }
+            }
+        }
+    }
+}
+
+// No bird is ever caught - the worst outcome is a panicked flush
+fn hunt_outcome_system(
+    mut hunt_events: EventReader<DomesticHuntEvent>,
+    mut bird_query: Query<(&mut BirdState, &mut Blackboard), With<BirdAI>>,
+    mut manager: ResMut<DomesticThreatManager>,
+    mut rating_events: EventWriter<StewardRatingEvent>,
+) {
+    for event in hunt_events.read() {
+        let Ok((mut state, mut blackboard)) = bird_query.get_mut(event.target) else {
+            continue;
+        };
+
+        *state = BirdState::Fleeing;
+
+        match event.outcome {
+            HuntOutcome::Startled => {
+                blackboard.internal.fear = 1.0;
+                blackboard.internal.energy = (blackboard.internal.energy - 0.2).max(0.0);
+                manager.birds_startled += 1;
+
+                rating_events.write(StewardRatingEvent {
+                    change: -3.0,
+                    reason: "A cat startled a feeding bird".to_string(),
+                });
+                info!("🐈 A cat pounced - the bird was startled and flew off");
+            },
+            HuntOutcome::Escaped => {
+                blackboard.internal.fear = (blackboard.internal.fear + 0.5).min(1.0);
+                manager.birds_escaped += 1;
+            },
+        }
+    }
+}
+
+// Right-clicking a visiting cat opens a conversation with its owner
+fn neighbor_dialog_trigger_system(
+    mouse_button: Res<ButtonInput<MouseButton>>,
+    windows: Query<&Window>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<Camera2d>>,
+    animal_query: Query<(Entity, &DomesticAnimal, &Transform)>,
+    mut dialog_events: EventWriter<NeighborDialogEvent>,
+) {
+    if !mouse_button.just_pressed(MouseButton::Right) {
+        return;
+    }
+
+    let Ok(window) = windows.single() else { return };
+    let Ok((camera, camera_transform)) = camera_query.single() else { return };
+    let Some(world_position) = cursor_world_position(window, camera, camera_transform) else { return };
+
+    for (entity, animal, transform) in animal_query.iter() {
+        if transform.translation.truncate().distance(world_position) < 40.0 {
+            let request = match animal.kind {
+                DomesticKind::Cat if !animal.has_collar_bell => NeighborRequest::CollarBell,
+                _ => NeighborRequest::KeepIndoors,
+            };
+            dialog_events.write(NeighborDialogEvent { animal: entity, request });
+            break;
+        }
+    }
+}
+
+fn neighbor_dialog_system(
+    mut dialog_events: EventReader<NeighborDialogEvent>,
+    mut animal_query: Query<&mut DomesticAnimal>,
+    mut manager: ResMut<DomesticThreatManager>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+    mut rating_events: EventWriter<StewardRatingEvent>,
+) {
+    for event in dialog_events.read() {
+        let Ok(mut animal) = animal_query.get_mut(event.animal) else {
+            continue;
+        };
+
+        // Neighbors are more receptive to a friendly sanctuary keeper
+        let agreement_chance = 0.4 + manager.steward_rating / 200.0;
+        let agreed = rand::random::<f32>() < agreement_chance;
+
+        let message = match (event.request, agreed) {
+            (NeighborRequest::CollarBell, true) => {
+                animal.has_collar_bell = true;
+                manager.agreements.entry(animal.neighbor_id).or_default().collar_bell = true;
+                manager.cats_belled += 1;
+                rating_events.write(StewardRatingEvent {
+                    change: 5.0,
+                    reason: "Neighbor fitted a collar bell".to_string(),
+                });
+                format!("Neighbor #{} agreed to put a bell on their cat's collar.", animal.neighbor_id + 1)
+            },
+            (NeighborRequest::KeepIndoors, true) => {
+                animal.state = DomesticState::Leaving;
+                rating_events.write(StewardRatingEvent {
+                    change: 2.0,
+                    reason: format!("Neighbor called their {} home", animal.kind.name()),
+                });
+                format!("Neighbor #{} called their {} back inside.", animal.neighbor_id + 1, animal.kind.name())
+            },
+            (_, false) => format!("Neighbor #{} isn't ready to change their routine yet.", animal.neighbor_id + 1),
+        };
+
+        notification_events.write(ShowNotificationEvent {
+            notification: NotificationType::Info { message },
+        });
+    }
+}
+
+fn steward_rating_system(
+    mut rating_events: EventReader<StewardRatingEvent>,
+    mut manager: ResMut<DomesticThreatManager>,
+) {
+    for event in rating_events.read() {
+        manager.steward_rating = (manager.steward_rating + event.change).clamp(0.0, 100.0);
+        info!("Steward rating {:+.0} ({}): now {:.0}", event.change, event.reason, manager.steward_rating);
+    }
+}
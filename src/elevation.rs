@@ -0,0 +1,244 @@
+// Elevation - Ground, pole, hanging branch and balcony rail tiers for feeders and placed objects
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use crate::bird::BirdSpecies;
+use crate::catalog::components::{PlaceableObject, PlaceableItemType};
+use crate::feeder::{Feeder, FeederType};
+use crate::keybindings::{GameAction, KeyBindings};
+use crate::notifications::{resources::ShowNotificationEvent, components::NotificationType};
+use crate::camera::cursor_world_position;
+
+// Depth catalog placements are dropped in at, tiers sort on top of it
+const PLACED_OBJECT_Z: f32 = 1.0;
+const SELECT_RANGE: f32 = 40.0;
+
+pub struct ElevationPlugin;
+
+impl Plugin for ElevationPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_systems(Update, (
+                attach_elevation_system,
+                cycle_height_system.run_if(crate::debug_console::console_is_not_visible),
+                apply_elevation_system,
+            ).chain().in_set(crate::GameSet::Simulation).run_if(in_state(crate::AppState::Playing)));
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HeightTier {
+    Ground,
+    Pole,
+    HangingBranch,
+    BalconyRail,
+}
+
+impl HeightTier {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Ground => "ground",
+            Self::Pole => "pole",
+            Self::HangingBranch => "hanging branch",
+            Self::BalconyRail => "balcony rail",
+        }
+    }
+
+    // How far above the ground the object sits, which is where its shadow falls
+    pub fn height(&self) -> f32 {
+        match self {
+            Self::Ground => 0.0,
+            Self::Pole => 50.0,
+            Self::BalconyRail => 40.0,
+            Self::HangingBranch => 80.0,
+        }
+    }
+
+    // Higher tiers draw over lower ones
+    fn z_offset(&self) -> f32 {
+        match self {
+            Self::Ground => 0.0,
+            Self::BalconyRail => 0.05,
+            Self::Pole => 0.1,
+            Self::HangingBranch => 0.15,
+        }
+    }
+
+    // Tube and swinging feeders need a bird that can cling or perch on something narrow
+    pub fn accessible_to(&self, species: BirdSpecies) -> bool {
+        match self {
+            Self::Ground | Self::BalconyRail => true,
+            Self::Pole => !ground_feeder(species),
+            Self::HangingBranch => !ground_feeder(species) && !too_heavy_to_hang(species),
+        }
+    }
+
+    // Cats can stalk along the ground and rails, baffled poles and branches are out of reach
+    pub fn reachable_by_cats(&self) -> bool {
+        matches!(self, Self::Ground | Self::BalconyRail)
+    }
+
+    pub fn for_feeder(feeder_type: FeederType) -> Self {
+        match feeder_type {
+            FeederType::Seed | FeederType::Fruit => Self::Pole,
+            FeederType::Suet | FeederType::Nectar => Self::HangingBranch,
+            FeederType::Ground => Self::Ground,
+        }
+    }
+
+    // Tiers an item can be moved between, the first is where it goes when placed
+    pub fn allowed_for(item_type: &PlaceableItemType) -> &'static [HeightTier] {
+        use PlaceableItemType::*;
+        match item_type {
+            BasicBirdSeed | PremiumSeed | FruitDispenser => &[Self::Pole, Self::Ground, Self::HangingBranch, Self::BalconyRail],
+            PeanutTray => &[Self::BalconyRail, Self::Ground, Self::Pole, Self::HangingBranch],
+            SuetCake | NectarFeeder => &[Self::HangingBranch, Self::Pole, Self::BalconyRail],
+            BirdHouse | NestingBox | RoostBox => &[Self::Pole, Self::HangingBranch, Self::BalconyRail],
+            WindChime | BellToy | MirrorToy | SwingSeat => &[Self::HangingBranch, Self::BalconyRail],
+            WoodenPerch | FancyPerch => &[Self::Ground, Self::Pole, Self::BalconyRail],
+            _ => &[Self::Ground],
+        }
+    }
+}
+
+// Doves, robins and thrushes feed on the ground or flat trays
+fn ground_feeder(species: BirdSpecies) -> bool {
+    matches!(species,
+        BirdSpecies::MourningDove | BirdSpecies::Robin | BirdSpecies::BrownThrasher | BirdSpecies::WoodThrush
+    )
+}
+
+fn too_heavy_to_hang(species: BirdSpecies) -> bool {
+    matches!(species,
+        BirdSpecies::CommonCrow | BirdSpecies::CommonGrackle | BirdSpecies::PileatedWoodpecker
+            | BirdSpecies::RedTailedHawk | BirdSpecies::CoopersHawk | BirdSpecies::GreatHornedOwl
+            | BirdSpecies::BarredOwl | BirdSpecies::BaldEagle | BirdSpecies::PeregrineFalcon
+    )
+}
+
+// Components
+#[derive(Component)]
+pub struct Elevation {
+    pub tier: HeightTier,
+    base_z: f32,
+    shadow_width: f32,
+    applied: Option<HeightTier>,
+}
+
+impl Elevation {
+    fn new(tier: HeightTier, base_z: f32, shadow_width: f32) -> Self {
+        Self {
+            tier,
+            base_z,
+            shadow_width,
+            applied: None,
+        }
+    }
+}
+
+// Tier a loaded object was saved at, consumed when its elevation is attached
+#[derive(Component)]
+pub struct RestoredHeight(pub HeightTier);
+
+#[derive(Component)]
+pub struct ElevationShadow;
+
+fn attach_elevation_system(
+    mut commands: Commands,
+    placed_query: Query<(Entity, &PlaceableObject, Option<&RestoredHeight>), (Added<PlaceableObject>, Without<Elevation>)>,
+    feeder_query: Query<(Entity, &Feeder, &Transform), (Added<Feeder>, Without<Elevation>)>,
+) {
+    for (entity, object, restored) in &placed_query {
+        let allowed = HeightTier::allowed_for(&object.item_type);
+        let tier = restored.map(|restored| restored.0)
+            .filter(|tier| allowed.contains(tier))
+            .unwrap_or(allowed[0]);
+        let width = object.item_type.physical_size().x;
+        commands.entity(entity)
+            .insert(Elevation::new(tier, PLACED_OBJECT_Z, width))
+            .remove::<RestoredHeight>();
+    }
+
+    for (entity, feeder, transform) in &feeder_query {
+        let tier = HeightTier::for_feeder(feeder.feeder_type);
+        commands.entity(entity).insert(Elevation::new(tier, transform.translation.z, 36.0));
+    }
+}
+
+// Press the height key over a placed object to move it to its next tier
+fn cycle_height_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    key_bindings: Res<KeyBindings>,
+    windows: Query<&Window>,
+    camera_query: Query<(&Camera, &GlobalTransform), (With<Camera2d>, Without<PlaceableObject>)>,
+    mut object_query: Query<(&PlaceableObject, &Transform, &mut Elevation)>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+) {
+    if !key_bindings.is_action_just_pressed(GameAction::CycleHeight, &keyboard, &mouse) {
+        return;
+    }
+
+    let Ok(window) = windows.single() else { return };
+    let Ok((camera, camera_transform)) = camera_query.single() else { return };
+    let Some(world_position) = cursor_world_position(window, camera, camera_transform) else { return };
+
+    let Some((object, _, mut elevation)) = object_query.iter_mut()
+        .filter(|(_, transform, _)| transform.translation.truncate().distance(world_position) < SELECT_RANGE)
+        .min_by(|a, b| {
+            let distance_a = a.1.translation.truncate().distance(world_position);
+            let distance_b = b.1.translation.truncate().distance(world_position);
+            distance_a.total_cmp(&distance_b)
+        }) else { return };
+
+    let allowed = HeightTier::allowed_for(&object.item_type);
+    if allowed.len() < 2 {
+        notification_events.write(ShowNotificationEvent {
+            notification: NotificationType::Info {
+                message: format!("{} only sits on the ground", object.item_type.name()),
+            },
+        });
+        return;
+    }
+
+    let index = allowed.iter().position(|tier| *tier == elevation.tier).unwrap_or(0);
+    elevation.tier = allowed[(index + 1) % allowed.len()];
+    notification_events.write(ShowNotificationEvent {
+        notification: NotificationType::Info {
+            message: format!("{} moved to a {}", object.item_type.name(), elevation.tier.name()),
+        },
+    });
+}
+
+// Sorts each object by its tier and drops its shadow to the ground below it
+fn apply_elevation_system(
+    mut commands: Commands,
+    mut elevated_query: Query<(Entity, &mut Elevation, &mut Transform, Option<&Children>)>,
+    shadow_query: Query<(), With<ElevationShadow>>,
+) {
+    for (entity, mut elevation, mut transform, children) in &mut elevated_query {
+        if elevation.applied == Some(elevation.tier) {
+            continue;
+        }
+        let tier = elevation.tier;
+        elevation.applied = Some(tier);
+        transform.translation.z = elevation.base_z + tier.z_offset();
+
+        if let Some(children) = children {
+            for child in children.iter().filter(|child| shadow_query.contains(*child)) {
+                commands.entity(child).despawn();
+            }
+        }
+
+        // The higher the object, the further and fainter its shadow
+        let height = tier.height();
+        let alpha = 0.35 - height / 400.0;
+        let width = elevation.shadow_width * (1.0 - height / 250.0);
+        commands.entity(entity).with_children(|parent| {
+            parent.spawn((
+                Sprite::from_color(Color::srgba(0.0, 0.0, 0.0, alpha), Vec2::new(width, 8.0)),
+                Transform::from_xyz(height * 0.3, -height - 12.0, -0.01),
+                ElevationShadow,
+            ));
+        });
+    }
+}
@@ -0,0 +1,176 @@
+// Almanac - Moon phase and seasonal sunrise/sunset for the sanctuary
+//
+// The moon runs on a 29.5 day cycle counted from the sanctuary's first day, and day length swings
+// from about nine hours at the winter solstice to fifteen at the summer one. Night lighting,
+// nocturnal birds and Lua scripts all read these through TimeState, and a small almanac card next
+// to the weather radar shows tonight's moon and today's sun times.
+use bevy::prelude::*;
+use super::resources::TimeState;
+
+const SYNODIC_MONTH: f32 = 29.53;
+// Hours of daylight at the equinoxes, and how far the solstices swing either side
+const EQUINOX_DAY_LENGTH: f32 = 12.0;
+const DAY_LENGTH_SWING: f32 = 3.0;
+const SPRING_EQUINOX_DAY: f32 = 80.0;
+const SOLAR_NOON: f32 = 12.5;
+// The almanac card sits just right of the weather radar
+const WIDGET_LEFT: f32 = 160.0;
+const WIDGET_BOTTOM: f32 = 80.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MoonPhase {
+    New,
+    WaxingCrescent,
+    FirstQuarter,
+    WaxingGibbous,
+    Full,
+    WaningGibbous,
+    LastQuarter,
+    WaningCrescent,
+}
+
+impl MoonPhase {
+    // Nearest named phase for a point in the cycle, 0 new through 0.5 full
+    pub fn from_cycle(cycle: f32) -> Self {
+        match ((cycle * 8.0).round() as u32) % 8 {
+            0 => Self::New,
+            1 => Self::WaxingCrescent,
+            2 => Self::FirstQuarter,
+            3 => Self::WaxingGibbous,
+            4 => Self::Full,
+            5 => Self::WaningGibbous,
+            6 => Self::LastQuarter,
+            _ => Self::WaningCrescent,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::New => "New Moon",
+            Self::WaxingCrescent => "Waxing Crescent",
+            Self::FirstQuarter => "First Quarter",
+            Self::WaxingGibbous => "Waxing Gibbous",
+            Self::Full => "Full Moon",
+            Self::WaningGibbous => "Waning Gibbous",
+            Self::LastQuarter => "Last Quarter",
+            Self::WaningCrescent => "Waning Crescent",
+        }
+    }
+
+    pub fn icon(&self) -> &'static str {
+        match self {
+            Self::New => "🌑",
+            Self::WaxingCrescent => "🌒",
+            Self::FirstQuarter => "🌓",
+            Self::WaxingGibbous => "🌔",
+            Self::Full => "🌕",
+            Self::WaningGibbous => "🌖",
+            Self::LastQuarter => "🌗",
+            Self::WaningCrescent => "🌘",
+        }
+    }
+}
+
+impl TimeState {
+    /// Position in the lunar cycle, 0.0 new moon through 0.5 full and back toward 1.0
+    pub fn moon_cycle(&self) -> f32 {
+        let day = (self.year.saturating_sub(1) * 365 + self.day_of_year) as f32 + self.hour / 24.0;
+        (day % SYNODIC_MONTH) / SYNODIC_MONTH
+    }
+
+    pub fn moon_phase(&self) -> MoonPhase {
+        MoonPhase::from_cycle(self.moon_cycle())
+    }
+
+    /// Lit fraction of the moon's disc, 0.0 at new moon and 1.0 at full
+    pub fn moon_illumination(&self) -> f32 {
+        (1.0 - (self.moon_cycle() * std::f32::consts::TAU).cos()) / 2.0
+    }
+
+    /// Hours of daylight, longest at the summer solstice
+    pub fn day_length(&self) -> f32 {
+        let angle = (self.day_of_year as f32 - SPRING_EQUINOX_DAY) / 365.0 * std::f32::consts::TAU;
+        EQUINOX_DAY_LENGTH + angle.sin() * DAY_LENGTH_SWING
+    }
+
+    pub fn sunrise_hour(&self) -> f32 {
+        SOLAR_NOON - self.day_length() / 2.0
+    }
+
+    pub fn sunset_hour(&self) -> f32 {
+        SOLAR_NOON + self.day_length() / 2.0
+    }
+}
+
+fn format_hour(hour: f32) -> String {
+    let minutes = (hour * 60.0).round() as u32;
+    format!("{:02}:{:02}", minutes / 60, minutes % 60)
+}
+
+// Components
+#[derive(Component)]
+pub struct AlmanacWidget;
+
+#[derive(Component)]
+pub struct AlmanacMoonText;
+
+#[derive(Component)]
+pub struct AlmanacSunText;
+
+pub fn setup_almanac_widget(mut commands: Commands) {
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(WIDGET_LEFT),
+            bottom: Val::Px(WIDGET_BOTTOM),
+            flex_direction: FlexDirection::Column,
+            row_gap: Val::Px(2.0),
+            padding: UiRect::axes(Val::Px(10.0), Val::Px(6.0)),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.1, 0.12, 0.2, 0.8)),
+        BorderRadius::all(Val::Px(6.0)),
+        Pickable::IGNORE,
+        AlmanacWidget,
+        Name::new("Almanac"),
+        StateScoped(crate::AppState::Playing),
+    )).with_children(|widget| {
+        widget.spawn((
+            Text::new(""),
+            TextFont { font_size: 12.0, ..default() },
+            TextColor(Color::srgb(0.9, 0.9, 0.8)),
+            AlmanacMoonText,
+        ));
+        widget.spawn((
+            Text::new(""),
+            TextFont { font_size: 11.0, ..default() },
+            TextColor(Color::srgb(0.95, 0.8, 0.5)),
+            AlmanacSunText,
+        ));
+    });
+}
+
+pub fn update_almanac_widget_system(
+    time_state: Res<TimeState>,
+    mut moon_query: Query<&mut Text, (With<AlmanacMoonText>, Without<AlmanacSunText>)>,
+    mut sun_query: Query<&mut Text, (With<AlmanacSunText>, Without<AlmanacMoonText>)>,
+) {
+    if !time_state.is_changed() {
+        return;
+    }
+
+    let phase = time_state.moon_phase();
+    let moon = format!("{} {} ({:.0}%)", phase.icon(), phase.name(), time_state.moon_illumination() * 100.0);
+    for mut text in &mut moon_query {
+        if text.0 != moon {
+            text.0 = moon.clone();
+        }
+    }
+
+    let sun = format!("Sunrise {} | Sunset {}", format_hour(time_state.sunrise_hour()), format_hour(time_state.sunset_hour()));
+    for mut text in &mut sun_query {
+        if text.0 != sun {
+            text.0 = sun.clone();
+        }
+    }
+}
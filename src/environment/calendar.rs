@@ -0,0 +1,72 @@
+// Events Calendar - Dated birding events across the year
+use crate::catalog::components::PlaceableItemType;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CalendarEvent {
+    GreatBackyardBirdCount,
+    SpringMigration,
+    PollinatorWeek,
+    FallMigration,
+    ChristmasBirdCount,
+}
+
+impl CalendarEvent {
+    pub const ALL: [CalendarEvent; 5] = [
+        Self::GreatBackyardBirdCount,
+        Self::SpringMigration,
+        Self::PollinatorWeek,
+        Self::FallMigration,
+        Self::ChristmasBirdCount,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::GreatBackyardBirdCount => "Great Backyard Bird Count",
+            Self::SpringMigration => "Spring Migration",
+            Self::PollinatorWeek => "Pollinator Week",
+            Self::FallMigration => "Fall Migration",
+            Self::ChristmasBirdCount => "Christmas Bird Count",
+        }
+    }
+
+    // Inclusive day-of-year range
+    pub fn days(&self) -> (u32, u32) {
+        match self {
+            Self::GreatBackyardBirdCount => (45, 48),
+            Self::SpringMigration => (110, 140),
+            Self::PollinatorWeek => (172, 178),
+            Self::FallMigration => (255, 285),
+            Self::ChristmasBirdCount => (348, 360),
+        }
+    }
+
+    // Catalog items the trading post features while the event runs
+    pub fn featured_items(&self) -> Vec<PlaceableItemType> {
+        match self {
+            Self::GreatBackyardBirdCount => vec![PlaceableItemType::PremiumSeed, PlaceableItemType::SuetCake],
+            Self::SpringMigration => vec![PlaceableItemType::NestingBox, PlaceableItemType::BirdHouse],
+            Self::PollinatorWeek => vec![PlaceableItemType::NectarFeeder, PlaceableItemType::FlowerPot],
+            Self::FallMigration => vec![PlaceableItemType::FruitDispenser, PlaceableItemType::StreamFeature],
+            Self::ChristmasBirdCount => vec![PlaceableItemType::SuetCake, PlaceableItemType::FountainBirdbath],
+        }
+    }
+
+    pub fn active_on(day_of_year: u32) -> Option<Self> {
+        Self::ALL.into_iter().find(|event| {
+            let (start, end) = event.days();
+            (start..=end).contains(&day_of_year)
+        })
+    }
+
+    // Next event to start after the given day, with the number of days until it begins
+    pub fn next_after(day_of_year: u32) -> (Self, u32) {
+        Self::ALL.into_iter()
+            .map(|event| {
+                let start = event.days().0;
+                let days_until = if start > day_of_year { start - day_of_year } else { start + 365 - day_of_year };
+                (event, days_until)
+            })
+            .min_by_key(|(_, days_until)| *days_until)
+            .unwrap_or((Self::GreatBackyardBirdCount, 0))
+    }
+}
@@ -6,6 +6,30 @@ pub struct EnvironmentEntity;
 #[derive(Component)]
 pub struct WeatherEffectEntity;
 
+// Background layer that scrolls at a fraction of the camera movement
+#[derive(Component)]
+pub struct ParallaxLayer {
+    pub base_position: Vec2,
+    pub camera_follow: f32, // 1.0 = fixed to the screen, 0.0 = fixed to the world
+}
+
+#[derive(Component)]
+pub struct SkyGradientBand {
+    pub height_fraction: f32, // 0.0 = horizon, 1.0 = top of the sky
+}
+
+#[derive(Component)]
+pub struct CloudSprite {
+    pub drift_speed: f32,
+    pub coverage_threshold: f32, // Cloud is shown once coverage exceeds this
+    pub current_alpha: f32,
+}
+
+#[derive(Component)]
+pub struct TreelineSilhouette {
+    pub shade: f32,
+}
+
 #[derive(Component)]
 pub struct DynamicAmbientLight {
     pub base_color: Color,
@@ -89,6 +113,7 @@ pub enum Weather {
     Rainy,
     Snowy,
     Windy,
+    Foggy,
 }
 
 impl Weather {
@@ -99,6 +124,7 @@ impl Weather {
             Self::Rainy => 0.4,     // Birds seek shelter
             Self::Snowy => 0.3,     // Minimal activity
             Self::Windy => 0.6,     // Reduced flying, more ground feeding
+            Self::Foggy => 0.8,     // Quieter, birds stay close to cover
         }
     }
     
@@ -120,6 +146,7 @@ impl Weather {
             Self::Rainy => Color::srgb(0.4, 0.4, 0.5),     // Dark gray
             Self::Snowy => Color::srgb(0.9, 0.9, 0.95),    // Light gray/white
             Self::Windy => Color::srgb(0.6, 0.7, 0.8),     // Dusty blue
+            Self::Foggy => Color::srgb(0.78, 0.8, 0.82),   // Pale gray
         }
     }
     
@@ -130,6 +157,7 @@ impl Weather {
             Self::Rainy => 0.5,     // Dim lighting
             Self::Snowy => 0.8,     // Bright but diffused
             Self::Windy => 0.9,     // Slightly reduced
+            Self::Foggy => 0.65,    // Flat, diffused light
         }
     }
     
@@ -149,6 +177,7 @@ impl Weather {
             Self::Clear => 0.0,
             Self::Cloudy => 0.1,    // Slight preference for cover
             Self::Windy => 0.4,     // Moderate need for wind protection
+            Self::Foggy => 0.05,    // Damp but calm
             Self::Rainy => 0.8,     // High urgency to stay dry
             Self::Snowy => 0.9,     // Very high urgency in snow
         }
@@ -160,6 +189,7 @@ impl Weather {
             Self::Clear => 0.0,
             Self::Cloudy => 0.05,
             Self::Windy => 0.2,     // Wind can be stressful
+            Self::Foggy => 0.1,     // Predators are harder to spot
             Self::Rainy => 0.3,     // Moderate stress from rain
             Self::Snowy => 0.4,     // High stress from snow/cold
         }
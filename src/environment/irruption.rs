@@ -0,0 +1,202 @@
+// Irruption - Multi-week waves of winter finches pushing south into the sanctuary
+//
+// Once per fall-to-winter stretch the sanctuary rolls each day for an irruption, with odds set by
+// that year's irruption outlook in variability.rs. When one starts, a few of the wandering seed
+// and berry eaters flood the spawn table for three to six weeks, the naturalist news announces it
+// and its departure, and the arrivals are counted so the journal can keep a record of every
+// irruption the sanctuary has seen.
+use bevy::prelude::*;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use crate::bird::{Bird, BirdSpecies};
+use crate::journal::milestones::{MilestoneEvent, MilestoneKind};
+use crate::notifications::{resources::ShowNotificationEvent, components::NotificationType};
+use super::components::Season;
+use super::resources::TimeState;
+use super::variability::YearlyVariability;
+
+// Wandering seed and berry eaters that move south when the northern crop fails
+const IRRUPTIVE_SPECIES: [(BirdSpecies, &str); 3] = [
+    (BirdSpecies::PurpleFinch, "Purple Finches"),
+    (BirdSpecies::Goldfinch, "Goldfinches"),
+    (BirdSpecies::CedarWaxwing, "Cedar Waxwings"),
+];
+// Outlooks below this never produce an irruption
+const MIN_OUTLOOK: f32 = 0.4;
+// Daily chance of an irruption starting in a year with the strongest outlook
+const MAX_DAILY_CHANCE: f32 = 0.08;
+const MIN_DAYS: u32 = 21;
+const MAX_EXTRA_DAYS: f32 = 21.0;
+// Spawn weight an irruptive species gets in a full irruption when the region has none of it
+const BASE_WEIGHT: f32 = 0.5;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IrruptionRecord {
+    // The year of the fall the irruption belongs to
+    pub year: u32,
+    pub start_day: u32,
+    pub days: u32,
+    pub species: Vec<BirdSpecies>,
+    pub strength: f32,
+    pub arrivals: u32,
+}
+
+impl IrruptionRecord {
+    pub fn species_names(&self) -> String {
+        let names: Vec<&str> = IRRUPTIVE_SPECIES.iter()
+            .filter(|(species, _)| self.species.contains(species))
+            .map(|(_, name)| *name)
+            .collect();
+        names.join(" and ")
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActiveIrruption {
+    pub record: IrruptionRecord,
+    // Absolute day, year * 365 + day of year, the birds move on
+    pub ends_on: u32,
+}
+
+// Resources
+#[derive(Resource, Serialize, Deserialize, Clone, Debug, Default)]
+pub struct Irruptions {
+    pub active: Option<ActiveIrruption>,
+    pub history: Vec<IrruptionRecord>,
+    // Fall year of the last stretch an irruption happened in, one per winter at most
+    pub last_winter: Option<u32>,
+    pub last_roll_day: u32,
+}
+
+impl Irruptions {
+    /// Floods the spawn pool with the irrupting species, adding them if the region has none
+    pub fn apply(&self, weights: &mut Vec<(BirdSpecies, f32)>) {
+        let Some(active) = &self.active else {
+            return;
+        };
+        let strength = active.record.strength;
+        for species in &active.record.species {
+            match weights.iter_mut().find(|(candidate, _)| candidate == species) {
+                Some((_, weight)) => *weight *= 4.0 + strength * 4.0,
+                None => weights.push((*species, BASE_WEIGHT * (0.5 + strength))),
+            }
+        }
+    }
+
+    /// Every irruption so far, oldest first, with the current one last
+    pub fn observed(&self) -> impl Iterator<Item = &IrruptionRecord> {
+        self.history.iter().chain(self.active.iter().map(|active| &active.record))
+    }
+}
+
+// Irruptions run from fall into the following winter, so the early winter days belong to last year's fall
+fn irruption_winter(time_state: &TimeState) -> Option<u32> {
+    match time_state.get_season() {
+        Season::Fall => Some(time_state.year),
+        Season::Winter if time_state.day_of_year > 355 => Some(time_state.year),
+        Season::Winter => Some(time_state.year.saturating_sub(1)),
+        Season::Spring | Season::Summer => None,
+    }
+}
+
+pub fn irruption_system(
+    mut irruptions: ResMut<Irruptions>,
+    time_state: Res<TimeState>,
+    variability: Res<YearlyVariability>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+    mut milestone_events: EventWriter<MilestoneEvent>,
+) {
+    let today = time_state.absolute_day();
+    if irruptions.last_roll_day == today {
+        return;
+    }
+    irruptions.last_roll_day = today;
+
+    if irruptions.active.as_ref().is_some_and(|active| today >= active.ends_on) {
+        let Some(finished) = irruptions.active.take() else {
+            return;
+        };
+        notification_events.write(ShowNotificationEvent {
+            notification: NotificationType::Info {
+                message: format!(
+                    "Naturalist News: the {} have moved on after {} weeks - {} arrivals logged",
+                    finished.record.species_names(), finished.record.days / 7, finished.record.arrivals,
+                ),
+            },
+        });
+        irruptions.history.push(finished.record);
+        return;
+    }
+    if irruptions.active.is_some() {
+        return;
+    }
+
+    let Some(winter) = irruption_winter(&time_state) else {
+        return;
+    };
+    if irruptions.last_winter.is_some_and(|last| last >= winter) {
+        return;
+    }
+    let outlook = variability.profile(winter).irruption;
+    if outlook < MIN_OUTLOOK {
+        return;
+    }
+
+    let mut rng = rand::rng();
+    let odds = ((outlook - MIN_OUTLOOK) / (1.0 - MIN_OUTLOOK)).powi(2) * MAX_DAILY_CHANCE;
+    if rng.random::<f32>() >= odds {
+        return;
+    }
+
+    let strength = (outlook * rng.random_range(0.7..1.1)).clamp(0.3, 1.0);
+    let mut species: Vec<BirdSpecies> = IRRUPTIVE_SPECIES.iter()
+        .map(|(species, _)| *species)
+        .filter(|_| rng.random::<f32>() < 0.4 + strength * 0.4)
+        .collect();
+    if species.is_empty() {
+        species.push(IRRUPTIVE_SPECIES[rng.random_range(0..IRRUPTIVE_SPECIES.len())].0);
+    }
+    let days = MIN_DAYS + (strength * MAX_EXTRA_DAYS) as u32;
+
+    let record = IrruptionRecord {
+        year: winter,
+        start_day: time_state.day_of_year,
+        days,
+        species,
+        strength,
+        arrivals: 0,
+    };
+    info!("🐦 Irruption of {} for {} days (strength {:.2})", record.species_names(), days, strength);
+    notification_events.write(ShowNotificationEvent {
+        notification: NotificationType::Info {
+            message: format!(
+                "Naturalist News: {} are irrupting south! Expect them at your feeders for the next {} weeks",
+                record.species_names(), days / 7,
+            ),
+        },
+    });
+    milestone_events.write(MilestoneEvent {
+        kind: MilestoneKind::Irruption,
+        title: format!("{} irrupted into the sanctuary", record.species_names()),
+        species: record.species.first().copied(),
+    });
+    irruptions.last_winter = Some(winter);
+    irruptions.active = Some(ActiveIrruption { record, ends_on: today + days });
+}
+
+pub fn irruption_arrival_system(
+    mut irruptions: ResMut<Irruptions>,
+    new_birds: Query<&Bird, Added<Bird>>,
+) {
+    if new_birds.is_empty() {
+        return;
+    }
+    let Some(active) = irruptions.active.as_mut() else {
+        return;
+    };
+    for bird in &new_birds {
+        if active.record.species.contains(&bird.species) {
+            active.record.arrivals += 1;
+        }
+    }
+}
@@ -1,6 +1,9 @@
 use bevy::prelude::*;
 use super::{components::*, resources::*};
 
+// Share of the night darkness a full moon takes away
+const MOONLIGHT: f32 = 0.3;
+
 pub fn setup_lighting_system(
     mut commands: Commands,
 ) {
@@ -76,7 +79,9 @@ pub fn dynamic_lighting_system(
     
     // Update day/night overlay
     if let Ok((mut sprite, mut overlay)) = overlay_query.single_mut() {
-        let night_intensity = calculate_night_intensity(time_state.hour);
+        // Moonlight lifts the darkness, most on the nights around the full moon
+        let night_intensity = calculate_night_intensity(time_state.hour, time_state.sunrise_hour(), time_state.sunset_hour())
+            * (1.0 - MOONLIGHT * time_state.moon_illumination());
         overlay.opacity = night_intensity * weather_modifier;
         
         // Blend seasonal colors into night overlay
@@ -124,16 +129,16 @@ pub fn seasonal_lighting_transition_system(
     seasonal.transition_speed = 0.5 + season_progress * 0.5; // Faster transition near season boundaries
 }
 
-fn calculate_night_intensity(hour: f32) -> f32 {
-    if hour >= 6.0 && hour <= 18.0 {
+fn calculate_night_intensity(hour: f32, sunrise: f32, sunset: f32) -> f32 {
+    if hour >= sunrise && hour <= sunset {
         0.0 // Full daylight
-    } else if hour < 6.0 {
+    } else if hour < sunrise {
         // Night to dawn transition
-        let night_factor = (6.0 - hour) / 6.0;
+        let night_factor = (sunrise - hour) / 6.0;
         (night_factor * 0.8).min(0.8)
     } else {
         // Dusk to night transition
-        let night_factor = (hour - 18.0) / 6.0;
+        let night_factor = (hour - sunset) / 6.0;
         (night_factor * 0.8).min(0.8)
     }
 }
@@ -216,6 +221,7 @@ pub fn weather_lighting_system(
             Weather::Snowy => Color::srgba(r * 0.9, g * 0.9, b * 1.0, a), // Cool white
             Weather::Cloudy => Color::srgba(r * 0.8, g * 0.8, b * 0.85, a), // Slightly muted
             Weather::Windy => Color::srgba(r * 0.9, g * 0.85, b * 0.8, a), // Dusty
+            Weather::Foggy => Color::srgba(r * 0.85, g * 0.87, b * 0.9, a), // Washed out
             Weather::Clear => light.color, // No modification
         };
     }
@@ -4,10 +4,21 @@ pub mod components;
 pub mod resources;
 pub mod systems;
 pub mod lighting;
+pub mod parallax;
+pub mod calendar;
+pub mod region;
+pub mod variability;
+pub mod irruption;
+pub mod almanac;
 
 use resources::*;
 use systems::*;
 use lighting::*;
+use parallax::*;
+use region::SanctuaryRegion;
+use variability::*;
+use irruption::*;
+use almanac::*;
 
 pub struct EnvironmentPlugin;
 
@@ -17,10 +28,15 @@ impl Plugin for EnvironmentPlugin {
             .init_resource::<TimeState>()
             .init_resource::<WeatherState>()
             .init_resource::<SeasonalState>()
+            .init_resource::<SanctuaryRegion>()
+            .init_resource::<YearlyVariability>()
+            .init_resource::<Irruptions>()
             .add_event::<WeatherChangeEvent>()
             .add_event::<TimeChangeEvent>()
-            .add_systems(Startup, (setup_environment, setup_lighting_system))
+            .add_event::<SeasonChangeEvent>()
+            .add_systems(Startup, (setup_environment, setup_lighting_system, setup_parallax_background))
             .add_systems(Update, (
+                region_change_system,
                 time_progression_system,
                 weather_system,
                 seasonal_migration_system,
@@ -29,6 +45,27 @@ impl Plugin for EnvironmentPlugin {
                 dynamic_lighting_system,
                 seasonal_lighting_transition_system,
                 weather_lighting_system,
-            ).run_if(in_state(crate::AppState::Playing)));
+                yearly_variability_system.after(time_progression_system),
+                irruption_system.after(yearly_variability_system),
+                irruption_arrival_system,
+            ).in_set(crate::GameSet::Simulation).run_if(in_state(crate::AppState::Playing)))
+            .add_systems(Update, toggle_yearly_stats_system
+                .run_if(crate::debug_console::console_is_not_visible)
+                .in_set(crate::GameSet::Input)
+                .run_if(in_state(crate::AppState::Playing)))
+            .add_systems(Update, refresh_yearly_stats_system
+                .in_set(crate::GameSet::UI)
+                .run_if(in_state(crate::AppState::Playing)))
+            .add_systems(OnExit(crate::AppState::Playing), close_yearly_stats_system)
+            .add_systems(OnEnter(crate::AppState::Playing), setup_almanac_widget)
+            .add_systems(Update, update_almanac_widget_system
+                .in_set(crate::GameSet::UI)
+                .run_if(in_state(crate::AppState::Playing)))
+            .add_systems(Update, (
+                sky_gradient_system,
+                cloud_layer_system,
+                treeline_season_system,
+                parallax_scroll_system,
+            ).chain().in_set(crate::GameSet::Presentation).run_if(in_state(crate::AppState::Playing)));
     }
 }
\ No newline at end of file
@@ -0,0 +1,192 @@
+use bevy::prelude::*;
+use super::{components::*, resources::*, region::SanctuaryRegion};
+use super::systems::{calculate_lighting_intensity, calculate_lighting_color};
+
+const SKY_BAND_COUNT: usize = 12;
+const SKY_WIDTH: f32 = 2400.0;
+const SKY_HEIGHT: f32 = 1500.0;
+const CLOUD_COUNT: usize = 14;
+const CLOUD_WRAP_WIDTH: f32 = 2400.0;
+
+pub fn setup_parallax_background(mut commands: Commands) {
+    // Sky gradient - horizontal bands from the horizon up, locked to the camera
+    let band_height = SKY_HEIGHT / SKY_BAND_COUNT as f32;
+    for i in 0..SKY_BAND_COUNT {
+        let y = -SKY_HEIGHT / 2.0 + band_height * (i as f32 + 0.5);
+        commands.spawn((
+            Sprite::from_color(Color::srgb(0.7, 0.9, 1.0), Vec2::new(SKY_WIDTH, band_height + 1.0)),
+            Transform::from_xyz(0.0, y, -10.0),
+            ParallaxLayer {
+                base_position: Vec2::new(0.0, y),
+                camera_follow: 1.0,
+            },
+            SkyGradientBand {
+                height_fraction: i as f32 / (SKY_BAND_COUNT - 1) as f32,
+            },
+            EnvironmentEntity,
+        ));
+    }
+
+    // Cloud layer - drifts slowly and barely moves with camera pans
+    for i in 0..CLOUD_COUNT {
+        let x = -CLOUD_WRAP_WIDTH / 2.0 + (i as f32 + rand::random::<f32>()) * CLOUD_WRAP_WIDTH / CLOUD_COUNT as f32;
+        let y = 250.0 + rand::random::<f32>() * 400.0;
+        let size = Vec2::new(140.0 + rand::random::<f32>() * 160.0, 40.0 + rand::random::<f32>() * 30.0);
+
+        commands.spawn((
+            Sprite::from_color(Color::srgba(1.0, 1.0, 1.0, 0.0), size),
+            Transform::from_xyz(x, y, -9.5),
+            ParallaxLayer {
+                base_position: Vec2::new(x, y),
+                camera_follow: 0.9,
+            },
+            CloudSprite {
+                drift_speed: 6.0 + rand::random::<f32>() * 8.0,
+                coverage_threshold: i as f32 / CLOUD_COUNT as f32,
+                current_alpha: 0.0,
+            },
+            EnvironmentEntity,
+        ));
+    }
+
+    // Distant treeline - irregular silhouettes along the horizon
+    let mut x = -1200.0;
+    while x < 1200.0 {
+        let width = 50.0 + rand::random::<f32>() * 70.0;
+        let height = 120.0 + rand::random::<f32>() * 110.0;
+        let y = 170.0 + height / 2.0;
+
+        commands.spawn((
+            Sprite::from_color(Color::srgb(0.15, 0.3, 0.18), Vec2::new(width, height)),
+            Transform::from_xyz(x, y, -9.0),
+            ParallaxLayer {
+                base_position: Vec2::new(x, y),
+                camera_follow: 0.7,
+            },
+            TreelineSilhouette {
+                shade: 0.85 + rand::random::<f32>() * 0.3,
+            },
+            EnvironmentEntity,
+        ));
+
+        x += width * 0.7;
+    }
+}
+
+pub fn parallax_scroll_system(
+    camera_query: Query<&Transform, (With<Camera2d>, Without<ParallaxLayer>)>,
+    mut layer_query: Query<(&mut Transform, &ParallaxLayer)>,
+) {
+    let Ok(camera_transform) = camera_query.single() else { return };
+    let camera_pos = camera_transform.translation.truncate();
+
+    for (mut transform, layer) in &mut layer_query {
+        let position = layer.base_position + camera_pos * layer.camera_follow;
+        transform.translation.x = position.x;
+        transform.translation.y = position.y;
+    }
+}
+
+pub fn sky_gradient_system(
+    time_state: Res<TimeState>,
+    weather_state: Res<WeatherState>,
+    region: Res<SanctuaryRegion>,
+    mut band_query: Query<(&mut Sprite, &SkyGradientBand)>,
+) {
+    let hour = time_state.hour;
+    let intensity = calculate_lighting_intensity(hour);
+    let tint = calculate_lighting_color(hour);
+    let overcast = cloud_coverage(weather_state.current_weather) * 0.7;
+    let weather_sky = weather_state.current_weather.background_color().to_srgba();
+
+    // Winter skies are paler and flatter
+    let pale = if time_state.get_season() == Season::Winter { 0.15 } else { 0.0 };
+
+    let day_zenith = Vec3::new(0.3, 0.55, 0.95);
+    let day_horizon = region.0.sky_horizon();
+    let night_zenith = Vec3::new(0.02, 0.03, 0.1);
+    let night_horizon = Vec3::new(0.08, 0.1, 0.2);
+    let weather_color = Vec3::new(weather_sky.red, weather_sky.green, weather_sky.blue);
+    let tint = Vec3::new(tint.0, tint.1, tint.2);
+
+    for (mut sprite, band) in &mut band_query {
+        let t = band.height_fraction;
+        let day = day_horizon.lerp(day_zenith, t).lerp(day_horizon, pale);
+        let night = night_horizon.lerp(night_zenith, t);
+
+        // Sunrise and sunset colors glow strongest near the horizon
+        let horizon_tint = Vec3::ONE.lerp(tint, 1.0 - t);
+        let mut color = night.lerp(day * horizon_tint, intensity);
+        color = color.lerp(weather_color * intensity.max(0.15), overcast);
+
+        sprite.color = Color::srgb(color.x, color.y, color.z);
+    }
+}
+
+// Fraction of the sky covered by cloud for each weather type
+fn cloud_coverage(weather: Weather) -> f32 {
+    match weather {
+        Weather::Clear => 0.15,
+        Weather::Windy => 0.4,
+        Weather::Foggy => 0.7,
+        Weather::Cloudy => 0.8,
+        Weather::Snowy => 0.9,
+        Weather::Rainy => 1.0,
+    }
+}
+
+pub fn cloud_layer_system(
+    time_state: Res<TimeState>,
+    weather_state: Res<WeatherState>,
+    mut cloud_query: Query<(&mut Sprite, &mut ParallaxLayer, &mut CloudSprite)>,
+    time: Res<Time>,
+) {
+    let coverage = cloud_coverage(weather_state.current_weather);
+    let intensity = calculate_lighting_intensity(time_state.hour);
+    let wind_multiplier = if weather_state.current_weather == Weather::Windy { 4.0 } else { 1.0 };
+
+    // Storm clouds are darker
+    let shade = match weather_state.current_weather {
+        Weather::Rainy => 0.55,
+        Weather::Snowy => 0.8,
+        Weather::Cloudy => 0.85,
+        _ => 1.0,
+    } * intensity.max(0.2);
+
+    for (mut sprite, mut layer, mut cloud) in &mut cloud_query {
+        layer.base_position.x += cloud.drift_speed * wind_multiplier * time.delta_secs();
+        if layer.base_position.x > CLOUD_WRAP_WIDTH / 2.0 {
+            layer.base_position.x -= CLOUD_WRAP_WIDTH;
+        }
+
+        // Fade clouds in and out as coverage changes
+        let target_alpha = if cloud.coverage_threshold < coverage { 0.85 } else { 0.0 };
+        cloud.current_alpha += (target_alpha - cloud.current_alpha) * (time.delta_secs() * 0.5).min(1.0);
+
+        sprite.color = Color::srgba(shade, shade, shade * 1.02, cloud.current_alpha);
+    }
+}
+
+pub fn treeline_season_system(
+    time_state: Res<TimeState>,
+    weather_state: Res<WeatherState>,
+    region: Res<SanctuaryRegion>,
+    mut tree_query: Query<(&mut Sprite, &TreelineSilhouette)>,
+) {
+    let base = region.0.treeline_color(time_state.get_season());
+
+    // Distant trees fade towards the sky color in poor visibility
+    let haze = match weather_state.current_weather {
+        Weather::Rainy | Weather::Snowy => 0.4,
+        Weather::Cloudy => 0.15,
+        _ => 0.0,
+    };
+    let sky = weather_state.current_weather.background_color().to_srgba();
+    let sky = Vec3::new(sky.red, sky.green, sky.blue);
+    let intensity = calculate_lighting_intensity(time_state.hour);
+
+    for (mut sprite, tree) in &mut tree_query {
+        let color = (base * tree.shade).lerp(sky, haze) * intensity;
+        sprite.color = Color::srgb(color.x, color.y, color.z);
+    }
+}
@@ -0,0 +1,253 @@
+// Region - The biome a sanctuary is founded in, driving species, weather, plants and scenery
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use crate::bird::BirdSpecies;
+use crate::aesthetic_objects::FlowerType;
+use super::components::{Season, Weather};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Region {
+    #[default]
+    EasternWoodland,
+    DesertSouthwest,
+    PacificCoast,
+}
+
+impl Region {
+    pub const ALL: [Region; 3] = [Self::EasternWoodland, Self::DesertSouthwest, Self::PacificCoast];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::EasternWoodland => "Eastern Woodland",
+            Self::DesertSouthwest => "Desert Southwest",
+            Self::PacificCoast => "Pacific Coast",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::EasternWoodland => "Leafy backyard with four true seasons. Cardinals, jays and titmice all year, snowy winters.",
+            Self::DesertSouthwest => "Hot, dry scrub where water is precious. Doves, thrashers and mockingbirds, with hummingbirds in spring.",
+            Self::PacificCoast => "Mild and damp under tall conifers. Wrens and creepers in the rain, kingfishers along the water.",
+        }
+    }
+
+    // Year-round residents, used until the region's species pack has loaded
+    pub fn residents(&self) -> &'static [BirdSpecies] {
+        match self {
+            Self::EasternWoodland => &[
+                BirdSpecies::Cardinal, BirdSpecies::BlueJay, BirdSpecies::Chickadee,
+                BirdSpecies::HouseFinch, BirdSpecies::Sparrow, BirdSpecies::CommonCrow,
+                BirdSpecies::EuropeanStarling, BirdSpecies::CommonGrackle,
+            ],
+            Self::DesertSouthwest => &[
+                BirdSpecies::HouseFinch, BirdSpecies::MourningDove, BirdSpecies::NorthernMockingbird,
+                BirdSpecies::CommonGrackle, BirdSpecies::Sparrow, BirdSpecies::CommonCrow,
+                BirdSpecies::EuropeanStarling, BirdSpecies::BrownThrasher,
+            ],
+            Self::PacificCoast => &[
+                BirdSpecies::HouseFinch, BirdSpecies::Sparrow, BirdSpecies::CommonCrow,
+                BirdSpecies::EuropeanStarling, BirdSpecies::Chickadee, BirdSpecies::DownyWoodpecker,
+                BirdSpecies::MourningDove, BirdSpecies::Robin,
+            ],
+        }
+    }
+
+    // Seasonal visitors with their spawn weight, the fallback for a missing species pack
+    pub fn seasonal_species(&self, season: Season) -> &'static [(BirdSpecies, f32)] {
+        match (self, season) {
+            (Self::EasternWoodland, Season::Spring) => &[
+                (BirdSpecies::Robin, 1.2), (BirdSpecies::YellowWarbler, 0.8),
+                (BirdSpecies::RedWingedBlackbird, 0.9), (BirdSpecies::BrownThrasher, 0.6),
+            ],
+            (Self::EasternWoodland, Season::Summer) => &[
+                (BirdSpecies::Robin, 1.3), (BirdSpecies::Goldfinch, 1.1),
+                (BirdSpecies::CedarWaxwing, 0.8), (BirdSpecies::YellowWarbler, 1.0),
+                (BirdSpecies::RedWingedBlackbird, 1.1),
+            ],
+            (Self::EasternWoodland, Season::Fall) => &[
+                (BirdSpecies::Robin, 0.9), (BirdSpecies::YellowWarbler, 0.7),
+                (BirdSpecies::WhiteBreastedNuthatch, 1.0), (BirdSpecies::CedarWaxwing, 1.2),
+                (BirdSpecies::BlueGrayGnatcatcher, 0.6),
+            ],
+            (Self::EasternWoodland, Season::Winter) => &[
+                (BirdSpecies::WhiteBreastedNuthatch, 1.1), (BirdSpecies::TuftedTitmouse, 1.0),
+                (BirdSpecies::CarolinaWren, 0.8), (BirdSpecies::MourningDove, 0.9),
+            ],
+            (Self::DesertSouthwest, Season::Spring) => &[
+                (BirdSpecies::RubyThroatedHummingbird, 1.2), (BirdSpecies::YellowWarbler, 0.7),
+                (BirdSpecies::BlueGrayGnatcatcher, 0.9), (BirdSpecies::PaintedBunting, 0.5),
+            ],
+            (Self::DesertSouthwest, Season::Summer) => &[
+                (BirdSpecies::RubyThroatedHummingbird, 1.3), (BirdSpecies::BaltimoreOriole, 0.7),
+                (BirdSpecies::PaintedBunting, 0.8), (BirdSpecies::Goldfinch, 0.6),
+            ],
+            (Self::DesertSouthwest, Season::Fall) => &[
+                (BirdSpecies::RubyThroatedHummingbird, 0.8), (BirdSpecies::YellowWarbler, 0.6),
+                (BirdSpecies::BlueGrayGnatcatcher, 1.0), (BirdSpecies::CedarWaxwing, 0.7),
+            ],
+            (Self::DesertSouthwest, Season::Winter) => &[
+                (BirdSpecies::YellowBelledSapsucker, 0.8), (BirdSpecies::Robin, 0.9),
+                (BirdSpecies::CedarWaxwing, 1.0), (BirdSpecies::Goldfinch, 0.9),
+            ],
+            (Self::PacificCoast, Season::Spring) => &[
+                (BirdSpecies::YellowWarbler, 0.9), (BirdSpecies::RedWingedBlackbird, 1.0),
+                (BirdSpecies::RubyThroatedHummingbird, 0.7), (BirdSpecies::BelttedKingfisher, 0.6),
+            ],
+            (Self::PacificCoast, Season::Summer) => &[
+                (BirdSpecies::Goldfinch, 1.1), (BirdSpecies::CedarWaxwing, 1.0),
+                (BirdSpecies::YellowWarbler, 1.0), (BirdSpecies::BelttedKingfisher, 0.8),
+            ],
+            (Self::PacificCoast, Season::Fall) => &[
+                (BirdSpecies::CedarWaxwing, 1.2), (BirdSpecies::BrownCreeper, 0.8),
+                (BirdSpecies::WinterWren, 0.7), (BirdSpecies::BelttedKingfisher, 0.7),
+            ],
+            (Self::PacificCoast, Season::Winter) => &[
+                (BirdSpecies::WinterWren, 1.0), (BirdSpecies::BrownCreeper, 1.0),
+                (BirdSpecies::CedarWaxwing, 0.9), (BirdSpecies::YellowBelledSapsucker, 0.6),
+            ],
+        }
+    }
+
+    // Maps a 0..10 roll onto the season's weather odds
+    pub fn weather_for_roll(&self, season: Season, roll: u32) -> Weather {
+        match (self, season) {
+            (Self::EasternWoodland, Season::Spring) => match roll {
+                0..=4 => Weather::Clear,
+                5 => Weather::Cloudy,
+                6 => Weather::Foggy,
+                7..=8 => Weather::Rainy,
+                _ => Weather::Windy,
+            },
+            (Self::EasternWoodland, Season::Summer) => match roll {
+                0..=6 => Weather::Clear,
+                7..=8 => Weather::Cloudy,
+                9 => Weather::Rainy,
+                _ => Weather::Windy,
+            },
+            (Self::EasternWoodland, Season::Fall) => match roll {
+                0..=3 => Weather::Clear,
+                4..=5 => Weather::Cloudy,
+                6 => Weather::Foggy,
+                7..=8 => Weather::Rainy,
+                _ => Weather::Windy,
+            },
+            (Self::EasternWoodland, Season::Winter) => match roll {
+                0..=2 => Weather::Clear,
+                3..=5 => Weather::Cloudy,
+                6..=7 => Weather::Snowy,
+                8 => Weather::Rainy,
+                _ => Weather::Windy,
+            },
+            // Desert skies are clear most of the year, with summer monsoon storms
+            (Self::DesertSouthwest, Season::Summer) => match roll {
+                0..=5 => Weather::Clear,
+                6 => Weather::Cloudy,
+                7 => Weather::Rainy,
+                _ => Weather::Windy,
+            },
+            (Self::DesertSouthwest, Season::Winter) => match roll {
+                0..=5 => Weather::Clear,
+                6..=7 => Weather::Cloudy,
+                8 => Weather::Rainy,
+                _ => Weather::Windy,
+            },
+            (Self::DesertSouthwest, _) => match roll {
+                0..=6 => Weather::Clear,
+                7 => Weather::Cloudy,
+                _ => Weather::Windy,
+            },
+            // The coast rarely freezes but is wet from fall through spring, and the marine layer
+            // rolls in as fog
+            (Self::PacificCoast, Season::Summer) => match roll {
+                0..=4 => Weather::Clear,
+                5..=6 => Weather::Cloudy,
+                7..=8 => Weather::Foggy,
+                _ => Weather::Windy,
+            },
+            (Self::PacificCoast, Season::Winter) => match roll {
+                0..=1 => Weather::Clear,
+                2..=4 => Weather::Cloudy,
+                5..=8 => Weather::Rainy,
+                _ => Weather::Windy,
+            },
+            (Self::PacificCoast, _) => match roll {
+                0..=2 => Weather::Clear,
+                3..=4 => Weather::Cloudy,
+                5 => Weather::Foggy,
+                6..=8 => Weather::Rainy,
+                _ => Weather::Windy,
+            },
+        }
+    }
+
+    pub fn base_temperature(&self, season: Season) -> f32 {
+        match (self, season) {
+            (Self::EasternWoodland, Season::Spring) => 15.0,
+            (Self::EasternWoodland, Season::Summer) => 25.0,
+            (Self::EasternWoodland, Season::Fall) => 10.0,
+            (Self::EasternWoodland, Season::Winter) => -2.0,
+            (Self::DesertSouthwest, Season::Spring) => 24.0,
+            (Self::DesertSouthwest, Season::Summer) => 36.0,
+            (Self::DesertSouthwest, Season::Fall) => 25.0,
+            (Self::DesertSouthwest, Season::Winter) => 12.0,
+            (Self::PacificCoast, Season::Spring) => 13.0,
+            (Self::PacificCoast, Season::Summer) => 19.0,
+            (Self::PacificCoast, Season::Fall) => 14.0,
+            (Self::PacificCoast, Season::Winter) => 8.0,
+        }
+    }
+
+    // Base colour of the distant treeline before shading and haze
+    pub fn treeline_color(&self, season: Season) -> Vec3 {
+        match (self, season) {
+            (Self::EasternWoodland, Season::Spring) => Vec3::new(0.3, 0.5, 0.3),
+            (Self::EasternWoodland, Season::Summer) => Vec3::new(0.15, 0.35, 0.18),
+            (Self::EasternWoodland, Season::Fall) => Vec3::new(0.55, 0.35, 0.15),
+            (Self::EasternWoodland, Season::Winter) => Vec3::new(0.35, 0.32, 0.3),
+            // Mesquite and creosote stay a dusty olive
+            (Self::DesertSouthwest, Season::Spring) => Vec3::new(0.45, 0.5, 0.3),
+            (Self::DesertSouthwest, _) => Vec3::new(0.5, 0.45, 0.32),
+            // Evergreen conifers barely change through the year
+            (Self::PacificCoast, Season::Winter) => Vec3::new(0.12, 0.25, 0.2),
+            (Self::PacificCoast, _) => Vec3::new(0.1, 0.28, 0.18),
+        }
+    }
+
+    // Clear-day sky colour at the horizon
+    pub fn sky_horizon(&self) -> Vec3 {
+        match self {
+            Self::EasternWoodland => Vec3::new(0.75, 0.88, 1.0),
+            Self::DesertSouthwest => Vec3::new(0.95, 0.85, 0.7),
+            Self::PacificCoast => Vec3::new(0.78, 0.85, 0.9),
+        }
+    }
+
+    pub fn hills_color(&self) -> Color {
+        match self {
+            Self::EasternWoodland => Color::srgb(0.4, 0.5, 0.6),
+            Self::DesertSouthwest => Color::srgb(0.7, 0.5, 0.4),
+            Self::PacificCoast => Color::srgb(0.3, 0.42, 0.45),
+        }
+    }
+
+    // Native flower planted in beds that peak in the given season
+    pub fn flower_for(&self, season: Season) -> FlowerType {
+        match (self, season) {
+            (Self::EasternWoodland, Season::Spring) => FlowerType::Tulips,
+            (Self::EasternWoodland, Season::Summer) => FlowerType::Roses,
+            (Self::EasternWoodland, Season::Fall) => FlowerType::Mums,
+            (Self::EasternWoodland, Season::Winter) => FlowerType::Pansies,
+            (Self::DesertSouthwest, Season::Spring) => FlowerType::Brittlebush,
+            (Self::DesertSouthwest, Season::Summer) => FlowerType::DesertMarigold,
+            (Self::DesertSouthwest, _) => FlowerType::Chamisa,
+            (Self::PacificCoast, Season::Spring) => FlowerType::CaliforniaPoppy,
+            (Self::PacificCoast, Season::Summer) => FlowerType::Fuchsia,
+            (Self::PacificCoast, _) => FlowerType::PacificAster,
+        }
+    }
+}
+
+// The region of the sanctuary currently being played
+#[derive(Resource, Debug, Clone, Copy, Default)]
+pub struct SanctuaryRegion(pub Region);
@@ -1,5 +1,7 @@
 use bevy::prelude::*;
 use super::components::{Season, Weather};
+use super::region::Region;
+use crate::species_packs::PackSpecies;
 use crate::bird::BirdSpecies;
 use std::collections::HashMap;
 
@@ -8,6 +10,7 @@ pub struct TimeState {
     pub hour: f32,           // 0.0-24.0
     pub time_speed: f32,     // Real seconds per game hour
     pub day_of_year: u32,    // 1-365
+    pub year: u32,           // Sanctuary year, starting at 1
 }
 
 impl Default for TimeState {
@@ -16,11 +19,27 @@ impl Default for TimeState {
             hour: 8.0,           // Start at 8 AM
             time_speed: 60.0,    // 1 minute real time = 1 hour game time
             day_of_year: 120,    // Start in late spring (day 120)
+            year: 1,
         }
     }
 }
 
 impl TimeState {
+    /// Days since the calendar's start, for spans that run across the new year
+    pub fn absolute_day(&self) -> u32 {
+        Self::day_index(self.year, self.day_of_year)
+    }
+
+    /// Same count for any other date, such as a past checklist's
+    pub fn day_index(year: u32, day_of_year: u32) -> u32 {
+        year * 365 + day_of_year
+    }
+
+    /// Game hours since the calendar's start, fractional within the current hour
+    pub fn absolute_hour(&self) -> f32 {
+        self.absolute_day() as f32 * 24.0 + self.hour
+    }
+
     pub fn get_season(&self) -> Season {
         match self.day_of_year {
             1..=79 => Season::Winter,
@@ -91,10 +110,12 @@ impl Default for WeatherState {
     }
 }
 
-#[derive(Resource)]
+#[derive(Resource, Clone)]
 pub struct SeasonalState {
     pub available_species: HashMap<BirdSpecies, f32>, // Species -> spawn probability
     pub migration_active: bool,
+    pub region: Region,
+    pub roster: Option<Vec<PackSpecies>>, // Region's species pack roster, once loaded
 }
 
 impl Default for SeasonalState {
@@ -112,6 +133,8 @@ impl Default for SeasonalState {
         Self {
             available_species,
             migration_active: false,
+            region: Region::default(),
+            roster: None,
         }
     }
 }
@@ -120,54 +143,29 @@ impl SeasonalState {
     pub fn update_for_season(&mut self, season: Season) {
         self.available_species.clear();
         
-        // Year-round residents (always available)
-        let residents = vec![
-            BirdSpecies::Cardinal, BirdSpecies::BlueJay, BirdSpecies::Chickadee,
-            BirdSpecies::HouseFinch, BirdSpecies::Sparrow, BirdSpecies::CommonCrow,
-            BirdSpecies::EuropeanStarling, BirdSpecies::CommonGrackle,
-        ];
+        if let Some(roster) = &self.roster {
+            for entry in roster {
+                let probability = entry.spawn_weight(season);
+                if probability > 0.0 {
+                    self.available_species.insert(entry.species, probability);
+                }
+            }
+            self.migration_active = matches!(season, Season::Spring | Season::Fall);
+            return;
+        }
         
-        for species in residents {
-            self.available_species.insert(species, 1.0);
+        // Year-round residents (always available)
+        for species in self.region.residents() {
+            self.available_species.insert(*species, 1.0);
         }
         
         // Seasonal species
-        match season {
-            Season::Spring => {
-                // Spring migrants
-                self.available_species.insert(BirdSpecies::Robin, 1.2);
-                self.available_species.insert(BirdSpecies::YellowWarbler, 0.8);
-                self.available_species.insert(BirdSpecies::RedWingedBlackbird, 0.9);
-                self.available_species.insert(BirdSpecies::BrownThrasher, 0.6);
-                self.migration_active = true;
-            },
-            Season::Summer => {
-                // Peak breeding season - high activity
-                self.available_species.insert(BirdSpecies::Robin, 1.3);
-                self.available_species.insert(BirdSpecies::Goldfinch, 1.1);
-                self.available_species.insert(BirdSpecies::CedarWaxwing, 0.8);
-                self.available_species.insert(BirdSpecies::YellowWarbler, 1.0);
-                self.available_species.insert(BirdSpecies::RedWingedBlackbird, 1.1);
-                self.migration_active = false;
-            },
-            Season::Fall => {
-                // Fall migration - high diversity
-                self.available_species.insert(BirdSpecies::Robin, 0.9);
-                self.available_species.insert(BirdSpecies::YellowWarbler, 0.7);
-                self.available_species.insert(BirdSpecies::WhiteBreastedNuthatch, 1.0);
-                self.available_species.insert(BirdSpecies::CedarWaxwing, 1.2);
-                self.available_species.insert(BirdSpecies::BlueGrayGnatcatcher, 0.6);
-                self.migration_active = true;
-            },
-            Season::Winter => {
-                // Mostly residents, some winter visitors
-                self.available_species.insert(BirdSpecies::WhiteBreastedNuthatch, 1.1);
-                self.available_species.insert(BirdSpecies::TuftedTitmouse, 1.0);
-                self.available_species.insert(BirdSpecies::CarolinaWren, 0.8);
-                self.available_species.insert(BirdSpecies::MourningDove, 0.9);
-                self.migration_active = false;
-            },
+        for (species, probability) in self.region.seasonal_species(season) {
+            self.available_species.insert(*species, *probability);
         }
+        
+        // Spring and fall are the migration seasons
+        self.migration_active = matches!(season, Season::Spring | Season::Fall);
     }
     
     pub fn get_spawn_probability(&self, species: &BirdSpecies) -> f32 {
@@ -185,4 +183,10 @@ pub struct WeatherChangeEvent {
 pub struct TimeChangeEvent {
     pub new_hour: f32,
     pub new_day: u32,
+}
+
+#[derive(Event)]
+pub struct SeasonChangeEvent {
+    pub previous: Season,
+    pub new_season: Season,
 }
\ No newline at end of file
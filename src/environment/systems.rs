@@ -1,15 +1,11 @@
 use bevy::prelude::*;
-use super::{components::*, resources::*};
+use super::{components::*, resources::*, region::SanctuaryRegion, variability::YearlyVariability};
+use crate::species_packs::SpeciesPackManager;
 use crate::notifications::{resources::ShowNotificationEvent, components::NotificationType};
 use rand::Rng;
 
 pub fn setup_environment(mut commands: Commands) {
-    // Spawn sky background entity
-    commands.spawn((
-        Sprite::from_color(Color::srgb(0.7, 0.9, 1.0), Vec2::new(2000.0, 1500.0)),
-        Transform::from_xyz(0.0, 0.0, -10.0), // Far background
-        EnvironmentEntity,
-    ));
+    // Sky, clouds and treeline are spawned as parallax layers (see parallax.rs)
     
     // Spawn day/night overlay for lighting transitions
     commands.spawn((
@@ -30,6 +26,7 @@ pub fn time_progression_system(
     mut time_state: ResMut<TimeState>,
     mut seasonal_state: ResMut<SeasonalState>,
     mut time_events: EventWriter<TimeChangeEvent>,
+    mut season_events: EventWriter<SeasonChangeEvent>,
     time: Res<Time>,
 ) {
     let old_hour = time_state.hour;
@@ -46,12 +43,14 @@ pub fn time_progression_system(
         
         if time_state.day_of_year > 365 {
             time_state.day_of_year = 1;
+            time_state.year += 1;
         }
         
         // Check for season change
         let new_season = time_state.get_season();
         if new_season != old_season {
             seasonal_state.update_for_season(new_season);
+            season_events.write(SeasonChangeEvent { previous: old_season, new_season });
             
             info!("Season changed to {:?}! Available species updated.", new_season);
         }
@@ -71,6 +70,8 @@ pub fn weather_system(
     time_state: Res<TimeState>,
     mut weather_events: EventWriter<WeatherChangeEvent>,
     mut notification_events: EventWriter<ShowNotificationEvent>,
+    region: Res<SanctuaryRegion>,
+    variability: Res<YearlyVariability>,
     time: Res<Time>,
 ) {
     weather_state.weather_timer.tick(time.delta());
@@ -79,50 +80,16 @@ pub fn weather_system(
         let mut rng = rand::rng();
         let season = time_state.get_season();
         
-        // Weather probabilities based on season
-        let new_weather = match season {
-            Season::Spring => {
-                match rng.random_range(0..10) {
-                    0..=4 => Weather::Clear,
-                    5..=6 => Weather::Cloudy,
-                    7..=8 => Weather::Rainy,
-                    _ => Weather::Windy,
-                }
-            },
-            Season::Summer => {
-                match rng.random_range(0..10) {
-                    0..=6 => Weather::Clear,
-                    7..=8 => Weather::Cloudy,
-                    9 => Weather::Rainy,
-                    _ => Weather::Windy,
-                }
-            },
-            Season::Fall => {
-                match rng.random_range(0..10) {
-                    0..=3 => Weather::Clear,
-                    4..=6 => Weather::Cloudy,
-                    7..=8 => Weather::Rainy,
-                    _ => Weather::Windy,
-                }
-            },
-            Season::Winter => {
-                match rng.random_range(0..10) {
-                    0..=2 => Weather::Clear,
-                    3..=5 => Weather::Cloudy,
-                    6..=7 => Weather::Snowy,
-                    8 => Weather::Rainy,
-                    _ => Weather::Windy,
-                }
-            },
-        };
+        // Weather probabilities based on season and region
+        let mut new_weather = region.0.weather_for_roll(season, rng.random_range(0..10));
+        
+        // Drought summers let the rain pass by
+        if new_weather == Weather::Rainy && rng.random::<f32>() < variability.current().rain_failure(season) {
+            new_weather = Weather::Clear;
+        }
         
         // Update temperature based on season and weather
-        let base_temp = match season {
-            Season::Spring => 15.0,
-            Season::Summer => 25.0,
-            Season::Fall => 10.0,
-            Season::Winter => -2.0,
-        };
+        let base_temp = region.0.base_temperature(season);
         
         let temp_modifier = match new_weather {
             Weather::Clear => 3.0,
@@ -130,6 +97,7 @@ pub fn weather_system(
             Weather::Rainy => -5.0,
             Weather::Snowy => -10.0,
             Weather::Windy => -2.0,
+            Weather::Foggy => -1.0,
         };
         
         weather_state.temperature = base_temp + temp_modifier + rng.random_range(-3.0..3.0);
@@ -164,6 +132,23 @@ pub fn weather_system(
     }
 }
 
+// Rebuilds the spawn pool whenever a new game or save picks the sanctuary's region,
+// or a species pack for it finishes loading
+pub fn region_change_system(
+    region: Res<SanctuaryRegion>,
+    packs: Res<SpeciesPackManager>,
+    time_state: Res<TimeState>,
+    mut seasonal_state: ResMut<SeasonalState>,
+) {
+    if !region.is_changed() && !packs.is_changed() {
+        return;
+    }
+    
+    seasonal_state.region = region.0;
+    seasonal_state.roster = packs.roster(region.0);
+    seasonal_state.update_for_season(time_state.get_season());
+}
+
 pub fn seasonal_migration_system(
     time_state: Res<TimeState>,
     seasonal_state: Res<SeasonalState>,
@@ -192,30 +177,9 @@ pub fn seasonal_migration_system(
 }
 
 pub fn environment_effect_system(
-    time_state: Res<TimeState>,
-    weather_state: Res<WeatherState>,
-    mut sky_query: Query<&mut Sprite, With<EnvironmentEntity>>,
     mut weather_events: EventReader<WeatherChangeEvent>,
 ) {
-    // Update sky color based on time and weather
-    for mut sprite in &mut sky_query {
-        let base_color = weather_state.current_weather.background_color();
-        
-        // Adjust for time of day
-        let time_factor = if time_state.hour < 6.0 || time_state.hour > 20.0 {
-            0.2 // Night - very dark
-        } else if time_state.hour < 8.0 || time_state.hour > 18.0 {
-            0.6 // Dawn/dusk - dimmed
-        } else {
-            1.0 // Day - full brightness
-        };
-        
-        sprite.color = Color::srgb(
-            base_color.to_srgba().red * time_factor,
-            base_color.to_srgba().green * time_factor,
-            base_color.to_srgba().blue * time_factor,
-        );
-    }
+    // Sky color is handled by the parallax sky gradient
     
     // Handle weather change effects
     for event in weather_events.read() {
@@ -236,34 +200,20 @@ pub fn environment_effect_system(
 
 pub fn lighting_transition_system(
     time_state: Res<TimeState>,
-    weather_state: Res<WeatherState>,
-    mut sky_query: Query<&mut Sprite, (With<EnvironmentEntity>, Without<DayNightOverlay>)>,
     mut overlay_query: Query<&mut Sprite, (With<DayNightOverlay>, Without<EnvironmentEntity>)>,
 ) {
     let hour = time_state.hour;
     
     // Calculate lighting factors
-    let lighting_intensity = calculate_lighting_intensity(hour);
-    let lighting_color = calculate_lighting_color(hour);
     let overlay_alpha = calculate_overlay_alpha(hour);
     
-    // Update sky background
-    for mut sky_sprite in &mut sky_query {
-        let base_color = weather_state.current_weather.background_color();
-        sky_sprite.color = Color::srgb(
-            base_color.to_srgba().red * lighting_intensity * lighting_color.0,
-            base_color.to_srgba().green * lighting_intensity * lighting_color.1,
-            base_color.to_srgba().blue * lighting_intensity * lighting_color.2,
-        );
-    }
-    
     // Update day/night overlay
     for mut overlay_sprite in &mut overlay_query {
         overlay_sprite.color = Color::srgba(0.0, 0.0, 0.3, overlay_alpha);
     }
 }
 
-fn calculate_lighting_intensity(hour: f32) -> f32 {
+pub(super) fn calculate_lighting_intensity(hour: f32) -> f32 {
     match hour {
         // Night (10 PM - 5 AM)
         h if h >= 22.0 || h < 5.0 => 0.15,
@@ -283,7 +233,7 @@ fn calculate_lighting_intensity(hour: f32) -> f32 {
     }
 }
 
-fn calculate_lighting_color(hour: f32) -> (f32, f32, f32) {
+pub(super) fn calculate_lighting_color(hour: f32) -> (f32, f32, f32) {
     match hour {
         // Dawn (5 AM - 8 AM) - warm orange/pink tones
         h if h >= 5.0 && h < 8.0 => {
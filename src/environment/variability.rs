@@ -0,0 +1,285 @@
+// Variability - Each sanctuary year gets its own character from a seeded generator
+//
+// A year is described by three numbers: how heavy the natural seed crop is, how strongly
+// northern finches push south for the winter, and how dry the summer runs. Mast years leave the
+// woods full of food so feeders go quiet in fall and winter, irruption years make a wave of
+// winter finches likely (see irruption.rs), and drought summers send birds to the water. Years
+// follow on from each other the way they do outside - trees rarely mast two years running, a
+// drought stunts the crop, and a crop failure up north after a big year pushes the finches down -
+// and the history is kept so the stats dashboard can plot it, alongside how each feeder is doing.
+use bevy::prelude::*;
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
+use serde::{Deserialize, Serialize};
+use crate::feeder::Feeder;
+use crate::feeder_stats::{feeder_report_rows, spawn_feeder_effectiveness_table, FeederRecord, FeederReportRow};
+use crate::keybindings::{GameAction, KeyBindings};
+use crate::notifications::{resources::ShowNotificationEvent, components::NotificationType};
+use crate::user_interface::chart::ScatterChart;
+use super::components::Season;
+use super::resources::TimeState;
+
+// Above these a year counts as a mast, irruption or drought year
+const MAST_THRESHOLD: f32 = 0.75;
+const IRRUPTION_THRESHOLD: f32 = 0.7;
+const DROUGHT_THRESHOLD: f32 = 0.7;
+const PANEL_WIDTH: f32 = 420.0;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct YearProfile {
+    pub year: u32,
+    // Natural seed and nut crop, 0-1
+    pub seed_crop: f32,
+    // Strength of the winter finch movement south, 0-1
+    pub irruption: f32,
+    // How dry the summer is, 0-1
+    pub drought: f32,
+}
+
+impl YearProfile {
+    // An ordinary year, used before the first year has been generated
+    fn average(year: u32) -> Self {
+        Self { year, seed_crop: 0.5, irruption: 0.2, drought: 0.3 }
+    }
+
+    pub fn is_mast_year(&self) -> bool {
+        self.seed_crop >= MAST_THRESHOLD
+    }
+
+    pub fn is_irruption_year(&self) -> bool {
+        self.irruption >= IRRUPTION_THRESHOLD
+    }
+
+    pub fn is_drought_summer(&self) -> bool {
+        self.drought >= DROUGHT_THRESHOLD
+    }
+
+    pub fn summary(&self) -> String {
+        let mut traits = Vec::new();
+        if self.is_mast_year() {
+            traits.push("mast year");
+        } else if self.seed_crop < 0.25 {
+            traits.push("poor seed crop");
+        }
+        if self.is_irruption_year() {
+            traits.push("finch irruption");
+        }
+        if self.is_drought_summer() {
+            traits.push("drought summer");
+        }
+        if traits.is_empty() {
+            "an ordinary year".to_string()
+        } else {
+            traits.join(", ")
+        }
+    }
+
+    /// How busy feeders are compared to an ordinary year; wild food competes with them in fall and winter
+    pub fn feeder_draw(&self, season: Season) -> f32 {
+        match season {
+            Season::Fall | Season::Winter => 1.0 - (self.seed_crop - 0.5) * 0.8,
+            Season::Spring | Season::Summer => 1.0,
+        }
+    }
+
+    /// How much more birds seek out water than usual
+    pub fn water_draw(&self, season: Season) -> f32 {
+        match season {
+            Season::Summer => 1.0 + self.drought,
+            _ => 1.0,
+        }
+    }
+
+    /// Chance a rainy spell fails to arrive
+    pub fn rain_failure(&self, season: Season) -> f32 {
+        match season {
+            Season::Summer if self.is_drought_summer() => self.drought * 0.8,
+            Season::Summer => self.drought * 0.3,
+            _ => 0.0,
+        }
+    }
+
+    // Drawn from the sanctuary's seed so the same year always comes out the same
+    fn generate(seed: u64, year: u32, previous: Option<&YearProfile>) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed ^ (year as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15));
+        let drought = rng.random::<f32>().powf(1.5);
+
+        // Trees that masted last year are spent, and a dry summer stunts the crop
+        let mut seed_crop = rng.random::<f32>();
+        if previous.is_some_and(|previous| previous.is_mast_year()) {
+            seed_crop *= 0.4;
+        }
+        seed_crop *= 1.0 - drought * 0.5;
+
+        // A big crop last year grew the finch population, and a poor one this year sends it south
+        let mut irruption = rng.random::<f32>() * 0.7;
+        if previous.is_some_and(|previous| previous.is_mast_year()) {
+            irruption += 0.25;
+        }
+        irruption += (0.3 - seed_crop).max(0.0);
+
+        Self {
+            year,
+            seed_crop: seed_crop.clamp(0.0, 1.0),
+            irruption: irruption.clamp(0.0, 1.0),
+            drought: drought.clamp(0.0, 1.0),
+        }
+    }
+}
+
+// Resources
+#[derive(Resource, Serialize, Deserialize, Clone, Debug, Default)]
+pub struct YearlyVariability {
+    // Picked when the sanctuary's first year is generated, 0 until then
+    pub seed: u64,
+    pub years: Vec<YearProfile>,
+    #[serde(skip)]
+    pub is_open: bool,
+    #[serde(skip)]
+    panel_dirty: bool,
+}
+
+impl YearlyVariability {
+    pub fn current(&self) -> YearProfile {
+        self.years.last().copied().unwrap_or_else(|| YearProfile::average(1))
+    }
+
+    pub fn profile(&self, year: u32) -> YearProfile {
+        self.years.iter().find(|profile| profile.year == year).copied().unwrap_or_else(|| self.current())
+    }
+
+    // Generates every year up to this one, returning how many were added
+    fn catch_up(&mut self, year: u32) -> usize {
+        if self.seed == 0 {
+            self.seed = rand::random::<u64>().max(1);
+        }
+        let before = self.years.len();
+        let mut next = self.years.last().map_or(1, |last| last.year + 1);
+        while next <= year {
+            let profile = YearProfile::generate(self.seed, next, self.years.last());
+            self.years.push(profile);
+            next += 1;
+        }
+        self.years.len() - before
+    }
+}
+
+// Components
+#[derive(Component)]
+pub struct YearlyStatsPanel;
+
+pub fn yearly_variability_system(
+    mut variability: ResMut<YearlyVariability>,
+    time_state: Res<TimeState>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+) {
+    let had_history = !variability.years.is_empty();
+    if variability.years.last().is_some_and(|last| last.year >= time_state.year) {
+        return;
+    }
+    if variability.catch_up(time_state.year) == 0 {
+        return;
+    }
+    variability.panel_dirty = true;
+
+    let year = variability.current();
+    info!("📅 Year {}: {}", year.year, year.summary());
+    // A new sanctuary starts without an announcement, later years are heralded
+    if had_history {
+        notification_events.write(ShowNotificationEvent {
+            notification: NotificationType::Info {
+                message: format!("Year {} outlook: {}", year.year, year.summary()),
+            },
+        });
+    }
+}
+
+pub fn toggle_yearly_stats_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    key_bindings: Res<KeyBindings>,
+    mut variability: ResMut<YearlyVariability>,
+) {
+    if key_bindings.is_action_just_pressed(GameAction::OpenYearlyStats, &keyboard, &mouse) {
+        variability.is_open = !variability.is_open;
+        variability.panel_dirty = true;
+    }
+}
+
+pub fn refresh_yearly_stats_system(
+    mut commands: Commands,
+    mut variability: ResMut<YearlyVariability>,
+    panel_query: Query<Entity, With<YearlyStatsPanel>>,
+    feeder_query: Query<(Entity, &Feeder, &FeederRecord)>,
+) {
+    if !variability.panel_dirty {
+        return;
+    }
+    variability.panel_dirty = false;
+
+    for panel in &panel_query {
+        commands.entity(panel).despawn();
+    }
+    if !variability.is_open {
+        return;
+    }
+
+    let feeders = feeder_report_rows(feeder_query.iter());
+    spawn_yearly_stats_panel(&mut commands, &variability, &feeders);
+}
+
+pub fn close_yearly_stats_system(
+    mut commands: Commands,
+    mut variability: ResMut<YearlyVariability>,
+    panel_query: Query<Entity, With<YearlyStatsPanel>>,
+) {
+    variability.is_open = false;
+    for panel in &panel_query {
+        commands.entity(panel).despawn();
+    }
+}
+
+fn spawn_yearly_stats_panel(commands: &mut Commands, variability: &YearlyVariability, feeders: &[FeederReportRow]) {
+    let series = |value: fn(&YearProfile) -> f32| -> Vec<Vec2> {
+        variability.years.iter().map(|year| Vec2::new(year.year as f32, value(year) * 100.0)).collect()
+    };
+
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            right: Val::Px(20.0),
+            top: Val::Px(80.0),
+            width: Val::Px(PANEL_WIDTH),
+            max_height: Val::Percent(80.0),
+            flex_direction: FlexDirection::Column,
+            padding: UiRect::all(Val::Px(14.0)),
+            row_gap: Val::Px(8.0),
+            overflow: Overflow::scroll_y(),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.95, 0.92, 0.88, 0.95)),
+        BorderRadius::all(Val::Px(8.0)),
+        YearlyStatsPanel,
+        Name::new("YearlyStats"),
+    )).with_children(|panel| {
+        panel.spawn((
+            Text::new("Year by Year"),
+            TextFont { font_size: 20.0, ..default() },
+            TextColor(Color::srgb(0.3, 0.2, 0.1)),
+        ));
+
+        for year in variability.years.iter().rev() {
+            panel.spawn((
+                Text::new(format!("Year {}: {}", year.year, year.summary())),
+                TextFont { font_size: 12.0, ..default() },
+                TextColor(Color::srgb(0.4, 0.3, 0.2)),
+            ));
+        }
+
+        ScatterChart::new("Natural seed crop").with_axes("year", "%").with_points(series(|year| year.seed_crop)).spawn(panel);
+        ScatterChart::new("Winter finch irruption").with_axes("year", "%").with_points(series(|year| year.irruption)).spawn(panel);
+        ScatterChart::new("Summer drought").with_axes("year", "%").with_points(series(|year| year.drought)).spawn(panel);
+
+        spawn_feeder_effectiveness_table(panel, feeders);
+    });
+}
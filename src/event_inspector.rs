@@ -0,0 +1,354 @@
+// Event Inspector - Developer window logging recent game events with timestamps and payload summaries
+use bevy::prelude::*;
+use std::collections::{BTreeSet, HashSet, VecDeque};
+use crate::achievements::AchievementUnlockedEvent;
+use crate::camera::CameraFocusEvent;
+use crate::catalog::resources::{PlaceObjectEvent, PurchaseItemEvent};
+use crate::corvids::CacheEvent;
+use crate::debug_console::ConsoleCommand;
+use crate::domestic_threats::StewardRatingEvent;
+use crate::environment::resources::{SeasonChangeEvent, TimeChangeEvent, TimeState, WeatherChangeEvent};
+use crate::feeder::FeederDepletionEvent;
+use crate::notifications::resources::ShowNotificationEvent;
+use crate::photo_mode::components::PhotoTakenEvent;
+use crate::predator_prey::{AlertCallEvent, PredatorAttackEvent};
+use crate::sanctuary_management::NestingEvent;
+use crate::save_load::resources::{LoadCompleteEvent, SaveCompleteEvent};
+use crate::search_palette::SearchJumpEvent;
+use crate::smart_objects::SpawnSmartObjectEvent;
+
+const LOG_CAPACITY: usize = 300;
+const SHOWN_ROWS: usize = 30;
+const PANEL_WIDTH: f32 = 620.0;
+
+pub struct EventInspectorPlugin;
+
+impl Plugin for EventInspectorPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<EventLog>()
+            .init_resource::<EventInspector>()
+            .add_systems(Update, (
+                toggle_event_inspector_system.run_if(crate::debug_console::console_is_not_visible),
+                event_inspector_button_system,
+                refresh_event_inspector_system,
+            ).chain().in_set(crate::GameSet::UI));
+    }
+
+    // Recorders are added once every plugin has registered its events
+    fn finish(&self, app: &mut App) {
+        inspect::<WeatherChangeEvent>(app, "WeatherChangeEvent", |event| {
+            format!("{:?}, {:.0}°C", event.new_weather, event.temperature)
+        });
+        inspect::<SeasonChangeEvent>(app, "SeasonChangeEvent", |event| {
+            format!("{:?} -> {:?}", event.previous, event.new_season)
+        });
+        inspect::<TimeChangeEvent>(app, "TimeChangeEvent", |event| {
+            format!("day {}, {:.1}h", event.new_day, event.new_hour)
+        });
+        inspect::<PhotoTakenEvent>(app, "PhotoTakenEvent", |event| {
+            format!("{:?}, score {}", event.species, event.score.total_score)
+        });
+        inspect::<SpawnSmartObjectEvent>(app, "SpawnSmartObjectEvent", |event| {
+            format!("{} at ({:.0}, {:.0})", event.definition_id, event.position.x, event.position.y)
+        });
+        inspect::<PurchaseItemEvent>(app, "PurchaseItemEvent", |event| event.item_type.name().to_string());
+        inspect::<PlaceObjectEvent>(app, "PlaceObjectEvent", |event| {
+            format!("{} at ({:.0}, {:.0})", event.item_type.name(), event.position.x, event.position.y)
+        });
+        inspect::<FeederDepletionEvent>(app, "FeederDepletionEvent", |event| {
+            format!("{:?} -{:.2}", event.feeder_entity, event.amount)
+        });
+        inspect::<AchievementUnlockedEvent>(app, "AchievementUnlockedEvent", |event| format!("{:?}", event.achievement));
+        inspect::<PredatorAttackEvent>(app, "PredatorAttackEvent", |event| {
+            format!("{:?} on {:?}, {}", event.predator, event.target, if event.success { "caught" } else { "missed" })
+        });
+        inspect::<AlertCallEvent>(app, "AlertCallEvent", |event| {
+            format!("{:?}, urgency {:.2}, range {:.0}", event.caller, event.urgency, event.call_range)
+        });
+        inspect::<NestingEvent>(app, "NestingEvent", |event| format!("{:?} {:?}", event.species, event.event_type));
+        inspect::<CacheEvent>(app, "CacheEvent", |event| {
+            format!("{:?} cached {:.1} at ({:.0}, {:.0})", event.species, event.food_amount, event.location.x, event.location.y)
+        });
+        inspect::<StewardRatingEvent>(app, "StewardRatingEvent", |event| format!("{:+.1} {}", event.change, event.reason));
+        inspect::<ShowNotificationEvent>(app, "ShowNotificationEvent", |event| format!("{:?}", event.notification));
+        inspect::<SaveCompleteEvent>(app, "SaveCompleteEvent", |event| {
+            format!("slot {}, {}", event.slot, if event.success { "ok" } else { "failed" })
+        });
+        inspect::<LoadCompleteEvent>(app, "LoadCompleteEvent", |event| {
+            format!("slot {}, {}", event.slot, if event.success { "ok" } else { "failed" })
+        });
+        inspect::<CameraFocusEvent>(app, "CameraFocusEvent", |event| {
+            format!("({:.0}, {:.0})", event.position.x, event.position.y)
+        });
+        inspect::<SearchJumpEvent>(app, "SearchJumpEvent", |event| format!("{:?}", event.target));
+        inspect::<ConsoleCommand>(app, "ConsoleCommand", |event| format!("{} {}", event.command, event.args.join(" ")));
+    }
+}
+
+// Logs every event of one type, read in PostUpdate so anything sent during Update shows the same frame
+fn inspect<E: Event>(app: &mut App, name: &'static str, summary: fn(&E) -> String) {
+    if !app.world().contains_resource::<Events<E>>() {
+        warn!("Event inspector: {} is not registered, skipping", name);
+        return;
+    }
+
+    app.add_systems(PostUpdate, move |mut events: EventReader<E>, mut log: ResMut<EventLog>, time: Res<Time>, time_state: Res<TimeState>| {
+        if log.paused {
+            events.clear();
+            return;
+        }
+        for event in events.read() {
+            log.record(LoggedEvent {
+                real_time: time.elapsed_secs_f64(),
+                game_day: time_state.day_of_year,
+                game_hour: time_state.hour,
+                kind: name,
+                summary: summary(event),
+            });
+        }
+    });
+}
+
+// Resources
+pub struct LoggedEvent {
+    pub real_time: f64,
+    pub game_day: u32,
+    pub game_hour: f32,
+    pub kind: &'static str,
+    pub summary: String,
+}
+
+#[derive(Resource, Default)]
+pub struct EventLog {
+    pub entries: VecDeque<LoggedEvent>,
+    pub paused: bool,
+    // Every event type seen so far, for the filter buttons
+    pub kinds: BTreeSet<&'static str>,
+}
+
+impl EventLog {
+    fn record(&mut self, event: LoggedEvent) {
+        self.kinds.insert(event.kind);
+        self.entries.push_back(event);
+        if self.entries.len() > LOG_CAPACITY {
+            self.entries.pop_front();
+        }
+    }
+}
+
+#[derive(Resource)]
+pub struct EventInspector {
+    pub is_open: bool,
+    // Event types filtered out of the list
+    pub hidden: HashSet<&'static str>,
+    // The log can fill many times a second, so the window redraws on a timer
+    refresh_timer: Timer,
+    panel_dirty: bool,
+}
+
+impl Default for EventInspector {
+    fn default() -> Self {
+        Self {
+            is_open: false,
+            hidden: HashSet::new(),
+            refresh_timer: Timer::from_seconds(0.25, TimerMode::Repeating),
+            panel_dirty: false,
+        }
+    }
+}
+
+// Components
+#[derive(Component)]
+pub struct EventInspectorPanel;
+
+#[derive(Component)]
+pub enum InspectorButton {
+    Kind(&'static str),
+    ShowAll,
+    Pause,
+    Clear,
+}
+
+fn toggle_event_inspector_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut inspector: ResMut<EventInspector>,
+) {
+    if keyboard.just_pressed(KeyCode::F8) {
+        inspector.is_open = !inspector.is_open;
+        inspector.panel_dirty = true;
+        info!("Event inspector {}", if inspector.is_open { "opened" } else { "closed" });
+    }
+}
+
+fn event_inspector_button_system(
+    interaction_query: Query<(&Interaction, &InspectorButton), (Changed<Interaction>, With<Button>)>,
+    mut inspector: ResMut<EventInspector>,
+    mut log: ResMut<EventLog>,
+) {
+    for (interaction, button) in &interaction_query {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        match button {
+            InspectorButton::Kind(kind) => {
+                if !inspector.hidden.remove(kind) {
+                    inspector.hidden.insert(kind);
+                }
+            }
+            InspectorButton::ShowAll => inspector.hidden.clear(),
+            InspectorButton::Pause => log.paused = !log.paused,
+            InspectorButton::Clear => log.entries.clear(),
+        }
+        inspector.panel_dirty = true;
+    }
+}
+
+fn refresh_event_inspector_system(
+    mut commands: Commands,
+    mut inspector: ResMut<EventInspector>,
+    log: Res<EventLog>,
+    panel_query: Query<Entity, With<EventInspectorPanel>>,
+    time: Res<Time>,
+) {
+    inspector.refresh_timer.tick(time.delta());
+    let log_updated = inspector.is_open && log.is_changed() && inspector.refresh_timer.finished();
+    if !inspector.panel_dirty && !log_updated {
+        return;
+    }
+    inspector.panel_dirty = false;
+
+    for panel in &panel_query {
+        commands.entity(panel).despawn();
+    }
+    if inspector.is_open {
+        spawn_event_inspector(&mut commands, &inspector, &log);
+    }
+}
+
+fn spawn_inspector_button(parent: &mut ChildSpawnerCommands, label: &str, active: bool, button: InspectorButton) {
+    let background = if active { Color::srgb(0.6, 0.5, 0.4) } else { Color::srgb(0.8, 0.76, 0.7) };
+    parent.spawn((
+        Button,
+        Node {
+            padding: UiRect::axes(Val::Px(6.0), Val::Px(2.0)),
+            ..default()
+        },
+        BackgroundColor(background),
+        BorderRadius::all(Val::Px(4.0)),
+        button,
+    )).with_children(|button| {
+        button.spawn((
+            Text::new(label),
+            TextFont { font_size: 10.0, ..default() },
+            TextColor(Color::WHITE),
+        ));
+    });
+}
+
+fn spawn_event_inspector(commands: &mut Commands, inspector: &EventInspector, log: &EventLog) {
+    let shown: Vec<&LoggedEvent> = log.entries.iter()
+        .rev()
+        .filter(|event| !inspector.hidden.contains(event.kind))
+        .take(SHOWN_ROWS)
+        .collect();
+
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            right: Val::Px(10.0),
+            top: Val::Px(60.0),
+            width: Val::Px(PANEL_WIDTH),
+            max_height: Val::Percent(85.0),
+            flex_direction: FlexDirection::Column,
+            padding: UiRect::all(Val::Px(10.0)),
+            row_gap: Val::Px(4.0),
+            overflow: Overflow::clip(),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.95, 0.92, 0.88, 0.95)),
+        BorderRadius::all(Val::Px(6.0)),
+        GlobalZIndex(40),
+        EventInspectorPanel,
+        Name::new("EventInspector"),
+    )).with_children(|panel| {
+        panel.spawn(Node {
+            width: Val::Percent(100.0),
+            justify_content: JustifyContent::SpaceBetween,
+            align_items: AlignItems::Center,
+            ..default()
+        }).with_children(|header| {
+            header.spawn((
+                Text::new(format!("Event Inspector  ({} logged{})", log.entries.len(), if log.paused { ", paused" } else { "" })),
+                TextFont { font_size: 16.0, ..default() },
+                TextColor(Color::srgb(0.3, 0.2, 0.1)),
+            ));
+            header.spawn(Node {
+                column_gap: Val::Px(4.0),
+                ..default()
+            }).with_children(|buttons| {
+                spawn_inspector_button(buttons, if log.paused { "Resume" } else { "Pause" }, true, InspectorButton::Pause);
+                spawn_inspector_button(buttons, "Clear", true, InspectorButton::Clear);
+            });
+        });
+
+        // One toggle per event type seen; dimmed types are filtered out
+        panel.spawn(Node {
+            width: Val::Percent(100.0),
+            flex_wrap: FlexWrap::Wrap,
+            column_gap: Val::Px(3.0),
+            row_gap: Val::Px(3.0),
+            margin: UiRect::bottom(Val::Px(4.0)),
+            ..default()
+        }).with_children(|filters| {
+            spawn_inspector_button(filters, "All", inspector.hidden.is_empty(), InspectorButton::ShowAll);
+            for kind in &log.kinds {
+                let label = kind.trim_end_matches("Event");
+                spawn_inspector_button(filters, label, !inspector.hidden.contains(kind), InspectorButton::Kind(kind));
+            }
+        });
+
+        if shown.is_empty() {
+            panel.spawn((
+                Text::new("No events yet"),
+                TextFont { font_size: 12.0, ..default() },
+                TextColor(Color::srgb(0.5, 0.4, 0.3)),
+            ));
+        }
+
+        for event in shown {
+            panel.spawn(Node {
+                width: Val::Percent(100.0),
+                column_gap: Val::Px(8.0),
+                ..default()
+            }).with_children(|row| {
+                let hour = event.game_hour.rem_euclid(24.0);
+                row.spawn((
+                    Text::new(format!("{:>8.2}s  D{} {:02}:{:02}", event.real_time, event.game_day, hour as u32, (hour.fract() * 60.0) as u32)),
+                    TextFont { font_size: 11.0, ..default() },
+                    TextColor(Color::srgb(0.5, 0.4, 0.3)),
+                    Node {
+                        width: Val::Px(130.0),
+                        flex_shrink: 0.0,
+                        ..default()
+                    },
+                ));
+                row.spawn((
+                    Text::new(event.kind),
+                    TextFont { font_size: 11.0, ..default() },
+                    TextColor(Color::srgb(0.3, 0.2, 0.1)),
+                    Node {
+                        width: Val::Px(170.0),
+                        flex_shrink: 0.0,
+                        ..default()
+                    },
+                ));
+                row.spawn((
+                    Text::new(event.summary.clone()),
+                    TextFont { font_size: 11.0, ..default() },
+                    TextColor(Color::srgb(0.2, 0.1, 0.05)),
+                ));
+            });
+        }
+    });
+}
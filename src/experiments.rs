@@ -0,0 +1,489 @@
+// Yard Experiments - Compare the yard before and after a layout change
+//
+// `experiment start [days]` snapshots the feeders and placed objects as the baseline layout and
+// starts a trial of that many in-game days (7 by default). The baseline window is the same number
+// of days just before the snapshot, read back from the sighting log and the yard's daily visit
+// counts, so there's nothing to wait for before making changes. The day of the snapshot is left
+// out of both windows while the yard is rearranged, and once the trial's last day is over a report
+// compares species diversity, visits and rare sightings across the two windows next to what moved
+// in the layout. `experiment` toggles the panel and `experiment cancel` drops a running trial.
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use crate::bird::BirdSpecies;
+use crate::catalog::components::PlaceableObject;
+use crate::debug_console::ConsoleCommand;
+use crate::environment::resources::TimeState;
+use crate::feeder::Feeder;
+use crate::feeder_stats::FeederStats;
+use crate::journal::resources::SightingLog;
+use crate::notifications::{resources::ShowNotificationEvent, components::NotificationType};
+
+const DEFAULT_TRIAL_DAYS: u32 = 7;
+const MAX_TRIAL_DAYS: u32 = 30;
+// Daily visit counts older than this can't be part of any baseline
+const VISIT_HISTORY_DAYS: u32 = MAX_TRIAL_DAYS + 1;
+// Objects closer than this to where they were count as not moved
+const MOVE_TOLERANCE: f32 = 4.0;
+const RARE_TIER: u8 = 3;
+
+pub struct ExperimentPlugin;
+
+impl Plugin for ExperimentPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<YardExperiments>()
+            .init_resource::<ExperimentPanel>()
+            .add_systems(Update, (experiment_command_system, experiment_tracking_system)
+                .chain()
+                .in_set(crate::GameSet::Simulation)
+                .run_if(in_state(crate::AppState::Playing)))
+            .add_systems(Update, (experiment_button_system, refresh_experiment_panel_system)
+                .chain()
+                .in_set(crate::GameSet::UI)
+                .run_if(in_state(crate::AppState::Playing)))
+            .add_systems(OnExit(crate::AppState::Playing), close_experiment_panel_system);
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LayoutItem {
+    pub label: String,
+    pub position: [f32; 2],
+}
+
+/// What the yard saw over a run of days
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WindowStats {
+    // Days in the window the yard has records for
+    pub days: u32,
+    pub species: Vec<BirdSpecies>,
+    pub visits: u32,
+    // One per rare species per day it was seen
+    pub rare_sightings: u32,
+    species_days: u32,
+}
+
+impl WindowStats {
+    fn measure(experiments: &YardExperiments, sighting_log: &SightingLog, first_day: u32, day_count: u32) -> Self {
+        let window = first_day..first_day + day_count;
+        let mut stats = Self::default();
+        let mut species: HashSet<BirdSpecies> = HashSet::new();
+        let mut recorded_days: HashSet<u32> = HashSet::new();
+
+        for (date, checklist) in &sighting_log.checklists {
+            let day = TimeState::day_index(date.year, date.day_of_year);
+            if !window.contains(&day) {
+                continue;
+            }
+            recorded_days.insert(day);
+            stats.species_days += checklist.species.len() as u32;
+            stats.rare_sightings += checklist.species.keys().filter(|species| species.rarity_tier() >= RARE_TIER).count() as u32;
+            species.extend(checklist.species.keys().copied());
+        }
+        for (day, visits) in experiments.daily_visits.range(window) {
+            recorded_days.insert(*day);
+            stats.visits += visits;
+        }
+
+        stats.days = recorded_days.len() as u32;
+        stats.species = species.into_iter().collect();
+        stats.species.sort_by_key(|species| format!("{:?}", species));
+        stats
+    }
+
+    pub fn visits_per_day(&self) -> f32 {
+        self.visits as f32 / self.days.max(1) as f32
+    }
+
+    pub fn species_per_day(&self) -> f32 {
+        self.species_days as f32 / self.days.max(1) as f32
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Experiment {
+    pub started_day: u32,
+    pub days: u32,
+    pub layout: Vec<LayoutItem>,
+    pub baseline: WindowStats,
+}
+
+impl Experiment {
+    fn trial_start(&self) -> u32 {
+        self.started_day + 1
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExperimentReport {
+    pub days: u32,
+    pub baseline: WindowStats,
+    pub trial: WindowStats,
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    pub moved: Vec<String>,
+}
+
+// Resources
+#[derive(Resource, Debug, Clone, Default, Serialize, Deserialize)]
+pub struct YardExperiments {
+    // Visits to feeders and baths across the yard, by day index
+    pub daily_visits: BTreeMap<u32, u32>,
+    pub active: Option<Experiment>,
+    pub last_report: Option<ExperimentReport>,
+    // Feeder stats' running visit total when last tallied; unset until the first tally after a load
+    #[serde(skip)]
+    counted_visits: Option<u64>,
+}
+
+#[derive(Resource, Default)]
+pub struct ExperimentPanel {
+    pub is_open: bool,
+    panel_dirty: bool,
+}
+
+#[derive(Component)]
+pub struct ExperimentPanelRoot;
+
+#[derive(Component)]
+pub enum ExperimentButton {
+    Cancel,
+    Close,
+}
+
+fn snapshot_layout<'a>(
+    feeders: impl Iterator<Item = (&'a Feeder, &'a Transform)>,
+    objects: impl Iterator<Item = (&'a PlaceableObject, &'a Transform)>,
+) -> Vec<LayoutItem> {
+    let feeders = feeders.map(|(feeder, transform)| (format!("{:?} Feeder", feeder.feeder_type), transform));
+    let objects = objects.map(|(object, transform)| (object.item_type.name().to_string(), transform));
+    feeders.chain(objects)
+        .map(|(label, transform)| LayoutItem { label, position: [transform.translation.x, transform.translation.y] })
+        .collect()
+}
+
+// Pairs what stayed put first, then items of the same kind as moves; the rest came or went
+fn compare_layouts(baseline: &[LayoutItem], current: &[LayoutItem]) -> (Vec<String>, Vec<String>, Vec<String>) {
+    let mut removed: Vec<&LayoutItem> = baseline.iter().collect();
+    let mut added: Vec<&LayoutItem> = Vec::new();
+    for item in current {
+        let unchanged = removed.iter().position(|old| {
+            old.label == item.label && Vec2::from(old.position).distance(Vec2::from(item.position)) <= MOVE_TOLERANCE
+        });
+        match unchanged {
+            Some(index) => { removed.swap_remove(index); }
+            None => added.push(item),
+        }
+    }
+
+    let mut moved = Vec::new();
+    added.retain(|item| {
+        let Some(index) = removed.iter().position(|old| old.label == item.label) else { return true };
+        removed.swap_remove(index);
+        moved.push(item.label.clone());
+        false
+    });
+
+    let labels = |items: Vec<&LayoutItem>| {
+        let mut labels: Vec<String> = items.into_iter().map(|item| item.label.clone()).collect();
+        labels.sort();
+        labels
+    };
+    let (added, removed) = (labels(added), labels(removed));
+    moved.sort();
+    (added, removed, moved)
+}
+
+// Tallies repeated entries as "Bird Bath x2"
+fn summarize(labels: &[String]) -> String {
+    let mut counts: Vec<(&String, u32)> = Vec::new();
+    for label in labels {
+        match counts.iter_mut().find(|(seen, _)| *seen == label) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((label, 1)),
+        }
+    }
+    counts.into_iter()
+        .map(|(label, count)| if count == 1 { label.clone() } else { format!("{} x{}", label, count) })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn experiment_command_system(
+    mut command_events: EventReader<ConsoleCommand>,
+    mut experiments: ResMut<YardExperiments>,
+    mut panel: ResMut<ExperimentPanel>,
+    sighting_log: Res<SightingLog>,
+    time_state: Res<TimeState>,
+    feeder_query: Query<(&Feeder, &Transform)>,
+    object_query: Query<(&PlaceableObject, &Transform)>,
+    mut notifications: EventWriter<ShowNotificationEvent>,
+) {
+    for command in command_events.read() {
+        if command.command != "experiment" {
+            continue;
+        }
+        match command.args.first().map(|arg| arg.as_str()) {
+            None => panel.is_open = !panel.is_open,
+            Some("start") => {
+                let days = command.args.get(1)
+                    .and_then(|arg| arg.parse::<u32>().ok())
+                    .unwrap_or(DEFAULT_TRIAL_DAYS)
+                    .clamp(1, MAX_TRIAL_DAYS);
+                let started_day = time_state.absolute_day();
+                let baseline = WindowStats::measure(&experiments, &sighting_log, started_day.saturating_sub(days), days);
+                let layout = snapshot_layout(feeder_query.iter(), object_query.iter());
+                info!("🧪 Experiment started: {} items in the baseline layout, {} of {} baseline days on record",
+                    layout.len(), baseline.days, days);
+                experiments.active = Some(Experiment { started_day, days, layout, baseline });
+                notifications.write(ShowNotificationEvent {
+                    notification: NotificationType::Info {
+                        message: format!("Layout saved as the baseline. Make your changes today - the {}-day trial starts tomorrow", days),
+                    },
+                });
+                panel.is_open = true;
+            }
+            Some("cancel") => {
+                if experiments.active.take().is_some() {
+                    info!("🧪 Experiment cancelled");
+                }
+            }
+            // The console reports the usage
+            Some(_) => continue,
+        }
+        panel.panel_dirty = true;
+    }
+}
+
+// Logs the day's visits and closes out a trial once its last day has passed
+#[allow(clippy::too_many_arguments)]
+pub fn experiment_tracking_system(
+    mut experiments: ResMut<YardExperiments>,
+    mut panel: ResMut<ExperimentPanel>,
+    feeder_stats: Res<FeederStats>,
+    sighting_log: Res<SightingLog>,
+    time_state: Res<TimeState>,
+    feeder_query: Query<(&Feeder, &Transform)>,
+    object_query: Query<(&PlaceableObject, &Transform)>,
+    mut notifications: EventWriter<ShowNotificationEvent>,
+) {
+    let today = time_state.absolute_day();
+    let new_visits = experiments.counted_visits.map_or(0, |counted| feeder_stats.yard_visits.saturating_sub(counted));
+    experiments.counted_visits = Some(feeder_stats.yard_visits);
+    if new_visits > 0 {
+        *experiments.daily_visits.entry(today).or_default() += new_visits as u32;
+        experiments.daily_visits.retain(|day, _| *day + VISIT_HISTORY_DAYS > today);
+    }
+
+    let Some(experiment) = experiments.active.as_ref() else { return };
+    let trial_end = experiment.trial_start() + experiment.days;
+    if today < trial_end {
+        if panel.is_open && new_visits > 0 {
+            panel.panel_dirty = true;
+        }
+        return;
+    }
+
+    let trial = WindowStats::measure(&experiments, &sighting_log, experiment.trial_start(), experiment.days);
+    let layout = snapshot_layout(feeder_query.iter(), object_query.iter());
+    let (added, removed, moved) = compare_layouts(&experiment.layout, &layout);
+    let report = ExperimentReport { days: experiment.days, baseline: experiment.baseline.clone(), trial, added, removed, moved };
+    info!("🧪 Experiment finished: {} species vs {} at baseline, {:.1} visits a day vs {:.1}",
+        report.trial.species.len(), report.baseline.species.len(), report.trial.visits_per_day(), report.baseline.visits_per_day());
+    notifications.write(ShowNotificationEvent {
+        notification: NotificationType::Info {
+            message: format!("Your {}-day yard experiment is done - the comparison report is open", report.days),
+        },
+    });
+    experiments.active = None;
+    experiments.last_report = Some(report);
+    panel.is_open = true;
+    panel.panel_dirty = true;
+}
+
+pub fn experiment_button_system(
+    interaction_query: Query<(&Interaction, &ExperimentButton), Changed<Interaction>>,
+    mut experiments: ResMut<YardExperiments>,
+    mut panel: ResMut<ExperimentPanel>,
+) {
+    for (interaction, button) in &interaction_query {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        match button {
+            ExperimentButton::Cancel => experiments.active = None,
+            ExperimentButton::Close => panel.is_open = false,
+        }
+        panel.panel_dirty = true;
+    }
+}
+
+fn spawn_experiment_button(parent: &mut ChildSpawnerCommands, label: &str, button: ExperimentButton) {
+    parent.spawn((
+        Button,
+        Node {
+            padding: UiRect::axes(Val::Px(6.0), Val::Px(2.0)),
+            ..default()
+        },
+        BackgroundColor(Color::srgb(0.6, 0.5, 0.4)),
+        BorderRadius::all(Val::Px(4.0)),
+        button,
+    )).with_children(|button| {
+        button.spawn((
+            Text::new(label),
+            TextFont { font_size: 10.0, ..default() },
+            TextColor(Color::WHITE),
+        ));
+    });
+}
+
+fn spawn_line(parent: &mut ChildSpawnerCommands, text: String, font_size: f32, color: Color) {
+    parent.spawn((
+        Text::new(text),
+        TextFont { font_size, ..default() },
+        TextColor(color),
+    ));
+}
+
+// Baseline, trial and the change between them, with the change colored by which way it went
+fn spawn_comparison_row(parent: &mut ChildSpawnerCommands, label: &str, baseline: f32, trial: f32, decimals: usize) {
+    let change = trial - baseline;
+    let color = if change > 0.05 {
+        Color::srgb(0.2, 0.5, 0.2)
+    } else if change < -0.05 {
+        Color::srgb(0.7, 0.3, 0.1)
+    } else {
+        Color::srgb(0.4, 0.3, 0.2)
+    };
+    parent.spawn(Node {
+        width: Val::Percent(100.0),
+        justify_content: JustifyContent::SpaceBetween,
+        ..default()
+    }).with_children(|row| {
+        spawn_line(row, label.to_string(), 12.0, Color::srgb(0.3, 0.2, 0.1));
+        spawn_line(row, format!("{:.*}  ->  {:.*}  ({:+.*})", decimals, baseline, decimals, trial, decimals, change), 12.0, color);
+    });
+}
+
+pub fn refresh_experiment_panel_system(
+    mut commands: Commands,
+    mut panel: ResMut<ExperimentPanel>,
+    experiments: Res<YardExperiments>,
+    time_state: Res<TimeState>,
+    panel_query: Query<Entity, With<ExperimentPanelRoot>>,
+) {
+    if !panel.panel_dirty {
+        return;
+    }
+    panel.panel_dirty = false;
+
+    for root in &panel_query {
+        commands.entity(root).despawn();
+    }
+    if !panel.is_open {
+        return;
+    }
+
+    let heading = Color::srgb(0.3, 0.2, 0.1);
+    let body = Color::srgb(0.4, 0.3, 0.2);
+    let hint = Color::srgb(0.5, 0.4, 0.3);
+
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            right: Val::Px(20.0),
+            top: Val::Px(80.0),
+            width: Val::Px(380.0),
+            max_height: Val::Percent(80.0),
+            flex_direction: FlexDirection::Column,
+            padding: UiRect::all(Val::Px(10.0)),
+            row_gap: Val::Px(4.0),
+            overflow: Overflow::clip(),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.95, 0.92, 0.88, 0.95)),
+        BorderRadius::all(Val::Px(8.0)),
+        GlobalZIndex(40),
+        ExperimentPanelRoot,
+        Name::new("YardExperiment"),
+    )).with_children(|root| {
+        root.spawn(Node {
+            width: Val::Percent(100.0),
+            justify_content: JustifyContent::SpaceBetween,
+            align_items: AlignItems::Center,
+            ..default()
+        }).with_children(|header| {
+            spawn_line(header, "Yard Experiment".to_string(), 16.0, heading);
+            spawn_experiment_button(header, "Close", ExperimentButton::Close);
+        });
+
+        if let Some(experiment) = &experiments.active {
+            let today = time_state.absolute_day();
+            let status = if today < experiment.trial_start() {
+                "Setting up: rearrange the yard today, the trial starts tomorrow".to_string()
+            } else {
+                format!("Trial day {} of {}", today - experiment.trial_start() + 1, experiment.days)
+            };
+            spawn_line(root, status, 12.0, body);
+            let trial_so_far = experiments.daily_visits
+                .range(experiment.trial_start()..=today)
+                .map(|(_, visits)| *visits)
+                .sum::<u32>();
+            spawn_line(root, format!("Baseline: {} species, {:.1} visits a day over {} days",
+                experiment.baseline.species.len(), experiment.baseline.visits_per_day(), experiment.baseline.days), 12.0, body);
+            spawn_line(root, format!("Trial so far: {} visits", trial_so_far), 12.0, body);
+            root.spawn(Node { margin: UiRect::top(Val::Px(4.0)), ..default() }).with_children(|row| {
+                spawn_experiment_button(row, "Cancel experiment", ExperimentButton::Cancel);
+            });
+        }
+
+        let Some(report) = &experiments.last_report else {
+            if experiments.active.is_none() {
+                spawn_line(root, "Type 'experiment start [days]' in the console to save today's layout as the baseline".to_string(), 12.0, hint);
+            }
+            return;
+        };
+
+        root.spawn(Node { margin: UiRect::top(Val::Px(6.0)), ..default() }).with_children(|title| {
+            spawn_line(title, format!("Last report: {} days before vs after", report.days), 14.0, heading);
+        });
+        if report.baseline.days < report.days {
+            spawn_line(root, format!("Only {} of the {} baseline days were on record; daily figures use those", report.baseline.days, report.days), 11.0, hint);
+        }
+        spawn_comparison_row(root, "Species seen", report.baseline.species.len() as f32, report.trial.species.len() as f32, 0);
+        spawn_comparison_row(root, "Species per day", report.baseline.species_per_day(), report.trial.species_per_day(), 1);
+        spawn_comparison_row(root, "Visits per day", report.baseline.visits_per_day(), report.trial.visits_per_day(), 1);
+        spawn_comparison_row(root, "Rare sightings", report.baseline.rare_sightings as f32, report.trial.rare_sightings as f32, 0);
+
+        let newcomers: Vec<String> = report.trial.species.iter()
+            .filter(|species| !report.baseline.species.contains(species))
+            .map(|species| format!("{:?}", species))
+            .collect();
+        if !newcomers.is_empty() {
+            spawn_line(root, format!("New in the trial: {}", newcomers.join(", ")), 11.0, body);
+        }
+
+        let changes = [("Added", &report.added), ("Removed", &report.removed), ("Moved", &report.moved)];
+        if changes.iter().all(|(_, labels)| labels.is_empty()) {
+            spawn_line(root, "Layout: unchanged from the baseline".to_string(), 11.0, body);
+        }
+        for (label, labels) in changes {
+            if !labels.is_empty() {
+                spawn_line(root, format!("{}: {}", label, summarize(labels)), 11.0, body);
+            }
+        }
+    });
+}
+
+pub fn close_experiment_panel_system(
+    mut commands: Commands,
+    mut panel: ResMut<ExperimentPanel>,
+    panel_query: Query<Entity, With<ExperimentPanelRoot>>,
+) {
+    panel.is_open = false;
+    for root in &panel_query {
+        commands.entity(root).despawn();
+    }
+}
@@ -0,0 +1,378 @@
+// Family Groups - Juvenile Begging and Parental Feeding
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+use crate::bird::{BirdSpecies, Bird};
+use crate::bird_ai::components::{BirdAI, BirdState, Blackboard, InternalState};
+use crate::animation::components::AnimatedBird;
+use crate::environment::resources::TimeState;
+use crate::environment::components::Season;
+use crate::photo_mode::components::PhotoTakenEvent;
+use crate::journal::resources::{ResearchMissionManager, DataType};
+use crate::sanctuary_management::{NestingEvent, NestingEventType};
+use crate::audio::captions::SoundCaption;
+use crate::audio::components::Vocalization;
+
+pub struct FamilyGroupsPlugin;
+
+impl Plugin for FamilyGroupsPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<FamilyGroupManager>()
+            .add_event::<JuvenileFedEvent>()
+            .add_systems(Update, (
+                fledgling_family_system,
+                summer_family_spawn_system,
+                juvenile_follow_system,
+                begging_animation_system,
+                parent_feeding_system,
+                juvenile_maturation_system,
+                family_photo_recognition_system,
+            ).chain()
+                // Runs after the behavior tree so juvenile states are not overwritten each tick
+                .after(crate::bird_ai::systems::behavior_tree_system)
+                .in_set(crate::GameSet::AI)
+                .run_if(in_state(crate::AppState::Playing)));
+    }
+}
+
+// Resources
+#[derive(Resource)]
+pub struct FamilyGroupManager {
+    pub spawn_timer: Timer,
+    pub max_families: usize,
+    pub families_observed: u32,
+    pub feedings_observed: u32,
+    pub family_photos: u32,
+}
+
+impl Default for FamilyGroupManager {
+    fn default() -> Self {
+        Self {
+            spawn_timer: Timer::from_seconds(90.0, TimerMode::Repeating),
+            max_families: 2,
+            families_observed: 0,
+            feedings_observed: 0,
+            family_photos: 0,
+        }
+    }
+}
+
+// Components
+#[derive(Component)]
+pub struct FamilyGroup {
+    pub species: BirdSpecies,
+    pub juveniles: Vec<Entity>,
+}
+
+#[derive(Component)]
+pub struct Juvenile {
+    pub parent: Entity,
+    pub age_days: f32,
+    pub independence_age_days: f32,
+    pub follow_offset: Vec2,
+    pub beg_call_timer: Timer,
+    pub times_fed: u32,
+}
+
+// Events
+#[derive(Event)]
+pub struct JuvenileFedEvent {
+    pub parent: Entity,
+    pub juvenile: Entity,
+    pub species: BirdSpecies,
+    pub position: Vec3,
+}
+
+const BEGGING_RANGE: f32 = 60.0;
+const FEEDING_RANGE: f32 = 30.0;
+
+// Spawn families when nest boxes report fledglings
+fn fledgling_family_system(
+    mut commands: Commands,
+    mut nesting_events: EventReader<NestingEvent>,
+    mut manager: ResMut<FamilyGroupManager>,
+    box_query: Query<&Transform>,
+) {
+    for event in nesting_events.read() {
+        let NestingEventType::Fledglings(count) = event.event_type else {
+            continue;
+        };
+        let Ok(box_transform) = box_query.get(event.box_id) else {
+            continue;
+        };
+
+        let position = box_transform.translation.truncate();
+        spawn_family_group(&mut commands, event.species, position, count.clamp(1, 4));
+        manager.families_observed += 1;
+        info!("🐣 {:?} fledglings left the nest with a parent", event.species);
+    }
+}
+
+// Standalone family groups arrive during summer even without a nest box
+fn summer_family_spawn_system(
+    mut commands: Commands,
+    mut manager: ResMut<FamilyGroupManager>,
+    time_state: Res<TimeState>,
+    time: Res<Time>,
+    family_query: Query<&FamilyGroup>,
+) {
+    if time_state.get_season() != Season::Summer || time_state.daylight_factor() < 1.0 {
+        return;
+    }
+
+    manager.spawn_timer.tick(time.delta());
+    if !manager.spawn_timer.just_finished() || family_query.iter().count() >= manager.max_families {
+        return;
+    }
+
+    if rand::random::<f32>() > 0.35 {
+        return;
+    }
+
+    let candidates = [
+        BirdSpecies::Cardinal,
+        BirdSpecies::Robin,
+        BirdSpecies::Chickadee,
+        BirdSpecies::BlueJay,
+        BirdSpecies::HouseFinch,
+        BirdSpecies::Sparrow,
+    ];
+    let species = candidates[rand::random::<u32>() as usize % candidates.len()];
+    let position = Vec2::new(
+        (rand::random::<f32>() - 0.5) * 700.0,
+        (rand::random::<f32>() - 0.5) * 500.0,
+    );
+    let brood_size = 2 + rand::random::<u32>() % 2;
+
+    spawn_family_group(&mut commands, species, position, brood_size);
+    manager.families_observed += 1;
+    info!("🐣 A {:?} family group has arrived", species);
+}
+
+// Helper function to spawn an adult with its juveniles
+pub fn spawn_family_group(commands: &mut Commands, species: BirdSpecies, position: Vec2, brood_size: u32) -> Entity {
+    let parent = spawn_family_member(commands, species, position.extend(1.0), 10.0);
+
+    let mut juveniles = Vec::new();
+    for i in 0..brood_size {
+        let angle = i as f32 / brood_size as f32 * std::f32::consts::TAU;
+        let follow_offset = Vec2::new(angle.cos(), angle.sin()) * 25.0;
+        let juvenile = spawn_family_member(commands, species, (position + follow_offset).extend(1.0), 7.0);
+
+        commands.entity(juvenile).insert((
+            Juvenile {
+                parent,
+                age_days: 0.0,
+                independence_age_days: 10.0 + rand::random::<f32>() * 5.0,
+                follow_offset,
+                beg_call_timer: Timer::from_seconds(2.0 + i as f32 * 0.4, TimerMode::Repeating),
+                times_fed: 0,
+            },
+            BirdState::Following,
+        ));
+        juveniles.push(juvenile);
+    }
+
+    commands.entity(parent).insert(FamilyGroup { species, juveniles });
+    parent
+}
+
+fn spawn_family_member(commands: &mut Commands, species: BirdSpecies, position: Vec3, radius: f32) -> Entity {
+    commands.spawn((
+        Sprite {
+            image: Handle::default(), // Will be set by animation system
+            custom_size: Some(Vec2::splat(radius * 2.0)),
+            ..default()
+        },
+        Transform::from_translation(position),
+        RigidBody::Dynamic,
+        Collider::ball(radius),
+        GravityScale(0.0),
+        Damping { linear_damping: 2.0, angular_damping: 10.0 },
+        Bird { species },
+        crate::bird::Velocity(Vec2::ZERO),
+        BirdAI,
+        BirdState::Wandering,
+        Blackboard {
+            internal: InternalState {
+                hunger: 0.6,
+                thirst: 0.4,
+                energy: 0.8,
+                social_need: 0.2,
+                territorial_stress: 0.1,
+                fear: 0.0,
+            },
+            ..default()
+        },
+        AnimatedBird { species },
+        crate::animation::components::AnimationController::default(),
+        crate::animation::components::AnimationLibrary::default(),
+    )).id()
+}
+
+// Juveniles trail their parent and beg whenever the parent is feeding nearby
+fn juvenile_follow_system(
+    mut juvenile_query: Query<(&Juvenile, &mut Transform, &mut BirdState), With<BirdAI>>,
+    parent_query: Query<(&Transform, &BirdState), (With<FamilyGroup>, Without<Juvenile>)>,
+    time: Res<Time>,
+) {
+    for (juvenile, mut transform, mut state) in juvenile_query.iter_mut() {
+        let Ok((parent_transform, parent_state)) = parent_query.get(juvenile.parent) else {
+            continue;
+        };
+
+        let target = parent_transform.translation.truncate() + juvenile.follow_offset;
+        let current = transform.translation.truncate();
+        let distance_to_parent = current.distance(parent_transform.translation.truncate());
+
+        if current.distance(target) > 5.0 {
+            let speed = if distance_to_parent > 150.0 { 110.0 } else { 60.0 };
+            let direction = (target - current).normalize_or_zero();
+            transform.translation += (direction * speed * time.delta_secs()).extend(0.0);
+        }
+
+        let parent_feeding = matches!(parent_state, BirdState::Eating | BirdState::Foraging | BirdState::HoverFeeding);
+        *state = if parent_feeding && distance_to_parent < BEGGING_RANGE {
+            BirdState::Begging
+        } else {
+            BirdState::Following
+        };
+    }
+}
+
+// Wing-fluttering posture and begging calls
+fn begging_animation_system(
+    mut commands: Commands,
+    mut juvenile_query: Query<(&mut Juvenile, &mut Transform, &BirdState, &Bird)>,
+    asset_server: Res<AssetServer>,
+    time: Res<Time>,
+) {
+    let elapsed = time.elapsed_secs();
+
+    for (mut juvenile, mut transform, state, bird) in juvenile_query.iter_mut() {
+        if *state != BirdState::Begging {
+            transform.rotation = Quat::IDENTITY;
+            transform.scale = Vec3::ONE;
+            continue;
+        }
+
+        // Rapid wing flutter with a crouched, gaping posture
+        let flutter = (elapsed * 18.0).sin() * 0.15;
+        transform.rotation = Quat::from_rotation_z(flutter);
+        transform.scale = Vec3::new(1.0 + flutter.abs() * 0.5, 0.9, 1.0);
+
+        juvenile.beg_call_timer.tick(time.delta());
+        if juvenile.beg_call_timer.just_finished() {
+            commands.spawn((
+                AudioPlayer::new(asset_server.load("audio/juvenile_begging.ogg")),
+                PlaybackSettings::ONCE.with_spatial(true),
+                Transform::from_translation(transform.translation),
+                Vocalization { species: bird.species, level: 0.3 },
+                SoundCaption::call(bird.species, "begging calls"),
+            ));
+        }
+    }
+}
+
+// Parents feeding at a feeder pass food to begging juveniles
+fn parent_feeding_system(
+    mut parent_query: Query<(Entity, &Transform, &BirdState, &FamilyGroup, &mut Blackboard), Without<Juvenile>>,
+    mut juvenile_query: Query<(&mut Juvenile, &Transform, &BirdState, &mut Blackboard), Without<FamilyGroup>>,
+    mut fed_events: EventWriter<JuvenileFedEvent>,
+    mut manager: ResMut<FamilyGroupManager>,
+    time: Res<Time>,
+) {
+    for (parent_entity, parent_transform, parent_state, family, mut parent_blackboard) in parent_query.iter_mut() {
+        if !matches!(parent_state, BirdState::Eating | BirdState::Foraging | BirdState::HoverFeeding) {
+            continue;
+        }
+
+        for &juvenile_entity in &family.juveniles {
+            let Ok((mut juvenile, juvenile_transform, juvenile_state, mut juvenile_blackboard)) = juvenile_query.get_mut(juvenile_entity) else {
+                continue;
+            };
+
+            if *juvenile_state != BirdState::Begging
+                || juvenile_transform.translation.distance(parent_transform.translation) > FEEDING_RANGE
+                || juvenile_blackboard.internal.hunger < 0.3
+            {
+                continue;
+            }
+
+            // Feeding passes a little of the parent's energy to the juvenile
+            let amount = 0.4 * time.delta_secs();
+            juvenile_blackboard.internal.hunger = (juvenile_blackboard.internal.hunger - amount).max(0.0);
+            parent_blackboard.internal.energy = (parent_blackboard.internal.energy - amount * 0.25).max(0.0);
+
+            if juvenile_blackboard.internal.hunger < 0.3 {
+                juvenile.times_fed += 1;
+                manager.feedings_observed += 1;
+                fed_events.write(JuvenileFedEvent {
+                    parent: parent_entity,
+                    juvenile: juvenile_entity,
+                    species: family.species,
+                    position: juvenile_transform.translation,
+                });
+            }
+        }
+    }
+}
+
+// Juveniles grow up and become independent birds
+fn juvenile_maturation_system(
+    mut commands: Commands,
+    mut juvenile_query: Query<(Entity, &mut Juvenile, &mut Transform)>,
+    mut family_query: Query<(Entity, &mut FamilyGroup)>,
+    time_state: Res<TimeState>,
+    time: Res<Time>,
+) {
+    // Convert real seconds into game days using the environment clock
+    let days_elapsed = time.delta_secs() / (time_state.time_speed * 24.0);
+
+    for (entity, mut juvenile, mut transform) in juvenile_query.iter_mut() {
+        juvenile.age_days += days_elapsed;
+
+        let orphaned = family_query.get(juvenile.parent).is_err();
+        if juvenile.age_days >= juvenile.independence_age_days || orphaned {
+            transform.rotation = Quat::IDENTITY;
+            transform.scale = Vec3::ONE;
+            commands.entity(entity).remove::<Juvenile>().insert(BirdState::Wandering);
+        }
+    }
+
+    for (parent, mut family) in family_query.iter_mut() {
+        family.juveniles.retain(|juvenile| {
+            juvenile_query.get(*juvenile).map(|(_, j, _)| j.age_days < j.independence_age_days).unwrap_or(false)
+        });
+
+        if family.juveniles.is_empty() {
+            commands.entity(parent).remove::<FamilyGroup>();
+        }
+    }
+}
+
+// Photos of begging juveniles count as breeding behavior observations
+fn family_photo_recognition_system(
+    mut photo_events: EventReader<PhotoTakenEvent>,
+    camera_query: Query<&Transform, With<crate::photo_mode::components::PhotoTarget>>,
+    juvenile_query: Query<(&Transform, &BirdState), With<Juvenile>>,
+    mut manager: ResMut<FamilyGroupManager>,
+    mut research: ResMut<ResearchMissionManager>,
+) {
+    for _event in photo_events.read() {
+        let Ok(camera_transform) = camera_query.single() else {
+            continue;
+        };
+        let camera_pos = camera_transform.translation.truncate();
+
+        let begging_in_frame = juvenile_query.iter().any(|(transform, state)| {
+            *state == BirdState::Begging && transform.translation.truncate().distance(camera_pos) < 200.0
+        });
+
+        if begging_in_frame {
+            manager.family_photos += 1;
+            *research.collected_data.entry(DataType::BreedingBehavior).or_insert(0) += 1;
+            info!("📸 Family feeding moment recorded in the journal ({} total)", manager.family_photos);
+        }
+    }
+}
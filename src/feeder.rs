@@ -14,7 +14,7 @@ impl Plugin for FeederPlugin {
                 update_feeder_capacity_system,
                 update_feeder_visual_system,
                 handle_feeder_upgrade_system,
-            ).run_if(in_state(crate::AppState::Playing)));
+            ).in_set(crate::GameSet::Simulation).run_if(in_state(crate::AppState::Playing)));
     }
 }
 
@@ -28,7 +28,7 @@ pub struct Feeder {
     pub upgrade_level: u32,   // 0 = basic, higher = better
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum FeederType {
     Seed,
     Suet,
@@ -88,6 +88,28 @@ impl FeederType {
         self.base_depletion_rate() * (1.0 / (1.0 + upgrade_level as f32 * 0.15))
     }
     
+    // Nutritional quality of the food offered (0.0-1.0), improved by upgrades
+    pub fn food_quality(&self, upgrade_level: u32) -> f32 {
+        let base = match self {
+            Self::Seed => 0.6,
+            Self::Suet => 0.8,   // High-fat, high-energy food
+            Self::Nectar => 0.5,
+            Self::Fruit => 0.65,
+            Self::Ground => 0.4, // Mixed scatter, often spoiled by weather
+        };
+        (base + upgrade_level as f32 * 0.08).min(1.0)
+    }
+
+    // Cost of refilling from empty, matched to catalog food prices
+    pub fn refill_cost(&self) -> u32 {
+        match self {
+            Self::Seed | Self::Ground => 30,
+            Self::Suet => 40,
+            Self::Nectar => 20,
+            Self::Fruit => 25,
+        }
+    }
+
     pub fn upgrade_cost(upgrade_level: u32) -> u32 {
         match upgrade_level {
             0 => 100,  // Level 0 -> 1
@@ -216,10 +238,12 @@ pub struct FeederDepletionEvent {
 fn update_feeder_capacity_system(
     mut feeder_query: Query<(Entity, &mut Feeder, &mut ProvidesUtility)>,
     mut depletion_events: EventReader<FeederDepletionEvent>,
+    difficulty: Res<crate::difficulty::DifficultySettings>,
 ) {
     for event in depletion_events.read() {
         if let Ok((_, mut feeder, mut utility)) = feeder_query.get_mut(event.feeder_entity) {
-            feeder.current_capacity = (feeder.current_capacity - event.amount).max(0.0);
+            let amount = event.amount * difficulty.depletion_multiplier();
+            feeder.current_capacity = (feeder.current_capacity - amount).max(0.0);
             
             // Reduce utility as feeder empties
             let capacity_ratio = feeder.current_capacity / feeder.max_capacity;
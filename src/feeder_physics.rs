@@ -0,0 +1,377 @@
+// Feeder Physics - Hanging feeders swing on Rapier joints, platforms tip under heavy birds, full feeders spill seed
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+use crate::bird::Bird;
+use crate::bird_ai::components::{BirdAI, BirdAction, BirdState, ProvidesUtility, SmartObject};
+use crate::bird_data::BirdDataRegistry;
+use crate::catalog::components::{PlaceableObject, PlaceableItemType};
+use crate::elevation::{Elevation, HeightTier};
+use crate::environment::components::Weather;
+use crate::environment::resources::{TimeState, WeatherState};
+use crate::feeder::{Feeder, FeederDepletionEvent, FeederType};
+use crate::notifications::{resources::ShowNotificationEvent, components::NotificationType};
+
+// Length of the wire a hanging feeder swings from
+const HANG_LENGTH: f32 = 40.0;
+// Platforms pivot on the top of their pole
+const PLATFORM_PIVOT_DROP: f32 = 8.0;
+const PLATFORM_TILT_LIMIT: f32 = 0.45;
+const PERCH_RANGE: f32 = 35.0;
+// Torque from a landing or departing bird, per size category
+const LANDING_TORQUE: f32 = 60.0;
+const WIND_FORCE: f32 = 40.0;
+// Past these a full feeder loses seed over the side
+const SPILL_SWING_SPEED: f32 = 2.5;
+const SPILL_TILT: f32 = 0.3;
+const OVERFILL_RATIO: f32 = 0.85;
+const SPILL_AMOUNT: f32 = 4.0;
+const SPILL_COOLDOWN: f32 = 8.0;
+const SPILL_MERGE_RANGE: f32 = 30.0;
+const SPILL_FEED_RANGE: f32 = 30.0;
+// A catcher tray under the feeder keeps the spill off the ground
+const CATCHER_RANGE: f32 = 40.0;
+// Seed left on the ground this long goes stale and starts drawing rodents
+pub const STALE_SPILL_DAYS: f32 = 1.0;
+
+pub struct FeederPhysicsPlugin;
+
+impl Plugin for FeederPhysicsPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_systems(Update, (
+                mount_feeder_system,
+                orphaned_anchor_cleanup_system,
+                perch_impulse_system,
+                wind_gust_system,
+                seed_spill_system,
+                spill_forage_system,
+            ).chain().in_set(crate::GameSet::Simulation).run_if(in_state(crate::AppState::Playing)));
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MountKind {
+    Hanging,
+    Platform,
+}
+
+impl MountKind {
+    // Hanging feeders and raised trays move, everything else stays bolted down
+    fn for_object(tier: HeightTier, item_type: Option<&PlaceableItemType>, feeder_type: Option<FeederType>) -> Option<Self> {
+        let is_tray = matches!(item_type, Some(PlaceableItemType::PeanutTray));
+        let is_feeder = feeder_type.is_some() || item_type.is_some_and(is_food_item);
+
+        match tier {
+            HeightTier::HangingBranch if is_feeder => Some(Self::Hanging),
+            HeightTier::BalconyRail if is_feeder => Some(Self::Platform),
+            HeightTier::Pole if is_tray => Some(Self::Platform),
+            _ => None,
+        }
+    }
+}
+
+fn is_food_item(item_type: &PlaceableItemType) -> bool {
+    matches!(item_type,
+        PlaceableItemType::BasicBirdSeed | PlaceableItemType::PremiumSeed | PlaceableItemType::SuetCake
+            | PlaceableItemType::NectarFeeder | PlaceableItemType::FruitDispenser | PlaceableItemType::PeanutTray
+    )
+}
+
+// Nectar and suet stay put however hard the feeder swings
+fn spills_seed(item_type: Option<&PlaceableItemType>, feeder_type: Option<FeederType>) -> bool {
+    match (item_type, feeder_type) {
+        (_, Some(feeder_type)) => matches!(feeder_type, FeederType::Seed | FeederType::Fruit | FeederType::Ground),
+        (Some(item_type), None) => matches!(item_type,
+            PlaceableItemType::BasicBirdSeed | PlaceableItemType::PremiumSeed
+                | PlaceableItemType::FruitDispenser | PlaceableItemType::PeanutTray
+        ),
+        (None, None) => false,
+    }
+}
+
+// Components
+#[derive(Component)]
+pub struct FeederMount {
+    pub kind: MountKind,
+    pub anchor: Entity,
+    perched: Vec<Entity>,
+    spill_cooldown: f32,
+}
+
+// Fixed body a swinging or tipping feeder is jointed to
+#[derive(Component)]
+pub struct MountAnchor {
+    pub object: Entity,
+}
+
+#[derive(Component)]
+pub struct SeedSpill {
+    pub amount: f32,
+    pub age_days: f32,
+    pub stale: bool,
+    // Landed in a seed catcher tray, which never goes stale
+    pub caught: bool,
+}
+
+impl SeedSpill {
+    fn size(&self) -> Vec2 {
+        Vec2::new(14.0 + self.amount * 2.0, 5.0 + self.amount * 0.5).min(Vec2::new(60.0, 16.0))
+    }
+}
+
+type MountChangeQuery<'w, 's> = Query<'w, 's, (
+    Entity,
+    &'static Elevation,
+    &'static mut Transform,
+    Option<&'static FeederMount>,
+    Option<&'static PlaceableObject>,
+    Option<&'static Feeder>,
+), Changed<Elevation>>;
+
+// Hangs, mounts or bolts down each feeder to suit the tier it has been moved to
+fn mount_feeder_system(
+    mut commands: Commands,
+    mut object_query: MountChangeQuery,
+) {
+    for (entity, elevation, mut transform, mount, object, feeder) in &mut object_query {
+        let item_type = object.map(|object| &object.item_type);
+        let wanted = MountKind::for_object(elevation.tier, item_type, feeder.map(|feeder| feeder.feeder_type));
+        if mount.map(|mount| mount.kind) == wanted {
+            continue;
+        }
+
+        if let Some(mount) = mount {
+            commands.entity(mount.anchor).despawn();
+            commands.entity(entity)
+                .remove::<(FeederMount, ImpulseJoint, Damping, GravityScale, AdditionalMassProperties)>()
+                .remove::<(ExternalImpulse, ExternalForce, Velocity)>()
+                .insert(RigidBody::Fixed);
+            transform.rotation = Quat::IDENTITY;
+        }
+
+        let Some(kind) = wanted else { continue };
+        let position = transform.translation.truncate();
+        let (anchor_offset, joint) = match kind {
+            MountKind::Hanging => (
+                Vec2::new(0.0, HANG_LENGTH),
+                RevoluteJointBuilder::new().local_anchor2(Vec2::new(0.0, HANG_LENGTH)),
+            ),
+            MountKind::Platform => (
+                Vec2::new(0.0, -PLATFORM_PIVOT_DROP),
+                RevoluteJointBuilder::new()
+                    .local_anchor2(Vec2::new(0.0, -PLATFORM_PIVOT_DROP))
+                    .limits([-PLATFORM_TILT_LIMIT, PLATFORM_TILT_LIMIT])
+                    .motor_position(0.0, 400.0, 40.0),
+            ),
+        };
+
+        let anchor = commands.spawn((
+            RigidBody::Fixed,
+            Transform::from_translation((position + anchor_offset).extend(transform.translation.z)),
+            MountAnchor { object: entity },
+        )).id();
+
+        commands.entity(entity).insert((
+            RigidBody::Dynamic,
+            ImpulseJoint::new(anchor, joint),
+            AdditionalMassProperties::MassProperties(MassProperties {
+                local_center_of_mass: Vec2::ZERO,
+                mass: 1.0,
+                principal_inertia: 400.0,
+            }),
+            Damping { linear_damping: 0.8, angular_damping: 1.2 },
+            // Platforms only move when something pushes them, the motor levels them out again
+            GravityScale(if kind == MountKind::Platform { 0.0 } else { 1.0 }),
+            ExternalImpulse::default(),
+            ExternalForce::default(),
+            Velocity::zero(),
+            FeederMount {
+                kind,
+                anchor,
+                perched: Vec::new(),
+                spill_cooldown: 0.0,
+            },
+        ));
+    }
+}
+
+fn orphaned_anchor_cleanup_system(
+    mut commands: Commands,
+    anchor_query: Query<(Entity, &MountAnchor)>,
+    mount_query: Query<(), With<FeederMount>>,
+) {
+    for (entity, anchor) in anchor_query.iter() {
+        if !mount_query.contains(anchor.object) {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+// Birds landing on or leaving a feeder knock it around, the heavier the bird the harder
+fn perch_impulse_system(
+    mut mount_query: Query<(&mut FeederMount, &Transform, &mut ExternalImpulse), Without<BirdAI>>,
+    bird_query: Query<(Entity, &Transform, &BirdState, &Bird), With<BirdAI>>,
+    bird_data: Res<BirdDataRegistry>,
+) {
+    for (mut mount, transform, mut impulse) in &mut mount_query {
+        let position = transform.translation.truncate();
+        let perched: Vec<Entity> = bird_query.iter()
+            .filter(|(_, _, state, _)| matches!(state, BirdState::Eating | BirdState::Drinking))
+            .filter(|(_, bird_transform, _, _)| bird_transform.translation.truncate().distance(position) < PERCH_RANGE)
+            .map(|(entity, _, _, _)| entity)
+            .collect();
+
+        let arrived = perched.iter().filter(|entity| !mount.perched.contains(entity));
+        let departed = mount.perched.iter().filter(|entity| !perched.contains(entity));
+        for (bird, landing) in arrived.map(|entity| (*entity, true)).chain(departed.map(|entity| (*entity, false))) {
+            let Ok((_, bird_transform, _, species)) = bird_query.get(bird) else { continue };
+            let side = (bird_transform.translation.x - position.x).signum();
+            let weight = bird_data.get_size_category(&species.species) as f32;
+            // Landing weighs the near side down, pushing off kicks it the other way
+            let direction = if landing { -side } else { side };
+            impulse.torque_impulse += direction * weight * LANDING_TORQUE;
+        }
+
+        mount.perched = perched;
+    }
+}
+
+fn wind_gust_system(
+    mut mount_query: Query<(&FeederMount, &mut ExternalForce)>,
+    weather_state: Res<WeatherState>,
+    time: Res<Time>,
+) {
+    let wind = match weather_state.current_weather {
+        Weather::Windy => 1.0,
+        Weather::Rainy => 0.5,
+        Weather::Snowy => 0.4,
+        _ => 0.1,
+    };
+    let t = time.elapsed_secs();
+    let gust = (t * 0.7).sin() + (t * 1.9).sin() * 0.5;
+
+    for (mount, mut force) in &mut mount_query {
+        force.force = match mount.kind {
+            MountKind::Hanging => Vec2::X * WIND_FORCE * wind * gust,
+            MountKind::Platform => Vec2::ZERO,
+        };
+    }
+}
+
+// Swinging too hard or tipping too far spills seed from a well-filled feeder onto the ground below
+fn seed_spill_system(
+    mut commands: Commands,
+    mut mount_query: Query<(Entity, &mut FeederMount, &Transform, &Velocity, &Elevation, Option<&PlaceableObject>, Option<&Feeder>)>,
+    mut spill_query: Query<(&mut SeedSpill, &Transform, &mut Sprite), Without<FeederMount>>,
+    tray_query: Query<(&PlaceableObject, &Transform), (Without<FeederMount>, Without<SeedSpill>)>,
+    mut depletion_events: EventWriter<FeederDepletionEvent>,
+    time: Res<Time>,
+) {
+    for (entity, mut mount, transform, velocity, elevation, object, feeder) in &mut mount_query {
+        mount.spill_cooldown = (mount.spill_cooldown - time.delta_secs()).max(0.0);
+        if mount.spill_cooldown > 0.0 {
+            continue;
+        }
+
+        let item_type = object.map(|object| &object.item_type);
+        if !spills_seed(item_type, feeder.map(|feeder| feeder.feeder_type)) {
+            continue;
+        }
+        // Catalog feeders don't track their fill, so they always count as topped up
+        if feeder.is_some_and(|feeder| feeder.current_capacity < feeder.max_capacity * OVERFILL_RATIO) {
+            continue;
+        }
+
+        let tilt = transform.rotation.to_euler(EulerRot::XYZ).2.abs();
+        let shaken = match mount.kind {
+            MountKind::Hanging => velocity.angvel.abs() > SPILL_SWING_SPEED,
+            MountKind::Platform => tilt > SPILL_TILT,
+        };
+        if !shaken {
+            continue;
+        }
+
+        mount.spill_cooldown = SPILL_COOLDOWN;
+        if feeder.is_some() {
+            depletion_events.write(FeederDepletionEvent {
+                feeder_entity: entity,
+                amount: SPILL_AMOUNT,
+            });
+        }
+
+        let mut ground = transform.translation.truncate() - Vec2::new(0.0, elevation.tier.height() + 12.0)
+            + Vec2::new((rand::random::<f32>() - 0.5) * 20.0, 0.0);
+        let catcher = tray_query.iter()
+            .filter(|(object, _)| object.item_type == PlaceableItemType::SeedCatcherTray)
+            .map(|(_, tray_transform)| tray_transform.translation.truncate())
+            .find(|tray| tray.distance(ground) < CATCHER_RANGE);
+        if let Some(tray) = catcher {
+            ground = tray + Vec2::new(0.0, 2.0);
+        }
+
+        let nearby = spill_query.iter_mut()
+            .find(|(spill, spill_transform, _)| {
+                spill.caught == catcher.is_some()
+                    && spill_transform.translation.truncate().distance(ground) < SPILL_MERGE_RANGE
+            });
+        if let Some((mut spill, _, mut sprite)) = nearby {
+            spill.amount += SPILL_AMOUNT;
+            sprite.custom_size = Some(spill.size());
+            continue;
+        }
+
+        let spill = SeedSpill { amount: SPILL_AMOUNT, age_days: 0.0, stale: false, caught: catcher.is_some() };
+        commands.spawn((
+            Sprite::from_color(Color::srgb(0.62, 0.5, 0.3), spill.size()),
+            Transform::from_translation(ground.extend(0.06)),
+            SmartObject,
+            ProvidesUtility {
+                action: BirdAction::Forage,
+                base_utility: 0.6,
+                range: 160.0,
+            },
+            spill,
+            Name::new("SeedSpill"),
+        ));
+    }
+}
+
+// Ground feeders clean up spilled seed, whatever is left too long goes stale
+fn spill_forage_system(
+    mut commands: Commands,
+    mut spill_query: Query<(Entity, &mut SeedSpill, &Transform, &mut Sprite)>,
+    bird_query: Query<(&Transform, &BirdState), With<BirdAI>>,
+    time_state: Res<TimeState>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+    time: Res<Time>,
+) {
+    let game_days = time.delta_secs() / (24.0 * time_state.time_speed);
+
+    for (entity, mut spill, transform, mut sprite) in &mut spill_query {
+        let position = transform.translation.truncate();
+        let foragers = bird_query.iter()
+            .filter(|(bird_transform, state)| {
+                **state == BirdState::Foraging
+                    && bird_transform.translation.truncate().distance(position) < SPILL_FEED_RANGE
+            })
+            .count();
+
+        spill.amount -= foragers as f32 * 0.5 * time.delta_secs();
+        if spill.amount <= 0.0 {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        sprite.custom_size = Some(spill.size());
+
+        spill.age_days += game_days;
+        if !spill.stale && !spill.caught && spill.age_days >= STALE_SPILL_DAYS {
+            spill.stale = true;
+            sprite.color = Color::srgb(0.45, 0.4, 0.3);
+            notification_events.write(ShowNotificationEvent {
+                notification: NotificationType::Warning {
+                    message: "Spilled seed under a feeder has gone stale and will draw rodents".to_string(),
+                },
+            });
+        }
+    }
+}
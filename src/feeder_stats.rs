@@ -0,0 +1,442 @@
+// Feeder Stats - Per-feeder visit, diversity, cost and uptime records with a report card
+//
+// Every feeder keeps a running record: each bird that arrives to feed counts as a visit by its
+// species, food leaving the feeder is costed at the refill price, and every game hour is logged
+// as stocked or empty. Right-click a feeder for its report card, graded against the yard's other
+// feeders on visits per day; the Year by Year stats panel compares them all side by side.
+use bevy::prelude::*;
+use std::collections::HashMap;
+use crate::bird::BirdSpecies;
+use crate::bird_ai::resources::FeederPresence;
+use crate::difficulty::DifficultySettings;
+use crate::environment::resources::TimeState;
+use crate::feeder::Feeder;
+use crate::camera::cursor_world_position;
+
+const CLICK_RANGE: f32 = 40.0;
+const PANEL_WIDTH: f32 = 320.0;
+const TABLE_COLUMNS: [(&str, f32); 7] = [
+    ("Feeder", 100.0), ("Visits", 50.0), ("Species", 50.0), ("Cost", 45.0),
+    ("Uptime", 50.0), ("Per 10c", 50.0), ("Grade", 40.0),
+];
+const PANEL_REFRESH_SECS: f32 = 1.0;
+
+pub struct FeederStatsPlugin;
+
+impl Plugin for FeederStatsPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<FeederStats>()
+            .add_systems(Update, (attach_feeder_record_system, feeder_visit_system, feeder_usage_system)
+                .chain()
+                .in_set(crate::GameSet::Simulation)
+                .run_if(in_state(crate::AppState::Playing)))
+            .add_systems(Update, feeder_report_click_system
+                .run_if(crate::debug_console::console_is_not_visible)
+                .in_set(crate::GameSet::Input)
+                .run_if(in_state(crate::AppState::Playing)))
+            .add_systems(Update, (feeder_report_button_system, refresh_feeder_report_system)
+                .chain()
+                .in_set(crate::GameSet::UI)
+                .run_if(in_state(crate::AppState::Playing)))
+            .add_systems(OnExit(crate::AppState::Playing), close_feeder_report_system);
+    }
+}
+
+// Components
+#[derive(Component, Debug, Clone, Default)]
+pub struct FeederRecord {
+    pub visits: u32,
+    pub species: HashMap<BirdSpecies, u32>,
+    // Food units taken by birds, raiders and spills
+    pub food_used: f32,
+    // Coins of food that went, at the refill price when it was used
+    pub seed_cost: f32,
+    pub stocked_hours: f32,
+    pub tracked_hours: f32,
+    last_capacity: f32,
+}
+
+impl FeederRecord {
+    pub fn visits_per_day(&self) -> f32 {
+        if self.tracked_hours < 1.0 {
+            return 0.0;
+        }
+        self.visits as f32 / (self.tracked_hours / 24.0)
+    }
+
+    /// Share of tracked time the feeder had food in it, 0-1
+    pub fn uptime(&self) -> f32 {
+        if self.tracked_hours <= 0.0 {
+            return 1.0;
+        }
+        self.stocked_hours / self.tracked_hours
+    }
+
+    pub fn visits_per_ten_coins(&self) -> Option<f32> {
+        (self.seed_cost >= 1.0).then(|| self.visits as f32 / self.seed_cost * 10.0)
+    }
+
+    // Most frequent visitors first
+    pub fn top_species(&self, count: usize) -> Vec<(BirdSpecies, u32)> {
+        let mut species: Vec<(BirdSpecies, u32)> = self.species.iter().map(|(species, visits)| (*species, *visits)).collect();
+        species.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| format!("{:?}", a.0).cmp(&format!("{:?}", b.0))));
+        species.truncate(count);
+        species
+    }
+}
+
+#[derive(Component)]
+pub struct FeederReportPanel;
+
+#[derive(Component)]
+pub struct FeederReportCloseButton;
+
+// Resources
+#[derive(Resource)]
+pub struct FeederStats {
+    // Visit already counted for each bird, by the feeder and the time it arrived
+    counted: HashMap<Entity, (Entity, f32)>,
+    // Arrivals at any feeder or bath since the game started running
+    pub yard_visits: u64,
+    last_hour: Option<f32>,
+    // Feeder whose report card is open
+    pub selected: Option<Entity>,
+    panel_dirty: bool,
+    refresh_timer: Timer,
+}
+
+impl Default for FeederStats {
+    fn default() -> Self {
+        Self {
+            counted: HashMap::new(),
+            yard_visits: 0,
+            last_hour: None,
+            selected: None,
+            panel_dirty: false,
+            refresh_timer: Timer::from_seconds(PANEL_REFRESH_SECS, TimerMode::Repeating),
+        }
+    }
+}
+
+/// One feeder's line in the effectiveness comparison
+pub struct FeederReportRow {
+    pub feeder: Entity,
+    pub label: String,
+    pub record: FeederRecord,
+    pub grade: char,
+}
+
+// Graded on visits per day against the yard's average feeder
+fn grade(visits_per_day: f32, yard_average: f32) -> char {
+    if yard_average <= 0.0 {
+        return '-';
+    }
+    match visits_per_day / yard_average {
+        ratio if ratio >= 1.25 => 'A',
+        ratio if ratio >= 0.9 => 'B',
+        ratio if ratio >= 0.5 => 'C',
+        _ => 'D',
+    }
+}
+
+/// Every feeder's record with a label and grade, in the order they were put out
+pub fn feeder_report_rows<'a>(feeders: impl Iterator<Item = (Entity, &'a Feeder, &'a FeederRecord)>) -> Vec<FeederReportRow> {
+    let mut feeders: Vec<(Entity, &Feeder, &FeederRecord)> = feeders.collect();
+    feeders.sort_by_key(|(entity, _, _)| *entity);
+    let yard_average = feeders.iter().map(|(_, _, record)| record.visits_per_day()).sum::<f32>() / feeders.len().max(1) as f32;
+
+    // Feeders of the same kind are numbered from the second one on
+    let mut seen: HashMap<String, u32> = HashMap::new();
+    feeders.into_iter().map(|(entity, feeder, record)| {
+        let kind = format!("{:?} Feeder", feeder.feeder_type);
+        let number = seen.entry(kind.clone()).or_default();
+        *number += 1;
+        let label = if *number == 1 { kind } else { format!("{} {}", kind, number) };
+        FeederReportRow { feeder: entity, label, record: record.clone(), grade: grade(record.visits_per_day(), yard_average) }
+    }).collect()
+}
+
+type NewFeederQuery<'w, 's> = Query<'w, 's, (Entity, &'static Feeder), (Added<Feeder>, Without<FeederRecord>)>;
+
+fn attach_feeder_record_system(
+    mut commands: Commands,
+    feeder_query: NewFeederQuery,
+) {
+    for (entity, feeder) in &feeder_query {
+        commands.entity(entity).insert(FeederRecord { last_capacity: feeder.current_capacity, ..default() });
+    }
+}
+
+// A bird settling in to feed is one visit, however long it stays
+fn feeder_visit_system(
+    presence: Res<FeederPresence>,
+    mut stats: ResMut<FeederStats>,
+    mut record_query: Query<&mut FeederRecord>,
+) {
+    if !presence.is_changed() {
+        return;
+    }
+    for (bird, visit) in &presence.visits {
+        if stats.counted.get(bird) == Some(&(visit.object, visit.arrived)) {
+            continue;
+        }
+        stats.counted.insert(*bird, (visit.object, visit.arrived));
+        stats.yard_visits += 1;
+        if let Ok(mut record) = record_query.get_mut(visit.object) {
+            record.visits += 1;
+            *record.species.entry(visit.species).or_default() += 1;
+        }
+    }
+    stats.counted.retain(|bird, _| presence.visits.contains_key(bird));
+}
+
+// Costs the food that left each feeder and logs the hours it spent stocked or empty
+fn feeder_usage_system(
+    mut stats: ResMut<FeederStats>,
+    time_state: Res<TimeState>,
+    difficulty: Res<DifficultySettings>,
+    mut feeder_query: Query<(&Feeder, &mut FeederRecord)>,
+) {
+    let now = time_state.absolute_hour();
+    let hours = stats.last_hour.map_or(0.0, |last| (now - last).max(0.0));
+    stats.last_hour = Some(now);
+
+    for (feeder, mut record) in &mut feeder_query {
+        let used = record.last_capacity - feeder.current_capacity;
+        if used > 0.0 {
+            record.food_used += used;
+            record.seed_cost += used / feeder.max_capacity.max(1.0)
+                * feeder.feeder_type.refill_cost() as f32
+                * difficulty.seed_cost_multiplier();
+        }
+        record.last_capacity = feeder.current_capacity;
+
+        if hours > 0.0 {
+            record.tracked_hours += hours;
+            if feeder.current_capacity > 0.0 {
+                record.stocked_hours += hours;
+            }
+        }
+    }
+}
+
+// Right-click a feeder to open its report card
+fn feeder_report_click_system(
+    mouse_button: Res<ButtonInput<MouseButton>>,
+    windows: Query<&Window>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<Camera2d>>,
+    feeder_query: Query<(Entity, &Transform), With<FeederRecord>>,
+    mut stats: ResMut<FeederStats>,
+) {
+    if !mouse_button.just_pressed(MouseButton::Right) {
+        return;
+    }
+    let Ok(window) = windows.single() else { return };
+    let Ok((camera, camera_transform)) = camera_query.single() else { return };
+    let Some(world_position) = cursor_world_position(window, camera, camera_transform) else { return };
+
+    let clicked = feeder_query.iter()
+        .find(|(_, transform)| transform.translation.truncate().distance(world_position) <= CLICK_RANGE);
+    if let Some((entity, _)) = clicked {
+        stats.selected = Some(entity);
+        stats.panel_dirty = true;
+    }
+}
+
+fn feeder_report_button_system(
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<FeederReportCloseButton>)>,
+    mut stats: ResMut<FeederStats>,
+) {
+    if interaction_query.iter().any(|interaction| *interaction == Interaction::Pressed) {
+        stats.selected = None;
+        stats.panel_dirty = true;
+    }
+}
+
+fn refresh_feeder_report_system(
+    mut commands: Commands,
+    mut stats: ResMut<FeederStats>,
+    feeder_query: Query<(Entity, &Feeder, &FeederRecord)>,
+    panel_query: Query<Entity, With<FeederReportPanel>>,
+    time: Res<Time>,
+) {
+    let timer_fired = stats.selected.is_some() && stats.refresh_timer.tick(time.delta()).just_finished();
+    if !stats.panel_dirty && !timer_fired {
+        return;
+    }
+    stats.panel_dirty = false;
+
+    for panel in &panel_query {
+        commands.entity(panel).despawn();
+    }
+    let Some(selected) = stats.selected else { return };
+    let rows = feeder_report_rows(feeder_query.iter());
+    let Some(row) = rows.iter().find(|row| row.feeder == selected) else {
+        stats.selected = None;
+        return;
+    };
+
+    spawn_feeder_report(&mut commands, row, rows.len());
+}
+
+fn close_feeder_report_system(
+    mut commands: Commands,
+    mut stats: ResMut<FeederStats>,
+    panel_query: Query<Entity, With<FeederReportPanel>>,
+) {
+    stats.selected = None;
+    for panel in &panel_query {
+        commands.entity(panel).despawn();
+    }
+}
+
+fn grade_color(grade: char) -> Color {
+    match grade {
+        'A' => Color::srgb(0.2, 0.55, 0.2),
+        'B' => Color::srgb(0.4, 0.5, 0.2),
+        'C' => Color::srgb(0.7, 0.5, 0.1),
+        'D' => Color::srgb(0.7, 0.25, 0.15),
+        _ => Color::srgb(0.5, 0.4, 0.3),
+    }
+}
+
+fn report_lines(record: &FeederRecord) -> Vec<String> {
+    let top = record.top_species(3).iter()
+        .map(|(species, visits)| format!("{:?} ({})", species, visits))
+        .collect::<Vec<_>>()
+        .join(", ");
+    vec![
+        format!("Visits: {} ({:.1} a day)", record.visits, record.visits_per_day()),
+        format!("Species: {}{}", record.species.len(), if top.is_empty() { String::new() } else { format!(" - {}", top) }),
+        format!("Food used: {:.0} units, {:.0} coins of seed", record.food_used, record.seed_cost),
+        format!("Uptime: {:.0}% of {:.0} hours stocked", record.uptime() * 100.0, record.tracked_hours),
+        match record.visits_per_ten_coins() {
+            Some(value) => format!("Value: {:.1} visits per 10 coins", value),
+            None => "Value: not enough food used yet".to_string(),
+        },
+    ]
+}
+
+fn spawn_feeder_report(commands: &mut Commands, row: &FeederReportRow, feeder_count: usize) {
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            right: Val::Px(20.0),
+            top: Val::Px(80.0),
+            width: Val::Px(PANEL_WIDTH),
+            flex_direction: FlexDirection::Column,
+            padding: UiRect::all(Val::Px(14.0)),
+            row_gap: Val::Px(6.0),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.95, 0.92, 0.88, 0.95)),
+        BorderRadius::all(Val::Px(8.0)),
+        FeederReportPanel,
+        Name::new("FeederReport"),
+    )).with_children(|panel| {
+        panel.spawn(Node {
+            width: Val::Percent(100.0),
+            justify_content: JustifyContent::SpaceBetween,
+            align_items: AlignItems::Center,
+            ..default()
+        }).with_children(|header| {
+            header.spawn((
+                Text::new(format!("{} report card", row.label)),
+                TextFont { font_size: 18.0, ..default() },
+                TextColor(Color::srgb(0.3, 0.2, 0.1)),
+            ));
+            header.spawn((
+                Text::new(row.grade.to_string()),
+                TextFont { font_size: 26.0, ..default() },
+                TextColor(grade_color(row.grade)),
+            ));
+        });
+
+        for line in report_lines(&row.record) {
+            panel.spawn((
+                Text::new(line),
+                TextFont { font_size: 12.0, ..default() },
+                TextColor(Color::srgb(0.4, 0.3, 0.2)),
+            ));
+        }
+
+        let advice = if row.record.tracked_hours >= 24.0 && row.record.uptime() < 0.6 {
+            "Often empty - birds give up on feeders that are bare when they come by."
+        } else if feeder_count < 2 {
+            "Put out another feeder to compare how they do."
+        } else {
+            "Graded on visits per day against your other feeders."
+        };
+        panel.spawn((
+            Text::new(advice),
+            TextFont { font_size: 11.0, ..default() },
+            TextColor(Color::srgb(0.5, 0.4, 0.3)),
+        ));
+
+        panel.spawn((
+            Button,
+            Node {
+                padding: UiRect::axes(Val::Px(10.0), Val::Px(4.0)),
+                align_self: AlignSelf::FlexEnd,
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.6, 0.5, 0.4)),
+            BorderRadius::all(Val::Px(4.0)),
+            FeederReportCloseButton,
+        )).with_children(|button| {
+            button.spawn((
+                Text::new("Close"),
+                TextFont { font_size: 12.0, ..default() },
+                TextColor(Color::WHITE),
+            ));
+        });
+    });
+}
+
+fn spawn_table_row(parent: &mut ChildSpawnerCommands, cells: [String; 7], color: Color) {
+    parent.spawn(Node {
+        width: Val::Percent(100.0),
+        ..default()
+    }).with_children(|row| {
+        for (cell, (_, width)) in cells.into_iter().zip(TABLE_COLUMNS) {
+            row.spawn((
+                Text::new(cell),
+                TextFont { font_size: 11.0, ..default() },
+                TextColor(color),
+                Node { width: Val::Px(width), ..default() },
+            ));
+        }
+    });
+}
+
+/// Side-by-side comparison of every feeder for the stats panel
+pub fn spawn_feeder_effectiveness_table(parent: &mut ChildSpawnerCommands, rows: &[FeederReportRow]) {
+    parent.spawn((
+        Text::new("Feeder effectiveness"),
+        TextFont { font_size: 14.0, ..default() },
+        TextColor(Color::srgb(0.3, 0.2, 0.1)),
+    ));
+    if rows.is_empty() {
+        parent.spawn((
+            Text::new("No feeders out yet"),
+            TextFont { font_size: 11.0, ..default() },
+            TextColor(Color::srgb(0.5, 0.4, 0.3)),
+        ));
+        return;
+    }
+
+    spawn_table_row(parent, TABLE_COLUMNS.map(|(title, _)| title.to_string()), Color::srgb(0.3, 0.2, 0.1));
+    for row in rows {
+        let record = &row.record;
+        spawn_table_row(parent, [
+            row.label.clone(),
+            format!("{:.1}/day", record.visits_per_day()),
+            record.species.len().to_string(),
+            format!("{:.0}c", record.seed_cost),
+            format!("{:.0}%", record.uptime() * 100.0),
+            record.visits_per_ten_coins().map_or("-".to_string(), |value| format!("{:.1}", value)),
+            row.grade.to_string(),
+        ], grade_color(row.grade));
+    }
+}
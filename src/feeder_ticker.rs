@@ -0,0 +1,307 @@
+// Feeder Ticker - Collapsible live list of the birds at each feeder and water source
+use bevy::prelude::*;
+use crate::bird_ai::resources::{FeederPresence, PresenceActivity};
+use crate::camera::CameraFocusEvent;
+use crate::feeder::Feeder;
+use crate::neighborhood_noise::NeighborhoodNoise;
+use crate::photo_mode::components::PhotoTarget;
+use crate::weather_effects::fog::Fog;
+
+const TICKER_WIDTH: f32 = 250.0;
+const MAX_ROWS: usize = 12;
+
+pub struct FeederTickerPlugin;
+
+impl Plugin for FeederTickerPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<FeederTicker>()
+            .add_systems(OnEnter(crate::AppState::Playing), setup_feeder_ticker)
+            .add_systems(OnExit(crate::AppState::Playing), cleanup_feeder_ticker)
+            .add_systems(Update, (
+                ticker_toggle_system,
+                ticker_row_click_system,
+                refresh_feeder_ticker_system,
+            ).chain().in_set(crate::GameSet::UI).run_if(in_state(crate::AppState::Playing)));
+    }
+}
+
+// Resources
+#[derive(Resource)]
+pub struct FeederTicker {
+    pub expanded: bool,
+    // Keeps the "for Ns" durations ticking over between arrivals
+    refresh_timer: Timer,
+    dirty: bool,
+}
+
+impl Default for FeederTicker {
+    fn default() -> Self {
+        Self {
+            expanded: true,
+            refresh_timer: Timer::from_seconds(1.0, TimerMode::Repeating),
+            dirty: true,
+        }
+    }
+}
+
+// Components
+#[derive(Component)]
+pub struct FeederTickerRoot;
+
+#[derive(Component)]
+pub struct FeederTickerHeader;
+
+#[derive(Component)]
+pub struct FeederTickerHeaderText;
+
+#[derive(Component)]
+pub struct FeederTickerList;
+
+// Group labels and rows, rebuilt on every refresh
+#[derive(Component)]
+pub struct FeederTickerEntry;
+
+#[derive(Component)]
+pub struct FeederTickerRow {
+    pub bird: Entity,
+}
+
+fn setup_feeder_ticker(mut commands: Commands, mut ticker: ResMut<FeederTicker>) {
+    ticker.dirty = true;
+
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(20.0),
+            top: Val::Px(80.0),
+            width: Val::Px(TICKER_WIDTH),
+            flex_direction: FlexDirection::Column,
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.95, 0.92, 0.88, 0.9)),
+        BorderRadius::all(Val::Px(6.0)),
+        FeederTickerRoot,
+        Name::new("FeederTicker"),
+    )).with_children(|root| {
+        root.spawn((
+            Button,
+            Node {
+                width: Val::Percent(100.0),
+                padding: UiRect::axes(Val::Px(10.0), Val::Px(6.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.6, 0.5, 0.4)),
+            BorderRadius::top(Val::Px(6.0)),
+            FeederTickerHeader,
+        )).with_children(|header| {
+            header.spawn((
+                Text::new("Now at your feeders"),
+                TextFont { font_size: 13.0, ..default() },
+                TextColor(Color::WHITE),
+                FeederTickerHeaderText,
+            ));
+        });
+
+        root.spawn((
+            Node {
+                width: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(2.0),
+                padding: UiRect::all(Val::Px(6.0)),
+                ..default()
+            },
+            FeederTickerList,
+        ));
+    });
+}
+
+fn cleanup_feeder_ticker(
+    mut commands: Commands,
+    ticker_query: Query<Entity, With<FeederTickerRoot>>,
+) {
+    for entity in &ticker_query {
+        commands.entity(entity).despawn();
+    }
+}
+
+fn ticker_toggle_system(
+    mut header_query: Query<(&Interaction, &mut BackgroundColor), (Changed<Interaction>, With<FeederTickerHeader>)>,
+    mut ticker: ResMut<FeederTicker>,
+) {
+    for (interaction, mut color) in &mut header_query {
+        match *interaction {
+            Interaction::Pressed => {
+                ticker.expanded = !ticker.expanded;
+                ticker.dirty = true;
+                *color = BackgroundColor(Color::srgb(0.5, 0.7, 0.5));
+            }
+            Interaction::Hovered => *color = BackgroundColor(Color::srgb(0.7, 0.6, 0.5)),
+            Interaction::None => *color = BackgroundColor(Color::srgb(0.6, 0.5, 0.4)),
+        }
+    }
+}
+
+fn ticker_row_click_system(
+    mut row_query: Query<(&Interaction, &FeederTickerRow, &mut BackgroundColor), Changed<Interaction>>,
+    bird_query: Query<&Transform>,
+    mut focus_events: EventWriter<CameraFocusEvent>,
+) {
+    for (interaction, row, mut color) in &mut row_query {
+        match *interaction {
+            Interaction::Pressed => {
+                if let Ok(transform) = bird_query.get(row.bird) {
+                    focus_events.write(CameraFocusEvent { position: transform.translation.truncate() });
+                }
+            }
+            Interaction::Hovered => *color = BackgroundColor(Color::srgba(0.6, 0.5, 0.4, 0.25)),
+            Interaction::None => *color = BackgroundColor(Color::NONE),
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn refresh_feeder_ticker_system(
+    mut commands: Commands,
+    mut ticker: ResMut<FeederTicker>,
+    presence: Res<FeederPresence>,
+    feeder_query: Query<&Feeder>,
+    object_query: Query<&Transform>,
+    camera_query: Query<&Transform, With<PhotoTarget>>,
+    list_query: Query<Entity, With<FeederTickerList>>,
+    entry_query: Query<Entity, With<FeederTickerEntry>>,
+    mut header_text_query: Query<&mut Text, With<FeederTickerHeaderText>>,
+    noise: Res<NeighborhoodNoise>,
+    fog: Res<Fog>,
+    time: Res<Time>,
+) {
+    ticker.refresh_timer.tick(time.delta());
+    let timer_fired = ticker.expanded && ticker.refresh_timer.just_finished();
+    if !ticker.dirty && !presence.is_changed() && !noise.is_changed() && !timer_fired {
+        return;
+    }
+    ticker.dirty = false;
+
+    // In fog only the feeders near the middle of the view can be made out
+    let viewer = camera_query.single().map_or(Vec2::ZERO, |transform| transform.translation.truncate());
+    let (groups, fogged): (Vec<_>, Vec<_>) = presence.by_object().into_iter().partition(|(object, _)| {
+        object_query.get(*object).map_or(true, |transform| fog.can_see(viewer, transform.translation.truncate()))
+    });
+    let visible: usize = groups.iter().map(|(_, visits)| visits.len()).sum();
+    let hidden_by_fog: usize = fogged.iter().map(|(_, visits)| visits.len()).sum();
+
+    let fold = if ticker.expanded { "-" } else { "+" };
+    let noisy = if noise.active.is_some() { " 🔊" } else { "" };
+    for mut text in &mut header_text_query {
+        text.0 = format!("[{}] Now at your feeders ({}){}", fold, visible, noisy);
+    }
+
+    for entry in &entry_query {
+        commands.entity(entry).despawn();
+    }
+
+    if !ticker.expanded {
+        return;
+    }
+    let Ok(list) = list_query.single() else { return };
+    let now = time.elapsed_secs();
+
+    commands.entity(list).with_children(|list| {
+        if let Some(active) = noise.active {
+            let muffled = if noise.muffling > 0.0 {
+                format!(", {:.0}% muffled by the garden", noise.muffling * 100.0)
+            } else {
+                String::new()
+            };
+            list.spawn((
+                Text::new(format!("🔊 {} - birds are skittish{}", active.kind.description(), muffled)),
+                TextFont { font_size: 11.0, ..default() },
+                TextColor(Color::srgb(0.7, 0.35, 0.2)),
+                FeederTickerEntry,
+            ));
+        }
+
+        if hidden_by_fog > 0 {
+            list.spawn((
+                Text::new(format!("🌫 Fog - {} more out of sight at distant feeders", hidden_by_fog)),
+                TextFont { font_size: 11.0, ..default() },
+                TextColor(Color::srgb(0.45, 0.5, 0.55)),
+                FeederTickerEntry,
+            ));
+        }
+
+        if presence.visits.is_empty() {
+            list.spawn((
+                Text::new("No birds feeding right now"),
+                TextFont { font_size: 11.0, ..default() },
+                TextColor(Color::srgb(0.5, 0.4, 0.3)),
+                FeederTickerEntry,
+            ));
+            return;
+        }
+
+        let mut rows_left = MAX_ROWS;
+        for (object, visits) in groups {
+            if rows_left == 0 {
+                break;
+            }
+
+            let label = match feeder_query.get(object) {
+                Ok(feeder) => format!("{:?} Feeder", feeder.feeder_type),
+                Err(_) => match visits[0].1.activity {
+                    PresenceActivity::Drinking => "Water Source".to_string(),
+                    PresenceActivity::HoverFeeding => "Flowers".to_string(),
+                    PresenceActivity::Eating => "Feeding Spot".to_string(),
+                },
+            };
+            list.spawn((
+                Text::new(format!("{} ({})", label, visits.len())),
+                TextFont { font_size: 12.0, ..default() },
+                TextColor(Color::srgb(0.3, 0.2, 0.1)),
+                Node {
+                    margin: UiRect::top(Val::Px(4.0)),
+                    ..default()
+                },
+                FeederTickerEntry,
+            ));
+
+            for (bird, visit) in visits.into_iter().take(rows_left) {
+                rows_left -= 1;
+                list.spawn((
+                    Button,
+                    Node {
+                        width: Val::Percent(100.0),
+                        justify_content: JustifyContent::SpaceBetween,
+                        padding: UiRect::axes(Val::Px(8.0), Val::Px(2.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::NONE),
+                    BorderRadius::all(Val::Px(3.0)),
+                    FeederTickerRow { bird },
+                    FeederTickerEntry,
+                )).with_children(|row| {
+                    row.spawn((
+                        Text::new(format!("{:?}", visit.species)),
+                        TextFont { font_size: 11.0, ..default() },
+                        TextColor(Color::srgb(0.3, 0.2, 0.1)),
+                    ));
+                    row.spawn((
+                        Text::new(format!("{} {:.0}s", visit.activity.verb(), (now - visit.arrived).max(0.0))),
+                        TextFont { font_size: 10.0, ..default() },
+                        TextColor(Color::srgb(0.5, 0.4, 0.3)),
+                    ));
+                });
+            }
+        }
+
+        let hidden = visible.saturating_sub(MAX_ROWS);
+        if hidden > 0 {
+            list.spawn((
+                Text::new(format!("...and {} more", hidden)),
+                TextFont { font_size: 10.0, ..default() },
+                TextColor(Color::srgb(0.5, 0.4, 0.3)),
+                FeederTickerEntry,
+            ));
+        }
+    });
+}
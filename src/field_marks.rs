@@ -0,0 +1,268 @@
+// Field Marks - Teaching callouts pointing at the marks that identify a bird
+//
+// When a bird is held in the center of the binoculars or the photo mode viewfinder, each clause of
+// its identification tips is matched to the part of the body it describes ("white wing bars",
+// "black cap") and drawn as a label with a leader line to that spot. The callouts fade after a few
+// seconds so they teach rather than clutter, and the same bird has to leave the center before it
+// is annotated again. They can be switched off under Accessibility in Settings.
+use bevy::prelude::*;
+use crate::binoculars::Binoculars;
+use crate::bird::{Bird, BIRD_HALF_EXTENT};
+use crate::journal::resources::BirdEducationData;
+use crate::menu::resources::GameSettings;
+use crate::photo_mode::components::PhotoTarget;
+use crate::photo_mode::resources::PhotoModeSettings;
+
+// Birds within this many screen pixels of the center count as centered
+const CENTER_RADIUS: f32 = 80.0;
+// Seconds a bird has to stay centered before it is annotated, so sweeping past birds does nothing
+const DWELL_SECONDS: f32 = 0.5;
+const HOLD_SECONDS: f32 = 4.0;
+const FADE_SECONDS: f32 = 1.0;
+const MAX_CALLOUTS: usize = 4;
+// Screen pixels from the bird to the end of each leader line, and between stacked labels
+const LEADER_PIXELS: f32 = 70.0;
+const SLOT_SPACING: f32 = 28.0;
+const LABEL_HEIGHT: f32 = 22.0;
+const CALLOUT_COLOR: Color = Color::srgb(1.0, 0.9, 0.55);
+
+pub struct FieldMarksPlugin;
+
+impl Plugin for FieldMarksPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<FieldMarkCallouts>()
+            // After the binoculars sway so the leader lines land on the bird this frame
+            .add_systems(Update, field_mark_callout_system
+                .after(crate::binoculars::binoculars_view_system)
+                .in_set(crate::GameSet::Presentation)
+                .run_if(in_state(crate::AppState::Playing)));
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BodyRegion {
+    Crown,
+    Face,
+    Bill,
+    Throat,
+    Breast,
+    Back,
+    Wing,
+    Tail,
+}
+
+impl BodyRegion {
+    // Checked in order, so "back of head" is the crown and "white below with blue crest" the crest
+    const KEYWORDS: [(&'static str, BodyRegion); 24] = [
+        ("bill", Self::Bill), ("beak", Self::Bill),
+        ("eye", Self::Face), ("mask", Self::Face), ("face", Self::Face), ("cheek", Self::Face), ("tear", Self::Face),
+        ("crest", Self::Crown), ("crown", Self::Crown), ("cap", Self::Crown), ("head", Self::Crown),
+        ("throat", Self::Throat), ("bib", Self::Throat), ("necklace", Self::Throat), ("chin", Self::Throat),
+        ("breast", Self::Breast), ("chest", Self::Breast), ("belly", Self::Breast), ("below", Self::Breast), ("sides", Self::Breast),
+        ("wing", Self::Wing),
+        ("tail", Self::Tail),
+        ("back", Self::Back), ("above", Self::Back),
+    ];
+
+    pub fn from_clause(clause: &str) -> Option<Self> {
+        let clause = clause.to_lowercase();
+        Self::KEYWORDS.iter()
+            .find(|(keyword, _)| clause.contains(keyword))
+            .map(|(_, region)| *region)
+    }
+
+    // Where the mark sits on a bird facing right, in half sprite sizes from its center
+    pub fn anchor(&self) -> Vec2 {
+        match self {
+            Self::Crown => Vec2::new(0.1, 0.8),
+            Self::Face => Vec2::new(0.45, 0.5),
+            Self::Bill => Vec2::new(0.9, 0.4),
+            Self::Throat => Vec2::new(0.5, 0.1),
+            Self::Breast => Vec2::new(0.35, -0.3),
+            Self::Back => Vec2::new(-0.2, 0.35),
+            Self::Wing => Vec2::new(-0.15, 0.0),
+            Self::Tail => Vec2::new(-0.9, -0.3),
+        }
+    }
+
+    // Labels for the front of the bird go on the side it faces, the rest behind it
+    pub fn is_front(&self) -> bool {
+        matches!(self, Self::Face | Self::Bill | Self::Throat | Self::Breast)
+    }
+}
+
+/// Splits identification tips into one mark per body region, skipping clauses that
+/// describe no particular spot ("Takes 5 years to develop adult plumage")
+pub fn field_marks(identification_tips: &str) -> Vec<(BodyRegion, String)> {
+    let mut marks: Vec<(BodyRegion, String)> = Vec::new();
+    for clause in identification_tips.split([',', '.', ';', ':']).map(str::trim) {
+        let Some(region) = BodyRegion::from_clause(clause) else { continue };
+        if marks.iter().any(|(existing, _)| *existing == region) {
+            continue;
+        }
+        let mut label = clause.to_string();
+        if let Some(first) = label.get_mut(0..1) {
+            first.make_ascii_uppercase();
+        }
+        marks.push((region, label));
+        if marks.len() == MAX_CALLOUTS {
+            break;
+        }
+    }
+    marks
+}
+
+// Resources
+#[derive(Resource, Default)]
+pub struct FieldMarkCallouts {
+    // Bird under the center of the view and how long it has stayed there
+    bird: Option<Entity>,
+    centered_for: f32,
+    // Seconds since this bird's callouts appeared, None until they have
+    shown_for: Option<f32>,
+}
+
+// Components
+#[derive(Component)]
+pub struct FieldMarkLabel {
+    region: BodyRegion,
+    // Screen pixels above (negative) or below the bird's center, fixed while the label is up
+    slot_y: f32,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn field_mark_callout_system(
+    mut commands: Commands,
+    mut callouts: ResMut<FieldMarkCallouts>,
+    settings: Res<GameSettings>,
+    binoculars: Res<Binoculars>,
+    photo_settings: Res<PhotoModeSettings>,
+    education_data: Res<BirdEducationData>,
+    camera_query: Query<(&Transform, &Projection), With<PhotoTarget>>,
+    bird_query: Query<(Entity, &Transform, &Bird, Option<&Sprite>)>,
+    window_query: Query<&Window, With<bevy::window::PrimaryWindow>>,
+    ui_scale: Res<UiScale>,
+    mut label_query: Query<(Entity, &FieldMarkLabel, &mut Node, &mut TextColor, &mut BackgroundColor)>,
+    mut gizmos: Gizmos,
+    time: Res<Time>,
+) {
+    let viewing = settings.field_mark_callouts && (binoculars.active || photo_settings.is_active);
+    let camera = camera_query.single().ok().and_then(|(transform, projection)| match projection {
+        Projection::Orthographic(ortho) => Some((transform.translation.truncate(), ortho.scale)),
+        _ => None,
+    });
+    let (Some((center, scale)), true) = (camera, viewing) else {
+        if callouts.bird.is_some() {
+            *callouts = FieldMarkCallouts::default();
+            for (label, ..) in &label_query {
+                commands.entity(label).despawn();
+            }
+        }
+        return;
+    };
+
+    let distance = |transform: &Transform| transform.translation.truncate().distance(center);
+    let radius = CENTER_RADIUS * scale;
+    // Stick with the current bird while it stays in the circle, so two close birds don't trade places
+    let current = callouts.bird
+        .and_then(|bird| bird_query.get(bird).ok())
+        .filter(|(_, transform, ..)| distance(transform) <= radius)
+        .map(|(entity, ..)| entity);
+    let target = current.or_else(|| bird_query.iter()
+        .filter(|(_, transform, ..)| distance(transform) <= radius)
+        .min_by(|(_, a, ..), (_, b, ..)| distance(a).total_cmp(&distance(b)))
+        .map(|(entity, ..)| entity));
+
+    if target != callouts.bird {
+        *callouts = FieldMarkCallouts { bird: target, ..default() };
+        for (label, ..) in &label_query {
+            commands.entity(label).despawn();
+        }
+    }
+    let Some((_, bird_transform, bird, sprite)) = target.and_then(|bird| bird_query.get(bird).ok()) else { return };
+
+    let dt = time.delta_secs();
+    let shown_for = match callouts.shown_for {
+        Some(shown_for) => shown_for + dt,
+        None => {
+            callouts.centered_for += dt;
+            if callouts.centered_for < DWELL_SECONDS {
+                return;
+            }
+            if let Some(facts) = education_data.species_facts.get(&bird.species) {
+                spawn_field_mark_labels(&mut commands, &field_marks(&facts.identification_tips));
+            }
+            0.0
+        }
+    };
+    callouts.shown_for = Some(shown_for);
+
+    let alpha = 1.0 - ((shown_for - HOLD_SECONDS) / FADE_SECONDS).clamp(0.0, 1.0);
+    if alpha <= 0.0 {
+        for (label, ..) in &label_query {
+            commands.entity(label).despawn();
+        }
+        return;
+    }
+    let Ok(window) = window_query.single() else { return };
+
+    let facing = if sprite.is_some_and(|sprite| sprite.flip_x) { -1.0 } else { 1.0 };
+    let bird_position = bird_transform.translation.truncate();
+    let half_extent = BIRD_HALF_EXTENT * bird_transform.scale.x.abs();
+    let window_size = Vec2::new(window.width(), window.height());
+    let line_color = CALLOUT_COLOR.with_alpha(alpha);
+
+    for (_, label, mut node, mut text_color, mut background) in &mut label_query {
+        let anchor = bird_position + label.region.anchor() * Vec2::new(facing, 1.0) * half_extent;
+        let side = if label.region.is_front() { facing } else { -facing };
+        // Leader lines end a fixed distance out on screen whatever the zoom
+        let end = bird_position + Vec2::new(side * LEADER_PIXELS, -label.slot_y) * scale
+            + Vec2::new(side * half_extent, 0.0);
+        gizmos.line_2d(anchor, end, line_color);
+        gizmos.circle_2d(anchor, 2.0 * scale, line_color);
+
+        // World to UI pixels; the camera is orthographic and never rotates
+        let screen = (window_size / 2.0 + Vec2::new(end.x - center.x, center.y - end.y) / scale) / ui_scale.0;
+        node.top = Val::Px(screen.y - LABEL_HEIGHT / 2.0);
+        if side > 0.0 {
+            node.left = Val::Px(screen.x);
+            node.right = Val::Auto;
+        } else {
+            node.left = Val::Auto;
+            node.right = Val::Px(window_size.x / ui_scale.0 - screen.x);
+        }
+        text_color.0 = line_color;
+        background.0 = Color::srgba(0.0, 0.0, 0.0, 0.6 * alpha);
+    }
+}
+
+fn spawn_field_mark_labels(commands: &mut Commands, marks: &[(BodyRegion, String)]) {
+    for front in [true, false] {
+        // Stacked top to bottom in the same order as the marks on the bird, centered on it
+        let mut side: Vec<&(BodyRegion, String)> = marks.iter().filter(|(region, _)| region.is_front() == front).collect();
+        side.sort_by(|(a, _), (b, _)| b.anchor().y.total_cmp(&a.anchor().y));
+        let middle = (side.len() as f32 - 1.0) / 2.0;
+
+        for (index, (region, text)) in side.into_iter().enumerate() {
+            // Invisible until the callout system places it next frame
+            commands.spawn((
+                Text::new(text.clone()),
+                TextFont { font_size: 13.0, ..default() },
+                TextColor(Color::NONE),
+                Node {
+                    position_type: PositionType::Absolute,
+                    padding: UiRect::axes(Val::Px(6.0), Val::Px(3.0)),
+                    ..default()
+                },
+                BackgroundColor(Color::NONE),
+                BorderRadius::all(Val::Px(4.0)),
+                // Above the binoculars lens mask
+                GlobalZIndex(21),
+                Pickable::IGNORE,
+                FieldMarkLabel { region: *region, slot_y: (index as f32 - middle) * SLOT_SPACING },
+                StateScoped(crate::AppState::Playing),
+            ));
+        }
+    }
+}
@@ -16,6 +16,6 @@ impl Plugin for FlockingPlugin {
                 territorial_behavior_system,
                 predator_avoidance_system,
                 social_feeding_system,
-            ).run_if(in_state(crate::AppState::Playing)));
+            ).in_set(crate::GameSet::AI).run_if(in_state(crate::AppState::Playing)));
     }
 }
\ No newline at end of file
@@ -30,7 +30,7 @@ impl Plugin for ForagingEcologyPlugin {
                 leader_follower_dynamics_system,
                 insectivore_behavior_system,
                 frugivore_behavior_system,
-            ).run_if(in_state(crate::AppState::Playing)));
+            ).in_set(crate::GameSet::AI).run_if(in_state(crate::AppState::Playing)));
     }
 }
 
@@ -497,6 +497,7 @@ fn fruit_ripening_system(
             Weather::Cloudy => 0.8,
             Weather::Rainy => 1.2, // Rain helps ripening
             Weather::Windy => 0.9,
+            Weather::Foggy => 0.8,
             Weather::Snowy => 0.3,
         } * time.delta_secs();
 
@@ -23,7 +23,7 @@ impl Plugin for GardenLightingPlugin {
                 insect_spawning_system,
                 light_optimization_system,
                 debug_lighting_info,
-            ).run_if(in_state(crate::AppState::Playing)));
+            ).in_set(crate::GameSet::Simulation).run_if(in_state(crate::AppState::Playing)));
     }
 }
 
@@ -0,0 +1,451 @@
+// Garden Lines - Fences, hedges and paths drawn as continuous runs with corner and end pieces
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use crate::bird_ai::components::{BirdAction, ProvidesUtility, SmartObject};
+use crate::catalog::resources::PlayerInventory;
+use crate::keybindings::{GameAction, KeyBindings};
+use crate::notifications::{resources::ShowNotificationEvent, components::NotificationType};
+use crate::terrain_painting::TerrainBrush;
+use crate::camera::cursor_world_position;
+
+// Every run is built from pieces of this length, and priced per piece
+const SEGMENT_LENGTH: f32 = 40.0;
+const MAX_RUN_SEGMENTS: usize = 40;
+// Each stretch of path lets one more visitor wait in the yard at once
+const PATH_SEGMENTS_PER_VISITOR: usize = 8;
+const MAX_VISITORS: usize = 3;
+
+pub struct GardenLinesPlugin;
+
+impl Plugin for GardenLinesPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<GardenLines>()
+            .init_resource::<LineDrawing>()
+            .add_systems(Update, (
+                line_tool_toggle_system.run_if(crate::debug_console::console_is_not_visible),
+                line_drawing_input_system,
+                line_preview_system,
+                rebuild_line_segments_system,
+            ).chain().in_set(crate::GameSet::UI).run_if(in_state(crate::AppState::Playing)));
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LineKind {
+    Fence,
+    Hedge,
+    Path,
+}
+
+impl LineKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Fence => "Fence",
+            Self::Hedge => "Hedge",
+            Self::Path => "Path",
+        }
+    }
+
+    pub fn segment_price(&self) -> u32 {
+        match self {
+            Self::Fence => 12,
+            Self::Hedge => 20,
+            Self::Path => 8,
+        }
+    }
+
+    fn width(&self) -> f32 {
+        match self {
+            Self::Fence => 6.0,
+            Self::Hedge => 18.0,
+            Self::Path => 22.0,
+        }
+    }
+
+    fn color(&self) -> Color {
+        match self {
+            Self::Fence => Color::srgb(0.9, 0.88, 0.82),
+            Self::Hedge => Color::srgb(0.2, 0.45, 0.2),
+            Self::Path => Color::srgb(0.65, 0.55, 0.42),
+        }
+    }
+
+    // Posts, rounded hedge ends and stepping stones at the ends and bends of a run
+    fn piece_color(&self) -> Color {
+        match self {
+            Self::Fence => Color::srgb(0.75, 0.72, 0.66),
+            Self::Hedge => Color::srgb(0.16, 0.38, 0.16),
+            Self::Path => Color::srgb(0.55, 0.5, 0.45),
+        }
+    }
+
+    fn piece_size(&self) -> Vec2 {
+        match self {
+            Self::Fence => Vec2::new(10.0, 24.0),
+            Self::Hedge => Vec2::splat(24.0),
+            Self::Path => Vec2::splat(26.0),
+        }
+    }
+
+    // Paths lie flat under everything, fences and hedges stand with the objects
+    fn z(&self) -> f32 {
+        match self {
+            Self::Fence => 0.5,
+            Self::Hedge => 0.55,
+            Self::Path => 0.05,
+        }
+    }
+
+    // Fences and hedges turn dogs away, cats slip over or under either
+    pub fn blocks_animals(&self) -> bool {
+        matches!(self, Self::Fence | Self::Hedge)
+    }
+
+    fn next(tool: Option<LineKind>) -> Option<LineKind> {
+        match tool {
+            None => Some(Self::Fence),
+            Some(Self::Fence) => Some(Self::Hedge),
+            Some(Self::Hedge) => Some(Self::Path),
+            Some(Self::Path) => None,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct LineRun {
+    pub kind: LineKind,
+    pub points: Vec<[f32; 2]>,
+}
+
+impl LineRun {
+    pub fn sections(&self) -> impl Iterator<Item = (Vec2, Vec2)> + '_ {
+        self.points.windows(2).map(|pair| (Vec2::from(pair[0]), Vec2::from(pair[1])))
+    }
+
+    pub fn segment_count(&self) -> usize {
+        self.sections().map(|(start, end)| section_segments(start, end)).sum()
+    }
+}
+
+fn section_segments(start: Vec2, end: Vec2) -> usize {
+    (start.distance(end) / SEGMENT_LENGTH).round().max(1.0) as usize
+}
+
+// Resources
+
+// Saved with the sanctuary, the segment entities are rebuilt from the runs
+#[derive(Resource, Serialize, Deserialize, Clone, Debug, Default)]
+pub struct GardenLines {
+    pub runs: Vec<LineRun>,
+    #[serde(skip)]
+    pub dirty: bool,
+}
+
+impl GardenLines {
+    pub fn segments(&self, kind: LineKind) -> usize {
+        self.runs.iter()
+            .filter(|run| run.kind == kind)
+            .map(|run| run.segment_count())
+            .sum()
+    }
+
+    // How many visitors the yard can hold at once
+    pub fn visitor_capacity(&self) -> usize {
+        (1 + self.segments(LineKind::Path) / PATH_SEGMENTS_PER_VISITOR).min(MAX_VISITORS)
+    }
+
+    // Whether a step from one point to another crosses a fence or hedge
+    pub fn blocks(&self, from: Vec2, to: Vec2) -> bool {
+        self.runs.iter()
+            .filter(|run| run.kind.blocks_animals())
+            .flat_map(|run| run.sections())
+            .any(|(start, end)| sections_cross(from, to, start, end))
+    }
+}
+
+fn sections_cross(a1: Vec2, a2: Vec2, b1: Vec2, b2: Vec2) -> bool {
+    let side = |p: Vec2, q: Vec2, r: Vec2| (q - p).perp_dot(r - p);
+    let d1 = side(b1, b2, a1);
+    let d2 = side(b1, b2, a2);
+    let d3 = side(a1, a2, b1);
+    let d4 = side(a1, a2, b2);
+    d1 * d2 < 0.0 && d3 * d4 < 0.0
+}
+
+#[derive(Resource, Default)]
+pub struct LineDrawing {
+    pub tool: Option<LineKind>,
+    points: Vec<Vec2>,
+}
+
+impl LineDrawing {
+    pub fn put_away(&mut self) {
+        self.tool = None;
+        self.points.clear();
+    }
+
+    fn segment_count(&self, cursor: Option<Vec2>) -> usize {
+        let placed: usize = self.points.windows(2).map(|pair| section_segments(pair[0], pair[1])).sum();
+        let pending = match (self.points.last(), cursor) {
+            (Some(last), Some(cursor)) if last.distance(cursor) > 1.0 => section_segments(*last, cursor),
+            _ => 0,
+        };
+        placed + pending
+    }
+}
+
+// Components
+#[derive(Component)]
+pub struct GardenLineSegment;
+
+#[derive(Component)]
+pub struct LineToolLabel;
+
+// Next point snapped to whole segments and 45 degree bends from the last one
+fn snap_point(last: Option<Vec2>, cursor: Vec2) -> Vec2 {
+    let Some(last) = last else { return cursor };
+    let offset = cursor - last;
+    if offset.length() < 1.0 {
+        return last;
+    }
+
+    let step = std::f32::consts::FRAC_PI_4;
+    let angle = (offset.to_angle() / step).round() * step;
+    let length = (offset.length() / SEGMENT_LENGTH).round().max(1.0) * SEGMENT_LENGTH;
+    last + Vec2::from_angle(angle) * length
+}
+
+fn line_tool_toggle_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    key_bindings: Res<KeyBindings>,
+    mut drawing: ResMut<LineDrawing>,
+    mut terrain_brush: ResMut<TerrainBrush>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+) {
+    if !key_bindings.is_action_just_pressed(GameAction::DrawLines, &keyboard, &mouse) {
+        return;
+    }
+
+    drawing.tool = LineKind::next(drawing.tool);
+    drawing.points.clear();
+
+    if let Some(kind) = drawing.tool {
+        terrain_brush.put_away();
+        notification_events.write(ShowNotificationEvent {
+            notification: NotificationType::Info {
+                message: format!(
+                    "Drawing {}: click to add points, Enter to build, right-click to cancel ({} coins per segment)",
+                    kind.name().to_lowercase(),
+                    kind.segment_price(),
+                ),
+            },
+        });
+    }
+}
+
+fn line_drawing_input_system(
+    mouse_button: Res<ButtonInput<MouseButton>>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    windows: Query<&Window>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<Camera2d>>,
+    mut drawing: ResMut<LineDrawing>,
+    mut lines: ResMut<GardenLines>,
+    mut inventory: ResMut<PlayerInventory>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+) {
+    let Some(kind) = drawing.tool else { return };
+
+    // Right-click drops the run being drawn, or puts the tool away if there is none
+    if mouse_button.just_pressed(MouseButton::Right) {
+        if drawing.points.is_empty() {
+            drawing.tool = None;
+        }
+        drawing.points.clear();
+        return;
+    }
+
+    if mouse_button.just_pressed(MouseButton::Left) {
+        let Ok(window) = windows.single() else { return };
+        let Ok((camera, camera_transform)) = camera_query.single() else { return };
+        let Some(cursor) = cursor_world_position(window, camera, camera_transform) else { return };
+
+        let point = snap_point(drawing.points.last().copied(), cursor);
+        if drawing.points.last() == Some(&point) {
+            return;
+        }
+        drawing.points.push(point);
+
+        if drawing.segment_count(None) > MAX_RUN_SEGMENTS {
+            drawing.points.pop();
+            notification_events.write(ShowNotificationEvent {
+                notification: NotificationType::Warning {
+                    message: format!("A single run can be at most {} segments long", MAX_RUN_SEGMENTS),
+                },
+            });
+        }
+    }
+
+    if !keyboard.just_pressed(KeyCode::Enter) || drawing.points.len() < 2 {
+        return;
+    }
+
+    let run = LineRun {
+        kind,
+        points: drawing.points.iter().map(|point| point.to_array()).collect(),
+    };
+    let segments = run.segment_count();
+    let cost = segments as u32 * kind.segment_price();
+    if inventory.currency < cost {
+        notification_events.write(ShowNotificationEvent {
+            notification: NotificationType::Warning {
+                message: format!("{} segments of {} cost {} coins", segments, kind.name().to_lowercase(), cost),
+            },
+        });
+        return;
+    }
+
+    inventory.currency -= cost;
+    lines.runs.push(run);
+    lines.dirty = true;
+    drawing.points.clear();
+
+    notification_events.write(ShowNotificationEvent {
+        notification: NotificationType::Info {
+            message: format!("Built {} {} segments for {} coins", segments, kind.name().to_lowercase(), cost),
+        },
+    });
+}
+
+// Rubber-band guide for the run being drawn and a running cost readout
+fn line_preview_system(
+    mut commands: Commands,
+    mut gizmos: Gizmos,
+    drawing: Res<LineDrawing>,
+    inventory: Res<PlayerInventory>,
+    windows: Query<&Window>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<Camera2d>>,
+    mut label_query: Query<(Entity, &mut Text), With<LineToolLabel>>,
+) {
+    let Some(kind) = drawing.tool else {
+        for (entity, _) in label_query.iter() {
+            commands.entity(entity).despawn();
+        }
+        return;
+    };
+
+    let cursor = windows.single().ok()
+        .zip(camera_query.single().ok())
+        .and_then(|(window, (camera, camera_transform))| cursor_world_position(window, camera, camera_transform))
+        .map(|cursor| snap_point(drawing.points.last().copied(), cursor));
+
+    let segments = drawing.segment_count(cursor);
+    let cost = segments as u32 * kind.segment_price();
+    let color = if cost > inventory.currency {
+        Color::srgba(0.9, 0.3, 0.3, 0.8)
+    } else {
+        Color::srgba(1.0, 1.0, 1.0, 0.7)
+    };
+
+    for pair in drawing.points.windows(2) {
+        gizmos.line_2d(pair[0], pair[1], color);
+    }
+    if let (Some(last), Some(cursor)) = (drawing.points.last(), cursor) {
+        gizmos.line_2d(*last, cursor, color.with_alpha(0.4));
+    }
+    if let Some(cursor) = cursor {
+        gizmos.circle_2d(cursor, 6.0, color);
+    }
+
+    let label = format!(
+        "{} tool - {} segments, {} coins  (Enter to build, right-click to cancel)",
+        kind.name(), segments, cost,
+    );
+    if let Ok((_, mut text)) = label_query.single_mut() {
+        text.0 = label;
+    } else {
+        commands.spawn((
+            Text::new(label),
+            TextFont { font_size: 14.0, ..default() },
+            TextColor(Color::srgb(0.3, 0.2, 0.1)),
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(60.0),
+                left: Val::Px(20.0),
+                padding: UiRect::all(Val::Px(8.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.95, 0.92, 0.88, 0.9)),
+            LineToolLabel,
+        ));
+    }
+}
+
+fn rebuild_line_segments_system(
+    mut commands: Commands,
+    mut lines: ResMut<GardenLines>,
+    segment_query: Query<Entity, With<GardenLineSegment>>,
+) {
+    if !lines.dirty {
+        return;
+    }
+    lines.dirty = false;
+
+    for entity in segment_query.iter() {
+        commands.entity(entity).despawn();
+    }
+
+    for run in &lines.runs {
+        let kind = run.kind;
+
+        for (start, end) in run.sections() {
+            let count = section_segments(start, end);
+            let step = (end - start) / count as f32;
+            let rotation = Quat::from_rotation_z(step.to_angle());
+
+            for index in 0..count {
+                let center = start + step * (index as f32 + 0.5);
+                let mut segment = commands.spawn((
+                    Sprite::from_color(kind.color(), Vec2::new(step.length(), kind.width())),
+                    Transform::from_translation(center.extend(kind.z())).with_rotation(rotation),
+                    GardenLineSegment,
+                    Name::new(format!("{}Segment", kind.name())),
+                ));
+
+                // Hedges give birds somewhere to duck into
+                if kind == LineKind::Hedge {
+                    segment.insert((
+                        SmartObject,
+                        ProvidesUtility {
+                            action: BirdAction::Shelter,
+                            base_utility: 0.5,
+                            range: 120.0,
+                        },
+                    ));
+                }
+            }
+        }
+
+        // End pieces cap the run, corner pieces sit wherever it bends
+        let points: Vec<Vec2> = run.points.iter().map(|point| Vec2::from(*point)).collect();
+        for (index, point) in points.iter().enumerate() {
+            let is_end = index == 0 || index == points.len() - 1;
+            let is_corner = !is_end && {
+                let incoming = (points[index] - points[index - 1]).normalize_or_zero();
+                let outgoing = (points[index + 1] - points[index]).normalize_or_zero();
+                incoming.dot(outgoing) < 0.99
+            };
+            if !is_end && !is_corner {
+                continue;
+            }
+
+            commands.spawn((
+                Sprite::from_color(kind.piece_color(), kind.piece_size()),
+                Transform::from_translation(point.extend(kind.z() + 0.01)),
+                GardenLineSegment,
+                Name::new(format!("{}{}", kind.name(), if is_end { "End" } else { "Corner" })),
+            ));
+        }
+    }
+}
@@ -0,0 +1,498 @@
+// Garden Pests - Aphid and tent caterpillar outbreaks on the flower beds
+//
+// Now and then in spring and summer a flower bed comes down with pests. The bed turns into an
+// insect colony for the foraging ecology, so warblers, orioles and robins flock to it and turn up
+// more often, but while the pests feed the bed loses health and can't bloom past it. Right-click
+// an infested bed to pick the pests off by hand, clearing it at once along with the birds it drew,
+// or release ladybugs that thin them out over a few days so the birds keep coming a while longer.
+use bevy::prelude::*;
+use rand::Rng;
+use crate::aesthetic_objects::FlowerBed;
+use crate::bird::BirdSpecies;
+use crate::catalog::resources::PlayerInventory;
+use crate::environment::components::Season;
+use crate::environment::resources::TimeState;
+use crate::foraging_ecology::{EmergenceCycle, InsectActivityPattern, InsectColony, InsectType};
+use crate::notifications::{resources::ShowNotificationEvent, components::NotificationType};
+use crate::camera::cursor_world_position;
+
+const PANEL_WIDTH: f32 = 340.0;
+// Daily chance a healthy bed picks up an outbreak in the growing season
+const DAILY_OUTBREAK_CHANCE: f32 = 0.05;
+const STARTING_SEVERITY: f32 = 0.25;
+// Severity gained a day with nothing eating the pests, and lost a day to released ladybugs
+const DAILY_SPREAD: f32 = 0.08;
+const DAILY_LADYBUG_KILL: f32 = 0.18;
+// Bed health lost a day at full severity, and regained a day once the bed is clean
+const DAILY_DAMAGE: f32 = 0.12;
+const DAILY_RECOVERY: f32 = 0.05;
+const LADYBUG_COST: u32 = 60;
+const SPECK_COUNT: usize = 8;
+const SPECK_SIZE: f32 = 6.0;
+// Insects in the colony at full severity
+const FULL_COLONY: f32 = 500.0;
+
+pub struct GardenPestsPlugin;
+
+impl Plugin for GardenPestsPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<GardenPests>()
+            .add_systems(Update, (pest_outbreak_system, pest_colony_system)
+                .chain()
+                .in_set(crate::GameSet::Simulation)
+                .run_if(in_state(crate::AppState::Playing)))
+            .add_systems(Update, (pest_bed_click_system, pest_speck_click_system)
+                .run_if(crate::debug_console::console_is_not_visible)
+                .in_set(crate::GameSet::Input)
+                .run_if(in_state(crate::AppState::Playing)))
+            .add_systems(Update, (pest_button_system, refresh_pest_panel_system)
+                .chain()
+                .in_set(crate::GameSet::UI)
+                .run_if(in_state(crate::AppState::Playing)))
+            .add_systems(OnExit(crate::AppState::Playing), close_pest_panel_system);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PestKind {
+    Aphids,
+    TentCaterpillars,
+}
+
+impl PestKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Aphids => "Aphids",
+            Self::TentCaterpillars => "Tent caterpillars",
+        }
+    }
+
+    pub fn insect_type(&self) -> InsectType {
+        match self {
+            Self::Aphids => InsectType::Aphids,
+            Self::TentCaterpillars => InsectType::Caterpillars,
+        }
+    }
+
+    // Tent caterpillars hatch with the spring leaves, aphids build up through the summer
+    fn for_season(season: Season) -> Option<Self> {
+        match season {
+            Season::Spring if rand::random::<f32>() < 0.5 => Some(Self::TentCaterpillars),
+            Season::Spring | Season::Summer => Some(Self::Aphids),
+            Season::Fall | Season::Winter => None,
+        }
+    }
+
+    // Green aphid clusters, pale silk tents
+    fn speck_color(&self) -> Color {
+        match self {
+            Self::Aphids => Color::srgb(0.55, 0.75, 0.3),
+            Self::TentCaterpillars => Color::srgb(0.92, 0.9, 0.85),
+        }
+    }
+}
+
+// Resources
+#[derive(Resource, Default)]
+pub struct GardenPests {
+    // Every current outbreak and its severity, refreshed daily for the spawn tables
+    pub active: Vec<(PestKind, f32)>,
+    pub last_roll_day: u32,
+    // Bed whose intervention panel is open
+    pub selected: Option<Entity>,
+    panel_dirty: bool,
+}
+
+impl GardenPests {
+    /// Insect eaters that favour the outbreak's insects turn up more often while it lasts
+    pub fn apply(&self, weights: &mut [(BirdSpecies, f32)]) {
+        for (kind, severity) in &self.active {
+            for (species, weight) in weights.iter_mut() {
+                let likes = species.insect_preference().iter()
+                    .any(|(insect_type, preference)| *insect_type == kind.insect_type() && *preference >= 0.5);
+                if likes {
+                    *weight *= 1.0 + severity * 2.0;
+                }
+            }
+        }
+    }
+}
+
+// Components
+#[derive(Component)]
+pub struct PestOutbreak {
+    pub kind: PestKind,
+    pub severity: f32,
+    pub ladybugs: bool,
+}
+
+/// How well a planted bed is growing, 0-1; blooms can't go past it
+#[derive(Component)]
+pub struct PlantHealth(pub f32);
+
+// One cluster of pests to pick off during hand removal
+#[derive(Component)]
+pub struct PestSpeck {
+    pub bed: Entity,
+}
+
+#[derive(Component)]
+pub struct PestPanel;
+
+#[derive(Component)]
+pub struct PestButton {
+    pub action: PestAction,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PestAction {
+    PickByHand,
+    ReleaseLadybugs,
+    Close,
+}
+
+fn bed_name(flower_bed: &FlowerBed) -> String {
+    format!("{:?} bed", flower_bed.flower_type)
+}
+
+// Once a day: outbreaks spread or die back, beds take damage or recover, and new outbreaks may start
+pub fn pest_outbreak_system(
+    mut commands: Commands,
+    mut pests: ResMut<GardenPests>,
+    time_state: Res<TimeState>,
+    mut bed_query: Query<(Entity, &FlowerBed, Option<&mut PlantHealth>, Option<&mut PestOutbreak>)>,
+    speck_query: Query<(Entity, &PestSpeck)>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+) {
+    let today = time_state.absolute_day();
+    if pests.last_roll_day == today {
+        return;
+    }
+    pests.last_roll_day = today;
+    pests.panel_dirty = true;
+
+    let mut rng = rand::rng();
+    let mut active = Vec::new();
+    let mut clean_beds = Vec::new();
+    for (entity, flower_bed, health, outbreak) in &mut bed_query {
+        let Some(mut health) = health else {
+            commands.entity(entity).insert(PlantHealth(1.0));
+            clean_beds.push((entity, bed_name(flower_bed)));
+            continue;
+        };
+        let Some(mut outbreak) = outbreak else {
+            health.0 = (health.0 + DAILY_RECOVERY).min(1.0);
+            clean_beds.push((entity, bed_name(flower_bed)));
+            continue;
+        };
+
+        health.0 = (health.0 - outbreak.severity * DAILY_DAMAGE).max(0.0);
+        outbreak.severity += if outbreak.ladybugs { -DAILY_LADYBUG_KILL } else { DAILY_SPREAD };
+        outbreak.severity = outbreak.severity.min(1.0);
+
+        if outbreak.severity > 0.0 {
+            active.push((outbreak.kind, outbreak.severity));
+            continue;
+        }
+        commands.entity(entity).remove::<(PestOutbreak, InsectColony)>();
+        for (speck, _) in speck_query.iter().filter(|(_, speck)| speck.bed == entity) {
+            commands.entity(speck).despawn();
+        }
+        notification_events.write(ShowNotificationEvent {
+            notification: NotificationType::Info {
+                message: format!("The ladybugs have cleared the {} from the {}", outbreak.kind.name().to_lowercase(), bed_name(flower_bed)),
+            },
+        });
+    }
+
+    let outbreak_starts = !clean_beds.is_empty() && rng.random::<f32>() < DAILY_OUTBREAK_CHANCE;
+    if let Some(kind) = PestKind::for_season(time_state.get_season()).filter(|_| outbreak_starts) {
+        let (entity, name) = &clean_beds[rng.random_range(0..clean_beds.len())];
+        commands.entity(*entity).insert((
+            PestOutbreak { kind, severity: STARTING_SEVERITY, ladybugs: false },
+            InsectColony {
+                insect_type: kind.insect_type(),
+                population: (STARTING_SEVERITY * FULL_COLONY) as u32,
+                emergence_cycle: match kind {
+                    PestKind::Aphids => EmergenceCycle::WeatherTriggered,
+                    PestKind::TentCaterpillars => EmergenceCycle::Seasonal,
+                },
+                activity_pattern: InsectActivityPattern::AllDay,
+                last_emergence: 0.0,
+                peak_activity_hour: 12.0,
+            },
+        ));
+        active.push((kind, STARTING_SEVERITY));
+        info!("🐛 {} outbreak on the {}", kind.name(), name);
+        notification_events.write(ShowNotificationEvent {
+            notification: NotificationType::Warning {
+                message: format!(
+                    "{} on the {}! Insect-eating birds will love it, the flowers won't. Right-click the bed to deal with them",
+                    kind.name(), name,
+                ),
+            },
+        });
+    }
+
+    pests.active = active;
+}
+
+// Keeps each outbreak's insect colony stocked in line with how bad it is
+pub fn pest_colony_system(
+    mut colony_query: Query<(&PestOutbreak, &mut InsectColony)>,
+) {
+    for (outbreak, mut colony) in &mut colony_query {
+        let cap = (outbreak.severity * FULL_COLONY) as u32;
+        if colony.population > cap {
+            colony.population = cap;
+        }
+    }
+}
+
+// Right-click an infested bed to open its intervention panel
+pub fn pest_bed_click_system(
+    mouse_button: Res<ButtonInput<MouseButton>>,
+    windows: Query<&Window>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<Camera2d>>,
+    bed_query: Query<(Entity, &Transform, &Sprite), With<PestOutbreak>>,
+    mut pests: ResMut<GardenPests>,
+) {
+    if !mouse_button.just_pressed(MouseButton::Right) {
+        return;
+    }
+    let Ok(window) = windows.single() else { return };
+    let Ok((camera, camera_transform)) = camera_query.single() else { return };
+    let Some(cursor) = cursor_world_position(window, camera, camera_transform) else { return };
+
+    let clicked = bed_query.iter().find(|(_, transform, sprite)| {
+        let half_size = sprite.custom_size.unwrap_or(Vec2::splat(40.0)) / 2.0;
+        let offset = (cursor - transform.translation.truncate()).abs();
+        offset.x <= half_size.x && offset.y <= half_size.y
+    });
+    if let Some((entity, _, _)) = clicked {
+        pests.selected = Some(entity);
+        pests.panel_dirty = true;
+    }
+}
+
+// Hand removal: left-click each pest cluster to pick it off
+#[allow(clippy::too_many_arguments)]
+pub fn pest_speck_click_system(
+    mut commands: Commands,
+    mouse_button: Res<ButtonInput<MouseButton>>,
+    windows: Query<&Window>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<Camera2d>>,
+    speck_query: Query<(Entity, &PestSpeck, &Transform)>,
+    mut bed_query: Query<(&FlowerBed, &mut PestOutbreak)>,
+    mut pests: ResMut<GardenPests>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+) {
+    if !mouse_button.just_pressed(MouseButton::Left) || speck_query.is_empty() {
+        return;
+    }
+    let Ok(window) = windows.single() else { return };
+    let Ok((camera, camera_transform)) = camera_query.single() else { return };
+    let Some(cursor) = cursor_world_position(window, camera, camera_transform) else { return };
+
+    let Some((speck_entity, speck, _)) = speck_query.iter()
+        .find(|(_, _, transform)| transform.translation.truncate().distance(cursor) <= SPECK_SIZE * 1.5) else { return };
+    commands.entity(speck_entity).despawn();
+
+    let remaining = speck_query.iter().filter(|(entity, other, _)| other.bed == speck.bed && *entity != speck_entity).count();
+    let Ok((flower_bed, mut outbreak)) = bed_query.get_mut(speck.bed) else { return };
+    outbreak.severity *= remaining as f32 / (remaining + 1) as f32;
+    if remaining > 0 {
+        return;
+    }
+
+    commands.entity(speck.bed).remove::<(PestOutbreak, InsectColony)>();
+    pests.active.retain(|(kind, _)| *kind != outbreak.kind);
+    pests.panel_dirty = true;
+    notification_events.write(ShowNotificationEvent {
+        notification: NotificationType::Info {
+            message: format!("You picked the {} off the {} - it can start to recover", outbreak.kind.name().to_lowercase(), bed_name(flower_bed)),
+        },
+    });
+}
+
+pub fn pest_button_system(
+    mut commands: Commands,
+    mut button_query: Query<(&Interaction, &PestButton, &mut BackgroundColor), Changed<Interaction>>,
+    mut pests: ResMut<GardenPests>,
+    mut inventory: ResMut<PlayerInventory>,
+    mut bed_query: Query<(&Transform, &Sprite, &mut PestOutbreak)>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+) {
+    let mut chosen = None;
+    for (interaction, button, mut bg_color) in &mut button_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *bg_color = Color::srgb(0.5, 0.7, 0.5).into();
+                chosen = Some(button.action);
+            }
+            Interaction::Hovered => *bg_color = Color::srgb(0.7, 0.6, 0.5).into(),
+            Interaction::None => *bg_color = Color::srgb(0.6, 0.5, 0.4).into(),
+        }
+    }
+    let Some(action) = chosen else { return };
+    let Some(bed) = pests.selected else { return };
+    pests.panel_dirty = true;
+    let Ok((transform, sprite, mut outbreak)) = bed_query.get_mut(bed) else {
+        pests.selected = None;
+        return;
+    };
+
+    match action {
+        PestAction::PickByHand => {
+            // Scatter the clusters over the bed for the player to click away
+            let mut rng = rand::rng();
+            let half_size = sprite.custom_size.unwrap_or(Vec2::splat(40.0)) / 2.0 - Vec2::splat(SPECK_SIZE);
+            for _ in 0..SPECK_COUNT {
+                let offset = Vec2::new(rng.random_range(-1.0..1.0), rng.random_range(-1.0..1.0)) * half_size;
+                commands.spawn((
+                    Sprite::from_color(outbreak.kind.speck_color(), Vec2::splat(SPECK_SIZE)),
+                    Transform::from_translation(transform.translation + offset.extend(0.05)),
+                    PestSpeck { bed },
+                    Name::new("PestSpeck"),
+                ));
+            }
+        }
+        PestAction::ReleaseLadybugs => {
+            if inventory.currency < LADYBUG_COST {
+                notification_events.write(ShowNotificationEvent {
+                    notification: NotificationType::Warning {
+                        message: format!("Ladybugs cost {} coins", LADYBUG_COST),
+                    },
+                });
+                return;
+            }
+            inventory.currency -= LADYBUG_COST;
+            outbreak.ladybugs = true;
+            notification_events.write(ShowNotificationEvent {
+                notification: NotificationType::Info {
+                    message: format!("Ladybugs released - they'll eat through the {} over the next few days", outbreak.kind.name().to_lowercase()),
+                },
+            });
+        }
+        PestAction::Close => pests.selected = None,
+    }
+}
+
+pub fn refresh_pest_panel_system(
+    mut commands: Commands,
+    mut pests: ResMut<GardenPests>,
+    bed_query: Query<(&FlowerBed, &PlantHealth, &PestOutbreak)>,
+    speck_query: Query<&PestSpeck>,
+    panel_query: Query<Entity, With<PestPanel>>,
+) {
+    if !pests.panel_dirty {
+        return;
+    }
+    pests.panel_dirty = false;
+
+    for panel in &panel_query {
+        commands.entity(panel).despawn();
+    }
+    let Some(bed) = pests.selected else { return };
+    let Ok((flower_bed, health, outbreak)) = bed_query.get(bed) else {
+        pests.selected = None;
+        return;
+    };
+    let picking = speck_query.iter().any(|speck| speck.bed == bed);
+
+    spawn_pest_panel(&mut commands, flower_bed, health, outbreak, picking);
+}
+
+pub fn close_pest_panel_system(
+    mut commands: Commands,
+    mut pests: ResMut<GardenPests>,
+    panel_query: Query<Entity, With<PestPanel>>,
+    speck_query: Query<Entity, With<PestSpeck>>,
+) {
+    pests.selected = None;
+    for entity in panel_query.iter().chain(speck_query.iter()) {
+        commands.entity(entity).despawn();
+    }
+}
+
+fn spawn_pest_panel(commands: &mut Commands, flower_bed: &FlowerBed, health: &PlantHealth, outbreak: &PestOutbreak, picking: bool) {
+    let body_font = TextFont { font_size: 12.0, ..default() };
+    let body_color = TextColor(Color::srgb(0.4, 0.3, 0.2));
+
+    let mut actions = Vec::new();
+    if !picking {
+        actions.push((PestAction::PickByHand, "Pick them off by hand".to_string()));
+    }
+    if !outbreak.ladybugs {
+        actions.push((PestAction::ReleaseLadybugs, format!("Release ladybugs ({})", LADYBUG_COST)));
+    }
+    actions.push((PestAction::Close, "Leave them for the birds".to_string()));
+
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            right: Val::Px(20.0),
+            top: Val::Px(80.0),
+            width: Val::Px(PANEL_WIDTH),
+            flex_direction: FlexDirection::Column,
+            padding: UiRect::all(Val::Px(14.0)),
+            row_gap: Val::Px(6.0),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.95, 0.92, 0.88, 0.95)),
+        BorderRadius::all(Val::Px(8.0)),
+        PestPanel,
+        Name::new("GardenPests"),
+    )).with_children(|panel| {
+        panel.spawn((
+            Text::new(format!("{} on the {}", outbreak.kind.name(), bed_name(flower_bed))),
+            TextFont { font_size: 18.0, ..default() },
+            TextColor(Color::srgb(0.3, 0.2, 0.1)),
+        ));
+        panel.spawn((
+            Text::new(format!("Infestation: {:.0}%{} | Bed health: {:.0}%",
+                outbreak.severity * 100.0,
+                if outbreak.ladybugs { " (ladybugs at work)" } else { "" },
+                health.0 * 100.0)),
+            body_font.clone(),
+            body_color,
+        ));
+        panel.spawn((
+            Text::new(if picking {
+                "Left-click each cluster on the bed to pick it off"
+            } else {
+                "Picking them off saves the flowers now but the insect eaters leave with them. Ladybugs take a few days, so the birds keep coming a while longer. Left alone, the birds feast and the bed suffers."
+            }),
+            TextFont { font_size: 11.0, ..default() },
+            TextColor(Color::srgb(0.5, 0.4, 0.3)),
+        ));
+
+        panel.spawn(Node {
+            width: Val::Percent(100.0),
+            flex_wrap: FlexWrap::Wrap,
+            column_gap: Val::Px(4.0),
+            row_gap: Val::Px(4.0),
+            ..default()
+        }).with_children(|buttons| {
+            for (action, label) in actions {
+                buttons.spawn((
+                    Button,
+                    Node {
+                        padding: UiRect::axes(Val::Px(8.0), Val::Px(4.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.6, 0.5, 0.4)),
+                    BorderRadius::all(Val::Px(4.0)),
+                    PestButton { action },
+                )).with_children(|button| {
+                    button.spawn((
+                        Text::new(label),
+                        TextFont { font_size: 11.0, ..default() },
+                        TextColor(Color::WHITE),
+                    ));
+                });
+            }
+        });
+    });
+}
@@ -0,0 +1,283 @@
+// Garden Styles - Cottage, zen and rustic sets built from scenery and decorations, with set bonuses and journal badges
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use crate::aesthetic_objects::{AestheticObject, AestheticType};
+use crate::catalog::components::{PlaceableObject, PlaceableItemType};
+use crate::catalog::resources::PlayerInventory;
+use crate::sanctuary_management::SanctuaryReputation;
+use crate::notifications::{resources::ShowNotificationEvent, components::NotificationType};
+
+const BADGE_REWARD: u32 = 100;
+
+pub struct GardenStylesPlugin;
+
+impl Plugin for GardenStylesPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<GardenStyles>()
+            .add_systems(Update, garden_style_system.in_set(crate::GameSet::Simulation).run_if(in_state(crate::AppState::Playing)));
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum GardenStyle {
+    Cottage,
+    Zen,
+    Rustic,
+}
+
+impl GardenStyle {
+    pub const ALL: [GardenStyle; 3] = [Self::Cottage, Self::Zen, Self::Rustic];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Cottage => "Cottage",
+            Self::Zen => "Zen",
+            Self::Rustic => "Rustic",
+        }
+    }
+
+    pub fn badge_name(&self) -> &'static str {
+        match self {
+            Self::Cottage => "Cottage Gardener",
+            Self::Zen => "Quiet Garden",
+            Self::Rustic => "Back to the Woods",
+        }
+    }
+
+    // Scenery that belongs to the style
+    pub fn scenery(&self) -> &'static [AestheticType] {
+        match self {
+            Self::Cottage => &[AestheticType::FlowerBed, AestheticType::PicketFence, AestheticType::GardenOrnament],
+            Self::Zen => &[AestheticType::MossyRock, AestheticType::Pathway],
+            Self::Rustic => &[AestheticType::FallenLog, AestheticType::Mushrooms, AestheticType::TallGrass],
+        }
+    }
+
+    // Catalog items the player can add to complete the set
+    pub fn items(&self) -> &'static [PlaceableItemType] {
+        match self {
+            Self::Cottage => &[PlaceableItemType::GardenGnome, PlaceableItemType::FlowerPot, PlaceableItemType::BirdHouse, PlaceableItemType::SwingSeat],
+            Self::Zen => &[PlaceableItemType::WindChime, PlaceableItemType::FountainBirdbath, PlaceableItemType::StreamFeature],
+            Self::Rustic => &[PlaceableItemType::WoodenPerch, PlaceableItemType::DeadSnag, PlaceableItemType::NestingBox, PlaceableItemType::RoostBox],
+        }
+    }
+
+    pub fn piece_count(&self) -> usize {
+        self.scenery().len() + self.items().len()
+    }
+
+    // Distinct pieces needed before the set bonus applies
+    pub fn threshold(&self) -> usize {
+        match self {
+            Self::Cottage => 5,
+            Self::Zen => 4,
+            Self::Rustic => 5,
+        }
+    }
+
+    // Extra share of bird visits while the set is active
+    pub fn attraction_bonus(&self) -> f32 {
+        match self {
+            Self::Cottage => 0.1,
+            Self::Zen => 0.0,
+            Self::Rustic => 0.05,
+        }
+    }
+
+    // Reputation points while the set is active
+    pub fn reputation_bonus(&self) -> f32 {
+        match self {
+            Self::Cottage => 0.0,
+            Self::Zen => 5.0,
+            Self::Rustic => 2.0,
+        }
+    }
+
+    pub fn bonus_description(&self) -> &'static str {
+        match self {
+            Self::Cottage => "+10% bird visits",
+            Self::Zen => "+5 reputation",
+            Self::Rustic => "+5% bird visits, +2 reputation",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StyleRating {
+    pub pieces: usize,
+    pub total: usize,
+}
+
+impl StyleRating {
+    pub fn stars(&self) -> usize {
+        if self.total == 0 {
+            return 0;
+        }
+        (self.pieces as f32 / self.total as f32 * 5.0).round() as usize
+    }
+
+    pub fn star_string(&self) -> String {
+        let stars = self.stars();
+        format!("{}{}", "★".repeat(stars), "☆".repeat(5 - stars))
+    }
+}
+
+// Resources
+
+// Badges are saved with the sanctuary, ratings are worked out again from what is in the garden
+#[derive(Resource, Serialize, Deserialize, Clone, Debug, Default)]
+pub struct GardenStyles {
+    pub badges: Vec<GardenStyle>,
+    #[serde(skip)]
+    pub ratings: Vec<(GardenStyle, StyleRating)>,
+}
+
+impl GardenStyles {
+    pub fn rating(&self, style: GardenStyle) -> StyleRating {
+        self.ratings.iter()
+            .find(|(rated, _)| *rated == style)
+            .map(|(_, rating)| *rating)
+            .unwrap_or_default()
+    }
+
+    pub fn set_active(&self, style: GardenStyle) -> bool {
+        self.rating(style).pieces >= style.threshold()
+    }
+
+    pub fn active_sets(&self) -> impl Iterator<Item = GardenStyle> + '_ {
+        GardenStyle::ALL.into_iter().filter(|style| self.set_active(*style))
+    }
+
+    // Scales bird visits, used by environmental spawning
+    pub fn attraction_multiplier(&self) -> f32 {
+        1.0 + self.active_sets().map(|style| style.attraction_bonus()).sum::<f32>()
+    }
+
+    pub fn reputation_bonus(&self) -> f32 {
+        self.active_sets().map(|style| style.reputation_bonus()).sum()
+    }
+}
+
+fn garden_style_system(
+    mut styles: ResMut<GardenStyles>,
+    mut reputation: ResMut<SanctuaryReputation>,
+    mut inventory: ResMut<PlayerInventory>,
+    scenery_query: Query<&AestheticObject>,
+    placed_query: Query<&PlaceableObject>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+    mut check_timer: Local<Option<Timer>>,
+    time: Res<Time>,
+) {
+    let timer = check_timer.get_or_insert_with(|| Timer::from_seconds(1.0, TimerMode::Repeating));
+    timer.tick(time.delta());
+    if !timer.just_finished() && !styles.ratings.is_empty() {
+        return;
+    }
+
+    let scenery: HashSet<AestheticType> = scenery_query.iter().map(|object| object.object_type).collect();
+    let placed: HashSet<PlaceableItemType> = placed_query.iter().map(|object| object.item_type.clone()).collect();
+
+    let ratings: Vec<(GardenStyle, StyleRating)> = GardenStyle::ALL.into_iter()
+        .map(|style| {
+            let pieces = style.scenery().iter().filter(|piece| scenery.contains(piece)).count()
+                + style.items().iter().filter(|piece| placed.contains(piece)).count();
+            (style, StyleRating { pieces, total: style.piece_count() })
+        })
+        .collect();
+
+    let unchanged = styles.ratings.len() == ratings.len()
+        && styles.ratings.iter().zip(&ratings).all(|(old, new)| old.1.pieces == new.1.pieces);
+    if unchanged {
+        return;
+    }
+
+    let was_active: Vec<GardenStyle> = styles.active_sets().collect();
+    let first_check = styles.ratings.is_empty();
+    styles.ratings = ratings;
+
+    for style in GardenStyle::ALL {
+        let rating = styles.rating(style);
+
+        if styles.set_active(style) && !was_active.contains(&style) && !first_check {
+            notification_events.write(ShowNotificationEvent {
+                notification: NotificationType::Info {
+                    message: format!("{} garden style set complete: {}", style.name(), style.bonus_description()),
+                },
+            });
+        }
+
+        if rating.pieces == rating.total && !styles.badges.contains(&style) {
+            styles.badges.push(style);
+            inventory.currency += BADGE_REWARD;
+            notification_events.write(ShowNotificationEvent {
+                notification: NotificationType::Achievement {
+                    title: style.badge_name().to_string(),
+                    description: format!("Completed the {} garden collection", style.name().to_lowercase()),
+                    currency_reward: BADGE_REWARD,
+                },
+            });
+        }
+    }
+
+    let bonus = styles.reputation_bonus();
+    reputation.set_style_bonus(bonus);
+}
+
+// Journal section listing each collection and whether its badge has been earned
+pub fn spawn_style_badges(parent: &mut ChildSpawnerCommands, styles: &GardenStyles) {
+    parent.spawn((
+        Text::new("Garden Collections"),
+        TextFont { font_size: 18.0, ..default() },
+        TextColor(Color::srgb(0.3, 0.2, 0.1)),
+    ));
+
+    parent.spawn(Node {
+        width: Val::Percent(100.0),
+        flex_direction: FlexDirection::Row,
+        flex_wrap: FlexWrap::Wrap,
+        column_gap: Val::Px(10.0),
+        row_gap: Val::Px(10.0),
+        ..default()
+    }).with_children(|grid| {
+        for style in GardenStyle::ALL {
+            let earned = styles.badges.contains(&style);
+            let rating = styles.rating(style);
+            let (bg_color, border_color, text_color) = if earned {
+                (Color::srgb(0.95, 0.9, 0.75), Color::srgb(0.85, 0.65, 0.2), Color::srgb(0.5, 0.35, 0.1))
+            } else {
+                (Color::srgb(0.9, 0.9, 0.9), Color::srgb(0.6, 0.6, 0.6), Color::srgb(0.5, 0.5, 0.5))
+            };
+
+            grid.spawn((
+                Node {
+                    width: Val::Px(280.0),
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(4.0),
+                    padding: UiRect::all(Val::Px(12.0)),
+                    border: UiRect::all(Val::Px(2.0)),
+                    ..default()
+                },
+                BackgroundColor(bg_color),
+                BorderColor(border_color),
+            )).with_children(|card| {
+                card.spawn((
+                    Text::new(format!("{} {}", if earned { "🏅" } else { "○" }, style.badge_name())),
+                    TextFont { font_size: 16.0, ..default() },
+                    TextColor(text_color),
+                ));
+                card.spawn((
+                    Text::new(format!("{} collection  {}  {}/{}", style.name(), rating.star_string(), rating.pieces, rating.total)),
+                    TextFont { font_size: 12.0, ..default() },
+                    TextColor(Color::srgb(0.4, 0.3, 0.2)),
+                ));
+                card.spawn((
+                    Text::new(format!("Set bonus at {}: {}", style.threshold(), style.bonus_description())),
+                    TextFont { font_size: 11.0, ..default() },
+                    TextColor(Color::srgb(0.6, 0.4, 0.1)),
+                ));
+            });
+        }
+    });
+}
@@ -0,0 +1,508 @@
+// Gear Care - Storm wear on the camera and yard gear, weather sealing, repairs and insurance
+//
+// Shooting in rain, snow or a storm wears the camera down, and a worn camera takes softer, less
+// precise photos until it is repaired. Feeders and the tripod camera in the yard already weather
+// with the rest of the garden (see smart_objects::maintenance); here they can be fitted with
+// weather seals so storms barely touch them. Insurance costs a weekly premium and covers most of
+// every repair bill, so a stormy season is a cost the player can plan for instead of a surprise.
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use crate::advanced_weather::{StormManager, StormSeverity};
+use crate::catalog::components::{PlaceableItemType, PlaceableObject};
+use crate::catalog::resources::PlayerInventory;
+use crate::environment::resources::{TimeState, WeatherState};
+use crate::keybindings::{GameAction, KeyBindings};
+use crate::notifications::{resources::ShowNotificationEvent, components::NotificationType};
+use crate::photo_mode::components::{PhotoScore, PhotoTakenEvent};
+use crate::photo_mode::resources::PhotoModeSettings;
+use crate::smart_objects::SmartObjectRegistry;
+use crate::smart_objects::maintenance::{weather_exposure, wear_profile, WearState};
+
+const PANEL_WIDTH: f32 = 380.0;
+// Camera condition lost per shot and per game hour in photo mode, at an exposure of 1
+const CAMERA_WEAR_PER_SHOT: f32 = 0.015;
+const CAMERA_WEAR_PER_HOUR: f32 = 0.02;
+// Sealed gear takes this fraction of the wear
+const SEALED_WEAR: f32 = 0.1;
+// Weather resistance of sealed feeders and tripods, whatever they had before
+const SEALED_RESISTANCE: f32 = 0.9;
+// A worn camera keeps at least this share of its clarity and technical score
+const WORN_CAMERA_FLOOR: f32 = 0.4;
+const CAMERA_REPAIR_COST: f32 = 240.0;
+const SEALED_CAMERA_COST: u32 = 600;
+const SEALED_FEEDERS_COST: u32 = 450;
+const INSURANCE_PREMIUM: u32 = 40;
+const INSURANCE_DAYS: u32 = 7;
+// Share of each repair bill the insurer pays
+const INSURANCE_COVER: f32 = 0.75;
+
+pub struct GearCarePlugin;
+
+impl Plugin for GearCarePlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<GearCare>()
+            .add_systems(Update, toggle_gear_panel_system
+                .run_if(crate::debug_console::console_is_not_visible)
+                .in_set(crate::GameSet::Input)
+                .run_if(in_state(crate::AppState::Playing)))
+            .add_systems(Update, (camera_wear_system, insurance_premium_system)
+                .in_set(crate::GameSet::Simulation)
+                .run_if(in_state(crate::AppState::Playing)))
+            .add_systems(Update, (gear_button_system, refresh_gear_panel_system)
+                .chain()
+                .in_set(crate::GameSet::UI)
+                .run_if(in_state(crate::AppState::Playing)))
+            .add_systems(OnExit(crate::AppState::Playing), close_gear_panel_system);
+    }
+}
+
+/// How much harder than a dry day the weather is on unsealed gear, 0 when it is dry and calm
+pub fn storm_exposure(weather_state: &WeatherState, storm_manager: &StormManager) -> f32 {
+    let storm = match storm_manager.current_severity {
+        StormSeverity::Light | StormSeverity::Moderate => 0.0,
+        StormSeverity::Severe => 1.0,
+        StormSeverity::Extreme => 2.0,
+    };
+    weather_exposure(&weather_state.current_weather) - 1.0 + storm
+}
+
+// Yard gear the weather seals fit: anything that holds food, and the tripod camera
+pub fn takes_weather_seals(item_type: &PlaceableItemType) -> bool {
+    matches!(
+        item_type,
+        PlaceableItemType::BasicBirdSeed
+            | PlaceableItemType::PremiumSeed
+            | PlaceableItemType::SuetCake
+            | PlaceableItemType::NectarFeeder
+            | PlaceableItemType::FruitDispenser
+            | PlaceableItemType::PeanutTray
+            | PlaceableItemType::SeedCatcherTray
+            | PlaceableItemType::TripodCamera
+    )
+}
+
+// Resources
+#[derive(Resource, Serialize, Deserialize, Clone, Debug)]
+pub struct GearCare {
+    // 1.0 is a camera fresh out of the box
+    pub camera_condition: f32,
+    pub sealed_camera: bool,
+    pub sealed_feeders: bool,
+    pub insured: bool,
+    // Absolute day the next premium is due
+    pub premium_due_day: u32,
+    #[serde(skip)]
+    pub is_open: bool,
+    #[serde(skip)]
+    panel_dirty: bool,
+}
+
+impl Default for GearCare {
+    fn default() -> Self {
+        Self {
+            camera_condition: 1.0,
+            sealed_camera: false,
+            sealed_feeders: false,
+            insured: false,
+            premium_due_day: 0,
+            is_open: false,
+            panel_dirty: false,
+        }
+    }
+}
+
+impl GearCare {
+    pub fn camera_state(&self) -> WearState {
+        WearState::from_condition(self.camera_condition)
+    }
+
+    /// What the player pays of a repair bill once insurance has chipped in
+    pub fn bill(&self, cost: u32) -> u32 {
+        if self.insured {
+            (cost as f32 * (1.0 - INSURANCE_COVER)).round() as u32
+        } else {
+            cost
+        }
+    }
+
+    pub fn camera_repair_cost(&self) -> u32 {
+        (CAMERA_REPAIR_COST * (1.0 - self.camera_condition)).round() as u32
+    }
+
+    /// Resistance to use for a placed object in place of its own, when it is sealed
+    pub fn sealed_resistance(&self, item_type: &PlaceableItemType) -> Option<f32> {
+        (self.sealed_feeders && takes_weather_seals(item_type)).then_some(SEALED_RESISTANCE)
+    }
+
+    // Grit on the sensor and a sticky focus ring cost sharpness and precision, nothing else
+    pub fn penalize(&self, score: &mut PhotoScore) {
+        if self.camera_condition >= 1.0 {
+            return;
+        }
+        let keep = WORN_CAMERA_FLOOR + (1.0 - WORN_CAMERA_FLOOR) * self.camera_condition.clamp(0.0, 1.0);
+        let clarity = (score.clarity_score as f32 * keep) as u32;
+        let technical = (score.technical_score as f32 * keep) as u32;
+        let penalty = (score.clarity_score - clarity) + (score.technical_score - technical);
+        score.clarity_score = clarity;
+        score.technical_score = technical;
+        score.total_score = score.total_score.saturating_sub(penalty);
+        if penalty > 0 {
+            info!("  Worn camera: -{}", penalty);
+        }
+    }
+
+    fn wear_camera(&mut self, amount: f32) -> Option<WearState> {
+        let previous = self.camera_state();
+        let amount = if self.sealed_camera { amount * SEALED_WEAR } else { amount };
+        self.camera_condition = (self.camera_condition - amount).max(0.0);
+        let state = self.camera_state();
+        (state != previous).then_some(state)
+    }
+}
+
+// Components
+#[derive(Component)]
+pub struct GearPanel;
+
+#[derive(Component)]
+pub struct GearButton {
+    pub action: GearAction,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GearAction {
+    RepairCamera,
+    SealCamera,
+    SealFeeders,
+    ToggleInsurance,
+}
+
+pub fn toggle_gear_panel_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    key_bindings: Res<KeyBindings>,
+    mut gear: ResMut<GearCare>,
+) {
+    if key_bindings.is_action_just_pressed(GameAction::OpenGearCare, &keyboard, &mouse) {
+        gear.is_open = !gear.is_open;
+        gear.panel_dirty = true;
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn camera_wear_system(
+    mut photo_events: EventReader<PhotoTakenEvent>,
+    mut gear: ResMut<GearCare>,
+    photo_settings: Res<PhotoModeSettings>,
+    weather_state: Res<WeatherState>,
+    storm_manager: Res<StormManager>,
+    time_state: Res<TimeState>,
+    time: Res<Time>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+) {
+    let shots = photo_events.read().count();
+    let exposure = storm_exposure(&weather_state, &storm_manager);
+    if !photo_settings.is_active || exposure <= 0.0 {
+        return;
+    }
+
+    let game_hours = time.delta_secs() / time_state.time_speed;
+    let wear = (shots as f32 * CAMERA_WEAR_PER_SHOT + game_hours * CAMERA_WEAR_PER_HOUR) * exposure;
+    let shown = (gear.camera_condition * 100.0).round();
+    let changed = gear.wear_camera(wear);
+    if (gear.camera_condition * 100.0).round() != shown {
+        gear.panel_dirty = true;
+    }
+
+    let message = match changed {
+        Some(WearState::Worn) => "Your camera is getting wet - a weather-sealed body would shrug this off",
+        Some(WearState::Damaged) => "Moisture in the camera is softening your photos - it needs a repair",
+        Some(WearState::Broken) => "Your camera is badly storm-damaged - repair it in Gear Care",
+        _ => return,
+    };
+    notification_events.write(ShowNotificationEvent {
+        notification: NotificationType::Warning { message: message.to_string() },
+    });
+}
+
+pub fn insurance_premium_system(
+    mut gear: ResMut<GearCare>,
+    mut inventory: ResMut<PlayerInventory>,
+    time_state: Res<TimeState>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+) {
+    let today = time_state.absolute_day();
+    if !gear.insured || today < gear.premium_due_day {
+        return;
+    }
+
+    if inventory.currency < INSURANCE_PREMIUM {
+        gear.insured = false;
+        gear.panel_dirty = true;
+        notification_events.write(ShowNotificationEvent {
+            notification: NotificationType::Warning {
+                message: "Gear insurance lapsed - not enough coins for the premium".to_string(),
+            },
+        });
+        return;
+    }
+    inventory.currency -= INSURANCE_PREMIUM;
+    gear.premium_due_day = today + INSURANCE_DAYS;
+    gear.panel_dirty = true;
+    info!("🛡️ Paid {} coin gear insurance premium", INSURANCE_PREMIUM);
+}
+
+pub fn gear_button_system(
+    mut button_query: Query<(&Interaction, &GearButton, &mut BackgroundColor), Changed<Interaction>>,
+    mut gear: ResMut<GearCare>,
+    mut inventory: ResMut<PlayerInventory>,
+    time_state: Res<TimeState>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+) {
+    let mut chosen = None;
+    for (interaction, button, mut bg_color) in &mut button_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *bg_color = Color::srgb(0.5, 0.7, 0.5).into();
+                chosen = Some(button.action);
+            }
+            Interaction::Hovered => *bg_color = Color::srgb(0.7, 0.6, 0.5).into(),
+            Interaction::None => *bg_color = Color::srgb(0.6, 0.5, 0.4).into(),
+        }
+    }
+    let Some(action) = chosen else { return };
+
+    let cost = match action {
+        GearAction::RepairCamera => gear.bill(gear.camera_repair_cost()),
+        GearAction::SealCamera => SEALED_CAMERA_COST,
+        GearAction::SealFeeders => SEALED_FEEDERS_COST,
+        // Cancelling is free, signing up pays the first week up front
+        GearAction::ToggleInsurance if gear.insured => 0,
+        GearAction::ToggleInsurance => INSURANCE_PREMIUM,
+    };
+    if inventory.currency < cost {
+        notification_events.write(ShowNotificationEvent {
+            notification: NotificationType::Warning {
+                message: format!("That costs {} coins", cost),
+            },
+        });
+        return;
+    }
+    inventory.currency -= cost;
+
+    let message = match action {
+        GearAction::RepairCamera => {
+            gear.camera_condition = 1.0;
+            format!("Camera cleaned and repaired for {} coins", cost)
+        }
+        GearAction::SealCamera => {
+            gear.sealed_camera = true;
+            "Weather-sealed camera body fitted".to_string()
+        }
+        GearAction::SealFeeders => {
+            gear.sealed_feeders = true;
+            "Weather seals fitted to your feeders and tripod".to_string()
+        }
+        GearAction::ToggleInsurance => {
+            gear.insured = !gear.insured;
+            if gear.insured {
+                gear.premium_due_day = time_state.absolute_day() + INSURANCE_DAYS;
+                format!("Gear insured - {} coins a week covers {:.0}% of repairs", INSURANCE_PREMIUM, INSURANCE_COVER * 100.0)
+            } else {
+                "Gear insurance cancelled".to_string()
+            }
+        }
+    };
+    gear.panel_dirty = true;
+    notification_events.write(ShowNotificationEvent {
+        notification: NotificationType::Info { message },
+    });
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn refresh_gear_panel_system(
+    mut commands: Commands,
+    mut gear: ResMut<GearCare>,
+    registry: Res<SmartObjectRegistry>,
+    object_query: Query<(Entity, &PlaceableObject)>,
+    weather_state: Res<WeatherState>,
+    storm_manager: Res<StormManager>,
+    panel_query: Query<Entity, With<GearPanel>>,
+    mut refresh_timer: Local<Option<Timer>>,
+    time: Res<Time>,
+) {
+    // Yard gear wears on its own, so an open panel catches up every few seconds
+    let timer = refresh_timer.get_or_insert_with(|| Timer::from_seconds(2.0, TimerMode::Repeating));
+    timer.tick(time.delta());
+    let refresh_due = gear.is_open && timer.just_finished();
+    if !gear.panel_dirty && !refresh_due {
+        return;
+    }
+    gear.panel_dirty = false;
+
+    for panel in &panel_query {
+        commands.entity(panel).despawn();
+    }
+    if !gear.is_open {
+        return;
+    }
+
+    let mut yard_gear: Vec<(String, f32)> = object_query.iter()
+        .filter(|(_, placed)| takes_weather_seals(&placed.item_type))
+        .filter_map(|(entity, placed)| {
+            let object = registry.active_objects.get(&entity)?;
+            let profile = wear_profile(&registry, &object.definition_id);
+            Some((placed.item_type.name().to_string(), (object.current_durability / profile.max_durability).clamp(0.0, 1.0)))
+        })
+        .collect();
+    yard_gear.sort_by(|a, b| a.1.total_cmp(&b.1));
+
+    let exposure = storm_exposure(&weather_state, &storm_manager);
+    spawn_gear_panel(&mut commands, &gear, &yard_gear, exposure);
+}
+
+pub fn close_gear_panel_system(
+    mut commands: Commands,
+    mut gear: ResMut<GearCare>,
+    panel_query: Query<Entity, With<GearPanel>>,
+) {
+    gear.is_open = false;
+    for panel in &panel_query {
+        commands.entity(panel).despawn();
+    }
+}
+
+fn spawn_gear_panel(commands: &mut Commands, gear: &GearCare, yard_gear: &[(String, f32)], exposure: f32) {
+    let body_font = TextFont { font_size: 12.0, ..default() };
+    let heading_color = TextColor(Color::srgb(0.3, 0.2, 0.1));
+    let body_color = TextColor(Color::srgb(0.4, 0.3, 0.2));
+
+    let weather_note = if exposure <= 0.0 {
+        "Dry weather - no wear on your gear".to_string()
+    } else if exposure >= 2.0 {
+        format!("Storm conditions - unsealed gear is wearing {:.0}x faster", 1.0 + exposure)
+    } else {
+        "Wet weather - unsealed gear is wearing".to_string()
+    };
+
+    let mut actions = Vec::new();
+    if gear.camera_condition < 1.0 {
+        actions.push((GearAction::RepairCamera, format!("Repair camera ({})", gear.bill(gear.camera_repair_cost()))));
+    }
+    if !gear.sealed_camera {
+        actions.push((GearAction::SealCamera, format!("Weather-sealed body ({})", SEALED_CAMERA_COST)));
+    }
+    if !gear.sealed_feeders {
+        actions.push((GearAction::SealFeeders, format!("Seal feeders and tripod ({})", SEALED_FEEDERS_COST)));
+    }
+    actions.push((
+        GearAction::ToggleInsurance,
+        if gear.insured { "Cancel insurance".to_string() } else { format!("Insure gear ({}/week)", INSURANCE_PREMIUM) },
+    ));
+
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            right: Val::Px(20.0),
+            top: Val::Px(80.0),
+            width: Val::Px(PANEL_WIDTH),
+            flex_direction: FlexDirection::Column,
+            padding: UiRect::all(Val::Px(14.0)),
+            row_gap: Val::Px(6.0),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.95, 0.92, 0.88, 0.95)),
+        BorderRadius::all(Val::Px(8.0)),
+        GearPanel,
+        Name::new("GearCare"),
+    )).with_children(|panel| {
+        panel.spawn((
+            Text::new("Gear Care"),
+            TextFont { font_size: 20.0, ..default() },
+            heading_color,
+        ));
+        panel.spawn((
+            Text::new(weather_note),
+            body_font.clone(),
+            TextColor(Color::srgb(0.5, 0.4, 0.3)),
+        ));
+
+        panel.spawn((
+            Text::new(format!(
+                "Camera: {:.0}% ({}){}",
+                gear.camera_condition * 100.0,
+                gear.camera_state().name(),
+                if gear.sealed_camera { " - weather-sealed" } else { "" },
+            )),
+            TextFont { font_size: 14.0, ..default() },
+            heading_color,
+        ));
+        if gear.camera_condition < 1.0 {
+            let keep = WORN_CAMERA_FLOOR + (1.0 - WORN_CAMERA_FLOOR) * gear.camera_condition;
+            panel.spawn((
+                Text::new(format!("Photos keep {:.0}% of their clarity and technical score", keep * 100.0)),
+                body_font.clone(),
+                body_color,
+            ));
+        }
+
+        panel.spawn((
+            Text::new(format!("Feeders and tripod{}", if gear.sealed_feeders { " - weather-sealed" } else { "" })),
+            TextFont { font_size: 14.0, ..default() },
+            heading_color,
+        ));
+        if yard_gear.is_empty() {
+            panel.spawn((Text::new("None placed yet"), body_font.clone(), body_color));
+        }
+        for (name, condition) in yard_gear {
+            panel.spawn((
+                Text::new(format!("{}: {:.0}% ({})", name, condition * 100.0, WearState::from_condition(*condition).name())),
+                body_font.clone(),
+                body_color,
+            ));
+        }
+        panel.spawn((
+            Text::new("Repairs to yard gear are made from the Maintenance panel"),
+            TextFont { font_size: 11.0, ..default() },
+            TextColor(Color::srgb(0.5, 0.4, 0.3)),
+        ));
+
+        panel.spawn((
+            Text::new(if gear.insured {
+                format!("Insured: {:.0}% of every repair bill is covered", INSURANCE_COVER * 100.0)
+            } else {
+                "Uninsured: you pay repair bills in full".to_string()
+            }),
+            body_font.clone(),
+            body_color,
+        ));
+
+        panel.spawn(Node {
+            width: Val::Percent(100.0),
+            flex_wrap: FlexWrap::Wrap,
+            column_gap: Val::Px(4.0),
+            row_gap: Val::Px(4.0),
+            ..default()
+        }).with_children(|buttons| {
+            for (action, label) in actions {
+                buttons.spawn((
+                    Button,
+                    Node {
+                        padding: UiRect::axes(Val::Px(8.0), Val::Px(4.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.6, 0.5, 0.4)),
+                    BorderRadius::all(Val::Px(4.0)),
+                    GearButton { action },
+                )).with_children(|button| {
+                    button.spawn((
+                        Text::new(label),
+                        TextFont { font_size: 11.0, ..default() },
+                        TextColor(Color::WHITE),
+                    ));
+                });
+            }
+        });
+    });
+}
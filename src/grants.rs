@@ -0,0 +1,661 @@
+// Grants - Conservation grant applications and seasonal budget planning
+use bevy::prelude::*;
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
+use std::collections::HashSet;
+use crate::animation::components::AnimatedBird;
+use crate::bird::BirdSpecies;
+use crate::catalog::resources::PlayerInventory;
+use crate::difficulty::DifficultySettings;
+use crate::environment::components::Season;
+use crate::environment::resources::TimeState;
+use crate::feeder::{Feeder, FeederType};
+use crate::keybindings::{GameAction, KeyBindings};
+use crate::notifications::{resources::ShowNotificationEvent, components::NotificationType};
+use crate::sanctuary_management::{
+    DeterrentType, EnhancementType, HabitatEnhancement, NestingBox, PredatorDeterrent,
+    ReputationTier, SanctuaryReputation, SustainabilityScore,
+};
+
+const GRANT_CYCLE_DAYS: u32 = 30;
+const SEASON_DAYS: f32 = 91.0;
+
+pub struct GrantsPlugin;
+
+impl Plugin for GrantsPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<GrantOffice>()
+            .add_systems(Update, (
+                grant_calendar_system,
+                grant_progress_system,
+                toggle_grants_panel_system,
+                grants_button_system,
+                refresh_grants_panel_system,
+            ).chain().in_set(crate::GameSet::UI).run_if(in_state(crate::AppState::Playing)))
+            .add_systems(OnExit(crate::AppState::Playing), close_grants_panel_system);
+    }
+}
+
+// Resources
+#[derive(Resource, Default)]
+pub struct GrantOffice {
+    pub open_calls: Vec<Grant>,
+    pub application: Option<GrantApplication>,
+    pub grants_awarded: u32,
+    pub total_funding: u32,
+    pub days_elapsed: u32,
+    last_day: Option<u32>,
+    pub is_open: bool,
+    pub view: GrantsView,
+    panel_dirty: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum GrantsView {
+    #[default]
+    Grants,
+    Budget,
+}
+
+#[derive(Debug, Clone)]
+pub struct Grant {
+    pub title: &'static str,
+    pub funder: &'static str,
+    pub requirements: Vec<GrantRequirement>,
+    pub award: u32,
+    pub deadline_days: u32,
+}
+
+#[derive(Debug, Clone)]
+pub enum GrantRequirement {
+    NativeSpecies(u32),
+    NativePlantings(u32),
+    Feeders(u32),
+    Reputation(ReputationTier),
+    Sustainability(u32),
+}
+
+#[derive(Debug, Clone)]
+pub struct GrantApplication {
+    pub grant: Grant,
+    pub deadline: u32, // In elapsed days
+    pub species_seen: HashSet<BirdSpecies>,
+    pub met: Vec<bool>,
+}
+
+impl GrantRequirement {
+    pub fn label(&self) -> String {
+        match self {
+            Self::NativeSpecies(count) => format!("Attract {} native species", count),
+            Self::NativePlantings(count) => format!("Plant {} native beds", count),
+            Self::Feeders(count) => format!("Keep {} feeders in the yard", count),
+            Self::Reputation(tier) => format!("Reach {} reputation", tier.name()),
+            Self::Sustainability(score) => format!("Reach a sustainability score of {}", score),
+        }
+    }
+}
+
+// Components
+#[derive(Component)]
+pub struct GrantsPanel;
+
+#[derive(Component)]
+pub struct GrantsButton {
+    pub action: GrantsAction,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GrantsAction {
+    Apply(usize),
+    Withdraw,
+    PlantNativeBed,
+    ShowView(GrantsView),
+}
+
+#[derive(Component)]
+pub struct NativePlantBed;
+
+fn grant_pool() -> Vec<Grant> {
+    vec![
+        Grant {
+            title: "Backyard Habitat Starter Grant",
+            funder: "County Conservation District",
+            requirements: vec![GrantRequirement::NativeSpecies(3)],
+            award: 450,
+            deadline_days: 10,
+        },
+        Grant {
+            title: "Native Plants for Birds",
+            funder: "Audubon Chapter",
+            requirements: vec![GrantRequirement::NativePlantings(2), GrantRequirement::NativeSpecies(5)],
+            award: 1200,
+            deadline_days: 20,
+        },
+        Grant {
+            title: "Community Feeding Station Fund",
+            funder: "Neighborhood Association",
+            requirements: vec![GrantRequirement::Feeders(3), GrantRequirement::NativeSpecies(4)],
+            award: 700,
+            deadline_days: 14,
+        },
+        Grant {
+            title: "Pollinator Corridor Initiative",
+            funder: "State Wildlife Agency",
+            requirements: vec![GrantRequirement::NativePlantings(5), GrantRequirement::NativeSpecies(8)],
+            award: 2600,
+            deadline_days: 30,
+        },
+        Grant {
+            title: "Citizen Science Site Award",
+            funder: "Regional Bird Atlas",
+            requirements: vec![GrantRequirement::Reputation(ReputationTier::Regional), GrantRequirement::NativeSpecies(6)],
+            award: 1800,
+            deadline_days: 21,
+        },
+        Grant {
+            title: "Green Yard Stewardship Award",
+            funder: "Municipal Recycling Program",
+            requirements: vec![GrantRequirement::Sustainability(50), GrantRequirement::NativeSpecies(4)],
+            award: 900,
+            deadline_days: 21,
+        },
+    ]
+}
+
+// Three calls are open each cycle, chosen by the cycle number so reloads see the same list
+fn open_calls_for_cycle(cycle: u32) -> Vec<Grant> {
+    let mut rng = StdRng::seed_from_u64(cycle as u64);
+    let mut pool = grant_pool();
+    let mut calls = Vec::new();
+    while calls.len() < 3 && !pool.is_empty() {
+        calls.push(pool.remove(rng.random_range(0..pool.len())));
+    }
+    calls
+}
+
+pub fn grant_calendar_system(
+    mut office: ResMut<GrantOffice>,
+    time_state: Res<TimeState>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+) {
+    let day = time_state.day_of_year;
+    match office.last_day {
+        Some(last_day) if last_day == day => return,
+        Some(_) => office.days_elapsed += 1,
+        None => {}
+    }
+    office.last_day = Some(day);
+
+    if office.open_calls.is_empty() || office.days_elapsed % GRANT_CYCLE_DAYS == 0 {
+        office.open_calls = open_calls_for_cycle(office.days_elapsed / GRANT_CYCLE_DAYS);
+        if office.days_elapsed > 0 {
+            notification_events.write(ShowNotificationEvent {
+                notification: NotificationType::Info {
+                    message: "New conservation grants are open for applications".to_string(),
+                },
+            });
+        }
+    }
+
+    let expired = office.application.as_ref().is_some_and(|application| office.days_elapsed > application.deadline);
+    if expired {
+        if let Some(application) = office.application.take() {
+            notification_events.write(ShowNotificationEvent {
+                notification: NotificationType::Warning {
+                    message: format!("The {} deadline passed without meeting every requirement", application.grant.title),
+                },
+            });
+        }
+    }
+    office.panel_dirty = true;
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn grant_progress_system(
+    mut office: ResMut<GrantOffice>,
+    new_birds: Query<&AnimatedBird, Added<AnimatedBird>>,
+    enhancement_query: Query<&HabitatEnhancement>,
+    feeder_query: Query<&Feeder>,
+    reputation: Res<SanctuaryReputation>,
+    sustainability: Res<SustainabilityScore>,
+    mut inventory: ResMut<PlayerInventory>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+) {
+    let Some(application) = office.application.as_mut() else { return };
+
+    for bird in &new_birds {
+        if bird.species.is_native() {
+            application.species_seen.insert(bird.species);
+        }
+    }
+
+    let plantings = enhancement_query.iter()
+        .filter(|enhancement| enhancement.enhancement_type == EnhancementType::NativePlanting)
+        .count() as u32;
+    let feeders = feeder_query.iter().count() as u32;
+
+    let met: Vec<bool> = application.grant.requirements.iter().map(|requirement| match requirement {
+        GrantRequirement::NativeSpecies(count) => application.species_seen.len() as u32 >= *count,
+        GrantRequirement::NativePlantings(count) => plantings >= *count,
+        GrantRequirement::Feeders(count) => feeders >= *count,
+        GrantRequirement::Reputation(tier) => reputation.tier >= *tier,
+        GrantRequirement::Sustainability(score) => sustainability.score >= *score as f32,
+    }).collect();
+
+    if met == application.met {
+        return;
+    }
+    application.met = met;
+    office.panel_dirty = true;
+
+    if !office.application.as_ref().is_some_and(|application| application.met.iter().all(|met| *met)) {
+        return;
+    }
+
+    if let Some(application) = office.application.take() {
+        inventory.currency += application.grant.award;
+        office.grants_awarded += 1;
+        office.total_funding += application.grant.award;
+        notification_events.write(ShowNotificationEvent {
+            notification: NotificationType::Currency {
+                amount: application.grant.award,
+                reason: format!("{} awarded by {}", application.grant.title, application.grant.funder),
+            },
+        });
+        info!("💰 Grant awarded: {} ({} coins)", application.grant.title, application.grant.award);
+    }
+}
+
+pub fn toggle_grants_panel_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    key_bindings: Res<KeyBindings>,
+    mut office: ResMut<GrantOffice>,
+) {
+    if key_bindings.is_action_just_pressed(GameAction::OpenGrants, &keyboard, &mouse) {
+        office.is_open = !office.is_open;
+        office.panel_dirty = true;
+    }
+}
+
+pub fn grants_button_system(
+    mut commands: Commands,
+    mut button_query: Query<(&Interaction, &GrantsButton, &mut BackgroundColor), Changed<Interaction>>,
+    mut office: ResMut<GrantOffice>,
+    mut inventory: ResMut<PlayerInventory>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+) {
+    for (interaction, button, mut bg_color) in &mut button_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *bg_color = Color::srgb(0.5, 0.7, 0.5).into();
+                match button.action {
+                    GrantsAction::Apply(index) => {
+                        if office.application.is_some() {
+                            continue;
+                        }
+                        let Some(grant) = office.open_calls.get(index).cloned() else { continue };
+                        notification_events.write(ShowNotificationEvent {
+                            notification: NotificationType::Info {
+                                message: format!("Applied for {} - {} days to meet the requirements", grant.title, grant.deadline_days),
+                            },
+                        });
+                        office.application = Some(GrantApplication {
+                            deadline: office.days_elapsed + grant.deadline_days,
+                            met: vec![false; grant.requirements.len()],
+                            species_seen: HashSet::new(),
+                            grant,
+                        });
+                    }
+                    GrantsAction::Withdraw => {
+                        office.application = None;
+                    }
+                    GrantsAction::PlantNativeBed => {
+                        let cost = EnhancementType::NativePlanting.cost();
+                        if inventory.currency < cost {
+                            notification_events.write(ShowNotificationEvent {
+                                notification: NotificationType::Warning {
+                                    message: format!("Need {} coins to plant a native bed", cost),
+                                },
+                            });
+                            continue;
+                        }
+                        inventory.currency -= cost;
+                        spawn_native_plant_bed(&mut commands);
+                        notification_events.write(ShowNotificationEvent {
+                            notification: NotificationType::Currency {
+                                amount: cost,
+                                reason: "Planted a native bed".to_string(),
+                            },
+                        });
+                    }
+                    GrantsAction::ShowView(view) => {
+                        office.view = view;
+                    }
+                }
+                office.panel_dirty = true;
+            }
+            Interaction::Hovered => *bg_color = Color::srgb(0.7, 0.6, 0.5).into(),
+            Interaction::None => *bg_color = Color::srgb(0.6, 0.5, 0.4).into(),
+        }
+    }
+}
+
+// Native beds go along the back fence line, spread out so they don't overlap
+fn spawn_native_plant_bed(commands: &mut Commands) {
+    let x = rand::random::<f32>() * 900.0 - 450.0;
+    let y = -200.0 - rand::random::<f32>() * 40.0;
+    let enhancement_type = EnhancementType::NativePlanting;
+
+    commands.spawn((
+        Sprite::from_color(Color::srgb(0.3, 0.5, 0.25), Vec2::new(70.0, 26.0)),
+        Transform::from_xyz(x, y, 0.5),
+        HabitatEnhancement {
+            species_attraction: enhancement_type.attracted_species(),
+            seasonal_effectiveness: vec![
+                (Season::Spring, 1.0),
+                (Season::Summer, 1.0),
+                (Season::Fall, 0.8),
+                (Season::Winter, 0.3),
+            ],
+            maintenance_cost: 40,
+            ecological_impact: 0.8,
+            enhancement_type,
+        },
+        NativePlantBed,
+        Name::new("NativePlantBed"),
+    ));
+}
+
+// Rebuilds the panel after applications, progress changes and view switches
+pub fn refresh_grants_panel_system(
+    mut commands: Commands,
+    mut office: ResMut<GrantOffice>,
+    inventory: Res<PlayerInventory>,
+    time_state: Res<TimeState>,
+    difficulty: Res<DifficultySettings>,
+    feeder_query: Query<&Feeder>,
+    enhancement_query: Query<&HabitatEnhancement>,
+    deterrent_query: Query<&PredatorDeterrent>,
+    nesting_query: Query<&NestingBox>,
+    panel_query: Query<Entity, With<GrantsPanel>>,
+) {
+    if !office.panel_dirty {
+        return;
+    }
+    office.panel_dirty = false;
+
+    for panel in &panel_query {
+        commands.entity(panel).despawn();
+    }
+
+    if !office.is_open {
+        return;
+    }
+
+    let upkeep = YardUpkeep {
+        feeders: feeder_query.iter().map(|feeder| feeder.feeder_type).collect(),
+        enhancement_maintenance: enhancement_query.iter().map(|enhancement| enhancement.maintenance_cost).sum(),
+        deterrents: deterrent_query.iter().filter(|deterrent| deterrent.active).map(|deterrent| deterrent.deterrent_type).collect(),
+        nesting_boxes: nesting_query.iter().count() as u32,
+        refill_multiplier: difficulty.seed_cost_multiplier() * difficulty.depletion_multiplier(),
+    };
+
+    spawn_grants_panel(&mut commands, &office, &upkeep, inventory.currency, time_state.get_season());
+}
+
+pub fn close_grants_panel_system(
+    mut commands: Commands,
+    mut office: ResMut<GrantOffice>,
+    panel_query: Query<Entity, With<GrantsPanel>>,
+) {
+    office.is_open = false;
+    for panel in &panel_query {
+        commands.entity(panel).despawn();
+    }
+}
+
+// Budget planner
+struct YardUpkeep {
+    feeders: Vec<FeederType>,
+    enhancement_maintenance: u32,
+    deterrents: Vec<DeterrentType>,
+    nesting_boxes: u32,
+    // Difficulty scaling of food prices and how quickly feeders empty
+    refill_multiplier: f32,
+}
+
+struct SeasonBudget {
+    season: Season,
+    seed: u32,
+    maintenance: u32,
+}
+
+fn deterrent_upkeep(deterrent_type: DeterrentType) -> u32 {
+    match deterrent_type {
+        DeterrentType::MotionActivatedSprinkler | DeterrentType::UltrasonicDevice => 30,
+        DeterrentType::ReflectiveTape | DeterrentType::ScareOwl => 10,
+        DeterrentType::ProtectiveMesh | DeterrentType::GardenFence => 20,
+        DeterrentType::NaturalBarrier => 5,
+    }
+}
+
+impl YardUpkeep {
+    // Busier seasons empty feeders faster; a quiet season still needs a refill every couple of weeks
+    fn project(&self, season: Season) -> SeasonBudget {
+        let refills = SEASON_DAYS / 14.0 * season.bird_activity_modifier() * self.refill_multiplier;
+        let seed = self.feeders.iter()
+            .map(|feeder_type| feeder_type.refill_cost() as f32 * refills)
+            .sum::<f32>() as u32;
+
+        let mut maintenance = self.enhancement_maintenance
+            + self.deterrents.iter().map(|deterrent| deterrent_upkeep(*deterrent)).sum::<u32>();
+        // Nest boxes get cleaned out once the breeding season is over
+        if season == Season::Fall {
+            maintenance += self.nesting_boxes * 15;
+        }
+
+        SeasonBudget { season, seed, maintenance }
+    }
+}
+
+fn spawn_grants_panel(commands: &mut Commands, office: &GrantOffice, upkeep: &YardUpkeep, currency: u32, season: Season) {
+    commands.spawn((
+        Node {
+            width: Val::Px(480.0),
+            position_type: PositionType::Absolute,
+            right: Val::Px(20.0),
+            top: Val::Px(80.0),
+            flex_direction: FlexDirection::Column,
+            padding: UiRect::all(Val::Px(12.0)),
+            row_gap: Val::Px(6.0),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.95, 0.92, 0.88, 0.95)),
+        BorderRadius::all(Val::Px(8.0)),
+        GrantsPanel,
+    )).with_children(|panel| {
+        panel.spawn(Node {
+            flex_direction: FlexDirection::Row,
+            column_gap: Val::Px(8.0),
+            ..default()
+        }).with_children(|tabs| {
+            spawn_grants_button(tabs, "Grants", GrantsAction::ShowView(GrantsView::Grants), office.view == GrantsView::Grants);
+            spawn_grants_button(tabs, "Budget Planner", GrantsAction::ShowView(GrantsView::Budget), office.view == GrantsView::Budget);
+        });
+
+        match office.view {
+            GrantsView::Grants => spawn_grants_view(panel, office),
+            GrantsView::Budget => spawn_budget_view(panel, office, upkeep, currency, season),
+        }
+    });
+}
+
+fn spawn_grants_view(panel: &mut ChildSpawnerCommands, office: &GrantOffice) {
+    let next_cycle = GRANT_CYCLE_DAYS - office.days_elapsed % GRANT_CYCLE_DAYS;
+    panel.spawn((
+        Text::new(format!(
+            "{} grants awarded ({} coins)  •  New calls in {} days",
+            office.grants_awarded, office.total_funding, next_cycle,
+        )),
+        TextFont { font_size: 12.0, ..default() },
+        TextColor(Color::srgb(0.5, 0.4, 0.3)),
+    ));
+
+    if let Some(application) = &office.application {
+        panel.spawn((
+            Text::new(format!(
+                "Applied: {} - {} days left",
+                application.grant.title,
+                application.deadline.saturating_sub(office.days_elapsed),
+            )),
+            TextFont { font_size: 14.0, ..default() },
+            TextColor(Color::srgb(0.3, 0.2, 0.1)),
+        ));
+        for (requirement, met) in application.grant.requirements.iter().zip(&application.met) {
+            let progress = match requirement {
+                GrantRequirement::NativeSpecies(count) => format!(" ({}/{})", application.species_seen.len().min(*count as usize), count),
+                _ => String::new(),
+            };
+            panel.spawn((
+                Text::new(format!("  {} {}{}", if *met { "✓" } else { "○" }, requirement.label(), progress)),
+                TextFont { font_size: 12.0, ..default() },
+                TextColor(if *met { Color::srgb(0.3, 0.45, 0.3) } else { Color::srgb(0.4, 0.3, 0.2) }),
+            ));
+        }
+    }
+
+    panel.spawn(Node {
+        flex_direction: FlexDirection::Row,
+        column_gap: Val::Px(8.0),
+        ..default()
+    }).with_children(|row| {
+        if office.application.is_some() {
+            spawn_grants_button(row, "Withdraw", GrantsAction::Withdraw, false);
+        }
+        spawn_grants_button(
+            row,
+            &format!("Plant Native Bed ({} coins)", EnhancementType::NativePlanting.cost()),
+            GrantsAction::PlantNativeBed,
+            false,
+        );
+    });
+
+    for (index, grant) in office.open_calls.iter().enumerate() {
+        panel.spawn((
+            Node {
+                width: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(6.0)),
+                row_gap: Val::Px(2.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.9, 0.88, 0.85)),
+            BorderRadius::all(Val::Px(4.0)),
+        )).with_children(|card| {
+            card.spawn((
+                Text::new(format!("{} - {} coins", grant.title, grant.award)),
+                TextFont { font_size: 14.0, ..default() },
+                TextColor(Color::srgb(0.3, 0.2, 0.1)),
+            ));
+            card.spawn((
+                Text::new(format!("{}  •  {} days to complete", grant.funder, grant.deadline_days)),
+                TextFont { font_size: 11.0, ..default() },
+                TextColor(Color::srgb(0.5, 0.4, 0.3)),
+            ));
+            for requirement in &grant.requirements {
+                card.spawn((
+                    Text::new(format!("  • {}", requirement.label())),
+                    TextFont { font_size: 12.0, ..default() },
+                    TextColor(Color::srgb(0.4, 0.3, 0.2)),
+                ));
+            }
+            if office.application.is_none() {
+                spawn_grants_button(card, "Apply", GrantsAction::Apply(index), false);
+            }
+        });
+    }
+}
+
+fn spawn_budget_view(panel: &mut ChildSpawnerCommands, office: &GrantOffice, upkeep: &YardUpkeep, currency: u32, season: Season) {
+    panel.spawn((
+        Text::new(format!(
+            "{} feeders, {} active deterrents, {} nest boxes  •  Balance: {} coins",
+            upkeep.feeders.len(), upkeep.deterrents.len(), upkeep.nesting_boxes, currency,
+        )),
+        TextFont { font_size: 12.0, ..default() },
+        TextColor(Color::srgb(0.5, 0.4, 0.3)),
+    ));
+
+    // Grant money only arrives once every requirement is met, so it's noted rather than projected
+    let pending = office.application.as_ref().map(|application| application.grant.award).unwrap_or(0);
+    let mut balance = currency as i64;
+    let mut upcoming = season;
+
+    for _ in 0..4 {
+        let budget = upkeep.project(upcoming);
+        let total = budget.seed + budget.maintenance;
+        balance -= total as i64;
+
+        panel.spawn(Node {
+            width: Val::Percent(100.0),
+            flex_direction: FlexDirection::Row,
+            justify_content: JustifyContent::SpaceBetween,
+            padding: UiRect::axes(Val::Px(6.0), Val::Px(4.0)),
+            ..default()
+        }).with_children(|row| {
+            for (text, width) in [
+                (format!("{:?}", budget.season), 70.0),
+                (format!("Seed {}", budget.seed), 90.0),
+                (format!("Upkeep {}", budget.maintenance), 90.0),
+                (format!("Total {}", total), 90.0),
+                (format!("Left {}", balance), 90.0),
+            ] {
+                row.spawn((
+                    Text::new(text),
+                    TextFont { font_size: 12.0, ..default() },
+                    TextColor(if balance < 0 { Color::srgb(0.7, 0.25, 0.2) } else { Color::srgb(0.3, 0.2, 0.1) }),
+                    Node { width: Val::Px(width), ..default() },
+                ));
+            }
+        });
+
+        upcoming = upcoming.next();
+    }
+
+    let note = if pending > 0 {
+        format!("A successful grant application would add {} coins", pending)
+    } else if balance < 0 {
+        "Projected costs exceed your savings - a grant could close the gap".to_string()
+    } else {
+        "Your savings cover the coming year of upkeep".to_string()
+    };
+    panel.spawn((
+        Text::new(note),
+        TextFont { font_size: 12.0, ..default() },
+        TextColor(Color::srgb(0.4, 0.3, 0.2)),
+    ));
+}
+
+fn spawn_grants_button(parent: &mut ChildSpawnerCommands, label: &str, action: GrantsAction, selected: bool) {
+    parent.spawn((
+        Button,
+        Node {
+            padding: UiRect::axes(Val::Px(10.0), Val::Px(5.0)),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            align_self: AlignSelf::FlexStart,
+            ..default()
+        },
+        BackgroundColor(if selected { Color::srgb(0.5, 0.7, 0.5) } else { Color::srgb(0.6, 0.5, 0.4) }),
+        BorderRadius::all(Val::Px(4.0)),
+        GrantsButton { action },
+    )).with_children(|button| {
+        button.spawn((
+            Text::new(label),
+            TextFont { font_size: 12.0, ..default() },
+            TextColor(Color::WHITE),
+        ));
+    });
+}
@@ -19,7 +19,7 @@ impl Plugin for HanabiEffectsPlugin {
                 handle_particle_events,
                 update_seasonal_particles,
                 cleanup_interactive_particles,
-            ).run_if(in_state(crate::AppState::Playing)));
+            ).in_set(crate::GameSet::Presentation).run_if(in_state(crate::AppState::Playing)));
     }
 }
 
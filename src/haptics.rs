@@ -0,0 +1,133 @@
+// Haptics - Controller rumble for the shutter, achievements, thunder and feeders
+//
+// Each kind of moment has its own feel: a short tick on the weak motor for the shutter, a swell
+// for an achievement, a long heavy roll for a lightning strike and a light bump when a feeder is
+// hung or upgraded. Every connected gamepad gets the same rumble, scaled by the intensity setting,
+// and each kind can be switched off on its own under Controls in Settings.
+use std::time::Duration;
+use bevy::prelude::*;
+use bevy::input::gamepad::{GamepadRumbleIntensity, GamepadRumbleRequest};
+use serde::{Deserialize, Serialize};
+use crate::achievements::AchievementUnlockedEvent;
+use crate::bird_ai::components::BirdAction;
+use crate::catalog::resources::PlaceObjectEvent;
+use crate::feeder::FeederUpgradeEvent;
+use crate::lightning::Lightning;
+use crate::menu::resources::GameSettings;
+use crate::photo_mode::components::PhotoTakenEvent;
+
+// Steps the intensity setting cycles through; 0 turns rumble off altogether
+pub const RUMBLE_INTENSITIES: [f32; 5] = [0.0, 0.25, 0.5, 0.75, 1.0];
+
+pub struct HapticsPlugin;
+
+impl Plugin for HapticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, rumble_feedback_system
+            .in_set(crate::GameSet::Presentation)
+            .run_if(in_state(crate::AppState::Playing)));
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RumbleKind {
+    Shutter,
+    Achievement,
+    Thunder,
+    Feeder,
+}
+
+impl RumbleKind {
+    // Strong motor, weak motor, milliseconds
+    fn pattern(&self) -> (f32, f32, u64) {
+        match self {
+            Self::Shutter => (0.0, 0.5, 70),
+            Self::Achievement => (0.4, 0.7, 450),
+            Self::Thunder => (1.0, 0.6, 900),
+            Self::Feeder => (0.25, 0.35, 150),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RumbleSettings {
+    pub intensity: f32,
+    pub shutter: bool,
+    pub achievements: bool,
+    pub thunder: bool,
+    pub feeders: bool,
+}
+
+impl Default for RumbleSettings {
+    fn default() -> Self {
+        Self {
+            intensity: 0.75,
+            shutter: true,
+            achievements: true,
+            thunder: true,
+            feeders: true,
+        }
+    }
+}
+
+impl RumbleSettings {
+    pub fn enabled(&self, kind: RumbleKind) -> bool {
+        self.intensity > 0.0 && match kind {
+            RumbleKind::Shutter => self.shutter,
+            RumbleKind::Achievement => self.achievements,
+            RumbleKind::Thunder => self.thunder,
+            RumbleKind::Feeder => self.feeders,
+        }
+    }
+
+    pub fn next_intensity(&self) -> f32 {
+        RUMBLE_INTENSITIES.iter().copied()
+            .find(|step| *step > self.intensity)
+            .unwrap_or(RUMBLE_INTENSITIES[0])
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn rumble_feedback_system(
+    settings: Res<GameSettings>,
+    gamepad_query: Query<Entity, With<Gamepad>>,
+    mut photo_events: EventReader<PhotoTakenEvent>,
+    mut achievement_events: EventReader<AchievementUnlockedEvent>,
+    mut place_events: EventReader<PlaceObjectEvent>,
+    mut upgrade_events: EventReader<FeederUpgradeEvent>,
+    lightning: Res<Lightning>,
+    mut last_strikes: Local<u32>,
+    mut rumble_requests: EventWriter<GamepadRumbleRequest>,
+) {
+    let mut kinds = Vec::new();
+    if photo_events.read().count() > 0 {
+        kinds.push(RumbleKind::Shutter);
+    }
+    if achievement_events.read().count() > 0 {
+        kinds.push(RumbleKind::Achievement);
+    }
+    if lightning.strikes != *last_strikes {
+        *last_strikes = lightning.strikes;
+        kinds.push(RumbleKind::Thunder);
+    }
+    let feeder_placed = place_events.read().any(|event| event.item_type.provides_actions().contains(&BirdAction::Eat));
+    if upgrade_events.read().count() > 0 || feeder_placed {
+        kinds.push(RumbleKind::Feeder);
+    }
+
+    let rumble = &settings.rumble;
+    for kind in kinds.into_iter().filter(|kind| rumble.enabled(*kind)) {
+        let (strong, weak, millis) = kind.pattern();
+        let intensity = GamepadRumbleIntensity {
+            strong_motor: strong * rumble.intensity,
+            weak_motor: weak * rumble.intensity,
+        };
+        for gamepad in &gamepad_query {
+            rumble_requests.write(GamepadRumbleRequest::Add {
+                gamepad,
+                intensity,
+                duration: Duration::from_millis(millis),
+            });
+        }
+    }
+}
@@ -0,0 +1,202 @@
+// Hot Reload - Developer mode that pushes edited data files into the running game
+//
+// Run with `--hot-reload` (or AVIANHAVEN_HOT_RELOAD=1) and the data files under assets/data are
+// polled for changes. An edited behavior tree or smart object catalog replaces the copy its
+// registry holds, and placed objects pick up their new utility values; edited bird data is
+// re-read and the species packs are reloaded on top of it; species packs themselves reload through
+// their usual asset path; and the UI theme is applied to the shared button and panel styles. A
+// file that no longer parses is reported and the running data is left as it was.
+use bevy::prelude::*;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+use crate::bird_ai::components::ProvidesUtility;
+use crate::bird_ai::config::BehaviorTreeConfig;
+use crate::bird_ai::resources::BehaviorTreeConfigResource;
+use crate::bird_data::{BirdDataRegistry, BIRD_DATA_FILES};
+use crate::content_validation::{BEHAVIOR_TREE_FILE, SMART_OBJECT_CATALOG_FILE};
+use crate::notifications::{resources::ShowNotificationEvent, components::NotificationType};
+use crate::smart_objects::SmartObjectRegistry;
+use crate::smart_objects::config::SmartObjectCatalog;
+use crate::species_packs::SpeciesPackManager;
+use crate::user_interface::styles::{ButtonStyle, PanelStyle, UiThemeConfig};
+
+const HOT_RELOAD_FLAG: &str = "--hot-reload";
+const HOT_RELOAD_ENV: &str = "AVIANHAVEN_HOT_RELOAD";
+const WATCHED_DIRECTORY: &str = "data";
+const UI_THEME_FILE: &str = "data/ui_theme.ron";
+const SPECIES_PACK_DIRECTORY: &str = "data/species_packs/";
+const POLL_SECONDS: f32 = 0.5;
+
+pub struct HotReloadPlugin;
+
+impl Plugin for HotReloadPlugin {
+    fn build(&self, app: &mut App) {
+        if !hot_reload_enabled() {
+            return;
+        }
+        info!("🔁 Hot reload on: watching assets/{}", WATCHED_DIRECTORY);
+        app
+            .init_resource::<ContentWatcher>()
+            .add_systems(Update, (
+                content_watcher_system,
+                reload_behavior_tree_system,
+                reload_smart_object_catalog_system,
+            ).chain().in_set(crate::GameSet::Simulation));
+    }
+}
+
+pub fn hot_reload_enabled() -> bool {
+    std::env::args().any(|arg| arg == HOT_RELOAD_FLAG)
+        || std::env::var(HOT_RELOAD_ENV).is_ok_and(|value| value != "0")
+}
+
+// Resources
+#[derive(Resource)]
+pub struct ContentWatcher {
+    timer: Timer,
+    // Last seen modification time per file, keyed by asset path; empty until the first poll
+    stamps: HashMap<String, SystemTime>,
+}
+
+impl Default for ContentWatcher {
+    fn default() -> Self {
+        Self {
+            timer: Timer::from_seconds(POLL_SECONDS, TimerMode::Repeating),
+            stamps: HashMap::new(),
+        }
+    }
+}
+
+// Every .ron file below the watched directory with its modification time
+fn scan_data_files(directory: &Path, asset_path: &str, files: &mut Vec<(String, SystemTime)>) {
+    let Ok(entries) = std::fs::read_dir(directory) else { return };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let name = entry.file_name().to_string_lossy().to_string();
+        let child = format!("{}/{}", asset_path, name);
+        if path.is_dir() {
+            scan_data_files(&path, &child, files);
+            continue;
+        }
+        if !name.ends_with(".ron") {
+            continue;
+        }
+        if let Ok(modified) = entry.metadata().and_then(|metadata| metadata.modified()) {
+            files.push((child, modified));
+        }
+    }
+}
+
+fn report_failure(notifications: &mut EventWriter<ShowNotificationEvent>, file: &str, error: &str) {
+    warn!("Hot reload of {} failed, keeping the running data: {}", file, error);
+    notifications.write(ShowNotificationEvent {
+        notification: NotificationType::Warning { message: format!("Couldn't reload {}", file) },
+    });
+}
+
+// Polls the data files and routes each edited one to whatever owns it
+#[allow(clippy::too_many_arguments)]
+pub fn content_watcher_system(
+    mut watcher: ResMut<ContentWatcher>,
+    time: Res<Time>,
+    asset_server: Res<AssetServer>,
+    mut bird_registry: ResMut<BirdDataRegistry>,
+    pack_manager: Res<SpeciesPackManager>,
+    mut button_style: ResMut<ButtonStyle>,
+    mut panel_style: ResMut<PanelStyle>,
+    mut notifications: EventWriter<ShowNotificationEvent>,
+) {
+    if !watcher.timer.tick(time.delta()).just_finished() {
+        return;
+    }
+
+    let mut files = Vec::new();
+    scan_data_files(&Path::new("assets").join(WATCHED_DIRECTORY), WATCHED_DIRECTORY, &mut files);
+    let first_poll = watcher.stamps.is_empty();
+    let changed: Vec<String> = files.into_iter()
+        .filter_map(|(file, modified)| (watcher.stamps.insert(file.clone(), modified) != Some(modified)).then_some(file))
+        .collect();
+    if first_poll {
+        return;
+    }
+
+    let mut bird_data_changed = false;
+    for file in changed {
+        info!("🔁 {} changed", file);
+        if file == BEHAVIOR_TREE_FILE || file == SMART_OBJECT_CATALOG_FILE || file.starts_with(SPECIES_PACK_DIRECTORY) {
+            // Picked up by the asset's Modified event once it has loaded again
+            asset_server.reload(file);
+        } else if BIRD_DATA_FILES.contains(&file.as_str()) {
+            bird_data_changed = true;
+        } else if file == UI_THEME_FILE {
+            let theme = std::fs::read_to_string(PathBuf::from("assets").join(&file))
+                .map_err(|e| e.to_string())
+                .and_then(|content| ron::from_str::<UiThemeConfig>(&content).map_err(|e| e.to_string()));
+            match theme {
+                Ok(theme) => {
+                    theme.apply(&mut button_style, &mut panel_style);
+                    info!("🔁 UI theme applied");
+                }
+                Err(e) => report_failure(&mut notifications, &file, &e),
+            }
+        }
+    }
+
+    if bird_data_changed {
+        match bird_registry.reload_from_files() {
+            Ok(species) => {
+                info!("🔁 Bird data reloaded with {} species", species);
+                // Packs override the base data, so they go back on top
+                pack_manager.reload_all(&asset_server);
+            }
+            Err(e) => report_failure(&mut notifications, "bird data", &e),
+        }
+    }
+}
+
+pub fn reload_behavior_tree_system(
+    mut events: EventReader<AssetEvent<BehaviorTreeConfig>>,
+    config_assets: Res<Assets<BehaviorTreeConfig>>,
+    mut config_resource: ResMut<BehaviorTreeConfigResource>,
+) {
+    for event in events.read() {
+        let AssetEvent::Modified { id } = event else { continue };
+        if config_resource.handle.as_ref().map(|handle| handle.id()) != Some(*id) {
+            continue;
+        }
+        let Some(config) = config_assets.get(*id) else { continue };
+        info!("🔁 Behavior tree reloaded with {} rules", config.rules.len());
+        config_resource.config = Some(config.clone());
+        config_resource.use_configurable = true;
+    }
+}
+
+// Swaps in the new catalog and re-resolves what placed objects took from their definitions
+pub fn reload_smart_object_catalog_system(
+    mut events: EventReader<AssetEvent<SmartObjectCatalog>>,
+    catalog_assets: Res<Assets<SmartObjectCatalog>>,
+    mut registry: ResMut<SmartObjectRegistry>,
+    mut utility_query: Query<&mut ProvidesUtility>,
+) {
+    for event in events.read() {
+        let AssetEvent::Modified { id } = event else { continue };
+        if registry.catalog_handle.as_ref().map(|handle| handle.id()) != Some(*id) {
+            continue;
+        }
+        let Some(catalog) = catalog_assets.get(*id) else { continue };
+        registry.catalog = Some(catalog.clone());
+        registry.catalog_loaded = true;
+
+        let mut refreshed = 0;
+        for (entity, object) in &registry.active_objects {
+            let Some(definition) = registry.get_definition(&object.definition_id) else { continue };
+            if let Ok(mut utility) = utility_query.get_mut(*entity) {
+                utility.base_utility = definition.behavior.base_utility;
+                utility.range = definition.behavior.interaction_range;
+                refreshed += 1;
+            }
+        }
+        info!("🔁 Smart object catalog reloaded with {} items, {} placed objects refreshed", catalog.items.len(), refreshed);
+    }
+}
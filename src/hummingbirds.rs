@@ -0,0 +1,667 @@
+// Hummingbirds - Nectar feeders, dawn torpor and territorial chases
+use bevy::prelude::*;
+use std::collections::HashSet;
+use crate::bird::{Bird, BirdSpecies};
+use crate::bird_ai::components::{BirdAI, BirdState, Blackboard};
+use crate::bird_ai::resources::FeederPresence;
+use crate::feeder::{Feeder, FeederType};
+use crate::catalog::components::{PlaceableObject, PlaceableItemType};
+use crate::catalog::resources::PlayerInventory;
+use crate::environment::resources::{TimeState, WeatherState};
+use crate::environment::components::Weather;
+use crate::photo_mode::components::{PhotoTakenEvent, PhotoTarget};
+use crate::journal::resources::{ResearchMissionManager, DataType};
+use crate::notifications::{resources::ShowNotificationEvent, components::NotificationType};
+use crate::difficulty::DifficultySettings;
+
+pub struct HummingbirdsPlugin;
+
+impl Plugin for HummingbirdsPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<HummingbirdLog>()
+            .add_systems(Update, (
+                attach_nectar_reservoir_system,
+                nectar_spoilage_system,
+                torpor_system,
+                territorial_claim_system,
+                chase_start_system,
+                chase_flight_system,
+                torpor_animation_system,
+                hummingbird_photo_challenge_system,
+            ).chain()
+                // Runs after the behavior tree so torpor and chase states are not overwritten each tick
+                .after(crate::bird_ai::systems::behavior_tree_system)
+                .in_set(crate::GameSet::AI)
+                .run_if(in_state(crate::AppState::Playing)))
+            .add_systems(OnEnter(crate::AppState::Playing), setup_nectar_panel)
+            .add_systems(OnExit(crate::AppState::Playing), cleanup_nectar_panel)
+            .add_systems(Update, (
+                nectar_panel_button_system,
+                refresh_nectar_panel_system,
+            ).chain().in_set(crate::GameSet::UI).run_if(in_state(crate::AppState::Playing)));
+    }
+}
+
+const TORPOR_TEMPERATURE: f32 = 10.0;
+const CLAIM_RANGE: f32 = 40.0;
+const CHASE_RADIUS: f32 = 130.0;
+const CHASE_ESCAPE_DISTANCE: f32 = 420.0;
+const SPOILED_FRESHNESS: f32 = 0.25;
+const CLEAN_COST: f32 = 15.0;
+
+pub fn is_hummingbird(species: BirdSpecies) -> bool {
+    matches!(species, BirdSpecies::RubyThroatedHummingbird)
+}
+
+// Resources
+#[derive(Resource)]
+pub struct HummingbirdLog {
+    pub chases_observed: u32,
+    pub torpor_wakeups: u32,
+    pub completed_shots: Vec<HummingbirdShot>,
+    pub spoil_warned: bool,
+    refresh_timer: Timer,
+    panel_dirty: bool,
+}
+
+impl Default for HummingbirdLog {
+    fn default() -> Self {
+        Self {
+            chases_observed: 0,
+            torpor_wakeups: 0,
+            completed_shots: Vec::new(),
+            spoil_warned: false,
+            refresh_timer: Timer::from_seconds(2.0, TimerMode::Repeating),
+            panel_dirty: true,
+        }
+    }
+}
+
+// Photo challenges, each paid out the first time it is captured
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HummingbirdShot {
+    NectarSip,
+    TorporDawn,
+    AerialChase,
+}
+
+impl HummingbirdShot {
+    pub fn title(&self) -> &'static str {
+        match self {
+            Self::NectarSip => "Sip at the Feeder",
+            Self::TorporDawn => "Torpid at Dawn",
+            Self::AerialChase => "Aerial Dogfight",
+        }
+    }
+
+    fn reward(&self) -> u32 {
+        match self {
+            Self::NectarSip => 25,
+            Self::TorporDawn => 60,
+            Self::AerialChase => 50,
+        }
+    }
+
+    fn data_type(&self) -> DataType {
+        match self {
+            Self::NectarSip => DataType::FeedingPatterns,
+            Self::TorporDawn => DataType::BehaviorObservations,
+            Self::AerialChase => DataType::SpeciesInteractions,
+        }
+    }
+}
+
+// Components
+#[derive(Component)]
+pub struct NectarReservoir {
+    // Parts water to one part sugar; 4 matches flower nectar
+    pub water_parts: u32,
+    // 1.0 freshly mixed, spoiled below SPOILED_FRESHNESS
+    pub freshness: f32,
+    // Tint restored when the feeder is cleaned
+    pub fresh_color: Color,
+}
+
+impl NectarReservoir {
+    pub fn sweetness(&self) -> f32 {
+        (1.0 - (self.water_parts as f32 - 4.0).abs() * 0.25).max(0.25)
+    }
+
+    pub fn is_spoiled(&self) -> bool {
+        self.freshness < SPOILED_FRESHNESS
+    }
+
+    fn next_mix(&self) -> u32 {
+        if self.water_parts >= 6 { 3 } else { self.water_parts + 1 }
+    }
+}
+
+#[derive(Component)]
+pub struct Torpor {
+    pub warmth: f32,
+}
+
+#[derive(Component)]
+pub struct FeederGuard {
+    pub feeder: Entity,
+    pub chase_cooldown: Timer,
+    pub claim_timer: Timer,
+}
+
+#[derive(Component)]
+pub struct HummingbirdChase {
+    pub target: Entity,
+    pub timer: Timer,
+}
+
+#[derive(Component)]
+pub struct ChasedHummingbird {
+    pub pursuer: Entity,
+}
+
+#[derive(Component)]
+pub struct NectarPanel;
+
+#[derive(Component)]
+pub struct NectarPanelList;
+
+#[derive(Component)]
+pub struct NectarPanelRow;
+
+#[derive(Component)]
+pub struct NectarPanelButton {
+    pub feeder: Entity,
+    pub action: NectarAction,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NectarAction {
+    Mix,
+    Clean,
+}
+
+fn attach_nectar_reservoir_system(
+    mut commands: Commands,
+    feeder_query: Query<(Entity, &Feeder, &Sprite), (Added<Feeder>, Without<NectarReservoir>)>,
+    object_query: Query<(Entity, &PlaceableObject, &Sprite), (Added<PlaceableObject>, Without<NectarReservoir>)>,
+    mut log: ResMut<HummingbirdLog>,
+) {
+    let feeders = feeder_query.iter()
+        .filter(|(_, feeder, _)| feeder.feeder_type == FeederType::Nectar)
+        .map(|(entity, _, sprite)| (entity, sprite.color));
+    let placed = object_query.iter()
+        .filter(|(_, object, _)| object.item_type == PlaceableItemType::NectarFeeder)
+        .map(|(entity, _, sprite)| (entity, sprite.color));
+
+    for (entity, fresh_color) in feeders.chain(placed) {
+        commands.entity(entity).insert(NectarReservoir { water_parts: 4, freshness: 1.0, fresh_color });
+        log.panel_dirty = true;
+    }
+}
+
+// Sugar water ferments faster in the heat, and a strong mix faster still
+fn nectar_spoilage_system(
+    mut reservoir_query: Query<(&mut NectarReservoir, &mut Sprite)>,
+    weather_state: Res<WeatherState>,
+    time_state: Res<TimeState>,
+    mut log: ResMut<HummingbirdLog>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+    time: Res<Time>,
+) {
+    let days_elapsed = time.delta_secs() / (time_state.time_speed * 24.0);
+    let days_to_spoil = (6.0 - (weather_state.temperature - 15.0).max(0.0) / 4.0).clamp(1.5, 6.0);
+
+    for (mut reservoir, mut sprite) in &mut reservoir_query {
+        let strength = if reservoir.water_parts <= 3 { 1.3 } else { 1.0 };
+        let was_spoiled = reservoir.is_spoiled();
+        reservoir.freshness = (reservoir.freshness - days_elapsed * strength / days_to_spoil).max(0.0);
+
+        if reservoir.is_spoiled() {
+            // Cloudy, fermented nectar
+            sprite.color = sprite.color.mix(&Color::srgb(0.55, 0.5, 0.35), 0.02);
+            if !was_spoiled && !log.spoil_warned {
+                log.spoil_warned = true;
+                notification_events.write(ShowNotificationEvent {
+                    notification: NotificationType::Warning {
+                        message: "A nectar feeder has spoiled - hummingbirds won't drink until it is cleaned".to_string(),
+                    },
+                });
+            }
+        }
+    }
+}
+
+// Cold nights send hummingbirds into torpor; they warm up slowly after dawn
+fn torpor_system(
+    mut commands: Commands,
+    mut bird_query: Query<(Entity, &Bird, &mut BirdState, &mut Blackboard, Option<&mut Torpor>, &mut Transform), (With<BirdAI>, Without<HummingbirdChase>, Without<ChasedHummingbird>)>,
+    time_state: Res<TimeState>,
+    weather_state: Res<WeatherState>,
+    mut log: ResMut<HummingbirdLog>,
+    time: Res<Time>,
+) {
+    let night = time_state.hour >= 21.0 || time_state.hour < 5.0;
+    let cold = weather_state.temperature < TORPOR_TEMPERATURE;
+
+    for (entity, bird, mut state, mut blackboard, torpor, mut transform) in &mut bird_query {
+        if !is_hummingbird(bird.species) {
+            continue;
+        }
+
+        let Some(mut torpor) = torpor else {
+            if night && cold {
+                commands.entity(entity).insert(Torpor { warmth: 0.0 });
+                *state = BirdState::Resting;
+                blackboard.current_target = None;
+            }
+            continue;
+        };
+
+        *state = BirdState::Resting;
+        if night {
+            continue;
+        }
+
+        // Sunshine and milder mornings speed up the warm-up
+        let sun = if weather_state.current_weather == Weather::Clear { 1.5 } else { 1.0 };
+        let mildness = ((weather_state.temperature + 5.0) / 20.0).clamp(0.2, 1.5);
+        torpor.warmth += 0.05 * sun * mildness * time.delta_secs();
+
+        if torpor.warmth >= 1.0 {
+            commands.entity(entity).remove::<Torpor>();
+            *state = BirdState::Wandering;
+            // Wakes ravenous after the long fast
+            blackboard.internal.hunger = blackboard.internal.hunger.max(0.9);
+            transform.rotation = Quat::IDENTITY;
+            transform.scale = Vec3::ONE;
+            log.torpor_wakeups += 1;
+        }
+    }
+}
+
+// Fluffed-up stillness with slow breathing, then a shiver before take-off
+fn torpor_animation_system(
+    mut torpor_query: Query<(&Torpor, &mut Transform)>,
+    time: Res<Time>,
+) {
+    let elapsed = time.elapsed_secs();
+
+    for (torpor, mut transform) in &mut torpor_query {
+        if torpor.warmth > 0.7 {
+            transform.rotation = Quat::from_rotation_z((elapsed * 40.0).sin() * 0.08);
+            transform.scale = Vec3::new(1.15, 1.1, 1.0);
+        } else {
+            let breath = (elapsed * 0.8).sin() * 0.03;
+            transform.rotation = Quat::IDENTITY;
+            transform.scale = Vec3::new(1.25 + breath, 1.15 + breath, 1.0);
+        }
+    }
+}
+
+// A hummingbird feeding at a nectar feeder claims it for a while
+fn territorial_claim_system(
+    mut commands: Commands,
+    mut guard_query: Query<(Entity, &mut FeederGuard)>,
+    bird_query: Query<(Entity, &Bird, &BirdState, &Blackboard, &Transform), (With<BirdAI>, Without<FeederGuard>)>,
+    reservoir_query: Query<&Transform, With<NectarReservoir>>,
+    time: Res<Time>,
+) {
+    for (entity, mut guard) in &mut guard_query {
+        guard.chase_cooldown.tick(time.delta());
+        guard.claim_timer.tick(time.delta());
+        if guard.claim_timer.finished() || reservoir_query.get(guard.feeder).is_err() {
+            commands.entity(entity).remove::<FeederGuard>();
+        }
+    }
+
+    let guarded: Vec<Entity> = guard_query.iter().map(|(_, guard)| guard.feeder).collect();
+    for (entity, bird, state, blackboard, transform) in &bird_query {
+        if !is_hummingbird(bird.species) || *state != BirdState::HoverFeeding {
+            continue;
+        }
+        let Some(feeder) = blackboard.current_target else { continue };
+        let Ok(feeder_transform) = reservoir_query.get(feeder) else { continue };
+        if guarded.contains(&feeder) || transform.translation.distance(feeder_transform.translation) > CLAIM_RANGE {
+            continue;
+        }
+
+        commands.entity(entity).insert(FeederGuard {
+            feeder,
+            chase_cooldown: Timer::from_seconds(4.0, TimerMode::Once),
+            claim_timer: Timer::from_seconds(120.0, TimerMode::Once),
+        });
+    }
+}
+
+// Guards drive off any other hummingbird that comes near their feeder
+fn chase_start_system(
+    mut commands: Commands,
+    mut guard_query: Query<(Entity, &mut FeederGuard), (Without<HummingbirdChase>, Without<Torpor>)>,
+    mut bird_query: Query<(Entity, &Bird, &Transform, &mut BirdState, &mut Blackboard), (With<BirdAI>, Without<ChasedHummingbird>, Without<Torpor>)>,
+    reservoir_query: Query<&Transform, With<NectarReservoir>>,
+    mut presence: ResMut<FeederPresence>,
+) {
+    for (guard_entity, mut guard) in &mut guard_query {
+        if !guard.chase_cooldown.finished() {
+            continue;
+        }
+        let Ok(feeder_transform) = reservoir_query.get(guard.feeder) else { continue };
+        let feeder_pos = feeder_transform.translation.truncate();
+
+        let intruder = bird_query.iter()
+            .filter(|(entity, bird, transform, _, _)| {
+                *entity != guard_entity
+                    && is_hummingbird(bird.species)
+                    && transform.translation.truncate().distance(feeder_pos) < CHASE_RADIUS
+            })
+            .map(|(entity, ..)| entity)
+            .next();
+        let Some(intruder) = intruder else { continue };
+
+        if let Ok((_, _, _, mut state, mut blackboard)) = bird_query.get_mut(intruder) {
+            *state = BirdState::Fleeing;
+            blackboard.current_target = None;
+        }
+        presence.leave(intruder);
+        presence.leave(guard_entity);
+
+        commands.entity(intruder).insert(ChasedHummingbird { pursuer: guard_entity });
+        commands.entity(guard_entity).insert(HummingbirdChase {
+            target: intruder,
+            timer: Timer::from_seconds(2.5 + rand::random::<f32>() * 1.5, TimerMode::Once),
+        });
+        guard.chase_cooldown.reset();
+    }
+}
+
+// Pursuer dives after the intruder in tight zig-zags until it gives up or the intruder escapes
+fn chase_flight_system(
+    mut commands: Commands,
+    mut pursuer_query: Query<(Entity, &mut HummingbirdChase, &mut Transform, &mut BirdState, &mut Blackboard), Without<ChasedHummingbird>>,
+    mut target_query: Query<(&ChasedHummingbird, &mut Transform, &mut BirdState), Without<HummingbirdChase>>,
+    mut log: ResMut<HummingbirdLog>,
+    time: Res<Time>,
+) {
+    let elapsed = time.elapsed_secs();
+    let dt = time.delta_secs();
+
+    for (pursuer, mut chase, mut transform, mut state, mut blackboard) in &mut pursuer_query {
+        chase.timer.tick(time.delta());
+
+        let Ok((chased, mut target_transform, mut target_state)) = target_query.get_mut(chase.target) else {
+            commands.entity(pursuer).remove::<HummingbirdChase>();
+            *state = BirdState::Wandering;
+            continue;
+        };
+
+        let pursuer_pos = transform.translation.truncate();
+        let target_pos = target_transform.translation.truncate();
+        let away = (target_pos - pursuer_pos).normalize_or(Vec2::X);
+        let swerve = away.perp() * (elapsed * 9.0).sin();
+
+        let escaped = pursuer_pos.distance(target_pos) > CHASE_ESCAPE_DISTANCE;
+        if chase.timer.finished() || escaped || chased.pursuer != pursuer {
+            for transform in [&mut *transform, &mut *target_transform] {
+                transform.rotation = Quat::IDENTITY;
+                transform.scale = Vec3::ONE;
+            }
+            commands.entity(pursuer).remove::<HummingbirdChase>();
+            commands.entity(chase.target).remove::<ChasedHummingbird>();
+            *state = BirdState::Wandering;
+            *target_state = BirdState::Wandering;
+            log.chases_observed += 1;
+            continue;
+        }
+
+        *state = BirdState::Territorial;
+        *target_state = BirdState::Fleeing;
+        blackboard.internal.energy = (blackboard.internal.energy - 0.05 * dt).max(0.0);
+
+        let pursuer_velocity = (away + swerve * 0.6).normalize_or_zero() * 260.0;
+        let target_velocity = (away - swerve * 0.8).normalize_or_zero() * 240.0;
+        transform.translation += (pursuer_velocity * dt).extend(0.0);
+        target_transform.translation += (target_velocity * dt).extend(0.0);
+
+        // Bank into each turn with a blur of wingbeats
+        let flutter = (elapsed * 30.0).sin() * 0.1;
+        transform.rotation = Quat::from_rotation_z(pursuer_velocity.y.atan2(pursuer_velocity.x) * 0.2 + flutter);
+        transform.scale = Vec3::new(1.0 + flutter.abs(), 0.95, 1.0);
+        target_transform.rotation = Quat::from_rotation_z(target_velocity.y.atan2(target_velocity.x) * 0.2 - flutter);
+    }
+}
+
+type PhotoSubjectQuery<'w, 's> = Query<'w, 's,
+    (&'static Bird, &'static Transform, &'static Blackboard, Has<Torpor>, Has<HummingbirdChase>, Has<ChasedHummingbird>)>;
+
+// Pays out when the photographed bird itself is a hummingbird caught sipping, torpid or mid-chase
+#[allow(clippy::too_many_arguments)]
+fn hummingbird_photo_challenge_system(
+    mut photo_events: EventReader<PhotoTakenEvent>,
+    camera_query: Query<&Transform, With<PhotoTarget>>,
+    bird_query: PhotoSubjectQuery,
+    reservoir_query: Query<(), With<NectarReservoir>>,
+    mut log: ResMut<HummingbirdLog>,
+    mut inventory: ResMut<PlayerInventory>,
+    mut research: ResMut<ResearchMissionManager>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+) {
+    for event in photo_events.read() {
+        let Some(species) = event.species.filter(|species| is_hummingbird(*species)) else {
+            continue;
+        };
+        let Ok(camera_transform) = camera_query.single() else {
+            continue;
+        };
+        let camera_pos = camera_transform.translation.truncate();
+
+        // The subject is the bird of the photographed species nearest the middle of the frame
+        let subject = bird_query.iter()
+            .filter(|(bird, ..)| bird.species == species)
+            .min_by(|a, b| {
+                let distance = |transform: &Transform| transform.translation.truncate().distance(camera_pos);
+                distance(a.1).total_cmp(&distance(b.1))
+            });
+        let Some((_, _, blackboard, torpid, chasing, chased)) = subject else {
+            continue;
+        };
+
+        let mut shots = HashSet::new();
+        if torpid {
+            shots.insert(HummingbirdShot::TorporDawn);
+        }
+        if chasing || chased {
+            shots.insert(HummingbirdShot::AerialChase);
+        }
+        let at_nectar = blackboard.current_target.is_some_and(|target| reservoir_query.contains(target));
+        if event.behavior == Some(BirdState::HoverFeeding) && at_nectar {
+            shots.insert(HummingbirdShot::NectarSip);
+        }
+
+        for shot in shots {
+            *research.collected_data.entry(shot.data_type()).or_insert(0) += 1;
+            if log.completed_shots.contains(&shot) {
+                continue;
+            }
+
+            log.completed_shots.push(shot);
+            inventory.currency += shot.reward();
+            notification_events.write(ShowNotificationEvent {
+                notification: NotificationType::Currency {
+                    amount: shot.reward(),
+                    reason: format!("Photo challenge: {}", shot.title()),
+                },
+            });
+        }
+    }
+}
+
+fn setup_nectar_panel(mut commands: Commands, mut log: ResMut<HummingbirdLog>) {
+    log.panel_dirty = true;
+
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(20.0),
+            bottom: Val::Px(20.0),
+            width: Val::Px(250.0),
+            flex_direction: FlexDirection::Column,
+            padding: UiRect::all(Val::Px(8.0)),
+            row_gap: Val::Px(4.0),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.95, 0.92, 0.88, 0.9)),
+        BorderRadius::all(Val::Px(6.0)),
+        Visibility::Hidden,
+        NectarPanel,
+        Name::new("NectarPanel"),
+    )).with_children(|panel| {
+        panel.spawn((
+            Text::new("Nectar Feeders"),
+            TextFont { font_size: 13.0, ..default() },
+            TextColor(Color::srgb(0.3, 0.2, 0.1)),
+        ));
+        panel.spawn((
+            Node {
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(4.0),
+                ..default()
+            },
+            NectarPanelList,
+        ));
+    });
+}
+
+fn cleanup_nectar_panel(
+    mut commands: Commands,
+    panel_query: Query<Entity, With<NectarPanel>>,
+) {
+    for entity in &panel_query {
+        commands.entity(entity).despawn();
+    }
+}
+
+fn nectar_panel_button_system(
+    mut button_query: Query<(&Interaction, &NectarPanelButton, &mut BackgroundColor), Changed<Interaction>>,
+    mut reservoir_query: Query<(&mut NectarReservoir, &mut Sprite, Option<&mut Feeder>)>,
+    mut inventory: ResMut<PlayerInventory>,
+    difficulty: Res<DifficultySettings>,
+    mut log: ResMut<HummingbirdLog>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+) {
+    for (interaction, button, mut color) in &mut button_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *color = BackgroundColor(Color::srgb(0.5, 0.7, 0.5));
+                let Ok((mut reservoir, mut sprite, feeder)) = reservoir_query.get_mut(button.feeder) else { continue };
+
+                match button.action {
+                    NectarAction::Mix => {
+                        // Takes effect with the next clean and refill
+                        reservoir.water_parts = reservoir.next_mix();
+                    }
+                    NectarAction::Clean => {
+                        let cost = (CLEAN_COST * difficulty.seed_cost_multiplier()).round() as u32;
+                        if inventory.currency < cost {
+                            notification_events.write(ShowNotificationEvent {
+                                notification: NotificationType::Warning {
+                                    message: format!("Cleaning and refilling costs {} coins", cost),
+                                },
+                            });
+                            continue;
+                        }
+                        inventory.currency -= cost;
+                        reservoir.freshness = 1.0;
+                        log.spoil_warned = false;
+                        sprite.color = reservoir.fresh_color;
+                        if let Some(mut feeder) = feeder {
+                            feeder.current_capacity = feeder.max_capacity;
+                        }
+                    }
+                }
+                log.panel_dirty = true;
+            }
+            Interaction::Hovered => *color = BackgroundColor(Color::srgb(0.7, 0.6, 0.5)),
+            Interaction::None => *color = BackgroundColor(Color::srgb(0.6, 0.5, 0.4)),
+        }
+    }
+}
+
+fn refresh_nectar_panel_system(
+    mut commands: Commands,
+    mut log: ResMut<HummingbirdLog>,
+    reservoir_query: Query<(Entity, &NectarReservoir)>,
+    mut panel_query: Query<&mut Visibility, With<NectarPanel>>,
+    list_query: Query<Entity, With<NectarPanelList>>,
+    row_query: Query<Entity, With<NectarPanelRow>>,
+    difficulty: Res<DifficultySettings>,
+    time: Res<Time>,
+) {
+    log.refresh_timer.tick(time.delta());
+    if !log.panel_dirty && !log.refresh_timer.just_finished() {
+        return;
+    }
+    log.panel_dirty = false;
+
+    for mut visibility in &mut panel_query {
+        *visibility = if reservoir_query.is_empty() { Visibility::Hidden } else { Visibility::Inherited };
+    }
+    for row in &row_query {
+        commands.entity(row).despawn();
+    }
+    let Ok(list) = list_query.single() else { return };
+
+    let clean_cost = (CLEAN_COST * difficulty.seed_cost_multiplier()).round() as u32;
+    let mut reservoirs: Vec<_> = reservoir_query.iter().collect();
+    reservoirs.sort_by_key(|(entity, _)| *entity);
+
+    commands.entity(list).with_children(|list| {
+        for (index, (entity, reservoir)) in reservoirs.into_iter().enumerate() {
+            let (status, status_color) = if reservoir.is_spoiled() {
+                ("Spoiled".to_string(), Color::srgb(0.7, 0.3, 0.2))
+            } else {
+                (format!("Fresh {:.0}%", reservoir.freshness * 100.0), Color::srgb(0.4, 0.3, 0.2))
+            };
+
+            list.spawn((
+                Node {
+                    width: Val::Percent(100.0),
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(6.0),
+                    ..default()
+                },
+                NectarPanelRow,
+            )).with_children(|row| {
+                row.spawn((
+                    Text::new(format!("#{} 1:{}", index + 1, reservoir.water_parts)),
+                    TextFont { font_size: 11.0, ..default() },
+                    TextColor(Color::srgb(0.3, 0.2, 0.1)),
+                ));
+                row.spawn((
+                    Text::new(status),
+                    TextFont { font_size: 11.0, ..default() },
+                    TextColor(status_color),
+                    Node { flex_grow: 1.0, ..default() },
+                ));
+                for (label, action) in [("Mix".to_string(), NectarAction::Mix), (format!("Clean ({})", clean_cost), NectarAction::Clean)] {
+                    row.spawn((
+                        Button,
+                        Node {
+                            padding: UiRect::axes(Val::Px(6.0), Val::Px(2.0)),
+                            ..default()
+                        },
+                        BackgroundColor(Color::srgb(0.6, 0.5, 0.4)),
+                        BorderRadius::all(Val::Px(3.0)),
+                        NectarPanelButton { feeder: entity, action },
+                    )).with_children(|button| {
+                        button.spawn((
+                            Text::new(label),
+                            TextFont { font_size: 10.0, ..default() },
+                            TextColor(Color::WHITE),
+                        ));
+                    });
+                }
+            });
+        }
+    });
+}
@@ -44,6 +44,98 @@ pub struct ConservationStatusBadge;
 #[derive(Component)]
 pub struct MigrationMap;
 
+#[derive(Component)]
+pub struct PlayCallButton {
+    pub species: BirdSpecies,
+}
+
+#[derive(Component)]
+pub struct SpectrogramPanel;
+
+// The sonogram image, slid left as the recording plays
+#[derive(Component)]
+pub struct SpectrogramScroll;
+
+// One syllable of the song's mnemonic under the sonogram, lit as it's sung
+#[derive(Component)]
+pub struct MnemonicSyllable {
+    pub index: usize,
+}
+
+#[derive(Component)]
+pub struct SpectrogramCloseButton;
+
+#[derive(Component)]
+pub struct CalendarDayButton {
+    pub date: crate::journal::resources::ChecklistDate,
+}
+
+// Steps the checklist calendar back or forward by a month
+#[derive(Component)]
+pub struct CalendarMonthButton {
+    pub step: i32,
+}
+
+#[derive(Component)]
+pub struct NoteSearchField;
+
+#[derive(Component)]
+pub struct NoteEntryButton {
+    pub target: crate::journal::resources::NoteTarget,
+}
+
+#[derive(Component)]
+pub struct NoteTextField;
+
+#[derive(Component)]
+pub struct NoteTagField;
+
+// Searches for the tag, or takes it off the entry when shown in the note editor
+#[derive(Component)]
+pub struct NoteTagChip {
+    pub tag: String,
+    pub remove_from: Option<crate::journal::resources::NoteTarget>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PhotoFilterAction {
+    Species,
+    MinScore,
+    MaxScore,
+    Weather,
+    TimeOfDay,
+    Behavior,
+    Clear,
+    // Saves the current filter as a smart collection
+    Save,
+    Open(usize),
+    Remove(usize),
+}
+
+#[derive(Component)]
+pub struct PhotoFilterButton {
+    pub action: PhotoFilterAction,
+}
+
+// Opens the species or photo a timeline entry is about
+#[derive(Component)]
+pub struct TimelineLinkButton {
+    pub target: crate::journal::resources::NoteTarget,
+}
+
+// Current position of a population on its route map
+#[derive(Component)]
+pub struct MigrationMarker {
+    pub progress: f32,
+}
+
+// Loops ahead of a migrating population to show its direction of travel
+#[derive(Component)]
+pub struct MigrationTrail {
+    pub progress: f32,
+    pub heading: f32,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum JournalTab {
     Species,
@@ -52,6 +144,11 @@ pub enum JournalTab {
     Migration,
     Research,
     Achievements,
+    Wildlife,
+    Clips,
+    Checklists,
+    Notes,
+    Timeline,
 }
 
 #[derive(Component)]
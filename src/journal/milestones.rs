@@ -0,0 +1,261 @@
+// Milestones - The sanctuary's story, logged as it happens for the journal timeline
+//
+// First sightings, achievements, rare visitors, irruptions, severe storms the yard came through
+// and catalog purchases are each recorded with the date and hour they happened. Sightings and
+// achievements are picked up here; irruptions, storms and purchases are reported by the modules
+// they happen in with a MilestoneEvent. The Timeline tab lists the log newest first,
+// with entries about a species linking to its journal page and its best photo.
+use bevy::prelude::*;
+use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
+use serde::{Deserialize, Serialize};
+use crate::achievements::AchievementUnlockedEvent;
+use crate::bird::BirdSpecies;
+use crate::environment::resources::TimeState;
+use crate::journal::components::{JournalTab, JournalTabContent, TimelineLinkButton};
+use crate::journal::resources::{BirdEducationData, ChecklistDate, JournalState, NoteTarget, SightingLog};
+use crate::journal::systems::{format_game_hour, species_label};
+use crate::photo_mode::resources::PhotoCollection;
+
+const RARE_TIER: u8 = 3;
+const SCROLL_LINE_PX: f32 = 24.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MilestoneKind {
+    FirstSighting,
+    Achievement,
+    RareVisitor,
+    Irruption,
+    Storm,
+    Purchase,
+}
+
+impl MilestoneKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::FirstSighting => "First sighting",
+            Self::Achievement => "Achievement",
+            Self::RareVisitor => "Rare visitor",
+            Self::Irruption => "Irruption",
+            Self::Storm => "Storm",
+            Self::Purchase => "Purchase",
+        }
+    }
+
+    fn color(&self) -> Color {
+        match self {
+            Self::FirstSighting => Color::srgb(0.2, 0.5, 0.2),
+            Self::Achievement => Color::srgb(0.7, 0.55, 0.1),
+            Self::RareVisitor => Color::srgb(0.5, 0.25, 0.6),
+            Self::Irruption => Color::srgb(0.2, 0.4, 0.6),
+            Self::Storm => Color::srgb(0.35, 0.35, 0.45),
+            Self::Purchase => Color::srgb(0.6, 0.4, 0.2),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Milestone {
+    pub date: ChecklistDate,
+    pub hour: f32,
+    pub kind: MilestoneKind,
+    pub title: String,
+    pub species: Option<BirdSpecies>,
+}
+
+// Resources
+#[derive(Resource, Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MilestoneLog {
+    // Oldest first
+    pub entries: Vec<Milestone>,
+}
+
+impl MilestoneLog {
+    pub fn record(&mut self, time_state: &TimeState, kind: MilestoneKind, title: String, species: Option<BirdSpecies>) {
+        let date = ChecklistDate { year: time_state.year, day_of_year: time_state.day_of_year };
+        info!("📜 Milestone: {} - {}", kind.label(), title);
+        self.entries.push(Milestone { date, hour: time_state.hour, kind, title, species });
+    }
+
+    fn logged_today(&self, date: ChecklistDate, species: BirdSpecies) -> bool {
+        self.entries.iter().rev()
+            .take_while(|entry| entry.date == date)
+            .any(|entry| entry.species == Some(species) && matches!(entry.kind, MilestoneKind::FirstSighting | MilestoneKind::RareVisitor))
+    }
+}
+
+// Events
+#[derive(Event)]
+pub struct MilestoneEvent {
+    pub kind: MilestoneKind,
+    pub title: String,
+    pub species: Option<BirdSpecies>,
+}
+
+// Species on today's checklist are first sightings if no earlier checklist has them, and rare
+// visitors once a day otherwise
+pub fn sighting_milestone_system(
+    sighting_log: Res<SightingLog>,
+    time_state: Res<TimeState>,
+    education_data: Res<BirdEducationData>,
+    mut milestones: ResMut<MilestoneLog>,
+) {
+    if !sighting_log.is_changed() {
+        return;
+    }
+    let today = ChecklistDate { year: time_state.year, day_of_year: time_state.day_of_year };
+    let Some(checklist) = sighting_log.checklist(today) else { return };
+
+    let mut species: Vec<BirdSpecies> = checklist.species.keys()
+        .filter(|species| !milestones.logged_today(today, **species))
+        .copied()
+        .collect();
+    species.sort_by_key(|species| format!("{:?}", species));
+
+    for species in species {
+        let name = species_label(species, &education_data);
+        let seen_before = sighting_log.checklists.range(..today).any(|(_, checklist)| checklist.species.contains_key(&species));
+        if !seen_before {
+            milestones.record(&time_state, MilestoneKind::FirstSighting, format!("First {} seen in the sanctuary", name), Some(species));
+        } else if species.rarity_tier() >= RARE_TIER {
+            milestones.record(&time_state, MilestoneKind::RareVisitor, format!("A {} stopped by", name), Some(species));
+        }
+    }
+}
+
+// Achievements, plus whatever other modules report
+pub fn event_milestone_system(
+    mut milestone_events: EventReader<MilestoneEvent>,
+    mut achievement_events: EventReader<AchievementUnlockedEvent>,
+    time_state: Res<TimeState>,
+    mut milestones: ResMut<MilestoneLog>,
+) {
+    for event in milestone_events.read() {
+        milestones.record(&time_state, event.kind, event.title.clone(), event.species);
+    }
+    for event in achievement_events.read() {
+        milestones.record(&time_state, MilestoneKind::Achievement,
+            format!("{} - {}", event.achievement.name(), event.achievement.description()), None);
+    }
+}
+
+// Entries link to the species' or photo's page on the Notes tab
+pub fn timeline_link_system(
+    interaction_query: Query<(&Interaction, &TimelineLinkButton), Changed<Interaction>>,
+    mut journal_state: ResMut<JournalState>,
+) {
+    for (interaction, link) in &interaction_query {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        journal_state.current_tab = JournalTab::Notes;
+        journal_state.note_target = Some(link.target);
+        journal_state.note_search.clear();
+    }
+}
+
+// The journal's content area clips at its height; the wheel moves it
+pub fn journal_scroll_system(
+    mut wheel_events: EventReader<MouseWheel>,
+    mut content_query: Query<&mut ScrollPosition, With<JournalTabContent>>,
+) {
+    let delta: f32 = wheel_events.read().map(|event| match event.unit {
+        MouseScrollUnit::Line => event.y * SCROLL_LINE_PX,
+        MouseScrollUnit::Pixel => event.y,
+    }).sum();
+    if delta == 0.0 {
+        return;
+    }
+    for mut scroll in &mut content_query {
+        // Layout clamps the far end to the content's height
+        scroll.offset_y = (scroll.offset_y - delta).max(0.0);
+    }
+}
+
+fn spawn_timeline_link(parent: &mut ChildSpawnerCommands, label: String, target: NoteTarget) {
+    parent.spawn((
+        Button,
+        Node {
+            padding: UiRect::axes(Val::Px(6.0), Val::Px(2.0)),
+            ..default()
+        },
+        BackgroundColor(Color::srgb(0.6, 0.5, 0.4)),
+        BorderRadius::all(Val::Px(4.0)),
+        TimelineLinkButton { target },
+    )).with_children(|button| {
+        button.spawn((
+            Text::new(label),
+            TextFont { font_size: 11.0, ..default() },
+            TextColor(Color::WHITE),
+        ));
+    });
+}
+
+pub fn spawn_milestone_timeline(
+    parent: &mut ChildSpawnerCommands,
+    milestones: &MilestoneLog,
+    photo_collection: &PhotoCollection,
+    education_data: &BirdEducationData,
+) {
+    if milestones.entries.is_empty() {
+        parent.spawn((
+            Text::new("Nothing recorded yet. First sightings, achievements, rare visitors, storms and purchases will appear here."),
+            TextFont { font_size: 12.0, ..default() },
+            TextColor(Color::srgb(0.5, 0.4, 0.3)),
+        ));
+        return;
+    }
+
+    let mut shown_date = None;
+    for entry in milestones.entries.iter().rev() {
+        if shown_date != Some(entry.date) {
+            shown_date = Some(entry.date);
+            parent.spawn((
+                Text::new(entry.date.label()),
+                TextFont { font_size: 14.0, ..default() },
+                TextColor(Color::srgb(0.3, 0.2, 0.1)),
+                Node { margin: UiRect::top(Val::Px(8.0)), ..default() },
+            ));
+        }
+
+        parent.spawn((
+            Node {
+                width: Val::Percent(100.0),
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(8.0),
+                padding: UiRect::axes(Val::Px(8.0), Val::Px(4.0)),
+                border: UiRect::left(Val::Px(3.0)),
+                ..default()
+            },
+            BorderColor(entry.kind.color()),
+            BackgroundColor(Color::srgba(0.9, 0.9, 0.9, 0.5)),
+        )).with_children(|row| {
+            row.spawn((
+                Text::new(format_game_hour(entry.hour)),
+                TextFont { font_size: 11.0, ..default() },
+                TextColor(Color::srgb(0.5, 0.4, 0.3)),
+            ));
+            row.spawn((
+                Text::new(entry.kind.label()),
+                TextFont { font_size: 11.0, ..default() },
+                TextColor(entry.kind.color()),
+                Node { width: Val::Px(90.0), ..default() },
+            ));
+            row.spawn((
+                Text::new(entry.title.clone()),
+                TextFont { font_size: 12.0, ..default() },
+                TextColor(Color::srgb(0.3, 0.2, 0.1)),
+                Node { flex_grow: 1.0, ..default() },
+            ));
+
+            let Some(species) = entry.species else { return };
+            spawn_timeline_link(row, species_label(species, education_data), NoteTarget::Species(species));
+            let best_photo = photo_collection.photos.iter()
+                .filter(|photo| photo.species == Some(species))
+                .max_by_key(|photo| photo.score.total_score);
+            if let Some(photo) = best_photo {
+                spawn_timeline_link(row, "Best photo".to_string(), NoteTarget::Photo(photo.id));
+            }
+        });
+    }
+}
@@ -1,6 +1,8 @@
 use bevy::prelude::*;
 
 pub mod components;
+pub mod milestones;
+pub mod research;
 pub mod resources;
 pub mod systems;
 pub mod ui_builder;
@@ -17,17 +19,47 @@ impl Plugin for JournalPlugin {
             .init_resource::<JournalState>()
             .init_resource::<BirdEducationData>()
             .init_resource::<ResearchMissionManager>()
+            .init_resource::<SpectrogramView>()
+            .init_resource::<SightingLog>()
+            .init_resource::<FieldNotes>()
+            .init_resource::<milestones::MilestoneLog>()
+            .add_event::<milestones::MilestoneEvent>()
             .add_systems(Startup, (load_education_data, setup_research_missions))
-            .add_systems(Update, toggle_journal_system.run_if(crate::debug_console::console_is_not_visible))
+            .add_systems(Update, toggle_journal_system.in_set(crate::GameSet::Input).run_if(crate::debug_console::console_is_not_visible))
+            .add_systems(Update, (
+                (sighting_log_system, milestones::sighting_milestone_system).chain(),
+                milestones::event_milestone_system,
+                research::log_research_observation_system,
+            ).in_set(crate::GameSet::Simulation).run_if(in_state(crate::AppState::Playing)))
+            .add_systems(Update, (
+                provide_journal_search_entries.in_set(crate::search_palette::SearchProviders),
+                journal_search_jump_system,
+            ).in_set(crate::GameSet::UI))
             .add_systems(OnEnter(crate::AppState::Journal), setup_journal_menu_system) // Re-enabled - using new journal implementation
-            .add_systems(OnExit(crate::AppState::Journal), teardown_journal_menu_system) // Re-enabled - using new journal implementation
+            .add_systems(OnExit(crate::AppState::Journal), (teardown_journal_menu_system, stop_species_call_system)) // Re-enabled - using new journal implementation
+            // Clicks and typing land before the monitor regenerates content, so tab switches show the same frame
+            .add_systems(Update, (
+                journal_interaction_system, // Re-enabled - using new journal implementation
+                journal_tab_system, // Re-enabled - using new journal implementation
+                journal_species_detail_system, // Re-enabled - using new journal implementation
+                journal_calendar_system,
+                (journal_notes_button_system, journal_notes_input_system).chain(),
+                milestones::timeline_link_system,
+                journal_photo_filter_system,
+                milestones::journal_scroll_system,
+            ).in_set(crate::GameSet::Input).run_if(in_state(crate::AppState::Journal).and(crate::debug_console::console_is_not_visible)))
             .add_systems(Update, (
                 update_journal_on_discovery_system,
-                journal_interaction_system.run_if(crate::debug_console::console_is_not_visible), // Re-enabled - using new journal implementation
-                journal_tab_system.run_if(crate::debug_console::console_is_not_visible), // Re-enabled - using new journal implementation  
-                journal_species_detail_system.run_if(crate::debug_console::console_is_not_visible), // Re-enabled - using new journal implementation
                 journal_state_monitor_system, // Monitor for state changes and update content
-            ).run_if(in_state(crate::AppState::Journal)));
+                animate_migration_markers_system,
+                (
+                    play_species_call_system,
+                    build_spectrogram_panel_system,
+                    scroll_spectrogram_system,
+                    highlight_mnemonic_system,
+                    spectrogram_close_system,
+                ).chain(),
+            ).in_set(crate::GameSet::UI).run_if(in_state(crate::AppState::Journal)));
     }
 }
 
@@ -0,0 +1,260 @@
+// Research - Field observations logged from photos, mission progress, and result plots
+//
+// Every photo adds an observation to the research log: when it was taken, the temperature, and
+// what each bird in frame was doing. Mission objectives are counted from that log, and once a
+// mission is complete its findings are plotted from the same observations, so the result is an
+// analysis of the player's own data rather than a canned figure.
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use crate::bird::{Bird, BirdSpecies};
+use crate::bird_ai::components::BirdState;
+use crate::catalog::resources::PlayerInventory;
+use crate::environment::resources::{TimeState, WeatherState};
+use crate::journal::resources::{MissionType, ObjectiveType, ResearchMission, ResearchMissionManager};
+use crate::notifications::{resources::ShowNotificationEvent, components::NotificationType};
+use crate::photo_mode::components::{PhotoTakenEvent, PhotoTarget};
+
+// Same "in frame" distance the other photo-driven research data uses
+const FRAME_RADIUS: f32 = 200.0;
+// Fewer photos than this and the plot is shown without a trend
+const MIN_TREND_POINTS: usize = 5;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ObservedBird {
+    pub species: BirdSpecies,
+    // Debug name of the bird's state when the photo was taken
+    pub behavior: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResearchObservation {
+    pub day: u32,
+    pub hour: f32,
+    pub temperature: f32,
+    pub score: u32,
+    pub birds: Vec<ObservedBird>,
+}
+
+impl ResearchObservation {
+    pub fn count(&self, species: BirdSpecies) -> usize {
+        self.birds.iter().filter(|bird| bird.species == species).count()
+    }
+
+    pub fn feeding(&self) -> usize {
+        self.birds.iter().filter(|bird| behavior_matches("Feeding", &bird.behavior)).count()
+    }
+
+    // Game hour since the sanctuary began, for counting distinct hours of coverage
+    fn absolute_hour(&self) -> u32 {
+        self.day * 24 + self.hour as u32
+    }
+}
+
+// Mission behaviors are written for people ("Singing"), bird states for the AI ("Territorial")
+pub fn behavior_matches(behavior: &str, state: &str) -> bool {
+    match behavior {
+        "Feeding" => matches!(state, "Eating" | "Foraging" | "HoverFeeding" | "Caching" | "Retrieving"),
+        "Singing" => matches!(state, "Territorial" | "Courting"),
+        _ => behavior.eq_ignore_ascii_case(state),
+    }
+}
+
+impl ResearchMission {
+    /// Recounts each objective from the observations logged since the mission started
+    /// and returns whether every objective is now met
+    pub fn update_progress(&mut self, observations: &[ResearchObservation]) -> bool {
+        let logged: Vec<&ResearchObservation> = observations.iter()
+            .filter(|observation| observation.day >= self.progress.start_day)
+            .collect();
+        let fraction = |done: usize, needed: f32| (done as f32 / needed.max(1.0)).min(1.0);
+
+        for objective in &mut self.objectives {
+            objective.progress = match &objective.objective_type {
+                ObjectiveType::CollectPhotos { species, min_score, count } => fraction(
+                    logged.iter().filter(|o| o.score >= *min_score && o.count(*species) > 0).count(),
+                    *count as f32,
+                ),
+                ObjectiveType::ObserveBehavior { species, behavior, count } => fraction(
+                    logged.iter()
+                        .filter(|o| o.birds.iter().any(|bird| bird.species == *species && behavior_matches(behavior, &bird.behavior)))
+                        .count(),
+                    *count as f32,
+                ),
+                ObjectiveType::DocumentInteraction { species_a, species_b, count } => fraction(
+                    logged.iter().filter(|o| o.count(*species_a) > 0 && o.count(*species_b) > 0).count(),
+                    *count as f32,
+                ),
+                ObjectiveType::TrackMovement { species, duration_hours } => fraction(
+                    logged.iter()
+                        .filter(|o| o.count(*species) > 0)
+                        .map(|o| o.absolute_hour())
+                        .collect::<HashSet<_>>()
+                        .len(),
+                    *duration_hours,
+                ),
+                ObjectiveType::AnalyzeFeeding { species_count, duration_hours, .. } => {
+                    let feeding: Vec<&&ResearchObservation> = logged.iter().filter(|o| o.feeding() > 0).collect();
+                    let species: HashSet<BirdSpecies> = feeding.iter()
+                        .flat_map(|o| o.birds.iter().filter(|bird| behavior_matches("Feeding", &bird.behavior)))
+                        .map(|bird| bird.species)
+                        .collect();
+                    let hours: HashSet<u32> = feeding.iter().map(|o| o.absolute_hour()).collect();
+                    fraction(species.len(), *species_count as f32).min(fraction(hours.len(), *duration_hours))
+                }
+            };
+            objective.completed = objective.progress >= 1.0;
+        }
+
+        self.progress.completion_percentage = self.objectives.iter().map(|objective| objective.progress).sum::<f32>()
+            / self.objectives.len().max(1) as f32;
+        self.objectives.iter().all(|objective| objective.completed)
+    }
+
+    /// What the mission measured, plotted from the player's observations
+    pub fn result_plot(&self, observations: &[ResearchObservation]) -> ResultPlot {
+        let logged = observations.iter().filter(|observation| observation.day >= self.progress.start_day);
+        match &self.mission_type {
+            MissionType::FeedingEcology { .. } => ResultPlot {
+                title: "Feeding rate vs temperature".to_string(),
+                x_label: "°C".to_string(),
+                y_label: "feeding".to_string(),
+                points: logged.map(|o| Vec2::new(o.temperature, o.feeding() as f32)).collect(),
+                subject: "Birds feeding per photo".to_string(),
+                factor: "temperature".to_string(),
+            },
+            MissionType::BehaviorStudy { target_species, .. } => species_by_hour(*target_species, logged),
+            MissionType::ConservationStudy { threatened_species, .. } => species_by_hour(*threatened_species, logged),
+            MissionType::MigrationTracking { species_group, .. } => ResultPlot {
+                title: "Sightings through the season".to_string(),
+                x_label: "day".to_string(),
+                y_label: "birds".to_string(),
+                points: logged
+                    .map(|o| Vec2::new(o.day as f32, species_group.iter().map(|species| o.count(*species)).sum::<usize>() as f32))
+                    .collect(),
+                subject: "Tracked birds per photo".to_string(),
+                factor: "the date".to_string(),
+            },
+            MissionType::PopulationCount { .. } => ResultPlot {
+                title: "Birds counted per photo".to_string(),
+                x_label: "day".to_string(),
+                y_label: "birds".to_string(),
+                points: logged.map(|o| Vec2::new(o.day as f32, o.birds.len() as f32)).collect(),
+                subject: "Birds per photo".to_string(),
+                factor: "the date".to_string(),
+            },
+        }
+    }
+}
+
+fn species_by_hour<'a>(species: BirdSpecies, logged: impl Iterator<Item = &'a ResearchObservation>) -> ResultPlot {
+    ResultPlot {
+        title: format!("{:?} activity by time of day", species),
+        x_label: "hour".to_string(),
+        y_label: "birds".to_string(),
+        points: logged.map(|o| Vec2::new(o.hour, o.count(species) as f32)).collect(),
+        subject: format!("{:?} per photo", species),
+        factor: "the hour".to_string(),
+    }
+}
+
+pub struct ResultPlot {
+    pub title: String,
+    pub x_label: String,
+    pub y_label: String,
+    pub points: Vec<Vec2>,
+    // How the finding is phrased, "<subject> rose with <factor>"
+    subject: String,
+    factor: String,
+}
+
+impl ResultPlot {
+    // Pearson correlation of the points, None when there is too little data or no spread
+    pub fn correlation(&self) -> Option<f32> {
+        if self.points.len() < MIN_TREND_POINTS {
+            return None;
+        }
+        let n = self.points.len() as f32;
+        let mean = self.points.iter().copied().sum::<Vec2>() / n;
+        let (mut covariance, mut variance_x, mut variance_y) = (0.0, 0.0, 0.0);
+        for point in &self.points {
+            let d = *point - mean;
+            covariance += d.x * d.y;
+            variance_x += d.x * d.x;
+            variance_y += d.y * d.y;
+        }
+        let denominator = (variance_x * variance_y).sqrt();
+        (denominator > f32::EPSILON).then(|| covariance / denominator)
+    }
+
+    pub fn finding(&self) -> String {
+        match self.correlation() {
+            None => format!("Only {} photos logged - too few to call a trend", self.points.len()),
+            Some(r) if r.abs() < 0.2 => format!("No clear link with {} (r = {:.2}, {} photos)", self.factor, r, self.points.len()),
+            Some(r) => format!(
+                "{} {} with {} (r = {:.2}, {} photos)",
+                self.subject,
+                if r > 0.0 { "rose" } else { "fell" },
+                self.factor,
+                r,
+                self.points.len(),
+            ),
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn log_research_observation_system(
+    mut photo_events: EventReader<PhotoTakenEvent>,
+    mut research: ResMut<ResearchMissionManager>,
+    mut inventory: ResMut<PlayerInventory>,
+    camera_query: Query<&Transform, With<PhotoTarget>>,
+    bird_query: Query<(&Transform, &Bird, &BirdState)>,
+    time_state: Res<TimeState>,
+    weather_state: Res<WeatherState>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+) {
+    let mut logged = false;
+    for event in photo_events.read() {
+        let Ok(camera_transform) = camera_query.single() else { continue };
+        let camera_pos = camera_transform.translation.truncate();
+
+        let birds = bird_query.iter()
+            .filter(|(transform, ..)| transform.translation.truncate().distance(camera_pos) <= FRAME_RADIUS)
+            .map(|(_, bird, state)| ObservedBird { species: bird.species, behavior: format!("{:?}", state) })
+            .collect();
+        research.observations.push(ResearchObservation {
+            day: time_state.absolute_day(),
+            hour: time_state.hour,
+            temperature: weather_state.temperature,
+            score: event.score.total_score,
+            birds,
+        });
+        logged = true;
+    }
+    if !logged {
+        return;
+    }
+
+    let research = research.as_mut();
+    let mut index = 0;
+    while index < research.active_missions.len() {
+        if !research.active_missions[index].update_progress(&research.observations) {
+            index += 1;
+            continue;
+        }
+
+        let mission = research.active_missions.remove(index);
+        research.research_points += mission.rewards.research_points;
+        inventory.currency += mission.rewards.currency;
+        notification_events.write(ShowNotificationEvent {
+            notification: NotificationType::Achievement {
+                title: format!("Research complete: {}", mission.title),
+                description: "Your results are plotted in the journal's Research tab".to_string(),
+                currency_reward: mission.rewards.currency,
+            },
+        });
+        info!("🔬 Completed research mission {}", mission.title);
+        research.completed_missions.push(mission);
+    }
+}
@@ -1,7 +1,8 @@
 use bevy::prelude::*;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use crate::bird::BirdSpecies;
 use crate::journal::components::JournalTab;
+use crate::photo_mode::resources::{PhotoCollection, SavedPhoto};
 use serde::{Serialize, Deserialize};
 
 #[derive(Resource, Default)]
@@ -12,6 +13,16 @@ pub struct JournalState {
     pub is_open: bool,
     pub current_tab: JournalTab,
     pub selected_species: Option<BirdSpecies>,
+    // Year and month index shown on the checklist calendar; None follows the current date
+    pub calendar_month: Option<(u32, usize)>,
+    pub selected_checklist: Option<ChecklistDate>,
+    pub note_search: String,
+    // Species or photo whose notes are open for editing on the Notes tab
+    pub note_target: Option<NoteTarget>,
+    pub note_input: Option<NoteInput>,
+    pub tag_draft: String,
+    // Narrows the photo gallery; not saved, smart collections are
+    pub photo_filter: crate::photo_mode::filters::PhotoFilter,
 }
 
 impl Default for JournalState {
@@ -20,10 +31,31 @@ impl Default for JournalState {
             is_open: false,
             current_tab: JournalTab::Species,
             selected_species: None,
+            calendar_month: None,
+            selected_checklist: None,
+            note_search: String::new(),
+            note_target: None,
+            note_input: None,
+            tag_draft: String::new(),
+            photo_filter: default(),
         }
     }
 }
 
+// Species call playing from the journal, shown alongside its sonogram
+#[derive(Resource, Default)]
+pub struct SpectrogramView {
+    pub species: Option<BirdSpecies>,
+    pub recording: Handle<bevy::audio::AudioSource>,
+    pub image: Option<Handle<Image>>,
+    pub duration: f32,
+    pub max_frequency: f32,
+    pub elapsed: f32,
+    pub player: Option<Entity>,
+    // When each syllable of the song's mnemonic is sung, lined up with the notes in the sonogram
+    pub syllable_times: Vec<f32>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BirdFacts {
     pub common_name: String,
@@ -83,6 +115,292 @@ pub struct MigrationData {
     pub interesting_fact: String,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MigrationPhase {
+    Wintering,
+    Northbound,
+    Breeding,
+    Southbound,
+}
+
+impl MigrationPhase {
+    pub fn label(&self) -> &'static str {
+        match self {
+            MigrationPhase::Wintering => "On wintering grounds",
+            MigrationPhase::Northbound => "Heading north",
+            MigrationPhase::Breeding => "On breeding grounds",
+            MigrationPhase::Southbound => "Heading south",
+        }
+    }
+}
+
+// Fallback windows when the timing text can't be read
+const DEFAULT_SPRING_WINDOW: (u32, u32) = (60, 151);
+const DEFAULT_FALL_WINDOW: (u32, u32) = (213, 304);
+pub const MONTH_STARTS: [u32; 12] = [1, 32, 60, 91, 121, 152, 182, 213, 244, 274, 305, 335];
+pub const MONTH_NAMES: [&str; 12] = [
+    "January", "February", "March", "April", "May", "June",
+    "July", "August", "September", "October", "November", "December",
+];
+
+impl MigrationData {
+    // Spring and fall passage as inclusive day-of-year ranges, read from
+    // timing text like "Spring: March-May, Fall: July-October"
+    pub fn seasonal_windows(&self) -> ((u32, u32), (u32, u32)) {
+        let mut spring = DEFAULT_SPRING_WINDOW;
+        let mut fall = DEFAULT_FALL_WINDOW;
+
+        for part in self.migration_timing.split(',') {
+            let Some((season, months)) = part.split_once(':') else { continue };
+            let Some((first, last)) = months.split_once('-') else { continue };
+            let (Some(first), Some(last)) = (month_index(first), month_index(last)) else { continue };
+
+            let window = (MONTH_STARTS[first], MONTH_STARTS.get(last + 1).map_or(365, |start| start - 1));
+            match season.trim() {
+                "Spring" => spring = window,
+                "Fall" => fall = window,
+                _ => {}
+            }
+        }
+
+        (spring, fall)
+    }
+
+    // Where the population sits on its route for the given day, from 0.0 on the
+    // wintering grounds to 1.0 on the breeding grounds
+    pub fn route_position(&self, day_of_year: u32) -> (f32, MigrationPhase) {
+        if !self.is_migratory {
+            return (1.0, MigrationPhase::Breeding);
+        }
+
+        let ((spring_start, spring_end), (fall_start, fall_end)) = self.seasonal_windows();
+        let progress = |start: u32, end: u32| {
+            (day_of_year - start) as f32 / (end - start).max(1) as f32
+        };
+
+        if (spring_start..=spring_end).contains(&day_of_year) {
+            (progress(spring_start, spring_end), MigrationPhase::Northbound)
+        } else if day_of_year > spring_end && day_of_year < fall_start {
+            (1.0, MigrationPhase::Breeding)
+        } else if (fall_start..=fall_end).contains(&day_of_year) {
+            (1.0 - progress(fall_start, fall_end), MigrationPhase::Southbound)
+        } else {
+            (0.0, MigrationPhase::Wintering)
+        }
+    }
+}
+
+fn month_index(name: &str) -> Option<usize> {
+    MONTH_NAMES.iter().position(|month| *month == name.trim())
+}
+
+pub fn days_in_month(month: usize) -> u32 {
+    MONTH_STARTS.get(month + 1).map_or(366, |next| *next) - MONTH_STARTS[month]
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct ChecklistDate {
+    pub year: u32,
+    pub day_of_year: u32,
+}
+
+impl ChecklistDate {
+    pub fn month(&self) -> usize {
+        MONTH_STARTS.iter().rposition(|start| *start <= self.day_of_year).unwrap_or(0)
+    }
+
+    pub fn day_of_month(&self) -> u32 {
+        self.day_of_year - MONTH_STARTS[self.month()] + 1
+    }
+
+    pub fn label(&self) -> String {
+        format!("{} {}, Year {}", MONTH_NAMES[self.month()], self.day_of_month(), self.year)
+    }
+}
+
+// One species on a day's checklist; times are in-game hours
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpeciesTally {
+    pub first_seen: f32,
+    pub last_seen: f32,
+    pub max_count: u32,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DailyChecklist {
+    pub species: HashMap<BirdSpecies, SpeciesTally>,
+}
+
+impl DailyChecklist {
+    // Species ordered by when they were first seen that day
+    pub fn sorted(&self) -> Vec<(BirdSpecies, &SpeciesTally)> {
+        let mut entries: Vec<_> = self.species.iter().map(|(species, tally)| (*species, tally)).collect();
+        entries.sort_by(|a, b| a.1.first_seen.total_cmp(&b.1.first_seen));
+        entries
+    }
+}
+
+// Every species detected in the sanctuary, one checklist per in-game day
+#[derive(Resource, Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SightingLog {
+    pub checklists: BTreeMap<ChecklistDate, DailyChecklist>,
+}
+
+impl SightingLog {
+    pub fn record(&mut self, date: ChecklistDate, hour: f32, species: BirdSpecies, count: u32) {
+        let tally = self.checklists.entry(date).or_default().species.entry(species)
+            .or_insert(SpeciesTally { first_seen: hour, last_seen: hour, max_count: 0 });
+        tally.last_seen = hour;
+        tally.max_count = tally.max_count.max(count);
+    }
+
+    pub fn checklist(&self, date: ChecklistDate) -> Option<&DailyChecklist> {
+        self.checklists.get(&date)
+    }
+}
+
+pub const MAX_NOTE_LENGTH: usize = 280;
+pub const MAX_TAG_LENGTH: usize = 24;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NoteTarget {
+    Species(BirdSpecies),
+    Photo(u32),
+}
+
+// Which text field on the Notes tab is taking keyboard input
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoteInput {
+    Search,
+    Text,
+    Tag,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EntryNotes {
+    pub text: String,
+    pub tags: Vec<String>,
+}
+
+impl EntryNotes {
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty() && self.tags.is_empty()
+    }
+
+    // Tags are stored lowercase without the leading # and never repeat
+    pub fn add_tag(&mut self, tag: &str) -> bool {
+        let tag = tag.trim().trim_start_matches('#').to_lowercase();
+        if tag.is_empty() || self.tags.contains(&tag) {
+            return false;
+        }
+        self.tags.push(tag);
+        true
+    }
+
+    pub fn remove_tag(&mut self, tag: &str) {
+        self.tags.retain(|existing| existing != tag);
+    }
+
+    // Every word of the query has to hit; "#word" only matches tags, anything else
+    // also matches the entry's name or note text
+    pub fn matches(&self, label: &str, query: &str) -> bool {
+        let label = label.to_lowercase();
+        let text = self.text.to_lowercase();
+        query.split_whitespace().all(|term| {
+            let term = term.to_lowercase();
+            match term.strip_prefix('#') {
+                Some(tag) => self.tags.iter().any(|existing| existing.starts_with(tag)),
+                None => label.contains(&term) || text.contains(&term)
+                    || self.tags.iter().any(|existing| existing.contains(&term)),
+            }
+        })
+    }
+}
+
+// Notes on a photo remember when it was taken, as photo ids start over each session
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PhotoNotes {
+    pub photo_id: u32,
+    pub taken_at: f64,
+    pub species: Option<BirdSpecies>,
+    pub notes: EntryNotes,
+}
+
+// The player's own notes and tags on species entries and photos
+#[derive(Resource, Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FieldNotes {
+    pub species: HashMap<BirdSpecies, EntryNotes>,
+    pub photos: Vec<PhotoNotes>,
+}
+
+impl FieldNotes {
+    pub fn species_notes(&self, species: BirdSpecies) -> Option<&EntryNotes> {
+        self.species.get(&species)
+    }
+
+    pub fn photo_notes(&self, photo: &SavedPhoto) -> Option<&EntryNotes> {
+        self.photos.iter()
+            .find(|entry| entry.photo_id == photo.id && entry.taken_at == photo.timestamp)
+            .map(|entry| &entry.notes)
+    }
+
+    pub fn notes(&self, target: NoteTarget, photos: &PhotoCollection) -> Option<&EntryNotes> {
+        match target {
+            NoteTarget::Species(species) => self.species_notes(species),
+            NoteTarget::Photo(id) => photos.get_photo(id).and_then(|photo| self.photo_notes(photo)),
+        }
+    }
+
+    pub fn notes_mut(&mut self, target: NoteTarget, photos: &PhotoCollection) -> Option<&mut EntryNotes> {
+        match target {
+            NoteTarget::Species(species) => Some(self.species.entry(species).or_default()),
+            NoteTarget::Photo(id) => {
+                let photo = photos.get_photo(id)?;
+                let index = match self.photos.iter().position(|entry| entry.photo_id == id && entry.taken_at == photo.timestamp) {
+                    Some(index) => index,
+                    None => {
+                        self.photos.push(PhotoNotes {
+                            photo_id: id,
+                            taken_at: photo.timestamp,
+                            species: photo.species,
+                            notes: EntryNotes::default(),
+                        });
+                        self.photos.len() - 1
+                    }
+                };
+                Some(&mut self.photos[index].notes)
+            }
+        }
+    }
+
+    // Drops entries that were opened for editing but never written in
+    pub fn prune(&mut self) {
+        self.species.retain(|_, notes| !notes.is_empty());
+        self.photos.retain(|entry| !entry.notes.is_empty());
+    }
+
+    // Every tag in use, for the tag list under the search bar
+    pub fn all_tags(&self) -> Vec<String> {
+        let mut tags: Vec<String> = self.species.values()
+            .chain(self.photos.iter().map(|entry| &entry.notes))
+            .flat_map(|notes| notes.tags.iter().cloned())
+            .collect();
+        tags.sort();
+        tags.dedup();
+        tags
+    }
+
+    // One line of notes and tags for captions and exports
+    pub fn summary(notes: &EntryNotes) -> String {
+        let tags = notes.tags.iter().map(|tag| format!("#{}", tag)).collect::<Vec<_>>().join(" ");
+        match (notes.text.is_empty(), tags.is_empty()) {
+            (false, false) => format!("{} {}", notes.text, tags),
+            (false, true) => notes.text.clone(),
+            _ => tags,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EducationDataConfig {
     pub species: Vec<BirdEducationEntry>,
@@ -117,7 +435,7 @@ impl BirdEducationData {
             if let Err(e) = self.load_education_file(file_path) {
                 error!("Failed to load education data from {}: {}", file_path, e);
             } else {
-                info!("Successfully loaded education data from {}", file_path);
+                debug!("Loaded education data from {}", file_path);
                 self.loaded_files.push(file_path.to_string());
             }
         }
@@ -148,6 +466,8 @@ pub struct ResearchMissionManager {
     pub completed_missions: Vec<ResearchMission>,
     pub collected_data: HashMap<DataType, u32>,
     pub research_points: u32,
+    // One entry per photo taken; objectives are counted and results plotted from these
+    pub observations: Vec<crate::journal::research::ResearchObservation>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -250,6 +570,9 @@ pub struct ResearchRewards {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MissionProgress {
     pub started_date: String,
+    // Absolute game day the mission was taken on; only observations from then on count
+    #[serde(default)]
+    pub start_day: u32,
     pub days_active: u32,
     pub completion_percentage: f32,
     pub data_quality_score: f32,
@@ -313,6 +636,7 @@ impl ResearchMissionManager {
                 },
                 progress: MissionProgress {
                     started_date: "2025-01-01".to_string(),
+                    start_day: 0,
                     days_active: 0,
                     completion_percentage: 0.0,
                     data_quality_score: 0.0,
@@ -362,6 +686,7 @@ impl ResearchMissionManager {
                 },
                 progress: MissionProgress {
                     started_date: "2025-01-01".to_string(),
+                    start_day: 0,
                     days_active: 0,
                     completion_percentage: 0.0,
                     data_quality_score: 0.0,
@@ -402,6 +727,7 @@ impl ResearchMissionManager {
                 },
                 progress: MissionProgress {
                     started_date: "2025-01-01".to_string(),
+                    start_day: 0,
                     days_active: 0,
                     completion_percentage: 0.0,
                     data_quality_score: 0.0,
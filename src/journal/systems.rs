@@ -1,9 +1,36 @@
 use bevy::prelude::*;
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input::ButtonState;
 use crate::journal::{components::*, resources::*};
 use crate::photo_mode::components::PhotoTakenEvent;
-use crate::photo_mode::resources::PhotoCollection;
+use crate::photo_mode::resources::{PhotoCollection, SavedPhoto};
+use crate::photo_mode::filters::{PhotoFilter, SmartCollections, TimeOfDay, SCORE_STEPS};
+use crate::environment::components::Weather;
 use crate::achievements::{AchievementProgress, Achievement};
 use crate::despawn::SafeDespawn;
+use crate::ambient_wildlife::{OtherWildlifeLog, WildlifeSpecies};
+use crate::clip_recorder::ClipLibrary;
+use crate::environment::resources::TimeState;
+use crate::environment::irruption::Irruptions;
+use crate::bird::{Bird, BirdSpecies};
+use crate::garden_styles::{GardenStyles, spawn_style_badges};
+use crate::search_palette::{SearchIndex, SearchJumpEvent, SearchTarget};
+use crate::user_interface::chart::ScatterChart;
+use crate::journal::milestones::{MilestoneLog, spawn_milestone_timeline};
+
+pub const JOURNAL_TABS: [(JournalTab, &str, &str); 11] = [
+    (JournalTab::Species, "Species", "Discovered bird species"),
+    (JournalTab::Photos, "Photos", "Photo collection"),
+    (JournalTab::Conservation, "Conservation", "Species status & protection"),
+    (JournalTab::Migration, "Migration", "Migration patterns & routes"),
+    (JournalTab::Research, "Research", "Active research missions"),
+    (JournalTab::Achievements, "Progress", "Achievements & milestones"),
+    (JournalTab::Wildlife, "Wildlife", "Other wildlife sightings"),
+    (JournalTab::Clips, "Clips", "Saved video clips"),
+    (JournalTab::Checklists, "Checklists", "Daily sighting checklists"),
+    (JournalTab::Notes, "Notes", "Your notes and tags"),
+    (JournalTab::Timeline, "Timeline", "Your sanctuary's milestones"),
+];
 
 pub fn load_education_data(mut education_data: ResMut<BirdEducationData>) {
     education_data.load_from_files();
@@ -15,21 +42,26 @@ pub fn toggle_journal_system(
     current_state: Res<State<crate::AppState>>,
     mut journal_state: ResMut<JournalState>,
 ) {
+    // A J typed into a note or the search bar shouldn't close the journal
+    if journal_state.note_input.is_some() {
+        return;
+    }
+
     if keyboard.just_pressed(KeyCode::KeyJ) {
-        info!("🔵 JOURNAL TOGGLE: J key pressed in state: {:?}", current_state.get());
+        debug!("Journal toggle pressed in state {:?}", current_state.get());
         match current_state.get() {
             crate::AppState::Playing => {
-                info!("🔵 JOURNAL TOGGLE: Opening journal, transitioning Playing -> Journal");
+                debug!("Opening journal");
                 journal_state.is_open = true;
                 next_state.set(crate::AppState::Journal);
             }
             crate::AppState::Journal => {
-                info!("🔵 JOURNAL TOGGLE: Closing journal, transitioning Journal -> Playing");
+                debug!("Closing journal");
                 journal_state.is_open = false;
                 next_state.set(crate::AppState::Playing);
             }
             _ => {
-                info!("🔵 JOURNAL TOGGLE: J key pressed but in invalid state: {:?}", current_state.get());
+                debug!("Journal toggle ignored in state {:?}", current_state.get());
             }
         }
     }
@@ -43,6 +75,15 @@ pub fn setup_journal_menu_system(
     photo_collection: Res<PhotoCollection>,
     research_manager: Res<ResearchMissionManager>,
     achievement_progress: Res<AchievementProgress>,
+    wildlife_log: Res<OtherWildlifeLog>,
+    clip_library: Res<ClipLibrary>,
+    time_state: Res<TimeState>,
+    sighting_log: Res<SightingLog>,
+    garden_styles: Res<GardenStyles>,
+    field_notes: Res<FieldNotes>,
+    irruptions: Res<Irruptions>,
+    milestones: Res<MilestoneLog>,
+    smart_collections: Res<SmartCollections>,
 ) {
     // Main journal container - field notebook style
     commands.spawn((
@@ -58,6 +99,7 @@ pub fn setup_journal_menu_system(
         BackgroundColor(Color::srgb(0.95, 0.92, 0.88)), // Aged paper color
         JournalMenu,
         JournalBackground,
+        StateScoped(crate::AppState::Journal),
     )).with_children(|journal| {
         // Journal header with binding rings effect
         journal.spawn((
@@ -120,16 +162,9 @@ pub fn setup_journal_menu_system(
             },
             BorderColor(Color::srgb(0.6, 0.5, 0.4)),
         )).with_children(|tabs| {
-            let tab_configs = [
-                (JournalTab::Species, "Species", "Discovered bird species"),
-                (JournalTab::Photos, "Photos", "Photo collection"),
-                (JournalTab::Conservation, "Conservation", "Species status & protection"),
-                (JournalTab::Migration, "Migration", "Migration patterns & routes"),
-                (JournalTab::Research, "Research", "Active research missions"),
-                (JournalTab::Achievements, "Progress", "Achievements & milestones"),
-            ];
+            let tab_width = 100.0 / JOURNAL_TABS.len() as f32;
             
-            for (tab, title, _tooltip) in tab_configs {
+            for (tab, title, _tooltip) in JOURNAL_TABS {
                 let is_active = tab == journal_state.current_tab;
                 let (bg_color, text_color) = if is_active {
                     (Color::srgb(0.95, 0.92, 0.88), Color::srgb(0.2, 0.1, 0.05))
@@ -140,7 +175,7 @@ pub fn setup_journal_menu_system(
                 tabs.spawn((
                     Button,
                     Node {
-                        width: Val::Percent(20.0),
+                        width: Val::Percent(tab_width),
                         height: Val::Percent(100.0),
                         justify_content: JustifyContent::Center,
                         align_items: AlignItems::Center,
@@ -259,26 +294,57 @@ pub fn setup_journal_menu_system(
                                         TextColor(Color::srgb(0.4, 0.3, 0.2)),
                                     ));
                                     
-                                    // Conservation status badge
                                     card.spawn((
                                         Node {
-                                            width: Val::Px(100.0),
-                                            height: Val::Px(20.0),
-                                            justify_content: JustifyContent::Center,
+                                            width: Val::Percent(100.0),
+                                            flex_direction: FlexDirection::Row,
+                                            justify_content: JustifyContent::SpaceBetween,
                                             align_items: AlignItems::Center,
                                             ..default()
                                         },
-                                        BackgroundColor(conservation.color()),
-                                        ConservationStatusBadge,
-                                    )).with_children(|badge| {
-                                        badge.spawn((
-                                            Text::new(conservation.label()),
-                                            TextFont {
-                                                font_size: 10.0,
+                                    )).with_children(|footer| {
+                                        // Conservation status badge
+                                        footer.spawn((
+                                            Node {
+                                                width: Val::Px(100.0),
+                                                height: Val::Px(20.0),
+                                                justify_content: JustifyContent::Center,
+                                                align_items: AlignItems::Center,
                                                 ..default()
                                             },
-                                            TextColor(Color::WHITE),
-                                        ));
+                                            BackgroundColor(conservation.color()),
+                                            ConservationStatusBadge,
+                                        )).with_children(|badge| {
+                                            badge.spawn((
+                                                Text::new(conservation.label()),
+                                                TextFont {
+                                                    font_size: 10.0,
+                                                    ..default()
+                                                },
+                                                TextColor(Color::WHITE),
+                                            ));
+                                        });
+
+                                        // Plays the song with its sonogram
+                                        footer.spawn((
+                                            Button,
+                                            Node {
+                                                padding: UiRect::axes(Val::Px(8.0), Val::Px(3.0)),
+                                                ..default()
+                                            },
+                                            BackgroundColor(Color::srgb(0.6, 0.5, 0.4)),
+                                            BorderRadius::all(Val::Px(4.0)),
+                                            PlayCallButton { species: *species },
+                                        )).with_children(|button| {
+                                            button.spawn((
+                                                Text::new("Listen"),
+                                                TextFont {
+                                                    font_size: 11.0,
+                                                    ..default()
+                                                },
+                                                TextColor(Color::WHITE),
+                                            ));
+                                        });
                                     });
                                 });
                             }
@@ -326,131 +392,7 @@ pub fn setup_journal_menu_system(
                             ));
                         });
                         
-                        if photo_collection.photos.is_empty() {
-                            // Empty state message
-                            photos_content.spawn((
-                                Node {
-                                    width: Val::Percent(100.0),
-                                    height: Val::Px(200.0),
-                                    justify_content: JustifyContent::Center,
-                                    align_items: AlignItems::Center,
-                                    ..default()
-                                },
-                                BackgroundColor(Color::srgba(0.9, 0.87, 0.83, 0.5)),
-                            )).with_children(|empty| {
-                                empty.spawn((
-                                    Text::new("No photos yet!\nPress P to enter Photo Mode and start building your collection."),
-                                    TextFont {
-                                        font_size: 16.0,
-                                        ..default()
-                                    },
-                                    TextColor(Color::srgb(0.5, 0.4, 0.3)),
-                                ));
-                            });
-                        } else {
-                            // Best photos section
-                            let best_photos = photo_collection.get_best_photos(6);
-                            
-                            photos_content.spawn((
-                                Text::new("Best Photos"),
-                                TextFont {
-                                    font_size: 18.0,
-                                    ..default()
-                                },
-                                TextColor(Color::srgb(0.3, 0.2, 0.1)),
-                                Node {
-                                    margin: UiRect::bottom(Val::Px(10.0)),
-                                    ..default()
-                                },
-                            ));
-                            
-                            // Photo grid
-                            photos_content.spawn((
-                                Node {
-                                    width: Val::Percent(100.0),
-                                    flex_direction: FlexDirection::Row,
-                                    flex_wrap: FlexWrap::Wrap,
-                                    row_gap: Val::Px(15.0),
-                                    column_gap: Val::Px(15.0),
-                                    ..default()
-                                },
-                            )).with_children(|grid| {
-                                for photo in best_photos {
-                                    grid.spawn((
-                                        Button,
-                                        Node {
-                                            width: Val::Px(200.0),
-                                            height: Val::Px(160.0),
-                                            flex_direction: FlexDirection::Column,
-                                            border: UiRect::all(Val::Px(2.0)),
-                                            padding: UiRect::all(Val::Px(10.0)),
-                                            ..default()
-                                        },
-                                        BackgroundColor(Color::srgb(0.98, 0.95, 0.92)),
-                                        BorderColor(Color::srgb(0.7, 0.6, 0.5)),
-                                        PhotoCard { timestamp: photo.timestamp },
-                                    )).with_children(|card| {
-                                        // Photo image placeholder (would show actual image)
-                                        card.spawn((
-                                            Node {
-                                                width: Val::Percent(100.0),
-                                                height: Val::Px(100.0),
-                                                justify_content: JustifyContent::Center,
-                                                align_items: AlignItems::Center,
-                                                ..default()
-                                            },
-                                            BackgroundColor(Color::srgb(0.8, 0.8, 0.8)),
-                                        )).with_children(|img| {
-                                            img.spawn((
-                                                Text::new("📸"),
-                                                TextFont {
-                                                    font_size: 32.0,
-                                                    ..default()
-                                                },
-                                                TextColor(Color::srgb(0.5, 0.5, 0.5)),
-                                            ));
-                                        });
-                                        
-                                        // Photo metadata
-                                        card.spawn((
-                                            Node {
-                                                width: Val::Percent(100.0),
-                                                flex_direction: FlexDirection::Column,
-                                                row_gap: Val::Px(5.0),
-                                                ..default()
-                                            },
-                                        )).with_children(|meta| {
-                                            // Species name
-                                            let species_text = if let Some(species) = photo.species {
-                                                format!("{:?}", species)
-                                            } else {
-                                                "Unknown species".to_string()
-                                            };
-                                            
-                                            meta.spawn((
-                                                Text::new(species_text),
-                                                TextFont {
-                                                    font_size: 12.0,
-                                                    ..default()
-                                                },
-                                                TextColor(Color::srgb(0.3, 0.2, 0.1)),
-                                            ));
-                                            
-                                            // Score and date
-                                            meta.spawn((
-                                                Text::new(format!("Score: {} | Day {:.0}", 
-                                                    photo.score.total_score, photo.timestamp)),
-                                                TextFont {
-                                                    font_size: 10.0,
-                                                    ..default()
-                                                },
-                                                TextColor(Color::srgb(0.5, 0.4, 0.3)),
-                                            ));
-                                        });
-                                    });
-                                }
-                            });
-                        }
+                        spawn_photo_gallery(photos_content, &photo_collection, &journal_state, &smart_collections);
                     });
                 },
                 JournalTab::Conservation => {
@@ -566,21 +508,8 @@ pub fn setup_journal_menu_system(
                             },
                         ));
                         
-                        // Migration statistics
-                        let migratory_count = discovered.0.iter()
-                            .filter_map(|species| education_data.migration_data.get(species))
-                            .filter(|data| data.is_migratory)
-                            .count();
-                        let resident_count = discovered.0.len() - migratory_count;
-                        
-                        migration_content.spawn((
-                            Text::new(format!("Migratory: {} | Resident: {}", migratory_count, resident_count)),
-                            TextFont {
-                                font_size: 14.0,
-                                ..default()
-                            },
-                            TextColor(Color::srgb(0.4, 0.3, 0.2)),
-                        ));
+                        spawn_migration_maps(migration_content, &discovered, &education_data, &time_state);
+                        spawn_irruption_log(migration_content, &irruptions);
                     });
                 },
                 JournalTab::Research => {
@@ -786,10 +715,63 @@ pub fn setup_journal_menu_system(
                                 });
                             }
                         }
+
+                        // Completed missions, each with its findings plotted from the player's photos
+                        if !research_manager.completed_missions.is_empty() {
+                            research_content.spawn((
+                                Text::new("Completed Research"),
+                                TextFont {
+                                    font_size: 18.0,
+                                    ..default()
+                                },
+                                TextColor(Color::srgb(0.3, 0.2, 0.1)),
+                                Node {
+                                    margin: UiRect::vertical(Val::Px(10.0)),
+                                    ..default()
+                                },
+                            ));
+                        }
+                        for mission in research_manager.completed_missions.iter().rev() {
+                            let plot = mission.result_plot(&research_manager.observations);
+                            research_content.spawn((
+                                Node {
+                                    width: Val::Percent(100.0),
+                                    flex_direction: FlexDirection::Column,
+                                    padding: UiRect::all(Val::Px(12.0)),
+                                    row_gap: Val::Px(6.0),
+                                    ..default()
+                                },
+                                BackgroundColor(Color::srgba(0.85, 0.9, 0.85, 0.6)),
+                            )).with_children(|card| {
+                                card.spawn((
+                                    Text::new(match &mission.citizen_science_partner {
+                                        Some(partner) => format!("{} - submitted to {}", mission.title, partner),
+                                        None => mission.title.clone(),
+                                    }),
+                                    TextFont {
+                                        font_size: 14.0,
+                                        ..default()
+                                    },
+                                    TextColor(Color::srgb(0.3, 0.2, 0.1)),
+                                ));
+                                card.spawn((
+                                    Text::new(plot.finding()),
+                                    TextFont {
+                                        font_size: 12.0,
+                                        ..default()
+                                    },
+                                    TextColor(Color::srgb(0.4, 0.3, 0.2)),
+                                ));
+                                ScatterChart::new(plot.title.clone())
+                                    .with_axes(plot.x_label.clone(), plot.y_label.clone())
+                                    .with_points(plot.points)
+                                    .spawn(card);
+                            });
+                        }
                     });
                 },
-                JournalTab::Achievements => {
-                    // Achievements tab content - Display actual achievements
+                JournalTab::Wildlife => {
+                    // Other Wildlife tab content - non-bird animals seen in the sanctuary
                     content.spawn((
                         Node {
                             width: Val::Percent(100.0),
@@ -798,175 +780,225 @@ pub fn setup_journal_menu_system(
                             row_gap: Val::Px(15.0),
                             ..default()
                         },
-                    )).with_children(|achievements_content| {
-                        // Achievement header with stats
-                        achievements_content.spawn((
-                            Node {
-                                width: Val::Percent(100.0),
-                                flex_direction: FlexDirection::Row,
-                                justify_content: JustifyContent::SpaceBetween,
-                                align_items: AlignItems::Center,
-                                margin: UiRect::bottom(Val::Px(15.0)),
+                    )).with_children(|wildlife_content| {
+                        wildlife_content.spawn((
+                            Text::new("Other Wildlife"),
+                            TextFont {
+                                font_size: 18.0,
                                 ..default()
                             },
-                        )).with_children(|header| {
-                            header.spawn((
-                                Text::new("Achievements & Progress"),
-                                TextFont {
-                                    font_size: 20.0,
-                                    ..default()
-                                },
-                                TextColor(Color::srgb(0.3, 0.2, 0.1)),
-                            ));
-                            
-                            header.spawn((
-                                Text::new(format!("Unlocked: {}/11", 
-                                    achievement_progress.unlocked.len())),
-                                TextFont {
-                                    font_size: 14.0,
-                                    ..default()
-                                },
-                                TextColor(Color::srgb(0.4, 0.3, 0.2)),
-                            ));
-                        });
-                        
-                        // Progress statistics
-                        achievements_content.spawn((
+                            TextColor(Color::srgb(0.3, 0.2, 0.1)),
                             Node {
-                                width: Val::Percent(100.0),
-                                flex_direction: FlexDirection::Column,
-                                row_gap: Val::Px(8.0),
-                                padding: UiRect::all(Val::Px(15.0)),
-                                border: UiRect::all(Val::Px(1.0)),
                                 margin: UiRect::bottom(Val::Px(15.0)),
                                 ..default()
                             },
-                            BackgroundColor(Color::srgba(0.95, 0.92, 0.88, 0.5)),
-                            BorderColor(Color::srgb(0.7, 0.6, 0.5)),
-                        )).with_children(|stats| {
-                            stats.spawn((
-                                Text::new("Progress Statistics"),
-                                TextFont {
-                                    font_size: 16.0,
-                                    ..default()
-                                },
-                                TextColor(Color::srgb(0.3, 0.2, 0.1)),
-                                Node {
-                                    margin: UiRect::bottom(Val::Px(8.0)),
-                                    ..default()
-                                },
-                            ));
+                        ));
+                        
+                        for species in WildlifeSpecies::ALL {
+                            let entry = wildlife_log.entries.get(&species);
+                            let photographed = entry.is_some_and(|entry| entry.photos > 0);
                             
-                            stats.spawn((
-                                Text::new(format!(
-                                    "Photos Taken: {} | Species Discovered: {} | Action Shots: {} | Multi-Bird Shots: {}", 
-                                    achievement_progress.photos_taken,
-                                    achievement_progress.species_discovered,
-                                    achievement_progress.action_shots_taken,
-                                    achievement_progress.multi_bird_shots
-                                )),
-                                TextFont {
-                                    font_size: 12.0,
+                            wildlife_content.spawn((
+                                Node {
+                                    width: Val::Percent(100.0),
+                                    flex_direction: FlexDirection::Column,
+                                    padding: UiRect::all(Val::Px(10.0)),
+                                    row_gap: Val::Px(4.0),
                                     ..default()
                                 },
-                                TextColor(Color::srgb(0.5, 0.4, 0.3)),
-                            ));
-                        });
-                        
-                        // Achievement grid
-                        achievements_content.spawn((
-                            Node {
-                                width: Val::Percent(100.0),
-                                flex_direction: FlexDirection::Row,
-                                flex_wrap: FlexWrap::Wrap,
-                                row_gap: Val::Px(10.0),
-                                column_gap: Val::Px(10.0),
-                                ..default()
-                            },
-                        )).with_children(|grid| {
-                            // List all achievements
-                            let all_achievements = [
-                                Achievement::FirstPhoto,
-                                Achievement::PhotoMaster,
-                                Achievement::ActionShot,
-                                Achievement::MultiSpeciesShot,
-                                Achievement::FirstSpecies,
-                                Achievement::CommonCollector,
-                                Achievement::Ornithologist,
-                                Achievement::Wealthy,
-                                Achievement::Millionaire,
-                                Achievement::FeederMaintainer,
-                                Achievement::FeederExpert,
-                            ];
-                            
-                            for achievement in all_achievements.iter() {
-                                let is_unlocked = achievement_progress.is_unlocked(&achievement);
-                                let (bg_color, border_color, text_color) = if is_unlocked {
-                                    (
-                                        Color::srgb(0.85, 0.95, 0.85),
-                                        Color::srgb(0.2, 0.8, 0.2),
-                                        Color::srgb(0.1, 0.4, 0.1)
-                                    )
+                                BackgroundColor(Color::srgba(0.9, 0.9, 0.9, 0.5)),
+                            )).with_children(|card| {
+                                let (title, detail) = if photographed {
+                                    (species.common_name().to_string(), species.description().to_string())
                                 } else {
-                                    (
-                                        Color::srgb(0.9, 0.9, 0.9),
-                                        Color::srgb(0.6, 0.6, 0.6),
-                                        Color::srgb(0.5, 0.5, 0.5)
-                                    )
+                                    ("???".to_string(), "Photograph this animal to add it to your journal.".to_string())
                                 };
                                 
-                                grid.spawn((
-                                    Button,
-                                    Node {
-                                        width: Val::Px(280.0),
-                                        height: Val::Px(100.0),
-                                        flex_direction: FlexDirection::Column,
-                                        justify_content: JustifyContent::SpaceBetween,
-                                        padding: UiRect::all(Val::Px(12.0)),
-                                        border: UiRect::all(Val::Px(2.0)),
+                                card.spawn((
+                                    Text::new(title),
+                                    TextFont {
+                                        font_size: 14.0,
                                         ..default()
                                     },
-                                    BackgroundColor(bg_color),
-                                    BorderColor(border_color),
-                                    AchievementCard { achievement: achievement.clone() },
-                                )).with_children(|card| {
-                                    // Achievement header
-                                    card.spawn((
-                                        Node {
-                                            width: Val::Percent(100.0),
-                                            flex_direction: FlexDirection::Row,
-                                            justify_content: JustifyContent::SpaceBetween,
-                                            align_items: AlignItems::Center,
+                                    TextColor(Color::srgb(0.3, 0.2, 0.1)),
+                                ));
+                                
+                                card.spawn((
+                                    Text::new(detail),
+                                    TextFont {
+                                        font_size: 12.0,
+                                        ..default()
+                                    },
+                                    TextColor(Color::srgb(0.5, 0.4, 0.3)),
+                                ));
+                                
+                                if let Some(entry) = entry {
+                                    card.spawn((
+                                        Text::new(format!("Sightings: {} | Photos: {}", entry.sightings, entry.photos)),
+                                        TextFont {
+                                            font_size: 12.0,
                                             ..default()
                                         },
-                                    )).with_children(|achievement_header| {
-                                        achievement_header.spawn((
-                                            Text::new(achievement.name()),
-                                            TextFont {
-                                                font_size: 16.0,
-                                                ..default()
-                                            },
-                                            TextColor(text_color),
-                                        ));
-                                        
-                                        let status_text = if is_unlocked { "✓" } else { "○" };
-                                        achievement_header.spawn((
-                                            Text::new(status_text),
+                                        TextColor(Color::srgb(0.4, 0.3, 0.2)),
+                                    ));
+                                }
+                            });
+                        }
+                    });
+                },
+                JournalTab::Checklists => {
+                    // Checklists tab content - calendar of daily sighting checklists
+                    content.spawn((
+                        Node {
+                            width: Val::Percent(100.0),
+                            flex_direction: FlexDirection::Column,
+                            row_gap: Val::Px(12.0),
+                            ..default()
+                        },
+                    )).with_children(|checklist_content| {
+                        checklist_content.spawn((
+                            Text::new("Sighting Checklists"),
+                            TextFont {
+                                font_size: 18.0,
+                                ..default()
+                            },
+                            TextColor(Color::srgb(0.3, 0.2, 0.1)),
+                        ));
+                        spawn_sighting_calendar(checklist_content, &sighting_log, &journal_state, &time_state);
+                    });
+                },
+                JournalTab::Notes => {
+                    // Notes tab content - searchable notes and tags on species and photos
+                    content.spawn((
+                        Node {
+                            width: Val::Percent(100.0),
+                            flex_direction: FlexDirection::Column,
+                            row_gap: Val::Px(12.0),
+                            ..default()
+                        },
+                    )).with_children(|notes_content| {
+                        notes_content.spawn((
+                            Text::new("Field Notes"),
+                            TextFont {
+                                font_size: 18.0,
+                                ..default()
+                            },
+                            TextColor(Color::srgb(0.3, 0.2, 0.1)),
+                        ));
+                        spawn_field_notes(notes_content, &field_notes, &journal_state, &discovered, &photo_collection, &education_data);
+                    });
+                },
+                JournalTab::Timeline => {
+                    // Timeline tab content - the sanctuary's milestones, newest first
+                    content.spawn((
+                        Node {
+                            width: Val::Percent(100.0),
+                            flex_direction: FlexDirection::Column,
+                            row_gap: Val::Px(4.0),
+                            ..default()
+                        },
+                    )).with_children(|timeline_content| {
+                        timeline_content.spawn((
+                            Text::new("Sanctuary Timeline"),
+                            TextFont {
+                                font_size: 18.0,
+                                ..default()
+                            },
+                            TextColor(Color::srgb(0.3, 0.2, 0.1)),
+                        ));
+                        spawn_milestone_timeline(timeline_content, &milestones, &photo_collection, &education_data);
+                    });
+                },
+                JournalTab::Clips => {
+                    // Clips tab content - saved recordings of notable moments
+                    content.spawn((
+                        Node {
+                            width: Val::Percent(100.0),
+                            height: Val::Percent(100.0),
+                            flex_direction: FlexDirection::Column,
+                            row_gap: Val::Px(15.0),
+                            ..default()
+                        },
+                    )).with_children(|clips_content| {
+                        clips_content.spawn((
+                            Text::new(format!("Saved Clips ({})", clip_library.clips.len())),
+                            TextFont {
+                                font_size: 18.0,
+                                ..default()
+                            },
+                            TextColor(Color::srgb(0.3, 0.2, 0.1)),
+                            Node {
+                                margin: UiRect::bottom(Val::Px(15.0)),
+                                ..default()
+                            },
+                        ));
+                        
+                        if clip_library.clips.is_empty() {
+                            clips_content.spawn((
+                                Text::new("No clips yet!\nPress V to save the last few seconds, or wait for a hawk strike or rare visitor."),
+                                TextFont {
+                                    font_size: 14.0,
+                                    ..default()
+                                },
+                                TextColor(Color::srgb(0.5, 0.4, 0.3)),
+                            ));
+                        }
+                        
+                        // Newest clips first
+                        for clip in clip_library.clips.iter().rev() {
+                            clips_content.spawn((
+                                Node {
+                                    width: Val::Percent(100.0),
+                                    flex_direction: FlexDirection::Row,
+                                    align_items: AlignItems::Center,
+                                    padding: UiRect::all(Val::Px(10.0)),
+                                    column_gap: Val::Px(12.0),
+                                    ..default()
+                                },
+                                BackgroundColor(Color::srgba(0.9, 0.9, 0.9, 0.5)),
+                            )).with_children(|card| {
+                                let mut preview = card.spawn((
+                                    Node {
+                                        width: Val::Px(128.0),
+                                        height: Val::Px(72.0),
+                                        justify_content: JustifyContent::Center,
+                                        align_items: AlignItems::Center,
+                                        ..default()
+                                    },
+                                    BackgroundColor(Color::srgb(0.8, 0.8, 0.8)),
+                                ));
+                                if let Some(thumbnail) = &clip.thumbnail {
+                                    preview.insert(ImageNode::new(thumbnail.clone()));
+                                } else {
+                                    preview.with_children(|img| {
+                                        img.spawn((
+                                            Text::new("🎬"),
                                             TextFont {
-                                                font_size: 16.0,
+                                                font_size: 28.0,
                                                 ..default()
                                             },
-                                            TextColor(if is_unlocked { 
-                                                Color::srgb(0.2, 0.8, 0.2) 
-                                            } else { 
-                                                Color::srgb(0.6, 0.6, 0.6) 
-                                            }),
+                                            TextColor(Color::srgb(0.5, 0.5, 0.5)),
                                         ));
                                     });
+                                }
+                                
+                                card.spawn(Node {
+                                    flex_direction: FlexDirection::Column,
+                                    row_gap: Val::Px(4.0),
+                                    ..default()
+                                }).with_children(|meta| {
+                                    meta.spawn((
+                                        Text::new(clip.title.clone()),
+                                        TextFont {
+                                            font_size: 14.0,
+                                            ..default()
+                                        },
+                                        TextColor(Color::srgb(0.3, 0.2, 0.1)),
+                                    ));
                                     
-                                    // Achievement description
-                                    card.spawn((
-                                        Text::new(achievement.description()),
+                                    let format = if clip.video_path().is_some() { "MP4" } else { "Image sequence" };
+                                    meta.spawn((
+                                        Text::new(format!("Day {} | {:.1}s | {}", clip.day, clip.duration_secs(), format)),
                                         TextFont {
                                             font_size: 12.0,
                                             ..default()
@@ -974,219 +1006,1945 @@ pub fn setup_journal_menu_system(
                                         TextColor(Color::srgb(0.4, 0.3, 0.2)),
                                     ));
                                     
-                                    // Reward info
-                                    card.spawn((
-                                        Text::new(format!("Reward: {} currency", achievement.currency_reward())),
+                                    meta.spawn((
+                                        Text::new(clip.directory.display().to_string()),
                                         TextFont {
-                                            font_size: 11.0,
+                                            font_size: 10.0,
                                             ..default()
                                         },
-                                        TextColor(Color::srgb(0.6, 0.4, 0.1)),
+                                        TextColor(Color::srgb(0.5, 0.4, 0.3)),
                                     ));
                                 });
-                            }
-                        });
+                            });
+                        }
                     });
-                }
-            }
-        });
-    });
-}
-
-pub fn teardown_journal_menu_system(
-    mut commands: Commands,
-    journal_query: Query<Entity, With<JournalMenu>>,
-) {
-    for entity in journal_query.iter() {
-        commands.entity(entity).safe_despawn();
-    }
-}
-
-pub fn journal_tab_system(
-    mut interaction_query: Query<
-        (&Interaction, &JournalTabButton, &mut BackgroundColor),
-        (Changed<Interaction>, With<Button>),
-    >,
+                },
+                JournalTab::Achievements => {
+                    // Achievements tab content - Display actual achievements
+                    content.spawn((
+                        Node {
+                            width: Val::Percent(100.0),
+                            height: Val::Percent(100.0),
+                            flex_direction: FlexDirection::Column,
+                            row_gap: Val::Px(15.0),
+                            ..default()
+                        },
+                    )).with_children(|achievements_content| {
+                        // Achievement header with stats
+                        achievements_content.spawn((
+                            Node {
+                                width: Val::Percent(100.0),
+                                flex_direction: FlexDirection::Row,
+                                justify_content: JustifyContent::SpaceBetween,
+                                align_items: AlignItems::Center,
+                                margin: UiRect::bottom(Val::Px(15.0)),
+                                ..default()
+                            },
+                        )).with_children(|header| {
+                            header.spawn((
+                                Text::new("Achievements & Progress"),
+                                TextFont {
+                                    font_size: 20.0,
+                                    ..default()
+                                },
+                                TextColor(Color::srgb(0.3, 0.2, 0.1)),
+                            ));
+                            
+                            header.spawn((
+                                Text::new(format!("Unlocked: {}/11", 
+                                    achievement_progress.unlocked.len())),
+                                TextFont {
+                                    font_size: 14.0,
+                                    ..default()
+                                },
+                                TextColor(Color::srgb(0.4, 0.3, 0.2)),
+                            ));
+                        });
+                        
+                        // Progress statistics
+                        achievements_content.spawn((
+                            Node {
+                                width: Val::Percent(100.0),
+                                flex_direction: FlexDirection::Column,
+                                row_gap: Val::Px(8.0),
+                                padding: UiRect::all(Val::Px(15.0)),
+                                border: UiRect::all(Val::Px(1.0)),
+                                margin: UiRect::bottom(Val::Px(15.0)),
+                                ..default()
+                            },
+                            BackgroundColor(Color::srgba(0.95, 0.92, 0.88, 0.5)),
+                            BorderColor(Color::srgb(0.7, 0.6, 0.5)),
+                        )).with_children(|stats| {
+                            stats.spawn((
+                                Text::new("Progress Statistics"),
+                                TextFont {
+                                    font_size: 16.0,
+                                    ..default()
+                                },
+                                TextColor(Color::srgb(0.3, 0.2, 0.1)),
+                                Node {
+                                    margin: UiRect::bottom(Val::Px(8.0)),
+                                    ..default()
+                                },
+                            ));
+                            
+                            stats.spawn((
+                                Text::new(format!(
+                                    "Photos Taken: {} | Species Discovered: {} | Action Shots: {} | Multi-Bird Shots: {}", 
+                                    achievement_progress.photos_taken,
+                                    achievement_progress.species_discovered,
+                                    achievement_progress.action_shots_taken,
+                                    achievement_progress.multi_bird_shots
+                                )),
+                                TextFont {
+                                    font_size: 12.0,
+                                    ..default()
+                                },
+                                TextColor(Color::srgb(0.5, 0.4, 0.3)),
+                            ));
+                        });
+                        
+                        // Achievement grid
+                        achievements_content.spawn((
+                            Node {
+                                width: Val::Percent(100.0),
+                                flex_direction: FlexDirection::Row,
+                                flex_wrap: FlexWrap::Wrap,
+                                row_gap: Val::Px(10.0),
+                                column_gap: Val::Px(10.0),
+                                ..default()
+                            },
+                        )).with_children(|grid| {
+                            // List all achievements
+                            let all_achievements = [
+                                Achievement::FirstPhoto,
+                                Achievement::PhotoMaster,
+                                Achievement::ActionShot,
+                                Achievement::MultiSpeciesShot,
+                                Achievement::FirstSpecies,
+                                Achievement::CommonCollector,
+                                Achievement::Ornithologist,
+                                Achievement::Wealthy,
+                                Achievement::Millionaire,
+                                Achievement::FeederMaintainer,
+                                Achievement::FeederExpert,
+                                Achievement::FirstCompost,
+                                Achievement::WasteNotWantNot,
+                                Achievement::GreenSanctuary,
+                            ];
+                            
+                            for achievement in all_achievements.iter() {
+                                let is_unlocked = achievement_progress.is_unlocked(&achievement);
+                                let (bg_color, border_color, text_color) = if is_unlocked {
+                                    (
+                                        Color::srgb(0.85, 0.95, 0.85),
+                                        Color::srgb(0.2, 0.8, 0.2),
+                                        Color::srgb(0.1, 0.4, 0.1)
+                                    )
+                                } else {
+                                    (
+                                        Color::srgb(0.9, 0.9, 0.9),
+                                        Color::srgb(0.6, 0.6, 0.6),
+                                        Color::srgb(0.5, 0.5, 0.5)
+                                    )
+                                };
+                                
+                                grid.spawn((
+                                    Button,
+                                    Node {
+                                        width: Val::Px(280.0),
+                                        height: Val::Px(100.0),
+                                        flex_direction: FlexDirection::Column,
+                                        justify_content: JustifyContent::SpaceBetween,
+                                        padding: UiRect::all(Val::Px(12.0)),
+                                        border: UiRect::all(Val::Px(2.0)),
+                                        ..default()
+                                    },
+                                    BackgroundColor(bg_color),
+                                    BorderColor(border_color),
+                                    AchievementCard { achievement: achievement.clone() },
+                                )).with_children(|card| {
+                                    // Achievement header
+                                    card.spawn((
+                                        Node {
+                                            width: Val::Percent(100.0),
+                                            flex_direction: FlexDirection::Row,
+                                            justify_content: JustifyContent::SpaceBetween,
+                                            align_items: AlignItems::Center,
+                                            ..default()
+                                        },
+                                    )).with_children(|achievement_header| {
+                                        achievement_header.spawn((
+                                            Text::new(achievement.name()),
+                                            TextFont {
+                                                font_size: 16.0,
+                                                ..default()
+                                            },
+                                            TextColor(text_color),
+                                        ));
+                                        
+                                        let status_text = if is_unlocked { "✓" } else { "○" };
+                                        achievement_header.spawn((
+                                            Text::new(status_text),
+                                            TextFont {
+                                                font_size: 16.0,
+                                                ..default()
+                                            },
+                                            TextColor(if is_unlocked { 
+                                                Color::srgb(0.2, 0.8, 0.2) 
+                                            } else { 
+                                                Color::srgb(0.6, 0.6, 0.6) 
+                                            }),
+                                        ));
+                                    });
+                                    
+                                    // Achievement description
+                                    card.spawn((
+                                        Text::new(achievement.description()),
+                                        TextFont {
+                                            font_size: 12.0,
+                                            ..default()
+                                        },
+                                        TextColor(Color::srgb(0.4, 0.3, 0.2)),
+                                    ));
+                                    
+                                    // Reward info
+                                    card.spawn((
+                                        Text::new(format!("Reward: {} currency", achievement.currency_reward())),
+                                        TextFont {
+                                            font_size: 11.0,
+                                            ..default()
+                                        },
+                                        TextColor(Color::srgb(0.6, 0.4, 0.1)),
+                                    ));
+                                });
+                            }
+                        });
+                        
+                        spawn_style_badges(achievements_content, &garden_styles);
+                    });
+                }
+            }
+        });
+    });
+}
+
+// The journal UI itself is state scoped; only the open text field needs resetting
+pub fn teardown_journal_menu_system(mut journal_state: ResMut<JournalState>) {
+    journal_state.note_input = None;
+}
+
+pub fn journal_tab_system(
+    mut interaction_query: Query<
+        (&Interaction, &JournalTabButton, &mut BackgroundColor),
+        (Changed<Interaction>, With<Button>),
+    >,
+    mut journal_state: ResMut<JournalState>,
+) {
+    for (interaction, tab_button, mut bg_color) in interaction_query.iter_mut() {
+        match *interaction {
+            Interaction::Pressed => {
+                *bg_color = Color::srgb(0.95, 0.92, 0.88).into();
+                
+                if journal_state.current_tab != tab_button.tab {
+                    debug!("Journal tab {:?} -> {:?}", journal_state.current_tab, tab_button.tab);
+                    journal_state.current_tab = tab_button.tab;
+                    // Note: Content regeneration will be handled by journal_state_monitor_system
+                }
+            }
+            Interaction::Hovered => {
+                if journal_state.current_tab != tab_button.tab {
+                    *bg_color = Color::srgb(0.9, 0.87, 0.83).into();
+                }
+            }
+            Interaction::None => {
+                if journal_state.current_tab != tab_button.tab {
+                    *bg_color = Color::srgb(0.85, 0.82, 0.78).into();
+                }
+            }
+        }
+    }
+}
+
+pub fn journal_species_detail_system(
+    interaction_query: Query<
+        (&Interaction, &SpeciesCard),
+        (Changed<Interaction>, With<Button>),
+    >,
+    mut journal_state: ResMut<JournalState>,
+) {
+    for (interaction, species_card) in interaction_query.iter() {
+        if *interaction == Interaction::Pressed {
+            journal_state.selected_species = Some(species_card.species);
+        }
+    }
+}
+
+pub fn journal_state_monitor_system(
+    journal_state: Res<JournalState>,
+    mut commands: Commands,
+    content_query: Query<Entity, With<JournalTabContent>>,
+    journal_query: Query<Entity, With<JournalMenu>>,
+    children_query: Query<&Children>,
+    discovered: Res<DiscoveredSpecies>,
+    education_data: Res<BirdEducationData>,
+    photo_collection: Res<PhotoCollection>,
+    research_manager: Res<ResearchMissionManager>,
+    achievement_progress: Res<AchievementProgress>,
+    wildlife_log: Res<OtherWildlifeLog>,
+    clip_library: Res<ClipLibrary>,
+    // Grouped to keep the system within Bevy's parameter limit
+    (time_state, irruptions, milestones, smart_collections): (Res<TimeState>, Res<Irruptions>, Res<MilestoneLog>, Res<SmartCollections>),
+    sighting_log: Res<SightingLog>,
+    garden_styles: Res<GardenStyles>,
+    field_notes: Res<FieldNotes>,
+) {
+    if journal_state.is_changed() && journal_state.is_open {
+        debug!("Journal state changed, regenerating content");
+        
+        if let Ok(journal_entity) = journal_query.single() {
+            regenerate_journal_content(
+                &mut commands,
+                journal_entity,
+                &children_query,
+                &content_query,
+                &journal_state,
+                &discovered,
+                &education_data,
+                &photo_collection,
+                &research_manager,
+                &achievement_progress,
+                &wildlife_log,
+                &clip_library,
+                &time_state,
+                &sighting_log,
+                &garden_styles,
+                &field_notes,
+                &irruptions,
+                &milestones,
+                &smart_collections,
+            );
+        }
+    }
+}
+
+pub fn update_journal_on_discovery_system(
+    mut discovered: ResMut<DiscoveredSpecies>,
+    mut photo_events: EventReader<PhotoTakenEvent>,
+) {
+    for photo_event in photo_events.read() {
+        if let Some(species) = photo_event.species {
+            discovered.0.insert(species);
+        }
+    }
+}
+
+pub fn journal_interaction_system(
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<CloseButton>)>,
+    mut next_state: ResMut<NextState<crate::AppState>>,
+    mut journal_state: ResMut<JournalState>,
+) {
+    for interaction in interaction_query.iter() {
+        if *interaction == Interaction::Pressed {
+            journal_state.is_open = false;
+            next_state.set(crate::AppState::Playing);
+        }
+    }
+}
+
+// Research missions setup system
+pub fn setup_research_missions(mut research_manager: ResMut<ResearchMissionManager>) {
+    research_manager.active_missions = ResearchMissionManager::generate_starter_missions();
+    debug!("Initialized {} research missions", research_manager.active_missions.len());
+}
+
+// Helper function to regenerate journal content when switching tabs
+fn regenerate_journal_content(
+    commands: &mut Commands,
+    journal_entity: Entity,
+    children_query: &Query<&Children>,
+    content_query: &Query<Entity, With<JournalTabContent>>,
+    journal_state: &JournalState,
+    discovered: &DiscoveredSpecies,
+    education_data: &BirdEducationData,
+    photo_collection: &PhotoCollection,
+    research_manager: &ResearchMissionManager,
+    achievement_progress: &AchievementProgress,
+    wildlife_log: &OtherWildlifeLog,
+    clip_library: &ClipLibrary,
+    time_state: &TimeState,
+    sighting_log: &SightingLog,
+    garden_styles: &GardenStyles,
+    field_notes: &FieldNotes,
+    irruptions: &Irruptions,
+    milestones: &MilestoneLog,
+    smart_collections: &SmartCollections,
+) {
+    // Find the existing content area and clear its children instead of despawning it
+    if let Ok(journal_children) = children_query.get(journal_entity) {
+        // Find the content area (it should be the third child after header and tabs)
+        for child in journal_children.iter() {
+            if content_query.contains(child) {
+                // Clear existing children of the content area
+                if let Ok(content_children) = children_query.get(child) {
+                    for content_child in content_children.iter() {
+                        commands.entity(content_child).safe_despawn();
+                    }
+                }
+                
+                // Add new content to the existing content area
+                commands.entity(child).with_children(|content| {
+                    match journal_state.current_tab {
+                        JournalTab::Species => {
+                            content.spawn((
+                                Text::new(format!("Species discovered: {}\nEducational data loaded: {}", 
+                                    discovered.0.len(), education_data.species_facts.len())),
+                                TextFont { font_size: 16.0, ..default() },
+                                TextColor(Color::srgb(0.3, 0.2, 0.1)),
+                            ));
+                        },
+                        JournalTab::Photos => {
+                            content.spawn((
+                                Text::new(format!("Photos: {}", photo_collection.photos.len())),
+                                TextFont { font_size: 16.0, ..default() },
+                                TextColor(Color::srgb(0.3, 0.2, 0.1)),
+                            ));
+                            spawn_photo_gallery(content, photo_collection, journal_state, smart_collections);
+                        },
+                        JournalTab::Research => {
+                            content.spawn((
+                                Text::new(format!("Research missions: {} active, {} completed", 
+                                    research_manager.active_missions.len(), research_manager.completed_missions.len())),
+                                TextFont { font_size: 16.0, ..default() },
+                                TextColor(Color::srgb(0.3, 0.2, 0.1)),
+                            ));
+                        },
+                        JournalTab::Achievements => {
+                            content.spawn((
+                                Text::new(format!("Achievements unlocked: {}/11\nPhotos taken: {}", 
+                                    achievement_progress.unlocked.len(), achievement_progress.photos_taken)),
+                                TextFont { font_size: 16.0, ..default() },
+                                TextColor(Color::srgb(0.3, 0.2, 0.1)),
+                            ));
+                            spawn_style_badges(content, garden_styles);
+                        },
+                        JournalTab::Conservation => {
+                            content.spawn((
+                                Text::new(format!("Conservation data for {} species", discovered.0.len())),
+                                TextFont { font_size: 16.0, ..default() },
+                                TextColor(Color::srgb(0.3, 0.2, 0.1)),
+                            ));
+                        },
+                        JournalTab::Migration => {
+                            spawn_migration_maps(content, discovered, education_data, time_state);
+                            spawn_irruption_log(content, irruptions);
+                        },
+                        JournalTab::Checklists => {
+                            spawn_sighting_calendar(content, sighting_log, journal_state, time_state);
+                        },
+                        JournalTab::Notes => {
+                            spawn_field_notes(content, field_notes, journal_state, discovered, photo_collection, education_data);
+                        },
+                        JournalTab::Timeline => {
+                            spawn_milestone_timeline(content, milestones, photo_collection, education_data);
+                        },
+                        JournalTab::Clips => {
+                            content.spawn((
+                                Text::new(format!("Saved clips: {}", clip_library.clips.len())),
+                                TextFont { font_size: 16.0, ..default() },
+                                TextColor(Color::srgb(0.3, 0.2, 0.1)),
+                            ));
+                        },
+                        JournalTab::Wildlife => {
+                            let photographed = wildlife_log.entries.values().filter(|entry| entry.photos > 0).count();
+                            content.spawn((
+                                Text::new(format!("Other wildlife photographed: {}/{}", photographed, WildlifeSpecies::ALL.len())),
+                                TextFont { font_size: 16.0, ..default() },
+                                TextColor(Color::srgb(0.3, 0.2, 0.1)),
+                            ));
+                        },
+                    }
+                });
+                break;
+            }
+        }
+    }
+}
+
+const MIGRATION_MAP_SIZE: Vec2 = Vec2::new(200.0, 110.0);
+const MIGRATION_MARKER_SIZE: f32 = 12.0;
+
+// Route from the wintering grounds (bottom left) to the breeding grounds (top right),
+// bowed so it reads as a flyway rather than a straight line
+fn migration_route_point(t: f32) -> Vec2 {
+    let south = Vec2::new(28.0, 88.0);
+    let north = Vec2::new(172.0, 22.0);
+    let bow = (north - south).perp().normalize() * 18.0 * (t * std::f32::consts::PI).sin();
+    south.lerp(north, t) - bow
+}
+
+// Seasonal route map for each discovered migratory species, with the population
+// placed where it is on the current in-game date
+fn spawn_migration_maps(
+    parent: &mut ChildSpawnerCommands,
+    discovered: &DiscoveredSpecies,
+    education_data: &BirdEducationData,
+    time_state: &TimeState,
+) {
+    let mut migratory: Vec<_> = discovered.0.iter()
+        .filter_map(|species| education_data.migration_data.get(species).map(|data| (*species, data)))
+        .filter(|(_, data)| data.is_migratory)
+        .collect();
+    migratory.sort_by_key(|(species, _)| format!("{:?}", species));
+    let resident_count = discovered.0.len() - migratory.len();
+
+    parent.spawn((
+        Text::new(format!("Day {} ({:?}) | Migratory: {} | Resident: {}",
+            time_state.day_of_year, time_state.get_season(), migratory.len(), resident_count)),
+        TextFont { font_size: 14.0, ..default() },
+        TextColor(Color::srgb(0.4, 0.3, 0.2)),
+    ));
+
+    if migratory.is_empty() {
+        parent.spawn((
+            Text::new("Discover a migratory species to chart its seasonal route."),
+            TextFont { font_size: 14.0, ..default() },
+            TextColor(Color::srgb(0.6, 0.5, 0.4)),
+        ));
+        return;
+    }
+
+    parent.spawn((
+        Node {
+            width: Val::Percent(100.0),
+            flex_direction: FlexDirection::Row,
+            flex_wrap: FlexWrap::Wrap,
+            column_gap: Val::Px(12.0),
+            row_gap: Val::Px(12.0),
+            ..default()
+        },
+    )).with_children(|grid| {
+        for (species, data) in migratory {
+            let (progress, phase) = data.route_position(time_state.day_of_year);
+            let heading = match phase {
+                MigrationPhase::Northbound => 1.0,
+                MigrationPhase::Southbound => -1.0,
+                MigrationPhase::Wintering | MigrationPhase::Breeding => 0.0,
+            };
+
+            let mut status = phase.label().to_string();
+            if let (Some(distance), true) = (data.migration_distance, heading != 0.0) {
+                let remaining = if heading > 0.0 { 1.0 - progress } else { progress };
+                status = format!("{} - about {:.0} km to go", status, distance * remaining);
+            }
+
+            grid.spawn((
+                Node {
+                    width: Val::Px(440.0),
+                    flex_direction: FlexDirection::Row,
+                    column_gap: Val::Px(12.0),
+                    padding: UiRect::all(Val::Px(10.0)),
+                    border: UiRect::all(Val::Px(1.0)),
+                    ..default()
+                },
+                BackgroundColor(Color::srgb(0.98, 0.95, 0.92)),
+                BorderColor(Color::srgb(0.8, 0.7, 0.6)),
+            )).with_children(|card| {
+                card.spawn((
+                    Node {
+                        width: Val::Px(MIGRATION_MAP_SIZE.x),
+                        height: Val::Px(MIGRATION_MAP_SIZE.y),
+                        flex_shrink: 0.0,
+                        overflow: Overflow::clip(),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.82, 0.88, 0.8)),
+                    BorderRadius::all(Val::Px(4.0)),
+                    MigrationMap,
+                )).with_children(|map| {
+                    for step in 1..12 {
+                        let point = migration_route_point(step as f32 / 12.0);
+                        map.spawn((
+                            Node {
+                                position_type: PositionType::Absolute,
+                                left: Val::Px(point.x - 2.0),
+                                top: Val::Px(point.y - 2.0),
+                                width: Val::Px(4.0),
+                                height: Val::Px(4.0),
+                                ..default()
+                            },
+                            BackgroundColor(Color::srgba(0.4, 0.3, 0.2, 0.5)),
+                            BorderRadius::all(Val::Px(2.0)),
+                        ));
+                    }
+
+                    for (t, color, label) in [
+                        (0.0, Color::srgb(0.85, 0.55, 0.2), "Winter"),
+                        (1.0, Color::srgb(0.3, 0.6, 0.3), "Breeding"),
+                    ] {
+                        let point = migration_route_point(t);
+                        map.spawn((
+                            Node {
+                                position_type: PositionType::Absolute,
+                                left: Val::Px(point.x - 5.0),
+                                top: Val::Px(point.y - 5.0),
+                                width: Val::Px(10.0),
+                                height: Val::Px(10.0),
+                                ..default()
+                            },
+                            BackgroundColor(color),
+                            BorderRadius::all(Val::Px(5.0)),
+                        ));
+                        map.spawn((
+                            Text::new(label),
+                            TextFont { font_size: 10.0, ..default() },
+                            TextColor(Color::srgb(0.3, 0.2, 0.1)),
+                            Node {
+                                position_type: PositionType::Absolute,
+                                left: Val::Px((point.x - 24.0).clamp(2.0, MIGRATION_MAP_SIZE.x - 50.0)),
+                                top: Val::Px(if t == 0.0 { point.y + 6.0 } else { point.y - 18.0 }),
+                                ..default()
+                            },
+                        ));
+                    }
+
+                    if heading != 0.0 {
+                        map.spawn((
+                            Node {
+                                position_type: PositionType::Absolute,
+                                width: Val::Px(6.0),
+                                height: Val::Px(6.0),
+                                ..default()
+                            },
+                            BackgroundColor(Color::srgba(0.7, 0.15, 0.1, 0.8)),
+                            BorderRadius::all(Val::Px(3.0)),
+                            MigrationTrail { progress, heading },
+                        ));
+                    }
+
+                    let point = migration_route_point(progress);
+                    map.spawn((
+                        Node {
+                            position_type: PositionType::Absolute,
+                            left: Val::Px(point.x - MIGRATION_MARKER_SIZE / 2.0),
+                            top: Val::Px(point.y - MIGRATION_MARKER_SIZE / 2.0),
+                            width: Val::Px(MIGRATION_MARKER_SIZE),
+                            height: Val::Px(MIGRATION_MARKER_SIZE),
+                            border: UiRect::all(Val::Px(2.0)),
+                            ..default()
+                        },
+                        BackgroundColor(Color::srgb(0.7, 0.15, 0.1)),
+                        BorderColor(Color::WHITE),
+                        BorderRadius::all(Val::Px(MIGRATION_MARKER_SIZE)),
+                        MigrationMarker { progress },
+                    ));
+                });
+
+                card.spawn((
+                    Node {
+                        flex_direction: FlexDirection::Column,
+                        row_gap: Val::Px(3.0),
+                        ..default()
+                    },
+                )).with_children(|details| {
+                    details.spawn((
+                        Text::new(format!("{:?}", species)),
+                        TextFont { font_size: 15.0, ..default() },
+                        TextColor(Color::srgb(0.2, 0.1, 0.05)),
+                    ));
+                    details.spawn((
+                        Text::new(status),
+                        TextFont { font_size: 12.0, ..default() },
+                        TextColor(Color::srgb(0.7, 0.15, 0.1)),
+                    ));
+                    for line in [
+                        format!("Breeds: {}", data.breeding_range),
+                        format!("Winters: {}", data.wintering_range),
+                        data.migration_timing.clone(),
+                    ] {
+                        details.spawn((
+                            Text::new(line),
+                            TextFont { font_size: 11.0, ..default() },
+                            TextColor(Color::srgb(0.4, 0.3, 0.2)),
+                        ));
+                    }
+                });
+            });
+        }
+    });
+}
+
+// Irruption years the sanctuary has seen, newest first
+fn spawn_irruption_log(parent: &mut ChildSpawnerCommands, irruptions: &Irruptions) {
+    parent.spawn((
+        Text::new("Irruption Years"),
+        TextFont { font_size: 16.0, ..default() },
+        TextColor(Color::srgb(0.3, 0.2, 0.1)),
+        Node {
+            margin: UiRect::top(Val::Px(15.0)),
+            ..default()
+        },
+    ));
+
+    let observed: Vec<_> = irruptions.observed().collect();
+    if observed.is_empty() {
+        parent.spawn((
+            Text::new("No irruptions yet. In poor seed years up north, winter finches can flood south for weeks."),
+            TextFont { font_size: 14.0, ..default() },
+            TextColor(Color::srgb(0.6, 0.5, 0.4)),
+        ));
+        return;
+    }
+
+    let ongoing = irruptions.active.is_some();
+    for (index, record) in observed.iter().enumerate().rev() {
+        let status = if ongoing && index == observed.len() - 1 { " (ongoing)" } else { "" };
+        parent.spawn((
+            Node {
+                width: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(10.0)),
+                row_gap: Val::Px(4.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.9, 0.9, 0.9, 0.5)),
+        )).with_children(|card| {
+            card.spawn((
+                Text::new(format!("Winter of year {}{}: {}", record.year, status, record.species_names())),
+                TextFont { font_size: 14.0, ..default() },
+                TextColor(Color::srgb(0.3, 0.2, 0.1)),
+            ));
+            card.spawn((
+                Text::new(format!("From day {} for {} weeks | Strength {:.0}% | Arrivals: {}",
+                    record.start_day, record.days / 7, record.strength * 100.0, record.arrivals)),
+                TextFont { font_size: 12.0, ..default() },
+                TextColor(Color::srgb(0.4, 0.3, 0.2)),
+            ));
+        });
+    }
+}
+
+// Pulses each population marker and sends its trail looping toward the destination
+pub fn animate_migration_markers_system(
+    time: Res<Time>,
+    mut marker_query: Query<(&MigrationMarker, &mut Node), Without<MigrationTrail>>,
+    mut trail_query: Query<(&MigrationTrail, &mut Node, &mut BackgroundColor), Without<MigrationMarker>>,
+) {
+    let elapsed = time.elapsed_secs();
+
+    let size = MIGRATION_MARKER_SIZE + 3.0 * (elapsed * 3.0).sin();
+    for (marker, mut node) in &mut marker_query {
+        let point = migration_route_point(marker.progress);
+        node.left = Val::Px(point.x - size / 2.0);
+        node.top = Val::Px(point.y - size / 2.0);
+        node.width = Val::Px(size);
+        node.height = Val::Px(size);
+    }
+
+    let cycle = (elapsed / 1.5).fract();
+    for (trail, mut node, mut color) in &mut trail_query {
+        let point = migration_route_point((trail.progress + trail.heading * 0.25 * cycle).clamp(0.0, 1.0));
+        node.left = Val::Px(point.x - 3.0);
+        node.top = Val::Px(point.y - 3.0);
+        color.0 = color.0.with_alpha(0.8 * (1.0 - cycle));
+    }
+}
+
+const SONOGRAM_WIDTH: f32 = 480.0;
+const SONOGRAM_HEIGHT: f32 = 128.0;
+const SONOGRAM_PIXELS_PER_SECOND: f32 = 160.0;
+const SONOGRAM_PLAYHEAD: f32 = 120.0;
+
+pub fn play_species_call_system(
+    mut commands: Commands,
+    interaction_query: Query<(&Interaction, &PlayCallButton), Changed<Interaction>>,
+    panel_query: Query<Entity, With<SpectrogramPanel>>,
+    mut view: ResMut<SpectrogramView>,
+    asset_server: Res<AssetServer>,
+    species_packs: Res<crate::species_packs::SpeciesPackManager>,
+) {
+    for (interaction, button) in &interaction_query {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        stop_species_call(&mut commands, &mut view, &panel_query);
+
+        let path = species_packs.call_sound(button.species, "Song")
+            .unwrap_or_else(|| crate::audio::systems::get_species_sound_path(button.species, crate::audio::systems::CallType::Song))
+            .to_string();
+        let recording: Handle<bevy::audio::AudioSource> = asset_server.load(path);
+        view.player = Some(commands.spawn((
+            AudioPlayer::new(recording.clone()),
+            PlaybackSettings::DESPAWN,
+        )).id());
+        view.species = Some(button.species);
+        view.recording = recording;
+    }
+}
+
+fn stop_species_call(
+    commands: &mut Commands,
+    view: &mut SpectrogramView,
+    panel_query: &Query<Entity, With<SpectrogramPanel>>,
+) {
+    if let Some(player) = view.player.take() {
+        commands.entity(player).try_despawn();
+    }
+    for panel in panel_query.iter() {
+        commands.entity(panel).safe_despawn();
+    }
+    *view = SpectrogramView::default();
+}
+
+// Spreads a mnemonic's syllables over the song's notes, or evenly when too few notes were picked out
+fn syllable_times(onsets: &[f32], syllables: usize, duration: f32) -> Vec<f32> {
+    if onsets.len() >= syllables {
+        return (0..syllables).map(|index| onsets[index * onsets.len() / syllables]).collect();
+    }
+    let start = onsets.first().copied().unwrap_or(0.0);
+    let step = (duration - start).max(0.0) / syllables.max(1) as f32;
+    (0..syllables).map(|index| start + index as f32 * step).collect()
+}
+
+// Runs the FFT once the recording has loaded and opens the sonogram beside the playback
+#[allow(clippy::too_many_arguments)]
+pub fn build_spectrogram_panel_system(
+    mut commands: Commands,
+    mut view: ResMut<SpectrogramView>,
+    audio_assets: Res<Assets<bevy::audio::AudioSource>>,
+    mut images: ResMut<Assets<Image>>,
+    asset_server: Res<AssetServer>,
+    education_data: Res<BirdEducationData>,
+    bird_data: Res<crate::bird_data::BirdDataRegistry>,
+    journal_query: Query<Entity, With<JournalMenu>>,
+) {
+    let Some(species) = view.species else { return };
+    if view.image.is_some() {
+        return;
+    }
+
+    let Some(source) = audio_assets.get(&view.recording) else {
+        if asset_server.get_load_state(&view.recording).is_some_and(|state| state.is_failed()) {
+            warn!("No recording available for {:?}, skipping sonogram", species);
+            *view = SpectrogramView::default();
+        }
+        return;
+    };
+    let Ok(journal_entity) = journal_query.single() else { return };
+
+    let spectrogram = crate::audio::spectrogram::Spectrogram::analyze(source);
+    let image = images.add(spectrogram.to_image());
+    view.image = Some(image.clone());
+    view.duration = spectrogram.duration();
+    view.max_frequency = spectrogram.max_frequency;
+    view.elapsed = 0.0;
+
+    let syllables: Vec<String> = bird_data.get_mnemonic(&species, "Song")
+        .map(|phrase| crate::bird_data::mnemonic_syllables(phrase).into_iter().map(str::to_string).collect())
+        .unwrap_or_default();
+    view.syllable_times = syllable_times(&spectrogram.note_onsets(), syllables.len(), spectrogram.duration());
+
+    let name = education_data.species_facts.get(&species)
+        .map(|facts| facts.common_name.clone())
+        .unwrap_or_else(|| format!("{:?}", species));
+
+    commands.entity(journal_entity).with_children(|journal| {
+        journal.spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                right: Val::Px(20.0),
+                bottom: Val::Px(20.0),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(8.0),
+                padding: UiRect::all(Val::Px(12.0)),
+                border: UiRect::all(Val::Px(2.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.95, 0.92, 0.88, 0.98)),
+            BorderColor(Color::srgb(0.7, 0.6, 0.5)),
+            BorderRadius::all(Val::Px(8.0)),
+            SpectrogramPanel,
+        )).with_children(|panel| {
+            panel.spawn((
+                Node {
+                    width: Val::Percent(100.0),
+                    flex_direction: FlexDirection::Row,
+                    justify_content: JustifyContent::SpaceBetween,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+            )).with_children(|header| {
+                header.spawn((
+                    Text::new(format!("Sonogram - {} song", name)),
+                    TextFont { font_size: 16.0, ..default() },
+                    TextColor(Color::srgb(0.3, 0.2, 0.1)),
+                ));
+                header.spawn((
+                    Button,
+                    Node {
+                        width: Val::Px(24.0),
+                        height: Val::Px(24.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.8, 0.4, 0.4)),
+                    SpectrogramCloseButton,
+                )).with_children(|button| {
+                    button.spawn((
+                        Text::new("x"),
+                        TextFont { font_size: 14.0, ..default() },
+                        TextColor(Color::WHITE),
+                    ));
+                });
+            });
+
+            panel.spawn((
+                Node {
+                    flex_direction: FlexDirection::Row,
+                    column_gap: Val::Px(6.0),
+                    ..default()
+                },
+            )).with_children(|row| {
+                // Frequency axis
+                row.spawn((
+                    Node {
+                        height: Val::Px(SONOGRAM_HEIGHT),
+                        flex_direction: FlexDirection::Column,
+                        justify_content: JustifyContent::SpaceBetween,
+                        align_items: AlignItems::End,
+                        ..default()
+                    },
+                )).with_children(|axis| {
+                    for fraction in [1.0, 0.5, 0.0] {
+                        axis.spawn((
+                            Text::new(format!("{:.1} kHz", spectrogram.max_frequency * fraction / 1000.0)),
+                            TextFont { font_size: 10.0, ..default() },
+                            TextColor(Color::srgb(0.5, 0.4, 0.3)),
+                        ));
+                    }
+                });
+
+                row.spawn((
+                    Node {
+                        width: Val::Px(SONOGRAM_WIDTH),
+                        height: Val::Px(SONOGRAM_HEIGHT),
+                        border: UiRect::all(Val::Px(1.0)),
+                        overflow: Overflow::clip(),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.96, 0.93, 0.87)),
+                    BorderColor(Color::srgb(0.6, 0.5, 0.4)),
+                )).with_children(|viewport| {
+                    viewport.spawn((
+                        ImageNode::new(image),
+                        Node {
+                            position_type: PositionType::Absolute,
+                            left: Val::Px(SONOGRAM_PLAYHEAD),
+                            top: Val::Px(0.0),
+                            width: Val::Px((spectrogram.duration() * SONOGRAM_PIXELS_PER_SECOND).max(1.0)),
+                            height: Val::Percent(100.0),
+                            ..default()
+                        },
+                        SpectrogramScroll,
+                    ));
+
+                    viewport.spawn((
+                        Node {
+                            position_type: PositionType::Absolute,
+                            left: Val::Px(SONOGRAM_PLAYHEAD),
+                            top: Val::Px(0.0),
+                            width: Val::Px(2.0),
+                            height: Val::Percent(100.0),
+                            ..default()
+                        },
+                        BackgroundColor(Color::srgba(0.8, 0.2, 0.15, 0.8)),
+                    ));
+                });
+            });
+
+            if !syllables.is_empty() {
+                panel.spawn((
+                    Node {
+                        flex_direction: FlexDirection::Row,
+                        flex_wrap: FlexWrap::Wrap,
+                        align_items: AlignItems::Baseline,
+                        column_gap: Val::Px(6.0),
+                        ..default()
+                    },
+                )).with_children(|phrase| {
+                    phrase.spawn((
+                        Text::new("Remember it as"),
+                        TextFont { font_size: 12.0, ..default() },
+                        TextColor(Color::srgb(0.5, 0.4, 0.3)),
+                    ));
+                    for (index, syllable) in syllables.iter().enumerate() {
+                        phrase.spawn((
+                            Text::new(syllable.clone()),
+                            TextFont { font_size: 18.0, ..default() },
+                            TextColor(MNEMONIC_UPCOMING),
+                            MnemonicSyllable { index },
+                        ));
+                    }
+                });
+            }
+
+            panel.spawn((
+                Text::new("Time runs left to right and pitch rises upward. Each dark mark is a note:\na slanted line is a slurred whistle, a stack of short ticks is a trill,\nand a tall smudge is a harsh, noisy call."),
+                TextFont { font_size: 11.0, ..default() },
+                TextColor(Color::srgb(0.5, 0.4, 0.3)),
+            ));
+        });
+    });
+}
+
+// Slides the sonogram under the playhead in step with the recording
+pub fn scroll_spectrogram_system(
+    time: Res<Time>,
+    mut view: ResMut<SpectrogramView>,
+    mut scroll_query: Query<&mut Node, With<SpectrogramScroll>>,
+) {
+    if view.image.is_none() || view.elapsed >= view.duration {
+        return;
+    }
+    view.elapsed = (view.elapsed + time.delta_secs()).min(view.duration);
+
+    for mut node in &mut scroll_query {
+        node.left = Val::Px(SONOGRAM_PLAYHEAD - view.elapsed * SONOGRAM_PIXELS_PER_SECOND);
+    }
+}
+
+const MNEMONIC_UPCOMING: Color = Color::srgb(0.7, 0.62, 0.55);
+const MNEMONIC_SUNG: Color = Color::srgb(0.3, 0.2, 0.1);
+const MNEMONIC_CURRENT: Color = Color::srgb(0.8, 0.3, 0.1);
+
+// Lights each mnemonic syllable as the note it stands for plays
+pub fn highlight_mnemonic_system(
+    view: Res<SpectrogramView>,
+    mut syllable_query: Query<(&mut TextColor, &MnemonicSyllable)>,
+) {
+    let sung = view.syllable_times.iter().filter(|time| view.elapsed >= **time).count();
+    let finished = view.elapsed >= view.duration;
+
+    for (mut color, syllable) in &mut syllable_query {
+        let target = if syllable.index >= sung {
+            MNEMONIC_UPCOMING
+        } else if syllable.index + 1 == sung && !finished {
+            MNEMONIC_CURRENT
+        } else {
+            MNEMONIC_SUNG
+        };
+        if color.0 != target {
+            color.0 = target;
+        }
+    }
+}
+
+pub fn spectrogram_close_system(
+    mut commands: Commands,
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<SpectrogramCloseButton>)>,
+    panel_query: Query<Entity, With<SpectrogramPanel>>,
+    mut view: ResMut<SpectrogramView>,
+) {
+    if interaction_query.iter().any(|interaction| *interaction == Interaction::Pressed) {
+        stop_species_call(&mut commands, &mut view, &panel_query);
+    }
+}
+
+// The panel goes with the journal, but the recording has to be stopped separately
+pub fn stop_species_call_system(
+    mut commands: Commands,
+    panel_query: Query<Entity, With<SpectrogramPanel>>,
+    mut view: ResMut<SpectrogramView>,
+) {
+    stop_species_call(&mut commands, &mut view, &panel_query);
+}
+
+const SIGHTING_SAMPLE_SECONDS: f32 = 2.0;
+
+// Tallies the birds currently in the sanctuary onto today's checklist
+pub fn sighting_log_system(
+    bird_query: Query<&Bird>,
+    mut sighting_log: ResMut<SightingLog>,
+    time_state: Res<TimeState>,
+    time: Res<Time>,
+    mut since_sample: Local<f32>,
+) {
+    *since_sample += time.delta_secs();
+    if *since_sample < SIGHTING_SAMPLE_SECONDS {
+        return;
+    }
+    *since_sample = 0.0;
+
+    let mut counts: std::collections::HashMap<crate::bird::BirdSpecies, u32> = std::collections::HashMap::new();
+    for bird in &bird_query {
+        *counts.entry(bird.species).or_default() += 1;
+    }
+
+    let date = ChecklistDate { year: time_state.year, day_of_year: time_state.day_of_year };
+    for (species, count) in counts {
+        sighting_log.record(date, time_state.hour, species, count);
+    }
+}
+
+pub fn journal_calendar_system(
+    interaction_query: Query<
+        (&Interaction, Option<&CalendarDayButton>, Option<&CalendarMonthButton>),
+        (Changed<Interaction>, With<Button>),
+    >,
+    mut journal_state: ResMut<JournalState>,
+    time_state: Res<TimeState>,
+) {
+    for (interaction, day_button, month_button) in &interaction_query {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        if let Some(day_button) = day_button {
+            journal_state.selected_checklist = Some(day_button.date);
+        }
+
+        if let Some(month_button) = month_button {
+            let (year, month) = shown_calendar_month(&journal_state, &time_state);
+            let index = (year as i32 * 12 + month as i32 + month_button.step).max(12);
+            journal_state.calendar_month = Some(((index / 12) as u32, (index % 12) as usize));
+        }
+    }
+}
+
+fn shown_calendar_month(journal_state: &JournalState, time_state: &TimeState) -> (u32, usize) {
+    journal_state.calendar_month.unwrap_or_else(|| {
+        let today = ChecklistDate { year: time_state.year, day_of_year: time_state.day_of_year };
+        (today.year, today.month())
+    })
+}
+
+pub fn format_game_hour(hour: f32) -> String {
+    let minutes = (hour * 60.0).round() as u32 % (24 * 60);
+    format!("{:02}:{:02}", minutes / 60, minutes % 60)
+}
+
+// Month calendar shaded by species count, with the chosen day's checklist underneath
+fn spawn_sighting_calendar(
+    parent: &mut ChildSpawnerCommands,
+    sighting_log: &SightingLog,
+    journal_state: &JournalState,
+    time_state: &TimeState,
+) {
+    let today = ChecklistDate { year: time_state.year, day_of_year: time_state.day_of_year };
+    let (year, month) = shown_calendar_month(journal_state, time_state);
+    let selected = journal_state.selected_checklist.unwrap_or(today);
+
+    let year_species: std::collections::HashSet<_> = sighting_log.checklists.iter()
+        .filter(|(date, _)| date.year == year)
+        .flat_map(|(_, checklist)| checklist.species.keys())
+        .collect();
+
+    parent.spawn((
+        Node {
+            width: Val::Px(7.0 * 58.0),
+            flex_direction: FlexDirection::Row,
+            justify_content: JustifyContent::SpaceBetween,
+            align_items: AlignItems::Center,
+            ..default()
+        },
+    )).with_children(|header| {
+        for (step, label) in [(-1, "<"), (1, ">")] {
+            if step > 0 {
+                header.spawn((
+                    Text::new(format!("{} - Year {}", MONTH_NAMES[month], year)),
+                    TextFont { font_size: 16.0, ..default() },
+                    TextColor(Color::srgb(0.3, 0.2, 0.1)),
+                ));
+            }
+            header.spawn((
+                Button,
+                Node {
+                    width: Val::Px(28.0),
+                    height: Val::Px(24.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                BackgroundColor(Color::srgb(0.6, 0.5, 0.4)),
+                BorderRadius::all(Val::Px(4.0)),
+                CalendarMonthButton { step },
+            )).with_children(|button| {
+                button.spawn((
+                    Text::new(label),
+                    TextFont { font_size: 14.0, ..default() },
+                    TextColor(Color::WHITE),
+                ));
+            });
+        }
+    });
+
+    parent.spawn((
+        Text::new(format!("{} checklists | {} species recorded in Year {}",
+            sighting_log.checklists.keys().filter(|date| date.year == year).count(), year_species.len(), year)),
+        TextFont { font_size: 12.0, ..default() },
+        TextColor(Color::srgb(0.5, 0.4, 0.3)),
+    ));
+
+    parent.spawn((
+        Node {
+            width: Val::Px(7.0 * 58.0),
+            flex_direction: FlexDirection::Row,
+            flex_wrap: FlexWrap::Wrap,
+            column_gap: Val::Px(4.0),
+            row_gap: Val::Px(4.0),
+            ..default()
+        },
+    )).with_children(|grid| {
+        for day in 1..=days_in_month(month) {
+            let date = ChecklistDate { year, day_of_year: MONTH_STARTS[month] + day - 1 };
+            let species_count = sighting_log.checklist(date).map_or(0, |checklist| checklist.species.len());
+            let shade = (species_count as f32 / 10.0).min(1.0);
+            let background = if species_count == 0 {
+                Color::srgb(0.9, 0.87, 0.83)
+            } else {
+                Color::srgb(0.85 - 0.45 * shade, 0.9 - 0.25 * shade, 0.75 - 0.4 * shade)
+            };
+            let border = if date == selected {
+                Color::srgb(0.3, 0.2, 0.1)
+            } else if date == today {
+                Color::srgb(0.8, 0.3, 0.2)
+            } else {
+                Color::srgb(0.75, 0.7, 0.65)
+            };
+
+            grid.spawn((
+                Button,
+                Node {
+                    width: Val::Px(54.0),
+                    height: Val::Px(44.0),
+                    flex_direction: FlexDirection::Column,
+                    justify_content: JustifyContent::SpaceBetween,
+                    padding: UiRect::all(Val::Px(4.0)),
+                    border: UiRect::all(Val::Px(2.0)),
+                    ..default()
+                },
+                BackgroundColor(background),
+                BorderColor(border),
+                CalendarDayButton { date },
+            )).with_children(|cell| {
+                cell.spawn((
+                    Text::new(day.to_string()),
+                    TextFont { font_size: 12.0, ..default() },
+                    TextColor(Color::srgb(0.3, 0.2, 0.1)),
+                ));
+                if species_count > 0 {
+                    cell.spawn((
+                        Text::new(format!("{} sp", species_count)),
+                        TextFont { font_size: 10.0, ..default() },
+                        TextColor(Color::srgb(0.2, 0.3, 0.15)),
+                    ));
+                }
+            });
+        }
+    });
+
+    parent.spawn((
+        Text::new(format!("Checklist - {}", selected.label())),
+        TextFont { font_size: 16.0, ..default() },
+        TextColor(Color::srgb(0.3, 0.2, 0.1)),
+        Node {
+            margin: UiRect::top(Val::Px(8.0)),
+            ..default()
+        },
+    ));
+
+    let Some(checklist) = sighting_log.checklist(selected) else {
+        parent.spawn((
+            Text::new("No sightings recorded on this day."),
+            TextFont { font_size: 13.0, ..default() },
+            TextColor(Color::srgb(0.6, 0.5, 0.4)),
+        ));
+        return;
+    };
+
+    for (species, tally) in checklist.sorted() {
+        parent.spawn((
+            Text::new(format!("{:?}  -  max {}  |  first {}  |  last {}",
+                species, tally.max_count, format_game_hour(tally.first_seen), format_game_hour(tally.last_seen))),
+            TextFont { font_size: 13.0, ..default() },
+            TextColor(Color::srgb(0.4, 0.3, 0.2)),
+        ));
+    }
+}
+
+const MAX_SEARCH_LENGTH: usize = 40;
+
+pub fn journal_notes_button_system(
+    interaction_query: Query<
+        (&Interaction, Option<&NoteEntryButton>, Option<&NoteTagChip>, Has<NoteSearchField>, Has<NoteTextField>, Has<NoteTagField>),
+        (Changed<Interaction>, With<Button>),
+    >,
+    mut journal_state: ResMut<JournalState>,
+    mut field_notes: ResMut<FieldNotes>,
+    photo_collection: Res<PhotoCollection>,
+) {
+    for (interaction, entry, chip, search_field, text_field, tag_field) in &interaction_query {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        if let Some(entry) = entry {
+            journal_state.note_target = if journal_state.note_target == Some(entry.target) { None } else { Some(entry.target) };
+            journal_state.note_input = None;
+            journal_state.tag_draft.clear();
+        }
+
+        if let Some(chip) = chip {
+            match chip.remove_from {
+                Some(target) => {
+                    if let Some(notes) = field_notes.notes_mut(target, &photo_collection) {
+                        notes.remove_tag(&chip.tag);
+                    }
+                    field_notes.prune();
+                    journal_state.set_changed();
+                }
+                None => {
+                    journal_state.note_search = format!("#{}", chip.tag);
+                    journal_state.note_input = None;
+                }
+            }
+        }
+
+        if search_field {
+            journal_state.note_input = Some(NoteInput::Search);
+        } else if text_field {
+            journal_state.note_input = Some(NoteInput::Text);
+        } else if tag_field {
+            journal_state.note_input = Some(NoteInput::Tag);
+        }
+    }
+}
+
+// Typing into whichever of the search bar, note or new tag field was clicked
+pub fn journal_notes_input_system(
+    mut keyboard_events: EventReader<KeyboardInput>,
+    mut journal_state: ResMut<JournalState>,
+    mut field_notes: ResMut<FieldNotes>,
+    photo_collection: Res<PhotoCollection>,
+) {
+    if journal_state.note_input.is_none() {
+        keyboard_events.clear();
+        return;
+    }
+
+    let mut changed = false;
+    for event in keyboard_events.read() {
+        if event.state != ButtonState::Pressed {
+            continue;
+        }
+        let state = journal_state.bypass_change_detection();
+        let Some(input) = state.note_input else { break };
+        changed = true;
+
+        match (&event.logical_key, input) {
+            (Key::Enter | Key::Escape | Key::Space, NoteInput::Tag) => {
+                let draft = std::mem::take(&mut state.tag_draft);
+                if let Some(notes) = state.note_target.and_then(|target| field_notes.notes_mut(target, &photo_collection)) {
+                    notes.add_tag(&draft);
+                }
+                if event.logical_key != Key::Space {
+                    state.note_input = None;
+                }
+            }
+            (Key::Enter | Key::Escape, _) => state.note_input = None,
+            (key, NoteInput::Search) => type_into(&mut state.note_search, key, MAX_SEARCH_LENGTH),
+            (key, NoteInput::Tag) => type_into(&mut state.tag_draft, key, MAX_TAG_LENGTH),
+            (key, NoteInput::Text) => {
+                if let Some(notes) = state.note_target.and_then(|target| field_notes.notes_mut(target, &photo_collection)) {
+                    type_into(&mut notes.text, key, MAX_NOTE_LENGTH);
+                }
+            }
+        }
+    }
+
+    if changed {
+        field_notes.prune();
+        journal_state.set_changed();
+    }
+}
+
+fn type_into(text: &mut String, key: &Key, max_length: usize) {
+    match key {
+        Key::Character(typed) if text.len() < max_length => text.push_str(typed),
+        Key::Space if text.len() < max_length => text.push(' '),
+        Key::Backspace => {
+            text.pop();
+        }
+        _ => {}
+    }
+}
+
+pub fn species_label(species: BirdSpecies, education_data: &BirdEducationData) -> String {
+    education_data.species_facts.get(&species)
+        .map(|facts| facts.common_name.clone())
+        .unwrap_or_else(|| format!("{:?}", species))
+}
+
+fn photo_label(species: Option<BirdSpecies>, education_data: &BirdEducationData) -> String {
+    match species {
+        Some(species) => format!("Photo: {}", species_label(species, education_data)),
+        None => "Photo: unknown species".to_string(),
+    }
+}
+
+fn spawn_tag_chip(parent: &mut ChildSpawnerCommands, tag: &str, remove_from: Option<NoteTarget>) {
+    let label = if remove_from.is_some() { format!("#{}  x", tag) } else { format!("#{}", tag) };
+    parent.spawn((
+        Button,
+        Node {
+            padding: UiRect::axes(Val::Px(6.0), Val::Px(2.0)),
+            ..default()
+        },
+        BackgroundColor(Color::srgb(0.6, 0.5, 0.4)),
+        BorderRadius::all(Val::Px(4.0)),
+        NoteTagChip { tag: tag.to_string(), remove_from },
+    )).with_children(|chip| {
+        chip.spawn((
+            Text::new(label),
+            TextFont { font_size: 11.0, ..default() },
+            TextColor(Color::WHITE),
+        ));
+    });
+}
+
+// A clickable text box; shows a cursor while it's taking input
+fn spawn_note_field(parent: &mut ChildSpawnerCommands, text: &str, placeholder: &str, editing: bool, width: Val, marker: impl Bundle) {
+    let (shown, color) = if editing {
+        (format!("{}|", text), Color::srgb(0.2, 0.1, 0.05))
+    } else if text.is_empty() {
+        (placeholder.to_string(), Color::srgb(0.6, 0.5, 0.4))
+    } else {
+        (text.to_string(), Color::srgb(0.2, 0.1, 0.05))
+    };
+    let border = if editing { Color::srgb(0.3, 0.2, 0.1) } else { Color::srgb(0.7, 0.6, 0.5) };
+
+    parent.spawn((
+        Button,
+        Node {
+            width,
+            min_height: Val::Px(28.0),
+            padding: UiRect::axes(Val::Px(8.0), Val::Px(5.0)),
+            border: UiRect::all(Val::Px(2.0)),
+            ..default()
+        },
+        BackgroundColor(Color::srgb(0.98, 0.95, 0.92)),
+        BorderColor(border),
+        marker,
+    )).with_children(|field| {
+        field.spawn((
+            Text::new(shown),
+            TextFont { font_size: 13.0, ..default() },
+            TextColor(color),
+        ));
+    });
+}
+
+// Search bar, the note editor for the chosen entry, then every species and photo matching the search
+fn spawn_field_notes(
+    parent: &mut ChildSpawnerCommands,
+    field_notes: &FieldNotes,
+    journal_state: &JournalState,
+    discovered: &DiscoveredSpecies,
+    photo_collection: &PhotoCollection,
+    education_data: &BirdEducationData,
+) {
+    let input = journal_state.note_input;
+    spawn_note_field(
+        parent,
+        &journal_state.note_search,
+        "Search species, notes and tags (#tag searches tags only)",
+        input == Some(NoteInput::Search),
+        Val::Percent(100.0),
+        NoteSearchField,
+    );
+
+    let tags = field_notes.all_tags();
+    if !tags.is_empty() {
+        parent.spawn(Node {
+            width: Val::Percent(100.0),
+            flex_wrap: FlexWrap::Wrap,
+            column_gap: Val::Px(4.0),
+            row_gap: Val::Px(4.0),
+            ..default()
+        }).with_children(|row| {
+            for tag in &tags {
+                spawn_tag_chip(row, tag, None);
+            }
+        });
+    }
+
+    if let Some(target) = journal_state.note_target {
+        let label = match target {
+            NoteTarget::Species(species) => Some(species_label(species, education_data)),
+            NoteTarget::Photo(id) => photo_collection.get_photo(id).map(|photo| photo_label(photo.species, education_data)),
+        };
+        if let Some(label) = label {
+            let empty = EntryNotes::default();
+            let notes = field_notes.notes(target, photo_collection).unwrap_or(&empty);
+
+            parent.spawn((
+                Node {
+                    width: Val::Percent(100.0),
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(6.0),
+                    padding: UiRect::all(Val::Px(10.0)),
+                    ..default()
+                },
+                BackgroundColor(Color::srgb(0.9, 0.87, 0.82)),
+                BorderRadius::all(Val::Px(6.0)),
+            )).with_children(|editor| {
+                editor.spawn((
+                    Text::new(label),
+                    TextFont { font_size: 15.0, ..default() },
+                    TextColor(Color::srgb(0.3, 0.2, 0.1)),
+                ));
+                spawn_note_field(editor, &notes.text, "Click to write a note", input == Some(NoteInput::Text), Val::Percent(100.0), NoteTextField);
+                editor.spawn(Node {
+                    width: Val::Percent(100.0),
+                    flex_wrap: FlexWrap::Wrap,
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(4.0),
+                    row_gap: Val::Px(4.0),
+                    ..default()
+                }).with_children(|row| {
+                    for tag in &notes.tags {
+                        spawn_tag_chip(row, tag, Some(target));
+                    }
+                    spawn_note_field(row, &journal_state.tag_draft, "+ tag", input == Some(NoteInput::Tag), Val::Px(140.0), NoteTagField);
+                });
+            });
+        }
+    }
+
+    let empty = EntryNotes::default();
+    let query = journal_state.note_search.as_str();
+    let mut species: Vec<BirdSpecies> = discovered.0.iter().copied().collect();
+    species.sort_by_key(|species| species_label(*species, education_data));
+
+    let mut entries: Vec<(NoteTarget, String, &EntryNotes)> = species.into_iter()
+        .map(|species| (
+            NoteTarget::Species(species),
+            species_label(species, education_data),
+            field_notes.species_notes(species).unwrap_or(&empty),
+        ))
+        .chain(photo_collection.photos.iter().map(|photo| (
+            NoteTarget::Photo(photo.id),
+            photo_label(photo.species, education_data),
+            field_notes.photo_notes(photo).unwrap_or(&empty),
+        )))
+        .collect();
+    entries.retain(|(_, label, notes)| notes.matches(label, query));
+
+    // Notes outlive their photos, which aren't kept between sessions
+    let gone_photos: Vec<&PhotoNotes> = field_notes.photos.iter()
+        .filter(|entry| !photo_collection.photos.iter().any(|photo| photo.id == entry.photo_id && photo.timestamp == entry.taken_at))
+        .filter(|entry| entry.notes.matches(&photo_label(entry.species, education_data), query))
+        .collect();
+
+    parent.spawn((
+        Text::new(format!("{} entries", entries.len() + gone_photos.len())),
+        TextFont { font_size: 12.0, ..default() },
+        TextColor(Color::srgb(0.5, 0.4, 0.3)),
+    ));
+
+    for (target, label, notes) in entries {
+        let selected = journal_state.note_target == Some(target);
+        parent.spawn((
+            Button,
+            Node {
+                width: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(3.0),
+                padding: UiRect::all(Val::Px(8.0)),
+                border: UiRect::all(Val::Px(2.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.98, 0.95, 0.92)),
+            BorderColor(if selected { Color::srgb(0.3, 0.2, 0.1) } else { Color::srgb(0.7, 0.6, 0.5) }),
+            NoteEntryButton { target },
+        )).with_children(|row| {
+            spawn_note_row_text(row, &label, notes);
+        });
+    }
+
+    for entry in gone_photos {
+        parent.spawn((
+            Node {
+                width: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(3.0),
+                padding: UiRect::all(Val::Px(8.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.9, 0.87, 0.83, 0.5)),
+        )).with_children(|row| {
+            spawn_note_row_text(row, &format!("{} (from an earlier session)", photo_label(entry.species, education_data)), &entry.notes);
+        });
+    }
+}
+
+fn spawn_note_row_text(row: &mut ChildSpawnerCommands, label: &str, notes: &EntryNotes) {
+    row.spawn((
+        Text::new(label),
+        TextFont { font_size: 14.0, ..default() },
+        TextColor(Color::srgb(0.2, 0.1, 0.05)),
+    ));
+    let (summary, color) = if notes.is_empty() {
+        ("No notes yet".to_string(), Color::srgb(0.6, 0.5, 0.4))
+    } else {
+        (FieldNotes::summary(notes), Color::srgb(0.4, 0.3, 0.2))
+    };
+    row.spawn((
+        Text::new(summary),
+        TextFont { font_size: 12.0, ..default() },
+        TextColor(color),
+    ));
+}
+
+// Weather a photo filter can pick, in the order the button steps through them
+const FILTER_WEATHER: [Weather; 6] = [Weather::Clear, Weather::Cloudy, Weather::Rainy, Weather::Snowy, Weather::Windy, Weather::Foggy];
+
+// Next option after the current one; past the last, or from a value not in the list, back to any
+fn cycle_filter<T: Clone + PartialEq>(options: &[T], current: Option<&T>) -> Option<T> {
+    match current {
+        None => options.first().cloned(),
+        Some(current) => options.iter().position(|option| option == current)
+            .and_then(|index| options.get(index + 1))
+            .cloned(),
+    }
+}
+
+fn single<T>(values: &[T]) -> Option<&T> {
+    match values {
+        [value] => Some(value),
+        _ => None,
+    }
+}
+
+pub fn journal_photo_filter_system(
+    interaction_query: Query<(&Interaction, &PhotoFilterButton), Changed<Interaction>>,
     mut journal_state: ResMut<JournalState>,
+    mut smart_collections: ResMut<SmartCollections>,
+    photo_collection: Res<PhotoCollection>,
 ) {
-    for (interaction, tab_button, mut bg_color) in interaction_query.iter_mut() {
-        match *interaction {
-            Interaction::Pressed => {
-                *bg_color = Color::srgb(0.95, 0.92, 0.88).into();
-                
-                if journal_state.current_tab != tab_button.tab {
-                    info!("🔵 JOURNAL TAB: Switching from {:?} to {:?}", journal_state.current_tab, tab_button.tab);
-                    journal_state.current_tab = tab_button.tab;
-                    // Note: Content regeneration will be handled by journal_state_monitor_system
+    for (interaction, button) in &interaction_query {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        let filter = &mut journal_state.photo_filter;
+        match button.action {
+            PhotoFilterAction::Species => {
+                // Only species the collection has photos of
+                let mut species: Vec<BirdSpecies> = photo_collection.photos.iter().filter_map(|photo| photo.species).collect();
+                species.sort_by_key(|species| format!("{:?}", species));
+                species.dedup();
+                // A collection's list of species steps back to any
+                filter.species = match filter.species.len() {
+                    0 | 1 => cycle_filter(&species, single(&filter.species)).into_iter().collect(),
+                    _ => Vec::new(),
+                };
+            }
+            PhotoFilterAction::MinScore => {
+                filter.min_score = cycle_filter(&SCORE_STEPS[1..], Some(&filter.min_score).filter(|min| **min > 0))
+                    .unwrap_or(0);
+            }
+            PhotoFilterAction::MaxScore => {
+                filter.max_score = cycle_filter(&SCORE_STEPS[1..], filter.max_score.as_ref());
+            }
+            PhotoFilterAction::Weather => {
+                // Likewise a list of conditions
+                filter.weather = match filter.weather.len() {
+                    0 | 1 => cycle_filter(&FILTER_WEATHER, single(&filter.weather)).into_iter().collect(),
+                    _ => Vec::new(),
+                };
+            }
+            PhotoFilterAction::TimeOfDay => {
+                filter.time_of_day = cycle_filter(&TimeOfDay::ALL, filter.time_of_day.as_ref());
+            }
+            PhotoFilterAction::Behavior => {
+                let mut behaviors: Vec<String> = photo_collection.photos.iter()
+                    .filter_map(|photo| photo.behavior.map(|state| format!("{:?}", state)))
+                    .collect();
+                behaviors.sort();
+                behaviors.dedup();
+                filter.behavior = cycle_filter(&behaviors, filter.behavior.as_ref());
+            }
+            PhotoFilterAction::Clear => *filter = PhotoFilter::default(),
+            PhotoFilterAction::Save => {
+                if let Some(index) = smart_collections.save(filter) {
+                    debug!("Saved smart collection '{}'", smart_collections.collections[index].name);
                 }
             }
-            Interaction::Hovered => {
-                if journal_state.current_tab != tab_button.tab {
-                    *bg_color = Color::srgb(0.9, 0.87, 0.83).into();
+            PhotoFilterAction::Open(index) => {
+                if let Some(collection) = smart_collections.collections.get(index) {
+                    *filter = collection.filter.clone();
                 }
             }
-            Interaction::None => {
-                if journal_state.current_tab != tab_button.tab {
-                    *bg_color = Color::srgb(0.85, 0.82, 0.78).into();
+            PhotoFilterAction::Remove(index) => {
+                if index < smart_collections.collections.len() {
+                    smart_collections.collections.remove(index);
                 }
             }
         }
     }
 }
 
-pub fn journal_species_detail_system(
-    interaction_query: Query<
-        (&Interaction, &SpeciesCard),
-        (Changed<Interaction>, With<Button>),
-    >,
-    mut journal_state: ResMut<JournalState>,
+fn spawn_photo_filter_button(parent: &mut ChildSpawnerCommands, label: String, active: bool, action: PhotoFilterAction) {
+    parent.spawn((
+        Button,
+        Node {
+            padding: UiRect::axes(Val::Px(8.0), Val::Px(3.0)),
+            ..default()
+        },
+        BackgroundColor(if active { Color::srgb(0.45, 0.55, 0.35) } else { Color::srgb(0.6, 0.5, 0.4) }),
+        BorderRadius::all(Val::Px(4.0)),
+        PhotoFilterButton { action },
+    )).with_children(|button| {
+        button.spawn((
+            Text::new(label),
+            TextFont { font_size: 11.0, ..default() },
+            TextColor(Color::WHITE),
+        ));
+    });
+}
+
+fn spawn_photo_card(grid: &mut ChildSpawnerCommands, photo: &SavedPhoto) {
+    grid.spawn((
+        Button,
+        Node {
+            width: Val::Px(200.0),
+            height: Val::Px(175.0),
+            flex_direction: FlexDirection::Column,
+            border: UiRect::all(Val::Px(2.0)),
+            padding: UiRect::all(Val::Px(10.0)),
+            ..default()
+        },
+        BackgroundColor(Color::srgb(0.98, 0.95, 0.92)),
+        BorderColor(Color::srgb(0.7, 0.6, 0.5)),
+        PhotoCard { timestamp: photo.timestamp },
+    )).with_children(|card| {
+        // Photo image placeholder (would show actual image)
+        card.spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Px(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.8, 0.8, 0.8)),
+        )).with_children(|img| {
+            img.spawn((
+                Text::new("📸"),
+                TextFont {
+                    font_size: 32.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.5, 0.5, 0.5)),
+            ));
+        });
+
+        // Photo metadata
+        card.spawn((
+            Node {
+                width: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(5.0),
+                ..default()
+            },
+        )).with_children(|meta| {
+            let species_text = match photo.species {
+                Some(species) => format!("{:?}", species),
+                None => "Unknown species".to_string(),
+            };
+            meta.spawn((
+                Text::new(species_text),
+                TextFont { font_size: 12.0, ..default() },
+                TextColor(Color::srgb(0.3, 0.2, 0.1)),
+            ));
+            meta.spawn((
+                Text::new(format!("Score: {} | {}", photo.score.total_score, photo.taken_on.label())),
+                TextFont { font_size: 10.0, ..default() },
+                TextColor(Color::srgb(0.5, 0.4, 0.3)),
+            ));
+            let behavior = photo.behavior.map_or_else(String::new, |state| format!(" | {:?}", state));
+            meta.spawn((
+                Text::new(format!("{:?}, {} {}{}", photo.weather, TimeOfDay::from_hour(photo.hour).name(), format_game_hour(photo.hour), behavior)),
+                TextFont { font_size: 10.0, ..default() },
+                TextColor(Color::srgb(0.5, 0.4, 0.3)),
+            ));
+        });
+    });
+}
+
+// Filter bar and smart collections over the photos that pass, best first
+fn spawn_photo_gallery(
+    parent: &mut ChildSpawnerCommands,
+    photo_collection: &PhotoCollection,
+    journal_state: &JournalState,
+    smart_collections: &SmartCollections,
 ) {
-    for (interaction, species_card) in interaction_query.iter() {
-        if *interaction == Interaction::Pressed {
-            journal_state.selected_species = Some(species_card.species);
-        }
+    if photo_collection.photos.is_empty() {
+        // Empty state message
+        parent.spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Px(200.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.9, 0.87, 0.83, 0.5)),
+        )).with_children(|empty| {
+            empty.spawn((
+                Text::new("No photos yet!\nPress P to enter Photo Mode and start building your collection."),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.5, 0.4, 0.3)),
+            ));
+        });
+        return;
     }
+
+    let filter = &journal_state.photo_filter;
+    let any_or = |value: Option<String>| value.unwrap_or_else(|| "Any".to_string());
+    let species = match filter.species.as_slice() {
+        [] => None,
+        [species] => Some(format!("{:?}", species)),
+        species => Some(format!("{} species", species.len())),
+    };
+    let weather = match filter.weather.as_slice() {
+        [] => None,
+        [weather] => Some(format!("{:?}", weather)),
+        weather => Some(weather.iter().map(|weather| format!("{:?}", weather)).collect::<Vec<_>>().join("/")),
+    };
+    let buttons = [
+        (format!("Species: {}", any_or(species.clone())), species.is_some(), PhotoFilterAction::Species),
+        (format!("Score from: {}", filter.min_score), filter.min_score > 0, PhotoFilterAction::MinScore),
+        (format!("Score to: {}", any_or(filter.max_score.map(|max| max.to_string()))), filter.max_score.is_some(), PhotoFilterAction::MaxScore),
+        (format!("Weather: {}", any_or(weather.clone())), weather.is_some(), PhotoFilterAction::Weather),
+        (format!("Time: {}", any_or(filter.time_of_day.map(|time| time.name().to_string()))), filter.time_of_day.is_some(), PhotoFilterAction::TimeOfDay),
+        (format!("Behavior: {}", any_or(filter.behavior.clone())), filter.behavior.is_some(), PhotoFilterAction::Behavior),
+    ];
+
+    let row_node = || Node {
+        width: Val::Percent(100.0),
+        flex_wrap: FlexWrap::Wrap,
+        align_items: AlignItems::Center,
+        column_gap: Val::Px(6.0),
+        row_gap: Val::Px(6.0),
+        ..default()
+    };
+    parent.spawn(row_node()).with_children(|row| {
+        for (label, active, action) in buttons {
+            spawn_photo_filter_button(row, label, active, action);
+        }
+        if !filter.is_empty() {
+            spawn_photo_filter_button(row, "Clear".to_string(), false, PhotoFilterAction::Clear);
+            spawn_photo_filter_button(row, "Save as collection".to_string(), false, PhotoFilterAction::Save);
+        }
+    });
+
+    parent.spawn(row_node()).with_children(|row| {
+        row.spawn((
+            Text::new("Smart collections:"),
+            TextFont { font_size: 12.0, ..default() },
+            TextColor(Color::srgb(0.4, 0.3, 0.2)),
+        ));
+        for (index, collection) in smart_collections.collections.iter().enumerate() {
+            let count = photo_collection.photos.iter().filter(|photo| collection.filter.matches(photo)).count();
+            let open = collection.filter == *filter;
+            spawn_photo_filter_button(row, format!("{} ({})", collection.name, count), open, PhotoFilterAction::Open(index));
+            if open {
+                spawn_photo_filter_button(row, "Remove".to_string(), false, PhotoFilterAction::Remove(index));
+            }
+        }
+    });
+
+    let mut photos: Vec<&SavedPhoto> = photo_collection.photos.iter().filter(|photo| filter.matches(photo)).collect();
+    photos.sort_by_key(|photo| std::cmp::Reverse(photo.score.total_score));
+
+    parent.spawn((
+        Text::new(format!("{} of {} photos", photos.len(), photo_collection.photos.len())),
+        TextFont { font_size: 12.0, ..default() },
+        TextColor(Color::srgb(0.5, 0.4, 0.3)),
+    ));
+
+    // Photo grid
+    parent.spawn((
+        Node {
+            width: Val::Percent(100.0),
+            flex_direction: FlexDirection::Row,
+            flex_wrap: FlexWrap::Wrap,
+            row_gap: Val::Px(15.0),
+            column_gap: Val::Px(15.0),
+            ..default()
+        },
+    )).with_children(|grid| {
+        for photo in photos {
+            spawn_photo_card(grid, photo);
+        }
+    });
 }
 
-pub fn journal_state_monitor_system(
-    journal_state: Res<JournalState>,
-    mut commands: Commands,
-    content_query: Query<Entity, With<JournalTabContent>>,
-    journal_query: Query<Entity, With<JournalMenu>>,
-    children_query: Query<&Children>,
+// Journal tabs, discovered species and any photo with notes, for the search palette
+pub fn provide_journal_search_entries(
+    mut index: ResMut<SearchIndex>,
     discovered: Res<DiscoveredSpecies>,
     education_data: Res<BirdEducationData>,
+    field_notes: Res<FieldNotes>,
     photo_collection: Res<PhotoCollection>,
-    research_manager: Res<ResearchMissionManager>,
-    achievement_progress: Res<AchievementProgress>,
 ) {
-    if journal_state.is_changed() && journal_state.is_open {
-        info!("🔵 JOURNAL STATE: Journal state changed, regenerating content");
-        
-        if let Ok(journal_entity) = journal_query.single() {
-            regenerate_journal_content(
-                &mut commands,
-                journal_entity,
-                &children_query,
-                &content_query,
-                &journal_state,
-                &discovered,
-                &education_data,
-                &photo_collection,
-                &research_manager,
-                &achievement_progress,
-            );
-        }
+    for (tab, title, description) in JOURNAL_TABS {
+        index.add("Journal", format!("Journal: {}", title), description, SearchTarget::JournalTab(tab));
     }
-}
 
-pub fn update_journal_on_discovery_system(
-    mut discovered: ResMut<DiscoveredSpecies>,
-    mut photo_events: EventReader<PhotoTakenEvent>,
-) {
-    for photo_event in photo_events.read() {
-        if let Some(species) = photo_event.species {
-            discovered.0.insert(species);
-        }
+    for species in &discovered.0 {
+        let keywords = match (education_data.species_facts.get(species), field_notes.species_notes(*species)) {
+            (Some(facts), Some(notes)) => format!("{} {}", facts.scientific_name, FieldNotes::summary(notes)),
+            (Some(facts), None) => facts.scientific_name.clone(),
+            (None, Some(notes)) => FieldNotes::summary(notes),
+            (None, None) => String::new(),
+        };
+        index.add("Species", species_label(*species, &education_data), keywords, SearchTarget::JournalNote(NoteTarget::Species(*species)));
     }
-}
 
-pub fn journal_interaction_system(
-    interaction_query: Query<&Interaction, (Changed<Interaction>, With<CloseButton>)>,
-    mut next_state: ResMut<NextState<crate::AppState>>,
-    mut journal_state: ResMut<JournalState>,
-) {
-    for interaction in interaction_query.iter() {
-        if *interaction == Interaction::Pressed {
-            journal_state.is_open = false;
-            next_state.set(crate::AppState::Playing);
-        }
+    for photo in &photo_collection.photos {
+        let Some(notes) = field_notes.photo_notes(photo) else { continue };
+        index.add("Photo notes", photo_label(photo.species, &education_data), FieldNotes::summary(notes), SearchTarget::JournalNote(NoteTarget::Photo(photo.id)));
     }
 }
 
-// Research missions setup system
-pub fn setup_research_missions(mut research_manager: ResMut<ResearchMissionManager>) {
-    research_manager.active_missions = ResearchMissionManager::generate_starter_missions();
-    info!("Initialized {} research missions", research_manager.active_missions.len());
-}
-
-// Helper function to regenerate journal content when switching tabs
-fn regenerate_journal_content(
-    commands: &mut Commands,
-    journal_entity: Entity,
-    children_query: &Query<&Children>,
-    content_query: &Query<Entity, With<JournalTabContent>>,
-    journal_state: &JournalState,
-    discovered: &DiscoveredSpecies,
-    education_data: &BirdEducationData,
-    photo_collection: &PhotoCollection,
-    research_manager: &ResearchMissionManager,
-    achievement_progress: &AchievementProgress,
+pub fn journal_search_jump_system(
+    mut jump_events: EventReader<SearchJumpEvent>,
+    mut journal_state: ResMut<JournalState>,
+    current_state: Res<State<crate::AppState>>,
+    mut next_state: ResMut<NextState<crate::AppState>>,
 ) {
-    // Find the existing content area and clear its children instead of despawning it
-    if let Ok(journal_children) = children_query.get(journal_entity) {
-        // Find the content area (it should be the third child after header and tabs)
-        for child in journal_children.iter() {
-            if content_query.contains(child) {
-                // Clear existing children of the content area
-                if let Ok(content_children) = children_query.get(child) {
-                    for content_child in content_children.iter() {
-                        commands.entity(content_child).safe_despawn();
-                    }
-                }
-                
-                // Add new content to the existing content area
-                commands.entity(child).with_children(|content| {
-                    match journal_state.current_tab {
-                        JournalTab::Species => {
-                            content.spawn((
-                                Text::new(format!("Species discovered: {}\nEducational data loaded: {}", 
-                                    discovered.0.len(), education_data.species_facts.len())),
-                                TextFont { font_size: 16.0, ..default() },
-                                TextColor(Color::srgb(0.3, 0.2, 0.1)),
-                            ));
-                        },
-                        JournalTab::Photos => {
-                            content.spawn((
-                                Text::new(format!("Photos: {}", photo_collection.photos.len())),
-                                TextFont { font_size: 16.0, ..default() },
-                                TextColor(Color::srgb(0.3, 0.2, 0.1)),
-                            ));
-                        },
-                        JournalTab::Research => {
-                            content.spawn((
-                                Text::new(format!("Research missions: {} active, {} completed", 
-                                    research_manager.active_missions.len(), research_manager.completed_missions.len())),
-                                TextFont { font_size: 16.0, ..default() },
-                                TextColor(Color::srgb(0.3, 0.2, 0.1)),
-                            ));
-                        },
-                        JournalTab::Achievements => {
-                            content.spawn((
-                                Text::new(format!("Achievements unlocked: {}/11\nPhotos taken: {}", 
-                                    achievement_progress.unlocked.len(), achievement_progress.photos_taken)),
-                                TextFont { font_size: 16.0, ..default() },
-                                TextColor(Color::srgb(0.3, 0.2, 0.1)),
-                            ));
-                        },
-                        JournalTab::Conservation => {
-                            content.spawn((
-                                Text::new(format!("Conservation data for {} species", discovered.0.len())),
-                                TextFont { font_size: 16.0, ..default() },
-                                TextColor(Color::srgb(0.3, 0.2, 0.1)),
-                            ));
-                        },
-                        JournalTab::Migration => {
-                            content.spawn((
-                                Text::new(format!("Migration data for {} species", discovered.0.len())),
-                                TextFont { font_size: 16.0, ..default() },
-                                TextColor(Color::srgb(0.3, 0.2, 0.1)),
-                            ));
-                        },
-                    }
-                });
-                break;
+    for event in jump_events.read() {
+        match event.target {
+            SearchTarget::JournalTab(tab) => journal_state.current_tab = tab,
+            SearchTarget::JournalNote(target) => {
+                journal_state.current_tab = JournalTab::Notes;
+                journal_state.note_target = Some(target);
+                journal_state.note_search.clear();
             }
+            _ => continue,
+        }
+
+        journal_state.is_open = true;
+        if *current_state.get() != crate::AppState::Journal {
+            next_state.set(crate::AppState::Journal);
         }
     }
 }
@@ -1263,6 +3021,30 @@ mod tests {
         assert!(discovered.0.contains(&crate::bird::BirdSpecies::Cardinal));
     }
 
+    #[test]
+    fn test_research_mission_progress_from_observations() {
+        use crate::journal::research::{ObservedBird, ResearchObservation};
+
+        // Dawn Chorus Study: 5 Robin photos scoring 400+ and 10 of Robins singing
+        let mut mission = ResearchMissionManager::generate_starter_missions().remove(0);
+        let singing_robin = |score| ResearchObservation {
+            day: 1,
+            hour: 7.0,
+            temperature: 12.0,
+            score,
+            birds: vec![ObservedBird { species: crate::bird::BirdSpecies::Robin, behavior: "Territorial".to_string() }],
+        };
+
+        let mut observations: Vec<ResearchObservation> = (0..5).map(|_| singing_robin(450)).collect();
+        assert!(!mission.update_progress(&observations));
+        assert!(mission.objectives[0].completed);
+        assert_eq!(mission.objectives[1].progress, 0.5);
+
+        observations.extend((0..5).map(|_| singing_robin(100)));
+        assert!(mission.update_progress(&observations));
+        assert_eq!(mission.progress.completion_percentage, 1.0);
+    }
+
     #[test]
     fn test_conservation_status_color() {
         use super::ConservationStatus;
@@ -1289,6 +3071,82 @@ mod tests {
         assert_eq!(ConservationStatus::Endangered.label(), "Endangered");
         assert_eq!(ConservationStatus::Extinct.label(), "Extinct");
     }
-}
 
+    #[test]
+    fn test_migration_route_position() {
+        let data = MigrationData {
+            is_migratory: true,
+            breeding_range: "Eastern North America".to_string(),
+            wintering_range: "Central America".to_string(),
+            migration_timing: "Spring: April-May, Fall: August-September".to_string(),
+            migration_distance: Some(2000.0),
+            interesting_fact: String::new(),
+        };
+
+        assert_eq!(data.seasonal_windows(), ((91, 151), (213, 273)));
+        assert_eq!(data.route_position(30), (0.0, MigrationPhase::Wintering));
+        assert_eq!(data.route_position(180), (1.0, MigrationPhase::Breeding));
+
+        let (progress, phase) = data.route_position(121);
+        assert_eq!(phase, MigrationPhase::Northbound);
+        assert!((progress - 0.5).abs() < 0.01);
+
+        let (progress, phase) = data.route_position(243);
+        assert_eq!(phase, MigrationPhase::Southbound);
+        assert!((progress - 0.5).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_sighting_log_daily_tally() {
+        let mut log = SightingLog::default();
+        let date = ChecklistDate { year: 1, day_of_year: 121 };
+
+        log.record(date, 6.5, crate::bird::BirdSpecies::Robin, 2);
+        log.record(date, 9.0, crate::bird::BirdSpecies::Robin, 4);
+        log.record(date, 17.25, crate::bird::BirdSpecies::Robin, 1);
+
+        let tally = &log.checklist(date).unwrap().species[&crate::bird::BirdSpecies::Robin];
+        assert_eq!(tally.first_seen, 6.5);
+        assert_eq!(tally.last_seen, 17.25);
+        assert_eq!(tally.max_count, 4);
+
+        assert_eq!(date.label(), "May 1, Year 1");
+        assert!(log.checklist(ChecklistDate { year: 2, day_of_year: 121 }).is_none());
+    }
+
+    #[test]
+    fn test_field_notes_search() {
+        let mut notes = EntryNotes {
+            text: "Pair visiting the suet feeder every morning".to_string(),
+            ..Default::default()
+        };
+        assert!(notes.add_tag("#Nesting"));
+        assert!(!notes.add_tag("nesting"));
+        assert!(notes.add_tag("backyard"));
+        assert_eq!(notes.tags, vec!["nesting".to_string(), "backyard".to_string()]);
+
+        assert!(notes.matches("Northern Cardinal", ""));
+        assert!(notes.matches("Northern Cardinal", "cardinal suet"));
+        assert!(notes.matches("Northern Cardinal", "#nest"));
+        assert!(!notes.matches("Northern Cardinal", "#suet"));
+        assert!(!notes.matches("Northern Cardinal", "cardinal evening"));
+
+        notes.remove_tag("nesting");
+        assert!(!notes.matches("Northern Cardinal", "#nesting"));
+        assert_eq!(FieldNotes::summary(&notes), "Pair visiting the suet feeder every morning #backyard");
+    }
+
+    #[test]
+    fn test_mnemonic_syllable_timing() {
+        let syllables = crate::bird_data::mnemonic_syllables("drink-your tea");
+        assert_eq!(syllables, vec!["drink", "your", "tea"]);
+
+        // More notes than syllables: every other note carries one
+        let onsets = [0.2, 0.5, 0.8, 1.1, 1.4, 1.7];
+        assert_eq!(syllable_times(&onsets, 3, 2.0), vec![0.2, 0.8, 1.4]);
 
+        // Too few notes: spread evenly from the first one
+        assert_eq!(syllable_times(&[1.0], 2, 3.0), vec![1.0, 2.0]);
+        assert!(syllable_times(&[], 0, 3.0).is_empty());
+    }
+}
@@ -1,7 +1,9 @@
 use bevy::prelude::*;
+use bevy::input::InputSystem;
+use bevy::input::mouse::MouseMotion;
 use serde::{Serialize, Deserialize};
 use std::collections::HashMap;
-use std::fs;
+use crate::photo_mode::resources::PhotoModeSettings;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum GameAction {
@@ -23,6 +25,12 @@ pub enum GameAction {
     OpenJournal,
     OpenCatalog,
     OpenSettings,
+    OpenJukebox,
+    OpenAlbumEditor,
+    OpenTradingPost,
+    OpenReputation,
+    OpenGrants,
+    OpenAdvisor,
     CloseMenu,
     PauseGame,
     
@@ -31,6 +39,168 @@ pub enum GameAction {
     RemoveObject,
     QuickSave,
     QuickLoad,
+    SaveClip,
+    RemoteShutter,
+    DrawLines,
+    PaintTerrain,
+    CycleHeight,
+    CallPlayback,
+    ToggleBinoculars,
+    ToggleSoundId,
+    OpenPhotoContest,
+    OpenGearCare,
+    OpenYearlyStats,
+    ReportBug,
+}
+
+impl GameAction {
+    pub const ALL: [GameAction; 37] = [
+        Self::CameraMoveUp, Self::CameraMoveDown, Self::CameraMoveLeft, Self::CameraMoveRight,
+        Self::CameraZoomIn, Self::CameraZoomOut, Self::CameraPan,
+        Self::TogglePhotoMode, Self::TakePhoto, Self::PhotoModeSettings,
+        Self::OpenJournal, Self::OpenCatalog, Self::OpenSettings, Self::OpenJukebox, Self::OpenAlbumEditor,
+        Self::OpenTradingPost, Self::OpenReputation, Self::OpenGrants, Self::OpenAdvisor, Self::CloseMenu, Self::PauseGame,
+        Self::PlaceFeeder, Self::RemoveObject, Self::QuickSave, Self::QuickLoad, Self::SaveClip, Self::RemoteShutter,
+        Self::DrawLines, Self::PaintTerrain, Self::CycleHeight, Self::CallPlayback, Self::ToggleBinoculars, Self::ToggleSoundId,
+        Self::OpenPhotoContest, Self::OpenGearCare, Self::OpenYearlyStats, Self::ReportBug,
+    ];
+    
+    // Steam Input action set the action is configured in; photo mode is a layer over the sanctuary set
+    pub fn action_set(&self) -> ActionSet {
+        match self {
+            Self::TakePhoto | Self::PhotoModeSettings => ActionSet::PhotoMode,
+            Self::CloseMenu => ActionSet::Menus,
+            _ => ActionSet::Sanctuary,
+        }
+    }
+    
+    // Name in the Steam Input action manifest, e.g. OpenJournal -> open_journal
+    pub fn steam_action_name(&self) -> String {
+        let mut name = String::new();
+        for (i, c) in format!("{:?}", self).chars().enumerate() {
+            if c.is_uppercase() && i > 0 {
+                name.push('_');
+            }
+            name.push(c.to_ascii_lowercase());
+        }
+        name
+    }
+    
+    // Controller layout used without Steam Input, and the glyph shown in prompts either way
+    pub fn default_gamepad_button(&self) -> Option<GamepadButton> {
+        match self {
+            Self::CameraMoveUp => Some(GamepadButton::DPadUp),
+            Self::CameraMoveDown => Some(GamepadButton::DPadDown),
+            Self::CameraMoveLeft => Some(GamepadButton::DPadLeft),
+            Self::CameraMoveRight => Some(GamepadButton::DPadRight),
+            Self::TogglePhotoMode => Some(GamepadButton::North),
+            Self::TakePhoto => Some(GamepadButton::South),
+            Self::PhotoModeSettings => Some(GamepadButton::West),
+            Self::OpenJournal => Some(GamepadButton::Select),
+            Self::OpenCatalog => Some(GamepadButton::West),
+            Self::OpenSettings => Some(GamepadButton::Start),
+            Self::CloseMenu => Some(GamepadButton::East),
+            Self::SaveClip => Some(GamepadButton::RightThumb),
+            Self::RemoteShutter => Some(GamepadButton::RightTrigger),
+            Self::CallPlayback => Some(GamepadButton::LeftTrigger),
+            Self::ToggleBinoculars => Some(GamepadButton::LeftThumb),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActionSet {
+    Sanctuary,
+    PhotoMode,
+    Menus,
+}
+
+impl ActionSet {
+    pub fn steam_name(&self) -> &'static str {
+        match self {
+            Self::Sanctuary => "sanctuary_controls",
+            Self::PhotoMode => "photo_mode_layer",
+            Self::Menus => "menu_controls",
+        }
+    }
+    
+    // Active sets for the current screen, highest precedence first
+    pub fn active(state: &crate::AppState, photo_mode: bool) -> &'static [ActionSet] {
+        match state {
+            crate::AppState::Playing if photo_mode => &[Self::PhotoMode, Self::Sanctuary],
+            crate::AppState::Playing => &[Self::Sanctuary],
+            _ => &[Self::Menus],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GlyphStyle {
+    Xbox,
+    PlayStation,
+    SteamDeck,
+    Generic,
+}
+
+impl GlyphStyle {
+    pub fn from_vendor(vendor_id: Option<u16>) -> Self {
+        match vendor_id {
+            Some(0x045E) => Self::Xbox,
+            Some(0x054C) => Self::PlayStation,
+            Some(0x28DE) => Self::SteamDeck,
+            _ => Self::Generic,
+        }
+    }
+    
+    pub fn button_glyph(&self, button: GamepadButton) -> &'static str {
+        use GamepadButton as B;
+        match (self, button) {
+            (Self::PlayStation, B::South) => "Cross",
+            (Self::PlayStation, B::East) => "Circle",
+            (Self::PlayStation, B::West) => "Square",
+            (Self::PlayStation, B::North) => "Triangle",
+            (Self::PlayStation, B::LeftTrigger) => "L1",
+            (Self::PlayStation, B::RightTrigger) => "R1",
+            (Self::PlayStation, B::LeftThumb) => "L3",
+            (Self::PlayStation, B::RightThumb) => "R3",
+            (Self::PlayStation, B::Select) => "Share",
+            (Self::PlayStation, B::Start) => "Options",
+            (Self::Generic, B::South) => "Bottom Button",
+            (Self::Generic, B::East) => "Right Button",
+            (Self::Generic, B::West) => "Left Button",
+            (Self::Generic, B::North) => "Top Button",
+            (_, B::South) => "A",
+            (_, B::East) => "B",
+            (_, B::West) => "X",
+            (_, B::North) => "Y",
+            (Self::SteamDeck, B::LeftTrigger) => "L1",
+            (Self::SteamDeck, B::RightTrigger) => "R1",
+            (Self::SteamDeck, B::LeftThumb) => "L3",
+            (Self::SteamDeck, B::RightThumb) => "R3",
+            (Self::SteamDeck, B::Select) => "View",
+            (Self::SteamDeck, B::Start) => "Menu",
+            (_, B::LeftTrigger) => "LB",
+            (_, B::RightTrigger) => "RB",
+            (_, B::LeftThumb) => "LS",
+            (_, B::RightThumb) => "RS",
+            (_, B::Select) => "View",
+            (_, B::Start) => "Menu",
+            (_, B::DPadUp) => "D-pad Up",
+            (_, B::DPadDown) => "D-pad Down",
+            (_, B::DPadLeft) => "D-pad Left",
+            (_, B::DPadRight) => "D-pad Right",
+            _ => "Button",
+        }
+    }
+}
+
+// Whichever device was used last decides which prompts are shown
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ActiveInputDevice {
+    #[default]
+    KeyboardMouse,
+    Gamepad(GlyphStyle),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -68,6 +238,12 @@ impl Default for KeyBindings {
         bindings.insert(GameAction::OpenJournal, vec![InputBinding::Keyboard(KeyCode::KeyJ)]);
         bindings.insert(GameAction::OpenCatalog, vec![InputBinding::Keyboard(KeyCode::KeyC)]);
         bindings.insert(GameAction::OpenSettings, vec![InputBinding::Keyboard(KeyCode::Escape)]);
+        bindings.insert(GameAction::OpenJukebox, vec![InputBinding::Keyboard(KeyCode::KeyM)]);
+        bindings.insert(GameAction::OpenAlbumEditor, vec![InputBinding::Keyboard(KeyCode::KeyB)]);
+        bindings.insert(GameAction::OpenTradingPost, vec![InputBinding::Keyboard(KeyCode::KeyT)]);
+        bindings.insert(GameAction::OpenReputation, vec![InputBinding::Keyboard(KeyCode::KeyR)]);
+        bindings.insert(GameAction::OpenGrants, vec![InputBinding::Keyboard(KeyCode::KeyU)]);
+        bindings.insert(GameAction::OpenAdvisor, vec![InputBinding::Keyboard(KeyCode::KeyO)]);
         bindings.insert(GameAction::CloseMenu, vec![InputBinding::Keyboard(KeyCode::Escape)]);
         bindings.insert(GameAction::PauseGame, vec![InputBinding::Keyboard(KeyCode::Escape)]);
         
@@ -76,44 +252,31 @@ impl Default for KeyBindings {
         bindings.insert(GameAction::RemoveObject, vec![InputBinding::Keyboard(KeyCode::Delete)]);
         bindings.insert(GameAction::QuickSave, vec![InputBinding::Keyboard(KeyCode::F5)]);
         bindings.insert(GameAction::QuickLoad, vec![InputBinding::Keyboard(KeyCode::F9)]);
+        bindings.insert(GameAction::SaveClip, vec![InputBinding::Keyboard(KeyCode::KeyV)]);
+        bindings.insert(GameAction::RemoteShutter, vec![InputBinding::Keyboard(KeyCode::KeyK)]);
+        bindings.insert(GameAction::DrawLines, vec![InputBinding::Keyboard(KeyCode::KeyL)]);
+        bindings.insert(GameAction::PaintTerrain, vec![InputBinding::Keyboard(KeyCode::KeyG)]);
+        bindings.insert(GameAction::CycleHeight, vec![InputBinding::Keyboard(KeyCode::KeyY)]);
+        bindings.insert(GameAction::CallPlayback, vec![InputBinding::Keyboard(KeyCode::F6)]);
+        bindings.insert(GameAction::ToggleBinoculars, vec![InputBinding::Keyboard(KeyCode::KeyN)]);
+        bindings.insert(GameAction::ToggleSoundId, vec![InputBinding::Keyboard(KeyCode::KeyI)]);
+        bindings.insert(GameAction::OpenPhotoContest, vec![InputBinding::Keyboard(KeyCode::Home)]);
+        bindings.insert(GameAction::OpenGearCare, vec![InputBinding::Keyboard(KeyCode::F11)]);
+        bindings.insert(GameAction::OpenYearlyStats, vec![InputBinding::Keyboard(KeyCode::End)]); // F12 is the Steam screenshot key
+        bindings.insert(GameAction::ReportBug, vec![InputBinding::Keyboard(KeyCode::F10)]);
         
         Self { bindings }
     }
 }
 
 impl KeyBindings {
-    pub fn get_bindings_path() -> std::path::PathBuf {
-        dirs::config_dir()
-            .unwrap_or_else(|| std::path::PathBuf::from("."))
-            .join("AvianHaven")
-            .join("keybindings.ron")
-    }
-    
     pub fn save_to_file(&self) -> Result<(), Box<dyn std::error::Error>> {
-        let bindings_path = Self::get_bindings_path();
-        
-        if let Some(parent) = bindings_path.parent() {
-            fs::create_dir_all(parent)?;
-        }
-        
-        let serialized = ron::to_string(self)?;
-        fs::write(bindings_path, serialized)?;
-        Ok(())
+        crate::user_config::write_config("keybindings.ron", self)
     }
     
     pub fn load_from_file() -> Self {
-        let bindings_path = Self::get_bindings_path();
-        
-        if bindings_path.exists() {
-            if let Ok(content) = fs::read_to_string(bindings_path) {
-                if let Ok(bindings) = ron::from_str::<KeyBindings>(&content) {
-                    return bindings;
-                }
-            }
-        }
-        
         // Return default bindings if loading fails
-        Self::default()
+        crate::user_config::read_config("keybindings.ron").unwrap_or_default()
     }
     
     pub fn is_action_pressed(&self, action: GameAction, input: &ButtonInput<KeyCode>, mouse: &ButtonInput<MouseButton>) -> bool {
@@ -182,6 +345,14 @@ impl KeyBindings {
         self.bindings.get(&action).and_then(|bindings| bindings.first())
     }
     
+    // Key name or controller glyph for on-screen prompts, following the last-used device
+    pub fn prompt_label(&self, action: GameAction, device: ActiveInputDevice) -> String {
+        match (device, action.default_gamepad_button()) {
+            (ActiveInputDevice::Gamepad(style), Some(button)) => style.button_glyph(button).to_string(),
+            _ => self.get_display_string(action),
+        }
+    }
+    
     pub fn get_display_string(&self, action: GameAction) -> String {
         if let Some(binding) = self.get_primary_binding(action) {
             match binding {
@@ -202,12 +373,18 @@ impl Plugin for KeyBindingsPlugin {
     fn build(&self, app: &mut App) {
         app
             .init_resource::<KeyBindings>()
+            .init_resource::<ActiveInputDevice>()
             .add_systems(Startup, load_keybindings)
+            // Before gameplay reads input, so controller presses look like their bound keys
+            .add_systems(PreUpdate, (
+                detect_input_device_system,
+                gamepad_action_input_system,
+            ).chain().after(InputSystem))
             .add_systems(Update, (
                 handle_camera_input,
                 handle_ui_input,
                 handle_gameplay_input,
-            ));
+            ).in_set(crate::GameSet::Input));
     }
 }
 
@@ -216,6 +393,89 @@ fn load_keybindings(mut commands: Commands) {
     commands.insert_resource(keybindings);
 }
 
+// Mouse movement below this many pixels in a frame is treated as desk jitter
+const MOUSE_MOTION_THRESHOLD: f32 = 4.0;
+const STICK_DEADZONE: f32 = 0.5;
+
+fn detect_input_device_system(
+    mut active_device: ResMut<ActiveInputDevice>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    mut mouse_motion: EventReader<MouseMotion>,
+    gamepad_query: Query<&Gamepad>,
+) {
+    let moved: f32 = mouse_motion.read().map(|motion| motion.delta.length()).sum();
+    let used_keyboard_mouse = keyboard.get_just_pressed().next().is_some()
+        || mouse.get_just_pressed().next().is_some()
+        || moved > MOUSE_MOTION_THRESHOLD;
+    
+    let used_gamepad = gamepad_query.iter().find(|gamepad| {
+        gamepad.get_just_pressed().next().is_some() || gamepad.left_stick().length() > STICK_DEADZONE
+    });
+    
+    let device = match used_gamepad {
+        Some(gamepad) => ActiveInputDevice::Gamepad(GlyphStyle::from_vendor(gamepad.vendor_id())),
+        None if used_keyboard_mouse => ActiveInputDevice::KeyboardMouse,
+        None => return,
+    };
+    active_device.set_if_neq(device);
+}
+
+// Presses the bound key for each controller action so every keyboard-driven system works on a controller.
+// Steam Input reports the same actions through its own layouts when it is running.
+fn gamepad_action_input_system(
+    mut keyboard: ResMut<ButtonInput<KeyCode>>,
+    keybindings: Res<KeyBindings>,
+    gamepad_query: Query<&Gamepad>,
+    state: Res<State<crate::AppState>>,
+    photo_mode: Option<Res<PhotoModeSettings>>,
+    mut held: Local<HashMap<GamepadButton, KeyCode>>,
+    mut stick_held: Local<Vec<KeyCode>>,
+) {
+    let photo_mode_active = photo_mode.is_some_and(|settings| settings.is_active);
+    let sets = ActionSet::active(state.get(), photo_mode_active);
+    let bound_key = |action: GameAction| keybindings.bindings.get(&action)
+        .and_then(|bindings| bindings.iter().find_map(|binding| match binding {
+            InputBinding::Keyboard(key) => Some(*key),
+            _ => None,
+        }));
+    
+    for gamepad in &gamepad_query {
+        for button in gamepad.get_just_pressed() {
+            let action = sets.iter().find_map(|set| GameAction::ALL.into_iter()
+                .find(|action| action.action_set() == *set && action.default_gamepad_button() == Some(*button)));
+            if let Some(key) = action.and_then(bound_key) {
+                keyboard.press(key);
+                held.insert(*button, key);
+            }
+        }
+        for button in gamepad.get_just_released() {
+            if let Some(key) = held.remove(button) {
+                keyboard.release(key);
+            }
+        }
+        
+        // The left stick pans the camera like the D-pad
+        let stick = gamepad.left_stick();
+        for (active, action) in [
+            (stick.y > STICK_DEADZONE, GameAction::CameraMoveUp),
+            (stick.y < -STICK_DEADZONE, GameAction::CameraMoveDown),
+            (stick.x < -STICK_DEADZONE, GameAction::CameraMoveLeft),
+            (stick.x > STICK_DEADZONE, GameAction::CameraMoveRight),
+        ] {
+            let Some(key) = bound_key(action) else { continue };
+            let was_held = stick_held.contains(&key);
+            if active && !was_held {
+                keyboard.press(key);
+                stick_held.push(key);
+            } else if !active && was_held {
+                keyboard.release(key);
+                stick_held.retain(|held_key| *held_key != key);
+            }
+        }
+    }
+}
+
 fn handle_camera_input(
     keybindings: Res<KeyBindings>,
     keyboard: Res<ButtonInput<KeyCode>>,
@@ -256,7 +516,7 @@ fn handle_ui_input(
     
     if keybindings.is_action_just_pressed(GameAction::CloseMenu, &keyboard, &mouse) {
         match current_state.get() {
-            crate::AppState::Settings | crate::AppState::LoadGame => {
+            crate::AppState::Settings | crate::AppState::LoadGame | crate::AppState::NewGameSetup => {
                 app_state.set(crate::AppState::MainMenu);
             }
             crate::AppState::Journal => {
@@ -21,7 +21,7 @@ impl Plugin for SmartObjectsPlugin {
                 seasonal_object_system,
                 sanctuary_interaction_system,
                 predator_deterrent_system,
-            ).run_if(in_state(crate::AppState::Playing)));
+            ).in_set(crate::GameSet::Simulation).run_if(in_state(crate::AppState::Playing)));
     }
 }
 
@@ -779,6 +779,7 @@ pub fn predator_deterrent_system(
                 DeterrentType::ScareOwl => 0.5,
                 DeterrentType::ProtectiveMesh => 0.9,
                 DeterrentType::NaturalBarrier => 0.4,
+                DeterrentType::GardenFence => 0.5,
             };
         }
     }
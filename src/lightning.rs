@@ -0,0 +1,199 @@
+// Lightning - Rare strikes on the yard's trees during severe storms
+//
+// While a severe storm or a thunderstorm cell is overhead there's a small chance each second of a
+// strike. It hits one of the trees - the shelter and roost objects - leaving it charred and thinner
+// cover, and every bird in the yard bolts from the flash. Once the storm has passed, the split-off
+// trunk stands beside the tree as a new snag. Beetles move into fresh deadwood, so woodpeckers come
+// to forage on it from twice as far off as anything else, as well as drumming and digging there.
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+use crate::advanced_weather::{PrecipitationKind, PrecipitationRadar, StormManager, StormSeverity};
+use crate::bird_ai::components::{BirdAI, BirdAction, BirdState, Blackboard, ProvidesUtility, SmartObject};
+use crate::cavity_nesters::Snag;
+use crate::journal::milestones::{MilestoneEvent, MilestoneKind};
+use crate::notifications::{components::NotificationType, resources::ShowNotificationEvent};
+
+// Per second while stormy; about one strike in a ten minute storm
+const STRIKE_CHANCE: f32 = 0.0015;
+// No second strike sooner than this after the last
+const STRIKE_COOLDOWN: f32 = 120.0;
+const FLASH_SECONDS: f32 = 0.35;
+const SCORCHED_COLOR: Color = Color::srgb(0.18, 0.14, 0.12);
+// A burned crown keeps off less rain
+const SCORCHED_UTILITY: f32 = 0.5;
+// The snag stands just to the side of the tree it split from
+const SNAG_OFFSET: Vec2 = Vec2::new(45.0, -10.0);
+
+pub struct LightningPlugin;
+
+impl Plugin for LightningPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<Lightning>()
+            .add_systems(Update, (
+                lightning_strike_system,
+                lightning_flash_system,
+                lightning_snag_system,
+            ).chain().in_set(crate::GameSet::Simulation).run_if(in_state(crate::AppState::Playing)));
+    }
+}
+
+// Resources
+#[derive(Resource, Default)]
+pub struct Lightning {
+    cooldown: f32,
+    pub strikes: u32,
+}
+
+// Components
+#[derive(Component)]
+pub struct Scorched {
+    // Cleared once the snag has been put up after the storm
+    pub snag_pending: bool,
+}
+
+/// Deadwood from a lightning strike, which woodpeckers forage on
+#[derive(Component)]
+pub struct LightningSnag;
+
+#[derive(Component)]
+pub struct LightningFlash {
+    elapsed: f32,
+}
+
+pub fn is_stormy(storm_manager: &StormManager, radar: &PrecipitationRadar) -> bool {
+    matches!(storm_manager.current_severity, StormSeverity::Severe | StormSeverity::Extreme)
+        || radar.overhead().is_some_and(|cell| cell.kind == PrecipitationKind::Thunderstorm)
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn lightning_strike_system(
+    mut commands: Commands,
+    mut lightning: ResMut<Lightning>,
+    storm_manager: Res<StormManager>,
+    radar: Res<PrecipitationRadar>,
+    mut tree_query: Query<(Entity, &Transform, &mut Sprite, &mut ProvidesUtility), Without<Scorched>>,
+    mut bird_query: Query<(&Transform, &mut BirdState, &mut Blackboard), With<BirdAI>>,
+    mut milestone_events: EventWriter<MilestoneEvent>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+    time: Res<Time>,
+) {
+    lightning.cooldown = (lightning.cooldown - time.delta_secs()).max(0.0);
+    if lightning.cooldown > 0.0 || !is_stormy(&storm_manager, &radar) {
+        return;
+    }
+    if rand::random::<f32>() > STRIKE_CHANCE * time.delta_secs() {
+        return;
+    }
+
+    let trees: Vec<Entity> = tree_query.iter()
+        .filter(|(_, _, _, utility)| matches!(utility.action, BirdAction::Shelter | BirdAction::Roost))
+        .map(|(entity, ..)| entity)
+        .collect();
+    if trees.is_empty() {
+        return;
+    }
+    let Ok((tree, transform, mut sprite, mut utility)) = tree_query.get_mut(trees[rand::random_range(0..trees.len())]) else { return };
+
+    lightning.cooldown = STRIKE_COOLDOWN;
+    lightning.strikes += 1;
+    let strike = transform.translation.truncate();
+    sprite.color = SCORCHED_COLOR;
+    utility.base_utility *= SCORCHED_UTILITY;
+    commands.entity(tree).insert(Scorched { snag_pending: true });
+    spawn_lightning_flash(&mut commands);
+
+    // Everything in the yard takes off, away from the strike
+    for (bird_transform, mut state, mut blackboard) in &mut bird_query {
+        blackboard.internal.fear = 1.0;
+        blackboard.world_knowledge.perceived_threat = Some(strike - bird_transform.translation.truncate());
+        *state = BirdState::Fleeing;
+    }
+
+    info!("⚡ Lightning struck the tree at {:?}", strike);
+    milestone_events.write(MilestoneEvent {
+        kind: MilestoneKind::Storm,
+        title: "Lightning struck a tree in the yard".to_string(),
+        species: None,
+    });
+    notification_events.write(ShowNotificationEvent {
+        notification: NotificationType::Warning {
+            message: "Lightning strike! The birds have scattered".to_string(),
+        },
+    });
+}
+
+fn spawn_lightning_flash(commands: &mut Commands) {
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(1.0, 1.0, 1.0, 0.85)),
+        GlobalZIndex(30),
+        Pickable::IGNORE,
+        LightningFlash { elapsed: 0.0 },
+        StateScoped(crate::AppState::Playing),
+    ));
+}
+
+pub fn lightning_flash_system(
+    mut commands: Commands,
+    mut flash_query: Query<(Entity, &mut LightningFlash, &mut BackgroundColor)>,
+    time: Res<Time>,
+) {
+    for (entity, mut flash, mut color) in &mut flash_query {
+        flash.elapsed += time.delta_secs();
+        if flash.elapsed >= FLASH_SECONDS {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        color.0.set_alpha(0.85 * (1.0 - flash.elapsed / FLASH_SECONDS));
+    }
+}
+
+// Puts up the snags once the weather has cleared, so they don't appear mid-storm
+pub fn lightning_snag_system(
+    mut commands: Commands,
+    storm_manager: Res<StormManager>,
+    radar: Res<PrecipitationRadar>,
+    mut tree_query: Query<(&Transform, &mut Scorched)>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+) {
+    if is_stormy(&storm_manager, &radar) {
+        return;
+    }
+    for (transform, mut scorched) in &mut tree_query {
+        if !scorched.snag_pending {
+            continue;
+        }
+        scorched.snag_pending = false;
+        spawn_lightning_snag(&mut commands, transform.translation.truncate() + SNAG_OFFSET);
+        notification_events.write(ShowNotificationEvent {
+            notification: NotificationType::Info {
+                message: "The storm has passed, leaving a lightning-split snag - watch for woodpeckers".to_string(),
+            },
+        });
+    }
+}
+
+fn spawn_lightning_snag(commands: &mut Commands, position: Vec2) {
+    commands.spawn((
+        Sprite::from_color(Color::srgb(0.32, 0.27, 0.23), Vec2::new(22.0, 90.0)),
+        Transform::from_translation(position.extend(0.4)),
+        RigidBody::Fixed,
+        Collider::cuboid(11.0, 45.0),
+        Sensor,
+        Snag::default(),
+        SmartObject,
+        ProvidesUtility {
+            action: BirdAction::Forage,
+            base_utility: 0.6,
+            range: 150.0,
+        },
+        LightningSnag,
+        Name::new("Lightning Snag"),
+    ));
+}
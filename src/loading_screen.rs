@@ -18,7 +18,7 @@ impl Plugin for LoadingScreenPlugin {
             .add_systems(OnExit(crate::AppState::LoadingScreen), cleanup_loading_screen)
             .add_systems(
                 Update,
-                (update_loading_progress, update_custom_progress_bar).run_if(in_state(crate::AppState::LoadingScreen))
+                (update_loading_progress, update_custom_progress_bar).in_set(crate::GameSet::UI).run_if(in_state(crate::AppState::LoadingScreen))
             );
     }
 }
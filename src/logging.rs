@@ -0,0 +1,592 @@
+// Logging - Per-module log levels, a rotating log file and an in-game log viewer
+//
+// Stands in for the log plugin's subscriber with one whose filter can be swapped while the game
+// runs. The default level and any per-module overrides live in logging.ron in the config directory;
+// `log <module> <level>` in the debug console changes them on the spot and saves them for the next
+// launch. Everything that passes the filter is also written to perch.log in the data directory,
+// which rolls over to perch.1.log and onwards at startup and whenever it grows past 5 MB, and the
+// most recent lines are kept in memory for the `logs` viewer panel and for bug reports.
+use bevy::prelude::*;
+use bevy::log::tracing;
+use bevy::log::tracing_subscriber::{self, filter::EnvFilter, layer::SubscriberExt, reload, util::SubscriberInitExt, Layer, Registry};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, VecDeque};
+use std::fmt::Write as _;
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, OnceLock};
+use crate::debug_console::{add_console_message, ConsoleCommand, ConsoleHistory, MessageType};
+use crate::user_config::{read_config, write_config};
+
+const CONFIG_FILENAME: &str = "logging.ron";
+// Always applied ahead of the player's levels; the renderer is chatty well below warnings
+const BASE_DIRECTIVES: &str = "wgpu=error,naga=warn";
+const LOG_FILENAME: &str = "perch";
+const MAX_LOG_FILE_BYTES: u64 = 5 * 1024 * 1024;
+// Current file plus this many rolled-over ones
+const KEPT_LOG_FILES: usize = 4;
+const RECENT_LINES: usize = 1000;
+const VIEWER_LINES: usize = 40;
+// Module names starting with these are other crates' targets; anything else is one of ours
+const DEPENDENCY_PREFIXES: [&str; 7] = ["bevy", "wgpu", "naga", "rapier", "gilrs", "cosmic_text", "perch"];
+
+static FILTER_HANDLE: OnceLock<reload::Handle<EnvFilter, Registry>> = OnceLock::new();
+// Lines that passed the filter, newest last, and how many there have been in total
+static RECENT_LOGS: Mutex<VecDeque<LogLine>> = Mutex::new(VecDeque::new());
+static LINES_LOGGED: AtomicU64 = AtomicU64::new(0);
+
+pub struct LoggingPlugin;
+
+impl Plugin for LoggingPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .insert_resource(read_config::<LogLevels>(CONFIG_FILENAME).unwrap_or_default())
+            .init_resource::<LogViewer>()
+            .add_systems(Update, (
+                log_command_system,
+                log_viewer_button_system,
+                refresh_log_viewer_system,
+            ).chain().in_set(crate::GameSet::UI));
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum LogLevel {
+    Off,
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl LogLevel {
+    pub const ALL: [LogLevel; 6] = [Self::Off, Self::Error, Self::Warn, Self::Info, Self::Debug, Self::Trace];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Off => "off",
+            Self::Error => "error",
+            Self::Warn => "warn",
+            Self::Info => "info",
+            Self::Debug => "debug",
+            Self::Trace => "trace",
+        }
+    }
+
+    pub fn parse(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|level| level.label().eq_ignore_ascii_case(name))
+    }
+
+    fn from_tracing(level: &tracing::Level) -> Self {
+        match *level {
+            tracing::Level::ERROR => Self::Error,
+            tracing::Level::WARN => Self::Warn,
+            tracing::Level::INFO => Self::Info,
+            tracing::Level::DEBUG => Self::Debug,
+            tracing::Level::TRACE => Self::Trace,
+        }
+    }
+
+    fn color(&self) -> Color {
+        match self {
+            Self::Error => Color::srgb(0.75, 0.15, 0.1),
+            Self::Warn => Color::srgb(0.7, 0.45, 0.0),
+            Self::Info => Color::srgb(0.2, 0.1, 0.05),
+            _ => Color::srgb(0.5, 0.4, 0.3),
+        }
+    }
+}
+
+/// The default level plus overrides keyed by tracing target, saved to logging.ron
+#[derive(Resource, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LogLevels {
+    pub default_level: LogLevel,
+    #[serde(default)]
+    pub modules: BTreeMap<String, LogLevel>,
+}
+
+impl Default for LogLevels {
+    fn default() -> Self {
+        Self {
+            default_level: LogLevel::Info,
+            modules: BTreeMap::new(),
+        }
+    }
+}
+
+impl LogLevels {
+    // EnvFilter picks the most specific matching directive, so order doesn't matter
+    pub fn directives(&self) -> String {
+        let mut directives = format!("{},{}", self.default_level.label(), BASE_DIRECTIVES);
+        for (module, level) in &self.modules {
+            let _ = write!(directives, ",{}={}", module, level.label());
+        }
+        directives
+    }
+}
+
+// Game modules can be named the way they're written in the source; targets carry the crate name
+pub fn module_target(name: &str) -> String {
+    let name = name.trim_start_matches("crate::");
+    if DEPENDENCY_PREFIXES.iter().any(|prefix| name.starts_with(prefix)) {
+        name.to_string()
+    } else {
+        format!("perch::{}", name)
+    }
+}
+
+/// Installs the global subscriber; called first thing in main, with the log plugin disabled
+pub fn init() {
+    let levels = read_config::<LogLevels>(CONFIG_FILENAME).unwrap_or_default();
+    // RUST_LOG still wins at startup for one-off runs
+    let filter = EnvFilter::try_from_default_env()
+        .or_else(|_| EnvFilter::try_new(levels.directives()))
+        .unwrap_or_else(|_| EnvFilter::new(format!("info,{}", BASE_DIRECTIVES)));
+    let (filter, handle) = reload::Layer::new(filter);
+
+    let file_layer = match RotatingFile::open(log_directory()) {
+        Ok(file) => Some(tracing_subscriber::fmt::layer().with_ansi(false).with_writer(Mutex::new(file))),
+        Err(e) => {
+            eprintln!("Couldn't open the log file in {}: {}", log_directory().display(), e);
+            None
+        }
+    };
+
+    let installed = Registry::default()
+        .with(filter)
+        .with(tracing_subscriber::fmt::layer().with_writer(io::stderr))
+        .with(file_layer)
+        .with(LogCapture)
+        .try_init();
+    if installed.is_ok() {
+        let _ = FILTER_HANDLE.set(handle);
+    }
+}
+
+fn apply_levels(levels: &LogLevels) -> Result<(), String> {
+    let filter = EnvFilter::try_new(levels.directives()).map_err(|e| e.to_string())?;
+    let handle = FILTER_HANDLE.get().ok_or("the log filter isn't installed")?;
+    handle.reload(filter).map_err(|e| e.to_string())
+}
+
+pub fn log_directory() -> PathBuf {
+    dirs::data_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("AvianHaven")
+        .join("logs")
+}
+
+/// The most recent captured lines, oldest first, formatted for a plain text file
+pub fn recent_lines(count: usize) -> Vec<String> {
+    let Ok(logs) = RECENT_LOGS.lock() else { return Vec::new() };
+    logs.iter()
+        .skip(logs.len().saturating_sub(count))
+        .map(LogLine::formatted)
+        .collect()
+}
+
+// The log file, renamed aside for a fresh one at startup and whenever it fills up
+struct RotatingFile {
+    directory: PathBuf,
+    file: File,
+    written: u64,
+}
+
+impl RotatingFile {
+    fn open(directory: PathBuf) -> io::Result<Self> {
+        fs::create_dir_all(&directory)?;
+        Self::rotate(&directory);
+        let file = File::create(Self::path(&directory, 0))?;
+        Ok(Self { directory, file, written: 0 })
+    }
+
+    fn path(directory: &Path, generation: usize) -> PathBuf {
+        match generation {
+            0 => directory.join(format!("{}.log", LOG_FILENAME)),
+            n => directory.join(format!("{}.{}.log", LOG_FILENAME, n)),
+        }
+    }
+
+    // Shifts every file up a generation, dropping the oldest; Windows won't rename over a file
+    fn rotate(directory: &Path) {
+        let _ = fs::remove_file(Self::path(directory, KEPT_LOG_FILES));
+        for generation in (0..KEPT_LOG_FILES).rev() {
+            let _ = fs::rename(Self::path(directory, generation), Self::path(directory, generation + 1));
+        }
+    }
+}
+
+impl Write for RotatingFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.written + buf.len() as u64 > MAX_LOG_FILE_BYTES {
+            self.file.flush()?;
+            Self::rotate(&self.directory);
+            self.file = File::create(Self::path(&self.directory, 0))?;
+            self.written = 0;
+        }
+        let written = self.file.write(buf)?;
+        self.written += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
+}
+
+struct LogLine {
+    time: String,
+    level: LogLevel,
+    target: String,
+    message: String,
+}
+
+impl LogLine {
+    fn formatted(&self) -> String {
+        format!("{} {:>5} {}:{}", self.time, self.level.label().to_uppercase(), self.target, self.message)
+    }
+}
+
+// Keeps recent lines for the viewer and bug reports; sits behind the filter like the other layers
+struct LogCapture;
+
+impl<S: tracing::Subscriber> Layer<S> for LogCapture {
+    fn on_event(&self, event: &tracing::Event<'_>, _context: tracing_subscriber::layer::Context<'_, S>) {
+        let metadata = event.metadata();
+        let mut message = String::new();
+        event.record(&mut LineVisitor(&mut message));
+        let line = LogLine {
+            time: chrono::Local::now().format("%H:%M:%S%.3f").to_string(),
+            level: LogLevel::from_tracing(metadata.level()),
+            target: metadata.target().to_string(),
+            message,
+        };
+
+        let Ok(mut logs) = RECENT_LOGS.lock() else { return };
+        if logs.len() == RECENT_LINES {
+            logs.pop_front();
+        }
+        logs.push_back(line);
+        LINES_LOGGED.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+struct LineVisitor<'a>(&'a mut String);
+
+impl tracing::field::Visit for LineVisitor<'_> {
+    fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            let _ = write!(self.0, " {:?}", value);
+        } else {
+            let _ = write!(self.0, " {}={:?}", field.name(), value);
+        }
+    }
+}
+
+// Resources
+#[derive(Resource)]
+pub struct LogViewer {
+    pub is_open: bool,
+    // Most verbose level shown
+    pub level: LogLevel,
+    // Target prefix, such as perch::journal
+    pub module: Option<String>,
+    // Case-insensitive text the message or target has to contain
+    pub search: String,
+    refresh_timer: Timer,
+    lines_seen: u64,
+    panel_dirty: bool,
+}
+
+impl Default for LogViewer {
+    fn default() -> Self {
+        Self {
+            is_open: false,
+            level: LogLevel::Trace,
+            module: None,
+            search: String::new(),
+            refresh_timer: Timer::from_seconds(0.5, TimerMode::Repeating),
+            lines_seen: 0,
+            panel_dirty: false,
+        }
+    }
+}
+
+impl LogViewer {
+    fn matches(&self, line: &LogLine) -> bool {
+        if line.level > self.level {
+            return false;
+        }
+        if self.module.as_ref().is_some_and(|module| !line.target.starts_with(module.as_str())) {
+            return false;
+        }
+        let search = self.search.to_lowercase();
+        search.is_empty()
+            || line.message.to_lowercase().contains(&search)
+            || line.target.to_lowercase().contains(&search)
+    }
+
+    // Steps through the modules that have something in the buffer, then back to all of them
+    fn next_module(&self) -> Option<String> {
+        let Ok(logs) = RECENT_LOGS.lock() else { return None };
+        let mut modules: Vec<String> = logs.iter().map(|line| module_of(&line.target)).collect();
+        modules.sort();
+        modules.dedup();
+        match &self.module {
+            None => modules.into_iter().next(),
+            Some(current) => modules.into_iter().find(|module| module > current),
+        }
+    }
+}
+
+// perch::journal::systems groups under perch::journal; other crates group by crate
+fn module_of(target: &str) -> String {
+    let depth = if target.starts_with("perch::") { 2 } else { 1 };
+    target.split("::").take(depth).collect::<Vec<_>>().join("::")
+}
+
+// Components
+#[derive(Component)]
+pub struct LogViewerRoot;
+
+#[derive(Component)]
+pub enum LogViewerButton {
+    Level,
+    Module,
+    ClearFilters,
+    Close,
+}
+
+// Handles `log` and `logs`; the console itself only skips them in its unknown-command check
+pub fn log_command_system(
+    mut command_events: EventReader<ConsoleCommand>,
+    mut levels: ResMut<LogLevels>,
+    mut viewer: ResMut<LogViewer>,
+    mut console_history: ResMut<ConsoleHistory>,
+) {
+    for command in command_events.read() {
+        match command.command.as_str() {
+            "logs" => {
+                if command.args.is_empty() {
+                    viewer.is_open = !viewer.is_open;
+                } else {
+                    viewer.is_open = true;
+                    viewer.search = command.args.join(" ");
+                }
+                viewer.panel_dirty = true;
+                let message = match (viewer.is_open, viewer.search.is_empty()) {
+                    (false, _) => "Log viewer closed".to_string(),
+                    (true, true) => "Log viewer opened".to_string(),
+                    (true, false) => format!("Log viewer showing lines containing '{}'", viewer.search),
+                };
+                add_console_message(&mut console_history, message, MessageType::Success);
+            }
+            "log" => {
+                let (message, message_type) = change_levels(&mut levels, &command.args);
+                add_console_message(&mut console_history, message, message_type);
+            }
+            _ => {}
+        }
+    }
+}
+
+fn change_levels(levels: &mut LogLevels, args: &[String]) -> (String, MessageType) {
+    let previous = levels.clone();
+    let summary = match args {
+        [] => {
+            let mut summary = format!("Default log level: {}", levels.default_level.label());
+            for (module, level) in &levels.modules {
+                let _ = write!(summary, "\n  {} = {}", module, level.label());
+            }
+            return (summary, MessageType::Info);
+        }
+        [reset] if reset == "reset" => {
+            *levels = LogLevels::default();
+            "Log levels reset to info everywhere".to_string()
+        }
+        [level] => {
+            let Some(level) = LogLevel::parse(level) else {
+                return (format!("Unknown log level: {}. Use off, error, warn, info, debug or trace", level), MessageType::Error);
+            };
+            levels.default_level = level;
+            format!("Default log level set to {}", level.label())
+        }
+        [module, level] => {
+            let target = module_target(module);
+            if level == "reset" {
+                levels.modules.remove(&target);
+                format!("{} follows the default level again", target)
+            } else if let Some(level) = LogLevel::parse(level) {
+                levels.modules.insert(target.clone(), level);
+                format!("{} now logs at {}", target, level.label())
+            } else {
+                return (format!("Unknown log level: {}. Use off, error, warn, info, debug, trace or reset", level), MessageType::Error);
+            }
+        }
+        _ => return ("Usage: log [module] <level> | log reset".to_string(), MessageType::Warning),
+    };
+
+    if let Err(e) = apply_levels(levels) {
+        *levels = previous;
+        return (format!("Couldn't change log levels: {}", e), MessageType::Error);
+    }
+    if let Err(e) = write_config(CONFIG_FILENAME, &*levels) {
+        return (format!("{} (not saved: {})", summary, e), MessageType::Warning);
+    }
+    (summary, MessageType::Success)
+}
+
+type LogViewerButtonQuery<'w, 's> = Query<'w, 's, (&'static Interaction, &'static LogViewerButton), (Changed<Interaction>, With<Button>)>;
+
+pub fn log_viewer_button_system(
+    interaction_query: LogViewerButtonQuery,
+    mut viewer: ResMut<LogViewer>,
+) {
+    for (interaction, button) in &interaction_query {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        match button {
+            LogViewerButton::Level => {
+                // Error up to trace; off would leave nothing to look at
+                viewer.level = match viewer.level {
+                    LogLevel::Trace => LogLevel::Error,
+                    level => LogLevel::ALL[level as usize + 1],
+                };
+            }
+            LogViewerButton::Module => viewer.module = viewer.next_module(),
+            LogViewerButton::ClearFilters => {
+                viewer.level = LogLevel::Trace;
+                viewer.module = None;
+                viewer.search.clear();
+            }
+            LogViewerButton::Close => viewer.is_open = false,
+        }
+        viewer.panel_dirty = true;
+    }
+}
+
+pub fn refresh_log_viewer_system(
+    mut commands: Commands,
+    mut viewer: ResMut<LogViewer>,
+    panel_query: Query<Entity, With<LogViewerRoot>>,
+    time: Res<Time>,
+) {
+    viewer.refresh_timer.tick(time.delta());
+    let lines_logged = LINES_LOGGED.load(Ordering::Relaxed);
+    let new_lines = viewer.is_open && viewer.refresh_timer.just_finished() && lines_logged != viewer.lines_seen;
+    if !viewer.panel_dirty && !new_lines {
+        return;
+    }
+    viewer.panel_dirty = false;
+    viewer.lines_seen = lines_logged;
+
+    for root in &panel_query {
+        commands.entity(root).despawn();
+    }
+    if !viewer.is_open {
+        return;
+    }
+    spawn_log_viewer(&mut commands, &viewer);
+}
+
+fn spawn_viewer_button(parent: &mut ChildSpawnerCommands, label: &str, button: LogViewerButton) {
+    parent.spawn((
+        Button,
+        Node {
+            padding: UiRect::axes(Val::Px(6.0), Val::Px(2.0)),
+            ..default()
+        },
+        BackgroundColor(Color::srgb(0.6, 0.5, 0.4)),
+        BorderRadius::all(Val::Px(4.0)),
+        button,
+    )).with_children(|button| {
+        button.spawn((
+            Text::new(label),
+            TextFont { font_size: 10.0, ..default() },
+            TextColor(Color::WHITE),
+        ));
+    });
+}
+
+fn spawn_log_viewer(commands: &mut Commands, viewer: &LogViewer) {
+    let (matching, total) = match RECENT_LOGS.lock() {
+        Ok(logs) => {
+            let matching: Vec<(String, LogLevel)> = logs.iter()
+                .filter(|line| viewer.matches(line))
+                .map(|line| (line.formatted(), line.level))
+                .collect();
+            (matching, logs.len())
+        }
+        Err(_) => (Vec::new(), 0),
+    };
+
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            right: Val::Px(10.0),
+            bottom: Val::Px(10.0),
+            width: Val::Percent(60.0),
+            max_height: Val::Percent(55.0),
+            flex_direction: FlexDirection::Column,
+            padding: UiRect::all(Val::Px(10.0)),
+            row_gap: Val::Px(2.0),
+            overflow: Overflow::clip(),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.95, 0.92, 0.88, 0.95)),
+        BorderRadius::all(Val::Px(6.0)),
+        GlobalZIndex(40),
+        LogViewerRoot,
+        Name::new("LogViewer"),
+    )).with_children(|panel| {
+        panel.spawn(Node {
+            width: Val::Percent(100.0),
+            justify_content: JustifyContent::SpaceBetween,
+            align_items: AlignItems::Center,
+            ..default()
+        }).with_children(|header| {
+            header.spawn((
+                Text::new(format!("Log  ({} of {} lines)", matching.len(), total)),
+                TextFont { font_size: 16.0, ..default() },
+                TextColor(Color::srgb(0.3, 0.2, 0.1)),
+            ));
+            header.spawn(Node {
+                column_gap: Val::Px(4.0),
+                ..default()
+            }).with_children(|buttons| {
+                spawn_viewer_button(buttons, &format!("Level: {} and up", viewer.level.label()), LogViewerButton::Level);
+                spawn_viewer_button(buttons, &format!("Module: {}", viewer.module.as_deref().unwrap_or("all")), LogViewerButton::Module);
+                spawn_viewer_button(buttons, "Clear filters", LogViewerButton::ClearFilters);
+                spawn_viewer_button(buttons, "Close", LogViewerButton::Close);
+            });
+        });
+
+        if !viewer.search.is_empty() {
+            panel.spawn((
+                Text::new(format!("Containing '{}' - 'logs <text>' in the console to change", viewer.search)),
+                TextFont { font_size: 11.0, ..default() },
+                TextColor(Color::srgb(0.5, 0.4, 0.3)),
+            ));
+        }
+        if matching.is_empty() {
+            panel.spawn((
+                Text::new("No lines match these filters"),
+                TextFont { font_size: 12.0, ..default() },
+                TextColor(Color::srgb(0.5, 0.4, 0.3)),
+            ));
+        }
+
+        // Newest at the bottom, like a terminal
+        for (text, level) in matching.iter().skip(matching.len().saturating_sub(VIEWER_LINES)) {
+            panel.spawn((
+                Text::new(text.clone()),
+                TextFont { font_size: 10.0, ..default() },
+                TextColor(level.color()),
+            ));
+        }
+    });
+}
@@ -14,17 +14,16 @@ impl Plugin for LunexUiPlugin {
             .add_plugins(UiLunexPlugins)
             .add_systems(Startup, setup_lunex_system)
             .add_systems(OnEnter(crate::AppState::MainMenu), setup_lunex_main_menu)
-            .add_systems(OnExit(crate::AppState::MainMenu), cleanup_lunex_main_menu)
-            .add_systems(Update, handle_lunex_main_menu_clicks.run_if(in_state(crate::AppState::MainMenu).and(crate::debug_console::console_is_not_visible)))
+            .add_systems(Update, handle_lunex_main_menu_clicks.in_set(crate::GameSet::UI).run_if(in_state(crate::AppState::MainMenu).and(crate::debug_console::console_is_not_visible)))
             .add_systems(Update, (
                 create_lunex_settings_buttons,
                 handle_lunex_button_clicks,
-            ).run_if(in_state(crate::AppState::Settings)))
+            ).in_set(crate::GameSet::UI).run_if(in_state(crate::AppState::Settings)))
             .add_systems(Update, (
                 setup_lunex_tutorial_ui,
                 handle_lunex_tutorial_buttons,
                 update_lunex_tutorial_content,
-            ).run_if(in_state(crate::AppState::Playing)))
+            ).in_set(crate::GameSet::UI).run_if(in_state(crate::AppState::Playing)))
             // Disabled - using new journal implementation instead
             // .add_systems(OnEnter(crate::AppState::Journal), setup_lunex_journal_simple)
             // .add_systems(OnExit(crate::AppState::Journal), cleanup_lunex_journal)
@@ -33,15 +32,13 @@ impl Plugin for LunexUiPlugin {
             //     update_bevy_journal_content,
             // ).run_if(in_state(crate::AppState::Journal)))
             // Debug system disabled - use F1 key diagnostic instead
-            // .add_systems(Update, debug_journal_entities.run_if(in_state(crate::AppState::Journal)))
+            // .add_systems(Update, debug_journal_entities.in_set(crate::GameSet::UI).run_if(in_state(crate::AppState::Journal)))
             .add_systems(OnEnter(crate::AppState::Catalog), setup_lunex_catalog)
-            .add_systems(OnExit(crate::AppState::Catalog), cleanup_lunex_catalog)
             .add_systems(Update, (
                 handle_lunex_catalog_navigation.run_if(crate::debug_console::console_is_not_visible),
                 update_catalog_currency_display,
                 debug_lunex_catalog_state,
-                catalog_health_monitor,
-            ).run_if(in_state(crate::AppState::Catalog)));
+            ).in_set(crate::GameSet::UI).run_if(in_state(crate::AppState::Catalog)));
     }
 }
 
@@ -298,6 +295,7 @@ pub fn setup_lunex_tutorial_ui(
             UiLayoutRoot::new_2d(),
             UiFetchFromCamera::<0>,
             Name::new("Lunex Tutorial Root"),
+            StateScoped(crate::AppState::Playing),
         )).id()
     };
     
@@ -311,6 +309,7 @@ pub fn setup_lunex_tutorial_ui(
             ]),
             Name::new("Lunex Tutorial Dialog"),
             LunexTutorialUI,
+            StateScoped(crate::AppState::Playing),
             LunexMigrationMarker,
         )).with_children(|dialog| {
             // Tutorial content text area
@@ -579,25 +578,25 @@ pub fn cleanup_lunex_journal(
     bevy_journal_query: Query<Entity, With<BevyJournalUI>>,
     // root_query: Query<Entity, (With<UiLayoutRoot>, Without<LunexJournalUI>)>,
 ) {
-    info!("🔴 JOURNAL: cleanup_lunex_journal called");
+    debug!("🔴 JOURNAL: cleanup_lunex_journal called");
     
     let mut cleanup_count = 0;
     
     // Clean up Lunex journal entities
     for entity in lunex_journal_query.iter() {
-        info!("🔴 JOURNAL: Despawning Lunex journal entity: {:?}", entity);
+        debug!("🔴 JOURNAL: Despawning Lunex journal entity: {:?}", entity);
         commands.entity(entity).despawn();
         cleanup_count += 1;
     }
     
     // Clean up Bevy UI journal entities  
     for entity in bevy_journal_query.iter() {
-        info!("🔴 JOURNAL: Despawning Bevy journal entity: {:?}", entity);
+        debug!("🔴 JOURNAL: Despawning Bevy journal entity: {:?}", entity);
         commands.entity(entity).despawn();
         cleanup_count += 1;
     }
     
-    info!("🔴 JOURNAL: Cleaned up {} journal entities total", cleanup_count);
+    debug!("🔴 JOURNAL: Cleaned up {} journal entities total", cleanup_count);
     
     // Clean up journal root if it exists - simplified approach
     // Note: More targeted cleanup would require additional component markers
@@ -873,10 +872,10 @@ pub fn debug_lunex_catalog_state(
     keyboard: Res<ButtonInput<KeyCode>>,
 ) {
     if keyboard.just_pressed(KeyCode::KeyC) {
-        info!("=== Catalog Debug ===");
-        info!("Catalog state is_open: {}", catalog_state.is_open);
-        info!("Catalog UI entities: {}", catalog_ui_query.iter().len());
-        info!("Lunex root entities: {}", lunex_root_query.iter().len());
+        debug!("=== Catalog Debug ===");
+        debug!("Catalog state is_open: {}", catalog_state.is_open);
+        debug!("Catalog UI entities: {}", catalog_ui_query.iter().len());
+        debug!("Lunex root entities: {}", lunex_root_query.iter().len());
     }
 }
 
@@ -910,14 +909,17 @@ pub fn setup_test_lunex_catalog(mut commands: Commands) {
 pub fn setup_lunex_catalog(
     mut commands: Commands,
     catalog_state: Res<crate::catalog::resources::CatalogState>,
+    registry: Res<crate::smart_objects::SmartObjectRegistry>,
+    asset_server: Res<AssetServer>,
+    time_state: Res<crate::environment::resources::TimeState>,
     time: Res<Time>,
 ) {
     let setup_start = Instant::now();
     let timestamp = time.elapsed_secs();
     
-    info!("🟢 CATALOG SETUP: === STARTING CATALOG SETUP AT {:.3}s ===", timestamp);
-    info!("🟢 CATALOG SETUP: Restoring original catalog design with Bevy UI");
-    info!("🟢 CATALOG SETUP: Catalog state is_open: {}", catalog_state.is_open);
+    debug!("🟢 CATALOG SETUP: === STARTING CATALOG SETUP AT {:.3}s ===", timestamp);
+    debug!("🟢 CATALOG SETUP: Restoring original catalog design with Bevy UI");
+    debug!("🟢 CATALOG SETUP: Catalog state is_open: {}", catalog_state.is_open);
     
     // Create full-screen modal catalog (original design restored)
     commands.spawn((
@@ -934,6 +936,7 @@ pub fn setup_lunex_catalog(
         BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.8)), // Dark overlay
         Name::new("Catalog Modal Background"),
         BevyCatalogUI,
+        StateScoped(crate::AppState::Catalog),
     )).with_children(|modal| {
         // Main catalog window (centered, 85% screen size)
         modal.spawn((
@@ -1201,42 +1204,16 @@ pub fn setup_lunex_catalog(
                             }
                         });
                     }
+                    
+                    // Every seasonal look of items that dress up through the year
+                    crate::seasonal_decor::spawn_seasonal_previews(grid, &registry, &asset_server, time_state.get_season());
                 });
             });
         });
     });
     
     let setup_duration = setup_start.elapsed();
-    info!("🟢 CATALOG SETUP: === CATALOG SETUP COMPLETED SUCCESSFULLY in {:?} ===", setup_duration);
-}
-
-// System to cleanup catalog UI on state exit (both Lunex and Bevy UI)
-pub fn cleanup_lunex_catalog(
-    mut commands: Commands,
-    lunex_catalog_query: Query<Entity, With<LunexCatalogUI>>,
-    bevy_catalog_query: Query<Entity, With<BevyCatalogUI>>,
-    time: Res<Time>,
-) {
-    let timestamp = time.elapsed_secs();
-    let lunex_count = lunex_catalog_query.iter().count();
-    let bevy_count = bevy_catalog_query.iter().count();
-    
-    info!("🔴 CATALOG CLEANUP: === CLEANUP TRIGGERED AT {:.3}s ===", timestamp);
-    info!("🔴 CATALOG CLEANUP: Cleaning up {} Lunex + {} Bevy catalog entities", lunex_count, bevy_count);
-    
-    // Clean up Lunex entities
-    for entity in lunex_catalog_query.iter() {
-        info!("🔴 CATALOG CLEANUP: Despawning Lunex entity: {:?}", entity);
-        commands.entity(entity).despawn();
-    }
-    
-    // Clean up Bevy entities  
-    for entity in bevy_catalog_query.iter() {
-        info!("🔴 CATALOG CLEANUP: Despawning Bevy entity: {:?}", entity);
-        commands.entity(entity).despawn();
-    }
-    
-    info!("🔴 CATALOG CLEANUP: Catalog cleanup completed");
+    debug!("🟢 CATALOG SETUP: === CATALOG SETUP COMPLETED SUCCESSFULLY in {:?} ===", setup_duration);
 }
 
 // System to handle catalog navigation with diagnostics
@@ -1264,36 +1241,10 @@ pub fn update_catalog_currency_display(
     }
 }
 
-// System to monitor catalog health every 1000ms as requested
-pub fn catalog_health_monitor(
-    lunex_catalog_query: Query<Entity, With<LunexCatalogUI>>,
-    bevy_catalog_query: Query<Entity, With<BevyCatalogUI>>,
-    time: Res<Time>,
-    mut last_check: Local<f32>,
-) {
-    let current_time = time.elapsed_secs();
-    
-    // Check every 1000ms (1 second) as requested
-    if current_time - *last_check >= 1.0 {
-        *last_check = current_time;
-        
-        let lunex_count = lunex_catalog_query.iter().count();
-        let bevy_count = bevy_catalog_query.iter().count();
-        let total_count = lunex_count + bevy_count;
-        
-        if total_count == 0 {
-            error!("🚨 CATALOG HEALTH: NO catalog UI entities found at {:.3}s! Something despawned them!", current_time);
-        } else {
-            info!("✅ CATALOG HEALTH: {} total catalog entities healthy at {:.3}s (Lunex: {}, Bevy: {})", 
-                  total_count, current_time, lunex_count, bevy_count);
-        }
-    }
-}
-
 // System to setup journal using Bevy UI (stable working implementation)
 pub fn setup_lunex_journal_simple(mut commands: Commands) {
-    info!("🔵 JOURNAL SETUP: === STARTING JOURNAL SETUP ===");
-    info!("🔵 JOURNAL SETUP: Creating Bevy UI journal (stable implementation)");
+    debug!("🔵 JOURNAL SETUP: === STARTING JOURNAL SETUP ===");
+    debug!("🔵 JOURNAL SETUP: Creating Bevy UI journal (stable implementation)");
     
     // Create ONLY the Bevy UI journal (remove conflicting Lunex UI)
     commands.spawn((
@@ -1372,8 +1323,8 @@ pub fn setup_lunex_journal_simple(mut commands: Commands) {
         ));
     });
     
-    info!("🔵 JOURNAL SETUP: Created professional Bevy UI journal interface");
-    info!("🔵 JOURNAL SETUP: === JOURNAL SETUP COMPLETED SUCCESSFULLY ===");
+    debug!("🔵 JOURNAL SETUP: Created professional Bevy UI journal interface");
+    debug!("🔵 JOURNAL SETUP: === JOURNAL SETUP COMPLETED SUCCESSFULLY ===");
 }
 
 // Debug system to track journal entities
@@ -1386,7 +1337,7 @@ pub fn debug_journal_entities(
     let root_count = root_query.iter().count();
     let text_count = text_query.iter().count();
     
-    info!("🔵 JOURNAL DEBUG: Journal entities: {}, Root entities: {}, Text entities: {}", 
+    trace!("🔵 JOURNAL DEBUG: Journal entities: {}, Root entities: {}, Text entities: {}", 
           journal_count, root_count, text_count);
 }
 
@@ -1478,6 +1429,7 @@ pub fn setup_lunex_main_menu(mut commands: Commands) {
         UiLayoutRoot::new_2d(),
         UiFetchFromCamera::<0>,
         Name::new("Lunex Main Menu Root"),
+        StateScoped(crate::AppState::MainMenu),
     )).with_children(|ui| {
         // Main menu container - centered
         ui.spawn((
@@ -1534,17 +1486,6 @@ pub fn setup_lunex_main_menu(mut commands: Commands) {
     });
 }
 
-// System to cleanup Lunex main menu on state exit
-pub fn cleanup_lunex_main_menu(
-    mut commands: Commands,
-    menu_query: Query<Entity, With<LunexMainMenuUI>>,
-) {
-    info!("Cleaning up Lunex Main Menu UI");
-    for entity in menu_query.iter() {
-        commands.entity(entity).despawn();
-    }
-}
-
 // System to handle main menu button clicks (keyboard and mouse)
 pub fn handle_lunex_main_menu_clicks(
     keyboard: Res<ButtonInput<KeyCode>>,
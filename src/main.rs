@@ -46,6 +46,62 @@ mod bird_selection; // Bird selection system with info cards
 mod automated_testing; // Automated testing system with time acceleration
 mod debug_console; // In-game debug console with ~ toggle
 mod garden_lighting; // Garden lighting system with solar lights and moth attraction
+mod family_groups; // Juvenile begging and family group behaviors
+mod bird_health; // Body condition and supplemental feeding consequences
+mod domestic_threats; // Neighborhood cats and dogs
+mod ambient_wildlife; // Chipmunks, rabbits and deer
+mod clip_recorder; // Rolling buffer clip capture of notable moments
+mod dialog; // Conversation panel for visiting characters
+mod npc_visitors; // Scientists, neighbors and rangers who drop by
+mod trading_post; // Daily rotating trades for surplus seed, photos and décor
+mod grants; // Conservation grant applications and budget planner
+mod weather_radar; // Precipitation radar minimap and forecast panel
+mod feeder_ticker; // Live list of birds at each feeder
+mod attraction_advisor; // Target species and advice on attracting them
+mod difficulty; // Difficulty presets and realism settings
+mod species_packs; // Regional species rosters loaded as data assets
+mod hummingbirds; // Hummingbird nectar, torpor and territorial chases
+mod cavity_nesters; // Woodpecker snags, cavity excavation and secondary nesters
+mod corvids; // Corvid cache memory, peanut training and gifts
+mod bird_cam; // Idle live-webcam mode that tours the busiest feeders
+mod tripod; // Placeable tripod cameras fired by remote shutter
+mod garden_styles; // Cottage, zen and rustic style sets with bonuses and badges
+mod seasonal_decor; // Per-season sprite variants swapped at season change
+mod garden_lines; // Fence, hedge and path drawing tools
+mod terrain_painting; // Ground cover painted onto a chunked grid
+mod elevation; // Height tiers for feeders and placed objects
+mod feeder_physics; // Swinging feeders, tipping platforms and spilled seed
+mod rodents; // Night rodents drawn by stale seed, and the owls that hunt them
+mod stealth; // Observer presence and bird flush radii
+mod call_playback; // Recorded call lures with ethical limits
+mod search_palette; // Ctrl+K quick search across game content
+mod event_inspector; // F8 developer log of recent game events
+mod object_atlas; // Placed-object sprites packed into a shared atlas
+mod user_config; // Versioned config directory with import/export
+mod steam_deck; // Steam Deck preset, frame cap and gamepad menu navigation
+mod binoculars; // Zoomed observation view with field-mark ID hints
+mod field_marks; // Teaching callouts on a centered bird's field marks
+mod sound_id; // Limited-use gadget that identifies calling birds
+mod photo_contest; // Seasonal photo contest with an NPC judge and yard trophies
+mod gear_care; // Storm wear on the camera and feeders, weather sealing and insurance
+mod garden_pests; // Aphid and tent caterpillar outbreaks on the flower beds
+mod compost; // Compost bin turning seed hulls and spills into fertilizer
+mod water_quality; // Fouling and algae in baths and ponds, scrubbing and solar aerators
+mod neighborhood_noise; // Off-screen lawnmowers, dogs and construction that unsettle the birds
+mod spawn_debug; // Dev-build panel breaking down per-species spawn weights
+mod content_validation; // Startup check of data files with a Content Problems dialog and strict mode
+mod hot_reload; // Dev mode pushing edited data files into the running game
+mod feeder_stats; // Per-feeder visit, cost and uptime records with report cards
+mod experiments; // Before-and-after comparisons of yard layout changes
+mod size_reference; // Sparrow/robin/crow scale bar under a centered bird
+mod lightning; // Storm lightning strikes that scorch trees and leave snags
+mod haptics; // Gamepad rumble for the shutter, achievements, thunder and feeders
+mod bug_report; // F10 bug report zip with screenshot, recent log and system specs
+mod logging; // Per-module log levels, rotating log file and in-game log viewer
+#[cfg(feature = "visual-regression")]
+mod visual_regression; // Screenshot comparison of UI screens against baselines
+#[cfg(feature = "wasm-mods")]
+mod wasm_mods; // Versioned wasmtime host for compiled mods
 
 use user_interface::UserInterfacePlugin;
 use bird::BirdPlugin;
@@ -57,7 +113,7 @@ use bird_ai::BirdAiPlugin;
 use animation::AnimationPlugin;
 use photo_mode::PhotoModePlugin;
 use journal::JournalPlugin;
-use despawn::{robust_despawn_system};
+use despawn::{robust_despawn_system, track_state_roots_system, despawn_audit_system, DespawnAudit};
 use audio::AudioPlugin;
 use achievements::AchievementPlugin;
 use notifications::NotificationPlugin;
@@ -85,6 +141,57 @@ use loading_screen::LoadingScreenPlugin;
 use automated_testing::AutomatedTestingPlugin;
 use debug_console::DebugConsolePlugin;
 use garden_lighting::GardenLightingPlugin;
+use family_groups::FamilyGroupsPlugin;
+use bird_health::BirdHealthPlugin;
+use domestic_threats::DomesticThreatsPlugin;
+use ambient_wildlife::AmbientWildlifePlugin;
+use clip_recorder::ClipRecorderPlugin;
+use dialog::DialogPlugin;
+use npc_visitors::NpcVisitorsPlugin;
+use trading_post::TradingPostPlugin;
+use grants::GrantsPlugin;
+use weather_radar::WeatherRadarPlugin;
+use feeder_ticker::FeederTickerPlugin;
+use attraction_advisor::AttractionAdvisorPlugin;
+use difficulty::DifficultyPlugin;
+use species_packs::SpeciesPacksPlugin;
+use hummingbirds::HummingbirdsPlugin;
+use cavity_nesters::CavityNestersPlugin;
+use corvids::CorvidsPlugin;
+use bird_cam::BirdCamPlugin;
+use tripod::TripodPlugin;
+use garden_styles::GardenStylesPlugin;
+use seasonal_decor::SeasonalDecorPlugin;
+use garden_lines::GardenLinesPlugin;
+use terrain_painting::TerrainPaintingPlugin;
+use elevation::ElevationPlugin;
+use feeder_physics::FeederPhysicsPlugin;
+use rodents::RodentsPlugin;
+use stealth::StealthPlugin;
+use call_playback::CallPlaybackPlugin;
+use search_palette::SearchPalettePlugin;
+use event_inspector::EventInspectorPlugin;
+use object_atlas::ObjectAtlasPlugin;
+use steam_deck::SteamDeckPlugin;
+use binoculars::BinocularsPlugin;
+use field_marks::FieldMarksPlugin;
+use sound_id::SoundIdPlugin;
+use photo_contest::PhotoContestPlugin;
+use gear_care::GearCarePlugin;
+use garden_pests::GardenPestsPlugin;
+use compost::CompostPlugin;
+use water_quality::WaterQualityPlugin;
+use neighborhood_noise::NeighborhoodNoisePlugin;
+use spawn_debug::SpawnDebugPlugin;
+use content_validation::ContentValidationPlugin;
+use hot_reload::HotReloadPlugin;
+use feeder_stats::FeederStatsPlugin;
+use experiments::ExperimentPlugin;
+use size_reference::SizeReferencePlugin;
+use lightning::LightningPlugin;
+use haptics::HapticsPlugin;
+use bug_report::BugReportPlugin;
+use logging::LoggingPlugin;
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Default, States)]
 #[states(scoped_entities)]
@@ -97,16 +204,46 @@ pub enum AppState {
     Catalog,
     Settings,
     LoadGame,
+    NewGameSetup,
+}
+
+// Frame stages every plugin's Update systems run in, in this order
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum GameSet {
+    // Keyboard, mouse and tool toggles
+    Input,
+    // Time, weather, feeders, economy and other world state
+    Simulation,
+    // Behavior tree and everything that overrides bird states after it
+    AI,
+    // Particles, lighting, audio and other visuals that read the settled world
+    Presentation,
+    // Panels and menus rebuilt from the frame's final state
+    UI,
 }
 
 fn main() {
-    App::new()
-        .add_plugins(DefaultPlugins)
+    logging::init();
+    let mut app = App::new();
+    // Player-imported ambient audio and species packs have to be registered before the asset server starts
+    audio::soundscape::register_user_ambient_source(&mut app);
+    species_packs::register_mod_pack_source(&mut app);
+    
+    app
+        // Logging installs its own subscriber so levels can change while the game runs
+        .add_plugins(DefaultPlugins.build().disable::<bevy::log::LogPlugin>())
         .add_plugins((
             RapierPhysicsPlugin::<NoUserData>::pixels_per_meter(100.0),
             FrameTimeDiagnosticsPlugin::default(),
         ))
         .init_state::<AppState>()
+        .configure_sets(Update, (
+            GameSet::Input,
+            GameSet::Simulation,
+            GameSet::AI,
+            GameSet::Presentation,
+            GameSet::UI,
+        ).chain())
         .init_resource::<GameConfig>()
         .add_plugins((
             BirdPlugin,
@@ -151,9 +288,67 @@ fn main() {
         .add_plugins(AutomatedTestingPlugin)
         .add_plugins(DebugConsolePlugin)
         .add_plugins(GardenLightingPlugin)
+        .add_plugins(FamilyGroupsPlugin)
+        .add_plugins(BirdHealthPlugin)
+        .add_plugins(DomesticThreatsPlugin)
+        .add_plugins(AmbientWildlifePlugin)
+        .add_plugins(ClipRecorderPlugin)
+        .add_plugins(DialogPlugin)
+        .add_plugins(NpcVisitorsPlugin)
+        .add_plugins(TradingPostPlugin)
+        .add_plugins(GrantsPlugin)
+        .add_plugins(WeatherRadarPlugin)
+        .add_plugins(FeederTickerPlugin)
+        .add_plugins(AttractionAdvisorPlugin)
+        .add_plugins(DifficultyPlugin)
+        .add_plugins(SpeciesPacksPlugin)
+        .add_plugins(HummingbirdsPlugin)
+        .add_plugins(CavityNestersPlugin)
+        .add_plugins(CorvidsPlugin)
+        .add_plugins(BirdCamPlugin)
+        .add_plugins(TripodPlugin)
+        .add_plugins(GardenStylesPlugin)
+        .add_plugins(SeasonalDecorPlugin)
+        .add_plugins(GardenLinesPlugin)
+        .add_plugins(TerrainPaintingPlugin)
+        .add_plugins(ElevationPlugin)
+        .add_plugins(FeederPhysicsPlugin)
+        .add_plugins(RodentsPlugin)
+        .add_plugins(StealthPlugin)
+        .add_plugins(CallPlaybackPlugin)
+        .add_plugins(SearchPalettePlugin)
+        .add_plugins(EventInspectorPlugin)
+        .add_plugins(ObjectAtlasPlugin)
+        .add_plugins(SteamDeckPlugin)
+        .add_plugins(BinocularsPlugin)
+        .add_plugins(FieldMarksPlugin)
+        .add_plugins(SoundIdPlugin)
+        .add_plugins(PhotoContestPlugin)
+        .add_plugins(GearCarePlugin)
+        .add_plugins(GardenPestsPlugin)
+        .add_plugins(CompostPlugin)
+        .add_plugins(WaterQualityPlugin)
+        .add_plugins(NeighborhoodNoisePlugin)
+        .add_plugins(SpawnDebugPlugin)
+        .add_plugins(ContentValidationPlugin)
+        .add_plugins(HotReloadPlugin)
+        .add_plugins(FeederStatsPlugin)
+        .add_plugins(ExperimentPlugin)
+        .add_plugins(SizeReferencePlugin)
+        .add_plugins(LightningPlugin)
+        .add_plugins(HapticsPlugin)
+        .add_plugins(BugReportPlugin)
+        .add_plugins(LoggingPlugin)
         .add_systems(Startup, setup)
-        .add_systems(PostUpdate, robust_despawn_system)
-        .run();
+        .init_resource::<DespawnAudit>()
+        .add_systems(PostUpdate, (robust_despawn_system, track_state_roots_system, despawn_audit_system));
+
+    #[cfg(feature = "visual-regression")]
+    app.add_plugins(visual_regression::VisualRegressionPlugin);
+    #[cfg(feature = "wasm-mods")]
+    app.add_plugins(wasm_mods::WasmModsPlugin);
+
+    app.run();
 }
 
 fn setup(mut commands: Commands) {
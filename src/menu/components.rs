@@ -24,11 +24,48 @@ pub struct TabTestWindow;
 #[derive(Component)]
 pub struct MenuTitle;
 
+// Sanctuary snapshot or chosen photo behind the main menu, panned slowly across
+#[derive(Component)]
+pub struct MenuBackdrop {
+    pub size: Vec2,
+    pub elapsed: f32,
+}
+
+// Rain, snow or a falling leaf drifting over the menu backdrop, in percent of the screen per second
+#[derive(Component)]
+pub struct MenuWeatherParticle {
+    pub velocity: Vec2,
+    pub sway: f32,
+    pub phase: f32,
+}
+
+#[derive(Component)]
+pub struct MenuBackdropButton;
+
+#[derive(Component)]
+pub struct MenuBackdropValueText;
+
+#[derive(Component)]
+pub struct ClipCaptureButton;
+
+#[derive(Component)]
+pub struct ClipCaptureValueText;
+
 #[derive(Component)]
 pub struct SaveSlotCard {
     pub slot: u32,
 }
 
+#[derive(Component)]
+pub struct NewGameSetupButton {
+    pub action: NewGameSetupAction,
+}
+
+#[derive(Component)]
+pub struct RegionCard {
+    pub region: crate::environment::region::Region,
+}
+
 #[derive(Component)]
 pub struct SettingsSlider {
     pub setting: SettingType,
@@ -52,6 +89,15 @@ pub enum SettingsAction {
     ApplySettings,
     OpenControls,
     BackToSettings,
+    ExportConfig,
+    ImportConfig,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NewGameSetupAction {
+    SelectRegion(crate::environment::region::Region),
+    Begin,
+    Back,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -59,7 +105,324 @@ pub enum SettingType {
     MasterVolume,
     MusicVolume,
     SfxVolume,
-    AutoSave,
+    CallVolume,
+    AmbientVolume,
+    MaxBirdVoices,
+}
+
+#[derive(Component)]
+pub struct DifficultyButton {
+    pub target: DifficultyTarget,
+}
+
+#[derive(Component)]
+pub struct DifficultyValueText {
+    pub target: DifficultyTarget,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DifficultyTarget {
+    Preset,
+    Aspect(crate::difficulty::RealismAspect),
+}
+
+#[derive(Component)]
+pub struct BirdCamSettingButton {
+    pub target: BirdCamSetting,
+}
+
+#[derive(Component)]
+pub struct BirdCamValueText {
+    pub target: BirdCamSetting,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BirdCamSetting {
+    IdleDelay,
+    LaunchOnStart,
+}
+
+impl BirdCamSetting {
+    pub fn value_label(&self, settings: &crate::menu::resources::GameSettings) -> String {
+        match self {
+            Self::IdleDelay => crate::bird_cam::idle_delay_label(settings.bird_cam_idle_minutes),
+            Self::LaunchOnStart => if settings.launch_into_bird_cam { "ON" } else { "OFF" }.to_string(),
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct AccessibilitySettingButton {
+    pub target: AccessibilitySetting,
+}
+
+#[derive(Component)]
+pub struct AccessibilityValueText {
+    pub target: AccessibilitySetting,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AccessibilitySetting {
+    FieldMarkCallouts,
+    SizeReference,
+    SoundCaptions,
+    CaptionBirdCalls,
+    CaptionNature,
+    CaptionYard,
+    CaptionNeighborhood,
+    CaptionMnemonics,
+}
+
+impl AccessibilitySetting {
+    pub const ALL: [AccessibilitySetting; 8] = [
+        Self::FieldMarkCallouts,
+        Self::SizeReference,
+        Self::SoundCaptions,
+        Self::CaptionBirdCalls,
+        Self::CaptionNature,
+        Self::CaptionYard,
+        Self::CaptionNeighborhood,
+        Self::CaptionMnemonics,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::FieldMarkCallouts => "Field Mark Callouts",
+            Self::SizeReference => "Size Reference",
+            Self::SoundCaptions => "Sound Captions",
+            Self::CaptionBirdCalls => "Captions: Bird Calls",
+            Self::CaptionNature => "Captions: Nature",
+            Self::CaptionYard => "Captions: Yard",
+            Self::CaptionNeighborhood => "Captions: Neighborhood",
+            Self::CaptionMnemonics => "Captions: Song Mnemonics",
+        }
+    }
+
+    pub fn value_label(&self, settings: &crate::menu::resources::GameSettings) -> String {
+        match self {
+            Self::FieldMarkCallouts => if settings.field_mark_callouts { "ON" } else { "OFF" }.to_string(),
+            Self::SizeReference => if settings.size_reference_overlay { "ON" } else { "OFF" }.to_string(),
+            Self::SoundCaptions => if settings.sound_captions { "ON" } else { "OFF" }.to_string(),
+            Self::CaptionBirdCalls => if settings.caption_bird_calls { "ON" } else { "OFF" }.to_string(),
+            Self::CaptionNature => if settings.caption_nature { "ON" } else { "OFF" }.to_string(),
+            Self::CaptionYard => if settings.caption_yard { "ON" } else { "OFF" }.to_string(),
+            Self::CaptionNeighborhood => if settings.caption_neighborhood { "ON" } else { "OFF" }.to_string(),
+            Self::CaptionMnemonics => if settings.caption_mnemonics { "ON" } else { "OFF" }.to_string(),
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct AutoSaveSettingButton {
+    pub target: AutoSaveSetting,
+}
+
+#[derive(Component)]
+pub struct AutoSaveValueText {
+    pub target: AutoSaveSetting,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AutoSaveSetting {
+    Enabled,
+    Interval,
+    Slots,
+    AfterPurchase,
+    AfterRareSighting,
+    OnQuit,
+}
+
+impl AutoSaveSetting {
+    pub const ALL: [AutoSaveSetting; 6] = [
+        Self::Enabled, Self::Interval, Self::Slots, Self::AfterPurchase, Self::AfterRareSighting, Self::OnQuit,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Enabled => "Auto-Save",
+            Self::Interval => "Auto-Save Every",
+            Self::Slots => "Auto-Save Slots",
+            Self::AfterPurchase => "Save After Purchases",
+            Self::AfterRareSighting => "Save After Rare Sightings",
+            Self::OnQuit => "Save On Quit",
+        }
+    }
+
+    pub fn value_label(&self, settings: &crate::menu::resources::GameSettings) -> String {
+        let on_off = |enabled: bool| if enabled { "ON" } else { "OFF" }.to_string();
+        match self {
+            Self::Enabled => on_off(settings.auto_save_enabled),
+            Self::Interval => format!("{} min", settings.auto_save_interval),
+            Self::Slots => settings.auto_save_slots.to_string(),
+            Self::AfterPurchase => on_off(settings.save_after_purchase),
+            Self::AfterRareSighting => on_off(settings.save_after_rare_sighting),
+            Self::OnQuit => on_off(settings.save_on_quit),
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct HudSettingButton {
+    pub target: HudSetting,
+}
+
+#[derive(Component)]
+pub struct HudValueText {
+    pub target: HudSetting,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HudSetting {
+    CurrencyCorner,
+    StatusBar,
+}
+
+impl HudSetting {
+    pub const ALL: [HudSetting; 2] = [Self::CurrencyCorner, Self::StatusBar];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::CurrencyCorner => "Currency Display",
+            Self::StatusBar => "Status Bar",
+        }
+    }
+
+    pub fn value_label(&self, layout: &crate::ui::HudLayout) -> String {
+        match self {
+            Self::CurrencyCorner => layout.currency_corner.label().to_string(),
+            Self::StatusBar => if layout.status_bar_at_top { "Top" } else { "Bottom" }.to_string(),
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct DeckSettingButton {
+    pub target: DeckSetting,
+}
+
+#[derive(Component)]
+pub struct DeckValueText {
+    pub target: DeckSetting,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DeckSetting {
+    Mode,
+    FrameCap,
+}
+
+impl DeckSetting {
+    pub const ALL: [DeckSetting; 2] = [Self::Mode, Self::FrameCap];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Mode => "Steam Deck Mode",
+            Self::FrameCap => "Frame Cap",
+        }
+    }
+
+    pub fn value_label(&self, settings: &crate::menu::resources::GameSettings) -> String {
+        match self {
+            Self::Mode => settings.deck_mode.label().to_string(),
+            Self::FrameCap if settings.frame_cap == 0 => "OFF".to_string(),
+            Self::FrameCap => format!("{} FPS", settings.frame_cap),
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct DisplaySettingButton {
+    pub target: DisplaySetting,
+}
+
+#[derive(Component)]
+pub struct DisplayValueText {
+    pub target: DisplaySetting,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DisplaySetting {
+    FullscreenType,
+    Monitor,
+    RefreshRate,
+}
+
+impl DisplaySetting {
+    pub const ALL: [DisplaySetting; 3] = [Self::FullscreenType, Self::Monitor, Self::RefreshRate];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::FullscreenType => "Fullscreen Type",
+            Self::Monitor => "Monitor",
+            Self::RefreshRate => "Refresh Rate",
+        }
+    }
+
+    pub fn value_label(&self, settings: &crate::menu::resources::GameSettings) -> String {
+        match self {
+            Self::FullscreenType if settings.exclusive_fullscreen => "Exclusive".to_string(),
+            Self::FullscreenType => "Borderless".to_string(),
+            Self::Monitor => settings.monitor.clone().unwrap_or_else(|| "Current".to_string()),
+            Self::RefreshRate => match settings.refresh_rate_millihertz {
+                None => "Monitor Default".to_string(),
+                Some(millihertz) if millihertz % 1000 == 0 => format!("{} Hz", millihertz / 1000),
+                Some(millihertz) => format!("{:.2} Hz", millihertz as f32 / 1000.0),
+            },
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct SafeAreaSlider;
+
+// Camera the UI moves onto while a safe area is set, so it can be inset from the window edges
+#[derive(Component)]
+pub struct SafeAreaUiCamera;
+
+#[derive(Component)]
+pub struct RumbleSettingButton {
+    pub target: RumbleSetting,
+}
+
+#[derive(Component)]
+pub struct RumbleValueText {
+    pub target: RumbleSetting,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RumbleSetting {
+    Intensity,
+    Shutter,
+    Achievements,
+    Thunder,
+    Feeders,
+}
+
+impl RumbleSetting {
+    pub const ALL: [RumbleSetting; 5] = [Self::Intensity, Self::Shutter, Self::Achievements, Self::Thunder, Self::Feeders];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Intensity => "Controller Rumble",
+            Self::Shutter => "Photo Shutter",
+            Self::Achievements => "Achievements",
+            Self::Thunder => "Thunder",
+            Self::Feeders => "Feeders",
+        }
+    }
+
+    pub fn value_label(&self, settings: &crate::menu::resources::GameSettings) -> String {
+        let rumble = &settings.rumble;
+        let on_off = |enabled: bool| if enabled { "ON" } else { "OFF" }.to_string();
+        match self {
+            Self::Intensity if rumble.intensity <= 0.0 => "OFF".to_string(),
+            Self::Intensity => format!("{:.0}%", rumble.intensity * 100.0),
+            Self::Shutter => on_off(rumble.shutter),
+            Self::Achievements => on_off(rumble.achievements),
+            Self::Thunder => on_off(rumble.thunder),
+            Self::Feeders => on_off(rumble.feeders),
+        }
+    }
 }
 
 #[derive(Component)]
@@ -67,6 +430,18 @@ pub struct VolumeSlider {
     pub setting_type: SettingType,
 }
 
+// Holds the soundscape heading, sliders and import button so they can be rebuilt together
+#[derive(Component)]
+pub struct SoundscapeSection;
+
+#[derive(Component)]
+pub struct SoundscapeSlider {
+    pub layer: crate::audio::soundscape::AmbientLayer,
+}
+
+#[derive(Component)]
+pub struct ImportAmbientButton;
+
 #[derive(Component)]
 pub struct GraphicsToggle {
     pub setting_type: GraphicsSettingType,
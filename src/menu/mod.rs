@@ -16,40 +16,71 @@ impl Plugin for MenuPlugin {
         info!("Registering MenuPlugin with resolution dropdown setup");
         app
             .init_resource::<MenuState>()
+            .init_resource::<NewGameSetup>()
             .init_resource::<crate::ui_widgets::CursorPosition>()
             .add_event::<MenuNavigationEvent>()
             .add_event::<crate::user_interface::slider::SliderValueChangedEvent>()
             .add_event::<crate::user_interface::dropdown::DropdownChangedEvent>()
             .add_systems(Startup, load_settings_on_startup)
             .add_systems(OnEnter(AppState::MainMenu), setup_main_menu) // Re-enabled as fallback
-            .add_systems(OnEnter(AppState::Settings), (setup_settings_menu, setup_audio_sliders_system, setup_resolution_dropdown_system).chain())
+            .add_systems(OnEnter(AppState::Settings), (setup_settings_menu, setup_audio_sliders_system, setup_soundscape_sliders_system, setup_resolution_dropdown_system).chain())
             .add_systems(OnEnter(AppState::LoadGame), setup_load_game_menu)
-            .add_systems(OnExit(AppState::MainMenu), cleanup_menu_ui)
-            .add_systems(OnExit(AppState::Settings), cleanup_menu_ui)
-            .add_systems(OnExit(AppState::LoadGame), cleanup_menu_ui)
+            .add_systems(OnEnter(AppState::NewGameSetup), setup_new_game_menu)
             .add_systems(Update, (
                 main_menu_button_system,
                 menu_navigation_system,
-            ).run_if(in_state(AppState::MainMenu))) // Re-enabled as fallback
+                menu_backdrop_pan_system,
+                menu_weather_system,
+            ).in_set(crate::GameSet::UI).run_if(in_state(AppState::MainMenu))) // Re-enabled as fallback
             .add_systems(Update, (
                 settings_button_system,
                 menu_navigation_system,
                 volume_slider_update_system,
+                soundscape_slider_update_system,
+                import_ambient_button_system,
+                rebuild_soundscape_sliders_system,
                 graphics_toggle_system,
                 handle_controls_menu,
                 // New simplified widget systems
                 resolution_dropdown_system,
                 graphics_quality_dropdown_system,
                 settings_toggle_system,
+                (
+                    difficulty_button_system,
+                    auto_save_setting_button_system,
+                    deck_setting_button_system,
+                    bird_cam_setting_button_system,
+                    hud_setting_button_system,
+                    menu_backdrop_button_system,
+                    clip_capture_button_system,
+                    accessibility_setting_button_system,
+                    rumble_setting_button_system,
+                    display_setting_button_system,
+                ),
+                safe_area_slider_system,
                 // StateScoped toggle widget system
                 fullscreen_toggle_system,
-            ).run_if(in_state(AppState::Settings)))
-            .add_systems(Update, (tab_test_system, tab_test_escape_system).run_if(in_state(AppState::MainMenu)))
-            .add_systems(Update, load_game_button_system.run_if(in_state(AppState::LoadGame)))
-            .add_systems(Update, escape_key_system.run_if(
+            ).in_set(crate::GameSet::UI).run_if(in_state(AppState::Settings)))
+            .add_systems(Update, (tab_test_system, tab_test_escape_system).in_set(crate::GameSet::UI).run_if(in_state(AppState::MainMenu)))
+            .add_systems(Update, load_game_button_system.in_set(crate::GameSet::UI).run_if(in_state(AppState::LoadGame)))
+            .add_systems(Update, (
+                new_game_setup_button_system,
+                menu_navigation_system,
+            ).in_set(crate::GameSet::UI).run_if(in_state(AppState::NewGameSetup)))
+            .add_systems(Update, (
+                apply_display_settings_system,
+                apply_audio_settings_system,
+                apply_ui_safe_area_system,
+            ).in_set(crate::GameSet::Presentation))
+            .add_systems(Update, (
+                provide_settings_search_entries.in_set(crate::search_palette::SearchProviders),
+                settings_search_jump_system,
+            ).in_set(crate::GameSet::UI))
+            .add_systems(Update, escape_key_system.in_set(crate::GameSet::Input).run_if(
                 in_state(AppState::MainMenu)
                     .or(in_state(AppState::Settings))
                     .or(in_state(AppState::LoadGame))
+                    .or(in_state(AppState::NewGameSetup))
             ));
     }
 }
\ No newline at end of file
@@ -1,7 +1,5 @@
 use bevy::prelude::*;
 use serde::{Serialize, Deserialize};
-use std::fs;
-use std::path::PathBuf;
 
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum GraphicsQuality {
@@ -61,24 +59,67 @@ pub enum MenuType {
     Settings,
     SettingsControls,
     LoadGame,
+    NewGameSetup,
     InGame,
 }
 
+// Choices made on the new game setup screen before the sanctuary is founded
+#[derive(Resource, Default)]
+pub struct NewGameSetup {
+    pub region: crate::environment::region::Region,
+}
+
 #[derive(Resource, Serialize, Deserialize, Clone)]
 pub struct GameSettings {
     // Audio settings
     pub master_volume: f32,
     pub music_volume: f32,
     pub sfx_volume: f32,
+    // Mixer buses under the master volume; sfx_volume is the effects bus
+    #[serde(default = "default_bus_volume")]
+    pub call_volume: f32,
+    #[serde(default = "default_bus_volume")]
+    pub ambient_volume: f32,
+    // Bird calls that can sound at once before the least important give way
+    #[serde(default = "default_max_bird_voices")]
+    pub max_bird_voices: usize,
     
     // Gameplay settings
     pub auto_save_enabled: bool,
     pub auto_save_interval: f32, // minutes
+    // Timed auto-saves rotate through this many slots after the regular ones
+    #[serde(default = "default_auto_save_slots")]
+    pub auto_save_slots: u32,
+    // Saves to the sanctuary's own slot when these happen
+    #[serde(default = "default_save_trigger")]
+    pub save_after_purchase: bool,
+    #[serde(default = "default_save_trigger")]
+    pub save_after_rare_sighting: bool,
+    #[serde(default = "default_save_trigger")]
+    pub save_on_quit: bool,
+    // Rolling clip buffer; grabs the window ten times a second, so it's opt-in
+    #[serde(default)]
+    pub clip_capture: bool,
+    // Difficulty for new games; each save keeps its own copy
+    #[serde(default)]
+    pub difficulty: crate::difficulty::DifficultySettings,
     
     // Graphics settings
     pub vsync_enabled: bool,
     pub fullscreen: bool,
     pub window_resolution: (u32, u32),
+    // Fullscreen takes over the monitor's video mode instead of covering it with a borderless window
+    #[serde(default)]
+    pub exclusive_fullscreen: bool,
+    // Monitor by name, since their order can change between launches; None keeps the current one
+    #[serde(default)]
+    pub monitor: Option<String>,
+    // Only exclusive fullscreen can pick one; None leaves it to the monitor
+    #[serde(default)]
+    pub refresh_rate_millihertz: Option<u32>,
+    // Share of each screen edge kept clear of UI, for TVs that crop the picture
+    #[serde(default)]
+    pub ui_safe_area: f32,
     pub graphics_quality: GraphicsQuality,
     pub particle_density: f32,
     pub shadow_quality: ShadowQuality,
@@ -86,24 +127,187 @@ pub struct GameSettings {
     // Controls
     pub camera_sensitivity: f32,
     pub zoom_sensitivity: f32,
+    #[serde(default)]
+    pub rumble: crate::haptics::RumbleSettings,
+    
+    // Bird cam: minutes of inactivity before it starts, 0 to never start on its own
+    #[serde(default = "default_bird_cam_idle_minutes")]
+    pub bird_cam_idle_minutes: f32,
+    #[serde(default)]
+    pub launch_into_bird_cam: bool,
+    // What the main menu shows behind it
+    #[serde(default)]
+    pub menu_backdrop: MenuBackdropSource,
+    
+    // Steam Deck preset: Auto follows hardware detection; the preset's defaults are written once
+    #[serde(default)]
+    pub deck_mode: DeckMode,
+    #[serde(default)]
+    pub deck_preset_applied: bool,
+    // Frames per second to cap at to save battery, 0 for uncapped
+    #[serde(default)]
+    pub frame_cap: u32,
+    
+    // Bug reports: sending is opt-in and needs an endpoint set here by hand
+    #[serde(default)]
+    pub bug_report_auto_submit: bool,
+    #[serde(default)]
+    pub bug_report_endpoint: String,
+    
+    // Accessibility
+    // Callouts pointing at a centered bird's field marks in binoculars and photo mode
+    #[serde(default = "default_field_mark_callouts")]
+    pub field_mark_callouts: bool,
+    // Sparrow, robin and crow scale bar under a centered bird
+    #[serde(default)]
+    pub size_reference_overlay: bool,
+    // Caption feed naming sounds as they play, filtered by category
+    #[serde(default)]
+    pub sound_captions: bool,
+    #[serde(default = "default_caption_category")]
+    pub caption_bird_calls: bool,
+    #[serde(default = "default_caption_category")]
+    pub caption_nature: bool,
+    #[serde(default = "default_caption_category")]
+    pub caption_yard: bool,
+    #[serde(default = "default_caption_category")]
+    pub caption_neighborhood: bool,
+    // Song mnemonics like "drink-your-tea" after the species in call captions
+    #[serde(default)]
+    pub caption_mnemonics: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum DeckMode {
+    #[default]
+    Auto,
+    On,
+    Off,
+}
+
+impl DeckMode {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Auto => "Auto",
+            Self::On => "ON",
+            Self::Off => "OFF",
+        }
+    }
+    
+    pub fn next(&self) -> Self {
+        match self {
+            Self::Auto => Self::On,
+            Self::On => Self::Off,
+            Self::Off => Self::Auto,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum MenuBackdropSource {
+    // The yard as it was last saved
+    #[default]
+    Sanctuary,
+    // A photo or panorama the player picked from the album editor
+    ChosenPhoto,
+}
+
+impl MenuBackdropSource {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Sanctuary => "Your Sanctuary",
+            Self::ChosenPhoto => "Chosen Photo",
+        }
+    }
+    
+    pub fn next(&self) -> Self {
+        match self {
+            Self::Sanctuary => Self::ChosenPhoto,
+            Self::ChosenPhoto => Self::Sanctuary,
+        }
+    }
+}
+
+// Choices the frame cap setting cycles through; 0 is uncapped
+pub const FRAME_CAPS: [u32; 4] = [0, 30, 40, 60];
+
+fn default_bird_cam_idle_minutes() -> f32 {
+    5.0
+}
+
+fn default_auto_save_slots() -> u32 {
+    2
+}
+
+fn default_save_trigger() -> bool {
+    true
 }
 
+fn default_bus_volume() -> f32 {
+    0.8
+}
+
+fn default_max_bird_voices() -> usize {
+    6
+}
+
+fn default_caption_category() -> bool {
+    true
+}
+
+fn default_field_mark_callouts() -> bool {
+    true
+}
+
+// Choices the auto-save interval setting cycles through, in minutes
+pub const AUTO_SAVE_INTERVALS: [f32; 5] = [2.0, 5.0, 10.0, 15.0, 30.0];
+
 impl Default for GameSettings {
     fn default() -> Self {
         Self {
             master_volume: 0.8,
             music_volume: 0.7,
             sfx_volume: 0.8,
+            call_volume: default_bus_volume(),
+            ambient_volume: default_bus_volume(),
+            max_bird_voices: default_max_bird_voices(),
             auto_save_enabled: true,
             auto_save_interval: 5.0,
+            auto_save_slots: default_auto_save_slots(),
+            save_after_purchase: true,
+            save_after_rare_sighting: true,
+            save_on_quit: true,
+            clip_capture: false,
+            difficulty: crate::difficulty::DifficultySettings::default(),
             vsync_enabled: true,
             fullscreen: false,
             window_resolution: (1920, 1080),
+            exclusive_fullscreen: false,
+            monitor: None,
+            refresh_rate_millihertz: None,
+            ui_safe_area: 0.0,
             graphics_quality: GraphicsQuality::High,
             particle_density: 1.0,
             shadow_quality: ShadowQuality::Medium,
             camera_sensitivity: 1.0,
             zoom_sensitivity: 1.0,
+            rumble: crate::haptics::RumbleSettings::default(),
+            bird_cam_idle_minutes: default_bird_cam_idle_minutes(),
+            launch_into_bird_cam: false,
+            menu_backdrop: MenuBackdropSource::default(),
+            bug_report_auto_submit: false,
+            bug_report_endpoint: String::new(),
+            deck_mode: DeckMode::Auto,
+            deck_preset_applied: false,
+            frame_cap: 0,
+            field_mark_callouts: default_field_mark_callouts(),
+            size_reference_overlay: false,
+            sound_captions: false,
+            caption_bird_calls: default_caption_category(),
+            caption_nature: default_caption_category(),
+            caption_yard: default_caption_category(),
+            caption_neighborhood: default_caption_category(),
+            caption_mnemonics: false,
         }
     }
 }
@@ -133,38 +337,13 @@ impl GameSettings {
             .unwrap_or(1) // Default to 1080p if not found
     }
 
-    pub fn get_settings_path() -> PathBuf {
-        dirs::config_dir()
-            .unwrap_or_else(|| PathBuf::from("."))
-            .join("AvianHaven")
-            .join("settings.ron")
-    }
-    
     pub fn save_to_file(&self) -> Result<(), Box<dyn std::error::Error>> {
-        let settings_path = Self::get_settings_path();
-        
-        if let Some(parent) = settings_path.parent() {
-            fs::create_dir_all(parent)?;
-        }
-        
-        let serialized = ron::to_string(self)?;
-        fs::write(settings_path, serialized)?;
-        Ok(())
+        crate::user_config::write_config("settings.ron", self)
     }
     
     pub fn load_from_file() -> Self {
-        let settings_path = Self::get_settings_path();
-        
-        if settings_path.exists() {
-            if let Ok(content) = fs::read_to_string(settings_path) {
-                if let Ok(settings) = ron::from_str::<GameSettings>(&content) {
-                    return settings;
-                }
-            }
-        }
-        
         // Return default settings if loading fails
-        Self::default()
+        crate::user_config::read_config("settings.ron").unwrap_or_default()
     }
 }
 
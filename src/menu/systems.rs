@@ -1,7 +1,10 @@
 use bevy::prelude::*;
+use bevy::core_pipeline::tonemapping::Tonemapping;
+use bevy::render::camera::Viewport;
+use bevy::render::view::RenderLayers;
+use bevy::window::{Monitor, PresentMode, MonitorSelection, PrimaryMonitor, PrimaryWindow, VideoMode, VideoModeSelection, WindowMode, WindowPosition, WindowResized};
 use crate::menu::{components::*, resources::*};
-use crate::save_load::resources::{SaveGameEvent, LoadGameEvent, SaveManager};
-use crate::despawn::SafeDespawn;
+use crate::save_load::resources::{SaveGameEvent, LoadGameEvent, SaveManager, AUTO_SAVE_FIRST_SLOT, MAX_AUTO_SAVE_SLOTS};
 use crate::ui_widgets::ToggleButton;
 use crate::user_interface::slider::{SliderBuilder, SliderValueChangedEvent};
 use crate::user_interface::dropdown::{DropdownBuilder, DropdownChangedEvent, DropdownChangeKind, DropdownConfig};
@@ -9,6 +12,11 @@ use crate::user_interface::toggle::{ToggleBuilder, ToggleChangedEvent};
 use crate::user_interface::scrollable::ScrollableBuilder;
 use crate::user_interface::tab_group::*;
 use crate::audio::resources::AudioSettings;
+use crate::keybindings::KeyBindings;
+use crate::notifications::{components::NotificationType, resources::ShowNotificationEvent};
+use crate::ui::HudLayout;
+use crate::audio::soundscape::{AmbientLayer, SoundscapeSettings, ImportAmbientTracksEvent, user_ambient_directory};
+use crate::environment::components::{Season, Weather};
 
 // Startup Systems
 
@@ -17,12 +25,33 @@ pub fn load_settings_on_startup(mut commands: Commands) {
     info!("Loaded settings from file: resolution={}x{}, master_volume={:.2}, fullscreen={}", 
         settings.window_resolution.0, settings.window_resolution.1, 
         settings.master_volume, settings.fullscreen);
+    commands.insert_resource(settings.difficulty.clone());
     commands.insert_resource(settings);
 }
 
 // Setup Systems
 
-pub fn setup_main_menu(mut commands: Commands, asset_server: Res<AssetServer>) {
+pub fn setup_main_menu(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut images: ResMut<Assets<Image>>,
+    settings: Res<GameSettings>,
+    save_manager: Res<SaveManager>,
+) {
+    // The yard as it was last saved, with its season and weather, or a picture the player chose;
+    // either one stands in when the other isn't there
+    let sanctuary = |images: &mut Assets<Image>| save_manager.latest_snapshot().and_then(|(path, save)| {
+        crate::photo_mode::photo_export::load_png_file(&path, images).map(|image| (image, save.environment))
+    });
+    let chosen = |images: &mut Assets<Image>| crate::photo_mode::photo_export::load_menu_background(images).map(|image| (image, None));
+    let backdrop = match settings.menu_backdrop {
+        MenuBackdropSource::Sanctuary => sanctuary(&mut images).or_else(|| chosen(&mut images)),
+        MenuBackdropSource::ChosenPhoto => chosen(&mut images).or_else(|| sanctuary(&mut images)),
+    };
+    let backdrop = backdrop.and_then(|(image, environment)| {
+        images.get(&image).map(|loaded| (image.clone(), loaded.size_f32(), environment))
+    });
+
     commands.spawn((
         Node {
             width: Val::Percent(100.0),
@@ -34,7 +63,24 @@ pub fn setup_main_menu(mut commands: Commands, asset_server: Res<AssetServer>) {
         },
         BackgroundColor(Color::srgb(0.1, 0.1, 0.15)),
         MenuUI,
+        StateScoped(crate::AppState::MainMenu),
     )).with_children(|parent| {
+        if let Some((image, size, environment)) = backdrop {
+            parent.spawn((
+                Node {
+                    position_type: PositionType::Absolute,
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    ..default()
+                },
+                ImageNode::new(image),
+                MenuBackdrop { size, elapsed: 0.0 },
+            ));
+            if let Some((season, weather)) = environment {
+                spawn_menu_weather(parent, season, weather);
+            }
+        }
+
         // Menu container
         parent.spawn((
             Node {
@@ -102,7 +148,88 @@ pub fn setup_main_menu(mut commands: Commands, asset_server: Res<AssetServer>) {
     });
 }
 
-pub fn setup_settings_menu(mut commands: Commands, settings: Res<GameSettings>) {
+// Season's tint over the sanctuary snapshot, and the weather it was saved in falling across it
+fn spawn_menu_weather(parent: &mut ChildSpawnerCommands, season: Season, weather: Weather) {
+    let tint = match season {
+        Season::Spring => Color::srgba(0.8, 1.0, 0.8, 0.05),
+        Season::Summer => Color::srgba(1.0, 0.95, 0.7, 0.06),
+        Season::Fall => Color::srgba(1.0, 0.7, 0.4, 0.1),
+        Season::Winter => Color::srgba(0.75, 0.85, 1.0, 0.12),
+    };
+    let veil = if weather == Weather::Foggy { Color::srgba(0.86, 0.88, 0.9, 0.35) } else { tint };
+    parent.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            ..default()
+        },
+        BackgroundColor(veil),
+    ));
+
+    // Count, size in pixels, color, fall velocity and sideways sway
+    let (count, size, color, velocity, sway) = match (weather, season) {
+        (Weather::Rainy, _) => (80, Vec2::new(2.0, 18.0), Color::srgba(0.75, 0.8, 0.9, 0.5), Vec2::new(-3.0, 120.0), 0.0),
+        (Weather::Snowy, _) => (70, Vec2::splat(5.0), Color::srgba(1.0, 1.0, 1.0, 0.85), Vec2::new(0.0, 12.0), 2.0),
+        (Weather::Clear | Weather::Cloudy | Weather::Windy, Season::Fall) => (18, Vec2::new(8.0, 6.0), Color::srgb(0.8, 0.45, 0.15), Vec2::new(4.0, 10.0), 4.0),
+        _ => return,
+    };
+    for _ in 0..count {
+        // Each one falls a little faster or slower than the rest
+        let speed = 0.7 + rand::random::<f32>() * 0.6;
+        parent.spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Percent(rand::random::<f32>() * 100.0),
+                top: Val::Percent(rand::random::<f32>() * 100.0),
+                width: Val::Px(size.x),
+                height: Val::Px(size.y),
+                ..default()
+            },
+            BackgroundColor(color),
+            BorderRadius::all(Val::Px(size.min_element() / 2.0)),
+            MenuWeatherParticle { velocity: velocity * speed, sway, phase: rand::random::<f32>() * std::f32::consts::TAU },
+        ));
+    }
+}
+
+pub fn menu_weather_system(mut particle_query: Query<(&mut Node, &mut MenuWeatherParticle)>, time: Res<Time>) {
+    let dt = time.delta_secs();
+    for (mut node, mut particle) in &mut particle_query {
+        particle.phase += dt;
+        let (Val::Percent(left), Val::Percent(top)) = (node.left, node.top) else { continue };
+        let drift = particle.velocity.x + particle.sway * particle.phase.sin();
+        // Off the bottom or a side, back in at the top
+        node.left = Val::Percent((left + drift * dt).rem_euclid(100.0));
+        node.top = Val::Percent(if top > 100.0 { -2.0 } else { top + particle.velocity.y * dt });
+    }
+}
+
+// Drifts slowly back and forth across a backdrop wider than the window, so panoramas show in full
+pub fn menu_backdrop_pan_system(
+    mut backdrop_query: Query<(&mut ImageNode, &mut MenuBackdrop)>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    time: Res<Time>,
+) {
+    let Ok(window) = window_query.single() else { return };
+    let aspect = window.width() / window.height().max(1.0);
+
+    for (mut image, mut backdrop) in &mut backdrop_query {
+        backdrop.elapsed += time.delta_secs();
+        let size = backdrop.size;
+        let view = if size.x / size.y > aspect {
+            Vec2::new(size.y * aspect, size.y)
+        } else {
+            Vec2::new(size.x, size.x / aspect)
+        };
+        // A full sweep one way takes about forty seconds
+        let sweep = 0.5 - 0.5 * (backdrop.elapsed * std::f32::consts::PI / 40.0).cos();
+        let min = Vec2::new((size.x - view.x) * sweep, (size.y - view.y) / 2.0);
+        image.rect = Some(Rect::from_corners(min, min + view));
+    }
+}
+
+pub fn setup_settings_menu(mut commands: Commands, settings: Res<GameSettings>, hud_layout: Res<HudLayout>) {
     // Create the fullscreen toggle widget first, before any UI hierarchy
     let toggle_config = crate::user_interface::toggle::ToggleConfig {
         size: Vec2::new(50.0, 25.0),
@@ -119,6 +246,15 @@ pub fn setup_settings_menu(mut commands: Commands, settings: Res<GameSettings>)
     // Tag the toggle for identification in event handling
     commands.entity(fullscreen_toggle_entity).insert(FullscreenToggle);
 
+    // Likewise the safe area slider, which needs commands of its own to build
+    let safe_area_slider = SliderBuilder::new(&mut commands)
+        .with_range(0.0, MAX_UI_SAFE_AREA)
+        .with_value(settings.ui_safe_area)
+        .with_step(0.005)
+        .with_value_formatter(|value| format!("{:.1}%", value * 100.0))
+        .spawn();
+    commands.entity(safe_area_slider).insert(SafeAreaSlider);
+
     // SCREEN POSITIONING
     // FULL SCREEN, CENTRE ALIGNED
     let menu_entity = commands.spawn((
@@ -132,6 +268,7 @@ pub fn setup_settings_menu(mut commands: Commands, settings: Res<GameSettings>)
         },
         BackgroundColor(Color::srgb(0.1, 0.1, 0.15)),
         MenuUI,
+        StateScoped(crate::AppState::Settings),
     )).id();
     
     // Create settings container (window)
@@ -337,6 +474,86 @@ pub fn setup_settings_menu(mut commands: Commands, settings: Res<GameSettings>)
                         TextColor(Color::srgb(0.3, 0.2, 0.1)),
                     ));
                 }).add_child(fullscreen_toggle_entity);
+                
+                // Fullscreen type, monitor and refresh rate, each row cycled by clicking
+                for target in DisplaySetting::ALL {
+                    section.spawn((
+                        Button,
+                        Node {
+                            width: Val::Percent(100.0),
+                            flex_direction: FlexDirection::Row,
+                            justify_content: JustifyContent::SpaceBetween,
+                            align_items: AlignItems::Center,
+                            padding: UiRect::all(Val::Px(10.0)),
+                            ..default()
+                        },
+                        BackgroundColor(Color::srgb(0.9, 0.9, 0.9)),
+                        BorderRadius::all(Val::Px(6.0)),
+                        DisplaySettingButton { target },
+                    )).with_children(|container| {
+                        container.spawn((
+                            Text::new(target.label()),
+                            TextFont { font_size: 16.0, ..default() },
+                            TextColor(Color::srgb(0.3, 0.2, 0.1)),
+                        ));
+                        container.spawn((
+                            Text::new(target.value_label(&settings)),
+                            TextFont { font_size: 16.0, ..default() },
+                            TextColor(Color::srgb(0.5, 0.3, 0.2)),
+                            DisplayValueText { target },
+                        ));
+                    });
+                }
+                
+                // UI safe area for TVs that overscan
+                section.spawn((
+                    Node {
+                        width: Val::Percent(100.0),
+                        flex_direction: FlexDirection::Row,
+                        justify_content: JustifyContent::SpaceBetween,
+                        align_items: AlignItems::Center,
+                        padding: UiRect::all(Val::Px(10.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.9, 0.9, 0.9)),
+                    BorderRadius::all(Val::Px(6.0)),
+                )).with_children(|container| {
+                    container.spawn((
+                        Text::new("UI Safe Area"),
+                        TextFont { font_size: 16.0, ..default() },
+                        TextColor(Color::srgb(0.3, 0.2, 0.1)),
+                    ));
+                }).add_child(safe_area_slider);
+                
+                // Steam Deck preset and battery frame cap, each row cycled by clicking
+                for target in DeckSetting::ALL {
+                    section.spawn((
+                        Button,
+                        Node {
+                            width: Val::Percent(100.0),
+                            flex_direction: FlexDirection::Row,
+                            justify_content: JustifyContent::SpaceBetween,
+                            align_items: AlignItems::Center,
+                            padding: UiRect::all(Val::Px(10.0)),
+                            ..default()
+                        },
+                        BackgroundColor(Color::srgb(0.9, 0.9, 0.9)),
+                        BorderRadius::all(Val::Px(6.0)),
+                        DeckSettingButton { target },
+                    )).with_children(|container| {
+                        container.spawn((
+                            Text::new(target.label()),
+                            TextFont { font_size: 16.0, ..default() },
+                            TextColor(Color::srgb(0.3, 0.2, 0.1)),
+                        ));
+                        container.spawn((
+                            Text::new(target.value_label(&settings)),
+                            TextFont { font_size: 16.0, ..default() },
+                            TextColor(Color::srgb(0.5, 0.3, 0.2)),
+                            DeckValueText { target },
+                        ));
+                    });
+                }
             });
             
             // Gameplay settings section (more content to demonstrate scrolling)
@@ -362,8 +579,102 @@ pub fn setup_settings_menu(mut commands: Commands, settings: Res<GameSettings>)
                     },
                 ));
                 
-                // Auto-save toggle
+                // Auto-save policy, each row cycled by clicking
+                for target in AutoSaveSetting::ALL {
+                    section.spawn((
+                        Button,
+                        Node {
+                            width: Val::Percent(100.0),
+                            flex_direction: FlexDirection::Row,
+                            justify_content: JustifyContent::SpaceBetween,
+                            align_items: AlignItems::Center,
+                            padding: UiRect::all(Val::Px(10.0)),
+                            ..default()
+                        },
+                        BackgroundColor(Color::srgb(0.9, 0.9, 0.9)),
+                        BorderRadius::all(Val::Px(6.0)),
+                        AutoSaveSettingButton { target },
+                    )).with_children(|container| {
+                        container.spawn((
+                            Text::new(target.label()),
+                            TextFont { font_size: 16.0, ..default() },
+                            TextColor(Color::srgb(0.3, 0.2, 0.1)),
+                        ));
+                        container.spawn((
+                            Text::new(target.value_label(&settings)),
+                            TextFont { font_size: 16.0, ..default() },
+                            TextColor(Color::srgb(0.5, 0.3, 0.2)),
+                            AutoSaveValueText { target },
+                        ));
+                    });
+                }
+                
+                // Bird cam idle delay and launch option, cycled by clicking
+                for (label, target) in [
+                    ("Bird Cam When Idle", BirdCamSetting::IdleDelay),
+                    ("Launch Into Bird Cam", BirdCamSetting::LaunchOnStart),
+                ] {
+                    section.spawn((
+                        Button,
+                        Node {
+                            width: Val::Percent(100.0),
+                            flex_direction: FlexDirection::Row,
+                            justify_content: JustifyContent::SpaceBetween,
+                            align_items: AlignItems::Center,
+                            padding: UiRect::all(Val::Px(10.0)),
+                            ..default()
+                        },
+                        BackgroundColor(Color::srgb(0.9, 0.9, 0.9)),
+                        BorderRadius::all(Val::Px(6.0)),
+                        BirdCamSettingButton { target },
+                    )).with_children(|container| {
+                        container.spawn((
+                            Text::new(label),
+                            TextFont { font_size: 16.0, ..default() },
+                            TextColor(Color::srgb(0.3, 0.2, 0.1)),
+                        ));
+                        container.spawn((
+                            Text::new(target.value_label(&settings)),
+                            TextFont { font_size: 16.0, ..default() },
+                            TextColor(Color::srgb(0.5, 0.3, 0.2)),
+                            BirdCamValueText { target },
+                        ));
+                    });
+                }
+                
+                // Where the HUD pieces sit in the yard, cycled by clicking
+                for target in HudSetting::ALL {
+                    section.spawn((
+                        Button,
+                        Node {
+                            width: Val::Percent(100.0),
+                            flex_direction: FlexDirection::Row,
+                            justify_content: JustifyContent::SpaceBetween,
+                            align_items: AlignItems::Center,
+                            padding: UiRect::all(Val::Px(10.0)),
+                            ..default()
+                        },
+                        BackgroundColor(Color::srgb(0.9, 0.9, 0.9)),
+                        BorderRadius::all(Val::Px(6.0)),
+                        HudSettingButton { target },
+                    )).with_children(|container| {
+                        container.spawn((
+                            Text::new(target.label()),
+                            TextFont { font_size: 16.0, ..default() },
+                            TextColor(Color::srgb(0.3, 0.2, 0.1)),
+                        ));
+                        container.spawn((
+                            Text::new(target.value_label(&hud_layout)),
+                            TextFont { font_size: 16.0, ..default() },
+                            TextColor(Color::srgb(0.5, 0.3, 0.2)),
+                            HudValueText { target },
+                        ));
+                    });
+                }
+                
+                // Main menu backdrop, toggled by clicking
                 section.spawn((
+                    Button,
                     Node {
                         width: Val::Percent(100.0),
                         flex_direction: FlexDirection::Row,
@@ -374,25 +685,24 @@ pub fn setup_settings_menu(mut commands: Commands, settings: Res<GameSettings>)
                     },
                     BackgroundColor(Color::srgb(0.9, 0.9, 0.9)),
                     BorderRadius::all(Val::Px(6.0)),
+                    MenuBackdropButton,
                 )).with_children(|container| {
                     container.spawn((
-                        Text::new("Auto-Save"),
+                        Text::new("Menu Backdrop"),
                         TextFont { font_size: 16.0, ..default() },
                         TextColor(Color::srgb(0.3, 0.2, 0.1)),
                     ));
                     container.spawn((
-                        Text::new(if settings.auto_save_enabled { "ON" } else { "OFF" }),
+                        Text::new(settings.menu_backdrop.label()),
                         TextFont { font_size: 16.0, ..default() },
-                        TextColor(if settings.auto_save_enabled { 
-                            Color::srgb(0.2, 0.6, 0.2) 
-                        } else { 
-                            Color::srgb(0.6, 0.2, 0.2) 
-                        }),
+                        TextColor(Color::srgb(0.5, 0.3, 0.2)),
+                        MenuBackdropValueText,
                     ));
                 });
                 
-                // Difficulty setting
+                // Rolling clip buffer, toggled by clicking
                 section.spawn((
+                    Button,
                     Node {
                         width: Val::Percent(100.0),
                         flex_direction: FlexDirection::Row,
@@ -403,45 +713,24 @@ pub fn setup_settings_menu(mut commands: Commands, settings: Res<GameSettings>)
                     },
                     BackgroundColor(Color::srgb(0.9, 0.9, 0.9)),
                     BorderRadius::all(Val::Px(6.0)),
+                    ClipCaptureButton,
                 )).with_children(|container| {
                     container.spawn((
-                        Text::new("Difficulty"),
+                        Text::new("Record Clip Buffer"),
                         TextFont { font_size: 16.0, ..default() },
                         TextColor(Color::srgb(0.3, 0.2, 0.1)),
                     ));
                     container.spawn((
-                        Text::new("Normal"),
+                        Text::new(if settings.clip_capture { "On" } else { "Off" }),
                         TextFont { font_size: 16.0, ..default() },
                         TextColor(Color::srgb(0.5, 0.3, 0.2)),
+                        ClipCaptureValueText,
                     ));
                 });
-            });
-            
-            // Controls settings section
-            scrollable_content.spawn((
-                Node {
-                    width: Val::Percent(100.0),
-                    flex_direction: FlexDirection::Column,
-                    row_gap: Val::Px(15.0),
-                    margin: UiRect::vertical(Val::Px(20.0)),
-                    ..default()
-                },
-            )).with_children(|section| {
-                section.spawn((
-                    Text::new("Controls"),
-                    TextFont {
-                        font_size: 20.0,
-                        ..default()
-                    },
-                    TextColor(Color::srgb(0.2, 0.2, 0.3)),
-                    Node {
-                        margin: UiRect::bottom(Val::Px(15.0)),
-                        ..default()
-                    },
-                ));
                 
-                // Mouse sensitivity slider - placeholder for now
+                // Difficulty preset, cycled by clicking
                 section.spawn((
+                    Button,
                     Node {
                         width: Val::Percent(100.0),
                         flex_direction: FlexDirection::Row,
@@ -452,74 +741,79 @@ pub fn setup_settings_menu(mut commands: Commands, settings: Res<GameSettings>)
                     },
                     BackgroundColor(Color::srgb(0.9, 0.9, 0.9)),
                     BorderRadius::all(Val::Px(6.0)),
+                    DifficultyButton { target: DifficultyTarget::Preset },
                 )).with_children(|container| {
                     container.spawn((
-                        Text::new("Mouse Sensitivity"),
+                        Text::new("Difficulty"),
                         TextFont { font_size: 16.0, ..default() },
                         TextColor(Color::srgb(0.3, 0.2, 0.1)),
                     ));
                     container.spawn((
-                        Text::new("1.0"),
-                        TextFont { font_size: 14.0, ..default() },
-                        TextColor(Color::srgb(0.5, 0.5, 0.5)),
-                    ));
-                });
-                
-                // Key bindings button
-                section.spawn((
-                    Button,
-                    Node {
-                        width: Val::Percent(100.0),
-                        flex_direction: FlexDirection::Row,
-                        justify_content: JustifyContent::SpaceBetween,
-                        align_items: AlignItems::Center,
-                        padding: UiRect::all(Val::Px(10.0)),
-                        ..default()
-                    },
-                    BackgroundColor(Color::srgb(0.8, 0.8, 0.9)),
-                    BorderRadius::all(Val::Px(6.0)),
-                )).with_children(|container| {
-                    container.spawn((
-                        Text::new("Configure Key Bindings"),
+                        Text::new(settings.difficulty.label()),
                         TextFont { font_size: 16.0, ..default() },
-                        TextColor(Color::srgb(0.3, 0.2, 0.1)),
-                    ));
-                    container.spawn((
-                        Text::new("→"),
-                        TextFont { font_size: 18.0, ..default() },
                         TextColor(Color::srgb(0.5, 0.3, 0.2)),
+                        DifficultyValueText { target: DifficultyTarget::Preset },
                     ));
                 });
+                
+                // Individual realism settings; changing any of them makes a custom game
+                for aspect in crate::difficulty::RealismAspect::ALL {
+                    let target = DifficultyTarget::Aspect(aspect);
+                    section.spawn((
+                        Button,
+                        Node {
+                            width: Val::Percent(100.0),
+                            flex_direction: FlexDirection::Row,
+                            justify_content: JustifyContent::SpaceBetween,
+                            align_items: AlignItems::Center,
+                            padding: UiRect::new(Val::Px(30.0), Val::Px(10.0), Val::Px(6.0), Val::Px(6.0)),
+                            ..default()
+                        },
+                        BackgroundColor(Color::srgb(0.93, 0.93, 0.93)),
+                        BorderRadius::all(Val::Px(6.0)),
+                        DifficultyButton { target },
+                    )).with_children(|container| {
+                        container.spawn((
+                            Text::new(aspect.label()),
+                            TextFont { font_size: 14.0, ..default() },
+                            TextColor(Color::srgb(0.3, 0.2, 0.1)),
+                        ));
+                        container.spawn((
+                            Text::new(settings.difficulty.level(aspect).name()),
+                            TextFont { font_size: 14.0, ..default() },
+                            TextColor(Color::srgb(0.5, 0.3, 0.2)),
+                            DifficultyValueText { target },
+                        ));
+                    });
+                }
             });
             
-            let gameplay_settings = [
-                ("Auto Save", SettingType::AutoSave, if settings.auto_save_enabled { 1.0 } else { 0.0 }),
-            ];
-            
+            // Accessibility settings section
             scrollable_content.spawn((
                 Node {
                     width: Val::Percent(100.0),
                     flex_direction: FlexDirection::Column,
-                    row_gap: Val::Px(10.0),
+                    row_gap: Val::Px(15.0),
                     margin: UiRect::vertical(Val::Px(20.0)),
                     ..default()
                 },
             )).with_children(|section| {
                 section.spawn((
-                    Text::new("Gameplay"),
+                    Text::new("Accessibility"),
                     TextFont {
                         font_size: 20.0,
                         ..default()
                     },
                     TextColor(Color::srgb(0.2, 0.2, 0.3)),
                     Node {
-                        margin: UiRect::bottom(Val::Px(10.0)),
+                        margin: UiRect::bottom(Val::Px(15.0)),
                         ..default()
                     },
                 ));
                 
-                for (label, _setting_type, value) in &gameplay_settings {
+                for target in AccessibilitySetting::ALL {
                     section.spawn((
+                        Button,
                         Node {
                             width: Val::Percent(100.0),
                             flex_direction: FlexDirection::Row,
@@ -529,27 +823,179 @@ pub fn setup_settings_menu(mut commands: Commands, settings: Res<GameSettings>)
                             ..default()
                         },
                         BackgroundColor(Color::srgb(0.9, 0.9, 0.9)),
-                    )).with_children(|item| {
-                        item.spawn((
-                            Text::new(*label),
-                            TextFont {
-                                font_size: 16.0,
-                                ..default()
-                            },
+                        BorderRadius::all(Val::Px(6.0)),
+                        AccessibilitySettingButton { target },
+                    )).with_children(|container| {
+                        container.spawn((
+                            Text::new(target.label()),
+                            TextFont { font_size: 16.0, ..default() },
                             TextColor(Color::srgb(0.3, 0.2, 0.1)),
                         ));
-                        
-                        item.spawn((
-                            Text::new(format!("{:.0}%", value * 100.0)),
-                            TextFont {
-                                font_size: 16.0,
-                                ..default()
-                            },
+                        container.spawn((
+                            Text::new(target.value_label(&settings)),
+                            TextFont { font_size: 16.0, ..default() },
+                            TextColor(Color::srgb(0.5, 0.3, 0.2)),
+                            AccessibilityValueText { target },
+                        ));
+                    });
+                }
+            });
+            
+            // Controls settings section
+            scrollable_content.spawn((
+                Node {
+                    width: Val::Percent(100.0),
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(15.0),
+                    margin: UiRect::vertical(Val::Px(20.0)),
+                    ..default()
+                },
+            )).with_children(|section| {
+                section.spawn((
+                    Text::new("Controls"),
+                    TextFont {
+                        font_size: 20.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.2, 0.2, 0.3)),
+                    Node {
+                        margin: UiRect::bottom(Val::Px(15.0)),
+                        ..default()
+                    },
+                ));
+                
+                // Mouse sensitivity slider - placeholder for now
+                section.spawn((
+                    Node {
+                        width: Val::Percent(100.0),
+                        flex_direction: FlexDirection::Row,
+                        justify_content: JustifyContent::SpaceBetween,
+                        align_items: AlignItems::Center,
+                        padding: UiRect::all(Val::Px(10.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.9, 0.9, 0.9)),
+                    BorderRadius::all(Val::Px(6.0)),
+                )).with_children(|container| {
+                    container.spawn((
+                        Text::new("Mouse Sensitivity"),
+                        TextFont { font_size: 16.0, ..default() },
+                        TextColor(Color::srgb(0.3, 0.2, 0.1)),
+                    ));
+                    container.spawn((
+                        Text::new("1.0"),
+                        TextFont { font_size: 14.0, ..default() },
+                        TextColor(Color::srgb(0.5, 0.5, 0.5)),
+                    ));
+                });
+                
+                // Rumble strength, then each kind of rumble indented beneath it
+                for target in RumbleSetting::ALL {
+                    let (padding, font_size, color) = match target {
+                        RumbleSetting::Intensity => (UiRect::all(Val::Px(10.0)), 16.0, Color::srgb(0.9, 0.9, 0.9)),
+                        _ => (UiRect::new(Val::Px(30.0), Val::Px(10.0), Val::Px(6.0), Val::Px(6.0)), 14.0, Color::srgb(0.93, 0.93, 0.93)),
+                    };
+                    section.spawn((
+                        Button,
+                        Node {
+                            width: Val::Percent(100.0),
+                            flex_direction: FlexDirection::Row,
+                            justify_content: JustifyContent::SpaceBetween,
+                            align_items: AlignItems::Center,
+                            padding,
+                            ..default()
+                        },
+                        BackgroundColor(color),
+                        BorderRadius::all(Val::Px(6.0)),
+                        RumbleSettingButton { target },
+                    )).with_children(|container| {
+                        container.spawn((
+                            Text::new(target.label()),
+                            TextFont { font_size, ..default() },
+                            TextColor(Color::srgb(0.3, 0.2, 0.1)),
+                        ));
+                        container.spawn((
+                            Text::new(target.value_label(&settings)),
+                            TextFont { font_size, ..default() },
                             TextColor(Color::srgb(0.5, 0.3, 0.2)),
+                            RumbleValueText { target },
                         ));
                     });
                 }
+                
+                // Key bindings button
+                section.spawn((
+                    Button,
+                    Node {
+                        width: Val::Percent(100.0),
+                        flex_direction: FlexDirection::Row,
+                        justify_content: JustifyContent::SpaceBetween,
+                        align_items: AlignItems::Center,
+                        padding: UiRect::all(Val::Px(10.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.8, 0.8, 0.9)),
+                    BorderRadius::all(Val::Px(6.0)),
+                )).with_children(|container| {
+                    container.spawn((
+                        Text::new("Configure Key Bindings"),
+                        TextFont { font_size: 16.0, ..default() },
+                        TextColor(Color::srgb(0.3, 0.2, 0.1)),
+                    ));
+                    container.spawn((
+                        Text::new("→"),
+                        TextFont { font_size: 18.0, ..default() },
+                        TextColor(Color::srgb(0.5, 0.3, 0.2)),
+                    ));
+                });
+                
+                // Settings, key bindings and HUD layout as one file for moving to another machine
+                section.spawn((
+                    Node {
+                        width: Val::Percent(100.0),
+                        flex_direction: FlexDirection::Row,
+                        justify_content: JustifyContent::SpaceBetween,
+                        align_items: AlignItems::Center,
+                        padding: UiRect::all(Val::Px(10.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.9, 0.9, 0.9)),
+                    BorderRadius::all(Val::Px(6.0)),
+                )).with_children(|container| {
+                    container.spawn((
+                        Text::new("Config File"),
+                        TextFont { font_size: 16.0, ..default() },
+                        TextColor(Color::srgb(0.3, 0.2, 0.1)),
+                    ));
+                    container.spawn(Node {
+                        column_gap: Val::Px(8.0),
+                        ..default()
+                    }).with_children(|buttons| {
+                        for (text, action) in [("Export", SettingsAction::ExportConfig), ("Import", SettingsAction::ImportConfig)] {
+                            buttons.spawn((
+                                Button,
+                                Node {
+                                    width: Val::Px(80.0),
+                                    height: Val::Px(28.0),
+                                    justify_content: JustifyContent::Center,
+                                    align_items: AlignItems::Center,
+                                    ..default()
+                                },
+                                BackgroundColor(Color::srgb(0.6, 0.5, 0.4)),
+                                BorderRadius::all(Val::Px(6.0)),
+                                SettingsButton { action },
+                            )).with_children(|button| {
+                                button.spawn((
+                                    Text::new(text),
+                                    TextFont { font_size: 14.0, ..default() },
+                                    TextColor(Color::WHITE),
+                                ));
+                            });
+                        }
+                    });
+                });
             });
+            
     }); // End scrollable_content
     
     // FOOTER
@@ -616,6 +1062,7 @@ pub fn setup_controls_menu(
         },
         BackgroundColor(Color::srgb(0.1, 0.1, 0.15)),
         MenuUI,
+        StateScoped(crate::AppState::Settings),
     )).with_children(|parent| {
         // Controls container
         parent.spawn((
@@ -717,6 +1164,8 @@ pub fn setup_controls_menu(
                 let photo_actions = [
                     ("Toggle Photo Mode", crate::keybindings::GameAction::TogglePhotoMode),
                     ("Take Photo", crate::keybindings::GameAction::TakePhoto),
+                    ("Save Clip", crate::keybindings::GameAction::SaveClip),
+                    ("Remote Shutter", crate::keybindings::GameAction::RemoteShutter),
                 ];
                 
                 for (label, action) in photo_actions {
@@ -769,6 +1218,22 @@ pub fn setup_controls_menu(
                 
                 let ui_actions = [
                     ("Open Journal", crate::keybindings::GameAction::OpenJournal),
+                    ("Open Jukebox", crate::keybindings::GameAction::OpenJukebox),
+                    ("Photo Albums", crate::keybindings::GameAction::OpenAlbumEditor),
+                    ("Trading Post", crate::keybindings::GameAction::OpenTradingPost),
+                    ("Reputation", crate::keybindings::GameAction::OpenReputation),
+                    ("Grants & Budget", crate::keybindings::GameAction::OpenGrants),
+                    ("Attraction Advisor", crate::keybindings::GameAction::OpenAdvisor),
+                    ("Draw Fences & Paths", crate::keybindings::GameAction::DrawLines),
+                    ("Paint Terrain", crate::keybindings::GameAction::PaintTerrain),
+                    ("Change Object Height", crate::keybindings::GameAction::CycleHeight),
+                    ("Call Playback", crate::keybindings::GameAction::CallPlayback),
+                    ("Binoculars", crate::keybindings::GameAction::ToggleBinoculars),
+                    ("Sound ID", crate::keybindings::GameAction::ToggleSoundId),
+                    ("Photo Contest", crate::keybindings::GameAction::OpenPhotoContest),
+                    ("Gear Care", crate::keybindings::GameAction::OpenGearCare),
+                    ("Year by Year", crate::keybindings::GameAction::OpenYearlyStats),
+                    ("Report a Bug", crate::keybindings::GameAction::ReportBug),
                     ("Close Menu", crate::keybindings::GameAction::CloseMenu),
                 ];
                 
@@ -872,6 +1337,7 @@ pub fn setup_load_game_menu(
         },
         BackgroundColor(Color::srgb(0.1, 0.1, 0.15)),
         MenuUI,
+        StateScoped(crate::AppState::LoadGame),
     )).with_children(|parent| {
         // Load game container
         parent.spawn((
@@ -913,9 +1379,22 @@ pub fn setup_load_game_menu(
                     ..default()
                 },
             )).with_children(|slots| {
-                // Show available save slots (0-9)
-                for slot in 0..10 {
+                // Show available save slots (0-9), then whichever auto-save slots have been written
+                let auto_save_slots = save_files.iter()
+                    .map(|save| save.slot)
+                    .filter(|slot| SaveManager::is_auto_save_slot(*slot))
+                    .collect::<Vec<_>>();
+                for slot in (0..10).chain(auto_save_slots) {
                     let save_info = save_files.iter().find(|s| s.slot == slot);
+                    let slot_label = if SaveManager::is_auto_save_slot(slot) {
+                        let number = slot - AUTO_SAVE_FIRST_SLOT + 1;
+                        match save_info.and_then(|info| info.home_slot) {
+                            Some(home_slot) => format!("Autosave {} (Slot {})", number, home_slot),
+                            None => format!("Autosave {}", number),
+                        }
+                    } else {
+                        format!("Save Slot {}", slot)
+                    };
                     
                     let (bg_color, text_color, is_enabled) = if save_info.is_some() {
                         (Color::srgb(0.9, 0.9, 0.9), Color::srgb(0.2, 0.2, 0.2), true)
@@ -950,7 +1429,7 @@ pub fn setup_load_game_menu(
                     
                     entity_commands.with_children(|card| {
                         card.spawn((
-                            Text::new(format!("Save Slot {}", slot)),
+                            Text::new(slot_label),
                             TextFont {
                                 font_size: 16.0,
                                 ..default()
@@ -969,6 +1448,10 @@ pub fn setup_load_game_menu(
                                 } else {
                                     format!("{} days ago", modified.as_secs() / 86400)
                                 };
+                                let time_text = match (&info.region, &info.difficulty) {
+                                    (Some(region), Some(difficulty)) => format!("{} - {} - {}", region.name(), difficulty.label(), time_text),
+                                    _ => time_text,
+                                };
                                 
                                 card.spawn((
                                     Text::new(time_text),
@@ -1029,6 +1512,156 @@ pub fn setup_load_game_menu(
     });
 }
 
+pub fn setup_new_game_menu(
+    mut commands: Commands,
+    mut setup: ResMut<NewGameSetup>,
+    settings: Res<GameSettings>,
+) {
+    *setup = NewGameSetup::default();
+    
+    commands.spawn((
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            position_type: PositionType::Absolute,
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        },
+        BackgroundColor(Color::srgb(0.1, 0.1, 0.15)),
+        MenuUI,
+        StateScoped(crate::AppState::NewGameSetup),
+    )).with_children(|parent| {
+        parent.spawn((
+            Node {
+                width: Val::Px(800.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(16.0),
+                padding: UiRect::all(Val::Px(40.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.95, 0.92, 0.88)),
+            BorderColor(Color::srgb(0.6, 0.4, 0.2)),
+        )).with_children(|setup_menu| {
+            setup_menu.spawn((
+                Text::new("New Sanctuary"),
+                TextFont {
+                    font_size: 28.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.3, 0.2, 0.1)),
+            ));
+            
+            setup_menu.spawn((
+                Text::new("Choose where to found your sanctuary. The region sets which birds visit, the weather, native plants and scenery."),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.4, 0.3, 0.2)),
+                Node {
+                    margin: UiRect::bottom(Val::Px(10.0)),
+                    ..default()
+                },
+            ));
+            
+            // Region cards
+            for region in crate::environment::region::Region::ALL {
+                setup_menu.spawn((
+                    Button,
+                    Node {
+                        width: Val::Percent(100.0),
+                        flex_direction: FlexDirection::Column,
+                        row_gap: Val::Px(4.0),
+                        padding: UiRect::all(Val::Px(15.0)),
+                        border: UiRect::all(Val::Px(2.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.9, 0.9, 0.9)),
+                    BorderColor(region_card_border(region == setup.region)),
+                    BorderRadius::all(Val::Px(6.0)),
+                    NewGameSetupButton { action: NewGameSetupAction::SelectRegion(region) },
+                    RegionCard { region },
+                )).with_children(|card| {
+                    card.spawn((
+                        Text::new(region.name()),
+                        TextFont {
+                            font_size: 18.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.2, 0.2, 0.2)),
+                    ));
+                    card.spawn((
+                        Text::new(region.description()),
+                        TextFont {
+                            font_size: 13.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.5, 0.4, 0.3)),
+                    ));
+                });
+            }
+            
+            setup_menu.spawn((
+                Text::new(format!("Difficulty: {} (change in Settings)", settings.difficulty.label())),
+                TextFont {
+                    font_size: 13.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.5, 0.4, 0.3)),
+            ));
+            
+            // Begin and back buttons
+            setup_menu.spawn((
+                Node {
+                    width: Val::Percent(100.0),
+                    justify_content: JustifyContent::Center,
+                    column_gap: Val::Px(20.0),
+                    margin: UiRect::top(Val::Px(10.0)),
+                    ..default()
+                },
+            )).with_children(|buttons| {
+                for (label, action) in [
+                    ("Back to Main Menu", NewGameSetupAction::Back),
+                    ("Begin", NewGameSetupAction::Begin),
+                ] {
+                    buttons.spawn((
+                        Button,
+                        Node {
+                            width: Val::Px(170.0),
+                            height: Val::Px(40.0),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            ..default()
+                        },
+                        BackgroundColor(Color::srgb(0.6, 0.5, 0.4)),
+                        BorderRadius::all(Val::Px(6.0)),
+                        NewGameSetupButton { action },
+                    )).with_children(|button| {
+                        button.spawn((
+                            Text::new(label),
+                            TextFont {
+                                font_size: 16.0,
+                                ..default()
+                            },
+                            TextColor(Color::WHITE),
+                        ));
+                    });
+                }
+            });
+        });
+    });
+}
+
+fn region_card_border(selected: bool) -> Color {
+    if selected {
+        Color::srgb(0.3, 0.6, 0.3)
+    } else {
+        Color::srgb(0.5, 0.5, 0.5)
+    }
+}
+
 // Interaction Systems
 
 pub fn main_menu_button_system(
@@ -1039,7 +1672,6 @@ pub fn main_menu_button_system(
     mut menu_nav_events: EventWriter<MenuNavigationEvent>,
     mut app_exit_events: EventWriter<AppExit>,
     mut _save_events: EventWriter<SaveGameEvent>,
-    mut save_manager: ResMut<SaveManager>,
 ) {
     for (interaction, menu_button, mut bg_color) in interaction_query.iter_mut() {
         match *interaction {
@@ -1048,11 +1680,10 @@ pub fn main_menu_button_system(
                 
                 match menu_button.action {
                     MainMenuAction::NewGame => {
-                        // Start a new game - switch to playing state
-                        save_manager.current_save_slot = Some(0); // Default to slot 0
+                        // Pick a region before the sanctuary starts
                         menu_nav_events.write(MenuNavigationEvent {
-                            target_menu: MenuType::InGame,
-                            target_app_state: Some(crate::AppState::Playing),
+                            target_menu: MenuType::NewGameSetup,
+                            target_app_state: Some(crate::AppState::NewGameSetup),
                         });
                     }
                     MainMenuAction::LoadGame => {
@@ -1082,13 +1713,19 @@ pub fn main_menu_button_system(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn settings_button_system(
+    mut commands: Commands,
     mut interaction_query: Query<
         (&Interaction, &SettingsButton, &mut BackgroundColor),
         (Changed<Interaction>, With<Button>),
     >,
     mut menu_nav_events: EventWriter<MenuNavigationEvent>,
     mut settings: ResMut<GameSettings>,
+    mut keybindings: ResMut<KeyBindings>,
+    mut hud_layout: ResMut<HudLayout>,
+    mut notifications: EventWriter<ShowNotificationEvent>,
+    scoped_query: Query<(Entity, &StateScoped<crate::AppState>)>,
 ) {
     for (interaction, settings_button, mut bg_color) in interaction_query.iter_mut() {
         match *interaction {
@@ -1126,6 +1763,51 @@ pub fn settings_button_system(
                         target_app_state: Some(crate::AppState::Settings),
                     });
                 }
+                SettingsAction::ExportConfig => {
+                    let notification = match crate::user_config::export_config(&settings, &keybindings, &hud_layout) {
+                        Ok(path) => NotificationType::Info {
+                            message: format!("Config exported to {}", path.display()),
+                        },
+                        Err(e) => {
+                            error!("Failed to export config: {}", e);
+                            NotificationType::Warning {
+                                message: "Config export failed, see log".to_string(),
+                            }
+                        }
+                    };
+                    notifications.write(ShowNotificationEvent { notification });
+                }
+                SettingsAction::ImportConfig => {
+                    match crate::user_config::import_config() {
+                        Ok(bundle) => {
+                            *settings = bundle.settings;
+                            *keybindings = bundle.keybindings;
+                            *hud_layout = bundle.hud_layout;
+                            let saved = settings.save_to_file()
+                                .and_then(|_| keybindings.save_to_file())
+                                .and_then(|_| hud_layout.save_to_file());
+                            if let Err(e) = saved {
+                                error!("Failed to store imported config: {}", e);
+                            }
+                            notifications.write(ShowNotificationEvent {
+                                notification: NotificationType::Info {
+                                    message: "Config imported".to_string(),
+                                },
+                            });
+                            // The window and audio follow the changed settings like any other edit;
+                            // the screen is rebuilt in place so every row shows the imported values
+                            rebuild_settings_menu(&mut commands, &scoped_query);
+                        }
+                        Err(e) => {
+                            warn!("Failed to import config from {:?}: {}", crate::user_config::export_path(), e);
+                            notifications.write(ShowNotificationEvent {
+                                notification: NotificationType::Warning {
+                                    message: format!("No config to import at {}", crate::user_config::export_path().display()),
+                                },
+                            });
+                        }
+                    }
+                }
             }
             }
             Interaction::Hovered => {
@@ -1138,12 +1820,35 @@ pub fn settings_button_system(
     }
 }
 
-pub fn graphics_toggle_system(
-    mut interaction_query: Query<
-        (&Interaction, &GraphicsToggle, &Children),
-        (Changed<Interaction>, With<Button>),
-    >,
-    mut text_query: Query<&mut Text>,
+// Keeps the master volume in step with the settings, whether changed by a slider or an imported file
+pub fn apply_audio_settings_system(
+    settings: Res<GameSettings>,
+    mut audio_settings: ResMut<AudioSettings>,
+) {
+    if settings.is_changed() && audio_settings.volume != settings.master_volume {
+        audio_settings.volume = settings.master_volume;
+    }
+}
+
+// Settings to Settings is not a transition, so the screen is torn down and set up again by hand
+fn rebuild_settings_menu(commands: &mut Commands, scoped_query: &Query<(Entity, &StateScoped<crate::AppState>)>) {
+    for (entity, scoped) in scoped_query.iter() {
+        if scoped.0 == crate::AppState::Settings {
+            commands.entity(entity).try_despawn();
+        }
+    }
+    commands.run_system_cached(setup_settings_menu);
+    commands.run_system_cached(setup_audio_sliders_system);
+    commands.run_system_cached(setup_soundscape_sliders_system);
+    commands.run_system_cached(setup_resolution_dropdown_system);
+}
+
+pub fn graphics_toggle_system(
+    mut interaction_query: Query<
+        (&Interaction, &GraphicsToggle, &Children),
+        (Changed<Interaction>, With<Button>),
+    >,
+    mut text_query: Query<&mut Text>,
     mut settings: ResMut<GameSettings>,
 ) {
     for (interaction, toggle, children) in interaction_query.iter() {
@@ -1210,6 +1915,68 @@ pub fn load_game_button_system(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
+pub fn new_game_setup_button_system(
+    mut interaction_query: Query<
+        (&Interaction, &NewGameSetupButton, &mut BackgroundColor),
+        (Changed<Interaction>, With<Button>),
+    >,
+    mut card_query: Query<(&RegionCard, &mut BorderColor)>,
+    mut menu_nav_events: EventWriter<MenuNavigationEvent>,
+    mut setup: ResMut<NewGameSetup>,
+    mut save_manager: ResMut<SaveManager>,
+    settings: Res<GameSettings>,
+    mut difficulty: ResMut<crate::difficulty::DifficultySettings>,
+    mut region: ResMut<crate::environment::region::SanctuaryRegion>,
+    mut soundscape: ResMut<SoundscapeSettings>,
+) {
+    for (interaction, setup_button, mut bg_color) in interaction_query.iter_mut() {
+        // Region cards keep their light background and show selection with the border
+        let (normal, hovered) = match setup_button.action {
+            NewGameSetupAction::SelectRegion(_) => (Color::srgb(0.9, 0.9, 0.9), Color::srgb(0.97, 0.97, 0.97)),
+            _ => (Color::srgb(0.6, 0.5, 0.4), Color::srgb(0.7, 0.6, 0.5)),
+        };
+        
+        match *interaction {
+            Interaction::Pressed => {
+                match setup_button.action {
+                    NewGameSetupAction::SelectRegion(selected) => {
+                        setup.region = selected;
+                        for (card, mut border) in card_query.iter_mut() {
+                            *border = BorderColor(region_card_border(card.region == selected));
+                        }
+                    }
+                    NewGameSetupAction::Begin => {
+                        *bg_color = Color::srgb(0.5, 0.7, 0.5).into();
+                        save_manager.current_save_slot = Some(0); // Default to slot 0
+                        *difficulty = settings.difficulty.clone();
+                        region.0 = setup.region;
+                        // The soundscape mix is saved per sanctuary, so a new one starts from the default
+                        *soundscape = SoundscapeSettings::default();
+                        menu_nav_events.write(MenuNavigationEvent {
+                            target_menu: MenuType::InGame,
+                            target_app_state: Some(crate::AppState::Playing),
+                        });
+                    }
+                    NewGameSetupAction::Back => {
+                        *bg_color = Color::srgb(0.5, 0.7, 0.5).into();
+                        menu_nav_events.write(MenuNavigationEvent {
+                            target_menu: MenuType::MainMenu,
+                            target_app_state: Some(crate::AppState::MainMenu),
+                        });
+                    }
+                }
+            }
+            Interaction::Hovered => {
+                *bg_color = hovered.into();
+            }
+            Interaction::None => {
+                *bg_color = normal.into();
+            }
+        }
+    }
+}
+
 pub fn menu_navigation_system(
     mut menu_nav_events: EventReader<MenuNavigationEvent>,
     mut menu_state: ResMut<MenuState>,
@@ -1431,7 +2198,7 @@ pub fn escape_key_system(
                     target_app_state: Some(crate::AppState::MainMenu),
                 });
             }
-            crate::AppState::LoadGame => {
+            crate::AppState::LoadGame | crate::AppState::NewGameSetup => {
                 menu_nav_events.write(MenuNavigationEvent {
                     target_menu: MenuType::MainMenu,
                     target_app_state: Some(crate::AppState::MainMenu),
@@ -1448,15 +2215,6 @@ pub fn escape_key_system(
     }
 }
 
-pub fn cleanup_menu_ui(
-    mut commands: Commands,
-    menu_query: Query<Entity, With<MenuUI>>,
-) {
-    for entity in menu_query.iter() {
-        commands.entity(entity).safe_despawn();
-    }
-}
-
 // System to add resolution dropdown after UI setup
 pub fn setup_resolution_dropdown_system(
     mut commands: Commands,
@@ -1625,6 +2383,54 @@ pub fn setup_audio_sliders_system(
         
         commands.entity(sfx_container).add_children(&[sfx_label, sfx_slider]);
         commands.entity(section_entity).add_children(&[sfx_container]);
+        
+        // Mixer rows: bird calls and ambience buses, and how many calls can overlap
+        let mixer_rows = [
+            ("Bird Calls", settings.call_volume, SettingType::CallVolume),
+            ("Ambience", settings.ambient_volume, SettingType::AmbientVolume),
+            ("Max Bird Voices", settings.max_bird_voices as f32, SettingType::MaxBirdVoices),
+        ];
+        for (label, value, setting_type) in mixer_rows {
+            let container = commands.spawn((
+                Node {
+                    width: Val::Percent(100.0),
+                    flex_direction: FlexDirection::Row,
+                    justify_content: JustifyContent::SpaceBetween,
+                    align_items: AlignItems::Center,
+                    margin: UiRect::bottom(Val::Px(10.0)),
+                    padding: UiRect::all(Val::Px(10.0)),
+                    ..default()
+                },
+                BackgroundColor(Color::srgb(0.9, 0.9, 0.9)),
+                BorderRadius::all(Val::Px(6.0)),
+            )).id();
+            
+            let label = commands.spawn((
+                Text::new(label),
+                TextFont { font_size: 16.0, ..default() },
+                TextColor(Color::srgb(0.3, 0.2, 0.1)),
+            )).id();
+            
+            let slider = if setting_type == SettingType::MaxBirdVoices {
+                SliderBuilder::new(&mut commands)
+                    .with_range(2.0, 12.0)
+                    .with_step(1.0)
+                    .with_value(value)
+                    .with_value_formatter(|value| format!("{}", value.round() as u32))
+                    .spawn()
+            } else {
+                SliderBuilder::new(&mut commands)
+                    .with_range(0.0, 1.0)
+                    .with_value(value)
+                    .with_value_formatter(|value| format!("{}%", (value * 100.0) as u32))
+                    .spawn()
+            };
+            
+            commands.entity(slider).insert(VolumeSlider { setting_type });
+            
+            commands.entity(container).add_children(&[label, slider]);
+            commands.entity(section_entity).add_children(&[container]);
+        }
     }
 }
 
@@ -1648,7 +2454,15 @@ pub fn volume_slider_update_system(
                 SettingType::SfxVolume => {
                     game_settings.sfx_volume = event.new_value;
                 }
-                _ => {}
+                SettingType::CallVolume => {
+                    game_settings.call_volume = event.new_value;
+                }
+                SettingType::AmbientVolume => {
+                    game_settings.ambient_volume = event.new_value;
+                }
+                SettingType::MaxBirdVoices => {
+                    game_settings.max_bird_voices = event.new_value.round() as usize;
+                }
             }
             
             // Auto-save settings when changed
@@ -1659,6 +2473,168 @@ pub fn volume_slider_update_system(
     }
 }
 
+// System to add soundscape layer sliders below the volume sliders
+pub fn setup_soundscape_sliders_system(
+    mut commands: Commands,
+    soundscape: Res<SoundscapeSettings>,
+    audio_section_query: Query<Entity, With<AudioSection>>,
+    soundscape_slider_query: Query<Entity, With<SoundscapeSlider>>,
+) {
+    if !soundscape_slider_query.is_empty() {
+        return;
+    }
+    
+    for section_entity in audio_section_query.iter() {
+        spawn_soundscape_section(&mut commands, &soundscape, section_entity);
+    }
+}
+
+// Imported tracks need sliders of their own, so the soundscape section is rebuilt after an import
+pub fn rebuild_soundscape_sliders_system(
+    mut commands: Commands,
+    soundscape: Res<SoundscapeSettings>,
+    audio_section_query: Query<Entity, With<AudioSection>>,
+    soundscape_section_query: Query<Entity, With<SoundscapeSection>>,
+    soundscape_slider_query: Query<&SoundscapeSlider>,
+) {
+    let layer_count = AmbientLayer::BUILT_IN.len() + soundscape.custom_tracks.len();
+    if !soundscape.is_changed() || soundscape_slider_query.iter().count() == layer_count {
+        return;
+    }
+    
+    for entity in soundscape_section_query.iter() {
+        commands.entity(entity).despawn();
+    }
+    for section_entity in audio_section_query.iter() {
+        spawn_soundscape_section(&mut commands, &soundscape, section_entity);
+    }
+}
+
+fn spawn_soundscape_section(commands: &mut Commands, soundscape: &SoundscapeSettings, section_entity: Entity) {
+    let mut layers: Vec<AmbientLayer> = AmbientLayer::BUILT_IN.to_vec();
+    layers.extend(soundscape.custom_tracks.iter().map(|track| AmbientLayer::Custom(track.file_name.clone())));
+    
+    let soundscape_section = commands.spawn((
+        Node {
+            width: Val::Percent(100.0),
+            flex_direction: FlexDirection::Column,
+            ..default()
+        },
+        SoundscapeSection,
+    )).id();
+    commands.entity(section_entity).add_children(&[soundscape_section]);
+    
+    let heading = commands.spawn((
+        Text::new("Ambient Soundscape"),
+        TextFont { font_size: 18.0, ..default() },
+        TextColor(Color::srgb(0.3, 0.2, 0.1)),
+        Node {
+            margin: UiRect::vertical(Val::Px(10.0)),
+            ..default()
+        },
+    )).id();
+    commands.entity(soundscape_section).add_children(&[heading]);
+    
+    for layer in &layers {
+        let container = commands.spawn((
+            Node {
+                width: Val::Percent(100.0),
+                flex_direction: FlexDirection::Row,
+                justify_content: JustifyContent::SpaceBetween,
+                align_items: AlignItems::Center,
+                margin: UiRect::bottom(Val::Px(10.0)),
+                padding: UiRect::all(Val::Px(10.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.9, 0.9, 0.9)),
+            BorderRadius::all(Val::Px(6.0)),
+        )).id();
+        
+        let label = commands.spawn((
+            Text::new(layer.label()),
+            TextFont { font_size: 16.0, ..default() },
+            TextColor(Color::srgb(0.3, 0.2, 0.1)),
+        )).id();
+        
+        let slider = SliderBuilder::new(commands)
+            .with_range(0.0, 1.0)
+            .with_value(soundscape.layer_volume(layer))
+            .with_value_formatter(|value| format!("{}%", (value * 100.0) as u32))
+            .spawn();
+            
+        commands.entity(slider).insert(SoundscapeSlider { layer: layer.clone() });
+        
+        commands.entity(container).add_children(&[label, slider]);
+        commands.entity(soundscape_section).add_children(&[container]);
+    }
+    
+    // Import button for the player's own ambient recordings
+    let import_button = commands.spawn((
+        Button,
+        Node {
+            width: Val::Px(240.0),
+            height: Val::Px(36.0),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            margin: UiRect::bottom(Val::Px(6.0)),
+            ..default()
+        },
+        BackgroundColor(Color::srgb(0.6, 0.5, 0.4)),
+        BorderRadius::all(Val::Px(6.0)),
+        ImportAmbientButton,
+    )).with_children(|button| {
+        button.spawn((
+            Text::new("Import Custom Ambience"),
+            TextFont { font_size: 14.0, ..default() },
+            TextColor(Color::WHITE),
+        ));
+    }).id();
+    
+    let import_hint = commands.spawn((
+        Text::new(format!("Place .ogg, .wav, .mp3 or .flac files in {}", user_ambient_directory().display())),
+        TextFont { font_size: 12.0, ..default() },
+        TextColor(Color::srgb(0.5, 0.4, 0.3)),
+    )).id();
+    
+    commands.entity(soundscape_section).add_children(&[import_button, import_hint]);
+}
+
+// System to handle soundscape slider changes
+pub fn soundscape_slider_update_system(
+    mut slider_events: EventReader<SliderValueChangedEvent>,
+    soundscape_slider_query: Query<&SoundscapeSlider>,
+    mut soundscape: ResMut<SoundscapeSettings>,
+) {
+    for event in slider_events.read() {
+        if let Ok(soundscape_slider) = soundscape_slider_query.get(event.entity) {
+            soundscape.set_layer_volume(&soundscape_slider.layer, event.new_value);
+        }
+    }
+}
+
+pub fn import_ambient_button_system(
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor),
+        (Changed<Interaction>, With<ImportAmbientButton>),
+    >,
+    mut import_events: EventWriter<ImportAmbientTracksEvent>,
+) {
+    for (interaction, mut bg_color) in interaction_query.iter_mut() {
+        match *interaction {
+            Interaction::Pressed => {
+                *bg_color = Color::srgb(0.5, 0.7, 0.5).into();
+                import_events.write(ImportAmbientTracksEvent);
+            }
+            Interaction::Hovered => {
+                *bg_color = Color::srgb(0.7, 0.6, 0.5).into();
+            }
+            Interaction::None => {
+                *bg_color = Color::srgb(0.6, 0.5, 0.4).into();
+            }
+        }
+    }
+}
+
 // Simplified dropdown systems (cycle through options on click)
 pub fn resolution_dropdown_system(
     mut dropdown_events: EventReader<DropdownChangedEvent>,
@@ -1781,4 +2757,523 @@ pub fn fullscreen_toggle_system(
             }
         }
     }
-}
\ No newline at end of file
+}
+
+pub fn difficulty_button_system(
+    interaction_query: Query<(&Interaction, &DifficultyButton), (Changed<Interaction>, With<Button>)>,
+    mut value_text_query: Query<(&mut Text, &DifficultyValueText)>,
+    mut settings: ResMut<GameSettings>,
+) {
+    for (interaction, button) in interaction_query.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        
+        match button.target {
+            DifficultyTarget::Preset => {
+                let next = settings.difficulty.preset.next();
+                settings.difficulty.apply_preset(next);
+            }
+            DifficultyTarget::Aspect(aspect) => settings.difficulty.cycle_aspect(aspect),
+        }
+        // Only the default for new sanctuaries; a game in progress keeps the difficulty it began on
+        info!("New game difficulty changed to: {}", settings.difficulty.label());
+        
+        for (mut text, value_text) in value_text_query.iter_mut() {
+            **text = match value_text.target {
+                DifficultyTarget::Preset => settings.difficulty.label(),
+                DifficultyTarget::Aspect(aspect) => settings.difficulty.level(aspect).name(),
+            }.to_string();
+        }
+        
+        // Auto-save settings when changed
+        if let Err(e) = settings.save_to_file() {
+            error!("Failed to save difficulty settings: {}", e);
+        }
+    }
+}
+
+pub fn auto_save_setting_button_system(
+    interaction_query: Query<(&Interaction, &AutoSaveSettingButton), (Changed<Interaction>, With<Button>)>,
+    mut value_text_query: Query<(&mut Text, &AutoSaveValueText)>,
+    mut settings: ResMut<GameSettings>,
+) {
+    for (interaction, button) in interaction_query.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        
+        match button.target {
+            AutoSaveSetting::Enabled => settings.auto_save_enabled = !settings.auto_save_enabled,
+            AutoSaveSetting::Interval => {
+                settings.auto_save_interval = AUTO_SAVE_INTERVALS.iter().copied()
+                    .find(|minutes| *minutes > settings.auto_save_interval)
+                    .unwrap_or(AUTO_SAVE_INTERVALS[0]);
+            }
+            AutoSaveSetting::Slots => {
+                settings.auto_save_slots = settings.auto_save_slots % MAX_AUTO_SAVE_SLOTS + 1;
+            }
+            AutoSaveSetting::AfterPurchase => settings.save_after_purchase = !settings.save_after_purchase,
+            AutoSaveSetting::AfterRareSighting => settings.save_after_rare_sighting = !settings.save_after_rare_sighting,
+            AutoSaveSetting::OnQuit => settings.save_on_quit = !settings.save_on_quit,
+        }
+        
+        for (mut text, value_text) in value_text_query.iter_mut() {
+            **text = value_text.target.value_label(&settings);
+        }
+        
+        // Auto-save settings when changed
+        if let Err(e) = settings.save_to_file() {
+            error!("Failed to save auto-save settings: {}", e);
+        }
+    }
+}
+
+pub fn deck_setting_button_system(
+    interaction_query: Query<(&Interaction, &DeckSettingButton), (Changed<Interaction>, With<Button>)>,
+    mut value_text_query: Query<(&mut Text, &DeckValueText)>,
+    mut settings: ResMut<GameSettings>,
+) {
+    for (interaction, button) in interaction_query.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        
+        match button.target {
+            DeckSetting::Mode => settings.deck_mode = settings.deck_mode.next(),
+            DeckSetting::FrameCap => {
+                settings.frame_cap = FRAME_CAPS.iter().copied()
+                    .find(|cap| *cap > settings.frame_cap)
+                    .unwrap_or(FRAME_CAPS[0]);
+            }
+        }
+        
+        for (mut text, value_text) in value_text_query.iter_mut() {
+            **text = value_text.target.value_label(&settings);
+        }
+        
+        // Auto-save settings when changed
+        if let Err(e) = settings.save_to_file() {
+            error!("Failed to save Steam Deck settings: {}", e);
+        }
+    }
+}
+
+// Largest UI safe area the slider offers, per screen edge
+pub const MAX_UI_SAFE_AREA: f32 = 0.1;
+// Nothing is drawn on this layer; the safe area camera only renders UI
+const SAFE_AREA_RENDER_LAYER: usize = 31;
+
+/// Keeps the primary window in step with the saved resolution, fullscreen and monitor settings
+pub fn apply_display_settings_system(
+    settings: Res<GameSettings>,
+    mut window_query: Query<&mut Window, With<PrimaryWindow>>,
+    monitor_query: Query<(Entity, &Monitor, Has<PrimaryMonitor>)>,
+    mut placed_on: Local<Option<Option<String>>>,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+    let Ok(mut window) = window_query.single_mut() else { return };
+
+    let (width, height) = settings.window_resolution;
+    if window.resolution.width() != width as f32 || window.resolution.height() != height as f32 {
+        window.resolution.set(width as f32, height as f32);
+    }
+
+    let chosen = settings.monitor.as_ref()
+        .and_then(|name| monitor_query.iter().find(|(_, monitor, _)| monitor.name.as_ref() == Some(name)));
+    let selection = chosen.map_or(MonitorSelection::Current, |(entity, ..)| MonitorSelection::Entity(entity));
+    let mode = if !settings.fullscreen {
+        WindowMode::Windowed
+    } else if settings.exclusive_fullscreen {
+        // The refresh rate comes from the chosen monitor, or the primary one when none is chosen
+        let monitor = chosen.or_else(|| monitor_query.iter().find(|(.., primary)| *primary));
+        let video_mode = monitor.and_then(|(_, monitor, _)| fullscreen_video_mode(monitor, settings.window_resolution, settings.refresh_rate_millihertz));
+        WindowMode::Fullscreen(selection, video_mode.map_or(VideoModeSelection::Current, VideoModeSelection::Specific))
+    } else {
+        WindowMode::BorderlessFullscreen(selection)
+    };
+    if window.mode != mode {
+        window.mode = mode;
+    }
+
+    let present_mode = if settings.vsync_enabled { PresentMode::AutoVsync } else { PresentMode::AutoNoVsync };
+    if window.present_mode != present_mode {
+        window.present_mode = present_mode;
+    }
+
+    // A window only moves when the monitor setting changes, so dragging it elsewhere sticks
+    if placed_on.as_ref() != Some(&settings.monitor) {
+        if placed_on.is_some() && chosen.is_some() {
+            window.position = WindowPosition::Centered(selection);
+        }
+        *placed_on = Some(settings.monitor.clone());
+    }
+}
+
+// Best match for the resolution, at the chosen refresh rate or the fastest one going
+fn fullscreen_video_mode(monitor: &Monitor, resolution: (u32, u32), refresh_rate_millihertz: Option<u32>) -> Option<VideoMode> {
+    let size = UVec2::new(resolution.0, resolution.1);
+    let sized: Vec<&VideoMode> = monitor.video_modes.iter().filter(|mode| mode.physical_size == size).collect();
+    let candidates = if sized.is_empty() { monitor.video_modes.iter().collect() } else { sized };
+    candidates.into_iter()
+        .max_by_key(|mode| (
+            Some(mode.refresh_rate_millihertz) == refresh_rate_millihertz,
+            mode.physical_size.element_product(),
+            mode.refresh_rate_millihertz,
+            mode.bit_depth,
+        ))
+        .copied()
+}
+
+// Refresh rates the monitor offers at the chosen resolution, slowest first
+fn refresh_rates(monitor: &Monitor, resolution: (u32, u32)) -> Vec<u32> {
+    let size = UVec2::new(resolution.0, resolution.1);
+    let mut rates: Vec<u32> = monitor.video_modes.iter()
+        .filter(|mode| mode.physical_size == size)
+        .map(|mode| mode.refresh_rate_millihertz)
+        .collect();
+    if rates.is_empty() {
+        rates = monitor.video_modes.iter().map(|mode| mode.refresh_rate_millihertz).collect();
+    }
+    rates.sort_unstable();
+    rates.dedup();
+    rates
+}
+
+/// Moves the UI onto a camera inset from the window edges while a safe area is set
+pub fn apply_ui_safe_area_system(
+    mut commands: Commands,
+    settings: Res<GameSettings>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    mut ui_camera_query: Query<(Entity, &mut Camera), With<SafeAreaUiCamera>>,
+    mut resize_events: EventReader<WindowResized>,
+) {
+    let resized = resize_events.read().count() > 0;
+    if !settings.is_changed() && !resized {
+        return;
+    }
+
+    let inset = settings.ui_safe_area.clamp(0.0, MAX_UI_SAFE_AREA);
+    if inset <= 0.0 {
+        // With no safe area the UI stays on the main camera, as it always has
+        for (entity, _) in &ui_camera_query {
+            commands.entity(entity).despawn();
+        }
+        return;
+    }
+    let Ok(window) = window_query.single() else { return };
+
+    let size = UVec2::new(window.physical_width(), window.physical_height());
+    let margin = (size.as_vec2() * inset).as_uvec2();
+    let viewport = Viewport {
+        physical_position: margin,
+        physical_size: size.saturating_sub(margin * 2).max(UVec2::ONE),
+        ..default()
+    };
+
+    match ui_camera_query.single_mut() {
+        Ok((_, mut camera)) => camera.viewport = Some(viewport),
+        Err(_) => {
+            // A 3D camera, so the many lookups of the one 2D world camera aren't thrown off
+            commands.spawn((
+                Camera3d::default(),
+                Camera {
+                    order: 10,
+                    clear_color: ClearColorConfig::None,
+                    viewport: Some(viewport),
+                    ..default()
+                },
+                Tonemapping::None,
+                Msaa::Off,
+                RenderLayers::layer(SAFE_AREA_RENDER_LAYER),
+                IsDefaultUiCamera,
+                SafeAreaUiCamera,
+                Name::new("SafeAreaUiCamera"),
+            ));
+        }
+    }
+}
+
+pub fn display_setting_button_system(
+    interaction_query: Query<(&Interaction, &DisplaySettingButton), (Changed<Interaction>, With<Button>)>,
+    mut value_text_query: Query<(&mut Text, &DisplayValueText)>,
+    monitor_query: Query<(&Monitor, Has<PrimaryMonitor>)>,
+    mut settings: ResMut<GameSettings>,
+) {
+    for (interaction, button) in interaction_query.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        
+        match button.target {
+            DisplaySetting::FullscreenType => settings.exclusive_fullscreen = !settings.exclusive_fullscreen,
+            DisplaySetting::Monitor => {
+                // Left to right as they're arranged, then back to whichever the window is on
+                // Monitors are remembered by name, so one the system can't name can't be chosen
+                let mut monitors: Vec<&Monitor> = monitor_query.iter()
+                    .map(|(monitor, _)| monitor)
+                    .filter(|monitor| monitor.name.is_some())
+                    .collect();
+                monitors.sort_by_key(|monitor| (monitor.physical_position.x, monitor.physical_position.y));
+                let names: Vec<String> = monitors.iter().filter_map(|monitor| monitor.name.clone()).collect();
+                let next = match &settings.monitor {
+                    None => names.first().cloned(),
+                    Some(current) => names.iter().skip_while(|name| *name != current).nth(1).cloned(),
+                };
+                settings.monitor = next;
+                // Rates differ between monitors
+                settings.refresh_rate_millihertz = None;
+            }
+            DisplaySetting::RefreshRate => {
+                let monitor = settings.monitor.as_ref()
+                    .and_then(|name| monitor_query.iter().find(|(monitor, _)| monitor.name.as_ref() == Some(name)))
+                    .or_else(|| monitor_query.iter().find(|(_, primary)| *primary));
+                let rates = monitor.map(|(monitor, _)| refresh_rates(monitor, settings.window_resolution)).unwrap_or_default();
+                settings.refresh_rate_millihertz = match settings.refresh_rate_millihertz {
+                    None => rates.first().copied(),
+                    Some(current) => rates.into_iter().find(|rate| *rate > current),
+                };
+            }
+        }
+        
+        for (mut text, value_text) in value_text_query.iter_mut() {
+            **text = value_text.target.value_label(&settings);
+        }
+        
+        // Auto-save settings when changed
+        if let Err(e) = settings.save_to_file() {
+            error!("Failed to save display settings: {}", e);
+        }
+    }
+}
+
+pub fn safe_area_slider_system(
+    mut slider_events: EventReader<SliderValueChangedEvent>,
+    safe_area_slider_query: Query<(), With<SafeAreaSlider>>,
+    mut settings: ResMut<GameSettings>,
+) {
+    for event in slider_events.read() {
+        if !safe_area_slider_query.contains(event.entity) {
+            continue;
+        }
+        settings.ui_safe_area = event.new_value.clamp(0.0, MAX_UI_SAFE_AREA);
+        if let Err(e) = settings.save_to_file() {
+            error!("Failed to save UI safe area: {}", e);
+        }
+    }
+}
+
+pub fn bird_cam_setting_button_system(
+    interaction_query: Query<(&Interaction, &BirdCamSettingButton), (Changed<Interaction>, With<Button>)>,
+    mut value_text_query: Query<(&mut Text, &BirdCamValueText)>,
+    mut settings: ResMut<GameSettings>,
+) {
+    for (interaction, button) in interaction_query.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        
+        match button.target {
+            BirdCamSetting::IdleDelay => {
+                settings.bird_cam_idle_minutes = crate::bird_cam::next_idle_delay(settings.bird_cam_idle_minutes);
+            }
+            BirdCamSetting::LaunchOnStart => settings.launch_into_bird_cam = !settings.launch_into_bird_cam,
+        }
+        
+        for (mut text, value_text) in value_text_query.iter_mut() {
+            **text = value_text.target.value_label(&settings);
+        }
+        
+        // Auto-save settings when changed
+        if let Err(e) = settings.save_to_file() {
+            error!("Failed to save bird cam settings: {}", e);
+        }
+    }
+}
+
+pub fn hud_setting_button_system(
+    interaction_query: Query<(&Interaction, &HudSettingButton), (Changed<Interaction>, With<Button>)>,
+    mut value_text_query: Query<(&mut Text, &HudValueText)>,
+    mut hud_layout: ResMut<HudLayout>,
+) {
+    for (interaction, button) in interaction_query.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        
+        match button.target {
+            HudSetting::CurrencyCorner => hud_layout.currency_corner = hud_layout.currency_corner.next(),
+            HudSetting::StatusBar => hud_layout.status_bar_at_top = !hud_layout.status_bar_at_top,
+        }
+        
+        for (mut text, value_text) in value_text_query.iter_mut() {
+            **text = value_text.target.value_label(&hud_layout);
+        }
+        
+        // The HUD is built on entering the yard, so the new layout shows from then on
+        if let Err(e) = hud_layout.save_to_file() {
+            error!("Failed to save HUD layout: {}", e);
+        }
+    }
+}
+
+pub fn menu_backdrop_button_system(
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<MenuBackdropButton>)>,
+    mut value_text_query: Query<&mut Text, With<MenuBackdropValueText>>,
+    mut settings: ResMut<GameSettings>,
+) {
+    for interaction in interaction_query.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        
+        settings.menu_backdrop = settings.menu_backdrop.next();
+        for mut text in value_text_query.iter_mut() {
+            **text = settings.menu_backdrop.label().to_string();
+        }
+        
+        // Auto-save settings when changed
+        if let Err(e) = settings.save_to_file() {
+            error!("Failed to save menu backdrop setting: {}", e);
+        }
+    }
+}
+
+pub fn clip_capture_button_system(
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<ClipCaptureButton>)>,
+    mut value_text_query: Query<&mut Text, With<ClipCaptureValueText>>,
+    mut settings: ResMut<GameSettings>,
+) {
+    for interaction in interaction_query.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        
+        settings.clip_capture = !settings.clip_capture;
+        for mut text in value_text_query.iter_mut() {
+            **text = if settings.clip_capture { "On" } else { "Off" }.to_string();
+        }
+        
+        // Auto-save settings when changed
+        if let Err(e) = settings.save_to_file() {
+            error!("Failed to save clip capture setting: {}", e);
+        }
+    }
+}
+
+pub fn accessibility_setting_button_system(
+    interaction_query: Query<(&Interaction, &AccessibilitySettingButton), (Changed<Interaction>, With<Button>)>,
+    mut value_text_query: Query<(&mut Text, &AccessibilityValueText)>,
+    mut settings: ResMut<GameSettings>,
+) {
+    for (interaction, button) in interaction_query.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        
+        match button.target {
+            AccessibilitySetting::FieldMarkCallouts => settings.field_mark_callouts = !settings.field_mark_callouts,
+            AccessibilitySetting::SizeReference => settings.size_reference_overlay = !settings.size_reference_overlay,
+            AccessibilitySetting::SoundCaptions => settings.sound_captions = !settings.sound_captions,
+            AccessibilitySetting::CaptionBirdCalls => settings.caption_bird_calls = !settings.caption_bird_calls,
+            AccessibilitySetting::CaptionNature => settings.caption_nature = !settings.caption_nature,
+            AccessibilitySetting::CaptionYard => settings.caption_yard = !settings.caption_yard,
+            AccessibilitySetting::CaptionNeighborhood => settings.caption_neighborhood = !settings.caption_neighborhood,
+            AccessibilitySetting::CaptionMnemonics => settings.caption_mnemonics = !settings.caption_mnemonics,
+        }
+        
+        for (mut text, value_text) in value_text_query.iter_mut() {
+            **text = value_text.target.value_label(&settings);
+        }
+        
+        // Auto-save settings when changed
+        if let Err(e) = settings.save_to_file() {
+            error!("Failed to save accessibility settings: {}", e);
+        }
+    }
+}
+
+pub fn rumble_setting_button_system(
+    interaction_query: Query<(&Interaction, &RumbleSettingButton), (Changed<Interaction>, With<Button>)>,
+    mut value_text_query: Query<(&mut Text, &RumbleValueText)>,
+    mut settings: ResMut<GameSettings>,
+) {
+    for (interaction, button) in interaction_query.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        
+        let rumble = &mut settings.rumble;
+        match button.target {
+            RumbleSetting::Intensity => rumble.intensity = rumble.next_intensity(),
+            RumbleSetting::Shutter => rumble.shutter = !rumble.shutter,
+            RumbleSetting::Achievements => rumble.achievements = !rumble.achievements,
+            RumbleSetting::Thunder => rumble.thunder = !rumble.thunder,
+            RumbleSetting::Feeders => rumble.feeders = !rumble.feeders,
+        }
+        
+        for (mut text, value_text) in value_text_query.iter_mut() {
+            **text = value_text.target.value_label(&settings);
+        }
+        
+        // Auto-save settings when changed
+        if let Err(e) = settings.save_to_file() {
+            error!("Failed to save rumble settings: {}", e);
+        }
+    }
+}
+
+// Settings screen rows, for the search palette
+const SEARCHABLE_SETTINGS: [(&str, &str, MenuType); 30] = [
+    ("Master Volume", "audio sound", MenuType::Settings),
+    ("Music Volume", "audio sound", MenuType::Settings),
+    ("SFX Volume", "audio sound effects", MenuType::Settings),
+    ("Bird Calls", "audio mixer songs vocalizations", MenuType::Settings),
+    ("Ambience", "audio mixer soundscape", MenuType::Settings),
+    ("Max Bird Voices", "audio mixer calls overlap", MenuType::Settings),
+    ("Ambient Soundscape", "audio nature background", MenuType::Settings),
+    ("Import Custom Ambience", "audio soundscape", MenuType::Settings),
+    ("Resolution", "graphics display window", MenuType::Settings),
+    ("Graphics Quality", "graphics video", MenuType::Settings),
+    ("VSync", "graphics frame rate", MenuType::Settings),
+    ("Fullscreen", "graphics display window", MenuType::Settings),
+    ("Fullscreen Type", "graphics display borderless exclusive", MenuType::Settings),
+    ("Monitor", "graphics display screen multi-monitor", MenuType::Settings),
+    ("Refresh Rate", "graphics display hz frame rate", MenuType::Settings),
+    ("UI Safe Area", "graphics display tv overscan inset margin", MenuType::Settings),
+    ("Auto-Save", "gameplay save autosave interval slots", MenuType::Settings),
+    ("Save On Quit", "gameplay save autosave exit purchases sightings", MenuType::Settings),
+    ("Record Clip Buffer", "gameplay clips video capture recording", MenuType::Settings),
+    ("Difficulty", "gameplay realism", MenuType::Settings),
+    ("Bird Cam When Idle", "gameplay camera", MenuType::Settings),
+    ("Currency Display", "gameplay hud layout corner status bar interface", MenuType::Settings),
+    ("Menu Backdrop", "main menu background sanctuary photo panorama", MenuType::Settings),
+    ("Field Mark Callouts", "accessibility identification teaching binoculars", MenuType::Settings),
+    ("Size Reference", "accessibility identification scale sparrow robin crow binoculars", MenuType::Settings),
+    ("Sound Captions", "accessibility subtitles audio hearing calls identification", MenuType::Settings),
+    ("Song Mnemonics", "captions phrases learning calls identification", MenuType::Settings),
+    ("Mouse Sensitivity", "controls camera", MenuType::Settings),
+    ("Controller Rumble", "controls gamepad haptics vibration shutter thunder", MenuType::Settings),
+    ("Key Bindings", "controls keys hotkeys", MenuType::SettingsControls),
+];
+
+pub fn provide_settings_search_entries(mut index: ResMut<crate::search_palette::SearchIndex>) {
+    for (label, keywords, menu) in SEARCHABLE_SETTINGS {
+        index.add("Setting", label, keywords, crate::search_palette::SearchTarget::Setting(menu));
+    }
+}
+
+pub fn settings_search_jump_system(
+    mut jump_events: EventReader<crate::search_palette::SearchJumpEvent>,
+    mut menu_state: ResMut<MenuState>,
+    mut app_state: ResMut<NextState<crate::AppState>>,
+) {
+    for event in jump_events.read() {
+        let crate::search_palette::SearchTarget::Setting(menu) = event.target else { continue };
+        menu_state.previous_menu = Some(menu_state.current_menu);
+        menu_state.current_menu = menu;
+        app_state.set(crate::AppState::Settings);
+    }
+}
@@ -0,0 +1,194 @@
+// Neighborhood Noise - Lawnmowers, barking dogs and building work just beyond the yard
+//
+// Every game hour the neighborhood may start up something loud off-screen. While it runs birds
+// are jumpier around the observer and fewer new ones drop in, by how loud it is once it reaches
+// the yard. Hedges soak up the most sound and fences a little, so a well-screened garden barely
+// notices the neighbor's mower. What's going on is shown at the top of the feeder ticker.
+use bevy::prelude::*;
+use bevy::audio::Volume;
+use rand::Rng;
+use crate::audio::captions::{CaptionCategory, SoundCaption};
+use crate::audio::resources::AudioSettings;
+use crate::environment::components::Season;
+use crate::environment::resources::TimeState;
+use crate::garden_lines::{GardenLines, LineKind};
+
+// Hedge and fence segments that give each its full muffling, and how much that is
+const HEDGE_SEGMENTS: f32 = 30.0;
+const FENCE_SEGMENTS: f32 = 40.0;
+const HEDGE_MUFFLING: f32 = 0.5;
+const FENCE_MUFFLING: f32 = 0.2;
+// At full loudness the flush radius grows by this share, and visits drop by this share
+const SKITTISHNESS: f32 = 0.8;
+const VISIT_DROP: f32 = 0.6;
+const SOUND_VOLUME: f32 = 0.5;
+
+pub struct NeighborhoodNoisePlugin;
+
+impl Plugin for NeighborhoodNoisePlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<NeighborhoodNoise>()
+            .add_systems(Update, (noise_buffer_system, neighborhood_noise_system)
+                .chain()
+                .in_set(crate::GameSet::Simulation)
+                .run_if(in_state(crate::AppState::Playing)));
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoiseKind {
+    Lawnmower,
+    DogBarking,
+    Construction,
+}
+
+impl NoiseKind {
+    pub const ALL: [NoiseKind; 3] = [Self::Lawnmower, Self::DogBarking, Self::Construction];
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::Lawnmower => "Lawnmower next door",
+            Self::DogBarking => "Dog barking down the street",
+            Self::Construction => "Construction up the road",
+        }
+    }
+
+    pub fn loudness(&self) -> f32 {
+        match self {
+            Self::Lawnmower => 0.6,
+            Self::DogBarking => 0.4,
+            Self::Construction => 0.9,
+        }
+    }
+
+    // Chance an hour that it starts, when it can
+    fn hourly_chance(&self) -> f32 {
+        match self {
+            Self::Lawnmower => 0.08,
+            Self::DogBarking => 0.1,
+            Self::Construction => 0.03,
+        }
+    }
+
+    // Game hours it runs for
+    fn duration(&self) -> std::ops::Range<f32> {
+        match self {
+            Self::Lawnmower => 0.5..1.5,
+            Self::DogBarking => 0.1..0.5,
+            Self::Construction => 3.0..7.0,
+        }
+    }
+
+    // Nobody mows in winter, and the neighbors keep noise to sociable hours
+    fn can_start(&self, season: Season, hour: f32) -> bool {
+        match self {
+            Self::Lawnmower => season != Season::Winter && (9.0..18.0).contains(&hour),
+            Self::DogBarking => (7.0..21.0).contains(&hour),
+            Self::Construction => (8.0..15.0).contains(&hour),
+        }
+    }
+
+    fn asset_path(&self) -> &'static str {
+        match self {
+            Self::Lawnmower => "audio/neighborhood_lawnmower.ogg",
+            Self::DogBarking => "audio/neighborhood_dog.ogg",
+            Self::Construction => "audio/neighborhood_construction.ogg",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ActiveNoise {
+    pub kind: NoiseKind,
+    // Absolute game hour it stops
+    pub ends_at: f32,
+}
+
+// Resources
+#[derive(Resource, Default)]
+pub struct NeighborhoodNoise {
+    pub active: Option<ActiveNoise>,
+    // Share of outside noise the garden's hedges and fences soak up, 0-1
+    pub muffling: f32,
+    last_roll_hour: u32,
+}
+
+impl NeighborhoodNoise {
+    /// How loud the current noise is once it reaches the yard, 0-1
+    pub fn felt_loudness(&self) -> f32 {
+        self.active.map_or(0.0, |noise| noise.kind.loudness() * (1.0 - self.muffling))
+    }
+
+    /// Multiplies flush radii while something loud is going on
+    pub fn skittishness(&self) -> f32 {
+        1.0 + self.felt_loudness() * SKITTISHNESS
+    }
+
+    /// Multiplies how often new birds drop in
+    pub fn visit_rate(&self) -> f32 {
+        1.0 - self.felt_loudness() * VISIT_DROP
+    }
+}
+
+// Components
+#[derive(Component)]
+pub struct NeighborhoodNoisePlayer;
+
+fn noise_buffer_system(
+    mut noise: ResMut<NeighborhoodNoise>,
+    garden_lines: Res<GardenLines>,
+) {
+    if !garden_lines.is_changed() {
+        return;
+    }
+    let hedges = (garden_lines.segments(LineKind::Hedge) as f32 / HEDGE_SEGMENTS).min(1.0) * HEDGE_MUFFLING;
+    let fences = (garden_lines.segments(LineKind::Fence) as f32 / FENCE_SEGMENTS).min(1.0) * FENCE_MUFFLING;
+    noise.muffling = hedges + fences;
+}
+
+// Ends the current noise when it's done and rolls each hour for a new one
+pub fn neighborhood_noise_system(
+    mut commands: Commands,
+    mut noise: ResMut<NeighborhoodNoise>,
+    time_state: Res<TimeState>,
+    audio_settings: Res<AudioSettings>,
+    asset_server: Res<AssetServer>,
+    player_query: Query<Entity, With<NeighborhoodNoisePlayer>>,
+) {
+    let now = time_state.absolute_hour();
+    if noise.active.is_some_and(|active| now >= active.ends_at) {
+        noise.active = None;
+        for player in &player_query {
+            commands.entity(player).despawn();
+        }
+    }
+
+    let hour = now as u32;
+    if noise.last_roll_hour == hour {
+        return;
+    }
+    noise.last_roll_hour = hour;
+    if noise.active.is_some() {
+        return;
+    }
+
+    let mut rng = rand::rng();
+    let season = time_state.get_season();
+    let Some(kind) = NoiseKind::ALL.into_iter()
+        .filter(|kind| kind.can_start(season, time_state.hour))
+        .find(|kind| rng.random::<f32>() < kind.hourly_chance()) else { return };
+
+    noise.active = Some(ActiveNoise { kind, ends_at: now + rng.random_range(kind.duration()) });
+    info!("🔊 {} ({:.0}% muffled by the garden)", kind.description(), noise.muffling * 100.0);
+
+    let volume = noise.felt_loudness() * SOUND_VOLUME * audio_settings.volume;
+    commands.spawn((
+        AudioPlayer::new(asset_server.load::<bevy::audio::AudioSource>(kind.asset_path())),
+        PlaybackSettings::LOOP.with_volume(Volume::Linear(volume)),
+        NeighborhoodNoisePlayer,
+        SoundCaption::sound(CaptionCategory::Neighborhood, kind.description()),
+        Name::new("NeighborhoodNoise"),
+        StateScoped(crate::AppState::Playing),
+    ));
+}
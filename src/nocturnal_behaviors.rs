@@ -2,8 +2,10 @@
 use bevy::prelude::*;
 use crate::bird::{BirdSpecies, Bird};
 use crate::bird_ai::components::{BirdAI, BirdState};
-use crate::environment::resources::TimeState;
+use crate::environment::resources::{TimeState, WeatherState};
 use crate::environment::components::Season;
+use crate::catalog::components::{PlaceableObject, PlaceableItemType};
+use std::collections::HashMap;
 
 pub struct NocturnalBehaviorPlugin;
 
@@ -23,7 +25,16 @@ impl Plugin for NocturnalBehaviorPlugin {
                 dawn_departure_system,
                 night_migration_system,
                 update_nocturnal_states,
-            ).run_if(in_state(crate::AppState::Playing)));
+                attach_roost_box_system,
+            ).in_set(crate::GameSet::AI).run_if(in_state(crate::AppState::Playing)))
+            .add_systems(Update, (
+                roost_travel_system,
+                departure_flight_system,
+            ).chain()
+                // Runs after the behavior tree so roosting birds stay put through the night
+                .after(crate::bird_ai::systems::behavior_tree_system)
+                .in_set(crate::GameSet::AI)
+                .run_if(in_state(crate::AppState::Playing)));
     }
 }
 
@@ -55,6 +66,30 @@ pub enum RoostType {
     CommunalSite,      // Crows, starlings
     Ground,            // Some game birds
     CliffLedge,        // Raptors
+    RoostBox,          // Insulated boxes for small songbirds and cavity roosters
+}
+
+impl RoostType {
+    // Whether a bird preferring `preference` will settle here
+    pub fn suits(&self, preference: RoostType) -> bool {
+        *self == preference || match self {
+            Self::RoostBox => matches!(preference, RoostType::TreeHollow | RoostType::DenseBranch),
+            Self::ConiferousTree => preference == RoostType::DenseBranch,
+            _ => false,
+        }
+    }
+
+    // Degrees of shelter from the night air before huddling
+    pub fn base_warmth(&self) -> f32 {
+        match self {
+            Self::TreeHollow => 5.0,
+            Self::DenseBranch => 2.0,
+            Self::ConiferousTree => 4.0,
+            Self::CommunalSite => 1.0,
+            Self::Ground | Self::CliffLedge => 0.0,
+            Self::RoostBox => 6.0,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -73,6 +108,8 @@ pub struct NocturnalBehavior {
     pub energy_level: f32,              // 0.0-1.0, affects activity
     pub last_hunt_time: f32,
     pub roost_arrival_time: Option<f32>,
+    // Position in the huddle, which also sets the morning departure wave
+    pub roost_slot: Option<u32>,
 }
 
 #[derive(Component)]
@@ -84,6 +121,31 @@ pub struct RoostSite {
     pub safety_rating: f32,             // 0.0-1.0, predator protection
 }
 
+impl RoostSite {
+    // Sheltered temperature gain; every extra body in the huddle adds a little more
+    pub fn warmth(&self) -> f32 {
+        self.roost_type.base_warmth() + (self.current_occupancy.saturating_sub(1) as f32 * 0.5).min(4.0)
+    }
+
+    // Tight rows of birds pressed together
+    pub fn slot_offset(slot: u32) -> Vec2 {
+        let row = slot / 4;
+        let column = slot % 4;
+        Vec2::new((column as f32 - 1.5) * 9.0 + (row % 2) as f32 * 4.5, row as f32 * 7.0)
+    }
+}
+
+// Departing birds burst outward from the roost before resuming normal behavior
+#[derive(Component)]
+pub struct RoostDeparture {
+    pub velocity: Vec2,
+    pub timer: Timer,
+}
+
+const DEPARTURE_WAVE_SIZE: u32 = 4;
+// Game hours between successive departure waves from the same roost
+const DEPARTURE_WAVE_GAP: f32 = 0.15;
+
 #[derive(Component)]
 pub struct HuntingTerritory {
     pub center: Vec3,
@@ -243,9 +305,12 @@ fn nocturnal_activity_cycle_system(
     mut nocturnal_manager: ResMut<NocturnalManager>,
 ) {
     let current_hour = time_state.hour as f32;
-    let is_night = current_hour >= 20.0 || current_hour <= 6.0;
-    let is_dusk = current_hour >= 18.0 && current_hour <= 20.0;
-    let is_dawn = current_hour >= 5.0 && current_hour <= 7.0;
+    let (sunrise, sunset) = (time_state.sunrise_hour(), time_state.sunset_hour());
+    let is_night = current_hour >= sunset + 1.0 || current_hour <= sunrise;
+    let is_dusk = current_hour >= sunset - 1.0 && current_hour <= sunset + 1.0;
+    let is_dawn = current_hour >= sunrise - 1.0 && current_hour <= sunrise + 1.0;
+    // Bright nights bring out the night hunters, dark ones keep them quiet
+    let moonlight = time_state.moon_illumination();
     
     // Update global nocturnal states
     nocturnal_manager.dusk_activity_peak = is_dusk;
@@ -257,13 +322,13 @@ fn nocturnal_activity_cycle_system(
         // Calculate activity level based on species pattern and time
         let activity_modifier = match traits.activity_pattern {
             ActivityPattern::Nocturnal => {
-                if is_night { 1.0 } else if is_dusk || is_dawn { 0.7 } else { 0.1 }
+                if is_night { 0.7 + moonlight * 0.3 } else if is_dusk || is_dawn { 0.7 } else { 0.1 }
             },
             ActivityPattern::Diurnal => {
                 if is_night { 0.1 } else if is_dusk || is_dawn { 0.6 } else { 1.0 }
             },
             ActivityPattern::Crepuscular => {
-                if is_dusk || is_dawn { 1.0 } else if is_night { 0.3 } else { 0.5 }
+                if is_dusk || is_dawn { 1.0 } else if is_night { 0.3 + moonlight * 0.2 } else { 0.5 }
             },
             ActivityPattern::Cathemeral => 0.8, // Always moderately active
         };
@@ -276,8 +341,8 @@ fn nocturnal_activity_cycle_system(
             // Low activity - diurnal birds should rest during deep night
             // Only allow emergency behaviors to override rest
             match *bird_state {
-                BirdState::Fleeing => {
-                    // Allow fleeing to continue (emergency)
+                BirdState::Fleeing | BirdState::Roosting => {
+                    // Allow fleeing to continue (emergency) and roosting birds to stay huddled
                 },
                 _ => {
                     *bird_state = BirdState::Resting;
@@ -344,10 +409,12 @@ fn owl_hunting_system(
         
         if let Some(territory) = best_territory {
             // Calculate hunting success
+            // Moonlight helps even the best night vision
             let success_chance = traits.hunting_efficiency * 
                                 traits.night_vision_quality * 
                                 nocturnal.energy_level * 
-                                best_score;
+                                best_score *
+                                (0.6 + time_state.moon_illumination() * 0.4);
             
             let success = rand::random::<f32>() < success_chance;
             nocturnal.last_hunt_time = time_state.hour as f32;
@@ -372,20 +439,25 @@ fn owl_hunting_system(
 
 // Roost Site Selection System
 fn roost_selection_system(
-    mut commands: Commands,
     time_state: Res<TimeState>,
+    weather_state: Res<WeatherState>,
     mut bird_query: Query<(Entity, &mut NocturnalBehavior, &Transform, &Bird), With<BirdAI>>,
-    roost_query: Query<(Entity, &mut RoostSite, &Transform), With<RoostSite>>,
+    roost_query: Query<(Entity, &RoostSite, &Transform), With<RoostSite>>,
     mut roost_events: EventWriter<RoostEvent>,
     mut nocturnal_manager: ResMut<NocturnalManager>,
 ) {
     let current_hour = time_state.hour as f32;
-    let seeking_roost_time = current_hour >= 18.0 || current_hour <= 7.0;
+    let seeking_roost_time = current_hour >= time_state.sunset_hour() - 0.5 || current_hour < time_state.sunrise_hour() - 1.0;
     
     if !seeking_roost_time {
         return;
     }
     
+    // Occupancy is only updated from events, so count this frame's claims separately
+    let mut claimed: HashMap<Entity, u32> = HashMap::new();
+    // Cold nights make warm roosts worth a longer flight
+    let cold = ((10.0 - weather_state.temperature) / 20.0).clamp(0.0, 1.0);
+    
     for (entity, mut nocturnal, transform, bird) in bird_query.iter_mut() {
         let traits = bird.species.nocturnal_traits();
         
@@ -401,32 +473,37 @@ fn roost_selection_system(
         
         for (roost_entity, roost_site, roost_transform) in roost_query.iter() {
             // Check roost type compatibility
-            if roost_site.roost_type != traits.roost_preference {
+            if !roost_site.roost_type.suits(traits.roost_preference) {
                 continue;
             }
             
-            // Check capacity for communal roosts
-            if traits.forms_communal_roosts && roost_site.current_occupancy >= roost_site.capacity {
+            // Every roost has room for only so many birds
+            let occupancy = roost_site.current_occupancy + claimed.get(&roost_entity).copied().unwrap_or(0);
+            if occupancy >= roost_site.capacity {
                 continue;
             }
             
+            let max_distance = 200.0 + cold * 150.0;
             let distance = transform.translation.distance(roost_transform.translation);
-            if distance > 200.0 { // Max roost seeking distance
+            if distance > max_distance { // Max roost seeking distance
                 continue;
             }
             
-            let score = roost_site.quality * roost_site.safety_rating * (200.0 - distance) / 200.0;
+            let warmth_bonus = 1.0 + cold * roost_site.warmth() / 6.0;
+            let score = roost_site.quality * roost_site.safety_rating * warmth_bonus * (max_distance - distance) / max_distance;
             
             if score > best_score {
                 best_score = score;
-                best_roost = Some(roost_entity);
+                best_roost = Some((roost_entity, occupancy));
             }
         }
         
         // Select roost site
-        if let Some(roost_entity) = best_roost {
+        if let Some((roost_entity, occupancy)) = best_roost {
             nocturnal.current_roost = Some(roost_entity);
             nocturnal.roost_arrival_time = Some(current_hour);
+            nocturnal.roost_slot = Some(occupancy);
+            *claimed.entry(roost_entity).or_insert(0) += 1;
             
             roost_events.write(RoostEvent {
                 bird_entity: entity,
@@ -434,30 +511,101 @@ fn roost_selection_system(
                 event_type: RoostEventType::Arrival,
             });
             
-            // Update roost occupancy for communal roosts
-            if traits.forms_communal_roosts {
-                // This would need to be handled in a separate system due to query restrictions
+            if traits.forms_communal_roosts && !nocturnal_manager.active_roost_sites.contains(&roost_entity) {
                 nocturnal_manager.active_roost_sites.push(roost_entity);
             }
         }
     }
 }
 
-// Dawn Departure System
+// Birds with a roost fly to their place in the huddle and stay until their departure wave
+fn roost_travel_system(
+    time_state: Res<TimeState>,
+    mut bird_query: Query<(&NocturnalBehavior, &mut Transform, &mut BirdState), (With<BirdAI>, Without<RoostDeparture>, Without<crate::hummingbirds::Torpor>)>,
+    roost_query: Query<&Transform, (With<RoostSite>, Without<BirdAI>)>,
+    time: Res<Time>,
+) {
+    let current_hour = time_state.hour as f32;
+    let roosting_hours = current_hour >= time_state.sunset_hour() - 0.5 || current_hour < time_state.sunrise_hour() + 2.0;
+    
+    for (nocturnal, mut transform, mut state) in bird_query.iter_mut() {
+        let (Some(roost), Some(slot)) = (nocturnal.current_roost, nocturnal.roost_slot) else {
+            continue;
+        };
+        if !roosting_hours || *state == BirdState::Fleeing {
+            continue;
+        }
+        let Ok(roost_transform) = roost_query.get(roost) else {
+            continue;
+        };
+        
+        let spot = roost_transform.translation.truncate() + RoostSite::slot_offset(slot);
+        let to_spot = spot - transform.translation.truncate();
+        *state = BirdState::Roosting;
+        
+        if to_spot.length() > 3.0 {
+            let step = to_spot.normalize() * 130.0 * time.delta_secs();
+            transform.translation += step.clamp_length_max(to_spot.length()).extend(0.0);
+            transform.scale = Vec3::ONE;
+        } else {
+            // Fluffed up and pressed against neighbours
+            transform.scale = Vec3::new(1.2, 0.85, 1.0);
+        }
+    }
+}
+
+fn departure_flight_system(
+    mut commands: Commands,
+    mut bird_query: Query<(Entity, &mut RoostDeparture, &mut Transform, &mut BirdState)>,
+    time: Res<Time>,
+) {
+    for (entity, mut departure, mut transform, mut state) in bird_query.iter_mut() {
+        departure.timer.tick(time.delta());
+        transform.translation += (departure.velocity * time.delta_secs()).extend(0.0);
+        
+        if departure.timer.finished() {
+            commands.entity(entity).remove::<RoostDeparture>();
+            *state = BirdState::Wandering;
+        } else {
+            *state = BirdState::Flocking;
+        }
+    }
+}
+
+fn attach_roost_box_system(
+    mut commands: Commands,
+    object_query: Query<(Entity, &PlaceableObject), (Added<PlaceableObject>, Without<RoostSite>)>,
+) {
+    for (entity, object) in object_query.iter() {
+        if object.item_type == PlaceableItemType::RoostBox {
+            commands.entity(entity).insert(RoostSite {
+                roost_type: RoostType::RoostBox,
+                capacity: 8,
+                current_occupancy: 0,
+                quality: 0.9,
+                safety_rating: 0.95,
+            });
+        }
+    }
+}
+
+// Dawn Departure System - roosts empty in waves, the first birds out at first light
 fn dawn_departure_system(
+    mut commands: Commands,
     time_state: Res<TimeState>,
-    mut bird_query: Query<(Entity, &mut NocturnalBehavior, &mut BirdState, &Bird), With<BirdAI>>,
+    mut bird_query: Query<(Entity, &mut NocturnalBehavior, &mut BirdState, &mut Transform, &Bird), With<BirdAI>>,
+    roost_query: Query<&Transform, (With<RoostSite>, Without<BirdAI>)>,
     mut roost_events: EventWriter<RoostEvent>,
 ) {
     let current_hour = time_state.hour as f32;
-    let sunrise_time = 6.0; // Simplified - could be seasonal
+    let sunrise_time = time_state.sunrise_hour();
     
     // Dawn departure window
     if current_hour < sunrise_time || current_hour > sunrise_time + 2.0 {
         return;
     }
     
-    for (entity, mut nocturnal, mut bird_state, bird) in bird_query.iter_mut() {
+    for (entity, mut nocturnal, mut bird_state, mut transform, bird) in bird_query.iter_mut() {
         let traits = bird.species.nocturnal_traits();
         
         // Skip nocturnal species that roost during day
@@ -467,12 +615,24 @@ fn dawn_departure_system(
         
         // Check if bird should depart roost
         if let Some(roost_site) = nocturnal.current_roost {
-            let departure_time = sunrise_time + traits.dawn_departure_time;
+            let wave = nocturnal.roost_slot.unwrap_or(0) / DEPARTURE_WAVE_SIZE;
+            let departure_time = sunrise_time + traits.dawn_departure_time + wave as f32 * DEPARTURE_WAVE_GAP;
             
             if current_hour >= departure_time {
-                // Depart from roost
+                // Depart from roost, fanning out away from it
+                let away = roost_query.get(roost_site)
+                    .map(|roost| transform.translation.truncate() - roost.translation.truncate())
+                    .unwrap_or_default();
+                let direction = (away + Vec2::new(rand::random::<f32>() - 0.5, 0.5)).normalize_or(Vec2::Y);
+                commands.entity(entity).insert(RoostDeparture {
+                    velocity: direction * 180.0,
+                    timer: Timer::from_seconds(1.5, TimerMode::Once),
+                });
+                
                 nocturnal.current_roost = None;
                 nocturnal.roost_arrival_time = None;
+                nocturnal.roost_slot = None;
+                transform.scale = Vec3::ONE;
                 *bird_state = BirdState::Wandering;
                 
                 roost_events.write(RoostEvent {
@@ -558,6 +718,7 @@ fn initialize_nocturnal_behavior(
             energy_level: 0.8,
             last_hunt_time: 0.0,
             roost_arrival_time: None,
+            roost_slot: None,
         });
     }
 }
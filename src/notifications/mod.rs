@@ -19,6 +19,6 @@ impl Plugin for NotificationPlugin {
                 notification_spawner_system,
                 notification_animation_system,
                 notification_cleanup_system,
-            ));
+            ).in_set(crate::GameSet::UI));
     }
 }
\ No newline at end of file
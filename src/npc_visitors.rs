@@ -0,0 +1,592 @@
+// NPC Visitors - Ornithologists, neighbor kids and park rangers dropping by the yard
+use bevy::prelude::*;
+use crate::bird::BirdSpecies;
+use crate::catalog::components::PlaceableItemType;
+use crate::catalog::resources::PlayerInventory;
+use crate::dialog::{DialogChoiceEvent, DialogOption, DialogState, StartDialogEvent};
+use crate::environment::resources::TimeState;
+use crate::garden_lines::GardenLines;
+use crate::journal::resources::{
+    MissionDifficulty, MissionProgress, MissionType, ObjectiveType, ResearchMission,
+    ResearchMissionManager, ResearchObjective, ResearchRewards,
+};
+use crate::notifications::{resources::ShowNotificationEvent, components::NotificationType};
+use crate::photo_mode::components::PhotoTakenEvent;
+use crate::sanctuary_management::{HabitatScore, ReputationTier, SanctuaryReputation};
+use crate::camera::cursor_world_position;
+
+const YARD_EDGE_X: f32 = 700.0;
+const WAITING_X: f32 = 520.0;
+const VISITOR_Y: f32 = -250.0;
+const WALK_SPEED: f32 = 60.0;
+
+const OPTION_ACCEPT: u32 = 1;
+const OPTION_DECLINE: u32 = 2;
+
+pub struct NpcVisitorsPlugin;
+
+impl Plugin for NpcVisitorsPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<VisitorSchedule>()
+            .init_resource::<Commissions>()
+            .add_systems(Update, (
+                visitor_scheduling_system,
+                visitor_movement_system,
+                visitor_click_system,
+                visitor_dialog_choice_system,
+                visitor_marker_system,
+                commission_photo_system,
+            ).chain().in_set(crate::GameSet::Simulation).run_if(in_state(crate::AppState::Playing)));
+    }
+}
+
+// Resources
+#[derive(Resource)]
+pub struct VisitorSchedule {
+    pub next_visit: Timer,
+    pub last_visitor: Option<VisitorKind>,
+    pub visits: u32,
+}
+
+impl Default for VisitorSchedule {
+    fn default() -> Self {
+        Self {
+            next_visit: Timer::from_seconds(150.0, TimerMode::Once),
+            last_visitor: None,
+            visits: 0,
+        }
+    }
+}
+
+// Photo commissions accepted from collectors, paid out on a qualifying shot
+#[derive(Resource, Default)]
+pub struct Commissions {
+    pub active: Vec<Commission>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Commission {
+    pub species: BirdSpecies,
+    pub min_score: u32,
+    pub reward: u32,
+}
+
+// Components
+#[derive(Component)]
+pub struct NpcVisitor {
+    pub kind: VisitorKind,
+    pub state: VisitorState,
+    pub side: f32, // -1.0 left edge, 1.0 right edge
+    pub waiting_x: f32, // Distance from the middle of the yard where the visitor stops
+    pub offer: Option<VisitorOffer>,
+}
+
+#[derive(Component)]
+pub struct VisitorOfferMarker;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum VisitorKind {
+    Ornithologist,
+    NeighborKid,
+    ParkRanger,
+    Birdwatcher,
+    Collector,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum VisitorState {
+    Arriving,
+    Waiting(Timer),
+    Leaving,
+}
+
+#[derive(Debug, Clone)]
+pub enum VisitorOffer {
+    Mission(Box<ResearchMission>),
+    Trade { item: PlaceableItemType, cost: u32 },
+    Donation(u32),
+    Commission(Commission),
+}
+
+impl VisitorKind {
+    pub const ALL: [VisitorKind; 5] = [
+        Self::Ornithologist,
+        Self::NeighborKid,
+        Self::ParkRanger,
+        Self::Birdwatcher,
+        Self::Collector,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Ornithologist => "Dr. Reyes, Ornithologist",
+            Self::NeighborKid => "Sam from next door",
+            Self::ParkRanger => "Ranger Okafor",
+            Self::Birdwatcher => "Priya from the birding club",
+            Self::Collector => "Mr. Whitlock, wildlife art collector",
+        }
+    }
+
+    fn color(&self) -> Color {
+        match self {
+            Self::Ornithologist => Color::srgb(0.35, 0.45, 0.3),
+            Self::NeighborKid => Color::srgb(0.85, 0.45, 0.3),
+            Self::ParkRanger => Color::srgb(0.5, 0.4, 0.25),
+            Self::Birdwatcher => Color::srgb(0.3, 0.5, 0.6),
+            Self::Collector => Color::srgb(0.25, 0.2, 0.3),
+        }
+    }
+
+    fn height(&self) -> f32 {
+        match self {
+            Self::NeighborKid => 34.0,
+            _ => 48.0,
+        }
+    }
+
+    // Scientists only bother visiting yards that are worth studying
+    pub fn min_habitat_score(&self) -> f32 {
+        match self {
+            Self::Ornithologist => 40.0,
+            Self::NeighborKid => 0.0,
+            Self::ParkRanger => 15.0,
+            Self::Birdwatcher | Self::Collector => 0.0,
+        }
+    }
+
+    // Word of mouth brings these visitors once the sanctuary has a reputation
+    pub fn min_reputation(&self) -> ReputationTier {
+        match self {
+            Self::Birdwatcher => ReputationTier::Local,
+            Self::Collector => ReputationTier::Renowned,
+            _ => ReputationTier::Unknown,
+        }
+    }
+
+    fn greeting(&self, habitat: &HabitatScore) -> String {
+        let score = habitat.score;
+        match self {
+            Self::Ornithologist if score >= 75.0 => format!(
+                "Remarkable - {} species in a single backyard! This is exactly the kind of site we need data from.",
+                habitat.species_present,
+            ),
+            Self::Ornithologist => "I heard there's been some interesting activity around your feeders. Mind if I take a look?".to_string(),
+            Self::NeighborKid if score >= 50.0 => "Whoa, your yard has SO many birds! Can I watch for a bit?".to_string(),
+            Self::NeighborKid => "Hi! I saw a bird land on your fence. Is it yours?".to_string(),
+            Self::ParkRanger if score >= 50.0 => format!(
+                "Your habitat rating is {} - you're doing the local population a real favor.",
+                habitat.rating().to_lowercase(),
+            ),
+            Self::ParkRanger => "Just making my rounds. Yards like this could be a real refuge with a bit more cover and food.".to_string(),
+            Self::Birdwatcher => format!(
+                "The whole club has been talking about your yard - {} species and counting!",
+                habitat.species_present,
+            ),
+            Self::Collector => "Your sanctuary's photographs have quite the reputation. I'd like to commission one.".to_string(),
+        }
+    }
+}
+
+fn visitor_scheduling_system(
+    mut commands: Commands,
+    mut schedule: ResMut<VisitorSchedule>,
+    habitat: Res<HabitatScore>,
+    reputation: Res<SanctuaryReputation>,
+    research_manager: Res<ResearchMissionManager>,
+    time_state: Res<TimeState>,
+    visitor_query: Query<&NpcVisitor>,
+    garden_lines: Res<GardenLines>,
+    time: Res<Time>,
+) {
+    schedule.next_visit.tick(time.delta());
+    if !schedule.next_visit.finished() {
+        return;
+    }
+
+    // Visitors only come by during the day, garden paths let more than one wait at once
+    let visitors = visitor_query.iter().count();
+    if !(8.0..18.0).contains(&time_state.hour) || visitors >= garden_lines.visitor_capacity() {
+        return;
+    }
+
+    let eligible: Vec<VisitorKind> = VisitorKind::ALL.iter()
+        .copied()
+        .filter(|kind| habitat.score >= kind.min_habitat_score() && reputation.tier >= kind.min_reputation())
+        .filter(|kind| Some(*kind) != schedule.last_visitor)
+        .collect();
+    let Some(&kind) = eligible.get(rand::random::<u32>() as usize % eligible.len().max(1)) else { return };
+
+    let side = if rand::random::<bool>() { 1.0 } else { -1.0 };
+    let offer = create_offer(kind, &habitat, reputation.tier, &research_manager, time_state.day_of_year);
+
+    commands.spawn((
+        Sprite::from_color(kind.color(), Vec2::new(22.0, kind.height())),
+        Transform::from_xyz(side * YARD_EDGE_X, VISITOR_Y, 0.9),
+        NpcVisitor {
+            kind,
+            state: VisitorState::Arriving,
+            side,
+            waiting_x: WAITING_X - visitors as f32 * 70.0,
+            offer: Some(offer),
+        },
+        Name::new(format!("Visitor_{:?}", kind)),
+    )).with_children(|visitor| {
+        // Head
+        visitor.spawn((
+            Sprite::from_color(Color::srgb(0.87, 0.72, 0.6), Vec2::splat(16.0)),
+            Transform::from_xyz(0.0, kind.height() / 2.0 + 8.0, 0.01),
+        ));
+
+        visitor.spawn((
+            Text2d::new("!"),
+            TextFont { font_size: 24.0, ..default() },
+            TextColor(Color::srgb(1.0, 0.85, 0.2)),
+            Transform::from_xyz(0.0, kind.height() / 2.0 + 34.0, 0.02),
+            VisitorOfferMarker,
+        ));
+    });
+
+    schedule.last_visitor = Some(kind);
+    schedule.visits += 1;
+    let delay = (120.0 + rand::random::<f32>() * 180.0) / reputation.tier.visit_rate();
+    schedule.next_visit = Timer::from_seconds(delay, TimerMode::Once);
+    info!("🚶 {} is visiting the sanctuary", kind.name());
+}
+
+fn create_offer(
+    kind: VisitorKind,
+    habitat: &HabitatScore,
+    tier: ReputationTier,
+    research_manager: &ResearchMissionManager,
+    day: u32,
+) -> VisitorOffer {
+    let mission_id = research_manager.active_missions.iter()
+        .chain(research_manager.completed_missions.iter())
+        .map(|mission| mission.id)
+        .max()
+        .unwrap_or(0) + 1;
+
+    match kind {
+        VisitorKind::Ornithologist => {
+            let species = pick_species(2);
+            let difficulty = if habitat.score >= 70.0 { MissionDifficulty::Student } else { MissionDifficulty::Citizen };
+            VisitorOffer::Mission(Box::new(visitor_mission(
+                mission_id,
+                format!("{:?} Field Survey", species),
+                format!("Help {} document {:?} visits to backyard habitats.", kind.name(), species),
+                MissionType::BehaviorStudy {
+                    target_species: species,
+                    target_behavior: "Feeding".to_string(),
+                    required_observations: 5,
+                },
+                difficulty,
+                ResearchObjective {
+                    id: format!("visitor_survey_{}", mission_id),
+                    description: format!("Photograph a {:?} scoring at least 300", species),
+                    objective_type: ObjectiveType::CollectPhotos { species, min_score: 300, count: 3 },
+                    completed: false,
+                    progress: 0.0,
+                },
+                Some("Regional Bird Atlas".to_string()),
+                day,
+            )))
+        }
+        VisitorKind::ParkRanger if habitat.score >= 40.0 => {
+            let species = pick_species(3);
+            VisitorOffer::Mission(Box::new(visitor_mission(
+                mission_id,
+                "Predator Watch".to_string(),
+                format!("{} wants records of how birds react to hawks near the yard.", kind.name()),
+                MissionType::ConservationStudy {
+                    threatened_species: species,
+                    habitat_assessment: true,
+                    threat_documentation: 3,
+                },
+                MissionDifficulty::Citizen,
+                ResearchObjective {
+                    id: format!("visitor_predator_watch_{}", mission_id),
+                    description: format!("Observe a {:?} sheltering from a predator 3 times", species),
+                    objective_type: ObjectiveType::ObserveBehavior {
+                        species,
+                        behavior: "Sheltering".to_string(),
+                        count: 3,
+                    },
+                    completed: false,
+                    progress: 0.0,
+                },
+                Some("State Parks Service".to_string()),
+                day,
+            )))
+        }
+        // A struggling yard gets a discounted nesting box to get things going
+        VisitorKind::ParkRanger => VisitorOffer::Trade { item: PlaceableItemType::NestingBox, cost: 40 },
+        VisitorKind::NeighborKid => {
+            let items = [PlaceableItemType::FlowerPot, PlaceableItemType::BellToy, PlaceableItemType::CardboardBox];
+            let item = items[rand::random::<u32>() as usize % items.len()].clone();
+            // Kids are generous to yards full of birds
+            let cost = if habitat.score >= 50.0 { 0 } else { 25 };
+            VisitorOffer::Trade { item, cost }
+        }
+        VisitorKind::Birdwatcher => {
+            let base = 30 + rand::random::<u32>() % 30;
+            let bonus = if tier == ReputationTier::Celebrated { 2 } else { 1 };
+            VisitorOffer::Donation(base * tier as u32 * bonus)
+        }
+        VisitorKind::Collector => {
+            let species = pick_species(4);
+            let min_score = 150 + species.rarity_tier() as u32 * 20;
+            let bonus = if tier == ReputationTier::Celebrated { 2 } else { 1 };
+            VisitorOffer::Commission(Commission {
+                species,
+                min_score,
+                reward: species.rarity_tier() as u32 * 120 * bonus,
+            })
+        }
+    }
+}
+
+// Uncommon-or-rarer species that are worth a study
+fn pick_species(max_tier: u8) -> BirdSpecies {
+    (0..50)
+        .map(|_| BirdSpecies::random_with_rarity(5.0))
+        .find(|species| (2..=max_tier).contains(&species.rarity_tier()))
+        .unwrap_or(BirdSpecies::Cardinal)
+}
+
+fn visitor_mission(
+    id: u32,
+    title: String,
+    description: String,
+    mission_type: MissionType,
+    difficulty: MissionDifficulty,
+    objective: ResearchObjective,
+    partner: Option<String>,
+    day: u32,
+) -> ResearchMission {
+    ResearchMission {
+        id,
+        title,
+        description,
+        mission_type,
+        difficulty,
+        objectives: vec![objective],
+        rewards: ResearchRewards {
+            research_points: difficulty.research_points(),
+            currency: difficulty.research_points() * 2,
+            unlocked_content: Vec::new(),
+            badge: None,
+            citizen_science_credit: true,
+        },
+        progress: MissionProgress {
+            started_date: format!("Day {}", day),
+            start_day: 0,
+            days_active: 0,
+            completion_percentage: 0.0,
+            data_quality_score: 0.0,
+        },
+        citizen_science_partner: partner,
+    }
+}
+
+fn visitor_movement_system(
+    mut commands: Commands,
+    mut visitor_query: Query<(Entity, &mut NpcVisitor, &mut Transform)>,
+    dialog_state: Res<DialogState>,
+    time: Res<Time>,
+) {
+    for (entity, mut visitor, mut transform) in &mut visitor_query {
+        let side = visitor.side;
+        let waiting_x = visitor.waiting_x;
+        match &mut visitor.state {
+            VisitorState::Arriving => {
+                transform.translation.x -= side * WALK_SPEED * time.delta_secs();
+                if transform.translation.x * side <= waiting_x {
+                    visitor.state = VisitorState::Waiting(Timer::from_seconds(75.0, TimerMode::Once));
+                }
+            }
+            VisitorState::Waiting(patience) => {
+                // Nobody walks off mid-conversation
+                if dialog_state.speaking_with() == Some(entity) {
+                    continue;
+                }
+                patience.tick(time.delta());
+                if patience.finished() {
+                    visitor.state = VisitorState::Leaving;
+                }
+            }
+            VisitorState::Leaving => {
+                transform.translation.x += side * WALK_SPEED * time.delta_secs();
+                if transform.translation.x * side >= YARD_EDGE_X {
+                    commands.entity(entity).despawn();
+                }
+            }
+        }
+    }
+}
+
+fn visitor_click_system(
+    mouse_button: Res<ButtonInput<MouseButton>>,
+    windows: Query<&Window>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<Camera2d>>,
+    visitor_query: Query<(Entity, &NpcVisitor, &Transform)>,
+    dialog_state: Res<DialogState>,
+    habitat: Res<HabitatScore>,
+    mut dialog_events: EventWriter<StartDialogEvent>,
+) {
+    if !mouse_button.just_pressed(MouseButton::Left) || dialog_state.is_open() {
+        return;
+    }
+
+    let Ok(window) = windows.single() else { return };
+    let Ok((camera, camera_transform)) = camera_query.single() else { return };
+    let Some(world_position) = cursor_world_position(window, camera, camera_transform) else { return };
+
+    for (entity, visitor, transform) in &visitor_query {
+        if transform.translation.truncate().distance(world_position) > 40.0 {
+            continue;
+        }
+
+        let mut lines = vec![visitor.kind.greeting(&habitat)];
+        let mut options = Vec::new();
+
+        match &visitor.offer {
+            Some(VisitorOffer::Mission(mission)) => {
+                lines.push(format!("{} {}", mission.description, mission.objectives[0].description));
+                lines.push(format!(
+                    "It's worth {} research points and {} coins. Interested?",
+                    mission.rewards.research_points, mission.rewards.currency,
+                ));
+                options.push(DialogOption::new(OPTION_ACCEPT, "Accept mission"));
+                options.push(DialogOption::new(OPTION_DECLINE, "Not right now"));
+            }
+            Some(VisitorOffer::Trade { item, cost: 0 }) => {
+                lines.push(format!("I made you something! Do you want this {}?", item.name()));
+                options.push(DialogOption::new(OPTION_ACCEPT, "Thank you!"));
+                options.push(DialogOption::new(OPTION_DECLINE, "You keep it"));
+            }
+            Some(VisitorOffer::Trade { item, cost }) => {
+                lines.push(format!("I've got a spare {} I could let you have for {} coins.", item.name(), cost));
+                options.push(DialogOption::new(OPTION_ACCEPT, format!("Buy for {}", cost)));
+                options.push(DialogOption::new(OPTION_DECLINE, "No thanks"));
+            }
+            Some(VisitorOffer::Donation(amount)) => {
+                lines.push(format!("We took up a collection at our last meeting - {} coins toward feeding the flock.", amount));
+                options.push(DialogOption::new(OPTION_ACCEPT, "Accept with thanks"));
+            }
+            Some(VisitorOffer::Commission(commission)) => {
+                lines.push(format!(
+                    "Bring me a photograph of a {:?} scoring at least {} and I'll pay {} coins.",
+                    commission.species, commission.min_score, commission.reward,
+                ));
+                options.push(DialogOption::new(OPTION_ACCEPT, "Accept commission"));
+                options.push(DialogOption::new(OPTION_DECLINE, "Not interested"));
+            }
+            None => lines.push("Thanks again - I'll be heading off soon.".to_string()),
+        }
+
+        dialog_events.write(StartDialogEvent {
+            source: entity,
+            speaker: visitor.kind.name().to_string(),
+            portrait_color: visitor.kind.color(),
+            lines,
+            options,
+        });
+        break;
+    }
+}
+
+fn visitor_dialog_choice_system(
+    mut choice_events: EventReader<DialogChoiceEvent>,
+    mut visitor_query: Query<&mut NpcVisitor>,
+    mut research_manager: ResMut<ResearchMissionManager>,
+    mut inventory: ResMut<PlayerInventory>,
+    mut commissions: ResMut<Commissions>,
+    time_state: Res<TimeState>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+) {
+    for event in choice_events.read() {
+        let Ok(mut visitor) = visitor_query.get_mut(event.source) else { continue };
+
+        if event.option_id != OPTION_ACCEPT {
+            continue;
+        }
+
+        let message = match visitor.offer.take() {
+            Some(VisitorOffer::Mission(mut mission)) => {
+                let message = format!("New research mission: {}", mission.title);
+                mission.progress.start_day = time_state.absolute_day();
+                research_manager.active_missions.push(*mission);
+                message
+            }
+            Some(VisitorOffer::Trade { item, cost }) => {
+                let item_name = item.name().to_string();
+                if inventory.currency < cost {
+                    visitor.offer = Some(VisitorOffer::Trade { item, cost });
+                    format!("You need {} coins for the {}", cost, item_name)
+                } else {
+                    inventory.currency -= cost;
+                    *inventory.owned_items.entry(item).or_insert(0) += 1;
+                    format!("Received {} from {}", item_name, visitor.kind.name())
+                }
+            }
+            Some(VisitorOffer::Donation(amount)) => {
+                inventory.currency += amount;
+                format!("{} donated {} coins", visitor.kind.name(), amount)
+            }
+            Some(VisitorOffer::Commission(commission)) => {
+                let message = format!("Commission accepted: photograph a {:?} ({}+ points)", commission.species, commission.min_score);
+                commissions.active.push(commission);
+                message
+            }
+            None => continue,
+        };
+
+        // Once the offer is settled the visitor heads home
+        if visitor.offer.is_none() {
+            visitor.state = VisitorState::Leaving;
+        }
+
+        notification_events.write(ShowNotificationEvent {
+            notification: NotificationType::Info { message },
+        });
+    }
+}
+
+fn visitor_marker_system(
+    visitor_query: Query<(&NpcVisitor, &Children), Changed<NpcVisitor>>,
+    mut marker_query: Query<&mut Visibility, With<VisitorOfferMarker>>,
+) {
+    for (visitor, children) in &visitor_query {
+        for child in children.iter() {
+            if let Ok(mut visibility) = marker_query.get_mut(child) {
+                *visibility = if visitor.offer.is_some() { Visibility::Inherited } else { Visibility::Hidden };
+            }
+        }
+    }
+}
+
+fn commission_photo_system(
+    mut photo_events: EventReader<PhotoTakenEvent>,
+    mut commissions: ResMut<Commissions>,
+    mut inventory: ResMut<PlayerInventory>,
+    mut reputation: ResMut<SanctuaryReputation>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+) {
+    for event in photo_events.read() {
+        let Some(species) = event.species else { continue };
+        let Some(index) = commissions.active.iter()
+            .position(|commission| commission.species == species && event.score.total_score >= commission.min_score)
+        else { continue };
+
+        let commission = commissions.active.remove(index);
+        inventory.currency += commission.reward;
+        reputation.record_commission();
+
+        notification_events.write(ShowNotificationEvent {
+            notification: NotificationType::Info {
+                message: format!("Commission fulfilled! The {:?} photo earned {} coins", species, commission.reward),
+            },
+        });
+    }
+}
@@ -0,0 +1,140 @@
+// Object Atlas - Packs placed-object sprites into one shared texture at startup
+//
+// Every catalog item used to be its own texture, so a busy yard broke sprite batching into one
+// draw call per object type. Once the object images finish loading they are packed into a single
+// atlas and placed objects, saved objects and the placement ghost are pointed at it. Objects with
+// seasonal variants keep their own textures because seasonal decor swaps their images at runtime.
+use bevy::prelude::*;
+use bevy::asset::{LoadState, RenderAssetUsages};
+use bevy::image::TextureAtlasBuilder;
+use bevy::render::view::VisibilitySystems;
+use std::collections::HashMap;
+use crate::catalog::components::{PlaceableObject, PlacementGhost};
+use crate::catalog::resources::ItemCategory;
+use crate::catalog::systems::object_filename;
+use crate::seasonal_decor::SeasonalSprites;
+
+const ATLAS_MAX_SIZE: u32 = 4096;
+// Transparent gap between packed sprites so filtering never bleeds a neighbour in
+const ATLAS_PADDING: u32 = 2;
+
+pub struct ObjectAtlasPlugin;
+
+impl Plugin for ObjectAtlasPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<ObjectAtlas>()
+            .add_systems(Startup, load_object_sprites_system)
+            .add_systems(Update, pack_object_atlas_system.in_set(crate::GameSet::Presentation))
+            // After Update so sprites spawned this frame and their seasonal variants are both in place
+            .add_systems(PostUpdate, apply_object_atlas_system.before(VisibilitySystems::CalculateBounds));
+    }
+}
+
+// Resources
+#[derive(Resource, Default)]
+pub struct ObjectAtlas {
+    // Source images held only until they are packed
+    sources: Vec<Handle<Image>>,
+    packed: Option<PackedAtlas>,
+    // Set when packing finishes so sprites spawned before it are converted too
+    just_packed: bool,
+}
+
+pub struct PackedAtlas {
+    pub image: Handle<Image>,
+    pub layout: Handle<TextureAtlasLayout>,
+    // Keyed by asset path, since the source images unload once nothing else uses them
+    indices: HashMap<String, usize>,
+}
+
+fn load_object_sprites_system(mut atlas: ResMut<ObjectAtlas>, asset_server: Res<AssetServer>) {
+    atlas.sources = ItemCategory::ALL.iter()
+        .flat_map(|category| category.items())
+        .map(|item| asset_server.load(format!("objects/{}.png", object_filename(&item))))
+        .collect();
+}
+
+fn pack_object_atlas_system(
+    mut atlas: ResMut<ObjectAtlas>,
+    mut images: ResMut<Assets<Image>>,
+    mut layouts: ResMut<Assets<TextureAtlasLayout>>,
+    asset_server: Res<AssetServer>,
+) {
+    if atlas.packed.is_some() || atlas.sources.is_empty() {
+        return;
+    }
+
+    // Missing sprites fail to load; pack whatever did load rather than waiting forever
+    let settled = atlas.sources.iter().all(|handle| {
+        matches!(asset_server.load_state(handle), LoadState::Loaded | LoadState::Failed(_))
+    });
+    if !settled {
+        return;
+    }
+
+    let sources = std::mem::take(&mut atlas.sources);
+    let mut builder = TextureAtlasBuilder::default();
+    builder
+        .max_size(UVec2::splat(ATLAS_MAX_SIZE))
+        .padding(UVec2::splat(ATLAS_PADDING));
+
+    let mut packed_paths = Vec::new();
+    for handle in &sources {
+        let (Some(image), Some(path)) = (images.get(handle), asset_server.get_path(handle)) else { continue };
+        builder.add_texture(Some(handle.id()), image);
+        packed_paths.push((handle.id(), path.to_string()));
+    }
+    if packed_paths.is_empty() {
+        warn!("Object atlas: no object sprites loaded, objects keep their own textures");
+        return;
+    }
+
+    let (layout, atlas_sources, mut atlas_image) = match builder.build() {
+        Ok(built) => built,
+        Err(e) => {
+            warn!("Object atlas: packing failed ({:?}), objects keep their own textures", e);
+            return;
+        }
+    };
+
+    let indices = packed_paths.into_iter()
+        .filter_map(|(id, path)| Some((path, atlas_sources.texture_index(id)?)))
+        .collect::<HashMap<_, _>>();
+    info!("🧩 Object atlas: packed {} sprites into a {}x{} texture", indices.len(), atlas_image.width(), atlas_image.height());
+
+    // The CPU copy is only needed for packing
+    atlas_image.asset_usage = RenderAssetUsages::RENDER_WORLD;
+    atlas.packed = Some(PackedAtlas {
+        image: images.add(atlas_image),
+        layout: layouts.add(layout),
+        indices,
+    });
+    atlas.just_packed = true;
+}
+
+fn apply_object_atlas_system(
+    mut atlas: ResMut<ObjectAtlas>,
+    mut sprite_query: Query<&mut Sprite, (Or<(With<PlaceableObject>, With<PlacementGhost>)>, Without<SeasonalSprites>)>,
+    asset_server: Res<AssetServer>,
+) {
+    let Some(packed) = &atlas.packed else { return };
+
+    for mut sprite in &mut sprite_query {
+        if !(sprite.is_changed() || atlas.just_packed) || sprite.texture_atlas.is_some() {
+            continue;
+        }
+        let Some(path) = asset_server.get_path(&sprite.image) else { continue };
+        let Some(&index) = packed.indices.get(&path.to_string()) else { continue };
+
+        sprite.image = packed.image.clone();
+        sprite.texture_atlas = Some(TextureAtlas {
+            layout: packed.layout.clone(),
+            index,
+        });
+    }
+
+    if atlas.just_packed {
+        atlas.just_packed = false;
+    }
+}
@@ -0,0 +1,260 @@
+// Profiling Scene - Steps the sanctuary through 100, 500 and 1000 birds in a storm and reports stage costs
+//
+// The crate is a single binary, so stand-alone criterion benches cannot reach the ECS systems.
+// Instead `benchmark` in the debug console runs this scene inside the live game, where the
+// behavior tree, flocking and particle systems run with their real schedules. Results are
+// written next to the previous run and compared, so a regression shows up as a percentage.
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs;
+use std::path::PathBuf;
+use crate::bird::Bird;
+use crate::despawn::SafeDespawn;
+use crate::environment::components::Weather;
+use crate::environment::resources::{WeatherChangeEvent, WeatherState};
+use crate::notifications::{components::NotificationType, resources::ShowNotificationEvent};
+use crate::performance::resources::StageTimings;
+use crate::resources::SpawnBirdEvent;
+use crate::weather_effects::components::{EnvironmentalParticle, RainParticle, SnowParticle};
+
+const BIRD_TIERS: [usize; 3] = [100, 500, 1000];
+// Real seconds each tier gets for spawned birds to settle into their behaviors
+const WARMUP_SECONDS: f32 = 2.0;
+const MEASURE_SECONDS: f32 = 5.0;
+// Cold rain keeps the particle systems saturated and raises a storm alert
+const STORM_WEATHER: Weather = Weather::Rainy;
+const STORM_TEMPERATURE: f32 = 2.0;
+
+#[derive(Event)]
+pub enum BenchmarkEvent {
+    Start,
+    Stop,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct BenchmarkResult {
+    pub birds: usize,
+    pub frame_ms: f32,
+    pub frame_p95_ms: f32,
+    pub ai_ms: f32,
+    pub presentation_ms: f32,
+    pub peak_particles: usize,
+}
+
+#[derive(Default)]
+struct TierSamples {
+    frame_ms: Vec<f32>,
+    ai_ms: Vec<f32>,
+    presentation_ms: Vec<f32>,
+    peak_particles: usize,
+}
+
+#[derive(Resource, Default)]
+pub struct BenchmarkRun {
+    pub active: bool,
+    tier: usize,
+    warming_up: bool,
+    elapsed: f32,
+    samples: TierSamples,
+    pub results: Vec<BenchmarkResult>,
+    // Birds that were in the sanctuary before the run; everything else is removed afterwards
+    resident_birds: HashSet<Entity>,
+    previous_weather: Option<(Weather, f32)>,
+}
+
+impl BenchmarkRun {
+    fn start_tier(&mut self, tier: usize) {
+        self.tier = tier;
+        self.warming_up = true;
+        self.elapsed = 0.0;
+        self.samples = TierSamples::default();
+    }
+}
+
+fn benchmark_report_path() -> PathBuf {
+    dirs::data_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("AvianHaven")
+        .join("benchmark.ron")
+}
+
+pub fn benchmark_control_system(
+    mut commands: Commands,
+    mut events: EventReader<BenchmarkEvent>,
+    mut run: ResMut<BenchmarkRun>,
+    mut weather: ResMut<WeatherState>,
+    mut weather_events: EventWriter<WeatherChangeEvent>,
+    mut spawn_events: EventWriter<SpawnBirdEvent>,
+    mut notifications: EventWriter<ShowNotificationEvent>,
+    bird_query: Query<Entity, With<Bird>>,
+) {
+    for event in events.read() {
+        match event {
+            BenchmarkEvent::Start if !run.active => {
+                info!("⏱️ Benchmark: starting {:?} bird tiers", BIRD_TIERS);
+                run.active = true;
+                run.results.clear();
+                run.resident_birds = bird_query.iter().collect();
+                run.previous_weather = Some((weather.current_weather, weather.temperature));
+
+                weather.current_weather = STORM_WEATHER;
+                weather.temperature = STORM_TEMPERATURE;
+                weather_events.write(WeatherChangeEvent {
+                    new_weather: STORM_WEATHER,
+                    temperature: STORM_TEMPERATURE,
+                });
+
+                run.start_tier(0);
+                top_up_birds(BIRD_TIERS[0], bird_query.iter().count(), &mut spawn_events);
+            }
+            BenchmarkEvent::Start => {}
+            BenchmarkEvent::Stop => {
+                if run.active {
+                    info!("⏱️ Benchmark: stopped after {} of {} tiers", run.results.len(), BIRD_TIERS.len());
+                    finish_benchmark(&mut commands, &mut run, &mut weather, &mut weather_events, &mut notifications, &bird_query);
+                }
+            }
+        }
+    }
+}
+
+pub fn benchmark_sample_system(
+    mut commands: Commands,
+    mut run: ResMut<BenchmarkRun>,
+    mut weather: ResMut<WeatherState>,
+    mut weather_events: EventWriter<WeatherChangeEvent>,
+    mut spawn_events: EventWriter<SpawnBirdEvent>,
+    mut notifications: EventWriter<ShowNotificationEvent>,
+    timings: Res<StageTimings>,
+    time: Res<Time<Real>>,
+    bird_query: Query<Entity, With<Bird>>,
+    particle_query: Query<(), Or<(With<RainParticle>, With<SnowParticle>, With<EnvironmentalParticle>)>>,
+) {
+    if !run.active {
+        return;
+    }
+
+    run.elapsed += time.delta_secs();
+    if run.warming_up {
+        if run.elapsed >= WARMUP_SECONDS {
+            run.warming_up = false;
+            run.elapsed = 0.0;
+        }
+        return;
+    }
+
+    run.samples.frame_ms.push(time.delta_secs() * 1000.0);
+    run.samples.ai_ms.push(timings.ai_ms);
+    run.samples.presentation_ms.push(timings.presentation_ms);
+    run.samples.peak_particles = run.samples.peak_particles.max(particle_query.iter().count());
+
+    if run.elapsed < MEASURE_SECONDS {
+        return;
+    }
+
+    let result = summarize_tier(bird_query.iter().count(), &run.samples);
+    info!("⏱️ Benchmark: {} birds - frame {:.2} ms (p95 {:.2}), AI {:.2} ms, presentation {:.2} ms",
+          result.birds, result.frame_ms, result.frame_p95_ms, result.ai_ms, result.presentation_ms);
+    run.results.push(result);
+
+    let next_tier = run.tier + 1;
+    if next_tier < BIRD_TIERS.len() {
+        run.start_tier(next_tier);
+        top_up_birds(BIRD_TIERS[next_tier], bird_query.iter().count(), &mut spawn_events);
+    } else {
+        report_results(&run.results);
+        finish_benchmark(&mut commands, &mut run, &mut weather, &mut weather_events, &mut notifications, &bird_query);
+    }
+}
+
+fn top_up_birds(target: usize, current: usize, spawn_events: &mut EventWriter<SpawnBirdEvent>) {
+    for _ in current..target {
+        spawn_events.write(SpawnBirdEvent);
+    }
+}
+
+fn summarize_tier(birds: usize, samples: &TierSamples) -> BenchmarkResult {
+    let mean = |values: &[f32]| values.iter().sum::<f32>() / values.len().max(1) as f32;
+
+    let mut sorted = samples.frame_ms.clone();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    let p95_index = (sorted.len() as f32 * 0.95) as usize;
+    let frame_p95_ms = sorted.get(p95_index.min(sorted.len().saturating_sub(1))).copied().unwrap_or(0.0);
+
+    BenchmarkResult {
+        birds,
+        frame_ms: mean(&samples.frame_ms),
+        frame_p95_ms,
+        ai_ms: mean(&samples.ai_ms),
+        presentation_ms: mean(&samples.presentation_ms),
+        peak_particles: samples.peak_particles,
+    }
+}
+
+fn report_results(results: &[BenchmarkResult]) {
+    let path = benchmark_report_path();
+    let previous: Vec<BenchmarkResult> = fs::read_to_string(&path).ok()
+        .and_then(|content| ron::from_str(&content).ok())
+        .unwrap_or_default();
+
+    info!("⏱️ Benchmark results (vs previous run):");
+    info!("   {:>6} | {:>15} | {:>9} | {:>15} | {:>15} | {:>9}", "birds", "frame ms", "p95 ms", "AI ms", "present ms", "particles");
+    for result in results {
+        let before = previous.iter().find(|entry| entry.birds == result.birds);
+        let delta = |now: f32, then: Option<f32>| match then {
+            Some(then) if then > 0.0 => format!("{:.2} ({:+.0}%)", now, (now - then) / then * 100.0),
+            _ => format!("{:.2}", now),
+        };
+        info!("   {:>6} | {:>15} | {:>9.2} | {:>15} | {:>15} | {:>9}",
+              result.birds,
+              delta(result.frame_ms, before.map(|entry| entry.frame_ms)),
+              result.frame_p95_ms,
+              delta(result.ai_ms, before.map(|entry| entry.ai_ms)),
+              delta(result.presentation_ms, before.map(|entry| entry.presentation_ms)),
+              result.peak_particles);
+    }
+
+    let saved = path.parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .map_err(|e| e.to_string())
+        .and_then(|_| ron::to_string(results).map_err(|e| e.to_string()))
+        .and_then(|content| fs::write(&path, content).map_err(|e| e.to_string()));
+    match saved {
+        Ok(()) => info!("⏱️ Benchmark: results saved to {:?}", path),
+        Err(e) => warn!("Benchmark: could not save results: {}", e),
+    }
+}
+
+fn finish_benchmark(
+    commands: &mut Commands,
+    run: &mut BenchmarkRun,
+    weather: &mut WeatherState,
+    weather_events: &mut EventWriter<WeatherChangeEvent>,
+    notifications: &mut EventWriter<ShowNotificationEvent>,
+    bird_query: &Query<Entity, With<Bird>>,
+) {
+    for entity in bird_query {
+        if !run.resident_birds.contains(&entity) {
+            commands.entity(entity).safe_despawn();
+        }
+    }
+
+    if let Some((previous_weather, temperature)) = run.previous_weather.take() {
+        weather.current_weather = previous_weather;
+        weather.temperature = temperature;
+        weather_events.write(WeatherChangeEvent {
+            new_weather: previous_weather,
+            temperature,
+        });
+    }
+
+    notifications.write(ShowNotificationEvent {
+        notification: NotificationType::Info {
+            message: format!("Benchmark finished: {} of {} tiers measured, see log", run.results.len(), BIRD_TIERS.len()),
+        },
+    });
+
+    run.active = false;
+    run.resident_birds.clear();
+}
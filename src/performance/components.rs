@@ -8,6 +8,10 @@ pub struct FpsDisplay;
 #[derive(Component)]
 pub struct PerformanceDisplay;
 
+/// Marks the HUD badge shown while the frame budget governor has lowered quality
+#[derive(Component)]
+pub struct QualityBadge;
+
 /// Component for UI elements that display profiling data
 #[derive(Component)]
 pub struct ProfilingDisplay;
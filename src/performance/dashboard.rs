@@ -0,0 +1,291 @@
+// Entity Dashboard - Developer panel counting what the world is made of and where frame time goes
+//
+// Dev builds only. The `entities` console command opens a panel that once a second counts entities
+// by the markers that matter - birds, weather particles, effect emitters, smart objects, sprites and
+// UI nodes - and tracks how the entity and archetype totals moved over the last ten seconds, since
+// steady archetype growth means components are being inserted and removed every frame. UI roots are
+// grouped by name with how many more there are than when the panel opened, and UI nodes parented to
+// something that isn't UI are called out, which is how leaked screens usually show up. The stage
+// timings from profiling are listed against the frame time from the frame time diagnostics.
+use bevy::prelude::*;
+use bevy::diagnostic::{DiagnosticsStore, FrameTimeDiagnosticsPlugin};
+use bevy::ecs::archetype::Archetypes;
+use bevy_hanabi::prelude::ParticleEffect;
+use std::collections::{BTreeMap, VecDeque};
+use crate::bird::Bird;
+use crate::bird_ai::components::SmartObject;
+use crate::performance::resources::ProfilingData;
+use crate::weather_effects::components::{EnvironmentalParticle, InteractiveParticle, RainParticle, SnowParticle};
+
+// One sample a second; churn is measured across the whole window
+const CHURN_WINDOW: usize = 10;
+const SHOWN_UI_ROOTS: usize = 10;
+const PANEL_WIDTH: f32 = 460.0;
+
+// Resources
+#[derive(Resource)]
+pub struct EntityDashboard {
+    pub is_open: bool,
+    // Entity and archetype totals, oldest first
+    samples: VecDeque<(usize, usize)>,
+    // UI root counts by name when the panel was opened
+    baseline_roots: Option<BTreeMap<String, usize>>,
+    refresh_timer: Timer,
+    panel_dirty: bool,
+}
+
+impl Default for EntityDashboard {
+    fn default() -> Self {
+        Self {
+            is_open: false,
+            samples: VecDeque::with_capacity(CHURN_WINDOW + 1),
+            baseline_roots: None,
+            refresh_timer: Timer::from_seconds(1.0, TimerMode::Repeating),
+            panel_dirty: false,
+        }
+    }
+}
+
+impl EntityDashboard {
+    pub fn toggle(&mut self) {
+        self.is_open = !self.is_open;
+        self.samples.clear();
+        self.baseline_roots = None;
+        self.panel_dirty = true;
+    }
+}
+
+// Components
+#[derive(Component)]
+pub struct EntityDashboardRoot;
+
+#[derive(Component)]
+pub struct EntityDashboardCloseButton;
+
+type MarkerQuery<'w, 's> = Query<'w, 's, (Has<Bird>, Has<SmartObject>, Has<ParticleEffect>, Has<Sprite>, Has<Node>, Has<Camera>)>;
+type WeatherParticleQuery<'w, 's> = Query<'w, 's, (), Or<(With<RainParticle>, With<SnowParticle>, With<EnvironmentalParticle>, With<InteractiveParticle>)>>;
+
+struct EntityCensus {
+    markers: Vec<(&'static str, usize)>,
+    total: usize,
+    archetypes: usize,
+    ui_roots: BTreeMap<String, usize>,
+    // UI nodes whose parent isn't a UI node, by their own name
+    misparented_ui: Vec<String>,
+}
+
+pub fn entity_dashboard_button_system(
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<EntityDashboardCloseButton>)>,
+    mut dashboard: ResMut<EntityDashboard>,
+) {
+    if interaction_query.iter().any(|interaction| *interaction == Interaction::Pressed) {
+        dashboard.toggle();
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn refresh_entity_dashboard_system(
+    mut commands: Commands,
+    mut dashboard: ResMut<EntityDashboard>,
+    panel_query: Query<Entity, With<EntityDashboardRoot>>,
+    marker_query: MarkerQuery,
+    particle_query: WeatherParticleQuery,
+    ui_query: Query<(Option<&ChildOf>, Option<&Name>), With<Node>>,
+    archetypes: &Archetypes,
+    profiling_data: Res<ProfilingData>,
+    diagnostics: Res<DiagnosticsStore>,
+    time: Res<Time>,
+) {
+    dashboard.refresh_timer.tick(time.delta());
+    let timer_fired = dashboard.is_open && dashboard.refresh_timer.just_finished();
+    if !dashboard.panel_dirty && !timer_fired {
+        return;
+    }
+    dashboard.panel_dirty = false;
+
+    for root in &panel_query {
+        commands.entity(root).despawn();
+    }
+    if !dashboard.is_open {
+        return;
+    }
+
+    let census = take_census(&marker_query, &particle_query, &ui_query, archetypes);
+    dashboard.samples.push_back((census.total, census.archetypes));
+    if dashboard.samples.len() > CHURN_WINDOW + 1 {
+        dashboard.samples.pop_front();
+    }
+    if dashboard.baseline_roots.is_none() {
+        dashboard.baseline_roots = Some(census.ui_roots.clone());
+    }
+
+    let frame_ms = diagnostics.get(&FrameTimeDiagnosticsPlugin::FRAME_TIME)
+        .and_then(|frame_time| frame_time.smoothed())
+        .unwrap_or(0.0) as f32;
+    let mut stages: Vec<(String, f32)> = profiling_data.system_times.iter()
+        .map(|(name, elapsed)| (name.clone(), elapsed.as_secs_f32() * 1000.0))
+        .collect();
+    stages.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+    spawn_entity_dashboard(&mut commands, &dashboard, &census, frame_ms, &stages);
+}
+
+fn take_census(
+    marker_query: &MarkerQuery,
+    particle_query: &WeatherParticleQuery,
+    ui_query: &Query<(Option<&ChildOf>, Option<&Name>), With<Node>>,
+    archetypes: &Archetypes,
+) -> EntityCensus {
+    let mut counts = [0usize; 6];
+    let mut total = 0;
+    for markers in marker_query.iter() {
+        total += 1;
+        let (bird, smart_object, emitter, sprite, node, camera) = markers;
+        for (count, present) in counts.iter_mut().zip([bird, smart_object, emitter, sprite, node, camera]) {
+            *count += present as usize;
+        }
+    }
+
+    let mut ui_roots = BTreeMap::new();
+    let mut misparented_ui = Vec::new();
+    for (child_of, name) in ui_query.iter() {
+        let label = name.map_or("unnamed".to_string(), |name| name.to_string());
+        match child_of {
+            None => *ui_roots.entry(label).or_insert(0) += 1,
+            Some(child_of) if !ui_query.contains(child_of.parent()) => misparented_ui.push(label),
+            Some(_) => {}
+        }
+    }
+
+    EntityCensus {
+        markers: vec![
+            ("Birds", counts[0]),
+            ("Weather particles", particle_query.iter().count()),
+            ("Effect emitters", counts[2]),
+            ("Smart objects", counts[1]),
+            ("Sprites", counts[3]),
+            ("UI nodes", counts[4]),
+            ("Cameras", counts[5]),
+        ],
+        total,
+        archetypes: archetypes.len(),
+        ui_roots,
+        misparented_ui,
+    }
+}
+
+fn signed(change: i64) -> String {
+    if change > 0 { format!("+{}", change) } else { change.to_string() }
+}
+
+fn spawn_dashboard_line(parent: &mut ChildSpawnerCommands, text: String, size: f32, color: Color) {
+    parent.spawn((
+        Text::new(text),
+        TextFont { font_size: size, ..default() },
+        TextColor(color),
+    ));
+}
+
+fn spawn_entity_dashboard(
+    commands: &mut Commands,
+    dashboard: &EntityDashboard,
+    census: &EntityCensus,
+    frame_ms: f32,
+    stages: &[(String, f32)],
+) {
+    let text_color = Color::srgb(0.2, 0.1, 0.05);
+    let heading_color = Color::srgb(0.3, 0.2, 0.1);
+    let muted = Color::srgb(0.5, 0.4, 0.3);
+    let alert = Color::srgb(0.75, 0.15, 0.1);
+
+    let (oldest_entities, oldest_archetypes) = dashboard.samples.front().copied().unwrap_or((census.total, census.archetypes));
+    let window = dashboard.samples.len().saturating_sub(1);
+
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            right: Val::Px(10.0),
+            top: Val::Px(60.0),
+            width: Val::Px(PANEL_WIDTH),
+            max_height: Val::Percent(85.0),
+            flex_direction: FlexDirection::Column,
+            padding: UiRect::all(Val::Px(10.0)),
+            row_gap: Val::Px(3.0),
+            overflow: Overflow::clip(),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.95, 0.92, 0.88, 0.95)),
+        BorderRadius::all(Val::Px(6.0)),
+        GlobalZIndex(40),
+        EntityDashboardRoot,
+        Name::new("EntityDashboard"),
+    )).with_children(|panel| {
+        panel.spawn(Node {
+            width: Val::Percent(100.0),
+            justify_content: JustifyContent::SpaceBetween,
+            align_items: AlignItems::Center,
+            ..default()
+        }).with_children(|header| {
+            spawn_dashboard_line(header, format!("Entities  ({} in {} archetypes)", census.total, census.archetypes), 16.0, heading_color);
+            header.spawn((
+                Button,
+                Node {
+                    padding: UiRect::axes(Val::Px(6.0), Val::Px(2.0)),
+                    ..default()
+                },
+                BackgroundColor(Color::srgb(0.6, 0.5, 0.4)),
+                BorderRadius::all(Val::Px(4.0)),
+                EntityDashboardCloseButton,
+            )).with_children(|button| {
+                spawn_dashboard_line(button, "Close".to_string(), 10.0, Color::WHITE);
+            });
+        });
+
+        // Churn: archetypes should settle once the yard is loaded
+        let archetype_growth = census.archetypes as i64 - oldest_archetypes as i64;
+        spawn_dashboard_line(panel, format!(
+            "Last {} s: entities {}, archetypes {}",
+            window, signed(census.total as i64 - oldest_entities as i64), signed(archetype_growth),
+        ), 11.0, if archetype_growth > 0 { alert } else { text_color });
+
+        for (label, count) in &census.markers {
+            spawn_dashboard_line(panel, format!("{:<18} {}", label, count), 11.0, text_color);
+        }
+
+        spawn_dashboard_line(panel, "UI roots (change since opened)".to_string(), 12.0, heading_color);
+        let baseline = dashboard.baseline_roots.as_ref();
+        let mut roots: Vec<(&String, &usize, i64)> = census.ui_roots.iter()
+            .map(|(label, count)| {
+                let before = baseline.and_then(|roots| roots.get(label)).copied().unwrap_or(0);
+                (label, count, *count as i64 - before as i64)
+            })
+            .collect();
+        roots.sort_by(|a, b| b.2.cmp(&a.2).then(b.1.cmp(a.1)));
+        for (label, count, change) in roots.iter().take(SHOWN_UI_ROOTS) {
+            let color = if *change > 0 { alert } else { text_color };
+            spawn_dashboard_line(panel, format!("  {} x{} ({})", label, count, signed(*change)), 11.0, color);
+        }
+        if roots.len() > SHOWN_UI_ROOTS {
+            spawn_dashboard_line(panel, format!("  and {} more", roots.len() - SHOWN_UI_ROOTS), 11.0, muted);
+        }
+
+        if !census.misparented_ui.is_empty() {
+            let mut names = census.misparented_ui.clone();
+            names.sort();
+            names.dedup();
+            spawn_dashboard_line(panel, format!(
+                "{} UI nodes under non-UI parents: {}",
+                census.misparented_ui.len(), names.join(", "),
+            ), 11.0, alert);
+        }
+
+        spawn_dashboard_line(panel, format!("Frame time {:.2} ms", frame_ms), 12.0, heading_color);
+        if stages.is_empty() {
+            spawn_dashboard_line(panel, "No stage timings yet".to_string(), 11.0, muted);
+        }
+        for (name, ms) in stages {
+            let share = if frame_ms > 0.0 { ms / frame_ms * 100.0 } else { 0.0 };
+            spawn_dashboard_line(panel, format!("  {:<20} {:.2} ms ({:.0}%)", name, ms, share), 11.0, text_color);
+        }
+    });
+}
@@ -0,0 +1,147 @@
+// Frame Budget Governor - Trades particles, AI tick rate and bird population for frame time
+//
+// Each quality level is a step down from full detail. The governor drops one level after the
+// frame budget has been blown for a sustained stretch and climbs back one level at a time once
+// frames come in well under budget again, so a single hitch never changes anything.
+use bevy::prelude::*;
+use std::time::Duration;
+use crate::bird_ai::resources::BehaviorTreeTimer;
+use crate::performance::benchmark::BenchmarkRun;
+use crate::performance::components::QualityBadge;
+use crate::performance::resources::PerformanceSettings;
+
+// Consecutive over-budget frames before quality drops a level
+const DEGRADE_AFTER_FRAMES: u32 = 90;
+// Consecutive frames with headroom before quality climbs back a level
+const RESTORE_AFTER_FRAMES: u32 = 300;
+// Frames slower than budget * this count as over budget
+const OVER_BUDGET_FACTOR: f32 = 1.1;
+// Frames faster than budget * this count as headroom
+const HEADROOM_FACTOR: f32 = 0.8;
+
+pub struct QualityLevel {
+    pub particle_scale: f32,
+    pub ai_interval_scale: f32,
+    pub max_birds: usize,
+}
+
+pub const QUALITY_LEVELS: [QualityLevel; 4] = [
+    QualityLevel { particle_scale: 1.0, ai_interval_scale: 1.0, max_birds: 15 },
+    QualityLevel { particle_scale: 0.6, ai_interval_scale: 1.5, max_birds: 12 },
+    QualityLevel { particle_scale: 0.35, ai_interval_scale: 2.0, max_birds: 9 },
+    QualityLevel { particle_scale: 0.15, ai_interval_scale: 3.0, max_birds: 6 },
+];
+
+#[derive(Resource, Default)]
+pub struct FrameBudgetGovernor {
+    pub level: usize,
+    over_budget_frames: u32,
+    headroom_frames: u32,
+    // Behavior tree interval before the governor first stretched it
+    base_ai_interval: Option<Duration>,
+}
+
+impl FrameBudgetGovernor {
+    pub fn quality(&self) -> &'static QualityLevel {
+        &QUALITY_LEVELS[self.level]
+    }
+
+    pub fn is_degraded(&self) -> bool {
+        self.level > 0
+    }
+}
+
+pub fn frame_budget_governor_system(
+    mut governor: ResMut<FrameBudgetGovernor>,
+    mut behavior_timer: ResMut<BehaviorTreeTimer>,
+    settings: Res<PerformanceSettings>,
+    benchmark: Res<BenchmarkRun>,
+    time: Res<Time<Real>>,
+) {
+    // The benchmark measures full-quality cost, so the governor stands aside while it runs
+    let target_level = if benchmark.active {
+        0
+    } else {
+        let frame_ms = time.delta_secs() * 1000.0;
+        let budget_ms = 1000.0 / settings.target_fps;
+        next_level(&mut governor, frame_ms, budget_ms)
+    };
+
+    if target_level == governor.level {
+        return;
+    }
+
+    if target_level > governor.level {
+        warn!("Frame budget governor: frames over budget, dropping to quality level {}", target_level);
+    } else {
+        info!("Frame budget governor: headroom returned, restoring quality level {}", target_level);
+    }
+    governor.level = target_level;
+    governor.over_budget_frames = 0;
+    governor.headroom_frames = 0;
+
+    let base_interval = *governor.base_ai_interval.get_or_insert(behavior_timer.0.duration());
+    behavior_timer.0.set_duration(base_interval.mul_f32(governor.quality().ai_interval_scale));
+}
+
+fn next_level(governor: &mut FrameBudgetGovernor, frame_ms: f32, budget_ms: f32) -> usize {
+    if frame_ms > budget_ms * OVER_BUDGET_FACTOR {
+        governor.over_budget_frames += 1;
+        governor.headroom_frames = 0;
+    } else if frame_ms < budget_ms * HEADROOM_FACTOR {
+        governor.headroom_frames += 1;
+        governor.over_budget_frames = 0;
+    } else {
+        governor.over_budget_frames = 0;
+        governor.headroom_frames = 0;
+    }
+
+    if governor.over_budget_frames >= DEGRADE_AFTER_FRAMES {
+        (governor.level + 1).min(QUALITY_LEVELS.len() - 1)
+    } else if governor.headroom_frames >= RESTORE_AFTER_FRAMES {
+        governor.level.saturating_sub(1)
+    } else {
+        governor.level
+    }
+}
+
+/// Shows a corner badge while the governor has quality turned down
+pub fn quality_badge_system(
+    mut commands: Commands,
+    governor: Res<FrameBudgetGovernor>,
+    mut badge_query: Query<(Entity, &mut Text), With<QualityBadge>>,
+) {
+    if !governor.is_degraded() {
+        for (entity, _) in &badge_query {
+            commands.entity(entity).despawn();
+        }
+        return;
+    }
+
+    let label = format!("Reduced quality {}/{}", governor.level, QUALITY_LEVELS.len() - 1);
+    if let Ok((_, mut text)) = badge_query.single_mut() {
+        if text.0 != label {
+            text.0 = label;
+        }
+        return;
+    }
+
+    commands.spawn((
+        Text::new(label),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(10.0),
+            right: Val::Px(10.0),
+            padding: UiRect::axes(Val::Px(8.0), Val::Px(4.0)),
+            ..default()
+        },
+        QualityBadge,
+        TextColor(Color::srgb(1.0, 0.85, 0.4)),
+        TextFont {
+            font_size: 12.0,
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.6)),
+        StateScoped(crate::AppState::Playing),
+    ));
+}
@@ -4,11 +4,17 @@ pub mod components;
 pub mod resources;
 pub mod systems;
 pub mod profiling;
+pub mod benchmark;
+pub mod governor;
+pub mod dashboard;
 
 // use components::*;
 use resources::*;
 use systems::*;
 use profiling::*;
+use benchmark::*;
+use governor::*;
+use dashboard::*;
 
 pub struct PerformancePlugin;
 
@@ -19,17 +25,46 @@ impl Plugin for PerformancePlugin {
             .init_resource::<PerformanceMetrics>()
             .init_resource::<ProfilingData>()
             .init_resource::<PerformanceSettings>()
+            .init_resource::<StageTimings>()
+            .init_resource::<MemoryTracker>()
+            .init_resource::<BenchmarkRun>()
+            .init_resource::<FrameBudgetGovernor>()
+            .add_event::<BenchmarkEvent>()
             .add_systems(Update, (
                 // Existing performance systems
                 bird_culling_system,
                 performance_monitoring_system,
                 memory_optimization_system,
+                track_context_release_system.before(memory_optimization_system),
                 // New profiling and monitoring systems
                 fps_counter_system,
                 performance_metrics_system,
                 profiling_system,
                 fps_display_system,
                 performance_display_system,
-            ));
+            ).in_set(crate::GameSet::Presentation))
+            // Stage markers sit on the GameSet boundaries so profiling sees the whole AI and Presentation cost
+            .add_systems(Update, (
+                begin_ai_stage_system.after(crate::GameSet::Simulation).before(crate::GameSet::AI),
+                end_ai_stage_system.after(crate::GameSet::AI).before(crate::GameSet::Presentation),
+                end_presentation_stage_system.after(crate::GameSet::Presentation).before(crate::GameSet::UI),
+            ))
+            .add_systems(Update, (
+                benchmark_control_system,
+                benchmark_sample_system,
+            ).chain().in_set(crate::GameSet::Simulation).run_if(in_state(crate::AppState::Playing)))
+            .add_systems(Update, (
+                entity_dashboard_button_system,
+                refresh_entity_dashboard_system,
+            ).chain().run_if(resource_exists::<EntityDashboard>).in_set(crate::GameSet::UI))
+            .add_systems(Update, (
+                frame_budget_governor_system.in_set(crate::GameSet::Simulation),
+                quality_badge_system.in_set(crate::GameSet::UI),
+            ).run_if(in_state(crate::AppState::Playing)));
+
+        // The console's `entities` command reports the dashboard missing in release builds
+        if cfg!(debug_assertions) {
+            app.init_resource::<EntityDashboard>();
+        }
     }
 }
\ No newline at end of file
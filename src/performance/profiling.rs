@@ -2,6 +2,44 @@ use bevy::prelude::*;
 use std::time::Instant;
 use crate::performance::resources::*;
 
+/// Opens the AI stage timer once every Simulation system has finished
+pub fn begin_ai_stage_system(mut timings: ResMut<StageTimings>) {
+    timings.stage_started = Some(Instant::now());
+}
+
+/// Closes the AI stage timer and opens the Presentation one
+pub fn end_ai_stage_system(
+    mut timings: ResMut<StageTimings>,
+    mut profiling_data: ResMut<ProfilingData>,
+    mut performance_metrics: ResMut<PerformanceMetrics>,
+) {
+    let now = Instant::now();
+    if let Some(started) = timings.stage_started {
+        let elapsed = now.duration_since(started);
+        timings.ai_ms = elapsed.as_secs_f32() * 1000.0;
+        performance_metrics.ai_system_time_ms = timings.ai_ms;
+        record_stage(&mut profiling_data, "AI stage", elapsed);
+    }
+    timings.stage_started = Some(now);
+}
+
+/// Closes the Presentation stage timer before the UI runs
+pub fn end_presentation_stage_system(
+    mut timings: ResMut<StageTimings>,
+    mut profiling_data: ResMut<ProfilingData>,
+) {
+    if let Some(started) = timings.stage_started.take() {
+        let elapsed = started.elapsed();
+        timings.presentation_ms = elapsed.as_secs_f32() * 1000.0;
+        record_stage(&mut profiling_data, "Presentation stage", elapsed);
+    }
+}
+
+fn record_stage(profiling_data: &mut ProfilingData, name: &str, elapsed: std::time::Duration) {
+    profiling_data.system_times.insert(name.to_string(), elapsed);
+    *profiling_data.system_call_counts.entry(name.to_string()).or_insert(0) += 1;
+}
+
 /// System for comprehensive performance profiling
 pub fn profiling_system(
     mut profiling_data: ResMut<ProfilingData>,
@@ -30,6 +68,7 @@ pub fn profiling_system(
     check_fps_bottlenecks(&mut profiling_data, &performance_metrics, &settings);
     check_memory_bottlenecks(&mut profiling_data, &performance_metrics, &settings);
     check_entity_count_bottlenecks(&mut profiling_data, &performance_metrics, &settings);
+    check_stage_bottlenecks(&mut profiling_data, &performance_metrics, &settings);
     
     // Update performance warnings
     performance_metrics.warnings.clear();
@@ -120,4 +159,34 @@ fn check_entity_count_bottlenecks(
             detected_at: Instant::now(),
         });
     }
-}
\ No newline at end of file
+}
+
+fn check_stage_bottlenecks(
+    profiling_data: &mut ResMut<ProfilingData>,
+    metrics: &PerformanceMetrics,
+    settings: &PerformanceSettings,
+) {
+    // The AI stage may use at most 40% of the frame budget before it is flagged
+    let frame_budget_ms = 1000.0 / settings.target_fps;
+    let ai_budget_ms = frame_budget_ms * 0.4;
+    if metrics.ai_system_time_ms <= ai_budget_ms {
+        return;
+    }
+
+    let severity = if metrics.ai_system_time_ms > frame_budget_ms {
+        BottleneckSeverity::Critical
+    } else if metrics.ai_system_time_ms > frame_budget_ms * 0.7 {
+        BottleneckSeverity::High
+    } else {
+        BottleneckSeverity::Medium
+    };
+
+    profiling_data.bottlenecks.push(PerformanceBottleneck {
+        system_name: "AI Stage".to_string(),
+        issue_type: BottleneckType::SlowSystem,
+        severity,
+        description: format!("AI took {:.2} ms (budget: {:.2} ms) for {} birds", metrics.ai_system_time_ms, ai_budget_ms, metrics.ai_bird_count),
+        suggested_fix: "Run the benchmark console command to compare behavior tree and flocking cost per bird count".to_string(),
+        detected_at: Instant::now(),
+    });
+}
@@ -46,6 +46,11 @@ pub struct PerformanceMetrics {
     pub estimated_memory_mb: f32,
     pub component_memory_mb: f32,
     pub resource_memory_mb: f32,
+    pub texture_memory_mb: f32,
+    pub audio_memory_mb: f32,
+    pub image_count: usize,
+    pub mesh_count: usize,
+    pub audio_count: usize,
     
     // Performance warnings
     pub warnings: Vec<String>,
@@ -71,6 +76,11 @@ impl Default for PerformanceMetrics {
             estimated_memory_mb: 0.0,
             component_memory_mb: 0.0,
             resource_memory_mb: 0.0,
+            texture_memory_mb: 0.0,
+            audio_memory_mb: 0.0,
+            image_count: 0,
+            mesh_count: 0,
+            audio_count: 0,
             warnings: Vec::new(),
             fps_history: VecDeque::with_capacity(300), // 5 minutes at 60fps
             entity_count_history: VecDeque::with_capacity(300),
@@ -101,6 +111,31 @@ impl Default for ProfilingData {
     }
 }
 
+/// Asset memory sampling, including the before/after comparison around context changes
+#[derive(Resource)]
+pub struct MemoryTracker {
+    pub sample_timer: Timer,
+    // Context that was just left and the asset memory measured before leaving it
+    pub pending_release: Option<(String, f32)>,
+}
+
+impl Default for MemoryTracker {
+    fn default() -> Self {
+        Self {
+            sample_timer: Timer::from_seconds(5.0, TimerMode::Repeating),
+            pending_release: None,
+        }
+    }
+}
+
+/// Wall-clock cost of the AI and Presentation stages, measured between GameSet boundaries
+#[derive(Resource, Default)]
+pub struct StageTimings {
+    pub stage_started: Option<Instant>,
+    pub ai_ms: f32,
+    pub presentation_ms: f32,
+}
+
 /// Represents a performance bottleneck or optimization opportunity
 #[derive(Debug, Clone)]
 pub struct PerformanceBottleneck {
@@ -13,14 +13,21 @@ pub fn bird_culling_system(
     camera_query: Query<&Transform, (With<Camera2d>, Without<Bird>)>,
     bird_query: Query<(Entity, &Transform), With<Bird>>,
     bird_count: ResMut<crate::resources::BirdCount>,
+    benchmark: Res<crate::performance::benchmark::BenchmarkRun>,
+    governor: Res<crate::performance::governor::FrameBudgetGovernor>,
 ) {
+    // The benchmark needs every bird it spawns to stay alive
+    if benchmark.active {
+        return;
+    }
+
     let Ok(camera_transform) = camera_query.single() else {
         return;
     };
     
     let camera_pos = camera_transform.translation.truncate();
     let cull_distance = 800.0; // Birds beyond this distance get culled
-    let max_birds = 15;
+    let max_birds = governor.quality().max_birds;
     
     let mut birds_to_cull = Vec::new();
     let mut bird_distances: Vec<(Entity, f32)> = Vec::new();
@@ -75,79 +82,91 @@ pub fn performance_monitoring_system(
     }
 }
 
+/// Measures texture and audio asset memory and reports what leaving a context released
 pub fn memory_optimization_system(
-    mut images: ResMut<Assets<Image>>,
-    mut meshes: ResMut<Assets<Mesh>>,
-    time: Res<Time>,
+    images: Res<Assets<Image>>,
+    meshes: Res<Assets<Mesh>>,
     audio_assets: Res<Assets<AudioSource>>,
+    mut tracker: ResMut<MemoryTracker>,
+    mut metrics: ResMut<PerformanceMetrics>,
+    time: Res<Time<Real>>,
 ) {
-    // Periodic cleanup of unused assets
-    static mut CLEANUP_TIMER: f32 = 0.0;
-    static mut LAST_IMAGE_COUNT: usize = 0;
-    static mut LAST_MESH_COUNT: usize = 0;
-    
-    unsafe {
-        CLEANUP_TIMER += time.delta().as_secs_f32();
-        if CLEANUP_TIMER > 60.0 { // Cleanup every minute
-            let initial_image_count = images.len();
-            let initial_mesh_count = meshes.len();
-            let audio_count = audio_assets.len();
-            
-            // Remove unused images (those with no strong references)
-            let all_image_ids: Vec<_> = images.ids().collect();
-            let unused_images: Vec<_> = all_image_ids.iter().filter(|&&id| {
-                if let Some(handle) = images.get_strong_handle(id) {
-                    handle.is_weak() // Only remove if no strong references exist
-                } else {
-                    true
-                }
-            }).copied().collect();
-            
-            for id in unused_images {
-                images.remove(id);
-            }
-            
-            // Remove unused meshes
-            let all_mesh_ids: Vec<_> = meshes.ids().collect();
-            let unused_meshes: Vec<_> = all_mesh_ids.iter().filter(|&&id| {
-                if let Some(handle) = meshes.get_strong_handle(id) {
-                    handle.is_weak()
-                } else {
-                    true
-                }
-            }).copied().collect();
-            
-            for id in unused_meshes {
-                meshes.remove(id);
-            }
-            
-            let final_image_count = images.len();
-            let final_mesh_count = meshes.len();
-            
-            let images_cleaned = initial_image_count - final_image_count;
-            let meshes_cleaned = initial_mesh_count - final_mesh_count;
-            
-            if images_cleaned > 0 || meshes_cleaned > 0 {
-                info!("Memory optimization: Cleaned {} images, {} meshes. Current: {} images, {} meshes, {} audio", 
-                      images_cleaned, meshes_cleaned, final_image_count, final_mesh_count, audio_count);
-            }
-            
-            // Warn if asset counts are growing too large
-            if final_image_count > 200 {
-                warn!("High image count: {} loaded images", final_image_count);
-            }
-            if final_mesh_count > 100 {
-                warn!("High mesh count: {} loaded meshes", final_mesh_count);
-            }
-            if audio_count > 50 {
-                warn!("High audio count: {} loaded audio files", audio_count);
-            }
-            
-            LAST_IMAGE_COUNT = final_image_count;
-            LAST_MESH_COUNT = final_mesh_count;
-            CLEANUP_TIMER = 0.0;
+    tracker.sample_timer.tick(time.delta());
+    if !tracker.sample_timer.just_finished() {
+        return;
+    }
+
+    let texture_bytes: usize = images.iter().map(|(_, image)| image_bytes(image)).sum();
+    let audio_bytes: usize = audio_assets.iter().map(|(_, audio)| audio.bytes.len()).sum();
+
+    let previous_counts = (metrics.image_count, metrics.mesh_count, metrics.audio_count);
+    metrics.texture_memory_mb = texture_bytes as f32 / 1024.0 / 1024.0;
+    metrics.audio_memory_mb = audio_bytes as f32 / 1024.0 / 1024.0;
+    metrics.image_count = images.len();
+    metrics.mesh_count = meshes.len();
+    metrics.audio_count = audio_assets.len();
+
+    let asset_memory_mb = metrics.texture_memory_mb + metrics.audio_memory_mb;
+    if let Some((context, before_mb)) = tracker.pending_release.take() {
+        info!("Memory: leaving {} released {:.1} MB of assets ({:.1} MB now loaded)",
+              context, (before_mb - asset_memory_mb).max(0.0), asset_memory_mb);
+    }
+
+    // Warn when asset counts grow past their limits
+    if metrics.image_count > 200 && previous_counts.0 <= 200 {
+        warn!("High image count: {} loaded images ({:.1} MB)", metrics.image_count, metrics.texture_memory_mb);
+    }
+    if metrics.mesh_count > 100 && previous_counts.1 <= 100 {
+        warn!("High mesh count: {} loaded meshes", metrics.mesh_count);
+    }
+    if metrics.audio_count > 50 && previous_counts.2 <= 50 {
+        warn!("High audio count: {} loaded audio files ({:.1} MB)", metrics.audio_count, metrics.audio_memory_mb);
+    }
+}
+
+// GPU-side size of an image, which stays valid after the CPU copy is dropped
+fn image_bytes(image: &Image) -> usize {
+    let descriptor = &image.texture_descriptor;
+    let (block_width, block_height) = descriptor.format.block_dimensions();
+    let block_size = descriptor.format.block_copy_size(None).unwrap_or(4) as usize;
+    let size = descriptor.size;
+    size.width.div_ceil(block_width) as usize
+        * size.height.div_ceil(block_height) as usize
+        * size.depth_or_array_layers as usize
+        * block_size
+}
+
+/// Schedules a memory sample right after the player leaves a state or a season turns.
+/// Bevy unloads an asset once its last strong handle drops, so leaving a context unloads what
+/// only it used: menu, journal and catalog UI are state scoped, the journal stops its recording
+/// on exit and seasonal decor swaps its variant sprites. The sample logs how much that released.
+pub fn track_context_release_system(
+    mut transitions: EventReader<StateTransitionEvent<crate::AppState>>,
+    mut tracker: ResMut<MemoryTracker>,
+    mut last_season: Local<Option<crate::environment::components::Season>>,
+    metrics: Res<PerformanceMetrics>,
+    time_state: Res<crate::environment::resources::TimeState>,
+) {
+    let mut left_context = None;
+
+    for transition in transitions.read() {
+        if let Some(exited) = transition.exited.filter(|exited| transition.entered != Some(*exited)) {
+            left_context = Some(format!("{:?}", exited));
         }
     }
+
+    let season = time_state.get_season();
+    if let Some(previous) = last_season.replace(season).filter(|previous| *previous != season) {
+        left_context = Some(format!("{:?}", previous));
+    }
+
+    if let Some(context) = left_context {
+        // Handles drop during this frame's despawns, so sample a second later
+        let before_mb = metrics.texture_memory_mb + metrics.audio_memory_mb;
+        tracker.pending_release.get_or_insert((context, before_mb));
+        let remaining = tracker.sample_timer.duration().saturating_sub(std::time::Duration::from_secs(1));
+        tracker.sample_timer.set_elapsed(remaining);
+    }
 }
 
 /// Enhanced FPS counter system with smoothing and statistics
@@ -200,7 +219,8 @@ pub fn performance_metrics_system(
     // Estimate memory usage (rough approximations)
     metrics.component_memory_mb = (metrics.total_entities * 500) as f32 / 1024.0 / 1024.0; // ~500 bytes per entity avg
     metrics.resource_memory_mb = 16.0; // Rough estimate for resources
-    metrics.estimated_memory_mb = metrics.component_memory_mb + metrics.resource_memory_mb;
+    metrics.estimated_memory_mb = metrics.component_memory_mb + metrics.resource_memory_mb
+        + metrics.texture_memory_mb + metrics.audio_memory_mb;
     
     // Update historical data
     metrics.fps_history.push_back(fps_counter.current_fps);
@@ -271,6 +291,7 @@ pub fn performance_display_system(
     metrics: Res<PerformanceMetrics>,
     settings: Res<PerformanceSettings>,
     profiling_data: Res<ProfilingData>,
+    governor: Res<crate::performance::governor::FrameBudgetGovernor>,
     display_query: Query<Entity, With<PerformanceDisplay>>,
     mut text_query: Query<&mut Text, With<PerformanceDisplay>>,
     input: Res<ButtonInput<KeyCode>>,
@@ -325,7 +346,10 @@ pub fn performance_display_system(
                 "=== PERFORMANCE METRICS ===\n\
                 Entities: {} (Birds: {}, AI: {})\n\
                 Memory: {:.1} MB\n\
+                Textures: {:.1} MB ({} images, {} meshes)\n\
+                Audio: {:.1} MB ({} clips)\n\
                 Feeders: {}, UI: {}\n\
+                Quality level: {} (0 = full)\n\
                 \n\
                 === BOTTLENECKS ===\n\
                 Active: {}\n\
@@ -338,8 +362,14 @@ pub fn performance_display_system(
                 metrics.bird_count,
                 metrics.ai_bird_count,
                 metrics.estimated_memory_mb,
+                metrics.texture_memory_mb,
+                metrics.image_count,
+                metrics.mesh_count,
+                metrics.audio_memory_mb,
+                metrics.audio_count,
                 metrics.feeder_count,
                 metrics.ui_element_count,
+                governor.level,
                 profiling_data.bottlenecks.len(),
                 warnings_text
             );
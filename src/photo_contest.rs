@@ -0,0 +1,616 @@
+// Photo Contest - Seasonal contest judged by a visiting NPC who explains the score
+//
+// One photo can be entered each season. When the season turns the judge scores it on craft rather
+// than rarity, weighing the composition model's categories, and talks the player through the
+// result: what held the photo back ("Subject too small in frame") and what worked. Entries are
+// placed against local rivals who get stronger as the sanctuary's reputation grows, and podium
+// finishes earn a prize and a trophy that stands on a shelf in the yard.
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use crate::bird::BirdSpecies;
+use crate::catalog::resources::PlayerInventory;
+use crate::dialog::StartDialogEvent;
+use crate::environment::resources::{SeasonChangeEvent, TimeState};
+use crate::keybindings::{GameAction, KeyBindings};
+use crate::notifications::{resources::ShowNotificationEvent, components::NotificationType};
+use crate::photo_mode::components::PhotoScore;
+use crate::photo_mode::resources::PhotoCollection;
+use crate::sanctuary_management::SanctuaryReputation;
+
+const PANEL_WIDTH: f32 = 460.0;
+const THUMBNAIL_SIZE: f32 = 96.0;
+const MAX_CANDIDATES: usize = 6;
+const RIVALS: usize = 3;
+// Categories scoring below this fraction of their maximum get a critique, above PRAISE_ABOVE praise
+const CRITIQUE_BELOW: f32 = 0.4;
+const PRAISE_ABOVE: f32 = 0.8;
+const MAX_CRITIQUES: usize = 3;
+const MAX_PRAISES: usize = 2;
+const JUDGE_NAME: &str = "Judge Marion Hale";
+// Trophy shelf just inside the garden fence, newest trophy on the left
+const SHELF_POSITION: Vec3 = Vec3::new(230.0, -255.0, 0.5);
+const SHELF_SLOTS: usize = 6;
+const SLOT_WIDTH: f32 = 18.0;
+
+pub struct PhotoContestPlugin;
+
+impl Plugin for PhotoContestPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<PhotoContest>()
+            .add_systems(Update, toggle_contest_panel_system
+                .run_if(crate::debug_console::console_is_not_visible)
+                .in_set(crate::GameSet::Input)
+                .run_if(in_state(crate::AppState::Playing)))
+            .add_systems(Update, judge_contest_system
+                .in_set(crate::GameSet::Simulation)
+                .run_if(in_state(crate::AppState::Playing)))
+            .add_systems(Update, rebuild_trophy_shelf_system
+                .in_set(crate::GameSet::Presentation)
+                .run_if(in_state(crate::AppState::Playing)))
+            .add_systems(Update, (contest_entry_button_system, refresh_contest_panel_system)
+                .chain()
+                .in_set(crate::GameSet::UI)
+                .run_if(in_state(crate::AppState::Playing)))
+            .add_systems(OnExit(crate::AppState::Playing), close_contest_panel_system);
+    }
+}
+
+// One scoring category as the judge sees it
+struct Criterion {
+    name: &'static str,
+    max: f32,
+    weight: f32,
+    critique: &'static str,
+    praise: &'static str,
+    value: fn(&PhotoScore) -> u32,
+}
+
+// Maxima match the caps in the photo scoring; species rarity is left out on purpose
+const CRITERIA: [Criterion; 9] = [
+    Criterion {
+        name: "composition",
+        max: 60.0,
+        weight: 1.5,
+        critique: "Composition is static - try placing the bird on a third of the frame",
+        praise: "Strong composition, the eye goes straight to the bird",
+        value: |score| score.composition_score,
+    },
+    Criterion {
+        name: "technique",
+        max: 50.0,
+        weight: 1.2,
+        critique: "Subject too small in frame - get closer or zoom in",
+        praise: "Technically clean - well exposed and well framed",
+        value: |score| score.technical_score,
+    },
+    Criterion {
+        name: "lighting",
+        max: 50.0,
+        weight: 1.0,
+        critique: "Flat, harsh light - early morning or evening would flatter the plumage",
+        praise: "Lovely light on the plumage",
+        value: |score| score.lighting_score,
+    },
+    Criterion {
+        name: "focus",
+        max: 25.0,
+        weight: 1.0,
+        critique: "The bird is soft - check your focus distance",
+        praise: "Sharp right where it matters",
+        value: |score| score.clarity_score,
+    },
+    Criterion {
+        name: "behavior",
+        max: 75.0,
+        weight: 1.0,
+        critique: "The bird is just passing through - behavior shots stand out",
+        praise: "A wonderful moment of behavior",
+        value: |score| score.behavior_score,
+    },
+    Criterion {
+        name: "placement",
+        max: 35.0,
+        weight: 0.8,
+        critique: "Subject drifts toward the edge of the frame",
+        praise: "Subject placed with real care",
+        value: |score| score.centering_score,
+    },
+    Criterion {
+        name: "timing",
+        max: 50.0,
+        weight: 0.8,
+        critique: "The shutter went a moment too early or too late",
+        praise: "Perfect timing",
+        value: |score| score.timing_score,
+    },
+    Criterion {
+        name: "story",
+        max: 40.0,
+        weight: 0.8,
+        critique: "Nothing is happening - wait for the bird to do something",
+        praise: "Tells a story at a glance",
+        value: |score| score.storytelling_score,
+    },
+    Criterion {
+        name: "setting",
+        max: 40.0,
+        weight: 0.6,
+        critique: "The setting says little - other birds or a natural perch would help",
+        praise: "The setting adds a lot to the picture",
+        value: |score| score.environment_score,
+    },
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TrophyPlace {
+    Gold,
+    Silver,
+    Bronze,
+}
+
+impl TrophyPlace {
+    pub fn from_place(place: usize) -> Option<Self> {
+        match place {
+            1 => Some(Self::Gold),
+            2 => Some(Self::Silver),
+            3 => Some(Self::Bronze),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Gold => "First place",
+            Self::Silver => "Second place",
+            Self::Bronze => "Third place",
+        }
+    }
+
+    pub fn prize(&self) -> u32 {
+        match self {
+            Self::Gold => 300,
+            Self::Silver => 150,
+            Self::Bronze => 75,
+        }
+    }
+
+    pub fn color(&self) -> Color {
+        match self {
+            Self::Gold => Color::srgb(0.95, 0.78, 0.2),
+            Self::Silver => Color::srgb(0.78, 0.8, 0.84),
+            Self::Bronze => Color::srgb(0.76, 0.5, 0.28),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContestEntry {
+    pub photo_id: u32,
+    pub species: Option<BirdSpecies>,
+    // Copied on entry, the photo itself may be cleared out of the collection before judging
+    pub score: PhotoScore,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContestResult {
+    // e.g. "Summer, year 2"
+    pub season: String,
+    pub species: Option<BirdSpecies>,
+    pub judged_score: u32,
+    pub rival_scores: Vec<u32>,
+    pub place: usize,
+    pub critique: Vec<String>,
+}
+
+impl ContestResult {
+    pub fn trophy(&self) -> Option<TrophyPlace> {
+        TrophyPlace::from_place(self.place)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContestTrophy {
+    pub place: TrophyPlace,
+    pub season: String,
+    pub species: Option<BirdSpecies>,
+}
+
+// Resources
+#[derive(Resource, Serialize, Deserialize, Clone, Debug, Default)]
+pub struct PhotoContest {
+    pub entry: Option<ContestEntry>,
+    pub last_result: Option<ContestResult>,
+    pub trophies: Vec<ContestTrophy>,
+    #[serde(skip)]
+    pub is_open: bool,
+    #[serde(skip)]
+    panel_dirty: bool,
+    // Set when the trophy shelf in the yard needs rebuilding, e.g. after a load
+    #[serde(skip)]
+    pub trophies_dirty: bool,
+}
+
+/// The judge's 0-100 score for a photo and the critique lines behind it, weakest category first
+pub fn judge_photo(score: &PhotoScore) -> (u32, Vec<String>) {
+    let fractions: Vec<(&Criterion, f32)> = CRITERIA.iter()
+        .map(|criterion| (criterion, ((criterion.value)(score) as f32 / criterion.max).clamp(0.0, 1.0)))
+        .collect();
+
+    let total_weight: f32 = CRITERIA.iter().map(|criterion| criterion.weight).sum();
+    let judged = fractions.iter().map(|(criterion, fraction)| criterion.weight * fraction).sum::<f32>() / total_weight;
+
+    let mut weakest = fractions.clone();
+    weakest.sort_by(|(_, a), (_, b)| a.total_cmp(b));
+    let mut critique: Vec<String> = weakest.iter()
+        .filter(|(_, fraction)| *fraction < CRITIQUE_BELOW)
+        .take(MAX_CRITIQUES)
+        .map(|(criterion, _)| criterion.critique.to_string())
+        .collect();
+    critique.extend(weakest.iter().rev()
+        .filter(|(_, fraction)| *fraction >= PRAISE_ABOVE)
+        .take(MAX_PRAISES)
+        .map(|(criterion, _)| criterion.praise.to_string()));
+    if critique.is_empty() {
+        let best = weakest.last().map(|(criterion, _)| criterion.name).unwrap_or("composition");
+        critique.push(format!("Solid all round, the {} is the strongest part - push it further", best));
+    }
+
+    ((judged * 100.0).round() as u32, critique)
+}
+
+// Local rivals improve as the sanctuary's standing draws better photographers
+fn rival_scores(reputation: f32) -> Vec<u32> {
+    let base = 35.0 + reputation.clamp(0.0, 100.0) * 0.35;
+    let mut scores: Vec<u32> = (0..RIVALS)
+        .map(|_| (base + rand::random::<f32>() * 20.0 - 10.0).clamp(0.0, 100.0) as u32)
+        .collect();
+    scores.sort_unstable_by(|a, b| b.cmp(a));
+    scores
+}
+
+pub fn toggle_contest_panel_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    key_bindings: Res<KeyBindings>,
+    mut contest: ResMut<PhotoContest>,
+) {
+    if key_bindings.is_action_just_pressed(GameAction::OpenPhotoContest, &keyboard, &mouse) {
+        contest.is_open = !contest.is_open;
+        contest.panel_dirty = true;
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn judge_contest_system(
+    mut season_events: EventReader<SeasonChangeEvent>,
+    mut contest: ResMut<PhotoContest>,
+    mut inventory: ResMut<PlayerInventory>,
+    reputation: Res<SanctuaryReputation>,
+    time_state: Res<TimeState>,
+    mut dialog_events: EventWriter<StartDialogEvent>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+) {
+    for event in season_events.read() {
+        let season = format!("{:?}, year {}", event.previous, time_state.year);
+        let Some(entry) = contest.entry.take() else {
+            info!("📷 No entry in the {} photo contest", season);
+            continue;
+        };
+
+        let (judged_score, critique) = judge_photo(&entry.score);
+        let rivals = rival_scores(reputation.score);
+        let place = 1 + rivals.iter().filter(|rival| **rival > judged_score).count();
+        let result = ContestResult {
+            season: season.clone(),
+            species: entry.species,
+            judged_score,
+            rival_scores: rivals,
+            place,
+            critique,
+        };
+
+        let subject = entry.species.map(|species| format!("{:?}", species)).unwrap_or_else(|| "bird".to_string());
+        let mut lines = vec![format!(
+            "I've judged the {} contest. Your {} photo scored {} out of 100.",
+            season, subject, judged_score,
+        )];
+        lines.extend(result.critique.iter().cloned());
+
+        match result.trophy() {
+            Some(trophy) => {
+                lines.push(format!("{} - congratulations! The trophy is on its way to your yard.", trophy.name()));
+                inventory.currency += trophy.prize();
+                contest.trophies.push(ContestTrophy { place: trophy, season: season.clone(), species: entry.species });
+                contest.trophies_dirty = true;
+                notification_events.write(ShowNotificationEvent {
+                    notification: NotificationType::Achievement {
+                        title: format!("{} in the {} photo contest", trophy.name(), season),
+                        description: format!("Your {} photo scored {}", subject, judged_score),
+                        currency_reward: trophy.prize(),
+                    },
+                });
+            }
+            None => {
+                lines.push(format!("You placed {} of {} this time. Keep at it.", place, RIVALS + 1));
+                notification_events.write(ShowNotificationEvent {
+                    notification: NotificationType::Info {
+                        message: format!("Placed {} in the {} photo contest", place, season),
+                    },
+                });
+            }
+        }
+
+        dialog_events.write(StartDialogEvent {
+            source: Entity::PLACEHOLDER,
+            speaker: JUDGE_NAME.to_string(),
+            portrait_color: Color::srgb(0.45, 0.35, 0.6),
+            lines,
+            options: Vec::new(),
+        });
+        info!("📷 {} photo contest judged: {} points, place {}", season, judged_score, place);
+        contest.last_result = Some(result);
+        contest.panel_dirty = true;
+    }
+}
+
+pub fn contest_entry_button_system(
+    mut button_query: Query<(&Interaction, &ContestPhotoButton, &mut BackgroundColor), Changed<Interaction>>,
+    mut contest: ResMut<PhotoContest>,
+    collection: Res<PhotoCollection>,
+    time_state: Res<TimeState>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+) {
+    let mut chosen = None;
+    for (interaction, button, mut bg_color) in &mut button_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *bg_color = Color::srgb(0.5, 0.7, 0.5).into();
+                chosen = Some(button.photo_id);
+            }
+            Interaction::Hovered => *bg_color = Color::srgb(0.7, 0.6, 0.5).into(),
+            Interaction::None => *bg_color = Color::srgb(0.6, 0.5, 0.4).into(),
+        }
+    }
+    let Some(photo) = chosen.and_then(|id| collection.get_photo(id)) else { return };
+
+    // Changing the entry is allowed right up to judging
+    contest.entry = Some(ContestEntry {
+        photo_id: photo.id,
+        species: photo.species,
+        score: photo.score.clone(),
+    });
+    contest.panel_dirty = true;
+    notification_events.write(ShowNotificationEvent {
+        notification: NotificationType::Info {
+            message: format!("Entered photo #{} in the {:?} contest", photo.id, time_state.get_season()),
+        },
+    });
+}
+
+pub fn refresh_contest_panel_system(
+    mut commands: Commands,
+    mut contest: ResMut<PhotoContest>,
+    collection: Res<PhotoCollection>,
+    time_state: Res<TimeState>,
+    panel_query: Query<Entity, With<PhotoContestPanel>>,
+) {
+    if !contest.panel_dirty {
+        return;
+    }
+    contest.panel_dirty = false;
+
+    for panel in &panel_query {
+        commands.entity(panel).despawn();
+    }
+    if !contest.is_open {
+        return;
+    }
+
+    spawn_contest_panel(&mut commands, &contest, &collection, &time_state);
+}
+
+pub fn close_contest_panel_system(
+    mut commands: Commands,
+    mut contest: ResMut<PhotoContest>,
+    panel_query: Query<Entity, With<PhotoContestPanel>>,
+) {
+    contest.is_open = false;
+    for panel in &panel_query {
+        commands.entity(panel).despawn();
+    }
+}
+
+pub fn rebuild_trophy_shelf_system(
+    mut commands: Commands,
+    mut contest: ResMut<PhotoContest>,
+    trophy_query: Query<Entity, With<ContestTrophyDisplay>>,
+) {
+    if !contest.trophies_dirty {
+        return;
+    }
+    contest.trophies_dirty = false;
+
+    for entity in &trophy_query {
+        commands.entity(entity).despawn();
+    }
+    if contest.trophies.is_empty() {
+        return;
+    }
+
+    let shown = contest.trophies.len().min(SHELF_SLOTS);
+    commands.spawn((
+        Sprite::from_color(Color::srgb(0.45, 0.32, 0.2), Vec2::new(SHELF_SLOTS as f32 * SLOT_WIDTH + 8.0, 6.0)),
+        Transform::from_translation(SHELF_POSITION),
+        ContestTrophyDisplay,
+        Name::new("TrophyShelf"),
+    ));
+
+    let left = SHELF_POSITION.x - (SHELF_SLOTS as f32 - 1.0) * SLOT_WIDTH / 2.0;
+    for (slot, trophy) in contest.trophies.iter().rev().take(shown).enumerate() {
+        let x = left + slot as f32 * SLOT_WIDTH;
+        let color = trophy.place.color();
+        // Base and cup, the cup a little wider so it reads as a trophy at a glance
+        commands.spawn((
+            Sprite::from_color(color.darker(0.2), Vec2::new(8.0, 4.0)),
+            Transform::from_xyz(x, SHELF_POSITION.y + 5.0, SHELF_POSITION.z + 0.01),
+            ContestTrophyDisplay,
+            Name::new("TrophyBase"),
+        ));
+        commands.spawn((
+            Sprite::from_color(color, Vec2::new(12.0, 10.0)),
+            Transform::from_xyz(x, SHELF_POSITION.y + 12.0, SHELF_POSITION.z + 0.02),
+            ContestTrophyDisplay,
+            Name::new(format!("Trophy {}", trophy.season)),
+        ));
+    }
+}
+
+// Components
+#[derive(Component)]
+pub struct PhotoContestPanel;
+
+#[derive(Component)]
+pub struct ContestPhotoButton {
+    pub photo_id: u32,
+}
+
+#[derive(Component)]
+pub struct ContestTrophyDisplay;
+
+fn spawn_contest_panel(
+    commands: &mut Commands,
+    contest: &PhotoContest,
+    collection: &PhotoCollection,
+    time_state: &TimeState,
+) {
+    let heading_font = TextFont { font_size: 15.0, ..default() };
+    let body_font = TextFont { font_size: 12.0, ..default() };
+    let heading_color = TextColor(Color::srgb(0.3, 0.2, 0.1));
+    let body_color = TextColor(Color::srgb(0.4, 0.3, 0.2));
+
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            right: Val::Px(20.0),
+            top: Val::Px(80.0),
+            width: Val::Px(PANEL_WIDTH),
+            flex_direction: FlexDirection::Column,
+            padding: UiRect::all(Val::Px(14.0)),
+            row_gap: Val::Px(6.0),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.95, 0.92, 0.88, 0.95)),
+        BorderRadius::all(Val::Px(8.0)),
+        PhotoContestPanel,
+        Name::new("PhotoContest"),
+    )).with_children(|panel| {
+        panel.spawn((
+            Text::new(format!("{:?} Photo Contest", time_state.get_season())),
+            TextFont { font_size: 20.0, ..default() },
+            heading_color,
+        ));
+        panel.spawn((
+            Text::new(format!("{} judges the entries when the season turns", JUDGE_NAME)),
+            body_font.clone(),
+            TextColor(Color::srgb(0.5, 0.4, 0.3)),
+        ));
+
+        let entry_text = match &contest.entry {
+            Some(entry) => format!(
+                "Entered: photo #{}{} - click another photo to swap",
+                entry.photo_id,
+                entry.species.map(|species| format!(" ({:?})", species)).unwrap_or_default(),
+            ),
+            None => "No entry yet - click a photo to enter it".to_string(),
+        };
+        panel.spawn((Text::new(entry_text), body_font.clone(), body_color));
+
+        let candidates = collection.get_best_photos(MAX_CANDIDATES);
+        if candidates.is_empty() {
+            panel.spawn((
+                Text::new("No photos yet. Take some in photo mode to enter the contest."),
+                body_font.clone(),
+                body_color,
+            ));
+        }
+
+        panel.spawn(Node {
+            width: Val::Percent(100.0),
+            flex_wrap: FlexWrap::Wrap,
+            column_gap: Val::Px(6.0),
+            row_gap: Val::Px(6.0),
+            ..default()
+        }).with_children(|grid| {
+            for photo in candidates {
+                let entered = contest.entry.as_ref().is_some_and(|entry| entry.photo_id == photo.id);
+                grid.spawn((
+                    Button,
+                    Node {
+                        width: Val::Px(THUMBNAIL_SIZE + 8.0),
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        padding: UiRect::all(Val::Px(4.0)),
+                        border: UiRect::all(Val::Px(2.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.6, 0.5, 0.4)),
+                    BorderColor(if entered { Color::srgb(0.95, 0.78, 0.2) } else { Color::NONE }),
+                    BorderRadius::all(Val::Px(4.0)),
+                    ContestPhotoButton { photo_id: photo.id },
+                )).with_children(|button| {
+                    button.spawn((
+                        ImageNode::new(photo.image_handle.clone()),
+                        Node {
+                            width: Val::Px(THUMBNAIL_SIZE),
+                            height: Val::Px(THUMBNAIL_SIZE * 0.75),
+                            ..default()
+                        },
+                    ));
+                    button.spawn((
+                        Text::new(format!(
+                            "{} - {} pts",
+                            photo.species.map(|species| format!("{:?}", species)).unwrap_or_else(|| "Scenery".to_string()),
+                            photo.score.total_score,
+                        )),
+                        TextFont { font_size: 10.0, ..default() },
+                        TextColor(Color::WHITE),
+                    ));
+                });
+            }
+        });
+
+        if let Some(result) = &contest.last_result {
+            panel.spawn((
+                Text::new(format!(
+                    "Last contest ({}): {} points, placed {} of {}",
+                    result.season,
+                    result.judged_score,
+                    result.place,
+                    result.rival_scores.len() + 1,
+                )),
+                heading_font.clone(),
+                heading_color,
+            ));
+            for line in &result.critique {
+                panel.spawn((Text::new(format!("- {}", line)), body_font.clone(), body_color));
+            }
+        }
+
+        if !contest.trophies.is_empty() {
+            panel.spawn((Text::new("Trophies"), heading_font, heading_color));
+            for trophy in contest.trophies.iter().rev() {
+                panel.spawn((
+                    Text::new(format!(
+                        "{} - {}{}",
+                        trophy.place.name(),
+                        trophy.season,
+                        trophy.species.map(|species| format!(", {:?}", species)).unwrap_or_default(),
+                    )),
+                    body_font.clone(),
+                    TextColor(trophy.place.color().darker(0.25)),
+                ));
+            }
+        }
+    });
+}
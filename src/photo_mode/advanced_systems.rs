@@ -3,6 +3,7 @@ use super::{components::*, resources::*};
 use crate::environment::{resources::TimeState, components::Season};
 use crate::bird_ai::components::{BirdAI, BirdState};
 use crate::animation::components::AnimatedBird;
+use super::depth_of_field::{blur_radius, yard_depth, MAX_BLUR_RADIUS};
 
 pub fn setup_advanced_photo_ui(mut commands: Commands) {
     // Composition grid overlay
@@ -187,6 +188,18 @@ pub fn photo_mode_input_system(
     if keyboard.just_pressed(settings.settings_toggle_key) {
         settings.show_camera_settings = !settings.show_camera_settings;
     }
+    
+    // Toggle burst shooting
+    if keyboard.just_pressed(settings.burst_toggle_key) {
+        settings.burst_mode = !settings.burst_mode;
+        info!("Burst mode {}", if settings.burst_mode { "on" } else { "off" });
+    }
+    
+    // Toggle flash
+    if keyboard.just_pressed(settings.flash_toggle_key) {
+        settings.flash_enabled = !settings.flash_enabled;
+        info!("Flash {}", if settings.flash_enabled { "on" } else { "off" });
+    }
 }
 
 pub fn enhanced_photo_scoring_system(
@@ -457,6 +470,12 @@ fn analyze_storytelling_elements(
         BirdState::HoverFeeding => {
             story_score += 45; // Spectacular hovering behavior
         },
+        BirdState::Begging => {
+            story_score += 40; // Family feeding moment
+        },
+        BirdState::Drumming => {
+            story_score += 35; // Woodpecker at work
+        },
         BirdState::Bathing => {
             story_score += 35; // Rare and interesting behavior
         },
@@ -521,6 +540,8 @@ fn calculate_enhanced_rarity_bonus(
         BirdState::Caching => bonus += 30,      // Very rare intelligent behavior
         BirdState::HoverFeeding => bonus += 35, // Spectacular rare behavior
         BirdState::Retrieving => bonus += 25,   // Smart cache retrieval
+        BirdState::Begging => bonus += 20,      // Seasonal family behavior
+        BirdState::Drumming => bonus += 15,     // Mid-drumroll timing
         BirdState::Foraging => bonus += 10,     // Natural but noteworthy
         _ => {}
     }
@@ -562,17 +583,10 @@ fn calculate_enhanced_centering(bird_transform: &Transform) -> u32 {
 }
 
 fn calculate_enhanced_clarity(bird_transform: &Transform, camera_controls: &CameraControls) -> u32 {
-    let bird_z = bird_transform.translation.z;
-    let focus_difference = (bird_z - camera_controls.focus_distance).abs();
-    
-    // Depth of field calculation based on aperture
-    let dof_range = camera_controls.aperture * 2.0; // Wider aperture = shallower DOF
-    
-    let clarity = if focus_difference < dof_range {
-        1.0 - (focus_difference / dof_range) * 0.3 // In focus
-    } else {
-        0.7 - ((focus_difference - dof_range) / 100.0).min(0.6) // Out of focus penalty
-    };
+    // The same blur the photo gets at the bird's depth in the yard
+    let depth = yard_depth(bird_transform.translation.y);
+    let blur = blur_radius(depth, camera_controls.focus_distance, camera_controls.aperture);
+    let clarity = 1.0 - (blur / MAX_BLUR_RADIUS) * 0.7;
     
     (clarity * 25.0) as u32
 }
@@ -596,6 +610,8 @@ fn get_behavior_score(bird_state: BirdState) -> u32 {
         BirdState::Caching => 65,     // Rare seed caching behavior
         BirdState::Retrieving => 55,  // Intelligent cache retrieval behavior
         BirdState::HoverFeeding => 70, // Spectacular hovering nectar feeding
+        BirdState::Begging => 70,     // Juvenile begging from a parent
+        BirdState::Drumming => 60,    // Woodpecker drumming or excavating
         BirdState::Fleeing => 30,
         BirdState::Resting => 25,
         BirdState::MovingToTarget => 20,
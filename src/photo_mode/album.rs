@@ -0,0 +1,884 @@
+// Photo Albums - Themed photo books with captions and page layouts
+use bevy::prelude::*;
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input::ButtonState;
+use crate::photo_mode::resources::{PhotoCollection, SavedPhoto};
+use crate::photo_mode::album_export::{ExportAlbumEvent, ExportFormat, AlbumExportJob, PAGE_WIDTH, PAGE_HEIGHT};
+use crate::photo_mode::photo_export::{ExportPhotoEvent, PhotoExportJob, PhotoExportStyle, PhotoFrame, PHOTO_SOURCE_SIZE};
+use crate::achievements::AchievementProgress;
+use crate::keybindings::{GameAction, KeyBindings};
+
+const MAX_CAPTION_LENGTH: usize = 60;
+const PREVIEW_WIDTH: f32 = 560.0;
+const PREVIEW_HEIGHT: f32 = 420.0;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AlbumTheme {
+    FieldJournal,
+    Gallery,
+    Meadow,
+    Winter,
+}
+
+impl AlbumTheme {
+    pub const ALL: [AlbumTheme; 4] = [Self::FieldJournal, Self::Gallery, Self::Meadow, Self::Winter];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::FieldJournal => "Field Journal",
+            Self::Gallery => "Gallery",
+            Self::Meadow => "Meadow",
+            Self::Winter => "Winter",
+        }
+    }
+
+    pub fn background(&self) -> Color {
+        match self {
+            Self::FieldJournal => Color::srgb(0.95, 0.91, 0.82),
+            Self::Gallery => Color::srgb(0.12, 0.12, 0.13),
+            Self::Meadow => Color::srgb(0.82, 0.9, 0.76),
+            Self::Winter => Color::srgb(0.9, 0.94, 0.98),
+        }
+    }
+
+    pub fn frame(&self) -> Color {
+        match self {
+            Self::FieldJournal => Color::srgb(0.55, 0.42, 0.28),
+            Self::Gallery => Color::srgb(0.9, 0.9, 0.9),
+            Self::Meadow => Color::srgb(0.35, 0.5, 0.25),
+            Self::Winter => Color::srgb(0.6, 0.68, 0.78),
+        }
+    }
+
+    pub fn text(&self) -> Color {
+        match self {
+            Self::FieldJournal => Color::srgb(0.3, 0.2, 0.1),
+            Self::Gallery => Color::srgb(0.92, 0.92, 0.92),
+            Self::Meadow => Color::srgb(0.15, 0.28, 0.12),
+            Self::Winter => Color::srgb(0.2, 0.27, 0.38),
+        }
+    }
+
+    fn next(&self) -> Self {
+        let index = Self::ALL.iter().position(|theme| theme == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AlbumLayout {
+    FullPage,
+    SideBySide,
+    Grid,
+    ContactSheet,
+}
+
+impl AlbumLayout {
+    pub const ALL: [AlbumLayout; 4] = [Self::FullPage, Self::SideBySide, Self::Grid, Self::ContactSheet];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::FullPage => "Full Page",
+            Self::SideBySide => "Side by Side",
+            Self::Grid => "2x2 Grid",
+            Self::ContactSheet => "Contact Sheet",
+        }
+    }
+
+    // Columns and rows of photos on each page
+    pub fn grid(&self) -> (u32, u32) {
+        match self {
+            Self::FullPage => (1, 1),
+            Self::SideBySide => (2, 1),
+            Self::Grid => (2, 2),
+            Self::ContactSheet => (3, 3),
+        }
+    }
+
+    pub fn photos_per_page(&self) -> usize {
+        let (columns, rows) = self.grid();
+        (columns * rows) as usize
+    }
+
+    // Photo rectangles in page space (origin top-left, y down), keeping the 4:3 capture aspect.
+    // Room is left under each photo for its caption and across the top for the album title.
+    pub fn photo_rects(&self, page: Vec2) -> Vec<Rect> {
+        let (columns, rows) = self.grid();
+        let margin = page.x * 0.04;
+        let gap = margin * 0.5;
+        let title_space = page.y * 0.12;
+        let caption_space = page.y * 0.05;
+
+        let cell_width = (page.x - margin * 2.0 - gap * (columns - 1) as f32) / columns as f32;
+        let cell_height = (page.y - title_space - margin - gap * (rows - 1) as f32) / rows as f32;
+
+        let photo_height = (cell_height - caption_space).min(cell_width * 0.75);
+        let photo_size = Vec2::new(photo_height / 0.75, photo_height);
+
+        let mut rects = Vec::new();
+        for row in 0..rows {
+            for column in 0..columns {
+                let cell_min = Vec2::new(
+                    margin + column as f32 * (cell_width + gap),
+                    title_space + row as f32 * (cell_height + gap),
+                );
+                let min = cell_min + Vec2::new((cell_width - photo_size.x) / 2.0, 0.0);
+                rects.push(Rect::from_corners(min, min + photo_size));
+            }
+        }
+        rects
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct AlbumEntry {
+    pub photo_id: u32,
+    pub caption: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct PhotoAlbum {
+    pub name: String,
+    pub theme: AlbumTheme,
+    pub layout: AlbumLayout,
+    pub entries: Vec<AlbumEntry>,
+}
+
+impl PhotoAlbum {
+    pub fn new(name: String) -> Self {
+        Self {
+            name,
+            theme: AlbumTheme::FieldJournal,
+            layout: AlbumLayout::Grid,
+            entries: Vec::new(),
+        }
+    }
+
+    pub fn page_count(&self) -> usize {
+        self.entries.len().div_ceil(self.layout.photos_per_page()).max(1)
+    }
+
+    pub fn page_entries(&self, page: usize) -> &[AlbumEntry] {
+        let per_page = self.layout.photos_per_page();
+        let start = (page * per_page).min(self.entries.len());
+        let end = (start + per_page).min(self.entries.len());
+        &self.entries[start..end]
+    }
+
+    pub fn contains(&self, photo_id: u32) -> bool {
+        self.entries.iter().any(|entry| entry.photo_id == photo_id)
+    }
+
+    pub fn add_photo(&mut self, photo: &SavedPhoto) {
+        if !self.contains(photo.id) {
+            self.entries.push(AlbumEntry {
+                photo_id: photo.id,
+                caption: default_caption(photo),
+            });
+        }
+    }
+}
+
+pub fn default_caption(photo: &SavedPhoto) -> String {
+    match photo.species {
+        Some(species) => format!("{:?} - Score {}", species, photo.score.total_score),
+        None => format!("Score {}", photo.score.total_score),
+    }
+}
+
+// Editor state
+#[derive(Resource, Default)]
+pub struct AlbumEditor {
+    pub is_open: bool,
+    pub selected_album: Option<usize>,
+    pub page: usize,
+    pub editing_caption: Option<usize>, // Entry index within the selected album
+    // Single-photo export view instead of the album pages
+    pub exporting_photo: bool,
+    pub export_photo: Option<u32>,
+    panel_dirty: bool,
+}
+
+// UI Components
+#[derive(Component)]
+pub struct AlbumEditorPanel;
+
+#[derive(Component)]
+pub struct AlbumEditorButton {
+    pub action: AlbumEditorAction,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AlbumEditorAction {
+    NewAlbum,
+    SelectAlbum(usize),
+    DeleteAlbum,
+    CycleTheme,
+    CycleLayout,
+    PreviousPage,
+    NextPage,
+    EditCaption(usize),
+    RemoveEntry(usize),
+    AddPhoto(u32),
+    Export(ExportFormat),
+    TogglePhotoExport,
+    SelectExportPhoto(u32),
+    CycleFrame,
+    CycleCrop,
+    ToggleDateStamp,
+    ToggleSpeciesStamp,
+    ToggleTiltShift,
+    ExportPhoto,
+    ExportMenuBackground,
+}
+
+pub fn toggle_album_editor_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    key_bindings: Res<KeyBindings>,
+    mut editor: ResMut<AlbumEditor>,
+) {
+    // Letters typed into a caption shouldn't close the editor
+    if editor.editing_caption.is_some() {
+        return;
+    }
+
+    if key_bindings.is_action_just_pressed(GameAction::OpenAlbumEditor, &keyboard, &mouse) {
+        editor.is_open = !editor.is_open;
+        editor.panel_dirty = true;
+    }
+}
+
+pub fn close_album_editor_system(
+    mut commands: Commands,
+    mut editor: ResMut<AlbumEditor>,
+    panel_query: Query<Entity, With<AlbumEditorPanel>>,
+) {
+    editor.is_open = false;
+    editor.editing_caption = None;
+    for panel in &panel_query {
+        commands.entity(panel).despawn();
+    }
+}
+
+pub fn album_editor_button_system(
+    mut interaction_query: Query<(&Interaction, &AlbumEditorButton, &mut BackgroundColor), Changed<Interaction>>,
+    mut editor: ResMut<AlbumEditor>,
+    mut collection: ResMut<PhotoCollection>,
+    mut export_events: EventWriter<ExportAlbumEvent>,
+    mut photo_export_events: EventWriter<ExportPhotoEvent>,
+    mut export_style: ResMut<PhotoExportStyle>,
+    achievement_progress: Res<AchievementProgress>,
+) {
+    for (interaction, button, mut bg_color) in &mut interaction_query {
+        // Photos and captions keep their own look; only the text buttons highlight
+        let highlight = !matches!(
+            button.action,
+            AlbumEditorAction::EditCaption(_) | AlbumEditorAction::RemoveEntry(_) | AlbumEditorAction::AddPhoto(_)
+                | AlbumEditorAction::SelectExportPhoto(_)
+        );
+
+        match *interaction {
+            Interaction::Pressed => {
+                if highlight {
+                    *bg_color = Color::srgb(0.5, 0.7, 0.5).into();
+                }
+            }
+            Interaction::Hovered => {
+                if highlight {
+                    *bg_color = Color::srgb(0.7, 0.6, 0.5).into();
+                }
+                continue;
+            }
+            Interaction::None => {
+                if highlight {
+                    *bg_color = Color::srgb(0.6, 0.5, 0.4).into();
+                }
+                continue;
+            }
+        }
+
+        match button.action {
+            AlbumEditorAction::NewAlbum => {
+                let name = format!("Album {}", collection.albums.len() + 1);
+                collection.albums.push(PhotoAlbum::new(name));
+                editor.selected_album = Some(collection.albums.len() - 1);
+                editor.page = 0;
+            }
+            AlbumEditorAction::SelectAlbum(index) => {
+                editor.selected_album = Some(index);
+                editor.page = 0;
+            }
+            AlbumEditorAction::DeleteAlbum => {
+                if let Some(index) = editor.selected_album.take() {
+                    if index < collection.albums.len() {
+                        collection.albums.remove(index);
+                    }
+                }
+            }
+            AlbumEditorAction::CycleTheme => {
+                if let Some(album) = selected_album_mut(&editor, &mut collection) {
+                    album.theme = album.theme.next();
+                }
+            }
+            AlbumEditorAction::CycleLayout => {
+                if let Some(album) = selected_album_mut(&editor, &mut collection) {
+                    let index = AlbumLayout::ALL.iter().position(|layout| *layout == album.layout).unwrap_or(0);
+                    album.layout = AlbumLayout::ALL[(index + 1) % AlbumLayout::ALL.len()];
+                }
+                editor.page = 0;
+            }
+            AlbumEditorAction::PreviousPage => {
+                editor.page = editor.page.saturating_sub(1);
+            }
+            AlbumEditorAction::NextPage => {
+                let page_count = selected_album_mut(&editor, &mut collection).map_or(1, |album| album.page_count());
+                editor.page = (editor.page + 1).min(page_count - 1);
+            }
+            AlbumEditorAction::EditCaption(entry) => {
+                editor.editing_caption = if editor.editing_caption == Some(entry) { None } else { Some(entry) };
+            }
+            AlbumEditorAction::RemoveEntry(entry) => {
+                if let Some(album) = selected_album_mut(&editor, &mut collection) {
+                    if entry < album.entries.len() {
+                        album.entries.remove(entry);
+                    }
+                    let page_count = album.page_count();
+                    editor.page = editor.page.min(page_count - 1);
+                }
+                editor.editing_caption = None;
+            }
+            AlbumEditorAction::AddPhoto(photo_id) => {
+                let Some(photo) = collection.get_photo(photo_id).cloned() else { continue };
+                if let Some(album) = selected_album_mut(&editor, &mut collection) {
+                    album.add_photo(&photo);
+                }
+            }
+            AlbumEditorAction::Export(format) => {
+                if let Some(album_index) = editor.selected_album {
+                    export_events.write(ExportAlbumEvent { album_index, format });
+                }
+            }
+            AlbumEditorAction::TogglePhotoExport => {
+                editor.exporting_photo = !editor.exporting_photo;
+                editor.editing_caption = None;
+            }
+            AlbumEditorAction::SelectExportPhoto(photo_id) => {
+                editor.export_photo = Some(photo_id);
+            }
+            AlbumEditorAction::CycleFrame => {
+                export_style.frame = export_style.frame.next_unlocked(&achievement_progress);
+            }
+            AlbumEditorAction::CycleCrop => {
+                export_style.crop = export_style.crop.next();
+            }
+            AlbumEditorAction::ToggleDateStamp => {
+                export_style.date_stamp = !export_style.date_stamp;
+            }
+            AlbumEditorAction::ToggleSpeciesStamp => {
+                export_style.species_stamp = !export_style.species_stamp;
+            }
+            AlbumEditorAction::ToggleTiltShift => {
+                export_style.tilt_shift = !export_style.tilt_shift;
+            }
+            AlbumEditorAction::ExportPhoto | AlbumEditorAction::ExportMenuBackground => {
+                if let Some(photo_id) = editor.export_photo {
+                    photo_export_events.write(ExportPhotoEvent {
+                        photo_id,
+                        menu_background: button.action == AlbumEditorAction::ExportMenuBackground,
+                    });
+                }
+            }
+        }
+
+        editor.panel_dirty = true;
+    }
+}
+
+fn selected_album_mut<'a>(editor: &AlbumEditor, collection: &'a mut PhotoCollection) -> Option<&'a mut PhotoAlbum> {
+    editor.selected_album.and_then(|index| collection.albums.get_mut(index))
+}
+
+pub fn album_caption_is_editing(editor: Res<AlbumEditor>) -> bool {
+    editor.editing_caption.is_some()
+}
+
+pub fn album_caption_input_system(
+    mut keyboard_events: EventReader<KeyboardInput>,
+    mut editor: ResMut<AlbumEditor>,
+    mut collection: ResMut<PhotoCollection>,
+) {
+    let (Some(entry_index), Some(album_index)) = (editor.editing_caption, editor.selected_album) else {
+        keyboard_events.clear();
+        return;
+    };
+
+    let Some(entry) = collection.albums.get_mut(album_index).and_then(|album| album.entries.get_mut(entry_index)) else {
+        editor.editing_caption = None;
+        return;
+    };
+
+    let mut changed = false;
+    for event in keyboard_events.read() {
+        if event.state != ButtonState::Pressed {
+            continue;
+        }
+
+        match &event.logical_key {
+            Key::Character(text) if entry.caption.len() < MAX_CAPTION_LENGTH => {
+                entry.caption.push_str(text);
+                changed = true;
+            }
+            Key::Space if entry.caption.len() < MAX_CAPTION_LENGTH => {
+                entry.caption.push(' ');
+                changed = true;
+            }
+            Key::Backspace => {
+                entry.caption.pop();
+                changed = true;
+            }
+            Key::Enter | Key::Escape => {
+                editor.editing_caption = None;
+                changed = true;
+                break;
+            }
+            _ => {}
+        }
+    }
+
+    if changed {
+        editor.panel_dirty = true;
+    }
+}
+
+// Rebuilds the editor whenever albums change or it's opened/closed
+pub fn refresh_album_editor_system(
+    mut commands: Commands,
+    mut editor: ResMut<AlbumEditor>,
+    collection: Res<PhotoCollection>,
+    export_job: Res<AlbumExportJob>,
+    photo_export_job: Res<PhotoExportJob>,
+    export_style: Res<PhotoExportStyle>,
+    achievement_progress: Res<AchievementProgress>,
+    panel_query: Query<Entity, With<AlbumEditorPanel>>,
+) {
+    if !editor.panel_dirty && !export_job.is_changed() && !photo_export_job.is_changed() {
+        return;
+    }
+    editor.panel_dirty = false;
+
+    for panel in &panel_query {
+        commands.entity(panel).despawn();
+    }
+
+    if !editor.is_open {
+        return;
+    }
+    if editor.exporting_photo {
+        spawn_photo_exporter(&mut commands, &editor, &collection, &photo_export_job, &export_style, &achievement_progress);
+    } else {
+        spawn_album_editor(&mut commands, &editor, &collection, &export_job);
+    }
+}
+
+fn spawn_editor_panel<'a>(commands: &'a mut Commands) -> EntityCommands<'a> {
+    commands.spawn((
+        Node {
+            width: Val::Percent(90.0),
+            height: Val::Percent(90.0),
+            position_type: PositionType::Absolute,
+            left: Val::Percent(5.0),
+            top: Val::Percent(5.0),
+            flex_direction: FlexDirection::Column,
+            padding: UiRect::all(Val::Px(16.0)),
+            row_gap: Val::Px(10.0),
+            border: UiRect::all(Val::Px(3.0)),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.95, 0.92, 0.88, 0.98)),
+        BorderColor(Color::srgb(0.6, 0.5, 0.4)),
+        ZIndex(50),
+        AlbumEditorPanel,
+    ))
+}
+
+fn spawn_album_editor(
+    commands: &mut Commands,
+    editor: &AlbumEditor,
+    collection: &PhotoCollection,
+    export_job: &AlbumExportJob,
+) {
+    let album = editor.selected_album.and_then(|index| collection.albums.get(index));
+
+    spawn_editor_panel(commands).with_children(|panel| {
+        // Header with album list
+        panel.spawn(Node {
+            width: Val::Percent(100.0),
+            flex_direction: FlexDirection::Row,
+            flex_wrap: FlexWrap::Wrap,
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(8.0),
+            row_gap: Val::Px(6.0),
+            ..default()
+        }).with_children(|header| {
+            header.spawn((
+                Text::new("Photo Albums"),
+                TextFont { font_size: 22.0, ..default() },
+                TextColor(Color::srgb(0.3, 0.2, 0.1)),
+                Node { margin: UiRect::right(Val::Px(12.0)), ..default() },
+            ));
+
+            for (index, existing) in collection.albums.iter().enumerate() {
+                let label = if editor.selected_album == Some(index) {
+                    format!("[{}]", existing.name)
+                } else {
+                    existing.name.clone()
+                };
+                spawn_editor_button(header, &label, AlbumEditorAction::SelectAlbum(index));
+            }
+            spawn_editor_button(header, "+ New Album", AlbumEditorAction::NewAlbum);
+            spawn_editor_button(header, "Export Photos", AlbumEditorAction::TogglePhotoExport);
+        });
+
+        let Some(album) = album else {
+            panel.spawn((
+                Text::new("Create an album, then click photos below to add them."),
+                TextFont { font_size: 14.0, ..default() },
+                TextColor(Color::srgb(0.5, 0.4, 0.3)),
+            ));
+            spawn_photo_picker(panel, collection, None);
+            return;
+        };
+
+        // Album controls
+        panel.spawn(Node {
+            width: Val::Percent(100.0),
+            flex_direction: FlexDirection::Row,
+            flex_wrap: FlexWrap::Wrap,
+            column_gap: Val::Px(8.0),
+            row_gap: Val::Px(6.0),
+            ..default()
+        }).with_children(|controls| {
+            spawn_editor_button(controls, &format!("Theme: {}", album.theme.name()), AlbumEditorAction::CycleTheme);
+            spawn_editor_button(controls, &format!("Layout: {}", album.layout.name()), AlbumEditorAction::CycleLayout);
+            spawn_editor_button(controls, "< Page", AlbumEditorAction::PreviousPage);
+            spawn_editor_button(controls, "Page >", AlbumEditorAction::NextPage);
+            spawn_editor_button(controls, "Export PNG", AlbumEditorAction::Export(ExportFormat::Png));
+            spawn_editor_button(controls, "Export PDF", AlbumEditorAction::Export(ExportFormat::Pdf));
+            spawn_editor_button(controls, "Delete Album", AlbumEditorAction::DeleteAlbum);
+        });
+
+        let status = match export_job.progress() {
+            Some((done, total)) => format!("Exporting... page {} of {}", (done + 1).min(total), total),
+            None => format!(
+                "Page {} of {} - click a caption to edit it, press Enter when done",
+                editor.page + 1,
+                album.page_count(),
+            ),
+        };
+        panel.spawn((
+            Text::new(status),
+            TextFont { font_size: 13.0, ..default() },
+            TextColor(Color::srgb(0.5, 0.4, 0.3)),
+        ));
+
+        spawn_page_preview(panel, editor, collection, album);
+        spawn_photo_picker(panel, collection, Some(album));
+    });
+}
+
+// Page preview drawn with the same layout the exporter composites
+fn spawn_page_preview(
+    panel: &mut ChildSpawnerCommands,
+    editor: &AlbumEditor,
+    collection: &PhotoCollection,
+    album: &PhotoAlbum,
+) {
+    let page_size = Vec2::new(PAGE_WIDTH as f32, PAGE_HEIGHT as f32);
+    let scale = PREVIEW_WIDTH / page_size.x;
+    let rects = album.layout.photo_rects(page_size);
+    let first_entry = editor.page * album.layout.photos_per_page();
+
+    panel.spawn((
+        Node {
+            width: Val::Px(PREVIEW_WIDTH),
+            height: Val::Px(page_size.y * scale),
+            align_self: AlignSelf::Center,
+            flex_shrink: 0.0,
+            border: UiRect::all(Val::Px(2.0)),
+            ..default()
+        },
+        BackgroundColor(album.theme.background()),
+        BorderColor(album.theme.frame()),
+    )).with_children(|page| {
+        page.spawn((
+            Text::new(album.name.clone()),
+            TextFont { font_size: 18.0, ..default() },
+            TextColor(album.theme.text()),
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(page_size.y * 0.03 * scale),
+                left: Val::Px(page_size.x * 0.04 * scale),
+                ..default()
+            },
+        ));
+
+        for (slot, entry) in album.page_entries(editor.page).iter().enumerate() {
+            let rect = rects[slot];
+            let entry_index = first_entry + slot;
+
+            let mut photo = page.spawn((
+                Button,
+                Node {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(rect.min.x * scale),
+                    top: Val::Px(rect.min.y * scale),
+                    width: Val::Px(rect.width() * scale),
+                    height: Val::Px(rect.height() * scale),
+                    border: UiRect::all(Val::Px(2.0)),
+                    ..default()
+                },
+                BorderColor(album.theme.frame()),
+                BackgroundColor(Color::srgb(0.8, 0.8, 0.8)),
+                AlbumEditorButton { action: AlbumEditorAction::RemoveEntry(entry_index) },
+            ));
+            if let Some(saved) = collection.get_photo(entry.photo_id) {
+                photo.insert(ImageNode::new(saved.image_handle.clone()));
+            }
+
+            let editing = editor.editing_caption == Some(entry_index);
+            let caption = if editing { format!("{}|", entry.caption) } else { entry.caption.clone() };
+            page.spawn((
+                Button,
+                Node {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(rect.min.x * scale),
+                    top: Val::Px((rect.max.y + 4.0) * scale),
+                    width: Val::Px(rect.width() * scale),
+                    justify_content: JustifyContent::Center,
+                    ..default()
+                },
+                BackgroundColor(if editing { Color::srgba(1.0, 1.0, 0.8, 0.6) } else { Color::NONE }),
+                AlbumEditorButton { action: AlbumEditorAction::EditCaption(entry_index) },
+            )).with_children(|caption_node| {
+                caption_node.spawn((
+                    Text::new(caption),
+                    TextFont { font_size: 11.0, ..default() },
+                    TextColor(album.theme.text()),
+                ));
+            });
+        }
+    });
+
+    panel.spawn((
+        Text::new("Click a photo on the page to remove it"),
+        TextFont { font_size: 11.0, ..default() },
+        TextColor(Color::srgb(0.5, 0.4, 0.3)),
+        Node { align_self: AlignSelf::Center, ..default() },
+    ));
+}
+
+// Single-photo export: pick a photo, style it and preview the framed result
+fn spawn_photo_exporter(
+    commands: &mut Commands,
+    editor: &AlbumEditor,
+    collection: &PhotoCollection,
+    export_job: &PhotoExportJob,
+    style: &PhotoExportStyle,
+    achievement_progress: &AchievementProgress,
+) {
+    let photo = editor.export_photo.and_then(|id| collection.get_photo(id));
+
+    spawn_editor_panel(commands).with_children(|panel| {
+        panel.spawn(Node {
+            width: Val::Percent(100.0),
+            flex_direction: FlexDirection::Row,
+            flex_wrap: FlexWrap::Wrap,
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(8.0),
+            row_gap: Val::Px(6.0),
+            ..default()
+        }).with_children(|header| {
+            header.spawn((
+                Text::new("Export Photo"),
+                TextFont { font_size: 22.0, ..default() },
+                TextColor(Color::srgb(0.3, 0.2, 0.1)),
+                Node { margin: UiRect::right(Val::Px(12.0)), ..default() },
+            ));
+            spawn_editor_button(header, &format!("Frame: {}", style.frame.name()), AlbumEditorAction::CycleFrame);
+            spawn_editor_button(header, &format!("Crop: {}", style.crop.name()), AlbumEditorAction::CycleCrop);
+            spawn_editor_button(header, &format!("Date: {}", if style.date_stamp { "On" } else { "Off" }), AlbumEditorAction::ToggleDateStamp);
+            spawn_editor_button(header, &format!("Species: {}", if style.species_stamp { "On" } else { "Off" }), AlbumEditorAction::ToggleSpeciesStamp);
+            spawn_editor_button(header, &format!("Tilt-Shift: {}", if style.tilt_shift { "On" } else { "Off" }), AlbumEditorAction::ToggleTiltShift);
+            if photo.is_some() {
+                spawn_editor_button(header, "Save PNG", AlbumEditorAction::ExportPhoto);
+                spawn_editor_button(header, "Use as Menu Backdrop", AlbumEditorAction::ExportMenuBackground);
+            }
+            spawn_editor_button(header, "Back to Albums", AlbumEditorAction::TogglePhotoExport);
+        });
+
+        let locked: Vec<String> = PhotoFrame::ALL.iter()
+            .filter(|frame| !frame.is_unlocked(achievement_progress))
+            .filter_map(|frame| frame.unlocked_by().map(|achievement| format!("{} ({})", frame.name(), achievement.name())))
+            .collect();
+        let status = if export_job.is_running() {
+            "Exporting...".to_string()
+        } else if photo.is_none() {
+            "Click a photo below to preview it".to_string()
+        } else if locked.is_empty() {
+            "All frames unlocked".to_string()
+        } else {
+            format!("Locked frames: {}", locked.join(", "))
+        };
+        panel.spawn((
+            Text::new(status),
+            TextFont { font_size: 13.0, ..default() },
+            TextColor(Color::srgb(0.5, 0.4, 0.3)),
+        ));
+
+        if let Some(photo) = photo {
+            spawn_export_preview(panel, style, photo);
+        }
+
+        panel.spawn((
+            Text::new(format!("Your Photos ({})", collection.photos.len())),
+            TextFont { font_size: 16.0, ..default() },
+            TextColor(Color::srgb(0.3, 0.2, 0.1)),
+        ));
+        panel.spawn(Node {
+            width: Val::Percent(100.0),
+            flex_direction: FlexDirection::Row,
+            flex_wrap: FlexWrap::Wrap,
+            column_gap: Val::Px(6.0),
+            row_gap: Val::Px(6.0),
+            overflow: Overflow::scroll_y(),
+            flex_grow: 1.0,
+            ..default()
+        }).with_children(|picker| {
+            for saved in &collection.photos {
+                let selected = editor.export_photo == Some(saved.id);
+                picker.spawn((
+                    Button,
+                    Node {
+                        width: Val::Px(96.0),
+                        height: Val::Px(72.0),
+                        border: UiRect::all(Val::Px(2.0)),
+                        ..default()
+                    },
+                    ImageNode::new(saved.image_handle.clone()),
+                    BorderColor(if selected { Color::srgb(0.9, 0.7, 0.2) } else { Color::srgb(0.6, 0.5, 0.4) }),
+                    AlbumEditorButton { action: AlbumEditorAction::SelectExportPhoto(saved.id) },
+                ));
+            }
+        });
+    });
+}
+
+// Framed, cropped and stamped the way the exporter will composite it
+fn spawn_export_preview(panel: &mut ChildSpawnerCommands, style: &PhotoExportStyle, photo: &SavedPhoto) {
+    let (_, full_canvas) = style.layout(1.0);
+    let scale = (PREVIEW_HEIGHT / full_canvas.y).min(PREVIEW_WIDTH / full_canvas.x);
+    let (photo_rect, canvas) = style.layout(scale);
+
+    panel.spawn((
+        Node {
+            width: Val::Px(canvas.x),
+            height: Val::Px(canvas.y),
+            align_self: AlignSelf::Center,
+            flex_shrink: 0.0,
+            ..default()
+        },
+        BackgroundColor(style.frame.color()),
+    )).with_children(|frame| {
+        let mut image = ImageNode::new(photo.image_handle.clone());
+        image.rect = Some(style.crop.source_rect(PHOTO_SOURCE_SIZE));
+        frame.spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(photo_rect.min.x),
+                top: Val::Px(photo_rect.min.y),
+                width: Val::Px(photo_rect.width()),
+                height: Val::Px(photo_rect.height()),
+                ..default()
+            },
+            image,
+        ));
+
+        if let Some(stamp) = style.stamp(photo) {
+            let margin = photo_rect.height() * 0.03;
+            frame.spawn((
+                Text::new(stamp),
+                TextFont { font_size: (photo_rect.height() * 0.045).max(9.0), ..default() },
+                TextColor(Color::srgba(1.0, 0.92, 0.75, 0.9)),
+                Node {
+                    position_type: PositionType::Absolute,
+                    right: Val::Px(canvas.x - photo_rect.max.x + margin),
+                    bottom: Val::Px(canvas.y - photo_rect.max.y + margin),
+                    ..default()
+                },
+            ));
+        }
+    });
+}
+
+fn spawn_photo_picker(panel: &mut ChildSpawnerCommands, collection: &PhotoCollection, album: Option<&PhotoAlbum>) {
+    panel.spawn((
+        Text::new(format!("Your Photos ({})", collection.photos.len())),
+        TextFont { font_size: 16.0, ..default() },
+        TextColor(Color::srgb(0.3, 0.2, 0.1)),
+    ));
+
+    panel.spawn(Node {
+        width: Val::Percent(100.0),
+        flex_direction: FlexDirection::Row,
+        flex_wrap: FlexWrap::Wrap,
+        column_gap: Val::Px(6.0),
+        row_gap: Val::Px(6.0),
+        overflow: Overflow::scroll_y(),
+        flex_grow: 1.0,
+        ..default()
+    }).with_children(|picker| {
+        for photo in &collection.photos {
+            let in_album = album.is_some_and(|album| album.contains(photo.id));
+            let mut thumbnail = picker.spawn((
+                Node {
+                    width: Val::Px(96.0),
+                    height: Val::Px(72.0),
+                    border: UiRect::all(Val::Px(2.0)),
+                    ..default()
+                },
+                ImageNode::new(photo.image_handle.clone())
+                    .with_color(if in_album { Color::srgba(1.0, 1.0, 1.0, 0.35) } else { Color::WHITE }),
+                BorderColor(if in_album { Color::srgb(0.5, 0.7, 0.5) } else { Color::srgb(0.6, 0.5, 0.4) }),
+            ));
+
+            if album.is_some() && !in_album {
+                thumbnail.insert((
+                    Button,
+                    AlbumEditorButton { action: AlbumEditorAction::AddPhoto(photo.id) },
+                ));
+            }
+        }
+    });
+}
+
+fn spawn_editor_button(parent: &mut ChildSpawnerCommands, label: &str, action: AlbumEditorAction) {
+    parent.spawn((
+        Button,
+        Node {
+            padding: UiRect::axes(Val::Px(10.0), Val::Px(6.0)),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        },
+        BackgroundColor(Color::srgb(0.6, 0.5, 0.4)),
+        BorderRadius::all(Val::Px(4.0)),
+        AlbumEditorButton { action },
+    )).with_children(|button| {
+        button.spawn((
+            Text::new(label),
+            TextFont { font_size: 13.0, ..default() },
+            TextColor(Color::WHITE),
+        ));
+    });
+}
@@ -0,0 +1,348 @@
+// Album Export - Offscreen page compositing and PNG/PDF contact sheets
+use bevy::prelude::*;
+use bevy::render::camera::ClearColorConfig;
+use bevy::render::view::RenderLayers;
+use bevy::text::TextBounds;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use crate::photo_mode::album::{AlbumLayout, AlbumTheme};
+use crate::photo_mode::offscreen::{capture_camera, write_png, OffscreenCapture};
+use crate::photo_mode::resources::PhotoCollection;
+use crate::journal::resources::FieldNotes;
+use crate::notifications::{resources::ShowNotificationEvent, components::NotificationType};
+
+pub const PAGE_WIDTH: u32 = 1600;
+pub const PAGE_HEIGHT: u32 = 1200;
+
+// Pages are composited on their own render layer so the sanctuary camera never sees them
+const ALBUM_RENDER_LAYER: usize = 7;
+// 1600px pages come out at 768x576pt (150 dpi) in the PDF
+const PDF_POINTS_PER_PIXEL: f32 = 0.48;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExportFormat {
+    Png,
+    Pdf,
+}
+
+#[derive(Event)]
+pub struct ExportAlbumEvent {
+    pub album_index: usize,
+    pub format: ExportFormat,
+}
+
+#[derive(Resource, Default)]
+pub struct AlbumExportJob {
+    active: Option<ActiveExport>,
+}
+
+impl AlbumExportJob {
+    // (pages finished, total pages) while an export is running
+    pub fn progress(&self) -> Option<(usize, usize)> {
+        self.active.as_ref().map(|export| (export.rendered_pages.len(), export.pages.len()))
+    }
+}
+
+struct ActiveExport {
+    album_name: String,
+    format: ExportFormat,
+    theme: AlbumTheme,
+    layout: AlbumLayout,
+    pages: Vec<Vec<(Handle<Image>, String)>>,
+    stage: ExportStage,
+    rendered_pages: Vec<Vec<u8>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ExportStage {
+    Compose,
+    Render,
+}
+
+#[derive(Component)]
+pub struct AlbumPageEntity;
+
+pub fn start_album_export_system(
+    mut export_events: EventReader<ExportAlbumEvent>,
+    mut job: ResMut<AlbumExportJob>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+    collection: Res<PhotoCollection>,
+    field_notes: Res<FieldNotes>,
+) {
+    for event in export_events.read() {
+        if job.active.is_some() {
+            warn!("Album export already in progress");
+            continue;
+        }
+
+        let Some(album) = collection.albums.get(event.album_index) else { continue };
+
+        let entries: Vec<(Handle<Image>, String)> = album.entries.iter()
+            .filter_map(|entry| {
+                collection.get_photo(entry.photo_id).map(|photo| {
+                    // The player's own notes and tags go under the caption
+                    let caption = match field_notes.photo_notes(photo) {
+                        Some(notes) => format!("{}\n{}", entry.caption, FieldNotes::summary(notes)),
+                        None => entry.caption.clone(),
+                    };
+                    (photo.image_handle.clone(), caption)
+                })
+            })
+            .collect();
+
+        if entries.is_empty() {
+            notification_events.write(ShowNotificationEvent {
+                notification: NotificationType::Info {
+                    message: format!("{} has no photos to export", album.name),
+                },
+            });
+            continue;
+        }
+
+        let pages = entries.chunks(album.layout.photos_per_page())
+            .map(|chunk| chunk.to_vec())
+            .collect();
+
+        job.active = Some(ActiveExport {
+            album_name: album.name.clone(),
+            format: event.format,
+            theme: album.theme,
+            layout: album.layout,
+            pages,
+            stage: ExportStage::Compose,
+            rendered_pages: Vec::new(),
+        });
+
+        info!("📖 Exporting album '{}' as {:?}", album.name, event.format);
+    }
+}
+
+// Steps the export one page at a time: compose the page scene, let it render, then read it back
+pub fn album_export_system(
+    mut commands: Commands,
+    mut job: ResMut<AlbumExportJob>,
+    mut images: ResMut<Assets<Image>>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+    page_query: Query<Entity, With<AlbumPageEntity>>,
+    mut capture_query: Query<&mut OffscreenCapture, With<AlbumPageEntity>>,
+) {
+    let Some(export) = job.bypass_change_detection().active.as_mut() else { return };
+
+    match export.stage {
+        ExportStage::Compose => {
+            for entity in &page_query {
+                commands.entity(entity).despawn();
+            }
+
+            if export.rendered_pages.len() < export.pages.len() {
+                spawn_page_scene(&mut commands, &mut images, export);
+                export.stage = ExportStage::Render;
+                return;
+            }
+
+            let result = write_album(export);
+            let message = match &result {
+                Ok(path) => format!("Album '{}' exported to {}", export.album_name, path.display()),
+                Err(e) => format!("Album export failed: {}", e),
+            };
+            match result {
+                Ok(_) => info!("{}", message),
+                Err(_) => error!("{}", message),
+            }
+
+            notification_events.write(ShowNotificationEvent {
+                notification: NotificationType::Info { message },
+            });
+            job.active = None;
+        }
+        ExportStage::Render => {
+            for mut capture in &mut capture_query {
+                if let Some(pixels) = capture.take_pixels() {
+                    export.rendered_pages.push(pixels);
+                    export.stage = ExportStage::Compose;
+                }
+            }
+        }
+    }
+}
+
+fn spawn_page_scene(commands: &mut Commands, images: &mut Assets<Image>, export: &ActiveExport) {
+    let page_index = export.rendered_pages.len();
+    let page_size = Vec2::new(PAGE_WIDTH as f32, PAGE_HEIGHT as f32);
+    let layer = RenderLayers::layer(ALBUM_RENDER_LAYER);
+
+    // Page space is top-left origin with y down; the page camera sits at the world origin
+    let to_world = |point: Vec2| Vec2::new(point.x - page_size.x / 2.0, page_size.y / 2.0 - point.y);
+
+    commands.spawn((
+        capture_camera(
+            images,
+            "album_page",
+            UVec2::new(PAGE_WIDTH, PAGE_HEIGHT),
+            -10,
+            ClearColorConfig::Custom(export.theme.background()),
+        ),
+        layer.clone(),
+        AlbumPageEntity,
+    ));
+
+    let title = format!("{}  ·  Page {} of {}", export.album_name, page_index + 1, export.pages.len());
+    let title_position = to_world(Vec2::new(page_size.x * 0.04, page_size.y * 0.04));
+    commands.spawn((
+        Text2d::new(title),
+        TextFont { font_size: 48.0, ..default() },
+        TextColor(export.theme.text()),
+        bevy::sprite::Anchor::TopLeft,
+        Transform::from_xyz(title_position.x, title_position.y, 1.0),
+        layer.clone(),
+        AlbumPageEntity,
+    ));
+
+    let rects = export.layout.photo_rects(page_size);
+    for ((image, caption), rect) in export.pages[page_index].iter().zip(rects) {
+        let center = to_world(rect.center());
+        let frame_width = (rect.width() * 0.015).max(4.0);
+
+        commands.spawn((
+            Sprite::from_color(export.theme.frame(), rect.size() + Vec2::splat(frame_width * 2.0)),
+            Transform::from_xyz(center.x, center.y, 0.0),
+            layer.clone(),
+            AlbumPageEntity,
+        ));
+
+        commands.spawn((
+            Sprite {
+                image: image.clone(),
+                custom_size: Some(rect.size()),
+                ..default()
+            },
+            Transform::from_xyz(center.x, center.y, 0.5),
+            layer.clone(),
+            AlbumPageEntity,
+        ));
+
+        let caption_position = to_world(Vec2::new(rect.center().x, rect.max.y + frame_width + 8.0));
+        commands.spawn((
+            Text2d::new(caption.clone()),
+            TextFont { font_size: (rect.height() * 0.07).clamp(18.0, 32.0), ..default() },
+            TextColor(export.theme.text()),
+            TextLayout::new_with_justify(JustifyText::Center),
+            TextBounds::new_horizontal(rect.width()),
+            bevy::sprite::Anchor::TopCenter,
+            Transform::from_xyz(caption_position.x, caption_position.y, 1.0),
+            layer.clone(),
+            AlbumPageEntity,
+        ));
+    }
+}
+
+pub fn album_export_directory() -> PathBuf {
+    dirs::data_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("AvianHaven")
+        .join("albums")
+}
+
+fn write_album(export: &ActiveExport) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    let file_stem: String = export.album_name.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_lowercase() } else { '_' })
+        .collect();
+    let directory = album_export_directory();
+    fs::create_dir_all(&directory)?;
+
+    match export.format {
+        ExportFormat::Png => {
+            let album_directory = directory.join(&file_stem);
+            fs::create_dir_all(&album_directory)?;
+
+            for (index, pixels) in export.rendered_pages.iter().enumerate() {
+                let path = album_directory.join(format!("page_{:02}.png", index + 1));
+                write_png(&path, UVec2::new(PAGE_WIDTH, PAGE_HEIGHT), pixels.clone())?;
+            }
+
+            Ok(album_directory)
+        }
+        ExportFormat::Pdf => {
+            let path = directory.join(format!("{}.pdf", file_stem));
+            write_pdf(&path, &export.rendered_pages, PAGE_WIDTH, PAGE_HEIGHT)?;
+            Ok(path)
+        }
+    }
+}
+
+// Minimal PDF writer - one full-bleed uncompressed RGB image per page
+fn write_pdf(path: &Path, pages: &[Vec<u8>], width: u32, height: u32) -> std::io::Result<()> {
+    let page_width = width as f32 * PDF_POINTS_PER_PIXEL;
+    let page_height = height as f32 * PDF_POINTS_PER_PIXEL;
+
+    let mut pdf: Vec<u8> = Vec::new();
+    let mut offsets: Vec<usize> = Vec::new();
+    pdf.extend_from_slice(b"%PDF-1.4\n%\xE2\xE3\xCF\xD3\n");
+
+    // Objects: 1 catalog, 2 page tree, then a page, content stream and image per page
+    let page_object = |index: usize| 3 + index * 3;
+    let kids: Vec<String> = (0..pages.len()).map(|index| format!("{} 0 R", page_object(index))).collect();
+
+    write_pdf_object(&mut pdf, &mut offsets, b"<< /Type /Catalog /Pages 2 0 R >>", None);
+    write_pdf_object(
+        &mut pdf,
+        &mut offsets,
+        format!("<< /Type /Pages /Kids [{}] /Count {} >>", kids.join(" "), pages.len()).as_bytes(),
+        None,
+    );
+
+    for (index, rgba) in pages.iter().enumerate() {
+        let content_object = page_object(index) + 1;
+        let image_object = page_object(index) + 2;
+
+        write_pdf_object(
+            &mut pdf,
+            &mut offsets,
+            format!(
+                "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {:.2} {:.2}] /Resources << /XObject << /Im0 {} 0 R >> >> /Contents {} 0 R >>",
+                page_width, page_height, image_object, content_object,
+            ).as_bytes(),
+            None,
+        );
+
+        let content = format!("q {:.2} 0 0 {:.2} 0 0 cm /Im0 Do Q", page_width, page_height);
+        write_pdf_object(&mut pdf, &mut offsets, b"<<", Some(content.as_bytes()));
+
+        let rgb: Vec<u8> = rgba.chunks(4).flat_map(|pixel| [pixel[0], pixel[1], pixel[2]]).collect();
+        write_pdf_object(
+            &mut pdf,
+            &mut offsets,
+            format!(
+                "<< /Type /XObject /Subtype /Image /Width {} /Height {} /ColorSpace /DeviceRGB /BitsPerComponent 8",
+                width, height,
+            ).as_bytes(),
+            Some(&rgb),
+        );
+    }
+
+    let xref_offset = pdf.len();
+    write!(pdf, "xref\n0 {}\n0000000000 65535 f \n", offsets.len() + 1)?;
+    for offset in &offsets {
+        write!(pdf, "{:010} 00000 n \n", offset)?;
+    }
+    write!(pdf, "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{}\n%%EOF\n", offsets.len() + 1, xref_offset)?;
+
+    fs::write(path, pdf)
+}
+
+// Appends the next numbered object. Streams pass their dictionary without the closing
+// `>>` so the length entry can be added.
+fn write_pdf_object(pdf: &mut Vec<u8>, offsets: &mut Vec<usize>, dictionary: &[u8], stream: Option<&[u8]>) {
+    offsets.push(pdf.len());
+    pdf.extend_from_slice(format!("{} 0 obj\n", offsets.len()).as_bytes());
+    pdf.extend_from_slice(dictionary);
+
+    if let Some(stream) = stream {
+        pdf.extend_from_slice(format!(" /Length {} >>\nstream\n", stream.len()).as_bytes());
+        pdf.extend_from_slice(stream);
+        pdf.extend_from_slice(b"\nendstream");
+    }
+
+    pdf.extend_from_slice(b"\nendobj\n");
+}
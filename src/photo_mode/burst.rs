@@ -0,0 +1,392 @@
+// Burst Shooting - Five quick frames per press, best one kept automatically, the rest reviewed on a strip
+use bevy::prelude::*;
+use crate::photo_mode::components::{CameraControls, PhotoScore, PhotoTakenEvent, PhotoTarget};
+use crate::photo_mode::resources::{PhotoCollection, PhotoModeSettings, SavedPhoto};
+use crate::photo_mode::disturbance::PhotoDisturbance;
+use crate::photo_mode::systems::capture_frame;
+use crate::animation::components::AnimatedBird;
+use crate::bird::BirdSpecies;
+use crate::bird_ai::components::{BirdAI, BirdState};
+use crate::catalog::resources::PlayerInventory;
+use crate::environment::components::Weather;
+use crate::environment::resources::{TimeState, WeatherState};
+use crate::journal::resources::ChecklistDate;
+use crate::notifications::{resources::ShowNotificationEvent, components::NotificationType};
+
+const BURST_FRAMES: usize = 5;
+// Five frames spread over half a second
+const FRAME_INTERVAL: f32 = 0.1;
+// Film and storage for every frame kept beyond the automatic best one
+const FILM_COST_PER_FRAME: u32 = 4;
+const THUMBNAIL_WIDTH: f32 = 128.0;
+const THUMBNAIL_HEIGHT: f32 = 96.0;
+
+struct BurstFrame {
+    image_handle: Handle<Image>,
+    score: PhotoScore,
+    species: Option<BirdSpecies>,
+    behavior: Option<BirdState>,
+    hour: f32,
+    weather: Weather,
+}
+
+// Resources
+#[derive(Resource, Default)]
+pub struct BurstSession {
+    frames: Vec<BurstFrame>,
+    shooting: bool,
+    frame_timer: Timer,
+    best: usize,
+    keep: Vec<bool>,
+    pub reviewing: bool,
+    dirty: bool,
+}
+
+impl BurstSession {
+    fn extra_frames(&self) -> usize {
+        self.keep.iter().enumerate().filter(|(index, keep)| **keep && *index != self.best).count()
+    }
+
+    fn close_review(&mut self) {
+        self.frames.clear();
+        self.keep.clear();
+        self.reviewing = false;
+        self.dirty = true;
+    }
+}
+
+// Components
+#[derive(Component)]
+pub struct BurstReviewRoot;
+
+#[derive(Component)]
+pub struct BurstFrameButton {
+    pub index: usize,
+}
+
+#[derive(Component)]
+pub struct BurstReviewButton {
+    pub action: BurstReviewAction,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BurstReviewAction {
+    KeepSelected,
+    KeepBestOnly,
+}
+
+pub fn burst_capture_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    settings: Res<PhotoModeSettings>,
+    mut session: ResMut<BurstSession>,
+    mut camera_query: Query<(&mut Camera, &CameraControls), With<PhotoTarget>>,
+    bird_query: Query<(&Transform, &AnimatedBird, &BirdState), With<BirdAI>>,
+    mut photo_events: EventWriter<PhotoTakenEvent>,
+    mut images: ResMut<Assets<Image>>,
+    mut commands: Commands,
+    time_state: Res<TimeState>,
+    mut disturbance: ResMut<PhotoDisturbance>,
+    gear: Res<crate::gear_care::GearCare>,
+    fog: Res<crate::weather_effects::fog::Fog>,
+    time: Res<Time>,
+    weather_state: Res<WeatherState>,
+) {
+    // Leaving photo mode mid-review keeps just the best frame, which is already saved
+    if !settings.is_active {
+        if session.reviewing {
+            session.close_review();
+        }
+        session.shooting = false;
+        return;
+    }
+    if !settings.burst_mode || session.reviewing {
+        return;
+    }
+
+    let starting = !session.shooting && keyboard.just_pressed(settings.capture_key);
+    if starting {
+        session.frames.clear();
+        session.shooting = true;
+        session.frame_timer = Timer::from_seconds(FRAME_INTERVAL, TimerMode::Repeating);
+    }
+    if !session.shooting {
+        return;
+    }
+
+    session.frame_timer.tick(time.delta());
+    let frame_due = starting || session.frame_timer.just_finished();
+
+    if frame_due && keyboard.pressed(settings.capture_key) && session.frames.len() < BURST_FRAMES {
+        let Ok((mut camera, camera_controls)) = camera_query.single_mut() else {
+            warn!("No photo target camera found");
+            session.shooting = false;
+            return;
+        };
+
+        let (image_handle, mut score, species, behavior) = capture_frame(
+            &mut camera,
+            camera_controls,
+            &bird_query,
+            &mut images,
+            &mut commands,
+            &time_state,
+        );
+        // Each frame of a flash burst fires the flash again
+        disturbance.penalize(&mut score);
+        gear.penalize(&mut score);
+        fog.adjust_photo(&mut score);
+        if settings.flash_enabled {
+            disturbance.flash();
+        }
+        session.frames.push(BurstFrame {
+            image_handle,
+            score,
+            species,
+            behavior,
+            hour: time_state.hour,
+            weather: weather_state.current_weather,
+        });
+    }
+
+    // Letting go of the shutter ends the burst early
+    if session.frames.len() < BURST_FRAMES && keyboard.pressed(settings.capture_key) {
+        return;
+    }
+    session.shooting = false;
+
+    let Some(best) = session.frames.iter().enumerate()
+        .max_by_key(|(_, frame)| frame.score.total_score)
+        .map(|(index, _)| index) else { return };
+
+    // The best frame counts as the shot, with the usual rewards
+    let best_frame = &session.frames[best];
+    photo_events.write(PhotoTakenEvent {
+        score: best_frame.score.clone(),
+        species: best_frame.species,
+        behavior: best_frame.behavior,
+        image_handle: best_frame.image_handle.clone(),
+    });
+    info!("Burst of {} frames, best frame {} scored {}", session.frames.len(), best + 1, best_frame.score.total_score);
+
+    if session.frames.len() > 1 {
+        session.best = best;
+        session.keep = (0..session.frames.len()).map(|index| index == best).collect();
+        session.reviewing = true;
+        session.dirty = true;
+    } else {
+        session.frames.clear();
+    }
+}
+
+pub fn burst_review_button_system(
+    frame_query: Query<(&Interaction, &BurstFrameButton), Changed<Interaction>>,
+    mut button_query: Query<(&Interaction, &BurstReviewButton, &mut BackgroundColor), Changed<Interaction>>,
+    mut session: ResMut<BurstSession>,
+    mut inventory: ResMut<PlayerInventory>,
+    mut collection: ResMut<PhotoCollection>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+    time: Res<Time>,
+    time_state: Res<TimeState>,
+) {
+    if !session.reviewing {
+        return;
+    }
+
+    for (interaction, frame_button) in frame_query.iter() {
+        if *interaction == Interaction::Pressed && frame_button.index != session.best {
+            if let Some(keep) = session.keep.get_mut(frame_button.index) {
+                *keep = !*keep;
+                session.dirty = true;
+            }
+        }
+    }
+
+    for (interaction, review_button, mut bg_color) in button_query.iter_mut() {
+        match *interaction {
+            Interaction::Pressed => {
+                *bg_color = Color::srgb(0.5, 0.7, 0.5).into();
+
+                match review_button.action {
+                    BurstReviewAction::KeepSelected => {
+                        let extras = session.extra_frames();
+                        let cost = extras as u32 * FILM_COST_PER_FRAME;
+                        if inventory.currency < cost {
+                            notification_events.write(ShowNotificationEvent {
+                                notification: NotificationType::Warning {
+                                    message: format!("Keeping {} extra frames costs {} coins", extras, cost),
+                                },
+                            });
+                            continue;
+                        }
+
+                        inventory.currency -= cost;
+                        let kept: Vec<usize> = (0..session.frames.len())
+                            .filter(|index| session.keep[*index] && *index != session.best)
+                            .collect();
+                        for index in kept {
+                            let frame = &session.frames[index];
+                            collection.add_photo(SavedPhoto {
+                                id: 0,
+                                species: frame.species,
+                                score: frame.score.clone(),
+                                image_handle: frame.image_handle.clone(),
+                                timestamp: time.elapsed().as_secs_f64(),
+                                taken_on: ChecklistDate { year: time_state.year, day_of_year: time_state.day_of_year },
+                                hour: frame.hour,
+                                weather: frame.weather,
+                                behavior: frame.behavior,
+                            });
+                        }
+                        if extras > 0 {
+                            notification_events.write(ShowNotificationEvent {
+                                notification: NotificationType::Info {
+                                    message: format!("Kept {} extra burst frames for {} coins", extras, cost),
+                                },
+                            });
+                        }
+                        session.close_review();
+                    }
+                    BurstReviewAction::KeepBestOnly => session.close_review(),
+                }
+            }
+            Interaction::Hovered => {
+                *bg_color = Color::srgb(0.7, 0.6, 0.5).into();
+            }
+            Interaction::None => {
+                *bg_color = Color::srgb(0.6, 0.5, 0.4).into();
+            }
+        }
+    }
+}
+
+pub fn refresh_burst_review_system(
+    mut commands: Commands,
+    mut session: ResMut<BurstSession>,
+    root_query: Query<Entity, With<BurstReviewRoot>>,
+) {
+    if !session.dirty {
+        return;
+    }
+    session.dirty = false;
+
+    for entity in root_query.iter() {
+        commands.entity(entity).despawn();
+    }
+    if !session.reviewing {
+        return;
+    }
+
+    let extras = session.extra_frames();
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(0.0),
+            right: Val::Px(0.0),
+            bottom: Val::Px(30.0),
+            justify_content: JustifyContent::Center,
+            ..default()
+        },
+        BurstReviewRoot,
+    )).with_children(|root| {
+        root.spawn((
+            Node {
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(8.0),
+                padding: UiRect::all(Val::Px(12.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.95, 0.92, 0.88, 0.9)),
+            BorderRadius::all(Val::Px(6.0)),
+        )).with_children(|panel| {
+            panel.spawn((
+                Text::new("Burst Review - best frame kept, click others to keep them too"),
+                TextFont { font_size: 15.0, ..default() },
+                TextColor(Color::srgb(0.3, 0.2, 0.1)),
+            ));
+
+            panel.spawn(Node {
+                flex_direction: FlexDirection::Row,
+                column_gap: Val::Px(8.0),
+                ..default()
+            }).with_children(|strip| {
+                for (index, frame) in session.frames.iter().enumerate() {
+                    let best = index == session.best;
+                    let border = if best {
+                        Color::srgb(0.85, 0.65, 0.2)
+                    } else if session.keep[index] {
+                        Color::srgb(0.3, 0.6, 0.3)
+                    } else {
+                        Color::srgb(0.5, 0.5, 0.5)
+                    };
+
+                    strip.spawn(Node {
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        row_gap: Val::Px(2.0),
+                        ..default()
+                    }).with_children(|cell| {
+                        cell.spawn((
+                            Button,
+                            Node {
+                                width: Val::Px(THUMBNAIL_WIDTH),
+                                height: Val::Px(THUMBNAIL_HEIGHT),
+                                border: UiRect::all(Val::Px(3.0)),
+                                ..default()
+                            },
+                            ImageNode::new(frame.image_handle.clone()),
+                            BorderColor(border),
+                            BackgroundColor(Color::srgb(0.8, 0.8, 0.8)),
+                            BurstFrameButton { index },
+                        ));
+                        cell.spawn((
+                            Text::new(if best {
+                                format!("{} pts  BEST", frame.score.total_score)
+                            } else {
+                                format!("{} pts", frame.score.total_score)
+                            }),
+                            TextFont { font_size: 12.0, ..default() },
+                            TextColor(if best { Color::srgb(0.6, 0.4, 0.1) } else { Color::srgb(0.4, 0.3, 0.2) }),
+                        ));
+                    });
+                }
+            });
+
+            panel.spawn(Node {
+                flex_direction: FlexDirection::Row,
+                column_gap: Val::Px(10.0),
+                ..default()
+            }).with_children(|buttons| {
+                let keep_label = if extras == 0 {
+                    "Done".to_string()
+                } else {
+                    format!("Keep {} More (-{})", extras, extras as u32 * FILM_COST_PER_FRAME)
+                };
+
+                for (label, action) in [
+                    (keep_label, BurstReviewAction::KeepSelected),
+                    ("Best Only".to_string(), BurstReviewAction::KeepBestOnly),
+                ] {
+                    buttons.spawn((
+                        Button,
+                        Node {
+                            padding: UiRect::axes(Val::Px(14.0), Val::Px(6.0)),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            ..default()
+                        },
+                        BackgroundColor(Color::srgb(0.6, 0.5, 0.4)),
+                        BorderRadius::all(Val::Px(6.0)),
+                        BurstReviewButton { action },
+                    )).with_children(|button| {
+                        button.spawn((
+                            Text::new(label),
+                            TextFont { font_size: 14.0, ..default() },
+                            TextColor(Color::WHITE),
+                        ));
+                    });
+                }
+            });
+        });
+    });
+}
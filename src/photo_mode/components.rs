@@ -65,6 +65,8 @@ impl Default for ScoreToast {
 pub struct PhotoTakenEvent {
     pub score: PhotoScore,
     pub species: Option<BirdSpecies>,
+    // What the subject was doing when the shutter fired
+    pub behavior: Option<crate::bird_ai::components::BirdState>,
     pub image_handle: Handle<Image>,
 }
 
@@ -0,0 +1,415 @@
+// Photo Critique - Optional walkthrough of what went wrong with a low-scoring shot
+//
+// A shot scoring well under what the player usually manages, or under a floor for newcomers,
+// offers a critique for a few seconds after the score toast. Opening it shows the photo with
+// numbered marks where the problems are - a subject cut off by the frame edge, parked dead center,
+// too dark for the light, soft or caught doing nothing - each with the setting change that would
+// have helped. The first couple of times an issue comes up it is explained in full; after that
+// only the suggestion is shown, since the player has heard the lesson.
+use bevy::prelude::*;
+use std::collections::{HashMap, VecDeque};
+use crate::bird::{Bird, BIRD_HALF_EXTENT};
+use crate::bird_ai::components::BirdState;
+use crate::environment::resources::TimeState;
+use crate::photo_mode::components::{CameraControls, PhotoScore, PhotoTakenEvent, PhotoTarget};
+use crate::photo_mode::resources::PhotoModeSettings;
+
+// Shots under this always offer a critique, whatever the player's average
+const LOW_SCORE_FLOOR: u32 = 80;
+// Or under this share of the recent average
+const LOW_SCORE_RATIO: f32 = 0.6;
+const RECENT_SHOTS: usize = 10;
+const OFFER_SECONDS: f32 = 6.0;
+// Times an issue is explained in full before the critique sticks to the suggestion
+const LESSON_REPEATS: u32 = 2;
+const MAX_MARKS: usize = 4;
+// Photos render at 800x600, see new_photo_texture
+const PHOTO_SIZE: Vec2 = Vec2::new(800.0, 600.0);
+const PREVIEW_WIDTH: f32 = 480.0;
+const PREVIEW_HEIGHT: f32 = 360.0;
+const MARK_SIZE: f32 = 30.0;
+const MARK_COLOR: Color = Color::srgb(1.0, 0.45, 0.3);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CritiqueIssue {
+    NoSubject,
+    CutOff,
+    DeadCenter,
+    TooSmall,
+    Underexposed,
+    Overexposed,
+    OutOfFocus,
+    HarshLight,
+    QuietMoment,
+}
+
+impl CritiqueIssue {
+    pub fn title(&self) -> &'static str {
+        match self {
+            Self::NoSubject => "No bird in frame",
+            Self::CutOff => "Subject cut off",
+            Self::DeadCenter => "Subject dead center",
+            Self::TooSmall => "Subject too small",
+            Self::Underexposed => "Underexposed",
+            Self::Overexposed => "Overexposed",
+            Self::OutOfFocus => "Out of focus",
+            Self::HarshLight => "Harsh midday light",
+            Self::QuietMoment => "Nothing happening",
+        }
+    }
+
+    fn lesson(&self) -> &'static str {
+        match self {
+            Self::NoSubject => "Every score starts from the bird, so a frame without one earns almost nothing.",
+            Self::CutOff => "A frame edge through the bird reads as a mistake; leave some space around it.",
+            Self::DeadCenter => "A subject in the exact middle looks static. Placing it where the thirds lines cross gives the shot room to breathe.",
+            Self::TooSmall => "Zoomed out this far, the bird is a speck and its field marks are lost.",
+            Self::Underexposed => "The sensor needs more light than the scene is giving it; dark shots lose detail in the feathers.",
+            Self::Overexposed => "Too much sensitivity for the light blows out highlights and adds noise.",
+            Self::OutOfFocus => "The bird sat outside the depth of field. Wide apertures keep only a thin slice sharp.",
+            Self::HarshLight => "Around noon the sun is high and flat, washing out color and casting hard shadows.",
+            Self::QuietMoment => "Behavior makes a photo. A bird eating, bathing or displaying tells a story a wandering one doesn't.",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CritiqueMark {
+    pub issue: CritiqueIssue,
+    // Where on the photo, 0-1 from the top left; None for problems with the whole frame
+    pub at: Option<Vec2>,
+    pub suggestion: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct Critique {
+    pub image: Handle<Image>,
+    pub score: u32,
+    pub marks: Vec<CritiqueMark>,
+}
+
+// Resources
+#[derive(Resource, Default)]
+pub struct PhotoCritique {
+    // Latest low-scoring shot's critique, while it's on offer or open
+    pub pending: Option<Critique>,
+    pub open: bool,
+    offered_for: f32,
+    recent_scores: VecDeque<u32>,
+    explained: HashMap<CritiqueIssue, u32>,
+}
+
+impl PhotoCritique {
+    fn threshold(&self) -> u32 {
+        if self.recent_scores.is_empty() {
+            return LOW_SCORE_FLOOR;
+        }
+        let average = self.recent_scores.iter().sum::<u32>() as f32 / self.recent_scores.len() as f32;
+        LOW_SCORE_FLOOR.max((average * LOW_SCORE_RATIO) as u32)
+    }
+}
+
+// Components
+#[derive(Component)]
+pub struct CritiqueOfferText;
+
+#[derive(Component)]
+pub struct CritiqueOverlay;
+
+/// Finds what held the shot back, from its score breakdown and the view it was taken with
+pub fn critique_photo(
+    score: &PhotoScore,
+    camera: (&Transform, f32, &CameraControls),
+    subject: Option<(&Transform, BirdState)>,
+    time_state: &TimeState,
+) -> Vec<CritiqueMark> {
+    let (camera_transform, scale, controls) = camera;
+    let mut marks = Vec::new();
+    let whole_frame = |issue: CritiqueIssue, suggestion: String| CritiqueMark { issue, at: None, suggestion };
+
+    // Position in the photo, from the top left
+    let frame = PHOTO_SIZE * scale;
+    let in_photo = |transform: &Transform| {
+        let offset = transform.translation.truncate() - camera_transform.translation.truncate();
+        Vec2::new(offset.x / frame.x + 0.5, 0.5 - offset.y / frame.y)
+    };
+
+    let framed = subject
+        .map(|(transform, _)| (transform, in_photo(transform)))
+        .filter(|(_, at)| (0.0..=1.0).contains(&at.x) && (0.0..=1.0).contains(&at.y));
+    match framed {
+        Some((transform, at)) => {
+            let extent = Vec2::splat(BIRD_HALF_EXTENT * transform.scale.y.abs()) / frame;
+            if at.x < extent.x || at.x > 1.0 - extent.x || at.y < extent.y || at.y > 1.0 - extent.y {
+                marks.push(CritiqueMark {
+                    issue: CritiqueIssue::CutOff,
+                    at: Some(at.clamp(Vec2::ZERO, Vec2::ONE)),
+                    suggestion: "Pan toward the bird or zoom out a little (mouse wheel) before the shutter".to_string(),
+                });
+            } else if (at - Vec2::splat(0.5)).length() < 0.06 && score.composition_score < 20 {
+                marks.push(CritiqueMark {
+                    issue: CritiqueIssue::DeadCenter,
+                    at: Some(at),
+                    suggestion: "Turn on the grid (G) and frame the bird on a thirds intersection".to_string(),
+                });
+            }
+            if controls.zoom_level < 1.5 {
+                marks.push(CritiqueMark {
+                    issue: CritiqueIssue::TooSmall,
+                    at: Some(at),
+                    suggestion: format!("Zoom in from {:.1}x to between 1.5x and 3x (mouse wheel)", controls.zoom_level),
+                });
+            }
+            if score.clarity_score < 15 {
+                let suggestion = if controls.aperture < 5.6 {
+                    format!("Stop down from f/{:.1} to around f/8 (E) for more depth of field", controls.aperture)
+                } else {
+                    "Hold on the bird until it settles; its distance fell outside the focus plane".to_string()
+                };
+                marks.push(CritiqueMark { issue: CritiqueIssue::OutOfFocus, at: Some(at), suggestion });
+            }
+        }
+        None => marks.push(whole_frame(CritiqueIssue::NoSubject,
+            "Center a bird in the viewfinder reticle before pressing the shutter".to_string())),
+    }
+
+    // The ISO the technical score looks for in this light
+    let daylight = time_state.daylight_factor();
+    let ideal_iso = if daylight > 0.8 { 400.0 } else if daylight > 0.5 { 800.0 } else { 1600.0 };
+    if controls.iso < ideal_iso / 2.0 || (daylight < 0.5 && controls.exposure < -0.5) {
+        marks.push(whole_frame(CritiqueIssue::Underexposed,
+            format!("Raise ISO from {:.0} toward {:.0} (D) or exposure (W)", controls.iso, ideal_iso)));
+    } else if controls.iso > ideal_iso * 2.0 || (daylight > 0.8 && controls.exposure > 1.0) {
+        marks.push(whole_frame(CritiqueIssue::Overexposed,
+            format!("Lower ISO from {:.0} toward {:.0} (A) or exposure (S)", controls.iso, ideal_iso)));
+    }
+
+    if (11.0..=14.0).contains(&time_state.hour) && score.lighting_score < 20 {
+        marks.push(whole_frame(CritiqueIssue::HarshLight,
+            "Come back in the golden hours, 6-8 in the morning or 18-20 in the evening".to_string()));
+    }
+
+    if let Some((transform, state)) = subject.filter(|_| score.behavior_score <= 25) {
+        marks.push(CritiqueMark {
+            issue: CritiqueIssue::QuietMoment,
+            at: Some(in_photo(transform).clamp(Vec2::ZERO, Vec2::ONE)),
+            suggestion: format!("Wait for it to feed, bathe or display; it was {}", format!("{:?}", state).to_lowercase()),
+        });
+    }
+
+    marks.truncate(MAX_MARKS);
+    marks
+}
+
+// Single shots scoring low offer a critique; the scene is read as the event arrives, like the
+// research log does, so it runs right after the shutter
+#[allow(clippy::too_many_arguments)]
+pub fn critique_offer_system(
+    mut commands: Commands,
+    mut photo_events: EventReader<PhotoTakenEvent>,
+    mut critique: ResMut<PhotoCritique>,
+    settings: Res<PhotoModeSettings>,
+    camera_query: Query<(&Transform, &Projection, &CameraControls), With<PhotoTarget>>,
+    bird_query: Query<(&Transform, &BirdState), With<Bird>>,
+    time_state: Res<TimeState>,
+    offer_query: Query<Entity, With<CritiqueOfferText>>,
+) {
+    for event in photo_events.read() {
+        let total = event.score.total_score;
+        let threshold = critique.threshold();
+        critique.recent_scores.push_back(total);
+        if critique.recent_scores.len() > RECENT_SHOTS {
+            critique.recent_scores.pop_front();
+        }
+
+        // Bursts pick their best frame after the fact, when the scene has already moved on
+        if total >= threshold || !settings.is_active || settings.burst_mode || critique.open {
+            continue;
+        }
+        let Ok((camera_transform, projection, controls)) = camera_query.single() else { continue };
+        let scale = match projection {
+            Projection::Orthographic(ortho) => ortho.scale,
+            _ => 1.0,
+        };
+        let center = camera_transform.translation.truncate();
+        let subject = bird_query.iter()
+            .min_by(|(a, _), (b, _)| a.translation.truncate().distance(center).total_cmp(&b.translation.truncate().distance(center)))
+            .map(|(transform, state)| (transform, *state));
+
+        let marks = critique_photo(&event.score, (camera_transform, scale, controls), subject, &time_state);
+        if marks.is_empty() {
+            continue;
+        }
+        critique.pending = Some(Critique { image: event.image_handle.clone(), score: total, marks });
+        critique.offered_for = 0.0;
+
+        for offer in &offer_query {
+            commands.entity(offer).despawn();
+        }
+        commands.spawn((
+            Text::new(format!("Only {} points. Press / to see what held the shot back", total)),
+            TextFont { font_size: 14.0, ..default() },
+            TextColor(Color::WHITE),
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(160.0),
+                right: Val::Px(50.0),
+                width: Val::Px(300.0),
+                padding: UiRect::all(Val::Px(8.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.7)),
+            BorderRadius::all(Val::Px(4.0)),
+            Pickable::IGNORE,
+            CritiqueOfferText,
+            StateScoped(crate::AppState::Playing),
+        ));
+    }
+}
+
+// Opens the offered critique, closes it again, and lets unanswered offers lapse
+pub fn critique_toggle_system(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut critique: ResMut<PhotoCritique>,
+    settings: Res<PhotoModeSettings>,
+    offer_query: Query<Entity, With<CritiqueOfferText>>,
+    overlay_query: Query<Entity, With<CritiqueOverlay>>,
+    time: Res<Time>,
+) {
+    let close = critique.open && (!settings.is_active || keyboard.just_pressed(settings.critique_key));
+    if close {
+        critique.open = false;
+        critique.pending = None;
+        for overlay in &overlay_query {
+            commands.entity(overlay).despawn();
+        }
+        return;
+    }
+    if critique.open || critique.pending.is_none() {
+        return;
+    }
+
+    critique.offered_for += time.delta_secs();
+    let lapsed = critique.offered_for >= OFFER_SECONDS || !settings.is_active;
+    let accepted = !lapsed && keyboard.just_pressed(settings.critique_key);
+    if !lapsed && !accepted {
+        return;
+    }
+    for offer in &offer_query {
+        commands.entity(offer).despawn();
+    }
+    if lapsed {
+        critique.pending = None;
+        return;
+    }
+
+    let Some(pending) = critique.pending.clone() else { return };
+    let lessons: Vec<bool> = pending.marks.iter().map(|mark| {
+        let explained = critique.explained.entry(mark.issue).or_insert(0);
+        *explained += 1;
+        *explained <= LESSON_REPEATS
+    }).collect();
+    critique.open = true;
+    spawn_critique_overlay(&mut commands, &pending, &lessons);
+}
+
+fn spawn_critique_overlay(commands: &mut Commands, critique: &Critique, lessons: &[bool]) {
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.6)),
+        GlobalZIndex(30),
+        CritiqueOverlay,
+        StateScoped(crate::AppState::Playing),
+    )).with_children(|overlay| {
+        overlay.spawn((
+            Node {
+                flex_direction: FlexDirection::Row,
+                column_gap: Val::Px(20.0),
+                padding: UiRect::all(Val::Px(20.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.08, 0.08, 0.1, 0.95)),
+            BorderRadius::all(Val::Px(8.0)),
+        )).with_children(|panel| {
+            // The photo, with numbered rings on the problem spots
+            panel.spawn((
+                Node {
+                    width: Val::Px(PREVIEW_WIDTH),
+                    height: Val::Px(PREVIEW_HEIGHT),
+                    ..default()
+                },
+                ImageNode::new(critique.image.clone()),
+            )).with_children(|photo| {
+                for (index, mark) in critique.marks.iter().enumerate() {
+                    let Some(at) = mark.at else { continue };
+                    photo.spawn((
+                        Node {
+                            position_type: PositionType::Absolute,
+                            left: Val::Px(at.x * PREVIEW_WIDTH - MARK_SIZE / 2.0),
+                            top: Val::Px(at.y * PREVIEW_HEIGHT - MARK_SIZE / 2.0),
+                            width: Val::Px(MARK_SIZE),
+                            height: Val::Px(MARK_SIZE),
+                            border: UiRect::all(Val::Px(3.0)),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            ..default()
+                        },
+                        BorderColor(MARK_COLOR),
+                        BorderRadius::all(Val::Percent(50.0)),
+                    )).with_children(|ring| {
+                        ring.spawn((
+                            Text::new(format!("{}", index + 1)),
+                            TextFont { font_size: 13.0, ..default() },
+                            TextColor(MARK_COLOR),
+                        ));
+                    });
+                }
+            });
+
+            panel.spawn(Node {
+                width: Val::Px(340.0),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(10.0),
+                ..default()
+            }).with_children(|notes| {
+                notes.spawn((
+                    Text::new(format!("Critique - {} points", critique.score)),
+                    TextFont { font_size: 20.0, ..default() },
+                    TextColor(Color::WHITE),
+                ));
+                for (index, mark) in critique.marks.iter().enumerate() {
+                    notes.spawn((
+                        Text::new(format!("{}. {}", index + 1, mark.issue.title())),
+                        TextFont { font_size: 15.0, ..default() },
+                        TextColor(MARK_COLOR),
+                    ));
+                    if lessons.get(index).copied().unwrap_or(false) {
+                        notes.spawn((
+                            Text::new(mark.issue.lesson()),
+                            TextFont { font_size: 12.0, ..default() },
+                            TextColor(Color::srgb(0.8, 0.8, 0.8)),
+                        ));
+                    }
+                    notes.spawn((
+                        Text::new(format!("Try: {}", mark.suggestion)),
+                        TextFont { font_size: 12.0, ..default() },
+                        TextColor(Color::srgb(0.95, 0.9, 0.7)),
+                    ));
+                }
+                notes.spawn((
+                    Text::new("Press / to close"),
+                    TextFont { font_size: 11.0, ..default() },
+                    TextColor(Color::srgb(0.6, 0.6, 0.6)),
+                ));
+            });
+        });
+    });
+}
@@ -0,0 +1,277 @@
+// Depth of Field - Aperture blur driven by where things stand in the 2.5D yard
+//
+// Everything drawn in the yard gets a depth from its pseudo-3D placement: the further up the lawn
+// something stands, the further it is from the lens, and raised feeders and perches count from the
+// ground where their shadow falls rather than where they're drawn. Parallax backdrops sit beyond
+// the horizon by how little they move with the camera, the sky furthest of all. Focus follows the
+// bird nearest the middle of the view. When a photo is taken the drawn entities are split into
+// bands by how much the aperture blurs them, each band renders on its own layer in the same frame,
+// and the bands are blurred and laid over each other from the back to the front before replacing
+// the photo's pixels. A shot where nothing falls out of focus keeps its plain capture.
+use bevy::prelude::*;
+use bevy::render::camera::ClearColorConfig;
+use bevy::render::view::RenderLayers;
+use bevy_hanabi::prelude::ParticleEffect;
+use crate::bird::Bird;
+use crate::elevation::{Elevation, ElevationShadow};
+use crate::environment::components::ParallaxLayer;
+use crate::photo_mode::offscreen::{capture_camera, OffscreenCapture};
+use crate::photo_mode::components::{CameraControls, PhotoTarget};
+use crate::photo_mode::photo_export::PHOTO_SOURCE_SIZE;
+use crate::photo_mode::resources::PhotoModeSettings;
+use crate::photo_mode::tilt_shift::box_blur;
+
+// The front edge of the lawn and its distance from the lens; each world unit up the screen is one further
+const YARD_FRONT_Y: f32 = -400.0;
+const YARD_FRONT_DEPTH: f32 = 100.0;
+// Where the lawn meets the distant treeline
+const HORIZON_Y: f32 = 170.0;
+// Blur radius in photo pixels at f/1 for something infinitely far behind the focus
+const BLUR_SCALE: f32 = 14.0;
+pub const MAX_BLUR_RADIUS: f32 = 10.0;
+// Radii a band can be blurred by; each entity rounds to the nearest
+const BLUR_STEPS: [usize; 5] = [0, 1, 3, 6, 10];
+// Bands use consecutive layers from here; sorted by depth there are at most two runs per step
+const DEPTH_LAYER_BASE: usize = 10;
+// How quickly focus pulls onto a new subject, and how close to the middle a bird must be to get it
+const FOCUS_SPEED: f32 = 6.0;
+const AUTOFOCUS_RANGE: f32 = 250.0;
+
+/// Distance from the lens to a point standing on the lawn at this world height
+pub fn yard_depth(ground_y: f32) -> f32 {
+    YARD_FRONT_DEPTH + ground_y.clamp(YARD_FRONT_Y, HORIZON_Y) - YARD_FRONT_Y
+}
+
+/// Depth of a drawn entity; `raised_by` is how far above the ground its elevation tier holds it
+pub fn scene_depth(position: Vec3, raised_by: f32, parallax: Option<&ParallaxLayer>) -> f32 {
+    let Some(layer) = parallax else {
+        return yard_depth(position.y - raised_by);
+    };
+    // A layer that moves 70% with the camera is a little over three times the horizon's distance away
+    let world_follow = 1.0 - layer.camera_follow;
+    if world_follow <= 0.0 {
+        f32::INFINITY
+    } else {
+        yard_depth(HORIZON_Y) / world_follow
+    }
+}
+
+/// Blur radius in photo pixels for something at `depth` with the lens focused at `focus`
+pub fn blur_radius(depth: f32, focus: f32, aperture: f32) -> f32 {
+    let focus = focus.max(YARD_FRONT_DEPTH);
+    let defocus = if depth.is_finite() { (depth - focus).abs() / depth } else { 1.0 };
+    (defocus * BLUR_SCALE / aperture.max(1.0)).min(MAX_BLUR_RADIUS)
+}
+
+fn blur_step(radius: f32) -> usize {
+    BLUR_STEPS.iter()
+        .copied()
+        .min_by(|a, b| (*a as f32 - radius).abs().total_cmp(&(*b as f32 - radius).abs()))
+        .unwrap_or(0)
+}
+
+// Resources
+#[derive(Resource, Default)]
+pub struct DepthOfFieldJob {
+    pending: Option<PendingCapture>,
+    active: Option<ActiveDepthOfField>,
+}
+
+impl DepthOfFieldJob {
+    /// Queues a just-captured photo to have its out-of-focus layers blurred
+    pub fn request(&mut self, photo: Handle<Image>, focus: f32, aperture: f32) {
+        self.pending = Some(PendingCapture { photo, focus, aperture });
+    }
+}
+
+struct PendingCapture {
+    photo: Handle<Image>,
+    focus: f32,
+    aperture: f32,
+}
+
+struct ActiveDepthOfField {
+    photo: Handle<Image>,
+    bands: Vec<DepthBand>,
+    // Entities given a band layer, which loses it again once the bands are read back
+    layered: Vec<Entity>,
+}
+
+// Entities at neighbouring depths that share a blur, back to front
+struct DepthBand {
+    camera: Entity,
+    radius: usize,
+    pixels: Option<Vec<u8>>,
+}
+
+// Components
+#[derive(Component)]
+pub struct DepthOfFieldEntity;
+
+type DrawnQuery<'w, 's> = Query<'w, 's,
+    (Entity, &'static GlobalTransform, Option<&'static ChildOf>, Has<ElevationShadow>, Option<&'static ParallaxLayer>),
+    (Or<(With<Sprite>, With<Text2d>, With<ParticleEffect>)>, Without<RenderLayers>)>;
+
+// Eases focus onto the bird nearest the middle of the view, or the lawn there when none is close
+pub fn autofocus_system(
+    settings: Res<PhotoModeSettings>,
+    mut camera_query: Query<(&Transform, &mut CameraControls), With<PhotoTarget>>,
+    bird_query: Query<&Transform, (With<Bird>, Without<PhotoTarget>)>,
+    time: Res<Time>,
+) {
+    if !settings.is_active {
+        return;
+    }
+    let Ok((camera_transform, mut controls)) = camera_query.single_mut() else { return };
+
+    let center = camera_transform.translation.truncate();
+    let subject_y = bird_query.iter()
+        .map(|transform| transform.translation.truncate())
+        .filter(|position| position.distance(center) < AUTOFOCUS_RANGE)
+        .min_by(|a, b| a.distance(center).total_cmp(&b.distance(center)))
+        .map_or(center.y, |position| position.y);
+
+    let blend = 1.0 - (-FOCUS_SPEED * time.delta_secs()).exp();
+    let target = yard_depth(subject_y);
+    controls.focus_distance += (target - controls.focus_distance) * blend;
+}
+
+pub fn start_depth_of_field_system(
+    mut commands: Commands,
+    mut job: ResMut<DepthOfFieldJob>,
+    mut images: ResMut<Assets<Image>>,
+    camera_query: Query<(&Transform, &Projection), With<PhotoTarget>>,
+    drawn_query: DrawnQuery,
+    elevation_query: Query<&Elevation>,
+) {
+    let Some(capture) = job.pending.take() else { return };
+    if job.active.is_some() {
+        warn!("Depth of field is still developing the previous photo, this one stays sharp");
+        return;
+    }
+    let Ok((transform, projection)) = camera_query.single() else { return };
+
+    // Children of a raised object stand where it does; its shadow is already drawn on the ground
+    let raised_by = |entity: Entity, child_of: Option<&ChildOf>, shadow: bool| {
+        let owner = match child_of {
+            Some(child_of) if !shadow && !elevation_query.contains(entity) => child_of.parent(),
+            _ => entity,
+        };
+        elevation_query.get(owner).map_or(0.0, |elevation| elevation.tier.height())
+    };
+    let mut drawn: Vec<(Entity, f32)> = drawn_query.iter()
+        .map(|(entity, global, child_of, shadow, parallax)| {
+            (entity, scene_depth(global.translation(), raised_by(entity, child_of, shadow), parallax))
+        })
+        .collect();
+    drawn.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+    // Blur falls toward the focus and rises past it, so equal blurs sit in runs
+    let mut runs: Vec<(usize, Vec<Entity>)> = Vec::new();
+    for (entity, depth) in drawn {
+        let step = blur_step(blur_radius(depth, capture.focus, capture.aperture));
+        match runs.last_mut() {
+            Some((radius, entities)) if *radius == step => entities.push(entity),
+            _ => runs.push((step, vec![entity])),
+        }
+    }
+    if runs.iter().all(|(radius, _)| *radius == 0) {
+        return;
+    }
+
+    let size = PHOTO_SOURCE_SIZE.as_uvec2();
+    let mut bands = Vec::with_capacity(runs.len());
+    let mut layered = Vec::new();
+    for (index, (radius, entities)) in runs.into_iter().enumerate() {
+        let layer = DEPTH_LAYER_BASE + index;
+        let camera = commands.spawn((
+            capture_camera(&mut images, "depth_band", size, -14, ClearColorConfig::Custom(Color::NONE)),
+            projection.clone(),
+            *transform,
+            bevy_light_2d::prelude::Light2d,
+            RenderLayers::layer(layer),
+            DepthOfFieldEntity,
+        )).id();
+        // Still on the default layer so the yard on screen doesn't change
+        for entity in &entities {
+            commands.entity(*entity).insert(RenderLayers::from_layers(&[0, layer]));
+        }
+        layered.extend(entities);
+        bands.push(DepthBand { camera, radius, pixels: None });
+    }
+
+    debug!("Depth of field at f/{:.1} focused {:.0} deep across {} bands", capture.aperture, capture.focus, bands.len());
+    job.active = Some(ActiveDepthOfField {
+        photo: capture.photo,
+        bands,
+        layered,
+    });
+}
+
+// Waits for every band to be read back, then blurs and stacks them into the photo
+pub fn depth_of_field_system(
+    mut commands: Commands,
+    mut job: ResMut<DepthOfFieldJob>,
+    mut images: ResMut<Assets<Image>>,
+    clear_color: Res<ClearColor>,
+    scene_query: Query<Entity, With<DepthOfFieldEntity>>,
+    mut capture_query: Query<&mut OffscreenCapture, With<DepthOfFieldEntity>>,
+) {
+    let Some(active) = job.bypass_change_detection().active.as_mut() else { return };
+
+    let ready = active.bands.iter()
+        .all(|band| capture_query.get(band.camera).is_ok_and(|capture| capture.is_ready()));
+    if !ready {
+        return;
+    }
+    for band in &mut active.bands {
+        band.pixels = capture_query.get_mut(band.camera).ok().and_then(|mut capture| capture.take_pixels());
+    }
+
+    for entity in &scene_query {
+        commands.entity(entity).despawn();
+    }
+    for entity in &active.layered {
+        if let Ok(mut entity_commands) = commands.get_entity(*entity) {
+            entity_commands.remove::<RenderLayers>();
+        }
+    }
+
+    let size = PHOTO_SOURCE_SIZE.as_uvec2();
+    let pixels = stack_bands(&active.bands, size, clear_color.0);
+    if let Some(photo) = images.get_mut(&active.photo) {
+        photo.data = Some(pixels);
+    }
+    job.active = None;
+}
+
+// Lays each band over the ones behind it, blurring its color and coverage together
+fn stack_bands(bands: &[DepthBand], size: UVec2, background: Color) -> Vec<u8> {
+    let background = background.to_srgba().to_u8_array();
+    let mut canvas: Vec<f32> = background.iter()
+        .map(|channel| *channel as f32)
+        .cycle()
+        .take(size.x as usize * size.y as usize * 4)
+        .collect();
+
+    for band in bands {
+        let Some(pixels) = &band.pixels else { continue };
+        // Premultiplied, so transparent edges don't blur in as black
+        let mut layer: Vec<u8> = pixels.chunks_exact(4)
+            .flat_map(|pixel| {
+                let alpha = pixel[3] as u32;
+                [0, 1, 2].map(|c| (pixel[c] as u32 * alpha / 255) as u8).into_iter().chain([pixel[3]])
+            })
+            .collect();
+        if band.radius > 0 {
+            layer = box_blur(&layer, size.x as usize, size.y as usize, band.radius);
+        }
+        for (target, source) in canvas.chunks_exact_mut(4).zip(layer.chunks_exact(4)) {
+            let cover = source[3] as f32 / 255.0;
+            for c in 0..3 {
+                target[c] = source[c] as f32 + target[c] * (1.0 - cover);
+            }
+        }
+    }
+    canvas.iter().map(|channel| channel.round().clamp(0.0, 255.0) as u8).collect()
+}
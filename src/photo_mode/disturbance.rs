@@ -0,0 +1,192 @@
+// Photo Disturbance - Fieldcraft meter: crowding, fast pans, hard zooms and flash unsettle birds, blinds hide you
+use bevy::prelude::*;
+use crate::bird_ai::components::{BirdAI, BirdState, Blackboard};
+use crate::photo_mode::components::{CameraControls, PhotoScore, PhotoTarget};
+use crate::photo_mode::resources::PhotoModeSettings;
+use crate::stealth::ObserverPresence;
+
+// Birds closer than this to the middle of the view notice the photographer
+const COMFORT_DISTANCE: f32 = 120.0;
+// Panning faster than this is a photographer striding about
+const CALM_PAN_SPEED: f32 = 150.0;
+const FLASH_DISTURBANCE: f32 = 0.3;
+const FLEE_RANGE: f32 = 250.0;
+// Working from a blind cuts how much the birds notice
+const BLIND_FACTOR: f32 = 0.3;
+const METER_WIDTH: f32 = 200.0;
+
+// How unsettled the birds are by the photographer, 0 calm to 1 about to scatter
+#[derive(Resource, Default)]
+pub struct PhotoDisturbance {
+    pub level: f32,
+    pub in_blind: bool,
+    last_position: Option<Vec2>,
+    last_zoom: Option<f32>,
+}
+
+impl PhotoDisturbance {
+    pub fn flash(&mut self) {
+        let gain = if self.in_blind { FLASH_DISTURBANCE * BLIND_FACTOR } else { FLASH_DISTURBANCE };
+        self.level = (self.level + gain).min(1.0);
+    }
+
+    // Unsettled birds hunch up or look away, so the shot loses up to half its worth
+    pub fn penalize(&self, score: &mut PhotoScore) {
+        let penalty = (score.total_score as f32 * self.level * 0.5) as u32;
+        score.behavior_score = score.behavior_score.saturating_sub(penalty);
+        score.total_score = score.total_score.saturating_sub(penalty);
+        if penalty > 0 {
+            info!("  Disturbance: -{}", penalty);
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct DisturbanceMeter;
+
+#[derive(Component)]
+pub struct DisturbanceMeterFill;
+
+#[derive(Component)]
+pub struct DisturbanceMeterLabel;
+
+pub fn setup_disturbance_meter(mut commands: Commands) {
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(30.0),
+            left: Val::Percent(50.0),
+            margin: UiRect::left(Val::Px(-METER_WIDTH * 0.5)),
+            width: Val::Px(METER_WIDTH),
+            flex_direction: FlexDirection::Column,
+            row_gap: Val::Px(4.0),
+            ..default()
+        },
+        DisturbanceMeter,
+        Visibility::Hidden,
+    )).with_children(|parent| {
+        parent.spawn((
+            Text::new("Disturbance"),
+            TextFont { font_size: 14.0, ..default() },
+            TextColor(Color::WHITE),
+            DisturbanceMeterLabel,
+        ));
+        parent.spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Px(10.0),
+                border: UiRect::all(Val::Px(1.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.6)),
+            BorderColor(Color::WHITE),
+        )).with_children(|bar| {
+            bar.spawn((
+                Node {
+                    width: Val::Percent(0.0),
+                    height: Val::Percent(100.0),
+                    ..default()
+                },
+                BackgroundColor(Color::srgb(0.4, 0.8, 0.4)),
+                DisturbanceMeterFill,
+            ));
+        });
+    });
+}
+
+// Getting close, panning fast and zooming hard all unsettle the birds; standing still lets them calm down
+pub fn disturbance_system(
+    settings: Res<PhotoModeSettings>,
+    mut disturbance: ResMut<PhotoDisturbance>,
+    camera_query: Query<(&Transform, &CameraControls), With<PhotoTarget>>,
+    bird_query: Query<&Transform, With<BirdAI>>,
+    presence: Res<ObserverPresence>,
+    time: Res<Time>,
+) {
+    let dt = time.delta_secs();
+    let Ok((camera_transform, controls)) = camera_query.single() else { return };
+    if !settings.is_active || dt <= 0.0 {
+        disturbance.level = (disturbance.level - 0.3 * dt).max(0.0);
+        disturbance.last_position = None;
+        disturbance.last_zoom = None;
+        return;
+    }
+
+    let position = camera_transform.translation.truncate();
+    disturbance.in_blind = presence.in_blind;
+
+    let closest = bird_query.iter()
+        .map(|transform| transform.translation.truncate().distance(position))
+        .fold(f32::MAX, f32::min);
+    let closeness = (1.0 - closest / COMFORT_DISTANCE).max(0.0);
+
+    let pan_speed = disturbance.last_position.map_or(0.0, |last| last.distance(position) / dt);
+    let panning = ((pan_speed - CALM_PAN_SPEED) / CALM_PAN_SPEED).clamp(0.0, 1.0);
+    let zoom_rate = disturbance.last_zoom.map_or(0.0, |last| (controls.zoom_level - last).abs() / dt);
+    disturbance.last_position = Some(position);
+    disturbance.last_zoom = Some(controls.zoom_level);
+
+    let mut gain = closeness * 0.4 + panning * 0.3 + zoom_rate * 0.2;
+    if disturbance.in_blind {
+        gain *= BLIND_FACTOR;
+    }
+    let calm = if disturbance.in_blind { 0.2 } else { 0.1 };
+    disturbance.level = (disturbance.level + (gain - calm) * dt).clamp(0.0, 1.0);
+}
+
+// The more unsettled the birds, the likelier those in view take off
+pub fn disturbance_flee_system(
+    settings: Res<PhotoModeSettings>,
+    disturbance: Res<PhotoDisturbance>,
+    camera_query: Query<&Transform, With<PhotoTarget>>,
+    mut bird_query: Query<(&Transform, &mut BirdState, &mut Blackboard), (With<BirdAI>, Without<PhotoTarget>)>,
+    time: Res<Time>,
+) {
+    if !settings.is_active || disturbance.level < 0.3 {
+        return;
+    }
+    let Ok(camera_transform) = camera_query.single() else { return };
+    let position = camera_transform.translation.truncate();
+    let flee_chance = disturbance.level * disturbance.level * 0.5 * time.delta_secs();
+
+    for (transform, mut state, mut blackboard) in &mut bird_query {
+        if *state == BirdState::Fleeing || transform.translation.truncate().distance(position) > FLEE_RANGE {
+            continue;
+        }
+        if rand::random::<f32>() < flee_chance {
+            blackboard.internal.fear = (blackboard.internal.fear + disturbance.level * 0.5).min(1.0);
+            *state = BirdState::Fleeing;
+        }
+    }
+}
+
+pub fn disturbance_meter_system(
+    settings: Res<PhotoModeSettings>,
+    disturbance: Res<PhotoDisturbance>,
+    mut meter_query: Query<&mut Visibility, With<DisturbanceMeter>>,
+    mut fill_query: Query<(&mut Node, &mut BackgroundColor), With<DisturbanceMeterFill>>,
+    mut label_query: Query<&mut Text, With<DisturbanceMeterLabel>>,
+) {
+    for mut visibility in &mut meter_query {
+        *visibility = if settings.is_active { Visibility::Inherited } else { Visibility::Hidden };
+    }
+    if !settings.is_active {
+        return;
+    }
+
+    let level = disturbance.level;
+    for (mut node, mut color) in &mut fill_query {
+        node.width = Val::Percent(level * 100.0);
+        color.0 = Color::srgb(0.4 + level * 0.5, 0.8 - level * 0.6, 0.4 - level * 0.2);
+    }
+    for mut text in &mut label_query {
+        let mut label = String::from("Disturbance");
+        if disturbance.in_blind {
+            label.push_str(" (in blind)");
+        }
+        if settings.flash_enabled {
+            label.push_str(" - Flash on");
+        }
+        **text = label;
+    }
+}
@@ -0,0 +1,140 @@
+// Photo Filters - Narrowing the gallery by what's in a photo, and smart collections of saved filters
+//
+// Every photo records the species, score, weather, hour and what its subject was doing. A filter
+// matches on any of those, and a smart collection is just a named filter: it's re-run against the
+// collection whenever it's opened, so "All hummingbirds" picks up tomorrow's shots without anyone
+// filing them. The collections are saved with the sanctuary; the filter being edited isn't.
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use crate::bird::BirdSpecies;
+use crate::environment::components::Weather;
+use crate::photo_mode::resources::SavedPhoto;
+
+// Steps the score range buttons cycle through
+pub const SCORE_STEPS: [u32; 6] = [0, 50, 100, 150, 200, 250];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TimeOfDay {
+    Dawn,
+    Day,
+    Dusk,
+    Night,
+}
+
+impl TimeOfDay {
+    pub const ALL: [TimeOfDay; 4] = [Self::Dawn, Self::Day, Self::Dusk, Self::Night];
+
+    pub fn from_hour(hour: f32) -> Self {
+        match hour {
+            h if (5.0..8.0).contains(&h) => Self::Dawn,
+            h if (8.0..17.0).contains(&h) => Self::Day,
+            h if (17.0..20.0).contains(&h) => Self::Dusk,
+            _ => Self::Night,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Dawn => "Dawn",
+            Self::Day => "Day",
+            Self::Dusk => "Dusk",
+            Self::Night => "Night",
+        }
+    }
+}
+
+/// Empty fields match everything
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PhotoFilter {
+    // Any of these species
+    pub species: Vec<BirdSpecies>,
+    pub min_score: u32,
+    pub max_score: Option<u32>,
+    // Any of these conditions
+    pub weather: Vec<Weather>,
+    pub time_of_day: Option<TimeOfDay>,
+    // Debug name of the subject's state, the same names research observations use
+    pub behavior: Option<String>,
+}
+
+impl PhotoFilter {
+    pub fn matches(&self, photo: &SavedPhoto) -> bool {
+        let score = photo.score.total_score;
+        (self.species.is_empty() || photo.species.is_some_and(|species| self.species.contains(&species)))
+            && score >= self.min_score
+            && self.max_score.is_none_or(|max| score <= max)
+            && (self.weather.is_empty() || self.weather.contains(&photo.weather))
+            && self.time_of_day.is_none_or(|time| TimeOfDay::from_hour(photo.hour) == time)
+            && self.behavior.as_ref().is_none_or(|behavior| photo.behavior.is_some_and(|state| format!("{:?}", state) == *behavior))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Short description used to name a collection saved from the filter
+    pub fn describe(&self) -> String {
+        let mut parts = Vec::new();
+        match self.species.as_slice() {
+            [] => {}
+            [species] => parts.push(format!("{:?}", species)),
+            species => parts.push(format!("{} species", species.len())),
+        }
+        match (self.min_score, self.max_score) {
+            (0, None) => {}
+            (min, None) => parts.push(format!("{}+ points", min)),
+            (min, Some(max)) => parts.push(format!("{}-{} points", min, max)),
+        }
+        if !self.weather.is_empty() {
+            parts.push(self.weather.iter().map(|weather| format!("{:?}", weather)).collect::<Vec<_>>().join("/"));
+        }
+        if let Some(time) = self.time_of_day {
+            parts.push(time.name().to_string());
+        }
+        if let Some(behavior) = &self.behavior {
+            parts.push(behavior.clone());
+        }
+        if parts.is_empty() { "All photos".to_string() } else { parts.join(", ") }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SmartCollection {
+    pub name: String,
+    pub filter: PhotoFilter,
+}
+
+// Resources
+#[derive(Resource, Debug, Clone, Serialize, Deserialize)]
+pub struct SmartCollections {
+    pub collections: Vec<SmartCollection>,
+}
+
+impl Default for SmartCollections {
+    fn default() -> Self {
+        Self {
+            collections: vec![
+                SmartCollection {
+                    name: "All hummingbirds".to_string(),
+                    // The sanctuary's only hummingbird so far; add new ones here as they arrive
+                    filter: PhotoFilter { species: vec![BirdSpecies::RubyThroatedHummingbird], ..default() },
+                },
+                SmartCollection {
+                    name: "Storm shots".to_string(),
+                    filter: PhotoFilter { weather: vec![Weather::Rainy, Weather::Snowy, Weather::Windy], ..default() },
+                },
+            ],
+        }
+    }
+}
+
+impl SmartCollections {
+    /// Saves the filter under its description, unless an identical one is already saved
+    pub fn save(&mut self, filter: &PhotoFilter) -> Option<usize> {
+        if filter.is_empty() || self.collections.iter().any(|collection| collection.filter == *filter) {
+            return None;
+        }
+        self.collections.push(SmartCollection { name: filter.describe(), filter: filter.clone() });
+        Some(self.collections.len() - 1)
+    }
+}
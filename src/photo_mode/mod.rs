@@ -5,11 +5,32 @@ pub mod resources;
 pub mod systems;
 pub mod advanced_systems;
 pub mod advanced_photo; // Phase 4: Advanced Photography Features
+pub mod album;
+pub mod album_export;
+pub mod photo_export;
+pub mod offscreen;
+pub mod burst;
+pub mod disturbance;
+pub mod filters;
+pub mod critique;
+pub mod panorama;
+pub mod tilt_shift;
+pub mod depth_of_field;
 
 use components::*;
 use resources::*;
 use systems::*;
 use advanced_systems::*;
+use album::*;
+use album_export::*;
+use photo_export::*;
+use offscreen::*;
+use burst::*;
+use disturbance::*;
+use filters::SmartCollections;
+use critique::*;
+use panorama::*;
+use depth_of_field::*;
 
 pub struct PhotoModePlugin;
 
@@ -20,17 +41,73 @@ impl Plugin for PhotoModePlugin {
             .init_resource::<CurrencyResource>()
             .init_resource::<DiscoveredSpecies>()
             .init_resource::<PhotoCollection>()
+            .init_resource::<AlbumEditor>()
+            .init_resource::<AlbumExportJob>()
+            .init_resource::<PhotoExportJob>()
+            .init_resource::<PhotoExportStyle>()
+            .init_resource::<BurstSession>()
+            .init_resource::<PhotoDisturbance>()
+            .init_resource::<SmartCollections>()
+            .init_resource::<PhotoCritique>()
+            .init_resource::<PanoramaSession>()
+            .init_resource::<DepthOfFieldJob>()
             .add_event::<PhotoTakenEvent>()
+            .add_event::<ExportAlbumEvent>()
+            .add_event::<ExportPhotoEvent>()
             .add_systems(Update, (
                 toggle_photo_mode_system.run_if(crate::debug_console::console_is_not_visible),
                 capture_photo_system.run_if(crate::debug_console::console_is_not_visible),
                 photo_reward_system,
                 photo_ui_system,
                 camera_controls_system.run_if(crate::debug_console::console_is_not_visible),
+                autofocus_system,
                 composition_grid_system,
                 camera_settings_panel_system,
                 photo_mode_input_system.run_if(crate::debug_console::console_is_not_visible),
-            ))
-            .add_systems(Startup, (setup_photo_ui, setup_advanced_photo_ui));
+            ).in_set(crate::GameSet::Input))
+            // Keys typed into a caption are read as text, so they must not reach the game's hotkeys
+            .add_systems(PreUpdate, crate::search_palette::block_game_keys_system
+                .run_if(album_caption_is_editing)
+                .after(bevy::input::InputSystem))
+            .add_systems(Update, (
+                toggle_album_editor_system.run_if(crate::debug_console::console_is_not_visible),
+                album_editor_button_system,
+                album_caption_input_system,
+                refresh_album_editor_system,
+            ).chain().in_set(crate::GameSet::UI).run_if(in_state(crate::AppState::Playing)))
+            .add_systems(Update, (
+                burst_capture_system.run_if(crate::debug_console::console_is_not_visible),
+                burst_review_button_system,
+                refresh_burst_review_system,
+            ).chain().in_set(crate::GameSet::UI).run_if(in_state(crate::AppState::Playing)))
+            .add_systems(Update, (start_depth_of_field_system, depth_of_field_system).chain()
+                // Bands render in the same frame as the shot, single or burst
+                .after(capture_photo_system)
+                .after(burst_capture_system)
+                .in_set(crate::GameSet::UI))
+            .add_systems(Update, (
+                critique_offer_system.after(capture_photo_system),
+                critique_toggle_system.run_if(crate::debug_console::console_is_not_visible),
+            ).chain().in_set(crate::GameSet::Input).run_if(in_state(crate::AppState::Playing)))
+            .add_systems(Update, (
+                disturbance_system,
+                disturbance_flee_system,
+                disturbance_meter_system,
+            ).chain()
+                // Spooked birds must not be overwritten by the behavior tree in the same frame
+                .after(crate::bird_ai::systems::behavior_tree_system)
+                .in_set(crate::GameSet::AI)
+                .run_if(in_state(crate::AppState::Playing)))
+            .add_systems(Update, offscreen_capture_system.in_set(crate::GameSet::Presentation))
+            .add_observer(offscreen_readback_observer)
+            .add_systems(Update, (start_album_export_system, album_export_system).chain().in_set(crate::GameSet::Presentation))
+            .add_systems(Update, (start_photo_export_system, photo_export_system).chain().in_set(crate::GameSet::Presentation))
+            .add_systems(Update, (
+                tilt_shift_toggle_system.run_if(crate::debug_console::console_is_not_visible),
+                start_panorama_system.run_if(crate::debug_console::console_is_not_visible),
+                panorama_capture_system,
+            ).chain().in_set(crate::GameSet::Presentation).run_if(in_state(crate::AppState::Playing)))
+            .add_systems(OnExit(crate::AppState::Playing), close_album_editor_system)
+            .add_systems(Startup, (setup_photo_ui, setup_advanced_photo_ui, setup_disturbance_meter));
     }
 }
@@ -0,0 +1,159 @@
+// Offscreen Capture - Cameras that draw into an image which is then read back to the CPU
+//
+// Album pages, exported photos, panorama slices, depth of field bands and the sanctuary snapshot
+// all render this way. A capture camera carries an OffscreenCapture; once it has had a couple of
+// frames to render, its image is read back and the pixels wait on the component for whoever spawned
+// the camera to take. A camera that moves on to a new shot, like the panorama sweep, asks for a
+// retake and is read back again once the new view has rendered.
+use bevy::prelude::*;
+use bevy::asset::RenderAssetUsages;
+use bevy::render::camera::{ClearColorConfig, ImageRenderTarget, RenderTarget};
+use bevy::render::gpu_readback::{Readback, ReadbackComplete};
+use bevy::render::render_resource::{
+    Extent3d, TextureDescriptor, TextureDimension, TextureFormat, TextureUsages,
+};
+use std::path::Path;
+
+// Frames to let a capture camera render before reading it back
+const RENDER_SETTLE_FRAMES: u32 = 2;
+
+#[derive(Component)]
+pub struct OffscreenCapture {
+    target: Handle<Image>,
+    size: UVec2,
+    frames: u32,
+    requested: bool,
+    pixels: Option<Vec<u8>>,
+}
+
+impl OffscreenCapture {
+    pub fn is_ready(&self) -> bool {
+        self.pixels.is_some()
+    }
+
+    /// The read-back RGBA pixels, once, as soon as they've arrived
+    pub fn take_pixels(&mut self) -> Option<Vec<u8>> {
+        self.pixels.take()
+    }
+
+    /// Renders and reads the view back again, for a camera that has moved on to a new shot
+    pub fn retake(&mut self) {
+        self.frames = 0;
+        self.requested = false;
+        self.pixels = None;
+    }
+}
+
+/// A 2D camera drawing into a fresh offscreen image of `size`, read back once it has rendered
+pub fn capture_camera(
+    images: &mut Assets<Image>,
+    label: &'static str,
+    size: UVec2,
+    order: isize,
+    clear_color: ClearColorConfig,
+) -> impl Bundle {
+    let target = images.add(offscreen_target(label, size.x, size.y));
+    (
+        Camera2d,
+        Camera {
+            target: RenderTarget::Image(ImageRenderTarget::from(target.clone())),
+            order,
+            clear_color,
+            ..default()
+        },
+        OffscreenCapture {
+            target,
+            size,
+            frames: 0,
+            requested: false,
+            pixels: None,
+        },
+    )
+}
+
+// Render target that a camera can draw into and that can be read back to the CPU
+fn offscreen_target(label: &'static str, width: u32, height: u32) -> Image {
+    let size = Extent3d {
+        width,
+        height,
+        depth_or_array_layers: 1,
+    };
+
+    let mut image = Image {
+        texture_descriptor: TextureDescriptor {
+            label: Some(label),
+            size,
+            dimension: TextureDimension::D2,
+            format: TextureFormat::Rgba8UnormSrgb,
+            mip_level_count: 1,
+            sample_count: 1,
+            usage: TextureUsages::COPY_SRC | TextureUsages::COPY_DST | TextureUsages::RENDER_ATTACHMENT | TextureUsages::TEXTURE_BINDING,
+            view_formats: &[],
+        },
+        ..default()
+    };
+    image.resize(size);
+    image
+}
+
+// Asks for a readback once each capture has had time to render
+pub fn offscreen_capture_system(
+    mut commands: Commands,
+    mut capture_query: Query<(Entity, &mut OffscreenCapture)>,
+) {
+    for (entity, mut capture) in &mut capture_query {
+        if capture.frames > RENDER_SETTLE_FRAMES {
+            continue;
+        }
+        capture.frames += 1;
+        if capture.frames > RENDER_SETTLE_FRAMES {
+            commands.entity(entity).insert(Readback::texture(capture.target.clone()));
+            capture.requested = true;
+        }
+    }
+}
+
+pub fn offscreen_readback_observer(
+    trigger: Trigger<ReadbackComplete>,
+    mut commands: Commands,
+    mut capture_query: Query<&mut OffscreenCapture>,
+) {
+    let Ok(mut capture) = capture_query.get_mut(trigger.target()) else { return };
+
+    // The readback repeats every frame until removed, so only take the first result
+    commands.entity(trigger.target()).remove::<Readback>();
+    if !capture.requested {
+        return;
+    }
+    capture.requested = false;
+    capture.pixels = Some(unpad_rows(&trigger.event().0, capture.size.x, capture.size.y));
+}
+
+// GPU readbacks pad each row to the copy alignment
+fn unpad_rows(data: &[u8], width: u32, height: u32) -> Vec<u8> {
+    let row_bytes = width as usize * 4;
+    let padded_row_bytes = data.len() / height as usize;
+    if padded_row_bytes == row_bytes {
+        return data.to_vec();
+    }
+
+    data.chunks(padded_row_bytes)
+        .take(height as usize)
+        .flat_map(|row| &row[..row_bytes])
+        .copied()
+        .collect()
+}
+
+// Read-back RGBA pixels saved as an opaque PNG
+pub fn write_png(path: &Path, size: UVec2, pixels: Vec<u8>) -> Result<(), Box<dyn std::error::Error>> {
+    Image::new(
+        Extent3d { width: size.x, height: size.y, depth_or_array_layers: 1 },
+        TextureDimension::D2,
+        pixels,
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::default(),
+    ).try_into_dynamic()?
+        .to_rgb8()
+        .save(path)?;
+    Ok(())
+}
@@ -0,0 +1,197 @@
+// Panorama - A sweep across the yard stitched into one wide picture
+//
+// In photo mode the panorama key sends a capture camera across the view from left to right,
+// stopping at each slice long enough to render it and read it back. Neighbouring slices overlap
+// by a quarter and are feathered together where they meet, so birds that moved between slices
+// fade rather than tear. The stitched image goes through the same save pipeline as exported
+// photos, tilt-shift included, and becomes the main menu backdrop.
+use bevy::prelude::*;
+use bevy::render::camera::ClearColorConfig;
+use crate::notifications::{resources::ShowNotificationEvent, components::NotificationType};
+use crate::photo_mode::offscreen::{capture_camera, write_png, OffscreenCapture};
+use crate::photo_mode::components::PhotoTarget;
+use crate::menu::resources::GameSettings;
+use crate::photo_mode::photo_export::{choose_photo_backdrop, export_timestamp, photo_export_directory, set_menu_background, PhotoExportStyle};
+use crate::photo_mode::resources::PhotoModeSettings;
+use crate::photo_mode::tilt_shift::apply_tilt_shift;
+
+const SLICE_COUNT: usize = 5;
+const SLICE_WIDTH: u32 = 800;
+const SLICE_HEIGHT: u32 = 600;
+// Share of each slice shared with the next
+const SLICE_OVERLAP: f32 = 0.25;
+
+#[derive(Resource, Default)]
+pub struct PanoramaSession {
+    active: Option<ActivePanorama>,
+}
+
+impl PanoramaSession {
+    pub fn is_running(&self) -> bool {
+        self.active.is_some()
+    }
+}
+
+struct ActivePanorama {
+    camera: Entity,
+    // Middle of the view the sweep is centered on
+    center: Vec3,
+    // World units between slice centers
+    step: f32,
+    tilt_shift: bool,
+    slices: Vec<Vec<u8>>,
+}
+
+// Where the camera stands for a slice, sweeping left to right across the center
+fn slice_translation(center: Vec3, step: f32, index: usize) -> Vec3 {
+    let offset = index as f32 - (SLICE_COUNT - 1) as f32 / 2.0;
+    center + Vec3::X * offset * step
+}
+
+#[derive(Component)]
+pub struct PanoramaEntity;
+
+#[allow(clippy::too_many_arguments)]
+pub fn start_panorama_system(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    settings: Res<PhotoModeSettings>,
+    mut session: ResMut<PanoramaSession>,
+    mut images: ResMut<Assets<Image>>,
+    camera_query: Query<(&Transform, &Projection), With<PhotoTarget>>,
+    style: Res<PhotoExportStyle>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+) {
+    if !settings.is_active || !keyboard.just_pressed(settings.panorama_key) || session.is_running() {
+        return;
+    }
+    let Ok((transform, projection)) = camera_query.single() else { return };
+    let scale = match projection {
+        Projection::Orthographic(ortho) => ortho.scale,
+        _ => 1.0,
+    };
+
+    let center = transform.translation;
+    let step = SLICE_WIDTH as f32 * (1.0 - SLICE_OVERLAP) * scale;
+    let camera = commands.spawn((
+        capture_camera(
+            &mut images,
+            "panorama_slice",
+            UVec2::new(SLICE_WIDTH, SLICE_HEIGHT),
+            -12,
+            ClearColorConfig::Default,
+        ),
+        projection.clone(),
+        transform.with_translation(slice_translation(center, step, 0)),
+        bevy_light_2d::prelude::Light2d,
+        PanoramaEntity,
+    )).id();
+
+    session.active = Some(ActivePanorama {
+        camera,
+        center,
+        step,
+        tilt_shift: style.tilt_shift,
+        slices: Vec::with_capacity(SLICE_COUNT),
+    });
+    notification_events.write(ShowNotificationEvent {
+        notification: NotificationType::Info {
+            message: "Sweeping a panorama across the yard...".to_string(),
+        },
+    });
+}
+
+// One slice at a time: read it back, move the camera on and retake; stitch once all are in
+pub fn panorama_capture_system(
+    mut commands: Commands,
+    mut session: ResMut<PanoramaSession>,
+    mut camera_query: Query<(&mut Transform, &mut OffscreenCapture), With<PanoramaEntity>>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+    mut settings: ResMut<GameSettings>,
+) {
+    let Some(panorama) = session.bypass_change_detection().active.as_mut() else { return };
+    let Ok((mut transform, mut capture)) = camera_query.get_mut(panorama.camera) else { return };
+    let Some(slice) = capture.take_pixels() else { return };
+
+    panorama.slices.push(slice);
+    if panorama.slices.len() < SLICE_COUNT {
+        transform.translation = slice_translation(panorama.center, panorama.step, panorama.slices.len());
+        capture.retake();
+        return;
+    }
+
+    commands.entity(panorama.camera).despawn();
+    let (width, mut pixels) = stitch_slices(&panorama.slices);
+    if panorama.tilt_shift {
+        apply_tilt_shift(&mut pixels, width, SLICE_HEIGHT);
+    }
+    let result = write_panorama(UVec2::new(width, SLICE_HEIGHT), pixels);
+    let message = match &result {
+        Ok(path) => format!("Panorama saved to {} and set as the menu backdrop", path.display()),
+        Err(e) => format!("Panorama failed: {}", e),
+    };
+    match result {
+        Ok(_) => {
+            info!("{}", message);
+            choose_photo_backdrop(&mut settings);
+        }
+        Err(_) => error!("{}", message),
+    }
+    notification_events.write(ShowNotificationEvent {
+        notification: NotificationType::Info { message },
+    });
+    session.active = None;
+}
+
+// Lays the slices side by side, cross-fading each overlap from the left slice into the right
+fn stitch_slices(slices: &[Vec<u8>]) -> (u32, Vec<u8>) {
+    let slice_width = SLICE_WIDTH as usize;
+    let overlap = (SLICE_WIDTH as f32 * SLICE_OVERLAP) as usize;
+    let advance = slice_width - overlap;
+    let width = advance * slices.len().saturating_sub(1) + slice_width;
+    let mut pixels = vec![0u8; width * SLICE_HEIGHT as usize * 4];
+
+    for (index, slice) in slices.iter().enumerate() {
+        let left = index * advance;
+        for y in 0..SLICE_HEIGHT as usize {
+            for x in 0..slice_width {
+                let source = (y * slice_width + x) * 4;
+                let target = (y * width + left + x) * 4;
+                // The first slice has nothing under its left edge to fade into
+                let weight = if index > 0 && x < overlap { (x as f32 + 0.5) / overlap as f32 } else { 1.0 };
+                for c in 0..4 {
+                    let under = pixels[target + c] as f32;
+                    pixels[target + c] = (under + (slice[source + c] as f32 - under) * weight).round() as u8;
+                }
+            }
+        }
+    }
+    (width as u32, pixels)
+}
+
+fn write_panorama(size: UVec2, pixels: Vec<u8>) -> Result<std::path::PathBuf, Box<dyn std::error::Error>> {
+    let directory = photo_export_directory();
+    std::fs::create_dir_all(&directory)?;
+
+    let path = directory.join(format!("panorama_{}.png", export_timestamp()));
+    write_png(&path, size, pixels)?;
+    set_menu_background(&path)?;
+    Ok(path)
+}
+
+pub fn tilt_shift_toggle_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    settings: Res<PhotoModeSettings>,
+    mut style: ResMut<PhotoExportStyle>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+) {
+    if !settings.is_active || !keyboard.just_pressed(settings.tilt_shift_key) {
+        return;
+    }
+    style.tilt_shift = !style.tilt_shift;
+    notification_events.write(ShowNotificationEvent {
+        notification: NotificationType::Info {
+            message: format!("Tilt-shift {} for exports and panoramas", if style.tilt_shift { "on" } else { "off" }),
+        },
+    });
+}
@@ -0,0 +1,409 @@
+// Photo Export - Single photos saved as PNG with a frame, date/species stamp and aspect crop
+//
+// Composited offscreen the same way album pages are: the photo is drawn as a cropped sprite
+// inside its frame on a private render layer, read back once and written to disk. The album
+// editor previews the current style with the same crop and border sizes. A saved photo can also
+// be copied over as the main menu backdrop, which the menu picks up the next time it opens.
+use bevy::prelude::*;
+use bevy::asset::RenderAssetUsages;
+use bevy::render::camera::ClearColorConfig;
+use bevy::render::view::RenderLayers;
+use std::fs;
+use std::path::{Path, PathBuf};
+use crate::achievements::{Achievement, AchievementProgress};
+use crate::photo_mode::offscreen::{capture_camera, write_png, OffscreenCapture};
+use crate::photo_mode::resources::{PhotoCollection, SavedPhoto};
+use crate::photo_mode::tilt_shift::apply_tilt_shift;
+use crate::menu::resources::{GameSettings, MenuBackdropSource};
+use crate::notifications::{resources::ShowNotificationEvent, components::NotificationType};
+
+// Captures are rendered at 800x600
+pub const PHOTO_SOURCE_SIZE: Vec2 = Vec2::new(800.0, 600.0);
+// Exported photos are upscaled so the stamp text stays crisp
+const EXPORT_SCALE: f32 = 2.0;
+const PHOTO_EXPORT_RENDER_LAYER: usize = 8;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PhotoFrame {
+    None,
+    Classic,
+    Polaroid,
+    Botanical,
+    Gilded,
+}
+
+impl PhotoFrame {
+    pub const ALL: [PhotoFrame; 5] = [Self::None, Self::Classic, Self::Polaroid, Self::Botanical, Self::Gilded];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::None => "None",
+            Self::Classic => "Classic",
+            Self::Polaroid => "Instant",
+            Self::Botanical => "Botanical",
+            Self::Gilded => "Gilded",
+        }
+    }
+
+    // Achievement that unlocks the frame; the plain ones are always available
+    pub fn unlocked_by(&self) -> Option<Achievement> {
+        match self {
+            Self::None | Self::Classic => None,
+            Self::Polaroid => Some(Achievement::PhotoMaster),
+            Self::Botanical => Some(Achievement::CommonCollector),
+            Self::Gilded => Some(Achievement::Ornithologist),
+        }
+    }
+
+    pub fn is_unlocked(&self, progress: &AchievementProgress) -> bool {
+        self.unlocked_by().is_none_or(|achievement| progress.is_unlocked(&achievement))
+    }
+
+    pub fn color(&self) -> Color {
+        match self {
+            Self::None => Color::NONE,
+            Self::Classic => Color::srgb(0.15, 0.12, 0.1),
+            Self::Polaroid => Color::srgb(0.97, 0.96, 0.93),
+            Self::Botanical => Color::srgb(0.32, 0.45, 0.26),
+            Self::Gilded => Color::srgb(0.78, 0.62, 0.28),
+        }
+    }
+
+    // Border widths (sides, top, bottom) as a fraction of the photo's shorter edge
+    pub fn borders(&self) -> (f32, f32, f32) {
+        match self {
+            Self::None => (0.0, 0.0, 0.0),
+            Self::Classic => (0.03, 0.03, 0.03),
+            // Instant prints leave a wide strip at the bottom for writing on
+            Self::Polaroid => (0.06, 0.06, 0.2),
+            Self::Botanical => (0.07, 0.07, 0.07),
+            Self::Gilded => (0.09, 0.09, 0.09),
+        }
+    }
+
+    // Next frame the player has unlocked
+    pub fn next_unlocked(&self, progress: &AchievementProgress) -> Self {
+        let index = Self::ALL.iter().position(|frame| frame == self).unwrap_or(0);
+        (1..=Self::ALL.len())
+            .map(|step| Self::ALL[(index + step) % Self::ALL.len()])
+            .find(|frame| frame.is_unlocked(progress))
+            .unwrap_or(Self::None)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PhotoCrop {
+    Original,
+    Square,
+    Widescreen,
+    Portrait,
+}
+
+impl PhotoCrop {
+    pub const ALL: [PhotoCrop; 4] = [Self::Original, Self::Square, Self::Widescreen, Self::Portrait];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Original => "4:3",
+            Self::Square => "Square",
+            Self::Widescreen => "16:9",
+            Self::Portrait => "4:5",
+        }
+    }
+
+    fn aspect(&self) -> f32 {
+        match self {
+            Self::Original => 4.0 / 3.0,
+            Self::Square => 1.0,
+            Self::Widescreen => 16.0 / 9.0,
+            Self::Portrait => 4.0 / 5.0,
+        }
+    }
+
+    // Largest centered rectangle of the capture with this aspect, in source pixels
+    pub fn source_rect(&self, source: Vec2) -> Rect {
+        let aspect = self.aspect();
+        let size = if source.x / source.y > aspect {
+            Vec2::new(source.y * aspect, source.y)
+        } else {
+            Vec2::new(source.x, source.x / aspect)
+        };
+        Rect::from_center_size(source / 2.0, size)
+    }
+
+    pub fn next(&self) -> Self {
+        let index = Self::ALL.iter().position(|crop| crop == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+}
+
+#[derive(Resource)]
+pub struct PhotoExportStyle {
+    pub frame: PhotoFrame,
+    pub crop: PhotoCrop,
+    pub date_stamp: bool,
+    pub species_stamp: bool,
+    // Miniature look, also used for panoramas
+    pub tilt_shift: bool,
+}
+
+impl Default for PhotoExportStyle {
+    fn default() -> Self {
+        Self {
+            frame: PhotoFrame::Classic,
+            crop: PhotoCrop::Original,
+            date_stamp: true,
+            species_stamp: true,
+            tilt_shift: false,
+        }
+    }
+}
+
+impl PhotoExportStyle {
+    pub fn stamp(&self, photo: &SavedPhoto) -> Option<String> {
+        let species = photo.species.filter(|_| self.species_stamp).map(|species| format!("{:?}", species));
+        let date = self.date_stamp.then(|| photo.taken_on.label());
+        match (species, date) {
+            (Some(species), Some(date)) => Some(format!("{}  ·  {}", species, date)),
+            (species, date) => species.or(date),
+        }
+    }
+
+    // Photo area and whole framed canvas for a photo shown at `scale` times its source size
+    pub fn layout(&self, scale: f32) -> (Rect, Vec2) {
+        let photo_size = self.crop.source_rect(PHOTO_SOURCE_SIZE).size() * scale;
+        let (side, top, bottom) = self.frame.borders();
+        let edge = photo_size.min_element();
+        let min = Vec2::new(side * edge, top * edge);
+        let canvas = photo_size + Vec2::new(side * 2.0 * edge, (top + bottom) * edge);
+        (Rect::from_corners(min, min + photo_size), canvas)
+    }
+}
+
+#[derive(Event)]
+pub struct ExportPhotoEvent {
+    pub photo_id: u32,
+    // Also use the exported image as the main menu backdrop
+    pub menu_background: bool,
+}
+
+#[derive(Resource, Default)]
+pub struct PhotoExportJob {
+    active: Option<ActivePhotoExport>,
+}
+
+impl PhotoExportJob {
+    pub fn is_running(&self) -> bool {
+        self.active.is_some()
+    }
+}
+
+struct ActivePhotoExport {
+    photo_id: u32,
+    size: UVec2,
+    tilt_shift: bool,
+    menu_background: bool,
+}
+
+#[derive(Component)]
+pub struct PhotoExportEntity;
+
+pub fn start_photo_export_system(
+    mut commands: Commands,
+    mut export_events: EventReader<ExportPhotoEvent>,
+    mut job: ResMut<PhotoExportJob>,
+    mut images: ResMut<Assets<Image>>,
+    style: Res<PhotoExportStyle>,
+    collection: Res<PhotoCollection>,
+) {
+    for event in export_events.read() {
+        if job.active.is_some() {
+            warn!("Photo export already in progress");
+            continue;
+        }
+        let Some(photo) = collection.get_photo(event.photo_id) else { continue };
+
+        let (photo_rect, canvas) = style.layout(EXPORT_SCALE);
+        let size = canvas.round().as_uvec2();
+        spawn_photo_scene(&mut commands, &mut images, &style, photo, photo_rect, canvas);
+
+        job.active = Some(ActivePhotoExport {
+            photo_id: photo.id,
+            size,
+            tilt_shift: style.tilt_shift,
+            menu_background: event.menu_background,
+        });
+        info!("🖼️ Exporting photo {} with {} frame, {} crop", photo.id, style.frame.name(), style.crop.name());
+    }
+}
+
+pub fn photo_export_system(
+    mut commands: Commands,
+    mut job: ResMut<PhotoExportJob>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+    scene_query: Query<Entity, With<PhotoExportEntity>>,
+    mut capture_query: Query<&mut OffscreenCapture, With<PhotoExportEntity>>,
+    mut settings: ResMut<GameSettings>,
+) {
+    let Some(export) = job.bypass_change_detection().active.as_mut() else { return };
+
+    for mut capture in &mut capture_query {
+        let Some(mut pixels) = capture.take_pixels() else { continue };
+        for entity in &scene_query {
+            commands.entity(entity).despawn();
+        }
+
+        if export.tilt_shift {
+            apply_tilt_shift(&mut pixels, export.size.x, export.size.y);
+        }
+        let result = write_photo(export.photo_id, export.size, pixels)
+            .and_then(|path| {
+                if export.menu_background {
+                    set_menu_background(&path)?;
+                }
+                Ok(path)
+            });
+        if result.is_ok() && export.menu_background {
+            choose_photo_backdrop(&mut settings);
+        }
+        let message = match &result {
+            Ok(path) if export.menu_background => format!("Photo exported to {} and set as the menu backdrop", path.display()),
+            Ok(path) => format!("Photo exported to {}", path.display()),
+            Err(e) => format!("Photo export failed: {}", e),
+        };
+        match result {
+            Ok(_) => info!("{}", message),
+            Err(_) => error!("{}", message),
+        }
+        notification_events.write(ShowNotificationEvent {
+            notification: NotificationType::Info { message },
+        });
+        job.active = None;
+        return;
+    }
+}
+
+fn spawn_photo_scene(
+    commands: &mut Commands,
+    images: &mut Assets<Image>,
+    style: &PhotoExportStyle,
+    photo: &SavedPhoto,
+    photo_rect: Rect,
+    canvas: Vec2,
+) {
+    let layer = RenderLayers::layer(PHOTO_EXPORT_RENDER_LAYER);
+    // Canvas space is top-left origin with y down; the camera sits at the world origin
+    let to_world = |point: Vec2| Vec2::new(point.x - canvas.x / 2.0, canvas.y / 2.0 - point.y);
+
+    // An unframed export is just the cropped photo, so the clear color never shows
+    let background = if style.frame == PhotoFrame::None { Color::BLACK } else { style.frame.color() };
+    commands.spawn((
+        capture_camera(
+            images,
+            "photo_export",
+            canvas.round().as_uvec2(),
+            -11,
+            ClearColorConfig::Custom(background),
+        ),
+        layer.clone(),
+        PhotoExportEntity,
+    ));
+
+    let center = to_world(photo_rect.center());
+    commands.spawn((
+        Sprite {
+            image: photo.image_handle.clone(),
+            custom_size: Some(photo_rect.size()),
+            rect: Some(style.crop.source_rect(PHOTO_SOURCE_SIZE)),
+            ..default()
+        },
+        Transform::from_xyz(center.x, center.y, 0.0),
+        layer.clone(),
+        PhotoExportEntity,
+    ));
+
+    if let Some(stamp) = style.stamp(photo) {
+        let margin = photo_rect.height() * 0.03;
+        let corner = to_world(photo_rect.max - Vec2::splat(margin));
+        commands.spawn((
+            Text2d::new(stamp),
+            TextFont { font_size: (photo_rect.height() * 0.045).clamp(16.0, 40.0), ..default() },
+            TextColor(Color::srgba(1.0, 0.92, 0.75, 0.9)),
+            bevy::sprite::Anchor::BottomRight,
+            Transform::from_xyz(corner.x, corner.y, 1.0),
+            layer.clone(),
+            PhotoExportEntity,
+        ));
+    }
+}
+
+pub fn photo_export_directory() -> PathBuf {
+    dirs::picture_dir()
+        .or_else(dirs::data_dir)
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("AvianHaven")
+}
+
+fn write_photo(photo_id: u32, size: UVec2, pixels: Vec<u8>) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    let directory = photo_export_directory();
+    fs::create_dir_all(&directory)?;
+
+    let path = directory.join(format!("photo_{}_{}.png", photo_id, export_timestamp()));
+    write_png(&path, size, pixels)?;
+    Ok(path)
+}
+
+pub fn export_timestamp() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or(0)
+}
+
+// Switches the main menu over to the chosen picture rather than the sanctuary snapshot
+pub fn choose_photo_backdrop(settings: &mut GameSettings) {
+    settings.menu_backdrop = MenuBackdropSource::ChosenPhoto;
+    if let Err(e) = settings.save_to_file() {
+        error!("Failed to save menu backdrop setting: {}", e);
+    }
+}
+
+pub fn menu_background_path() -> PathBuf {
+    dirs::data_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("AvianHaven")
+        .join("menu_background.png")
+}
+
+// Copies an exported image into place as the main menu backdrop
+pub fn set_menu_background(source: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    let destination = menu_background_path();
+    if let Some(directory) = destination.parent() {
+        fs::create_dir_all(directory)?;
+    }
+    fs::copy(source, &destination)?;
+    Ok(())
+}
+
+pub fn load_menu_background(images: &mut Assets<Image>) -> Option<Handle<Image>> {
+    load_png_file(&menu_background_path(), images)
+}
+
+// Saved pictures live outside the assets folder, so they're decoded by hand rather than loaded
+pub fn load_png_file(path: &Path, images: &mut Assets<Image>) -> Option<Handle<Image>> {
+    let bytes = fs::read(path).ok()?;
+    match Image::from_buffer(
+        &bytes,
+        bevy::image::ImageType::Extension("png"),
+        bevy::image::CompressedImageFormats::NONE,
+        true,
+        bevy::image::ImageSampler::Default,
+        RenderAssetUsages::RENDER_WORLD,
+    ) {
+        Ok(image) => Some(images.add(image)),
+        Err(e) => {
+            warn!("Couldn't load {}: {}", path.display(), e);
+            None
+        }
+    }
+}
@@ -2,6 +2,10 @@ use bevy::prelude::*;
 use std::collections::HashSet;
 use crate::bird::BirdSpecies;
 use crate::photo_mode::components::PhotoScore;
+use crate::photo_mode::album::PhotoAlbum;
+use crate::journal::resources::ChecklistDate;
+use crate::bird_ai::components::BirdState;
+use crate::environment::components::Weather;
 
 #[derive(Resource)]
 pub struct PhotoModeSettings {
@@ -10,6 +14,17 @@ pub struct PhotoModeSettings {
     pub capture_key: KeyCode,
     pub grid_toggle_key: KeyCode,
     pub settings_toggle_key: KeyCode,
+    pub burst_toggle_key: KeyCode,
+    pub flash_toggle_key: KeyCode,
+    // Opens the critique offered after a low-scoring shot
+    pub critique_key: KeyCode,
+    // Sweeps a panorama across the view, see panorama.rs
+    pub panorama_key: KeyCode,
+    pub tilt_shift_key: KeyCode,
+    // Holding the shutter fires a burst instead of a single shot
+    pub burst_mode: bool,
+    // Flash lights up dim shots but startles whatever is in front of it
+    pub flash_enabled: bool,
     pub show_composition_grid: bool,
     pub show_camera_settings: bool,
     pub show_depth_preview: bool,
@@ -23,6 +38,13 @@ impl Default for PhotoModeSettings {
             capture_key: KeyCode::Space,
             grid_toggle_key: KeyCode::KeyG,
             settings_toggle_key: KeyCode::KeyC,
+            burst_toggle_key: KeyCode::KeyX,
+            flash_toggle_key: KeyCode::KeyZ,
+            critique_key: KeyCode::Slash,
+            panorama_key: KeyCode::Period,
+            tilt_shift_key: KeyCode::Comma,
+            burst_mode: false,
+            flash_enabled: false,
             show_composition_grid: false,
             show_camera_settings: false,
             show_depth_preview: false,
@@ -50,27 +72,61 @@ impl DiscoveredSpecies {
 
 #[derive(Clone)]
 pub struct SavedPhoto {
+    pub id: u32, // Assigned by the collection when added
     pub species: Option<BirdSpecies>,
     pub score: PhotoScore,
     pub image_handle: Handle<Image>,
     pub timestamp: f64, // Game time when photo was taken
+    pub taken_on: ChecklistDate, // Sanctuary calendar date, used for the export date stamp
+    pub hour: f32, // Sanctuary clock when the photo was taken
+    pub weather: Weather,
+    pub behavior: Option<BirdState>,
 }
 
 #[derive(Resource, Default)]
 pub struct PhotoCollection {
     pub photos: Vec<SavedPhoto>,
+    pub albums: Vec<PhotoAlbum>,
+    next_photo_id: u32,
 }
 
 impl PhotoCollection {
-    pub fn add_photo(&mut self, photo: SavedPhoto) {
+    pub fn add_photo(&mut self, mut photo: SavedPhoto) {
+        photo.id = self.next_photo_id;
+        self.next_photo_id += 1;
         self.photos.push(photo);
+        
         // Keep only the best 100 photos to prevent memory issues
         if self.photos.len() > 100 {
-            self.photos.sort_by(|a, b| b.score.total_score.cmp(&a.score.total_score));
+            // Photos placed in an album are kept regardless of score
+            let in_albums: HashSet<u32> = self.albums.iter()
+                .flat_map(|album| album.entries.iter().map(|entry| entry.photo_id))
+                .collect();
+            self.photos.sort_by(|a, b| {
+                in_albums.contains(&b.id).cmp(&in_albums.contains(&a.id))
+                    .then(b.score.total_score.cmp(&a.score.total_score))
+            });
             self.photos.truncate(100);
         }
     }
     
+    pub fn get_photo(&self, id: u32) -> Option<&SavedPhoto> {
+        self.photos.iter().find(|photo| photo.id == id)
+    }
+    
+    pub fn is_in_album(&self, id: u32) -> bool {
+        self.albums.iter().any(|album| album.entries.iter().any(|entry| entry.photo_id == id))
+    }
+    
+    // Album photos stay put; anything else can be removed
+    pub fn remove_photo(&mut self, id: u32) -> Option<SavedPhoto> {
+        if self.is_in_album(id) {
+            return None;
+        }
+        let index = self.photos.iter().position(|photo| photo.id == id)?;
+        Some(self.photos.remove(index))
+    }
+    
     pub fn get_best_photos(&self, count: usize) -> Vec<&SavedPhoto> {
         let mut sorted_photos: Vec<&SavedPhoto> = self.photos.iter().collect();
         sorted_photos.sort_by(|a, b| b.score.total_score.cmp(&a.score.total_score));
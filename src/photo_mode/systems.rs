@@ -7,7 +7,8 @@ use crate::photo_mode::{components::*, resources::*};
 use crate::bird::BirdSpecies;
 use crate::animation::components::AnimatedBird;
 use crate::bird_ai::components::{BirdAI, BirdState};
-use crate::environment::resources::TimeState;
+use crate::environment::resources::{TimeState, WeatherState};
+use crate::journal::resources::ChecklistDate;
 
 pub fn setup_photo_ui(mut commands: Commands) {
     // Viewfinder UI - initially hidden
@@ -114,8 +115,12 @@ pub fn capture_photo_system(
     mut images: ResMut<Assets<Image>>,
     mut commands: Commands,
     time_state: Res<TimeState>,
+    mut disturbance: ResMut<crate::photo_mode::disturbance::PhotoDisturbance>,
+    gear: Res<crate::gear_care::GearCare>,
+    fog: Res<crate::weather_effects::fog::Fog>,
 ) {
-    if !settings.is_active || !keyboard.just_pressed(settings.capture_key) {
+    // Burst mode takes over the shutter, see burst.rs
+    if !settings.is_active || settings.burst_mode || !keyboard.just_pressed(settings.capture_key) {
         return;
     }
 
@@ -124,6 +129,90 @@ pub fn capture_photo_system(
         return;
     };
 
+    let (image_handle, mut score, species, behavior) = capture_frame(
+        &mut camera,
+        camera_controls,
+        &bird_query,
+        &mut images,
+        &mut commands,
+        &time_state,
+    );
+    
+    // Log score breakdown
+    info!("Photo Score Breakdown:");
+    info!("  Species: {}", score.species_score);
+    info!("  Behavior: {}", score.behavior_score);
+    info!("  Timing: {}", score.timing_score);
+    info!("  Centering: {}", score.centering_score);
+    info!("  Clarity: {}", score.clarity_score);
+    info!("  Composition: {}", score.composition_score);
+    info!("  Lighting: {}", score.lighting_score);
+    info!("  Environment: {}", score.environment_score);
+    info!("  Technical: {}", score.technical_score);
+    info!("  Storytelling: {}", score.storytelling_score);
+    info!("  Rarity Bonus: {}", score.rarity_bonus);
+    disturbance.penalize(&mut score);
+    gear.penalize(&mut score);
+    fog.adjust_photo(&mut score);
+    info!("  Total: {}", score.total_score);
+    if settings.flash_enabled {
+        disturbance.flash();
+    }
+    
+    photo_events.write(PhotoTakenEvent {
+        score,
+        species,
+        behavior,
+        image_handle,
+    });
+}
+
+// Renders the current view into a new image and scores it; shared by single shots and bursts
+pub fn capture_frame(
+    camera: &mut Camera,
+    camera_controls: &CameraControls,
+    bird_query: &Query<(&Transform, &AnimatedBird, &BirdState), With<BirdAI>>,
+    images: &mut Assets<Image>,
+    commands: &mut Commands,
+    time_state: &TimeState,
+) -> (Handle<Image>, PhotoScore, Option<BirdSpecies>, Option<BirdState>) {
+    let image_handle = new_photo_texture(images);
+    
+    // Temporarily set camera to render to our texture
+    let original_target = camera.target.clone();
+    camera.target = RenderTarget::Image(ImageRenderTarget::from(image_handle.clone()));
+    
+    // Schedule restoration of camera target for next frame
+    commands.queue(move |world: &mut World| {
+        let mut camera_query = world.query_filtered::<&mut Camera, With<PhotoTarget>>();
+        if let Ok(mut camera) = camera_query.single_mut(world) {
+            camera.target = original_target;
+        }
+    });
+
+    // The aperture's blur is laid on once the depth bands have rendered, see depth_of_field.rs
+    let (photo, focus, aperture) = (image_handle.clone(), camera_controls.focus_distance, camera_controls.aperture);
+    commands.queue(move |world: &mut World| {
+        world.resource_mut::<crate::photo_mode::depth_of_field::DepthOfFieldJob>().request(photo, focus, aperture);
+    });
+
+    // Find closest bird for subject analysis
+    let camera_pos = Vec2::ZERO; // Camera center for analysis
+    let closest_bird = find_closest_bird_to_center(bird_query, camera_pos);
+    
+    // Calculate photo score using enhanced system
+    let score = crate::photo_mode::advanced_systems::enhanced_photo_scoring_system(
+        bird_query, 
+        time_state, 
+        camera_controls, 
+        closest_bird
+    );
+    
+    (image_handle, score, closest_bird.map(|(_, bird, _)| bird.species), closest_bird.map(|(_, _, state)| state))
+}
+
+// Blank 800x600 render target that a camera can draw a photo into
+pub fn new_photo_texture(images: &mut Assets<Image>) -> Handle<Image> {
     // Create render texture for screenshot
     let size = Extent3d {
         width: 800,
@@ -146,52 +235,7 @@ pub fn capture_photo_system(
     };
     render_texture.resize(size);
 
-    let image_handle = images.add(render_texture);
-    
-    // Temporarily set camera to render to our texture
-    let original_target = camera.target.clone();
-    camera.target = RenderTarget::Image(ImageRenderTarget::from(image_handle.clone()));
-    
-    // Schedule restoration of camera target for next frame
-    commands.queue(move |world: &mut World| {
-        let mut camera_query = world.query::<&mut Camera>();
-        if let Ok(mut camera) = camera_query.single_mut(world) {
-            camera.target = original_target;
-        }
-    });
-
-    // Find closest bird for subject analysis
-    let camera_pos = Vec2::ZERO; // Camera center for analysis
-    let closest_bird = find_closest_bird_to_center(&bird_query, camera_pos);
-    
-    // Calculate photo score using enhanced system
-    let score = crate::photo_mode::advanced_systems::enhanced_photo_scoring_system(
-        &bird_query, 
-        &time_state, 
-        &camera_controls, 
-        closest_bird
-    );
-    
-    // Log score breakdown
-    info!("Photo Score Breakdown:");
-    info!("  Species: {}", score.species_score);
-    info!("  Behavior: {}", score.behavior_score);
-    info!("  Timing: {}", score.timing_score);
-    info!("  Centering: {}", score.centering_score);
-    info!("  Clarity: {}", score.clarity_score);
-    info!("  Composition: {}", score.composition_score);
-    info!("  Lighting: {}", score.lighting_score);
-    info!("  Environment: {}", score.environment_score);
-    info!("  Technical: {}", score.technical_score);
-    info!("  Storytelling: {}", score.storytelling_score);
-    info!("  Rarity Bonus: {}", score.rarity_bonus);
-    info!("  Total: {}", score.total_score);
-    
-    photo_events.write(PhotoTakenEvent {
-        score,
-        species: closest_bird.map(|(_, bird, _)| bird.species),
-        image_handle,
-    });
+    images.add(render_texture)
 }
 
 pub fn photo_reward_system(
@@ -202,6 +246,9 @@ pub fn photo_reward_system(
     mut toast_query: Query<(&mut Visibility, &Children, &mut ScoreToast)>,
     mut text_query: Query<&mut Text>,
     time: Res<Time>,
+    time_state: Res<TimeState>,
+    weather_state: Res<WeatherState>,
+    difficulty: Res<crate::difficulty::DifficultySettings>,
 ) {
     for event in photo_events.read() {
         // Grant currency based on total score
@@ -209,9 +256,12 @@ pub fn photo_reward_system(
         
         let mut bonus_text = String::new();
         
-        // Check for new species discovery
+        // Check for new species discovery; stricter settings need a sharper, better framed shot
+        let identifiable = event.score.centering_score + event.score.clarity_score >= difficulty.identification_threshold();
         if let Some(species) = event.species {
-            if discovered_species.discover(species) {
+            if !identifiable && !discovered_species.is_discovered(&species) {
+                bonus_text = " Too unclear to identify".to_string();
+            } else if discovered_species.discover(species) {
                 bonus_text = format!(" New Species Bonus!");
                 currency.0 += 50; // Bonus for first discovery
             }
@@ -239,10 +289,15 @@ pub fn photo_reward_system(
         
         // Save photo to collection
         photo_collection.add_photo(SavedPhoto {
+            id: 0,
             species: event.species,
             score: event.score.clone(),
             image_handle: event.image_handle.clone(),
             timestamp: time.elapsed().as_secs_f64(),
+            taken_on: ChecklistDate { year: time_state.year, day_of_year: time_state.day_of_year },
+            hour: time_state.hour,
+            weather: weather_state.current_weather,
+            behavior: event.behavior,
         });
         
         info!("Currency awarded: {} (Total: {})", event.score.total_score, currency.0);
@@ -330,6 +385,8 @@ fn calculate_photo_score(
         BirdState::Caching => 65,    // Rare seed caching behavior
         BirdState::Retrieving => 55, // Intelligent cache retrieval behavior
         BirdState::HoverFeeding => 70, // Spectacular hovering nectar feeding
+        BirdState::Begging => 70,    // Juvenile begging from a parent
+        BirdState::Drumming => 60,   // Woodpecker drumming or excavating
         BirdState::Fleeing => 30,    // Action shot bonus
         BirdState::Resting => 25,    // Peaceful moment
         BirdState::MovingToTarget => 20, // Bird in motion
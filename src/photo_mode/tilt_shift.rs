@@ -0,0 +1,91 @@
+// Tilt-Shift - Miniature look applied to exported photos and panoramas
+//
+// A band across the lower middle of the picture stays sharp while everything above and below it
+// softens the further it is from the band, the way a tilted lens throws a real scene out of focus
+// and makes it read as a model. Colors are pushed a little richer to finish the toy-like look.
+// Runs on the read-back pixels just before they're written, so the preview stays untouched.
+
+// Middle of the sharp band, as a share of the height from the top; birds sit low in the frame
+const FOCUS_CENTER: f32 = 0.58;
+// Half the height of the sharp band, and of the falloff to full blur beyond it
+const FOCUS_HALF_HEIGHT: f32 = 0.12;
+const FALLOFF: f32 = 0.25;
+// Blur radius at the edges as a share of the height
+const MAX_BLUR: f32 = 0.012;
+const SATURATION_BOOST: f32 = 1.3;
+
+/// Applies the miniature effect in place to tightly packed RGBA8 pixels
+pub fn apply_tilt_shift(pixels: &mut [u8], width: u32, height: u32) {
+    let (width, height) = (width as usize, height as usize);
+    if width == 0 || height == 0 || pixels.len() < width * height * 4 {
+        return;
+    }
+    let radius = ((height as f32 * MAX_BLUR).round() as usize).max(1);
+    let blurred = box_blur(pixels, width, height, radius);
+
+    for y in 0..height {
+        let distance = ((y as f32 + 0.5) / height as f32 - FOCUS_CENTER).abs();
+        let amount = ((distance - FOCUS_HALF_HEIGHT) / FALLOFF).clamp(0.0, 1.0);
+        for x in 0..width {
+            let i = (y * width + x) * 4;
+            let mut rgb = [0.0; 3];
+            for (c, value) in rgb.iter_mut().enumerate() {
+                *value = pixels[i + c] as f32 + (blurred[i + c] as f32 - pixels[i + c] as f32) * amount;
+            }
+            let luma = 0.299 * rgb[0] + 0.587 * rgb[1] + 0.114 * rgb[2];
+            for (c, value) in rgb.iter().enumerate() {
+                pixels[i + c] = (luma + (value - luma) * SATURATION_BOOST).clamp(0.0, 255.0) as u8;
+            }
+        }
+    }
+}
+
+/// Two box passes each way over all four channels; close enough to a gaussian and cheap at export sizes
+pub fn box_blur(pixels: &[u8], width: usize, height: usize, radius: usize) -> Vec<u8> {
+    let mut current = pixels.to_vec();
+    let mut scratch = vec![0u8; current.len()];
+    for _ in 0..2 {
+        blur_pass(&current, &mut scratch, width, height, radius, true);
+        blur_pass(&scratch, &mut current, width, height, radius, false);
+    }
+    current
+}
+
+fn blur_pass(source: &[u8], target: &mut [u8], width: usize, height: usize, radius: usize, horizontal: bool) {
+    let (lines, length) = if horizontal { (height, width) } else { (width, height) };
+    let index = |line: usize, along: usize| if horizontal { (line * width + along) * 4 } else { (along * width + line) * 4 };
+
+    for line in 0..lines {
+        // Running sum over the window, so the cost doesn't grow with the radius
+        let mut sum = [0u32; 4];
+        for sample in 0..=radius.min(length - 1) {
+            let i = index(line, sample);
+            for (c, total) in sum.iter_mut().enumerate() {
+                *total += source[i + c] as u32;
+            }
+        }
+        for along in 0..length {
+            let start = along.saturating_sub(radius);
+            let end = (along + radius).min(length - 1);
+            let count = (end - start + 1) as u32;
+            let i = index(line, along);
+            for (c, total) in sum.iter().enumerate() {
+                target[i + c] = (total / count) as u8;
+            }
+
+            // Slide the window along one pixel
+            if along + radius + 1 < length {
+                let entering = index(line, along + radius + 1);
+                for (c, total) in sum.iter_mut().enumerate() {
+                    *total += source[entering + c] as u32;
+                }
+            }
+            if along >= radius {
+                let leaving = index(line, along - radius);
+                for (c, total) in sum.iter_mut().enumerate() {
+                    *total -= source[leaving + c] as u32;
+                }
+            }
+        }
+    }
+}
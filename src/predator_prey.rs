@@ -19,7 +19,7 @@ impl Plugin for PredatorPreyPlugin {
                 prey_response_system,
                 alert_call_system,
                 predator_detection_system,
-            ).run_if(in_state(crate::AppState::Playing)));
+            ).in_set(crate::GameSet::AI).run_if(in_state(crate::AppState::Playing)));
     }
 }
 
@@ -93,6 +93,7 @@ pub fn predator_spawning_system(
     predator_query: Query<Entity, With<Predator>>,
     time: Res<Time>,
     asset_server: Res<AssetServer>,
+    difficulty: Res<crate::difficulty::DifficultySettings>,
 ) {
     // Update spawn cooldown
     predator_manager.attack_cooldown.tick(time.delta());
@@ -107,7 +108,7 @@ pub fn predator_spawning_system(
     
     // Spawn a predator occasionally
     if predator_manager.attack_cooldown.finished() {
-        if rand::random::<f32>() < 0.05 { // 5% chance when cooldown expires
+        if rand::random::<f32>() < 0.05 * difficulty.predator_frequency() { // 5% chance when cooldown expires
             spawn_predator(&mut commands, &asset_server);
             predator_manager.attack_cooldown = Timer::from_seconds(120.0, TimerMode::Once);
             info!("Spawned predator");
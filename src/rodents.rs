@@ -0,0 +1,462 @@
+// Rodents - Mice and rats drawn to stale spilled seed at night, and the owls that come hunting them
+use bevy::prelude::*;
+use crate::bird::BirdSpecies;
+use crate::compost::Compost;
+use crate::domestic_threats::StewardRatingEvent;
+use crate::environment::resources::TimeState;
+use crate::feeder_physics::SeedSpill;
+use crate::garden_lines::LineDrawing;
+use crate::terrain_painting::TerrainBrush;
+use crate::photo_mode::components::{PhotoTakenEvent, PhotoTarget};
+use crate::photo_mode::resources::CurrencyResource;
+use crate::notifications::{resources::ShowNotificationEvent, components::NotificationType};
+use crate::camera::cursor_world_position;
+
+const MAX_RODENTS: usize = 6;
+const RAT_SPILL_AMOUNT: f32 = 12.0;
+const RODENT_EDGE_X: f32 = 650.0;
+const OWL_PERCH_HEIGHT: f32 = 140.0;
+const OWL_PHOTO_RANGE: f32 = 250.0;
+const RAKE_RANGE: f32 = 30.0;
+
+pub struct RodentsPlugin;
+
+impl Plugin for RodentsPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<RodentManager>()
+            .add_systems(Update, (
+                rodent_spawning_system,
+                rodent_movement_system,
+                owl_attraction_system,
+                mousing_owl_system,
+                owl_photo_system,
+                rake_spill_system.run_if(crate::debug_console::console_is_not_visible),
+            ).chain().in_set(crate::GameSet::Simulation).run_if(in_state(crate::AppState::Playing)));
+    }
+}
+
+// Rodents only come out after dark
+pub fn is_rodent_hours(hour: f32) -> bool {
+    !(6.0..20.0).contains(&hour)
+}
+
+// Resources
+#[derive(Resource)]
+pub struct RodentManager {
+    pub spawn_timer: Timer,
+    pub owl_timer: Timer,
+    pub rodents_seen: u32,
+    pub rodents_caught: u32,
+    pub spills_raked: u32,
+}
+
+impl Default for RodentManager {
+    fn default() -> Self {
+        Self {
+            spawn_timer: Timer::from_seconds(15.0, TimerMode::Repeating),
+            owl_timer: Timer::from_seconds(45.0, TimerMode::Repeating),
+            rodents_seen: 0,
+            rodents_caught: 0,
+            spills_raked: 0,
+        }
+    }
+}
+
+// Components
+#[derive(Component)]
+pub struct Rodent {
+    pub kind: RodentKind,
+    pub state: RodentState,
+    pub spill: Entity,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RodentKind {
+    Mouse,
+    Rat,
+}
+
+impl RodentKind {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Mouse => "mouse",
+            Self::Rat => "rat",
+        }
+    }
+
+    fn color(&self) -> Color {
+        match self {
+            Self::Mouse => Color::srgb(0.55, 0.5, 0.45),
+            Self::Rat => Color::srgb(0.35, 0.3, 0.28),
+        }
+    }
+
+    fn size(&self) -> Vec2 {
+        match self {
+            Self::Mouse => Vec2::new(10.0, 6.0),
+            Self::Rat => Vec2::new(18.0, 9.0),
+        }
+    }
+
+    fn speed(&self) -> f32 {
+        match self {
+            Self::Mouse => 90.0,
+            Self::Rat => 70.0,
+        }
+    }
+
+    // Seed eaten per second
+    fn eat_rate(&self) -> f32 {
+        match self {
+            Self::Mouse => 0.1,
+            Self::Rat => 0.3,
+        }
+    }
+
+    // Visitors and neighbors think less of a sanctuary with rats than one with the odd mouse
+    fn rating_penalty(&self) -> f32 {
+        match self {
+            Self::Mouse => -1.0,
+            Self::Rat => -3.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RodentState {
+    Approaching,
+    Feeding,
+    Leaving,
+}
+
+#[derive(Component)]
+pub struct MousingOwl {
+    pub species: BirdSpecies,
+    pub state: OwlState,
+    pub prey: Option<Entity>,
+    pub timer: Timer,
+    pub photographed: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OwlState {
+    Arriving,
+    Watching,
+    Pouncing,
+    Feeding,
+    Leaving,
+}
+
+fn rodent_spawning_system(
+    mut commands: Commands,
+    mut manager: ResMut<RodentManager>,
+    spill_query: Query<(Entity, &SeedSpill, &Transform)>,
+    rodent_query: Query<&Rodent>,
+    time_state: Res<TimeState>,
+    mut rating_events: EventWriter<StewardRatingEvent>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+    time: Res<Time>,
+) {
+    manager.spawn_timer.tick(time.delta());
+    if !manager.spawn_timer.just_finished()
+        || !is_rodent_hours(time_state.hour)
+        || rodent_query.iter().count() >= MAX_RODENTS
+    {
+        return;
+    }
+
+    // The bigger the pile, the more mouths it can feed
+    let open_spills: Vec<(Entity, &SeedSpill, &Transform)> = spill_query.iter()
+        .filter(|(_, spill, _)| spill.stale)
+        .filter(|(entity, spill, _)| {
+            let feeding = rodent_query.iter().filter(|rodent| rodent.spill == *entity).count();
+            feeding < (1 + (spill.amount / 8.0) as usize).min(3)
+        })
+        .collect();
+    if open_spills.is_empty() || rand::random::<f32>() > 0.6 {
+        return;
+    }
+
+    let (spill_entity, spill, spill_transform) = open_spills[rand::random::<u32>() as usize % open_spills.len()];
+    let kind = if spill.amount >= RAT_SPILL_AMOUNT && rand::random::<bool>() { RodentKind::Rat } else { RodentKind::Mouse };
+    let side = if rand::random::<bool>() { 1.0 } else { -1.0 };
+
+    commands.spawn((
+        Sprite::from_color(kind.color(), kind.size()),
+        Transform::from_xyz(side * RODENT_EDGE_X, spill_transform.translation.y, 0.35),
+        Rodent {
+            kind,
+            state: RodentState::Approaching,
+            spill: spill_entity,
+        },
+        Name::new("Rodent"),
+    ));
+
+    manager.rodents_seen += 1;
+    rating_events.write(StewardRatingEvent {
+        change: kind.rating_penalty(),
+        reason: format!("A {} came for spilled seed", kind.name()),
+    });
+    if manager.rodents_seen == 1 || kind == RodentKind::Rat {
+        notification_events.write(ShowNotificationEvent {
+            notification: NotificationType::Warning {
+                message: format!("A {} is feeding on spilled seed - rake it up or put a catcher tray under the feeder", kind.name()),
+            },
+        });
+    }
+}
+
+fn rodent_movement_system(
+    mut commands: Commands,
+    mut rodent_query: Query<(Entity, &mut Rodent, &mut Transform)>,
+    mut spill_query: Query<(&mut SeedSpill, &Transform), Without<Rodent>>,
+    time_state: Res<TimeState>,
+    time: Res<Time>,
+) {
+    let daylight = !is_rodent_hours(time_state.hour);
+
+    for (entity, mut rodent, mut transform) in &mut rodent_query {
+        let position = transform.translation.truncate();
+        let spill = spill_query.get_mut(rodent.spill).ok();
+        if rodent.state != RodentState::Leaving && (daylight || spill.is_none()) {
+            rodent.state = RodentState::Leaving;
+        }
+
+        let target = match rodent.state {
+            RodentState::Approaching | RodentState::Feeding => {
+                let Some((mut spill, spill_transform)) = spill else { continue };
+                let spill_position = spill_transform.translation.truncate();
+                if position.distance(spill_position) < 8.0 {
+                    rodent.state = RodentState::Feeding;
+                    spill.amount -= rodent.kind.eat_rate() * time.delta_secs();
+                    continue;
+                }
+                spill_position
+            },
+            RodentState::Leaving => {
+                let exit = Vec2::new(position.x.signum() * (RODENT_EDGE_X + 50.0), position.y);
+                if position.distance(exit) < 10.0 {
+                    commands.entity(entity).despawn();
+                    continue;
+                }
+                exit
+            },
+        };
+
+        // Rodents scurry in short bursts
+        let dash = if (time.elapsed_secs() * 3.0 + entity.index() as f32).sin() > -0.3 { 1.0 } else { 0.0 };
+        let direction = (target - position).normalize_or_zero();
+        transform.translation += (direction * rodent.kind.speed() * dash * time.delta_secs()).extend(0.0);
+    }
+}
+
+// Rodents about after dark are what bring an owl in
+fn owl_attraction_system(
+    mut commands: Commands,
+    mut manager: ResMut<RodentManager>,
+    rodent_query: Query<&Rodent>,
+    owl_query: Query<(), With<MousingOwl>>,
+    time_state: Res<TimeState>,
+    asset_server: Res<AssetServer>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+    time: Res<Time>,
+) {
+    let feeding = rodent_query.iter().filter(|rodent| rodent.state == RodentState::Feeding).count();
+    if feeding == 0 || !owl_query.is_empty() || !is_rodent_hours(time_state.hour) {
+        return;
+    }
+
+    manager.owl_timer.tick(time.delta());
+    if !manager.owl_timer.just_finished() || rand::random::<f32>() > 0.3 + feeding as f32 * 0.1 {
+        return;
+    }
+
+    let species = if rand::random::<bool>() { BirdSpecies::GreatHornedOwl } else { BirdSpecies::BarredOwl };
+    let side = if rand::random::<bool>() { 1.0 } else { -1.0 };
+    let sprite_path = format!("sprites/birds/{:?}.png", species).to_lowercase();
+
+    commands.spawn((
+        Sprite {
+            image: asset_server.load(sprite_path),
+            custom_size: Some(Vec2::splat(36.0)),
+            ..default()
+        },
+        Transform::from_xyz(side * 750.0, 300.0, 0.9),
+        MousingOwl {
+            species,
+            state: OwlState::Arriving,
+            prey: None,
+            timer: Timer::from_seconds(8.0, TimerMode::Once),
+            photographed: false,
+        },
+        Name::new("MousingOwl"),
+    ));
+
+    notification_events.write(ShowNotificationEvent {
+        notification: NotificationType::Info {
+            message: "An owl has come hunting the rodents - grab the camera!".to_string(),
+        },
+    });
+}
+
+fn mousing_owl_system(
+    mut commands: Commands,
+    mut owl_query: Query<(Entity, &mut MousingOwl, &mut Transform)>,
+    rodent_query: Query<(Entity, &Transform), (With<Rodent>, Without<MousingOwl>)>,
+    mut manager: ResMut<RodentManager>,
+    time_state: Res<TimeState>,
+    time: Res<Time>,
+) {
+    for (entity, mut owl, mut transform) in &mut owl_query {
+        owl.timer.tick(time.delta());
+        let position = transform.translation.truncate();
+
+        // Pick the nearest rodent to hunt, or give up if they have all gone
+        let prey_position = owl.prey.and_then(|prey| rodent_query.get(prey).ok()).map(|(_, prey)| prey.translation.truncate());
+        if prey_position.is_none() && !matches!(owl.state, OwlState::Feeding | OwlState::Leaving) {
+            owl.prey = rodent_query.iter()
+                .min_by(|a, b| {
+                    let distance_a = a.1.translation.truncate().distance(position);
+                    let distance_b = b.1.translation.truncate().distance(position);
+                    distance_a.total_cmp(&distance_b)
+                })
+                .map(|(rodent, _)| rodent);
+            if owl.prey.is_none() {
+                owl.state = OwlState::Leaving;
+            } else if owl.state == OwlState::Pouncing {
+                owl.state = OwlState::Watching;
+            }
+            continue;
+        }
+        if !is_rodent_hours(time_state.hour) && owl.state != OwlState::Leaving {
+            owl.state = OwlState::Leaving;
+        }
+
+        let (target, speed) = match owl.state {
+            OwlState::Arriving | OwlState::Watching => {
+                let Some(prey) = prey_position else { continue };
+                let perch = prey + Vec2::new(0.0, OWL_PERCH_HEIGHT);
+                if owl.state == OwlState::Arriving && position.distance(perch) < 10.0 {
+                    owl.state = OwlState::Watching;
+                    owl.timer = Timer::from_seconds(6.0 + rand::random::<f32>() * 6.0, TimerMode::Once);
+                } else if owl.state == OwlState::Watching && owl.timer.finished() {
+                    owl.state = OwlState::Pouncing;
+                }
+                (perch, 160.0)
+            },
+            OwlState::Pouncing => {
+                let Some(prey) = prey_position else { continue };
+                if position.distance(prey) < 12.0 {
+                    if let Some(rodent) = owl.prey.take() {
+                        commands.entity(rodent).despawn();
+                        manager.rodents_caught += 1;
+                    }
+                    owl.state = OwlState::Feeding;
+                    owl.timer = Timer::from_seconds(5.0, TimerMode::Once);
+                    continue;
+                }
+                (prey, 380.0)
+            },
+            OwlState::Feeding => {
+                if owl.timer.finished() {
+                    // A well-stocked hunting ground keeps the owl around for another try
+                    owl.state = if rodent_query.is_empty() { OwlState::Leaving } else { OwlState::Arriving };
+                }
+                continue;
+            },
+            OwlState::Leaving => {
+                let exit = Vec2::new(position.x.signum() * 800.0, 350.0);
+                if position.distance(exit) < 20.0 {
+                    commands.entity(entity).despawn();
+                    continue;
+                }
+                (exit, 200.0)
+            },
+        };
+
+        let direction = (target - position).normalize_or_zero();
+        let step = (speed * time.delta_secs()).min(position.distance(target));
+        transform.translation += (direction * step).extend(0.0);
+        if direction.x.abs() > 0.1 {
+            transform.scale.x = direction.x.signum() * transform.scale.x.abs();
+        }
+    }
+}
+
+// The silver lining of a rodent problem - owl hunting shots pay well
+fn owl_photo_system(
+    mut photo_events: EventReader<PhotoTakenEvent>,
+    camera_query: Query<&Transform, With<PhotoTarget>>,
+    mut owl_query: Query<(&mut MousingOwl, &Transform)>,
+    mut currency: ResMut<CurrencyResource>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+) {
+    for _event in photo_events.read() {
+        let Ok(camera_transform) = camera_query.single() else { continue };
+        let camera_pos = camera_transform.translation.truncate();
+
+        for (mut owl, transform) in &mut owl_query {
+            let hunting = matches!(owl.state, OwlState::Watching | OwlState::Pouncing | OwlState::Feeding);
+            if owl.photographed || !hunting || transform.translation.truncate().distance(camera_pos) > OWL_PHOTO_RANGE {
+                continue;
+            }
+
+            owl.photographed = true;
+            let bonus = if owl.state == OwlState::Pouncing { 60 } else { 30 };
+            let name = if owl.species == BirdSpecies::GreatHornedOwl { "great horned owl" } else { "barred owl" };
+            currency.0 += bonus;
+            notification_events.write(ShowNotificationEvent {
+                notification: NotificationType::Info {
+                    message: format!("Silver lining: a {} caught on the hunt (+{} coins)", name, bonus),
+                },
+            });
+        }
+    }
+}
+
+// Right-clicking a patch of spilled seed rakes it up before it draws rodents
+fn rake_spill_system(
+    mut commands: Commands,
+    mouse_button: Res<ButtonInput<MouseButton>>,
+    windows: Query<&Window>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<Camera2d>>,
+    spill_query: Query<(Entity, &SeedSpill, &Transform)>,
+    line_drawing: Res<LineDrawing>,
+    terrain_brush: Res<TerrainBrush>,
+    mut manager: ResMut<RodentManager>,
+    mut compost: ResMut<Compost>,
+    mut rating_events: EventWriter<StewardRatingEvent>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+) {
+    if !mouse_button.just_pressed(MouseButton::Right) || line_drawing.tool.is_some() || terrain_brush.tool.is_some() {
+        return;
+    }
+
+    let Ok(window) = windows.single() else { return };
+    let Ok((camera, camera_transform)) = camera_query.single() else { return };
+    let Some(world_position) = cursor_world_position(window, camera, camera_transform) else { return };
+
+    let Some((entity, spill, _)) = spill_query.iter()
+        .find(|(_, _, transform)| transform.translation.truncate().distance(world_position) < RAKE_RANGE) else { return };
+
+    commands.entity(entity).despawn();
+    manager.spills_raked += 1;
+    compost.collect(spill.amount);
+    if spill.stale {
+        rating_events.write(StewardRatingEvent {
+            change: 1.0,
+            reason: "Raked up stale seed before it drew more rodents".to_string(),
+        });
+    }
+    notification_events.write(ShowNotificationEvent {
+        notification: NotificationType::Info {
+            message: match (spill.caught, compost.has_bin) {
+                (true, true) => "Emptied the seed catcher tray onto the compost".to_string(),
+                (true, false) => "Emptied the seed catcher tray".to_string(),
+                (false, true) => "Raked up the spilled seed onto the compost".to_string(),
+                (false, false) => "Raked up the spilled seed".to_string(),
+            },
+        },
+    });
+}
@@ -4,6 +4,17 @@ use crate::bird::{BirdSpecies};
 use crate::bird_ai::components::{SmartObject, ProvidesUtility};
 use crate::environment::components::{Season};
 use crate::advanced_weather::{WeatherShelter, ShelterType};
+use crate::bird_health::{BodyCondition, ConditionCategory, ProvisioningRecord};
+use crate::animation::components::AnimatedBird;
+use crate::feeder::Feeder;
+use crate::catalog::components::{PlaceableItemType, PlaceableObject};
+use crate::catalog::resources::ItemCategory;
+use crate::keybindings::{GameAction, KeyBindings};
+use crate::notifications::{resources::ShowNotificationEvent, components::NotificationType};
+use crate::photo_mode::components::PhotoTakenEvent;
+use crate::garden_styles::{GardenStyle, GardenStyles};
+use crate::compost::Compost;
+use std::collections::HashSet;
 
 // Additional sanctuary management extensions to ShelterType
 impl ShelterType {
@@ -102,6 +113,226 @@ pub struct PredatorManagement {
     pub protection_zones: Vec<ProtectionZone>,
 }
 
+// Aggregate body condition of all birds in the sanctuary
+#[derive(Resource, Default)]
+pub struct FlockHealth {
+    pub average_condition: f32,
+    pub birds_tracked: u32,
+    pub birds_in_poor_condition: u32,
+    pub provisioning_quality: f32,
+    pub return_rate_multiplier: f32,
+}
+
+impl FlockHealth {
+    pub fn rating(&self) -> ConditionCategory {
+        ConditionCategory::from_condition(self.average_condition)
+    }
+}
+
+// Overall quality of the sanctuary as bird habitat, 0-100
+#[derive(Resource)]
+pub struct HabitatScore {
+    pub score: f32,
+    pub species_present: u32,
+    pub feeders: u32,
+    pub enhancements: u32,
+    update_timer: Timer,
+}
+
+impl Default for HabitatScore {
+    fn default() -> Self {
+        Self {
+            score: 0.0,
+            species_present: 0,
+            feeders: 0,
+            enhancements: 0,
+            update_timer: Timer::from_seconds(5.0, TimerMode::Repeating),
+        }
+    }
+}
+
+impl HabitatScore {
+    pub fn rating(&self) -> &'static str {
+        match self.score {
+            s if s < 25.0 => "Sparse",
+            s if s < 50.0 => "Developing",
+            s if s < 75.0 => "Thriving",
+            _ => "Exceptional",
+        }
+    }
+}
+
+// How lightly the sanctuary treads, 0-100
+#[derive(Resource)]
+pub struct SustainabilityScore {
+    pub score: f32,
+    // Share of hulls and spilled seed kept out of the trash, 0-1
+    pub waste_diverted: f32,
+    pub catcher_trays: u32,
+    update_timer: Timer,
+}
+
+impl Default for SustainabilityScore {
+    fn default() -> Self {
+        Self {
+            score: 0.0,
+            waste_diverted: 0.0,
+            catcher_trays: 0,
+            update_timer: Timer::from_seconds(5.0, TimerMode::Repeating),
+        }
+    }
+}
+
+impl SustainabilityScore {
+    pub fn rating(&self) -> &'static str {
+        match self.score {
+            s if s < 25.0 => "Wasteful",
+            s if s < 50.0 => "Improving",
+            s if s < 75.0 => "Green",
+            _ => "Exemplary",
+        }
+    }
+
+    // Reputation points a well-run yard earns, up to 10
+    pub fn reputation_bonus(&self) -> f32 {
+        (self.score / 10.0).round()
+    }
+}
+
+// Standing of the sanctuary among local birders, 0-100
+#[derive(Resource, Default)]
+pub struct SanctuaryReputation {
+    pub score: f32,
+    pub tier: ReputationTier,
+    pub species_recorded: HashSet<BirdSpecies>,
+    pub rare_sightings: u32,
+    pub photo_quality: f32, // Rolling average of photo scores, 0-1
+    pub commissions_completed: u32,
+    // Points from active garden style sets
+    pub style_bonus: f32,
+    // Points from the sustainability score
+    pub sustainability_bonus: f32,
+    pub screen_open: bool,
+    screen_dirty: bool,
+    needs_update: bool,
+}
+
+impl SanctuaryReputation {
+    // Diversity over time, rare visitors, photo quality and fulfilled commissions
+    pub fn recalculate(&mut self) -> ReputationTier {
+        let diversity = (self.species_recorded.len() as f32 / 20.0).min(1.0) * 40.0;
+        let rarity = (self.rare_sightings as f32 / 15.0).min(1.0) * 30.0;
+        let photos = self.photo_quality.clamp(0.0, 1.0) * 20.0;
+        let commissions = (self.commissions_completed as f32 / 5.0).min(1.0) * 10.0;
+
+        self.score = (diversity + rarity + photos + commissions + self.style_bonus + self.sustainability_bonus).min(100.0);
+        ReputationTier::from_score(self.score)
+    }
+
+    // Called whenever garden style ratings change, so the screen shows the new ratings too
+    pub fn set_style_bonus(&mut self, bonus: f32) {
+        if self.style_bonus != bonus {
+            self.style_bonus = bonus;
+            self.needs_update = true;
+        }
+        self.screen_dirty = true;
+    }
+
+    pub fn set_sustainability_bonus(&mut self, bonus: f32) {
+        if self.sustainability_bonus != bonus {
+            self.sustainability_bonus = bonus;
+            self.needs_update = true;
+        }
+    }
+
+    pub fn record_commission(&mut self) {
+        self.commissions_completed += 1;
+        self.needs_update = true;
+    }
+
+    // Restored progress is picked up without announcing every tier again
+    pub fn restore(&mut self, species: HashSet<BirdSpecies>, rare_sightings: u32, photo_quality: f32, commissions: u32) {
+        self.species_recorded = species;
+        self.rare_sightings = rare_sightings;
+        self.photo_quality = photo_quality;
+        self.commissions_completed = commissions;
+        self.tier = self.recalculate();
+        self.screen_dirty = true;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum ReputationTier {
+    #[default]
+    Unknown,
+    Local,
+    Regional,
+    Renowned,
+    Celebrated,
+}
+
+impl ReputationTier {
+    pub const ALL: [ReputationTier; 5] = [
+        Self::Unknown,
+        Self::Local,
+        Self::Regional,
+        Self::Renowned,
+        Self::Celebrated,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Unknown => "Unknown",
+            Self::Local => "Local Favorite",
+            Self::Regional => "Regional Hotspot",
+            Self::Renowned => "Renowned Sanctuary",
+            Self::Celebrated => "Celebrated Haven",
+        }
+    }
+
+    pub fn threshold(&self) -> f32 {
+        match self {
+            Self::Unknown => 0.0,
+            Self::Local => 20.0,
+            Self::Regional => 40.0,
+            Self::Renowned => 60.0,
+            Self::Celebrated => 80.0,
+        }
+    }
+
+    pub fn from_score(score: f32) -> Self {
+        Self::ALL.into_iter()
+            .rev()
+            .find(|tier| score >= tier.threshold())
+            .unwrap_or(Self::Unknown)
+    }
+
+    pub fn next(&self) -> Option<Self> {
+        Self::ALL.into_iter().find(|tier| tier > self)
+    }
+
+    // Perks beyond catalog items, which are listed from the catalog itself
+    pub fn perks(&self) -> &'static [&'static str] {
+        match self {
+            Self::Unknown => &["Visits from neighbors, rangers and scientists"],
+            Self::Local => &["Birding club members drop by with donations"],
+            Self::Regional => &["Visitors come by more often"],
+            Self::Renowned => &["Collectors offer paid photo commissions"],
+            Self::Celebrated => &["Larger donations and commission payouts"],
+        }
+    }
+
+    // Scales how often human visitors come by
+    pub fn visit_rate(&self) -> f32 {
+        match self {
+            Self::Unknown | Self::Local => 1.0,
+            Self::Regional => 1.4,
+            Self::Renowned => 1.7,
+            Self::Celebrated => 2.0,
+        }
+    }
+}
+
 #[derive(Component, Debug, Clone)]
 pub struct PredatorDeterrent {
     pub deterrent_type: DeterrentType,
@@ -120,6 +351,7 @@ pub enum DeterrentType {
     ScareOwl,               // Small mammals
     ProtectiveMesh,         // Physical barrier
     NaturalBarrier,         // Thorny bushes, etc.
+    GardenFence,            // Keeps out dogs, slows cats
 }
 
 impl DeterrentType {
@@ -131,6 +363,9 @@ impl DeterrentType {
             (Self::ScareOwl, PredatorType::Rodent) => 0.5,
             (Self::ProtectiveMesh, _) => 0.9,
             (Self::NaturalBarrier, PredatorType::Cat) => 0.4,
+            (Self::GardenFence, PredatorType::Dog) => 0.95,
+            (Self::GardenFence, PredatorType::Cat) => 0.3,
+            (Self::MotionActivatedSprinkler, PredatorType::Dog) => 0.6,
             _ => 0.1,
         }
     }
@@ -143,6 +378,7 @@ impl DeterrentType {
             Self::ScareOwl => 100,
             Self::ProtectiveMesh => 250,
             Self::NaturalBarrier => 150,
+            Self::GardenFence => 400,
         }
     }
     
@@ -154,6 +390,7 @@ impl DeterrentType {
             Self::ScareOwl => 90.0,
             Self::ProtectiveMesh => 120.0,
             Self::NaturalBarrier => 180.0,
+            Self::GardenFence => 365.0,
         }
     }
 }
@@ -161,6 +398,7 @@ impl DeterrentType {
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum PredatorType {
     Cat,
+    Dog,
     Hawk,
     Snake,
     Rodent,
@@ -273,6 +511,10 @@ impl EnhancementType {
 // Note: WeatherShelter and NestingBox are components, not trait implementations
 // They integrate with the SmartObject system through the existing BirdAction mechanism
 
+// Progression screen listing reputation thresholds and unlocks
+#[derive(Component)]
+pub struct ReputationScreen;
+
 // Events
 #[derive(Event)]
 pub struct PredatorSpottedEvent {
@@ -310,12 +552,25 @@ impl Plugin for SanctuaryManagementPlugin {
             .add_event::<PredatorSpottedEvent>()
             .add_event::<NestingEvent>()
             .add_event::<ShelterOccupancyEvent>()
+            .init_resource::<FlockHealth>()
+            .init_resource::<HabitatScore>()
+            .init_resource::<SanctuaryReputation>()
+            .init_resource::<SustainabilityScore>()
             .add_systems(OnEnter(crate::AppState::Playing), setup_sanctuary_objects)
             .add_systems(Update, (
                 nesting_box_system,
                 predator_management_system,
                 shelter_maintenance_system,
-            ).run_if(in_state(crate::AppState::Playing)));
+                flock_health_system,
+                habitat_score_system,
+                sustainability_score_system,
+                reputation_system,
+            ).in_set(crate::GameSet::Simulation).run_if(in_state(crate::AppState::Playing)))
+            .add_systems(Update, (
+                toggle_reputation_screen_system,
+                refresh_reputation_screen_system,
+            ).chain().in_set(crate::GameSet::UI).run_if(in_state(crate::AppState::Playing)))
+            .add_systems(OnExit(crate::AppState::Playing), close_reputation_screen_system);
     }
 }
 
@@ -335,6 +590,20 @@ fn setup_sanctuary_objects(mut commands: Commands) {
         },
     ));
     
+    // Garden fence along the yard boundary keeps neighborhood dogs out
+    commands.spawn((
+        Transform::from_xyz(0.0, -280.0, 0.4),
+        Sprite::from_color(Color::srgb(0.55, 0.45, 0.35), Vec2::new(600.0, 12.0)),
+        PredatorDeterrent {
+            deterrent_type: DeterrentType::GardenFence,
+            position: Vec3::new(0.0, -280.0, 0.4),
+            effectiveness: 0.9,
+            range: 320.0,
+            maintenance_timer: Timer::from_seconds(600.0, TimerMode::Repeating),
+            active: true,
+        },
+    ));
+    
     // Spawn nesting boxes
     commands.spawn((
         Transform::from_xyz(150.0, 120.0, 0.6),
@@ -392,4 +661,345 @@ fn shelter_maintenance_system(
             shelter.current_occupancy = shelter.capacity;
         }
     }
-}
\ No newline at end of file
+}
+
+fn flock_health_system(
+    condition_query: Query<&BodyCondition>,
+    provisioning: Res<ProvisioningRecord>,
+    mut flock_health: ResMut<FlockHealth>,
+) {
+    let mut total = 0.0;
+    let mut tracked = 0;
+    let mut poor = 0;
+
+    for condition in condition_query.iter() {
+        total += condition.condition;
+        tracked += 1;
+        if condition.category() == ConditionCategory::Poor {
+            poor += 1;
+        }
+    }
+
+    flock_health.average_condition = if tracked > 0 { total / tracked as f32 } else { 0.0 };
+    flock_health.birds_tracked = tracked;
+    flock_health.birds_in_poor_condition = poor;
+    flock_health.provisioning_quality = provisioning.rolling_quality;
+    flock_health.return_rate_multiplier = provisioning.return_rate_multiplier;
+}
+
+fn habitat_score_system(
+    bird_query: Query<&AnimatedBird>,
+    feeder_query: Query<&Feeder>,
+    enhancement_query: Query<&HabitatEnhancement>,
+    deterrent_query: Query<&PredatorDeterrent>,
+    flock_health: Res<FlockHealth>,
+    terrain: Res<crate::terrain_painting::TerrainMap>,
+    mut habitat: ResMut<HabitatScore>,
+    time: Res<Time>,
+) {
+    habitat.update_timer.tick(time.delta());
+    if !habitat.update_timer.just_finished() {
+        return;
+    }
+
+    let species: HashSet<BirdSpecies> = bird_query.iter().map(|bird| bird.species).collect();
+    let feeders = feeder_query.iter().count() as u32;
+    let enhancements = enhancement_query.iter().count() as u32;
+    let deterrents = deterrent_query.iter().filter(|deterrent| deterrent.active).count();
+
+    // Diversity matters most, then food, plantings, bird condition and protection
+    let diversity = (species.len() as f32 / 12.0).min(1.0) * 40.0;
+    let food = (feeders as f32 / 4.0).min(1.0) * 20.0;
+    let plantings = ((enhancements as f32 + terrain.planting_value()) / 5.0).min(1.0) * 15.0;
+    let condition = flock_health.average_condition.clamp(0.0, 1.0) * 15.0;
+    let protection = (deterrents as f32 / 3.0).min(1.0) * 10.0;
+
+    habitat.score = diversity + food + plantings + condition + protection;
+    habitat.species_present = species.len() as u32;
+    habitat.feeders = feeders;
+    habitat.enhancements = enhancements;
+}
+
+#[allow(clippy::too_many_arguments)]
+fn sustainability_score_system(
+    object_query: Query<&PlaceableObject>,
+    feeder_query: Query<&Feeder>,
+    enhancement_query: Query<&HabitatEnhancement>,
+    compost: Res<Compost>,
+    terrain: Res<crate::terrain_painting::TerrainMap>,
+    mut sustainability: ResMut<SustainabilityScore>,
+    mut reputation: ResMut<SanctuaryReputation>,
+    time: Res<Time>,
+) {
+    sustainability.update_timer.tick(time.delta());
+    if !sustainability.update_timer.just_finished() {
+        return;
+    }
+
+    let feeders = feeder_query.iter().count() as u32;
+    let trays = object_query.iter().filter(|object| object.item_type == PlaceableItemType::SeedCatcherTray).count() as u32;
+    let native_plantings = enhancement_query.iter()
+        .filter(|enhancement| enhancement.enhancement_type == EnhancementType::NativePlanting)
+        .count() as f32;
+
+    // Composting counts most, then feeding the compost back, native plantings and catching spills
+    let diverted = compost.diverted_share() * 40.0;
+    let returned = (compost.fertilizer_spread / 20.0).min(1.0) * 20.0;
+    let plantings = ((native_plantings + terrain.planting_value()) / 5.0).min(1.0) * 20.0;
+    let catching = if feeders > 0 { (trays as f32 / feeders as f32).min(1.0) * 20.0 } else { 0.0 };
+
+    sustainability.score = diverted + returned + plantings + catching;
+    sustainability.waste_diverted = compost.diverted_share();
+    sustainability.catcher_trays = trays;
+    reputation.set_sustainability_bonus(sustainability.reputation_bonus());
+}
+
+fn reputation_system(
+    new_birds: Query<&AnimatedBird, Added<AnimatedBird>>,
+    mut photo_events: EventReader<PhotoTakenEvent>,
+    mut reputation: ResMut<SanctuaryReputation>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+) {
+    let mut changed = std::mem::take(&mut reputation.needs_update);
+
+    for bird in &new_birds {
+        changed |= reputation.species_recorded.insert(bird.species);
+        if bird.species.rarity_tier() >= 3 {
+            reputation.rare_sightings += 1;
+            changed = true;
+        }
+    }
+
+    for event in photo_events.read() {
+        let quality = (event.score.total_score as f32 / 250.0).min(1.0);
+        reputation.photo_quality = reputation.photo_quality * 0.9 + quality * 0.1;
+        changed = true;
+    }
+
+    if !changed {
+        return;
+    }
+
+    let tier = reputation.recalculate();
+    if tier > reputation.tier {
+        notification_events.write(ShowNotificationEvent {
+            notification: NotificationType::Info {
+                message: format!("Your sanctuary is now a {}!", tier.name()),
+            },
+        });
+        info!("⭐ Sanctuary reputation reached {} ({:.0})", tier.name(), reputation.score);
+    }
+    reputation.tier = tier;
+    reputation.screen_dirty = true;
+}
+
+fn toggle_reputation_screen_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    key_bindings: Res<KeyBindings>,
+    mut reputation: ResMut<SanctuaryReputation>,
+) {
+    if key_bindings.is_action_just_pressed(GameAction::OpenReputation, &keyboard, &mouse) {
+        reputation.screen_open = !reputation.screen_open;
+        reputation.screen_dirty = true;
+    }
+}
+
+fn refresh_reputation_screen_system(
+    mut commands: Commands,
+    mut reputation: ResMut<SanctuaryReputation>,
+    garden_styles: Res<GardenStyles>,
+    sustainability: Res<SustainabilityScore>,
+    flock_health: Res<FlockHealth>,
+    screen_query: Query<Entity, With<ReputationScreen>>,
+) {
+    if !reputation.screen_dirty {
+        return;
+    }
+    reputation.screen_dirty = false;
+
+    for screen in &screen_query {
+        commands.entity(screen).despawn();
+    }
+
+    if reputation.screen_open {
+        spawn_reputation_screen(&mut commands, &reputation, &garden_styles, &sustainability, &flock_health);
+    }
+}
+
+fn close_reputation_screen_system(
+    mut commands: Commands,
+    mut reputation: ResMut<SanctuaryReputation>,
+    screen_query: Query<Entity, With<ReputationScreen>>,
+) {
+    reputation.screen_open = false;
+    for screen in &screen_query {
+        commands.entity(screen).despawn();
+    }
+}
+
+fn spawn_reputation_screen(
+    commands: &mut Commands,
+    reputation: &SanctuaryReputation,
+    garden_styles: &GardenStyles,
+    sustainability: &SustainabilityScore,
+    flock_health: &FlockHealth,
+) {
+    let catalog_items: Vec<PlaceableItemType> = [
+        ItemCategory::Comfort,
+        ItemCategory::Food,
+        ItemCategory::Water,
+        ItemCategory::Decorative,
+        ItemCategory::Special,
+    ].iter().flat_map(|category| category.items()).collect();
+
+    commands.spawn((
+        Node {
+            width: Val::Px(460.0),
+            position_type: PositionType::Absolute,
+            left: Val::Percent(50.0),
+            top: Val::Px(70.0),
+            margin: UiRect::left(Val::Px(-230.0)),
+            flex_direction: FlexDirection::Column,
+            padding: UiRect::all(Val::Px(14.0)),
+            row_gap: Val::Px(8.0),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.95, 0.92, 0.88, 0.97)),
+        BorderRadius::all(Val::Px(8.0)),
+        ZIndex(30),
+        ReputationScreen,
+    )).with_children(|panel| {
+        panel.spawn((
+            Text::new(format!("Sanctuary Reputation - {}", reputation.tier.name())),
+            TextFont { font_size: 18.0, ..default() },
+            TextColor(Color::srgb(0.3, 0.2, 0.1)),
+        ));
+
+        // Meter
+        panel.spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Px(14.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.85, 0.82, 0.78)),
+            BorderRadius::all(Val::Px(7.0)),
+        )).with_children(|meter| {
+            meter.spawn((
+                Node {
+                    width: Val::Percent(reputation.score.clamp(0.0, 100.0)),
+                    height: Val::Percent(100.0),
+                    ..default()
+                },
+                BackgroundColor(Color::srgb(0.5, 0.7, 0.5)),
+                BorderRadius::all(Val::Px(7.0)),
+            ));
+        });
+
+        let next_line = match reputation.tier.next() {
+            Some(next) => format!("{:.0} / 100  •  {:.0} more to reach {}", reputation.score, next.threshold() - reputation.score, next.name()),
+            None => format!("{:.0} / 100  •  Highest standing reached", reputation.score),
+        };
+        panel.spawn((
+            Text::new(next_line),
+            TextFont { font_size: 12.0, ..default() },
+            TextColor(Color::srgb(0.5, 0.4, 0.3)),
+        ));
+
+        panel.spawn((
+            Text::new(format!(
+                "Species recorded: {}   Rare sightings: {}   Photo quality: {:.0}%   Commissions: {}",
+                reputation.species_recorded.len(),
+                reputation.rare_sightings,
+                reputation.photo_quality * 100.0,
+                reputation.commissions_completed,
+            )),
+            TextFont { font_size: 12.0, ..default() },
+            TextColor(Color::srgb(0.4, 0.3, 0.2)),
+        ));
+
+        panel.spawn((
+            Text::new(format!(
+                "Sustainability: {:.0} ({})  •  {:.0}% of waste composted  •  +{:.0} reputation",
+                sustainability.score,
+                sustainability.rating(),
+                sustainability.waste_diverted * 100.0,
+                reputation.sustainability_bonus,
+            )),
+            TextFont { font_size: 12.0, ..default() },
+            TextColor(Color::srgb(0.3, 0.45, 0.3)),
+        ));
+
+        // Body condition feeds the habitat score, and steady feeding brings birds back more often
+        let flock_line = if flock_health.birds_tracked == 0 {
+            "Flock health: no birds to assess yet".to_string()
+        } else {
+            format!(
+                "Flock health: {} ({:.0}%)  •  {} of {} in poor condition  •  Feeding {:.0}%  •  Return visits x{:.2}",
+                flock_health.rating().label(),
+                flock_health.average_condition * 100.0,
+                flock_health.birds_in_poor_condition,
+                flock_health.birds_tracked,
+                flock_health.provisioning_quality * 100.0,
+                flock_health.return_rate_multiplier,
+            )
+        };
+        panel.spawn((
+            Text::new(flock_line),
+            TextFont { font_size: 12.0, ..default() },
+            TextColor(if flock_health.birds_in_poor_condition > 0 { Color::srgb(0.6, 0.35, 0.25) } else { Color::srgb(0.3, 0.45, 0.3) }),
+        ));
+
+        // Garden style ratings
+        for style in GardenStyle::ALL {
+            let rating = garden_styles.rating(style);
+            let status = if garden_styles.set_active(style) {
+                format!("Set bonus: {}", style.bonus_description())
+            } else {
+                format!("{} more for set bonus", style.threshold().saturating_sub(rating.pieces))
+            };
+            panel.spawn((
+                Text::new(format!("{} style  {}  {}/{}  •  {}", style.name(), rating.star_string(), rating.pieces, rating.total, status)),
+                TextFont { font_size: 12.0, ..default() },
+                TextColor(if garden_styles.set_active(style) { Color::srgb(0.3, 0.45, 0.3) } else { Color::srgb(0.5, 0.4, 0.3) }),
+            ));
+        }
+
+        for tier in ReputationTier::ALL {
+            let reached = reputation.tier >= tier;
+            let mut unlocks: Vec<String> = tier.perks().iter().map(|perk| perk.to_string()).collect();
+            let items: Vec<&str> = catalog_items.iter()
+                .filter(|item| item.required_reputation() == tier && tier != ReputationTier::Unknown)
+                .map(|item| item.name())
+                .collect();
+            if !items.is_empty() {
+                unlocks.push(format!("Catalog: {}", items.join(", ")));
+            }
+
+            panel.spawn((
+                Node {
+                    width: Val::Percent(100.0),
+                    flex_direction: FlexDirection::Column,
+                    padding: UiRect::all(Val::Px(6.0)),
+                    ..default()
+                },
+                BackgroundColor(if reached { Color::srgb(0.88, 0.93, 0.85) } else { Color::srgb(0.9, 0.88, 0.85) }),
+                BorderRadius::all(Val::Px(4.0)),
+            )).with_children(|row| {
+                row.spawn((
+                    Text::new(format!("{} {} ({:.0}+)", if reached { "✓" } else { "🔒" }, tier.name(), tier.threshold())),
+                    TextFont { font_size: 14.0, ..default() },
+                    TextColor(Color::srgb(0.3, 0.2, 0.1)),
+                ));
+                for unlock in unlocks {
+                    row.spawn((
+                        Text::new(format!("  • {}", unlock)),
+                        TextFont { font_size: 12.0, ..default() },
+                        TextColor(if reached { Color::srgb(0.3, 0.45, 0.3) } else { Color::srgb(0.55, 0.5, 0.45) }),
+                    ));
+                }
+            });
+        }
+    });
+}
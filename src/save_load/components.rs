@@ -6,4 +6,6 @@ pub struct SaveMarker;
 #[derive(Component)]
 pub struct PersistentObject {
     pub save_id: String,
-}
\ No newline at end of file
+}
+#[derive(Component)]
+pub struct SaveIndicator;
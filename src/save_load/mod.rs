@@ -3,10 +3,12 @@ use bevy::prelude::*;
 pub mod components;
 pub mod resources;
 pub mod systems;
+pub mod snapshot;
 
 // use components::*;
 use resources::*;
 use systems::*;
+use snapshot::*;
 //use crate::AppState;
 
 pub struct SaveLoadPlugin;
@@ -16,15 +18,28 @@ impl Plugin for SaveLoadPlugin {
         app
             .init_resource::<SaveManager>()
             .init_resource::<PlaytimeTracker>()
+            .init_resource::<PendingSaves>()
+            .init_resource::<ScriptStore>()
+            .init_resource::<SnapshotJob>()
             .add_event::<SaveGameEvent>()
             .add_event::<LoadGameEvent>()
             .add_event::<SaveCompleteEvent>()
             .add_event::<LoadCompleteEvent>()
             .add_systems(Update, (
                 save_game_system,
+                finish_pending_saves_system,
                 load_game_system,
                 auto_save_system,
                 track_playtime_system,
-            ));
+            ).in_set(crate::GameSet::Simulation))
+            .add_systems(Update, (start_snapshot_system, snapshot_system)
+                .chain()
+                .after(finish_pending_saves_system)
+                .in_set(crate::GameSet::Simulation)
+                .run_if(in_state(crate::AppState::Playing)))
+            .add_systems(Update, save_indicator_system
+                .in_set(crate::GameSet::UI)
+                .run_if(in_state(crate::AppState::Playing)))
+            .add_systems(Last, save_on_quit_system);
     }
 }
\ No newline at end of file
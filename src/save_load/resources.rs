@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use bevy::tasks::Task;
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 use std::fs;
@@ -6,15 +7,41 @@ use std::path::{PathBuf};
 
 use crate::bird::BirdSpecies;
 use crate::catalog::components::PlaceableItemType;
-use crate::environment::components::{Weather};
+use crate::environment::components::{Season, Weather};
 use crate::achievements::Achievement;
+use crate::audio::soundscape::SoundscapeSettings;
+use crate::audio::jukebox::MusicTrack;
+use crate::difficulty::DifficultySettings;
+use crate::environment::region::Region;
+use crate::journal::resources::{FieldNotes, SightingLog};
+use crate::journal::milestones::MilestoneLog;
+use crate::photo_mode::filters::SmartCollections;
+use crate::corvids::CorvidMemory;
+use crate::trading_post::TradingPostSales;
+use crate::garden_styles::GardenStyles;
+use crate::garden_lines::GardenLines;
+use crate::photo_contest::PhotoContest;
+use crate::gear_care::GearCare;
+use crate::environment::variability::YearlyVariability;
+use crate::environment::irruption::Irruptions;
+use crate::compost::Compost;
+use crate::domestic_threats::NeighborAgreement;
+use crate::experiments::YardExperiments;
+use crate::terrain_painting::TerrainMap;
+use crate::elevation::HeightTier;
+
+// Timed auto-saves rotate through slots after the ten regular ones
+pub const AUTO_SAVE_FIRST_SLOT: u32 = 10;
+pub const MAX_AUTO_SAVE_SLOTS: u32 = 3;
 
 #[derive(Resource)]
 pub struct SaveManager {
     pub save_directory: PathBuf,
     pub current_save_slot: Option<u32>,
-    pub auto_save_enabled: bool,
     pub auto_save_timer: Timer,
+    // Event-triggered save waiting out the cooldown, and when the last one was written
+    pub pending_event_save: Option<String>,
+    pub last_event_save: Option<f64>,
 }
 
 impl Default for SaveManager {
@@ -27,22 +54,45 @@ impl Default for SaveManager {
         Self {
             save_directory: save_dir,
             current_save_slot: None,
-            auto_save_enabled: true,
-            auto_save_timer: Timer::from_seconds(300.0, TimerMode::Repeating), // Auto-save every 5 minutes
+            auto_save_timer: Timer::from_seconds(300.0, TimerMode::Repeating), // Interval comes from GameSettings
+            pending_event_save: None,
+            last_event_save: None,
         }
     }
 }
 
 impl SaveManager {
-    pub fn ensure_save_directory(&self) -> Result<(), Box<dyn std::error::Error>> {
-        fs::create_dir_all(&self.save_directory)?;
-        Ok(())
-    }
-    
     pub fn get_save_path(&self, slot: u32) -> PathBuf {
         self.save_directory.join(format!("save_{}.ron", slot))
     }
     
+    // Picture of the yard taken as the slot was last saved, shown behind the main menu
+    pub fn get_snapshot_path(&self, slot: u32) -> PathBuf {
+        self.save_directory.join(format!("save_{}.png", slot))
+    }
+    
+    // The most recently written save that has a snapshot alongside it
+    pub fn latest_snapshot(&self) -> Option<(PathBuf, SaveFileInfo)> {
+        self.list_save_files().into_iter()
+            .filter(|save| self.get_snapshot_path(save.slot).exists())
+            .max_by_key(|save| save.last_modified)
+            .map(|save| (self.get_snapshot_path(save.slot), save))
+    }
+    
+    pub fn is_auto_save_slot(slot: u32) -> bool {
+        (AUTO_SAVE_FIRST_SLOT..AUTO_SAVE_FIRST_SLOT + MAX_AUTO_SAVE_SLOTS).contains(&slot)
+    }
+    
+    // The first unused auto-save slot, otherwise the one written longest ago
+    pub fn next_auto_save_slot(&self, slot_count: u32) -> u32 {
+        let slots = AUTO_SAVE_FIRST_SLOT..AUTO_SAVE_FIRST_SLOT + slot_count.clamp(1, MAX_AUTO_SAVE_SLOTS);
+        slots.min_by_key(|slot| {
+            fs::metadata(self.get_save_path(*slot))
+                .and_then(|metadata| metadata.modified())
+                .ok()
+        }).unwrap_or(AUTO_SAVE_FIRST_SLOT)
+    }
+    
     pub fn list_save_files(&self) -> Vec<SaveFileInfo> {
         let mut saves = Vec::new();
         
@@ -54,11 +104,17 @@ impl SaveManager {
                             if let Ok(slot) = slot_str.parse::<u32>() {
                                 if let Ok(metadata) = entry.metadata() {
                                     if let Ok(modified) = metadata.modified() {
+                                        let summary = fs::read_to_string(entry.path()).ok()
+                                            .and_then(|content| ron::from_str::<SaveSlotSummary>(&content).ok());
                                         saves.push(SaveFileInfo {
                                             slot,
                                             filename: filename.to_string(),
                                             last_modified: modified,
                                             exists: true,
+                                            difficulty: summary.as_ref().map(|summary| summary.difficulty.clone()),
+                                            region: summary.as_ref().map(|summary| summary.region),
+                                            home_slot: summary.as_ref().and_then(|summary| summary.home_slot),
+                                            environment: summary.map(|summary| (summary.environment_state.season(), summary.environment_state.current_weather)),
                                         });
                                     }
                                 }
@@ -80,6 +136,42 @@ pub struct SaveFileInfo {
     pub filename: String,
     pub last_modified: std::time::SystemTime,
     pub exists: bool,
+    pub difficulty: Option<DifficultySettings>,
+    pub region: Option<Region>,
+    pub home_slot: Option<u32>,
+    // Season and weather the sanctuary was saved in
+    pub environment: Option<(Season, Weather)>,
+}
+
+// Just the fields the load screen shows; everything else in the save is ignored
+#[derive(Deserialize)]
+struct SaveSlotSummary {
+    #[serde(default)]
+    difficulty: DifficultySettings,
+    #[serde(default)]
+    region: Region,
+    #[serde(default)]
+    home_slot: Option<u32>,
+    #[serde(default)]
+    environment_state: EnvironmentSummary,
+}
+
+#[derive(Deserialize)]
+struct EnvironmentSummary {
+    day_of_year: u32,
+    current_weather: Weather,
+}
+
+impl Default for EnvironmentSummary {
+    fn default() -> Self {
+        Self { day_of_year: 120, current_weather: Weather::Clear }
+    }
+}
+
+impl EnvironmentSummary {
+    fn season(&self) -> Season {
+        crate::environment::resources::TimeState { day_of_year: self.day_of_year, ..default() }.get_season()
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -97,6 +189,102 @@ pub struct GameSaveData {
     pub environment_state: EnvironmentSaveData,
     pub placed_objects: Vec<PlacedObjectSaveData>,
     
+    // Per-save ambient soundscape mix (older saves fall back to the default mix)
+    #[serde(default)]
+    pub soundscape: SoundscapeSettings,
+    
+    // Music tracks unlocked for the jukebox
+    #[serde(default)]
+    pub unlocked_music: Vec<MusicTrack>,
+    
+    // Sanctuary reputation inputs; the meter and tier are recalculated on load
+    #[serde(default)]
+    pub reputation: ReputationSaveData,
+    
+    // Per-day species checklists
+    #[serde(default)]
+    pub sightings: SightingLog,
+    
+    // Player notes and tags on species and photos
+    #[serde(default)]
+    pub field_notes: FieldNotes,
+    
+    // Dated milestones for the journal timeline
+    #[serde(default)]
+    pub milestones: MilestoneLog,
+    
+    // Saved photo gallery filters
+    #[serde(default)]
+    pub smart_collections: SmartCollections,
+    
+    // Species marked as attraction targets
+    #[serde(default)]
+    pub species_targets: HashSet<BirdSpecies>,
+    
+    // Difficulty preset and realism settings (older saves play on Standard)
+    #[serde(default)]
+    pub difficulty: DifficultySettings,
+    
+    // Region the sanctuary was founded in (older saves are Eastern Woodland)
+    #[serde(default)]
+    pub region: Region,
+    
+    // Corvid cache locations, trust and collected gifts
+    #[serde(default)]
+    pub corvids: CorvidMemory,
+    
+    // Garden collection badges earned
+    #[serde(default)]
+    pub garden_styles: GardenStyles,
+    
+    // Fence, hedge and path runs drawn around the garden
+    #[serde(default)]
+    pub garden_lines: GardenLines,
+    
+    // Seasonal photo contest entry, last result and trophies won
+    #[serde(default)]
+    pub photo_contest: PhotoContest,
+    
+    // Camera condition, weather seals bought and gear insurance
+    #[serde(default)]
+    pub gear_care: GearCare,
+    
+    // Seed and history of mast, irruption and drought years
+    #[serde(default)]
+    pub yearly_variability: YearlyVariability,
+    
+    // Active and past winter finch irruptions
+    #[serde(default)]
+    pub irruptions: Irruptions,
+    
+    // Compost heap, finished fertilizer and lifetime waste totals
+    #[serde(default)]
+    pub compost: Compost,
+    
+    // What the neighbors have agreed to about their pets
+    #[serde(default)]
+    pub neighbor_agreements: HashMap<u32, NeighborAgreement>,
+    
+    // What's left of the trading post's stock for the day it was saved on
+    #[serde(default)]
+    pub trading_post: Option<TradingPostSales>,
+    
+    // Painted ground cover, only chunks with something painted
+    #[serde(default)]
+    pub terrain: TerrainMap,
+    
+    // Values Lua mods stored for this sanctuary
+    #[serde(default)]
+    pub script_store: ScriptStore,
+    
+    // Daily yard visits, a running layout experiment and the last experiment's report
+    #[serde(default)]
+    pub experiments: YardExperiments,
+    
+    // Slot of the sanctuary an auto-save belongs to, so loading one keeps saving there
+    #[serde(default)]
+    pub home_slot: Option<u32>,
+    
     // Game statistics
     pub total_photos_taken: u32,
     pub total_playtime_seconds: f64,
@@ -109,6 +297,14 @@ pub struct InventorySaveData {
     pub owned_items: HashMap<PlaceableItemType, u32>,
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct ReputationSaveData {
+    pub species_recorded: HashSet<BirdSpecies>,
+    pub rare_sightings: u32,
+    pub photo_quality: f32,
+    pub commissions_completed: u32,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct AchievementProgressSaveData {
     pub photos_taken: u32,
@@ -121,6 +317,9 @@ pub struct AchievementProgressSaveData {
 pub struct EnvironmentSaveData {
     pub current_hour: f32,
     pub day_of_year: u32,
+    // Older saves predate multi-year play and load into year 1
+    #[serde(default)]
+    pub year: u32,
     pub current_weather: Weather,
     pub temperature: f32,
 }
@@ -130,9 +329,50 @@ pub struct PlacedObjectSaveData {
     pub item_type: PlaceableItemType,
     pub position: [f32; 3],
     pub save_id: String,
+    // Weathered condition, absent in older saves where everything comes back as new
+    #[serde(default)]
+    pub durability: Option<f32>,
+    // Height tier, absent in older saves where objects go back to their default tier
+    #[serde(default)]
+    pub height_tier: Option<HeightTier>,
+    // Solar aerator fitted to a still bath, absent in older saves
+    #[serde(default)]
+    pub solar_aerator: bool,
 }
 
 // Events
+// Sandboxed key-value store Lua mods read and write through scripting/lua_api.rs, kept in the save
+const SCRIPT_STORE_MAX_KEYS: usize = 256;
+const SCRIPT_STORE_MAX_KEY_LEN: usize = 64;
+const SCRIPT_STORE_MAX_VALUE_LEN: usize = 1024;
+
+#[derive(Resource, Serialize, Deserialize, Clone, Debug, Default)]
+pub struct ScriptStore {
+    values: HashMap<String, String>,
+}
+
+impl ScriptStore {
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.values.get(key).map(String::as_str)
+    }
+
+    /// Stores a value, refusing oversized keys and values or a new key once the store is full
+    pub fn set(&mut self, key: String, value: String) -> bool {
+        if key.is_empty() || key.len() > SCRIPT_STORE_MAX_KEY_LEN || value.len() > SCRIPT_STORE_MAX_VALUE_LEN {
+            return false;
+        }
+        if !self.values.contains_key(&key) && self.values.len() >= SCRIPT_STORE_MAX_KEYS {
+            return false;
+        }
+        self.values.insert(key, value);
+        true
+    }
+
+    pub fn remove(&mut self, key: &str) -> bool {
+        self.values.remove(key).is_some()
+    }
+}
+
 #[derive(Event)]
 pub struct SaveGameEvent {
     pub slot: u32,
@@ -155,6 +395,13 @@ impl PlaytimeTracker {
     }
 }
 
+// Save files being written off the main thread, and how long the indicator lingers after
+#[derive(Resource, Default)]
+pub struct PendingSaves {
+    pub tasks: Vec<(u32, Task<Result<(), String>>)>,
+    pub indicator_linger: f32,
+}
+
 #[derive(Event)]
 pub struct LoadGameEvent {
     pub slot: u32,
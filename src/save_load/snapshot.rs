@@ -0,0 +1,74 @@
+// Sanctuary Snapshot - A picture of the yard kept with each save for the main menu backdrop
+//
+// Whenever a save lands while the yard is on screen, a capture camera renders the player's current
+// view without any of the HUD, reads it back and writes it beside the save file. The main menu
+// shows the newest one, panning across it with the season and weather it was saved in.
+use bevy::prelude::*;
+use bevy::render::camera::ClearColorConfig;
+use crate::photo_mode::offscreen::{capture_camera, write_png, OffscreenCapture};
+use crate::photo_mode::components::PhotoTarget;
+use crate::save_load::resources::{SaveCompleteEvent, SaveManager};
+
+const SNAPSHOT_WIDTH: u32 = 1280;
+const SNAPSHOT_HEIGHT: u32 = 720;
+
+#[derive(Resource, Default)]
+pub struct SnapshotJob {
+    active: Option<ActiveSnapshot>,
+}
+
+struct ActiveSnapshot {
+    slot: u32,
+}
+
+#[derive(Component)]
+pub struct SnapshotEntity;
+
+pub fn start_snapshot_system(
+    mut commands: Commands,
+    mut save_complete_events: EventReader<SaveCompleteEvent>,
+    mut job: ResMut<SnapshotJob>,
+    mut images: ResMut<Assets<Image>>,
+    camera_query: Query<(&Transform, &Projection), With<PhotoTarget>>,
+) {
+    // Only the last save of a frame needs a picture
+    let Some(slot) = save_complete_events.read().filter(|event| event.success).map(|event| event.slot).last() else { return };
+    if job.active.is_some() {
+        return;
+    }
+    let Ok((transform, projection)) = camera_query.single() else { return };
+
+    commands.spawn((
+        capture_camera(
+            &mut images,
+            "sanctuary_snapshot",
+            UVec2::new(SNAPSHOT_WIDTH, SNAPSHOT_HEIGHT),
+            -13,
+            ClearColorConfig::Default,
+        ),
+        projection.clone(),
+        *transform,
+        bevy_light_2d::prelude::Light2d,
+        SnapshotEntity,
+    ));
+    job.active = Some(ActiveSnapshot { slot });
+}
+
+pub fn snapshot_system(
+    mut commands: Commands,
+    mut job: ResMut<SnapshotJob>,
+    save_manager: Res<SaveManager>,
+    mut snapshot_query: Query<(Entity, &mut OffscreenCapture), With<SnapshotEntity>>,
+) {
+    let Some(snapshot) = job.bypass_change_detection().active.as_ref() else { return };
+    let Ok((entity, mut capture)) = snapshot_query.single_mut() else { return };
+    let Some(pixels) = capture.take_pixels() else { return };
+
+    commands.entity(entity).despawn();
+    let path = save_manager.get_snapshot_path(snapshot.slot);
+    match write_png(&path, UVec2::new(SNAPSHOT_WIDTH, SNAPSHOT_HEIGHT), pixels) {
+        Ok(()) => info!("📷 Sanctuary snapshot saved for slot {}", snapshot.slot),
+        Err(e) => warn!("Couldn't save sanctuary snapshot for slot {}: {}", snapshot.slot, e),
+    }
+    job.active = None;
+}
@@ -1,72 +1,181 @@
 use bevy::prelude::*;
+use bevy::tasks::{block_on, futures::check_ready, IoTaskPool};
 // use std::collections::{HashMap, HashSet};
 use std::fs;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use crate::save_load::resources::*;
-use crate::catalog::resources::{PlayerInventory, PlacedObjects};
-use crate::journal::resources::DiscoveredSpecies;
+use crate::catalog::resources::{PlayerInventory, PlacedObjects, PurchaseItemEvent};
+use crate::journal::resources::{DiscoveredSpecies, FieldNotes, SightingLog};
+use crate::journal::milestones::MilestoneLog;
+use crate::photo_mode::filters::SmartCollections;
 use crate::environment::resources::{TimeState, WeatherState};
 use crate::achievements::{AchievementProgress};
 use crate::catalog::components::{PlaceableObject};
-use crate::save_load::components::PersistentObject;
+use crate::save_load::components::{PersistentObject, SaveIndicator};
 use crate::despawn::SafeDespawn;
+use crate::audio::soundscape::SoundscapeSettings;
+use crate::audio::jukebox::Jukebox;
+use crate::sanctuary_management::SanctuaryReputation;
+use crate::attraction_advisor::SpeciesTargets;
+use crate::difficulty::DifficultySettings;
+use crate::environment::region::SanctuaryRegion;
+use crate::corvids::CorvidMemory;
+use crate::trading_post::TradingPost;
+use crate::garden_styles::GardenStyles;
+use crate::garden_lines::GardenLines;
+use crate::photo_contest::PhotoContest;
+use crate::gear_care::GearCare;
+use crate::environment::variability::YearlyVariability;
+use crate::environment::irruption::Irruptions;
+use crate::compost::Compost;
+use crate::domestic_threats::DomesticThreatManager;
+use crate::experiments::YardExperiments;
+use crate::terrain_painting::TerrainMap;
+use crate::smart_objects::SmartObjectRegistry;
+use crate::smart_objects::maintenance::RestoredDurability;
+use crate::elevation::{Elevation, RestoredHeight};
+use crate::water_quality::SolarAerator;
+use crate::animation::components::AnimatedBird;
+use crate::menu::resources::GameSettings;
+use crate::AppState;
+use bevy::ecs::system::SystemParam;
+
+// Seconds between event-triggered saves, so a shopping spree or a busy feeder writes once
+const EVENT_SAVE_COOLDOWN_SECONDS: f64 = 30.0;
+// How long "Saved" stays in the corner after the last write finishes
+const SAVED_LINGER_SECONDS: f32 = 1.5;
+
+// The journal's records, nested so the sanctuary groups below stay within the parameter limit
+#[derive(SystemParam)]
+pub struct JournalRecords<'w> {
+    sighting_log: Res<'w, SightingLog>,
+    field_notes: Res<'w, FieldNotes>,
+    milestones: Res<'w, MilestoneLog>,
+    smart_collections: Res<'w, SmartCollections>,
+}
+
+#[derive(SystemParam)]
+pub struct JournalRecordsMut<'w> {
+    sighting_log: ResMut<'w, SightingLog>,
+    field_notes: ResMut<'w, FieldNotes>,
+    milestones: ResMut<'w, MilestoneLog>,
+    smart_collections: ResMut<'w, SmartCollections>,
+}
+
+// Per-sanctuary records, grouped to keep the save and load systems within the parameter limit
+#[derive(SystemParam)]
+pub struct SanctuaryRecords<'w> {
+    journal: JournalRecords<'w>,
+    species_targets: Res<'w, SpeciesTargets>,
+    difficulty: Res<'w, DifficultySettings>,
+    region: Res<'w, SanctuaryRegion>,
+    corvids: Res<'w, CorvidMemory>,
+    garden_styles: Res<'w, GardenStyles>,
+    garden_lines: Res<'w, GardenLines>,
+    photo_contest: Res<'w, PhotoContest>,
+    gear_care: Res<'w, GearCare>,
+    yearly_variability: Res<'w, YearlyVariability>,
+    irruptions: Res<'w, Irruptions>,
+    compost: Res<'w, Compost>,
+    domestic_threats: Res<'w, DomesticThreatManager>,
+    trading_post: Res<'w, TradingPost>,
+    terrain: Res<'w, TerrainMap>,
+    script_store: Res<'w, ScriptStore>,
+    smart_objects: Res<'w, SmartObjectRegistry>,
+    experiments: Res<'w, YardExperiments>,
+}
+
+#[derive(SystemParam)]
+pub struct SanctuaryRecordsMut<'w> {
+    journal: JournalRecordsMut<'w>,
+    species_targets: ResMut<'w, SpeciesTargets>,
+    difficulty: ResMut<'w, DifficultySettings>,
+    region: ResMut<'w, SanctuaryRegion>,
+    corvids: ResMut<'w, CorvidMemory>,
+    garden_styles: ResMut<'w, GardenStyles>,
+    garden_lines: ResMut<'w, GardenLines>,
+    photo_contest: ResMut<'w, PhotoContest>,
+    gear_care: ResMut<'w, GearCare>,
+    yearly_variability: ResMut<'w, YearlyVariability>,
+    irruptions: ResMut<'w, Irruptions>,
+    compost: ResMut<'w, Compost>,
+    domestic_threats: ResMut<'w, DomesticThreatManager>,
+    trading_post: ResMut<'w, TradingPost>,
+    terrain: ResMut<'w, TerrainMap>,
+    script_store: ResMut<'w, ScriptStore>,
+    experiments: ResMut<'w, YardExperiments>,
+}
+
+// Everything a save is built from, shared by queued saves and the synchronous save on quit
+#[derive(SystemParam)]
+pub struct SaveSources<'w, 's> {
+    save_manager: Res<'w, SaveManager>,
+    player_inventory: Res<'w, PlayerInventory>,
+    discovered_species: Res<'w, DiscoveredSpecies>,
+    achievement_progress: Res<'w, AchievementProgress>,
+    time_state: Res<'w, TimeState>,
+    weather_state: Res<'w, WeatherState>,
+    playtime_tracker: Res<'w, PlaytimeTracker>,
+    soundscape: Res<'w, SoundscapeSettings>,
+    jukebox: Res<'w, Jukebox>,
+    reputation: Res<'w, SanctuaryReputation>,
+    records: SanctuaryRecords<'w>,
+    placed_object_query: Query<'w, 's, (Entity, &'static Transform, &'static PlaceableObject, Option<&'static PersistentObject>, Option<&'static Elevation>, Option<&'static SolarAerator>)>,
+}
 
 pub fn save_game_system(
     mut save_events: EventReader<SaveGameEvent>,
-    mut save_complete_events: EventWriter<SaveCompleteEvent>,
-    save_manager: Res<SaveManager>,
-    
-    // Resources to save
-    player_inventory: Res<PlayerInventory>,
-    discovered_species: Res<DiscoveredSpecies>,
-    achievement_progress: Res<AchievementProgress>,
-    time_state: Res<TimeState>,
-    weather_state: Res<WeatherState>,
-    placed_objects: Res<PlacedObjects>,
-    playtime_tracker: Res<PlaytimeTracker>,
-    
-    // Queries for world objects
-    placed_object_query: Query<(&Transform, &PlaceableObject, Option<&PersistentObject>)>,
+    mut pending_saves: ResMut<PendingSaves>,
+    sources: SaveSources,
 ) {
     for save_event in save_events.read() {
-        let result = perform_save(
-            &save_manager,
-            save_event.slot,
-            &player_inventory,
-            &discovered_species,
-            &achievement_progress,
-            &time_state,
-            &weather_state,
-            &placed_objects,
-            &playtime_tracker,
-            &placed_object_query,
-        );
-        
-        let (success, error_message) = match result {
-            Ok(_) => (true, None),
-            Err(e) => (false, Some(e.to_string())),
-        };
-        
-        save_complete_events.write(SaveCompleteEvent {
-            slot: save_event.slot,
-            success,
-            error_message: error_message.clone(),
+        // The snapshot is taken this frame; serializing and writing it happens off the main thread
+        let save_data = build_save_data(&sources, save_event.slot);
+        let save_directory = sources.save_manager.save_directory.clone();
+        let save_path = sources.save_manager.get_save_path(save_event.slot);
+        let task = IoTaskPool::get().spawn(async move {
+            write_save(&save_directory, &save_path, &save_data)
         });
-        
-        if success {
-            info!("Game saved successfully to slot {}", save_event.slot);
-        } else {
-            error!("Failed to save game to slot {}: {:?}", save_event.slot, error_message);
-        }
+        pending_saves.tasks.push((save_event.slot, task));
+    }
+}
+
+pub fn finish_pending_saves_system(
+    mut pending_saves: ResMut<PendingSaves>,
+    mut save_complete_events: EventWriter<SaveCompleteEvent>,
+) {
+    let mut any_saved = false;
+    pending_saves.tasks.retain_mut(|(slot, task)| {
+        let Some(result) = check_ready(task) else { return true };
+        any_saved |= result.is_ok();
+        report_save(*slot, result, &mut save_complete_events);
+        false
+    });
+
+    if any_saved {
+        pending_saves.indicator_linger = SAVED_LINGER_SECONDS;
+    }
+}
+
+fn report_save(slot: u32, result: Result<(), String>, save_complete_events: &mut EventWriter<SaveCompleteEvent>) {
+    match &result {
+        Ok(()) => info!("Game saved successfully to slot {}", slot),
+        Err(e) => error!("Failed to save game to slot {}: {}", slot, e),
     }
+    save_complete_events.write(SaveCompleteEvent {
+        slot,
+        success: result.is_ok(),
+        error_message: result.err(),
+    });
 }
 
 pub fn load_game_system(
     mut commands: Commands,
     mut load_events: EventReader<LoadGameEvent>,
     mut load_complete_events: EventWriter<LoadCompleteEvent>,
-    save_manager: Res<SaveManager>,
+    mut save_manager: ResMut<SaveManager>,
     asset_server: Res<AssetServer>,
     
     // Resources to update
@@ -76,6 +185,10 @@ pub fn load_game_system(
     mut time_state: ResMut<TimeState>,
     mut weather_state: ResMut<WeatherState>,
     mut placed_objects: ResMut<PlacedObjects>,
+    mut soundscape: ResMut<SoundscapeSettings>,
+    mut jukebox: ResMut<Jukebox>,
+    mut reputation: ResMut<SanctuaryReputation>,
+    mut records: SanctuaryRecordsMut,
     
     // Clear existing placed objects
     placed_object_query: Query<Entity, With<PlaceableObject>>,
@@ -92,11 +205,21 @@ pub fn load_game_system(
             &mut time_state,
             &mut weather_state,
             &mut placed_objects,
+            &mut soundscape,
+            &mut jukebox,
+            &mut reputation,
+            &mut records,
             &placed_object_query,
         );
         
         let (success, error_message) = match result {
-            Ok(_) => (true, None),
+            Ok(home_slot) => {
+                // An auto-save keeps saving back to the sanctuary it was taken from
+                if SaveManager::is_auto_save_slot(load_event.slot) {
+                    save_manager.current_save_slot = home_slot;
+                }
+                (true, None)
+            }
             Err(e) => (false, Some(e.to_string())),
         };
         
@@ -117,40 +240,139 @@ pub fn load_game_system(
 pub fn auto_save_system(
     mut save_manager: ResMut<SaveManager>,
     mut save_events: EventWriter<SaveGameEvent>,
+    mut purchase_events: EventReader<PurchaseItemEvent>,
+    new_birds: Query<&AnimatedBird, Added<AnimatedBird>>,
+    settings: Res<GameSettings>,
+    state: Res<State<AppState>>,
     time: Res<Time>,
 ) {
-    if save_manager.auto_save_enabled {
+    let purchased = purchase_events.read().count() > 0;
+    let rare_sighting = new_birds.iter().any(|bird| bird.species.rarity_tier() >= 3);
+
+    // Menus have no sanctuary in front of them to save
+    let in_sanctuary = matches!(state.get(), AppState::Playing | AppState::Journal | AppState::Catalog);
+    let Some(home_slot) = save_manager.current_save_slot.filter(|_| in_sanctuary) else {
+        return;
+    };
+
+    // Timed saves rotate through the auto-save slots so a bad moment never overwrites every copy
+    if settings.auto_save_enabled {
+        let interval = Duration::from_secs_f32(settings.auto_save_interval * 60.0);
+        if save_manager.auto_save_timer.duration() != interval {
+            save_manager.auto_save_timer.set_duration(interval);
+        }
         save_manager.auto_save_timer.tick(time.delta());
-        
+
         if save_manager.auto_save_timer.just_finished() {
-            if let Some(slot) = save_manager.current_save_slot {
-                save_events.write(SaveGameEvent {
-                    slot,
-                    save_name: Some("Auto Save".to_string()),
-                });
-                info!("Auto-saving to slot {}", slot);
-            }
+            let slot = save_manager.next_auto_save_slot(settings.auto_save_slots);
+            save_events.write(SaveGameEvent {
+                slot,
+                save_name: Some("Auto Save".to_string()),
+            });
+            info!("Auto-saving slot {} to auto-save slot {}", home_slot, slot);
+        }
+    }
+
+    // Event saves go straight to the sanctuary's own slot, at most once per cooldown
+    if purchased && settings.save_after_purchase {
+        save_manager.pending_event_save = Some("purchase".to_string());
+    }
+    if rare_sighting && settings.save_after_rare_sighting {
+        save_manager.pending_event_save = Some("rare sighting".to_string());
+    }
+
+    let now = time.elapsed_secs_f64();
+    let cooled_down = save_manager.last_event_save.is_none_or(|last| now - last >= EVENT_SAVE_COOLDOWN_SECONDS);
+    if cooled_down {
+        if let Some(reason) = save_manager.pending_event_save.take() {
+            save_manager.last_event_save = Some(now);
+            save_events.write(SaveGameEvent {
+                slot: home_slot,
+                save_name: None,
+            });
+            info!("Saving slot {} after {}", home_slot, reason);
         }
     }
 }
 
-fn perform_save(
-    save_manager: &SaveManager,
-    slot: u32,
-    player_inventory: &PlayerInventory,
-    discovered_species: &DiscoveredSpecies,
-    achievement_progress: &AchievementProgress,
-    time_state: &TimeState,
-    weather_state: &WeatherState,
-    placed_objects: &PlacedObjects,
-    playtime_tracker: &PlaytimeTracker,
-    placed_object_query: &Query<(&Transform, &PlaceableObject, Option<&PersistentObject>)>,
-) -> Result<(), Box<dyn std::error::Error>> {
-    save_manager.ensure_save_directory()?;
-    
+// Runs in Last so the exit request from any menu or window close is seen before the app stops
+pub fn save_on_quit_system(
+    mut exit_events: EventReader<AppExit>,
+    mut pending_saves: ResMut<PendingSaves>,
+    settings: Res<GameSettings>,
+    sources: SaveSources,
+) {
+    if exit_events.read().count() == 0 {
+        return;
+    }
+
+    // Saves still being written would be dropped with the task pool
+    for (slot, task) in pending_saves.tasks.drain(..) {
+        if let Err(e) = block_on(task) {
+            error!("Failed to save game to slot {}: {}", slot, e);
+        }
+    }
+
+    if !settings.save_on_quit {
+        return;
+    }
+    let Some(slot) = sources.save_manager.current_save_slot else { return };
+
+    let save_data = build_save_data(&sources, slot);
+    match write_save(&sources.save_manager.save_directory, &sources.save_manager.get_save_path(slot), &save_data) {
+        Ok(()) => info!("Saved slot {} on quit", slot),
+        Err(e) => error!("Failed to save slot {} on quit: {}", slot, e),
+    }
+}
+
+/// Small corner label while saves are being written, replacing the old frame hitch
+pub fn save_indicator_system(
+    mut commands: Commands,
+    mut pending_saves: ResMut<PendingSaves>,
+    mut indicator_query: Query<(Entity, &mut Text), With<SaveIndicator>>,
+    time: Res<Time<Real>>,
+) {
+    let label = if !pending_saves.tasks.is_empty() {
+        "Saving..."
+    } else if pending_saves.indicator_linger > 0.0 {
+        pending_saves.indicator_linger -= time.delta_secs();
+        "Saved"
+    } else {
+        for (entity, _) in &indicator_query {
+            commands.entity(entity).despawn();
+        }
+        return;
+    };
+
+    if let Ok((_, mut text)) = indicator_query.single_mut() {
+        if text.0 != label {
+            text.0 = label.to_string();
+        }
+        return;
+    }
+
+    commands.spawn((
+        Text::new(label),
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(10.0),
+            right: Val::Px(10.0),
+            ..default()
+        },
+        SaveIndicator,
+        TextColor(Color::srgba(1.0, 1.0, 1.0, 0.7)),
+        TextFont {
+            font_size: 12.0,
+            ..default()
+        },
+        StateScoped(AppState::Playing),
+    ));
+}
+
+fn build_save_data(sources: &SaveSources, slot: u32) -> GameSaveData {
     // Collect placed objects data
     let mut placed_objects_data = Vec::new();
-    for (transform, placeable_object, persistent_object) in placed_object_query.iter() {
+    for (entity, transform, placeable_object, persistent_object, elevation, aerator) in sources.placed_object_query.iter() {
         let save_id = if let Some(persistent) = persistent_object {
             persistent.save_id.clone()
         } else {
@@ -166,11 +388,13 @@ fn perform_save(
                 transform.translation.z,
             ],
             save_id,
+            durability: sources.records.smart_objects.active_objects.get(&entity).map(|object| object.current_durability),
+            height_tier: elevation.map(|elevation| elevation.tier),
+            solar_aerator: aerator.is_some(),
         });
     }
     
-    // Create save data structure
-    let save_data = GameSaveData {
+    GameSaveData {
         version: env!("CARGO_PKG_VERSION").to_string(),
         save_timestamp: SystemTime::now()
             .duration_since(UNIX_EPOCH)
@@ -178,41 +402,71 @@ fn perform_save(
             .as_secs(),
             
         player_inventory: InventorySaveData {
-            currency: player_inventory.currency,
-            owned_items: player_inventory.owned_items.clone(),
+            currency: sources.player_inventory.currency,
+            owned_items: sources.player_inventory.owned_items.clone(),
         },
         
-        discovered_species: discovered_species.0.clone(),
+        discovered_species: sources.discovered_species.0.clone(),
         
-        achievements: achievement_progress.unlocked.clone(),
+        achievements: sources.achievement_progress.unlocked.clone(),
         
         achievement_progress: AchievementProgressSaveData {
-            photos_taken: achievement_progress.photos_taken,
+            photos_taken: sources.achievement_progress.photos_taken,
             currency_earned: 0, // We'll track this separately
-            species_discovered: achievement_progress.species_discovered,
+            species_discovered: sources.achievement_progress.species_discovered,
             feeders_upgraded: 0, // We'll track this separately
         },
         
         environment_state: EnvironmentSaveData {
-            current_hour: time_state.hour,
-            day_of_year: time_state.day_of_year,
-            current_weather: weather_state.current_weather,
-            temperature: weather_state.temperature,
+            current_hour: sources.time_state.hour,
+            day_of_year: sources.time_state.day_of_year,
+            year: sources.time_state.year,
+            current_weather: sources.weather_state.current_weather,
+            temperature: sources.weather_state.temperature,
         },
         
         placed_objects: placed_objects_data,
         
-        total_photos_taken: achievement_progress.photos_taken,
-        total_playtime_seconds: playtime_tracker.get_total_seconds(),
-        birds_observed: discovered_species.0.len() as u32,
-    };
-    
-    // Serialize and write to file
-    let serialized = ron::to_string(&save_data)?;
-    let save_path = save_manager.get_save_path(slot);
-    fs::write(save_path, serialized)?;
-    
-    Ok(())
+        soundscape: sources.soundscape.clone(),
+        unlocked_music: sources.jukebox.unlocked.iter().copied().collect(),
+        reputation: ReputationSaveData {
+            species_recorded: sources.reputation.species_recorded.clone(),
+            rare_sightings: sources.reputation.rare_sightings,
+            photo_quality: sources.reputation.photo_quality,
+            commissions_completed: sources.reputation.commissions_completed,
+        },
+        sightings: sources.records.journal.sighting_log.clone(),
+        field_notes: sources.records.journal.field_notes.clone(),
+        milestones: sources.records.journal.milestones.clone(),
+        smart_collections: sources.records.journal.smart_collections.clone(),
+        species_targets: sources.records.species_targets.species.clone(),
+        difficulty: sources.records.difficulty.clone(),
+        region: sources.records.region.0,
+        corvids: sources.records.corvids.clone(),
+        garden_styles: sources.records.garden_styles.clone(),
+        garden_lines: sources.records.garden_lines.clone(),
+        photo_contest: sources.records.photo_contest.clone(),
+        gear_care: sources.records.gear_care.clone(),
+        yearly_variability: sources.records.yearly_variability.clone(),
+        irruptions: sources.records.irruptions.clone(),
+        compost: sources.records.compost.clone(),
+        neighbor_agreements: sources.records.domestic_threats.agreements.clone(),
+        trading_post: sources.records.trading_post.sales(),
+        terrain: sources.records.terrain.clone(),
+        script_store: sources.records.script_store.clone(),
+        experiments: sources.records.experiments.clone(),
+        home_slot: if SaveManager::is_auto_save_slot(slot) { sources.save_manager.current_save_slot } else { Some(slot) },
+        
+        total_photos_taken: sources.achievement_progress.photos_taken,
+        total_playtime_seconds: sources.playtime_tracker.get_total_seconds(),
+        birds_observed: sources.discovered_species.0.len() as u32,
+    }
+}
+
+fn write_save(save_directory: &Path, save_path: &Path, save_data: &GameSaveData) -> Result<(), String> {
+    fs::create_dir_all(save_directory).map_err(|e| e.to_string())?;
+    let serialized = ron::to_string(save_data).map_err(|e| e.to_string())?;
+    fs::write(save_path, serialized).map_err(|e| e.to_string())
 }
 
 fn perform_load(
@@ -226,8 +480,12 @@ fn perform_load(
     time_state: &mut TimeState,
     weather_state: &mut WeatherState,
     placed_objects: &mut PlacedObjects,
+    soundscape: &mut SoundscapeSettings,
+    jukebox: &mut Jukebox,
+    reputation: &mut SanctuaryReputation,
+    records: &mut SanctuaryRecordsMut,
     placed_object_query: &Query<Entity, With<PlaceableObject>>,
-) -> Result<(), Box<dyn std::error::Error>> {
+) -> Result<Option<u32>, Box<dyn std::error::Error>> {
     let save_path = save_manager.get_save_path(slot);
     
     if !save_path.exists() {
@@ -259,9 +517,92 @@ fn perform_load(
     // Restore environment state
     time_state.hour = save_data.environment_state.current_hour;
     time_state.day_of_year = save_data.environment_state.day_of_year;
+    time_state.year = save_data.environment_state.year.max(1);
     weather_state.current_weather = save_data.environment_state.current_weather;
     weather_state.temperature = save_data.environment_state.temperature;
     
+    // Restore the soundscape mix for this save
+    *soundscape = save_data.soundscape;
+    
+    // Restore unlocked jukebox tracks
+    for track in save_data.unlocked_music {
+        jukebox.unlock(track);
+    }
+    
+    // Restore sanctuary reputation
+    let saved_reputation = save_data.reputation;
+    reputation.restore(
+        saved_reputation.species_recorded,
+        saved_reputation.rare_sightings,
+        saved_reputation.photo_quality,
+        saved_reputation.commissions_completed,
+    );
+    
+    // Restore sighting checklists
+    *records.journal.sighting_log = save_data.sightings;
+    
+    // Restore journal notes and tags
+    *records.journal.field_notes = save_data.field_notes;
+    
+    // Restore the timeline's milestones
+    *records.journal.milestones = save_data.milestones;
+    
+    // Restore the photo gallery's smart collections
+    *records.journal.smart_collections = save_data.smart_collections;
+    
+    // Restore attraction targets
+    records.species_targets.species = save_data.species_targets;
+    
+    // Restore the difficulty this sanctuary is played on
+    *records.difficulty = save_data.difficulty;
+    
+    // Restore the region, which rebuilds the species pool and scenery
+    records.region.0 = save_data.region;
+    
+    // Restore what the crows and jays remember about this sanctuary
+    *records.corvids = save_data.corvids;
+    
+    // Restore earned garden badges, ratings are recounted from the restored garden
+    records.garden_styles.badges = save_data.garden_styles.badges;
+    
+    // Restore fences, hedges and paths, their pieces are rebuilt next frame
+    *records.garden_lines = save_data.garden_lines;
+    records.garden_lines.dirty = true;
+    
+    // Restore the contest entry, result and trophies, the trophy shelf is rebuilt next frame
+    *records.photo_contest = save_data.photo_contest;
+    records.photo_contest.trophies_dirty = true;
+    
+    // Restore camera condition, weather seals and insurance
+    *records.gear_care = save_data.gear_care;
+    
+    // Restore the sanctuary's year history, older saves generate theirs next frame
+    *records.yearly_variability = save_data.yearly_variability;
+    
+    // Restore the current irruption and the ones observed before it
+    *records.irruptions = save_data.irruptions;
+    
+    // Restore the compost heap and waste totals, the bin itself comes back with the placed objects
+    *records.compost = save_data.compost;
+    
+    // Restore what the neighbors agreed to, their pets come back belled
+    records.domestic_threats.agreements = save_data.neighbor_agreements;
+    
+    // Restore the day's trading post stock, so offers already traded stay sold out
+    if let Some(sales) = save_data.trading_post {
+        records.trading_post.restore_sales(sales, time_state.day_of_year);
+    }
+    
+    // Restore painted ground cover
+    *records.terrain = save_data.terrain;
+    records.terrain.mark_all_dirty();
+    
+    // Restore what Lua mods stored for this sanctuary
+    *records.script_store = save_data.script_store;
+    
+    // Restore visit history and any experiment in progress
+    *records.experiments = save_data.experiments;
+    
     // Restore placed objects
     use crate::bird_ai::components::{SmartObject, ProvidesUtility};
     use bevy_rapier2d::prelude::*;
@@ -311,6 +652,16 @@ fn perform_load(
             });
         }
         
+        if let Some(durability) = object_data.durability {
+            entity_commands.insert(RestoredDurability(durability));
+        }
+        if let Some(tier) = object_data.height_tier {
+            entity_commands.insert(RestoredHeight(tier));
+        }
+        if object_data.solar_aerator {
+            entity_commands.insert(SolarAerator);
+        }
+        
         let entity = entity_commands.id();
         
         // Add secondary utility providers for multi-action items
@@ -330,7 +681,7 @@ fn perform_load(
     }
     
     info!("Loaded {} placed objects", placed_objects_len);
-    Ok(())
+    Ok(save_data.home_slot)
 }
 
 pub fn track_playtime_system(
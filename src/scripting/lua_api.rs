@@ -1,6 +1,18 @@
 use bevy::prelude::*;
+use std::collections::HashMap;
 use crate::bird_ai::components::{BirdState, BirdAction, Blackboard};
 use crate::environment::resources::{TimeState, WeatherState};
+use crate::save_load::resources::ScriptStore;
+use crate::smart_objects::{RemoveSmartObjectEvent, SmartObjectRegistry, SpawnSmartObjectEvent};
+
+// Scripts can't flood the world or the scheduler
+const MAX_SCRIPT_OBJECTS: usize = 50;
+const MAX_PENDING_CALLBACKS: usize = 64;
+// Game events scripts can subscribe to, and the arguments their handlers get
+pub const SCRIPT_EVENTS: [&str; 2] = [
+    "weather_changed", // (weather, temperature)
+    "photo_taken",     // (species or "", total score)
+];
 
 // Lua API functions that scripts can call
 
@@ -43,6 +55,133 @@ pub fn get_time_of_day() -> f32 {
     12.0
 }
 
+// Almanac queries, read from the sanctuary's clock
+pub fn get_moon_phase(time_state: Res<TimeState>) -> f32 {
+    time_state.moon_cycle()
+}
+
+pub fn get_moon_illumination(time_state: Res<TimeState>) -> f32 {
+    time_state.moon_illumination()
+}
+
+pub fn get_sunrise_hour(time_state: Res<TimeState>) -> f32 {
+    time_state.sunrise_hour()
+}
+
+pub fn get_sunset_hour(time_state: Res<TimeState>) -> f32 {
+    time_state.sunset_hour()
+}
+
+// World editing: smart objects spawned by scripts go through the same events as the catalog's
+pub fn spawn_smart_object(
+    In((definition_id, x, y)): In<(String, f32, f32)>,
+    registry: Res<SmartObjectRegistry>,
+    mut spawned: ResMut<ScriptSpawnedObjects>,
+    mut spawn_events: EventWriter<SpawnSmartObjectEvent>,
+) -> bool {
+    if registry.get_definition(&definition_id).is_none() {
+        warn!("Lua tried to spawn unknown smart object '{}'", definition_id);
+        return false;
+    }
+    if spawned.count >= MAX_SCRIPT_OBJECTS {
+        warn!("Lua smart object limit of {} reached", MAX_SCRIPT_OBJECTS);
+        return false;
+    }
+    spawned.count += 1;
+    spawn_events.write(SpawnSmartObjectEvent {
+        definition_id,
+        position: Vec3::new(x, y, 0.0),
+        custom_modifications: None,
+    });
+    true
+}
+
+// Entity ids of the live smart objects made from a definition, for passing back to remove_smart_object
+pub fn find_smart_objects(
+    In((definition_id,)): In<(String,)>,
+    registry: Res<SmartObjectRegistry>,
+) -> Vec<u64> {
+    registry.active_objects.iter()
+        .filter(|(_, object)| object.definition_id == definition_id)
+        .map(|(entity, _)| entity.to_bits())
+        .collect()
+}
+
+pub fn remove_smart_object(
+    In((id,)): In<(u64,)>,
+    registry: Res<SmartObjectRegistry>,
+    mut spawned: ResMut<ScriptSpawnedObjects>,
+    mut remove_events: EventWriter<RemoveSmartObjectEvent>,
+) -> bool {
+    let Ok(entity) = Entity::try_from_bits(id) else { return false };
+    if !registry.active_objects.contains_key(&entity) {
+        return false;
+    }
+    spawned.count = spawned.count.saturating_sub(1);
+    remove_events.write(RemoveSmartObjectEvent { entity, refund_player: false });
+    true
+}
+
+// Events: the named Lua function is called with the event's arguments, see SCRIPT_EVENTS
+pub fn subscribe_event(
+    In((event_name, handler)): In<(String, String)>,
+    mut subscriptions: ResMut<ScriptSubscriptions>,
+) -> bool {
+    if !SCRIPT_EVENTS.contains(&event_name.as_str()) {
+        warn!("Lua tried to subscribe to unknown event '{}', expected one of {:?}", event_name, SCRIPT_EVENTS);
+        return false;
+    }
+    let handlers = subscriptions.handlers.entry(event_name).or_default();
+    if !handlers.contains(&handler) {
+        handlers.push(handler);
+    }
+    true
+}
+
+pub fn unsubscribe_event(
+    In((event_name, handler)): In<(String, String)>,
+    mut subscriptions: ResMut<ScriptSubscriptions>,
+) {
+    if let Some(handlers) = subscriptions.handlers.get_mut(&event_name) {
+        handlers.retain(|existing| *existing != handler);
+    }
+}
+
+// Timers: calls the named Lua function once after the delay, in real seconds
+pub fn schedule_callback(
+    In((delay, handler)): In<(f32, String)>,
+    mut callbacks: ResMut<ScriptCallbacks>,
+) -> bool {
+    if callbacks.pending.len() >= MAX_PENDING_CALLBACKS {
+        warn!("Lua callback limit of {} reached, dropping '{}'", MAX_PENDING_CALLBACKS, handler);
+        return false;
+    }
+    callbacks.pending.push((Timer::from_seconds(delay.max(0.0), TimerMode::Once), handler));
+    true
+}
+
+// Storage: a small key-value store kept in the save, see ScriptStore for its limits
+pub fn store_get(
+    In((key,)): In<(String,)>,
+    store: Res<ScriptStore>,
+) -> Option<String> {
+    store.get(&key).map(str::to_string)
+}
+
+pub fn store_set(
+    In((key, value)): In<(String, String)>,
+    mut store: ResMut<ScriptStore>,
+) -> bool {
+    store.set(key, value)
+}
+
+pub fn store_remove(
+    In((key,)): In<(String,)>,
+    mut store: ResMut<ScriptStore>,
+) -> bool {
+    store.remove(&key)
+}
+
 pub fn random_float() -> f32 {
     rand::random::<f32>()
 }
@@ -53,6 +192,23 @@ pub fn distance_to_target() -> f32 {
     50.0
 }
 
+// Resources
+#[derive(Resource, Default)]
+pub struct ScriptSubscriptions {
+    // Event name -> Lua functions to call
+    pub handlers: HashMap<String, Vec<String>>,
+}
+
+#[derive(Resource, Default)]
+pub struct ScriptCallbacks {
+    pub pending: Vec<(Timer, String)>,
+}
+
+#[derive(Resource, Default)]
+pub struct ScriptSpawnedObjects {
+    pub count: usize,
+}
+
 // More advanced API functions that would require system access
 pub struct LuaBirdAPI {
     pub entity: Entity,
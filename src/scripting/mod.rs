@@ -1,15 +1,24 @@
 use bevy::prelude::*;
 use bevy_scriptum::{prelude::*, runtimes::lua::prelude::*};
+use crate::environment::resources::WeatherChangeEvent;
+use crate::notifications::resources::ShowNotificationEvent;
+use crate::photo_mode::components::PhotoTakenEvent;
 
 pub mod lua_api;
+pub mod sandbox;
 
 use lua_api::*;
+use sandbox::*;
 
 pub struct ScriptingPlugin;
 
 impl Plugin for ScriptingPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugins(ScriptingPlugin::default())
+            .init_resource::<ScriptSubscriptions>()
+            .init_resource::<ScriptCallbacks>()
+            .init_resource::<ScriptSpawnedObjects>()
+            .init_resource::<ScriptHealthPanel>()
             .add_scripting::<LuaRuntime>(|runtime| {
                 // Register bird AI functions
                 runtime.add_function(String::from("log_info"), log_info);
@@ -19,22 +28,109 @@ impl Plugin for ScriptingPlugin {
                 runtime.add_function(String::from("get_weather_fear"), get_weather_fear);
                 runtime.add_function(String::from("get_time_of_day"), get_time_of_day);
                 
+                // Register almanac functions
+                runtime.add_function(String::from("get_moon_phase"), get_moon_phase);
+                runtime.add_function(String::from("get_moon_illumination"), get_moon_illumination);
+                runtime.add_function(String::from("get_sunrise_hour"), get_sunrise_hour);
+                runtime.add_function(String::from("get_sunset_hour"), get_sunset_hour);
+                
+                // Register world editing functions
+                runtime.add_function(String::from("spawn_smart_object"), spawn_smart_object);
+                runtime.add_function(String::from("find_smart_objects"), find_smart_objects);
+                runtime.add_function(String::from("remove_smart_object"), remove_smart_object);
+                
+                // Register event and timer functions
+                runtime.add_function(String::from("subscribe_event"), subscribe_event);
+                runtime.add_function(String::from("unsubscribe_event"), unsubscribe_event);
+                runtime.add_function(String::from("schedule_callback"), schedule_callback);
+                
+                // Register save storage functions
+                runtime.add_function(String::from("store_get"), store_get);
+                runtime.add_function(String::from("store_set"), store_set);
+                runtime.add_function(String::from("store_remove"), store_remove);
+                
                 // Register utility functions
                 runtime.add_function(String::from("random_float"), random_float);
                 runtime.add_function(String::from("distance_to_target"), distance_to_target);
             })
-            .add_systems(Update, execute_behavior_scripts);
+            .add_systems(Startup, install_sandbox_limits)
+            .add_systems(Update, (
+                attach_script_health_system,
+                script_load_error_system,
+                execute_behavior_scripts,
+                dispatch_script_events,
+                run_script_callbacks,
+            ).chain().in_set(crate::GameSet::AI))
+            .add_systems(Update, (
+                toggle_script_health_panel_system,
+                script_health_button_system,
+                refresh_script_health_panel_system,
+            ).chain().in_set(crate::GameSet::UI));
     }
 }
 
-// System to execute Lua behavior scripts
+// System to execute Lua behavior scripts, each call kept inside the sandbox budgets
 fn execute_behavior_scripts(
-    mut script_query: Query<&mut Script<LuaRuntime>>,
+    mut script_query: Query<(&mut Script<LuaRuntime>, &mut ScriptHealth)>,
+    mut notifications: EventWriter<ShowNotificationEvent>,
 ) {
-    for mut script in script_query.iter_mut() {
+    for (mut script, mut health) in script_query.iter_mut() {
         // Execute the script's behavior evaluation function if it exists
         if script.has_function("evaluate_behavior") {
-            let _ = script.call_if_exists("evaluate_behavior", ());
+            run_guarded(&mut health, "evaluate_behavior", &mut notifications, || script.call_if_exists("evaluate_behavior", ()));
+        }
+    }
+}
+
+// Calls every subscribed handler with the event's arguments
+fn dispatch_script_events(
+    mut weather_events: EventReader<WeatherChangeEvent>,
+    mut photo_events: EventReader<PhotoTakenEvent>,
+    subscriptions: Res<ScriptSubscriptions>,
+    mut script_query: Query<(&mut Script<LuaRuntime>, &mut ScriptHealth)>,
+    mut notifications: EventWriter<ShowNotificationEvent>,
+) {
+    let handlers = |event_name: &str| subscriptions.handlers.get(event_name).cloned().unwrap_or_default();
+
+    for event in weather_events.read() {
+        for handler in handlers("weather_changed") {
+            for (mut script, mut health) in script_query.iter_mut() {
+                let args = (format!("{:?}", event.new_weather), event.temperature);
+                run_guarded(&mut health, &handler, &mut notifications, || script.call_if_exists(&handler, args));
+            }
+        }
+    }
+
+    for event in photo_events.read() {
+        let species = event.species.map_or(String::new(), |species| format!("{:?}", species));
+        for handler in handlers("photo_taken") {
+            for (mut script, mut health) in script_query.iter_mut() {
+                let args = (species.clone(), event.score.total_score);
+                run_guarded(&mut health, &handler, &mut notifications, || script.call_if_exists(&handler, args));
+            }
+        }
+    }
+}
+
+// Fires delayed callbacks scheduled with schedule_callback
+fn run_script_callbacks(
+    time: Res<Time>,
+    mut callbacks: ResMut<ScriptCallbacks>,
+    mut script_query: Query<(&mut Script<LuaRuntime>, &mut ScriptHealth)>,
+    mut notifications: EventWriter<ShowNotificationEvent>,
+) {
+    let mut due = Vec::new();
+    callbacks.pending.retain_mut(|(timer, handler)| {
+        timer.tick(time.delta());
+        if timer.finished() {
+            due.push(handler.clone());
+        }
+        !timer.finished()
+    });
+
+    for handler in due {
+        for (mut script, mut health) in script_query.iter_mut() {
+            run_guarded(&mut health, &handler, &mut notifications, || script.call_if_exists(&handler, ()));
         }
     }
 }
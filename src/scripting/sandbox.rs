@@ -0,0 +1,321 @@
+// Script Sandbox - Execution budgets, memory caps and error isolation for Lua scripts
+//
+// Every call into a script runs against an instruction budget enforced by a Lua hook and a
+// wall-clock budget measured around the call, inside one Lua state capped in memory. Errors,
+// panics and overruns are recorded on the script's ScriptHealth instead of reaching the game; a
+// script that panics, or keeps failing or overrunning, is disabled with a warning toast. The
+// `scripts` console command opens a health panel listing each script's load and runtime errors,
+// where disabled scripts can be turned back on.
+use bevy::prelude::*;
+use bevy_scriptum::{prelude::*, runtimes::lua::prelude::*};
+use std::collections::VecDeque;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::Instant;
+use crate::debug_console::ConsoleCommand;
+use crate::notifications::{resources::ShowNotificationEvent, components::NotificationType};
+use super::LuaBehaviorScript;
+
+// The hook fires every HOOK_INTERVAL instructions, so the budget is counted in those steps
+const HOOK_INTERVAL: u32 = 1_000;
+const INSTRUCTION_BUDGET: u32 = 200; // x HOOK_INTERVAL instructions per call
+const TIME_BUDGET_MS: f32 = 4.0;
+const MEMORY_LIMIT_BYTES: usize = 32 * 1024 * 1024;
+// Errors or overruns before a script is switched off
+const MAX_STRIKES: u32 = 5;
+const KEPT_ERRORS: usize = 5;
+
+// Hook steps used by the call in progress; calls run one at a time on the main thread
+static HOOK_STEPS: AtomicU32 = AtomicU32::new(0);
+
+pub fn install_sandbox_limits(mut runtime: ResMut<LuaRuntime>) {
+    runtime.with_engine_mut(|lua| {
+        if let Err(e) = lua.set_memory_limit(MEMORY_LIMIT_BYTES) {
+            warn!("Could not cap Lua memory: {}", e);
+        }
+        lua.set_hook(mlua::HookTriggers::new().every_nth_instruction(HOOK_INTERVAL), |_, _| {
+            if HOOK_STEPS.fetch_add(1, Ordering::Relaxed) >= INSTRUCTION_BUDGET {
+                return Err(mlua::Error::RuntimeError("instruction budget exceeded".to_string()));
+            }
+            Ok(mlua::VmState::Continue)
+        });
+    });
+    info!("Lua sandbox: {}k instructions and {:.0}ms per call, {}MB memory",
+        INSTRUCTION_BUDGET * HOOK_INTERVAL / 1_000, TIME_BUDGET_MS, MEMORY_LIMIT_BYTES / (1024 * 1024));
+}
+
+// Components
+#[derive(Component, Default)]
+pub struct ScriptHealth {
+    pub name: String,
+    pub load_error: Option<String>,
+    // Newest last
+    pub errors: VecDeque<String>,
+    pub strikes: u32,
+    pub calls: u64,
+    pub disabled: bool,
+}
+
+impl ScriptHealth {
+    fn record_error(&mut self, error: String) {
+        self.errors.push_back(error);
+        if self.errors.len() > KEPT_ERRORS {
+            self.errors.pop_front();
+        }
+    }
+
+    fn disable(&mut self, reason: &str, notifications: &mut EventWriter<ShowNotificationEvent>) {
+        self.disabled = true;
+        warn!("Lua script '{}' disabled: {}", self.name, reason);
+        notifications.write(ShowNotificationEvent {
+            notification: NotificationType::Warning {
+                message: format!("Script '{}' was disabled: {}. Type 'scripts' in the console for details", self.name, reason),
+            },
+        });
+    }
+}
+
+/// Runs one call into a script within the budgets, keeping any failure inside the script's health
+pub fn run_guarded<T, E: std::fmt::Display>(
+    health: &mut ScriptHealth,
+    function: &str,
+    notifications: &mut EventWriter<ShowNotificationEvent>,
+    call: impl FnOnce() -> Result<T, E>,
+) -> Option<T> {
+    if health.disabled || health.load_error.is_some() {
+        return None;
+    }
+    health.calls += 1;
+    HOOK_STEPS.store(0, Ordering::Relaxed);
+    let started = Instant::now();
+    let result = catch_unwind(AssertUnwindSafe(call));
+    let elapsed_ms = started.elapsed().as_secs_f32() * 1000.0;
+
+    let value = match result {
+        Err(_) => {
+            health.record_error(format!("{}: panicked", function));
+            health.disable("it crashed", notifications);
+            return None;
+        }
+        Ok(Err(e)) => {
+            health.record_error(format!("{}: {}", function, e));
+            health.strikes += 1;
+            None
+        }
+        Ok(Ok(value)) => Some(value),
+    };
+
+    if elapsed_ms > TIME_BUDGET_MS {
+        health.record_error(format!("{}: took {:.1}ms of its {:.0}ms budget", function, elapsed_ms, TIME_BUDGET_MS));
+        health.strikes += 1;
+    }
+    if health.strikes >= MAX_STRIKES {
+        health.disable("it kept failing or running too long", notifications);
+    }
+    value
+}
+
+// Every script gets a health record, named after its file
+pub fn attach_script_health_system(
+    mut commands: Commands,
+    script_query: Query<(Entity, Option<&LuaBehaviorScript>), Added<Script<LuaRuntime>>>,
+) {
+    for (entity, behavior) in &script_query {
+        let name = behavior.map_or_else(|| format!("script {}", entity.index()), |behavior| behavior.script_path.clone());
+        commands.entity(entity).insert(ScriptHealth { name, ..default() });
+    }
+}
+
+// Scripts that fail to load never run, the panel shows why
+pub fn script_load_error_system(
+    asset_server: Res<AssetServer>,
+    mut script_query: Query<(&Script<LuaRuntime>, &mut ScriptHealth)>,
+    mut notifications: EventWriter<ShowNotificationEvent>,
+) {
+    for (script, mut health) in &mut script_query {
+        if health.load_error.is_some() {
+            continue;
+        }
+        if let Some(bevy::asset::LoadState::Failed(error)) = asset_server.get_load_state(&script.script) {
+            health.load_error = Some(error.to_string());
+            warn!("Lua script '{}' failed to load: {}", health.name, error);
+            notifications.write(ShowNotificationEvent {
+                notification: NotificationType::Warning {
+                    message: format!("Script '{}' failed to load. Type 'scripts' in the console for details", health.name),
+                },
+            });
+        }
+    }
+}
+
+// Health panel
+#[derive(Resource, Default)]
+pub struct ScriptHealthPanel {
+    pub is_open: bool,
+    panel_dirty: bool,
+}
+
+#[derive(Component)]
+pub struct ScriptHealthPanelRoot;
+
+#[derive(Component)]
+pub enum ScriptHealthButton {
+    Enable(Entity),
+    Close,
+}
+
+pub fn toggle_script_health_panel_system(
+    mut command_events: EventReader<ConsoleCommand>,
+    mut panel: ResMut<ScriptHealthPanel>,
+    changed_query: Query<(), Changed<ScriptHealth>>,
+) {
+    for command in command_events.read() {
+        if command.command == "scripts" {
+            panel.is_open = !panel.is_open;
+            panel.panel_dirty = true;
+        }
+    }
+    if panel.is_open && !changed_query.is_empty() {
+        panel.panel_dirty = true;
+    }
+}
+
+pub fn script_health_button_system(
+    interaction_query: Query<(&Interaction, &ScriptHealthButton), Changed<Interaction>>,
+    mut panel: ResMut<ScriptHealthPanel>,
+    mut health_query: Query<&mut ScriptHealth>,
+) {
+    for (interaction, button) in &interaction_query {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        match button {
+            ScriptHealthButton::Enable(entity) => {
+                if let Ok(mut health) = health_query.get_mut(*entity) {
+                    health.disabled = false;
+                    health.strikes = 0;
+                    info!("Lua script '{}' re-enabled", health.name);
+                }
+            }
+            ScriptHealthButton::Close => panel.is_open = false,
+        }
+        panel.panel_dirty = true;
+    }
+}
+
+fn spawn_health_button(parent: &mut ChildSpawnerCommands, label: &str, button: ScriptHealthButton) {
+    parent.spawn((
+        Button,
+        Node {
+            padding: UiRect::axes(Val::Px(6.0), Val::Px(2.0)),
+            ..default()
+        },
+        BackgroundColor(Color::srgb(0.6, 0.5, 0.4)),
+        BorderRadius::all(Val::Px(4.0)),
+        button,
+    )).with_children(|button| {
+        button.spawn((
+            Text::new(label),
+            TextFont { font_size: 10.0, ..default() },
+            TextColor(Color::WHITE),
+        ));
+    });
+}
+
+pub fn refresh_script_health_panel_system(
+    mut commands: Commands,
+    mut panel: ResMut<ScriptHealthPanel>,
+    panel_query: Query<Entity, With<ScriptHealthPanelRoot>>,
+    health_query: Query<(Entity, &ScriptHealth)>,
+) {
+    if !panel.panel_dirty {
+        return;
+    }
+    panel.panel_dirty = false;
+
+    for root in &panel_query {
+        commands.entity(root).despawn();
+    }
+    if !panel.is_open {
+        return;
+    }
+
+    let mut scripts: Vec<(Entity, &ScriptHealth)> = health_query.iter().collect();
+    scripts.sort_by(|(_, a), (_, b)| a.name.cmp(&b.name));
+
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            right: Val::Px(10.0),
+            top: Val::Px(60.0),
+            width: Val::Px(520.0),
+            max_height: Val::Percent(85.0),
+            flex_direction: FlexDirection::Column,
+            padding: UiRect::all(Val::Px(10.0)),
+            row_gap: Val::Px(4.0),
+            overflow: Overflow::clip(),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.95, 0.92, 0.88, 0.95)),
+        BorderRadius::all(Val::Px(6.0)),
+        GlobalZIndex(40),
+        ScriptHealthPanelRoot,
+        Name::new("ScriptHealth"),
+    )).with_children(|root| {
+        root.spawn(Node {
+            width: Val::Percent(100.0),
+            justify_content: JustifyContent::SpaceBetween,
+            align_items: AlignItems::Center,
+            ..default()
+        }).with_children(|header| {
+            header.spawn((
+                Text::new(format!("Script Health  ({} scripts)", scripts.len())),
+                TextFont { font_size: 16.0, ..default() },
+                TextColor(Color::srgb(0.3, 0.2, 0.1)),
+            ));
+            spawn_health_button(header, "Close", ScriptHealthButton::Close);
+        });
+
+        if scripts.is_empty() {
+            root.spawn((
+                Text::new("No scripts loaded"),
+                TextFont { font_size: 12.0, ..default() },
+                TextColor(Color::srgb(0.5, 0.4, 0.3)),
+            ));
+        }
+
+        for (entity, health) in scripts {
+            let (status, color) = match (&health.load_error, health.disabled) {
+                (Some(_), _) => ("Failed to load", Color::srgb(0.7, 0.2, 0.1)),
+                (None, true) => ("Disabled", Color::srgb(0.7, 0.4, 0.1)),
+                (None, false) if health.strikes > 0 => ("Running with errors", Color::srgb(0.6, 0.5, 0.1)),
+                (None, false) => ("OK", Color::srgb(0.2, 0.5, 0.2)),
+            };
+
+            root.spawn(Node {
+                width: Val::Percent(100.0),
+                justify_content: JustifyContent::SpaceBetween,
+                align_items: AlignItems::Center,
+                margin: UiRect::top(Val::Px(4.0)),
+                ..default()
+            }).with_children(|row| {
+                row.spawn((
+                    Text::new(format!("{} - {} ({} calls, {} strikes)", health.name, status, health.calls, health.strikes)),
+                    TextFont { font_size: 12.0, ..default() },
+                    TextColor(color),
+                ));
+                if health.disabled {
+                    spawn_health_button(row, "Enable", ScriptHealthButton::Enable(entity));
+                }
+            });
+
+            for error in health.load_error.iter().chain(health.errors.iter()) {
+                root.spawn((
+                    Text::new(format!("  {}", error)),
+                    TextFont { font_size: 11.0, ..default() },
+                    TextColor(Color::srgb(0.4, 0.3, 0.2)),
+                ));
+            }
+        }
+    });
+}
@@ -0,0 +1,444 @@
+// Search Palette - Ctrl+K quick search across species, catalog items, settings, journal entries and console commands
+use bevy::prelude::*;
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input::{ButtonState, InputSystem};
+use crate::catalog::components::PlaceableItemType;
+use crate::journal::components::JournalTab;
+use crate::journal::resources::NoteTarget;
+use crate::menu::resources::MenuType;
+use crate::camera::CameraFocusEvent;
+
+const PANEL_WIDTH: f32 = 560.0;
+const MAX_RESULTS: usize = 12;
+const MAX_QUERY_LENGTH: usize = 40;
+
+pub struct SearchPalettePlugin;
+
+impl Plugin for SearchPalettePlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<SearchPalette>()
+            .init_resource::<SearchIndex>()
+            .add_event::<SearchJumpEvent>()
+            // Runs straight after input is read so the keys typed into the palette never reach the game
+            .add_systems(PreUpdate, (
+                toggle_search_palette_system.run_if(crate::debug_console::console_is_not_visible),
+                block_game_keys_system.run_if(search_palette_is_open),
+            ).chain().after(InputSystem))
+            .configure_sets(Update, SearchProviders
+                .in_set(crate::GameSet::UI)
+                .run_if(search_index_is_stale)
+                .after(search_palette_input_system))
+            .add_systems(Update, (
+                search_palette_input_system.run_if(search_palette_is_open),
+                rank_search_results_system.after(SearchProviders),
+                search_result_button_system,
+                refresh_search_palette_system,
+            ).chain().in_set(crate::GameSet::UI))
+            .add_systems(Update, (
+                provide_bird_search_entries.in_set(SearchProviders),
+                entity_search_jump_system,
+            ).in_set(crate::GameSet::UI));
+    }
+}
+
+// Each module adds its own systems to this set to fill the index whenever the palette opens
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SearchProviders;
+
+pub fn search_palette_is_open(palette: Res<SearchPalette>) -> bool {
+    palette.is_open
+}
+
+pub fn search_index_is_stale(index: Res<SearchIndex>) -> bool {
+    index.stale
+}
+
+// Where picking a result takes the player; each module handles its own kind of jump
+#[derive(Debug, Clone, PartialEq)]
+pub enum SearchTarget {
+    Entity(Entity),
+    CatalogItem(PlaceableItemType),
+    Setting(MenuType),
+    JournalTab(JournalTab),
+    JournalNote(NoteTarget),
+    ConsoleCommand(&'static str),
+}
+
+#[derive(Event)]
+pub struct SearchJumpEvent {
+    pub target: SearchTarget,
+}
+
+pub struct SearchEntry {
+    pub category: &'static str,
+    pub label: String,
+    // Matched too, but ranked below a hit on the label
+    pub keywords: String,
+    pub target: SearchTarget,
+}
+
+impl SearchEntry {
+    fn score(&self, query: &str) -> Option<i32> {
+        let label = fuzzy_score(query, &self.label);
+        let keywords = fuzzy_score(query, &self.keywords).map(|score| score - 10);
+        label.into_iter().chain(keywords).max()
+    }
+}
+
+// Resources
+#[derive(Resource, Default)]
+pub struct SearchIndex {
+    entries: Vec<SearchEntry>,
+    stale: bool,
+}
+
+impl SearchIndex {
+    pub fn add(&mut self, category: &'static str, label: impl Into<String>, keywords: impl Into<String>, target: SearchTarget) {
+        self.entries.push(SearchEntry {
+            category,
+            label: label.into(),
+            keywords: keywords.into(),
+            target,
+        });
+    }
+}
+
+#[derive(Resource, Default)]
+pub struct SearchPalette {
+    pub is_open: bool,
+    pub query: String,
+    pub selected: usize,
+    // Index entries matching the query, best first
+    results: Vec<usize>,
+    panel_dirty: bool,
+}
+
+impl SearchPalette {
+    fn close(&mut self) {
+        self.is_open = false;
+        self.query.clear();
+        self.selected = 0;
+        self.results.clear();
+        self.panel_dirty = true;
+    }
+}
+
+// Components
+#[derive(Component)]
+pub struct SearchPalettePanel;
+
+#[derive(Component)]
+pub struct SearchResultButton {
+    pub rank: usize,
+}
+
+// Query letters have to appear in order; runs of letters and hits on word starts rank higher
+pub fn fuzzy_score(query: &str, text: &str) -> Option<i32> {
+    let query: Vec<char> = query.chars().filter(|c| !c.is_whitespace()).flat_map(char::to_lowercase).collect();
+    if query.is_empty() {
+        return None;
+    }
+
+    let mut score = 0;
+    let mut next = 0;
+    let mut previous_match: Option<usize> = None;
+    let mut previous_char = ' ';
+    for (index, c) in text.chars().flat_map(char::to_lowercase).enumerate() {
+        if next < query.len() && c == query[next] {
+            score += 1;
+            if previous_match == Some(index.wrapping_sub(1)) {
+                score += 5;
+            }
+            if !previous_char.is_alphanumeric() {
+                score += 8;
+            }
+            if next == 0 {
+                score -= (index as i32).min(10);
+            }
+            previous_match = Some(index);
+            next += 1;
+        }
+        previous_char = c;
+    }
+
+    (next == query.len()).then_some(score)
+}
+
+fn toggle_search_palette_system(
+    mut keyboard: ResMut<ButtonInput<KeyCode>>,
+    current_state: Res<State<crate::AppState>>,
+    mut palette: ResMut<SearchPalette>,
+    mut index: ResMut<SearchIndex>,
+) {
+    let ctrl = keyboard.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]);
+    if !ctrl || !keyboard.just_pressed(KeyCode::KeyK) {
+        return;
+    }
+
+    if palette.is_open {
+        palette.close();
+        // The keys stop being blocked once it's closed, and K on its own fires the remote shutter
+        keyboard.reset(KeyCode::KeyK);
+        return;
+    }
+    if !matches!(current_state.get(), crate::AppState::Playing | crate::AppState::Journal | crate::AppState::Catalog) {
+        return;
+    }
+
+    palette.is_open = true;
+    palette.panel_dirty = true;
+    // Birds come and go and the journal grows, so the index is gathered fresh each time
+    index.entries.clear();
+    index.stale = true;
+}
+
+// Letters typed into the palette would otherwise set off every hotkey in the game
+pub fn block_game_keys_system(mut keyboard: ResMut<ButtonInput<KeyCode>>) {
+    let keys: Vec<KeyCode> = keyboard.get_pressed()
+        .chain(keyboard.get_just_pressed())
+        .chain(keyboard.get_just_released())
+        .copied()
+        .filter(|key| !matches!(key, KeyCode::ControlLeft | KeyCode::ControlRight))
+        .collect();
+    for key in keys {
+        keyboard.reset(key);
+    }
+}
+
+pub fn search_palette_input_system(
+    mut keyboard_events: EventReader<KeyboardInput>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut palette: ResMut<SearchPalette>,
+    mut jump_events: EventWriter<SearchJumpEvent>,
+    index: Res<SearchIndex>,
+) {
+    let ctrl = keyboard.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]);
+    for event in keyboard_events.read() {
+        if event.state != ButtonState::Pressed {
+            continue;
+        }
+
+        match &event.logical_key {
+            Key::Escape => {
+                palette.close();
+                return;
+            }
+            Key::Enter => {
+                if let Some(entry) = palette.results.get(palette.selected).and_then(|&entry| index.entries.get(entry)) {
+                    jump_events.write(SearchJumpEvent { target: entry.target.clone() });
+                }
+                palette.close();
+                return;
+            }
+            Key::ArrowDown => {
+                palette.selected = (palette.selected + 1).min(palette.results.len().saturating_sub(1));
+            }
+            Key::ArrowUp => {
+                palette.selected = palette.selected.saturating_sub(1);
+            }
+            Key::Backspace => {
+                palette.query.pop();
+            }
+            Key::Space if palette.query.len() < MAX_QUERY_LENGTH => palette.query.push(' '),
+            // Skips the K of Ctrl+K, which arrives here as well
+            Key::Character(text) if palette.query.len() < MAX_QUERY_LENGTH && !ctrl => palette.query.push_str(text),
+            _ => continue,
+        }
+        palette.panel_dirty = true;
+    }
+}
+
+fn rank_search_results_system(
+    mut palette: ResMut<SearchPalette>,
+    mut index: ResMut<SearchIndex>,
+) {
+    if !palette.is_open || !(palette.is_changed() || index.stale) {
+        return;
+    }
+    index.stale = false;
+
+    let query = palette.query.trim().to_string();
+    let mut scored: Vec<(i32, usize)> = index.entries.iter()
+        .enumerate()
+        .filter_map(|(position, entry)| entry.score(&query).map(|score| (score, position)))
+        .collect();
+    scored.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+
+    let results: Vec<usize> = scored.into_iter().take(MAX_RESULTS).map(|(_, position)| position).collect();
+    let palette = palette.bypass_change_detection();
+    palette.selected = palette.selected.min(results.len().saturating_sub(1));
+    palette.results = results;
+    palette.panel_dirty = true;
+}
+
+fn search_result_button_system(
+    mut button_query: Query<(&Interaction, &SearchResultButton, &mut BackgroundColor), Changed<Interaction>>,
+    mut palette: ResMut<SearchPalette>,
+    mut jump_events: EventWriter<SearchJumpEvent>,
+    index: Res<SearchIndex>,
+) {
+    for (interaction, button, mut bg_color) in &mut button_query {
+        match *interaction {
+            Interaction::Pressed => {
+                if let Some(entry) = palette.results.get(button.rank).and_then(|&entry| index.entries.get(entry)) {
+                    jump_events.write(SearchJumpEvent { target: entry.target.clone() });
+                }
+                palette.close();
+                return;
+            }
+            Interaction::Hovered => *bg_color = Color::srgb(0.9, 0.85, 0.78).into(),
+            Interaction::None => {
+                *bg_color = if button.rank == palette.selected {
+                    Color::srgb(0.85, 0.78, 0.68).into()
+                } else {
+                    Color::srgba(0.0, 0.0, 0.0, 0.0).into()
+                };
+            }
+        }
+    }
+}
+
+fn refresh_search_palette_system(
+    mut commands: Commands,
+    mut palette: ResMut<SearchPalette>,
+    index: Res<SearchIndex>,
+    panel_query: Query<Entity, With<SearchPalettePanel>>,
+) {
+    if !palette.panel_dirty {
+        return;
+    }
+    palette.bypass_change_detection().panel_dirty = false;
+
+    for panel in &panel_query {
+        commands.entity(panel).despawn();
+    }
+    if palette.is_open {
+        spawn_search_palette(&mut commands, &palette, &index);
+    }
+}
+
+fn spawn_search_palette(commands: &mut Commands, palette: &SearchPalette, index: &SearchIndex) {
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Percent(50.0),
+            top: Val::Px(90.0),
+            margin: UiRect::left(Val::Px(-PANEL_WIDTH * 0.5)),
+            width: Val::Px(PANEL_WIDTH),
+            flex_direction: FlexDirection::Column,
+            padding: UiRect::all(Val::Px(12.0)),
+            row_gap: Val::Px(4.0),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.95, 0.92, 0.88, 0.97)),
+        BorderRadius::all(Val::Px(8.0)),
+        GlobalZIndex(50),
+        SearchPalettePanel,
+        Name::new("SearchPalette"),
+    )).with_children(|panel| {
+        panel.spawn((
+            Node {
+                width: Val::Percent(100.0),
+                padding: UiRect::axes(Val::Px(8.0), Val::Px(6.0)),
+                border: UiRect::all(Val::Px(2.0)),
+                margin: UiRect::bottom(Val::Px(6.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.98, 0.95, 0.92)),
+            BorderColor(Color::srgb(0.3, 0.2, 0.1)),
+        )).with_children(|field| {
+            field.spawn((
+                Text::new(format!("{}|", palette.query)),
+                TextFont { font_size: 16.0, ..default() },
+                TextColor(Color::srgb(0.2, 0.1, 0.05)),
+            ));
+        });
+
+        if palette.query.trim().is_empty() {
+            panel.spawn((
+                Text::new("Search species, birds in the yard, catalog items, settings, journal notes and console commands"),
+                TextFont { font_size: 12.0, ..default() },
+                TextColor(Color::srgb(0.5, 0.4, 0.3)),
+            ));
+            return;
+        }
+        if palette.results.is_empty() {
+            panel.spawn((
+                Text::new("Nothing matches"),
+                TextFont { font_size: 12.0, ..default() },
+                TextColor(Color::srgb(0.5, 0.4, 0.3)),
+            ));
+            return;
+        }
+
+        for (rank, entry) in palette.results.iter().filter_map(|&entry| index.entries.get(entry)).enumerate() {
+            let background = if rank == palette.selected {
+                Color::srgb(0.85, 0.78, 0.68)
+            } else {
+                Color::srgba(0.0, 0.0, 0.0, 0.0)
+            };
+            panel.spawn((
+                Button,
+                Node {
+                    width: Val::Percent(100.0),
+                    justify_content: JustifyContent::SpaceBetween,
+                    padding: UiRect::axes(Val::Px(8.0), Val::Px(4.0)),
+                    ..default()
+                },
+                BackgroundColor(background),
+                BorderRadius::all(Val::Px(4.0)),
+                SearchResultButton { rank },
+            )).with_children(|row| {
+                row.spawn((
+                    Text::new(entry.label.clone()),
+                    TextFont { font_size: 14.0, ..default() },
+                    TextColor(Color::srgb(0.2, 0.1, 0.05)),
+                ));
+                row.spawn((
+                    Text::new(entry.category),
+                    TextFont { font_size: 11.0, ..default() },
+                    TextColor(Color::srgb(0.5, 0.4, 0.3)),
+                ));
+            });
+        }
+
+        panel.spawn((
+            Text::new("Up/Down to choose, Enter to jump, Esc to close"),
+            TextFont { font_size: 11.0, ..default() },
+            TextColor(Color::srgb(0.6, 0.5, 0.4)),
+            Node {
+                margin: UiRect::top(Val::Px(6.0)),
+                ..default()
+            },
+        ));
+    });
+}
+
+// Birds currently in the yard, so the camera can be sent straight to one
+fn provide_bird_search_entries(
+    mut index: ResMut<SearchIndex>,
+    bird_query: Query<(Entity, &crate::bird::Bird)>,
+) {
+    for (entity, bird) in &bird_query {
+        index.add("Bird in yard", format!("{:?}", bird.species), "bird yard live", SearchTarget::Entity(entity));
+    }
+}
+
+// Glides the camera over to the entity, back in the yard if a screen was open
+fn entity_search_jump_system(
+    mut jump_events: EventReader<SearchJumpEvent>,
+    mut focus_events: EventWriter<CameraFocusEvent>,
+    target_query: Query<&GlobalTransform>,
+    current_state: Res<State<crate::AppState>>,
+    mut next_state: ResMut<NextState<crate::AppState>>,
+) {
+    for event in jump_events.read() {
+        let SearchTarget::Entity(entity) = event.target else { continue };
+        let Ok(target) = target_query.get(entity) else { continue };
+        focus_events.write(CameraFocusEvent { position: target.translation().truncate() });
+        if *current_state.get() != crate::AppState::Playing {
+            next_state.set(crate::AppState::Playing);
+        }
+    }
+}
@@ -0,0 +1,241 @@
+// Seasonal Decor - Objects and scenery dressed up for each season, swapped when the season turns
+use bevy::prelude::*;
+use crate::aesthetic_objects::AestheticObject;
+use crate::catalog::components::PlaceableObject;
+use crate::catalog::systems::object_filename;
+use crate::environment::components::Season;
+use crate::environment::resources::{SeasonChangeEvent, TimeState};
+use crate::notifications::{resources::ShowNotificationEvent, components::NotificationType};
+use crate::save_load::resources::LoadCompleteEvent;
+use crate::smart_objects::SmartObjectRegistry;
+use crate::smart_objects::config::SmartObjectDefinition;
+
+const SEASONS: [Season; 4] = [Season::Spring, Season::Summer, Season::Fall, Season::Winter];
+const PREVIEW_SIZE: f32 = 56.0;
+
+pub struct SeasonalDecorPlugin;
+
+impl Plugin for SeasonalDecorPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_systems(Update, (
+                attach_seasonal_sprites_system,
+                seasonal_sprite_swap_system,
+            ).chain().in_set(crate::GameSet::Presentation).run_if(in_state(crate::AppState::Playing)));
+    }
+}
+
+// Matches the season keys used in smart object definitions
+pub fn season_key(season: Season) -> &'static str {
+    match season {
+        Season::Spring => "Spring",
+        Season::Summer => "Summer",
+        Season::Fall => "Fall",
+        Season::Winter => "Winter",
+    }
+}
+
+// Components
+#[derive(Component)]
+pub struct SeasonalSprites {
+    // Image paths for the seasons that have a decorated look
+    pub variants: Vec<(Season, String)>,
+    pub default_image: Handle<Image>,
+    pub default_color: Color,
+    // Plain colour-block scenery drops its tint while wearing a decorated sprite
+    pub untinted: bool,
+    applied: Option<Season>,
+}
+
+impl SeasonalSprites {
+    pub fn new(variants: Vec<(Season, String)>, sprite: &Sprite, untinted: bool) -> Self {
+        Self {
+            variants,
+            default_image: sprite.image.clone(),
+            default_color: sprite.color,
+            untinted,
+            applied: None,
+        }
+    }
+
+    // Variants declared by a smart object definition, with images under the given folder
+    pub fn from_definition(definition: &SmartObjectDefinition, folder: &str, sprite: &Sprite) -> Option<Self> {
+        let variants: Vec<(Season, String)> = SEASONS.into_iter()
+            .filter_map(|season| {
+                let filename = definition.visual.seasonal_sprites.get(season_key(season))?;
+                Some((season, format!("{}/{}.png", folder, filename)))
+            })
+            .collect();
+
+        if variants.is_empty() {
+            None
+        } else {
+            Some(Self::new(variants, sprite, false))
+        }
+    }
+
+    fn variant(&self, season: Season) -> Option<&str> {
+        self.variants.iter()
+            .find(|(variant_season, _)| *variant_season == season)
+            .map(|(_, path)| path.as_str())
+    }
+}
+
+fn attach_seasonal_sprites_system(
+    mut commands: Commands,
+    registry: Res<SmartObjectRegistry>,
+    placed_query: Query<(Entity, &PlaceableObject, &Sprite), (Added<PlaceableObject>, Without<SeasonalSprites>)>,
+    scenery_query: Query<(Entity, &AestheticObject, &Sprite), (Added<AestheticObject>, Without<SeasonalSprites>)>,
+) {
+    for (entity, object, sprite) in &placed_query {
+        let Some(definition) = registry.get_definition(&object_filename(&object.item_type)) else { continue };
+        if let Some(seasonal) = SeasonalSprites::from_definition(definition, "objects", sprite) {
+            commands.entity(entity).insert(seasonal);
+        }
+    }
+
+    for (entity, object, sprite) in &scenery_query {
+        let variants: Vec<(Season, String)> = SEASONS.into_iter()
+            .filter_map(|season| {
+                let filename = object.object_type.seasonal_sprite(season)?;
+                Some((season, format!("objects/{}.png", filename)))
+            })
+            .collect();
+
+        if !variants.is_empty() {
+            commands.entity(entity).insert(SeasonalSprites::new(variants, sprite, true));
+        }
+    }
+}
+
+fn seasonal_sprite_swap_system(
+    mut season_events: EventReader<SeasonChangeEvent>,
+    mut load_events: EventReader<LoadCompleteEvent>,
+    mut decor_query: Query<(&mut SeasonalSprites, &mut Sprite)>,
+    time_state: Res<TimeState>,
+    asset_server: Res<AssetServer>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+) {
+    let season_turned = season_events.read().last().map(|event| event.new_season);
+    // A loaded sanctuary can be in a different season without one having turned
+    let loaded = load_events.read().any(|event| event.success);
+    let season = time_state.get_season();
+
+    let mut redecorated = 0;
+    for (mut seasonal, mut sprite) in &mut decor_query {
+        let due = seasonal.applied.is_none() || season_turned.is_some() || loaded;
+        if !due || seasonal.applied == Some(season) {
+            continue;
+        }
+
+        let had_variant = seasonal.applied.is_some_and(|applied| seasonal.variant(applied).is_some());
+        match seasonal.variant(season) {
+            Some(path) => {
+                sprite.image = asset_server.load(path.to_string());
+                if seasonal.untinted {
+                    sprite.color = Color::WHITE;
+                }
+                redecorated += 1;
+            }
+            None => {
+                sprite.image = seasonal.default_image.clone();
+                if seasonal.untinted {
+                    sprite.color = seasonal.default_color;
+                }
+                if had_variant {
+                    redecorated += 1;
+                }
+            }
+        }
+        seasonal.applied = Some(season);
+    }
+
+    if let Some(new_season) = season_turned {
+        if redecorated > 0 {
+            notification_events.write(ShowNotificationEvent {
+                notification: NotificationType::Info {
+                    message: format!("The garden has been dressed for {}", season_key(new_season).to_lowercase()),
+                },
+            });
+        }
+    }
+}
+
+// Catalog detail section showing every seasonal look an item can take
+pub fn spawn_seasonal_previews(
+    parent: &mut ChildSpawnerCommands,
+    registry: &SmartObjectRegistry,
+    asset_server: &AssetServer,
+    current_season: Season,
+) {
+    let Some(catalog) = &registry.catalog else { return };
+    let decorated: Vec<&SmartObjectDefinition> = catalog.items.iter()
+        .filter(|definition| !definition.visual.seasonal_sprites.is_empty())
+        .collect();
+    if decorated.is_empty() {
+        return;
+    }
+
+    parent.spawn((
+        Text::new("Seasonal Looks"),
+        TextFont { font_size: 18.0, ..default() },
+        TextColor(Color::srgb(0.3, 0.2, 0.1)),
+        Node {
+            margin: UiRect::new(Val::Px(5.0), Val::Px(0.0), Val::Px(15.0), Val::Px(5.0)),
+            ..default()
+        },
+    ));
+
+    for definition in decorated {
+        parent.spawn((
+            Node {
+                width: Val::Percent(100.0),
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(10.0),
+                padding: UiRect::all(Val::Px(8.0)),
+                margin: UiRect::vertical(Val::Px(4.0)),
+                border: UiRect::all(Val::Px(1.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.98, 0.96, 0.94)),
+            BorderColor(Color::srgb(0.7, 0.6, 0.5)),
+        )).with_children(|row| {
+            row.spawn((
+                Text::new(definition.metadata.name.clone()),
+                TextFont { font_size: 13.0, ..default() },
+                TextColor(Color::srgb(0.2, 0.1, 0.0)),
+                Node { width: Val::Px(140.0), ..default() },
+            ));
+
+            for season in SEASONS {
+                let current = season == current_season;
+                let decorated = definition.visual.seasonal_sprites.contains_key(season_key(season));
+
+                row.spawn(Node {
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    row_gap: Val::Px(2.0),
+                    ..default()
+                }).with_children(|cell| {
+                    cell.spawn((
+                        ImageNode::new(asset_server.load(format!("objects/{}.png", definition.sprite_for_season(season_key(season))))),
+                        Node {
+                            width: Val::Px(PREVIEW_SIZE),
+                            height: Val::Px(PREVIEW_SIZE),
+                            border: UiRect::all(Val::Px(2.0)),
+                            ..default()
+                        },
+                        BackgroundColor(Color::srgb(0.9, 0.9, 0.85)),
+                        BorderColor(if current { Color::srgb(0.85, 0.65, 0.2) } else { Color::srgb(0.7, 0.7, 0.6) }),
+                    ));
+                    cell.spawn((
+                        Text::new(if decorated { season_key(season).to_string() } else { format!("{} (plain)", season_key(season)) }),
+                        TextFont { font_size: 10.0, ..default() },
+                        TextColor(if current { Color::srgb(0.6, 0.4, 0.1) } else { Color::srgb(0.4, 0.3, 0.2) }),
+                    ));
+                });
+            }
+        });
+    }
+}
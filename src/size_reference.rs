@@ -0,0 +1,166 @@
+// Size Reference - A scale bar comparing the bird in view with birds everyone knows
+//
+// Size is one of the first things a birder judges, and "a bit bigger than a sparrow" is easier to
+// learn than a length in centimeters. When a bird is centered in the binoculars or the photo mode
+// viewfinder, a bar for it is drawn among sparrow, robin and crow bars just below it, its length
+// taken from the species' size category in the bird data. It's off by default and switched on
+// under Accessibility in Settings.
+use bevy::prelude::*;
+use crate::binoculars::Binoculars;
+use crate::bird::{Bird, BIRD_HALF_EXTENT};
+use crate::bird_data::BirdDataRegistry;
+use crate::journal::resources::{BirdEducationData, DiscoveredSpecies};
+use crate::menu::resources::GameSettings;
+use crate::photo_mode::components::PhotoTarget;
+use crate::photo_mode::resources::PhotoModeSettings;
+
+// Birds within this many screen pixels of the center count as centered, as for field marks
+const CENTER_RADIUS: f32 = 80.0;
+// Screen pixels between the bottom of the bird and the top of the overlay
+const GAP_PIXELS: f32 = 16.0;
+const PIXELS_PER_CM: f32 = 2.5;
+const PANEL_WIDTH: f32 = 300.0;
+// Fixed rather than looked up: the data puts sparrows and robins in the same size category
+const REFERENCES: [(&str, f32); 3] = [("Sparrow", 16.0), ("Robin", 25.0), ("Crow", 45.0)];
+const REFERENCE_COLOR: Color = Color::srgb(0.6, 0.6, 0.6);
+const TARGET_COLOR: Color = Color::srgb(1.0, 0.9, 0.55);
+
+pub struct SizeReferencePlugin;
+
+impl Plugin for SizeReferencePlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<SizeReference>()
+            // After the binoculars sway so the overlay sits under the bird this frame
+            .add_systems(Update, size_reference_system
+                .after(crate::binoculars::binoculars_view_system)
+                .in_set(crate::GameSet::Presentation)
+                .run_if(in_state(crate::AppState::Playing)));
+    }
+}
+
+// Resources
+#[derive(Resource, Default)]
+pub struct SizeReference {
+    bird: Option<Entity>,
+}
+
+// Components
+#[derive(Component)]
+pub struct SizeReferenceOverlay;
+
+#[allow(clippy::too_many_arguments)]
+pub fn size_reference_system(
+    mut commands: Commands,
+    mut size_reference: ResMut<SizeReference>,
+    settings: Res<GameSettings>,
+    binoculars: Res<Binoculars>,
+    photo_settings: Res<PhotoModeSettings>,
+    (bird_data, education_data, discovered): (Res<BirdDataRegistry>, Res<BirdEducationData>, Res<DiscoveredSpecies>),
+    camera_query: Query<(&Transform, &Projection), With<PhotoTarget>>,
+    bird_query: Query<(Entity, &Transform, &Bird)>,
+    window_query: Query<&Window, With<bevy::window::PrimaryWindow>>,
+    ui_scale: Res<UiScale>,
+    mut overlay_query: Query<(Entity, &mut Node), With<SizeReferenceOverlay>>,
+) {
+    let viewing = settings.size_reference_overlay && (binoculars.active || photo_settings.is_active);
+    let camera = camera_query.single().ok().and_then(|(transform, projection)| match projection {
+        Projection::Orthographic(ortho) => Some((transform.translation.truncate(), ortho.scale)),
+        _ => None,
+    });
+
+    let distance = |transform: &Transform, center: Vec2| transform.translation.truncate().distance(center);
+    let target = camera.filter(|_| viewing).and_then(|(center, scale)| {
+        let radius = CENTER_RADIUS * scale;
+        // Stick with the current bird while it stays in the circle, so two close birds don't trade places
+        size_reference.bird
+            .and_then(|bird| bird_query.get(bird).ok())
+            .filter(|(_, transform, _)| distance(transform, center) <= radius)
+            .or_else(|| bird_query.iter()
+                .filter(|(_, transform, _)| distance(transform, center) <= radius)
+                .min_by(|(_, a, _), (_, b, _)| distance(a, center).total_cmp(&distance(b, center))))
+    });
+
+    if target.map(|(entity, ..)| entity) != size_reference.bird {
+        size_reference.bird = target.map(|(entity, ..)| entity);
+        for (overlay, _) in &overlay_query {
+            commands.entity(overlay).despawn();
+        }
+        if let Some((_, _, bird)) = target {
+            let name = match education_data.species_facts.get(&bird.species) {
+                Some(facts) if discovered.0.contains(&bird.species) => facts.common_name.clone(),
+                _ if discovered.0.contains(&bird.species) => format!("{:?}", bird.species),
+                _ => "This bird".to_string(),
+            };
+            spawn_size_reference_overlay(&mut commands, &name, bird_data.get_length_cm(&bird.species));
+        }
+        // Placed from next frame, once the overlay exists
+        return;
+    }
+
+    let (Some((_, bird_transform, _)), Some((center, scale))) = (target, camera) else { return };
+    let Ok(window) = window_query.single() else { return };
+
+    // World to UI pixels; the camera is orthographic and never rotates
+    let bird_position = bird_transform.translation.truncate();
+    let half_extent = BIRD_HALF_EXTENT * bird_transform.scale.y.abs();
+    let window_size = Vec2::new(window.width(), window.height());
+    let screen = (window_size / 2.0 + Vec2::new(bird_position.x - center.x, center.y - bird_position.y) / scale) / ui_scale.0;
+    for (_, mut node) in &mut overlay_query {
+        node.left = Val::Px(screen.x - PANEL_WIDTH / 2.0);
+        node.top = Val::Px(screen.y + half_extent / scale / ui_scale.0 + GAP_PIXELS);
+    }
+}
+
+fn spawn_size_reference_overlay(commands: &mut Commands, name: &str, length_cm: f32) {
+    let mut bars: Vec<(String, f32, Color)> = REFERENCES.iter()
+        .map(|(label, length)| (label.to_string(), *length, REFERENCE_COLOR))
+        .collect();
+    bars.push((format!("{} (~{:.0} cm)", name, length_cm), length_cm, TARGET_COLOR));
+    bars.sort_by(|(_, a, _), (_, b, _)| a.total_cmp(b));
+
+    // Off screen until the size reference system places it next frame
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(-1000.0),
+            width: Val::Px(PANEL_WIDTH),
+            flex_direction: FlexDirection::Column,
+            row_gap: Val::Px(3.0),
+            padding: UiRect::all(Val::Px(6.0)),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.6)),
+        BorderRadius::all(Val::Px(4.0)),
+        // Above the binoculars lens mask
+        GlobalZIndex(21),
+        Pickable::IGNORE,
+        SizeReferenceOverlay,
+        StateScoped(crate::AppState::Playing),
+    )).with_children(|panel| {
+        for (label, length, color) in bars {
+            panel.spawn(Node {
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(6.0),
+                ..default()
+            }).with_children(|row| {
+                // Rounded at both ends so it reads as a body rather than a chart bar
+                row.spawn((
+                    Node {
+                        width: Val::Px(length * PIXELS_PER_CM),
+                        height: Val::Px(6.0),
+                        ..default()
+                    },
+                    BackgroundColor(color),
+                    BorderRadius::all(Val::Px(3.0)),
+                ));
+                row.spawn((
+                    Text::new(label),
+                    TextFont { font_size: 11.0, ..default() },
+                    TextColor(color),
+                ));
+            });
+        }
+    });
+}
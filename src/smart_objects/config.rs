@@ -47,6 +47,8 @@ pub struct VisualData {
     pub animation_set: Option<String>,
     pub particle_effects: Vec<String>,
     pub sound_effects: Vec<String>,
+    #[serde(default)]
+    pub seasonal_sprites: HashMap<String, String>, // season -> sprite filename, swapped in at season change
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -138,6 +140,13 @@ impl SmartObjectCatalog {
 }
 
 impl SmartObjectDefinition {
+    /// Sprite filename to show in a season, falling back to the regular sprite
+    pub fn sprite_for_season(&self, season: &str) -> &str {
+        self.visual.seasonal_sprites.get(season)
+            .map(|sprite| sprite.as_str())
+            .unwrap_or(&self.visual.sprite_filename)
+    }
+    
     /// Convert string action names to BirdAction enum values
     pub fn get_bird_actions(&self) -> Vec<BirdAction> {
         self.behavior.provides_actions.iter()
@@ -68,6 +68,7 @@ impl Default for CreationKitTemplates {
                 animation_set: None,
                 particle_effects: Vec::new(),
                 sound_effects: vec!["perch_land".to_string()],
+                seasonal_sprites: HashMap::new(),
             },
             physics: PhysicsData {
                 collision_shape: "Rectangle".to_string(),
@@ -136,6 +137,7 @@ impl Default for CreationKitTemplates {
                 animation_set: None,
                 particle_effects: Vec::new(),
                 sound_effects: vec!["seed_scatter".to_string()],
+                seasonal_sprites: HashMap::new(),
             },
             physics: PhysicsData {
                 collision_shape: "Rectangle".to_string(),
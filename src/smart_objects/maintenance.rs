@@ -0,0 +1,589 @@
+// Maintenance - Weather wear on garden objects, breakage and the repair panel
+use bevy::prelude::*;
+use bevy::audio::Volume;
+use std::collections::HashMap;
+use crate::bird_ai::components::{SmartObject, ProvidesUtility};
+use crate::catalog::components::PlaceableObject;
+use crate::catalog::resources::PlayerInventory;
+use crate::catalog::systems::object_filename;
+use crate::environment::components::Weather;
+use crate::environment::resources::WeatherState;
+use crate::audio::captions::{CaptionCategory, SoundCaption};
+use crate::audio::resources::AudioSettings;
+use crate::audio::components::{PositionalAudioSource, AudioVolumeCurve};
+use crate::feeder::Feeder;
+use crate::gear_care::GearCare;
+use crate::hummingbirds::NectarReservoir;
+use crate::notifications::{resources::ShowNotificationEvent, components::NotificationType};
+use crate::smart_objects::SmartObjectRegistry;
+use crate::smart_objects::config::ConfigurableSmartObject;
+use crate::tooltip::Hoverable;
+
+// Wear for placed objects the smart object catalog has no definition for
+const FALLBACK_DECAY_RATE: f32 = 0.03;
+const FALLBACK_WEATHER_RESISTANCE: f32 = 0.5;
+const FALLBACK_REPAIR_MULTIPLIER: f32 = 0.4;
+// Colour a fully worn object fades towards, and how far it gets
+const WEATHERED_TINT: Color = Color::srgb(0.7, 0.65, 0.55);
+const MAX_FADE: f32 = 0.6;
+const BROKEN_LEAN: f32 = 0.12;
+const CREAK_AUDIO_RANGE: f32 = 400.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WearState {
+    Good,
+    Worn,
+    Damaged,
+    Broken,
+}
+
+impl WearState {
+    // Condition is durability as a fraction of what the object had when new
+    pub fn from_condition(condition: f32) -> Self {
+        if condition >= 0.6 {
+            Self::Good
+        } else if condition >= 0.3 {
+            Self::Worn
+        } else if condition > 0.1 {
+            Self::Damaged
+        } else {
+            Self::Broken
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Good => "Good",
+            Self::Worn => "Worn",
+            Self::Damaged => "Damaged",
+            Self::Broken => "Broken",
+        }
+    }
+
+    pub fn utility_multiplier(&self) -> f32 {
+        match self {
+            Self::Good => 1.0,
+            Self::Worn => 0.85,
+            Self::Damaged => 0.55,
+            Self::Broken => 0.3,
+        }
+    }
+
+    fn color(&self) -> Color {
+        match self {
+            Self::Good => Color::srgb(0.3, 0.5, 0.3),
+            Self::Worn => Color::srgb(0.6, 0.5, 0.2),
+            Self::Damaged => Color::srgb(0.7, 0.4, 0.2),
+            Self::Broken => Color::srgb(0.7, 0.3, 0.2),
+        }
+    }
+}
+
+// How hard the current weather is on exposed objects compared to a dry day
+pub fn weather_exposure(weather: &Weather) -> f32 {
+    match weather {
+        Weather::Clear | Weather::Cloudy => 1.0,
+        Weather::Foggy => 1.3,
+        Weather::Windy => 1.8,
+        Weather::Rainy => 2.5,
+        Weather::Snowy => 3.0,
+    }
+}
+
+pub struct WearProfile {
+    pub max_durability: f32,
+    pub decay_rate: f32,
+    pub weather_resistance: f32,
+    pub repair_cost_multiplier: f32,
+}
+
+pub fn wear_profile(registry: &SmartObjectRegistry, definition_id: &str) -> WearProfile {
+    match registry.get_definition(definition_id) {
+        Some(definition) => WearProfile {
+            max_durability: definition.economy.durability.max(0.01),
+            decay_rate: definition.economy.decay_rate,
+            weather_resistance: definition.behavior.weather_resistance.clamp(0.0, 1.0),
+            repair_cost_multiplier: definition.economy.repair_cost_multiplier,
+        },
+        None => WearProfile {
+            max_durability: 1.0,
+            decay_rate: FALLBACK_DECAY_RATE,
+            weather_resistance: FALLBACK_WEATHER_RESISTANCE,
+            repair_cost_multiplier: FALLBACK_REPAIR_MULTIPLIER,
+        },
+    }
+}
+
+pub fn repair_cost(weathering: &Weathering, profile: &WearProfile, condition: f32) -> u32 {
+    let cost = weathering.purchase_cost as f32 * profile.repair_cost_multiplier * (1.0 - condition);
+    (cost.round() as u32).max(1)
+}
+
+// Resources
+#[derive(Resource)]
+pub struct MaintenanceLog {
+    pub repairs_made: u32,
+    pub panel_dirty: bool,
+    refresh_timer: Timer,
+}
+
+impl Default for MaintenanceLog {
+    fn default() -> Self {
+        Self {
+            repairs_made: 0,
+            panel_dirty: true,
+            refresh_timer: Timer::from_seconds(2.0, TimerMode::Repeating),
+        }
+    }
+}
+
+// Components
+#[derive(Component)]
+pub struct Weathering {
+    pub base_color: Color,
+    pub state: WearState,
+    pub purchase_cost: u32,
+    applied_condition: f32,
+    creak_timer: Timer,
+}
+
+impl Weathering {
+    pub fn new(base_color: Color, purchase_cost: u32) -> Self {
+        Self {
+            base_color,
+            state: WearState::Good,
+            purchase_cost,
+            applied_condition: 1.0,
+            creak_timer: Timer::from_seconds(8.0, TimerMode::Once),
+        }
+    }
+}
+
+// Durability read back from a save, applied once the object is registered
+#[derive(Component)]
+pub struct RestoredDurability(pub f32);
+
+#[derive(Component)]
+pub struct MaintenancePanel;
+
+#[derive(Component)]
+pub struct MaintenancePanelList;
+
+#[derive(Component)]
+pub struct MaintenancePanelRow;
+
+#[derive(Component)]
+pub struct RepairButton {
+    // None repairs everything that needs it
+    pub target: Option<Entity>,
+}
+
+// Events
+#[derive(Event)]
+pub struct WearStateChangedEvent {
+    pub entity: Entity,
+    pub previous: WearState,
+    pub state: WearState,
+}
+
+// Catalog-placed objects wear like any other smart object once they are in the registry
+pub fn register_placed_objects_system(
+    mut commands: Commands,
+    mut registry: ResMut<SmartObjectRegistry>,
+    object_query: Query<(Entity, &PlaceableObject, &Sprite, Option<&RestoredDurability>), Added<PlaceableObject>>,
+    mut removed_objects: RemovedComponents<PlaceableObject>,
+    mut wear_events: EventWriter<WearStateChangedEvent>,
+    time: Res<Time>,
+) {
+    for entity in removed_objects.read() {
+        registry.active_objects.remove(&entity);
+    }
+
+    for (entity, object, sprite, restored) in &object_query {
+        let definition_id = object_filename(&object.item_type);
+        let profile = wear_profile(&registry, &definition_id);
+        let purchase_cost = registry.get_definition(&definition_id)
+            .map_or(object.placement_cost, |definition| definition.economy.purchase_cost);
+        let durability = restored.map_or(profile.max_durability, |restored| restored.0.clamp(0.0, profile.max_durability));
+
+        commands.entity(entity).insert(Weathering::new(sprite.color, purchase_cost)).remove::<RestoredDurability>();
+
+        // Objects saved worn come back worn, consequences included
+        let state = WearState::from_condition(durability / profile.max_durability);
+        if state != WearState::Good {
+            wear_events.write(WearStateChangedEvent { entity, previous: WearState::Good, state });
+        }
+
+        registry.active_objects.insert(entity, ConfigurableSmartObject {
+            definition_id,
+            current_durability: durability,
+            last_maintenance: time.elapsed_secs_f64(),
+            current_users: Vec::new(),
+            total_usage_time: 0.0,
+            custom_modifications: HashMap::new(),
+        });
+    }
+}
+
+// Sprites fade towards a sun-bleached grey as their condition drops
+pub fn weathering_tint_system(
+    registry: Res<SmartObjectRegistry>,
+    mut object_query: Query<(Entity, &mut Weathering, &mut Sprite), (Without<NectarReservoir>, Without<Feeder>)>,
+) {
+    for (entity, mut weathering, mut sprite) in &mut object_query {
+        let Some(object) = registry.active_objects.get(&entity) else { continue };
+        let profile = wear_profile(&registry, &object.definition_id);
+        let condition = (object.current_durability / profile.max_durability).clamp(0.0, 1.0);
+
+        if (condition - weathering.applied_condition).abs() < 0.01 {
+            continue;
+        }
+        weathering.applied_condition = condition;
+        sprite.color = weathering.base_color.mix(&WEATHERED_TINT, (1.0 - condition) * MAX_FADE);
+    }
+}
+
+pub fn wear_consequence_system(
+    mut commands: Commands,
+    mut wear_events: EventReader<WearStateChangedEvent>,
+    mut object_query: Query<(&mut Weathering, &mut Transform, Option<&PlaceableObject>, Option<&mut ProvidesUtility>)>,
+    camera_query: Query<&Transform, (With<Camera2d>, Without<Weathering>)>,
+    asset_server: Res<AssetServer>,
+    audio_settings: Res<AudioSettings>,
+    mut log: ResMut<MaintenanceLog>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+) {
+    let listener = camera_query.single().map(|t| t.translation.truncate()).ok();
+
+    for event in wear_events.read() {
+        let Ok((mut weathering, mut transform, placed, utility)) = object_query.get_mut(event.entity) else { continue };
+        let previous = weathering.state;
+        weathering.state = event.state;
+        log.panel_dirty = true;
+
+        // Scaled rather than set so feeders and trays keep whatever else they track
+        if let Some(mut utility) = utility {
+            utility.base_utility *= event.state.utility_multiplier() / previous.utility_multiplier();
+        }
+
+        let name = placed.map_or("Garden object".to_string(), |placed| placed.item_type.name().to_string());
+        match event.state {
+            WearState::Broken => {
+                commands.entity(event.entity).remove::<SmartObject>();
+                transform.rotation = Quat::from_rotation_z(BROKEN_LEAN);
+                notification_events.write(ShowNotificationEvent {
+                    notification: NotificationType::Warning {
+                        message: format!("{} has broken and birds are avoiding it", name),
+                    },
+                });
+            }
+            WearState::Damaged if previous != WearState::Broken => {
+                notification_events.write(ShowNotificationEvent {
+                    notification: NotificationType::Warning {
+                        message: format!("{} is weather-damaged and needs repair", name),
+                    },
+                });
+            }
+            _ => {}
+        }
+        if previous == WearState::Broken && event.state != WearState::Broken {
+            commands.entity(event.entity).insert(SmartObject);
+            transform.rotation = Quat::IDENTITY;
+        }
+
+        // A groan as it gives way
+        if matches!(event.state, WearState::Damaged | WearState::Broken) && event.state != previous {
+            spawn_creak(&mut commands, &asset_server, &audio_settings, listener, event.entity, transform.translation);
+        }
+    }
+}
+
+// Damaged objects creak now and then when the wind and rain get at them
+pub fn weathered_creak_system(
+    mut commands: Commands,
+    mut object_query: Query<(Entity, &mut Weathering, &Transform)>,
+    camera_query: Query<&Transform, (With<Camera2d>, Without<Weathering>)>,
+    weather_state: Res<WeatherState>,
+    asset_server: Res<AssetServer>,
+    audio_settings: Res<AudioSettings>,
+    time: Res<Time>,
+) {
+    if !matches!(weather_state.current_weather, Weather::Windy | Weather::Rainy) {
+        return;
+    }
+    let listener = camera_query.single().map(|t| t.translation.truncate()).ok();
+
+    for (entity, mut weathering, transform) in &mut object_query {
+        if !matches!(weathering.state, WearState::Damaged | WearState::Broken) {
+            continue;
+        }
+
+        weathering.creak_timer.tick(time.delta());
+        if weathering.creak_timer.finished() {
+            weathering.creak_timer = Timer::from_seconds(6.0 + rand::random::<f32>() * 8.0, TimerMode::Once);
+            spawn_creak(&mut commands, &asset_server, &audio_settings, listener, entity, transform.translation);
+        }
+    }
+}
+
+fn spawn_creak(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    audio_settings: &AudioSettings,
+    listener: Option<Vec2>,
+    source: Entity,
+    position: Vec3,
+) {
+    let distance = listener.map_or(0.0, |listener| listener.distance(position.truncate()));
+    if distance >= CREAK_AUDIO_RANGE {
+        return;
+    }
+
+    let gain = (1.0 - distance / CREAK_AUDIO_RANGE).powi(2) * audio_settings.volume * 0.6;
+    commands.spawn((
+        AudioPlayer::new(asset_server.load("audio/wood_creak.ogg")),
+        PlaybackSettings::DESPAWN
+            .with_spatial(true)
+            .with_volume(Volume::Linear(gain)),
+        Transform::from_translation(position),
+        PositionalAudioSource {
+            source_entity: source,
+            max_distance: CREAK_AUDIO_RANGE,
+            volume_curve: AudioVolumeCurve::InverseSquare,
+        },
+        SoundCaption::sound(CaptionCategory::Yard, "Wood creaking"),
+    ));
+}
+
+pub fn setup_maintenance_panel(mut commands: Commands, mut log: ResMut<MaintenanceLog>) {
+    log.panel_dirty = true;
+
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(530.0),
+            bottom: Val::Px(20.0),
+            width: Val::Px(240.0),
+            flex_direction: FlexDirection::Column,
+            padding: UiRect::all(Val::Px(8.0)),
+            row_gap: Val::Px(4.0),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.95, 0.92, 0.88, 0.9)),
+        BorderRadius::all(Val::Px(6.0)),
+        Visibility::Hidden,
+        MaintenancePanel,
+        Name::new("MaintenancePanel"),
+    )).with_children(|panel| {
+        panel.spawn((
+            Text::new("Maintenance"),
+            TextFont { font_size: 13.0, ..default() },
+            TextColor(Color::srgb(0.3, 0.2, 0.1)),
+        ));
+        panel.spawn((
+            Node {
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(4.0),
+                ..default()
+            },
+            MaintenancePanelList,
+        ));
+    });
+}
+
+pub fn cleanup_maintenance_panel(
+    mut commands: Commands,
+    panel_query: Query<Entity, With<MaintenancePanel>>,
+) {
+    for entity in &panel_query {
+        commands.entity(entity).despawn();
+    }
+}
+
+pub fn repair_button_system(
+    mut button_query: Query<(&Interaction, &RepairButton, &mut BackgroundColor), Changed<Interaction>>,
+    weathering_query: Query<(Entity, &Weathering)>,
+    mut registry: ResMut<SmartObjectRegistry>,
+    mut inventory: ResMut<PlayerInventory>,
+    mut log: ResMut<MaintenanceLog>,
+    gear: Res<GearCare>,
+    mut wear_events: EventWriter<WearStateChangedEvent>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+    time: Res<Time>,
+) {
+    for (interaction, button, mut color) in &mut button_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *color = BackgroundColor(Color::srgb(0.5, 0.7, 0.5));
+
+                // Work out the bill before touching anything so Repair All is all or nothing
+                let mut jobs = Vec::new();
+                for (entity, weathering) in &weathering_query {
+                    if button.target.is_some_and(|target| target != entity) || weathering.state == WearState::Good {
+                        continue;
+                    }
+                    let Some(object) = registry.active_objects.get(&entity) else { continue };
+                    let profile = wear_profile(&registry, &object.definition_id);
+                    let condition = object.current_durability / profile.max_durability;
+                    jobs.push((entity, weathering.state, profile.max_durability, repair_cost(weathering, &profile, condition)));
+                }
+                if jobs.is_empty() {
+                    continue;
+                }
+
+                // Insurance pays its share of the whole bill
+                let total = gear.bill(jobs.iter().map(|job| job.3).sum());
+                if inventory.currency < total {
+                    notification_events.write(ShowNotificationEvent {
+                        notification: NotificationType::Warning {
+                            message: format!("Repairs cost {} coins", total),
+                        },
+                    });
+                    continue;
+                }
+                inventory.currency -= total;
+
+                for (entity, previous, max_durability, _) in &jobs {
+                    if let Some(object) = registry.active_objects.get_mut(entity) {
+                        object.current_durability = *max_durability;
+                        object.last_maintenance = time.elapsed_secs_f64();
+                    }
+                    wear_events.write(WearStateChangedEvent {
+                        entity: *entity,
+                        previous: *previous,
+                        state: WearState::Good,
+                    });
+                }
+                log.repairs_made += jobs.len() as u32;
+                log.panel_dirty = true;
+                notification_events.write(ShowNotificationEvent {
+                    notification: NotificationType::Info {
+                        message: format!("Repaired {} object{} for {} coins", jobs.len(), if jobs.len() == 1 { "" } else { "s" }, total),
+                    },
+                });
+            }
+            Interaction::Hovered => *color = BackgroundColor(Color::srgb(0.7, 0.6, 0.5)),
+            Interaction::None => *color = BackgroundColor(Color::srgb(0.6, 0.5, 0.4)),
+        }
+    }
+}
+
+pub fn refresh_maintenance_panel_system(
+    mut commands: Commands,
+    mut log: ResMut<MaintenanceLog>,
+    registry: Res<SmartObjectRegistry>,
+    weathering_query: Query<(Entity, &Weathering, Option<&PlaceableObject>)>,
+    mut panel_query: Query<&mut Visibility, With<MaintenancePanel>>,
+    list_query: Query<Entity, With<MaintenancePanelList>>,
+    row_query: Query<Entity, With<MaintenancePanelRow>>,
+    gear: Res<GearCare>,
+    time: Res<Time>,
+) {
+    log.refresh_timer.tick(time.delta());
+    if !log.panel_dirty && !log.refresh_timer.just_finished() {
+        return;
+    }
+    log.panel_dirty = false;
+
+    // Worst first, and only what actually needs attention
+    let mut needing_repair: Vec<_> = weathering_query.iter()
+        .filter(|(_, weathering, _)| weathering.state != WearState::Good)
+        .filter_map(|(entity, weathering, placed)| {
+            let object = registry.active_objects.get(&entity)?;
+            let profile = wear_profile(&registry, &object.definition_id);
+            let condition = (object.current_durability / profile.max_durability).clamp(0.0, 1.0);
+            Some((entity, weathering, placed, profile, condition))
+        })
+        .collect();
+    needing_repair.sort_by(|a, b| a.4.total_cmp(&b.4));
+
+    for mut visibility in &mut panel_query {
+        *visibility = if needing_repair.is_empty() { Visibility::Hidden } else { Visibility::Inherited };
+    }
+    for row in &row_query {
+        commands.entity(row).despawn();
+    }
+    let Ok(list) = list_query.single() else { return };
+
+    let total = gear.bill(needing_repair.iter()
+        .map(|(_, weathering, _, profile, condition)| repair_cost(weathering, profile, *condition))
+        .sum());
+
+    commands.entity(list).with_children(|list| {
+        for (entity, weathering, placed, profile, condition) in &needing_repair {
+            let name = placed.map_or("Garden object", |placed| placed.item_type.name());
+            let cost = gear.bill(repair_cost(weathering, profile, *condition));
+            let tooltip = format!(
+                "{}\nCondition: {:.0}% ({})\nWeather resistance: {:.0}%\nRepair: {} coins",
+                name, condition * 100.0, weathering.state.name(), profile.weather_resistance * 100.0, cost,
+            );
+
+            list.spawn((
+                Node {
+                    width: Val::Percent(100.0),
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(6.0),
+                    ..default()
+                },
+                MaintenancePanelRow,
+            )).with_children(|row| {
+                row.spawn((
+                    Text::new(name),
+                    TextFont { font_size: 11.0, ..default() },
+                    TextColor(Color::srgb(0.3, 0.2, 0.1)),
+                ));
+                row.spawn((
+                    Text::new(format!("{:.0}%", condition * 100.0)),
+                    TextFont { font_size: 11.0, ..default() },
+                    TextColor(weathering.state.color()),
+                    Node { flex_grow: 1.0, ..default() },
+                ));
+                row.spawn((
+                    Button,
+                    Node {
+                        padding: UiRect::axes(Val::Px(6.0), Val::Px(2.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.6, 0.5, 0.4)),
+                    BorderRadius::all(Val::Px(3.0)),
+                    RepairButton { target: Some(*entity) },
+                    Hoverable::new(&tooltip),
+                )).with_children(|button| {
+                    button.spawn((
+                        Text::new(format!("Repair ({})", cost)),
+                        TextFont { font_size: 10.0, ..default() },
+                        TextColor(Color::WHITE),
+                    ));
+                });
+            });
+        }
+
+        if needing_repair.len() > 1 {
+            list.spawn((
+                Node {
+                    width: Val::Percent(100.0),
+                    justify_content: JustifyContent::FlexEnd,
+                    ..default()
+                },
+                MaintenancePanelRow,
+            )).with_children(|row| {
+                row.spawn((
+                    Button,
+                    Node {
+                        padding: UiRect::axes(Val::Px(8.0), Val::Px(3.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.6, 0.5, 0.4)),
+                    BorderRadius::all(Val::Px(3.0)),
+                    RepairButton { target: None },
+                )).with_children(|button| {
+                    button.spawn((
+                        Text::new(format!("Repair All ({})", total)),
+                        TextFont { font_size: 10.0, ..default() },
+                        TextColor(Color::WHITE),
+                    ));
+                });
+            });
+        }
+    });
+}
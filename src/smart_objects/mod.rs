@@ -5,11 +5,13 @@ pub mod config;
 pub mod systems;
 pub mod workshop;
 pub mod creation_kit;
+pub mod maintenance;
 
 use config::*;
 use systems::*;
 use creation_kit::*;
 use workshop::*;
+use maintenance::*;
 
 #[derive(Default)]
 pub struct SmartObjectCatalogAssetLoader;
@@ -66,6 +68,7 @@ impl Plugin for ConfigurableSmartObjectsPlugin {
             .init_resource::<CreationKitTemplates>()
             .init_resource::<SteamWorkshopClient>()
             .init_resource::<WorkshopBrowserState>()
+            .init_resource::<MaintenanceLog>()
             .add_event::<SpawnSmartObjectEvent>()
             .add_event::<RemoveSmartObjectEvent>()
             .add_event::<ModifySmartObjectEvent>()
@@ -77,6 +80,7 @@ impl Plugin for ConfigurableSmartObjectsPlugin {
             .add_event::<UpdatePublishedItemEvent>()
             .add_event::<RateItemEvent>()
             .add_event::<ReportItemEvent>()
+            .add_event::<WearStateChangedEvent>()
             .add_systems(Startup, (
                 setup_smart_object_registry,
                 load_catalog_from_config,
@@ -87,14 +91,24 @@ impl Plugin for ConfigurableSmartObjectsPlugin {
                 handle_spawn_smart_object_events,
                 handle_remove_smart_object_events,
                 handle_modify_smart_object_events,
-                update_smart_object_durability,
                 process_smart_object_interactions,
                 handle_workshop_events,
                 process_workshop_downloads,
                 process_workshop_uploads,
                 handle_workshop_subscriptions,
                 update_workshop_browser,
-            ).run_if(in_state(crate::AppState::Playing)));
+            ).in_set(crate::GameSet::Simulation).run_if(in_state(crate::AppState::Playing)))
+            .add_systems(OnEnter(crate::AppState::Playing), setup_maintenance_panel)
+            .add_systems(OnExit(crate::AppState::Playing), cleanup_maintenance_panel)
+            .add_systems(Update, (
+                register_placed_objects_system,
+                update_smart_object_durability,
+                wear_consequence_system,
+                weathering_tint_system,
+                weathered_creak_system,
+                repair_button_system,
+                refresh_maintenance_panel_system,
+            ).chain().in_set(crate::GameSet::Simulation).run_if(in_state(crate::AppState::Playing)));
     }
 }
 
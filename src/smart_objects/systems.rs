@@ -3,9 +3,11 @@ use bevy_rapier2d::prelude::*;
 use crate::bird_ai::components::{SmartObject, ProvidesUtility};
 use crate::smart_objects::config::*;
 use crate::smart_objects::*;
+use crate::smart_objects::maintenance::{WearState, WearStateChangedEvent, Weathering, wear_profile};
+use crate::catalog::components::PlaceableObject;
+use crate::seasonal_decor::SeasonalSprites;
 use crate::environment::resources::{TimeState, WeatherState};
 use std::collections::HashMap;
-use crate::notifications::{resources::ShowNotificationEvent, components::NotificationType};
 use crate::catalog::resources::PlayerInventory;
 use crate::garden_lighting::{spawn_solar_light, spawn_garden_lamp};
 
@@ -139,42 +141,44 @@ pub fn handle_modify_smart_object_events(
 pub fn update_smart_object_durability(
     mut registry: ResMut<SmartObjectRegistry>,
     time: Res<Time>,
-    mut notification_events: EventWriter<ShowNotificationEvent>,
+    time_state: Res<TimeState>,
+    weather_state: Res<WeatherState>,
+    storm_manager: Res<crate::advanced_weather::StormManager>,
+    gear: Res<crate::gear_care::GearCare>,
+    placed_query: Query<&PlaceableObject>,
+    mut wear_events: EventWriter<WearStateChangedEvent>,
 ) {
-    let current_time = time.elapsed_secs_f64();
-    let delta_time = time.delta_secs();
+    // Decay rates are per game day, so fast-forwarding weathers objects faster too
+    let game_days = time.delta_secs() / (24.0 * time_state.time_speed);
+    // Storms are harder on everything than the weather alone
+    let exposure = 1.0 + crate::gear_care::storm_exposure(&weather_state, &storm_manager);
 
     // 1) Collect the entities up front (no &mut held across the loop)
     let entities: Vec<_> = registry.active_objects.keys().cloned().collect();
 
     for entity in entities {
         // 2) Read what you need immutably (no &mut borrow yet)
-        let (def_id, last_maintenance) = match registry.active_objects.get(&entity) {
-            Some(obj) => (obj.definition_id.clone(), obj.last_maintenance),
-            None => continue,
-        };
-
-        let definition = match registry.get_definition(&def_id).cloned() {
-            Some(d) => d,
+        let mut profile = match registry.active_objects.get(&entity) {
+            Some(obj) => wear_profile(&registry, &obj.definition_id),
             None => continue,
         };
+        // Weather-sealed feeders and tripods, see gear_care.rs
+        if let Some(resistance) = placed_query.get(entity).ok().and_then(|placed| gear.sealed_resistance(&placed.item_type)) {
+            profile.weather_resistance = profile.weather_resistance.max(resistance);
+        }
 
         // 3) Now take the mutable borrow and write updates
         if let Some(obj) = registry.active_objects.get_mut(&entity) {
-            let time_since_last_update = current_time - last_maintenance;
-            let decay_amount = definition.economy.decay_rate * delta_time / 86400.0;
+            // Sheltered objects shrug off most of the extra wear from bad weather
+            let weathering = 1.0 + (exposure - 1.0) * (1.0 - profile.weather_resistance);
+            let decay_amount = profile.decay_rate * weathering * game_days;
 
+            let previous = WearState::from_condition(obj.current_durability / profile.max_durability);
             obj.current_durability = (obj.current_durability - decay_amount).max(0.0);
+            let state = WearState::from_condition(obj.current_durability / profile.max_durability);
 
-            let days_since_maintenance = time_since_last_update / 86400.0;
-            if days_since_maintenance >= definition.economy.maintenance_cost as f64
-                && obj.current_durability < 0.3
-            {
-                notification_events.write(ShowNotificationEvent {
-                    notification: NotificationType::Warning {
-                        message: format!("{} requires maintenance, its durability is now at {:.0}%", obj.definition_id, obj.current_durability * 100.0),
-                    },
-                });                
+            if state != previous {
+                wear_events.write(WearStateChangedEvent { entity, previous, state });
             }
         }
     }
@@ -182,7 +186,8 @@ pub fn update_smart_object_durability(
 
 pub fn process_smart_object_interactions(
     mut registry: ResMut<SmartObjectRegistry>,
-    mut smart_object_query: Query<(Entity, &mut ProvidesUtility), With<SmartObject>>,
+    // Catalog-placed objects keep their own utility, only wear scales it
+    mut smart_object_query: Query<(Entity, &mut ProvidesUtility), (With<SmartObject>, Without<PlaceableObject>)>,
     time_state: Res<TimeState>,
     weather_state: Res<WeatherState>,
 ) {
@@ -208,8 +213,9 @@ pub fn process_smart_object_interactions(
                 utility_provider.range = definition.behavior.interaction_range;
                 
                 // Update durability effect on utility
-                let durability_modifier = configurable_object.current_durability.max(0.1);
-                utility_provider.base_utility *= durability_modifier;
+                let profile = wear_profile(&registry, &configurable_object.definition_id);
+                let wear_state = WearState::from_condition(configurable_object.current_durability / profile.max_durability);
+                utility_provider.base_utility *= wear_state.utility_multiplier();
             }
         }
     }
@@ -291,10 +297,16 @@ fn spawn_smart_object_from_definition(
         GlobalTransform::default(),
         Visibility::default(),
         SmartObject,
+        Weathering::new(sprite.color, definition.economy.purchase_cost),
         collider,
     ))
     .id();
     
+    // Seasonal looks declared by the definition
+    if let Some(seasonal) = SeasonalSprites::from_definition(definition, "sprites/objects", &sprite) {
+        commands.entity(entity).insert(seasonal);
+    }
+    
     // Add ProvidesUtility components for each action the object provides
     let bird_actions = definition.get_bird_actions();
     for action in bird_actions {
@@ -29,7 +29,7 @@ impl Plugin for SocialFeaturesPlugin {
                 badge_notification_system,
                 challenge_ui_update_system,
                 daily_challenge_refresh_system,
-            ).run_if(in_state(AppState::Playing)));
+            ).in_set(crate::GameSet::Simulation).run_if(in_state(AppState::Playing)));
     }
 }
 
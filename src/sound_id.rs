@@ -0,0 +1,261 @@
+// Sound ID - Pocket gadget that names the birds it hears, slowly and only a few times a day
+//
+// While listening, every bird call playing within earshot counts toward an identification of its
+// species. The name fills in letter by letter as more of the bird is heard and is only given in
+// full once it has called for long enough. Each listening session uses one of a few daily charges,
+// so it helps with a tricky call rather than replacing learning them by ear.
+use bevy::prelude::*;
+use bevy::audio::{AudioSinkPlayback, SpatialAudioSink};
+use crate::audio::components::PositionalAudioSource;
+use crate::bird::{Bird, BirdSpecies};
+use crate::environment::resources::TimeState;
+use crate::journal::resources::BirdEducationData;
+use crate::keybindings::{GameAction, KeyBindings};
+use crate::notifications::{resources::ShowNotificationEvent, components::NotificationType};
+use crate::photo_mode::components::PhotoTarget;
+
+const DAILY_CHARGES: u32 = 3;
+const SESSION_SECONDS: f32 = 90.0;
+// Seconds of a species calling before its name is given in full
+const REVEAL_SECONDS: f32 = 6.0;
+const MAX_LISTED: usize = 5;
+
+pub struct SoundIdPlugin;
+
+impl Plugin for SoundIdPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<SoundId>()
+            .add_systems(Update, toggle_sound_id_system
+                .run_if(crate::debug_console::console_is_not_visible)
+                .in_set(crate::GameSet::Input)
+                .run_if(in_state(crate::AppState::Playing)))
+            .add_systems(Update, (sound_id_listen_system, refresh_sound_id_panel_system)
+                .chain()
+                .in_set(crate::GameSet::UI)
+                .run_if(in_state(crate::AppState::Playing)))
+            .add_systems(OnExit(crate::AppState::Playing), close_sound_id_system);
+    }
+}
+
+// Resources
+#[derive(Resource)]
+pub struct SoundId {
+    pub listening: bool,
+    pub charges: u32,
+    // Absolute day the charges were last refilled
+    charge_day: u32,
+    session_left: f32,
+    // Seconds each species has been heard this session, in the order they were first heard
+    heard: Vec<(BirdSpecies, f32)>,
+}
+
+impl Default for SoundId {
+    fn default() -> Self {
+        Self {
+            listening: false,
+            charges: DAILY_CHARGES,
+            charge_day: 0,
+            session_left: 0.0,
+            heard: Vec::new(),
+        }
+    }
+}
+
+impl SoundId {
+    fn refill_charges(&mut self, time_state: &TimeState) {
+        let today = time_state.absolute_day();
+        if self.charge_day != today {
+            self.charge_day = today;
+            self.charges = DAILY_CHARGES;
+        }
+    }
+}
+
+// Components
+#[derive(Component)]
+pub struct SoundIdPanel;
+
+#[derive(Component)]
+pub struct SoundIdText;
+
+/// The name with letters hidden until enough of the bird has been heard, revealed from the start
+pub fn partial_name(name: &str, progress: f32) -> String {
+    let letters = name.chars().filter(|c| c.is_alphabetic()).count();
+    let mut shown = (letters as f32 * progress.clamp(0.0, 1.0)).floor() as usize;
+    name.chars()
+        .map(|c| {
+            if !c.is_alphabetic() {
+                c
+            } else if shown > 0 {
+                shown -= 1;
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn toggle_sound_id_system(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    key_bindings: Res<KeyBindings>,
+    time_state: Res<TimeState>,
+    mut sound_id: ResMut<SoundId>,
+    panel_query: Query<Entity, With<SoundIdPanel>>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+) {
+    if !key_bindings.is_action_just_pressed(GameAction::ToggleSoundId, &keyboard, &mouse) {
+        return;
+    }
+
+    // Stopping early does not give the charge back
+    if sound_id.listening {
+        sound_id.listening = false;
+        for panel in &panel_query {
+            commands.entity(panel).despawn();
+        }
+        return;
+    }
+
+    sound_id.refill_charges(&time_state);
+    if sound_id.charges == 0 {
+        notification_events.write(ShowNotificationEvent {
+            notification: NotificationType::Info {
+                message: "Sound ID is out of charges until tomorrow".to_string(),
+            },
+        });
+        return;
+    }
+
+    sound_id.charges -= 1;
+    sound_id.listening = true;
+    sound_id.session_left = SESSION_SECONDS;
+    sound_id.heard.clear();
+    spawn_sound_id_panel(&mut commands);
+    info!("🎧 Sound ID listening, {} charges left today", sound_id.charges);
+}
+
+pub fn sound_id_listen_system(
+    mut commands: Commands,
+    mut sound_id: ResMut<SoundId>,
+    camera_query: Query<&Transform, With<PhotoTarget>>,
+    call_query: Query<(&PositionalAudioSource, &Transform, &SpatialAudioSink)>,
+    bird_query: Query<&Bird>,
+    panel_query: Query<Entity, With<SoundIdPanel>>,
+    time: Res<Time>,
+) {
+    if !sound_id.listening {
+        return;
+    }
+
+    let dt = time.delta_secs();
+    sound_id.session_left -= dt;
+    if sound_id.session_left <= 0.0 {
+        sound_id.listening = false;
+        for panel in &panel_query {
+            commands.entity(panel).despawn();
+        }
+        return;
+    }
+
+    let Ok(camera_transform) = camera_query.single() else { return };
+    let listener = camera_transform.translation.truncate();
+
+    // Feeder clatter and drumming play the same way, so only sounds coming from a bird count
+    for (source, transform, sink) in &call_query {
+        if sink.empty() || sink.is_paused() {
+            continue;
+        }
+        if transform.translation.truncate().distance(listener) > source.max_distance {
+            continue;
+        }
+        let Ok(bird) = bird_query.get(source.source_entity) else { continue };
+
+        match sound_id.heard.iter_mut().find(|(species, _)| *species == bird.species) {
+            Some((_, seconds)) => *seconds += dt,
+            None => sound_id.heard.push((bird.species, dt)),
+        }
+    }
+}
+
+pub fn refresh_sound_id_panel_system(
+    sound_id: Res<SoundId>,
+    education_data: Res<BirdEducationData>,
+    mut text_query: Query<&mut Text, With<SoundIdText>>,
+) {
+    if !sound_id.listening {
+        return;
+    }
+    let Ok(mut text) = text_query.single_mut() else { return };
+
+    let mut lines = vec![format!(
+        "Listening... {:.0}s left, {} charges left today",
+        sound_id.session_left.ceil(),
+        sound_id.charges,
+    )];
+    if sound_id.heard.is_empty() {
+        lines.push("Waiting for a bird to call".to_string());
+    }
+    for (species, seconds) in sound_id.heard.iter().rev().take(MAX_LISTED) {
+        let name = education_data.species_facts.get(species)
+            .map(|facts| facts.common_name.clone())
+            .unwrap_or_else(|| format!("{:?}", species));
+        let progress = seconds / REVEAL_SECONDS;
+        lines.push(if progress >= 1.0 {
+            format!("Likely {}", name)
+        } else {
+            format!("{}  {:.0}%", partial_name(&name, progress), progress * 100.0)
+        });
+    }
+
+    let content = lines.join("\n");
+    if text.0 != content {
+        text.0 = content;
+    }
+}
+
+pub fn close_sound_id_system(
+    mut commands: Commands,
+    mut sound_id: ResMut<SoundId>,
+    panel_query: Query<Entity, With<SoundIdPanel>>,
+) {
+    sound_id.listening = false;
+    for panel in &panel_query {
+        commands.entity(panel).despawn();
+    }
+}
+
+fn spawn_sound_id_panel(commands: &mut Commands) {
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(20.0),
+            bottom: Val::Px(120.0),
+            min_width: Val::Px(260.0),
+            flex_direction: FlexDirection::Column,
+            padding: UiRect::all(Val::Px(12.0)),
+            row_gap: Val::Px(6.0),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.95, 0.92, 0.88, 0.95)),
+        BorderRadius::all(Val::Px(8.0)),
+        SoundIdPanel,
+        Name::new("SoundId"),
+    )).with_children(|panel| {
+        panel.spawn((
+            Text::new("Sound ID"),
+            TextFont { font_size: 18.0, ..default() },
+            TextColor(Color::srgb(0.3, 0.2, 0.1)),
+        ));
+        panel.spawn((
+            Text::new(""),
+            TextFont { font_size: 13.0, ..default() },
+            TextColor(Color::srgb(0.4, 0.3, 0.2)),
+            SoundIdText,
+        ));
+    });
+}
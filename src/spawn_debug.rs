@@ -0,0 +1,406 @@
+// Spawn Debug - Developer panel breaking down why each species does or doesn't turn up
+//
+// Dev builds only. The `spawns` console command opens a table of every species in this season's
+// regional pool with the multipliers behind its spawn weight - seasonal availability, migration
+// timing, the difficulty's rarity scaling and any irruption or pest outbreak - and its odds of
+// being picked when a bird arrives. Above it sit the yard-wide factors that set how often a bird
+// arrives at all, and below it the last few species rolls the spawner made.
+use bevy::prelude::*;
+use std::collections::VecDeque;
+use crate::bird::{seasonal_spawn_weights, spawn_weight_factors, BirdSpecies, SpawnWeightFactors};
+use crate::bird_data::BirdDataRegistry;
+use crate::difficulty::DifficultySettings;
+use crate::environment::irruption::Irruptions;
+use crate::environment::resources::{SeasonalState, TimeState};
+use crate::garden_pests::GardenPests;
+use crate::journal::resources::BirdEducationData;
+
+const RECENT_ROLLS: usize = 8;
+const PANEL_WIDTH: f32 = 640.0;
+const COLUMN_WIDTHS: [f32; 7] = [150.0, 70.0, 70.0, 60.0, 70.0, 70.0, 60.0];
+
+pub struct SpawnDebugPlugin;
+
+impl Plugin for SpawnDebugPlugin {
+    fn build(&self, app: &mut App) {
+        // The console's `spawns` command reports the panel missing in release builds
+        if !cfg!(debug_assertions) {
+            return;
+        }
+        app
+            .init_resource::<SpawnDiagnostics>()
+            .init_resource::<SpawnDebugPanel>()
+            .add_systems(Update, (
+                spawn_debug_button_system,
+                refresh_spawn_debug_system,
+            ).chain().in_set(crate::GameSet::UI));
+    }
+}
+
+/// Yard-wide multipliers behind the per-frame chance of a bird arriving
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SpawnFactors {
+    pub season: f32,
+    pub weather: f32,
+    pub time_of_day: f32,
+    pub song: f32,
+    pub provisioning: f32,
+    pub garden_style: f32,
+    pub noise: f32,
+    pub chance: f32,
+}
+
+/// One species pick, with the roll that made it and the odds it had
+#[derive(Debug, Clone, Copy)]
+pub struct SpawnRoll {
+    pub species: BirdSpecies,
+    pub roll: f32,
+    pub odds: f32,
+}
+
+// Resources
+#[derive(Resource, Default)]
+pub struct SpawnDiagnostics {
+    pub factors: SpawnFactors,
+    pub bird_count: usize,
+    // Newest first, stamped with the game day and hour
+    pub recent: VecDeque<(SpawnRoll, u32, f32)>,
+}
+
+impl SpawnDiagnostics {
+    pub fn record(&mut self, factors: SpawnFactors, bird_count: usize, spawned: Option<SpawnRoll>, time_state: &TimeState) {
+        self.factors = factors;
+        self.bird_count = bird_count;
+        if let Some(roll) = spawned {
+            self.recent.push_front((roll, time_state.day_of_year, time_state.hour));
+            self.recent.truncate(RECENT_ROLLS);
+        }
+    }
+}
+
+#[derive(Resource)]
+pub struct SpawnDebugPanel {
+    pub is_open: bool,
+    // Also list pool species whose weight is zero right now
+    pub show_excluded: bool,
+    refresh_timer: Timer,
+    panel_dirty: bool,
+}
+
+impl Default for SpawnDebugPanel {
+    fn default() -> Self {
+        Self {
+            is_open: false,
+            show_excluded: false,
+            refresh_timer: Timer::from_seconds(1.0, TimerMode::Repeating),
+            panel_dirty: false,
+        }
+    }
+}
+
+impl SpawnDebugPanel {
+    pub fn toggle(&mut self) {
+        self.is_open = !self.is_open;
+        self.panel_dirty = true;
+    }
+}
+
+// Components
+#[derive(Component)]
+pub struct SpawnDebugPanelRoot;
+
+#[derive(Component)]
+pub enum SpawnDebugButton {
+    ToggleExcluded,
+    Close,
+}
+
+struct SpeciesRow {
+    species: BirdSpecies,
+    // None for species an irruption brought in from outside the pool
+    factors: Option<SpawnWeightFactors>,
+    events: Option<f32>,
+    weight: f32,
+    odds: f32,
+}
+
+// Mirrors spawn_seasonal_bird: seasonal weights first, then irruptions and pest outbreaks
+#[allow(clippy::too_many_arguments)]
+fn species_rows(
+    seasonal_state: &SeasonalState,
+    bird_registry: &BirdDataRegistry,
+    education_data: &BirdEducationData,
+    difficulty: &DifficultySettings,
+    irruptions: &Irruptions,
+    pests: &GardenPests,
+    time_state: &TimeState,
+    show_excluded: bool,
+) -> Vec<SpeciesRow> {
+    let season = time_state.get_season();
+    let seasonal = seasonal_spawn_weights(seasonal_state, bird_registry, education_data, difficulty, season);
+    let mut weights = seasonal.clone();
+    irruptions.apply(&mut weights);
+    pests.apply(&mut weights);
+    let total: f32 = weights.iter().map(|(_, weight)| weight).sum();
+
+    let mut rows: Vec<SpeciesRow> = weights.iter()
+        .map(|(species, weight)| {
+            let before = seasonal.iter().find(|(candidate, _)| candidate == species).map(|(_, weight)| *weight);
+            SpeciesRow {
+                species: *species,
+                factors: before.map(|_| spawn_weight_factors(*species, bird_registry, education_data, difficulty, season)),
+                events: before.map(|before| weight / before),
+                weight: *weight,
+                odds: if total > 0.0 { weight / total } else { 0.0 },
+            }
+        })
+        .collect();
+
+    if show_excluded {
+        for species in seasonal_state.available_species.keys() {
+            if rows.iter().any(|row| row.species == *species) {
+                continue;
+            }
+            rows.push(SpeciesRow {
+                species: *species,
+                factors: Some(spawn_weight_factors(*species, bird_registry, education_data, difficulty, season)),
+                events: Some(1.0),
+                weight: 0.0,
+                odds: 0.0,
+            });
+        }
+    }
+
+    rows.sort_by(|a, b| b.odds.total_cmp(&a.odds).then_with(|| format!("{:?}", a.species).cmp(&format!("{:?}", b.species))));
+    rows
+}
+
+type SpawnDebugButtonQuery<'w, 's> = Query<'w, 's, (&'static Interaction, &'static SpawnDebugButton), (Changed<Interaction>, With<Button>)>;
+
+fn spawn_debug_button_system(
+    interaction_query: SpawnDebugButtonQuery,
+    mut panel: ResMut<SpawnDebugPanel>,
+) {
+    for (interaction, button) in &interaction_query {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        match button {
+            SpawnDebugButton::ToggleExcluded => panel.show_excluded = !panel.show_excluded,
+            SpawnDebugButton::Close => panel.is_open = false,
+        }
+        panel.panel_dirty = true;
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn refresh_spawn_debug_system(
+    mut commands: Commands,
+    mut panel: ResMut<SpawnDebugPanel>,
+    diagnostics: Res<SpawnDiagnostics>,
+    panel_query: Query<Entity, With<SpawnDebugPanelRoot>>,
+    world: (Res<SeasonalState>, Res<BirdDataRegistry>, Res<BirdEducationData>, Res<DifficultySettings>),
+    events: (Res<Irruptions>, Res<GardenPests>),
+    time_state: Res<TimeState>,
+    time: Res<Time>,
+) {
+    panel.refresh_timer.tick(time.delta());
+    let timer_fired = panel.is_open && panel.refresh_timer.just_finished();
+    if !panel.panel_dirty && !timer_fired {
+        return;
+    }
+    panel.panel_dirty = false;
+
+    for root in &panel_query {
+        commands.entity(root).despawn();
+    }
+    if !panel.is_open {
+        return;
+    }
+
+    let (seasonal_state, bird_registry, education_data, difficulty) = world;
+    let (irruptions, pests) = events;
+    let rows = species_rows(
+        &seasonal_state, &bird_registry, &education_data, &difficulty, &irruptions, &pests, &time_state, panel.show_excluded,
+    );
+    spawn_debug_panel(&mut commands, &panel, &diagnostics, &rows, &time_state);
+}
+
+fn spawn_debug_button(parent: &mut ChildSpawnerCommands, label: &str, button: SpawnDebugButton) {
+    parent.spawn((
+        Button,
+        Node {
+            padding: UiRect::axes(Val::Px(6.0), Val::Px(2.0)),
+            ..default()
+        },
+        BackgroundColor(Color::srgb(0.6, 0.5, 0.4)),
+        BorderRadius::all(Val::Px(4.0)),
+        button,
+    )).with_children(|button| {
+        button.spawn((
+            Text::new(label),
+            TextFont { font_size: 10.0, ..default() },
+            TextColor(Color::WHITE),
+        ));
+    });
+}
+
+fn spawn_table_row(parent: &mut ChildSpawnerCommands, cells: [String; 7], color: Color) {
+    parent.spawn(Node {
+        width: Val::Percent(100.0),
+        ..default()
+    }).with_children(|row| {
+        for (cell, width) in cells.into_iter().zip(COLUMN_WIDTHS) {
+            row.spawn((
+                Text::new(cell),
+                TextFont { font_size: 11.0, ..default() },
+                TextColor(color),
+                Node {
+                    width: Val::Px(width),
+                    flex_shrink: 0.0,
+                    ..default()
+                },
+            ));
+        }
+    });
+}
+
+fn multiplier(value: f32) -> String {
+    format!("x{:.2}", value)
+}
+
+fn spawn_debug_panel(
+    commands: &mut Commands,
+    panel: &SpawnDebugPanel,
+    diagnostics: &SpawnDiagnostics,
+    rows: &[SpeciesRow],
+    time_state: &TimeState,
+) {
+    let factors = diagnostics.factors;
+
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(10.0),
+            top: Val::Px(60.0),
+            width: Val::Px(PANEL_WIDTH),
+            max_height: Val::Percent(85.0),
+            flex_direction: FlexDirection::Column,
+            padding: UiRect::all(Val::Px(10.0)),
+            row_gap: Val::Px(3.0),
+            overflow: Overflow::clip(),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.95, 0.92, 0.88, 0.95)),
+        BorderRadius::all(Val::Px(6.0)),
+        GlobalZIndex(40),
+        SpawnDebugPanelRoot,
+        Name::new("SpawnDebug"),
+    )).with_children(|panel_node| {
+        panel_node.spawn(Node {
+            width: Val::Percent(100.0),
+            justify_content: JustifyContent::SpaceBetween,
+            align_items: AlignItems::Center,
+            ..default()
+        }).with_children(|header| {
+            header.spawn((
+                Text::new(format!("Spawn Debug  ({:?}, {} birds of 15)", time_state.get_season(), diagnostics.bird_count)),
+                TextFont { font_size: 16.0, ..default() },
+                TextColor(Color::srgb(0.3, 0.2, 0.1)),
+            ));
+            header.spawn(Node {
+                column_gap: Val::Px(4.0),
+                ..default()
+            }).with_children(|buttons| {
+                let excluded = if panel.show_excluded { "Hide excluded" } else { "Show excluded" };
+                spawn_debug_button(buttons, excluded, SpawnDebugButton::ToggleExcluded);
+                spawn_debug_button(buttons, "Close", SpawnDebugButton::Close);
+            });
+        });
+
+        // How often a bird arrives at all
+        panel_node.spawn((
+            Text::new(format!(
+                "Arrival chance {:.5}/frame = season {} · weather {} · time {} · song {} · 0.001",
+                factors.chance,
+                multiplier(factors.season),
+                multiplier(factors.weather),
+                multiplier(factors.time_of_day),
+                multiplier(factors.song),
+            )),
+            TextFont { font_size: 11.0, ..default() },
+            TextColor(Color::srgb(0.2, 0.1, 0.05)),
+        ));
+        panel_node.spawn((
+            Text::new(format!(
+                "Habitat: provisioning {} · garden style {} · neighborhood noise {}",
+                multiplier(factors.provisioning),
+                multiplier(factors.garden_style),
+                multiplier(factors.noise),
+            )),
+            TextFont { font_size: 11.0, ..default() },
+            TextColor(Color::srgb(0.2, 0.1, 0.05)),
+            Node {
+                margin: UiRect::bottom(Val::Px(4.0)),
+                ..default()
+            },
+        ));
+
+        // Which species it is once one arrives
+        let header_cells = ["Species", "Seasonal", "Migration", "Rarity", "Events", "Weight", "Odds"].map(String::from);
+        spawn_table_row(panel_node, header_cells, Color::srgb(0.5, 0.4, 0.3));
+
+        if rows.is_empty() {
+            panel_node.spawn((
+                Text::new("No species in this season's pool"),
+                TextFont { font_size: 12.0, ..default() },
+                TextColor(Color::srgb(0.5, 0.4, 0.3)),
+            ));
+        }
+
+        for row in rows {
+            let (seasonal, migration, rarity) = match row.factors {
+                Some(factors) => (format!("{:.2}", factors.seasonal), multiplier(factors.migration), multiplier(factors.rarity)),
+                None => ("-".to_string(), "-".to_string(), "-".to_string()),
+            };
+            let events = row.events.map_or("irruption".to_string(), multiplier);
+            let color = if row.odds > 0.0 { Color::srgb(0.2, 0.1, 0.05) } else { Color::srgb(0.6, 0.5, 0.45) };
+            spawn_table_row(panel_node, [
+                format!("{:?}", row.species),
+                seasonal,
+                migration,
+                rarity,
+                events,
+                format!("{:.3}", row.weight),
+                format!("{:.1}%", row.odds * 100.0),
+            ], color);
+        }
+
+        panel_node.spawn((
+            Text::new("Recent rolls"),
+            TextFont { font_size: 12.0, ..default() },
+            TextColor(Color::srgb(0.3, 0.2, 0.1)),
+            Node {
+                margin: UiRect::top(Val::Px(6.0)),
+                ..default()
+            },
+        ));
+        if diagnostics.recent.is_empty() {
+            panel_node.spawn((
+                Text::new("No birds spawned yet"),
+                TextFont { font_size: 11.0, ..default() },
+                TextColor(Color::srgb(0.5, 0.4, 0.3)),
+            ));
+        }
+        for (roll, day, hour) in &diagnostics.recent {
+            panel_node.spawn((
+                Text::new(format!(
+                    "D{} {:02}:{:02}  {:?} - rolled {:.3}, {:.1}% odds",
+                    day, *hour as u32, (hour.fract() * 60.0) as u32, roll.species, roll.roll, roll.odds * 100.0,
+                )),
+                TextFont { font_size: 11.0, ..default() },
+                TextColor(Color::srgb(0.2, 0.1, 0.05)),
+            ));
+        }
+    });
+}
@@ -0,0 +1,276 @@
+// Species Packs - Regional bird rosters loaded as data assets, extendable by mods and the Workshop
+use bevy::prelude::*;
+use bevy::asset::{io::Reader, io::AssetSourceBuilder, AssetLoader, LoadContext};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use crate::bird::BirdSpecies;
+use crate::bird_data::{BirdData, BirdDataRegistry};
+use crate::journal::resources::{BirdEducationData, BirdFacts};
+use crate::environment::components::Season;
+use crate::environment::region::Region;
+use crate::animation::resources::TextureAtlasCache;
+use crate::animation::systems::register_species_spritesheet;
+
+pub const MOD_PACK_SOURCE: &str = "species_mods";
+const PACK_EXTENSION: &str = "pack.ron";
+
+// Packs shipped with the game, one per region
+pub const BUILT_IN_PACKS: [&str; 3] = [
+    "data/species_packs/eastern_woodland.pack.ron",
+    "data/species_packs/desert_southwest.pack.ron",
+    "data/species_packs/pacific_coast.pack.ron",
+];
+
+pub struct SpeciesPacksPlugin;
+
+impl Plugin for SpeciesPacksPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_asset::<SpeciesPack>()
+            .register_asset_loader(SpeciesPackLoader)
+            .init_resource::<SpeciesPackManager>()
+            .add_systems(Startup, load_species_packs_system)
+            .add_systems(Update, apply_species_packs_system.in_set(crate::GameSet::Simulation));
+    }
+}
+
+// A region's avifauna: which species visit and when, plus the assets and data that come with them
+#[derive(Asset, TypePath, Debug, Clone, Deserialize, Serialize)]
+pub struct SpeciesPack {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub author: String,
+    pub region: Region,
+    pub species: Vec<PackSpecies>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PackSpecies {
+    pub species: BirdSpecies,
+    // Residents visit all year unless a season says otherwise
+    #[serde(default)]
+    pub resident: bool,
+    // Spawn weight by season name ("Spring", "Summer", "Fall", "Winter")
+    #[serde(default)]
+    pub seasons: HashMap<String, f32>,
+    // Spritesheet path, laid out like the built-in bird sheets
+    #[serde(default)]
+    pub sprite: Option<String>,
+    // Call recordings by call type name ("Song", "Alarm", ...), with "Call" used for the rest
+    #[serde(default)]
+    pub calls: HashMap<String, String>,
+    #[serde(default)]
+    pub facts: Option<BirdFacts>,
+    // Full stat block, replacing the entry from data/birds
+    #[serde(default)]
+    pub data: Option<BirdData>,
+    // Individual behavioral traits layered over the stat block
+    #[serde(default)]
+    pub behavior_overrides: HashMap<String, f32>,
+}
+
+impl PackSpecies {
+    pub fn spawn_weight(&self, season: Season) -> f32 {
+        let default = if self.resident { 1.0 } else { 0.0 };
+        self.seasons.get(&format!("{:?}", season)).copied().unwrap_or(default)
+    }
+}
+
+#[derive(Default)]
+pub struct SpeciesPackLoader;
+
+impl AssetLoader for SpeciesPackLoader {
+    type Asset = SpeciesPack;
+    type Settings = ();
+    type Error = Box<dyn std::error::Error + Send + Sync>;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+
+        match ron::de::from_bytes::<SpeciesPack>(&bytes) {
+            Ok(pack) => {
+                info!("SpeciesPackLoader: Loaded '{}' with {} species", pack.name, pack.species.len());
+                Ok(pack)
+            }
+            Err(e) => {
+                error!("SpeciesPackLoader: Failed to parse {:?}: {}", load_context.path(), e);
+                Err(Box::new(e))
+            }
+        }
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &[PACK_EXTENSION]
+    }
+}
+
+// Resources
+#[derive(Resource, Default)]
+pub struct SpeciesPackManager {
+    handles: Vec<Handle<SpeciesPack>>,
+    // Loaded packs in request order; later packs override earlier ones species by species
+    loaded: Vec<(AssetId<SpeciesPack>, SpeciesPack)>,
+}
+
+impl SpeciesPackManager {
+    pub fn request(&mut self, asset_server: &AssetServer, path: impl Into<String>) {
+        let path = path.into();
+        info!("Requesting species pack {}", path);
+        self.handles.push(asset_server.load(path));
+    }
+
+    /// Keeps a pack built at runtime (e.g. by a compiled mod) alive and ordered after those requested so far
+    #[cfg(feature = "wasm-mods")]
+    pub fn track(&mut self, handle: Handle<SpeciesPack>) {
+        self.handles.push(handle);
+    }
+
+    /// Reloads every requested pack so they layer back on top of freshly reloaded bird data
+    pub fn reload_all(&self, asset_server: &AssetServer) {
+        for path in self.handles.iter().filter_map(|handle| handle.path()) {
+            asset_server.reload(path.clone());
+        }
+    }
+
+    pub fn packs(&self) -> impl DoubleEndedIterator<Item = &SpeciesPack> {
+        self.loaded.iter().map(|(_, pack)| pack)
+    }
+
+    fn insert(&mut self, id: AssetId<SpeciesPack>, pack: SpeciesPack) {
+        match self.loaded.iter_mut().find(|(loaded_id, _)| *loaded_id == id) {
+            Some(loaded) => loaded.1 = pack,
+            None => self.loaded.push((id, pack)),
+        }
+
+        // Built-in packs are requested first, so mods always layer on top regardless of load speed
+        let handles = &self.handles;
+        self.loaded.sort_by_key(|(id, _)| handles.iter().position(|handle| handle.id() == *id));
+    }
+
+    // Merged roster for a region, or None when no pack covers it
+    pub fn roster(&self, region: Region) -> Option<Vec<PackSpecies>> {
+        let mut roster: Vec<PackSpecies> = Vec::new();
+        let mut covered = false;
+
+        for pack in self.packs().filter(|pack| pack.region == region) {
+            covered = true;
+            for entry in &pack.species {
+                match roster.iter_mut().find(|existing| existing.species == entry.species) {
+                    Some(existing) => *existing = entry.clone(),
+                    None => roster.push(entry.clone()),
+                }
+            }
+        }
+
+        covered.then_some(roster)
+    }
+
+    pub fn call_sound(&self, species: BirdSpecies, call_type: &str) -> Option<&str> {
+        self.packs().rev()
+            .flat_map(|pack| pack.species.iter())
+            .filter(|entry| entry.species == species)
+            .find_map(|entry| entry.calls.get(call_type).or_else(|| entry.calls.get("Call")))
+            .map(String::as_str)
+    }
+}
+
+pub fn mod_pack_directory() -> PathBuf {
+    dirs::data_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("AvianHaven")
+        .join("mods")
+        .join("species_packs")
+}
+
+// Must be called before DefaultPlugins so the asset server knows about the source
+pub fn register_mod_pack_source(app: &mut App) {
+    let directory = mod_pack_directory();
+    if let Err(e) = fs::create_dir_all(&directory) {
+        warn!("Could not create species pack folder {:?}: {}", directory, e);
+    }
+
+    app.register_asset_source(
+        MOD_PACK_SOURCE,
+        AssetSourceBuilder::platform_default(&directory.to_string_lossy(), None),
+    );
+}
+
+pub fn scan_mod_packs() -> Vec<String> {
+    let mut files: Vec<String> = fs::read_dir(mod_pack_directory())
+        .map(|entries| {
+            entries.flatten()
+                .filter_map(|entry| entry.file_name().to_str().map(str::to_string))
+                .filter(|name| name.ends_with(&format!(".{}", PACK_EXTENSION)))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    // Alphabetical order decides which mod wins when two touch the same species
+    files.sort();
+    files
+}
+
+fn load_species_packs_system(
+    mut manager: ResMut<SpeciesPackManager>,
+    asset_server: Res<AssetServer>,
+) {
+    for path in BUILT_IN_PACKS {
+        manager.request(&asset_server, path);
+    }
+
+    for file_name in scan_mod_packs() {
+        manager.request(&asset_server, format!("{}://{}", MOD_PACK_SOURCE, file_name));
+    }
+}
+
+// Folds each pack into the bird data, education and animation registries as it finishes loading
+fn apply_species_packs_system(
+    mut pack_events: EventReader<AssetEvent<SpeciesPack>>,
+    pack_assets: Res<Assets<SpeciesPack>>,
+    mut manager: ResMut<SpeciesPackManager>,
+    mut bird_registry: ResMut<BirdDataRegistry>,
+    mut education_data: ResMut<BirdEducationData>,
+    asset_server: Res<AssetServer>,
+    mut texture_atlas_layouts: ResMut<Assets<TextureAtlasLayout>>,
+    mut atlas_cache: ResMut<TextureAtlasCache>,
+) {
+    for event in pack_events.read() {
+        let id = match event {
+            AssetEvent::LoadedWithDependencies { id } | AssetEvent::Modified { id } => *id,
+            // Packs added straight to Assets never see a load event
+            AssetEvent::Added { id } if asset_server.get_load_state(*id).is_none() => *id,
+            _ => continue,
+        };
+        let Some(pack) = pack_assets.get(id) else { continue };
+
+        for entry in &pack.species {
+            let species_name = format!("{:?}", entry.species);
+
+            if let Some(data) = &entry.data {
+                bird_registry.species_data.insert(species_name.clone(), data.clone());
+            }
+            if let Some(data) = bird_registry.species_data.get_mut(&species_name) {
+                data.behavioral_traits.extend(entry.behavior_overrides.clone());
+            }
+            if let Some(facts) = &entry.facts {
+                education_data.species_facts.insert(entry.species, facts.clone());
+            }
+            if let Some(sprite) = &entry.sprite {
+                register_species_spritesheet(entry.species, sprite, &asset_server, &mut texture_atlas_layouts, &mut atlas_cache);
+            }
+        }
+
+        // Reloading a pack replaces its earlier copy in place
+        manager.insert(id, pack.clone());
+        info!("Species pack '{}' by {} active for {}", pack.name, pack.author, pack.region.name());
+    }
+}
@@ -0,0 +1,203 @@
+// Stealth - The player's presence as an observer: movement, stillness, blinds and wind set how close birds tolerate you
+use bevy::prelude::*;
+use crate::bird::Bird;
+use crate::bird_ai::components::{BirdAI, BirdState, Blackboard};
+use crate::bird_data::BirdDataRegistry;
+use crate::catalog::components::{PlaceableObject, PlaceableItemType};
+use crate::environment::components::Weather;
+use crate::environment::resources::WeatherState;
+use crate::neighborhood_noise::NeighborhoodNoise;
+use crate::photo_mode::components::PhotoTarget;
+use crate::photo_mode::resources::PhotoModeSettings;
+
+pub const BLIND_RANGE: f32 = 120.0;
+// Moving slower than this counts as keeping still
+const STILL_SPEED: f32 = 20.0;
+// Seconds of stillness before birds have fully settled around you
+const SETTLE_TIME: f32 = 20.0;
+// Birds start watching you from this far beyond their flush radius
+const ALERT_MARGIN: f32 = 1.6;
+
+pub struct StealthPlugin;
+
+impl Plugin for StealthPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<ObserverPresence>()
+            .add_systems(Startup, setup_presence_indicator)
+            .add_systems(Update, (
+                observer_presence_system,
+                flush_system,
+                presence_indicator_system,
+            ).chain()
+                // Flushed birds must not be overwritten by the behavior tree in the same frame
+                .after(crate::bird_ai::systems::behavior_tree_system)
+                .in_set(crate::GameSet::AI)
+                .run_if(in_state(crate::AppState::Playing)));
+    }
+}
+
+// Resources
+#[derive(Resource)]
+pub struct ObserverPresence {
+    // Multiplies every bird's flush radius
+    pub detectability: f32,
+    pub in_blind: bool,
+    pub speed: f32,
+    pub stillness: f32,
+    last_position: Option<Vec2>,
+}
+
+impl Default for ObserverPresence {
+    fn default() -> Self {
+        Self {
+            detectability: 1.0,
+            in_blind: false,
+            speed: 0.0,
+            stillness: 0.0,
+            last_position: None,
+        }
+    }
+}
+
+impl ObserverPresence {
+    // Bigger birds are warier and flush from further away
+    pub fn flush_radius(&self, size_category: u8) -> f32 {
+        (30.0 + size_category as f32 * 8.0) * self.detectability
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self.detectability {
+            d if d < 0.3 => "Hidden",
+            d if d < 0.6 => "Quiet",
+            d if d < 1.0 => "Noticeable",
+            _ => "Conspicuous",
+        }
+    }
+}
+
+// Components
+#[derive(Component)]
+pub struct PresenceIndicator;
+
+fn setup_presence_indicator(mut commands: Commands) {
+    commands.spawn((
+        Text::new("Presence: Noticeable"),
+        TextFont { font_size: 14.0, ..default() },
+        TextColor(Color::srgb(0.3, 0.2, 0.1)),
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(10.0),
+            left: Val::Px(20.0),
+            padding: UiRect::axes(Val::Px(8.0), Val::Px(4.0)),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.95, 0.92, 0.88, 0.9)),
+        PresenceIndicator,
+    ));
+}
+
+// Fast movement is loud, keeping still lets birds settle, a blind or a gusty day covers you
+fn observer_presence_system(
+    mut presence: ResMut<ObserverPresence>,
+    camera_query: Query<&Transform, (With<Camera2d>, With<PhotoTarget>)>,
+    object_query: Query<(&PlaceableObject, &Transform), Without<Camera2d>>,
+    weather_state: Res<WeatherState>,
+    noise: Res<NeighborhoodNoise>,
+    time: Res<Time>,
+) {
+    let dt = time.delta_secs();
+    let Ok(camera_transform) = camera_query.single() else { return };
+    if dt <= 0.0 {
+        return;
+    }
+
+    let position = camera_transform.translation.truncate();
+    let instant_speed = presence.last_position.map_or(0.0, |last| last.distance(position) / dt);
+    presence.last_position = Some(position);
+    presence.speed += (instant_speed - presence.speed) * (dt * 5.0).min(1.0);
+
+    if presence.speed < STILL_SPEED {
+        presence.stillness += dt;
+    } else {
+        presence.stillness = 0.0;
+    }
+
+    presence.in_blind = object_query.iter().any(|(object, transform)| {
+        object.item_type == PlaceableItemType::WildlifeBlind
+            && transform.translation.truncate().distance(position) < BLIND_RANGE
+    });
+
+    let movement = (0.5 + presence.speed / 300.0).min(1.5);
+    let patience = 1.0 - (presence.stillness / SETTLE_TIME).min(1.0) * 0.5;
+    let cover = if presence.in_blind { 0.35 } else { 1.0 };
+    let wind = if weather_state.current_weather == Weather::Windy { 0.8 } else { 1.0 };
+    // A mower or building work next door already has everything on edge
+    presence.detectability = movement * patience * cover * wind * noise.skittishness();
+}
+
+// Birds inside their flush radius take off
+fn flush_system(
+    presence: Res<ObserverPresence>,
+    camera_query: Query<&Transform, (With<Camera2d>, With<PhotoTarget>)>,
+    mut bird_query: Query<(&Transform, &Bird, &mut BirdState, &mut Blackboard), (With<BirdAI>, Without<Camera2d>)>,
+    bird_data: Res<BirdDataRegistry>,
+    time: Res<Time>,
+) {
+    let Ok(camera_transform) = camera_query.single() else { return };
+    let position = camera_transform.translation.truncate();
+
+    for (transform, bird, mut state, mut blackboard) in &mut bird_query {
+        if *state == BirdState::Fleeing {
+            continue;
+        }
+        let radius = presence.flush_radius(bird_data.get_size_category(&bird.species));
+        let distance = transform.translation.truncate().distance(position);
+        if distance < radius && rand::random::<f32>() < 2.0 * time.delta_secs() {
+            blackboard.internal.fear = (blackboard.internal.fear + 0.4).min(1.0);
+            *state = BirdState::Fleeing;
+        }
+    }
+}
+
+// Readout of how visible the player is, with watchful birds ringed while the camera is out
+fn presence_indicator_system(
+    mut gizmos: Gizmos,
+    presence: Res<ObserverPresence>,
+    settings: Res<PhotoModeSettings>,
+    camera_query: Query<&Transform, (With<Camera2d>, With<PhotoTarget>)>,
+    bird_query: Query<(&Transform, &Bird, &BirdState), (With<BirdAI>, Without<Camera2d>)>,
+    bird_data: Res<BirdDataRegistry>,
+    mut indicator_query: Query<(&mut Text, &mut TextColor), With<PresenceIndicator>>,
+) {
+    let level = (presence.detectability / 1.5).min(1.0);
+    let color = Color::srgb(0.2 + level * 0.7, 0.6 - level * 0.4, 0.2);
+
+    for (mut text, mut text_color) in &mut indicator_query {
+        let mut label = format!("Presence: {}", presence.description());
+        if presence.in_blind {
+            label.push_str(" (in blind)");
+        } else if presence.stillness >= SETTLE_TIME {
+            label.push_str(" (birds settled)");
+        }
+        text.0 = label;
+        text_color.0 = color;
+    }
+
+    if !settings.is_active {
+        return;
+    }
+    let Ok(camera_transform) = camera_query.single() else { return };
+    let position = camera_transform.translation.truncate();
+
+    for (transform, bird, state) in bird_query.iter() {
+        if *state == BirdState::Fleeing {
+            continue;
+        }
+        let radius = presence.flush_radius(bird_data.get_size_category(&bird.species));
+        let bird_position = transform.translation.truncate();
+        if bird_position.distance(position) < radius * ALERT_MARGIN {
+            gizmos.circle_2d(bird_position + Vec2::new(0.0, 18.0), 4.0, color);
+        }
+    }
+}
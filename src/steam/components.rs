@@ -6,6 +6,12 @@ pub struct SteamUser {
     pub display_name: String,
 }
 
+#[derive(Component)]
+pub struct AchievementSyncPanelRoot;
+
+#[derive(Component)]
+pub struct AchievementSyncRetryButton;
+
 #[derive(Component)]
 pub struct WorkshopItem {
     pub workshop_id: u64,
@@ -29,6 +35,9 @@ pub enum WorkshopItemType {
         theme_name: String,
         assets: Vec<String>,
     },
+    SpeciesPack {
+        pack_path: String,
+    },
 }
 
 #[derive(Debug, Clone)]
@@ -16,12 +16,30 @@ impl Plugin for SteamPlugin {
             .init_resource::<SteamState>()
     .init_resource::<SteamStats>()
             .init_resource::<SteamAchievements>()
+            .init_resource::<SteamRichPresence>()
+            .init_resource::<SteamOverlay>()
+            .init_resource::<SteamInputState>()
+            .init_resource::<AchievementSyncPanel>()
             .add_event::<SteamAchievementEvent>()
             .add_event::<SteamStatsEvent>()
+            .add_event::<SteamOverlayEvent>()
             .add_systems(Startup, (initialize_steam_systems, load_workshop_content))
             .add_systems(Update, (
                 steam_achievement_sync_system,
                 steam_stats_tracking_system,
-            ).run_if(resource_exists::<SteamState>));
+            ).in_set(crate::GameSet::Simulation).run_if(resource_exists::<SteamState>))
+            .add_systems(Update, (
+                steam_overlay_hotkey_system,
+                steam_overlay_pause_system,
+                steam_input_action_set_system,
+            ).chain().in_set(crate::GameSet::Input).run_if(resource_exists::<SteamState>))
+            .add_systems(Update, steam_rich_presence_system
+                .in_set(crate::GameSet::UI)
+                .run_if(resource_exists::<SteamState>))
+            .add_systems(Update, (
+                achievement_sync_panel_toggle_system.run_if(crate::debug_console::console_is_not_visible),
+                achievement_sync_retry_button_system,
+                refresh_achievement_sync_panel_system,
+            ).chain().in_set(crate::GameSet::UI));
     }
 }
\ No newline at end of file
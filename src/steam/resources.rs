@@ -1,7 +1,14 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
 
+// Seconds before the first retry of a failed sync; each further failure doubles it up to the cap
+pub const SYNC_RETRY_BASE_SECS: f64 = 5.0;
+pub const SYNC_RETRY_MAX_SECS: f64 = 300.0;
+
 // Steam client wrapper that's thread-safe
 pub type SteamClientWrapper = Arc<Mutex<Option<steamworks::SingleClient>>>;
 
@@ -30,7 +37,32 @@ impl Default for SteamState {
 pub struct SteamAchievements {
     pub local_achievements: HashMap<String, bool>,
     pub steam_achievements: HashMap<String, bool>,
-    pub sync_pending: Vec<String>,
+    // Local achievement ID -> Steam API name
+    pub steam_ids: HashMap<String, String>,
+    // Unlocks recorded locally and not yet confirmed by Steam, oldest first
+    pub sync_pending: Vec<PendingAchievementSync>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PendingAchievementSync {
+    pub achievement_id: String,
+    // Unix seconds of the local unlock
+    pub unlocked_at: u64,
+    pub attempts: u32,
+    // Real seconds since startup when the next attempt is due
+    #[serde(skip)]
+    pub next_attempt: f64,
+    #[serde(skip)]
+    pub last_error: Option<String>,
+}
+
+impl PendingAchievementSync {
+    pub fn schedule_retry(&mut self, now: f64, error: String) {
+        self.attempts += 1;
+        let delay = SYNC_RETRY_BASE_SECS * 2f64.powi(self.attempts.saturating_sub(1).min(16) as i32);
+        self.next_attempt = now + delay.min(SYNC_RETRY_MAX_SECS);
+        self.last_error = Some(error);
+    }
 }
 
 impl SteamAchievements {
@@ -48,22 +80,92 @@ impl SteamAchievements {
             ("Millionaire", "MILLIONAIRE_BIRDER"),
             ("FeederMaintainer", "FEEDER_MAINTAINER"),
             ("FeederExpert", "FEEDER_EXPERT"),
+            ("FirstCompost", "FIRST_COMPOST"),
+            ("WasteNotWantNot", "WASTE_NOT_WANT_NOT"),
+            ("GreenSanctuary", "GREEN_SANCTUARY"),
         ];
         
-        for (local_id, _steam_id) in mappings {
-            self.local_achievements.insert(local_id.to_string(), false);
-            self.steam_achievements.insert(local_id.to_string(), false);
+        for (local_id, steam_id) in mappings {
+            self.local_achievements.entry(local_id.to_string()).or_insert(false);
+            self.steam_achievements.entry(local_id.to_string()).or_insert(false);
+            self.steam_ids.insert(local_id.to_string(), steam_id.to_string());
+        }
+    }
+    
+    /// Records an unlock and queues it for Steam; returns false if it was already unlocked
+    pub fn unlock_achievement(&mut self, achievement_id: &str) -> bool {
+        let Some(unlocked) = self.local_achievements.get_mut(achievement_id) else {
+            return false;
+        };
+        if *unlocked {
+            return false;
         }
+        *unlocked = true;
+        self.queue_sync(achievement_id);
+        true
     }
     
-    pub fn unlock_achievement(&mut self, achievement_id: &str) {
-        if let Some(unlocked) = self.local_achievements.get_mut(achievement_id) {
-            if !*unlocked {
-                *unlocked = true;
-                self.sync_pending.push(achievement_id.to_string());
+    /// Queues an achievement Steam does not have yet, even if it was unlocked in an earlier session
+    pub fn queue_sync(&mut self, achievement_id: &str) -> bool {
+        let on_steam = self.steam_achievements.get(achievement_id).copied().unwrap_or(false);
+        if on_steam || self.sync_pending.iter().any(|pending| pending.achievement_id == achievement_id) {
+            return false;
+        }
+        self.sync_pending.push(PendingAchievementSync {
+            achievement_id: achievement_id.to_string(),
+            unlocked_at: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|elapsed| elapsed.as_secs())
+                .unwrap_or(0),
+            attempts: 0,
+            next_attempt: 0.0,
+            last_error: None,
+        });
+        true
+    }
+    
+    fn queue_path() -> PathBuf {
+        dirs::data_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("AvianHaven")
+            .join("achievement_queue.ron")
+    }
+    
+    /// Restores unlocks that were still waiting for Steam when the game last closed
+    pub fn load_queue(&mut self) {
+        let Ok(content) = fs::read_to_string(Self::queue_path()) else { return };
+        match ron::from_str::<Vec<PendingAchievementSync>>(&content) {
+            Ok(queue) => {
+                for pending in queue {
+                    if let Some(unlocked) = self.local_achievements.get_mut(&pending.achievement_id) {
+                        *unlocked = true;
+                    }
+                    if !self.sync_pending.iter().any(|queued| queued.achievement_id == pending.achievement_id) {
+                        self.sync_pending.push(pending);
+                    }
+                }
+                if !self.sync_pending.is_empty() {
+                    info!("Restored {} achievement unlocks waiting for Steam", self.sync_pending.len());
+                }
             }
+            Err(e) => warn!("Could not read achievement sync queue: {}", e),
         }
     }
+    
+    pub fn save_queue(&self) -> Result<(), Box<dyn std::error::Error>> {
+        let path = Self::queue_path();
+        if self.sync_pending.is_empty() {
+            if path.exists() {
+                fs::remove_file(&path)?;
+            }
+            return Ok(());
+        }
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, ron::ser::to_string_pretty(&self.sync_pending, ron::ser::PrettyConfig::default())?)?;
+        Ok(())
+    }
 }
 
 #[derive(Resource, Default)]
@@ -106,4 +208,34 @@ pub struct SteamAchievementEvent {
 pub struct SteamStatsEvent {
     pub stat_name: String,
     pub value: u64,
-}
\ No newline at end of file
+}
+
+// Context shown to friends, rebuilt when the activity, weather or journal changes
+#[derive(Resource, Default)]
+pub struct SteamRichPresence {
+    pub status: String,
+}
+
+#[derive(Resource, Default)]
+pub struct SteamOverlay {
+    pub active: bool,
+    // Only resume time the overlay paused, never a pause the player set
+    pub paused_time: bool,
+}
+
+#[derive(Event)]
+pub struct SteamOverlayEvent {
+    pub active: bool,
+}
+
+// Steam Input action sets currently activated on the controller, highest precedence first
+#[derive(Resource, Default)]
+pub struct SteamInputState {
+    pub active_sets: Vec<crate::keybindings::ActionSet>,
+}
+// Developer panel listing achievement unlocks still waiting for Steam
+#[derive(Resource, Default)]
+pub struct AchievementSyncPanel {
+    pub is_open: bool,
+    pub panel_dirty: bool,
+}
@@ -2,6 +2,14 @@ use bevy::prelude::*;
 use super::{components::*, resources::*};
 use crate::achievements::{AchievementUnlockedEvent, Achievement};
 use crate::photo_mode::components::PhotoTakenEvent;
+use crate::species_packs::SpeciesPackManager;
+use crate::advanced_weather::{PrecipitationKind, PrecipitationRadar};
+use crate::environment::components::Weather;
+use crate::environment::resources::WeatherState;
+use crate::journal::resources::DiscoveredSpecies;
+use crate::photo_mode::resources::PhotoModeSettings;
+use crate::keybindings::ActionSet;
+use crate::AppState;
 use std::process::Command;
 
 pub fn initialize_steam_systems(
@@ -28,6 +36,9 @@ pub fn initialize_steam_systems(
     
     // Register achievement mappings
     steam_achievements.register_achievement_mapping();
+    steam_achievements.load_queue();
+    // In production, steam_achievements would be seeded from user_stats.achievement(steam_id).get()
+    // so unlocks Steam already has are not sent again
     
     info!("Steam systems initialized");
 }
@@ -268,9 +279,6 @@ pub fn initialize_steam_systems_with_check(
         steam_state.is_connected = true;
         steam_state.user_id = Some(123456789); // In production, get from Steam API
         
-        // Register achievement mappings
-        steam_achievements.register_achievement_mapping();
-        
         info!("Steam integration fully initialized");
     } else {
         // Steam not available - run in offline mode
@@ -281,57 +289,230 @@ pub fn initialize_steam_systems_with_check(
         
         info!("Steam integration disabled - all features will work offline");
     }
+    
+    // Unlocks are queued locally either way and synced once Steam is reachable
+    steam_achievements.register_achievement_mapping();
+    steam_achievements.load_queue();
 }
 
 pub fn steam_achievement_sync_system(
     mut achievement_events: EventReader<AchievementUnlockedEvent>,
     mut steam_achievements: ResMut<SteamAchievements>,
     mut steam_achievement_events: EventWriter<SteamAchievementEvent>,
-    steam_state: Res<SteamState>,
+    mut steam_state: ResMut<SteamState>,
+    mut sync_panel: ResMut<AchievementSyncPanel>,
+    achievement_progress: Res<crate::achievements::AchievementProgress>,
+    time: Res<Time<Real>>,
 ) {
-    if !steam_state.is_connected {
-        return;
-    }
+    let mut queue_changed = false;
     
+    // Unlocks are recorded whether or not Steam is reachable right now
     for event in achievement_events.read() {
-        let achievement_id = match event.achievement {
-            Achievement::FirstPhoto => "FirstPhoto",
-            Achievement::PhotoMaster => "PhotoMaster",
-            Achievement::ActionShot => "ActionShot",
-            Achievement::MultiSpeciesShot => "MultiSpeciesShot",
-            Achievement::FirstSpecies => "FirstSpecies",
-            Achievement::CommonCollector => "CommonCollector",
-            Achievement::Ornithologist => "Ornithologist",
-            Achievement::Wealthy => "Wealthy",
-            Achievement::Millionaire => "Millionaire",
-            Achievement::FeederMaintainer => "FeederMaintainer",
-            Achievement::FeederExpert => "FeederExpert",
-        };
-        
-        steam_achievements.unlock_achievement(achievement_id);
-        steam_achievement_events.write(SteamAchievementEvent {
-            achievement_id: achievement_id.to_string(),
-        });
-        
-        info!("Steam achievement unlocked: {}", achievement_id);
+        let achievement_id = local_achievement_id(&event.achievement);
+        if steam_achievements.unlock_achievement(achievement_id) {
+            queue_changed = true;
+            steam_achievement_events.write(SteamAchievementEvent {
+                achievement_id: achievement_id.to_string(),
+            });
+            info!("Steam achievement queued: {}", achievement_id);
+        }
     }
     
-    // Process pending achievement syncs
-    let pending_achievements = steam_achievements.sync_pending.clone();
-    steam_achievements.sync_pending.clear();
+    // A loaded save can hold unlocks Steam never heard about
+    if achievement_progress.is_changed() {
+        for achievement in &achievement_progress.unlocked {
+            let achievement_id = local_achievement_id(achievement);
+            if let Some(unlocked) = steam_achievements.local_achievements.get_mut(achievement_id) {
+                *unlocked = true;
+            }
+            queue_changed |= steam_achievements.queue_sync(achievement_id);
+        }
+    }
     
-    for achievement_id in pending_achievements {
-        // Call Steam API to unlock achievement
-        if let Err(e) = unlock_steam_achievement(&achievement_id, &*steam_state) {
-            error!("Failed to unlock Steam achievement {}: {}", achievement_id, e);
-            // Re-add to pending if failed
-            steam_achievements.sync_pending.push(achievement_id);
-        } else {
-            info!("Successfully synced achievement to Steam: {}", achievement_id);
+    // Process pending achievement syncs that are due
+    let now = time.elapsed_secs_f64();
+    if !steam_achievements.sync_pending.iter().any(|pending| pending.next_attempt <= now) {
+        if queue_changed {
+            save_achievement_queue(&steam_achievements, &mut sync_panel);
         }
+        return;
+    }
+    
+    // Steam may have started or come back online since the last attempt
+    if !steam_state.is_connected && check_steam_connection() {
+        steam_state.is_initialized = true;
+        steam_state.is_connected = true;
+        info!("Steam connection restored, syncing {} queued achievements", steam_achievements.sync_pending.len());
+    }
+    
+    let SteamAchievements { steam_achievements: on_steam, steam_ids, sync_pending, .. } = &mut *steam_achievements;
+    sync_pending.retain_mut(|pending| {
+        if pending.next_attempt > now {
+            return true;
+        }
+        let steam_id = steam_ids.get(&pending.achievement_id).unwrap_or(&pending.achievement_id);
+        match unlock_steam_achievement(steam_id, &steam_state) {
+            Ok(()) => {
+                on_steam.insert(pending.achievement_id.clone(), true);
+                info!("Successfully synced achievement to Steam: {}", pending.achievement_id);
+                false
+            }
+            Err(e) => {
+                pending.schedule_retry(now, e);
+                warn!("Failed to sync achievement {} to Steam (attempt {}), retrying in {:.0}s: {}",
+                      pending.achievement_id, pending.attempts, pending.next_attempt - now,
+                      pending.last_error.as_deref().unwrap_or_default());
+                true
+            }
+        }
+    });
+    save_achievement_queue(&steam_achievements, &mut sync_panel);
+}
+
+fn local_achievement_id(achievement: &Achievement) -> &'static str {
+    match achievement {
+        Achievement::FirstPhoto => "FirstPhoto",
+        Achievement::PhotoMaster => "PhotoMaster",
+        Achievement::ActionShot => "ActionShot",
+        Achievement::MultiSpeciesShot => "MultiSpeciesShot",
+        Achievement::FirstSpecies => "FirstSpecies",
+        Achievement::CommonCollector => "CommonCollector",
+        Achievement::Ornithologist => "Ornithologist",
+        Achievement::Wealthy => "Wealthy",
+        Achievement::Millionaire => "Millionaire",
+        Achievement::FeederMaintainer => "FeederMaintainer",
+        Achievement::FeederExpert => "FeederExpert",
+        Achievement::FirstCompost => "FirstCompost",
+        Achievement::WasteNotWantNot => "WasteNotWantNot",
+        Achievement::GreenSanctuary => "GreenSanctuary",
     }
 }
 
+fn save_achievement_queue(steam_achievements: &SteamAchievements, sync_panel: &mut AchievementSyncPanel) {
+    if let Err(e) = steam_achievements.save_queue() {
+        error!("Failed to save achievement sync queue: {}", e);
+    }
+    sync_panel.panel_dirty = true;
+}
+
+pub fn achievement_sync_panel_toggle_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut sync_panel: ResMut<AchievementSyncPanel>,
+) {
+    if keyboard.just_pressed(KeyCode::F7) {
+        sync_panel.is_open = !sync_panel.is_open;
+        sync_panel.panel_dirty = true;
+        info!("Achievement sync panel {}", if sync_panel.is_open { "opened" } else { "closed" });
+    }
+}
+
+pub fn achievement_sync_retry_button_system(
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<AchievementSyncRetryButton>)>,
+    mut steam_achievements: ResMut<SteamAchievements>,
+) {
+    for interaction in &interaction_query {
+        if *interaction == Interaction::Pressed {
+            for pending in &mut steam_achievements.sync_pending {
+                pending.next_attempt = 0.0;
+            }
+        }
+    }
+}
+
+pub fn refresh_achievement_sync_panel_system(
+    mut commands: Commands,
+    mut sync_panel: ResMut<AchievementSyncPanel>,
+    steam_achievements: Res<SteamAchievements>,
+    steam_state: Res<SteamState>,
+    panel_query: Query<Entity, With<AchievementSyncPanelRoot>>,
+    time: Res<Time<Real>>,
+    mut since_refresh: Local<f32>,
+) {
+    // Retry countdowns tick down, so an open panel redraws once a second
+    *since_refresh += time.delta_secs();
+    let countdown_due = sync_panel.is_open && *since_refresh >= 1.0;
+    if !sync_panel.panel_dirty && !countdown_due {
+        return;
+    }
+    sync_panel.panel_dirty = false;
+    *since_refresh = 0.0;
+    
+    for panel in &panel_query {
+        commands.entity(panel).despawn();
+    }
+    if !sync_panel.is_open {
+        return;
+    }
+    
+    let now = time.elapsed_secs_f64();
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(10.0),
+            top: Val::Px(60.0),
+            width: Val::Px(420.0),
+            flex_direction: FlexDirection::Column,
+            padding: UiRect::all(Val::Px(10.0)),
+            row_gap: Val::Px(4.0),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.95, 0.92, 0.88, 0.95)),
+        BorderRadius::all(Val::Px(6.0)),
+        GlobalZIndex(40),
+        AchievementSyncPanelRoot,
+        Name::new("AchievementSyncPanel"),
+    )).with_children(|panel| {
+        panel.spawn(Node {
+            width: Val::Percent(100.0),
+            justify_content: JustifyContent::SpaceBetween,
+            align_items: AlignItems::Center,
+            ..default()
+        }).with_children(|header| {
+            header.spawn((
+                Text::new(format!("Achievement Sync  (Steam {})", if steam_state.is_connected { "online" } else { "offline" })),
+                TextFont { font_size: 16.0, ..default() },
+                TextColor(Color::srgb(0.3, 0.2, 0.1)),
+            ));
+            header.spawn((
+                Button,
+                Node {
+                    padding: UiRect::axes(Val::Px(6.0), Val::Px(2.0)),
+                    ..default()
+                },
+                BackgroundColor(Color::srgb(0.6, 0.5, 0.4)),
+                BorderRadius::all(Val::Px(4.0)),
+                AchievementSyncRetryButton,
+            )).with_children(|button| {
+                button.spawn((
+                    Text::new("Retry now"),
+                    TextFont { font_size: 10.0, ..default() },
+                    TextColor(Color::WHITE),
+                ));
+            });
+        });
+        
+        if steam_achievements.sync_pending.is_empty() {
+            panel.spawn((
+                Text::new("No pending syncs"),
+                TextFont { font_size: 12.0, ..default() },
+                TextColor(Color::srgb(0.5, 0.4, 0.3)),
+            ));
+        }
+        
+        for pending in &steam_achievements.sync_pending {
+            let status = match &pending.last_error {
+                Some(error) => format!("{} attempts, retry in {:.0}s - {}", pending.attempts, (pending.next_attempt - now).max(0.0), error),
+                None => "waiting for first attempt".to_string(),
+            };
+            panel.spawn((
+                Text::new(format!("{}  -  {}", pending.achievement_id, status)),
+                TextFont { font_size: 11.0, ..default() },
+                TextColor(Color::srgb(0.3, 0.2, 0.1)),
+            ));
+        }
+    });
+}
+
 pub fn steam_stats_tracking_system(
     mut photo_events: EventReader<PhotoTakenEvent>,
     mut steam_stats: ResMut<SteamStats>,
@@ -388,9 +569,145 @@ pub fn steam_stats_tracking_system(
     }
 }
 
+pub fn steam_rich_presence_system(
+    mut presence: ResMut<SteamRichPresence>,
+    steam_state: Res<SteamState>,
+    app_state: Res<State<AppState>>,
+    photo_mode: Res<PhotoModeSettings>,
+    weather: Res<WeatherState>,
+    radar: Res<PrecipitationRadar>,
+    discovered_species: Res<DiscoveredSpecies>,
+) {
+    if !steam_state.is_connected {
+        return;
+    }
+    
+    let context_changed = app_state.is_changed() || photo_mode.is_changed() || weather.is_changed()
+        || radar.is_changed() || discovered_species.is_changed();
+    if !context_changed {
+        return;
+    }
+    
+    let activity = match app_state.get() {
+        AppState::Playing if photo_mode.is_active => "Photographing",
+        AppState::Playing => "Birdwatching",
+        AppState::Journal => "Reading the field journal",
+        AppState::Catalog => "Shopping for the sanctuary",
+        _ => "In the menus",
+    };
+    let status = if matches!(app_state.get(), AppState::Playing) {
+        let storm_overhead = radar.cells.iter()
+            .any(|cell| cell.kind == PrecipitationKind::Thunderstorm && cell.covers_sanctuary_in(0.0));
+        let conditions = if storm_overhead {
+            "in a thunderstorm"
+        } else {
+            match weather.current_weather {
+                Weather::Clear => "on a clear day",
+                Weather::Cloudy => "under grey skies",
+                Weather::Rainy => "in the rain",
+                Weather::Snowy => "in the snow",
+                Weather::Windy => "in the wind",
+                Weather::Foggy => "in the fog",
+            }
+        };
+        format!("{} {} - {} species discovered", activity, conditions, discovered_species.0.len())
+    } else {
+        activity.to_string()
+    };
+    
+    // Steam rate-limits presence updates, so only real changes are sent
+    if status == presence.status {
+        return;
+    }
+    if let Err(e) = set_steam_rich_presence("status", &status, &steam_state) {
+        warn!("Failed to update Steam rich presence: {}", e);
+        return;
+    }
+    presence.status = status;
+}
+
+// Stands in for the GameOverlayActivated callback until the steamworks client is initialized;
+// Shift+Tab is Steam's default overlay hotkey
+pub fn steam_overlay_hotkey_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    overlay: Res<SteamOverlay>,
+    steam_state: Res<SteamState>,
+    mut overlay_events: EventWriter<SteamOverlayEvent>,
+) {
+    if !steam_state.is_connected {
+        return;
+    }
+    
+    // In production, this would use steamworks API:
+    // client.register_callback(|overlay: steamworks::GameOverlayActivated| { ... overlay.active ... });
+    let shift = keyboard.pressed(KeyCode::ShiftLeft) || keyboard.pressed(KeyCode::ShiftRight);
+    if shift && keyboard.just_pressed(KeyCode::Tab) {
+        overlay_events.write(SteamOverlayEvent { active: !overlay.active });
+    }
+}
+
+pub fn steam_overlay_pause_system(
+    mut overlay_events: EventReader<SteamOverlayEvent>,
+    mut overlay: ResMut<SteamOverlay>,
+    mut time: ResMut<Time<Virtual>>,
+    mut keyboard: ResMut<ButtonInput<KeyCode>>,
+    mut mouse: ResMut<ButtonInput<MouseButton>>,
+) {
+    for event in overlay_events.read() {
+        if event.active == overlay.active {
+            continue;
+        }
+        overlay.active = event.active;
+        
+        if event.active {
+            if !time.is_paused() {
+                time.pause();
+                overlay.paused_time = true;
+            }
+            info!("Steam overlay opened, sanctuary paused");
+        } else {
+            if overlay.paused_time {
+                time.unpause();
+                overlay.paused_time = false;
+            }
+            info!("Steam overlay closed, sanctuary resumed");
+        }
+        
+        // Keys held as the overlay opened never see their release, so nothing stays stuck down
+        keyboard.reset_all();
+        mouse.reset_all();
+    }
+}
+
+// Switches the controller to the action set for the current screen, so Steam shows the right
+// layout and glyphs for it. Without Steam, keybindings maps controllers with the same sets.
+pub fn steam_input_action_set_system(
+    mut input_state: ResMut<SteamInputState>,
+    steam_state: Res<SteamState>,
+    app_state: Res<State<AppState>>,
+    photo_mode: Res<PhotoModeSettings>,
+) {
+    if !steam_state.is_connected {
+        return;
+    }
+    
+    let sets = ActionSet::active(app_state.get(), photo_mode.is_active);
+    if input_state.active_sets == sets {
+        return;
+    }
+    
+    if let Err(e) = activate_steam_action_sets(sets, &steam_state) {
+        warn!("Failed to activate Steam Input action sets: {}", e);
+        return;
+    }
+    input_state.active_sets = sets.to_vec();
+}
+
 pub fn load_workshop_content(
     mut commands: Commands,
     steam_state: Res<SteamState>,
+    mut species_packs: ResMut<SpeciesPackManager>,
+    asset_server: Res<AssetServer>,
 ) {
     info!("Loading workshop content at startup...");
     
@@ -399,7 +716,7 @@ pub fn load_workshop_content(
     
     for item in workshop_items {
         if validate_workshop_content(&item) {
-            integrate_workshop_item(&mut commands, item);
+            integrate_workshop_item(&mut commands, &mut species_packs, &asset_server, item);
         } else {
             warn!("Invalid workshop item: {}", item.title);
         }
@@ -408,7 +725,12 @@ pub fn load_workshop_content(
     info!("Workshop content loading complete");
 }
 
-fn integrate_workshop_item(_commands: &mut Commands, item: WorkshopItem) {
+fn integrate_workshop_item(
+    _commands: &mut Commands,
+    species_packs: &mut SpeciesPackManager,
+    asset_server: &AssetServer,
+    item: WorkshopItem,
+) {
     match item.item_type {
         WorkshopItemType::CustomBird { species_name: _, behavior_data: _ } => {
             info!("Loading custom bird: {} by {}", item.title, item.author);
@@ -431,6 +753,11 @@ fn integrate_workshop_item(_commands: &mut Commands, item: WorkshopItem) {
             // In production: update environment asset registry
             info!("Habitat theme '{}' successfully integrated", item.title);
         },
+        WorkshopItemType::SpeciesPack { pack_path } => {
+            info!("Loading species pack: {} by {}", item.title, item.author);
+            // Merged into the regional rosters once the asset finishes loading
+            species_packs.request(asset_server, pack_path);
+        },
     }
 }
 
@@ -529,6 +856,9 @@ pub fn validate_workshop_content(item: &WorkshopItem) -> bool {
             // Validate habitat data
             !theme_name.is_empty() && !assets.is_empty() && assets.len() < 20
         },
+        WorkshopItemType::SpeciesPack { pack_path } => {
+            pack_path.ends_with(".pack.ron")
+        },
     }
 }
 
@@ -564,6 +894,37 @@ fn update_steam_stat(stat_name: &str, value: u64, steam_state: &SteamState) -> R
     Ok(())
 }
 
+fn set_steam_rich_presence(key: &str, value: &str, steam_state: &SteamState) -> Result<(), String> {
+    if !steam_state.is_connected {
+        return Err("Steam not connected".to_string());
+    }
+    
+    info!("Steam rich presence: {} = {}", key, value);
+    
+    // In production, this would use steamworks API:
+    // client.friends().set_rich_presence(key, Some(value));
+    
+    Ok(())
+}
+
+fn activate_steam_action_sets(sets: &[ActionSet], steam_state: &SteamState) -> Result<(), String> {
+    if !steam_state.is_connected {
+        return Err("Steam not connected".to_string());
+    }
+    
+    let (base, layers) = sets.split_last().ok_or("No action set to activate")?;
+    info!("Steam Input action set: {} (layers: {:?})", base.steam_name(), layers.iter().map(|set| set.steam_name()).collect::<Vec<_>>());
+    
+    // In production, this would use steamworks API:
+    // let input = client.input();
+    // input.activate_action_set_handle(ALL_CONTROLLERS, input.get_action_set_handle(base.steam_name()));
+    // input.deactivate_all_action_set_layers(ALL_CONTROLLERS);
+    // for layer in layers { input.activate_action_set_layer(ALL_CONTROLLERS, input.get_action_set_handle(layer.steam_name())); }
+    // Digital actions are then read per GameAction with get_digital_action_handle(action.steam_action_name())
+    
+    Ok(())
+}
+
 fn sync_steam_leaderboards(score: u64, category: &str) -> Result<(), String> {
     if std::env::var("STEAM_OFFLINE").is_ok() {
         return Err("Steam offline mode".to_string());
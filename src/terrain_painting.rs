@@ -0,0 +1,451 @@
+// Terrain Painting - Grass, mulch, gravel and flowerbed ground cover painted onto a chunked grid
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use crate::catalog::resources::PlayerInventory;
+use crate::garden_lines::LineDrawing;
+use crate::keybindings::{GameAction, KeyBindings};
+use crate::notifications::{resources::ShowNotificationEvent, components::NotificationType};
+use crate::photo_mode::resources::PhotoModeSettings;
+use crate::camera::cursor_world_position;
+
+const CELL_SIZE: f32 = 20.0;
+const CHUNK_CELLS: i32 = 16;
+// Ground stops at the distant treeline
+const GROUND_TOP: f32 = 150.0;
+const GROUND_BOTTOM: f32 = -450.0;
+const GROUND_HALF_WIDTH: f32 = 900.0;
+// Under paths, feeders and everything else placed in the garden
+const TERRAIN_Z: f32 = 0.02;
+const BRUSH_SIZES: [i32; 4] = [1, 2, 3, 5];
+// Painted cells needed to count as much as one habitat planting
+const CELLS_PER_PLANTING: f32 = 40.0;
+
+pub struct TerrainPaintingPlugin;
+
+impl Plugin for TerrainPaintingPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<TerrainMap>()
+            .init_resource::<TerrainBrush>()
+            .add_systems(Update, (
+                terrain_tool_toggle_system.run_if(crate::debug_console::console_is_not_visible),
+                terrain_paint_system,
+                terrain_brush_preview_system,
+                render_terrain_chunks_system,
+            ).chain().in_set(crate::GameSet::UI).run_if(in_state(crate::AppState::Playing)));
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TerrainType {
+    Grass,
+    Mulch,
+    Gravel,
+    Flowerbed,
+}
+
+impl TerrainType {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Grass => "Grass",
+            Self::Mulch => "Mulch",
+            Self::Gravel => "Gravel",
+            Self::Flowerbed => "Flowerbed",
+        }
+    }
+
+    fn color(&self) -> Color {
+        match self {
+            Self::Grass => Color::srgb(0.35, 0.6, 0.25),
+            Self::Mulch => Color::srgb(0.4, 0.28, 0.18),
+            Self::Gravel => Color::srgb(0.68, 0.66, 0.6),
+            Self::Flowerbed => Color::srgb(0.45, 0.32, 0.25),
+        }
+    }
+
+    pub fn cell_cost(&self) -> u32 {
+        match self {
+            Self::Grass => 0,
+            Self::Mulch | Self::Gravel => 1,
+            Self::Flowerbed => 2,
+        }
+    }
+
+    // How rewarding the ground is for birds that forage on it, unpainted ground counts as 1.0
+    pub fn foraging_suitability(&self) -> f32 {
+        match self {
+            Self::Grass => 1.0,
+            Self::Mulch => 1.4,     // Leaf litter full of insects
+            Self::Gravel => 0.4,
+            Self::Flowerbed => 1.2, // Fallen seed
+        }
+    }
+
+    // Contribution to how planted the garden looks
+    pub fn appeal(&self) -> f32 {
+        match self {
+            Self::Grass => 0.2,
+            Self::Mulch => 0.4,
+            Self::Gravel => 0.3,
+            Self::Flowerbed => 1.0,
+        }
+    }
+
+    // Off, each ground type in turn, then the eraser
+    fn next(tool: Option<BrushTool>) -> Option<BrushTool> {
+        match tool {
+            None => Some(BrushTool::Paint(Self::Grass)),
+            Some(BrushTool::Paint(Self::Grass)) => Some(BrushTool::Paint(Self::Mulch)),
+            Some(BrushTool::Paint(Self::Mulch)) => Some(BrushTool::Paint(Self::Gravel)),
+            Some(BrushTool::Paint(Self::Gravel)) => Some(BrushTool::Paint(Self::Flowerbed)),
+            Some(BrushTool::Paint(Self::Flowerbed)) => Some(BrushTool::Erase),
+            Some(BrushTool::Erase) => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BrushTool {
+    Paint(TerrainType),
+    Erase,
+}
+
+impl BrushTool {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Paint(terrain) => terrain.name(),
+            Self::Erase => "Eraser",
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TerrainChunk {
+    pub cells: Vec<Option<TerrainType>>,
+}
+
+impl Default for TerrainChunk {
+    fn default() -> Self {
+        Self {
+            cells: vec![None; (CHUNK_CELLS * CHUNK_CELLS) as usize],
+        }
+    }
+}
+
+impl TerrainChunk {
+    fn is_empty(&self) -> bool {
+        self.cells.iter().all(|cell| cell.is_none())
+    }
+}
+
+fn cell_at(position: Vec2) -> IVec2 {
+    (position / CELL_SIZE).floor().as_ivec2()
+}
+
+fn chunk_of(cell: IVec2) -> [i32; 2] {
+    [cell.x.div_euclid(CHUNK_CELLS), cell.y.div_euclid(CHUNK_CELLS)]
+}
+
+fn cell_index(cell: IVec2) -> usize {
+    (cell.y.rem_euclid(CHUNK_CELLS) * CHUNK_CELLS + cell.x.rem_euclid(CHUNK_CELLS)) as usize
+}
+
+fn cell_center(cell: IVec2) -> Vec2 {
+    (cell.as_vec2() + Vec2::splat(0.5)) * CELL_SIZE
+}
+
+fn on_ground(cell: IVec2) -> bool {
+    let center = cell_center(cell);
+    center.x.abs() < GROUND_HALF_WIDTH && (GROUND_BOTTOM..GROUND_TOP).contains(&center.y)
+}
+
+// Resources
+
+// Saved with the sanctuary, only chunks with something painted are kept
+#[derive(Resource, Serialize, Deserialize, Clone, Debug, Default)]
+pub struct TerrainMap {
+    pub chunks: HashMap<[i32; 2], TerrainChunk>,
+    #[serde(skip)]
+    dirty_chunks: HashSet<[i32; 2]>,
+    #[serde(skip)]
+    rebuild_all: bool,
+}
+
+impl TerrainMap {
+    pub fn terrain_at(&self, position: Vec2) -> Option<TerrainType> {
+        let cell = cell_at(position);
+        self.chunks.get(&chunk_of(cell)).and_then(|chunk| chunk.cells[cell_index(cell)])
+    }
+
+    pub fn foraging_suitability(&self, position: Vec2) -> f32 {
+        self.terrain_at(position).map_or(1.0, |terrain| terrain.foraging_suitability())
+    }
+
+    // Painted ground expressed as a number of habitat plantings
+    pub fn planting_value(&self) -> f32 {
+        let appeal: f32 = self.chunks.values()
+            .flat_map(|chunk| chunk.cells.iter().flatten())
+            .map(|terrain| terrain.appeal())
+            .sum();
+        appeal / CELLS_PER_PLANTING
+    }
+
+    // Returns the previous terrain if the cell changed
+    fn set(&mut self, cell: IVec2, terrain: Option<TerrainType>) -> Option<Option<TerrainType>> {
+        let coord = chunk_of(cell);
+        let index = cell_index(cell);
+        let previous = self.chunks.get(&coord).and_then(|chunk| chunk.cells[index]);
+        if previous == terrain {
+            return None;
+        }
+
+        let chunk = self.chunks.entry(coord).or_default();
+        chunk.cells[index] = terrain;
+        if chunk.is_empty() {
+            self.chunks.remove(&coord);
+        }
+        self.dirty_chunks.insert(coord);
+        Some(previous)
+    }
+
+    // After a load every chunk on screen is thrown away and drawn again
+    pub fn mark_all_dirty(&mut self) {
+        self.rebuild_all = true;
+    }
+}
+
+#[derive(Resource)]
+pub struct TerrainBrush {
+    pub tool: Option<BrushTool>,
+    size_index: usize,
+}
+
+impl Default for TerrainBrush {
+    fn default() -> Self {
+        Self {
+            tool: None,
+            size_index: 1,
+        }
+    }
+}
+
+impl TerrainBrush {
+    pub fn put_away(&mut self) {
+        self.tool = None;
+    }
+
+    fn radius(&self) -> i32 {
+        BRUSH_SIZES[self.size_index]
+    }
+
+    // Cells under a round brush centred on the cursor cell
+    fn cells(&self, center: IVec2) -> impl Iterator<Item = IVec2> {
+        let radius = self.radius() - 1;
+        (-radius..=radius)
+            .flat_map(move |y| (-radius..=radius).map(move |x| IVec2::new(x, y)))
+            .filter(move |offset| offset.length_squared() <= radius * radius + radius)
+            .map(move |offset| center + offset)
+    }
+}
+
+// Components
+#[derive(Component)]
+pub struct TerrainChunkSprite {
+    pub coord: [i32; 2],
+}
+
+#[derive(Component)]
+pub struct TerrainBrushLabel;
+
+fn terrain_tool_toggle_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    key_bindings: Res<KeyBindings>,
+    photo_settings: Res<PhotoModeSettings>,
+    mut brush: ResMut<TerrainBrush>,
+    mut line_drawing: ResMut<LineDrawing>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+) {
+    if photo_settings.is_active {
+        return;
+    }
+
+    if key_bindings.is_action_just_pressed(GameAction::PaintTerrain, &keyboard, &mouse) {
+        let was_off = brush.tool.is_none();
+        brush.tool = TerrainType::next(brush.tool);
+        if was_off && brush.tool.is_some() {
+            line_drawing.put_away();
+            notification_events.write(ShowNotificationEvent {
+                notification: NotificationType::Info {
+                    message: "Terrain painting: hold left-click to paint, [ and ] change the brush size".to_string(),
+                },
+            });
+        }
+    }
+
+    if brush.tool.is_none() {
+        return;
+    }
+    if keyboard.just_pressed(KeyCode::BracketLeft) {
+        brush.size_index = brush.size_index.saturating_sub(1);
+    }
+    if keyboard.just_pressed(KeyCode::BracketRight) {
+        brush.size_index = (brush.size_index + 1).min(BRUSH_SIZES.len() - 1);
+    }
+    if mouse.just_pressed(MouseButton::Right) {
+        brush.put_away();
+    }
+}
+
+fn terrain_paint_system(
+    mouse_button: Res<ButtonInput<MouseButton>>,
+    windows: Query<&Window>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<Camera2d>>,
+    brush: Res<TerrainBrush>,
+    mut terrain: ResMut<TerrainMap>,
+    mut inventory: ResMut<PlayerInventory>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+    mut warned: Local<bool>,
+) {
+    let Some(tool) = brush.tool else { return };
+    if !mouse_button.pressed(MouseButton::Left) {
+        *warned = false;
+        return;
+    }
+
+    let Ok(window) = windows.single() else { return };
+    let Ok((camera, camera_transform)) = camera_query.single() else { return };
+    let Some(cursor) = cursor_world_position(window, camera, camera_transform) else { return };
+
+    let painted = match tool {
+        BrushTool::Paint(terrain_type) => Some(terrain_type),
+        BrushTool::Erase => None,
+    };
+    let cost = painted.map_or(0, |terrain_type| terrain_type.cell_cost());
+
+    for cell in brush.cells(cell_at(cursor)).filter(|cell| on_ground(*cell)) {
+        if inventory.currency < cost {
+            if !*warned {
+                *warned = true;
+                notification_events.write(ShowNotificationEvent {
+                    notification: NotificationType::Warning {
+                        message: format!("Not enough coins for more {}", tool.name().to_lowercase()),
+                    },
+                });
+            }
+            break;
+        }
+        if terrain.set(cell, painted).is_some() {
+            inventory.currency -= cost;
+        }
+    }
+}
+
+// Brush outline under the cursor and the current tool readout
+fn terrain_brush_preview_system(
+    mut commands: Commands,
+    mut gizmos: Gizmos,
+    brush: Res<TerrainBrush>,
+    windows: Query<&Window>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<Camera2d>>,
+    mut label_query: Query<(Entity, &mut Text), With<TerrainBrushLabel>>,
+) {
+    let Some(tool) = brush.tool else {
+        for (entity, _) in label_query.iter() {
+            commands.entity(entity).despawn();
+        }
+        return;
+    };
+
+    let cursor = windows.single().ok()
+        .zip(camera_query.single().ok())
+        .and_then(|(window, (camera, camera_transform))| cursor_world_position(window, camera, camera_transform));
+    if let Some(cursor) = cursor {
+        let color = match tool {
+            BrushTool::Paint(terrain_type) => terrain_type.color().with_alpha(0.9),
+            BrushTool::Erase => Color::srgba(0.9, 0.3, 0.3, 0.8),
+        };
+        for cell in brush.cells(cell_at(cursor)).filter(|cell| on_ground(*cell)) {
+            gizmos.rect_2d(cell_center(cell), Vec2::splat(CELL_SIZE), color);
+        }
+    }
+
+    let cost = match tool {
+        BrushTool::Paint(terrain_type) => format!("{} per cell", terrain_type.cell_cost()),
+        BrushTool::Erase => "free".to_string(),
+    };
+    let label = format!("{} brush - size {}, {}  ([ ] resize, right-click to stop)", tool.name(), brush.radius(), cost);
+    if let Ok((_, mut text)) = label_query.single_mut() {
+        text.0 = label;
+    } else {
+        commands.spawn((
+            Text::new(label),
+            TextFont { font_size: 14.0, ..default() },
+            TextColor(Color::srgb(0.3, 0.2, 0.1)),
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(60.0),
+                left: Val::Px(20.0),
+                padding: UiRect::all(Val::Px(8.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.95, 0.92, 0.88, 0.9)),
+            TerrainBrushLabel,
+        ));
+    }
+}
+
+fn render_terrain_chunks_system(
+    mut commands: Commands,
+    mut terrain: ResMut<TerrainMap>,
+    chunk_query: Query<(Entity, &TerrainChunkSprite)>,
+) {
+    if !terrain.rebuild_all && terrain.dirty_chunks.is_empty() {
+        return;
+    }
+
+    let dirty: HashSet<[i32; 2]> = if std::mem::take(&mut terrain.rebuild_all) {
+        terrain.dirty_chunks.clear();
+        chunk_query.iter().map(|(_, chunk)| chunk.coord)
+            .chain(terrain.chunks.keys().copied())
+            .collect()
+    } else {
+        std::mem::take(&mut terrain.dirty_chunks)
+    };
+
+    for (entity, chunk) in chunk_query.iter() {
+        if dirty.contains(&chunk.coord) {
+            commands.entity(entity).despawn();
+        }
+    }
+
+    for coord in dirty {
+        let Some(chunk) = terrain.chunks.get(&coord) else { continue };
+        let origin = Vec2::new(coord[0] as f32, coord[1] as f32) * CHUNK_CELLS as f32 * CELL_SIZE;
+
+        commands.spawn((
+            Transform::from_translation(origin.extend(TERRAIN_Z)),
+            Visibility::default(),
+            TerrainChunkSprite { coord },
+            Name::new(format!("TerrainChunk_{}_{}", coord[0], coord[1])),
+        )).with_children(|parent| {
+            for (index, terrain_type) in chunk.cells.iter().enumerate() {
+                let Some(terrain_type) = terrain_type else { continue };
+                let local = IVec2::new(index as i32 % CHUNK_CELLS, index as i32 / CHUNK_CELLS);
+                parent.spawn((
+                    Sprite::from_color(terrain_type.color(), Vec2::splat(CELL_SIZE)),
+                    Transform::from_translation(cell_center(local).extend(0.0)),
+                ));
+
+                // Scattered blooms so flowerbeds read as more than bare soil
+                if *terrain_type == TerrainType::Flowerbed && (local.x + local.y) % 2 == 0 {
+                    parent.spawn((
+                        Sprite::from_color(Color::srgb(0.9, 0.45, 0.65), Vec2::splat(6.0)),
+                        Transform::from_translation(cell_center(local).extend(0.001)),
+                    ));
+                }
+            }
+        });
+    }
+}
@@ -11,7 +11,7 @@ impl Plugin for TooltipPlugin {
                 tooltip_hover_system,
                 tooltip_display_system,
                 tooltip_cleanup_system,
-            ));
+            ).in_set(crate::GameSet::UI));
     }
 }
 
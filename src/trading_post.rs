@@ -0,0 +1,448 @@
+// Trading Post - Swap surplus seed, photos and spare décor for rare items
+use bevy::prelude::*;
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
+use serde::{Deserialize, Serialize};
+use crate::bird::BirdSpecies;
+use crate::catalog::components::PlaceableItemType;
+use crate::catalog::resources::{ItemCategory, PlacedObjects, PlayerInventory};
+use crate::environment::calendar::CalendarEvent;
+use crate::environment::resources::TimeState;
+use crate::journal::resources::ResearchMissionManager;
+use crate::keybindings::{GameAction, KeyBindings};
+use crate::notifications::{resources::ShowNotificationEvent, components::NotificationType};
+use crate::photo_mode::resources::PhotoCollection;
+
+pub struct TradingPostPlugin;
+
+impl Plugin for TradingPostPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<TradingPost>()
+            .add_systems(Update, (
+                restock_trading_post_system,
+                toggle_trading_post_system,
+                trading_post_button_system,
+                refresh_trading_post_panel_system,
+            ).chain().in_set(crate::GameSet::UI).run_if(in_state(crate::AppState::Playing)))
+            .add_systems(OnExit(crate::AppState::Playing), close_trading_post_system);
+    }
+}
+
+// Resources
+#[derive(Resource, Default)]
+pub struct TradingPost {
+    pub stock: Vec<TradeOffer>,
+    // Absolute day the stock was generated for
+    pub stock_day: Option<u32>,
+    pub event: Option<CalendarEvent>,
+    pub is_open: bool,
+    panel_dirty: bool,
+}
+
+impl TradingPost {
+    pub fn sales(&self) -> Option<TradingPostSales> {
+        Some(TradingPostSales {
+            day: self.stock_day?,
+            remaining: self.stock.iter().map(|offer| offer.remaining).collect(),
+        })
+    }
+
+    /// Rebuilds a saved day's stock with only what was left of it
+    pub fn restore_sales(&mut self, sales: TradingPostSales, day_of_year: u32) {
+        let event = CalendarEvent::active_on(day_of_year);
+        self.stock = generate_stock(sales.day, event);
+        for (offer, remaining) in self.stock.iter_mut().zip(sales.remaining) {
+            offer.remaining = offer.remaining.min(remaining);
+        }
+        self.stock_day = Some(sales.day);
+        self.event = event;
+        self.panel_dirty = true;
+    }
+}
+
+// A day's stock regenerates from its seed, so only what's left of each offer is saved
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TradingPostSales {
+    pub day: u32,
+    pub remaining: Vec<u32>,
+}
+
+#[derive(Debug, Clone)]
+pub struct TradeOffer {
+    pub cost: TradeCost,
+    pub reward: TradeReward,
+    pub remaining: u32,
+}
+
+#[derive(Debug, Clone)]
+pub enum TradeCost {
+    Seed { item: PlaceableItemType, amount: u32 },
+    Photo { species: BirdSpecies },
+    SpareDecor { amount: u32 },
+}
+
+#[derive(Debug, Clone)]
+pub enum TradeReward {
+    Item(PlaceableItemType),
+    ResearchPoints(u32),
+}
+
+impl TradeCost {
+    pub fn label(&self) -> String {
+        match self {
+            Self::Seed { item, amount } => format!("{}x {}", amount, item.name()),
+            Self::Photo { species } => format!("A photo of a {:?}", species),
+            Self::SpareDecor { amount } => format!("{} spare décor item{}", amount, if *amount == 1 { "" } else { "s" }),
+        }
+    }
+}
+
+impl TradeReward {
+    pub fn label(&self) -> String {
+        match self {
+            Self::Item(item) => item.name().to_string(),
+            Self::ResearchPoints(points) => format!("{} research points", points),
+        }
+    }
+}
+
+// Components
+#[derive(Component)]
+pub struct TradingPostPanel;
+
+#[derive(Component)]
+pub struct TradeButton {
+    pub offer: usize,
+}
+
+const RARE_ITEMS: [PlaceableItemType; 4] = [
+    PlaceableItemType::FancyPerch,
+    PlaceableItemType::SwingSeat,
+    PlaceableItemType::FountainBirdbath,
+    PlaceableItemType::StreamFeature,
+];
+
+const SURPLUS_SEED: [(PlaceableItemType, u32); 3] = [
+    (PlaceableItemType::BasicBirdSeed, 3),
+    (PlaceableItemType::PremiumSeed, 2),
+    (PlaceableItemType::SuetCake, 3),
+];
+
+// Same day, same stock - the rotation is seeded by the absolute day, so each year has its own
+fn generate_stock(day: u32, event: Option<CalendarEvent>) -> Vec<TradeOffer> {
+    let mut rng = StdRng::seed_from_u64(day as u64);
+    let mut stock = Vec::new();
+
+    let (seed, amount) = SURPLUS_SEED[rng.random_range(0..SURPLUS_SEED.len())].clone();
+    stock.push(TradeOffer {
+        cost: TradeCost::Seed { item: seed, amount },
+        reward: TradeReward::Item(RARE_ITEMS[rng.random_range(0..RARE_ITEMS.len())].clone()),
+        remaining: 1,
+    });
+
+    // Photo requests pay double while a calendar event is running
+    for _ in 0..2 {
+        let species = BirdSpecies::random_with_rarity(rng.random_range(1.0..3.0));
+        let multiplier = if event.is_some() { 2 } else { 1 };
+        let points = species.rarity_tier() as u32 * 25 * multiplier;
+        stock.push(TradeOffer {
+            cost: TradeCost::Photo { species },
+            reward: TradeReward::ResearchPoints(points),
+            remaining: 2,
+        });
+    }
+
+    stock.push(TradeOffer {
+        cost: TradeCost::SpareDecor { amount: rng.random_range(1..=2) },
+        reward: if rng.random_bool(0.5) {
+            TradeReward::ResearchPoints(40)
+        } else {
+            TradeReward::Item(RARE_ITEMS[rng.random_range(0..2)].clone())
+        },
+        remaining: 2,
+    });
+
+    if let Some(event) = event {
+        for item in event.featured_items() {
+            stock.push(TradeOffer {
+                cost: TradeCost::SpareDecor { amount: 1 },
+                reward: TradeReward::Item(item),
+                remaining: 1,
+            });
+        }
+    }
+
+    stock
+}
+
+// Décor the player owns beyond what they need - one of each kind is kept unless it's already placed
+fn spare_decor(inventory: &PlayerInventory, placed: &PlacedObjects) -> Vec<(PlaceableItemType, u32)> {
+    ItemCategory::Comfort.items().into_iter()
+        .chain(ItemCategory::Decorative.items())
+        .filter_map(|item| {
+            let owned = inventory.owned_items.get(&item).copied().unwrap_or(0);
+            let has_placed = placed.objects.values().any(|placed_item| *placed_item == item);
+            let spare = if has_placed { owned } else { owned.saturating_sub(1) };
+            (spare > 0).then_some((item, spare))
+        })
+        .collect()
+}
+
+// Lowest-scoring photo of the species that isn't pinned in an album
+fn tradeable_photo(photos: &PhotoCollection, species: BirdSpecies) -> Option<u32> {
+    photos.get_species_photos(species).into_iter()
+        .filter(|photo| !photos.is_in_album(photo.id))
+        .min_by_key(|photo| photo.score.total_score)
+        .map(|photo| photo.id)
+}
+
+fn can_afford(cost: &TradeCost, inventory: &PlayerInventory, placed: &PlacedObjects, photos: &PhotoCollection) -> bool {
+    match cost {
+        TradeCost::Seed { item, amount } => inventory.owned_items.get(item).copied().unwrap_or(0) >= *amount,
+        TradeCost::Photo { species } => tradeable_photo(photos, *species).is_some(),
+        TradeCost::SpareDecor { amount } => {
+            spare_decor(inventory, placed).iter().map(|(_, spare)| spare).sum::<u32>() >= *amount
+        }
+    }
+}
+
+pub fn restock_trading_post_system(
+    mut trading_post: ResMut<TradingPost>,
+    time_state: Res<TimeState>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+) {
+    let day = time_state.absolute_day();
+    if trading_post.stock_day == Some(day) {
+        return;
+    }
+
+    let event = CalendarEvent::active_on(time_state.day_of_year);
+    let first_stock = trading_post.stock_day.is_none();
+    trading_post.stock = generate_stock(day, event);
+    trading_post.stock_day = Some(day);
+    trading_post.panel_dirty = true;
+
+    // Announce only when an event starts, not on every daily rotation
+    if let Some(started) = event.filter(|_| !first_stock && event != trading_post.event) {
+        notification_events.write(ShowNotificationEvent {
+            notification: NotificationType::Info {
+                message: format!("{} has begun - the trading post has special stock!", started.name()),
+            },
+        });
+    }
+    trading_post.event = event;
+    info!("🏪 Trading post restocked for day {} ({} offers)", day, trading_post.stock.len());
+}
+
+pub fn toggle_trading_post_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    key_bindings: Res<KeyBindings>,
+    mut trading_post: ResMut<TradingPost>,
+) {
+    if key_bindings.is_action_just_pressed(GameAction::OpenTradingPost, &keyboard, &mouse) {
+        trading_post.is_open = !trading_post.is_open;
+        trading_post.panel_dirty = true;
+    }
+}
+
+pub fn trading_post_button_system(
+    mut button_query: Query<(&Interaction, &TradeButton, &mut BackgroundColor), Changed<Interaction>>,
+    mut trading_post: ResMut<TradingPost>,
+    mut inventory: ResMut<PlayerInventory>,
+    placed_objects: Res<PlacedObjects>,
+    mut photos: ResMut<PhotoCollection>,
+    mut research_manager: ResMut<ResearchMissionManager>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+) {
+    for (interaction, button, mut bg_color) in &mut button_query {
+        match *interaction {
+            Interaction::Pressed => {
+                let Some(offer) = trading_post.stock.get(button.offer).cloned() else { continue };
+                if offer.remaining == 0 || !can_afford(&offer.cost, &inventory, &placed_objects, &photos) {
+                    notification_events.write(ShowNotificationEvent {
+                        notification: NotificationType::Warning {
+                            message: format!("You need {} for this trade", offer.cost.label().to_lowercase()),
+                        },
+                    });
+                    continue;
+                }
+
+                match &offer.cost {
+                    TradeCost::Seed { item, amount } => {
+                        if let Some(count) = inventory.owned_items.get_mut(item) {
+                            *count -= amount;
+                        }
+                    }
+                    TradeCost::Photo { species } => {
+                        if let Some(id) = tradeable_photo(&photos, *species) {
+                            photos.remove_photo(id);
+                        }
+                    }
+                    TradeCost::SpareDecor { amount } => {
+                        let mut needed = *amount;
+                        for (item, spare) in spare_decor(&inventory, &placed_objects) {
+                            let taken = spare.min(needed);
+                            if let Some(count) = inventory.owned_items.get_mut(&item) {
+                                *count -= taken;
+                            }
+                            needed -= taken;
+                            if needed == 0 {
+                                break;
+                            }
+                        }
+                    }
+                }
+
+                match &offer.reward {
+                    TradeReward::Item(item) => {
+                        *inventory.owned_items.entry(item.clone()).or_insert(0) += 1;
+                    }
+                    TradeReward::ResearchPoints(points) => {
+                        research_manager.research_points += points;
+                    }
+                }
+
+                trading_post.stock[button.offer].remaining -= 1;
+                trading_post.panel_dirty = true;
+                notification_events.write(ShowNotificationEvent {
+                    notification: NotificationType::Info {
+                        message: format!("Traded {} for {}", offer.cost.label().to_lowercase(), offer.reward.label()),
+                    },
+                });
+            }
+            Interaction::Hovered => *bg_color = Color::srgb(0.7, 0.6, 0.5).into(),
+            Interaction::None => *bg_color = Color::srgb(0.6, 0.5, 0.4).into(),
+        }
+    }
+}
+
+// Rebuilds the panel on restock, after trades, and when holdings change while it's open
+pub fn refresh_trading_post_panel_system(
+    mut commands: Commands,
+    mut trading_post: ResMut<TradingPost>,
+    inventory: Res<PlayerInventory>,
+    placed_objects: Res<PlacedObjects>,
+    photos: Res<PhotoCollection>,
+    time_state: Res<TimeState>,
+    panel_query: Query<Entity, With<TradingPostPanel>>,
+) {
+    if trading_post.is_open && (inventory.is_changed() || photos.is_changed()) {
+        trading_post.panel_dirty = true;
+    }
+    if !trading_post.panel_dirty {
+        return;
+    }
+    trading_post.panel_dirty = false;
+
+    for panel in &panel_query {
+        commands.entity(panel).despawn();
+    }
+
+    if trading_post.is_open {
+        spawn_trading_post_panel(&mut commands, &trading_post, &inventory, &placed_objects, &photos, time_state.day_of_year);
+    }
+}
+
+pub fn close_trading_post_system(
+    mut commands: Commands,
+    mut trading_post: ResMut<TradingPost>,
+    panel_query: Query<Entity, With<TradingPostPanel>>,
+) {
+    trading_post.is_open = false;
+    for panel in &panel_query {
+        commands.entity(panel).despawn();
+    }
+}
+
+fn spawn_trading_post_panel(
+    commands: &mut Commands,
+    trading_post: &TradingPost,
+    inventory: &PlayerInventory,
+    placed_objects: &PlacedObjects,
+    photos: &PhotoCollection,
+    day: u32,
+) {
+    let calendar_line = match trading_post.event {
+        Some(event) => format!("🎉 {} - featured stock available", event.name()),
+        None => {
+            let (event, days_until) = CalendarEvent::next_after(day);
+            format!("Next event: {} in {} day{}", event.name(), days_until, if days_until == 1 { "" } else { "s" })
+        }
+    };
+
+    commands.spawn((
+        Node {
+            width: Val::Px(420.0),
+            position_type: PositionType::Absolute,
+            left: Val::Px(20.0),
+            top: Val::Px(80.0),
+            flex_direction: FlexDirection::Column,
+            padding: UiRect::all(Val::Px(12.0)),
+            row_gap: Val::Px(6.0),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.95, 0.92, 0.88, 0.95)),
+        BorderRadius::all(Val::Px(8.0)),
+        TradingPostPanel,
+    )).with_children(|panel| {
+        panel.spawn((
+            Text::new("Trading Post"),
+            TextFont { font_size: 18.0, ..default() },
+            TextColor(Color::srgb(0.3, 0.2, 0.1)),
+        ));
+
+        panel.spawn((
+            Text::new(format!("Stock refreshes daily  •  {}", calendar_line)),
+            TextFont { font_size: 12.0, ..default() },
+            TextColor(Color::srgb(0.5, 0.4, 0.3)),
+        ));
+
+        for (index, offer) in trading_post.stock.iter().enumerate() {
+            let available = offer.remaining > 0;
+            let affordable = available && can_afford(&offer.cost, inventory, placed_objects, photos);
+
+            panel.spawn(Node {
+                width: Val::Percent(100.0),
+                flex_direction: FlexDirection::Row,
+                justify_content: JustifyContent::SpaceBetween,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(8.0),
+                ..default()
+            }).with_children(|row| {
+                row.spawn((
+                    Text::new(format!("{} → {}  ({} left)", offer.cost.label(), offer.reward.label(), offer.remaining)),
+                    TextFont { font_size: 13.0, ..default() },
+                    TextColor(if affordable { Color::srgb(0.3, 0.2, 0.1) } else { Color::srgb(0.6, 0.55, 0.5) }),
+                    Node { flex_grow: 1.0, ..default() },
+                ));
+
+                let mut button = row.spawn((
+                    Button,
+                    Node {
+                        width: Val::Px(70.0),
+                        height: Val::Px(26.0),
+                        flex_shrink: 0.0,
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    BackgroundColor(if available { Color::srgb(0.6, 0.5, 0.4) } else { Color::srgb(0.85, 0.82, 0.78) }),
+                    BorderRadius::all(Val::Px(4.0)),
+                ));
+                button.with_children(|button| {
+                    button.spawn((
+                        Text::new(if available { "Trade" } else { "Sold out" }),
+                        TextFont { font_size: 12.0, ..default() },
+                        TextColor(Color::WHITE),
+                    ));
+                });
+
+                if available {
+                    button.insert(TradeButton { offer: index });
+                }
+            });
+        }
+    });
+}
@@ -0,0 +1,283 @@
+// Tripod - Fixed cameras composed in the garden and fired remotely when birds step into frame
+use bevy::prelude::*;
+use bevy::render::camera::{ImageRenderTarget, RenderTarget};
+use crate::animation::components::AnimatedBird;
+use crate::bird_ai::components::{BirdAI, BirdState};
+use crate::catalog::components::{PlaceableObject, PlaceableItemType};
+use crate::environment::resources::TimeState;
+use crate::keybindings::{ActiveInputDevice, GameAction, KeyBindings};
+use crate::notifications::{resources::ShowNotificationEvent, components::NotificationType};
+use crate::photo_mode::components::{CameraControls, PhotoTakenEvent};
+use crate::photo_mode::systems::new_photo_texture;
+use crate::camera::cursor_world_position;
+
+// How far from a tripod its frame can be aimed
+const AIM_RANGE: f32 = 400.0;
+const DEFAULT_FRAME_OFFSET: Vec2 = Vec2::new(0.0, 90.0);
+// Locked-off shots come out sharper than handheld ones
+const STEADINESS_BONUS: u32 = 20;
+// Photo scoring works in the 800x600 space of a handheld shot
+const PHOTO_WIDTH: f32 = 800.0;
+
+pub struct TripodPlugin;
+
+impl Plugin for TripodPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_systems(Update, (
+                attach_tripod_system,
+                tripod_render_cleanup_system,
+                tripod_compose_system,
+                remote_shutter_system.run_if(crate::debug_console::console_is_not_visible),
+                tripod_frame_gizmo_system,
+            ).chain().in_set(crate::GameSet::Presentation).run_if(in_state(crate::AppState::Playing)));
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TripodFraming {
+    Wide,
+    Standard,
+    Tight,
+}
+
+impl TripodFraming {
+    pub fn size(&self) -> Vec2 {
+        match self {
+            Self::Wide => Vec2::new(360.0, 270.0),
+            Self::Standard => Vec2::new(240.0, 180.0),
+            Self::Tight => Vec2::new(150.0, 112.5),
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Wide => "wide",
+            Self::Standard => "standard",
+            Self::Tight => "tight",
+        }
+    }
+
+    fn next(&self) -> Self {
+        match self {
+            Self::Wide => Self::Standard,
+            Self::Standard => Self::Tight,
+            Self::Tight => Self::Wide,
+        }
+    }
+
+    // Lens settings the scoring model sees for this framing
+    fn camera_controls(&self) -> CameraControls {
+        CameraControls {
+            zoom_level: PHOTO_WIDTH / self.size().x,
+            min_zoom: 0.5,
+            max_zoom: 5.0,
+            zoom_speed: 0.0,
+            focus_distance: 0.0,
+            aperture: 8.0,
+            exposure: 0.0,
+            iso: 200.0,
+        }
+    }
+}
+
+// Components
+#[derive(Component)]
+pub struct Tripod {
+    pub frame_center: Vec2,
+    pub framing: TripodFraming,
+    pub shots_taken: u32,
+}
+
+impl Tripod {
+    pub fn frame_contains(&self, position: Vec2) -> bool {
+        let half = self.framing.size() * 0.5;
+        let offset = (position - self.frame_center).abs();
+        offset.x <= half.x && offset.y <= half.y
+    }
+}
+
+// Offscreen camera that exists for the single frame a remote shot takes to render
+#[derive(Component)]
+pub struct TripodRenderCamera;
+
+fn attach_tripod_system(
+    mut commands: Commands,
+    object_query: Query<(Entity, &PlaceableObject, &Transform), (Added<PlaceableObject>, Without<Tripod>)>,
+    key_bindings: Res<KeyBindings>,
+    input_device: Res<ActiveInputDevice>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+) {
+    for (entity, object, transform) in &object_query {
+        if object.item_type != PlaceableItemType::TripodCamera {
+            continue;
+        }
+
+        commands.entity(entity).insert(Tripod {
+            frame_center: transform.translation.truncate() + DEFAULT_FRAME_OFFSET,
+            framing: TripodFraming::Standard,
+            shots_taken: 0,
+        });
+        notification_events.write(ShowNotificationEvent {
+            notification: NotificationType::Info {
+                message: format!(
+                    "Tripod set up. Shift + right-click to aim it, right-click it to change framing, {} to fire",
+                    key_bindings.prompt_label(GameAction::RemoteShutter, *input_device),
+                ),
+            },
+        });
+    }
+}
+
+fn tripod_render_cleanup_system(
+    mut commands: Commands,
+    render_camera_query: Query<Entity, With<TripodRenderCamera>>,
+) {
+    for entity in render_camera_query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
+
+// The player's view of the yard, not a tripod's own render camera
+type YardCameraQuery<'w, 's> = Query<'w, 's, (&'static Camera, &'static GlobalTransform), (With<Camera2d>, Without<TripodRenderCamera>)>;
+
+// Right-click a tripod to cycle its framing, Shift + right-click elsewhere to aim the nearest one there
+fn tripod_compose_system(
+    mouse_button: Res<ButtonInput<MouseButton>>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    windows: Query<&Window>,
+    camera_query: YardCameraQuery,
+    mut tripod_query: Query<(&mut Tripod, &Transform), Without<Camera2d>>,
+) {
+    if !mouse_button.just_pressed(MouseButton::Right) {
+        return;
+    }
+
+    let Ok(window) = windows.single() else { return };
+    let Ok((camera, camera_transform)) = camera_query.single() else { return };
+    let Some(world_position) = cursor_world_position(window, camera, camera_transform) else { return };
+
+    let aiming = keyboard.pressed(KeyCode::ShiftLeft) || keyboard.pressed(KeyCode::ShiftRight);
+    let reach = if aiming { AIM_RANGE } else { 30.0 };
+
+    let Some((mut tripod, _)) = tripod_query.iter_mut()
+        .map(|(tripod, transform)| {
+            let distance = transform.translation.truncate().distance(world_position);
+            (tripod, distance)
+        })
+        .filter(|(_, distance)| *distance < reach)
+        .min_by(|a, b| a.1.total_cmp(&b.1)) else { return };
+
+    if aiming {
+        tripod.frame_center = world_position;
+    } else {
+        tripod.framing = tripod.framing.next();
+        info!("Tripod framing set to {}", tripod.framing.name());
+    }
+}
+
+fn remote_shutter_system(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    key_bindings: Res<KeyBindings>,
+    mut tripod_query: Query<&mut Tripod>,
+    bird_query: Query<(&Transform, &AnimatedBird, &BirdState), With<BirdAI>>,
+    mut images: ResMut<Assets<Image>>,
+    mut photo_events: EventWriter<PhotoTakenEvent>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+    time_state: Res<TimeState>,
+) {
+    if !key_bindings.is_action_just_pressed(GameAction::RemoteShutter, &keyboard, &mouse) {
+        return;
+    }
+    if tripod_query.is_empty() {
+        return;
+    }
+
+    let mut fired = 0;
+    for mut tripod in tripod_query.iter_mut() {
+        // The subject is whichever bird sits closest to the middle of the composed frame
+        let Some((bird_transform, animated_bird, bird_state)) = bird_query.iter()
+            .filter(|(transform, _, _)| tripod.frame_contains(transform.translation.truncate()))
+            .min_by(|a, b| {
+                let distance_a = a.0.translation.truncate().distance(tripod.frame_center);
+                let distance_b = b.0.translation.truncate().distance(tripod.frame_center);
+                distance_a.total_cmp(&distance_b)
+            }) else { continue };
+
+        let frame_size = tripod.framing.size();
+        let image_handle = new_photo_texture(&mut images);
+        commands.spawn((
+            Camera2d,
+            Camera {
+                target: RenderTarget::Image(ImageRenderTarget::from(image_handle.clone())),
+                order: -1,
+                ..default()
+            },
+            Projection::Orthographic(OrthographicProjection {
+                scale: frame_size.x / PHOTO_WIDTH,
+                ..OrthographicProjection::default_2d()
+            }),
+            Transform::from_translation(tripod.frame_center.extend(1000.0)),
+            TripodRenderCamera,
+        ));
+
+        // Score the bird where it sits within the tripod's frame rather than the main view
+        let mut framed_transform = *bird_transform;
+        let framed_position = (bird_transform.translation.truncate() - tripod.frame_center) * (PHOTO_WIDTH / frame_size.x);
+        framed_transform.translation = framed_position.extend(bird_transform.translation.z);
+
+        let mut score = crate::photo_mode::advanced_systems::enhanced_photo_scoring_system(
+            &bird_query,
+            &time_state,
+            &tripod.framing.camera_controls(),
+            Some((framed_transform, *animated_bird, *bird_state)),
+        );
+        score.clarity_score += STEADINESS_BONUS;
+        score.total_score += STEADINESS_BONUS;
+
+        tripod.shots_taken += 1;
+        fired += 1;
+        info!("Tripod shot of {:?} scored {}", animated_bird.species, score.total_score);
+
+        photo_events.write(PhotoTakenEvent {
+            score,
+            species: Some(animated_bird.species),
+            behavior: Some(*bird_state),
+            image_handle,
+        });
+    }
+
+    if fired == 0 {
+        notification_events.write(ShowNotificationEvent {
+            notification: NotificationType::Info {
+                message: "No birds in any tripod frame".to_string(),
+            },
+        });
+    }
+}
+
+fn tripod_frame_gizmo_system(
+    mut gizmos: Gizmos,
+    tripod_query: Query<(&Tripod, &Transform)>,
+    bird_query: Query<&Transform, With<BirdAI>>,
+    render_camera_query: Query<(), With<TripodRenderCamera>>,
+) {
+    // Keep the guides out of the picture while a remote shot renders
+    if !render_camera_query.is_empty() {
+        return;
+    }
+
+    for (tripod, transform) in tripod_query.iter() {
+        let occupied = bird_query.iter().any(|bird| tripod.frame_contains(bird.translation.truncate()));
+        let color = if occupied {
+            Color::srgba(0.4, 0.9, 0.4, 0.8)
+        } else {
+            Color::srgba(1.0, 1.0, 1.0, 0.4)
+        };
+
+        gizmos.rect_2d(tripod.frame_center, tripod.framing.size(), color);
+        gizmos.line_2d(transform.translation.truncate(), tripod.frame_center, Color::srgba(1.0, 1.0, 1.0, 0.15));
+    }
+}
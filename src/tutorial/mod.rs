@@ -26,6 +26,6 @@ impl Plugin for TutorialPlugin {
                 tutorial_highlight_system,
                 tutorial_completion_system,
                 // tutorial_button_system, // Disabled - using Lunex UI
-            ).run_if(in_state(AppState::Playing)));
+            ).in_set(crate::GameSet::UI).run_if(in_state(AppState::Playing)));
     }
 }
\ No newline at end of file
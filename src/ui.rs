@@ -1,14 +1,17 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 use crate::{AppState, resources::{BirdCount, SpawnBirdEvent}};
 use crate::environment::resources::{TimeState, WeatherState};
 use crate::photo_mode::resources::CurrencyResource;
+use crate::keybindings::{ActiveInputDevice, GameAction, KeyBindings};
 
 pub struct UiPlugin;
 
 impl Plugin for UiPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<TooltipTimer>()
+            .insert_resource(HudLayout::load_from_file())
             .add_systems(OnEnter(AppState::Playing), (setup_ui, update_currency_ui_initial))
             .add_systems(OnExit(AppState::Playing), cleanup_gameplay_ui)
             .add_systems(
@@ -21,11 +24,74 @@ impl Plugin for UiPlugin {
                     handle_popout_menu,
                     update_popout_menu_visibility,
                     handle_menu_tooltips,
-                ).run_if(in_state(AppState::Playing))
+                ).in_set(crate::GameSet::UI).run_if(in_state(AppState::Playing))
             );
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum HudCorner {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+impl HudCorner {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::TopLeft => "Top Left",
+            Self::TopRight => "Top Right",
+            Self::BottomLeft => "Bottom Left",
+            Self::BottomRight => "Bottom Right",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        match self {
+            Self::TopLeft => Self::TopRight,
+            Self::TopRight => Self::BottomRight,
+            Self::BottomRight => Self::BottomLeft,
+            Self::BottomLeft => Self::TopLeft,
+        }
+    }
+
+    fn place(&self, node: &mut Node, margin: Val) {
+        match self {
+            Self::TopLeft => (node.top, node.left) = (margin, margin),
+            Self::TopRight => (node.top, node.right) = (margin, margin),
+            Self::BottomLeft => (node.bottom, node.left) = (margin, margin),
+            Self::BottomRight => (node.bottom, node.right) = (margin, margin),
+        }
+    }
+}
+
+// Where the gameplay HUD pieces sit; kept with the other user config so it travels with exports
+#[derive(Resource, Clone, Serialize, Deserialize)]
+pub struct HudLayout {
+    pub currency_corner: HudCorner,
+    pub status_bar_at_top: bool,
+}
+
+impl Default for HudLayout {
+    fn default() -> Self {
+        Self {
+            currency_corner: HudCorner::BottomLeft,
+            status_bar_at_top: false,
+        }
+    }
+}
+
+impl HudLayout {
+    pub fn save_to_file(&self) -> Result<(), Box<dyn std::error::Error>> {
+        crate::user_config::write_config("hud_layout.ron", self)
+    }
+    
+    pub fn load_from_file() -> Self {
+        crate::user_config::read_config("hud_layout.ron").unwrap_or_default()
+    }
+}
+
 #[derive(Component)]
 struct SpawnButton;
 
@@ -68,7 +134,9 @@ struct MenuItemsContainer;
 
 #[derive(Component)]
 struct MenuTooltip {
-    pub text: String,
+    pub label: &'static str,
+    // Shown as a key or controller glyph after the label
+    pub action: Option<GameAction>,
 }
 
 #[derive(Component)]
@@ -89,7 +157,7 @@ impl Default for TooltipTimer {
     }
 }
 
-fn setup_ui(mut commands: Commands, currency: Res<CurrencyResource>) {
+fn setup_ui(mut commands: Commands, currency: Res<CurrencyResource>, layout: Res<HudLayout>) {
     // Pop-out menu in top left
     commands.spawn((
         Node {
@@ -147,17 +215,17 @@ fn setup_ui(mut commands: Commands, currency: Res<CurrencyResource>) {
             Name::new("Menu Items Container"),
         )).with_children(|menu_container| {
             let menu_items = [
-                ("📷", MenuAction::Photography, "Toggle Photography Mode (P)"), 
-                ("📖", MenuAction::Journal, "Open Bird Journal (J)"),     
-                ("🛍️", MenuAction::Catalog, "Open Shop & Catalog (C)"),    
-                ("⚙️", MenuAction::Settings, "Open Settings"),   
+                ("📷", MenuAction::Photography, "Toggle Photography Mode", Some(GameAction::TogglePhotoMode)), 
+                ("📖", MenuAction::Journal, "Open Bird Journal", Some(GameAction::OpenJournal)),     
+                ("🛍️", MenuAction::Catalog, "Open Shop & Catalog", Some(GameAction::OpenCatalog)),    
+                ("⚙️", MenuAction::Settings, "Open Settings", None),   
             ];
             
-            for (icon, action, tooltip_text) in menu_items {
+            for (icon, action, label, prompt_action) in menu_items {
                 menu_container.spawn((
                     Button,
                     MenuIconButton { action },
-                    MenuTooltip { text: tooltip_text.to_string() },
+                    MenuTooltip { label, action: prompt_action },
                     Node {
                         width: Val::Px(48.0),
                         height: Val::Px(48.0),
@@ -182,21 +250,21 @@ fn setup_ui(mut commands: Commands, currency: Res<CurrencyResource>) {
         });
     });
     
-    // Currency counter in bottom left
+    // Currency counter, bottom left unless the HUD layout moves it
+    let mut currency_node = Node {
+        position_type: PositionType::Absolute,
+        width: Val::Auto,
+        height: Val::Auto,
+        flex_direction: FlexDirection::Row,
+        align_items: AlignItems::Center,
+        column_gap: Val::Px(8.0),
+        padding: UiRect::all(Val::Px(10.0)),
+        border: UiRect::all(Val::Px(2.0)),
+        ..default()
+    };
+    layout.currency_corner.place(&mut currency_node, Val::Px(20.0));
     commands.spawn((
-        Node {
-            position_type: PositionType::Absolute,
-            left: Val::Px(20.0),
-            bottom: Val::Px(20.0),
-            width: Val::Auto,
-            height: Val::Auto,
-            flex_direction: FlexDirection::Row,
-            align_items: AlignItems::Center,
-            column_gap: Val::Px(8.0),
-            padding: UiRect::all(Val::Px(10.0)),
-            border: UiRect::all(Val::Px(2.0)),
-            ..default()
-        },
+        currency_node,
         BackgroundColor(Color::srgba(0.1, 0.1, 0.1, 0.8)),
         BorderColor(Color::srgb(0.8, 0.6, 0.2)),
         CurrencyCounter,
@@ -223,13 +291,19 @@ fn setup_ui(mut commands: Commands, currency: Res<CurrencyResource>) {
         ));
     });
 
-    // Bottom UI container (non-blocking)
+    // Status bar container (non-blocking), along the bottom edge unless the HUD layout moves it up
+    let (status_top, status_bottom) = if layout.status_bar_at_top {
+        (Val::Px(20.0), Val::Auto)
+    } else {
+        (Val::Auto, Val::Px(20.0))
+    };
     commands.spawn((
         Node {
             width: Val::Percent(100.0),
             height: Val::Auto,
             position_type: PositionType::Absolute,
-            bottom: Val::Px(20.0),
+            top: status_top,
+            bottom: status_bottom,
             left: Val::Px(0.0),
             flex_direction: FlexDirection::Row,
             justify_content: JustifyContent::Center,
@@ -468,6 +542,8 @@ fn handle_menu_tooltips(
     mut tooltip_timer: ResMut<TooltipTimer>,
     tooltip_query: Query<(&Interaction, &MenuTooltip), With<MenuIconButton>>, // Remove Changed filter
     existing_tooltip: Query<Entity, With<TooltipContainer>>,
+    keybindings: Res<KeyBindings>,
+    input_device: Res<ActiveInputDevice>,
 ) {
     let mut should_show_tooltip = false;
     let mut tooltip_text = String::new();
@@ -476,7 +552,10 @@ fn handle_menu_tooltips(
     for (interaction, tooltip) in tooltip_query.iter() {
         if *interaction == Interaction::Hovered {
             should_show_tooltip = true;
-            tooltip_text = tooltip.text.clone();
+            tooltip_text = match tooltip.action {
+                Some(action) => format!("{} ({})", tooltip.label, keybindings.prompt_label(action, *input_device)),
+                None => tooltip.label.to_string(),
+            };
             break;
         }
     }
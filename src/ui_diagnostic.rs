@@ -1,19 +1,36 @@
 // UI Diagnostic and Testing Module
-// Comprehensive error checking and self-testing for UI systems
+// Comprehensive error checking and self-testing for UI systems, plus the F2 hierarchy inspector:
+// hover any UI node to outline it and see its layout values, components and parent chain, and
+// Ctrl+click to pin it so its size, padding, margin and gaps can be nudged live. Tweaks aren't
+// saved anywhere; "Log Node" writes the edited values to the log for copying back into the code.
 
 use bevy::prelude::*;
+use bevy::ecs::archetype::Archetypes;
+use bevy::ecs::component::Components;
+use bevy::ecs::entity::Entities;
+use bevy::window::PrimaryWindow;
 use crate::lunex_ui::{LunexJournalUI, LunexCatalogUI, BevyJournalUI};
 
+const INSPECTOR_PANEL_WIDTH: f32 = 420.0;
+const MAX_LISTED_COMPONENTS: usize = 24;
+
 pub struct UiDiagnosticPlugin;
 
 impl Plugin for UiDiagnosticPlugin {
     fn build(&self, app: &mut App) {
         app
+            .init_resource::<UiInspector>()
             .add_systems(Update, (
                 diagnostic_journal_state,
                 diagnostic_catalog_state,
                 comprehensive_ui_health_check,
-            ))
+            ).in_set(crate::GameSet::UI))
+            .add_systems(Update, (
+                toggle_ui_inspector_system.run_if(crate::debug_console::console_is_not_visible),
+                ui_inspector_hover_system,
+                ui_inspector_button_system,
+                refresh_ui_inspector_system,
+            ).chain().in_set(crate::GameSet::UI))
             .add_systems(OnEnter(crate::AppState::Journal), verify_journal_setup)
             .add_systems(OnEnter(crate::AppState::Catalog), verify_catalog_setup);
     }
@@ -59,7 +76,9 @@ pub fn diagnostic_catalog_state(
     lunex_catalog_query: Query<Entity, With<LunexCatalogUI>>,
     keyboard: Res<ButtonInput<KeyCode>>,
 ) {
-    if keyboard.just_pressed(KeyCode::F2) {
+    // Plain F2 opens the hierarchy inspector
+    let shift = keyboard.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+    if shift && keyboard.just_pressed(KeyCode::F2) {
         info!("🔍 CATALOG DIAGNOSTIC:");
         info!("  App State: {:?}", app_state.get());
         info!("  Catalog State Open: {}", catalog_state.is_open);
@@ -140,7 +159,7 @@ pub fn comprehensive_ui_health_check(
             }
         }
         
-        info!("🏥 Health check completed. Use F1 (Journal) or F2 (Catalog) for detailed diagnostics.");
+        info!("🏥 Health check completed. Use F1 (Journal) or Shift+F2 (Catalog) for detailed diagnostics, F2 to inspect UI nodes.");
     }
 }
 
@@ -174,4 +193,482 @@ pub fn verify_catalog_setup(
     } else {
         info!("✅ CATALOG VERIFY: Found {} catalog UI entities", catalog_count);
     }
-}
\ No newline at end of file
+}
+
+// Hierarchy Inspector
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum LayoutField {
+    #[default]
+    Width,
+    Height,
+    Padding,
+    Margin,
+    Gap,
+}
+
+impl LayoutField {
+    const ALL: [LayoutField; 5] = [Self::Width, Self::Height, Self::Padding, Self::Margin, Self::Gap];
+
+    fn label(&self) -> &'static str {
+        match self {
+            Self::Width => "Width",
+            Self::Height => "Height",
+            Self::Padding => "Padding",
+            Self::Margin => "Margin",
+            Self::Gap => "Gap",
+        }
+    }
+
+    fn next(&self) -> Self {
+        let index = Self::ALL.iter().position(|field| field == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+}
+
+#[derive(Resource)]
+pub struct UiInspector {
+    pub active: bool,
+    pub hovered: Option<Entity>,
+    // Ctrl+clicked node; stays in the panel for editing while the cursor moves on
+    pub pinned: Option<Entity>,
+    pub field: LayoutField,
+    refresh_timer: Timer,
+    panel_dirty: bool,
+}
+
+impl Default for UiInspector {
+    fn default() -> Self {
+        Self {
+            active: false,
+            hovered: None,
+            pinned: None,
+            field: LayoutField::default(),
+            refresh_timer: Timer::from_seconds(0.25, TimerMode::Repeating),
+            panel_dirty: false,
+        }
+    }
+}
+
+impl UiInspector {
+    fn target(&self) -> Option<Entity> {
+        self.pinned.or(self.hovered)
+    }
+}
+
+// Roots of the inspector's own UI, which it never picks
+#[derive(Component)]
+pub struct UiInspectorUi;
+
+#[derive(Component)]
+pub struct UiInspectorPanel;
+
+#[derive(Component)]
+pub struct UiInspectorHighlight;
+
+#[derive(Component)]
+pub enum UiInspectorButton {
+    Field,
+    Decrease,
+    Increase,
+    LogNode,
+    Unpin,
+    Close,
+}
+
+pub fn toggle_ui_inspector_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut inspector: ResMut<UiInspector>,
+) {
+    // Shift and Ctrl+F2 belong to the catalog diagnostic and the test shortcuts
+    let modified = keyboard.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight, KeyCode::ControlLeft, KeyCode::ControlRight]);
+    if modified || !keyboard.just_pressed(KeyCode::F2) {
+        return;
+    }
+    inspector.active = !inspector.active;
+    inspector.hovered = None;
+    inspector.pinned = None;
+    inspector.panel_dirty = true;
+    info!("UI inspector {}", if inspector.active { "opened" } else { "closed" });
+}
+
+type InspectableNodeQuery<'w, 's> = Query<'w, 's, (Entity, &'static ComputedNode, &'static GlobalTransform, &'static InheritedVisibility), Without<UiInspectorUi>>;
+
+// Picks the topmost visible node under the cursor and moves the outline onto it
+#[allow(clippy::too_many_arguments)]
+pub fn ui_inspector_hover_system(
+    mut inspector: ResMut<UiInspector>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    node_query: InspectableNodeQuery,
+    parent_query: Query<&ChildOf>,
+    inspector_ui_query: Query<(), With<UiInspectorUi>>,
+    mut highlight_query: Query<(&mut Node, &mut Visibility), With<UiInspectorHighlight>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+) {
+    if !inspector.active {
+        return;
+    }
+
+    // Node transforms and sizes are in physical pixels
+    let cursor = window_query.single().ok().and_then(|window| window.physical_cursor_position());
+    let hovered = cursor.and_then(|cursor| {
+        node_query.iter()
+            .filter(|(entity, node, transform, visibility)| {
+                visibility.get()
+                    && node.size().min_element() > 0.0
+                    && Rect::from_center_size(transform.translation().truncate(), node.size()).contains(cursor)
+                    && !is_inspector_ui(*entity, &parent_query, &inspector_ui_query)
+            })
+            .max_by_key(|(_, node, ..)| node.stack_index())
+            .map(|(entity, ..)| entity)
+    });
+    if hovered != inspector.hovered {
+        inspector.hovered = hovered;
+        inspector.panel_dirty = true;
+    }
+
+    let ctrl = keyboard.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]);
+    if ctrl && mouse.just_pressed(MouseButton::Left) && hovered.is_some() {
+        inspector.pinned = hovered;
+        inspector.panel_dirty = true;
+    }
+
+    let outlined = inspector.hovered.or(inspector.pinned).and_then(|entity| node_query.get(entity).ok());
+    for (mut highlight, mut visibility) in &mut highlight_query {
+        let Some((_, node, transform, _)) = outlined else {
+            *visibility = Visibility::Hidden;
+            continue;
+        };
+        let scale = node.inverse_scale_factor();
+        let top_left = (transform.translation().truncate() - node.size() / 2.0) * scale;
+        highlight.left = Val::Px(top_left.x);
+        highlight.top = Val::Px(top_left.y);
+        highlight.width = Val::Px(node.size().x * scale);
+        highlight.height = Val::Px(node.size().y * scale);
+        *visibility = Visibility::Visible;
+    }
+}
+
+fn is_inspector_ui(entity: Entity, parent_query: &Query<&ChildOf>, inspector_ui_query: &Query<(), With<UiInspectorUi>>) -> bool {
+    std::iter::successors(Some(entity), |current| parent_query.get(*current).ok().map(|child_of| child_of.parent()))
+        .any(|ancestor| inspector_ui_query.contains(ancestor))
+}
+
+type UiInspectorButtonQuery<'w, 's> = Query<'w, 's, (&'static Interaction, &'static UiInspectorButton), (Changed<Interaction>, With<Button>)>;
+
+pub fn ui_inspector_button_system(
+    interaction_query: UiInspectorButtonQuery,
+    mut inspector: ResMut<UiInspector>,
+    mut node_query: Query<(&mut Node, &ComputedNode), Without<UiInspectorUi>>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+) {
+    for (interaction, button) in &interaction_query {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let step = if keyboard.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]) { 10.0 } else { 2.0 };
+        match button {
+            UiInspectorButton::Field => inspector.field = inspector.field.next(),
+            UiInspectorButton::Decrease | UiInspectorButton::Increase => {
+                let step = if matches!(button, UiInspectorButton::Decrease) { -step } else { step };
+                let Some((mut node, computed)) = inspector.pinned.and_then(|entity| node_query.get_mut(entity).ok()) else { continue };
+                let size = computed.size() * computed.inverse_scale_factor();
+                match inspector.field {
+                    LayoutField::Width => node.width = nudge(node.width, size.x, step),
+                    LayoutField::Height => node.height = nudge(node.height, size.y, step),
+                    LayoutField::Padding => node.padding = nudge_rect(node.padding, step),
+                    LayoutField::Margin => node.margin = nudge_rect(node.margin, step),
+                    LayoutField::Gap => {
+                        node.row_gap = nudge(node.row_gap, 0.0, step);
+                        node.column_gap = nudge(node.column_gap, 0.0, step);
+                    }
+                }
+            }
+            UiInspectorButton::LogNode => {
+                if let Some((node, _)) = inspector.pinned.and_then(|entity| node_query.get(entity).ok()) {
+                    info!(
+                        "UI inspector {:?}: width: Val::{:?}, height: Val::{:?}, padding: {}, margin: {}, row_gap: Val::{:?}, column_gap: Val::{:?}",
+                        inspector.pinned, node.width, node.height, rect_code(node.padding), rect_code(node.margin), node.row_gap, node.column_gap,
+                    );
+                }
+            }
+            UiInspectorButton::Unpin => inspector.pinned = None,
+            UiInspectorButton::Close => {
+                inspector.active = false;
+                inspector.hovered = None;
+                inspector.pinned = None;
+            }
+        }
+        inspector.panel_dirty = true;
+    }
+}
+
+// Percentages step by whole percent; anything else becomes pixels starting from the laid-out size
+fn nudge(value: Val, laid_out: f32, step: f32) -> Val {
+    match value {
+        Val::Px(px) => Val::Px((px + step).max(0.0)),
+        Val::Percent(percent) => Val::Percent((percent + step.signum()).max(0.0)),
+        _ => Val::Px((laid_out + step).max(0.0)),
+    }
+}
+
+fn nudge_rect(rect: UiRect, step: f32) -> UiRect {
+    UiRect {
+        left: nudge(rect.left, 0.0, step),
+        right: nudge(rect.right, 0.0, step),
+        top: nudge(rect.top, 0.0, step),
+        bottom: nudge(rect.bottom, 0.0, step),
+    }
+}
+
+fn val_label(value: Val) -> String {
+    match value {
+        Val::Px(px) => format!("{}px", px),
+        Val::Percent(percent) => format!("{}%", percent),
+        Val::Auto => "auto".to_string(),
+        other => format!("{:?}", other),
+    }
+}
+
+fn rect_label(rect: UiRect) -> String {
+    if rect.left == rect.right && rect.left == rect.top && rect.left == rect.bottom {
+        val_label(rect.left)
+    } else {
+        format!("l {} r {} t {} b {}", val_label(rect.left), val_label(rect.right), val_label(rect.top), val_label(rect.bottom))
+    }
+}
+
+fn rect_code(rect: UiRect) -> String {
+    if rect.left == rect.right && rect.left == rect.top && rect.left == rect.bottom {
+        format!("UiRect::all(Val::{:?})", rect.left)
+    } else {
+        format!("UiRect::new(Val::{:?}, Val::{:?}, Val::{:?}, Val::{:?})", rect.left, rect.right, rect.top, rect.bottom)
+    }
+}
+
+// bevy_ui::ui_node::Node reads as Node; generic arguments are elided
+fn short_type_name(name: &str) -> String {
+    let base = name.split('<').next().unwrap_or(name);
+    let short = base.rsplit("::").next().unwrap_or(base);
+    if base.len() < name.len() { format!("{}<..>", short) } else { short.to_string() }
+}
+
+fn entity_label(entity: Entity, name_query: &Query<&Name>) -> String {
+    match name_query.get(entity) {
+        Ok(name) => format!("{} ({})", name, entity),
+        Err(_) => entity.to_string(),
+    }
+}
+
+// Component names straight from the archetype, so nothing has to be registered for reflection
+fn component_names(entity: Entity, entities: &Entities, archetypes: &Archetypes, components: &Components) -> Vec<String> {
+    let Some(archetype) = entities.get(entity).and_then(|location| archetypes.get(location.archetype_id)) else { return Vec::new() };
+    let mut names: Vec<String> = archetype.components()
+        .filter_map(|id| components.get_name(id))
+        .map(|name| short_type_name(&name))
+        .collect();
+    names.sort();
+    names
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn refresh_ui_inspector_system(
+    mut commands: Commands,
+    mut inspector: ResMut<UiInspector>,
+    panel_query: Query<Entity, With<UiInspectorPanel>>,
+    highlight_query: Query<Entity, With<UiInspectorHighlight>>,
+    node_query: Query<(&Node, &ComputedNode, &GlobalTransform), Without<UiInspectorUi>>,
+    parent_query: Query<&ChildOf>,
+    name_query: Query<&Name>,
+    world_info: (&Entities, &Archetypes, &Components),
+    time: Res<Time>,
+) {
+    // Layout values move on their own, so an open panel refreshes a few times a second
+    inspector.refresh_timer.tick(time.delta());
+    let timer_fired = inspector.active && inspector.refresh_timer.just_finished();
+    if !inspector.panel_dirty && !timer_fired {
+        return;
+    }
+    inspector.panel_dirty = false;
+
+    for panel in &panel_query {
+        commands.entity(panel).despawn();
+    }
+    if !inspector.active {
+        for highlight in &highlight_query {
+            commands.entity(highlight).despawn();
+        }
+        return;
+    }
+    if highlight_query.is_empty() {
+        commands.spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                border: UiRect::all(Val::Px(2.0)),
+                ..default()
+            },
+            BorderColor(Color::srgb(0.2, 0.6, 1.0)),
+            BackgroundColor(Color::srgba(0.2, 0.6, 1.0, 0.12)),
+            GlobalZIndex(1000),
+            Visibility::Hidden,
+            Pickable::IGNORE,
+            UiInspectorUi,
+            UiInspectorHighlight,
+            Name::new("UiInspectorHighlight"),
+        ));
+    }
+
+    let target = inspector.target().and_then(|entity| node_query.get(entity).ok().map(|node| (entity, node)));
+    let (entities, archetypes, components) = world_info;
+    spawn_ui_inspector_panel(&mut commands, &inspector, target, |entity| {
+        let mut chain: Vec<String> = std::iter::successors(parent_query.get(entity).ok().map(|child_of| child_of.parent()), |current| {
+            parent_query.get(*current).ok().map(|child_of| child_of.parent())
+        })
+        .map(|ancestor| entity_label(ancestor, &name_query))
+        .collect();
+        chain.reverse();
+        (entity_label(entity, &name_query), chain, component_names(entity, entities, archetypes, components))
+    });
+}
+
+fn spawn_inspector_button(parent: &mut ChildSpawnerCommands, label: &str, button: UiInspectorButton) {
+    parent.spawn((
+        Button,
+        Node {
+            padding: UiRect::axes(Val::Px(6.0), Val::Px(2.0)),
+            ..default()
+        },
+        BackgroundColor(Color::srgb(0.6, 0.5, 0.4)),
+        BorderRadius::all(Val::Px(4.0)),
+        button,
+    )).with_children(|button| {
+        button.spawn((
+            Text::new(label),
+            TextFont { font_size: 10.0, ..default() },
+            TextColor(Color::WHITE),
+        ));
+    });
+}
+
+fn spawn_inspector_line(parent: &mut ChildSpawnerCommands, text: String, color: Color) {
+    parent.spawn((
+        Text::new(text),
+        TextFont { font_size: 11.0, ..default() },
+        TextColor(color),
+    ));
+}
+
+// Label, parent chain from the root down and component names for an entity
+type NodeDescription = (String, Vec<String>, Vec<String>);
+
+fn spawn_ui_inspector_panel(
+    commands: &mut Commands,
+    inspector: &UiInspector,
+    target: Option<(Entity, (&Node, &ComputedNode, &GlobalTransform))>,
+    describe: impl Fn(Entity) -> NodeDescription,
+) {
+    let text_color = Color::srgb(0.2, 0.1, 0.05);
+    let muted = Color::srgb(0.5, 0.4, 0.3);
+
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(10.0),
+            bottom: Val::Px(10.0),
+            width: Val::Px(INSPECTOR_PANEL_WIDTH),
+            max_height: Val::Percent(70.0),
+            flex_direction: FlexDirection::Column,
+            padding: UiRect::all(Val::Px(10.0)),
+            row_gap: Val::Px(3.0),
+            overflow: Overflow::clip(),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.95, 0.92, 0.88, 0.95)),
+        BorderRadius::all(Val::Px(6.0)),
+        GlobalZIndex(41),
+        UiInspectorUi,
+        UiInspectorPanel,
+        Name::new("UiInspector"),
+    )).with_children(|panel| {
+        panel.spawn(Node {
+            width: Val::Percent(100.0),
+            justify_content: JustifyContent::SpaceBetween,
+            align_items: AlignItems::Center,
+            ..default()
+        }).with_children(|header| {
+            header.spawn((
+                Text::new("UI Inspector"),
+                TextFont { font_size: 16.0, ..default() },
+                TextColor(Color::srgb(0.3, 0.2, 0.1)),
+            ));
+            header.spawn(Node {
+                column_gap: Val::Px(4.0),
+                ..default()
+            }).with_children(|buttons| {
+                if inspector.pinned.is_some() {
+                    spawn_inspector_button(buttons, "Unpin", UiInspectorButton::Unpin);
+                }
+                spawn_inspector_button(buttons, "Close", UiInspectorButton::Close);
+            });
+        });
+
+        let Some((entity, (node, computed, transform))) = target else {
+            spawn_inspector_line(panel, "Hover a UI node to inspect it; Ctrl+click to pin it for editing".to_string(), muted);
+            return;
+        };
+        let (label, chain, component_names) = describe(entity);
+        let state = if inspector.pinned == Some(entity) { "pinned" } else { "hovered" };
+        spawn_inspector_line(panel, format!("{} - {}", label, state), Color::srgb(0.3, 0.2, 0.1));
+
+        // Computed values are physical pixels; show them in the logical pixels Node uses
+        let scale = computed.inverse_scale_factor();
+        let size = computed.size() * scale;
+        let top_left = (transform.translation().truncate() - computed.size() / 2.0) * scale;
+        spawn_inspector_line(panel, format!(
+            "Laid out {:.0}x{:.0} at ({:.0}, {:.0}), stack index {}",
+            size.x, size.y, top_left.x, top_left.y, computed.stack_index(),
+        ), text_color);
+        spawn_inspector_line(panel, format!(
+            "{:?}, {:?}, {:?}, overflow {:?}/{:?}",
+            node.display, node.position_type, node.flex_direction, node.overflow.x, node.overflow.y,
+        ), text_color);
+        spawn_inspector_line(panel, format!(
+            "width {}  height {}  min {}x{}  max {}x{}",
+            val_label(node.width), val_label(node.height),
+            val_label(node.min_width), val_label(node.min_height),
+            val_label(node.max_width), val_label(node.max_height),
+        ), text_color);
+        spawn_inspector_line(panel, format!(
+            "padding {}  margin {}  gap {} x {}",
+            rect_label(node.padding), rect_label(node.margin), val_label(node.row_gap), val_label(node.column_gap),
+        ), text_color);
+        spawn_inspector_line(panel, format!(
+            "justify {:?}  align items {:?}  align self {:?}",
+            node.justify_content, node.align_items, node.align_self,
+        ), text_color);
+
+        if inspector.pinned == Some(entity) {
+            panel.spawn(Node {
+                column_gap: Val::Px(4.0),
+                margin: UiRect::vertical(Val::Px(3.0)),
+                ..default()
+            }).with_children(|buttons| {
+                spawn_inspector_button(buttons, &format!("Edit: {}", inspector.field.label()), UiInspectorButton::Field);
+                spawn_inspector_button(buttons, "-", UiInspectorButton::Decrease);
+                spawn_inspector_button(buttons, "+", UiInspectorButton::Increase);
+                spawn_inspector_button(buttons, "Log Node", UiInspectorButton::LogNode);
+            });
+            spawn_inspector_line(panel, "Steps of 2px, or 10px with Shift; percentages step by 1%".to_string(), muted);
+        }
+
+        let shown = component_names.len().min(MAX_LISTED_COMPONENTS);
+        let mut components = component_names[..shown].join(", ");
+        if component_names.len() > shown {
+            components.push_str(&format!(" and {} more", component_names.len() - shown));
+        }
+        spawn_inspector_line(panel, format!("Components: {}", components), text_color);
+        let parents = if chain.is_empty() { "none - this is a root node".to_string() } else { chain.join(" > ") };
+        spawn_inspector_line(panel, format!("Parents: {}", parents), muted);
+    });
+}
@@ -0,0 +1,95 @@
+// User Config - One versioned directory for settings, key bindings and HUD layout
+//
+// Each file lives in the platform config directory (XDG_CONFIG_HOME on Linux, AppData\Roaming on
+// Windows, Application Support on macOS) wrapped with the format version it was written in, so a
+// future layout change can migrate old files instead of quietly falling back to defaults. Export
+// bundles all three into one file in the player's documents folder for carrying to another machine.
+use bevy::prelude::*;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+use crate::keybindings::KeyBindings;
+use crate::menu::resources::GameSettings;
+use crate::ui::HudLayout;
+
+// Bump when a config file changes shape in a way serde defaults cannot absorb
+pub const CONFIG_VERSION: u32 = 1;
+const EXPORT_FILENAME: &str = "AvianHaven Config.ron";
+
+#[derive(Serialize, Deserialize)]
+struct Versioned<T> {
+    version: u32,
+    data: T,
+}
+
+pub fn config_dir() -> PathBuf {
+    dirs::config_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("AvianHaven")
+}
+
+pub fn read_config<T: DeserializeOwned>(filename: &str) -> Option<T> {
+    let content = fs::read_to_string(config_dir().join(filename)).ok()?;
+    match ron::from_str::<Versioned<T>>(&content) {
+        Ok(versioned) => {
+            if versioned.version > CONFIG_VERSION {
+                warn!("{} was written by a newer version (config v{}), some options may be ignored", filename, versioned.version);
+            }
+            Some(versioned.data)
+        }
+        // Files from before the config was versioned hold the bare value
+        Err(_) => ron::from_str::<T>(&content).ok(),
+    }
+}
+
+pub fn write_config<T: Serialize>(filename: &str, data: &T) -> Result<(), Box<dyn std::error::Error>> {
+    fs::create_dir_all(config_dir())?;
+    let serialized = ron::ser::to_string_pretty(
+        &Versioned { version: CONFIG_VERSION, data },
+        ron::ser::PrettyConfig::default(),
+    )?;
+    fs::write(config_dir().join(filename), serialized)?;
+    Ok(())
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ConfigBundle {
+    pub version: u32,
+    pub settings: GameSettings,
+    pub keybindings: KeyBindings,
+    #[serde(default)]
+    pub hud_layout: HudLayout,
+}
+
+pub fn export_path() -> PathBuf {
+    dirs::document_dir()
+        .unwrap_or_else(config_dir)
+        .join(EXPORT_FILENAME)
+}
+
+pub fn export_config(settings: &GameSettings, keybindings: &KeyBindings, hud_layout: &HudLayout) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    let bundle = ConfigBundle {
+        version: CONFIG_VERSION,
+        settings: settings.clone(),
+        keybindings: keybindings.clone(),
+        hud_layout: hud_layout.clone(),
+    };
+    let path = export_path();
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&path, ron::ser::to_string_pretty(&bundle, ron::ser::PrettyConfig::default())?)?;
+    Ok(path)
+}
+
+pub fn import_config() -> Result<ConfigBundle, Box<dyn std::error::Error>> {
+    let path = export_path();
+    let content = fs::read_to_string(&path)
+        .map_err(|e| format!("could not read {:?}: {}", path, e))?;
+    let bundle: ConfigBundle = ron::from_str(&content)?;
+    if bundle.version > CONFIG_VERSION {
+        warn!("Imported config is from a newer version (config v{}), some options may be ignored", bundle.version);
+    }
+    Ok(bundle)
+}
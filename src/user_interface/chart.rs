@@ -0,0 +1,126 @@
+// src/user_interface/chart.rs
+use bevy::prelude::*;
+
+const DOT_SIZE: f32 = 6.0;
+
+// Small scatter plot drawn from plain UI nodes, for static results such as research findings.
+// Axis ranges fit the data with a little padding so no point sits on the frame.
+pub struct ScatterChart {
+    pub title: String,
+    pub x_label: String,
+    pub y_label: String,
+    pub points: Vec<Vec2>,
+    pub width: f32,
+    pub height: f32,
+    pub dot_color: Color,
+}
+
+impl ScatterChart {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            x_label: String::new(),
+            y_label: String::new(),
+            points: Vec::new(),
+            width: 320.0,
+            height: 160.0,
+            dot_color: Color::srgb(0.2, 0.5, 0.8),
+        }
+    }
+
+    pub fn with_axes(mut self, x_label: impl Into<String>, y_label: impl Into<String>) -> Self {
+        self.x_label = x_label.into();
+        self.y_label = y_label.into();
+        self
+    }
+
+    pub fn with_points(mut self, points: Vec<Vec2>) -> Self {
+        self.points = points;
+        self
+    }
+
+    // Min and max of each axis, padded by a tenth of the span
+    fn ranges(&self) -> (Vec2, Vec2) {
+        let min = self.points.iter().copied().reduce(Vec2::min).unwrap_or(Vec2::ZERO);
+        let max = self.points.iter().copied().reduce(Vec2::max).unwrap_or(Vec2::ONE);
+        let span = (max - min).max(Vec2::ONE);
+        (min - span * 0.1, max + span * 0.1)
+    }
+
+    pub fn spawn(self, parent: &mut ChildSpawnerCommands) {
+        let (min, max) = self.ranges();
+        let axis_font = TextFont { font_size: 10.0, ..default() };
+        let axis_color = TextColor(Color::srgb(0.5, 0.4, 0.3));
+
+        parent.spawn(Node {
+            flex_direction: FlexDirection::Column,
+            row_gap: Val::Px(4.0),
+            ..default()
+        }).with_children(|chart| {
+            chart.spawn((
+                Text::new(self.title.clone()),
+                TextFont { font_size: 13.0, ..default() },
+                TextColor(Color::srgb(0.3, 0.2, 0.1)),
+            ));
+
+            chart.spawn(Node {
+                flex_direction: FlexDirection::Row,
+                column_gap: Val::Px(4.0),
+                ..default()
+            }).with_children(|row| {
+                // Y axis range and label
+                row.spawn(Node {
+                    height: Val::Px(self.height),
+                    flex_direction: FlexDirection::Column,
+                    justify_content: JustifyContent::SpaceBetween,
+                    align_items: AlignItems::End,
+                    ..default()
+                }).with_children(|axis| {
+                    axis.spawn((Text::new(format!("{:.0}", max.y)), axis_font.clone(), axis_color));
+                    axis.spawn((Text::new(self.y_label.clone()), axis_font.clone(), axis_color));
+                    axis.spawn((Text::new(format!("{:.0}", min.y)), axis_font.clone(), axis_color));
+                });
+
+                row.spawn((
+                    Node {
+                        width: Val::Px(self.width),
+                        height: Val::Px(self.height),
+                        border: UiRect { left: Val::Px(1.0), bottom: Val::Px(1.0), ..default() },
+                        ..default()
+                    },
+                    BorderColor(Color::srgb(0.5, 0.4, 0.3)),
+                    BackgroundColor(Color::srgba(1.0, 1.0, 1.0, 0.4)),
+                )).with_children(|plot| {
+                    let span = max - min;
+                    for point in &self.points {
+                        let fraction = (*point - min) / span;
+                        plot.spawn((
+                            Node {
+                                position_type: PositionType::Absolute,
+                                left: Val::Px(fraction.x * self.width - DOT_SIZE / 2.0),
+                                bottom: Val::Px(fraction.y * self.height - DOT_SIZE / 2.0),
+                                width: Val::Px(DOT_SIZE),
+                                height: Val::Px(DOT_SIZE),
+                                ..default()
+                            },
+                            BackgroundColor(self.dot_color.with_alpha(0.75)),
+                            BorderRadius::MAX,
+                        ));
+                    }
+                });
+            });
+
+            // X axis range and label, under the plot area
+            chart.spawn(Node {
+                width: Val::Px(self.width),
+                margin: UiRect::left(Val::Px(24.0)),
+                justify_content: JustifyContent::SpaceBetween,
+                ..default()
+            }).with_children(|axis| {
+                axis.spawn((Text::new(format!("{:.0}", min.x)), axis_font.clone(), axis_color));
+                axis.spawn((Text::new(self.x_label.clone()), axis_font.clone(), axis_color));
+                axis.spawn((Text::new(format!("{:.0}", max.x)), axis_font, axis_color));
+            });
+        });
+    }
+}
@@ -24,9 +24,10 @@ impl Plugin for DropdownPlugin {
                 (
                     DropdownSystem::ProcessInput
                         .after(bevy::input::InputSystem)
-                        .before(bevy::ui::UiSystem::Layout),
-                    DropdownSystem::UpdateAnimation.after(DropdownSystem::ProcessInput),
-                    DropdownSystem::UpdateVisuals.after(DropdownSystem::UpdateAnimation),
+                        .before(bevy::ui::UiSystem::Layout)
+                        .in_set(crate::GameSet::Input),
+                    DropdownSystem::UpdateAnimation.after(DropdownSystem::ProcessInput).in_set(crate::GameSet::UI),
+                    DropdownSystem::UpdateVisuals.after(DropdownSystem::UpdateAnimation).in_set(crate::GameSet::UI),
                 )
                 .chain(),
             )
@@ -37,8 +38,8 @@ impl Plugin for DropdownPlugin {
                 dropdown_keyboard_system.in_set(DropdownSystem::ProcessInput),
                 dropdown_animation_system.in_set(DropdownSystem::UpdateAnimation),
                 dropdown_visual_update_system.in_set(DropdownSystem::UpdateVisuals),
-                dropdown_z_index_system,
-                dropdown_focus_management_system,
+                dropdown_z_index_system.in_set(crate::GameSet::UI),
+                dropdown_focus_management_system.in_set(crate::GameSet::UI),
             ));
     }
 }
\ No newline at end of file
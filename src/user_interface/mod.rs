@@ -11,6 +11,7 @@ pub mod scrollable;
 pub mod scrollable_systems;
 pub mod tab_group;
 pub mod progress_bar;
+pub mod chart;
 
 use styles::*;
 use slider::SliderPlugin;
@@ -35,6 +36,6 @@ impl Plugin for UserInterfacePlugin {
                 mouse_wheel_scroll_system,
                 scrollbar_drag_system,
                 update_scrollbar_system,
-            ));
+            ).in_set(crate::GameSet::Input));
     }
 }
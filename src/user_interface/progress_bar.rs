@@ -723,10 +723,10 @@ impl Plugin for ProgressBarPlugin {
                 progress_bar_animation_system,
                 progress_bar_text_system,
                 progress_threshold_system,
-            ).chain());
+            ).chain().in_set(crate::GameSet::UI));
 
         #[cfg(debug_assertions)]
-        app.add_systems(Update, progress_bar_contrast_check_system);
+        app.add_systems(Update, progress_bar_contrast_check_system.in_set(crate::GameSet::UI));
     }
 }
 
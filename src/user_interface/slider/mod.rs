@@ -23,8 +23,9 @@ impl Plugin for SliderPlugin {
                 (
                     SliderSystem::ProcessInput
                         .after(bevy::input::InputSystem)
-                        .before(bevy::ui::UiSystem::Layout),
-                    SliderSystem::UpdateVisuals.after(SliderSystem::ProcessInput),
+                        .before(bevy::ui::UiSystem::Layout)
+                        .in_set(crate::GameSet::Input),
+                    SliderSystem::UpdateVisuals.after(SliderSystem::ProcessInput).in_set(crate::GameSet::UI),
                 )
                     .chain(),
             )
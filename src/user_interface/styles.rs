@@ -37,4 +37,44 @@ impl Default for PanelStyle {
             border_color: Color::srgb(0.3, 0.3, 0.3),
         }
     }
-}
\ No newline at end of file
+}
+// The colors from assets/data/ui_theme.ron that the shared button and panel styles use
+#[derive(Debug, Clone, Copy, serde::Deserialize)]
+pub struct ThemeColor {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+    pub a: f32,
+}
+
+impl From<ThemeColor> for Color {
+    fn from(color: ThemeColor) -> Self {
+        Color::srgba(color.r, color.g, color.b, color.a)
+    }
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct ThemeColors {
+    pub background: ThemeColor,
+    pub background_hover: ThemeColor,
+    pub background_pressed: ThemeColor,
+    pub border_default: ThemeColor,
+    pub panel_background: ThemeColor,
+    pub panel_border: ThemeColor,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct UiThemeConfig {
+    pub colors: ThemeColors,
+}
+
+impl UiThemeConfig {
+    pub fn apply(&self, button_style: &mut ButtonStyle, panel_style: &mut PanelStyle) {
+        button_style.normal_color = self.colors.background.into();
+        button_style.hovered_color = self.colors.background_hover.into();
+        button_style.pressed_color = self.colors.background_pressed.into();
+        button_style.border_color = self.colors.border_default.into();
+        panel_style.background_color = self.colors.panel_background.into();
+        panel_style.border_color = self.colors.panel_border.into();
+    }
+}
@@ -12,8 +12,9 @@ impl Plugin for TabPlugin {
                 (
                     TabSystem::ProcessInput
                         .after(bevy::input::InputSystem)
-                        .before(bevy::ui::UiSystem::Layout),
-                    TabSystem::UpdateContent.after(TabSystem::ProcessInput),
+                        .before(bevy::ui::UiSystem::Layout)
+                        .in_set(crate::GameSet::Input),
+                    TabSystem::UpdateContent.after(TabSystem::ProcessInput).in_set(crate::GameSet::UI),
                 )
                 .chain(),
             )
@@ -22,8 +23,8 @@ impl Plugin for TabPlugin {
                 tab_keyboard_navigation_system.in_set(TabSystem::ProcessInput),
                 tab_focus_system.in_set(TabSystem::ProcessInput),
                 tab_content_management_system.in_set(TabSystem::UpdateContent),
-                tab_content_visibility_system.after(TabSystem::UpdateContent), // Responds to events
+                tab_content_visibility_system.after(TabSystem::UpdateContent).in_set(crate::GameSet::UI), // Responds to events
             ))
-            .add_systems(Update, tab_continuous_visual_update_system); // Independent continuous system
+            .add_systems(Update, tab_continuous_visual_update_system.in_set(crate::GameSet::UI)); // Independent continuous system
     }
 }
\ No newline at end of file
@@ -21,9 +21,10 @@ impl Plugin for TogglePlugin {
                 (
                     ToggleSystem::ProcessInput
                         .after(bevy::input::InputSystem)
-                        .before(bevy::ui::UiSystem::Layout),
-                    ToggleSystem::UpdateAnimation.after(ToggleSystem::ProcessInput),
-                    ToggleSystem::UpdateVisuals.after(ToggleSystem::UpdateAnimation),
+                        .before(bevy::ui::UiSystem::Layout)
+                        .in_set(crate::GameSet::Input),
+                    ToggleSystem::UpdateAnimation.after(ToggleSystem::ProcessInput).in_set(crate::GameSet::UI),
+                    ToggleSystem::UpdateVisuals.after(ToggleSystem::UpdateAnimation).in_set(crate::GameSet::UI),
                 )
                 .chain(),
             )
@@ -36,6 +37,6 @@ impl Plugin for TogglePlugin {
             ));
         
         #[cfg(debug_assertions)]
-        app.add_systems(Update, debug_toggle_lifecycle_system);
+        app.add_systems(Update, debug_toggle_lifecycle_system.in_set(crate::GameSet::UI));
     }
 }
\ No newline at end of file
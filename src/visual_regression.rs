@@ -0,0 +1,243 @@
+// Visual Regression - Walks the UI screens, screenshots each one and compares it against stored baselines
+//
+// Built only with `--features visual-regression`. The run takes over the game, steps through the
+// main menu, settings, every journal tab and the catalog, then exits with an error code if any
+// screen drifted from its baseline. Set PERCH_UPDATE_BASELINES=1 to accept the current screens.
+// Headless CI needs a render-capable display, e.g. `xvfb-run cargo run --features visual-regression`.
+use bevy::prelude::*;
+use bevy::asset::RenderAssetUsages;
+use bevy::image::{CompressedImageFormats, ImageSampler, ImageType};
+use bevy::render::view::screenshot::{Screenshot, ScreenshotCaptured};
+use bevy::window::{PrimaryWindow, WindowResolution};
+use std::path::PathBuf;
+use crate::AppState;
+use crate::clip_recorder::ClipLibrary;
+use crate::difficulty::DifficultySettings;
+use crate::journal::components::JournalTab;
+use crate::journal::systems::JOURNAL_TABS;
+use crate::journal::resources::JournalState;
+use crate::menu::resources::GameSettings;
+
+const SHOT_WIDTH: f32 = 1280.0;
+const SHOT_HEIGHT: f32 = 720.0;
+// Real seconds a screen gets to build and lay out before it is captured
+const SETTLE_SECONDS: f32 = 1.5;
+// Channel difference below this counts as the same pixel, covering font antialiasing
+const PIXEL_TOLERANCE: u8 = 8;
+// Share of differing pixels a screen may have before it fails
+const MAX_DIFF_RATIO: f32 = 0.005;
+
+pub struct VisualRegressionPlugin;
+
+impl Plugin for VisualRegressionPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .insert_resource(VisualRegression::new())
+            .add_systems(PostStartup, reset_user_profile_system)
+            .add_systems(Update, (
+                hide_world_sprites_system,
+                visual_regression_system,
+            ).chain().in_set(crate::GameSet::UI));
+    }
+}
+
+struct Shot {
+    name: String,
+    state: AppState,
+    journal_tab: Option<JournalTab>,
+}
+
+#[derive(PartialEq)]
+enum Phase {
+    // Waiting for the loading screen to hand over to the main menu
+    Booting,
+    NextShot,
+    Settling(f32),
+    // Waiting on the screenshot observer
+    Capturing,
+    Done,
+}
+
+#[derive(Resource)]
+pub struct VisualRegression {
+    shots: Vec<Shot>,
+    current: usize,
+    phase: Phase,
+    update_baselines: bool,
+    failures: Vec<String>,
+}
+
+impl VisualRegression {
+    fn new() -> Self {
+        let mut shots = vec![
+            Shot { name: "main_menu".to_string(), state: AppState::MainMenu, journal_tab: None },
+            Shot { name: "settings".to_string(), state: AppState::Settings, journal_tab: None },
+        ];
+        for (tab, _, _) in JOURNAL_TABS {
+            shots.push(Shot {
+                name: format!("journal_{:?}", tab).to_lowercase(),
+                state: AppState::Journal,
+                journal_tab: Some(tab),
+            });
+        }
+        shots.push(Shot { name: "catalog".to_string(), state: AppState::Catalog, journal_tab: None });
+
+        Self {
+            shots,
+            current: 0,
+            phase: Phase::Booting,
+            update_baselines: std::env::var("PERCH_UPDATE_BASELINES").is_ok_and(|value| value == "1"),
+            failures: Vec::new(),
+        }
+    }
+}
+
+fn baseline_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests").join("visual_baselines")
+}
+
+fn output_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("target").join("visual_regression")
+}
+
+// Saved settings, difficulty and clips would make every machine's screens differ from the baselines
+fn reset_user_profile_system(
+    mut commands: Commands,
+    mut window_query: Query<&mut Window, With<PrimaryWindow>>,
+) {
+    commands.insert_resource(GameSettings::default());
+    commands.insert_resource(DifficultySettings::default());
+    commands.insert_resource(ClipLibrary::default());
+
+    for mut window in &mut window_query {
+        window.resolution = WindowResolution::new(SHOT_WIDTH, SHOT_HEIGHT).with_scale_factor_override(1.0);
+    }
+}
+
+// Birds, weather and particles move between runs, so only the UI is captured
+fn hide_world_sprites_system(mut sprite_query: Query<&mut Visibility, With<Sprite>>) {
+    for mut visibility in &mut sprite_query {
+        visibility.set_if_neq(Visibility::Hidden);
+    }
+}
+
+fn visual_regression_system(
+    mut commands: Commands,
+    mut harness: ResMut<VisualRegression>,
+    state: Res<State<AppState>>,
+    mut next_state: ResMut<NextState<AppState>>,
+    mut journal_state: ResMut<JournalState>,
+    mut exit_events: EventWriter<AppExit>,
+    time: Res<Time<Real>>,
+) {
+    match harness.phase {
+        Phase::Booting => {
+            if *state.get() != AppState::LoadingScreen {
+                harness.phase = Phase::NextShot;
+            }
+        }
+        Phase::NextShot => {
+            if harness.current >= harness.shots.len() {
+                finish(&harness, &mut exit_events);
+                harness.phase = Phase::Done;
+                return;
+            }
+            begin_shot(&harness, &mut next_state, &mut journal_state);
+            harness.phase = Phase::Settling(0.0);
+        }
+        Phase::Settling(elapsed) => {
+            let elapsed = elapsed + time.delta_secs();
+            harness.phase = Phase::Settling(elapsed);
+            if elapsed >= SETTLE_SECONDS {
+                commands.spawn(Screenshot::primary_window()).observe(screenshot_captured);
+                harness.phase = Phase::Capturing;
+            }
+        }
+        Phase::Capturing | Phase::Done => {}
+    }
+}
+
+fn begin_shot(harness: &VisualRegression, next_state: &mut NextState<AppState>, journal_state: &mut JournalState) {
+    let shot = &harness.shots[harness.current];
+    next_state.set(shot.state);
+    if let Some(tab) = shot.journal_tab {
+        journal_state.current_tab = tab;
+    }
+    info!("📸 Visual regression: {} ({}/{})", shot.name, harness.current + 1, harness.shots.len());
+}
+
+fn screenshot_captured(trigger: Trigger<ScreenshotCaptured>, mut harness: ResMut<VisualRegression>) {
+    let Some(shot) = harness.shots.get(harness.current) else { return };
+    let name = shot.name.clone();
+
+    match compare_with_baseline(&name, &trigger.event().0, harness.update_baselines) {
+        Ok(()) => {}
+        Err(reason) => {
+            error!("❌ Visual regression: {} - {}", name, reason);
+            harness.failures.push(format!("{}: {}", name, reason));
+        }
+    }
+
+    harness.current += 1;
+    harness.phase = Phase::NextShot;
+}
+
+fn compare_with_baseline(name: &str, captured: &Image, update_baselines: bool) -> Result<(), String> {
+    let actual = captured.clone()
+        .try_into_dynamic()
+        .map_err(|e| format!("could not read capture: {}", e))?
+        .to_rgba8();
+    let baseline_path = baseline_dir().join(format!("{}.png", name));
+
+    if update_baselines || !baseline_path.exists() {
+        std::fs::create_dir_all(baseline_dir()).map_err(|e| e.to_string())?;
+        actual.save(&baseline_path).map_err(|e| e.to_string())?;
+        info!("🆕 Visual regression: wrote baseline {:?}", baseline_path);
+        return Ok(());
+    }
+
+    let bytes = std::fs::read(&baseline_path).map_err(|e| format!("could not read baseline: {}", e))?;
+    let expected = Image::from_buffer(
+        &bytes,
+        ImageType::Extension("png"),
+        CompressedImageFormats::NONE,
+        true,
+        ImageSampler::Default,
+        RenderAssetUsages::default(),
+    )
+        .map_err(|e| format!("could not decode baseline: {}", e))?
+        .try_into_dynamic()
+        .map_err(|e| format!("could not read baseline: {}", e))?
+        .to_rgba8();
+
+    if actual.dimensions() != expected.dimensions() {
+        return Err(format!("size {:?} does not match baseline {:?}", actual.dimensions(), expected.dimensions()));
+    }
+
+    let differing = actual.pixels().zip(expected.pixels())
+        .filter(|(a, b)| a.0.iter().zip(b.0.iter()).any(|(a, b)| a.abs_diff(*b) > PIXEL_TOLERANCE))
+        .count();
+    let ratio = differing as f32 / (actual.width() * actual.height()).max(1) as f32;
+    if ratio <= MAX_DIFF_RATIO {
+        return Ok(());
+    }
+
+    // Keep the offending capture next to the build output for inspection
+    let _ = std::fs::create_dir_all(output_dir());
+    let actual_path = output_dir().join(format!("{}.png", name));
+    let _ = actual.save(&actual_path);
+    Err(format!("{:.2}% of pixels differ, capture saved to {:?}", ratio * 100.0, actual_path))
+}
+
+fn finish(harness: &VisualRegression, exit_events: &mut EventWriter<AppExit>) {
+    if harness.failures.is_empty() {
+        info!("✅ Visual regression: all {} screens match", harness.shots.len());
+        exit_events.write(AppExit::Success);
+    } else {
+        error!("❌ Visual regression: {} of {} screens differ", harness.failures.len(), harness.shots.len());
+        for failure in &harness.failures {
+            error!("   {}", failure);
+        }
+        exit_events.write(AppExit::error());
+    }
+}
@@ -0,0 +1,295 @@
+// WASM Mods - Compiled mods run in a wasmtime host alongside the Lua scripts
+//
+// Built only with `--features wasm-mods`. Every .wasm file in the player's mods/wasm folder is
+// instantiated at startup against a versioned interface, bumped whenever an import or export
+// below changes shape. Calls run on fuel so a runaway mod can't stall a frame, and a mod that
+// traps or runs dry is disabled with a warning toast instead of taking the game down.
+//
+// Interface v1, strings are (pointer, length) pairs of UTF-8 in the mod's exported `memory`:
+//   Exports  perch_api_version() -> i32               must return WASM_API_VERSION
+//            perch_alloc(len: i32) -> i32             optional, needed to receive strings
+//            perch_init()                             optional, once after loading
+//            perch_update(dt: f32)                    optional, every frame
+//            on_weather_changed(weather: i32, temp: f32)       optional, see weather_code
+//            on_photo_taken(species: i32, len: i32, score: i32) optional, species "" if none
+//   Imports  perch.log(msg: i32, len: i32)
+//            perch.spawn_smart_object(id: i32, len: i32, x: f32, y: f32) -> i32   1 if queued
+//            perch.register_species_pack(ron: i32, len: i32) -> i32              1 if it parsed
+use bevy::prelude::*;
+use std::fs;
+use std::path::PathBuf;
+use wasmtime::{Caller, Config, Engine, Instance, Linker, Memory, Module, Store, TypedFunc};
+use crate::environment::components::Weather;
+use crate::environment::resources::WeatherChangeEvent;
+use crate::notifications::{resources::ShowNotificationEvent, components::NotificationType};
+use crate::photo_mode::components::PhotoTakenEvent;
+use crate::smart_objects::SpawnSmartObjectEvent;
+use crate::species_packs::{SpeciesPack, SpeciesPackManager};
+
+pub const WASM_API_VERSION: i32 = 1;
+// Fuel per call; roughly one unit per wasm instruction
+const FUEL_PER_CALL: u64 = 5_000_000;
+const MAX_STRING_LEN: usize = 64 * 1024;
+
+pub struct WasmModsPlugin;
+
+impl Plugin for WasmModsPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .insert_non_send_resource(WasmMods::default())
+            .add_systems(Startup, load_wasm_mods_system)
+            .add_systems(Update, (
+                wasm_mod_update_system,
+                wasm_mod_requests_system,
+            ).chain().in_set(crate::GameSet::Simulation));
+    }
+}
+
+pub fn wasm_mod_directory() -> PathBuf {
+    dirs::data_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("AvianHaven")
+        .join("mods")
+        .join("wasm")
+}
+
+/// Stable numbering of the weather for on_weather_changed
+pub fn weather_code(weather: Weather) -> i32 {
+    match weather {
+        Weather::Clear => 0,
+        Weather::Cloudy => 1,
+        Weather::Rainy => 2,
+        Weather::Snowy => 3,
+        Weather::Windy => 4,
+        Weather::Foggy => 5,
+    }
+}
+
+// What a mod asked the game to do during a call, applied afterwards by wasm_mod_requests_system
+enum HostRequest {
+    SpawnSmartObject { definition_id: String, position: Vec2 },
+    RegisterSpeciesPack(Box<SpeciesPack>),
+}
+
+struct HostState {
+    mod_name: String,
+    requests: Vec<HostRequest>,
+}
+
+struct WasmMod {
+    name: String,
+    store: Store<HostState>,
+    memory: Option<Memory>,
+    alloc: Option<TypedFunc<i32, i32>>,
+    update: Option<TypedFunc<f32, ()>>,
+    weather_changed: Option<TypedFunc<(i32, f32), ()>>,
+    photo_taken: Option<TypedFunc<(i32, i32, i32), ()>>,
+    disabled: bool,
+}
+
+impl WasmMod {
+    fn instantiate(engine: &Engine, linker: &Linker<HostState>, name: String, bytes: &[u8]) -> Result<Self, String> {
+        let module = Module::new(engine, bytes).map_err(|e| e.to_string())?;
+        let mut store = Store::new(engine, HostState { mod_name: name.clone(), requests: Vec::new() });
+        store.set_fuel(FUEL_PER_CALL).map_err(|e| e.to_string())?;
+        let instance: Instance = linker.instantiate(&mut store, &module).map_err(|e| e.to_string())?;
+
+        let version = instance.get_typed_func::<(), i32>(&mut store, "perch_api_version")
+            .map_err(|_| "missing perch_api_version export".to_string())?
+            .call(&mut store, ())
+            .map_err(|e| e.to_string())?;
+        if version != WASM_API_VERSION {
+            return Err(format!("built for mod API v{}, this game speaks v{}", version, WASM_API_VERSION));
+        }
+
+        let mut wasm_mod = Self {
+            name,
+            memory: instance.get_memory(&mut store, "memory"),
+            alloc: instance.get_typed_func(&mut store, "perch_alloc").ok(),
+            update: instance.get_typed_func(&mut store, "perch_update").ok(),
+            weather_changed: instance.get_typed_func(&mut store, "on_weather_changed").ok(),
+            photo_taken: instance.get_typed_func(&mut store, "on_photo_taken").ok(),
+            store,
+            disabled: false,
+        };
+        if let Ok(init) = instance.get_typed_func::<(), ()>(&mut wasm_mod.store, "perch_init") {
+            wasm_mod.store.set_fuel(FUEL_PER_CALL).map_err(|e| e.to_string())?;
+            init.call(&mut wasm_mod.store, ()).map_err(|e| e.to_string())?;
+        }
+        Ok(wasm_mod)
+    }
+
+    // Copies a string into the mod's memory through its own allocator
+    fn write_string(&mut self, text: &str) -> Option<(i32, i32)> {
+        let (alloc, memory) = (self.alloc.clone()?, self.memory?);
+        self.store.set_fuel(FUEL_PER_CALL).ok()?;
+        let pointer = alloc.call(&mut self.store, text.len() as i32).ok()?;
+        memory.write(&mut self.store, pointer as usize, text.as_bytes()).ok()?;
+        Some((pointer, text.len() as i32))
+    }
+
+    // Runs one export on a fresh tank of fuel, disabling the mod if it traps
+    fn call<P: wasmtime::WasmParams>(
+        &mut self,
+        function: Option<TypedFunc<P, ()>>,
+        params: P,
+        notifications: &mut EventWriter<ShowNotificationEvent>,
+    ) {
+        let Some(function) = function else { return };
+        if self.disabled {
+            return;
+        }
+        let result = self.store.set_fuel(FUEL_PER_CALL)
+            .and_then(|_| function.call(&mut self.store, params));
+        if let Err(e) = result {
+            self.disabled = true;
+            warn!("WASM mod '{}' disabled: {}", self.name, e);
+            notifications.write(ShowNotificationEvent {
+                notification: NotificationType::Warning {
+                    message: format!("Mod '{}' stopped working and was disabled", self.name),
+                },
+            });
+        }
+    }
+}
+
+// Resources
+#[derive(Default)]
+pub struct WasmMods {
+    mods: Vec<WasmMod>,
+}
+
+fn read_guest_string(caller: &mut Caller<'_, HostState>, pointer: i32, length: i32) -> Option<String> {
+    let memory = caller.get_export("memory")?.into_memory()?;
+    let (start, length) = (usize::try_from(pointer).ok()?, usize::try_from(length).ok()?);
+    if length > MAX_STRING_LEN {
+        return None;
+    }
+    let bytes = memory.data(&caller).get(start..start.checked_add(length)?)?;
+    String::from_utf8(bytes.to_vec()).ok()
+}
+
+fn host_linker(engine: &Engine) -> wasmtime::Result<Linker<HostState>> {
+    let mut linker = Linker::new(engine);
+
+    linker.func_wrap("perch", "log", |mut caller: Caller<'_, HostState>, pointer: i32, length: i32| {
+        if let Some(message) = read_guest_string(&mut caller, pointer, length) {
+            info!("WASM mod '{}': {}", caller.data().mod_name, message);
+        }
+    })?;
+
+    linker.func_wrap("perch", "spawn_smart_object", |mut caller: Caller<'_, HostState>, pointer: i32, length: i32, x: f32, y: f32| -> i32 {
+        let Some(definition_id) = read_guest_string(&mut caller, pointer, length) else { return 0 };
+        caller.data_mut().requests.push(HostRequest::SpawnSmartObject { definition_id, position: Vec2::new(x, y) });
+        1
+    })?;
+
+    linker.func_wrap("perch", "register_species_pack", |mut caller: Caller<'_, HostState>, pointer: i32, length: i32| -> i32 {
+        let Some(text) = read_guest_string(&mut caller, pointer, length) else { return 0 };
+        match ron::from_str::<SpeciesPack>(&text) {
+            Ok(pack) => {
+                caller.data_mut().requests.push(HostRequest::RegisterSpeciesPack(Box::new(pack)));
+                1
+            }
+            Err(e) => {
+                warn!("WASM mod '{}' sent a species pack that doesn't parse: {}", caller.data().mod_name, e);
+                0
+            }
+        }
+    })?;
+
+    Ok(linker)
+}
+
+pub fn load_wasm_mods_system(mut wasm_mods: NonSendMut<WasmMods>) {
+    let Ok(entries) = fs::read_dir(wasm_mod_directory()) else { return };
+
+    let engine = match Engine::new(Config::new().consume_fuel(true)) {
+        Ok(engine) => engine,
+        Err(e) => {
+            error!("Could not start the WASM mod host: {}", e);
+            return;
+        }
+    };
+    let linker = match host_linker(&engine) {
+        Ok(linker) => linker,
+        Err(e) => {
+            error!("Could not set up the WASM mod interface: {}", e);
+            return;
+        }
+    };
+
+    for path in entries.flatten().map(|entry| entry.path()).filter(|path| path.extension().is_some_and(|ext| ext == "wasm")) {
+        let name = path.file_stem().map_or_else(String::new, |stem| stem.to_string_lossy().to_string());
+        let loaded = fs::read(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|bytes| WasmMod::instantiate(&engine, &linker, name.clone(), &bytes));
+        match loaded {
+            Ok(wasm_mod) => {
+                info!("Loaded WASM mod '{}'", name);
+                wasm_mods.mods.push(wasm_mod);
+            }
+            Err(e) => error!("Could not load WASM mod {:?}: {}", path, e),
+        }
+    }
+}
+
+// Hands each mod the frame's time and any events it exports a handler for
+pub fn wasm_mod_update_system(
+    mut wasm_mods: NonSendMut<WasmMods>,
+    mut weather_events: EventReader<WeatherChangeEvent>,
+    mut photo_events: EventReader<PhotoTakenEvent>,
+    mut notifications: EventWriter<ShowNotificationEvent>,
+    time: Res<Time>,
+) {
+    let weather: Vec<(i32, f32)> = weather_events.read()
+        .map(|event| (weather_code(event.new_weather), event.temperature))
+        .collect();
+    let photos: Vec<(String, i32)> = photo_events.read()
+        .map(|event| (event.species.map_or(String::new(), |species| format!("{:?}", species)), event.score.total_score as i32))
+        .collect();
+
+    for wasm_mod in wasm_mods.mods.iter_mut() {
+        let update = wasm_mod.update.clone();
+        wasm_mod.call(update, time.delta_secs(), &mut notifications);
+
+        for (code, temperature) in &weather {
+            let handler = wasm_mod.weather_changed.clone();
+            wasm_mod.call(handler, (*code, *temperature), &mut notifications);
+        }
+        for (species, score) in &photos {
+            if wasm_mod.photo_taken.is_none() || wasm_mod.disabled {
+                continue;
+            }
+            let Some((pointer, length)) = wasm_mod.write_string(species) else { continue };
+            let handler = wasm_mod.photo_taken.clone();
+            wasm_mod.call(handler, (pointer, length, *score), &mut notifications);
+        }
+    }
+}
+
+// Applies what the mods asked for this frame
+pub fn wasm_mod_requests_system(
+    mut wasm_mods: NonSendMut<WasmMods>,
+    mut spawn_events: EventWriter<SpawnSmartObjectEvent>,
+    mut pack_assets: ResMut<Assets<SpeciesPack>>,
+    mut pack_manager: ResMut<SpeciesPackManager>,
+) {
+    for wasm_mod in wasm_mods.mods.iter_mut() {
+        for request in wasm_mod.store.data_mut().requests.drain(..) {
+            match request {
+                HostRequest::SpawnSmartObject { definition_id, position } => {
+                    spawn_events.write(SpawnSmartObjectEvent {
+                        definition_id,
+                        position: position.extend(0.0),
+                        custom_modifications: None,
+                    });
+                }
+                HostRequest::RegisterSpeciesPack(pack) => {
+                    info!("WASM mod '{}' registered species pack '{}'", wasm_mod.name, pack.name);
+                    pack_manager.track(pack_assets.add(*pack));
+                }
+            }
+        }
+    }
+}
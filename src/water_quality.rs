@@ -0,0 +1,437 @@
+// Water Quality - Baths and ponds foul with heat and use, and still water grows algae
+//
+// Every bird bath, pond and stream carries a quality meter that drops a little each day, faster
+// in hot weather and with every bird that drinks or splashes in it. Once still water gets murky
+// in the warmth, algae blooms and turns it visibly green, and birds stop bathing there until it
+// is scrubbed out. Fountains and streams keep moving and foul slower without ever blooming, and a
+// solar aerator does the same for a still bath. Right-click any water feature to look after it.
+use bevy::prelude::*;
+use crate::bird_ai::components::{BirdAI, BirdAction, BirdState, ProvidesUtility};
+use crate::catalog::components::{PlaceableItemType, PlaceableObject};
+use crate::catalog::resources::PlayerInventory;
+use crate::environment::resources::{TimeState, WeatherState};
+use crate::feeder::Feeder;
+use crate::notifications::{resources::ShowNotificationEvent, components::NotificationType};
+use crate::camera::cursor_world_position;
+
+const PANEL_WIDTH: f32 = 320.0;
+// Quality lost a day in mild weather, and for each degree above WARM_TEMPERATURE
+const DAILY_FOULING: f32 = 0.06;
+const HEAT_FOULING: f32 = 0.01;
+const WARM_TEMPERATURE: f32 = 20.0;
+// Quality lost for each second a bird spends drinking or bathing
+const USE_FOULING: f32 = 0.002;
+const USE_RANGE: f32 = 40.0;
+// Moving water fouls at this share of the still-water rate
+const MOVING_WATER_FOULING: f32 = 0.5;
+// Algae takes hold in warm water below this quality, and covers it in about this many days
+const BLOOM_QUALITY: f32 = 0.4;
+const BLOOM_TEMPERATURE: f32 = 15.0;
+const BLOOM_DAYS: f32 = 4.0;
+const BLOOM_WARNING: f32 = 0.3;
+const ALGAE_GREEN: Color = Color::srgb(0.35, 0.6, 0.25);
+const AERATOR_COST: u32 = 150;
+const CLICK_RANGE: f32 = 35.0;
+
+pub struct WaterQualityPlugin;
+
+impl Plugin for WaterQualityPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<WaterCare>()
+            .add_systems(Update, (attach_water_quality_system, water_fouling_system, algae_bloom_system)
+                .chain()
+                .in_set(crate::GameSet::Simulation)
+                .run_if(in_state(crate::AppState::Playing)))
+            .add_systems(Update, water_click_system
+                .run_if(crate::debug_console::console_is_not_visible)
+                .in_set(crate::GameSet::Input)
+                .run_if(in_state(crate::AppState::Playing)))
+            .add_systems(Update, (water_button_system, refresh_water_panel_system)
+                .chain()
+                .in_set(crate::GameSet::UI)
+                .run_if(in_state(crate::AppState::Playing)))
+            .add_systems(OnExit(crate::AppState::Playing), close_water_panel_system);
+    }
+}
+
+// Resources
+#[derive(Resource, Default)]
+pub struct WaterCare {
+    // Water feature whose care panel is open
+    pub selected: Option<Entity>,
+    panel_dirty: bool,
+}
+
+// Components
+#[derive(Component)]
+pub struct WaterQuality {
+    // 1.0 freshly scrubbed and refilled, 0.0 fouled
+    pub quality: f32,
+    // Share of the surface covered in algae, 0-1
+    pub algae: f32,
+    // Fountains and streams keep moving and never bloom
+    pub moving: bool,
+    // Tint restored when the water is cleaned
+    pub clean_color: Color,
+    bloom_warned: bool,
+}
+
+impl WaterQuality {
+    /// How appealing the water is for an action; algae puts birds off bathing far more than drinking
+    pub fn attraction(&self, action: BirdAction) -> f32 {
+        match action {
+            BirdAction::Bathe => (0.4 + 0.6 * self.quality) * (1.0 - self.algae * 0.9),
+            _ => (0.6 + 0.4 * self.quality) * (1.0 - self.algae * 0.4),
+        }
+    }
+
+    pub fn condition(&self) -> &'static str {
+        match self.quality {
+            q if q >= 0.75 => "Clear",
+            q if q >= 0.4 => "Murky",
+            _ => "Foul",
+        }
+    }
+}
+
+/// Keeps still water moving so it fouls slower and algae can't take hold
+#[derive(Component)]
+pub struct SolarAerator;
+
+#[derive(Component)]
+pub struct WaterPanel;
+
+#[derive(Component)]
+pub struct WaterButton {
+    pub action: WaterAction,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WaterAction {
+    Scrub,
+    InstallAerator,
+    Close,
+}
+
+fn moving_water(item_type: Option<&PlaceableItemType>) -> bool {
+    matches!(item_type, Some(PlaceableItemType::FountainBirdbath | PlaceableItemType::StreamFeature))
+}
+
+// Placed baths go by their catalog name, the yard's own water is the pond
+fn feature_title(object: Option<&PlaceableObject>) -> &str {
+    object.map_or("Pond", |object| object.item_type.name())
+}
+
+fn feature_name(object: Option<&PlaceableObject>) -> String {
+    feature_title(object).to_lowercase()
+}
+
+type NewWaterQuery<'w, 's> = Query<'w, 's, (
+    Entity,
+    &'static ProvidesUtility,
+    &'static Sprite,
+    Option<&'static PlaceableObject>,
+), (Added<ProvidesUtility>, Without<Feeder>, Without<WaterQuality>)>;
+
+// Anything visible that birds drink or bathe at, apart from nectar feeders
+fn attach_water_quality_system(
+    mut commands: Commands,
+    water_query: NewWaterQuery,
+) {
+    for (entity, utility, sprite, object) in &water_query {
+        if !matches!(utility.action, BirdAction::Drink | BirdAction::Bathe) {
+            continue;
+        }
+        commands.entity(entity).insert(WaterQuality {
+            quality: 1.0,
+            algae: 0.0,
+            moving: moving_water(object.map(|object| &object.item_type)),
+            clean_color: sprite.color,
+            bloom_warned: false,
+        });
+    }
+}
+
+// Heat fouls the water a little all day, and every bird using it a little more
+fn water_fouling_system(
+    mut water_query: Query<(&mut WaterQuality, &Transform, Option<&SolarAerator>)>,
+    bird_query: Query<(&Transform, &BirdState), With<BirdAI>>,
+    weather_state: Res<WeatherState>,
+    time_state: Res<TimeState>,
+    time: Res<Time>,
+) {
+    let days_elapsed = time.delta_secs() / (time_state.time_speed * 24.0);
+    let heat = (weather_state.temperature - WARM_TEMPERATURE).max(0.0);
+
+    for (mut water, transform, aerator) in &mut water_query {
+        let position = transform.translation.truncate();
+        let users = bird_query.iter()
+            .filter(|(bird_transform, state)| {
+                matches!(state, BirdState::Drinking | BirdState::Bathing)
+                    && bird_transform.translation.truncate().distance(position) < USE_RANGE
+            })
+            .count();
+
+        let mut fouling = days_elapsed * (DAILY_FOULING + heat * HEAT_FOULING) + users as f32 * USE_FOULING * time.delta_secs();
+        if water.moving || aerator.is_some() {
+            fouling *= MOVING_WATER_FOULING;
+        }
+        water.quality = (water.quality - fouling).max(0.0);
+    }
+}
+
+// Algae spreads over murky, warm, still water and dies back once it's clean or moving again
+fn algae_bloom_system(
+    mut water_query: Query<(&mut WaterQuality, &mut Sprite, Option<&PlaceableObject>, Option<&SolarAerator>)>,
+    weather_state: Res<WeatherState>,
+    time_state: Res<TimeState>,
+    mut care: ResMut<WaterCare>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+    time: Res<Time>,
+) {
+    let days_elapsed = time.delta_secs() / (time_state.time_speed * 24.0);
+    let warm = weather_state.temperature >= BLOOM_TEMPERATURE;
+
+    for (mut water, mut sprite, object, aerator) in &mut water_query {
+        let still = !water.moving && aerator.is_none();
+        let growing = still && warm && water.quality < BLOOM_QUALITY;
+        let change = days_elapsed / BLOOM_DAYS;
+        let algae = if growing { water.algae + change } else { water.algae - change };
+        let algae = algae.clamp(0.0, 1.0);
+        if algae == water.algae {
+            continue;
+        }
+        // The panel shows whole percents, so only rebuild it when one ticks over
+        if (algae * 100.0).round() != (water.algae * 100.0).round() && care.selected.is_some() {
+            care.panel_dirty = true;
+        }
+        water.algae = algae;
+        sprite.color = water.clean_color.mix(&ALGAE_GREEN, water.algae * 0.8);
+
+        if water.algae >= BLOOM_WARNING && !water.bloom_warned {
+            water.bloom_warned = true;
+            notification_events.write(ShowNotificationEvent {
+                notification: NotificationType::Warning {
+                    message: format!("Algae is blooming in the {} - birds won't bathe there until it's scrubbed. Right-click it to clean", feature_name(object)),
+                },
+            });
+        }
+    }
+}
+
+// Right-click a water feature to open its care panel
+pub fn water_click_system(
+    mouse_button: Res<ButtonInput<MouseButton>>,
+    windows: Query<&Window>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<Camera2d>>,
+    water_query: Query<(Entity, &Transform), With<WaterQuality>>,
+    mut care: ResMut<WaterCare>,
+) {
+    if !mouse_button.just_pressed(MouseButton::Right) {
+        return;
+    }
+    let Ok(window) = windows.single() else { return };
+    let Ok((camera, camera_transform)) = camera_query.single() else { return };
+    let Some(cursor) = cursor_world_position(window, camera, camera_transform) else { return };
+
+    let clicked = water_query.iter()
+        .find(|(_, transform)| transform.translation.truncate().distance(cursor) <= CLICK_RANGE);
+    if let Some((entity, _)) = clicked {
+        care.selected = Some(entity);
+        care.panel_dirty = true;
+    }
+}
+
+pub fn water_button_system(
+    mut commands: Commands,
+    mut button_query: Query<(&Interaction, &WaterButton, &mut BackgroundColor), Changed<Interaction>>,
+    mut care: ResMut<WaterCare>,
+    mut inventory: ResMut<PlayerInventory>,
+    mut water_query: Query<(&mut WaterQuality, &mut Sprite, Option<&PlaceableObject>)>,
+    mut notification_events: EventWriter<ShowNotificationEvent>,
+) {
+    let mut chosen = None;
+    for (interaction, button, mut bg_color) in &mut button_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *bg_color = Color::srgb(0.5, 0.7, 0.5).into();
+                chosen = Some(button.action);
+            }
+            Interaction::Hovered => *bg_color = Color::srgb(0.7, 0.6, 0.5).into(),
+            Interaction::None => *bg_color = Color::srgb(0.6, 0.5, 0.4).into(),
+        }
+    }
+    let Some(action) = chosen else { return };
+    let Some(feature) = care.selected else { return };
+    care.panel_dirty = true;
+    let Ok((mut water, mut sprite, object)) = water_query.get_mut(feature) else {
+        care.selected = None;
+        return;
+    };
+
+    match action {
+        WaterAction::Scrub => {
+            water.quality = 1.0;
+            water.algae = 0.0;
+            water.bloom_warned = false;
+            sprite.color = water.clean_color;
+            notification_events.write(ShowNotificationEvent {
+                notification: NotificationType::Info {
+                    message: format!("Scrubbed out and refilled the {}", feature_name(object)),
+                },
+            });
+        }
+        WaterAction::InstallAerator => {
+            if inventory.currency < AERATOR_COST {
+                notification_events.write(ShowNotificationEvent {
+                    notification: NotificationType::Warning {
+                        message: format!("A solar aerator costs {} coins", AERATOR_COST),
+                    },
+                });
+                return;
+            }
+            inventory.currency -= AERATOR_COST;
+            commands.entity(feature).insert(SolarAerator);
+            notification_events.write(ShowNotificationEvent {
+                notification: NotificationType::Info {
+                    message: format!("Installed a solar aerator in the {} - it'll stay fresh longer and algae can't take hold", feature_name(object)),
+                },
+            });
+        }
+        WaterAction::Close => care.selected = None,
+    }
+}
+
+pub fn refresh_water_panel_system(
+    mut commands: Commands,
+    mut care: ResMut<WaterCare>,
+    water_query: Query<(&WaterQuality, Option<&PlaceableObject>, Option<&SolarAerator>)>,
+    panel_query: Query<Entity, With<WaterPanel>>,
+) {
+    if !care.panel_dirty {
+        return;
+    }
+    care.panel_dirty = false;
+
+    for panel in &panel_query {
+        commands.entity(panel).despawn();
+    }
+    let Some(feature) = care.selected else { return };
+    let Ok((water, object, aerator)) = water_query.get(feature) else {
+        care.selected = None;
+        return;
+    };
+
+    spawn_water_panel(&mut commands, water, feature_title(object), aerator.is_some());
+}
+
+pub fn close_water_panel_system(
+    mut commands: Commands,
+    mut care: ResMut<WaterCare>,
+    panel_query: Query<Entity, With<WaterPanel>>,
+) {
+    care.selected = None;
+    for panel in &panel_query {
+        commands.entity(panel).despawn();
+    }
+}
+
+fn spawn_water_panel(commands: &mut Commands, water: &WaterQuality, title: &str, aerated: bool) {
+    let mut actions = vec![(WaterAction::Scrub, "Scrub and refill".to_string())];
+    if !water.moving && !aerated {
+        actions.push((WaterAction::InstallAerator, format!("Install solar aerator ({})", AERATOR_COST)));
+    }
+    actions.push((WaterAction::Close, "Close".to_string()));
+
+    let circulation = if water.moving {
+        "Moving water"
+    } else if aerated {
+        "Solar aerator running"
+    } else {
+        "Still water"
+    };
+
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            right: Val::Px(20.0),
+            top: Val::Px(80.0),
+            width: Val::Px(PANEL_WIDTH),
+            flex_direction: FlexDirection::Column,
+            padding: UiRect::all(Val::Px(14.0)),
+            row_gap: Val::Px(6.0),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.95, 0.92, 0.88, 0.95)),
+        BorderRadius::all(Val::Px(8.0)),
+        WaterPanel,
+        Name::new("WaterCare"),
+    )).with_children(|panel| {
+        panel.spawn((
+            Text::new(title),
+            TextFont { font_size: 18.0, ..default() },
+            TextColor(Color::srgb(0.3, 0.2, 0.1)),
+        ));
+
+        // Quality meter
+        panel.spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Px(10.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.85, 0.82, 0.78)),
+            BorderRadius::all(Val::Px(5.0)),
+        )).with_children(|meter| {
+            meter.spawn((
+                Node {
+                    width: Val::Percent(water.quality * 100.0),
+                    height: Val::Percent(100.0),
+                    ..default()
+                },
+                BackgroundColor(Color::srgb(0.35, 0.55, 0.75)),
+                BorderRadius::all(Val::Px(5.0)),
+            ));
+        });
+
+        panel.spawn((
+            Text::new(format!("{} ({:.0}%) | Algae: {:.0}% | {}",
+                water.condition(), water.quality * 100.0, water.algae * 100.0, circulation)),
+            TextFont { font_size: 12.0, ..default() },
+            TextColor(Color::srgb(0.4, 0.3, 0.2)),
+        ));
+        panel.spawn((
+            Text::new("Heat and every visiting bird foul the water. Murky still water grows algae in warm weather, and birds won't bathe in a green bath."),
+            TextFont { font_size: 11.0, ..default() },
+            TextColor(Color::srgb(0.5, 0.4, 0.3)),
+        ));
+
+        panel.spawn(Node {
+            width: Val::Percent(100.0),
+            flex_wrap: FlexWrap::Wrap,
+            column_gap: Val::Px(4.0),
+            row_gap: Val::Px(4.0),
+            ..default()
+        }).with_children(|buttons| {
+            for (action, label) in actions {
+                buttons.spawn((
+                    Button,
+                    Node {
+                        padding: UiRect::axes(Val::Px(8.0), Val::Px(4.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.6, 0.5, 0.4)),
+                    BorderRadius::all(Val::Px(4.0)),
+                    WaterButton { action },
+                )).with_children(|button| {
+                    button.spawn((
+                        Text::new(label),
+                        TextFont { font_size: 11.0, ..default() },
+                        TextColor(Color::WHITE),
+                    ));
+                });
+            }
+        });
+    });
+}
@@ -0,0 +1,214 @@
+// Bird Weather - Rain and snow on the birds themselves
+//
+// Birds out in the open soak up rain, or collect snow more slowly, while those under cover - a
+// shelter bush, roost tree or storm shelter - stay dry. A wet bird shakes itself off every few
+// seconds with a quick wobble and a spray of droplets, and its wetness draws it toward cover from
+// further away than a dry one would look. When a shower ends, puddles are left around the yard
+// in proportion to how long it rained; birds drink and bathe in them until they dry up.
+use bevy::prelude::*;
+use rand::Rng;
+use super::components::*;
+use crate::advanced_weather::WeatherShelter;
+use crate::bird_ai::components::{BirdAction, BirdAI, BirdState, ProvidesUtility, SmartObject};
+use crate::despawn::SafeDespawn;
+use crate::environment::components::Weather;
+use crate::environment::resources::WeatherState;
+
+// Per second out in the open; snow settles slower than rain soaks in
+const RAIN_SOAK_RATE: f32 = 0.05;
+const SNOW_SOAK_RATE: f32 = 0.02;
+const DRY_RATE: f32 = 0.015;
+// Birds this close to cover are out of the weather
+const COVER_RADIUS: f32 = 60.0;
+// Wetter than this and birds start shaking off
+const SHAKE_THRESHOLD: f32 = 0.3;
+const SHAKE_SECONDS: f32 = 0.5;
+const SHAKE_FREQUENCY: f32 = 40.0;
+const SHAKE_ANGLE: f32 = 0.25;
+// Share of the water a shake gets rid of
+const SHAKE_DRYING: f32 = 0.15;
+const SHAKE_DROPLETS: usize = 6;
+// A puddle for every this many seconds of rain
+const SECONDS_PER_PUDDLE: f32 = 45.0;
+const MAX_PUDDLES: usize = 5;
+// Seconds a puddle lasts under cloud; sun and heat dry it faster, frost holds it
+const PUDDLE_SECONDS: f32 = 240.0;
+const PUDDLE_MIN_SPACING: f32 = 80.0;
+
+pub fn bird_wetness_system(
+    mut commands: Commands,
+    mut bird_query: Query<(Entity, &Transform, &BirdState, &mut Wetness)>,
+    dry_bird_query: Query<Entity, (With<BirdAI>, Without<Wetness>)>,
+    cover_query: Query<(&Transform, &ProvidesUtility)>,
+    shelter_query: Query<&Transform, With<WeatherShelter>>,
+    weather_state: Res<WeatherState>,
+    time: Res<Time>,
+) {
+    let soak_rate = match weather_state.current_weather {
+        Weather::Rainy => RAIN_SOAK_RATE,
+        Weather::Snowy => SNOW_SOAK_RATE,
+        _ => 0.0,
+    };
+    let droplet_color = match weather_state.current_weather {
+        Weather::Snowy => Color::srgba(1.0, 1.0, 1.0, 0.8),
+        _ => Color::srgba(0.6, 0.8, 1.0, 0.7),
+    };
+    let cover: Vec<Vec2> = cover_query.iter()
+        .filter(|(_, utility)| matches!(utility.action, BirdAction::Shelter | BirdAction::Roost))
+        .map(|(transform, _)| transform.translation.truncate())
+        .chain(shelter_query.iter().map(|transform| transform.translation.truncate()))
+        .collect();
+    let dt = time.delta_secs();
+    let mut rng = rand::rng();
+
+    // Birds only start keeping track once the weather first wets them
+    if soak_rate > 0.0 {
+        for entity in &dry_bird_query {
+            commands.entity(entity).insert(Wetness::default());
+        }
+    }
+
+    for (entity, transform, state, mut wetness) in &mut bird_query {
+        let position = transform.translation.truncate();
+        let covered = matches!(state, BirdState::Sheltering | BirdState::Roosting | BirdState::Nesting)
+            || cover.iter().any(|cover| cover.distance(position) <= COVER_RADIUS);
+        wetness.level = if soak_rate > 0.0 && !covered {
+            (wetness.level + soak_rate * dt).min(1.0)
+        } else {
+            (wetness.level - DRY_RATE * dt).max(0.0)
+        };
+
+        // Nobody shakes off mid-flight
+        let settled = !matches!(state, BirdState::MovingToTarget | BirdState::Fleeing);
+        if wetness.level < SHAKE_THRESHOLD || wetness.shaking.is_some() || !settled {
+            continue;
+        }
+        wetness.shake_cooldown -= dt;
+        if wetness.shake_cooldown > 0.0 {
+            continue;
+        }
+        // The wetter the bird, the sooner it shakes again
+        wetness.shake_cooldown = rng.random_range(3.0..8.0) / wetness.level;
+        wetness.shaking = Some(0.0);
+        wetness.level *= 1.0 - SHAKE_DRYING;
+        spawn_shake_droplets(&mut commands, entity, position, droplet_color);
+    }
+}
+
+fn spawn_shake_droplets(commands: &mut Commands, bird: Entity, position: Vec2, color: Color) {
+    let mut rng = rand::rng();
+    for _ in 0..SHAKE_DROPLETS {
+        // Flung out sideways and a little up, then falling
+        let side = if rng.random_bool(0.5) { 1.0 } else { -1.0 };
+        commands.spawn((
+            Sprite::from_color(color, Vec2::new(1.5, 1.5)),
+            Transform::from_xyz(position.x, position.y + 4.0, 1.2),
+            InteractiveParticle {
+                particle_type: InteractiveParticleType::BirdSplash { splash_size: 1.5 },
+                velocity: Vec2::new(side * rng.random_range(20.0..50.0), rng.random_range(10.0..40.0)),
+                lifetime: rng.random_range(0.6..1.2),
+                source_entity: Some(bird),
+            },
+        ));
+    }
+}
+
+// The shake itself, a quick wobble that dies away; the tilt it added is taken back off as it goes
+pub fn bird_shake_animation_system(
+    mut bird_query: Query<(&mut Transform, &mut Wetness)>,
+    time: Res<Time>,
+) {
+    for (mut transform, mut wetness) in &mut bird_query {
+        let Some(elapsed) = wetness.shaking else { continue };
+        let elapsed = elapsed + time.delta_secs();
+        let tilt = if elapsed < SHAKE_SECONDS {
+            wetness.shaking = Some(elapsed);
+            (elapsed * SHAKE_FREQUENCY).sin() * SHAKE_ANGLE * (1.0 - elapsed / SHAKE_SECONDS)
+        } else {
+            wetness.shaking = None;
+            0.0
+        };
+        transform.rotate_z(tilt - wetness.applied_tilt);
+        wetness.applied_tilt = tilt;
+    }
+}
+
+// Lays puddles when a shower ends, tops them up if it rains again, and dries them out
+pub fn puddle_system(
+    mut commands: Commands,
+    mut effects_state: ResMut<WeatherEffectsState>,
+    mut puddle_query: Query<(Entity, &Transform, &mut Puddle, Option<&mut Sprite>)>,
+    weather_state: Res<WeatherState>,
+    time: Res<Time>,
+) {
+    let dt = time.delta_secs();
+    let raining = weather_state.current_weather == Weather::Rainy;
+
+    if raining {
+        effects_state.rain_seconds += dt;
+    } else if effects_state.rain_seconds > 0.0 {
+        // Each puddle is two smart objects at one spot, one to drink from and one to bathe in
+        let mut spots: Vec<Vec2> = puddle_query.iter().map(|(_, transform, ..)| transform.translation.truncate()).collect();
+        let wanted = ((effects_state.rain_seconds / SECONDS_PER_PUDDLE).ceil() as usize).min(MAX_PUDDLES);
+        let existing = puddle_query.iter().count() / 2;
+        let mut rng = rand::rng();
+        for _ in existing..wanted {
+            // A few tries at a spot clear of the other puddles
+            let spot = (0..8)
+                .map(|_| Vec2::new(rng.random_range(-450.0..450.0), rng.random_range(-280.0..120.0)))
+                .find(|spot| spots.iter().all(|other| other.distance(*spot) >= PUDDLE_MIN_SPACING));
+            let Some(spot) = spot else { continue };
+            spots.push(spot);
+            spawn_puddle(&mut commands, spot, Vec2::new(rng.random_range(30.0..55.0), rng.random_range(12.0..20.0)));
+        }
+        if wanted > existing {
+            info!("💧 Rain left {} puddles", wanted - existing);
+        }
+        effects_state.rain_seconds = 0.0;
+    }
+
+    let drying = match weather_state.current_weather {
+        _ if weather_state.temperature <= 0.0 => 0.0,
+        Weather::Rainy => -1.0,
+        Weather::Clear => 1.5,
+        _ => 1.0,
+    } * (1.0 + (weather_state.temperature - 20.0).max(0.0) / 20.0);
+
+    for (entity, _, mut puddle, sprite) in &mut puddle_query {
+        puddle.water = (puddle.water - drying * dt / PUDDLE_SECONDS).min(1.0);
+        if puddle.water <= 0.0 {
+            commands.entity(entity).safe_despawn();
+            continue;
+        }
+        if let Some(mut sprite) = sprite {
+            sprite.custom_size = Some(puddle.size * (0.4 + 0.6 * puddle.water));
+            sprite.color.set_alpha(0.3 + 0.3 * puddle.water);
+        }
+    }
+}
+
+fn spawn_puddle(commands: &mut Commands, position: Vec2, size: Vec2) {
+    commands.spawn((
+        Sprite::from_color(Color::srgba(0.45, 0.55, 0.7, 0.6), size),
+        Transform::from_translation(position.extend(0.05)),
+        SmartObject,
+        ProvidesUtility {
+            action: BirdAction::Drink,
+            base_utility: 0.5,
+            range: 160.0,
+        },
+        Puddle { water: 1.0, size },
+        Name::new("Puddle"),
+    ));
+    commands.spawn((
+        Transform::from_translation(position.extend(0.05)),
+        SmartObject,
+        ProvidesUtility {
+            action: BirdAction::Bathe,
+            base_utility: 0.6,
+            range: 140.0,
+        },
+        Puddle { water: 1.0, size },
+        Name::new("PuddleBath"),
+    ));
+}
@@ -42,6 +42,8 @@ pub struct WeatherEffectsState {
     pub environmental_timer: f32,
     pub wind_strength: f32,
     pub wind_direction: Vec2,
+    // Seconds of rain since the last puddles were laid, decides how many appear when it stops
+    pub rain_seconds: f32,
 }
 
 #[derive(Component)]
@@ -64,4 +66,24 @@ pub enum InteractiveParticleType {
 pub enum WeatherType {
     Rain,
     Snow,
-}
\ No newline at end of file
+}
+
+// How soaked a bird's plumage is, 0 dry to 1 drenched
+#[derive(Component, Default)]
+pub struct Wetness {
+    pub level: f32,
+    // Seconds until the bird next shakes itself off
+    pub shake_cooldown: f32,
+    // Seconds into the current shake
+    pub shaking: Option<f32>,
+    // Tilt the shake has put on the bird, taken off again as it settles
+    pub applied_tilt: f32,
+}
+
+// Rainwater standing after a shower; a drink and a bath for birds until it dries up
+#[derive(Component)]
+pub struct Puddle {
+    // 1 just after the rain, drying to 0
+    pub water: f32,
+    pub size: Vec2,
+}
@@ -0,0 +1,152 @@
+// Fog - Foggy weather settling over the yard and closing in how far you can see
+//
+// Fog thickens over a minute or so once the weather turns foggy, thinning as the morning wears on,
+// and burns off again when it changes. A full-screen shader lays a pale, drifting veil over the
+// yard that washes out contrast. While it's down, the feeder ticker only lists feeders near the
+// middle of the view and the binoculars need a bird closer to center before they'll pick it out.
+// Photos taken in it pick up a mood bonus for the atmosphere but lose sharpness.
+use bevy::prelude::*;
+use bevy::render::render_resource::{AsBindGroup, ShaderRef};
+use crate::advanced_weather::WindManager;
+use crate::environment::components::Weather;
+use crate::environment::resources::{TimeState, WeatherState};
+use crate::photo_mode::components::PhotoScore;
+
+// Seconds for fog to come in fully or lift
+const FOG_TRANSITION_SECONDS: f32 = 60.0;
+const MORNING_DENSITY: f32 = 0.9;
+const DAYTIME_DENSITY: f32 = 0.6;
+// Fog burns off by late morning
+const BURN_OFF_HOUR: f32 = 10.0;
+// World units from the middle of the view that can still be made out, in clear air and in the thickest fog
+const CLEAR_VISIBILITY: f32 = 1200.0;
+const THICK_VISIBILITY: f32 = 250.0;
+// Binoculars' pick-up radius in the thickest fog, as a share of normal
+const THICK_DETECTION: f32 = 0.4;
+// Photo points for the atmosphere, and the share of sharpness lost, in the thickest fog
+const MOOD_BONUS: f32 = 40.0;
+const SHARPNESS_LOSS: f32 = 0.6;
+const FOG_COLOR: Color = Color::srgb(0.86, 0.88, 0.9);
+
+// Resources
+#[derive(Resource, Default)]
+pub struct Fog {
+    // 0 clear to 1 as thick as it gets
+    pub density: f32,
+}
+
+impl Fog {
+    pub fn is_down(&self) -> bool {
+        self.density > 0.05
+    }
+
+    /// How far from the middle of the view birds can still be made out, in world units
+    pub fn visibility_range(&self) -> f32 {
+        CLEAR_VISIBILITY - (CLEAR_VISIBILITY - THICK_VISIBILITY) * self.density
+    }
+
+    pub fn can_see(&self, viewer: Vec2, position: Vec2) -> bool {
+        !self.is_down() || viewer.distance(position) <= self.visibility_range()
+    }
+
+    /// Multiplier on how far from center the binoculars pick a bird out
+    pub fn detection_factor(&self) -> f32 {
+        1.0 - (1.0 - THICK_DETECTION) * self.density
+    }
+
+    // Soft, moody light is worth something; the haze between lens and bird costs sharpness
+    pub fn adjust_photo(&self, score: &mut PhotoScore) {
+        if !self.is_down() {
+            return;
+        }
+        let mood = (MOOD_BONUS * self.density) as u32;
+        let keep = 1.0 - SHARPNESS_LOSS * self.density;
+        let clarity = (score.clarity_score as f32 * keep) as u32;
+        let technical = (score.technical_score as f32 * keep) as u32;
+        let penalty = (score.clarity_score - clarity) + (score.technical_score - technical);
+        score.environment_score += mood;
+        score.clarity_score = clarity;
+        score.technical_score = technical;
+        score.total_score = (score.total_score + mood).saturating_sub(penalty);
+        info!("  Fog: +{} mood, -{} sharpness", mood, penalty);
+    }
+}
+
+#[derive(Asset, TypePath, AsBindGroup, Debug, Clone)]
+pub struct FogMaterial {
+    #[uniform(0)]
+    color: LinearRgba,
+    // Density, seconds elapsed, drift speed, unused
+    #[uniform(1)]
+    params: Vec4,
+}
+
+impl UiMaterial for FogMaterial {
+    fn fragment_shader() -> ShaderRef {
+        "shaders/fog.wgsl".into()
+    }
+}
+
+// Components
+#[derive(Component)]
+pub struct FogOverlay;
+
+pub fn setup_fog_overlay(mut commands: Commands, mut materials: ResMut<Assets<FogMaterial>>) {
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            ..default()
+        },
+        MaterialNode(materials.add(FogMaterial {
+            color: FOG_COLOR.into(),
+            params: Vec4::ZERO,
+        })),
+        // Over the yard but under every panel and HUD
+        GlobalZIndex(-10),
+        Visibility::Hidden,
+        Pickable::IGNORE,
+        FogOverlay,
+        StateScoped(crate::AppState::Playing),
+    ));
+}
+
+pub fn fog_density_system(
+    mut fog: ResMut<Fog>,
+    weather_state: Res<WeatherState>,
+    time_state: Res<TimeState>,
+    time: Res<Time>,
+) {
+    let target = match weather_state.current_weather {
+        Weather::Foggy if time_state.hour < BURN_OFF_HOUR => MORNING_DENSITY,
+        Weather::Foggy => DAYTIME_DENSITY,
+        _ => 0.0,
+    };
+    let step = time.delta_secs() / FOG_TRANSITION_SECONDS;
+    let density = fog.density + (target - fog.density).clamp(-step, step);
+    // Only touch the resource when it moves, so readers can rely on change detection
+    if density != fog.density {
+        fog.density = density;
+    }
+}
+
+pub fn fog_overlay_system(
+    fog: Res<Fog>,
+    wind: Res<WindManager>,
+    mut materials: ResMut<Assets<FogMaterial>>,
+    mut overlay_query: Query<(&MaterialNode<FogMaterial>, &mut Visibility), With<FogOverlay>>,
+    time: Res<Time>,
+) {
+    for (material, mut visibility) in &mut overlay_query {
+        let shown = if fog.is_down() { Visibility::Inherited } else { Visibility::Hidden };
+        visibility.set_if_neq(shown);
+        if !fog.is_down() {
+            continue;
+        }
+        let Some(material) = materials.get_mut(&material.0) else { continue };
+        // Banks roll with the wind, barely moving on a still morning
+        let drift = 0.5 + wind.current_effects.speed_kmh / 10.0;
+        material.params = Vec4::new(fog.density, time.elapsed_secs(), drift, 0.0);
+    }
+}
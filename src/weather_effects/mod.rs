@@ -2,9 +2,13 @@ use bevy::prelude::*;
 
 pub mod components;
 pub mod systems;
+pub mod bird_weather;
+pub mod fog;
 
 use components::*;
 use systems::*;
+use bird_weather::*;
+use fog::*;
 
 pub struct WeatherEffectsPlugin;
 
@@ -12,7 +16,10 @@ impl Plugin for WeatherEffectsPlugin {
     fn build(&self, app: &mut App) {
         app
             .init_resource::<WeatherEffectsState>()
+            .init_resource::<Fog>()
+            .add_plugins(UiMaterialPlugin::<FogMaterial>::default())
             .add_systems(Startup, setup_weather_particles)
+            .add_systems(OnEnter(crate::AppState::Playing), setup_fog_overlay)
             .add_systems(Update, (
                 weather_particle_system,
                 rain_particle_movement,
@@ -20,8 +27,14 @@ impl Plugin for WeatherEffectsPlugin {
                 environmental_particle_system,
                 environmental_particle_movement,
                 // interactive_particle_system,
-                // interactive_particle_movement,
+                interactive_particle_movement,
                 particle_cleanup_system,
-            ).run_if(in_state(crate::AppState::Playing)));
+            ).in_set(crate::GameSet::Presentation).run_if(in_state(crate::AppState::Playing)))
+            .add_systems(Update, (bird_wetness_system, puddle_system, fog_density_system)
+                .in_set(crate::GameSet::Simulation)
+                .run_if(in_state(crate::AppState::Playing)))
+            .add_systems(Update, (bird_shake_animation_system, fog_overlay_system)
+                .in_set(crate::GameSet::Presentation)
+                .run_if(in_state(crate::AppState::Playing)));
     }
 }
\ No newline at end of file
@@ -4,6 +4,7 @@ use super::components::*;
 use crate::environment::{resources::{WeatherState, WeatherChangeEvent, TimeState}, components::{Weather, Season}};
 use crate::bird_ai::components::{BirdAI, BirdState};
 use crate::animation::components::AnimatedBird;
+use crate::performance::governor::FrameBudgetGovernor;
 use rand::Rng;
 
 const RAIN_SPAWN_RATE: f32 = 0.01; // particles per frame per intensity
@@ -25,6 +26,7 @@ pub fn weather_particle_system(
     time: Res<Time>,
     rain_query: Query<Entity, With<RainParticle>>,
     snow_query: Query<Entity, With<SnowParticle>>,
+    governor: Res<FrameBudgetGovernor>,
 ) {
     // Handle weather change events
     for event in weather_events.read() {
@@ -58,7 +60,8 @@ pub fn weather_particle_system(
             WeatherType::Snow => SNOW_SPAWN_RATE,
         };
         
-        if effects_state.spawn_timer > spawn_rate && effects_state.particle_count < MAX_PARTICLES {
+        let max_particles = (MAX_PARTICLES as f32 * governor.quality().particle_scale) as usize;
+        if effects_state.spawn_timer > spawn_rate && effects_state.particle_count < max_particles {
             effects_state.spawn_timer = 0.0;
             spawn_weather_particle(&mut commands, weather_type, &weather_state);
             effects_state.particle_count += 1;
@@ -130,11 +133,12 @@ pub fn environmental_particle_system(
     time_state: Res<TimeState>,
     time: Res<Time>,
     camera_query: Query<&Transform, With<Camera2d>>,
+    governor: Res<FrameBudgetGovernor>,
 ) {
     effects_state.environmental_timer += time.delta().as_secs_f32();
     
-    // Spawn environmental particles based on season
-    if effects_state.environmental_timer > 2.0 { // Every 2 seconds
+    // Spawn environmental particles based on season, less often when the governor has cut quality
+    if effects_state.environmental_timer > 2.0 / governor.quality().particle_scale { // Every 2 seconds at full quality
         effects_state.environmental_timer = 0.0;
         
         let Ok(camera_transform) = camera_query.single() else {
@@ -432,6 +436,9 @@ pub fn interactive_particle_movement(
                 particle.velocity.y -= 150.0 * time.delta().as_secs_f32(); // Faster gravity for water
                 particle.velocity *= 0.95; // Air resistance
             },
+            InteractiveParticleType::BirdSplash { .. } => {
+                particle.velocity.y -= 150.0 * time.delta().as_secs_f32(); // Droplets shaken off a wet bird
+            },
             _ => {}
         }
         
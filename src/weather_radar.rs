@@ -0,0 +1,319 @@
+// Weather Radar - Minimap of incoming precipitation with a clickable forecast panel
+use bevy::prelude::*;
+use crate::advanced_weather::{PrecipitationKind, PrecipitationRadar, StormManager, WindManager, RADAR_RANGE_KM};
+use crate::environment::resources::{TimeState, WeatherState};
+
+const RADAR_SIZE: f32 = 130.0;
+const FORECAST_HOURS: u32 = 6;
+const TRACK_HOURS: [f32; 3] = [1.0, 2.0, 3.0];
+
+pub struct WeatherRadarPlugin;
+
+impl Plugin for WeatherRadarPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<RadarDisplay>()
+            .add_systems(OnEnter(crate::AppState::Playing), setup_weather_radar)
+            .add_systems(OnExit(crate::AppState::Playing), cleanup_weather_radar)
+            .add_systems(Update, (
+                radar_click_system,
+                radar_blip_system,
+                refresh_forecast_panel_system,
+            ).chain().in_set(crate::GameSet::UI).run_if(in_state(crate::AppState::Playing)));
+    }
+}
+
+// Resources
+#[derive(Resource)]
+pub struct RadarDisplay {
+    pub forecast_open: bool,
+    sweep_timer: Timer,
+    forecast_dirty: bool,
+}
+
+impl Default for RadarDisplay {
+    fn default() -> Self {
+        Self {
+            forecast_open: false,
+            sweep_timer: Timer::from_seconds(0.5, TimerMode::Repeating),
+            forecast_dirty: false,
+        }
+    }
+}
+
+// Components
+#[derive(Component)]
+pub struct WeatherRadar;
+
+#[derive(Component)]
+pub struct RadarBlip;
+
+#[derive(Component)]
+pub struct ForecastPanel;
+
+fn setup_weather_radar(mut commands: Commands) {
+    commands.spawn((
+        Button,
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(20.0),
+            bottom: Val::Px(80.0),
+            width: Val::Px(RADAR_SIZE),
+            height: Val::Px(RADAR_SIZE),
+            border: UiRect::all(Val::Px(2.0)),
+            overflow: Overflow::clip(),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.08, 0.18, 0.12, 0.85)),
+        BorderColor(Color::srgb(0.4, 0.7, 0.45)),
+        BorderRadius::all(Val::Px(RADAR_SIZE / 2.0)),
+        WeatherRadar,
+        Name::new("WeatherRadar"),
+    )).with_children(|radar| {
+        // Half-range ring
+        radar.spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(RADAR_SIZE / 4.0 - 2.0),
+                top: Val::Px(RADAR_SIZE / 4.0 - 2.0),
+                width: Val::Px(RADAR_SIZE / 2.0),
+                height: Val::Px(RADAR_SIZE / 2.0),
+                border: UiRect::all(Val::Px(1.0)),
+                ..default()
+            },
+            BorderColor(Color::srgba(0.4, 0.7, 0.45, 0.5)),
+            BorderRadius::all(Val::Px(RADAR_SIZE / 4.0)),
+        ));
+
+        // The sanctuary at the center
+        radar.spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(RADAR_SIZE / 2.0 - 5.0),
+                top: Val::Px(RADAR_SIZE / 2.0 - 5.0),
+                width: Val::Px(6.0),
+                height: Val::Px(6.0),
+                ..default()
+            },
+            BackgroundColor(Color::WHITE),
+            BorderRadius::all(Val::Px(3.0)),
+        ));
+
+        radar.spawn((
+            Text::new("RADAR"),
+            TextFont { font_size: 9.0, ..default() },
+            TextColor(Color::srgba(0.6, 0.9, 0.65, 0.8)),
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(RADAR_SIZE / 2.0 - 16.0),
+                bottom: Val::Px(8.0),
+                ..default()
+            },
+        ));
+    });
+}
+
+fn cleanup_weather_radar(
+    mut commands: Commands,
+    mut display: ResMut<RadarDisplay>,
+    radar_query: Query<Entity, Or<(With<WeatherRadar>, With<ForecastPanel>)>>,
+) {
+    display.forecast_open = false;
+    for entity in &radar_query {
+        commands.entity(entity).despawn();
+    }
+}
+
+fn radar_click_system(
+    radar_query: Query<&Interaction, (Changed<Interaction>, With<WeatherRadar>)>,
+    mut display: ResMut<RadarDisplay>,
+) {
+    for interaction in &radar_query {
+        if *interaction == Interaction::Pressed {
+            display.forecast_open = !display.forecast_open;
+            display.forecast_dirty = true;
+        }
+    }
+}
+
+fn blip_color(kind: PrecipitationKind, intensity: f32, alpha: f32) -> Color {
+    match kind {
+        PrecipitationKind::Rain if intensity < 0.5 => Color::srgba(0.3, 0.85, 0.35, alpha),
+        PrecipitationKind::Rain => Color::srgba(0.95, 0.85, 0.2, alpha),
+        PrecipitationKind::Snow => Color::srgba(0.75, 0.85, 1.0, alpha),
+        PrecipitationKind::Thunderstorm => Color::srgba(0.95, 0.25, 0.2, alpha),
+    }
+}
+
+// Redraws the cells and their projected tracks on each radar sweep
+fn radar_blip_system(
+    mut commands: Commands,
+    mut display: ResMut<RadarDisplay>,
+    radar: Res<PrecipitationRadar>,
+    radar_query: Query<Entity, With<WeatherRadar>>,
+    blip_query: Query<Entity, With<RadarBlip>>,
+    time: Res<Time>,
+) {
+    display.sweep_timer.tick(time.delta());
+    if !display.sweep_timer.just_finished() {
+        return;
+    }
+    // The forecast follows the radar so an open panel stays current
+    display.forecast_dirty |= display.forecast_open;
+
+    let Ok(radar_entity) = radar_query.single() else { return };
+    for blip in &blip_query {
+        commands.entity(blip).despawn();
+    }
+
+    let scale = RADAR_SIZE / 2.0 / RADAR_RANGE_KM;
+    let center = Vec2::splat(RADAR_SIZE / 2.0 - 2.0);
+
+    commands.entity(radar_entity).with_children(|parent| {
+        for cell in &radar.cells {
+            let projections = std::iter::once((0.0, 1.0)).chain(TRACK_HOURS.iter().map(|hours| (*hours, 0.25)));
+            for (hours, alpha) in projections {
+                let position = cell.position_in(hours);
+                if position.length() > RADAR_RANGE_KM {
+                    continue;
+                }
+                let size = if hours == 0.0 { (cell.radius * scale * 2.0).max(6.0) } else { 4.0 };
+                // North is up on the map, so world y flips for UI space
+                let screen = center + Vec2::new(position.x, -position.y) * scale - Vec2::splat(size / 2.0);
+
+                parent.spawn((
+                    Node {
+                        position_type: PositionType::Absolute,
+                        left: Val::Px(screen.x),
+                        top: Val::Px(screen.y),
+                        width: Val::Px(size),
+                        height: Val::Px(size),
+                        ..default()
+                    },
+                    BackgroundColor(blip_color(cell.kind, cell.intensity, alpha * 0.8)),
+                    BorderRadius::all(Val::Px(size / 2.0)),
+                    RadarBlip,
+                ));
+            }
+        }
+    });
+}
+
+fn refresh_forecast_panel_system(
+    mut commands: Commands,
+    mut display: ResMut<RadarDisplay>,
+    radar: Res<PrecipitationRadar>,
+    weather_state: Res<WeatherState>,
+    wind_manager: Res<WindManager>,
+    storm_manager: Res<StormManager>,
+    time_state: Res<TimeState>,
+    panel_query: Query<Entity, With<ForecastPanel>>,
+) {
+    if !display.forecast_dirty {
+        return;
+    }
+    display.forecast_dirty = false;
+
+    for panel in &panel_query {
+        commands.entity(panel).despawn();
+    }
+
+    if !display.forecast_open {
+        return;
+    }
+
+    let wind = &wind_manager.current_effects;
+    let mut lines = vec![format!(
+        "Now: {:?}, {:.0}°C, wind {:.0} km/h {}",
+        weather_state.current_weather,
+        weather_state.temperature,
+        wind.speed_kmh,
+        compass_direction(wind.direction),
+    )];
+    if storm_manager.is_storm_warning {
+        lines.push("⚠ Storm warning in effect - birds are seeking shelter".to_string());
+    }
+
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(RADAR_SIZE + 36.0),
+            bottom: Val::Px(80.0),
+            width: Val::Px(300.0),
+            flex_direction: FlexDirection::Column,
+            padding: UiRect::all(Val::Px(12.0)),
+            row_gap: Val::Px(4.0),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.95, 0.92, 0.88, 0.95)),
+        BorderRadius::all(Val::Px(8.0)),
+        ForecastPanel,
+    )).with_children(|panel| {
+        panel.spawn((
+            Text::new("Forecast"),
+            TextFont { font_size: 18.0, ..default() },
+            TextColor(Color::srgb(0.3, 0.2, 0.1)),
+        ));
+
+        for line in lines {
+            panel.spawn((
+                Text::new(line),
+                TextFont { font_size: 12.0, ..default() },
+                TextColor(Color::srgb(0.4, 0.3, 0.2)),
+            ));
+        }
+
+        for hour in 1..=FORECAST_HOURS {
+            let clock = (time_state.hour.floor() as u32 + hour) % 24;
+            let outlook = match radar.outlook(hour as f32) {
+                Some(cell) => format!("{} ({})", cell.kind.name(), intensity_label(cell.intensity)),
+                None => "Dry".to_string(),
+            };
+            panel.spawn((
+                Text::new(format!("{:02}:00   {}", clock, outlook)),
+                TextFont { font_size: 13.0, ..default() },
+                TextColor(Color::srgb(0.3, 0.2, 0.1)),
+            ));
+        }
+
+        let mut approaching: Vec<(f32, &str, f32)> = radar.cells.iter()
+            .filter_map(|cell| cell.hours_until_arrival().map(|hours| (hours, cell.kind.name(), cell.position.length())))
+            .filter(|(hours, _, _)| *hours > 0.0)
+            .collect();
+        approaching.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let summary = match approaching.first() {
+            Some((hours, name, distance)) => format!("{} {:.0} km out, arriving in about {:.0}h", name, distance, hours.ceil()),
+            None => "Nothing on track to reach the sanctuary".to_string(),
+        };
+        panel.spawn((
+            Text::new(summary),
+            TextFont { font_size: 12.0, ..default() },
+            TextColor(Color::srgb(0.5, 0.4, 0.3)),
+        ));
+    });
+}
+
+fn intensity_label(intensity: f32) -> &'static str {
+    match intensity {
+        i if i < 0.45 => "light",
+        i if i < 0.75 => "moderate",
+        _ => "heavy",
+    }
+}
+
+// Direction the wind is blowing from
+fn compass_direction(direction: Vec2) -> &'static str {
+    let from = -direction;
+    let angle = from.y.atan2(from.x).to_degrees().rem_euclid(360.0);
+    match ((angle + 22.5) / 45.0) as u32 % 8 {
+        0 => "from the E",
+        1 => "from the NE",
+        2 => "from the N",
+        3 => "from the NW",
+        4 => "from the W",
+        5 => "from the SW",
+        6 => "from the S",
+        _ => "from the SE",
+    }
+}